@@ -0,0 +1,205 @@
+use crate::{clients::Clients, output::Output};
+use onvif::{
+    analytics,
+    schema::{self, onvif::ReferenceToken},
+};
+use serde_json::json;
+use std::time::Duration;
+use structopt::StructOpt;
+
+#[derive(StructOpt)]
+pub enum AnalyticsCmd {
+    /// Lists the rules of an analytics configuration.
+    Rules {
+        /// Analytics configuration token; defaults to the first video analytics configuration.
+        #[structopt(long)]
+        configuration: Option<String>,
+    },
+
+    /// Lists the rule types an analytics configuration supports, with their parameters and the
+    /// values the device allows for them.
+    SupportedRules {
+        #[structopt(long)]
+        configuration: Option<String>,
+    },
+
+    /// Changes the sensitivity of a rule, e.g. of an audio detector.
+    SetSensitivity {
+        #[structopt(long)]
+        configuration: Option<String>,
+
+        /// Name of the rule.
+        rule: String,
+
+        /// New sensitivity, in the range of the rule type (commonly 0 to 100).
+        sensitivity: u32,
+    },
+
+    /// Creates a line, field or loitering detector rule.
+    CreateRule {
+        #[structopt(long)]
+        configuration: Option<String>,
+
+        /// `line`, `field` or `loitering`.
+        #[structopt(long = "type", parse(try_from_str = parse_rule_type))]
+        rule_type: RuleType,
+
+        /// Name of the new rule.
+        name: String,
+
+        /// Point of the line or field as `X,Y` in normalized coordinates (-1 to 1, y up).
+        /// Repeat for every point.
+        #[structopt(
+            long,
+            required = true,
+            allow_hyphen_values = true,
+            parse(try_from_str = crate::masks::parse_point)
+        )]
+        point: Vec<(f64, f64)>,
+
+        /// Crossings detected by line detectors: `left`, `right` or `any`.
+        #[structopt(long, default_value = "any", parse(try_from_str = parse_direction))]
+        direction: analytics::Direction,
+
+        /// Seconds an object stays in the field before loitering detectors trigger.
+        #[structopt(long, default_value = "10")]
+        time_threshold: u64,
+    },
+}
+
+pub enum RuleType {
+    Line,
+    Field,
+    Loitering,
+}
+
+fn parse_rule_type(s: &str) -> Result<RuleType, String> {
+    match s {
+        "line" => Ok(RuleType::Line),
+        "field" => Ok(RuleType::Field),
+        "loitering" => Ok(RuleType::Loitering),
+        _ => Err(format!("expected line, field or loitering, got {}", s)),
+    }
+}
+
+fn parse_direction(s: &str) -> Result<analytics::Direction, String> {
+    match s {
+        "left" => Ok(analytics::Direction::Left),
+        "right" => Ok(analytics::Direction::Right),
+        "any" => Ok(analytics::Direction::Any),
+        _ => Err(format!("expected left, right or any, got {}", s)),
+    }
+}
+
+/// Returns the given configuration token, or that of the device's first video analytics
+/// configuration.
+async fn configuration_token(
+    clients: &Clients,
+    configuration: Option<String>,
+) -> Result<ReferenceToken, String> {
+    if let Some(configuration) = configuration {
+        return Ok(ReferenceToken(configuration));
+    }
+    schema::media::get_video_analytics_configurations(clients.media()?, &Default::default())
+        .await?
+        .configurations
+        .into_iter()
+        .next()
+        .map(|c| c.token)
+        .ok_or_else(|| "device has no video analytics configurations".to_string())
+}
+
+pub async fn analytics(clients: &Clients, output: Output, cmd: AnalyticsCmd) -> Result<(), String> {
+    let analytics_client = clients.analytics()?;
+    match cmd {
+        AnalyticsCmd::Rules { configuration } => {
+            let token = configuration_token(clients, configuration).await?;
+            let rules = analytics::rules(analytics_client, &token)
+                .await
+                .map_err(|e| e.to_string())?;
+            output.print(
+                rules
+                    .iter()
+                    .map(|r| {
+                        json!({
+                            "name": r.name,
+                            "type": r.config_type,
+                            "parameters": r.simple_items,
+                            "element_items": r.element_items.keys().collect::<Vec<_>>(),
+                        })
+                    })
+                    .collect(),
+                || {
+                    for r in &rules {
+                        println!("{} ({})", r.name, r.config_type);
+                        for (name, value) in &r.simple_items {
+                            println!("    {}: {}", name, value);
+                        }
+                        for name in r.element_items.keys() {
+                            println!("    {}: <element>", name);
+                        }
+                    }
+                },
+            );
+        }
+        AnalyticsCmd::SupportedRules { configuration } => {
+            let token = configuration_token(clients, configuration).await?;
+            let rules = analytics::supported_rules(analytics_client, &token)
+                .await
+                .map_err(|e| e.to_string())?;
+            output.print(
+                serde_json::to_value(&rules).map_err(|e| e.to_string())?,
+                || {
+                    for r in &rules {
+                        let fixed = if r.fixed { ", fixed" } else { "" };
+                        match r.max_instances {
+                            Some(max) => println!("{} (up to {}{})", r.rule_type, max, fixed),
+                            None => println!("{}{}", r.rule_type, fixed),
+                        }
+                        for p in &r.parameters {
+                            match &p.options {
+                                Some(options) => {
+                                    println!("    {}: {} {:?}", p.name, p.value_type, options)
+                                }
+                                None => println!("    {}: {}", p.name, p.value_type),
+                            }
+                        }
+                    }
+                },
+            );
+        }
+        AnalyticsCmd::SetSensitivity {
+            configuration,
+            rule,
+            sensitivity,
+        } => {
+            let token = configuration_token(clients, configuration).await?;
+            analytics::set_rule_sensitivity(analytics_client, &token, &rule, sensitivity)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+        AnalyticsCmd::CreateRule {
+            configuration,
+            rule_type,
+            name,
+            point,
+            direction,
+            time_threshold,
+        } => {
+            let token = configuration_token(clients, configuration).await?;
+            let rule = match rule_type {
+                RuleType::Line => analytics::LineDetector::new(&name, &point)
+                    .direction(direction)
+                    .build(),
+                RuleType::Field => analytics::FieldDetector::new(&name, &point).build(),
+                RuleType::Loitering => analytics::LoiteringDetector::new(&name, &point)
+                    .time_threshold(Duration::from_secs(time_threshold))
+                    .build(),
+            };
+            analytics::create_rules(analytics_client, &token, &[rule])
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,270 @@
+use onvif::{
+    schema::{self, onvif as tt},
+    soap,
+    xaddr::{self, Service, XAddrPolicy},
+};
+use std::sync::Arc;
+use tracing::{debug, warn};
+use url::Url;
+
+const DEVICE_NS: &str = "http://www.onvif.org/ver10/device/wsdl";
+
+/// SOAP clients for every service advertised by a device.
+pub struct Clients {
+    pub devicemgmt: soap::client::Client,
+    pub event: Option<soap::client::Client>,
+    pub deviceio: Option<soap::client::Client>,
+    pub media: Option<soap::client::Client>,
+    pub media2: Option<soap::client::Client>,
+    pub imaging: Option<soap::client::Client>,
+    pub ptz: Option<soap::client::Client>,
+    pub analytics: Option<soap::client::Client>,
+    creds: Option<soap::client::Credentials>,
+    recorder: Option<Arc<soap::recorder::Recorder>>,
+}
+
+impl Clients {
+    /// Creates clients for the services the device advertises, at addresses `policy` allows.
+    /// Services in `overrides` use the given address instead, which isn't required to be within
+    /// `base_uri`.
+    pub async fn new(
+        base_uri: &Url,
+        creds: Option<soap::client::Credentials>,
+        recorder: Option<Arc<soap::recorder::Recorder>>,
+        overrides: &[(Service, Url)],
+        policy: XAddrPolicy,
+    ) -> Result<Self, String> {
+        let devicemgmt_uri = device_service_uri(base_uri)?;
+        let mut out = Self::device_only(base_uri, creds, recorder)?;
+        let advertised = services(&out.devicemgmt).await?;
+        let resolved = xaddr::resolve_services(policy, base_uri, &advertised, overrides)
+            .map_err(|e| e.to_string())?;
+        for (namespace, url) in resolved {
+            match Service::from_namespace(&namespace) {
+                Some(service) => *out.service_mut(service) = Some(out.builder(&url).build()),
+                None if namespace == DEVICE_NS => {
+                    if !xaddr::equivalent(&url, &devicemgmt_uri) {
+                        let message = format!(
+                            "advertised device mgmt uri {} not expected {}",
+                            &url, &devicemgmt_uri
+                        );
+                        if policy == XAddrPolicy::Strict {
+                            return Err(message);
+                        }
+                        debug!("{}", message);
+                    }
+                }
+                None => debug!("unknown service: {} at {}", namespace, url),
+            }
+        }
+        Ok(out)
+    }
+
+    fn service_mut(&mut self, service: Service) -> &mut Option<soap::client::Client> {
+        match service {
+            Service::Event => &mut self.event,
+            Service::DeviceIo => &mut self.deviceio,
+            Service::Media => &mut self.media,
+            Service::Media2 => &mut self.media2,
+            Service::Imaging => &mut self.imaging,
+            Service::Ptz => &mut self.ptz,
+            Service::Analytics => &mut self.analytics,
+        }
+    }
+
+    /// Creates only the device management client, without asking the device for its services.
+    pub fn device_only(
+        base_uri: &Url,
+        creds: Option<soap::client::Credentials>,
+        recorder: Option<Arc<soap::recorder::Recorder>>,
+    ) -> Result<Self, String> {
+        Ok(Self {
+            devicemgmt: soap::client::ClientBuilder::new(&device_service_uri(base_uri)?)
+                .credentials(creds.clone())
+                .recorder(recorder.clone())
+                .build(),
+            imaging: None,
+            ptz: None,
+            event: None,
+            deviceio: None,
+            media: None,
+            media2: None,
+            analytics: None,
+            creds,
+            recorder,
+        })
+    }
+
+    /// Returns a client builder for an address handed out by the device, such as an event
+    /// subscription.
+    pub fn builder(&self, uri: &Url) -> soap::client::ClientBuilder {
+        soap::client::ClientBuilder::new(uri)
+            .credentials(self.creds.clone())
+            .recorder(self.recorder.clone())
+    }
+
+    /// Returns the analytics client, or an error if the device doesn't advertise the analytics
+    /// service.
+    pub fn analytics(&self) -> Result<&soap::client::Client, String> {
+        self.analytics
+            .as_ref()
+            .ok_or_else(|| "device doesn't support the analytics service".to_string())
+    }
+
+    /// Returns the device I/O client, or an error if the device doesn't advertise the device I/O
+    /// service.
+    pub fn deviceio(&self) -> Result<&soap::client::Client, String> {
+        self.deviceio
+            .as_ref()
+            .ok_or_else(|| "device doesn't support the device I/O service".to_string())
+    }
+
+    /// Returns the event client, or an error if the device doesn't advertise the event service.
+    pub fn event(&self) -> Result<&soap::client::Client, String> {
+        self.event
+            .as_ref()
+            .ok_or_else(|| "device doesn't support the event service".to_string())
+    }
+
+    /// Returns the imaging client, or an error if the device doesn't advertise the imaging
+    /// service.
+    pub fn imaging(&self) -> Result<&soap::client::Client, String> {
+        self.imaging
+            .as_ref()
+            .ok_or_else(|| "device doesn't support the imaging service".to_string())
+    }
+
+    /// Returns the media client, or an error if the device doesn't advertise the media service.
+    pub fn media(&self) -> Result<&soap::client::Client, String> {
+        self.media
+            .as_ref()
+            .ok_or_else(|| "device doesn't support the media service".to_string())
+    }
+
+    /// Returns the media2 client, or an error if the device doesn't advertise the media2 service.
+    pub fn media2(&self) -> Result<&soap::client::Client, String> {
+        self.media2
+            .as_ref()
+            .ok_or_else(|| "device doesn't support the media2 service".to_string())
+    }
+
+    /// Returns the PTZ client, or an error if the device doesn't advertise the PTZ service.
+    pub fn ptz(&self) -> Result<&soap::client::Client, String> {
+        self.ptz
+            .as_ref()
+            .ok_or_else(|| "device doesn't support the PTZ service".to_string())
+    }
+}
+
+fn device_service_uri(base_uri: &Url) -> Result<Url, String> {
+    base_uri
+        .join("onvif/device_service")
+        .map_err(|e| e.to_string())
+}
+
+/// Returns the namespace and address of every service of a device.
+///
+/// Devices predating Profile S only implement `GetCapabilities`, so that is used if `GetServices`
+/// fails.
+async fn services(devicemgmt: &soap::client::Client) -> Result<Vec<(String, String)>, String> {
+    let error = match schema::devicemgmt::get_services(devicemgmt, &Default::default()).await {
+        Ok(services) => {
+            return Ok(services
+                .service
+                .into_iter()
+                .map(|s| (s.namespace, s.x_addr))
+                .collect())
+        }
+        Err(error) => error,
+    };
+
+    warn!(
+        "GetServices failed, falling back to GetCapabilities: {}",
+        error
+    );
+    let capabilities = schema::devicemgmt::get_capabilities(
+        devicemgmt,
+        &schema::devicemgmt::GetCapabilities {
+            category: vec![tt::CapabilityCategory::All],
+        },
+    )
+    .await?
+    .capabilities;
+
+    Ok(capability_services(&capabilities))
+}
+
+fn capability_services(capabilities: &tt::Capabilities) -> Vec<(String, String)> {
+    let mut services = vec![];
+    let mut add =
+        |namespace: &str, x_addr: &str| services.push((namespace.to_string(), x_addr.to_string()));
+
+    for c in &capabilities.device {
+        add(DEVICE_NS, &c.x_addr);
+    }
+    for c in &capabilities.events {
+        add(Service::Event.namespace(), &c.x_addr);
+    }
+    for c in &capabilities.media {
+        add(Service::Media.namespace(), &c.x_addr);
+    }
+    for c in &capabilities.imaging {
+        add(Service::Imaging.namespace(), &c.x_addr);
+    }
+    for c in &capabilities.ptz {
+        add(Service::Ptz.namespace(), &c.x_addr);
+    }
+    for c in &capabilities.analytics {
+        add(Service::Analytics.namespace(), &c.x_addr);
+    }
+    if let Some(c) = capabilities
+        .extension
+        .as_ref()
+        .and_then(|e| e.device_io.as_ref())
+    {
+        add(Service::DeviceIo.namespace(), &c.x_addr);
+    }
+
+    services
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn services_from_capabilities() {
+        let capabilities = tt::Capabilities {
+            device: vec![tt::DeviceCapabilities {
+                x_addr: "http://192.168.0.2/onvif/device_service".to_string(),
+                ..Default::default()
+            }],
+            media: vec![tt::MediaCapabilities {
+                x_addr: "http://192.168.0.2/onvif/media".to_string(),
+                ..Default::default()
+            }],
+            ptz: vec![tt::Ptzcapabilities {
+                x_addr: "http://192.168.0.2/onvif/ptz".to_string(),
+            }],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            capability_services(&capabilities),
+            vec![
+                (
+                    DEVICE_NS.to_string(),
+                    "http://192.168.0.2/onvif/device_service".to_string()
+                ),
+                (
+                    Service::Media.namespace().to_string(),
+                    "http://192.168.0.2/onvif/media".to_string()
+                ),
+                (
+                    Service::Ptz.namespace().to_string(),
+                    "http://192.168.0.2/onvif/ptz".to_string()
+                ),
+            ]
+        );
+    }
+}
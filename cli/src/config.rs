@@ -0,0 +1,102 @@
+use onvif::soap::client::Credentials;
+use serde::Deserialize;
+use std::{collections::HashMap, path::PathBuf};
+use url::Url;
+
+/// Optional TOML config file, by default `~/.config/onvif-cli/config.toml`:
+///
+/// ```toml
+/// [devices."192.168.0.2"]
+/// username = "admin"
+/// password = "qwerty"
+///
+/// # A port may be given to tell apart several devices behind one address.
+/// [devices."192.168.0.3:8000"]
+/// username = "admin"
+/// password = "hunter2"
+/// ```
+#[derive(Default, Deserialize)]
+pub struct Config {
+    #[serde(default)]
+    devices: HashMap<String, DeviceConfig>,
+}
+
+#[derive(Deserialize)]
+struct DeviceConfig {
+    username: String,
+    password: String,
+}
+
+impl Config {
+    /// Loads `path`, or the default location if `None`.
+    /// A missing file at the default location is not an error.
+    pub fn load(path: Option<PathBuf>) -> Result<Self, String> {
+        let (path, required) = match path {
+            Some(path) => (path, true),
+            None => match default_path() {
+                Some(path) => (path, false),
+                None => return Ok(Self::default()),
+            },
+        };
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if !required && e.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(Self::default())
+            }
+            Err(e) => return Err(format!("can't read {}: {}", path.display(), e)),
+        };
+        toml::from_str(&text).map_err(|e| format!("can't parse {}: {}", path.display(), e))
+    }
+
+    /// Returns the credentials configured for the device at `uri`, preferring an entry that
+    /// includes the port over one for the bare host.
+    pub fn credentials(&self, uri: &Url) -> Option<Credentials> {
+        let host = uri.host_str()?;
+        let with_port = uri
+            .port_or_known_default()
+            .map(|p| format!("{}:{}", host, p));
+        with_port
+            .and_then(|k| self.devices.get(&k))
+            .or_else(|| self.devices.get(host))
+            .map(|d| Credentials {
+                username: d.username.clone(),
+                password: d.password.clone(),
+            })
+    }
+}
+
+fn default_path() -> Option<PathBuf> {
+    let home = std::env::var_os("HOME")?;
+    Some(PathBuf::from(home).join(".config/onvif-cli/config.toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn credentials_lookup() {
+        let config: Config = toml::from_str(
+            r#"
+            [devices."192.168.0.2"]
+            username = "admin"
+            password = "qwerty"
+
+            [devices."192.168.0.2:8000"]
+            username = "other"
+            password = "secret"
+            "#,
+        )
+        .unwrap();
+
+        let creds = |uri: &str| {
+            config
+                .credentials(&Url::parse(uri).unwrap())
+                .map(|c| c.username)
+        };
+
+        assert_eq!(creds("http://192.168.0.2/").as_deref(), Some("admin"));
+        assert_eq!(creds("http://192.168.0.2:8000/").as_deref(), Some("other"));
+        assert_eq!(creds("http://192.168.0.3/"), None);
+    }
+}
@@ -0,0 +1,328 @@
+use crate::{clients::Clients, output::Output};
+use onvif::{
+    capabilities,
+    health::wait,
+    schema::{self, json::ToJson, onvif::UserLevel},
+};
+use serde_json::json;
+use std::time::Duration;
+use structopt::StructOpt;
+use tracing::debug;
+
+#[derive(StructOpt)]
+pub enum UserCmd {
+    /// Lists the device's users and their levels.
+    List,
+
+    /// Creates a user.
+    Add {
+        name: String,
+
+        password: String,
+
+        /// One of `administrator`, `operator` or `user`.
+        #[structopt(long, default_value = "user", parse(try_from_str = parse_user_level))]
+        level: UserLevel,
+    },
+
+    /// Deletes a user.
+    Delete { name: String },
+}
+
+fn parse_user_level(s: &str) -> Result<UserLevel, String> {
+    match s.to_ascii_lowercase().as_str() {
+        "administrator" | "admin" => Ok(UserLevel::Administrator),
+        "operator" => Ok(UserLevel::Operator),
+        "user" => Ok(UserLevel::User),
+        _ => Err(format!("unknown user level {:?}", s)),
+    }
+}
+
+fn user_level_str(level: &UserLevel) -> &str {
+    match level {
+        UserLevel::Administrator => "Administrator",
+        UserLevel::Operator => "Operator",
+        UserLevel::User => "User",
+        UserLevel::Anonymous => "Anonymous",
+        UserLevel::Extended => "Extended",
+        UserLevel::__Unknown__(s) => s,
+        _ => "Unknown",
+    }
+}
+
+pub async fn info(clients: &Clients, output: Output) -> Result<(), String> {
+    let info = schema::devicemgmt::get_device_information(&clients.devicemgmt, &Default::default())
+        .await?;
+    debug!("get_device_information response: {:#?}", &info);
+    output.print(
+        json!({
+            "manufacturer": info.manufacturer,
+            "model": info.model,
+            "firmware_version": info.firmware_version,
+            "serial_number": info.serial_number,
+            "hardware_id": info.hardware_id,
+        }),
+        || {
+            println!("manufacturer: {}", &info.manufacturer);
+            println!("model: {}", &info.model);
+            println!("firmware version: {}", &info.firmware_version);
+            println!("serial number: {}", &info.serial_number);
+            println!("hardware id: {}", &info.hardware_id);
+        },
+    );
+    Ok(())
+}
+
+pub async fn health(clients: &Clients, output: Output) -> Result<(), String> {
+    let report = onvif::health::health_check(&clients.devicemgmt).await;
+    let latency_ms = report.latency.map(|l| l.as_millis() as u64);
+    let yes_no = |b: bool| if b { "yes" } else { "no" };
+    output.print(
+        json!({
+            "reachable": report.reachable,
+            "latency_ms": latency_ms,
+            "clock_drift_s": report.clock_drift,
+            "authenticated": report.authenticated,
+            "error": report.error,
+        }),
+        || {
+            println!("reachable: {}", yes_no(report.reachable));
+            if let Some(latency_ms) = latency_ms {
+                println!("latency: {} ms", latency_ms);
+            }
+            if let Some(drift) = report.clock_drift {
+                println!("clock drift: {} s", drift);
+            }
+            if let Some(authenticated) = report.authenticated {
+                println!("authenticated: {}", yes_no(authenticated));
+            }
+            if let Some(error) = &report.error {
+                println!("error: {}", error);
+            }
+        },
+    );
+    Ok(())
+}
+
+pub async fn get_system_date_and_time(clients: &Clients, output: Output) -> Result<(), String> {
+    let resp =
+        schema::devicemgmt::get_system_date_and_time(&clients.devicemgmt, &Default::default())
+            .await?;
+    let date = &resp.system_date_and_time;
+    let format_date_time = |dt: &schema::onvif::DateTime| {
+        format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            dt.date.year, dt.date.month, dt.date.day, dt.time.hour, dt.time.minute, dt.time.second
+        )
+    };
+    output.print(
+        json!({
+            "ntp": date.date_time_type == schema::onvif::SetDateTimeType::Ntp,
+            "daylight_savings": date.daylight_savings,
+            "time_zone": date.time_zone.as_ref().map(|tz| &tz.tz),
+            "utc": date.utc_date_time.as_ref().map(format_date_time),
+            "local": date.local_date_time.as_ref().map(format_date_time),
+        }),
+        || println!("{:#?}", date),
+    );
+    Ok(())
+}
+
+pub async fn get_capabilities(clients: &Clients, output: Output) -> Result<(), String> {
+    match schema::devicemgmt::get_capabilities(&clients.devicemgmt, &Default::default()).await {
+        Ok(capabilities) => {
+            output.print(capabilities.to_json()?, || println!("{:#?}", capabilities))
+        }
+        Err(error) => println!("Failed to fetch capabilities: {}", error),
+    }
+    Ok(())
+}
+
+pub async fn get_service_capabilities(clients: &Clients, output: Output) -> Result<(), String> {
+    let report = capabilities::capabilities_report(
+        &clients.devicemgmt,
+        &capabilities::Services {
+            event: clients.event.as_ref(),
+            deviceio: clients.deviceio.as_ref(),
+            media: clients.media.as_ref(),
+            media2: clients.media2.as_ref(),
+            imaging: clients.imaging.as_ref(),
+            ptz: clients.ptz.as_ref(),
+            analytics: clients.analytics.as_ref(),
+        },
+    )
+    .await;
+    output.print(
+        serde_json::to_value(&report).map_err(|e| e.to_string())?,
+        || {
+            if let Some(c) = &report.devicemgmt {
+                println!("devicemgmt: {:#?}", c);
+            }
+            if let Some(c) = &report.event {
+                println!("event: {:#?}", c);
+            }
+            if let Some(c) = &report.deviceio {
+                println!("deviceio: {:#?}", c);
+            }
+            if let Some(c) = &report.media {
+                println!("media: {:#?}", c);
+            }
+            if let Some(c) = &report.media2 {
+                println!("media2: {:#?}", c);
+            }
+            if let Some(c) = &report.imaging {
+                println!("imaging: {:#?}", c);
+            }
+            if let Some(c) = &report.ptz {
+                println!("ptz: {:#?}", c);
+            }
+            if let Some(c) = &report.analytics {
+                println!("analytics: {:#?}", c);
+            }
+            for (service, error) in &report.errors {
+                println!("Failed to fetch {}: {}", service, error);
+            }
+        },
+    );
+    Ok(())
+}
+
+pub async fn get_hostname(clients: &Clients, output: Output) -> Result<(), String> {
+    let resp = schema::devicemgmt::get_hostname(&clients.devicemgmt, &Default::default()).await?;
+    debug!("get_hostname response: {:#?}", &resp);
+    let info = &resp.hostname_information;
+    output.print(
+        json!({
+            "name": info.name,
+            "from_dhcp": info.from_dhcp,
+        }),
+        || println!("{}", info.name.as_deref().unwrap_or("(unset)")),
+    );
+    Ok(())
+}
+
+pub async fn set_hostname(clients: &Clients, hostname: String) -> Result<(), String> {
+    schema::devicemgmt::set_hostname(
+        &clients.devicemgmt,
+        &schema::devicemgmt::SetHostname { name: hostname },
+    )
+    .await?;
+    Ok(())
+}
+
+pub async fn reboot(clients: &Clients, output: Output, wait: Option<u64>) -> Result<(), String> {
+    let resp = schema::devicemgmt::system_reboot(&clients.devicemgmt, &Default::default()).await?;
+    let back_after = match wait {
+        Some(wait) => Some(wait_for_reboot(clients, wait).await?),
+        None => None,
+    };
+    output.print(
+        json!({
+            "message": resp.message,
+            "back_after": back_after.map(|d| d.as_secs_f64()),
+        }),
+        || {
+            println!("{}", &resp.message);
+            if let Some(back_after) = back_after {
+                println!("back after {:.0?}", back_after);
+            }
+        },
+    );
+    Ok(())
+}
+
+/// Waits up to `wait` seconds for the device to go down and answer again, reporting progress on
+/// stderr.
+pub async fn wait_for_reboot(clients: &Clients, wait: u64) -> Result<Duration, String> {
+    wait::wait_for_reboot(&clients.devicemgmt, Duration::from_secs(wait), |progress| {
+        eprintln!("{:?} after {:.0?}", progress.state, progress.elapsed)
+    })
+    .await
+    .map_err(|e| e.to_string())
+}
+
+pub async fn user(clients: &Clients, output: Output, cmd: UserCmd) -> Result<(), String> {
+    match cmd {
+        UserCmd::List => {
+            let resp =
+                schema::devicemgmt::get_users(&clients.devicemgmt, &Default::default()).await?;
+            output.print(
+                resp.user
+                    .iter()
+                    .map(|u| json!({ "name": u.username, "level": user_level_str(&u.user_level) }))
+                    .collect(),
+                || {
+                    for u in &resp.user {
+                        println!("{} ({})", &u.username, user_level_str(&u.user_level));
+                    }
+                },
+            );
+        }
+        UserCmd::Add {
+            name,
+            password,
+            level,
+        } => {
+            schema::devicemgmt::create_users(
+                &clients.devicemgmt,
+                &schema::devicemgmt::CreateUsers {
+                    user: vec![schema::onvif::User {
+                        username: name,
+                        password: Some(password),
+                        user_level: level,
+                        extension: None,
+                    }],
+                },
+            )
+            .await?;
+        }
+        UserCmd::Delete { name } => {
+            schema::devicemgmt::delete_users(
+                &clients.devicemgmt,
+                &schema::devicemgmt::DeleteUsers {
+                    username: vec![name],
+                },
+            )
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+pub async fn get_storage_configurations(clients: &Clients, output: Output) -> Result<(), String> {
+    let resp =
+        schema::devicemgmt::get_storage_configurations(&clients.devicemgmt, &Default::default())
+            .await?;
+    debug!("get_storage_configurations response: {:#?}", &resp);
+    output.print(
+        resp.storage_configurations
+            .iter()
+            .map(|c| {
+                json!({
+                    "type": c.data._type,
+                    "local_path": c.data.local_path,
+                    "storage_uri": c.data.storage_uri,
+                    "user": c.data.user.as_ref().map(|u| &u.user_name),
+                })
+            })
+            .collect(),
+        || {
+            if resp.storage_configurations.is_empty() {
+                println!("No storage configurations");
+            }
+            for c in &resp.storage_configurations {
+                println!("type={}", &c.data._type);
+                if let Some(ref local_path) = c.data.local_path {
+                    println!("    local path: {}", local_path);
+                }
+                if let Some(ref storage_uri) = c.data.storage_uri {
+                    println!("    storage uri: {}", storage_uri);
+                }
+                if let Some(ref user) = c.data.user {
+                    println!("    user: {}", &user.user_name);
+                }
+            }
+        },
+    );
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use crate::{clients::Clients, output::Output};
+use futures_util::{pin_mut, StreamExt};
+use onvif::event;
+use std::time::Duration;
+use structopt::StructOpt;
+use tracing::{debug, warn};
+
+#[derive(StructOpt)]
+pub enum EventsCmd {
+    /// Prints events as they arrive until interrupted with Ctrl-C.
+    Tail {
+        /// Concrete set topic expression, e.g. `tns1:RuleEngine//.`. Defaults to all topics.
+        #[structopt(long)]
+        topic: Option<String>,
+
+        /// Only prints events from sources with this simple item, e.g.
+        /// `VideoSourceConfigurationToken=1`. Filtered on the device. May be repeated.
+        #[structopt(long, parse(try_from_str = parse_item))]
+        source: Vec<(String, String)>,
+
+        /// Asks the device to send only property changes, not their initial state.
+        #[structopt(long)]
+        changed_only: bool,
+
+        /// How long each pull waits for events, in seconds.
+        #[structopt(long, default_value = "10")]
+        pull_timeout: u64,
+
+        /// Requests the current state of all properties after subscribing.
+        #[structopt(long)]
+        synchronize: bool,
+    },
+}
+
+pub async fn events(clients: &Clients, output: Output, cmd: EventsCmd) -> Result<(), String> {
+    match cmd {
+        EventsCmd::Tail {
+            topic,
+            source,
+            changed_only,
+            pull_timeout,
+            synchronize,
+        } => {
+            let mut filter = event::Filter::default();
+            if let Some(topic) = &topic {
+                filter = filter.topic(topic);
+            }
+            for (name, value) in &source {
+                filter = filter.source_item(name, value);
+            }
+            let options = event::SubscriptionOptions::default()
+                .filter(filter)
+                .policy(event::SubscriptionPolicy::default().changed_only(changed_only))
+                .pull_timeout(Duration::from_secs(pull_timeout))
+                .synchronize(synchronize);
+            tail(clients, output, options).await
+        }
+    }
+}
+
+async fn tail(
+    clients: &Clients,
+    output: Output,
+    options: event::SubscriptionOptions,
+) -> Result<(), String> {
+    // Ctrl-C deletes the subscription rather than leaving it to expire on the device.
+    let notifications = event::subscribe_until(
+        clients.event()?,
+        |address, timeout| {
+            debug!("subscribed at {}", address);
+            clients.builder(address).timeout(timeout).build()
+        },
+        options,
+        async {
+            let _ = tokio::signal::ctrl_c().await;
+        },
+    );
+    pin_mut!(notifications);
+
+    loop {
+        match notifications.next().await {
+            Some(event::Notification::Event(e)) | Some(event::Notification::PropertyState(e)) => {
+                output.print(serde_json::json!(e), || print_event(&e))
+            }
+            Some(notification) => {
+                output.print(serde_json::json!(notification), || match &notification {
+                    event::Notification::SubscriptionLost { error } => {
+                        warn!("subscription lost, events may be missed: {}", error)
+                    }
+                    _ => warn!("subscription restored"),
+                })
+            }
+            None => return Ok(()),
+        }
+    }
+}
+
+fn parse_item(s: &str) -> Result<(String, String), String> {
+    let (name, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected NAME=VALUE, got {}", s))?;
+    Ok((name.to_string(), value.to_string()))
+}
+
+fn print_event(e: &event::Event) {
+    let items = e
+        .source
+        .iter()
+        .chain(&e.key)
+        .chain(&e.data)
+        .map(|(name, value)| format!("{}={}", name, value))
+        .collect::<Vec<_>>()
+        .join(" ");
+    println!(
+        "{} {} {}",
+        e.utc_time.as_deref().unwrap_or("-"),
+        &e.topic,
+        items
+    );
+}
@@ -0,0 +1,44 @@
+use crate::{clients::Clients, media::video_source_token, output::Output};
+use serde_json::json;
+use std::time::Duration;
+use structopt::StructOpt;
+
+#[derive(StructOpt)]
+pub enum ImagingCmd {
+    /// Focuses automatically once, then keeps that focus.
+    Autofocus {
+        /// Video source token; defaults to the source of the first media profile.
+        #[structopt(long)]
+        video_source: Option<String>,
+
+        /// How long the lens is given to find focus, in milliseconds.
+        #[structopt(long, default_value = "2000")]
+        settle: u64,
+    },
+}
+
+pub async fn imaging(clients: &Clients, output: Output, cmd: ImagingCmd) -> Result<(), String> {
+    let imaging = clients.imaging()?;
+    match cmd {
+        ImagingCmd::Autofocus {
+            video_source,
+            settle,
+        } => {
+            let token = video_source_token(clients, video_source).await?;
+            let autofocus =
+                onvif::imaging::trigger_autofocus(imaging, &token, Duration::from_millis(settle))
+                    .await
+                    .map_err(|e| e.to_string())?;
+            output.print(
+                json!({ "video_source": token.0, "mechanism": format!("{:?}", autofocus) }),
+                || match autofocus {
+                    onvif::imaging::Autofocus::AlreadyAuto => {
+                        println!("{}: focus is automatic already", token.0)
+                    }
+                    onvif::imaging::Autofocus::ModeToggle => println!("{}: focused", token.0),
+                },
+            );
+        }
+    }
+    Ok(())
+}
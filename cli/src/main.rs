@@ -0,0 +1,334 @@
+mod analytics;
+mod clients;
+mod config;
+mod device;
+mod events;
+mod imaging;
+mod masks;
+mod media;
+mod output;
+mod ptz;
+mod serial;
+
+use clients::Clients;
+use futures_util::stream::StreamExt;
+use onvif::{discovery, soap, xaddr};
+use output::Output;
+use serde_json::json;
+use std::{path::PathBuf, sync::Arc, time::Duration};
+use structopt::StructOpt;
+use url::Url;
+
+#[derive(StructOpt)]
+#[structopt(name = "onvif-cli", about = "ONVIF device discovery and control tool")]
+struct Args {
+    /// Device username. Prefer `ONVIF_USERNAME`/`ONVIF_PASSWORD` or the config file, which don't
+    /// leak into shell history and process lists.
+    #[structopt(global = true, long, requires = "password")]
+    username: Option<String>,
+
+    #[structopt(global = true, long, requires = "username")]
+    password: Option<String>,
+
+    /// The device's base URI, typically just to the HTTP root.
+    /// The service-specific path (such as `/onvif/device_support`) will be appended to this.
+    // Note this is an `Option` because global options can't be required in clap.
+    // https://github.com/clap-rs/clap/issues/1546
+    #[structopt(global = true, long)]
+    uri: Option<Url>,
+
+    /// Uses this address for a service instead of the one the device advertises, e.g.
+    /// `media=http://192.168.0.2:8080/onvif/media`. Services are `event`, `deviceio`, `media`,
+    /// `media2`, `imaging`, `ptz` and `analytics`. May be repeated.
+    #[structopt(global = true, long = "service", parse(try_from_str = parse_service_override))]
+    service_overrides: Vec<(xaddr::Service, Url)>,
+
+    /// What to do with service addresses the device advertises outside of `--uri`, e.g.
+    /// `http://0.0.0.0/onvif/media`: `strict` rejects them, `rewrite` reaches them at the host of
+    /// `--uri` and `trust` uses them as advertised.
+    #[structopt(global = true, long, default_value = "strict")]
+    xaddr_policy: xaddr::XAddrPolicy,
+
+    /// TOML file mapping device hosts to credentials.
+    /// Defaults to `~/.config/onvif-cli/config.toml`.
+    #[structopt(global = true, long, parse(from_os_str))]
+    config: Option<PathBuf>,
+
+    /// Writes every SOAP exchange, with passwords redacted, to a file in this directory.
+    /// Useful for reporting interoperability problems.
+    #[structopt(global = true, long, parse(from_os_str))]
+    trace_dir: Option<PathBuf>,
+
+    /// Output format: `text` or `json`.
+    #[structopt(global = true, long, default_value = "text")]
+    output: Output,
+
+    #[structopt(subcommand)]
+    cmd: Cmd,
+}
+
+#[derive(StructOpt)]
+enum Cmd {
+    /// Discovers devices on the local network using WS-discovery.
+    Discover {
+        /// How long to wait for responses, in seconds.
+        #[structopt(long, default_value = "1")]
+        duration: u64,
+    },
+
+    /// Gets model, firmware, manufacturer and other information related to the device.
+    Info,
+
+    /// Checks reachability, latency, clock drift and authentication.
+    Health,
+
+    GetSystemDateAndTime,
+
+    GetCapabilities,
+
+    /// Gets the capabilities of all known ONVIF services supported by this device.
+    GetServiceCapabilities,
+
+    GetHostname,
+
+    SetHostname {
+        hostname: String,
+    },
+
+    /// Reboots the device.
+    Reboot {
+        /// Waits up to this many seconds for the device to come back.
+        #[structopt(long)]
+        wait: Option<u64>,
+    },
+
+    /// Manages device users.
+    User(device::UserCmd),
+
+    /// Subscribes to device events.
+    Events(events::EventsCmd),
+
+    /// Lists the storage configurations (e.g. local SD card or NAS) known to the device.
+    GetStorageConfigurations,
+
+    /// Lists media profiles with a summary of their video/audio configuration.
+    Profiles,
+
+    /// Gets RTSP URIs for all profiles, along with a summary of the video/audio streams.
+    StreamUris {
+        /// How RTP is delivered: `udp`, `tcp`, `rtsp` (interleaved) or `http` (tunnelled).
+        /// Given a comma-separated list, the next one is tried while the device rejects one.
+        #[structopt(long, default_value = "rtsp", use_delimiter = true)]
+        transport: Vec<onvif::media::StreamTransport>,
+    },
+
+    /// Gets JPEG snapshot URIs for all profiles.
+    SnapshotUris,
+
+    /// Downloads JPEG snapshots.
+    Snapshot {
+        /// Media profile token; defaults to the first profile.
+        #[structopt(long)]
+        profile: Option<String>,
+
+        /// Fetches a snapshot for every profile.
+        #[structopt(long, conflicts_with = "profile")]
+        all_profiles: bool,
+
+        /// Output file, or `-` for stdout. With `--all-profiles`, the output directory.
+        /// Defaults to timestamped files in the current directory.
+        #[structopt(short = "o", long = "out", parse(from_os_str))]
+        out: Option<PathBuf>,
+    },
+
+    /// Lists the modes of a video source, such as its maximum resolution and frame rate.
+    VideoSourceModes {
+        /// Video source token; defaults to the source of the first media profile.
+        #[structopt(long)]
+        video_source: Option<String>,
+    },
+
+    /// Switches a video source to another mode and reports the encoder configurations it
+    /// invalidates.
+    SetVideoSourceMode {
+        #[structopt(long)]
+        video_source: Option<String>,
+
+        /// Token of the mode, as listed by `video-source-modes`.
+        mode: String,
+
+        /// Waits up to this many seconds for the device to come back if it reboots.
+        #[structopt(long)]
+        wait: Option<u64>,
+    },
+
+    /// Manages privacy masks.
+    Masks(masks::MaskCmd),
+
+    /// Attempts to enable a `vnd.onvif.metadata` RTSP stream with analytics.
+    EnableAnalytics,
+
+    /// Gets information about the currently enabled and supported video analytics.
+    GetAnalytics,
+
+    /// Manages analytics rules.
+    Analytics(analytics::AnalyticsCmd),
+
+    /// Controls focus and other imaging settings.
+    Imaging(imaging::ImagingCmd),
+
+    /// Controls pan, tilt and zoom.
+    Ptz(ptz::PtzCmd),
+
+    /// Talks to peripherals connected to the device's serial ports.
+    Serial(serial::SerialCmd),
+
+    /// Tries to get any possible information.
+    GetAll,
+}
+
+#[tokio::main]
+async fn main() {
+    // Logs go to stderr so that they don't interfere with `--output json`.
+    tracing_subscriber::fmt()
+        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+        .with_writer(std::io::stderr)
+        .init();
+
+    let args = Args::from_args();
+
+    if let Err(error) = run(args).await {
+        eprintln!("Error: {}", error);
+        std::process::exit(1);
+    }
+}
+
+async fn run(args: Args) -> Result<(), String> {
+    let output = args.output;
+
+    if let Cmd::Discover { duration } = args.cmd {
+        return discover(output, Duration::from_secs(duration)).await;
+    }
+
+    let base_uri = args
+        .uri
+        .ok_or_else(|| "--uri must be specified.".to_string())?;
+    // Command line flags take precedence over the environment, which takes precedence over the
+    // config file.
+    let creds = match (args.username, args.password) {
+        (Some(username), Some(password)) => Some(soap::client::Credentials { username, password }),
+        (None, None) => match soap::client::Credentials::from_env() {
+            Some(creds) => Some(creds),
+            None => config::Config::load(args.config)?.credentials(&base_uri),
+        },
+        _ => return Err("username and password must be specified together".to_string()),
+    };
+
+    // Keeps the most recent exchanges only, so that a long `events tail` doesn't fill the disk.
+    const MAX_TRACE_FILES: usize = 1000;
+    let recorder = args
+        .trace_dir
+        .map(|dir| Arc::new(soap::recorder::Recorder::new(dir, MAX_TRACE_FILES)));
+
+    // Doesn't depend on `GetServices`, so that it can report on devices where that fails.
+    if let Cmd::Health = args.cmd {
+        let clients = Clients::device_only(&base_uri, creds, recorder)?;
+        return device::health(&clients, output).await;
+    }
+
+    let clients = Clients::new(
+        &base_uri,
+        creds,
+        recorder,
+        &args.service_overrides,
+        args.xaddr_policy,
+    )
+    .await?;
+
+    match args.cmd {
+        Cmd::Discover { .. } | Cmd::Health => unreachable!(),
+        Cmd::Info => device::info(&clients, output).await,
+        Cmd::GetSystemDateAndTime => device::get_system_date_and_time(&clients, output).await,
+        Cmd::GetCapabilities => device::get_capabilities(&clients, output).await,
+        Cmd::GetServiceCapabilities => device::get_service_capabilities(&clients, output).await,
+        Cmd::GetHostname => device::get_hostname(&clients, output).await,
+        Cmd::SetHostname { hostname } => device::set_hostname(&clients, hostname).await,
+        Cmd::Reboot { wait } => device::reboot(&clients, output, wait).await,
+        Cmd::User(cmd) => device::user(&clients, output, cmd).await,
+        Cmd::Events(cmd) => events::events(&clients, output, cmd).await,
+        Cmd::GetStorageConfigurations => device::get_storage_configurations(&clients, output).await,
+        Cmd::Profiles => media::profiles(&clients, output).await,
+        Cmd::StreamUris { transport } => media::stream_uris(&clients, output, &transport).await,
+        Cmd::SnapshotUris => media::snapshot_uris(&clients, output).await,
+        Cmd::Snapshot {
+            profile,
+            all_profiles,
+            out,
+        } => media::snapshot(&clients, profile, all_profiles, out).await,
+        Cmd::VideoSourceModes { video_source } => {
+            media::video_source_modes(&clients, output, video_source).await
+        }
+        Cmd::SetVideoSourceMode {
+            video_source,
+            mode,
+            wait,
+        } => media::set_video_source_mode(&clients, output, video_source, mode, wait).await,
+        Cmd::Masks(cmd) => masks::masks(&clients, output, cmd).await,
+        Cmd::EnableAnalytics => media::enable_analytics(&clients).await,
+        Cmd::GetAnalytics => media::get_analytics(&clients, output).await,
+        Cmd::Analytics(cmd) => analytics::analytics(&clients, output, cmd).await,
+        Cmd::Imaging(cmd) => imaging::imaging(&clients, output, cmd).await,
+        Cmd::Ptz(cmd) => ptz::ptz(&clients, output, cmd).await,
+        Cmd::Serial(cmd) => serial::serial(&clients, output, cmd).await,
+        Cmd::GetAll => {
+            device::get_system_date_and_time(&clients, output).await?;
+            device::get_capabilities(&clients, output).await?;
+            device::get_service_capabilities(&clients, output).await?;
+            media::stream_uris(&clients, output, &[onvif::media::StreamTransport::Rtsp]).await?;
+            device::get_hostname(&clients, output).await?;
+            media::get_analytics(&clients, output).await?;
+            if clients.ptz.is_some() {
+                ptz::ptz(&clients, output, ptz::PtzCmd::Status { profile: None }).await?;
+            }
+            device::get_storage_configurations(&clients, output).await
+        }
+    }
+}
+
+fn parse_service_override(s: &str) -> Result<(xaddr::Service, Url), String> {
+    let (service, uri) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected SERVICE=URI, got {}", s))?;
+    Ok((
+        service.parse()?,
+        uri.parse().map_err(|e: url::ParseError| e.to_string())?,
+    ))
+}
+
+async fn discover(output: Output, duration: Duration) -> Result<(), String> {
+    const MAX_CONCURRENT_JUMPERS: usize = 100;
+
+    discovery::discover(duration)
+        .await
+        .map_err(|e| e.to_string())?
+        .for_each_concurrent(MAX_CONCURRENT_JUMPERS, |device| async move {
+            output.print(
+                json!({
+                    "id": device.id(),
+                    "name": device.name,
+                    "url": device.url.as_str(),
+                    "endpoint_reference": device.endpoint_reference,
+                }),
+                || {
+                    println!(
+                        "{} {}",
+                        device.url,
+                        device.name.as_deref().unwrap_or("(unnamed)")
+                    )
+                },
+            );
+        })
+        .await;
+
+    Ok(())
+}
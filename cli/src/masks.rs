@@ -0,0 +1,171 @@
+use crate::{clients::Clients, output::Output};
+use onvif::{
+    media2::{self, MaskArea, MaskFill, PrivacyMask},
+    schema::{self, json::ToJson, onvif::ReferenceToken},
+};
+use serde_json::json;
+use structopt::StructOpt;
+
+#[derive(StructOpt)]
+pub enum MaskCmd {
+    /// Lists the privacy masks of a video source configuration.
+    List {
+        /// Video source configuration token; defaults to that of the first media profile.
+        #[structopt(long)]
+        configuration: Option<String>,
+    },
+
+    /// Creates a privacy mask, after checking it against the device's mask options.
+    Create {
+        #[structopt(long)]
+        configuration: Option<String>,
+
+        /// Rectangle as `LEFT,TOP,RIGHT,BOTTOM` in normalized coordinates (-1 to 1, y up).
+        #[structopt(
+            long,
+            required_unless = "point",
+            allow_hyphen_values = true,
+            parse(try_from_str = parse_rectangle)
+        )]
+        rectangle: Option<MaskArea>,
+
+        /// Polygon corner as `X,Y` in normalized coordinates. May be repeated.
+        #[structopt(
+            long,
+            conflicts_with = "rectangle",
+            allow_hyphen_values = true,
+            parse(try_from_str = parse_point)
+        )]
+        point: Vec<(f64, f64)>,
+
+        /// `color`, `pixelated` or `blurred`.
+        #[structopt(long = "type", default_value = "color")]
+        fill: String,
+
+        /// Colour of `color` masks as `X,Y,Z` in the device's colour space, e.g. YCbCr.
+        #[structopt(long, default_value = "16,128,128")]
+        color: String,
+    },
+
+    /// Deletes a privacy mask.
+    Delete { token: String },
+}
+
+fn parse_numbers(s: &str, count: usize) -> Result<Vec<f64>, String> {
+    let numbers = s
+        .split(',')
+        .map(|n| n.trim().parse::<f64>().map_err(|e| e.to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+    if numbers.len() != count {
+        return Err(format!(
+            "expected {} comma-separated numbers, got {}",
+            count, s
+        ));
+    }
+    Ok(numbers)
+}
+
+fn parse_rectangle(s: &str) -> Result<MaskArea, String> {
+    let n = parse_numbers(s, 4)?;
+    Ok(MaskArea::rectangle(n[0], n[1], n[2], n[3]))
+}
+
+pub fn parse_point(s: &str) -> Result<(f64, f64), String> {
+    let n = parse_numbers(s, 2)?;
+    Ok((n[0], n[1]))
+}
+
+fn parse_fill(fill: &str, color: &str) -> Result<MaskFill, String> {
+    match fill {
+        "color" => {
+            let n = parse_numbers(color, 3)?;
+            Ok(MaskFill::Color(schema::onvif::Color {
+                x: n[0],
+                y: n[1],
+                z: n[2],
+                colorspace: None,
+            }))
+        }
+        "pixelated" => Ok(MaskFill::Pixelated),
+        "blurred" => Ok(MaskFill::Blurred),
+        _ => Err(format!(
+            "expected color, pixelated or blurred, got {}",
+            fill
+        )),
+    }
+}
+
+/// Returns the given configuration token, or that of the device's first media profile's video
+/// source configuration.
+async fn configuration_token(
+    clients: &Clients,
+    configuration: Option<String>,
+) -> Result<ReferenceToken, String> {
+    if let Some(configuration) = configuration {
+        return Ok(ReferenceToken(configuration));
+    }
+    schema::media::get_profiles(clients.media()?, &Default::default())
+        .await?
+        .profiles
+        .into_iter()
+        .find_map(|p| p.video_source_configuration)
+        .map(|c| c.token)
+        .ok_or_else(|| "device has no media profile with a video source".to_string())
+}
+
+pub async fn masks(clients: &Clients, output: Output, cmd: MaskCmd) -> Result<(), String> {
+    let media2_client = clients.media2()?;
+    match cmd {
+        MaskCmd::List { configuration } => {
+            let token = configuration_token(clients, configuration).await?;
+            let masks = media2::masks(media2_client, Some(&token))
+                .await
+                .map_err(|e| e.to_string())?;
+            output.print(
+                masks
+                    .iter()
+                    .map(|m| m.to_json())
+                    .collect::<Result<Vec<_>, _>>()?
+                    .into(),
+                || {
+                    for m in &masks {
+                        println!(
+                            "{}: {} with {} points{}",
+                            m.token.as_ref().map_or("?", |t| t.0.as_str()),
+                            m._type,
+                            m.polygon.point.len(),
+                            if m.enabled { "" } else { " (disabled)" }
+                        );
+                    }
+                },
+            );
+        }
+        MaskCmd::Create {
+            configuration,
+            rectangle,
+            point,
+            fill,
+            color,
+        } => {
+            let token = configuration_token(clients, configuration).await?;
+            let area = rectangle.unwrap_or_else(|| {
+                point
+                    .into_iter()
+                    .fold(MaskArea::new(), |area, (x, y)| area.point(x, y))
+            });
+            let mask = PrivacyMask::new(area, parse_fill(&fill, &color)?);
+            let mask_token = media2::create_mask(media2_client, &token, &mask)
+                .await
+                .map_err(|e| e.to_string())?;
+            output.print(json!({ "token": mask_token.0 }), || {
+                println!("created mask {}", mask_token.0)
+            });
+        }
+        MaskCmd::Delete { token } => {
+            media2::delete_mask(media2_client, &ReferenceToken(token))
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,369 @@
+use crate::{clients::Clients, device, output::Output};
+use onvif::{
+    media,
+    schema::{self, json::ToJson, onvif::ReferenceToken},
+};
+use serde_json::json;
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+};
+use tracing::debug;
+use url::Url;
+
+/// Returns the given video source token, or that of the device's first media profile.
+pub async fn video_source_token(
+    clients: &Clients,
+    video_source: Option<String>,
+) -> Result<ReferenceToken, String> {
+    if let Some(video_source) = video_source {
+        return Ok(ReferenceToken(video_source));
+    }
+    schema::media::get_profiles(clients.media()?, &Default::default())
+        .await?
+        .profiles
+        .into_iter()
+        .find_map(|p| p.video_source_configuration)
+        .map(|c| c.source_token)
+        .ok_or_else(|| "device has no media profile with a video source".to_string())
+}
+
+/// Summarizes a profile's video and audio encoder configuration.
+fn profile_json(p: &schema::onvif::Profile) -> serde_json::Value {
+    json!({
+        "token": p.token.0,
+        "name": p.name.0,
+        "video": p.video_encoder_configuration.as_ref().map(|v| json!({
+            "encoding": format!("{:?}", v.encoding),
+            "width": v.resolution.width,
+            "height": v.resolution.height,
+            "fps": v.rate_control.as_ref().map(|r| r.frame_rate_limit),
+            "kbps": v.rate_control.as_ref().map(|r| r.bitrate_limit),
+        })),
+        "audio": p.audio_encoder_configuration.as_ref().map(|a| json!({
+            "encoding": format!("{:?}", a.encoding),
+            "kbps": a.bitrate,
+            "khz": a.sample_rate,
+        })),
+    })
+}
+
+fn print_profile(p: &schema::onvif::Profile, uri: Option<&str>) {
+    println!("token={} name={}", &p.token.0, &p.name.0);
+    if let Some(uri) = uri {
+        println!("    {}", uri);
+    }
+    if let Some(ref v) = p.video_encoder_configuration {
+        println!(
+            "    {:?}, {}x{}",
+            v.encoding, v.resolution.width, v.resolution.height
+        );
+        if let Some(ref r) = v.rate_control {
+            println!("    {} fps, {} kbps", r.frame_rate_limit, r.bitrate_limit);
+        }
+    }
+    if let Some(ref a) = p.audio_encoder_configuration {
+        println!(
+            "    audio: {:?}, {} kbps, {} kHz",
+            a.encoding, a.bitrate, a.sample_rate
+        );
+    }
+}
+
+pub async fn profiles(clients: &Clients, output: Output) -> Result<(), String> {
+    let profiles = schema::media::get_profiles(clients.media()?, &Default::default()).await?;
+    debug!("get_profiles response: {:#?}", &profiles);
+    output.print(profiles.profiles.iter().map(profile_json).collect(), || {
+        for p in &profiles.profiles {
+            print_profile(p, None);
+        }
+    });
+    Ok(())
+}
+
+pub async fn stream_uris(
+    clients: &Clients,
+    output: Output,
+    transports: &[media::StreamTransport],
+) -> Result<(), String> {
+    let media_client = clients.media()?;
+    let profiles = schema::media::get_profiles(media_client, &Default::default()).await?;
+    debug!("get_profiles response: {:#?}", &profiles);
+    let uris = media::profile_stream_uris(media_client, &profiles.profiles, transports).await?;
+    let uri = |p: &schema::onvif::Profile| uris.get(&p.token.0).map(|u| u.media_uri.uri.as_str());
+
+    output.print(
+        profiles
+            .profiles
+            .iter()
+            .map(|p| {
+                let mut summary = profile_json(p);
+                summary["uri"] = uri(p).into();
+                summary
+            })
+            .collect(),
+        || {
+            for p in &profiles.profiles {
+                print_profile(p, uri(p));
+            }
+        },
+    );
+    Ok(())
+}
+
+pub async fn snapshot_uris(clients: &Clients, output: Output) -> Result<(), String> {
+    let media_client = clients.media()?;
+    let profiles = schema::media::get_profiles(media_client, &Default::default()).await?;
+    let requests: Vec<_> = profiles
+        .profiles
+        .iter()
+        .map(|p| schema::media::GetSnapshotUri {
+            profile_token: p.token.clone(),
+        })
+        .collect();
+    let responses = futures_util::future::try_join_all(
+        requests
+            .iter()
+            .map(|r| schema::media::get_snapshot_uri(media_client, r)),
+    )
+    .await?;
+    output.print(
+        profiles
+            .profiles
+            .iter()
+            .zip(responses.iter())
+            .map(|(p, resp)| json!({ "token": p.token.0, "uri": resp.media_uri.uri }))
+            .collect(),
+        || {
+            for (p, resp) in profiles.profiles.iter().zip(responses.iter()) {
+                println!("token={} {}", &p.token.0, &resp.media_uri.uri);
+            }
+        },
+    );
+    Ok(())
+}
+
+/// Downloads JPEG snapshots. Writes to `out` (`-` for stdout) if given, otherwise to timestamped
+/// files named after the profile in the current directory. With `all_profiles`, `out` is the
+/// directory to write into.
+pub async fn snapshot(
+    clients: &Clients,
+    profile: Option<String>,
+    all_profiles: bool,
+    out: Option<PathBuf>,
+) -> Result<(), String> {
+    let media_client = clients.media()?;
+    let tokens: Vec<ReferenceToken> = match profile {
+        Some(profile) if !all_profiles => vec![ReferenceToken(profile)],
+        _ => {
+            let profiles = schema::media::get_profiles(media_client, &Default::default()).await?;
+            let tokens = profiles.profiles.into_iter().map(|p| p.token);
+            if all_profiles {
+                tokens.collect()
+            } else {
+                tokens.take(1).collect()
+            }
+        }
+    };
+    if tokens.is_empty() {
+        return Err("device has no media profiles".to_string());
+    }
+
+    let timestamp = chrono::Local::now().format("%Y%m%dT%H%M%S").to_string();
+    for profile_token in tokens {
+        let resp = schema::media::get_snapshot_uri(
+            media_client,
+            &schema::media::GetSnapshotUri {
+                profile_token: profile_token.clone(),
+            },
+        )
+        .await?;
+        let uri = Url::parse(&resp.media_uri.uri).map_err(|e| e.to_string())?;
+        let jpeg = media_client.get(&uri).await?;
+        debug!("got {} byte snapshot from {}", jpeg.len(), &uri);
+
+        let file_name = format!("snapshot-{}-{}.jpg", &profile_token.0, timestamp);
+        let path = match &out {
+            Some(dir) if all_profiles => dir.join(file_name),
+            Some(path) => path.clone(),
+            None => PathBuf::from(file_name),
+        };
+        if path == Path::new("-") {
+            std::io::stdout()
+                .write_all(&jpeg)
+                .map_err(|e| e.to_string())?;
+        } else {
+            std::fs::write(&path, &jpeg)
+                .map_err(|e| format!("can't write {}: {}", path.display(), e))?;
+            eprintln!("{}", path.display());
+        }
+    }
+    Ok(())
+}
+
+pub async fn enable_analytics(clients: &Clients) -> Result<(), String> {
+    let media_client = clients.media()?;
+    let mut config =
+        schema::media::get_metadata_configurations(media_client, &Default::default()).await?;
+    if config.configurations.len() != 1 {
+        return Err("Expected exactly one analytics config".to_string());
+    }
+    let mut c = config.configurations.pop().unwrap();
+    let token = c.token.clone();
+    println!("{:#?}", &c);
+    if c.analytics != Some(true) || c.events.is_none() {
+        println!("Enabling analytics in metadata configuration {}", &token.0);
+        c.analytics = Some(true);
+        c.events = Some(schema::onvif::EventSubscription {
+            filter: None,
+            subscription_policy: None,
+        });
+        schema::media::set_metadata_configuration(
+            media_client,
+            &schema::media::SetMetadataConfiguration {
+                configuration: c,
+                force_persistence: true,
+            },
+        )
+        .await?;
+    } else {
+        println!(
+            "Analytics already enabled in metadata configuration {}",
+            &token.0
+        );
+    }
+
+    let profiles = schema::media::get_profiles(media_client, &Default::default()).await?;
+    let requests: Vec<_> = profiles
+        .profiles
+        .iter()
+        .filter_map(
+            |p: &schema::onvif::Profile| match p.metadata_configuration {
+                Some(_) => None,
+                None => Some(schema::media::AddMetadataConfiguration {
+                    profile_token: p.token.clone(),
+                    configuration_token: token.clone(),
+                }),
+            },
+        )
+        .collect();
+    if !requests.is_empty() {
+        println!(
+            "Enabling metadata on {}/{} configs",
+            requests.len(),
+            profiles.profiles.len()
+        );
+        futures_util::future::try_join_all(
+            requests
+                .iter()
+                .map(|r| schema::media::add_metadata_configuration(media_client, r)),
+        )
+        .await?;
+    } else {
+        println!(
+            "Metadata already enabled on {} configs",
+            profiles.profiles.len()
+        );
+    }
+    Ok(())
+}
+
+pub async fn get_analytics(clients: &Clients, output: Output) -> Result<(), String> {
+    let config =
+        schema::media::get_video_analytics_configurations(clients.media()?, &Default::default())
+            .await?;
+    output.print(config.to_json()?, || println!("{:#?}", &config));
+    let c = match config.configurations.first() {
+        Some(c) => c,
+        None => return Ok(()),
+    };
+    if let Some(ref a) = clients.analytics {
+        let mods = schema::analytics::get_supported_analytics_modules(
+            a,
+            &schema::analytics::GetSupportedAnalyticsModules {
+                configuration_token: c.token.clone(),
+            },
+        )
+        .await?;
+        output.print(mods.to_json()?, || println!("{:#?}", &mods));
+    }
+    Ok(())
+}
+
+pub async fn video_source_modes(
+    clients: &Clients,
+    output: Output,
+    video_source: Option<String>,
+) -> Result<(), String> {
+    let video_source_token = video_source_token(clients, video_source).await?;
+    let modes = schema::media::get_video_source_modes(
+        clients.media()?,
+        &schema::media::GetVideoSourceModes { video_source_token },
+    )
+    .await?;
+    output.print(modes.to_json()?, || {
+        for mode in &modes.video_source_modes {
+            println!(
+                "{}{}: {}x{} up to {} fps, {}{}",
+                mode.token.0,
+                if mode.enabled == Some(true) {
+                    " (active)"
+                } else {
+                    ""
+                },
+                mode.max_resolution.width,
+                mode.max_resolution.height,
+                mode.max_framerate,
+                mode.encodings.0.join("/"),
+                if mode.reboot { ", requires reboot" } else { "" },
+            );
+        }
+    });
+    Ok(())
+}
+
+pub async fn set_video_source_mode(
+    clients: &Clients,
+    output: Output,
+    video_source: Option<String>,
+    mode: String,
+    wait: Option<u64>,
+) -> Result<(), String> {
+    let video_source_token = video_source_token(clients, video_source).await?;
+    let switch = media::switch_video_source_mode(
+        clients.media()?,
+        &video_source_token,
+        &ReferenceToken(mode),
+    )
+    .await?;
+    let back_after = match wait {
+        Some(wait) if switch.reboot => Some(device::wait_for_reboot(clients, wait).await?),
+        _ => None,
+    };
+    output.print(
+        json!({
+            "reboot": switch.reboot,
+            "back_after": back_after.map(|d| d.as_secs_f64()),
+            "invalidated": switch
+                .invalidated
+                .iter()
+                .map(|i| json!({ "token": i.token, "reason": i.reason }))
+                .collect::<Vec<_>>(),
+        }),
+        || {
+            if switch.reboot {
+                println!("device reboots to apply the mode");
+            }
+            if let Some(back_after) = back_after {
+                println!("back after {:.0?}", back_after);
+            }
+            for i in &switch.invalidated {
+                println!(
+                    "encoder configuration {} is invalidated: {}",
+                    i.token, i.reason
+                );
+            }
+        },
+    );
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use std::str::FromStr;
+
+/// How command results are written to stdout.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Output {
+    /// Human-readable text.
+    Text,
+
+    /// One JSON document per result, suitable for piping into `jq`.
+    Json,
+}
+
+impl Output {
+    /// Prints `json` when in JSON mode, or calls `text` otherwise.
+    pub fn print(self, json: serde_json::Value, text: impl FnOnce()) {
+        match self {
+            Output::Text => text(),
+            Output::Json => println!("{}", json),
+        }
+    }
+}
+
+impl FromStr for Output {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Output::Text),
+            "json" => Ok(Output::Json),
+            _ => Err(format!(
+                "unknown output format {:?}; expected text or json",
+                s
+            )),
+        }
+    }
+}
@@ -0,0 +1,536 @@
+use crate::{clients::Clients, output::Output};
+use crossterm::{
+    event::{Event, EventStream, KeyCode, KeyModifiers},
+    terminal,
+};
+use futures_util::stream::StreamExt;
+use onvif::{
+    ptz::calibration::{self, Calibration, Position},
+    schema::{self, json::ToJson, onvif::ReferenceToken},
+};
+use serde_json::json;
+use std::time::{Duration, Instant};
+use structopt::StructOpt;
+
+#[derive(StructOpt)]
+pub enum PtzCmd {
+    /// Gets the PTZ status.
+    Status {
+        /// Media profile token; defaults to the first profile.
+        #[structopt(long)]
+        profile: Option<String>,
+    },
+
+    /// Moves at the given velocity for a while, then stops.
+    Move {
+        #[structopt(long)]
+        profile: Option<String>,
+
+        /// Pan velocity, from -1.0 to 1.0.
+        #[structopt(long, default_value = "0", allow_hyphen_values = true)]
+        pan: f64,
+
+        /// Tilt velocity, from -1.0 to 1.0.
+        #[structopt(long, default_value = "0", allow_hyphen_values = true)]
+        tilt: f64,
+
+        /// Zoom velocity, from -1.0 to 1.0.
+        #[structopt(long, default_value = "0", allow_hyphen_values = true)]
+        zoom: f64,
+
+        /// How long to move for, in milliseconds.
+        #[structopt(long, default_value = "500")]
+        duration: u64,
+    },
+
+    /// Moves to an absolute position, in the default position spaces of the profile.
+    Goto {
+        #[structopt(long)]
+        profile: Option<String>,
+
+        #[structopt(long, requires = "tilt", allow_hyphen_values = true)]
+        pan: Option<f64>,
+
+        #[structopt(long, requires = "pan", allow_hyphen_values = true)]
+        tilt: Option<f64>,
+
+        #[structopt(long, allow_hyphen_values = true)]
+        zoom: Option<f64>,
+
+        /// Checks the position against the limits of the PTZ configuration first: `clamp` moves
+        /// as far as they allow, `reject` fails.
+        #[structopt(long, parse(try_from_str = parse_guard))]
+        limits: Option<onvif::ptz::Guard>,
+    },
+
+    /// Gets the position in degrees, with zoom in percent.
+    Position {
+        #[structopt(long)]
+        profile: Option<String>,
+
+        #[structopt(flatten)]
+        calibration: CalibrationArgs,
+    },
+
+    /// Moves to an absolute position in degrees, with zoom in percent.
+    GotoDegrees {
+        #[structopt(long)]
+        profile: Option<String>,
+
+        #[structopt(long, allow_hyphen_values = true)]
+        pan: f64,
+
+        #[structopt(long, allow_hyphen_values = true)]
+        tilt: f64,
+
+        #[structopt(long)]
+        zoom: Option<f64>,
+
+        #[structopt(flatten)]
+        calibration: CalibrationArgs,
+    },
+
+    /// Steers the camera from the keyboard: arrows or WASD to pan and tilt, +/- to zoom.
+    Interactive {
+        #[structopt(long)]
+        profile: Option<String>,
+
+        /// Initial velocity, from 0.0 to 1.0. Adjust with [ and ].
+        #[structopt(long, default_value = "0.5")]
+        speed: f64,
+    },
+
+    /// Stops any ongoing movement.
+    Stop {
+        #[structopt(long)]
+        profile: Option<String>,
+    },
+
+    /// Lists the presets of a profile.
+    Presets {
+        #[structopt(long)]
+        profile: Option<String>,
+    },
+
+    /// Moves to a preset.
+    GotoPreset {
+        #[structopt(long)]
+        profile: Option<String>,
+
+        preset: String,
+    },
+
+    /// Saves the current position as a preset.
+    SetPreset {
+        #[structopt(long)]
+        profile: Option<String>,
+
+        /// Name of the new preset.
+        name: String,
+    },
+}
+
+/// How generic positions map to degrees. Without ranges, those of the node's spherical degree
+/// space are used, or -180 to 180 for pan and -90 to 90 for tilt.
+#[derive(StructOpt)]
+pub struct CalibrationArgs {
+    /// Pan angles at the ends of the pan range as `MIN,MAX`, e.g. `-170,170`.
+    #[structopt(long, allow_hyphen_values = true, parse(try_from_str = parse_range))]
+    pan_degrees: Option<(f64, f64)>,
+
+    /// Tilt angles at the ends of the tilt range as `MIN,MAX`, e.g. `-90,0`.
+    #[structopt(long, allow_hyphen_values = true, parse(try_from_str = parse_range))]
+    tilt_degrees: Option<(f64, f64)>,
+
+    /// Degrees added to pan, e.g. the compass bearing of pan 0.
+    #[structopt(long, default_value = "0", allow_hyphen_values = true)]
+    pan_offset: f64,
+
+    /// Degrees added to tilt.
+    #[structopt(long, default_value = "0", allow_hyphen_values = true)]
+    tilt_offset: f64,
+}
+
+fn parse_range(s: &str) -> Result<(f64, f64), String> {
+    let (min, max) = s
+        .split_once(',')
+        .ok_or_else(|| format!("expected MIN,MAX, got {}", s))?;
+    let parse = |n: &str| n.trim().parse::<f64>().map_err(|e| e.to_string());
+    Ok((parse(min)?, parse(max)?))
+}
+
+/// Reads the calibration of the profile's PTZ node and applies the command line arguments.
+async fn node_calibration(
+    clients: &Clients,
+    profile_token: &ReferenceToken,
+    args: &CalibrationArgs,
+) -> Result<Calibration, String> {
+    let profile = schema::media::get_profile(
+        clients.media()?,
+        &schema::media::GetProfile {
+            profile_token: profile_token.clone(),
+        },
+    )
+    .await?
+    .profile;
+    let mut calibration = calibration::profile_calibration(clients.ptz()?, &profile)
+        .await
+        .map_err(|e| e.to_string())?
+        .offsets(args.pan_offset, args.tilt_offset);
+    if let Some((min, max)) = args.pan_degrees {
+        calibration = calibration.pan_degrees(min, max);
+    }
+    if let Some((min, max)) = args.tilt_degrees {
+        calibration = calibration.tilt_degrees(min, max);
+    }
+    Ok(calibration)
+}
+
+/// Returns the given profile token, or that of the device's first media profile.
+async fn profile_token(
+    clients: &Clients,
+    profile: Option<String>,
+) -> Result<ReferenceToken, String> {
+    if let Some(profile) = profile {
+        return Ok(ReferenceToken(profile));
+    }
+    schema::media::get_profiles(clients.media()?, &Default::default())
+        .await?
+        .profiles
+        .into_iter()
+        .next()
+        .map(|p| p.token)
+        .ok_or_else(|| "device has no media profiles".to_string())
+}
+
+fn parse_guard(s: &str) -> Result<onvif::ptz::Guard, String> {
+    match s {
+        "clamp" => Ok(onvif::ptz::Guard::Clamp),
+        "reject" => Ok(onvif::ptz::Guard::Reject),
+        _ => Err(format!("expected clamp or reject, got {}", s)),
+    }
+}
+
+pub async fn ptz(clients: &Clients, output: Output, cmd: PtzCmd) -> Result<(), String> {
+    let ptz = clients.ptz()?;
+    match cmd {
+        PtzCmd::Status { profile } => {
+            let profile_token = profile_token(clients, profile).await?;
+            let status =
+                schema::ptz::get_status(ptz, &schema::ptz::GetStatus { profile_token }).await?;
+            output.print(status.to_json()?, || println!("ptz status: {:#?}", &status));
+        }
+        PtzCmd::Move {
+            profile,
+            pan,
+            tilt,
+            zoom,
+            duration,
+        } => {
+            let profile_token = profile_token(clients, profile).await?;
+            let duration = Duration::from_millis(duration);
+            continuous_move(ptz, profile_token.clone(), pan, tilt, zoom, duration).await?;
+            tokio::time::sleep(duration).await;
+            stop(ptz, profile_token).await?;
+        }
+        PtzCmd::Goto {
+            profile,
+            pan,
+            tilt,
+            zoom,
+            limits,
+        } => {
+            let profile_token = profile_token(clients, profile).await?;
+            let position = schema::onvif::Ptzvector {
+                pan_tilt: pan
+                    .zip(tilt)
+                    .map(|(x, y)| schema::onvif::Vector2D { x, y, space: None }),
+                zoom: zoom.map(|x| schema::onvif::Vector1D { x, space: None }),
+            };
+            let limits = match limits {
+                Some(guard) => {
+                    let profile = schema::media::get_profile(
+                        clients.media()?,
+                        &schema::media::GetProfile {
+                            profile_token: profile_token.clone(),
+                        },
+                    )
+                    .await?
+                    .profile;
+                    Some((onvif::ptz::profile_limits(&profile), guard))
+                }
+                None => None,
+            };
+            onvif::ptz::absolute_move(
+                ptz,
+                &profile_token,
+                &position,
+                None,
+                limits.as_ref().map(|(l, g)| (l, *g)),
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+        }
+        PtzCmd::Position {
+            profile,
+            calibration: args,
+        } => {
+            let profile_token = profile_token(clients, profile).await?;
+            let calibration = node_calibration(clients, &profile_token, &args).await?;
+            let position = calibration::position(ptz, &profile_token, &calibration)
+                .await
+                .map_err(|e| e.to_string())?;
+            output.print(
+                json!({
+                    "pan": position.pan,
+                    "tilt": position.tilt,
+                    "zoom": position.zoom,
+                }),
+                || {
+                    print!("pan={:.1}° tilt={:.1}°", position.pan, position.tilt);
+                    match position.zoom {
+                        Some(zoom) => println!(" zoom={:.0}%", zoom),
+                        None => println!(),
+                    }
+                },
+            );
+        }
+        PtzCmd::GotoDegrees {
+            profile,
+            pan,
+            tilt,
+            zoom,
+            calibration: args,
+        } => {
+            let profile_token = profile_token(clients, profile).await?;
+            let calibration = node_calibration(clients, &profile_token, &args).await?;
+            let position = calibration.to_generic(&Position { pan, tilt, zoom });
+            onvif::ptz::absolute_move(ptz, &profile_token, &position, None, None)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+        PtzCmd::Interactive { profile, speed } => {
+            let profile_token = profile_token(clients, profile).await?;
+            interactive(ptz, profile_token, speed).await?;
+        }
+        PtzCmd::Stop { profile } => {
+            let profile_token = profile_token(clients, profile).await?;
+            stop(ptz, profile_token).await?;
+        }
+        PtzCmd::Presets { profile } => {
+            let profile_token = profile_token(clients, profile).await?;
+            let presets =
+                schema::ptz::get_presets(ptz, &schema::ptz::GetPresets { profile_token }).await?;
+            output.print(
+                presets
+                    .preset
+                    .iter()
+                    .map(|p| {
+                        json!({
+                            "token": p.token.as_ref().map(|t| &t.0),
+                            "name": p.name.as_ref().map(|n| &n.0),
+                        })
+                    })
+                    .collect(),
+                || {
+                    for p in &presets.preset {
+                        println!(
+                            "token={} name={}",
+                            p.token.as_ref().map_or("", |t| t.0.as_str()),
+                            p.name.as_ref().map_or("", |n| n.0.as_str())
+                        );
+                    }
+                },
+            );
+        }
+        PtzCmd::GotoPreset { profile, preset } => {
+            let profile_token = profile_token(clients, profile).await?;
+            schema::ptz::goto_preset(
+                ptz,
+                &schema::ptz::GotoPreset {
+                    profile_token,
+                    preset_token: ReferenceToken(preset),
+                    speed: None,
+                },
+            )
+            .await?;
+        }
+        PtzCmd::SetPreset { profile, name } => {
+            let profile_token = profile_token(clients, profile).await?;
+            let resp = schema::ptz::set_preset(
+                ptz,
+                &schema::ptz::SetPreset {
+                    profile_token,
+                    preset_name: Some(name),
+                    preset_token: None,
+                },
+            )
+            .await?;
+            output.print(json!({ "token": resp.preset_token.0 }), || {
+                println!("{}", &resp.preset_token.0)
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Moves for at most `timeout`, after which the device stops on its own, e.g. if the stop request
+/// doesn't make it.
+async fn continuous_move(
+    ptz: &onvif::soap::client::Client,
+    profile_token: ReferenceToken,
+    pan: f64,
+    tilt: f64,
+    zoom: f64,
+    timeout: Duration,
+) -> Result<(), String> {
+    let timeout = format!("PT{}S", timeout.as_secs_f64())
+        .parse()
+        .map_err(|e| format!("Invalid timeout: {:?}", e))?;
+    schema::ptz::continuous_move(
+        ptz,
+        &schema::ptz::ContinuousMove {
+            profile_token,
+            velocity: schema::onvif::Ptzspeed {
+                pan_tilt: Some(schema::onvif::Vector2D {
+                    x: pan,
+                    y: tilt,
+                    space: None,
+                }),
+                zoom: Some(schema::onvif::Vector1D {
+                    x: zoom,
+                    space: None,
+                }),
+            },
+            timeout: Some(timeout),
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+async fn stop(
+    ptz: &onvif::soap::client::Client,
+    profile_token: ReferenceToken,
+) -> Result<(), String> {
+    schema::ptz::stop(
+        ptz,
+        &schema::ptz::Stop {
+            profile_token,
+            pan_tilt: Some(true),
+            zoom: Some(true),
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+/// Restores the terminal when interactive mode exits, including on errors.
+struct RawMode;
+
+impl RawMode {
+    fn enable() -> Result<Self, String> {
+        terminal::enable_raw_mode().map_err(|e| e.to_string())?;
+        Ok(RawMode)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Terminals only report key presses, not releases, so movement stops once keys haven't been
+/// repeated for this long.
+const KEY_RELEASE_TIMEOUT: Duration = Duration::from_millis(300);
+
+/// Timeout of the moves in interactive mode, renewed while keys are held, so that the camera stops
+/// by itself if the connection or this program goes away.
+const MOVE_TIMEOUT: Duration = Duration::from_secs(1);
+
+async fn interactive(
+    ptz: &onvif::soap::client::Client,
+    profile_token: ReferenceToken,
+    speed: f64,
+) -> Result<(), String> {
+    // Raw mode needs explicit carriage returns.
+    eprint!("arrows/WASD: pan and tilt, +/-: zoom, [/]: speed, space: stop, q/Esc: quit\r\n");
+
+    let _raw_mode = RawMode::enable()?;
+    let result = drive(ptz, &profile_token, speed).await;
+    // Also after errors, which may have happened while moving.
+    let stopped = stop(ptz, profile_token).await;
+    result.and(stopped)
+}
+
+async fn drive(
+    ptz: &onvif::soap::client::Client,
+    profile_token: &ReferenceToken,
+    mut speed: f64,
+) -> Result<(), String> {
+    let mut keys = EventStream::new();
+    // The velocity and when it was sent.
+    let mut moving: Option<((f64, f64, f64), Instant)> = None;
+
+    loop {
+        let key = if moving.is_some() {
+            match tokio::time::timeout(KEY_RELEASE_TIMEOUT, keys.next()).await {
+                Ok(key) => key,
+                Err(_) => {
+                    moving = None;
+                    stop(ptz, profile_token.clone()).await?;
+                    continue;
+                }
+            }
+        } else {
+            keys.next().await
+        };
+
+        let key = match key {
+            Some(Ok(Event::Key(key))) => key,
+            Some(Ok(_)) => continue,
+            Some(Err(e)) => return Err(e.to_string()),
+            None => return Ok(()),
+        };
+
+        let velocity = match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(()),
+            KeyCode::Esc | KeyCode::Char('q') => return Ok(()),
+            KeyCode::Left | KeyCode::Char('a') => (-speed, 0.0, 0.0),
+            KeyCode::Right | KeyCode::Char('d') => (speed, 0.0, 0.0),
+            KeyCode::Up | KeyCode::Char('w') => (0.0, speed, 0.0),
+            KeyCode::Down | KeyCode::Char('s') => (0.0, -speed, 0.0),
+            KeyCode::Char('+') | KeyCode::Char('=') => (0.0, 0.0, speed),
+            KeyCode::Char('-') => (0.0, 0.0, -speed),
+            KeyCode::Char('[') | KeyCode::Char(']') => {
+                let step = if key.code == KeyCode::Char(']') {
+                    0.1
+                } else {
+                    -0.1
+                };
+                speed = (speed + step).clamp(0.1, 1.0);
+                eprint!("speed: {:.1}\r\n", speed);
+                continue;
+            }
+            KeyCode::Char(' ') => {
+                moving = None;
+                stop(ptz, profile_token.clone()).await?;
+                continue;
+            }
+            _ => continue,
+        };
+
+        // Key repeat sends the same velocity over and over; only tell the device about changes,
+        // and renew the move before it times out.
+        let renew = match moving {
+            Some((current, sent)) => current != velocity || sent.elapsed() > MOVE_TIMEOUT / 2,
+            None => true,
+        };
+        if renew {
+            let (pan, tilt, zoom) = velocity;
+            continuous_move(ptz, profile_token.clone(), pan, tilt, zoom, MOVE_TIMEOUT).await?;
+            moving = Some((velocity, Instant::now()));
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{clients::Clients, output::Output};
+use onvif::{
+    deviceio::{self, SerialCommand, SerialData},
+    schema::onvif::ReferenceToken,
+};
+use serde_json::json;
+use std::time::Duration;
+use structopt::StructOpt;
+
+#[derive(StructOpt)]
+pub enum SerialCmd {
+    /// Lists the serial ports of the device.
+    Ports,
+
+    /// Sends data to a serial port and prints the response.
+    Send {
+        /// Serial port token; defaults to the first serial port.
+        #[structopt(long)]
+        port: Option<String>,
+
+        /// How `data` is given: `hex` (e.g. `ff 01 00 51 00 00 52`), `base64` or `text`.
+        #[structopt(long, default_value = "hex")]
+        encoding: String,
+
+        data: String,
+
+        /// Milliseconds the device waits for the peripheral to respond.
+        #[structopt(long, default_value = "1000")]
+        timeout: u64,
+
+        /// Number of bytes the response is complete after.
+        #[structopt(long)]
+        data_length: Option<u32>,
+
+        /// Sequence the response is complete after, e.g. `OK`.
+        #[structopt(long)]
+        delimiter: Option<String>,
+    },
+}
+
+fn parse_data(encoding: &str, data: &str) -> Result<SerialData, String> {
+    match encoding {
+        "hex" => SerialData::from_hex(data).map_err(|e| e.to_string()),
+        "base64" => SerialData::from_base64(data).map_err(|e| e.to_string()),
+        "text" => Ok(SerialData::String(data.to_string())),
+        _ => Err(format!("expected hex, base64 or text, got {}", encoding)),
+    }
+}
+
+/// Returns the given port token, or that of the device's first serial port.
+async fn port_token(clients: &Clients, port: Option<String>) -> Result<ReferenceToken, String> {
+    if let Some(port) = port {
+        return Ok(ReferenceToken(port));
+    }
+    deviceio::serial_ports(clients.deviceio()?)
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .next()
+        .ok_or_else(|| "device has no serial ports".to_string())
+}
+
+pub async fn serial(clients: &Clients, output: Output, cmd: SerialCmd) -> Result<(), String> {
+    let deviceio_client = clients.deviceio()?;
+    match cmd {
+        SerialCmd::Ports => {
+            let ports = deviceio::serial_ports(deviceio_client)
+                .await
+                .map_err(|e| e.to_string())?;
+            output.print(ports.iter().map(|p| json!(p.0)).collect(), || {
+                for p in &ports {
+                    println!("{}", p.0);
+                }
+            });
+        }
+        SerialCmd::Send {
+            port,
+            encoding,
+            data,
+            timeout,
+            data_length,
+            delimiter,
+        } => {
+            let mut command = SerialCommand::new(parse_data(&encoding, &data)?)
+                .timeout(Duration::from_millis(timeout));
+            if let Some(data_length) = data_length {
+                command = command.data_length(data_length);
+            }
+            if let Some(delimiter) = &delimiter {
+                command = command.delimiter(delimiter);
+            }
+
+            let token = port_token(clients, port).await?;
+            let response = deviceio::send_receive(deviceio_client, &token, &command)
+                .await
+                .map_err(|e| e.to_string())?;
+            output.print(
+                json!({
+                    "hex": response.as_ref().map(|r| r.to_hex()),
+                    "base64": response.as_ref().map(|r| r.to_base64()),
+                    "text": response.as_ref().map(|r| String::from_utf8_lossy(r.as_bytes())),
+                }),
+                || match &response {
+                    Some(SerialData::String(text)) => println!("{}", text),
+                    Some(data) => println!("{}", data.to_hex()),
+                    None => println!("(no response)"),
+                },
+            );
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,57 @@
+//! Normalization of endpoint references, the stable identity of devices. See the `identity`
+//! module of the `onvif` crate to request them from devices.
+
+use uuid::Uuid;
+
+/// The UUID of an endpoint reference, in any of the forms devices use.
+pub fn parse_uuid(endpoint_reference: &str) -> Option<Uuid> {
+    let trimmed = endpoint_reference.trim();
+    let uuid = ["urn:uuid:", "uuid:"]
+        .iter()
+        .find_map(|prefix| {
+            trimmed
+                .get(..prefix.len())
+                .filter(|start| start.eq_ignore_ascii_case(prefix))
+                .map(|_| &trimmed[prefix.len()..])
+        })
+        .unwrap_or(trimmed);
+    let uuid = uuid
+        .strip_prefix('{')
+        .and_then(|uuid| uuid.strip_suffix('}'))
+        .unwrap_or(uuid);
+    Uuid::parse_str(uuid).ok()
+}
+
+/// The endpoint reference as `urn:uuid:` and the lower case UUID, or trimmed but otherwise as it
+/// is if it isn't a UUID, e.g. an URL.
+pub fn normalize(endpoint_reference: &str) -> String {
+    match parse_uuid(endpoint_reference) {
+        Some(uuid) => uuid.to_urn().to_string(),
+        None => endpoint_reference.trim().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_endpoint_references() {
+        let expected = "urn:uuid:a1f48ac2-dc8b-11df-b255-00408c1836b2";
+        for endpoint_reference in &[
+            "urn:uuid:a1f48ac2-dc8b-11df-b255-00408c1836b2",
+            "URN:UUID:A1F48AC2-DC8B-11DF-B255-00408C1836B2",
+            "uuid:a1f48ac2-dc8b-11df-b255-00408c1836b2",
+            " a1f48ac2-dc8b-11df-b255-00408c1836b2\n",
+            "{A1F48AC2-DC8B-11DF-B255-00408C1836B2}",
+            "a1f48ac2dc8b11dfb25500408c1836b2",
+        ] {
+            assert_eq!(normalize(endpoint_reference), expected);
+        }
+        assert_eq!(
+            normalize(" http://192.168.0.2/onvif/device_service "),
+            "http://192.168.0.2/onvif/device_service"
+        );
+        assert_eq!(parse_uuid("urn:uuid:not-a-uuid"), None);
+    }
+}
@@ -0,0 +1,789 @@
+//! WS-Discovery of ONVIF devices, and their discovery settings.
+
+pub mod identity;
+pub mod settings;
+
+use async_stream::stream;
+use futures_core::stream::Stream;
+use futures_util::{
+    future::ready,
+    stream::{self, FuturesUnordered},
+    StreamExt,
+};
+use onvif_soap as soap;
+use rand::Rng;
+use schema::{
+    transport::Error as TransportError,
+    ws_discovery::{probe, probe_matches},
+};
+use std::{
+    collections::HashSet,
+    fmt,
+    future::Future,
+    iter,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV6},
+    sync::Arc,
+};
+use thiserror::Error;
+use tokio::{
+    io,
+    net::UdpSocket,
+    time::{self, Duration, Instant},
+};
+use tracing::debug;
+use url::{Host, Url};
+
+const LOCAL_PORT: u16 = 0;
+const MULTI_PORT: u16 = 3702;
+const MULTI_IPV4_ADDR: Ipv4Addr = Ipv4Addr::new(239, 255, 255, 250);
+const MULTI_IPV6_ADDR: Ipv6Addr = Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 0xc);
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Network error: {0}")]
+    Network(#[from] io::Error),
+
+    #[error("(De)serialization error: {0}")]
+    Serde(String),
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Device {
+    pub name: Option<String>,
+    pub url: Url,
+    /// WS-Addressing endpoint reference of the device (usually `urn:uuid:...`), if advertised,
+    /// [normalized](identity::normalize).
+    pub endpoint_reference: Option<String>,
+}
+
+impl Device {
+    /// The stable identity of the device: the endpoint reference if advertised, the address
+    /// otherwise.
+    pub fn id(&self) -> String {
+        self.endpoint_reference
+            .clone()
+            .unwrap_or_else(|| self.url.to_string())
+    }
+}
+
+/// Discovers devices on a local network asynchronously using WS-discovery.
+///
+/// Internally it sends a multicast probe and waits for responses for a specified amount of time.
+/// The result is a stream of discovered devices one address per device.
+/// The stream is terminated after provided amount of time.
+///
+/// This is a shortcut for `DiscoveryBuilder::default().duration(duration).run()`,
+/// see [`DiscoveryBuilder`] for the available options.
+///
+/// There are many different ways to iterate over and process the values in a `Stream`
+/// https://rust-lang.github.io/async-book/05_streams/02_iteration_and_concurrency.html
+///
+/// # Examples
+///
+/// You can access each element on the stream concurrently as soon as devices respond:
+///
+/// ```
+/// use onvif_discovery;
+/// use futures_util::stream::StreamExt; // to use for_each_concurrent
+///
+/// const MAX_CONCURRENT_JUMPERS: usize = 100;
+///
+/// async {
+///     discovery::discover(std::time::Duration::from_secs(1))
+///         .await
+///         .unwrap()
+///         .for_each_concurrent(MAX_CONCURRENT_JUMPERS, |addr| {
+///             async move {
+///                 println!("Device found: {:?}", addr);
+///             }
+///         })
+///         .await;
+/// };
+/// ```
+///
+/// Or you can await on a collection of unique devices found in one second:
+///
+/// ```
+/// use onvif_discovery;
+/// use futures_util::stream::StreamExt; // to use collect
+/// use std::collections::HashSet;
+///
+/// async {
+///     let devices = discovery::discover(std::time::Duration::from_secs(1))
+///         .await
+///         .unwrap()
+///         .collect::<HashSet<_>>()
+///         .await;
+///
+///     println!("Devices found: {:?}", devices);
+/// };
+/// ```
+pub async fn discover(duration: Duration) -> Result<impl Stream<Item = Device>, Error> {
+    DiscoveryBuilder::default().duration(duration).run().await
+}
+
+/// How to choose the address of a device among the XAddrs it advertises.
+///
+/// Devices often advertise several, e.g. an IPv4 address, an IPv6 link-local address and a host
+/// name, of which some aren't reachable from here. Every address the policy accepts is probed,
+/// and the best ranked one that answers is reported.
+#[derive(Clone)]
+pub enum XAddrPolicy {
+    /// Whichever address answers first.
+    FirstResponding,
+
+    /// IPv4 addresses, then host names, then IPv6 addresses, with link-local addresses last.
+    PreferRoutableIpv4,
+
+    /// Addresses in one of the given networks, as addresses and prefix lengths, e.g. those of
+    /// the local interfaces; then as [`XAddrPolicy::PreferRoutableIpv4`].
+    SameSubnet(Vec<(IpAddr, u8)>),
+
+    /// Ranks addresses with a callback, lower first. Addresses ranked `None` are never used.
+    Custom(Arc<dyn Fn(&Url) -> Option<u32> + Send + Sync>),
+}
+
+impl XAddrPolicy {
+    pub fn custom(rank: impl Fn(&Url) -> Option<u32> + Send + Sync + 'static) -> Self {
+        XAddrPolicy::Custom(Arc::new(rank))
+    }
+
+    /// Rank of `url`, lower is better, or `None` if it must not be used.
+    pub fn rank(&self, url: &Url) -> Option<u32> {
+        match self {
+            XAddrPolicy::FirstResponding => Some(0),
+            XAddrPolicy::PreferRoutableIpv4 => Some(routable_rank(url)),
+            XAddrPolicy::SameSubnet(networks) => {
+                let same_subnet = host_ip(url).map_or(false, |ip| {
+                    networks
+                        .iter()
+                        .any(|&(network, prefix_length)| in_network(ip, network, prefix_length))
+                });
+                Some(if same_subnet {
+                    0
+                } else {
+                    1 + routable_rank(url)
+                })
+            }
+            XAddrPolicy::Custom(rank) => rank(url),
+        }
+    }
+}
+
+impl Default for XAddrPolicy {
+    fn default() -> Self {
+        XAddrPolicy::PreferRoutableIpv4
+    }
+}
+
+impl fmt::Debug for XAddrPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            XAddrPolicy::FirstResponding => f.write_str("FirstResponding"),
+            XAddrPolicy::PreferRoutableIpv4 => f.write_str("PreferRoutableIpv4"),
+            XAddrPolicy::SameSubnet(networks) => {
+                f.debug_tuple("SameSubnet").field(networks).finish()
+            }
+            XAddrPolicy::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+fn host_ip(url: &Url) -> Option<IpAddr> {
+    match url.host()? {
+        Host::Ipv4(ip) => Some(IpAddr::V4(ip)),
+        Host::Ipv6(ip) => Some(IpAddr::V6(ip)),
+        Host::Domain(_) => None,
+    }
+}
+
+fn routable_rank(url: &Url) -> u32 {
+    match url.host() {
+        Some(Host::Ipv4(ip)) if !ip.is_link_local() && !ip.is_loopback() => 0,
+        Some(Host::Domain(_)) => 1,
+        Some(Host::Ipv6(ip)) if !is_ipv6_link_local(&ip) && !ip.is_loopback() => 2,
+        Some(Host::Ipv4(_)) => 3,
+        _ => 4,
+    }
+}
+
+fn is_ipv6_link_local(ip: &Ipv6Addr) -> bool {
+    ip.segments()[0] & 0xffc0 == 0xfe80
+}
+
+fn in_network(ip: IpAddr, network: IpAddr, prefix_length: u8) -> bool {
+    let (ip, network, bits) = match (ip, network) {
+        (IpAddr::V4(ip), IpAddr::V4(network)) => {
+            (u32::from(ip) as u128, u32::from(network) as u128, 32)
+        }
+        (IpAddr::V6(ip), IpAddr::V6(network)) => (u128::from(ip), u128::from(network), 128),
+        _ => return false,
+    };
+    let prefix_length = u32::from(prefix_length).min(bits);
+    let host_bits = bits - prefix_length;
+    host_bits == bits || (ip >> host_bits) == (network >> host_bits)
+}
+
+/// Configures and runs WS-discovery.
+///
+/// By default probes are sent both to the IPv4 (`239.255.255.250`) and to the IPv6 link-local
+/// (`FF02::C`) multicast groups. Devices answering on both are reported once: responses are
+/// deduplicated by the endpoint reference the device advertises.
+///
+/// UDP is unreliable, so on lossy networks (e.g. Wi-Fi backhauls) it is worth sending the probe
+/// more than once, as WS-Discovery recommends. Repeated probes are spaced by a randomized delay to
+/// avoid synchronized bursts of responses.
+///
+/// Of the addresses a device advertises, the one reported is picked by the [`XAddrPolicy`] among
+/// those that answer a `GetSystemDateAndTime` request within the reachability timeout.
+///
+/// ```
+/// use onvif_discovery::{DiscoveryBuilder, XAddrPolicy};
+/// use std::time::Duration;
+///
+/// async {
+///     let devices = DiscoveryBuilder::default()
+///         .duration(Duration::from_secs(3))
+///         .probe_count(3)
+///         .probe_interval(Duration::from_millis(500))
+///         .ipv6_interfaces(vec![2, 3])
+///         .xaddr_policy(XAddrPolicy::SameSubnet(vec![(
+///             "192.168.0.0".parse().unwrap(),
+///             24,
+///         )]))
+///         .run()
+///         .await
+///         .unwrap();
+/// };
+/// ```
+#[derive(Clone, Debug)]
+pub struct DiscoveryBuilder {
+    duration: Duration,
+    probe_count: u32,
+    probe_interval: Duration,
+    ipv4: bool,
+    ipv6_interfaces: Vec<u32>,
+    xaddr_policy: XAddrPolicy,
+    reachability_timeout: Duration,
+}
+
+impl Default for DiscoveryBuilder {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_secs(1),
+            probe_count: 1,
+            probe_interval: Duration::from_millis(200),
+            ipv4: true,
+            ipv6_interfaces: vec![0],
+            xaddr_policy: XAddrPolicy::default(),
+            reachability_timeout: Duration::from_millis(500),
+        }
+    }
+}
+
+impl DiscoveryBuilder {
+    /// How long to wait for responses before terminating the stream.
+    ///
+    /// The time is counted from the first probe, repeated probes do not extend it.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Total number of probe transmissions, including the first one. Values below 1 are treated
+    /// as 1.
+    pub fn probe_count(mut self, count: u32) -> Self {
+        self.probe_count = count;
+        self
+    }
+
+    /// Average delay between probe transmissions. Each actual delay is picked randomly between
+    /// half and one and a half of this value.
+    pub fn probe_interval(mut self, interval: Duration) -> Self {
+        self.probe_interval = interval;
+        self
+    }
+
+    /// Whether to probe over IPv4 multicast.
+    pub fn ipv4(mut self, enabled: bool) -> Self {
+        self.ipv4 = enabled;
+        self
+    }
+
+    /// Interface indexes (IPv6 scope IDs) to send link-local multicast probes on.
+    ///
+    /// `0` lets the OS pick the interface. An empty list disables IPv6 discovery.
+    pub fn ipv6_interfaces(mut self, interfaces: Vec<u32>) -> Self {
+        self.ipv6_interfaces = interfaces;
+        self
+    }
+
+    /// How to choose among the addresses a device advertises, see [`XAddrPolicy`].
+    pub fn xaddr_policy(mut self, policy: XAddrPolicy) -> Self {
+        self.xaddr_policy = policy;
+        self
+    }
+
+    /// How long to wait for an address to answer before considering it unreachable.
+    pub fn reachability_timeout(mut self, timeout: Duration) -> Self {
+        self.reachability_timeout = timeout;
+        self
+    }
+
+    pub async fn run(self) -> Result<impl Stream<Item = Device>, Error> {
+        let probe = build_probe();
+        let probe_xml = yaserde::ser::to_string(&probe).map_err(Error::Serde)?;
+
+        debug!("Probe XML: {}", probe_xml);
+
+        let mut sockets = vec![];
+
+        if self.ipv4 {
+            sockets.push(send_probe_ipv4(&probe_xml).await?);
+        }
+
+        for &interface in &self.ipv6_interfaces {
+            // IPv6 is often unavailable (e.g. in containers), it must not prevent IPv4 discovery.
+            match send_probe_ipv6(&probe_xml, interface).await {
+                Ok(socket) => sockets.push(socket),
+                Err(e) => debug!(
+                    "Failed to send IPv6 probe on interface {}: {}",
+                    interface, e
+                ),
+            }
+        }
+
+        let start = Instant::now();
+        let deadline = start + self.duration;
+        let resend_at = self.resend_schedule(start);
+
+        let responses =
+            stream::select_all(sockets.into_iter().map(|(socket, multi_socket_addr)| {
+                Box::pin(recv_until(
+                    socket,
+                    ProbeSchedule {
+                        xml: probe_xml.clone(),
+                        addr: multi_socket_addr,
+                        resend_at: resend_at.clone(),
+                    },
+                    deadline,
+                ))
+            }));
+
+        let policy = self.xaddr_policy;
+        let reachability_timeout = self.reachability_timeout;
+        let check_addr = move |uri: Url| is_addr_responding(uri, reachability_timeout);
+
+        Ok(stream! {
+            let probe = &probe;
+            let mut responses = responses;
+            let mut seen_endpoints = HashSet::new();
+
+            while let Some(xml) = responses.next().await {
+                debug!("Probe match XML: {}", xml);
+
+                let envelope = match yaserde::de::from_str::<probe_matches::Envelope>(&xml) {
+                    Ok(envelope) => envelope,
+                    Err(_) => continue,
+                };
+
+                if envelope.header.relates_to != probe.header.message_id {
+                    continue;
+                }
+
+                let endpoints = endpoint_references(&envelope);
+
+                if !endpoints.is_empty() && endpoints.is_subset(&seen_endpoints) {
+                    debug!("Already discovered: {:?}", endpoints);
+                    continue;
+                }
+
+                if let Some(device) = get_responding_addr(envelope, &policy, check_addr).await {
+                    seen_endpoints.extend(endpoints);
+                    yield device;
+                }
+            }
+        })
+    }
+
+    /// Instants at which the probe is retransmitted after the first transmission at `start`.
+    fn resend_schedule(&self, start: Instant) -> Vec<Instant> {
+        let mut rng = rand::thread_rng();
+        let interval_ms = self.probe_interval.as_millis() as u64;
+
+        (1..self.probe_count)
+            .scan(start, |at, _| {
+                *at += Duration::from_millis(rng.gen_range(interval_ms / 2..=interval_ms * 3 / 2));
+                Some(*at)
+            })
+            .collect()
+    }
+}
+
+struct ProbeSchedule {
+    xml: String,
+    addr: SocketAddr,
+    resend_at: Vec<Instant>,
+}
+
+async fn send_probe_ipv4(probe_xml: &str) -> io::Result<(UdpSocket, SocketAddr)> {
+    const LOCAL_IPV4_ADDR: Ipv4Addr = Ipv4Addr::UNSPECIFIED;
+
+    let local_socket_addr = SocketAddr::new(IpAddr::V4(LOCAL_IPV4_ADDR), LOCAL_PORT);
+    let multi_socket_addr = SocketAddr::new(IpAddr::V4(MULTI_IPV4_ADDR), MULTI_PORT);
+
+    let socket = UdpSocket::bind(local_socket_addr).await?;
+    socket.join_multicast_v4(MULTI_IPV4_ADDR, LOCAL_IPV4_ADDR)?;
+    socket
+        .send_to(probe_xml.as_bytes(), multi_socket_addr)
+        .await?;
+
+    Ok((socket, multi_socket_addr))
+}
+
+async fn send_probe_ipv6(probe_xml: &str, interface: u32) -> io::Result<(UdpSocket, SocketAddr)> {
+    const LOCAL_IPV6_ADDR: Ipv6Addr = Ipv6Addr::UNSPECIFIED;
+
+    let local_socket_addr = SocketAddr::new(IpAddr::V6(LOCAL_IPV6_ADDR), LOCAL_PORT);
+    let multi_socket_addr =
+        SocketAddr::V6(SocketAddrV6::new(MULTI_IPV6_ADDR, MULTI_PORT, 0, interface));
+
+    let socket = UdpSocket::bind(local_socket_addr).await?;
+    socket.join_multicast_v6(&MULTI_IPV6_ADDR, interface)?;
+    socket
+        .send_to(probe_xml.as_bytes(), multi_socket_addr)
+        .await?;
+
+    Ok((socket, multi_socket_addr))
+}
+
+/// Receives responses until `deadline`, retransmitting the probe as scheduled meanwhile.
+fn recv_until(
+    socket: UdpSocket,
+    probe: ProbeSchedule,
+    deadline: Instant,
+) -> impl Stream<Item = String> {
+    stream! {
+        let mut resend_at = probe.resend_at.into_iter().peekable();
+
+        loop {
+            let now = Instant::now();
+            if now >= deadline {
+                break;
+            }
+
+            let wake_up = match resend_at.peek() {
+                Some(&at) if at <= now => {
+                    resend_at.next();
+                    debug!("Sending probe again to {}", probe.addr);
+                    if let Err(e) = socket.send_to(probe.xml.as_bytes(), probe.addr).await {
+                        debug!("Failed to send probe to {}: {}", probe.addr, e);
+                    }
+                    continue;
+                }
+                Some(&at) => at.min(deadline),
+                None => deadline,
+            };
+
+            if let Ok(xml) = recv_string(&socket, wake_up - now).await {
+                yield xml;
+            }
+        }
+    }
+}
+
+async fn recv_string(s: &UdpSocket, timeout: Duration) -> io::Result<String> {
+    let mut buf = vec![0; 16 * 1024];
+    let (len, _src) = time::timeout(timeout, s.recv_from(&mut buf)).await??;
+
+    Ok(String::from_utf8_lossy(&buf[..len]).to_string())
+}
+
+fn endpoint_references(envelope: &probe_matches::Envelope) -> HashSet<String> {
+    envelope
+        .body
+        .probe_matches
+        .probe_match
+        .iter()
+        .filter_map(|probe_match| probe_match.endpoint_reference())
+        .map(identity::normalize)
+        .collect()
+}
+
+/// Probes the addresses `policy` accepts with `check_addr`, and returns the best ranked one that
+/// responds. Stops as soon as one of the best possible rank responds.
+async fn get_responding_addr<F, Fut>(
+    envelope: probe_matches::Envelope,
+    policy: &XAddrPolicy,
+    check_addr: F,
+) -> Option<Device>
+where
+    F: Fn(Url) -> Fut + Copy,
+    Fut: Future<Output = bool>,
+{
+    let candidates: Vec<_> = envelope
+        .body
+        .probe_matches
+        .probe_match
+        .iter()
+        .filter(|probe_match| {
+            probe_match
+                .find_in_scopes("onvif://www.onvif.org")
+                .is_some()
+        })
+        .flat_map(|probe_match| {
+            let identity = (
+                probe_match.name(),
+                probe_match.endpoint_reference().map(identity::normalize),
+            );
+            probe_match
+                .x_addrs()
+                .into_iter()
+                .zip(iter::repeat(identity))
+        })
+        .filter_map(|(url, identity)| policy.rank(&url).map(|rank| (rank, url, identity)))
+        .collect();
+    let best_rank = candidates.iter().map(|(rank, _, _)| *rank).min()?;
+
+    let mut responding = candidates
+        .into_iter()
+        .map(|(rank, url, identity)| async move {
+            check_addr(url.clone()).await.then(|| (rank, url, identity))
+        })
+        .collect::<FuturesUnordered<_>>()
+        .filter_map(ready);
+
+    let mut best: Option<(u32, Url, (Option<String>, Option<String>))> = None;
+    while let Some((rank, url, identity)) = responding.next().await {
+        debug!("Responding addr: {:?}", url);
+        if rank == best_rank {
+            best = Some((rank, url, identity));
+            break;
+        }
+        if best.as_ref().map_or(true, |(best, _, _)| rank < *best) {
+            best = Some((rank, url, identity));
+        }
+    }
+
+    best.map(|(_, url, (name, endpoint_reference))| Device {
+        name,
+        url,
+        endpoint_reference,
+    })
+}
+
+fn build_probe() -> probe::Envelope {
+    use probe::*;
+
+    Envelope {
+        header: Header {
+            message_id: format!("uuid:{}", uuid::Uuid::new_v4()),
+            action: "http://schemas.xmlsoap.org/ws/2005/04/discovery/Probe".into(),
+            to: "urn:schemas-xmlsoap-org:ws:2005:04:discovery".into(),
+        },
+        ..Default::default()
+    }
+}
+
+async fn is_addr_responding(uri: Url, timeout: Duration) -> bool {
+    matches!(
+        schema::devicemgmt::get_system_date_and_time(
+            &soap::client::ClientBuilder::new(&uri)
+                .timeout(timeout)
+                .build(),
+            &Default::default(),
+        )
+        .await,
+        Ok(_) | Err(TransportError::Authorization(_))
+    )
+}
+
+#[test]
+fn test_xaddrs_extraction() {
+    fn make_xml(relates_to: &str, xaddrs: &str) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <SOAP-ENV:Envelope
+                        xmlns:SOAP-ENV="http://www.w3.org/2003/05/soap-envelope"
+                        xmlns:wsa="http://schemas.xmlsoap.org/ws/2004/08/addressing"
+                        xmlns:d="http://schemas.xmlsoap.org/ws/2005/04/discovery"
+                        xmlns:dn="http://www.onvif.org/ver10/network/wsdl">
+                <SOAP-ENV:Header>
+                    <wsa:RelatesTo>{relates_to}</wsa:RelatesTo>
+                </SOAP-ENV:Header>
+                <SOAP-ENV:Body>
+                    <d:ProbeMatches>
+                        <d:ProbeMatch>
+                            <d:XAddrs>http://something.else</d:XAddrs>
+                        </d:ProbeMatch>
+                        <d:ProbeMatch>
+                            <d:Scopes>onvif://www.onvif.org/name/MyCamera2000</d:Scopes>
+                            <d:XAddrs>{xaddrs}</d:XAddrs>
+                        </d:ProbeMatch>
+                    </d:ProbeMatches>
+                </SOAP-ENV:Body>
+            </SOAP-ENV:Envelope>
+            "#,
+            relates_to = relates_to,
+            xaddrs = xaddrs
+        )
+    }
+
+    let our_uuid = "uuid:84ede3de-7dec-11d0-c360-F01234567890";
+    let bad_uuid = "uuid:84ede3de-7dec-11d0-c360-F00000000000";
+
+    let input = vec![
+        make_xml(our_uuid, "http://addr_10"),
+        make_xml(our_uuid, "http://addr_20 http://addr_21 http://addr_22"),
+        make_xml(bad_uuid, "http://addr_30 http://addr_31"),
+    ];
+
+    async fn is_addr_responding(uri: Url) -> bool {
+        let responding_addrs = vec![
+            "http://addr_10".parse().unwrap(),
+            "http://addr_21".parse().unwrap(),
+            "http://addr_30".parse().unwrap(),
+        ];
+
+        responding_addrs.contains(&uri)
+    }
+
+    let actual = input
+        .iter()
+        .filter_map(|xml| yaserde::de::from_str::<probe_matches::Envelope>(xml).ok())
+        .filter(|envelope| envelope.header.relates_to == our_uuid)
+        .filter_map(|envelope| {
+            tokio::runtime::Runtime::new()
+                .unwrap()
+                .block_on(get_responding_addr(
+                    envelope,
+                    &XAddrPolicy::FirstResponding,
+                    is_addr_responding,
+                ))
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(actual.len(), 2);
+
+    // OK: message UUID matches and addr responds
+    assert!(actual.contains(&Device {
+        url: Url::parse("http://addr_10").unwrap(),
+        name: Some("MyCamera2000".to_string()),
+        endpoint_reference: None,
+    }));
+
+    // OK: message UUID matches and one of addresses responds
+    assert!(
+        actual.contains(&Device {
+            url: Url::parse("http://addr_21").unwrap(),
+            name: Some("MyCamera2000".to_string()),
+            endpoint_reference: None,
+        }) || actual.contains(&Device {
+            url: Url::parse("http://addr_22").unwrap(),
+            name: Some("MyCamera2000".to_string()),
+            endpoint_reference: None,
+        })
+    );
+
+    // BAD: wrong message UUID
+    assert!(!actual.contains(&Device {
+        url: Url::parse("http://addr_30").unwrap(),
+        name: Some("MyCamera2000".to_string()),
+        endpoint_reference: None,
+    }));
+}
+
+#[test]
+fn test_resend_schedule() {
+    let start = Instant::now();
+
+    assert!(DiscoveryBuilder::default()
+        .resend_schedule(start)
+        .is_empty());
+
+    let schedule = DiscoveryBuilder::default()
+        .probe_count(3)
+        .probe_interval(Duration::from_millis(100))
+        .resend_schedule(start);
+
+    assert_eq!(schedule.len(), 2);
+
+    let mut previous = start;
+    for at in schedule {
+        assert!(at - previous >= Duration::from_millis(50));
+        assert!(at - previous <= Duration::from_millis(150));
+        previous = at;
+    }
+}
+
+#[test]
+fn test_xaddr_policy() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <SOAP-ENV:Envelope
+                    xmlns:SOAP-ENV="http://www.w3.org/2003/05/soap-envelope"
+                    xmlns:wsa="http://schemas.xmlsoap.org/ws/2004/08/addressing"
+                    xmlns:d="http://schemas.xmlsoap.org/ws/2005/04/discovery">
+            <SOAP-ENV:Header>
+                <wsa:RelatesTo>uuid:84ede3de-7dec-11d0-c360-F01234567890</wsa:RelatesTo>
+            </SOAP-ENV:Header>
+            <SOAP-ENV:Body>
+                <d:ProbeMatches>
+                    <d:ProbeMatch>
+                        <d:Scopes>onvif://www.onvif.org/name/MyCamera2000</d:Scopes>
+                        <d:XAddrs>http://[fe80::1]/onvif/device_service http://camera.local/onvif/device_service http://169.254.3.4/onvif/device_service http://10.0.0.2/onvif/device_service http://192.168.0.2/onvif/device_service</d:XAddrs>
+                    </d:ProbeMatch>
+                </d:ProbeMatches>
+            </SOAP-ENV:Body>
+        </SOAP-ENV:Envelope>
+        "#;
+
+    async fn is_addr_responding(uri: Url) -> bool {
+        uri.host_str() != Some("10.0.0.2")
+    }
+
+    let select = |policy: XAddrPolicy| {
+        let envelope = yaserde::de::from_str::<probe_matches::Envelope>(xml).unwrap();
+        tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(get_responding_addr(envelope, &policy, is_addr_responding))
+            .map(|device| device.url.host_str().unwrap().to_string())
+    };
+
+    assert_eq!(
+        select(XAddrPolicy::PreferRoutableIpv4).as_deref(),
+        Some("192.168.0.2")
+    );
+    assert_eq!(
+        select(XAddrPolicy::SameSubnet(vec![(
+            "169.254.0.0".parse().unwrap(),
+            16
+        )]))
+        .as_deref(),
+        Some("169.254.3.4")
+    );
+    assert_eq!(
+        select(XAddrPolicy::custom(|url| url
+            .host_str()
+            .filter(|host| host.ends_with(".local"))
+            .map(|_| 0)))
+        .as_deref(),
+        Some("camera.local")
+    );
+    assert_eq!(select(XAddrPolicy::custom(|_| None)), None);
+
+    assert!(in_network(
+        "fe80::1".parse().unwrap(),
+        "fe80::".parse().unwrap(),
+        64
+    ));
+    assert!(!in_network(
+        "192.168.1.2".parse().unwrap(),
+        "192.168.0.0".parse().unwrap(),
+        24
+    ));
+    assert!(in_network(
+        "192.168.1.2".parse().unwrap(),
+        "10.0.0.0".parse().unwrap(),
+        0
+    ));
+}
@@ -0,0 +1,269 @@
+//! Discovery settings of a device, e.g. to stop answering multicast probes once a device is
+//! provisioned, and to announce it to a discovery proxy instead.
+//!
+//! ```no_run
+//! # async fn example(device_service: onvif_soap::client::Client) {
+//! use onvif_discovery::settings;
+//!
+//! settings::set_proxy_addresses(&device_service, &["dp.example.com".parse().unwrap()])
+//!     .await
+//!     .unwrap();
+//! settings::set_remote_discoverable(&device_service, true)
+//!     .await
+//!     .unwrap();
+//! settings::set_discoverable(&device_service, false)
+//!     .await
+//!     .unwrap();
+//! # }
+//! ```
+
+use schema::{
+    devicemgmt, onvif as tt,
+    transport::{Error as TransportError, Transport},
+};
+use std::{
+    fmt,
+    net::{Ipv4Addr, Ipv6Addr},
+    str::FromStr,
+};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Transport error: {0}")]
+    Transport(#[from] TransportError),
+
+    #[error("Unexpected response: {0}")]
+    Response(String),
+}
+
+/// Address of a discovery proxy.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProxyAddress {
+    Ipv4(Ipv4Addr),
+    Ipv6(Ipv6Addr),
+    Dns(String),
+}
+
+impl FromStr for ProxyAddress {
+    type Err = std::convert::Infallible;
+
+    /// Parses an IP address, or takes anything else as a host name.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(if let Ok(address) = s.parse() {
+            ProxyAddress::Ipv4(address)
+        } else if let Ok(address) = s.parse() {
+            ProxyAddress::Ipv6(address)
+        } else {
+            ProxyAddress::Dns(s.to_string())
+        })
+    }
+}
+
+impl fmt::Display for ProxyAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProxyAddress::Ipv4(address) => address.fmt(f),
+            ProxyAddress::Ipv6(address) => address.fmt(f),
+            ProxyAddress::Dns(name) => f.write_str(name),
+        }
+    }
+}
+
+impl ProxyAddress {
+    fn to_schema(&self) -> tt::NetworkHost {
+        let mut host = tt::NetworkHost::default();
+        match self {
+            ProxyAddress::Ipv4(address) => {
+                host._type = tt::NetworkHostType::Ipv4;
+                host.i_pv_4_address = Some(tt::Ipv4Address(address.to_string()));
+            }
+            ProxyAddress::Ipv6(address) => {
+                host._type = tt::NetworkHostType::Ipv6;
+                host.i_pv_6_address = Some(tt::Ipv6Address(address.to_string()));
+            }
+            ProxyAddress::Dns(name) => {
+                host._type = tt::NetworkHostType::Dns;
+                host.dn_sname = Some(tt::Dnsname(name.clone()));
+            }
+        }
+        host
+    }
+
+    fn from_schema(host: &tt::NetworkHost) -> Result<Self, Error> {
+        let invalid = || Error::Response(format!("invalid discovery proxy address {:?}", host));
+        match host._type {
+            tt::NetworkHostType::Ipv4 => host
+                .i_pv_4_address
+                .as_ref()
+                .and_then(|address| address.0.parse().ok())
+                .map(ProxyAddress::Ipv4)
+                .ok_or_else(invalid),
+            tt::NetworkHostType::Ipv6 => host
+                .i_pv_6_address
+                .as_ref()
+                .and_then(|address| address.0.parse().ok())
+                .map(ProxyAddress::Ipv6)
+                .ok_or_else(invalid),
+            tt::NetworkHostType::Dns => host
+                .dn_sname
+                .as_ref()
+                .map(|name| ProxyAddress::Dns(name.0.clone()))
+                .ok_or_else(invalid),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Discovery settings, see [`settings`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiscoverySettings {
+    /// Whether the device answers multicast probes and announces itself with Hello messages.
+    pub discoverable: bool,
+
+    /// Whether the device announces itself to discovery proxies, or `None` if it doesn't
+    /// support remote discovery.
+    pub remote_discoverable: Option<bool>,
+
+    /// The discovery proxies, empty if there are none or remote discovery isn't supported.
+    pub proxy_addresses: Vec<ProxyAddress>,
+}
+
+/// Requests the discovery settings. Devices without remote discovery support answer its
+/// requests with faults, which is reported as `remote_discoverable: None`.
+pub async fn settings<T: Transport>(device_service: &T) -> Result<DiscoverySettings, Error> {
+    let discoverable = devicemgmt::get_discovery_mode(device_service, &Default::default())
+        .await?
+        .discovery_mode
+        == tt::DiscoveryMode::Discoverable;
+
+    let remote_discoverable =
+        match devicemgmt::get_remote_discovery_mode(device_service, &Default::default()).await {
+            Ok(response) => Some(response.remote_discovery_mode == tt::DiscoveryMode::Discoverable),
+            Err(TransportError::Fault(fault)) => {
+                tracing::debug!("remote discovery isn't supported: {}", fault);
+                None
+            }
+            Err(error) => return Err(error.into()),
+        };
+    let proxy_addresses = match remote_discoverable {
+        Some(_) => devicemgmt::get_dp_addresses(device_service, &Default::default())
+            .await?
+            .dp_address
+            .iter()
+            .map(ProxyAddress::from_schema)
+            .collect::<Result<_, _>>()?,
+        None => vec![],
+    };
+
+    Ok(DiscoverySettings {
+        discoverable,
+        remote_discoverable,
+        proxy_addresses,
+    })
+}
+
+pub async fn set_discoverable<T: Transport>(
+    device_service: &T,
+    discoverable: bool,
+) -> Result<(), Error> {
+    devicemgmt::set_discovery_mode(
+        device_service,
+        &devicemgmt::SetDiscoveryMode {
+            discovery_mode: discovery_mode(discoverable),
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+pub async fn set_remote_discoverable<T: Transport>(
+    device_service: &T,
+    discoverable: bool,
+) -> Result<(), Error> {
+    devicemgmt::set_remote_discovery_mode(
+        device_service,
+        &devicemgmt::SetRemoteDiscoveryMode {
+            remote_discovery_mode: discovery_mode(discoverable),
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+/// Replaces the discovery proxies. Devices that don't support remote discovery answer with a
+/// fault.
+pub async fn set_proxy_addresses<T: Transport>(
+    device_service: &T,
+    addresses: &[ProxyAddress],
+) -> Result<(), Error> {
+    devicemgmt::set_dp_addresses(
+        device_service,
+        &devicemgmt::SetDPAddresses {
+            dp_address: addresses.iter().map(ProxyAddress::to_schema).collect(),
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+fn discovery_mode(discoverable: bool) -> tt::DiscoveryMode {
+    if discoverable {
+        tt::DiscoveryMode::Discoverable
+    } else {
+        tt::DiscoveryMode::NonDiscoverable
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use schema::transport::Fault;
+
+    /// Discoverable, without remote discovery support.
+    struct FakeDevice;
+
+    #[async_trait]
+    impl Transport for FakeDevice {
+        async fn request(&self, message: &str) -> Result<String, TransportError> {
+            if message.contains("tds:GetRemoteDiscoveryMode") {
+                return Err(TransportError::Fault(Fault::new(
+                    vec![
+                        "env:Receiver".to_string(),
+                        "ter:ActionNotSupported".to_string(),
+                    ],
+                    None,
+                )));
+            }
+            Ok(r#"
+                <tds:GetDiscoveryModeResponse
+                    xmlns:tds="http://www.onvif.org/ver10/device/wsdl">
+                    <tds:DiscoveryMode>Discoverable</tds:DiscoveryMode>
+                </tds:GetDiscoveryModeResponse>
+                "#
+            .to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn discovery_settings() {
+        assert_eq!(
+            settings(&FakeDevice).await.unwrap(),
+            DiscoverySettings {
+                discoverable: true,
+                remote_discoverable: None,
+                proxy_addresses: vec![],
+            }
+        );
+
+        for address in &["192.168.0.3", "fe80::1", "dp.example.com"] {
+            let parsed: ProxyAddress = address.parse().unwrap();
+            assert_eq!(parsed.to_string(), *address);
+            assert_eq!(
+                ProxyAddress::from_schema(&parsed.to_schema()).unwrap(),
+                parsed
+            );
+        }
+    }
+}
@@ -0,0 +1,256 @@
+//! An HTTP gateway exposing cameras as JSON endpoints:
+//!
+//! - `GET /devices`
+//! - `GET /devices/{id}/capabilities`
+//! - `GET /devices/{id}/profiles`
+//! - `GET /devices/{id}/snapshot?profile={token}`, a JPEG of the first profile by default
+//! - `POST /devices/{id}/ptz/move` with `{"profile": "...", "pan": 0.5, "tilt": 0, "zoom": 0}`
+//!   in the generic spaces, or `"relative": true` for a relative move
+//!
+//! Devices are given as `id=uri` pairs, credentials with `ONVIF_USERNAME` and `ONVIF_PASSWORD`:
+//!
+//! ```shell script
+//! cargo run --example gateway -- front=http://192.168.0.2 back=http://192.168.0.3
+//! curl localhost:3000/devices/front/profiles
+//! ```
+
+use axum::{
+    extract::{Extension, Path, Query},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    routing::{get, post},
+    AddExtensionLayer, Json, Router,
+};
+use onvif::{
+    capabilities::CapabilityCache,
+    ptz::{self, Guard},
+    schema::{json::ToJson, media, onvif as tt},
+    soap::client::{Client, ClientBuilder, Credentials},
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::{collections::BTreeMap, fmt::Display, net::SocketAddr, sync::Arc, time::Duration};
+use url::Url;
+
+const MEDIA_NS: &str = "http://www.onvif.org/ver10/media/wsdl";
+const PTZ_NS: &str = "http://www.onvif.org/ver20/ptz/wsdl";
+
+/// Error responses, as status and message.
+type ApiError = (StatusCode, String);
+
+struct Device {
+    device_service: Client,
+    credentials: Option<Credentials>,
+    // Service addresses rarely change, so `GetServices` isn't repeated on every request.
+    capabilities: CapabilityCache,
+}
+
+impl Device {
+    fn connect(&self, uri: &Url) -> Client {
+        ClientBuilder::new(uri)
+            .credentials(self.credentials.clone())
+            .build()
+    }
+
+    /// A client for the service with `namespace`, or 404 if the device doesn't offer it.
+    async fn service(&self, namespace: &str) -> Result<Client, ApiError> {
+        let capabilities = self
+            .capabilities
+            .get(&self.device_service, |uri| self.connect(uri))
+            .await
+            .map_err(bad_gateway)?;
+        let uri = capabilities.services.get(namespace).ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                format!("device doesn't offer {}", namespace),
+            )
+        })?;
+        Ok(self.connect(uri))
+    }
+}
+
+type Devices = Arc<BTreeMap<String, Device>>;
+
+fn device<'a>(devices: &'a Devices, id: &str) -> Result<&'a Device, ApiError> {
+    devices
+        .get(id)
+        .ok_or_else(|| (StatusCode::NOT_FOUND, format!("unknown device {}", id)))
+}
+
+/// Errors of the device, as opposed to errors of the request.
+fn bad_gateway(error: impl Display) -> ApiError {
+    (StatusCode::BAD_GATEWAY, error.to_string())
+}
+
+async fn list(Extension(devices): Extension<Devices>) -> Json<Value> {
+    Json(json!(devices.keys().collect::<Vec<_>>()))
+}
+
+async fn capabilities(
+    Extension(devices): Extension<Devices>,
+    Path(id): Path<String>,
+) -> Result<Json<Value>, ApiError> {
+    let device = device(&devices, &id)?;
+    let capabilities = device
+        .capabilities
+        .get(&device.device_service, |uri| device.connect(uri))
+        .await
+        .map_err(bad_gateway)?;
+    Ok(Json(json!({
+        "firmware_version": capabilities.firmware_version,
+        "services": capabilities.services,
+        "capabilities": capabilities.report,
+    })))
+}
+
+async fn profiles(
+    Extension(devices): Extension<Devices>,
+    Path(id): Path<String>,
+) -> Result<Json<Value>, ApiError> {
+    let media_service = device(&devices, &id)?.service(MEDIA_NS).await?;
+    let profiles = media::get_profiles(&media_service, &Default::default())
+        .await
+        .map_err(bad_gateway)?;
+    Ok(Json(profiles.to_json().map_err(bad_gateway)?))
+}
+
+#[derive(Deserialize)]
+struct SnapshotQuery {
+    profile: Option<String>,
+}
+
+async fn snapshot(
+    Extension(devices): Extension<Devices>,
+    Path(id): Path<String>,
+    Query(query): Query<SnapshotQuery>,
+) -> Result<(HeaderMap, Vec<u8>), ApiError> {
+    let media_service = device(&devices, &id)?.service(MEDIA_NS).await?;
+    let profile_token = match query.profile {
+        Some(profile) => tt::ReferenceToken(profile),
+        None => {
+            media::get_profiles(&media_service, &Default::default())
+                .await
+                .map_err(bad_gateway)?
+                .profiles
+                .into_iter()
+                .next()
+                .ok_or_else(|| bad_gateway("device has no media profiles"))?
+                .token
+        }
+    };
+
+    let uri = media::get_snapshot_uri(&media_service, &media::GetSnapshotUri { profile_token })
+        .await
+        .map_err(bad_gateway)?
+        .media_uri
+        .uri;
+    let uri = Url::parse(&uri).map_err(bad_gateway)?;
+    // Snapshot URIs usually need the same credentials as the services.
+    let jpeg = media_service.get(&uri).await.map_err(bad_gateway)?;
+
+    let mut headers = HeaderMap::new();
+    headers.insert(header::CONTENT_TYPE, HeaderValue::from_static("image/jpeg"));
+    Ok((headers, jpeg))
+}
+
+#[derive(Deserialize)]
+struct Move {
+    profile: String,
+    pan: f64,
+    tilt: f64,
+    zoom: Option<f64>,
+    #[serde(default)]
+    relative: bool,
+}
+
+async fn ptz_move(
+    Extension(devices): Extension<Devices>,
+    Path(id): Path<String>,
+    Json(request): Json<Move>,
+) -> Result<Json<Value>, ApiError> {
+    let device = device(&devices, &id)?;
+    let media_service = device.service(MEDIA_NS).await?;
+    let ptz_service = device.service(PTZ_NS).await?;
+
+    // Out of range targets are refused rather than driving the mechanics into their stops.
+    let profile_token = tt::ReferenceToken(request.profile);
+    let profile = media::get_profile(
+        &media_service,
+        &media::GetProfile {
+            profile_token: profile_token.clone(),
+        },
+    )
+    .await
+    .map_err(bad_gateway)?
+    .profile;
+    let limits = ptz::profile_limits(&profile);
+
+    let vector = tt::Ptzvector {
+        pan_tilt: Some(tt::Vector2D {
+            x: request.pan,
+            y: request.tilt,
+            space: None,
+        }),
+        zoom: request.zoom.map(|x| tt::Vector1D { x, space: None }),
+    };
+    let guard = Some((&limits, Guard::Reject));
+    let result = if request.relative {
+        ptz::relative_move(&ptz_service, &profile_token, &vector, None, guard).await
+    } else {
+        ptz::absolute_move(&ptz_service, &profile_token, &vector, None, guard).await
+    };
+
+    match result {
+        Ok(()) => Ok(Json(json!({ "moved": true }))),
+        Err(error @ ptz::Error::OutOfLimits { .. }) => {
+            Err((StatusCode::UNPROCESSABLE_ENTITY, error.to_string()))
+        }
+        Err(error) => Err(bad_gateway(error)),
+    }
+}
+
+fn parse_device(arg: &str) -> Result<(String, Url), String> {
+    let (id, uri) = arg
+        .split_once('=')
+        .ok_or_else(|| format!("expected ID=URI, got {}", arg))?;
+    let uri = Url::parse(uri)
+        .and_then(|uri| uri.join("onvif/device_service"))
+        .map_err(|e| format!("{}: {}", uri, e))?;
+    Ok((id.to_string(), uri))
+}
+
+#[tokio::main]
+async fn main() {
+    dotenv::dotenv().ok();
+    tracing_subscriber::fmt::init();
+
+    let credentials = Credentials::from_env();
+    let devices: BTreeMap<String, Device> = std::env::args()
+        .skip(1)
+        .map(|arg| {
+            let (id, uri) = parse_device(&arg).unwrap_or_else(|e| panic!("{}", e));
+            let device = Device {
+                device_service: ClientBuilder::new(&uri)
+                    .credentials(credentials.clone())
+                    .build(),
+                credentials: credentials.clone(),
+                capabilities: CapabilityCache::new(Duration::from_secs(300)),
+            };
+            (id, device)
+        })
+        .collect();
+
+    let app = Router::new()
+        .route("/devices", get(list))
+        .route("/devices/:id/capabilities", get(capabilities))
+        .route("/devices/:id/profiles", get(profiles))
+        .route("/devices/:id/snapshot", get(snapshot))
+        .route("/devices/:id/ptz/move", post(ptz_move))
+        .layer(AddExtensionLayer::new(Arc::new(devices)));
+
+    let address = SocketAddr::from(([0, 0, 0, 0], 3000));
+    println!("Listening on {}", address);
+    axum::Server::bind(&address)
+        .serve(app.into_make_service())
+        .await
+        .unwrap();
+}
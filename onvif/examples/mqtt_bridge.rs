@@ -0,0 +1,125 @@
+//! Publishes events of devices to an MQTT broker, see [`onvif::mqtt`] for the topics:
+//!
+//! ```shell script
+//! MQTT_HOST=localhost ONVIF_USERNAME=admin ONVIF_PASSWORD=qwerty \
+//!     cargo run --example mqtt_bridge --features mqtt-bridge -- front=http://192.168.0.2
+//! mosquitto_sub -t 'onvif/front/#' -v
+//! ```
+//!
+//! `MQTT_PORT` defaults to 1883 and `MQTT_PREFIX` to `onvif`. Ctrl-C deletes the subscriptions.
+
+use futures_util::future::join_all;
+use onvif::{
+    capabilities::CapabilityCache,
+    event, mqtt,
+    soap::client::{ClientBuilder, Credentials},
+};
+use rumqttc::{AsyncClient, MqttOptions};
+use std::time::Duration;
+use tokio::sync::watch;
+use url::Url;
+
+const EVENTS_NS: &str = "http://www.onvif.org/ver10/events/wsdl";
+
+async fn run(
+    mqtt_client: &AsyncClient,
+    prefix: &str,
+    device: &str,
+    uri: Url,
+    credentials: Option<Credentials>,
+    mut shutdown: watch::Receiver<bool>,
+) -> Result<(), String> {
+    let connect = |uri: &Url| {
+        ClientBuilder::new(uri)
+            .credentials(credentials.clone())
+            .build()
+    };
+    let capabilities = CapabilityCache::new(Duration::from_secs(0))
+        .get(&connect(&uri), connect)
+        .await
+        .map_err(|e| e.to_string())?;
+    let event_service = connect(
+        capabilities
+            .services
+            .get(EVENTS_NS)
+            .ok_or("device has no event service")?,
+    );
+
+    let notifications = event::subscribe_until(
+        &event_service,
+        |address, timeout| {
+            ClientBuilder::new(address)
+                .credentials(credentials.clone())
+                .timeout(timeout)
+                .build()
+        },
+        // The current state of sensors is published when the bridge starts.
+        event::SubscriptionOptions::default().synchronize(true),
+        async move {
+            let _ = shutdown.changed().await;
+        },
+    );
+    mqtt::bridge(mqtt_client, prefix, device, notifications)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tokio::main]
+async fn main() {
+    dotenv::dotenv().ok();
+    tracing_subscriber::fmt::init();
+
+    let host = std::env::var("MQTT_HOST").unwrap_or_else(|_| "localhost".to_string());
+    let port = std::env::var("MQTT_PORT")
+        .ok()
+        .map(|port| port.parse().expect("invalid MQTT_PORT"))
+        .unwrap_or(1883);
+    let prefix = std::env::var("MQTT_PREFIX").unwrap_or_else(|_| "onvif".to_string());
+    let credentials = Credentials::from_env();
+
+    let devices: Vec<(String, Url)> = std::env::args()
+        .skip(1)
+        .map(|arg| {
+            let (device, uri) = arg
+                .split_once('=')
+                .unwrap_or_else(|| panic!("expected DEVICE=URI, got {}", arg));
+            let uri = Url::parse(uri)
+                .and_then(|uri| uri.join("onvif/device_service"))
+                .unwrap_or_else(|e| panic!("{}: {}", uri, e));
+            (device.to_string(), uri)
+        })
+        .collect();
+
+    let mut options = MqttOptions::new("onvif-bridge", host, port);
+    options.set_keep_alive(30);
+    let (mqtt_client, mut event_loop) = AsyncClient::new(options, 64);
+    tokio::spawn(async move {
+        loop {
+            if let Err(error) = event_loop.poll().await {
+                tracing::warn!("MQTT connection failed: {}", error);
+                tokio::time::sleep(Duration::from_secs(5)).await;
+            }
+        }
+    });
+
+    let (stop, shutdown) = watch::channel(false);
+    tokio::spawn(async move {
+        let _ = tokio::signal::ctrl_c().await;
+        let _ = stop.send(true);
+    });
+
+    let bridges = devices.into_iter().map(|(device, uri)| {
+        let (mqtt_client, prefix, credentials, shutdown) =
+            (&mqtt_client, &prefix, credentials.clone(), shutdown.clone());
+        async move {
+            if let Err(error) = run(mqtt_client, prefix, &device, uri, credentials, shutdown).await
+            {
+                eprintln!("{}: {}", device, error);
+            }
+        }
+    });
+    join_all(bridges).await;
+
+    // Gives the event loop a moment to send the final availability messages.
+    tokio::time::sleep(Duration::from_millis(500)).await;
+}
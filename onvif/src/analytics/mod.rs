@@ -0,0 +1,1048 @@
+//! Analytics service rules.
+//!
+//! The generated `tt:ElementItem` type drops the content of the item, which would wipe e.g. the
+//! detection field of a rule on a read-modify-write cycle. Rules are therefore read and written
+//! as raw XML here, and so are analytics modules, which share the `tt:Config` type.
+
+use schema::{
+    onvif as tt,
+    transport::{Error as TransportError, Transport},
+};
+use serde::Serialize;
+use std::{collections::BTreeMap, str::FromStr, time::Duration};
+use thiserror::Error;
+use xmltree::{Element, EmitterConfig, Namespace, XMLNode};
+
+const TAN_NS: &str = "http://www.onvif.org/ver20/analytics/wsdl";
+const TT_NS: &str = "http://www.onvif.org/ver10/schema";
+
+/// Simple item holding the sensitivity of many detectors, including audio detection rules
+/// whose events are [`DETECTED_SOUND_TOPIC`](crate::event::DETECTED_SOUND_TOPIC).
+pub const SENSITIVITY: &str = "Sensitivity";
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Transport error: {0}")]
+    Transport(#[from] TransportError),
+
+    #[error("Unexpected response: {0}")]
+    Response(String),
+
+    #[error("No rule named {0}")]
+    UnknownRule(String),
+}
+
+/// A rule or analytics module configuration, i.e. a `tt:Config`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Config {
+    pub name: String,
+
+    /// Qualified type name, e.g. `tt:CellMotionDetector`.
+    pub config_type: String,
+
+    pub simple_items: BTreeMap<String, String>,
+
+    /// Element items with their content, e.g. the `tt:Polygon` of a field detector.
+    pub element_items: BTreeMap<String, Element>,
+
+    // Namespace of the prefix of `config_type` as declared by the device, which has to be
+    // declared again when sending the configuration back.
+    type_namespace: Option<String>,
+}
+
+impl Config {
+    /// A configuration with a type in the ONVIF schema namespace, e.g. `tt:LineDetector`.
+    pub fn new(name: &str, config_type: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            config_type: config_type.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Namespace of the prefix of `config_type`, for types outside the ONVIF schema namespace,
+    /// e.g. `ttr:SpotMeasurementModule`.
+    pub fn type_namespace(mut self, namespace: &str) -> Self {
+        self.type_namespace = Some(namespace.to_string());
+        self
+    }
+
+    pub fn simple_item(mut self, name: &str, value: &str) -> Self {
+        self.simple_items
+            .insert(name.to_string(), value.to_string());
+        self
+    }
+
+    pub fn element_item(mut self, name: &str, content: Element) -> Self {
+        self.element_items.insert(name.to_string(), content);
+        self
+    }
+
+    fn from_element(element: &Element) -> Self {
+        let config_type = element.attributes.get("Type").cloned().unwrap_or_default();
+        let type_namespace = config_type.split_once(':').and_then(|(prefix, _)| {
+            element
+                .namespaces
+                .as_ref()
+                .and_then(|n| n.get(prefix))
+                .map(str::to_string)
+        });
+
+        let mut config = Config {
+            name: element.attributes.get("Name").cloned().unwrap_or_default(),
+            config_type,
+            type_namespace,
+            ..Default::default()
+        };
+        if let Some(parameters) = element.get_child("Parameters") {
+            for item in children(parameters, "SimpleItem") {
+                if let (Some(name), Some(value)) =
+                    (item.attributes.get("Name"), item.attributes.get("Value"))
+                {
+                    config.simple_items.insert(name.clone(), value.clone());
+                }
+            }
+            for item in children(parameters, "ElementItem") {
+                let content = item.children.iter().find_map(|node| match node {
+                    XMLNode::Element(content) => Some(content.clone()),
+                    _ => None,
+                });
+                if let (Some(name), Some(content)) = (item.attributes.get("Name"), content) {
+                    config.element_items.insert(name.clone(), content);
+                }
+            }
+        }
+        config
+    }
+
+    /// Serializes the configuration as an element named `tag`, e.g. `tan:Rule`.
+    fn to_xml(&self, tag: &str) -> Result<String, Error> {
+        let mut declarations = format!(r#" xmlns:tt="{}""#, TT_NS);
+        if let (Some((prefix, _)), Some(namespace)) =
+            (self.config_type.split_once(':'), &self.type_namespace)
+        {
+            if prefix != "tt" {
+                declarations += &format!(r#" xmlns:{}="{}""#, prefix, escape(namespace));
+            }
+        }
+
+        let mut parameters = String::new();
+        for (name, value) in &self.simple_items {
+            parameters += &format!(
+                r#"<tt:SimpleItem Name="{}" Value="{}"/>"#,
+                escape(name),
+                escape(value)
+            );
+        }
+        for (name, content) in &self.element_items {
+            parameters += &format!(
+                r#"<tt:ElementItem Name="{}">{}</tt:ElementItem>"#,
+                escape(name),
+                element_to_string(content)?
+            );
+        }
+
+        Ok(format!(
+            r#"<{tag} Name="{}" Type="{}"{}><tt:Parameters>{}</tt:Parameters></{tag}>"#,
+            escape(&self.name),
+            escape(&self.config_type),
+            declarations,
+            parameters,
+            tag = tag
+        ))
+    }
+}
+
+/// Encodes a `tt:Polyline` in normalized coordinates, e.g. for the `Segments` of line rules.
+pub fn polyline(points: &[(f64, f64)]) -> Element {
+    points_element("Polyline", points)
+}
+
+/// Encodes a `tt:Polygon` in normalized coordinates, e.g. for the `Field` of field rules.
+pub fn polygon(points: &[(f64, f64)]) -> Element {
+    points_element("Polygon", points)
+}
+
+/// A `tt:LineCrossingCounter` rule (Profile M), which counts the objects crossing `line` and
+/// reports the count every `report_interval`. An empty `class_filter` counts all objects,
+/// otherwise only those of the given classes, e.g. `Human`.
+pub fn line_crossing_counter(
+    name: &str,
+    line: &[(f64, f64)],
+    report_interval: Duration,
+    class_filter: &[&str],
+) -> Config {
+    with_class_filter(
+        Config::new(name, "tt:LineCrossingCounter")
+            .element_item("Segments", polyline(line))
+            .simple_item("ReportTimeInterval", &xs_duration(report_interval)),
+        class_filter,
+    )
+}
+
+/// A `tt:CountAggregation` rule (Profile M), which counts the objects inside `field` and reports
+/// the count every `report_interval`. See [`line_crossing_counter`] for `class_filter`.
+pub fn object_counter(
+    name: &str,
+    field: &[(f64, f64)],
+    report_interval: Duration,
+    class_filter: &[&str],
+) -> Config {
+    with_class_filter(
+        Config::new(name, "tt:CountAggregation")
+            .element_item("Field", polygon(field))
+            .simple_item("ReportTimeInterval", &xs_duration(report_interval)),
+        class_filter,
+    )
+}
+
+/// Which crossings of a line are detected, as seen when walking along its segments.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Direction {
+    Left,
+    Right,
+    Any,
+}
+
+impl Direction {
+    fn as_str(self) -> &'static str {
+        match self {
+            Direction::Left => "Left",
+            Direction::Right => "Right",
+            Direction::Any => "Any",
+        }
+    }
+}
+
+/// Builds a `tt:LineDetector` rule, which raises `tns1:RuleEngine/LineDetector/Crossed` events.
+///
+/// ```
+/// use onvif::analytics::{Direction, LineDetector};
+///
+/// let rule = LineDetector::new("Fence", &[(-0.8, -0.2), (0.8, -0.2)])
+///     .direction(Direction::Left)
+///     .build();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineDetector {
+    name: String,
+    segments: Vec<(f64, f64)>,
+    direction: Direction,
+}
+
+impl LineDetector {
+    /// A detector for the polyline through `segments`, in normalized coordinates.
+    pub fn new(name: &str, segments: &[(f64, f64)]) -> Self {
+        Self {
+            name: name.to_string(),
+            segments: segments.to_vec(),
+            direction: Direction::Any,
+        }
+    }
+
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    pub fn build(&self) -> Config {
+        Config::new(&self.name, "tt:LineDetector")
+            .simple_item("Direction", self.direction.as_str())
+            .element_item("Segments", polyline(&self.segments))
+    }
+}
+
+/// Builds a `tt:FieldDetector` rule, which raises `tns1:RuleEngine/FieldDetector/ObjectsInside`
+/// events.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldDetector {
+    name: String,
+    field: Vec<(f64, f64)>,
+}
+
+impl FieldDetector {
+    /// A detector for the polygon `field`, in normalized coordinates.
+    pub fn new(name: &str, field: &[(f64, f64)]) -> Self {
+        Self {
+            name: name.to_string(),
+            field: field.to_vec(),
+        }
+    }
+
+    pub fn build(&self) -> Config {
+        Config::new(&self.name, "tt:FieldDetector").element_item("Field", polygon(&self.field))
+    }
+}
+
+/// Builds a `tt:LoiteringDetector` rule, which raises
+/// `tns1:RuleEngine/LoiteringDetector/ObjectIsLoitering` events.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoiteringDetector {
+    name: String,
+    field: Vec<(f64, f64)>,
+    time_threshold: Duration,
+}
+
+impl LoiteringDetector {
+    /// A detector for objects staying in the polygon `field` for 10 seconds by default.
+    pub fn new(name: &str, field: &[(f64, f64)]) -> Self {
+        Self {
+            name: name.to_string(),
+            field: field.to_vec(),
+            time_threshold: Duration::from_secs(10),
+        }
+    }
+
+    /// How long an object has to stay in the field to be loitering.
+    pub fn time_threshold(mut self, time_threshold: Duration) -> Self {
+        self.time_threshold = time_threshold;
+        self
+    }
+
+    pub fn build(&self) -> Config {
+        Config::new(&self.name, "tt:LoiteringDetector")
+            .simple_item("TimeThreshold", &xs_duration(self.time_threshold))
+            .element_item("Field", polygon(&self.field))
+    }
+}
+
+fn with_class_filter(config: Config, class_filter: &[&str]) -> Config {
+    if class_filter.is_empty() {
+        config
+    } else {
+        config.simple_item("ClassFilter", &class_filter.join(" "))
+    }
+}
+
+fn points_element(name: &str, points: &[(f64, f64)]) -> Element {
+    let mut element = tt_element(name);
+    let mut namespaces = Namespace::empty();
+    namespaces.put("tt", TT_NS);
+    element.namespaces = Some(namespaces);
+
+    for &(x, y) in points {
+        let mut point = tt_element("Point");
+        point.attributes.insert("x".to_string(), x.to_string());
+        point.attributes.insert("y".to_string(), y.to_string());
+        element.children.push(XMLNode::Element(point));
+    }
+    element
+}
+
+fn tt_element(name: &str) -> Element {
+    let mut element = Element::new(name);
+    element.prefix = Some("tt".to_string());
+    element.namespace = Some(TT_NS.to_string());
+    element
+}
+
+fn xs_duration(duration: Duration) -> String {
+    format!("PT{}S", duration.as_secs())
+}
+
+/// Lists the rules of an analytics configuration.
+pub async fn rules<T: Transport>(
+    analytics_service: &T,
+    configuration_token: &tt::ReferenceToken,
+) -> Result<Vec<Config>, Error> {
+    get_configs(analytics_service, "GetRules", "Rule", configuration_token).await
+}
+
+pub async fn create_rules<T: Transport>(
+    analytics_service: &T,
+    configuration_token: &tt::ReferenceToken,
+    rules: &[Config],
+) -> Result<(), Error> {
+    send_configs(
+        analytics_service,
+        "CreateRules",
+        "tan:Rule",
+        configuration_token,
+        rules,
+    )
+    .await
+}
+
+/// Replaces the rules with the same names.
+pub async fn modify_rules<T: Transport>(
+    analytics_service: &T,
+    configuration_token: &tt::ReferenceToken,
+    rules: &[Config],
+) -> Result<(), Error> {
+    send_configs(
+        analytics_service,
+        "ModifyRules",
+        "tan:Rule",
+        configuration_token,
+        rules,
+    )
+    .await
+}
+
+pub async fn delete_rules<T: Transport>(
+    analytics_service: &T,
+    configuration_token: &tt::ReferenceToken,
+    names: &[&str],
+) -> Result<(), Error> {
+    delete_configs(
+        analytics_service,
+        "DeleteRules",
+        "RuleName",
+        configuration_token,
+        names,
+    )
+    .await
+}
+
+/// Lists the analytics modules of an analytics configuration.
+pub async fn analytics_modules<T: Transport>(
+    analytics_service: &T,
+    configuration_token: &tt::ReferenceToken,
+) -> Result<Vec<Config>, Error> {
+    get_configs(
+        analytics_service,
+        "GetAnalyticsModules",
+        "AnalyticsModule",
+        configuration_token,
+    )
+    .await
+}
+
+pub async fn create_analytics_modules<T: Transport>(
+    analytics_service: &T,
+    configuration_token: &tt::ReferenceToken,
+    modules: &[Config],
+) -> Result<(), Error> {
+    send_configs(
+        analytics_service,
+        "CreateAnalyticsModules",
+        "tan:AnalyticsModule",
+        configuration_token,
+        modules,
+    )
+    .await
+}
+
+/// Replaces the analytics modules with the same names.
+pub async fn modify_analytics_modules<T: Transport>(
+    analytics_service: &T,
+    configuration_token: &tt::ReferenceToken,
+    modules: &[Config],
+) -> Result<(), Error> {
+    send_configs(
+        analytics_service,
+        "ModifyAnalyticsModules",
+        "tan:AnalyticsModule",
+        configuration_token,
+        modules,
+    )
+    .await
+}
+
+pub async fn delete_analytics_modules<T: Transport>(
+    analytics_service: &T,
+    configuration_token: &tt::ReferenceToken,
+    names: &[&str],
+) -> Result<(), Error> {
+    delete_configs(
+        analytics_service,
+        "DeleteAnalyticsModules",
+        "AnalyticsModuleName",
+        configuration_token,
+        names,
+    )
+    .await
+}
+
+/// Changes the [`SENSITIVITY`] of a rule, leaving its other parameters as they are. The range
+/// depends on the rule type, see `GetRuleOptions`; it's commonly 0 to 100.
+pub async fn set_rule_sensitivity<T: Transport>(
+    analytics_service: &T,
+    configuration_token: &tt::ReferenceToken,
+    rule: &str,
+    sensitivity: u32,
+) -> Result<(), Error> {
+    let mut config = rules(analytics_service, configuration_token)
+        .await?
+        .into_iter()
+        .find(|c| c.name == rule)
+        .ok_or_else(|| Error::UnknownRule(rule.to_string()))?;
+    config
+        .simple_items
+        .insert(SENSITIVITY.to_string(), sensitivity.to_string());
+
+    modify_rules(analytics_service, configuration_token, &[config]).await
+}
+
+/// A rule type an analytics configuration supports, with the parameters a rule of that type
+/// takes. Together with the [`ParameterOptions`], this is enough to render a form for creating
+/// or editing rules without knowing the rule types in advance.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct RuleDescription {
+    /// Qualified type name, e.g. `tt:LineDetector`.
+    pub rule_type: String,
+
+    /// Rules of this type can't be created or deleted, only modified.
+    pub fixed: bool,
+
+    pub max_instances: Option<u32>,
+    pub parameters: Vec<ParameterDescription>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum ParameterKind {
+    /// Goes into [`Config::simple_items`].
+    Simple,
+
+    /// Goes into [`Config::element_items`].
+    Element,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ParameterDescription {
+    pub name: String,
+    pub kind: ParameterKind,
+
+    /// Qualified type name, e.g. `xs:boolean` for simple items or `tt:Polygon` for element items.
+    pub value_type: String,
+
+    /// Allowed values from `GetRuleOptions`, if the device reports any.
+    pub options: Option<ParameterOptions>,
+}
+
+/// Allowed values of a rule parameter, decoded from the options element of `tan:RuleOptions`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub enum ParameterOptions {
+    IntRange {
+        min: i64,
+        max: i64,
+    },
+    FloatRange {
+        min: f64,
+        max: f64,
+    },
+
+    /// Durations in `xs:duration` format, e.g. `PT10S`.
+    DurationRange {
+        min: String,
+        max: String,
+    },
+
+    IntList(Vec<i64>),
+    FloatList(Vec<f64>),
+
+    /// Enumerated values, e.g. the directions of a line detector.
+    StringList(Vec<String>),
+
+    Polygon {
+        rectangle_only: bool,
+        min_points: Option<u32>,
+        max_points: Option<u32>,
+    },
+
+    /// Options of a type not decoded here, by the name of their element.
+    Other(String),
+}
+
+/// Describes the rule types of an analytics configuration and their parameters, using
+/// `GetSupportedRules` and `GetRuleOptions`. Devices without rule options are common, so a
+/// failing `GetRuleOptions` leaves the parameters without options rather than failing.
+pub async fn supported_rules<T: Transport>(
+    analytics_service: &T,
+    configuration_token: &tt::ReferenceToken,
+) -> Result<Vec<RuleDescription>, Error> {
+    let request = format!(
+        r#"<tan:GetSupportedRules xmlns:tan="{}"><tan:ConfigurationToken>{}</tan:ConfigurationToken></tan:GetSupportedRules>"#,
+        TAN_NS,
+        escape(&configuration_token.0)
+    );
+    let response = parse(&analytics_service.request(&request).await?)?;
+    let mut descriptions = response
+        .get_child("SupportedRules")
+        .map(|s| {
+            children(s, "RuleDescription")
+                .map(parse_rule_description)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    match rule_options(analytics_service, configuration_token).await {
+        Ok(options) => {
+            for (rule_type, name, parameter_options) in options {
+                for description in &mut descriptions {
+                    if rule_type
+                        .as_deref()
+                        .map_or(false, |t| t != description.rule_type)
+                    {
+                        continue;
+                    }
+                    if let Some(parameter) =
+                        description.parameters.iter_mut().find(|p| p.name == name)
+                    {
+                        parameter.options = Some(parameter_options.clone());
+                    }
+                }
+            }
+        }
+        Err(error) => tracing::debug!("failed to get rule options: {}", error),
+    }
+    Ok(descriptions)
+}
+
+/// Requests the options of all rule types, as (rule type, parameter name, options). The rule type
+/// is missing if the device doesn't say which type the options belong to.
+async fn rule_options<T: Transport>(
+    analytics_service: &T,
+    configuration_token: &tt::ReferenceToken,
+) -> Result<Vec<(Option<String>, String, ParameterOptions)>, Error> {
+    let request = format!(
+        r#"<tan:GetRuleOptions xmlns:tan="{}"><tan:ConfigurationToken>{}</tan:ConfigurationToken></tan:GetRuleOptions>"#,
+        TAN_NS,
+        escape(&configuration_token.0)
+    );
+    let response = parse(&analytics_service.request(&request).await?)?;
+    Ok(children(&response, "RuleOptions")
+        .filter_map(|options| {
+            let name = options.attributes.get("Name")?.clone();
+            let content = options.children.iter().find_map(|node| match node {
+                XMLNode::Element(content) => Some(content),
+                _ => None,
+            })?;
+            Some((
+                options.attributes.get("RuleType").cloned(),
+                name,
+                parse_parameter_options(content),
+            ))
+        })
+        .collect())
+}
+
+fn parse_rule_description(element: &Element) -> RuleDescription {
+    let mut parameters = vec![];
+    if let Some(items) = element.get_child("Parameters") {
+        for (tag, kind) in &[
+            ("SimpleItemDescription", ParameterKind::Simple),
+            ("ElementItemDescription", ParameterKind::Element),
+        ] {
+            for item in children(items, tag) {
+                if let Some(name) = item.attributes.get("Name") {
+                    parameters.push(ParameterDescription {
+                        name: name.clone(),
+                        kind: *kind,
+                        value_type: item.attributes.get("Type").cloned().unwrap_or_default(),
+                        options: None,
+                    });
+                }
+            }
+        }
+    }
+
+    RuleDescription {
+        rule_type: element.attributes.get("Name").cloned().unwrap_or_default(),
+        fixed: element
+            .attributes
+            .get("fixed")
+            .map_or(false, |f| f == "true"),
+        max_instances: element
+            .attributes
+            .get("maxInstances")
+            .and_then(|m| m.trim().parse().ok()),
+        parameters,
+    }
+}
+
+fn parse_parameter_options(element: &Element) -> ParameterOptions {
+    let child_text = |name: &str| element.get_child(name).and_then(text);
+    let items = || -> Vec<String> {
+        let items: Vec<String> = children(element, "Items").filter_map(text).collect();
+        if items.is_empty() {
+            // Some devices send the list type directly instead of wrapping it in `Items`.
+            text(element)
+                .map(|t| t.split_whitespace().map(str::to_string).collect())
+                .unwrap_or_default()
+        } else {
+            items
+        }
+    };
+
+    let decoded = match element.name.as_str() {
+        "IntRange" => range(element).map(|(min, max)| ParameterOptions::IntRange { min, max }),
+        "FloatRange" => range(element).map(|(min, max)| ParameterOptions::FloatRange { min, max }),
+        "DurationRange" => {
+            range(element).map(|(min, max)| ParameterOptions::DurationRange { min, max })
+        }
+        "IntList" => items()
+            .iter()
+            .map(|i| i.parse().ok())
+            .collect::<Option<_>>()
+            .map(ParameterOptions::IntList),
+        "FloatList" => items()
+            .iter()
+            .map(|f| f.parse().ok())
+            .collect::<Option<_>>()
+            .map(ParameterOptions::FloatList),
+        "StringList" => Some(ParameterOptions::StringList(items())),
+        "StringItems" => Some(ParameterOptions::StringList(
+            children(element, "Item").filter_map(text).collect(),
+        )),
+        "PolygonOptions" => {
+            let limits = element.get_child("VertexLimits");
+            let limit = |name: &str| {
+                limits
+                    .and_then(|l| l.get_child(name))
+                    .and_then(text)
+                    .and_then(|t| t.parse().ok())
+            };
+            Some(ParameterOptions::Polygon {
+                rectangle_only: child_text("RectangleOnly").map_or(false, |r| r == "true"),
+                min_points: limit("Min"),
+                max_points: limit("Max"),
+            })
+        }
+        _ => None,
+    };
+    decoded.unwrap_or_else(|| ParameterOptions::Other(element.name.clone()))
+}
+
+fn range<V: FromStr>(element: &Element) -> Option<(V, V)> {
+    let bound = |name: &str| element.get_child(name).and_then(text)?.parse().ok();
+    Some((bound("Min")?, bound("Max")?))
+}
+
+async fn get_configs<T: Transport>(
+    analytics_service: &T,
+    operation: &str,
+    tag: &str,
+    configuration_token: &tt::ReferenceToken,
+) -> Result<Vec<Config>, Error> {
+    let request = format!(
+        r#"<tan:{op} xmlns:tan="{}"><tan:ConfigurationToken>{}</tan:ConfigurationToken></tan:{op}>"#,
+        TAN_NS,
+        escape(&configuration_token.0),
+        op = operation
+    );
+
+    let response = parse(&analytics_service.request(&request).await?)?;
+    Ok(children(&response, tag).map(Config::from_element).collect())
+}
+
+async fn send_configs<T: Transport>(
+    analytics_service: &T,
+    operation: &str,
+    tag: &str,
+    configuration_token: &tt::ReferenceToken,
+    configs: &[Config],
+) -> Result<(), Error> {
+    let configs = configs
+        .iter()
+        .map(|config| config.to_xml(tag))
+        .collect::<Result<String, _>>()?;
+    let request = format!(
+        r#"<tan:{op} xmlns:tan="{}"><tan:ConfigurationToken>{}</tan:ConfigurationToken>{}</tan:{op}>"#,
+        TAN_NS,
+        escape(&configuration_token.0),
+        configs,
+        op = operation
+    );
+
+    analytics_service.request(&request).await?;
+    Ok(())
+}
+
+async fn delete_configs<T: Transport>(
+    analytics_service: &T,
+    operation: &str,
+    name_tag: &str,
+    configuration_token: &tt::ReferenceToken,
+    names: &[&str],
+) -> Result<(), Error> {
+    let request = format!(
+        r#"<tan:{op} xmlns:tan="{}"><tan:ConfigurationToken>{}</tan:ConfigurationToken>{}</tan:{op}>"#,
+        TAN_NS,
+        escape(&configuration_token.0),
+        names
+            .iter()
+            .map(|name| format!("<tan:{tag}>{}</tan:{tag}>", escape(name), tag = name_tag))
+            .collect::<String>(),
+        op = operation
+    );
+
+    analytics_service.request(&request).await?;
+    Ok(())
+}
+
+fn children<'a>(element: &'a Element, name: &'a str) -> impl Iterator<Item = &'a Element> + 'a {
+    element.children.iter().filter_map(move |node| match node {
+        XMLNode::Element(child) if child.name == name => Some(child),
+        _ => None,
+    })
+}
+
+fn text(element: &Element) -> Option<String> {
+    element.get_text().map(|t| t.trim().to_string())
+}
+
+fn parse(xml: &str) -> Result<Element, Error> {
+    Element::parse(xml.as_bytes()).map_err(|e| Error::Response(e.to_string()))
+}
+
+fn element_to_string(element: &Element) -> Result<String, Error> {
+    let mut out = vec![];
+    element
+        .write_with_config(
+            &mut out,
+            EmitterConfig::new()
+                .write_document_declaration(false)
+                .perform_indent(false),
+        )
+        .map_err(|e| Error::Response(e.to_string()))?;
+    String::from_utf8(out).map_err(|e| Error::Response(e.to_string()))
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::fake_device::FakeDevice;
+
+    /// Has one vendor rule.
+    fn fake_analytics() -> FakeDevice {
+        FakeDevice::new()
+            .respond(
+                "GetRules",
+                r#"
+                <tan:GetRulesResponse
+                    xmlns:tan="http://www.onvif.org/ver20/analytics/wsdl"
+                    xmlns:tt="http://www.onvif.org/ver10/schema"
+                    xmlns:vnd="http://example.com/analytics">
+                    <tan:Rule Name="LoudNoise" Type="vnd:AudioDetector">
+                        <tt:Parameters>
+                            <tt:SimpleItem Name="Sensitivity" Value="50"/>
+                            <tt:ElementItem Name="Schedule">
+                                <vnd:Schedule Days="Mon Tue"/>
+                            </tt:ElementItem>
+                        </tt:Parameters>
+                    </tan:Rule>
+                </tan:GetRulesResponse>
+                "#,
+            )
+            .respond(
+                "ModifyRules",
+                r#"<tan:ModifyRulesResponse xmlns:tan="http://www.onvif.org/ver20/analytics/wsdl"/>"#,
+            )
+    }
+
+    #[test]
+    fn counter_rules() {
+        let rule = line_crossing_counter(
+            "Entrance",
+            &[(-0.5, 0.0), (0.5, 0.0)],
+            Duration::from_secs(60),
+            &["Human", "Vehicle"],
+        );
+
+        // Rules are sent inside operations declaring the `tan` prefix.
+        crate::utils::xml_eq::assert_xml_eq(
+            &format!(
+                r#"<tan:CreateRules xmlns:tan="{}">{}</tan:CreateRules>"#,
+                TAN_NS,
+                rule.to_xml("tan:Rule").unwrap()
+            ),
+            r#"
+            <tan:CreateRules xmlns:tan="http://www.onvif.org/ver20/analytics/wsdl">
+                <tan:Rule Name="Entrance" Type="tt:LineCrossingCounter"
+                    xmlns:tt="http://www.onvif.org/ver10/schema">
+                    <tt:Parameters>
+                        <tt:SimpleItem Name="ClassFilter" Value="Human Vehicle"/>
+                        <tt:SimpleItem Name="ReportTimeInterval" Value="PT60S"/>
+                        <tt:ElementItem Name="Segments">
+                            <tt:Polyline>
+                                <tt:Point x="-0.5" y="0"/>
+                                <tt:Point x="0.5" y="0"/>
+                            </tt:Polyline>
+                        </tt:ElementItem>
+                    </tt:Parameters>
+                </tan:Rule>
+            </tan:CreateRules>
+            "#,
+        );
+    }
+
+    #[test]
+    fn detector_rules() {
+        let field = [(-0.5, 0.5), (0.5, 0.5), (0.5, -0.5), (-0.5, -0.5)];
+
+        let line = LineDetector::new("Fence", &[(-0.8, -0.2), (0.8, -0.2)])
+            .direction(Direction::Left)
+            .build();
+        assert_eq!(line.config_type, "tt:LineDetector");
+        assert_eq!(line.simple_items["Direction"], "Left");
+        assert_eq!(line.element_items["Segments"].name, "Polyline");
+        assert_eq!(line.element_items["Segments"].children.len(), 2);
+
+        let inside = FieldDetector::new("Yard", &field).build();
+        assert_eq!(inside.config_type, "tt:FieldDetector");
+        assert!(inside.simple_items.is_empty());
+        assert_eq!(inside.element_items["Field"].name, "Polygon");
+        assert_eq!(inside.element_items["Field"].children.len(), 4);
+
+        let loitering = LoiteringDetector::new("Door", &field)
+            .time_threshold(Duration::from_secs(30))
+            .build();
+        assert_eq!(loitering.config_type, "tt:LoiteringDetector");
+        assert_eq!(loitering.simple_items["TimeThreshold"], "PT30S");
+        assert_eq!(loitering.element_items["Field"], polygon(&field));
+    }
+
+    /// Has a line detector and a fixed motion region detector.
+    fn fake_supported_rules() -> FakeDevice {
+        FakeDevice::new()
+            .respond(
+                "GetSupportedRules",
+                r#"
+                <tan:GetSupportedRulesResponse
+                    xmlns:tan="http://www.onvif.org/ver20/analytics/wsdl"
+                    xmlns:tt="http://www.onvif.org/ver10/schema">
+                    <tan:SupportedRules>
+                        <tt:RuleDescription Name="tt:LineDetector" maxInstances="4">
+                            <tt:Parameters>
+                                <tt:SimpleItemDescription Name="Direction" Type="tt:Direction"/>
+                                <tt:ElementItemDescription Name="Segments" Type="tt:Polyline"/>
+                            </tt:Parameters>
+                        </tt:RuleDescription>
+                        <tt:RuleDescription Name="tt:MotionRegionDetector" fixed="true">
+                            <tt:Parameters>
+                                <tt:SimpleItemDescription Name="Sensitivity" Type="xs:integer"/>
+                                <tt:ElementItemDescription Name="MotionRegion" Type="tt:Polygon"/>
+                            </tt:Parameters>
+                        </tt:RuleDescription>
+                    </tan:SupportedRules>
+                </tan:GetSupportedRulesResponse>
+                "#,
+            )
+            .respond(
+                "GetRuleOptions",
+                r#"
+                <tan:GetRuleOptionsResponse
+                    xmlns:tan="http://www.onvif.org/ver20/analytics/wsdl"
+                    xmlns:tt="http://www.onvif.org/ver10/schema">
+                    <tan:RuleOptions Name="Direction" RuleType="tt:LineDetector">
+                        <tt:StringList>Left Right Any</tt:StringList>
+                    </tan:RuleOptions>
+                    <tan:RuleOptions Name="Sensitivity" RuleType="tt:MotionRegionDetector">
+                        <tt:IntRange><tt:Min>0</tt:Min><tt:Max>100</tt:Max></tt:IntRange>
+                    </tan:RuleOptions>
+                    <tan:RuleOptions Name="MotionRegion">
+                        <tt:PolygonOptions>
+                            <tt:RectangleOnly>true</tt:RectangleOnly>
+                            <tt:VertexLimits><tt:Min>4</tt:Min><tt:Max>4</tt:Max></tt:VertexLimits>
+                        </tt:PolygonOptions>
+                    </tan:RuleOptions>
+                    <tan:RuleOptions Name="Segments" RuleType="tt:LineDetector">
+                        <vnd:SegmentOptions xmlns:vnd="http://example.com/analytics"/>
+                    </tan:RuleOptions>
+                </tan:GetRuleOptionsResponse>
+                "#,
+            )
+    }
+
+    #[tokio::test]
+    async fn describes_supported_rules() {
+        let rules = supported_rules(
+            &fake_supported_rules(),
+            &tt::ReferenceToken("analytics".to_string()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(rules.len(), 2);
+
+        let line = &rules[0];
+        assert_eq!(line.rule_type, "tt:LineDetector");
+        assert!(!line.fixed);
+        assert_eq!(line.max_instances, Some(4));
+        assert_eq!(
+            line.parameters,
+            vec![
+                ParameterDescription {
+                    name: "Direction".to_string(),
+                    kind: ParameterKind::Simple,
+                    value_type: "tt:Direction".to_string(),
+                    options: Some(ParameterOptions::StringList(vec![
+                        "Left".to_string(),
+                        "Right".to_string(),
+                        "Any".to_string()
+                    ])),
+                },
+                ParameterDescription {
+                    name: "Segments".to_string(),
+                    kind: ParameterKind::Element,
+                    value_type: "tt:Polyline".to_string(),
+                    options: Some(ParameterOptions::Other("SegmentOptions".to_string())),
+                },
+            ]
+        );
+
+        let motion = &rules[1];
+        assert!(motion.fixed);
+        assert_eq!(motion.max_instances, None);
+        assert_eq!(
+            motion.parameters[0].options,
+            Some(ParameterOptions::IntRange { min: 0, max: 100 })
+        );
+        assert_eq!(
+            motion.parameters[1].options,
+            Some(ParameterOptions::Polygon {
+                rectangle_only: true,
+                min_points: Some(4),
+                max_points: Some(4),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn sets_sensitivity_keeping_element_items() {
+        let analytics = fake_analytics();
+        let token = tt::ReferenceToken("analytics".to_string());
+
+        let rules = rules(&analytics, &token).await.unwrap();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].config_type, "vnd:AudioDetector");
+        assert_eq!(rules[0].simple_items[SENSITIVITY], "50");
+        assert_eq!(rules[0].element_items["Schedule"].name, "Schedule");
+
+        set_rule_sensitivity(&analytics, &token, "LoudNoise", 80)
+            .await
+            .unwrap();
+        assert!(matches!(
+            set_rule_sensitivity(&analytics, &token, "Glass", 80).await,
+            Err(Error::UnknownRule(_))
+        ));
+
+        let requests = analytics.requests("ModifyRules");
+        assert_eq!(requests.len(), 1);
+        crate::utils::xml_eq::assert_xml_eq(
+            &requests[0],
+            r#"
+            <tan:ModifyRules xmlns:tan="http://www.onvif.org/ver20/analytics/wsdl">
+                <tan:ConfigurationToken>analytics</tan:ConfigurationToken>
+                <tan:Rule Name="LoudNoise" Type="vnd:AudioDetector"
+                    xmlns:tt="http://www.onvif.org/ver10/schema"
+                    xmlns:vnd="http://example.com/analytics">
+                    <tt:Parameters>
+                        <tt:SimpleItem Name="Sensitivity" Value="80"/>
+                        <tt:ElementItem Name="Schedule">
+                            <vnd:Schedule Days="Mon Tue"/>
+                        </tt:ElementItem>
+                    </tt:Parameters>
+                </tan:Rule>
+            </tan:ModifyRules>
+            "#,
+        );
+    }
+}
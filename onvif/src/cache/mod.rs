@@ -0,0 +1,224 @@
+//! Memoization of option requests, enabled with the `cache` feature. The options of video
+//! encoders, imaging settings or PTZ configurations don't change while a camera runs, yet helpers
+//! and interactive UIs request them over and over. [`OptionsCache`] is a
+//! [layer](crate::soap::layer) which answers repeated requests from memory, and makes concurrent
+//! identical requests share one round trip.
+//!
+//! ```no_run
+//! # async fn example(
+//! #     media_service: onvif::soap::client::Client,
+//! #     ptz_service: onvif::soap::client::Client,
+//! # ) {
+//! use onvif::{cache::OptionsCache, soap::layer::TransportExt};
+//!
+//! // One cache per camera, shared by the clients of its services.
+//! let cache = OptionsCache::new().operation("GetNodes");
+//! let media_service = media_service.with(cache.clone());
+//! let ptz_service = ptz_service.with(cache.clone());
+//!
+//! // After the configuration "main" was changed through another client:
+//! cache.invalidate("GetVideoEncoderConfigurationOptions", Some("main"));
+//! # }
+//! ```
+//!
+//! Responses are cached by the whole request, so requests with different parameters are cached
+//! apart, and only successful responses are cached. Requests of operations which don't start
+//! with `Get`, such as `SetVideoEncoderConfiguration`, invalidate the entries sharing a token with
+//! them when they go through the cache.
+
+use crate::soap::layer::Layer;
+use async_trait::async_trait;
+use schema::transport::{Error, Transport};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+use tokio::sync::OnceCell;
+use xmltree::{Element, XMLNode};
+
+/// Caches the responses of operations whose name ends with `Options`, and of operations added
+/// with [`OptionsCache::operation`]. Clones share their entries.
+#[derive(Clone, Debug, Default)]
+pub struct OptionsCache {
+    operations: Vec<String>,
+    entries: Arc<Mutex<HashMap<String, Entry>>>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    operation: String,
+    tokens: Vec<String>,
+    response: Arc<OnceCell<String>>,
+}
+
+impl OptionsCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also caches `operation`, e.g. `GetNodes` of the PTZ service, whose response should only
+    /// change when the device is reconfigured.
+    pub fn operation(mut self, operation: &str) -> Self {
+        self.operations.push(operation.to_string());
+        self
+    }
+
+    /// Forgets the responses of `operation`, only those of requests with `token` among their
+    /// parameters if given.
+    pub fn invalidate(&self, operation: &str, token: Option<&str>) {
+        self.entries.lock().unwrap().retain(|_, entry| {
+            entry.operation != operation
+                || token.map_or(false, |token| !entry.tokens.iter().any(|t| t == token))
+        });
+    }
+
+    /// Forgets all responses, e.g. after a firmware upgrade.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    fn caches(&self, operation: &str) -> bool {
+        operation.ends_with("Options") || self.operations.iter().any(|o| o == operation)
+    }
+}
+
+impl<T: Transport + Sync> Layer<T> for OptionsCache {
+    type Transport = Cached<T>;
+
+    fn layer(&self, inner: T) -> Cached<T> {
+        Cached {
+            inner,
+            cache: self.clone(),
+        }
+    }
+}
+
+pub struct Cached<T> {
+    inner: T,
+    cache: OptionsCache,
+}
+
+#[async_trait]
+impl<T: Transport + Sync> Transport for Cached<T> {
+    async fn request(&self, message: &str) -> Result<String, Error> {
+        let (operation, tokens) = match parameters(message) {
+            Some(parameters) => parameters,
+            None => return self.inner.request(message).await,
+        };
+
+        if !self.cache.caches(&operation) {
+            let result = self.inner.request(message).await;
+            if !operation.starts_with("Get") && !tokens.is_empty() {
+                self.cache
+                    .entries
+                    .lock()
+                    .unwrap()
+                    .retain(|_, entry| !entry.tokens.iter().any(|t| tokens.contains(t)));
+            }
+            return result;
+        }
+
+        let response = self
+            .cache
+            .entries
+            .lock()
+            .unwrap()
+            .entry(message.to_string())
+            .or_insert_with(|| Entry {
+                operation,
+                tokens,
+                response: Default::default(),
+            })
+            .response
+            .clone();
+        response
+            .get_or_try_init(|| self.inner.request(message))
+            .await
+            .map(Clone::clone)
+    }
+}
+
+/// The operation of a request, and the tokens among its parameters: elements whose name ends
+/// with `Token`, and `token` attributes of configurations.
+fn parameters(message: &str) -> Option<(String, Vec<String>)> {
+    let root = Element::parse(message.as_bytes()).ok()?;
+    let mut tokens = vec![];
+    for child in root.children.iter().filter_map(XMLNode::as_element) {
+        if child.name.ends_with("Token") {
+            tokens.extend(child.get_text().map(|token| token.trim().to_string()));
+        }
+        tokens.extend(child.attributes.get("token").cloned());
+    }
+    Some((root.name, tokens))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soap::layer::TransportExt;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// Answers every request with the number of requests so far.
+    #[derive(Default)]
+    struct Counter {
+        requests: AtomicU32,
+    }
+
+    #[async_trait]
+    impl Transport for Counter {
+        async fn request(&self, _message: &str) -> Result<String, Error> {
+            tokio::task::yield_now().await;
+            let requests = self.requests.fetch_add(1, Ordering::SeqCst) + 1;
+            Ok(format!("<Response>{}</Response>", requests))
+        }
+    }
+
+    fn options(token: &str) -> String {
+        format!(
+            r#"<trt:GetVideoEncoderConfigurationOptions
+                xmlns:trt="http://www.onvif.org/ver10/media/wsdl">
+                <trt:ConfigurationToken>{}</trt:ConfigurationToken>
+            </trt:GetVideoEncoderConfigurationOptions>"#,
+            token
+        )
+    }
+
+    #[tokio::test]
+    async fn caches_options() {
+        let cache = OptionsCache::new();
+        let media_service = Counter::default().with(cache.clone());
+
+        let (first, second) = tokio::join!(
+            media_service.request(&options("main")),
+            media_service.request(&options("main"))
+        );
+        assert_eq!(first.unwrap(), "<Response>1</Response>");
+        assert_eq!(second.unwrap(), "<Response>1</Response>");
+        let sub = media_service.request(&options("sub")).await.unwrap();
+        assert_eq!(sub, "<Response>2</Response>");
+
+        let profiles = r#"<trt:GetProfiles xmlns:trt="http://www.onvif.org/ver10/media/wsdl"/>"#;
+        media_service.request(profiles).await.unwrap();
+        media_service.request(profiles).await.unwrap();
+        assert_eq!(media_service.inner.requests.load(Ordering::SeqCst), 4);
+
+        cache.invalidate("GetVideoEncoderConfigurationOptions", Some("main"));
+        let main = media_service.request(&options("main")).await.unwrap();
+        assert_eq!(main, "<Response>5</Response>");
+        let sub = media_service.request(&options("sub")).await.unwrap();
+        assert_eq!(sub, "<Response>2</Response>");
+
+        media_service
+            .request(
+                r#"<trt:SetVideoEncoderConfiguration
+                    xmlns:trt="http://www.onvif.org/ver10/media/wsdl">
+                    <trt:Configuration token="sub"/>
+                    <trt:ForcePersistence>true</trt:ForcePersistence>
+                </trt:SetVideoEncoderConfiguration>"#,
+            )
+            .await
+            .unwrap();
+        let sub = media_service.request(&options("sub")).await.unwrap();
+        assert_eq!(sub, "<Response>7</Response>");
+    }
+}
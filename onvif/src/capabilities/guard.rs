@@ -0,0 +1,263 @@
+//! Fails requests of operations a device doesn't support before they are sent, e.g. for generic
+//! UIs that enable controls per device and would otherwise make it fault.
+//!
+//! ```no_run
+//! # async fn example(
+//! #     device_service: onvif::soap::client::Client,
+//! #     ptz_service: onvif::soap::client::Client,
+//! # ) {
+//! use onvif::{
+//!     capabilities::{guard::CapabilityGuard, CapabilityCache},
+//!     soap::{client::ClientBuilder, layer::TransportExt},
+//! };
+//! use std::time::Duration;
+//!
+//! let credentials = device_service.credentials();
+//! let capabilities = CapabilityCache::new(Duration::from_secs(3600))
+//!     .get(&device_service, |address| {
+//!         ClientBuilder::new(address)
+//!             .credentials(credentials.clone())
+//!             .build()
+//!     })
+//!     .await
+//!     .unwrap();
+//! let ptz_service = ptz_service.with(CapabilityGuard::new(
+//!     "http://www.onvif.org/ver20/ptz/wsdl",
+//!     capabilities,
+//! ));
+//! # }
+//! ```
+//!
+//! Operations are refused with [`Error::Unsupported`] if their service isn't listed by
+//! `GetServices`, or if a capability flag covering them is false. Flags a device omits, and
+//! services whose capabilities failed to answer, don't refuse anything, so that the guard never
+//! stops requests the device might support.
+
+use super::{
+    DeviceCapabilities, ANALYTICS_NS, DEVICEIO_NS, DEVICE_NS, EVENTS_NS, IMAGING_NS, MEDIA2_NS,
+    MEDIA_NS, PTZ_NS,
+};
+use crate::soap::layer::Layer;
+use async_trait::async_trait;
+use schema::transport::{Error, Transport};
+use std::sync::Arc;
+use xmltree::Element;
+
+/// Refuses the requests of the service with `namespace` that [`supports`] rules out.
+///
+/// The capabilities are those at the time the guard is created; create another one once the
+/// [`CapabilityCache`](super::CapabilityCache) fetched new ones. Clones share them.
+#[derive(Clone, Debug)]
+pub struct CapabilityGuard {
+    namespace: String,
+    capabilities: Arc<DeviceCapabilities>,
+}
+
+impl CapabilityGuard {
+    pub fn new(namespace: &str, capabilities: Arc<DeviceCapabilities>) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+            capabilities,
+        }
+    }
+}
+
+impl<T: Transport + Sync> Layer<T> for CapabilityGuard {
+    type Transport = Guarded<T>;
+
+    fn layer(&self, inner: T) -> Guarded<T> {
+        Guarded {
+            inner,
+            guard: self.clone(),
+        }
+    }
+}
+
+pub struct Guarded<T> {
+    inner: T,
+    guard: CapabilityGuard,
+}
+
+#[async_trait]
+impl<T: Transport + Sync> Transport for Guarded<T> {
+    async fn request(&self, message: &str) -> Result<String, Error> {
+        if let Ok(request) = Element::parse(message.as_bytes()) {
+            let (namespace, capabilities) = (&self.guard.namespace, &self.guard.capabilities);
+            if !supports(capabilities, namespace, &request.name) {
+                return Err(Error::Unsupported {
+                    service: service_name(namespace).to_string(),
+                    operation: request.name,
+                });
+            }
+        }
+        self.inner.request(message).await
+    }
+}
+
+/// Whether the device may support `operation` of the service with `namespace`, according to its
+/// capabilities.
+pub fn supports(capabilities: &DeviceCapabilities, namespace: &str, operation: &str) -> bool {
+    if namespace != DEVICE_NS && !capabilities.services.contains_key(namespace) {
+        return false;
+    }
+    flag(capabilities, namespace, operation) != Some(false)
+}
+
+/// The capability flag covering `operation`, if there is one and the device reported it.
+fn flag(capabilities: &DeviceCapabilities, namespace: &str, operation: &str) -> Option<bool> {
+    let report = &capabilities.report;
+    match namespace {
+        DEVICE_NS => {
+            let device = report.devicemgmt.as_ref()?;
+            let (network, security, system) = (&device.network, &device.security, &device.system);
+            match operation {
+                "GetSystemBackup" | "RestoreSystem" => system.system_backup,
+                "StartSystemRestore" => system.http_system_backup,
+                "GetSystemLog" => system.system_logging,
+                "UpgradeSystemFirmware" => system.firmware_upgrade,
+                "StartFirmwareUpgrade" => system.http_firmware_upgrade,
+                "GetStorageConfigurations"
+                | "GetStorageConfiguration"
+                | "CreateStorageConfiguration"
+                | "SetStorageConfiguration"
+                | "DeleteStorageConfiguration" => system.storage_configuration,
+                "GetZeroConfiguration" | "SetZeroConfiguration" => network.zero_configuration,
+                "GetIPAddressFilter"
+                | "SetIPAddressFilter"
+                | "AddIPAddressFilter"
+                | "RemoveIPAddressFilter" => network.ip_filter,
+                "GetDynamicDNS" | "SetDynamicDNS" => network.dyn_dns,
+                "GetDot11Capabilities" | "GetDot11Status" | "ScanAvailableDot11Networks" => {
+                    network.dot_11_configuration
+                }
+                "CreateCertificate" => security.onboard_key_generation,
+                "GetAccessPolicy" | "SetAccessPolicy" => security.access_policy_config,
+                "GetRemoteUser" | "SetRemoteUser" => security.remote_user_handling,
+                _ => None,
+            }
+        }
+        EVENTS_NS => {
+            let event = report.event.as_ref()?;
+            match operation {
+                "CreatePullPointSubscription" => event.ws_pull_point_support,
+                "Seek" => event.persistent_notification_storage,
+                "PauseSubscription" | "ResumeSubscription" => {
+                    event.ws_pausable_subscription_manager_interface_support
+                }
+                _ => None,
+            }
+        }
+        MEDIA_NS => {
+            let media = report.media.as_ref()?;
+            match operation {
+                "GetSnapshotUri" => media.snapshot_uri,
+                "GetVideoSourceModes" | "SetVideoSourceMode" => media.video_source_mode,
+                "GetOSDs" | "GetOSD" | "GetOSDOptions" | "CreateOSD" | "SetOSD" | "DeleteOSD" => {
+                    media.osd
+                }
+                _ => None,
+            }
+        }
+        MEDIA2_NS => {
+            let media2 = report.media2.as_ref()?;
+            match operation {
+                "GetSnapshotUri" => media2.snapshot_uri,
+                "GetVideoSourceModes" | "SetVideoSourceMode" => media2.video_source_mode,
+                "GetOSDs" | "GetOSDOptions" | "CreateOSD" | "SetOSD" | "DeleteOSD" => media2.osd,
+                "GetMasks" | "GetMaskOptions" | "CreateMask" | "SetMask" | "DeleteMask" => {
+                    media2.mask
+                }
+                _ => None,
+            }
+        }
+        IMAGING_NS => match operation {
+            "GetPresets" | "GetCurrentPreset" | "SetCurrentPreset" => {
+                report.imaging.as_ref()?.presets
+            }
+            _ => None,
+        },
+        PTZ_NS => match operation {
+            "GetCompatibleConfigurations" => report.ptz.as_ref()?.get_compatible_configurations,
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn service_name(namespace: &str) -> &str {
+    match namespace {
+        DEVICE_NS => "device",
+        EVENTS_NS => "event",
+        DEVICEIO_NS => "deviceio",
+        MEDIA_NS => "media",
+        MEDIA2_NS => "media2",
+        IMAGING_NS => "imaging",
+        PTZ_NS => "ptz",
+        ANALYTICS_NS => "analytics",
+        namespace => namespace,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        capabilities::CapabilitiesReport, soap::layer::TransportExt, utils::fake_device::FakeDevice,
+    };
+    use schema::ptz;
+    use url::Url;
+
+    #[tokio::test]
+    async fn refuses_unsupported_operations() {
+        let capabilities = Arc::new(DeviceCapabilities {
+            firmware_version: "1.0".to_string(),
+            services: vec![(
+                PTZ_NS.to_string(),
+                Url::parse("http://192.168.0.2/onvif/ptz").unwrap(),
+            )]
+            .into_iter()
+            .collect(),
+            report: CapabilitiesReport {
+                ptz: Some(ptz::Capabilities {
+                    get_compatible_configurations: Some(false),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        });
+        let request = |operation: &str| format!(r#"<tptz:{} xmlns:tptz="{}"/>"#, operation, PTZ_NS);
+
+        let ptz_service = FakeDevice::new()
+            .respond("GetNodes", "<tptz:GetNodesResponse/>")
+            .respond(
+                "GetCompatibleConfigurations",
+                "<tptz:GetCompatibleConfigurationsResponse/>",
+            )
+            .with(CapabilityGuard::new(PTZ_NS, capabilities.clone()));
+        ptz_service.request(&request("GetNodes")).await.unwrap();
+        match ptz_service
+            .request(&request("GetCompatibleConfigurations"))
+            .await
+        {
+            Err(Error::Unsupported { service, operation }) => {
+                assert_eq!(service, "ptz");
+                assert_eq!(operation, "GetCompatibleConfigurations");
+            }
+            result => panic!("{:?}", result),
+        }
+        assert_eq!(ptz_service.inner.operations(), vec!["GetNodes"]);
+
+        // Not listed by `GetServices`.
+        let media_service =
+            FakeDevice::new().with(CapabilityGuard::new(MEDIA_NS, capabilities.clone()));
+        assert!(matches!(
+            media_service
+                .request("<trt:GetProfiles xmlns:trt=\"http://www.onvif.org/ver10/media/wsdl\"/>")
+                .await,
+            Err(Error::Unsupported { .. })
+        ));
+
+        // The device service is always there, and unknown capabilities don't refuse anything.
+        assert!(supports(&capabilities, DEVICE_NS, "GetSystemBackup"));
+    }
+}
@@ -0,0 +1,413 @@
+//! `GetServiceCapabilities` of all services of a device in one report, e.g. for inventories,
+//! and a cache of it for helpers that consult capabilities on every call. A
+//! [guard](guard::CapabilityGuard) fails requests of operations the cached capabilities rule out.
+
+pub mod guard;
+
+use schema::{
+    analytics, deviceio, devicemgmt, event, imaging,
+    json::ToJson,
+    media, media2, ptz,
+    transport::{Error as TransportError, Transport},
+};
+use serde::{ser::SerializeMap, Serialize, Serializer};
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use thiserror::Error;
+use url::Url;
+
+const DEVICE_NS: &str = "http://www.onvif.org/ver10/device/wsdl";
+const EVENTS_NS: &str = "http://www.onvif.org/ver10/events/wsdl";
+const DEVICEIO_NS: &str = "http://www.onvif.org/ver10/deviceIO/wsdl";
+const MEDIA_NS: &str = "http://www.onvif.org/ver10/media/wsdl";
+const MEDIA2_NS: &str = "http://www.onvif.org/ver20/media/wsdl";
+const IMAGING_NS: &str = "http://www.onvif.org/ver20/imaging/wsdl";
+const PTZ_NS: &str = "http://www.onvif.org/ver20/ptz/wsdl";
+const ANALYTICS_NS: &str = "http://www.onvif.org/ver20/analytics/wsdl";
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Transport error: {0}")]
+    Transport(#[from] TransportError),
+}
+
+/// Transports of the services besides the device service. Services the device doesn't offer are
+/// `None`.
+pub struct Services<'a, T> {
+    pub event: Option<&'a T>,
+    pub deviceio: Option<&'a T>,
+    pub media: Option<&'a T>,
+    pub media2: Option<&'a T>,
+    pub imaging: Option<&'a T>,
+    pub ptz: Option<&'a T>,
+    pub analytics: Option<&'a T>,
+}
+
+// Not derived, which would require `T: Default`.
+impl<'a, T> Default for Services<'a, T> {
+    fn default() -> Self {
+        Self {
+            event: None,
+            deviceio: None,
+            media: None,
+            media2: None,
+            imaging: None,
+            ptz: None,
+            analytics: None,
+        }
+    }
+}
+
+/// The capabilities of every service, `None` for services that are absent or failed to answer.
+///
+/// Serializes to an object with a key per service, rendering the capabilities as described in
+/// [`schema::json`], and an `errors` key.
+#[derive(Debug, Default, PartialEq)]
+pub struct CapabilitiesReport {
+    pub devicemgmt: Option<devicemgmt::DeviceServiceCapabilities>,
+    pub event: Option<event::Capabilities>,
+    pub deviceio: Option<deviceio::Capabilities>,
+    pub media: Option<media::Capabilities>,
+    pub media2: Option<media2::Capabilities2>,
+    pub imaging: Option<imaging::Capabilities>,
+    pub ptz: Option<ptz::Capabilities>,
+    pub analytics: Option<analytics::Capabilities>,
+
+    /// Errors of the services that failed to answer, by service name.
+    pub errors: BTreeMap<String, String>,
+}
+
+macro_rules! fetch_capabilities {
+    ($report:ident, $module:ident, $service:expr) => {
+        if let Some(service) = $service {
+            match $module::get_service_capabilities(service, &Default::default()).await {
+                Ok(response) => $report.$module = Some(response.capabilities),
+                Err(error) => {
+                    $report
+                        .errors
+                        .insert(stringify!($module).to_string(), error.to_string());
+                }
+            }
+        }
+    };
+}
+
+/// Requests the capabilities of the device service and the given services. Failures are
+/// recorded in [`CapabilitiesReport::errors`] rather than failing the whole report.
+pub async fn capabilities_report<T: Transport>(
+    device_service: &T,
+    services: &Services<'_, T>,
+) -> CapabilitiesReport {
+    let mut report = CapabilitiesReport::default();
+    fetch_capabilities!(report, devicemgmt, Some(device_service));
+    fetch_capabilities!(report, event, services.event);
+    fetch_capabilities!(report, deviceio, services.deviceio);
+    fetch_capabilities!(report, media, services.media);
+    fetch_capabilities!(report, media2, services.media2);
+    fetch_capabilities!(report, imaging, services.imaging);
+    fetch_capabilities!(report, ptz, services.ptz);
+    fetch_capabilities!(report, analytics, services.analytics);
+    report
+}
+
+impl Serialize for CapabilitiesReport {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // The generated types only implement XML serialization.
+        fn json<C: ToJson, E: serde::ser::Error>(
+            capabilities: &Option<C>,
+        ) -> Result<serde_json::Value, E> {
+            match capabilities {
+                Some(capabilities) => capabilities.to_json().map_err(E::custom),
+                None => Ok(serde_json::Value::Null),
+            }
+        }
+
+        let mut map = serializer.serialize_map(Some(9))?;
+        map.serialize_entry("devicemgmt", &json(&self.devicemgmt)?)?;
+        map.serialize_entry("event", &json(&self.event)?)?;
+        map.serialize_entry("deviceio", &json(&self.deviceio)?)?;
+        map.serialize_entry("media", &json(&self.media)?)?;
+        map.serialize_entry("media2", &json(&self.media2)?)?;
+        map.serialize_entry("imaging", &json(&self.imaging)?)?;
+        map.serialize_entry("ptz", &json(&self.ptz)?)?;
+        map.serialize_entry("analytics", &json(&self.analytics)?)?;
+        map.serialize_entry("errors", &self.errors)?;
+        map.end()
+    }
+}
+
+/// What [`CapabilityCache`] keeps about a device.
+#[derive(Debug)]
+pub struct DeviceCapabilities {
+    /// Firmware version at the time of fetching, as reported by `GetDeviceInformation`.
+    pub firmware_version: String,
+
+    /// Service addresses by namespace, as returned by `GetServices`.
+    pub services: BTreeMap<String, Url>,
+
+    pub report: CapabilitiesReport,
+}
+
+/// Caches [`DeviceCapabilities`] of a device.
+///
+/// Once `ttl` passed, the next [`CapabilityCache::get`] requests `GetDeviceInformation` and only
+/// fetches everything again if the firmware version changed, e.g. after an upgrade. Otherwise the
+/// cached capabilities are trusted for another `ttl`.
+#[derive(Debug)]
+pub struct CapabilityCache {
+    ttl: Duration,
+    // Not held across requests, so concurrent callers may both fetch on a miss.
+    cached: Mutex<Option<(Arc<DeviceCapabilities>, Instant)>>,
+}
+
+impl CapabilityCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// Returns the cached capabilities, fetching them if there are none or the firmware changed.
+    ///
+    /// `connect` creates the transport for a service address returned by `GetServices`.
+    pub async fn get<T, F>(
+        &self,
+        device_service: &T,
+        connect: F,
+    ) -> Result<Arc<DeviceCapabilities>, Error>
+    where
+        T: Transport,
+        F: Fn(&Url) -> T,
+    {
+        let cached = self.cached.lock().unwrap().clone();
+        let (capabilities, checked) = match cached {
+            Some(cached) => cached,
+            None => return self.refresh(device_service, connect).await,
+        };
+        if checked.elapsed() < self.ttl {
+            return Ok(capabilities);
+        }
+
+        let firmware_version = firmware_version(device_service).await?;
+        if firmware_version != capabilities.firmware_version {
+            tracing::debug!(
+                "firmware changed from {} to {}, fetching capabilities",
+                capabilities.firmware_version,
+                firmware_version
+            );
+            return self.refresh(device_service, connect).await;
+        }
+
+        *self.cached.lock().unwrap() = Some((capabilities.clone(), Instant::now()));
+        Ok(capabilities)
+    }
+
+    /// Fetches the capabilities regardless of the cache, e.g. after changing the configuration
+    /// of the device.
+    pub async fn refresh<T, F>(
+        &self,
+        device_service: &T,
+        connect: F,
+    ) -> Result<Arc<DeviceCapabilities>, Error>
+    where
+        T: Transport,
+        F: Fn(&Url) -> T,
+    {
+        let firmware_version = firmware_version(device_service).await?;
+        let services: BTreeMap<String, Url> =
+            devicemgmt::get_services(device_service, &Default::default())
+                .await?
+                .service
+                .into_iter()
+                .filter_map(|service| match Url::parse(&service.x_addr) {
+                    Ok(address) => Some((service.namespace, address)),
+                    Err(e) => {
+                        tracing::debug!("invalid address of {}: {}", service.namespace, e);
+                        None
+                    }
+                })
+                .collect();
+
+        let transports: BTreeMap<&str, T> = services
+            .iter()
+            .map(|(namespace, address)| (namespace.as_str(), connect(address)))
+            .collect();
+        let report = capabilities_report(
+            device_service,
+            &Services {
+                event: transports.get(EVENTS_NS),
+                deviceio: transports.get(DEVICEIO_NS),
+                media: transports.get(MEDIA_NS),
+                media2: transports.get(MEDIA2_NS),
+                imaging: transports.get(IMAGING_NS),
+                ptz: transports.get(PTZ_NS),
+                analytics: transports.get(ANALYTICS_NS),
+            },
+        )
+        .await;
+
+        let capabilities = Arc::new(DeviceCapabilities {
+            firmware_version,
+            services,
+            report,
+        });
+        *self.cached.lock().unwrap() = Some((capabilities.clone(), Instant::now()));
+        Ok(capabilities)
+    }
+
+    /// Forgets the cached capabilities, so that the next [`CapabilityCache::get`] fetches them.
+    pub fn invalidate(&self) {
+        *self.cached.lock().unwrap() = None;
+    }
+}
+
+async fn firmware_version<T: Transport>(device_service: &T) -> Result<String, Error> {
+    Ok(
+        devicemgmt::get_device_information(device_service, &Default::default())
+            .await?
+            .firmware_version,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::fake_device::FakeDevice;
+    use async_trait::async_trait;
+
+    /// A PTZ service answering `GetServiceCapabilities` only.
+    fn ptz_service() -> FakeDevice {
+        FakeDevice::new().respond(
+            "GetServiceCapabilities",
+            r#"
+            <tptz:GetServiceCapabilitiesResponse
+                xmlns:tptz="http://www.onvif.org/ver20/ptz/wsdl">
+                <tptz:Capabilities MoveStatus="true" StatusPosition="true"/>
+            </tptz:GetServiceCapabilitiesResponse>
+            "#,
+        )
+    }
+
+    #[tokio::test]
+    async fn report() {
+        let report = capabilities_report(
+            &FakeDevice::new(),
+            &Services {
+                ptz: Some(&ptz_service()),
+                ..Default::default()
+            },
+        )
+        .await;
+
+        assert!(report.devicemgmt.is_none());
+        assert!(report.media.is_none());
+        assert_eq!(report.ptz.as_ref().unwrap().move_status, Some(true));
+        assert_eq!(report.errors.keys().collect::<Vec<_>>(), vec!["devicemgmt"]);
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["ptz"]["MoveStatus"], "true");
+        assert!(json["media"].is_null());
+        assert_eq!(
+            json["errors"]["devicemgmt"],
+            "Protocol error: ActionNotSupported"
+        );
+    }
+
+    /// A device with a PTZ service whose firmware version can be changed, counting the requests
+    /// of each operation.
+    #[derive(Clone, Default)]
+    struct FakeCamera {
+        firmware_version: Arc<Mutex<String>>,
+        requests: Arc<Mutex<BTreeMap<String, usize>>>,
+    }
+
+    impl FakeCamera {
+        fn count(&self, operation: &str) -> usize {
+            self.requests
+                .lock()
+                .unwrap()
+                .get(operation)
+                .copied()
+                .unwrap_or_default()
+        }
+    }
+
+    #[async_trait]
+    impl Transport for FakeCamera {
+        async fn request(&self, message: &str) -> Result<String, TransportError> {
+            let operation = [
+                "GetDeviceInformation",
+                "GetServices",
+                "GetServiceCapabilities",
+            ]
+            .iter()
+            .find(|operation| message.contains(&format!(":{}", operation)))
+            .ok_or_else(|| TransportError::Protocol("ActionNotSupported".to_string()))?;
+            *self
+                .requests
+                .lock()
+                .unwrap()
+                .entry(operation.to_string())
+                .or_default() += 1;
+
+            match *operation {
+                "GetDeviceInformation" => Ok(format!(
+                    r#"<tds:GetDeviceInformationResponse
+                        xmlns:tds="http://www.onvif.org/ver10/device/wsdl">
+                        <tds:FirmwareVersion>{}</tds:FirmwareVersion>
+                    </tds:GetDeviceInformationResponse>"#,
+                    self.firmware_version.lock().unwrap()
+                )),
+                "GetServices" => Ok(r#"
+                    <tds:GetServicesResponse xmlns:tds="http://www.onvif.org/ver10/device/wsdl">
+                        <tds:Service>
+                            <tds:Namespace>http://www.onvif.org/ver20/ptz/wsdl</tds:Namespace>
+                            <tds:XAddr>http://192.168.0.2/onvif/ptz</tds:XAddr>
+                        </tds:Service>
+                    </tds:GetServicesResponse>
+                    "#
+                .to_string()),
+                _ if message.contains("tptz:") => ptz_service().request(message).await,
+                _ => Err(TransportError::Protocol("ActionNotSupported".to_string())),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn caches_until_firmware_changes() {
+        let camera = FakeCamera::default();
+        *camera.firmware_version.lock().unwrap() = "1.0".to_string();
+        // Checks the firmware on every call.
+        let cache = CapabilityCache::new(Duration::from_secs(0));
+        let connect = |address: &Url| {
+            assert_eq!(address.path(), "/onvif/ptz");
+            camera.clone()
+        };
+
+        let capabilities = cache.get(&camera, connect).await.unwrap();
+        assert_eq!(capabilities.firmware_version, "1.0");
+        assert_eq!(
+            capabilities.report.ptz.as_ref().unwrap().move_status,
+            Some(true)
+        );
+        cache.get(&camera, connect).await.unwrap();
+        assert_eq!(camera.count("GetServices"), 1);
+
+        *camera.firmware_version.lock().unwrap() = "2.0".to_string();
+        let capabilities = cache.get(&camera, connect).await.unwrap();
+        assert_eq!(capabilities.firmware_version, "2.0");
+        assert_eq!(camera.count("GetServices"), 2);
+
+        let cache = CapabilityCache::new(Duration::from_secs(3600));
+        cache.get(&camera, connect).await.unwrap();
+        cache.get(&camera, connect).await.unwrap();
+        let information = camera.count("GetDeviceInformation");
+        cache.refresh(&camera, connect).await.unwrap();
+        assert_eq!(camera.count("GetDeviceInformation"), information + 1);
+        assert_eq!(camera.count("GetServices"), 4);
+    }
+}
@@ -0,0 +1,270 @@
+//! Which ONVIF profiles a device plausibly conforms to.
+//!
+//! Devices advertise profiles with scopes such as `onvif://www.onvif.org/Profile/T`, but many
+//! claim profiles they don't implement or omit ones they do. [`detect_profiles`] infers them from
+//! the services the device offers instead, and reports both, so that a mismatch is visible.
+//!
+//! This is a heuristic based on the services each profile mandates, not a conformance test.
+//!
+//! ```no_run
+//! # async fn example(device_service: onvif::soap::client::Client) {
+//! use onvif::{capabilities::CapabilityCache, conformance, soap::client::ClientBuilder};
+//! use std::time::Duration;
+//!
+//! let credentials = device_service.credentials();
+//! let capabilities = CapabilityCache::new(Duration::from_secs(3600))
+//!     .get(&device_service, |address| {
+//!         ClientBuilder::new(address)
+//!             .credentials(credentials.clone())
+//!             .build()
+//!     })
+//!     .await
+//!     .unwrap();
+//! let scopes = conformance::scopes(&device_service).await.unwrap();
+//!
+//! let report = conformance::detect_profiles(&capabilities, &scopes);
+//! for (profile, evidence) in &report.evidence {
+//!     println!("{:?}: {}", profile, evidence.join(", "));
+//! }
+//! # }
+//! ```
+
+use crate::capabilities::DeviceCapabilities;
+use schema::{
+    devicemgmt,
+    transport::{Error as TransportError, Transport},
+};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use thiserror::Error;
+
+const EVENTS_NS: &str = "http://www.onvif.org/ver10/events/wsdl";
+const MEDIA_NS: &str = "http://www.onvif.org/ver10/media/wsdl";
+const MEDIA2_NS: &str = "http://www.onvif.org/ver20/media/wsdl";
+const ANALYTICS_NS: &str = "http://www.onvif.org/ver20/analytics/wsdl";
+const RECORDING_NS: &str = "http://www.onvif.org/ver10/recording/wsdl";
+const SEARCH_NS: &str = "http://www.onvif.org/ver10/search/wsdl";
+const REPLAY_NS: &str = "http://www.onvif.org/ver10/replay/wsdl";
+const ACCESS_CONTROL_NS: &str = "http://www.onvif.org/ver10/accesscontrol/wsdl";
+const DOOR_CONTROL_NS: &str = "http://www.onvif.org/ver10/doorcontrol/wsdl";
+const ACCESS_RULES_NS: &str = "http://www.onvif.org/ver10/accessrules/wsdl";
+const CREDENTIAL_NS: &str = "http://www.onvif.org/ver10/credential/wsdl";
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Transport error: {0}")]
+    Transport(#[from] TransportError),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub enum Profile {
+    /// Streaming, with the media service.
+    S,
+    /// Advanced streaming, with the media2 service.
+    T,
+    /// Recording and replay.
+    G,
+    /// Metadata and analytics.
+    M,
+    /// Access control configuration.
+    A,
+    /// Physical access control.
+    C,
+}
+
+impl Profile {
+    pub const ALL: [Profile; 6] = [
+        Profile::S,
+        Profile::T,
+        Profile::G,
+        Profile::M,
+        Profile::A,
+        Profile::C,
+    ];
+
+    /// Services the profile mandates, as namespaces and names for the evidence.
+    fn services(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Profile::S => &[(MEDIA_NS, "media"), (EVENTS_NS, "event")],
+            Profile::T => &[(MEDIA2_NS, "media2"), (EVENTS_NS, "event")],
+            Profile::G => &[
+                (RECORDING_NS, "recording"),
+                (SEARCH_NS, "search"),
+                (REPLAY_NS, "replay"),
+            ],
+            Profile::M => &[
+                (MEDIA2_NS, "media2"),
+                (ANALYTICS_NS, "analytics"),
+                (EVENTS_NS, "event"),
+            ],
+            Profile::A => &[
+                (ACCESS_CONTROL_NS, "access control"),
+                (ACCESS_RULES_NS, "access rules"),
+                (CREDENTIAL_NS, "credential"),
+            ],
+            Profile::C => &[
+                (ACCESS_CONTROL_NS, "access control"),
+                (DOOR_CONTROL_NS, "door control"),
+                (EVENTS_NS, "event"),
+            ],
+        }
+    }
+
+    /// Last segment of the scope claiming the profile, e.g. `Profile/T`. Profile S predates the
+    /// letters and is claimed as `Profile/Streaming`.
+    fn scope_names(self) -> &'static [&'static str] {
+        match self {
+            Profile::S => &["Streaming", "S"],
+            Profile::T => &["T"],
+            Profile::G => &["G"],
+            Profile::M => &["M"],
+            Profile::A => &["A"],
+            Profile::C => &["C"],
+        }
+    }
+}
+
+/// Result of [`detect_profiles`].
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ProfileReport {
+    /// Profiles whose mandatory services the device offers.
+    pub profiles: BTreeSet<Profile>,
+
+    /// Profiles claimed by scopes, whether or not they were detected.
+    pub claimed: BTreeSet<Profile>,
+
+    /// Reasons for each determination, e.g. `media2 service offered` or `no replay service`.
+    pub evidence: BTreeMap<Profile, Vec<String>>,
+}
+
+/// Infers profiles from the services in `capabilities` and compares them with `scopes`, e.g. of
+/// [`scopes`].
+///
+/// A profile is detected if the device offers all services it mandates. For services whose
+/// capabilities were fetched, the device must also have answered `GetServiceCapabilities`.
+pub fn detect_profiles(capabilities: &DeviceCapabilities, scopes: &[String]) -> ProfileReport {
+    let mut report = ProfileReport::default();
+    for &profile in Profile::ALL.iter() {
+        let mut evidence = vec![];
+        let mut detected = true;
+        for (namespace, name) in profile.services() {
+            if !capabilities.services.contains_key(*namespace) {
+                detected = false;
+                evidence.push(format!("no {} service", name));
+            } else if let Some(error) = capability_error(capabilities, namespace) {
+                detected = false;
+                evidence.push(format!("{} service failed to answer: {}", name, error));
+            } else {
+                evidence.push(format!("{} service offered", name));
+            }
+        }
+
+        let claimed = scopes.iter().any(|scope| claims(scope, profile));
+        if claimed {
+            report.claimed.insert(profile);
+            evidence.push(if detected {
+                "claimed by scope".to_string()
+            } else {
+                "claimed by scope, but mandatory services are missing".to_string()
+            });
+        }
+
+        if detected {
+            report.profiles.insert(profile);
+        }
+        report.evidence.insert(profile, evidence);
+    }
+    report
+}
+
+/// Requests the scope URIs of the device.
+pub async fn scopes<T: Transport>(device_service: &T) -> Result<Vec<String>, Error> {
+    Ok(devicemgmt::get_scopes(device_service, &Default::default())
+        .await?
+        .scopes
+        .into_iter()
+        .map(|scope| scope.scope_item)
+        .collect())
+}
+
+/// Error of the capabilities request of the service with `namespace`, if the report includes
+/// the service and it failed.
+fn capability_error<'a>(capabilities: &'a DeviceCapabilities, namespace: &str) -> Option<&'a str> {
+    let service = match namespace {
+        EVENTS_NS => "event",
+        MEDIA_NS => "media",
+        MEDIA2_NS => "media2",
+        ANALYTICS_NS => "analytics",
+        _ => return None,
+    };
+    capabilities.report.errors.get(service).map(String::as_str)
+}
+
+fn claims(scope: &str, profile: Profile) -> bool {
+    let name = match scope
+        .trim()
+        .trim_end_matches('/')
+        .strip_prefix("onvif://www.onvif.org/Profile/")
+    {
+        Some(name) => name,
+        None => return false,
+    };
+    profile
+        .scope_names()
+        .iter()
+        .any(|candidate| candidate.eq_ignore_ascii_case(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use url::Url;
+
+    #[test]
+    fn detects_profiles() {
+        let address = Url::parse("http://192.168.0.2/onvif/service").unwrap();
+        let mut capabilities = DeviceCapabilities {
+            firmware_version: "1.0".to_string(),
+            services: [MEDIA_NS, MEDIA2_NS, EVENTS_NS, RECORDING_NS]
+                .iter()
+                .map(|namespace| (namespace.to_string(), address.clone()))
+                .collect(),
+            report: Default::default(),
+        };
+        capabilities
+            .report
+            .errors
+            .insert("media2".to_string(), "ActionNotSupported".to_string());
+        let scopes = vec![
+            "onvif://www.onvif.org/Profile/Streaming".to_string(),
+            "onvif://www.onvif.org/Profile/G".to_string(),
+            "onvif://www.onvif.org/name/Camera".to_string(),
+        ];
+
+        let report = detect_profiles(&capabilities, &scopes);
+        assert_eq!(
+            report.profiles,
+            [Profile::S].iter().copied().collect::<BTreeSet<_>>()
+        );
+        assert_eq!(
+            report.claimed,
+            [Profile::S, Profile::G]
+                .iter()
+                .copied()
+                .collect::<BTreeSet<_>>()
+        );
+        assert_eq!(
+            report.evidence[&Profile::G],
+            vec![
+                "recording service offered",
+                "no search service",
+                "no replay service",
+                "claimed by scope, but mandatory services are missing"
+            ]
+        );
+        assert_eq!(
+            report.evidence[&Profile::T][0],
+            "media2 service failed to answer: ActionNotSupported"
+        );
+    }
+}
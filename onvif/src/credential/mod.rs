@@ -0,0 +1,360 @@
+//! Credential service helpers: encoding card numbers and PINs, and enrolling credentials.
+//!
+//! Identifier values are octet strings whose encoding depends on their format type, most of
+//! which are listed in ISO 16484-5 Annex P. [`Identifier`] encodes the common ones, which
+//! [`enroll`] checks against the identifier types and formats the device supports before
+//! creating the credential.
+//!
+//! ```no_run
+//! # async fn example(credential_service: onvif::soap::client::Client) {
+//! use onvif::{
+//!     credential::{self, Identifier},
+//!     schema::types as pt,
+//! };
+//!
+//! let token = credential::enroll(
+//!     &credential_service,
+//!     "jdoe",
+//!     &[
+//!         Identifier::Wiegand26 {
+//!             facility: 12,
+//!             card: 3456,
+//!         },
+//!         Identifier::Pin("4711".to_string()),
+//!     ],
+//!     &[pt::ReferenceToken("staff".to_string())],
+//! )
+//! .await
+//! .unwrap();
+//! # }
+//! ```
+
+use schema::{
+    credential as tcr,
+    transport::{Error as TransportError, Transport},
+    types as pt,
+};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Transport error: {0}")]
+    Transport(#[from] TransportError),
+
+    #[error("Invalid identifier: {0}")]
+    InvalidIdentifier(String),
+
+    #[error("Unsupported: {0}")]
+    Unsupported(String),
+}
+
+/// Identifier type of cards.
+pub const CARD: &str = "pt:Card";
+
+/// Identifier type of PINs.
+pub const PIN: &str = "pt:PIN";
+
+/// A credential identifier to encode.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Identifier {
+    /// A 26 bit Wiegand card, format type `WIEGAND26`. The value is the whole frame, parity bits
+    /// included, right-aligned in 4 octets.
+    Wiegand26 { facility: u8, card: u16 },
+
+    /// A 34 bit Wiegand card, format type `WIEGAND34`, which isn't part of ISO 16484-5 but is
+    /// defined by many manufacturers. The value is the whole frame, parity bits included,
+    /// right-aligned in 5 octets.
+    Wiegand34 { facility: u16, card: u16 },
+
+    /// A PIN of 4 to 16 digits, format type `SIMPLE_ALPHA_NUMERIC`. The value is the digits as
+    /// ASCII, so leading zeros are kept.
+    Pin(String),
+
+    /// Any other identifier, e.g. the UID of a smart card or a manufacturer-defined format.
+    Raw {
+        type_name: String,
+        format_type: String,
+        value: Vec<u8>,
+    },
+}
+
+impl Identifier {
+    /// Identifier type, e.g. [`CARD`].
+    pub fn type_name(&self) -> &str {
+        match self {
+            Identifier::Wiegand26 { .. } | Identifier::Wiegand34 { .. } => CARD,
+            Identifier::Pin(_) => PIN,
+            Identifier::Raw { type_name, .. } => type_name,
+        }
+    }
+
+    pub fn format_type(&self) -> &str {
+        match self {
+            Identifier::Wiegand26 { .. } => "WIEGAND26",
+            Identifier::Wiegand34 { .. } => "WIEGAND34",
+            Identifier::Pin(_) => "SIMPLE_ALPHA_NUMERIC",
+            Identifier::Raw { format_type, .. } => format_type,
+        }
+    }
+
+    /// The value as an octet string.
+    pub fn value(&self) -> Result<Vec<u8>, Error> {
+        match self {
+            Identifier::Wiegand26 { facility, card } => {
+                Ok(wiegand(u64::from(*facility) << 16 | u64::from(*card), 24)[4..].to_vec())
+            }
+            Identifier::Wiegand34 { facility, card } => {
+                Ok(wiegand(u64::from(*facility) << 16 | u64::from(*card), 32)[3..].to_vec())
+            }
+            Identifier::Pin(pin) => {
+                if !(4..=16).contains(&pin.len()) || !pin.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(Error::InvalidIdentifier(
+                        "a PIN has 4 to 16 digits".to_string(),
+                    ));
+                }
+                Ok(pin.as_bytes().to_vec())
+            }
+            Identifier::Raw { value, .. } if value.is_empty() => {
+                Err(Error::InvalidIdentifier("empty value".to_string()))
+            }
+            Identifier::Raw { value, .. } => Ok(value.clone()),
+        }
+    }
+
+    /// The identifier as the credential service expects it, with the value in hexadecimal.
+    pub fn encode(&self) -> Result<tcr::CredentialIdentifier, Error> {
+        Ok(tcr::CredentialIdentifier {
+            _type: tcr::CredentialIdentifierType {
+                name: pt::Name(self.type_name().to_string()),
+                format_type: self.format_type().to_string(),
+            },
+            exempted_from_authentication: false,
+            value: self.value()?.iter().map(|b| format!("{:02X}", b)).collect(),
+        })
+    }
+}
+
+/// A Wiegand frame of `bits` data bits between an even parity bit over the first half and an
+/// odd parity bit over the second half, as big-endian octets.
+fn wiegand(data: u64, bits: u32) -> [u8; 8] {
+    let half = bits / 2;
+    let first = (data >> half).count_ones();
+    let second = (data & ((1 << half) - 1)).count_ones();
+    let even = u64::from(first % 2 == 1);
+    let odd = u64::from(second % 2 == 0);
+    (even << (bits + 1) | data << 1 | odd).to_be_bytes()
+}
+
+/// Checks that the device supports the types and formats of `identifiers`, and as many access
+/// profiles per credential as `access_profiles`.
+pub async fn validate<T: Transport>(
+    credential_service: &T,
+    identifiers: &[Identifier],
+    access_profiles: usize,
+) -> Result<(), Error> {
+    let capabilities = tcr::get_service_capabilities(credential_service, &Default::default())
+        .await?
+        .capabilities;
+
+    if access_profiles > capabilities.max_access_profiles_per_credential.0 as usize {
+        return Err(Error::Unsupported(format!(
+            "at most {} access profiles per credential",
+            capabilities.max_access_profiles_per_credential.0
+        )));
+    }
+
+    for identifier in identifiers {
+        let type_name = identifier.type_name();
+        if !capabilities
+            .supported_identifier_type
+            .iter()
+            .any(|name| name.0 == type_name)
+        {
+            return Err(Error::Unsupported(format!("identifier type {}", type_name)));
+        }
+
+        let formats = tcr::get_supported_format_types(
+            credential_service,
+            &tcr::GetSupportedFormatTypes {
+                credential_identifier_type_name: type_name.to_string(),
+            },
+        )
+        .await?
+        .format_type_info;
+        if !formats
+            .iter()
+            .any(|format| format.format_type == identifier.format_type())
+        {
+            return Err(Error::Unsupported(format!(
+                "format type {} of {}",
+                identifier.format_type(),
+                type_name
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Creates an enabled credential for `holder`, a reference to the person in an external system,
+/// with `identifiers`, granting the access profiles `access_profiles`. Schedules apply through
+/// the access profiles. Returns the token of the new credential.
+///
+/// Identifiers are encoded and checked with [`validate`] first, so that nothing is created on
+/// the device if one is invalid or unsupported.
+pub async fn enroll<T: Transport>(
+    credential_service: &T,
+    holder: &str,
+    identifiers: &[Identifier],
+    access_profiles: &[pt::ReferenceToken],
+) -> Result<pt::ReferenceToken, Error> {
+    if identifiers.is_empty() {
+        return Err(Error::InvalidIdentifier(
+            "a credential needs at least one identifier".to_string(),
+        ));
+    }
+    let credential_identifier = identifiers
+        .iter()
+        .map(Identifier::encode)
+        .collect::<Result<Vec<_>, _>>()?;
+    validate(credential_service, identifiers, access_profiles.len()).await?;
+
+    let credential = tcr::Credential {
+        credential_identifier,
+        credential_access_profile: access_profiles
+            .iter()
+            .map(|token| tcr::CredentialAccessProfile {
+                access_profile_token: token.clone(),
+                ..Default::default()
+            })
+            .collect(),
+        base: tcr::CredentialInfo {
+            credential_holder_reference: tcr::credential_info::CredentialHolderReferenceType(
+                holder.to_string(),
+            ),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    Ok(tcr::create_credential(
+        credential_service,
+        &tcr::CreateCredential {
+            credential,
+            state: tcr::CredentialState {
+                enabled: true,
+                ..Default::default()
+            },
+        },
+    )
+    .await?
+    .token)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    #[test]
+    fn encodes_identifiers() {
+        // Facility 12, card 3456: even parity over 00001100 0000, odd parity over 110110000000.
+        let wiegand26 = Identifier::Wiegand26 {
+            facility: 12,
+            card: 3456,
+        };
+        assert_eq!(wiegand26.encode().unwrap().value, "00181B01");
+        assert_eq!(
+            Identifier::Wiegand34 {
+                facility: 1,
+                card: 1
+            }
+            .encode()
+            .unwrap()
+            .value,
+            "0200020002"
+        );
+
+        let pin = Identifier::Pin("0042".to_string()).encode().unwrap();
+        assert_eq!(pin._type.name.0, PIN);
+        assert_eq!(pin.value, "30303432");
+        assert!(matches!(
+            Identifier::Pin("12a4".to_string()).encode(),
+            Err(Error::InvalidIdentifier(_))
+        ));
+    }
+
+    struct FakeCredentialService {
+        created: Mutex<Option<String>>,
+    }
+
+    #[async_trait]
+    impl Transport for FakeCredentialService {
+        async fn request(&self, message: &str) -> Result<String, TransportError> {
+            let ns = r#"xmlns:tcr="http://www.onvif.org/ver10/credential/wsdl""#;
+            if message.contains("GetServiceCapabilities") {
+                Ok(format!(
+                    r#"<tcr:GetServiceCapabilitiesResponse {}>
+                        <tcr:Capabilities MaxLimit="10" CredentialValiditySupported="false"
+                            CredentialAccessProfileValiditySupported="false"
+                            ValiditySupportsTimeValue="false" MaxCredentials="100"
+                            MaxAccessProfilesPerCredential="2"
+                            ResetAntipassbackSupported="false">
+                            <tcr:SupportedIdentifierType>pt:Card</tcr:SupportedIdentifierType>
+                        </tcr:Capabilities>
+                    </tcr:GetServiceCapabilitiesResponse>"#,
+                    ns
+                ))
+            } else if message.contains("GetSupportedFormatTypes") {
+                Ok(format!(
+                    r#"<tcr:GetSupportedFormatTypesResponse {}>
+                        <tcr:FormatTypeInfo>
+                            <tcr:FormatType>WIEGAND26</tcr:FormatType>
+                            <tcr:Description>26 bit Wiegand</tcr:Description>
+                        </tcr:FormatTypeInfo>
+                    </tcr:GetSupportedFormatTypesResponse>"#,
+                    ns
+                ))
+            } else {
+                *self.created.lock().unwrap() = Some(message.to_string());
+                Ok(format!(
+                    "<tcr:CreateCredentialResponse {}><tcr:Token>credential_1</tcr:Token>\
+                     </tcr:CreateCredentialResponse>",
+                    ns
+                ))
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn enrolls_supported_identifiers() {
+        let service = FakeCredentialService {
+            created: Mutex::new(None),
+        };
+        let profiles = [pt::ReferenceToken("staff".to_string())];
+        let card = Identifier::Wiegand26 {
+            facility: 12,
+            card: 3456,
+        };
+
+        assert!(matches!(
+            enroll(
+                &service,
+                "jdoe",
+                &[card.clone(), Identifier::Pin("4711".to_string())],
+                &profiles
+            )
+            .await,
+            Err(Error::Unsupported(_))
+        ));
+        assert!(service.created.lock().unwrap().is_none());
+
+        let token = enroll(&service, "jdoe", &[card], &profiles).await.unwrap();
+        assert_eq!(token.0, "credential_1");
+        let created = service.created.lock().unwrap().clone().unwrap();
+        assert!(created.contains("00181B01"));
+        assert!(created.contains("staff"));
+        assert!(created.contains("jdoe"));
+    }
+}
@@ -0,0 +1,287 @@
+//! Door control helpers: momentary access, verified through the door state.
+//!
+//! Door controllers acknowledge commands before the lock moves, so the helpers here poll
+//! `GetDoorState` until the door reports the intended mode instead of trusting the response.
+//!
+//! ```no_run
+//! # async fn example(door_service: onvif::soap::client::Client) {
+//! use onvif::{door, schema::types as pt};
+//! use std::time::Duration;
+//!
+//! let front = pt::ReferenceToken("front".to_string());
+//! door::unlock_for(&door_service, &front, Duration::from_secs(10))
+//!     .await
+//!     .unwrap();
+//! # }
+//! ```
+
+use schema::{
+    doorcontrol::{self, DoorMode, DoorState, LockPhysicalState},
+    transport::{Error as TransportError, Transport},
+    types as pt,
+};
+use std::time::Duration;
+use thiserror::Error;
+use tokio::time::{self, Instant};
+use xsd_types::types as xs;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Transport error: {0}")]
+    Transport(#[from] TransportError),
+
+    /// The device accepted the command, but the door didn't get to the intended state in time.
+    #[error("Door didn't reach the intended state, it is {0}")]
+    Unconfirmed(String),
+}
+
+/// How a door opened by [`unlock_for`] was locked again.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Relock {
+    /// The device locked it at the end of the access time, as it should.
+    ByDevice,
+
+    /// The device didn't, so it was locked with `LockDoor`.
+    ByClient,
+}
+
+/// How long the door is given to reach a state, on top of the time it is expected to.
+const CONFIRMATION_TIMEOUT: Duration = Duration::from_secs(5);
+
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Grants momentary access with `AccessDoor`, for the access time configured on the device, and
+/// waits until the door reports it.
+pub async fn access<T: Transport>(
+    door_service: &T,
+    door: &pt::ReferenceToken,
+) -> Result<(), Error> {
+    access_door(door_service, door, None).await.map(drop)
+}
+
+/// Grants momentary access for `duration`, then makes sure the door is locked again, locking it
+/// with `LockDoor` if the device doesn't within [`CONFIRMATION_TIMEOUT`].
+pub async fn unlock_for<T: Transport>(
+    door_service: &T,
+    door: &pt::ReferenceToken,
+    duration: Duration,
+) -> Result<Relock, Error> {
+    let relock = access_door(door_service, door, Some(duration)).await? + duration;
+
+    time::sleep_until(relock).await;
+    if wait_for(door_service, door, is_locked, relock + CONFIRMATION_TIMEOUT)
+        .await
+        .is_ok()
+    {
+        return Ok(Relock::ByDevice);
+    }
+
+    tracing::warn!("door {} wasn't relocked by the device, locking it", door.0);
+    doorcontrol::lock_door(
+        door_service,
+        &doorcontrol::LockDoor {
+            token: door.clone(),
+        },
+    )
+    .await?;
+    wait_for(
+        door_service,
+        door,
+        is_locked,
+        Instant::now() + CONFIRMATION_TIMEOUT,
+    )
+    .await?;
+    Ok(Relock::ByClient)
+}
+
+/// Sends `AccessDoor` and waits until the door reports it, returning when it was sent.
+async fn access_door<T: Transport>(
+    door_service: &T,
+    door: &pt::ReferenceToken,
+    access_time: Option<Duration>,
+) -> Result<Instant, Error> {
+    let sent = Instant::now();
+    doorcontrol::access_door(
+        door_service,
+        &doorcontrol::AccessDoor {
+            token: door.clone(),
+            access_time: access_time.map(xs_duration).transpose()?,
+            ..Default::default()
+        },
+    )
+    .await?;
+
+    // The door is still locked until it moves, but may also relock before a poll sees it
+    // accessed if the access time is short, which still confirms the access.
+    let relocked = |state: &DoorState| {
+        access_time.map_or(false, |access_time| {
+            Instant::now() >= sent + access_time && is_locked(state)
+        })
+    };
+    wait_for(
+        door_service,
+        door,
+        |state| is_accessed(state) || relocked(state),
+        Instant::now() + CONFIRMATION_TIMEOUT,
+    )
+    .await?;
+    Ok(sent)
+}
+
+/// Polls the state of `door` until `reached` holds for it, or fails at `deadline` with the last
+/// state.
+async fn wait_for<T, F>(
+    door_service: &T,
+    door: &pt::ReferenceToken,
+    reached: F,
+    deadline: Instant,
+) -> Result<(), Error>
+where
+    T: Transport,
+    F: Fn(&DoorState) -> bool,
+{
+    loop {
+        let state = doorcontrol::get_door_state(
+            door_service,
+            &doorcontrol::GetDoorState {
+                token: door.clone(),
+            },
+        )
+        .await?
+        .door_state;
+        if reached(&state) {
+            return Ok(());
+        }
+        if Instant::now() + POLL_INTERVAL > deadline {
+            return Err(Error::Unconfirmed(format!("{:?}", state.door_mode)));
+        }
+        time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+fn is_accessed(state: &DoorState) -> bool {
+    state.door_mode == DoorMode::Accessed
+        || state.lock_physical_state == Some(LockPhysicalState::Unlocked)
+}
+
+/// Devices without a lock monitor only report the mode.
+fn is_locked(state: &DoorState) -> bool {
+    state.door_mode == DoorMode::Locked
+        && state.lock_physical_state != Some(LockPhysicalState::Unlocked)
+}
+
+fn xs_duration(duration: Duration) -> Result<xs::Duration, Error> {
+    format!("PT{}S", duration.as_secs_f64())
+        .parse()
+        .map_err(|e| Error::Transport(TransportError::Serialization(format!("{:?}", e))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    /// A door which is accessed on `AccessDoor`, after staying locked for `opens_after` state
+    /// requests, and relocks on `LockDoor`, or after `relocks` state requests if set.
+    struct FakeDoor {
+        opens_after: u32,
+        relocks: Option<u32>,
+        state: Mutex<(&'static str, u32)>,
+        requests: Mutex<Vec<String>>,
+    }
+
+    impl FakeDoor {
+        fn new(opens_after: u32, relocks: Option<u32>) -> Self {
+            Self {
+                opens_after,
+                relocks,
+                state: Mutex::new(("Locked", 0)),
+                requests: Mutex::new(vec![]),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Transport for FakeDoor {
+        async fn request(&self, message: &str) -> Result<String, TransportError> {
+            self.requests.lock().unwrap().push(message.to_string());
+            let mut state = self.state.lock().unwrap();
+            let ns = r#"xmlns:tdc="http://www.onvif.org/ver10/doorcontrol/wsdl""#;
+            if message.contains("AccessDoor") {
+                *state = ("Opening", 0);
+                Ok(format!("<tdc:AccessDoorResponse {}/>", ns))
+            } else if message.contains("LockDoor") {
+                *state = ("Locked", 0);
+                Ok(format!("<tdc:LockDoorResponse {}/>", ns))
+            } else {
+                state.1 += 1;
+                if state.0 == "Opening" && state.1 > self.opens_after {
+                    state.0 = "Accessed";
+                }
+                if self.relocks.map_or(false, |relocks| state.1 > relocks) {
+                    state.0 = "Locked";
+                }
+                Ok(format!(
+                    "<tdc:GetDoorStateResponse {}><tdc:DoorState>\
+                     <tdc:DoorMode>{}</tdc:DoorMode>\
+                     </tdc:DoorState></tdc:GetDoorStateResponse>",
+                    ns,
+                    if state.0 == "Opening" {
+                        "Locked"
+                    } else {
+                        state.0
+                    }
+                ))
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn relocks() {
+        let door = pt::ReferenceToken("front".to_string());
+
+        let device = FakeDoor::new(0, Some(1));
+        assert_eq!(
+            unlock_for(&device, &door, Duration::from_millis(10))
+                .await
+                .unwrap(),
+            Relock::ByDevice
+        );
+        assert!(device.requests.lock().unwrap()[0].contains("<tdc:AccessTime>"));
+
+        let device = FakeDoor::new(0, None);
+        time::pause();
+        assert_eq!(
+            unlock_for(&device, &door, Duration::from_millis(10))
+                .await
+                .unwrap(),
+            Relock::ByClient
+        );
+        assert!(device
+            .requests
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|request| request.contains("LockDoor")));
+    }
+
+    #[tokio::test]
+    async fn waits_until_the_door_moves() {
+        time::pause();
+        let door = pt::ReferenceToken("front".to_string());
+
+        let device = FakeDoor::new(1, None);
+        access(&device, &door).await.unwrap();
+        assert_eq!(*device.state.lock().unwrap(), ("Accessed", 2));
+
+        let device = FakeDoor::new(1, Some(3));
+        assert_eq!(
+            unlock_for(&device, &door, Duration::from_millis(10))
+                .await
+                .unwrap(),
+            Relock::ByDevice
+        );
+        assert_eq!(*device.state.lock().unwrap(), ("Locked", 4));
+    }
+}
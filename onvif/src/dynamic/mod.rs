@@ -0,0 +1,275 @@
+//! Calls of operations by name with JSON parameters, for tools that expose arbitrary operations
+//! without code per operation, e.g. REST gateways or test tools. Requires the `dynamic` feature.
+//!
+//! Parameters map to the request element as follows, the inverse of [`schema::json`]:
+//!
+//! - keys become child elements, in the order of the object, which has to follow the schema,
+//! - keys starting with `@` become attributes,
+//! - arrays become repeated elements and `null` becomes an empty element,
+//! - strings, numbers and booleans become text, as does a `value` key next to attributes.
+//!
+//! Children of the request element are in the namespace of the service, deeper elements in the
+//! `tt` namespace, as is the case for all ONVIF services. The response is rendered as described in
+//! [`schema::json`].
+//!
+//! ```no_run
+//! # async fn example(media_service: &onvif::soap::client::Client) {
+//! let response = media_service
+//!     .call_dynamic(
+//!         "media/GetStreamUri",
+//!         &serde_json::json!({
+//!             "StreamSetup": {
+//!                 "Stream": "RTP-Unicast",
+//!                 "Transport": { "Protocol": "RTSP" },
+//!             },
+//!             "ProfileToken": "profile_1",
+//!         }),
+//!     )
+//!     .await
+//!     .unwrap();
+//! println!("{}", response["MediaUri"]["Uri"]);
+//! # }
+//! ```
+
+use crate::soap::client::Client;
+use schema::{
+    json::xml_to_json,
+    operations::{self, Operation},
+    transport::{Error as TransportError, Transport},
+};
+use serde_json::Value;
+use thiserror::Error;
+
+const TT_NS: &str = "http://www.onvif.org/ver10/schema";
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Unknown operation: {0}")]
+    UnknownOperation(String),
+
+    #[error("Ambiguous operation {0}, qualify it with one of the services {1}")]
+    AmbiguousOperation(String, String),
+
+    #[error("Invalid parameters: {0}")]
+    Parameters(String),
+
+    #[error("Transport error: {0}")]
+    Transport(#[from] TransportError),
+
+    #[error("Unexpected response: {0}")]
+    Response(String),
+}
+
+/// Finds an operation by its name, e.g. `GetDeviceInformation`, or by service and name, e.g.
+/// `ptz/GetServiceCapabilities`, for names that several services share.
+pub fn operation(name: &str) -> Result<&'static Operation, Error> {
+    if let Some((service, name)) = name.split_once('/') {
+        return operations::find(service, name)
+            .ok_or_else(|| Error::UnknownOperation(format!("{}/{}", service, name)));
+    }
+
+    let candidates: Vec<&Operation> = operations::all()
+        .iter()
+        .filter(|operation| operation.name == name)
+        .collect();
+    match candidates.as_slice() {
+        [] => Err(Error::UnknownOperation(name.to_string())),
+        [operation] => Ok(*operation),
+        candidates => Err(Error::AmbiguousOperation(
+            name.to_string(),
+            candidates
+                .iter()
+                .map(|operation| operation.service)
+                .collect::<Vec<_>>()
+                .join(", "),
+        )),
+    }
+}
+
+/// Calls `operation`, see [`operation`] for how it is named, with `parameters` as described in
+/// the module documentation and returns the response as JSON.
+pub async fn call<T: Transport>(
+    transport: &T,
+    operation: &str,
+    parameters: &Value,
+) -> Result<Value, Error> {
+    let operation = self::operation(operation)?;
+    let request = request_xml(operation, parameters)?;
+    let response = transport.request(&request).await?;
+    xml_to_json(&response).map_err(Error::Response)
+}
+
+impl Client {
+    /// See [`call`].
+    pub async fn call_dynamic(&self, operation: &str, parameters: &Value) -> Result<Value, Error> {
+        call(self, operation, parameters).await
+    }
+}
+
+fn request_xml(operation: &Operation, parameters: &Value) -> Result<String, Error> {
+    // Device I/O reuses requests of the device service, listed as e.g. `tds::GetRelayOutputs`.
+    let (element, namespace) = match operation.request.split_once("::") {
+        Some((_, element)) => (
+            element,
+            operations::find("devicemgmt", element).map_or(operation.namespace, |o| o.namespace),
+        ),
+        None => (operation.request, operation.namespace),
+    };
+    let name = format!("m:{}", element);
+    let open = format!(
+        r#"{} xmlns:m="{}" xmlns:tt="{}""#,
+        name,
+        escape(namespace),
+        TT_NS
+    );
+    let mut xml = String::new();
+    write_element(&mut xml, &open, &name, parameters, "m").map_err(Error::Parameters)?;
+    Ok(xml)
+}
+
+/// Writes an element with the start tag content `open` and the end tag name `close`. Its child
+/// elements get the prefix `children`.
+fn write_element(
+    xml: &mut String,
+    open: &str,
+    close: &str,
+    value: &Value,
+    children: &str,
+) -> Result<(), String> {
+    xml.push('<');
+    xml.push_str(open);
+    match value {
+        Value::Null => {
+            xml.push_str("/>");
+            return Ok(());
+        }
+        Value::Object(map) => {
+            for (key, value) in map {
+                if let Some(attribute) = key.strip_prefix('@') {
+                    xml.push_str(&format!(r#" {}="{}""#, attribute, escape(&text(value)?)));
+                }
+            }
+            xml.push('>');
+            for (key, value) in map {
+                if key.starts_with('@') {
+                    continue;
+                }
+                if key == "value" {
+                    xml.push_str(&escape(&text(value)?));
+                    continue;
+                }
+                let items = match value {
+                    Value::Array(items) => items.as_slice(),
+                    value => std::slice::from_ref(value),
+                };
+                let child = format!("{}:{}", children, key);
+                for item in items {
+                    write_element(xml, &child, &child, item, "tt")?;
+                }
+            }
+        }
+        Value::Array(_) => return Err(format!("nested array in {}", close)),
+        value => {
+            xml.push('>');
+            xml.push_str(&escape(&text(value)?));
+        }
+    }
+    xml.push_str(&format!("</{}>", close));
+    Ok(())
+}
+
+fn text(value: &Value) -> Result<String, String> {
+    match value {
+        Value::String(text) => Ok(text.clone()),
+        Value::Number(number) => Ok(number.to_string()),
+        Value::Bool(boolean) => Ok(boolean.to_string()),
+        value => Err(format!(
+            "expected a string, number or boolean, got {}",
+            value
+        )),
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::fake_device::FakeDevice;
+
+    #[tokio::test]
+    async fn calls_by_name() {
+        let media = FakeDevice::new().respond(
+            "GetStreamUri",
+            r#"
+            <trt:GetStreamUriResponse
+                xmlns:trt="http://www.onvif.org/ver10/media/wsdl"
+                xmlns:tt="http://www.onvif.org/ver10/schema">
+                <trt:MediaUri>
+                    <tt:Uri>rtsp://192.168.0.2/stream1</tt:Uri>
+                    <tt:InvalidAfterConnect>false</tt:InvalidAfterConnect>
+                </trt:MediaUri>
+            </trt:GetStreamUriResponse>
+            "#,
+        );
+        let parameters = serde_json::json!({
+            "StreamSetup": {
+                "Stream": "RTP-Unicast",
+                "Transport": { "Protocol": "RTSP", "Tunnel": null },
+            },
+            "ProfileToken": "a&b",
+        });
+
+        let response = call(&media, "media/GetStreamUri", &parameters)
+            .await
+            .unwrap();
+        assert_eq!(response["MediaUri"]["Uri"], "rtsp://192.168.0.2/stream1");
+
+        crate::utils::xml_eq::assert_xml_eq(
+            &media.requests("GetStreamUri")[0],
+            r#"
+            <m:GetStreamUri
+                xmlns:m="http://www.onvif.org/ver10/media/wsdl"
+                xmlns:tt="http://www.onvif.org/ver10/schema">
+                <m:StreamSetup>
+                    <tt:Stream>RTP-Unicast</tt:Stream>
+                    <tt:Transport><tt:Protocol>RTSP</tt:Protocol><tt:Tunnel/></tt:Transport>
+                </m:StreamSetup>
+                <m:ProfileToken>a&amp;b</m:ProfileToken>
+            </m:GetStreamUri>
+            "#,
+        );
+    }
+
+    #[test]
+    fn resolves_operations() {
+        assert_eq!(
+            operation("GetDeviceInformation").unwrap().service,
+            "devicemgmt"
+        );
+        assert_eq!(
+            operation("ptz/GetServiceCapabilities").unwrap().namespace,
+            "http://www.onvif.org/ver20/ptz/wsdl"
+        );
+        assert!(matches!(
+            operation("GetServiceCapabilities"),
+            Err(Error::AmbiguousOperation(..))
+        ));
+        assert!(matches!(
+            operation("ptz/GetDeviceInformation"),
+            Err(Error::UnknownOperation(_))
+        ));
+
+        let attributes = request_xml(
+            operation("media/GetStreamUri").unwrap(),
+            &serde_json::json!({ "StreamSetup": { "@Extra": "1", "value": "x" } }),
+        )
+        .unwrap();
+        assert!(attributes.contains(r#"<m:StreamSetup Extra="1">x</m:StreamSetup>"#));
+    }
+}
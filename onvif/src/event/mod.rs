@@ -0,0 +1,1253 @@
+//! Pull-point event subscriptions.
+//!
+//! The generated `wsnt` types leave filters and message payloads empty, so requests here are
+//! built by hand and notifications are decoded from the raw XML.
+//!
+//! Operations on a subscription (pulling, renewing, unsubscribing) have to be sent to the
+//! subscription manager address returned by [`create_pull_point_subscription`], so callers need a
+//! separate transport for it. Devices that additionally require the WS-Addressing reference
+//! parameters to be echoed in SOAP headers are not supported yet.
+//!
+//! [`subscribe`] combines these into a stream of events that survives network failures,
+//! [`sensor::Sensors`] turns motion and tamper events into debounced states and [`sink::store`]
+//! persists them.
+
+pub mod sensor;
+pub mod sink;
+
+use crate::{metadata, metrics, radiometry};
+use async_stream::stream;
+use futures_core::stream::Stream;
+use futures_util::{
+    future::{self, Either},
+    pin_mut, FutureExt,
+};
+use schema::transport::{Error as TransportError, Transport};
+use serde::Serialize;
+use std::{collections::BTreeMap, future::Future, time::Duration};
+use thiserror::Error;
+use url::Url;
+use xmltree::{Element, XMLNode};
+
+const TEV_NS: &str = "http://www.onvif.org/ver10/events/wsdl";
+const WSNT_NS: &str = "http://docs.oasis-open.org/wsn/b-2";
+const TNS1_NS: &str = "http://www.onvif.org/ver10/topics";
+const TT_NS: &str = "http://www.onvif.org/ver10/schema";
+const CONCRETE_SET_DIALECT: &str = "http://www.onvif.org/ver10/tev/topicExpression/ConcreteSet";
+const ITEM_FILTER_DIALECT: &str = "http://www.onvif.org/ver10/tev/messageContentFilter/ItemFilter";
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Transport error: {0}")]
+    Transport(#[from] TransportError),
+
+    #[error("Unexpected response: {0}")]
+    Response(String),
+}
+
+/// A decoded notification.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Event {
+    /// Topic, e.g. `tns1:RuleEngine/CellMotionDetector/Motion`.
+    pub topic: String,
+
+    /// Time the event was produced, as sent by the device.
+    pub utc_time: Option<String>,
+
+    /// `Initialized`, `Changed` or `Deleted` for property events.
+    pub property_operation: Option<String>,
+
+    /// Simple items identifying the producing component, e.g. a video source token.
+    pub source: BTreeMap<String, String>,
+
+    pub key: BTreeMap<String, String>,
+
+    /// Simple items carrying the event payload, e.g. `IsMotion`.
+    pub data: BTreeMap<String, String>,
+
+    /// Objects in element items of the payload, e.g. of object detection rules.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub objects: Vec<metadata::Object>,
+
+    /// Readings of temperature measurement modules of radiometric thermal devices, see
+    /// [`radiometry::SPOT_TEMPERATURE_TOPIC`].
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub temperatures: Vec<radiometry::TemperatureReading>,
+}
+
+/// Item of the stream returned by [`subscribe`].
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Notification {
+    Event(Event),
+
+    /// Current state of a property rather than a change of it, see [`Event::is_property_state`].
+    PropertyState(Event),
+
+    /// There is no working subscription, so events may be missed until it's restored. Also
+    /// yielded if the first subscription attempt fails.
+    SubscriptionLost {
+        error: String,
+    },
+
+    /// A new subscription was created after [`Notification::SubscriptionLost`].
+    SubscriptionRestored,
+}
+
+/// Topic of audio detection events, e.g. for loud noises or glass breaking.
+pub const DETECTED_SOUND_TOPIC: &str = "tns1:AudioAnalytics/Audio/DetectedSound";
+
+/// Topics of motion events and the data item carrying their state. Cameras report motion on
+/// any of these, depending on model and firmware.
+pub const MOTION_TOPICS: &[(&str, &str)] = &[
+    ("tns1:RuleEngine/CellMotionDetector/Motion", "IsMotion"),
+    ("tns1:RuleEngine/MotionRegionDetector/Motion", "State"),
+    ("tns1:VideoSource/MotionAlarm", "State"),
+];
+
+/// Topics of tamper events, e.g. a covered or moved camera, and the data item carrying their
+/// state.
+pub const TAMPER_TOPICS: &[(&str, &str)] = &[
+    ("tns1:RuleEngine/TamperDetector/Tamper", "IsTamper"),
+    ("tns1:VideoSource/GlobalSceneChange/ImagingService", "State"),
+];
+
+/// Decoded [`DETECTED_SOUND_TOPIC`] event.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DetectedSound {
+    pub audio_source_configuration: Option<String>,
+    pub audio_analytics_configuration: Option<String>,
+
+    /// Name of the audio detection rule that triggered.
+    pub rule: Option<String>,
+
+    /// Whether the sound started or stopped.
+    pub is_sound_detected: bool,
+}
+
+/// Topics of access control events: access points granting or denying access, and doors
+/// changing state. Devices add to these paths, e.g. `tns1:AccessControl/Denied/Credential`.
+pub const ACCESS_GRANTED_TOPIC: &str = "tns1:AccessControl/AccessGranted";
+pub const ACCESS_DENIED_TOPIC: &str = "tns1:AccessControl/Denied";
+pub const DOOR_PHYSICAL_STATE_TOPIC: &str = "tns1:Door/State/DoorPhysicalState";
+pub const DOOR_TAMPER_TOPIC: &str = "tns1:Door/State/DoorTamper";
+
+/// Decoded access control event, see [`Event::access`].
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AccessEvent {
+    Granted(AccessAttempt),
+    Denied(AccessAttempt),
+
+    /// A door opened or closed, e.g. `Open`, `Closed` or `Fault`.
+    DoorPhysicalState {
+        door: Option<String>,
+        state: String,
+    },
+
+    /// Tampering of a door was detected or cleared, e.g. `NotInTamper` or `TamperDetected`.
+    DoorTamper {
+        door: Option<String>,
+        state: String,
+    },
+}
+
+/// An attempt to pass an access point.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct AccessAttempt {
+    pub access_point: Option<String>,
+
+    /// Token of the credential presented, if the device knows it.
+    pub credential: Option<String>,
+
+    pub credential_holder: Option<String>,
+
+    /// Why access was denied, e.g. `CredentialNotEnabled`, or `CredentialNotFound` for unknown
+    /// cards.
+    pub reason: Option<String>,
+
+    /// Value of an unknown card, as reported with `CredentialNotFound`.
+    pub card: Option<String>,
+}
+
+impl Event {
+    /// Whether the event is of `topic`, regardless of the prefix the device bound the topic
+    /// namespace to.
+    pub fn has_topic(&self, topic: &str) -> bool {
+        unprefixed(&self.topic) == unprefixed(topic)
+    }
+
+    /// Decodes an audio detection event, or returns `None` for other events.
+    pub fn detected_sound(&self) -> Option<DetectedSound> {
+        if !self.has_topic(DETECTED_SOUND_TOPIC) {
+            return None;
+        }
+        Some(DetectedSound {
+            audio_source_configuration: self.source.get("AudioSourceConfigurationToken").cloned(),
+            audio_analytics_configuration: self
+                .source
+                .get("AudioAnalyticsConfigurationToken")
+                .cloned(),
+            rule: self.source.get("Rule").cloned(),
+            is_sound_detected: self.data.get("IsSoundDetected")?.trim() == "true",
+        })
+    }
+
+    /// Decodes an access control event, or returns `None` for other events.
+    pub fn access(&self) -> Option<AccessEvent> {
+        let path = unprefixed(&self.topic);
+        let under = |topic: &str| {
+            let topic = unprefixed(topic);
+            path == topic || path.starts_with(&format!("{}/", topic))
+        };
+
+        if under(ACCESS_GRANTED_TOPIC) || under(ACCESS_DENIED_TOPIC) {
+            let mut attempt = AccessAttempt {
+                access_point: self.source.get("AccessPointToken").cloned(),
+                credential: self.data.get("CredentialToken").cloned(),
+                credential_holder: self.data.get("CredentialHolderName").cloned(),
+                reason: self.data.get("Reason").cloned(),
+                card: self.data.get("Card").cloned(),
+            };
+            if under(ACCESS_GRANTED_TOPIC) {
+                return Some(AccessEvent::Granted(attempt));
+            }
+            if attempt.reason.is_none() && path.contains("/CredentialNotFound") {
+                attempt.reason = Some("CredentialNotFound".to_string());
+            }
+            return Some(AccessEvent::Denied(attempt));
+        }
+
+        let door = self.source.get("DoorToken").cloned();
+        let state = self.data.get("State").map(|state| state.trim().to_string());
+        if self.has_topic(DOOR_PHYSICAL_STATE_TOPIC) {
+            Some(AccessEvent::DoorPhysicalState {
+                door,
+                state: state?,
+            })
+        } else if self.has_topic(DOOR_TAMPER_TOPIC) {
+            Some(AccessEvent::DoorTamper {
+                door,
+                state: state?,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Decodes a motion event into whether there is motion, or returns `None` for other events.
+    pub fn is_motion(&self) -> Option<bool> {
+        self.state(MOTION_TOPICS)
+    }
+
+    /// Decodes a tamper event into whether the camera is tampered with, or returns `None` for
+    /// other events.
+    pub fn is_tamper(&self) -> Option<bool> {
+        self.state(TAMPER_TOPICS)
+    }
+
+    /// Token of the video source or video source configuration the event is about, if any.
+    pub fn video_source(&self) -> Option<&str> {
+        [
+            "VideoSourceConfigurationToken",
+            "VideoSourceToken",
+            "Source",
+        ]
+        .iter()
+        .find_map(|name| self.source.get(*name))
+        .map(String::as_str)
+    }
+
+    fn state(&self, topics: &[(&str, &str)]) -> Option<bool> {
+        let (_, item) = topics.iter().find(|(topic, _)| self.has_topic(topic))?;
+        match self.data.get(*item)?.trim() {
+            "true" | "1" => Some(true),
+            "false" | "0" => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Whether the event reports the state of a property as it is when subscribing or after
+    /// [`set_synchronization_point`], i.e. its `PropertyOperation` is `Initialized`.
+    pub fn is_property_state(&self) -> bool {
+        self.property_operation.as_deref() == Some("Initialized")
+    }
+}
+
+fn unprefixed(topic: &str) -> &str {
+    topic.split_once(':').map_or(topic, |(_, path)| path)
+}
+
+/// Events the device delivers on a subscription. Matches all events by default.
+///
+/// Filtering on the device is much cheaper than pulling every event of a busy device, but not
+/// every device supports message content filters; see `MessageContentFilterDialect` in the
+/// event service `GetEventProperties` response.
+///
+/// ```
+/// use onvif::event::Filter;
+///
+/// let filter = Filter::default()
+///     .topic("tns1:RuleEngine/CellMotionDetector/Motion")
+///     .source_item("VideoSourceConfigurationToken", "1");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Filter {
+    topic: Option<String>,
+    message_content: Vec<String>,
+}
+
+impl Filter {
+    /// Concrete set topic expression such as `tns1:RuleEngine//.`.
+    pub fn topic(mut self, expression: &str) -> Self {
+        self.topic = Some(expression.to_string());
+        self
+    }
+
+    /// XPath expression over the `tt:Message` in the ItemFilter dialect, e.g.
+    /// `boolean(//tt:SimpleItem[@Name="Rule" and @Value="MyMotionDetectorRule"])`. The `tt`
+    /// prefix is bound to the ONVIF schema namespace. Events have to match all expressions.
+    pub fn message_content(mut self, expression: &str) -> Self {
+        self.message_content.push(expression.to_string());
+        self
+    }
+
+    /// Only matches events whose source has the simple item, e.g. a video source configuration
+    /// token. Both may contain any characters, including quotes.
+    pub fn source_item(self, name: &str, value: &str) -> Self {
+        self.message_content(&format!(
+            "boolean(//tt:Source/tt:SimpleItem[@Name={} and @Value={}])",
+            xpath_literal(name),
+            xpath_literal(value)
+        ))
+    }
+
+    fn to_xml(&self) -> String {
+        let mut xml = String::new();
+        if let Some(topic) = &self.topic {
+            xml += &format!(
+                r#"<wsnt:TopicExpression Dialect="{}" xmlns:tns1="{}">{}</wsnt:TopicExpression>"#,
+                CONCRETE_SET_DIALECT,
+                TNS1_NS,
+                escape(topic)
+            );
+        }
+        if !self.message_content.is_empty() {
+            xml += &format!(
+                r#"<wsnt:MessageContent Dialect="{}" xmlns:tt="{}">{}</wsnt:MessageContent>"#,
+                ITEM_FILTER_DIALECT,
+                TT_NS,
+                escape(&self.message_content.join(" and "))
+            );
+        }
+
+        if xml.is_empty() {
+            xml
+        } else {
+            format!("<tev:Filter>{}</tev:Filter>", xml)
+        }
+    }
+}
+
+/// Subscription policy of [`create_pull_point_subscription`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SubscriptionPolicy {
+    changed_only: bool,
+}
+
+impl SubscriptionPolicy {
+    /// Asks the device not to send `Initialized` and `Deleted` property events, only changes.
+    pub fn changed_only(mut self, changed_only: bool) -> Self {
+        self.changed_only = changed_only;
+        self
+    }
+
+    fn to_xml(&self) -> String {
+        if self.changed_only {
+            "<tev:SubscriptionPolicy><tev:ChangedOnly/></tev:SubscriptionPolicy>".to_string()
+        } else {
+            String::new()
+        }
+    }
+}
+
+/// Options of [`subscribe`].
+#[derive(Clone, Debug)]
+pub struct SubscriptionOptions {
+    filter: Filter,
+    policy: SubscriptionPolicy,
+    termination: Duration,
+    pull_timeout: Duration,
+    message_limit: u32,
+    retry_delay: Duration,
+    max_retry_delay: Duration,
+    synchronize: bool,
+    shutdown_timeout: Duration,
+}
+
+impl Default for SubscriptionOptions {
+    fn default() -> Self {
+        Self {
+            filter: Filter::default(),
+            policy: SubscriptionPolicy::default(),
+            termination: Duration::from_secs(60),
+            pull_timeout: Duration::from_secs(10),
+            message_limit: 100,
+            retry_delay: Duration::from_secs(1),
+            max_retry_delay: Duration::from_secs(60),
+            synchronize: false,
+            shutdown_timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+impl SubscriptionOptions {
+    /// All events by default.
+    pub fn filter(mut self, filter: Filter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    pub fn policy(mut self, policy: SubscriptionPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// How long the subscription outlives the last pull, 60 seconds by default.
+    pub fn termination(mut self, termination: Duration) -> Self {
+        self.termination = termination;
+        self
+    }
+
+    /// How long each pull waits for events on the device, 10 seconds by default.
+    pub fn pull_timeout(mut self, pull_timeout: Duration) -> Self {
+        self.pull_timeout = pull_timeout;
+        self
+    }
+
+    pub fn message_limit(mut self, message_limit: u32) -> Self {
+        self.message_limit = message_limit;
+        self
+    }
+
+    /// Requests the state of all properties with [`set_synchronization_point`] after every
+    /// (re)subscription, for devices that don't send it on their own. Off by default.
+    pub fn synchronize(mut self, synchronize: bool) -> Self {
+        self.synchronize = synchronize;
+        self
+    }
+
+    /// Delay before the first attempt to resubscribe, doubled after every failed attempt up to
+    /// `max`. 1 and 60 seconds by default.
+    pub fn retry_delay(mut self, initial: Duration, max: Duration) -> Self {
+        self.retry_delay = initial;
+        self.max_retry_delay = max;
+        self
+    }
+
+    /// How long [`subscribe_until`] waits for the subscription to be deleted on shutdown, 5
+    /// seconds by default.
+    pub fn shutdown_timeout(mut self, shutdown_timeout: Duration) -> Self {
+        self.shutdown_timeout = shutdown_timeout;
+        self
+    }
+}
+
+/// How many pulls in a row may time out before the subscription is considered lost. Devices
+/// commonly answer a long poll a bit later than requested.
+const MAX_PULL_TIMEOUTS: u32 = 2;
+
+/// Extra time the HTTP request of a pull is given on top of the pull timeout.
+pub const PULL_TIMEOUT_MARGIN: Duration = Duration::from_secs(5);
+
+/// Subscribes to events and keeps pulling them until the stream is dropped.
+///
+/// When pulling fails, e.g. because the network is down or the device rebooted and forgot the
+/// subscription, [`Notification::SubscriptionLost`] is yielded and new subscriptions are attempted
+/// with exponential backoff until one can be pulled from, which yields
+/// [`Notification::SubscriptionRestored`]. Pulls that time out are long polls the device answered
+/// late, up to two in a row; other errors, such as faults, lose the
+/// subscription.
+///
+/// `connect` creates the transport for a subscription manager address. Its HTTP timeout has to be
+/// longer than the pull timeout; the duration passed to it includes [`PULL_TIMEOUT_MARGIN`].
+///
+/// A subscription that is active when the stream is dropped is not deleted, it expires after the
+/// termination time. See [`subscribe_until`] to delete it.
+pub fn subscribe<'a, T, S, F>(
+    event_service: &'a T,
+    connect: F,
+    options: SubscriptionOptions,
+) -> impl Stream<Item = Notification> + 'a
+where
+    T: Transport,
+    S: Transport + 'a,
+    F: Fn(&Url, Duration) -> S + 'a,
+{
+    subscribe_until(event_service, connect, options, future::pending())
+}
+
+/// Like [`subscribe`], but ends the stream once `shutdown` completes, e.g. on a termination
+/// signal. The active subscription is deleted first, waiting at most
+/// [`SubscriptionOptions::shutdown_timeout`] for the device to confirm.
+pub fn subscribe_until<'a, T, S, F, D>(
+    event_service: &'a T,
+    connect: F,
+    options: SubscriptionOptions,
+    shutdown: D,
+) -> impl Stream<Item = Notification> + 'a
+where
+    T: Transport,
+    S: Transport + 'a,
+    F: Fn(&Url, Duration) -> S + 'a,
+    D: Future<Output = ()> + 'a,
+{
+    stream! {
+        let mut retry_delay = options.retry_delay;
+        let mut lost = false;
+        let shutdown = shutdown.fuse();
+        pin_mut!(shutdown);
+
+        'subscriptions: loop {
+            let created = until(
+                create_pull_point_subscription(
+                    event_service,
+                    &options.filter,
+                    &options.policy,
+                    options.termination,
+                ),
+                &mut shutdown,
+            )
+            .await;
+            let subscription = match created {
+                // A subscription created meanwhile expires after the termination time.
+                None => break,
+                Some(Ok(address)) => connect(&address, options.pull_timeout + PULL_TIMEOUT_MARGIN),
+                Some(Err(error)) => {
+                    tracing::debug!("failed to subscribe: {}", error);
+                    if !lost {
+                        lost = true;
+                        yield Notification::SubscriptionLost { error: error.to_string() };
+                    }
+                    if until(tokio::time::sleep(retry_delay), &mut shutdown).await.is_none() {
+                        break;
+                    }
+                    retry_delay = (retry_delay * 2).min(options.max_retry_delay);
+                    metrics::retry("resubscribe");
+                    continue;
+                }
+            };
+
+            if options.synchronize {
+                if let Err(error) = set_synchronization_point(&subscription).await {
+                    tracing::debug!("failed to set synchronization point: {}", error);
+                }
+            }
+
+            let mut timeouts = 0;
+            let error = loop {
+                let pulled = until(
+                    pull_messages(&subscription, options.pull_timeout, options.message_limit),
+                    &mut shutdown,
+                )
+                .await;
+                let pulled = match pulled {
+                    Some(pulled) => pulled,
+                    None => {
+                        close(&subscription, options.shutdown_timeout).await;
+                        break 'subscriptions;
+                    }
+                };
+                match pulled {
+                    Ok(events) => {
+                        timeouts = 0;
+                        // Devices may accept subscriptions they then fault on, so only a pull
+                        // restores the stream.
+                        retry_delay = options.retry_delay;
+                        if lost {
+                            lost = false;
+                            yield Notification::SubscriptionRestored;
+                        }
+                        for event in events {
+                            yield if event.is_property_state() {
+                                Notification::PropertyState(event)
+                            } else {
+                                Notification::Event(event)
+                            };
+                        }
+                    }
+                    Err(Error::Transport(TransportError::Timeout(error)))
+                        if timeouts < MAX_PULL_TIMEOUTS =>
+                    {
+                        tracing::debug!("pull timed out: {}", error);
+                        timeouts += 1;
+                    }
+                    Err(error) => break error,
+                }
+
+                if let Err(error) = renew(&subscription, options.termination).await {
+                    tracing::debug!("failed to renew subscription: {}", error);
+                }
+            };
+
+            // The subscription may survive on the device, e.g. if only this network path failed.
+            if let Err(e) = unsubscribe(&subscription).await {
+                tracing::debug!("failed to unsubscribe lost subscription: {}", e);
+            }
+
+            if !lost {
+                lost = true;
+                yield Notification::SubscriptionLost { error: error.to_string() };
+            }
+            if until(tokio::time::sleep(retry_delay), &mut shutdown).await.is_none() {
+                break;
+            }
+            retry_delay = (retry_delay * 2).min(options.max_retry_delay);
+            metrics::retry("resubscribe");
+        }
+    }
+}
+
+/// Runs `future` unless `shutdown` completes first.
+async fn until<F, D>(future: F, shutdown: &mut D) -> Option<F::Output>
+where
+    F: Future,
+    D: Future<Output = ()> + Unpin,
+{
+    match future::select(Box::pin(future), shutdown).await {
+        Either::Left((output, _)) => Some(output),
+        Either::Right(_) => None,
+    }
+}
+
+async fn close<S: Transport>(subscription: &S, timeout: Duration) {
+    match tokio::time::timeout(timeout, unsubscribe(subscription)).await {
+        Ok(Ok(())) => tracing::debug!("unsubscribed on shutdown"),
+        Ok(Err(error)) => tracing::debug!("failed to unsubscribe on shutdown: {}", error),
+        Err(_) => tracing::debug!("unsubscribing timed out after {:?}", timeout),
+    }
+}
+
+/// Creates a pull-point subscription and returns the subscription manager address.
+///
+/// The subscription expires after `termination` unless renewed.
+pub async fn create_pull_point_subscription<T: Transport>(
+    event_service: &T,
+    filter: &Filter,
+    policy: &SubscriptionPolicy,
+    termination: Duration,
+) -> Result<Url, Error> {
+    let request = format!(
+        r#"<tev:CreatePullPointSubscription xmlns:tev="{}" xmlns:wsnt="{}">{}<tev:InitialTerminationTime>{}</tev:InitialTerminationTime>{}</tev:CreatePullPointSubscription>"#,
+        TEV_NS,
+        WSNT_NS,
+        filter.to_xml(),
+        xs_duration(termination),
+        policy.to_xml()
+    );
+
+    let response = parse(&event_service.request(&request).await?)?;
+    let address = response
+        .get_child("SubscriptionReference")
+        .and_then(|r| r.get_child("Address"))
+        .and_then(|a| a.get_text())
+        .ok_or_else(|| Error::Response("no SubscriptionReference address".to_string()))?;
+
+    let address = Url::parse(address.trim()).map_err(|e| Error::Response(e.to_string()))?;
+    metrics::subscription_created();
+    Ok(address)
+}
+
+/// Waits up to `timeout` for events on a subscription and returns at most `limit` of them.
+/// Returns an empty list if no event arrived in time.
+pub async fn pull_messages<T: Transport>(
+    subscription: &T,
+    timeout: Duration,
+    limit: u32,
+) -> Result<Vec<Event>, Error> {
+    let request = format!(
+        r#"<tev:PullMessages xmlns:tev="{}"><tev:Timeout>{}</tev:Timeout><tev:MessageLimit>{}</tev:MessageLimit></tev:PullMessages>"#,
+        TEV_NS,
+        xs_duration(timeout),
+        limit
+    );
+
+    parse_pull_messages_response(&subscription.request(&request).await?)
+}
+
+/// Extends a subscription so that it expires `termination` from now.
+pub async fn renew<T: Transport>(subscription: &T, termination: Duration) -> Result<(), Error> {
+    let request = format!(
+        r#"<wsnt:Renew xmlns:wsnt="{}"><wsnt:TerminationTime>{}</wsnt:TerminationTime></wsnt:Renew>"#,
+        WSNT_NS,
+        xs_duration(termination)
+    );
+
+    subscription.request(&request).await?;
+    Ok(())
+}
+
+/// Asks the device to send the current state of all properties on a subscription, e.g. whether
+/// motion is being detected right now. They arrive with the next pulls as events whose
+/// [`Event::is_property_state`] is true.
+pub async fn set_synchronization_point<T: Transport>(subscription: &T) -> Result<(), Error> {
+    let request = format!(r#"<tev:SetSynchronizationPoint xmlns:tev="{}"/>"#, TEV_NS);
+
+    subscription.request(&request).await?;
+    Ok(())
+}
+
+/// Deletes a subscription.
+pub async fn unsubscribe<T: Transport>(subscription: &T) -> Result<(), Error> {
+    let request = format!(r#"<wsnt:Unsubscribe xmlns:wsnt="{}"/>"#, WSNT_NS);
+
+    subscription.request(&request).await?;
+    metrics::subscription_deleted();
+    Ok(())
+}
+
+fn parse_pull_messages_response(xml: &str) -> Result<Vec<Event>, Error> {
+    let response = parse(xml)?;
+
+    Ok(children(&response, "NotificationMessage")
+        .map(parse_notification_message)
+        .collect())
+}
+
+/// Decodes a `wsnt:NotificationMessage`, which is also how events are embedded in the metadata
+/// stream.
+pub(crate) fn parse_notification_message(notification: &Element) -> Event {
+    let mut event = Event {
+        topic: notification
+            .get_child("Topic")
+            .and_then(|t| t.get_text())
+            .map(|t| t.trim().to_string())
+            .unwrap_or_default(),
+        ..Default::default()
+    };
+
+    // wsnt:Message wraps the ONVIF tt:Message.
+    if let Some(message) = notification
+        .get_child("Message")
+        .and_then(|m| m.get_child("Message"))
+    {
+        event.utc_time = message.attributes.get("UtcTime").cloned();
+        event.property_operation = message.attributes.get("PropertyOperation").cloned();
+        event.source = simple_items(message, "Source");
+        event.key = simple_items(message, "Key");
+        event.data = simple_items(message, "Data");
+        if let Some(data) = message.get_child("Data") {
+            event.objects = children(data, "ElementItem")
+                .filter_map(|item| item.get_child("Object"))
+                .map(metadata::parse_object)
+                .collect();
+            event.temperatures = children(data, "ElementItem")
+                .filter_map(|item| {
+                    item.children.iter().find_map(|node| match node {
+                        XMLNode::Element(content) => radiometry::parse_reading(content),
+                        _ => None,
+                    })
+                })
+                .collect();
+        }
+    }
+
+    event
+}
+
+fn simple_items(message: &Element, name: &str) -> BTreeMap<String, String> {
+    message
+        .get_child(name)
+        .map(|items| {
+            children(items, "SimpleItem")
+                .filter_map(|item| {
+                    Some((
+                        item.attributes.get("Name")?.clone(),
+                        item.attributes.get("Value")?.clone(),
+                    ))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn children<'a>(element: &'a Element, name: &'a str) -> impl Iterator<Item = &'a Element> + 'a {
+    element.children.iter().filter_map(move |node| match node {
+        XMLNode::Element(child) if child.name == name => Some(child),
+        _ => None,
+    })
+}
+
+fn parse(xml: &str) -> Result<Element, Error> {
+    Element::parse(xml.as_bytes()).map_err(|e| Error::Response(e.to_string()))
+}
+
+fn xs_duration(duration: Duration) -> String {
+    format!("PT{}S", duration.as_secs().max(1))
+}
+
+/// `text` as an XPath 1.0 string literal, which can't escape quotes: quoted with `"` unless it
+/// contains one, with `'` unless it contains that too, and otherwise concatenated from parts
+/// quoted either way.
+fn xpath_literal(text: &str) -> String {
+    if !text.contains('"') {
+        format!("\"{}\"", text)
+    } else if !text.contains('\'') {
+        format!("'{}'", text)
+    } else {
+        let parts: Vec<String> = text
+            .split('"')
+            .map(|part| format!("\"{}\"", part))
+            .collect();
+        format!("concat({})", parts.join(", '\"', "))
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use futures_util::StreamExt;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    #[test]
+    fn pull_messages_response() {
+        let response = r#"
+            <tev:PullMessagesResponse
+                xmlns:tev="http://www.onvif.org/ver10/events/wsdl"
+                xmlns:wsnt="http://docs.oasis-open.org/wsn/b-2"
+                xmlns:tns1="http://www.onvif.org/ver10/topics"
+                xmlns:tt="http://www.onvif.org/ver10/schema">
+                <tev:CurrentTime>2021-06-01T10:00:00Z</tev:CurrentTime>
+                <tev:TerminationTime>2021-06-01T10:01:00Z</tev:TerminationTime>
+                <wsnt:NotificationMessage>
+                    <wsnt:Topic Dialect="http://www.onvif.org/ver10/tev/topicExpression/ConcreteSet">
+                        tns1:RuleEngine/CellMotionDetector/Motion
+                    </wsnt:Topic>
+                    <wsnt:Message>
+                        <tt:Message UtcTime="2021-06-01T09:59:58Z" PropertyOperation="Changed">
+                            <tt:Source>
+                                <tt:SimpleItem Name="VideoSourceConfigurationToken" Value="00000"/>
+                                <tt:SimpleItem Name="Rule" Value="MyMotionDetectorRule"/>
+                            </tt:Source>
+                            <tt:Data>
+                                <tt:SimpleItem Name="IsMotion" Value="true"/>
+                            </tt:Data>
+                        </tt:Message>
+                    </wsnt:Message>
+                </wsnt:NotificationMessage>
+                <wsnt:NotificationMessage>
+                    <wsnt:Topic>tns1:Device/Trigger/DigitalInput</wsnt:Topic>
+                    <wsnt:Message>
+                        <tt:Message UtcTime="2021-06-01T09:59:59Z">
+                            <tt:Data>
+                                <tt:SimpleItem Name="LogicalState" Value="false"/>
+                            </tt:Data>
+                        </tt:Message>
+                    </wsnt:Message>
+                </wsnt:NotificationMessage>
+            </tev:PullMessagesResponse>
+            "#;
+
+        let events = parse_pull_messages_response(response).unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].topic, "tns1:RuleEngine/CellMotionDetector/Motion");
+        assert_eq!(events[0].utc_time.as_deref(), Some("2021-06-01T09:59:58Z"));
+        assert_eq!(events[0].property_operation.as_deref(), Some("Changed"));
+        assert_eq!(events[0].source["Rule"], "MyMotionDetectorRule");
+        assert_eq!(events[0].data["IsMotion"], "true");
+        assert!(events[0].key.is_empty());
+        assert_eq!(events[1].topic, "tns1:Device/Trigger/DigitalInput");
+        assert_eq!(events[1].property_operation, None);
+        assert!(!events[0].is_property_state());
+        assert!(Event {
+            property_operation: Some("Initialized".to_string()),
+            ..Default::default()
+        }
+        .is_property_state());
+        assert_eq!(events[1].data["LogicalState"], "false");
+    }
+
+    #[test]
+    fn object_in_event() {
+        let response = r#"
+            <tev:PullMessagesResponse
+                xmlns:tev="http://www.onvif.org/ver10/events/wsdl"
+                xmlns:wsnt="http://docs.oasis-open.org/wsn/b-2"
+                xmlns:tt="http://www.onvif.org/ver10/schema">
+                <wsnt:NotificationMessage>
+                    <wsnt:Topic>tns1:RuleEngine/MyObjectDetector/ObjectDetected</wsnt:Topic>
+                    <wsnt:Message>
+                        <tt:Message UtcTime="2021-06-01T09:59:58Z">
+                            <tt:Data>
+                                <tt:ElementItem Name="Object">
+                                    <tt:Object ObjectId="5">
+                                        <tt:Appearance>
+                                            <tt:Class><tt:Type Likelihood="0.8">Human</tt:Type></tt:Class>
+                                        </tt:Appearance>
+                                    </tt:Object>
+                                </tt:ElementItem>
+                            </tt:Data>
+                        </tt:Message>
+                    </wsnt:Message>
+                </wsnt:NotificationMessage>
+            </tev:PullMessagesResponse>
+            "#;
+
+        let events = parse_pull_messages_response(response).unwrap();
+
+        assert_eq!(events[0].objects.len(), 1);
+        assert_eq!(events[0].objects[0].object_id, Some(5));
+        assert_eq!(events[0].objects[0].most_likely_class(), Some("Human"));
+    }
+
+    #[test]
+    fn detected_sound() {
+        let mut event = Event {
+            topic: "ns0:AudioAnalytics/Audio/DetectedSound".to_string(),
+            ..Default::default()
+        };
+        event.source.insert(
+            "AudioSourceConfigurationToken".to_string(),
+            "audio".to_string(),
+        );
+        event
+            .source
+            .insert("Rule".to_string(), "LoudNoise".to_string());
+        event
+            .data
+            .insert("IsSoundDetected".to_string(), "true".to_string());
+
+        assert_eq!(
+            event.detected_sound(),
+            Some(DetectedSound {
+                audio_source_configuration: Some("audio".to_string()),
+                audio_analytics_configuration: None,
+                rule: Some("LoudNoise".to_string()),
+                is_sound_detected: true,
+            })
+        );
+        event.topic = "tns1:RuleEngine/CellMotionDetector/Motion".to_string();
+        assert_eq!(event.detected_sound(), None);
+    }
+
+    #[test]
+    fn access_events() {
+        let mut event = Event {
+            topic: "tns1:AccessControl/Denied/CredentialNotFound/Card".to_string(),
+            ..Default::default()
+        };
+        event
+            .source
+            .insert("AccessPointToken".to_string(), "entrance".to_string());
+        event
+            .data
+            .insert("Card".to_string(), "00181B01".to_string());
+        assert_eq!(
+            event.access(),
+            Some(AccessEvent::Denied(AccessAttempt {
+                access_point: Some("entrance".to_string()),
+                reason: Some("CredentialNotFound".to_string()),
+                card: Some("00181B01".to_string()),
+                ..Default::default()
+            }))
+        );
+
+        event.topic = "ns0:AccessControl/AccessGranted/Credential".to_string();
+        event
+            .data
+            .insert("CredentialToken".to_string(), "credential_1".to_string());
+        assert!(matches!(
+            event.access(),
+            Some(AccessEvent::Granted(AccessAttempt { credential: Some(c), .. })) if c == "credential_1"
+        ));
+
+        let mut door = Event {
+            topic: "tns1:Door/State/DoorPhysicalState".to_string(),
+            ..Default::default()
+        };
+        door.source
+            .insert("DoorToken".to_string(), "front".to_string());
+        door.data.insert("State".to_string(), "Open".to_string());
+        assert_eq!(
+            door.access(),
+            Some(AccessEvent::DoorPhysicalState {
+                door: Some("front".to_string()),
+                state: "Open".to_string(),
+            })
+        );
+
+        door.topic = "tns1:AccessControl/AccessGrantedElsewhere".to_string();
+        assert_eq!(door.access(), None);
+    }
+
+    #[test]
+    fn motion_and_tamper() {
+        let mut event = Event {
+            topic: "ns:VideoSource/MotionAlarm".to_string(),
+            ..Default::default()
+        };
+        event
+            .source
+            .insert("Source".to_string(), "video".to_string());
+        event.data.insert("State".to_string(), "true".to_string());
+
+        assert_eq!(event.is_motion(), Some(true));
+        assert_eq!(event.is_tamper(), None);
+        assert_eq!(event.video_source(), Some("video"));
+
+        event.topic = "tns1:RuleEngine/TamperDetector/Tamper".to_string();
+        assert_eq!(event.is_tamper(), None);
+        event
+            .data
+            .insert("IsTamper".to_string(), "false".to_string());
+        assert_eq!(event.is_tamper(), Some(false));
+        assert_eq!(event.is_motion(), None);
+    }
+
+    #[test]
+    fn empty_pull_messages_response() {
+        let response = r#"
+            <tev:PullMessagesResponse xmlns:tev="http://www.onvif.org/ver10/events/wsdl">
+                <tev:CurrentTime>2021-06-01T10:00:00Z</tev:CurrentTime>
+                <tev:TerminationTime>2021-06-01T10:01:00Z</tev:TerminationTime>
+            </tev:PullMessagesResponse>
+            "#;
+
+        assert!(parse_pull_messages_response(response).unwrap().is_empty());
+    }
+
+    #[test]
+    fn filter_and_policy() {
+        assert_eq!(Filter::default().to_xml(), "");
+        assert_eq!(SubscriptionPolicy::default().to_xml(), "");
+
+        let filter = Filter::default()
+            .topic("tns1:RuleEngine//.")
+            .source_item("VideoSourceConfigurationToken", "1")
+            .message_content(r#"boolean(//tt:SimpleItem[@Name="Rule" and @Value="R<1>"])"#);
+        assert_eq!(
+            filter.to_xml(),
+            concat!(
+                r#"<tev:Filter>"#,
+                r#"<wsnt:TopicExpression Dialect="http://www.onvif.org/ver10/tev/topicExpression/ConcreteSet" xmlns:tns1="http://www.onvif.org/ver10/topics">tns1:RuleEngine//.</wsnt:TopicExpression>"#,
+                r#"<wsnt:MessageContent Dialect="http://www.onvif.org/ver10/tev/messageContentFilter/ItemFilter" xmlns:tt="http://www.onvif.org/ver10/schema">"#,
+                r#"boolean(//tt:Source/tt:SimpleItem[@Name="VideoSourceConfigurationToken" and @Value="1"]) and boolean(//tt:SimpleItem[@Name="Rule" and @Value="R&lt;1&gt;"])"#,
+                r#"</wsnt:MessageContent>"#,
+                r#"</tev:Filter>"#
+            )
+        );
+        assert_eq!(
+            SubscriptionPolicy::default().changed_only(true).to_xml(),
+            "<tev:SubscriptionPolicy><tev:ChangedOnly/></tev:SubscriptionPolicy>"
+        );
+    }
+
+    #[test]
+    fn quotes_source_items() {
+        let content = |value| {
+            Filter::default()
+                .source_item("Token", value)
+                .message_content
+        };
+        assert_eq!(
+            content(r#"a"b"#),
+            vec![r#"boolean(//tt:Source/tt:SimpleItem[@Name="Token" and @Value='a"b'])"#]
+        );
+        assert_eq!(
+            content(r#"it's "b""#),
+            vec![concat!(
+                r#"boolean(//tt:Source/tt:SimpleItem[@Name="Token" and "#,
+                r#"@Value=concat("it's ", '"', "b", '"', "")])"#
+            )]
+        );
+    }
+
+    struct FakeEventService;
+
+    #[async_trait]
+    impl Transport for FakeEventService {
+        async fn request(&self, _message: &str) -> Result<String, TransportError> {
+            Ok(r#"
+                <tev:CreatePullPointSubscriptionResponse
+                    xmlns:tev="http://www.onvif.org/ver10/events/wsdl"
+                    xmlns:wsa="http://www.w3.org/2005/08/addressing">
+                    <tev:SubscriptionReference>
+                        <wsa:Address>http://192.168.0.2/onvif/subscription</wsa:Address>
+                    </tev:SubscriptionReference>
+                </tev:CreatePullPointSubscriptionResponse>
+                "#
+            .to_string())
+        }
+    }
+
+    /// Answers pulls with one event each, except that the network is down for the second pull.
+    struct FakeSubscription(Arc<AtomicUsize>);
+
+    #[async_trait]
+    impl Transport for FakeSubscription {
+        async fn request(&self, message: &str) -> Result<String, TransportError> {
+            if !message.contains("PullMessages") {
+                return Ok("<Response/>".to_string());
+            }
+            match self.0.fetch_add(1, Ordering::SeqCst) {
+                0 => Err(TransportError::Timeout("timed out".to_string())),
+                2 => Err(TransportError::Connection("unreachable".to_string())),
+                _ => Ok(r#"
+                    <tev:PullMessagesResponse
+                        xmlns:tev="http://www.onvif.org/ver10/events/wsdl"
+                        xmlns:wsnt="http://docs.oasis-open.org/wsn/b-2">
+                        <wsnt:NotificationMessage>
+                            <wsnt:Topic>tns1:Device/Trigger/DigitalInput</wsnt:Topic>
+                        </wsnt:NotificationMessage>
+                    </tev:PullMessagesResponse>
+                    "#
+                .to_string()),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn subscription_survives_network_failure() {
+        let pulls = Arc::new(AtomicUsize::new(0));
+        let options = SubscriptionOptions::default()
+            .retry_delay(Duration::from_millis(1), Duration::from_millis(1));
+        let notifications = subscribe(
+            &FakeEventService,
+            |_, _| FakeSubscription(pulls.clone()),
+            options,
+        );
+
+        let notifications: Vec<_> = notifications.take(4).collect().await;
+
+        let event = Notification::Event(Event {
+            topic: "tns1:Device/Trigger/DigitalInput".to_string(),
+            ..Default::default()
+        });
+        assert_eq!(
+            notifications,
+            vec![
+                event.clone(),
+                Notification::SubscriptionLost {
+                    error: "Transport error: Connection error: unreachable".to_string()
+                },
+                Notification::SubscriptionRestored,
+                event,
+            ]
+        );
+    }
+
+    /// Accepts the first `accepts` subscriptions, then never answers.
+    struct CountingEventService {
+        accepts: usize,
+        created: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl Transport for CountingEventService {
+        async fn request(&self, message: &str) -> Result<String, TransportError> {
+            if self.created.fetch_add(1, Ordering::SeqCst) >= self.accepts {
+                future::pending::<()>().await;
+            }
+            FakeEventService.request(message).await
+        }
+    }
+
+    /// Faults on every pull.
+    struct FaultySubscription;
+
+    #[async_trait]
+    impl Transport for FaultySubscription {
+        async fn request(&self, message: &str) -> Result<String, TransportError> {
+            if !message.contains("PullMessages") {
+                return Ok("<Response/>".to_string());
+            }
+            Err(TransportError::Fault(schema::transport::Fault::new(
+                vec!["env:Receiver".to_string()],
+                Some("Internal error".to_string()),
+            )))
+        }
+    }
+
+    #[tokio::test]
+    async fn backs_off_when_pulls_fail() {
+        tokio::time::pause();
+        let event_service = CountingEventService {
+            accepts: 100,
+            created: AtomicUsize::new(0),
+        };
+        let options = SubscriptionOptions::default()
+            .retry_delay(Duration::from_secs(1), Duration::from_secs(60));
+        let notifications = subscribe(&event_service, |_, _| FaultySubscription, options);
+        pin_mut!(notifications);
+
+        assert!(matches!(
+            notifications.next().await,
+            Some(Notification::SubscriptionLost { .. })
+        ));
+        // Subscriptions at 0, 1, 3 and 7 seconds, without a restore in between.
+        assert!(
+            tokio::time::timeout(Duration::from_secs(10), notifications.next())
+                .await
+                .is_err()
+        );
+        assert_eq!(event_service.created.load(Ordering::SeqCst), 4);
+    }
+
+    /// Answers the first pull with an event and never answers the following ones.
+    #[derive(Clone, Default)]
+    struct StalledSubscription(Arc<std::sync::Mutex<Vec<String>>>);
+
+    #[async_trait]
+    impl Transport for StalledSubscription {
+        async fn request(&self, message: &str) -> Result<String, TransportError> {
+            let pulls = {
+                let mut requests = self.0.lock().unwrap();
+                requests.push(message.to_string());
+                requests
+                    .iter()
+                    .filter(|r| r.contains("PullMessages"))
+                    .count()
+            };
+            if message.contains("PullMessages") && pulls > 1 {
+                future::pending::<()>().await;
+            }
+            FakeSubscription(Arc::new(AtomicUsize::new(1)))
+                .request(message)
+                .await
+        }
+    }
+
+    #[tokio::test]
+    async fn unsubscribes_on_shutdown() {
+        let subscription = StalledSubscription::default();
+        let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+        let notifications = subscribe_until(
+            &FakeEventService,
+            |_, _| subscription.clone(),
+            SubscriptionOptions::default(),
+            stopped.map(|_| ()),
+        );
+        pin_mut!(notifications);
+
+        assert!(matches!(
+            notifications.next().await,
+            Some(Notification::Event(_))
+        ));
+        stop.send(()).unwrap();
+        assert_eq!(notifications.next().await, None);
+
+        let requests = subscription.0.lock().unwrap();
+        assert!(requests.last().unwrap().contains("wsnt:Unsubscribe"));
+    }
+}
@@ -0,0 +1,238 @@
+//! Debounced boolean states of motion and tamper sensors, e.g. for automations that switch a
+//! light on motion.
+//!
+//! Devices report motion as it starts and stops, often flickering between the two while
+//! something moves. A state turns on with the first event and only turns off once no motion
+//! was reported for the off delay.
+//!
+//! ```no_run
+//! # async fn example(event_service: onvif::soap::client::Client) {
+//! use onvif::{
+//!     event::{
+//!         self,
+//!         sensor::{Sensor, Sensors},
+//!     },
+//!     soap::client::ClientBuilder,
+//! };
+//! use std::time::Duration;
+//!
+//! let sensors = Sensors::new(Duration::from_secs(30));
+//! let mut motion = sensors.watch(Sensor::Motion, "VideoSourceConfig_1");
+//! tokio::spawn(async move {
+//!     while motion.changed().await.is_ok() {
+//!         println!("motion: {}", *motion.borrow());
+//!     }
+//! });
+//!
+//! let credentials = event_service.credentials();
+//! let notifications = event::subscribe(
+//!     &event_service,
+//!     |address, timeout| {
+//!         ClientBuilder::new(address)
+//!             .credentials(credentials.clone())
+//!             .timeout(timeout)
+//!             .build()
+//!     },
+//!     event::SubscriptionOptions::default().synchronize(true),
+//! );
+//! sensors.run(notifications).await;
+//! # }
+//! ```
+
+use super::{Event, Notification};
+use futures_core::stream::Stream;
+use futures_util::{
+    future::{self, Either},
+    pin_mut, StreamExt,
+};
+use std::{
+    collections::BTreeMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+use tokio::sync::watch;
+
+/// Kind of a sensor, see [`Event::is_motion`] and [`Event::is_tamper`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Sensor {
+    Motion,
+    Tamper,
+}
+
+/// States of the sensors of a device, by kind and video source.
+#[derive(Debug)]
+pub struct Sensors {
+    off_delay: Duration,
+    states: Mutex<BTreeMap<(Sensor, String), State>>,
+}
+
+#[derive(Debug)]
+struct State {
+    sender: watch::Sender<bool>,
+    // Kept so that sending never fails for lack of receivers.
+    receiver: watch::Receiver<bool>,
+    off_at: Option<Instant>,
+}
+
+impl State {
+    fn new() -> Self {
+        let (sender, receiver) = watch::channel(false);
+        Self {
+            sender,
+            receiver,
+            off_at: None,
+        }
+    }
+
+    fn set(&mut self, on: bool) {
+        if *self.receiver.borrow() != on {
+            let _ = self.sender.send(on);
+        }
+    }
+}
+
+impl Sensors {
+    /// Sensors that turn off once they reported no detection for `off_delay`.
+    pub fn new(off_delay: Duration) -> Self {
+        Self {
+            off_delay,
+            states: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// State of the sensor of `kind` for `source`, off until an event says otherwise.
+    ///
+    /// `source` is the token of [`Event::video_source`], or empty for events without one.
+    pub fn watch(&self, kind: Sensor, source: &str) -> watch::Receiver<bool> {
+        self.states
+            .lock()
+            .unwrap()
+            .entry((kind, source.to_string()))
+            .or_insert_with(State::new)
+            .receiver
+            .clone()
+    }
+
+    /// Sensors that received events or were watched so far.
+    pub fn sources(&self) -> Vec<(Sensor, String)> {
+        self.states.lock().unwrap().keys().cloned().collect()
+    }
+
+    /// Updates the states from `notifications`, e.g. of [`super::subscribe`], until the stream
+    /// ends. States stay as they are while the subscription is lost.
+    pub async fn run<S>(&self, notifications: S)
+    where
+        S: Stream<Item = Notification>,
+    {
+        pin_mut!(notifications);
+        loop {
+            let deadline = self.next_off();
+            let timer = async {
+                match deadline {
+                    Some(deadline) => tokio::time::sleep_until(deadline.into()).await,
+                    None => future::pending().await,
+                }
+            };
+            pin_mut!(timer);
+
+            match future::select(notifications.next(), timer).await {
+                Either::Left((None, _)) => return,
+                Either::Left((Some(notification), _)) => {
+                    if let Notification::Event(event) | Notification::PropertyState(event) =
+                        notification
+                    {
+                        self.update(&event, Instant::now());
+                    }
+                }
+                Either::Right(_) => {}
+            }
+            self.expire(Instant::now());
+        }
+    }
+
+    fn update(&self, event: &Event, now: Instant) {
+        let (kind, on) = match (event.is_motion(), event.is_tamper()) {
+            (Some(on), _) => (Sensor::Motion, on),
+            (_, Some(on)) => (Sensor::Tamper, on),
+            _ => return,
+        };
+        let source = event.video_source().unwrap_or_default().to_string();
+
+        let mut states = self.states.lock().unwrap();
+        let state = states.entry((kind, source)).or_insert_with(State::new);
+        if on {
+            state.off_at = None;
+            state.set(true);
+        } else if *state.receiver.borrow() && state.off_at.is_none() {
+            // Later off events don't postpone turning off.
+            state.off_at = Some(now + self.off_delay);
+        }
+        drop(states);
+        self.expire(now);
+    }
+
+    fn expire(&self, now: Instant) {
+        for state in self.states.lock().unwrap().values_mut() {
+            if matches!(state.off_at, Some(off_at) if off_at <= now) {
+                state.off_at = None;
+                state.set(false);
+            }
+        }
+    }
+
+    fn next_off(&self) -> Option<Instant> {
+        self.states
+            .lock()
+            .unwrap()
+            .values()
+            .filter_map(|state| state.off_at)
+            .min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn motion(source: &str, is_motion: bool) -> Event {
+        let mut event = Event {
+            topic: "tns1:RuleEngine/CellMotionDetector/Motion".to_string(),
+            ..Default::default()
+        };
+        event.source.insert(
+            "VideoSourceConfigurationToken".to_string(),
+            source.to_string(),
+        );
+        event
+            .data
+            .insert("IsMotion".to_string(), is_motion.to_string());
+        event
+    }
+
+    #[test]
+    fn debounces_off() {
+        let sensors = Sensors::new(Duration::from_secs(10));
+        let first = sensors.watch(Sensor::Motion, "1");
+        let start = Instant::now();
+
+        sensors.update(&motion("1", true), start);
+        assert!(*first.borrow());
+        sensors.update(&motion("1", false), start + Duration::from_secs(1));
+        sensors.expire(start + Duration::from_secs(5));
+        assert!(*first.borrow());
+
+        // Motion before the off delay passed keeps the state on.
+        sensors.update(&motion("1", true), start + Duration::from_secs(6));
+        sensors.update(&motion("1", false), start + Duration::from_secs(7));
+        sensors.expire(start + Duration::from_secs(12));
+        assert!(*first.borrow());
+        sensors.expire(start + Duration::from_secs(17));
+        assert!(!*first.borrow());
+
+        // Sources have separate states, created by their first event.
+        sensors.update(&motion("2", true), start);
+        assert!(*sensors.watch(Sensor::Motion, "2").borrow());
+        assert!(!*sensors.watch(Sensor::Tamper, "2").borrow());
+        assert_eq!(sensors.sources().len(), 3);
+    }
+}
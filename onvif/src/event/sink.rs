@@ -0,0 +1,209 @@
+//! Persistence of notifications, e.g. for durable event logs.
+//!
+//! [`store`] writes the notifications of a subscription to an [`EventSink`], tagged with the
+//! device they came from. [`JsonLines`] appends them to a file, one JSON object per line, and
+//! [`RingBuffer`] keeps the latest ones in memory.
+//!
+//! ```no_run
+//! # async fn example(event_service: onvif::soap::client::Client) {
+//! use onvif::{
+//!     event::{self, sink::JsonLines},
+//!     soap::client::ClientBuilder,
+//! };
+//!
+//! let credentials = event_service.credentials();
+//! let notifications = event::subscribe(
+//!     &event_service,
+//!     |address, timeout| {
+//!         ClientBuilder::new(address)
+//!             .credentials(credentials.clone())
+//!             .timeout(timeout)
+//!             .build()
+//!     },
+//!     Default::default(),
+//! );
+//! let mut log = JsonLines::open("events.jsonl").unwrap();
+//! event::sink::store("camera", notifications, &mut log)
+//!     .await
+//!     .unwrap();
+//! # }
+//! ```
+
+use super::Notification;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures_core::stream::Stream;
+use futures_util::{pin_mut, StreamExt};
+use serde::Serialize;
+use std::{
+    collections::VecDeque,
+    fs::{File, OpenOptions},
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+/// A notification and the device it came from.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DeviceEvent {
+    /// Identifier the device was given to [`store`] with.
+    pub device: String,
+
+    /// Local time the notification was received, as device clocks may be off.
+    pub received: DateTime<Utc>,
+
+    #[serde(flatten)]
+    pub notification: Notification,
+}
+
+/// Where [`store`] writes notifications, e.g. files or a database.
+#[async_trait]
+pub trait EventSink: Send {
+    async fn store(&mut self, event: DeviceEvent) -> io::Result<()>;
+}
+
+/// Stores `notifications`, e.g. of [`super::subscribe`], until the stream ends or the sink fails.
+pub async fn store<S, K>(device: &str, notifications: S, sink: &mut K) -> io::Result<()>
+where
+    S: Stream<Item = Notification>,
+    K: EventSink + ?Sized,
+{
+    pin_mut!(notifications);
+    while let Some(notification) = notifications.next().await {
+        sink.store(DeviceEvent {
+            device: device.to_string(),
+            received: Utc::now(),
+            notification,
+        })
+        .await?;
+    }
+    Ok(())
+}
+
+/// Appends events to a file as JSON lines. Each line is flushed as it is written, so that the log
+/// is complete up to the last event if the process dies.
+#[derive(Debug)]
+pub struct JsonLines {
+    writer: BufWriter<File>,
+}
+
+impl JsonLines {
+    /// Opens `path` for appending, creating it if needed.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            writer: BufWriter::new(file),
+        })
+    }
+}
+
+#[async_trait]
+impl EventSink for JsonLines {
+    async fn store(&mut self, event: DeviceEvent) -> io::Result<()> {
+        serde_json::to_writer(&mut self.writer, &event)?;
+        self.writer.write_all(b"\n")?;
+        self.writer.flush()
+    }
+}
+
+/// Keeps the latest events in memory, e.g. to show recent activity.
+#[derive(Clone, Debug)]
+pub struct RingBuffer {
+    events: VecDeque<DeviceEvent>,
+    capacity: usize,
+}
+
+impl RingBuffer {
+    /// Keeps up to `capacity` events, dropping the oldest ones.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            events: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Stored events, oldest first.
+    pub fn events(&self) -> impl Iterator<Item = &DeviceEvent> {
+        self.events.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+#[async_trait]
+impl EventSink for RingBuffer {
+    async fn store(&mut self, event: DeviceEvent) -> io::Result<()> {
+        if self.capacity == 0 {
+            return Ok(());
+        }
+        if self.events.len() == self.capacity {
+            self.events.pop_front();
+        }
+        self.events.push_back(event);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::Event;
+
+    fn notifications() -> impl Stream<Item = Notification> {
+        futures_util::stream::iter(vec![
+            Notification::SubscriptionLost {
+                error: "refused".to_string(),
+            },
+            Notification::SubscriptionRestored,
+            Notification::Event(Event {
+                topic: "tns1:VideoSource/MotionAlarm".to_string(),
+                ..Default::default()
+            }),
+        ])
+    }
+
+    #[tokio::test]
+    async fn keeps_latest_events() {
+        let mut buffer = RingBuffer::new(2);
+        store("camera", notifications(), &mut buffer).await.unwrap();
+
+        assert_eq!(buffer.len(), 2);
+        let events: Vec<_> = buffer.events().collect();
+        assert_eq!(events[0].device, "camera");
+        assert_eq!(events[0].notification, Notification::SubscriptionRestored);
+        assert!(matches!(
+            &events[1].notification,
+            Notification::Event(event) if event.topic == "tns1:VideoSource/MotionAlarm"
+        ));
+    }
+
+    #[tokio::test]
+    async fn appends_json_lines() {
+        let path =
+            std::env::temp_dir().join(format!("onvif-events-{}.jsonl", uuid::Uuid::new_v4()));
+        for _ in 0..2 {
+            let mut log = JsonLines::open(&path).unwrap();
+            store("camera", notifications(), &mut log).await.unwrap();
+        }
+
+        let lines: Vec<serde_json::Value> = std::fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines[0]["device"], "camera");
+        assert_eq!(lines[0]["type"], "subscription_lost");
+        assert_eq!(lines[0]["error"], "refused");
+        assert_eq!(lines[2]["type"], "event");
+        assert_eq!(lines[2]["topic"], "tns1:VideoSource/MotionAlarm");
+        assert!(lines[2]["received"].is_string());
+    }
+}
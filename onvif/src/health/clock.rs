@@ -0,0 +1,336 @@
+//! Clock drift monitoring across a fleet.
+//!
+//! Device clocks drifting away silently break WS-UsernameToken authentication, which devices
+//! reject when its timestamp is a few seconds off, and the timestamps of recordings. A
+//! [`DriftMonitor`] samples the clocks of devices at an interval and reports those drifting
+//! beyond a threshold, optionally setting them to the local time.
+//!
+//! ```no_run
+//! # async fn example(cameras: Vec<(String, onvif::soap::client::Client)>) {
+//! use futures_util::{pin_mut, StreamExt};
+//! use onvif::health::clock::DriftMonitor;
+//! use std::time::Duration;
+//!
+//! let monitor = DriftMonitor::new(Duration::from_secs(3600))
+//!     .threshold(Duration::from_secs(2))
+//!     .auto_correct(true);
+//! let alerts = monitor.run(&cameras);
+//! pin_mut!(alerts);
+//! while let Some(alert) = alerts.next().await {
+//!     println!("{} is {} s off: {:?}", alert.device, alert.drift, alert.correction);
+//! }
+//! # }
+//! ```
+
+use super::to_chrono;
+use async_stream::stream;
+use chrono::{Datelike, Timelike, Utc};
+use futures_core::stream::Stream;
+use futures_util::future;
+use schema::{
+    devicemgmt, onvif as tt,
+    transport::{Error as TransportError, Transport},
+};
+use std::time::Duration;
+use tokio::time::{self, Instant};
+
+/// A sample of a device clock.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClockReading {
+    /// Device clock minus local clock, in seconds. `None` if the device doesn't report its UTC
+    /// time.
+    pub drift: Option<i64>,
+
+    /// Whether the device sets its clock through NTP.
+    pub ntp: bool,
+
+    pub daylight_savings: bool,
+
+    /// POSIX 1003.1 time zone of the device, if it reports one.
+    pub time_zone: Option<String>,
+
+    /// Round trip time of `GetSystemDateAndTime`.
+    pub latency: Duration,
+}
+
+/// Reads the clock of a device with `GetSystemDateAndTime`, which doesn't require
+/// authentication, so it also works when drift already made it fail.
+pub async fn read_clock<T: Transport>(device_service: &T) -> Result<ClockReading, TransportError> {
+    let start = Instant::now();
+    let sent_at = Utc::now();
+    let date_time = devicemgmt::get_system_date_and_time(device_service, &Default::default())
+        .await?
+        .system_date_and_time;
+    let latency = start.elapsed();
+
+    // The device read its clock somewhere during the round trip; assume the middle.
+    let drift = match (
+        date_time.utc_date_time.as_ref().and_then(to_chrono),
+        chrono::Duration::from_std(latency / 2),
+    ) {
+        (Some(device), Ok(half)) => Some((device - (sent_at + half).naive_utc()).num_seconds()),
+        _ => None,
+    };
+
+    Ok(ClockReading {
+        drift,
+        ntp: date_time.date_time_type == tt::SetDateTimeType::Ntp,
+        daylight_savings: date_time.daylight_savings,
+        time_zone: date_time.time_zone.map(|time_zone| time_zone.tz),
+        latency,
+    })
+}
+
+/// Sets the clock of a device to the local time, manually, keeping its time zone and daylight
+/// savings setting. Devices using NTP stop doing so.
+pub async fn set_clock<T: Transport>(
+    device_service: &T,
+    reading: &ClockReading,
+) -> Result<(), TransportError> {
+    let now = Utc::now();
+    devicemgmt::set_system_date_and_time(
+        device_service,
+        &devicemgmt::SetSystemDateAndTime {
+            date_time_type: tt::SetDateTimeType::Manual,
+            daylight_savings: reading.daylight_savings,
+            time_zone: reading
+                .time_zone
+                .as_ref()
+                .map(|tz| tt::TimeZone { tz: tz.clone() }),
+            utc_date_time: Some(tt::DateTime {
+                time: tt::Time {
+                    hour: now.hour() as i32,
+                    minute: now.minute() as i32,
+                    second: now.second() as i32,
+                },
+                date: tt::Date {
+                    year: now.year(),
+                    month: now.month() as i32,
+                    day: now.day() as i32,
+                },
+            }),
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+/// A device whose clock drifted beyond the threshold of a [`DriftMonitor`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DriftAlert {
+    /// Identifier the device was given to the monitor with.
+    pub device: String,
+
+    /// Device clock minus local clock, in seconds.
+    pub drift: i64,
+
+    /// Whether the device sets its clock through NTP, in which case its NTP servers are likely
+    /// unreachable or wrong.
+    pub ntp: bool,
+
+    pub correction: Correction,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Correction {
+    /// Correction is disabled, or the device uses NTP, which correcting would turn off.
+    NotAttempted,
+
+    Corrected,
+
+    Failed(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct DriftMonitor {
+    interval: Duration,
+    threshold: Duration,
+    auto_correct: bool,
+}
+
+impl DriftMonitor {
+    /// Samples every device each `interval`.
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval: interval.max(Duration::from_millis(1)),
+            threshold: Duration::from_secs(5),
+            auto_correct: false,
+        }
+    }
+
+    /// Drift beyond which devices are reported, 5 seconds by default.
+    pub fn threshold(mut self, threshold: Duration) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Sets the clocks of drifting devices which don't use NTP with [`set_clock`]. Off by
+    /// default.
+    pub fn auto_correct(mut self, auto_correct: bool) -> Self {
+        self.auto_correct = auto_correct;
+        self
+    }
+
+    /// Samples the clock of one device, returning an alert if it drifted beyond the threshold.
+    pub async fn check<T: Transport>(
+        &self,
+        device: &str,
+        device_service: &T,
+    ) -> Result<Option<DriftAlert>, TransportError> {
+        let reading = read_clock(device_service).await?;
+        let drift = match reading.drift {
+            Some(drift) if drift.unsigned_abs() > self.threshold.as_secs() => drift,
+            _ => return Ok(None),
+        };
+
+        let correction = if self.auto_correct && !reading.ntp {
+            match set_clock(device_service, &reading).await {
+                Ok(()) => Correction::Corrected,
+                Err(e) => Correction::Failed(e.to_string()),
+            }
+        } else {
+            Correction::NotAttempted
+        };
+
+        Ok(Some(DriftAlert {
+            device: device.to_string(),
+            drift,
+            ntp: reading.ntp,
+            correction,
+        }))
+    }
+
+    /// Samples all `devices`, identified by the first element of each pair, concurrently every
+    /// interval, and yields alerts for those drifting beyond the threshold. Devices that fail to
+    /// answer are logged and skipped until the next round. Runs until dropped.
+    pub fn run<'a, T: Transport>(
+        &'a self,
+        devices: &'a [(String, T)],
+    ) -> impl Stream<Item = DriftAlert> + 'a {
+        stream! {
+            let mut next = Instant::now();
+            loop {
+                time::sleep_until(next).await;
+                let results = future::join_all(
+                    devices
+                        .iter()
+                        .map(|(device, device_service)| self.check(device, device_service)),
+                )
+                .await;
+
+                for ((device, _), result) in devices.iter().zip(results) {
+                    match result {
+                        Ok(Some(alert)) => yield alert,
+                        Ok(None) => {}
+                        Err(e) => tracing::warn!("failed to read the clock of {}: {}", device, e),
+                    }
+                }
+
+                next += self.interval;
+                let now = Instant::now();
+                while next < now {
+                    next += self.interval;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use futures_util::{pin_mut, StreamExt};
+    use std::sync::Mutex;
+
+    /// A device whose clock is stuck in 2021, or set to the local time once corrected.
+    struct FakeDevice {
+        ntp: bool,
+        set: Mutex<Option<String>>,
+    }
+
+    impl FakeDevice {
+        fn new(ntp: bool) -> Self {
+            Self {
+                ntp,
+                set: Mutex::new(None),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Transport for FakeDevice {
+        async fn request(&self, message: &str) -> Result<String, TransportError> {
+            if message.contains("SetSystemDateAndTime") {
+                *self.set.lock().unwrap() = Some(message.to_string());
+                return Ok(r#"<tds:SetSystemDateAndTimeResponse
+                    xmlns:tds="http://www.onvif.org/ver10/device/wsdl"/>"#
+                    .to_string());
+            }
+
+            let now = Utc::now();
+            let (year, month, day, hour) = if self.set.lock().unwrap().is_some() {
+                (now.year(), now.month(), now.day(), now.hour())
+            } else {
+                (2021, 6, 1, 10)
+            };
+            Ok(format!(
+                r#"
+                <tds:GetSystemDateAndTimeResponse
+                    xmlns:tds="http://www.onvif.org/ver10/device/wsdl"
+                    xmlns:tt="http://www.onvif.org/ver10/schema">
+                    <tds:SystemDateAndTime>
+                        <tt:DateTimeType>{}</tt:DateTimeType>
+                        <tt:DaylightSavings>true</tt:DaylightSavings>
+                        <tt:TimeZone><tt:TZ>CET-1CEST,M3.5.0,M10.5.0/3</tt:TZ></tt:TimeZone>
+                        <tt:UTCDateTime>
+                            <tt:Time><tt:Hour>{}</tt:Hour><tt:Minute>{}</tt:Minute><tt:Second>{}</tt:Second></tt:Time>
+                            <tt:Date><tt:Year>{}</tt:Year><tt:Month>{}</tt:Month><tt:Day>{}</tt:Day></tt:Date>
+                        </tt:UTCDateTime>
+                    </tds:SystemDateAndTime>
+                </tds:GetSystemDateAndTimeResponse>
+                "#,
+                if self.ntp { "NTP" } else { "Manual" },
+                hour,
+                now.minute(),
+                now.second(),
+                year,
+                month,
+                day
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn corrects_drift() {
+        let device = FakeDevice::new(false);
+        let monitor = DriftMonitor::new(Duration::from_secs(60)).auto_correct(true);
+
+        let alert = monitor.check("camera", &device).await.unwrap().unwrap();
+        assert!(alert.drift < 0);
+        assert!(!alert.ntp);
+        assert_eq!(alert.correction, Correction::Corrected);
+
+        let set = device.set.lock().unwrap().clone().unwrap();
+        assert!(set.contains("<tt:DateTimeType>Manual</tt:DateTimeType>"));
+        assert!(set.contains("<tt:TZ>CET-1CEST,M3.5.0,M10.5.0/3</tt:TZ>"));
+        assert!(set.contains(&format!("<tt:Year>{}</tt:Year>", Utc::now().year())));
+
+        // Seconds may tick between the fake device and the check.
+        assert_eq!(monitor.check("camera", &device).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn reports_ntp_devices() {
+        let devices = vec![("camera".to_string(), FakeDevice::new(true))];
+        let monitor = DriftMonitor::new(Duration::from_millis(1)).auto_correct(true);
+        let alerts = monitor.run(&devices);
+        pin_mut!(alerts);
+
+        let alert = alerts.next().await.unwrap();
+        assert_eq!(alert.device, "camera");
+        assert!(alert.ntp);
+        assert_eq!(alert.correction, Correction::NotAttempted);
+        assert!(devices[0].1.set.lock().unwrap().is_none());
+    }
+}
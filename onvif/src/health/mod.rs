@@ -0,0 +1,113 @@
+//! Single-call device health checks for fleet monitoring.
+
+pub mod clock;
+pub mod wait;
+
+use chrono::NaiveDate;
+use schema::{
+    devicemgmt, onvif as tt,
+    transport::{Error as TransportError, Transport},
+};
+use serde::Serialize;
+use std::time::Duration;
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct HealthReport {
+    /// Whether the device answered `GetSystemDateAndTime`.
+    pub reachable: bool,
+
+    /// Round trip time of `GetSystemDateAndTime`.
+    pub latency: Option<Duration>,
+
+    /// Device clock minus local clock, in seconds. `None` if the device doesn't report its UTC
+    /// time. WS-UsernameToken authentication fails if this is more than a few seconds off.
+    pub clock_drift: Option<i64>,
+
+    /// Whether `GetDeviceInformation`, which requires authentication, succeeded. `None` if that
+    /// couldn't be determined, e.g. because the device is unreachable.
+    pub authenticated: Option<bool>,
+
+    /// Error that made a check fail.
+    pub error: Option<String>,
+}
+
+/// Checks reachability, latency, clock drift and authentication of a device. Failures are
+/// reported in the returned report rather than as an error.
+pub async fn health_check<T: Transport>(device_service: &T) -> HealthReport {
+    let mut report = HealthReport::default();
+
+    let reading = match clock::read_clock(device_service).await {
+        Ok(reading) => reading,
+        Err(error) => {
+            report.error = Some(error.to_string());
+            return report;
+        }
+    };
+
+    report.reachable = true;
+    report.latency = Some(reading.latency);
+    report.clock_drift = reading.drift;
+
+    match devicemgmt::get_device_information(device_service, &Default::default()).await {
+        Ok(_) => report.authenticated = Some(true),
+        Err(TransportError::Authorization(error)) => {
+            report.authenticated = Some(false);
+            report.error = Some(error);
+        }
+        Err(error) => report.error = Some(error.to_string()),
+    }
+
+    report
+}
+
+fn to_chrono(date_time: &tt::DateTime) -> Option<chrono::NaiveDateTime> {
+    let date = &date_time.date;
+    let time = &date_time.time;
+    NaiveDate::from_ymd_opt(date.year, date.month as u32, date.day as u32)?.and_hms_opt(
+        time.hour as u32,
+        time.minute as u32,
+        time.second as u32,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::fake_device::FakeDevice;
+
+    fn fake_device() -> FakeDevice {
+        FakeDevice::new()
+            .respond(
+                "GetSystemDateAndTime",
+                r#"
+                <tds:GetSystemDateAndTimeResponse
+                    xmlns:tds="http://www.onvif.org/ver10/device/wsdl"
+                    xmlns:tt="http://www.onvif.org/ver10/schema">
+                    <tds:SystemDateAndTime>
+                        <tt:DateTimeType>NTP</tt:DateTimeType>
+                        <tt:DaylightSavings>false</tt:DaylightSavings>
+                        <tt:UTCDateTime>
+                            <tt:Time><tt:Hour>10</tt:Hour><tt:Minute>0</tt:Minute><tt:Second>0</tt:Second></tt:Time>
+                            <tt:Date><tt:Year>2021</tt:Year><tt:Month>6</tt:Month><tt:Day>1</tt:Day></tt:Date>
+                        </tt:UTCDateTime>
+                    </tds:SystemDateAndTime>
+                </tds:GetSystemDateAndTimeResponse>
+                "#,
+            )
+            .fail("GetDeviceInformation", || {
+                TransportError::Authorization("Unauthorized".to_string())
+            })
+    }
+
+    #[tokio::test]
+    async fn reports_drift_and_auth_failure() {
+        let report = health_check(&fake_device()).await;
+
+        assert!(report.reachable);
+        assert!(report.latency.is_some());
+        // The fake device clock is stuck in the past.
+        assert!(report.clock_drift.unwrap() < 0);
+        assert_eq!(report.authenticated, Some(false));
+        assert_eq!(report.error.as_deref(), Some("Unauthorized"));
+    }
+}
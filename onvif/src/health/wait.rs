@@ -0,0 +1,388 @@
+//! Waiting for a device to come back, e.g. after a reboot, a firmware upgrade or a change of its
+//! network configuration.
+//!
+//! ```no_run
+//! # async fn example(device_service: onvif::soap::client::Client, uri: url::Url) {
+//! use onvif::{health::wait, schema::devicemgmt, soap::client::Credentials};
+//! use std::time::Duration;
+//!
+//! devicemgmt::system_reboot(&device_service, &Default::default()).await.unwrap();
+//! let elapsed = wait::wait_for_reboot(&device_service, Duration::from_secs(300), |progress| {
+//!     println!("{:?} after {:?}", progress.state, progress.elapsed)
+//! })
+//! .await
+//! .unwrap();
+//! println!("back after {:?}", elapsed);
+//!
+//! // Or, without a client, e.g. if the address changed.
+//! wait::wait_for_device(
+//!     &uri,
+//!     Credentials::from_env(),
+//!     Duration::from_secs(300),
+//!     |_| {},
+//! )
+//! .await
+//! .unwrap();
+//! # }
+//! ```
+
+use crate::soap::client::{ClientBuilder, Credentials};
+use schema::{
+    devicemgmt,
+    transport::{Error as TransportError, Transport},
+};
+use serde::Serialize;
+use std::time::Duration;
+use thiserror::Error;
+use tokio::time::{self, Instant};
+use url::Url;
+
+/// Delay before the second poll, doubling after each poll up to [`MAX_POLL_INTERVAL`].
+const INITIAL_POLL_INTERVAL: Duration = Duration::from_secs(1);
+const MAX_POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Interval of the polls of [`wait_until_down`], which doesn't back off as devices go down soon
+/// after being asked to.
+const DOWN_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Timeout of each poll of [`wait_for_device`], shorter than the default of the client so that
+/// polls of a device that is still down don't delay noticing it's back.
+const POLL_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Why the device wasn't back before the wait ended, from the last poll.
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Device didn't answer within {0:?}: {1}")]
+    Gone(Duration, TransportError),
+
+    #[error("Device didn't finish booting within {0:?}: {1}")]
+    Booting(Duration, TransportError),
+
+    #[error("Device rejected the credentials for {0:?}: {1}")]
+    Unauthorized(Duration, TransportError),
+
+    /// The device kept answering when it was expected to go down, e.g. to reboot.
+    #[error("Device didn't go down within {0:?}")]
+    StillUp(Duration),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeviceState {
+    /// Doesn't answer: connections are refused or time out.
+    Unreachable,
+
+    /// Answers, but with HTTP errors or faults, as devices do while their services start.
+    Booting,
+
+    /// Answers, but rejects the credentials. After a reboot, this may last until the device
+    /// synchronized its clock, which WS-UsernameToken authentication depends on.
+    Unauthorized,
+
+    /// Answers `GetDeviceInformation`, which requires authentication.
+    Up,
+}
+
+/// Outcome of a poll, passed to the progress callback.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Progress {
+    /// Number of polls so far, including this one.
+    pub attempt: u32,
+
+    /// Time since the wait started.
+    pub elapsed: Duration,
+
+    pub state: DeviceState,
+}
+
+/// Polls the device service at `uri` with backoff until it answers an authenticated request,
+/// for at most `max_wait`, and returns how long that took. `progress` is called after every
+/// poll.
+pub async fn wait_for_device<F>(
+    uri: &Url,
+    credentials: Option<Credentials>,
+    max_wait: Duration,
+    progress: F,
+) -> Result<Duration, Error>
+where
+    F: FnMut(&Progress),
+{
+    let device_service = ClientBuilder::new(uri)
+        .credentials(credentials)
+        .timeout(POLL_TIMEOUT)
+        .build();
+    wait_for(&device_service, max_wait, progress).await
+}
+
+/// [`wait_for_device`] with a given transport to the device service.
+pub async fn wait_for<T, F>(
+    device_service: &T,
+    max_wait: Duration,
+    mut progress: F,
+) -> Result<Duration, Error>
+where
+    T: Transport,
+    F: FnMut(&Progress),
+{
+    let start = Instant::now();
+    let mut interval = INITIAL_POLL_INTERVAL;
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        let result = poll(device_service).await;
+        let elapsed = start.elapsed();
+        let state = match &result {
+            Ok(()) => DeviceState::Up,
+            Err((state, _)) => *state,
+        };
+        progress(&Progress {
+            attempt,
+            elapsed,
+            state,
+        });
+
+        let (state, error) = match result {
+            Ok(()) => return Ok(elapsed),
+            Err(failure) => failure,
+        };
+        if elapsed >= max_wait {
+            return Err(match state {
+                DeviceState::Unreachable => Error::Gone(elapsed, error),
+                DeviceState::Unauthorized => Error::Unauthorized(elapsed, error),
+                _ => Error::Booting(elapsed, error),
+            });
+        }
+        time::sleep(interval.min(max_wait - elapsed)).await;
+        interval = (interval * 2).min(MAX_POLL_INTERVAL);
+    }
+}
+
+/// Polls the device service until it stops answering, for at most `max_wait`, and returns how
+/// long that took. Devices answer requests to reboot, or to apply settings that make them reboot,
+/// before they do, so waiting for them to answer again only makes sense once they went down.
+/// Answers with errors, e.g. while services shut down, count as down.
+pub async fn wait_until_down<T, F>(
+    device_service: &T,
+    max_wait: Duration,
+    mut progress: F,
+) -> Result<Duration, Error>
+where
+    T: Transport,
+    F: FnMut(&Progress),
+{
+    until_down(device_service, max_wait, &mut progress)
+        .await
+        .map(|(elapsed, _)| elapsed)
+}
+
+/// [`wait_until_down`], then [`wait_for`], for at most `max_wait` together. Progress is reported
+/// for both, as if they were one wait.
+pub async fn wait_for_reboot<T, F>(
+    device_service: &T,
+    max_wait: Duration,
+    mut progress: F,
+) -> Result<Duration, Error>
+where
+    T: Transport,
+    F: FnMut(&Progress),
+{
+    let (down, attempts) = until_down(device_service, max_wait, &mut progress).await?;
+    let up = wait_for(device_service, max_wait.saturating_sub(down), |p| {
+        progress(&Progress {
+            attempt: attempts + p.attempt,
+            elapsed: down + p.elapsed,
+            state: p.state,
+        })
+    })
+    .await
+    .map_err(|error| match error {
+        Error::Gone(elapsed, e) => Error::Gone(down + elapsed, e),
+        Error::Booting(elapsed, e) => Error::Booting(down + elapsed, e),
+        Error::Unauthorized(elapsed, e) => Error::Unauthorized(down + elapsed, e),
+        Error::StillUp(elapsed) => Error::StillUp(down + elapsed),
+    })?;
+    Ok(down + up)
+}
+
+/// [`wait_until_down`], also returning the number of polls.
+async fn until_down<T, F>(
+    device_service: &T,
+    max_wait: Duration,
+    progress: &mut F,
+) -> Result<(Duration, u32), Error>
+where
+    T: Transport,
+    F: FnMut(&Progress),
+{
+    let start = Instant::now();
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        let state = match poll(device_service).await {
+            Ok(()) => DeviceState::Up,
+            Err((state, _)) => state,
+        };
+        let elapsed = start.elapsed();
+        progress(&Progress {
+            attempt,
+            elapsed,
+            state,
+        });
+
+        if state != DeviceState::Up {
+            return Ok((elapsed, attempt));
+        }
+        if elapsed >= max_wait {
+            return Err(Error::StillUp(elapsed));
+        }
+        time::sleep(DOWN_POLL_INTERVAL.min(max_wait - elapsed)).await;
+    }
+}
+
+/// Asks for the time, which devices answer without authentication, then for the device
+/// information, which requires it.
+async fn poll<T: Transport>(device_service: &T) -> Result<(), (DeviceState, TransportError)> {
+    let state = |error: &TransportError| match error {
+        TransportError::Connection(_) | TransportError::Timeout(_) => DeviceState::Unreachable,
+        TransportError::Authorization(_) => DeviceState::Unauthorized,
+        _ => DeviceState::Booting,
+    };
+
+    if let Err(error) =
+        devicemgmt::get_system_date_and_time(device_service, &Default::default()).await
+    {
+        // Devices may require authentication for the time although they shouldn't.
+        if state(&error) != DeviceState::Unauthorized {
+            return Err((state(&error), error));
+        }
+    }
+    devicemgmt::get_device_information(device_service, &Default::default())
+        .await
+        .map(drop)
+        .map_err(|error| {
+            // The device answered the first request, so it's not gone.
+            match state(&error) {
+                DeviceState::Unreachable => (DeviceState::Booting, error),
+                state => (state, error),
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// A device which is up for `stays_up` polls, then unreachable for two, then faults while
+    /// booting, then rejects the credentials until its clock is set, and is then up.
+    #[derive(Default)]
+    struct RebootingDevice {
+        stays_up: u32,
+        polls: AtomicU32,
+    }
+
+    #[async_trait]
+    impl Transport for RebootingDevice {
+        async fn request(&self, message: &str) -> Result<String, TransportError> {
+            let poll = if message.contains("GetSystemDateAndTime") {
+                self.polls.fetch_add(1, Ordering::SeqCst) + 1
+            } else {
+                self.polls.load(Ordering::SeqCst)
+            };
+            match poll.checked_sub(self.stays_up).unwrap_or(0) {
+                1 | 2 => Err(TransportError::Connection("refused".to_string())),
+                3 => Err(TransportError::Protocol(
+                    "503 Service Unavailable".to_string(),
+                )),
+                4 if message.contains("GetDeviceInformation") => {
+                    Err(TransportError::Authorization("Unauthorized".to_string()))
+                }
+                _ if message.contains("GetDeviceInformation") => Ok(r#"
+                    <tds:GetDeviceInformationResponse
+                        xmlns:tds="http://www.onvif.org/ver10/device/wsdl"/>"#
+                    .to_string()),
+                _ => Ok(r#"
+                    <tds:GetSystemDateAndTimeResponse
+                        xmlns:tds="http://www.onvif.org/ver10/device/wsdl"
+                        xmlns:tt="http://www.onvif.org/ver10/schema">
+                        <tds:SystemDateAndTime>
+                            <tt:DateTimeType>NTP</tt:DateTimeType>
+                            <tt:DaylightSavings>false</tt:DaylightSavings>
+                        </tds:SystemDateAndTime>
+                    </tds:GetSystemDateAndTimeResponse>"#
+                    .to_string()),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn waits_with_backoff() {
+        time::pause();
+        let mut states = vec![];
+        let elapsed = wait_for(
+            &RebootingDevice::default(),
+            Duration::from_secs(60),
+            |progress| states.push(progress.state),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            states,
+            vec![
+                DeviceState::Unreachable,
+                DeviceState::Unreachable,
+                DeviceState::Booting,
+                DeviceState::Unauthorized,
+                DeviceState::Up,
+            ]
+        );
+        assert_eq!(elapsed, Duration::from_secs(1 + 2 + 4 + 8));
+
+        let device = RebootingDevice::default();
+        let error = wait_for(&device, Duration::from_secs(1), |_| {})
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::Gone(..)), "{}", error);
+        assert_eq!(device.polls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn waits_for_reboots_to_start() {
+        time::pause();
+        let device = RebootingDevice {
+            stays_up: 2,
+            ..Default::default()
+        };
+        let mut progress = vec![];
+        let elapsed = wait_for_reboot(&device, Duration::from_secs(60), |p| {
+            progress.push((p.attempt, p.state))
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            progress,
+            vec![
+                (1, DeviceState::Up),
+                (2, DeviceState::Up),
+                (3, DeviceState::Unreachable),
+                (4, DeviceState::Unreachable),
+                (5, DeviceState::Booting),
+                (6, DeviceState::Unauthorized),
+                (7, DeviceState::Up),
+            ]
+        );
+        assert_eq!(elapsed, Duration::from_secs(2 + 1 + 2 + 4));
+
+        let device = RebootingDevice {
+            stays_up: 100,
+            ..Default::default()
+        };
+        let error = wait_until_down(&device, Duration::from_secs(5), |_| {})
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::StillUp(..)), "{}", error);
+    }
+}
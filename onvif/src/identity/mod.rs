@@ -0,0 +1,272 @@
+//! Stable identity of devices.
+//!
+//! Addresses change with DHCP leases and network settings, but the endpoint reference of a device
+//! doesn't. It is advertised by WS-Discovery and returned by `GetEndpointReference`, usually as a
+//! UUID, though not always in the same form: `urn:uuid:...`, `uuid:...`, a bare UUID, in upper
+//! case or with braces. [`normalize`] turns all of them into `urn:uuid:` and lower case, which is
+//! what [`Device::id`](crate::discovery::Device::id) and the
+//! [registry](crate::registry::DeviceRegistry) key devices by.
+//!
+//! ```no_run
+//! # async fn example(device_service: onvif::soap::client::Client) {
+//! use onvif::identity;
+//!
+//! let id = identity::endpoint_reference(&device_service).await.unwrap();
+//! println!("{}", id);
+//! # }
+//! ```
+//!
+//! Once known, the identity can be pinned, so that a different device answering at the address,
+//! e.g. after its lease went to another one, is noticed instead of being configured:
+//!
+//! ```no_run
+//! # async fn example(uri: url::Url, id: String) {
+//! use onvif::{
+//!     identity::IdentityPin,
+//!     soap::{
+//!         client::{ClientBuilder, ConnectionEvent},
+//!         layer::TransportExt,
+//!     },
+//! };
+//!
+//! let pin = IdentityPin::new(&id);
+//! let device_service = ClientBuilder::new(&uri)
+//!     .on_connection_event({
+//!         let pin = pin.clone();
+//!         move |event| {
+//!             if let ConnectionEvent::Reconnected { .. } = event {
+//!                 pin.reverify();
+//!             }
+//!         }
+//!     })
+//!     .build();
+//! let client = device_service.clone();
+//! let device_service = device_service.with(pin.clone());
+//!
+//! // After the address changed, e.g. as rediscovered.
+//! client.set_uri(&uri);
+//! pin.reverify();
+//! # }
+//! ```
+
+use crate::soap::layer::Layer;
+use async_trait::async_trait;
+use schema::{
+    devicemgmt,
+    transport::{Error as TransportError, Transport},
+};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use thiserror::Error;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+pub use onvif_discovery::identity::{normalize, parse_uuid};
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Transport error: {0}")]
+    Transport(#[from] TransportError),
+
+    #[error("Unexpected response: {0}")]
+    Response(String),
+
+    #[error("Expected device {expected}, found {found}")]
+    DeviceIdentityChanged { expected: String, found: String },
+}
+
+/// Requests the endpoint reference of the device with `GetEndpointReference`, normalized.
+pub async fn endpoint_reference<T: Transport>(device_service: &T) -> Result<String, Error> {
+    let guid = devicemgmt::get_endpoint_reference(device_service, &Default::default())
+        .await?
+        .guid;
+    match guid.trim() {
+        "" => Err(Error::Response("empty endpoint reference".to_string())),
+        guid => Ok(normalize(guid)),
+    }
+}
+
+/// Requests the UUID of the device with `GetEndpointReference`.
+pub async fn device_uuid<T: Transport>(device_service: &T) -> Result<Uuid, Error> {
+    let guid = devicemgmt::get_endpoint_reference(device_service, &Default::default())
+        .await?
+        .guid;
+    parse_uuid(&guid)
+        .ok_or_else(|| Error::Response(format!("endpoint reference {:?} isn't a UUID", guid)))
+}
+
+/// Checks that the device answering is the one with the endpoint reference `expected`, in any of
+/// the forms [`normalize`] accepts.
+pub async fn verify<T: Transport>(device_service: &T, expected: &str) -> Result<(), Error> {
+    let expected = normalize(expected);
+    let found = endpoint_reference(device_service).await?;
+    if found == expected {
+        Ok(())
+    } else {
+        Err(Error::DeviceIdentityChanged { expected, found })
+    }
+}
+
+/// Pins the device service to the device with an endpoint reference, see [`verify`].
+///
+/// The first request verifies the identity. Later ones are sent without checking it again until
+/// [`IdentityPin::reverify`] is called, e.g. after a reconnection or a new address. Requests fail
+/// with [`TransportError::DeviceIdentityChanged`] as long as another device answers. Clones share
+/// the verification.
+#[derive(Clone, Debug)]
+pub struct IdentityPin {
+    expected: String,
+    // Bumped by `reverify`.
+    generation: Arc<AtomicUsize>,
+    // The generation last verified, locked while verifying so that concurrent requests wait for
+    // the outcome.
+    verified: Arc<Mutex<Option<usize>>>,
+}
+
+impl IdentityPin {
+    pub fn new(endpoint_reference: &str) -> Self {
+        Self {
+            expected: normalize(endpoint_reference),
+            generation: Arc::new(AtomicUsize::new(0)),
+            verified: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Verifies the identity again before the next request.
+    pub fn reverify(&self) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+impl<T: Transport + Sync> Layer<T> for IdentityPin {
+    type Transport = Pinned<T>;
+
+    fn layer(&self, inner: T) -> Pinned<T> {
+        Pinned {
+            inner,
+            pin: self.clone(),
+        }
+    }
+}
+
+pub struct Pinned<T> {
+    inner: T,
+    pin: IdentityPin,
+}
+
+#[async_trait]
+impl<T: Transport + Sync> Transport for Pinned<T> {
+    async fn request(&self, message: &str) -> Result<String, TransportError> {
+        {
+            let generation = self.pin.generation.load(Ordering::SeqCst);
+            let mut verified = self.pin.verified.lock().await;
+            if *verified != Some(generation) {
+                match verify(&self.inner, &self.pin.expected).await {
+                    Ok(()) => *verified = Some(generation),
+                    Err(Error::Transport(e)) => return Err(e),
+                    Err(Error::Response(e)) => return Err(TransportError::Protocol(e)),
+                    Err(Error::DeviceIdentityChanged { expected, found }) => {
+                        tracing::warn!("expected device {} but found {}", expected, found);
+                        return Err(TransportError::DeviceIdentityChanged { expected, found });
+                    }
+                }
+            }
+        }
+        self.inner.request(message).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{soap::layer::TransportExt, utils::fake_device::FakeDevice};
+    use std::sync::Mutex as StdMutex;
+
+    #[tokio::test]
+    async fn requests_endpoint_reference() {
+        let device = FakeDevice::new().respond(
+            "GetEndpointReference",
+            r#"
+            <tds:GetEndpointReferenceResponse
+                xmlns:tds="http://www.onvif.org/ver10/device/wsdl">
+                <tds:GUID>A1F48AC2-DC8B-11DF-B255-00408C1836B2</tds:GUID>
+            </tds:GetEndpointReferenceResponse>
+            "#,
+        );
+
+        assert_eq!(
+            endpoint_reference(&device).await.unwrap(),
+            "urn:uuid:a1f48ac2-dc8b-11df-b255-00408c1836b2"
+        );
+        assert_eq!(
+            device_uuid(&device).await.unwrap().to_string(),
+            "a1f48ac2-dc8b-11df-b255-00408c1836b2"
+        );
+    }
+
+    /// Whichever device currently answers at the address, keeping the other requests it got.
+    struct SharedAddress {
+        guid: StdMutex<&'static str>,
+        requests: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl Transport for SharedAddress {
+        async fn request(&self, message: &str) -> Result<String, TransportError> {
+            if !message.contains("GetEndpointReference") {
+                self.requests.fetch_add(1, Ordering::SeqCst);
+                return Ok("<tds:GetHostnameResponse/>".to_string());
+            }
+            Ok(format!(
+                r#"
+                <tds:GetEndpointReferenceResponse
+                    xmlns:tds="http://www.onvif.org/ver10/device/wsdl">
+                    <tds:GUID>{}</tds:GUID>
+                </tds:GetEndpointReferenceResponse>
+                "#,
+                self.guid.lock().unwrap()
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn notices_another_device_at_the_address() {
+        const CAMERA: &str = "urn:uuid:a1f48ac2-dc8b-11df-b255-00408c1836b2";
+        const OTHER: &str = "urn:uuid:5f5a69c2-e0ae-504f-829b-00abcdef0123";
+        let expected = "A1F48AC2-DC8B-11DF-B255-00408C1836B2";
+
+        let pin = IdentityPin::new(expected);
+        let device_service = SharedAddress {
+            guid: StdMutex::new(CAMERA),
+            requests: AtomicUsize::new(0),
+        }
+        .with(pin.clone());
+        device_service.request("<tds:GetHostname/>").await.unwrap();
+
+        // Unnoticed until told about the reconnection.
+        *device_service.inner.guid.lock().unwrap() = OTHER;
+        device_service.request("<tds:GetHostname/>").await.unwrap();
+        pin.reverify();
+        for _ in 0..2 {
+            match device_service.request("<tds:GetHostname/>").await {
+                Err(TransportError::DeviceIdentityChanged { expected, found }) => {
+                    assert_eq!(expected, CAMERA);
+                    assert_eq!(found, OTHER);
+                }
+                result => panic!("{:?}", result),
+            }
+        }
+        assert_eq!(device_service.inner.requests.load(Ordering::SeqCst), 2);
+        assert!(matches!(
+            verify(&device_service.inner, expected).await,
+            Err(Error::DeviceIdentityChanged { .. })
+        ));
+
+        // The camera is back.
+        *device_service.inner.guid.lock().unwrap() = CAMERA;
+        device_service.request("<tds:GetHostname/>").await.unwrap();
+        verify(&device_service.inner, expected).await.unwrap();
+    }
+}
@@ -0,0 +1,185 @@
+//! Imaging service helpers.
+
+use schema::{
+    imaging, onvif as tt,
+    transport::{Error as TransportError, Transport},
+};
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Transport error: {0}")]
+    Transport(#[from] TransportError),
+
+    #[error("Unsupported: {0}")]
+    Unsupported(String),
+}
+
+/// How [`trigger_autofocus`] got the device to focus.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Autofocus {
+    /// Focus is continuously automatic already, so nothing was changed.
+    AlreadyAuto,
+
+    /// Focus was switched to `AUTO` and back to `MANUAL` once the lens settled, which keeps the
+    /// focus it found.
+    ModeToggle,
+}
+
+/// Makes a video source focus automatically once and then stay at that focus, like the
+/// "one push" button of many cameras.
+///
+/// ONVIF has no dedicated operation for this, so the device's imaging options decide what is
+/// done, see [`Autofocus`]. `settle` is how long the lens is given to find focus.
+pub async fn trigger_autofocus<T: Transport>(
+    imaging_service: &T,
+    video_source: &tt::ReferenceToken,
+    settle: Duration,
+) -> Result<Autofocus, Error> {
+    let settings = imaging::get_imaging_settings(
+        imaging_service,
+        &imaging::GetImagingSettings {
+            video_source_token: video_source.clone(),
+        },
+    )
+    .await?
+    .imaging_settings;
+    let focus = settings
+        .focus
+        .ok_or_else(|| Error::Unsupported("video source has no focus settings".to_string()))?;
+    if focus.auto_focus_mode == tt::AutoFocusMode::Auto {
+        return Ok(Autofocus::AlreadyAuto);
+    }
+
+    let options = imaging::get_options(
+        imaging_service,
+        &imaging::GetOptions {
+            video_source_token: video_source.clone(),
+        },
+    )
+    .await?
+    .imaging_options;
+    let supports_auto = options.focus.map_or(false, |f| {
+        f.auto_focus_modes.contains(&tt::AutoFocusMode::Auto)
+    });
+    if !supports_auto {
+        return Err(Error::Unsupported(
+            "video source doesn't support automatic focus".to_string(),
+        ));
+    }
+
+    set_focus_mode(
+        imaging_service,
+        video_source,
+        &focus,
+        tt::AutoFocusMode::Auto,
+    )
+    .await?;
+    tokio::time::sleep(settle).await;
+    set_focus_mode(
+        imaging_service,
+        video_source,
+        &focus,
+        tt::AutoFocusMode::Manual,
+    )
+    .await?;
+
+    Ok(Autofocus::ModeToggle)
+}
+
+/// Changes only the focus mode, keeping the other focus settings and leaving everything else
+/// untouched.
+async fn set_focus_mode<T: Transport>(
+    imaging_service: &T,
+    video_source: &tt::ReferenceToken,
+    focus: &tt::FocusConfiguration20,
+    mode: tt::AutoFocusMode,
+) -> Result<(), Error> {
+    imaging::set_imaging_settings(
+        imaging_service,
+        &imaging::SetImagingSettings {
+            video_source_token: video_source.clone(),
+            imaging_settings: tt::ImagingSettings20 {
+                focus: Some(tt::FocusConfiguration20 {
+                    auto_focus_mode: mode,
+                    default_speed: focus.default_speed,
+                    near_limit: focus.near_limit,
+                    far_limit: focus.far_limit,
+                    extension: None,
+                    af_mode: None,
+                }),
+                ..Default::default()
+            },
+            force_persistence: false,
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::fake_device::FakeDevice;
+
+    /// Has a manual focus which can be set to autofocus.
+    fn fake_imaging() -> FakeDevice {
+        FakeDevice::new()
+            .respond(
+                "GetImagingSettings",
+                r#"<timg:GetImagingSettingsResponse
+                    xmlns:timg="http://www.onvif.org/ver20/imaging/wsdl"
+                    xmlns:tt="http://www.onvif.org/ver10/schema">
+                    <timg:ImagingSettings>
+                        <tt:Focus><tt:AutoFocusMode>MANUAL</tt:AutoFocusMode></tt:Focus>
+                    </timg:ImagingSettings>
+                </timg:GetImagingSettingsResponse>"#,
+            )
+            .respond(
+                "GetOptions",
+                r#"<timg:GetOptionsResponse
+                    xmlns:timg="http://www.onvif.org/ver20/imaging/wsdl"
+                    xmlns:tt="http://www.onvif.org/ver10/schema">
+                    <timg:ImagingOptions>
+                        <tt:Focus>
+                            <tt:AutoFocusModes>AUTO</tt:AutoFocusModes>
+                            <tt:AutoFocusModes>MANUAL</tt:AutoFocusModes>
+                        </tt:Focus>
+                    </timg:ImagingOptions>
+                </timg:GetOptionsResponse>"#,
+            )
+            .respond(
+                "SetImagingSettings",
+                r#"<timg:SetImagingSettingsResponse
+                    xmlns:timg="http://www.onvif.org/ver20/imaging/wsdl"/>"#,
+            )
+    }
+
+    #[tokio::test]
+    async fn toggles_focus_mode() {
+        let imaging = fake_imaging();
+
+        let autofocus = trigger_autofocus(
+            &imaging,
+            &tt::ReferenceToken("source".to_string()),
+            Duration::from_millis(1),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(autofocus, Autofocus::ModeToggle);
+        let set_modes: Vec<&str> = imaging
+            .requests("SetImagingSettings")
+            .iter()
+            .map(|request| {
+                if request.contains("AUTO") {
+                    "AUTO"
+                } else {
+                    "MANUAL"
+                }
+            })
+            .collect();
+        assert_eq!(set_modes, vec!["AUTO", "MANUAL"]);
+    }
+}
@@ -0,0 +1,371 @@
+//! Installation tooling, looking at snapshots to set up a camera.
+//!
+//! [`autofocus_sweep`] focuses fixed-lens cameras without autofocus: it steps the lens through
+//! its range with the provisioning service, scores a snapshot at each step with [`sharpness`]
+//! and returns the lens to the sharpest step.
+//!
+//! ```no_run
+//! # async fn example(
+//! #     provisioning_service: onvif::soap::client::Client,
+//! #     imaging_service: onvif::soap::client::Client,
+//! #     media_service: onvif::soap::client::Client,
+//! # ) {
+//! use onvif::{
+//!     install::{self, SweepOptions},
+//!     media::snapshots,
+//!     schema::onvif as tt,
+//! };
+//!
+//! let profile = tt::ReferenceToken("main".to_string());
+//! let uri = snapshots::snapshot_uri(&media_service, &profile).await.unwrap();
+//! let result = install::autofocus_sweep(
+//!     &provisioning_service,
+//!     Some(&imaging_service),
+//!     &tt::ReferenceToken("lens".to_string()),
+//!     || media_service.get(&uri),
+//!     &SweepOptions::default().steps(30),
+//! )
+//! .await
+//! .unwrap();
+//! println!("focused at step {} of {}", result.best, result.samples.len());
+//! # }
+//! ```
+
+use schema::{
+    imaging, onvif as tt, provisioning,
+    transport::{Error as TransportError, Transport},
+};
+use std::{future::Future, time::Duration};
+use thiserror::Error;
+use tokio::time;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Transport error: {0}")]
+    Transport(#[from] TransportError),
+
+    #[error("Invalid image: {0}")]
+    Image(String),
+}
+
+/// Contrast of an image as the mean squared difference between neighboring pixels of its
+/// luminance, which grows as the image gets sharper. Scores are only comparable between images
+/// of the same scene and size.
+pub fn sharpness(image: &[u8]) -> Result<f64, Error> {
+    let image = image::load_from_memory(image)
+        .map_err(|e| Error::Image(e.to_string()))?
+        .to_luma8();
+    let (width, height) = image.dimensions();
+    if width < 2 || height < 2 {
+        return Err(Error::Image(format!("{}x{} is too small", width, height)));
+    }
+
+    let luma = |x, y| f64::from(image.get_pixel(x, y).0[0]);
+    let mut sum = 0.0;
+    for y in 0..height - 1 {
+        for x in 0..width - 1 {
+            let here = luma(x, y);
+            sum += (luma(x + 1, y) - here).powi(2) + (luma(x, y + 1) - here).powi(2);
+        }
+    }
+    Ok(sum / f64::from((width - 1) * (height - 1)))
+}
+
+#[derive(Clone, Debug)]
+pub struct SweepOptions {
+    steps: u32,
+    step: Duration,
+    travel: Duration,
+    settle: Duration,
+}
+
+impl Default for SweepOptions {
+    fn default() -> Self {
+        Self {
+            steps: 20,
+            step: Duration::from_millis(200),
+            travel: Duration::from_secs(10),
+            settle: Duration::from_millis(500),
+        }
+    }
+}
+
+impl SweepOptions {
+    /// Number of steps through the focus range, 20 by default.
+    pub fn steps(mut self, steps: u32) -> Self {
+        self.steps = steps.max(1);
+        self
+    }
+
+    /// How long the lens moves for each step, 200 ms by default. The number of steps times
+    /// this should cover the focus range.
+    pub fn step(mut self, step: Duration) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// How long the lens moves to reach the far end of its range, 10 seconds by default.
+    /// Devices stop moving at their default timeout of the provisioning service, so this may
+    /// need to be at most that.
+    pub fn travel(mut self, travel: Duration) -> Self {
+        self.travel = travel;
+        self
+    }
+
+    /// How long to wait after a move for the lens and the exposure to settle before taking a
+    /// snapshot, 500 ms by default.
+    pub fn settle(mut self, settle: Duration) -> Self {
+        self.settle = settle;
+        self
+    }
+}
+
+/// A snapshot taken during a sweep.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sample {
+    /// Steps from the far end of the focus range.
+    pub step: u32,
+
+    /// Focus position from the imaging status, if the device reports it.
+    pub position: Option<f64>,
+
+    pub sharpness: f64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SweepResult {
+    /// Samples from the far end to the near end.
+    pub samples: Vec<Sample>,
+
+    /// Step of the sharpest sample, where the lens was left.
+    pub best: u32,
+
+    /// Sharpness once the lens was back at the best step, which is lower than that of the
+    /// sample if the lens doesn't return to the same position reliably.
+    pub sharpness: f64,
+}
+
+/// Sweeps the focus of `video_source` from far to near with `FocusMove` of the provisioning
+/// service, scoring a snapshot fetched with `fetch` at each step, then moves the lens back to
+/// the sharpest step.
+///
+/// `FocusMove` has no positions, so the lens returns to the best step the way it got there: all
+/// the way to the far end, then the same number of steps. `imaging_service` is only used to
+/// record the focus positions the device reports, which helps to tell whether it does so.
+pub async fn autofocus_sweep<T, F, Fut>(
+    provisioning_service: &T,
+    imaging_service: Option<&T>,
+    video_source: &tt::ReferenceToken,
+    mut fetch: F,
+    options: &SweepOptions,
+) -> Result<SweepResult, Error>
+where
+    T: Transport,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<Vec<u8>, TransportError>>,
+{
+    let mut samples = vec![];
+    for step in 0..=options.steps {
+        if step == 0 {
+            focus_move(
+                provisioning_service,
+                video_source,
+                provisioning::FocusDirection::Far,
+                options.travel,
+            )
+            .await?;
+        } else {
+            focus_move(
+                provisioning_service,
+                video_source,
+                provisioning::FocusDirection::Near,
+                options.step,
+            )
+            .await?;
+        }
+        time::sleep(options.settle).await;
+
+        let position = match imaging_service {
+            Some(imaging_service) => focus_position(imaging_service, video_source).await,
+            None => None,
+        };
+        let sharpness = sharpness(&fetch().await?)?;
+        tracing::debug!("focus step {}: sharpness {}", step, sharpness);
+        samples.push(Sample {
+            step,
+            position,
+            sharpness,
+        });
+    }
+
+    let best = samples
+        .iter()
+        .fold(&samples[0], |best, sample| {
+            if sample.sharpness > best.sharpness {
+                sample
+            } else {
+                best
+            }
+        })
+        .step;
+
+    focus_move(
+        provisioning_service,
+        video_source,
+        provisioning::FocusDirection::Far,
+        options.travel,
+    )
+    .await?;
+    for _ in 0..best {
+        focus_move(
+            provisioning_service,
+            video_source,
+            provisioning::FocusDirection::Near,
+            options.step,
+        )
+        .await?;
+    }
+    time::sleep(options.settle).await;
+    let sharpness = sharpness(&fetch().await?)?;
+
+    Ok(SweepResult {
+        samples,
+        best,
+        sharpness,
+    })
+}
+
+/// Moves the focus for `duration` and waits until it's done, as `FocusMove` returns right away.
+async fn focus_move<T: Transport>(
+    provisioning_service: &T,
+    video_source: &tt::ReferenceToken,
+    direction: provisioning::FocusDirection,
+    duration: Duration,
+) -> Result<(), Error> {
+    let timeout = format!("PT{}S", duration.as_secs_f64())
+        .parse()
+        .map_err(|e| TransportError::Serialization(format!("{:?}", e)))?;
+    provisioning::focus_move(
+        provisioning_service,
+        &provisioning::FocusMove {
+            video_source: video_source.clone(),
+            direction,
+            timeout: Some(timeout),
+        },
+    )
+    .await?;
+    time::sleep(duration).await;
+    Ok(())
+}
+
+async fn focus_position<T: Transport>(
+    imaging_service: &T,
+    video_source: &tt::ReferenceToken,
+) -> Option<f64> {
+    match imaging::get_status(
+        imaging_service,
+        &imaging::GetStatus {
+            video_source_token: video_source.clone(),
+        },
+    )
+    .await
+    {
+        Ok(response) => response.status.focus_status_20.map(|focus| focus.position),
+        Err(e) => {
+            tracing::debug!("failed to read the focus position: {}", e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use image::{codecs::jpeg::JpegEncoder, ColorType};
+    use std::sync::{Arc, Mutex};
+
+    /// A grey image with stripes whose contrast is `contrast`, between 0 and 127.
+    fn stripes(contrast: u8) -> Vec<u8> {
+        let pixels: Vec<u8> = (0..64 * 64)
+            .map(|i| {
+                if i % 2 == 0 {
+                    128 + contrast
+                } else {
+                    128 - contrast
+                }
+            })
+            .collect();
+        let mut jpeg = vec![];
+        JpegEncoder::new_with_quality(&mut jpeg, 100)
+            .encode(&pixels, 64, 64, ColorType::L8)
+            .unwrap();
+        jpeg
+    }
+
+    #[test]
+    fn scores_contrast() {
+        assert!(sharpness(&stripes(100)).unwrap() > sharpness(&stripes(10)).unwrap());
+        assert!(sharpness(&stripes(0)).unwrap() < 1.0);
+        assert!(matches!(sharpness(b"not an image"), Err(Error::Image(_))));
+    }
+
+    /// A lens, positioned in steps from the far end, which is in focus at step 3.
+    #[derive(Clone, Default)]
+    struct FakeLens {
+        position: Arc<Mutex<u32>>,
+    }
+
+    #[async_trait]
+    impl Transport for FakeLens {
+        async fn request(&self, message: &str) -> Result<String, TransportError> {
+            let mut position = self.position.lock().unwrap();
+            if message.contains("Far") {
+                *position = 0;
+            } else if message.contains("Near") {
+                *position += 1;
+            } else {
+                return Ok(format!(
+                    r#"<timg:GetStatusResponse
+                        xmlns:timg="http://www.onvif.org/ver20/imaging/wsdl"
+                        xmlns:tt="http://www.onvif.org/ver10/schema">
+                        <timg:Status><tt:FocusStatus20>
+                            <tt:Position>{}</tt:Position><tt:MoveStatus>IDLE</tt:MoveStatus>
+                        </tt:FocusStatus20></timg:Status>
+                    </timg:GetStatusResponse>"#,
+                    *position as f64 / 10.0
+                ));
+            }
+            Ok(r#"<tpv:FocusMoveResponse
+                xmlns:tpv="http://www.onvif.org/ver10/provisioning/wsdl"/>"#
+                .to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn focuses_sharpest_step() {
+        time::pause();
+        let lens = FakeLens::default();
+        let position = lens.position.clone();
+        let fetch = || {
+            let distance = (*position.lock().unwrap() as i32 - 3).unsigned_abs();
+            futures_util::future::ready(Ok(stripes(100 / (1 + distance as u8 * 3))))
+        };
+
+        let result = autofocus_sweep(
+            &lens,
+            Some(&lens),
+            &tt::ReferenceToken("lens".to_string()),
+            fetch,
+            &SweepOptions::default().steps(6),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.samples.len(), 7);
+        assert_eq!(result.samples[2].position, Some(0.2));
+        assert_eq!(result.best, 3);
+        assert_eq!(*lens.position.lock().unwrap(), 3);
+        assert!((result.sharpness - result.samples[3].sharpness).abs() < 1e-9);
+    }
+}
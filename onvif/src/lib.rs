@@ -2,8 +2,42 @@
 #[macro_use]
 extern crate yaserde_derive;
 
-pub mod discovery;
-pub mod soap;
+pub mod analytics;
+#[cfg(feature = "cache")]
+pub mod cache;
+pub mod capabilities;
+pub mod conformance;
+pub mod credential;
+pub mod deviceio;
+pub mod door;
+#[cfg(feature = "dynamic")]
+pub mod dynamic;
+pub mod event;
+pub mod health;
+pub mod identity;
+pub mod imaging;
+#[cfg(feature = "install")]
+pub mod install;
+pub mod media;
+pub mod media2;
+pub mod metadata;
+pub mod metrics;
+#[cfg(feature = "mqtt-bridge")]
+pub mod mqtt;
+pub mod network;
+pub mod paging;
+pub mod provisioning;
+pub mod ptz;
+pub mod radiometry;
+pub mod registry;
+pub mod schedule;
+pub mod scopes;
+pub mod storage;
+pub mod support;
+pub mod tasks;
+pub mod xaddr;
+pub use onvif_discovery as discovery;
+pub use onvif_soap as soap;
 pub use schema;
 
 mod utils;
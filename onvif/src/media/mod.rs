@@ -0,0 +1,1090 @@
+//! Media service helpers for the requests every video management integration starts with.
+
+pub mod snapshots;
+
+use chrono::{DateTime, Utc};
+use futures_util::future::try_join_all;
+use schema::{
+    diff,
+    json::ToJson,
+    media, onvif as tt,
+    transport::{Error, OnvifFaultKind, Transport},
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+/// How RTP is delivered to the client, i.e. the `StreamSetup` of `GetStreamUri`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StreamTransport {
+    /// RTP over UDP, set up with RTSP.
+    Udp,
+
+    /// RTP over TCP. Deprecated by ONVIF in favour of [`StreamTransport::Rtsp`].
+    Tcp,
+
+    /// RTP interleaved into the RTSP connection.
+    Rtsp,
+
+    /// RTSP tunnelled over HTTP.
+    Http,
+}
+
+impl StreamTransport {
+    pub fn stream_setup(self) -> tt::StreamSetup {
+        tt::StreamSetup {
+            stream: tt::StreamType::RtpUnicast,
+            transport: tt::Transport {
+                protocol: match self {
+                    StreamTransport::Udp => tt::TransportProtocol::Udp,
+                    StreamTransport::Tcp => tt::TransportProtocol::Tcp,
+                    StreamTransport::Rtsp => tt::TransportProtocol::Rtsp,
+                    StreamTransport::Http => tt::TransportProtocol::Http,
+                },
+                tunnel: vec![],
+            },
+        }
+    }
+}
+
+impl FromStr for StreamTransport {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "udp" => Ok(StreamTransport::Udp),
+            "tcp" => Ok(StreamTransport::Tcp),
+            "rtsp" => Ok(StreamTransport::Rtsp),
+            "http" => Ok(StreamTransport::Http),
+            _ => Err(format!(
+                "unknown stream transport: {}, expected udp, tcp, rtsp or http",
+                s
+            )),
+        }
+    }
+}
+
+/// A stream URI along with the transport the device accepted for it.
+#[derive(Debug, PartialEq)]
+pub struct StreamUri {
+    pub transport: StreamTransport,
+    pub media_uri: tt::MediaUri,
+}
+
+/// Gets the stream URI of a profile.
+///
+/// `transports` are tried in order of preference as long as the device rejects them with an
+/// `InvalidStreamSetup` fault, e.g. because it doesn't support the HTTP tunnel.
+pub async fn stream_uri<T: Transport>(
+    media_service: &T,
+    profile: &tt::ReferenceToken,
+    transports: &[StreamTransport],
+) -> Result<StreamUri, Error> {
+    let mut last_error = Error::Other("no stream transport given".to_string());
+    for &transport in transports {
+        let request = media::GetStreamUri {
+            profile_token: profile.clone(),
+            stream_setup: transport.stream_setup(),
+        };
+
+        match media::get_stream_uri(media_service, &request).await {
+            Ok(response) => {
+                return Ok(StreamUri {
+                    transport,
+                    media_uri: response.media_uri,
+                })
+            }
+            Err(error) if is_invalid_stream_setup(&error) => {
+                tracing::debug!("{:?} stream setup rejected: {}", transport, error);
+                last_error = error;
+            }
+            Err(error) => return Err(error),
+        }
+    }
+
+    Err(last_error)
+}
+
+/// Gets the stream URIs of all media profiles, keyed by profile token. See [`stream_uri`] for
+/// `transports`.
+pub async fn stream_uris<T: Transport>(
+    media_service: &T,
+    transports: &[StreamTransport],
+) -> Result<BTreeMap<String, StreamUri>, Error> {
+    let profiles = media::get_profiles(media_service, &Default::default())
+        .await?
+        .profiles;
+
+    profile_stream_uris(media_service, &profiles, transports).await
+}
+
+/// Like [`stream_uris`], for profiles the caller already fetched. The URIs are requested
+/// concurrently.
+pub async fn profile_stream_uris<T: Transport>(
+    media_service: &T,
+    profiles: &[tt::Profile],
+    transports: &[StreamTransport],
+) -> Result<BTreeMap<String, StreamUri>, Error> {
+    let uris = try_join_all(
+        profiles
+            .iter()
+            .map(|p| stream_uri(media_service, &p.token, transports)),
+    )
+    .await?;
+
+    Ok(profiles
+        .iter()
+        .map(|p| p.token.0.clone())
+        .zip(uris)
+        .collect())
+}
+
+/// A video codec, e.g. of a profile created with [`create_profile`] or of
+/// [media2 encoder settings](crate::media2::EncoderSettings).
+///
+/// Devices name codecs in several ways, and new ones appear, e.g. AV1, so names that aren't
+/// known are kept as [`Codec::Other`] and round-trip unchanged. More variants may be added.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Codec {
+    H264,
+
+    /// The media service predates H.265, so with it only devices that report it anyway, as the
+    /// encoding `H265`, support it. Use the media2 service otherwise.
+    H265,
+
+    Mjpeg,
+    Mpeg4,
+
+    /// Any other codec, by the name the device uses.
+    Other(String),
+}
+
+impl Codec {
+    /// Recognizes the names of the media and media2 services, e.g. `H264` and `MPV4-ES`, and
+    /// common spellings such as `h.265`, `HEVC` or `MJPEG`, ignoring case.
+    pub fn parse(name: &str) -> Self {
+        match name.trim().to_ascii_uppercase().as_str() {
+            "H264" | "H.264" | "AVC" => Codec::H264,
+            "H265" | "H.265" | "HEVC" => Codec::H265,
+            "JPEG" | "MJPEG" => Codec::Mjpeg,
+            "MPEG4" | "MPV4-ES" => Codec::Mpeg4,
+            _ => Codec::Other(name.trim().to_string()),
+        }
+    }
+
+    /// The name used by the media2 service, e.g. in `GetVideoEncoderConfigurationOptions`.
+    pub fn media2_name(&self) -> &str {
+        match self {
+            Codec::H264 => "H264",
+            Codec::H265 => "H265",
+            Codec::Mjpeg => "JPEG",
+            Codec::Mpeg4 => "MPV4-ES",
+            Codec::Other(name) => name,
+        }
+    }
+
+    /// The encoding of the media service.
+    pub fn encoding(&self) -> tt::VideoEncoding {
+        match self {
+            Codec::H264 => tt::VideoEncoding::H264,
+            Codec::Mjpeg => tt::VideoEncoding::Jpeg,
+            Codec::Mpeg4 => tt::VideoEncoding::Mpeg4,
+            Codec::H265 | Codec::Other(_) => {
+                tt::VideoEncoding::__Unknown__(self.media2_name().to_string())
+            }
+        }
+    }
+
+    pub fn from_encoding(encoding: &tt::VideoEncoding) -> Self {
+        match encoding {
+            tt::VideoEncoding::H264 => Codec::H264,
+            tt::VideoEncoding::Jpeg => Codec::Mjpeg,
+            tt::VideoEncoding::Mpeg4 => Codec::Mpeg4,
+            tt::VideoEncoding::__Unknown__(name) => Codec::parse(name),
+            encoding => Codec::parse(&format!("{:?}", encoding)),
+        }
+    }
+}
+
+impl fmt::Display for Codec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.media2_name())
+    }
+}
+
+impl FromStr for Codec {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Codec::parse(s))
+    }
+}
+
+impl Serialize for Codec {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.media2_name())
+    }
+}
+
+impl<'de> Deserialize<'de> for Codec {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Codec::parse(&String::deserialize(deserializer)?))
+    }
+}
+
+/// The profile [`create_profile`] builds.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProfileSpec {
+    pub name: String,
+    pub codec: Codec,
+    pub width: i32,
+    pub height: i32,
+
+    /// Frames per second, the highest the encoder supports if `None`.
+    pub frame_rate: Option<i32>,
+
+    /// Bitrate limit in kbit/s, that of the encoder configuration if `None`.
+    pub bitrate: Option<i32>,
+}
+
+impl ProfileSpec {
+    pub fn new(name: &str, codec: Codec, width: i32, height: i32) -> Self {
+        Self {
+            name: name.to_string(),
+            codec,
+            width,
+            height,
+            frame_rate: None,
+            bitrate: None,
+        }
+    }
+
+    pub fn frame_rate(mut self, frame_rate: i32) -> Self {
+        self.frame_rate = Some(frame_rate);
+        self
+    }
+
+    pub fn bitrate(mut self, bitrate: i32) -> Self {
+        self.bitrate = Some(bitrate);
+        self
+    }
+}
+
+/// Creates a profile streaming as `spec` describes, and returns it.
+///
+/// The profile gets the first compatible video source configuration whose bounds cover the
+/// resolution, and a compatible video encoder configuration whose options allow `spec`, which is
+/// then changed accordingly. Encoder configurations no other profile uses are preferred, since
+/// changing a configuration changes every profile using it. If no configuration fits, the new
+/// profile is deleted again.
+pub async fn create_profile<T: Transport>(
+    media_service: &T,
+    spec: &ProfileSpec,
+) -> Result<tt::Profile, Error> {
+    let profile = media::create_profile(
+        media_service,
+        &media::CreateProfile {
+            name: tt::Name(spec.name.clone()),
+            token: None,
+        },
+    )
+    .await?
+    .profile
+    .token;
+
+    match configure_profile(media_service, &profile, spec).await {
+        Ok(profile) => Ok(profile),
+        Err(error) => {
+            let request = media::DeleteProfile {
+                profile_token: profile.clone(),
+            };
+            if let Err(e) = media::delete_profile(media_service, &request).await {
+                tracing::warn!("failed to delete incomplete profile {}: {}", profile.0, e);
+            }
+            Err(error)
+        }
+    }
+}
+
+async fn configure_profile<T: Transport>(
+    media_service: &T,
+    profile: &tt::ReferenceToken,
+    spec: &ProfileSpec,
+) -> Result<tt::Profile, Error> {
+    let sources = media::get_compatible_video_source_configurations(
+        media_service,
+        &media::GetCompatibleVideoSourceConfigurations {
+            profile_token: profile.clone(),
+        },
+    )
+    .await?
+    .configurations;
+    let source = sources
+        .iter()
+        .find(|source| source.bounds.width >= spec.width && source.bounds.height >= spec.height)
+        .ok_or_else(|| {
+            Error::Other(format!(
+                "no video source configuration covers {}x{}",
+                spec.width, spec.height
+            ))
+        })?;
+    media::add_video_source_configuration(
+        media_service,
+        &media::AddVideoSourceConfiguration {
+            profile_token: profile.clone(),
+            configuration_token: source.token.clone(),
+        },
+    )
+    .await?;
+
+    let mut encoders = media::get_compatible_video_encoder_configurations(
+        media_service,
+        &media::GetCompatibleVideoEncoderConfigurations {
+            profile_token: profile.clone(),
+        },
+    )
+    .await?
+    .configurations;
+    let encoding = spec.codec.encoding();
+    encoders.sort_by_key(|encoder| (encoder.use_count > 0, encoder.encoding != encoding));
+
+    let mut rejected = vec![];
+    for mut encoder in encoders {
+        let options = media::get_video_encoder_configuration_options(
+            media_service,
+            &media::GetVideoEncoderConfigurationOptions {
+                configuration_token: Some(encoder.token.clone()),
+                profile_token: Some(profile.clone()),
+            },
+        )
+        .await?
+        .options;
+        let current = encoder.to_json().map_err(Error::Serialization)?;
+        if let Err(reason) = apply_spec(&mut encoder, &options, spec) {
+            rejected.push(format!("{}: {}", encoder.token.0, reason));
+            continue;
+        }
+        let desired = encoder.to_json().map_err(Error::Serialization)?;
+        for change in diff::diff_json(&current, &desired) {
+            tracing::debug!("encoder {}: {}", encoder.token.0, change);
+        }
+
+        media::add_video_encoder_configuration(
+            media_service,
+            &media::AddVideoEncoderConfiguration {
+                profile_token: profile.clone(),
+                configuration_token: encoder.token.clone(),
+            },
+        )
+        .await?;
+        media::set_video_encoder_configuration(
+            media_service,
+            &media::SetVideoEncoderConfiguration {
+                configuration: encoder,
+                force_persistence: true,
+            },
+        )
+        .await?;
+
+        return Ok(media::get_profile(
+            media_service,
+            &media::GetProfile {
+                profile_token: profile.clone(),
+            },
+        )
+        .await?
+        .profile);
+    }
+
+    Err(Error::Other(format!(
+        "no compatible video encoder configuration supports the profile ({})",
+        rejected.join(", ")
+    )))
+}
+
+/// Changes `encoder` to `spec`, if `options` allow it.
+fn apply_spec(
+    encoder: &mut tt::VideoEncoderConfiguration,
+    options: &tt::VideoEncoderConfigurationOptions,
+    spec: &ProfileSpec,
+) -> Result<(), String> {
+    let extension = options.extension.as_ref();
+    let (resolutions, frame_rates, bitrates) = match &spec.codec {
+        Codec::H264 => {
+            let h264 = options.h264.as_ref().ok_or("H.264 isn't supported")?;
+            if encoder.h264.is_none() {
+                encoder.h264 = Some(tt::H264Configuration {
+                    gov_length: spec
+                        .frame_rate
+                        .unwrap_or(h264.frame_rate_range.max)
+                        .max(h264.gov_length_range.min)
+                        .min(h264.gov_length_range.max),
+                    h264_profile: tt::H264Profile::Main,
+                });
+            }
+            (
+                Some(&h264.resolutions_available),
+                Some(&h264.frame_rate_range),
+                extension
+                    .and_then(|e| e.h264.as_ref())
+                    .map(|h264| &h264.bitrate_range),
+            )
+        }
+        Codec::Mjpeg => {
+            let jpeg = options.jpeg.as_ref().ok_or("JPEG isn't supported")?;
+            (
+                Some(&jpeg.resolutions_available),
+                Some(&jpeg.frame_rate_range),
+                extension
+                    .and_then(|e| e.jpeg.as_ref())
+                    .map(|jpeg| &jpeg.bitrate_range),
+            )
+        }
+        // There are no options for other codecs, the device validates the configuration itself.
+        _ if encoder.encoding == spec.codec.encoding() => (None, None, None),
+        codec => return Err(format!("{} isn't supported by the media service", codec)),
+    };
+
+    if let Some(resolutions) = resolutions {
+        if !resolutions
+            .iter()
+            .any(|r| r.width == spec.width && r.height == spec.height)
+        {
+            return Err(format!("{}x{} isn't available", spec.width, spec.height));
+        }
+    }
+
+    let current = encoder.rate_control.as_ref();
+    let frame_rate = match (spec.frame_rate, frame_rates) {
+        (Some(fps), Some(range)) if fps < range.min || fps > range.max => {
+            return Err(format!(
+                "{} fps is out of {}..={}",
+                fps, range.min, range.max
+            ))
+        }
+        (Some(fps), _) => fps,
+        (None, Some(range)) => range.max,
+        (None, None) => current.map_or(0, |rate| rate.frame_rate_limit),
+    };
+    let bitrate = match (spec.bitrate, bitrates) {
+        (Some(bitrate), Some(range)) if bitrate < range.min || bitrate > range.max => {
+            return Err(format!(
+                "{} kbit/s is out of {}..={}",
+                bitrate, range.min, range.max
+            ))
+        }
+        (Some(bitrate), _) => bitrate,
+        (None, _) => current.map_or(0, |rate| rate.bitrate_limit),
+    };
+
+    encoder.encoding = spec.codec.encoding();
+    encoder.resolution = tt::VideoResolution {
+        width: spec.width,
+        height: spec.height,
+    };
+    encoder.rate_control = Some(tt::VideoRateControl {
+        frame_rate_limit: frame_rate,
+        encoding_interval: current.map_or(1, |rate| rate.encoding_interval),
+        bitrate_limit: bitrate,
+    });
+    Ok(())
+}
+
+/// Which profiles [`cleanup_profiles`] deletes: those whose name matches a pattern, optionally
+/// only if they were created before a cutoff. Fixed profiles are never deleted.
+///
+/// Devices don't tell when a profile was created, so ages come from the caller, e.g. recorded
+/// when the profiles were created with [`create_profile`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProfileCleanup {
+    pattern: String,
+    created_before: Option<(DateTime<Utc>, BTreeMap<String, DateTime<Utc>>)>,
+    dry_run: bool,
+}
+
+impl ProfileCleanup {
+    /// Matches profiles by name, where `*` stands for any number of characters and `?` for one,
+    /// e.g. `MediaProfile000*`.
+    pub fn new(pattern: &str) -> Self {
+        Self {
+            pattern: pattern.to_string(),
+            created_before: None,
+            dry_run: false,
+        }
+    }
+
+    /// Only matches profiles created before `cutoff`, according to `created`, which maps profile
+    /// tokens to creation times. Profiles missing from `created` are kept.
+    pub fn created_before(
+        mut self,
+        cutoff: DateTime<Utc>,
+        created: BTreeMap<String, DateTime<Utc>>,
+    ) -> Self {
+        self.created_before = Some((cutoff, created));
+        self
+    }
+
+    /// Lists the profiles that would be deleted without deleting them.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    pub fn matches(&self, profile: &tt::Profile) -> bool {
+        if profile.fixed == Some(true) || !glob_match(&self.pattern, &profile.name.0) {
+            return false;
+        }
+        match &self.created_before {
+            Some((cutoff, created)) => created
+                .get(&profile.token.0)
+                .map_or(false, |created| created < cutoff),
+            None => true,
+        }
+    }
+}
+
+/// Deletes the profiles `cleanup` matches, or only lists them if it is a dry run. Returns the
+/// tokens of the matching profiles.
+pub async fn cleanup_profiles<T: Transport>(
+    media_service: &T,
+    cleanup: &ProfileCleanup,
+) -> Result<Vec<String>, Error> {
+    let profiles = media::get_profiles(media_service, &Default::default())
+        .await?
+        .profiles;
+
+    let mut matching = vec![];
+    for profile in profiles.iter().filter(|profile| cleanup.matches(profile)) {
+        if !cleanup.dry_run {
+            tracing::debug!("deleting profile {} ({})", profile.token.0, profile.name.0);
+            media::delete_profile(
+                media_service,
+                &media::DeleteProfile {
+                    profile_token: profile.token.clone(),
+                },
+            )
+            .await?;
+        }
+        matching.push(profile.token.0.clone());
+    }
+    Ok(matching)
+}
+
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    // Backtracks to the last `*`, letting it match one more character, on mismatch.
+    let (mut p, mut n) = (0, 0);
+    let mut star = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((star_p, star_n)) => {
+                    p = star_p + 1;
+                    n = star_n + 1;
+                    star = Some((star_p, star_n + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// An encoder configuration that exceeds what a video source mode supports.
+#[derive(Debug, PartialEq)]
+pub struct InvalidatedConfiguration {
+    pub token: String,
+    pub reason: String,
+}
+
+/// Outcome of [`switch_video_source_mode`].
+#[derive(Debug, PartialEq)]
+pub struct ModeSwitch {
+    /// Whether the device reboots to apply the mode.
+    pub reboot: bool,
+
+    /// Encoder configurations of the video source's profiles that the device will have to
+    /// change or reject in the new mode.
+    pub invalidated: Vec<InvalidatedConfiguration>,
+}
+
+/// Switches a video source to the mode with the token `mode`, as listed by `GetVideoSourceModes`.
+///
+/// Devices may reboot to apply a mode, see [`ModeSwitch::reboot`], which
+/// [`wait_for_reboot`](crate::health::wait::wait_for_reboot) waits for.
+pub async fn switch_video_source_mode<T: Transport>(
+    media_service: &T,
+    video_source: &tt::ReferenceToken,
+    mode: &tt::ReferenceToken,
+) -> Result<ModeSwitch, Error> {
+    let modes = media::get_video_source_modes(
+        media_service,
+        &media::GetVideoSourceModes {
+            video_source_token: video_source.clone(),
+        },
+    )
+    .await?
+    .video_source_modes;
+    let target = modes
+        .iter()
+        .find(|m| m.token == *mode)
+        .ok_or_else(|| Error::Other(format!("no video source mode {}", mode.0)))?;
+    let profiles = media::get_profiles(media_service, &Default::default())
+        .await?
+        .profiles;
+    let invalidated = invalidated_configurations(target, &profiles, video_source);
+
+    let reboot = media::set_video_source_mode(
+        media_service,
+        &media::SetVideoSourceMode {
+            video_source_token: video_source.clone(),
+            video_source_mode_token: mode.clone(),
+        },
+    )
+    .await?
+    .reboot;
+
+    Ok(ModeSwitch {
+        reboot,
+        invalidated,
+    })
+}
+
+/// Lists the encoder configurations of the profiles of `video_source` that `mode` doesn't
+/// support, each once.
+pub fn invalidated_configurations(
+    mode: &media::VideoSourceMode,
+    profiles: &[tt::Profile],
+    video_source: &tt::ReferenceToken,
+) -> Vec<InvalidatedConfiguration> {
+    let mut invalidated: Vec<InvalidatedConfiguration> = vec![];
+    for profile in profiles {
+        let uses_source = profile
+            .video_source_configuration
+            .as_ref()
+            .map_or(false, |c| c.source_token == *video_source);
+        let encoder = match &profile.video_encoder_configuration {
+            Some(encoder) if uses_source => encoder,
+            _ => continue,
+        };
+        if invalidated.iter().any(|i| i.token == encoder.token.0) {
+            continue;
+        }
+
+        let max = &mode.max_resolution;
+        let encoding = Codec::from_encoding(&encoder.encoding);
+        let reason =
+            if encoder.resolution.width > max.width || encoder.resolution.height > max.height {
+                format!(
+                    "resolution {}x{} exceeds {}x{}",
+                    encoder.resolution.width, encoder.resolution.height, max.width, max.height
+                )
+            } else if !mode.encodings.0.iter().any(|e| Codec::parse(e) == encoding) {
+                format!("encoding {} isn't supported", encoding)
+            } else {
+                match &encoder.rate_control {
+                    Some(rate) if f64::from(rate.frame_rate_limit) > mode.max_framerate => format!(
+                        "frame rate {} exceeds {}",
+                        rate.frame_rate_limit, mode.max_framerate
+                    ),
+                    _ => continue,
+                }
+            };
+
+        invalidated.push(InvalidatedConfiguration {
+            token: encoder.token.0.clone(),
+            reason,
+        });
+    }
+    invalidated
+}
+
+fn is_invalid_stream_setup(error: &Error) -> bool {
+    match error {
+        Error::Fault(fault) => fault.kind == OnvifFaultKind::InvalidStreamSetup,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use schema::transport::Fault;
+
+    struct FakeMedia;
+
+    #[async_trait]
+    impl Transport for FakeMedia {
+        async fn request(&self, message: &str) -> Result<String, Error> {
+            if message.contains("GetProfiles") {
+                return Ok(r#"
+                    <trt:GetProfilesResponse
+                        xmlns:trt="http://www.onvif.org/ver10/media/wsdl"
+                        xmlns:tt="http://www.onvif.org/ver10/schema">
+                        <trt:Profiles token="main"><tt:Name>Main</tt:Name></trt:Profiles>
+                        <trt:Profiles token="sub"><tt:Name>Sub</tt:Name></trt:Profiles>
+                    </trt:GetProfilesResponse>
+                    "#
+                .to_string());
+            }
+
+            if message.contains("HTTP") {
+                return Err(Error::Fault(Fault::new(
+                    vec![
+                        "env:Sender".to_string(),
+                        "ter:InvalidArgVal".to_string(),
+                        "ter:InvalidStreamSetup".to_string(),
+                    ],
+                    None,
+                )));
+            }
+            assert!(message.contains("RTSP"));
+            let token = message
+                .split("ProfileToken>")
+                .nth(1)
+                .and_then(|rest| rest.split('<').next())
+                .unwrap();
+            Ok(format!(
+                r#"
+                <trt:GetStreamUriResponse
+                    xmlns:trt="http://www.onvif.org/ver10/media/wsdl"
+                    xmlns:tt="http://www.onvif.org/ver10/schema">
+                    <trt:MediaUri>
+                        <tt:Uri>rtsp://192.168.0.2/{}</tt:Uri>
+                        <tt:InvalidAfterConnect>false</tt:InvalidAfterConnect>
+                        <tt:InvalidAfterReboot>false</tt:InvalidAfterReboot>
+                        <tt:Timeout>PT0S</tt:Timeout>
+                    </trt:MediaUri>
+                </trt:GetStreamUriResponse>
+                "#,
+                token
+            ))
+        }
+    }
+
+    /// One video source configuration, and a H.264 encoder configuration used by another
+    /// profile next to an unused JPEG one, recording the requests that change something.
+    #[derive(Default)]
+    struct FakeProfiles {
+        changes: std::sync::Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl Transport for FakeProfiles {
+        async fn request(&self, message: &str) -> Result<String, Error> {
+            let (name, body) = if message.contains("trt:GetCompatibleVideoSourceConfigurations") {
+                (
+                    "GetCompatibleVideoSourceConfigurationsResponse",
+                    r#"<trt:Configurations token="vsc">
+                        <tt:Name>Source</tt:Name>
+                        <tt:UseCount>1</tt:UseCount>
+                        <tt:SourceToken>source</tt:SourceToken>
+                        <tt:Bounds x="0" y="0" width="1920" height="1080"/>
+                    </trt:Configurations>"#,
+                )
+            } else if message.contains("trt:GetCompatibleVideoEncoderConfigurations") {
+                (
+                    "GetCompatibleVideoEncoderConfigurationsResponse",
+                    r#"<trt:Configurations token="used">
+                        <tt:Name>Used</tt:Name>
+                        <tt:UseCount>1</tt:UseCount>
+                        <tt:Encoding>H264</tt:Encoding>
+                        <tt:Resolution><tt:Width>1920</tt:Width><tt:Height>1080</tt:Height></tt:Resolution>
+                        <tt:Quality>5</tt:Quality>
+                    </trt:Configurations>
+                    <trt:Configurations token="free">
+                        <tt:Name>Free</tt:Name>
+                        <tt:UseCount>0</tt:UseCount>
+                        <tt:Encoding>JPEG</tt:Encoding>
+                        <tt:Resolution><tt:Width>640</tt:Width><tt:Height>480</tt:Height></tt:Resolution>
+                        <tt:Quality>5</tt:Quality>
+                        <tt:RateControl>
+                            <tt:FrameRateLimit>5</tt:FrameRateLimit>
+                            <tt:EncodingInterval>1</tt:EncodingInterval>
+                            <tt:BitrateLimit>512</tt:BitrateLimit>
+                        </tt:RateControl>
+                    </trt:Configurations>"#,
+                )
+            } else if message.contains("trt:GetVideoEncoderConfigurationOptions") {
+                (
+                    "GetVideoEncoderConfigurationOptionsResponse",
+                    r#"<trt:Options>
+                        <tt:QualityRange><tt:Min>0</tt:Min><tt:Max>10</tt:Max></tt:QualityRange>
+                        <tt:H264>
+                            <tt:ResolutionsAvailable><tt:Width>1920</tt:Width><tt:Height>1080</tt:Height></tt:ResolutionsAvailable>
+                            <tt:ResolutionsAvailable><tt:Width>1280</tt:Width><tt:Height>720</tt:Height></tt:ResolutionsAvailable>
+                            <tt:GovLengthRange><tt:Min>1</tt:Min><tt:Max>60</tt:Max></tt:GovLengthRange>
+                            <tt:FrameRateRange><tt:Min>1</tt:Min><tt:Max>25</tt:Max></tt:FrameRateRange>
+                            <tt:EncodingIntervalRange><tt:Min>1</tt:Min><tt:Max>1</tt:Max></tt:EncodingIntervalRange>
+                            <tt:H264ProfilesSupported>Main</tt:H264ProfilesSupported>
+                        </tt:H264>
+                        <tt:Extension>
+                            <tt:H264>
+                                <tt:ResolutionsAvailable><tt:Width>1920</tt:Width><tt:Height>1080</tt:Height></tt:ResolutionsAvailable>
+                                <tt:ResolutionsAvailable><tt:Width>1280</tt:Width><tt:Height>720</tt:Height></tt:ResolutionsAvailable>
+                                <tt:GovLengthRange><tt:Min>1</tt:Min><tt:Max>60</tt:Max></tt:GovLengthRange>
+                                <tt:FrameRateRange><tt:Min>1</tt:Min><tt:Max>25</tt:Max></tt:FrameRateRange>
+                                <tt:EncodingIntervalRange><tt:Min>1</tt:Min><tt:Max>1</tt:Max></tt:EncodingIntervalRange>
+                                <tt:H264ProfilesSupported>Main</tt:H264ProfilesSupported>
+                                <tt:BitrateRange><tt:Min>64</tt:Min><tt:Max>8192</tt:Max></tt:BitrateRange>
+                            </tt:H264>
+                        </tt:Extension>
+                    </trt:Options>"#,
+                )
+            } else if message.contains("trt:CreateProfile") {
+                (
+                    "CreateProfileResponse",
+                    r#"<trt:Profile token="new"><tt:Name>New</tt:Name></trt:Profile>"#,
+                )
+            } else if message.contains("trt:GetProfile") {
+                (
+                    "GetProfileResponse",
+                    r#"<trt:Profile token="new"><tt:Name>New</tt:Name></trt:Profile>"#,
+                )
+            } else {
+                self.changes.lock().unwrap().push(message.to_string());
+                let name = message
+                    .trim_start_matches('<')
+                    .split(|c: char| c.is_whitespace() || c == '>' || c == '/')
+                    .next()
+                    .unwrap()
+                    .trim_start_matches("trt:");
+                return Ok(format!(
+                    r#"<trt:{}Response xmlns:trt="http://www.onvif.org/ver10/media/wsdl"/>"#,
+                    name
+                ));
+            };
+            Ok(format!(
+                r#"<trt:{name}
+                    xmlns:trt="http://www.onvif.org/ver10/media/wsdl"
+                    xmlns:tt="http://www.onvif.org/ver10/schema">{body}</trt:{name}>"#,
+                name = name,
+                body = body
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn creates_profiles() {
+        let media = FakeProfiles::default();
+        let spec = ProfileSpec::new("New", Codec::H264, 1280, 720)
+            .frame_rate(15)
+            .bitrate(2048);
+
+        let profile = create_profile(&media, &spec).await.unwrap();
+        assert_eq!(profile.token.0, "new");
+
+        let changes = media.changes.lock().unwrap().split_off(0);
+        assert_eq!(changes.len(), 3);
+        assert!(changes[0].contains("trt:AddVideoSourceConfiguration"));
+        assert!(changes[1].contains("trt:AddVideoEncoderConfiguration"));
+        assert!(changes[1].contains("<trt:ConfigurationToken>free</trt:ConfigurationToken>"));
+        let set = &changes[2];
+        assert!(set.contains("trt:SetVideoEncoderConfiguration"));
+        assert!(set.contains("<tt:Encoding>H264</tt:Encoding>"));
+        assert!(set.contains("<tt:Width>1280</tt:Width>"));
+        assert!(set.contains("<tt:FrameRateLimit>15</tt:FrameRateLimit>"));
+        assert!(set.contains("<tt:BitrateLimit>2048</tt:BitrateLimit>"));
+        assert!(set.contains("<tt:GovLength>15</tt:GovLength>"));
+
+        // Nothing fits, so the profile is deleted again.
+        let spec = ProfileSpec::new("New", Codec::Mjpeg, 1280, 720);
+        assert!(create_profile(&media, &spec).await.is_err());
+        let changes = media.changes.lock().unwrap().split_off(0);
+        assert!(changes.last().unwrap().contains("trt:DeleteProfile"));
+    }
+
+    #[test]
+    fn codecs() {
+        assert_eq!(Codec::parse("h.265"), Codec::H265);
+        assert_eq!(Codec::parse("MPV4-ES"), Codec::Mpeg4);
+        assert_eq!(
+            Codec::from_encoding(&tt::VideoEncoding::__Unknown__("HEVC".to_string())),
+            Codec::H265
+        );
+        assert_eq!(
+            Codec::H265.encoding(),
+            tt::VideoEncoding::__Unknown__("H265".to_string())
+        );
+
+        let av1 = Codec::parse("AV1");
+        assert_eq!(av1, Codec::Other("AV1".to_string()));
+        assert_eq!(av1.to_string(), "AV1");
+        assert_eq!(
+            Codec::from_encoding(&av1.encoding()),
+            Codec::Other("AV1".to_string())
+        );
+
+        let json = serde_json::to_string(&[Codec::Mjpeg, av1]).unwrap();
+        assert_eq!(json, r#"["JPEG","AV1"]"#);
+        assert_eq!(
+            serde_json::from_str::<Vec<Codec>>(&json).unwrap(),
+            vec![Codec::Mjpeg, Codec::Other("AV1".to_string())]
+        );
+    }
+
+    #[test]
+    fn glob_patterns() {
+        assert!(glob_match("MediaProfile000*", "MediaProfile0001"));
+        assert!(glob_match("MediaProfile000?", "MediaProfile0001"));
+        assert!(glob_match("*tmp*", "profile_tmp_3"));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("MediaProfile000?", "MediaProfile00012"));
+        assert!(!glob_match("Temp*", "MainStream"));
+    }
+
+    #[tokio::test]
+    async fn cleans_up_profiles() {
+        struct FakeProfiles(std::sync::Mutex<Vec<String>>);
+
+        #[async_trait]
+        impl Transport for FakeProfiles {
+            async fn request(&self, message: &str) -> Result<String, Error> {
+                if message.contains("trt:DeleteProfile") {
+                    self.0.lock().unwrap().push(message.to_string());
+                    return Ok(r#"<trt:DeleteProfileResponse xmlns:trt="http://www.onvif.org/ver10/media/wsdl"/>"#.to_string());
+                }
+                Ok(r#"
+                    <trt:GetProfilesResponse
+                        xmlns:trt="http://www.onvif.org/ver10/media/wsdl"
+                        xmlns:tt="http://www.onvif.org/ver10/schema">
+                        <trt:Profiles token="main" fixed="true"><tt:Name>MediaProfile0000</tt:Name></trt:Profiles>
+                        <trt:Profiles token="tmp1"><tt:Name>MediaProfile0001</tt:Name></trt:Profiles>
+                        <trt:Profiles token="tmp2"><tt:Name>MediaProfile0002</tt:Name></trt:Profiles>
+                        <trt:Profiles token="user"><tt:Name>Lobby</tt:Name></trt:Profiles>
+                    </trt:GetProfilesResponse>
+                    "#
+                .to_string())
+            }
+        }
+
+        let media = FakeProfiles(Default::default());
+        let cleanup = ProfileCleanup::new("MediaProfile*");
+
+        assert_eq!(
+            cleanup_profiles(&media, &cleanup.clone().dry_run(true))
+                .await
+                .unwrap(),
+            vec!["tmp1", "tmp2"]
+        );
+        assert!(media.0.lock().unwrap().is_empty());
+
+        let now = Utc::now();
+        let created = vec![
+            ("tmp1".to_string(), now - chrono::Duration::days(2)),
+            ("tmp2".to_string(), now),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            cleanup_profiles(
+                &media,
+                &cleanup.created_before(now - chrono::Duration::days(1), created)
+            )
+            .await
+            .unwrap(),
+            vec!["tmp1"]
+        );
+        let deleted = media.0.lock().unwrap();
+        assert_eq!(deleted.len(), 1);
+        assert!(deleted[0].contains("<trt:ProfileToken>tmp1</trt:ProfileToken>"));
+    }
+
+    #[test]
+    fn invalidated_by_mode() {
+        let profiles: media::GetProfilesResponse = yaserde::de::from_str(
+            r#"
+            <trt:GetProfilesResponse
+                xmlns:trt="http://www.onvif.org/ver10/media/wsdl"
+                xmlns:tt="http://www.onvif.org/ver10/schema">
+                <trt:Profiles token="main">
+                    <tt:Name>Main</tt:Name>
+                    <tt:VideoSourceConfiguration token="vsc">
+                        <tt:Name>Source</tt:Name>
+                        <tt:UseCount>2</tt:UseCount>
+                        <tt:SourceToken>source</tt:SourceToken>
+                        <tt:Bounds x="0" y="0" width="3840" height="2160"/>
+                    </tt:VideoSourceConfiguration>
+                    <tt:VideoEncoderConfiguration token="uhd">
+                        <tt:Name>UHD</tt:Name>
+                        <tt:UseCount>1</tt:UseCount>
+                        <tt:Encoding>H264</tt:Encoding>
+                        <tt:Resolution><tt:Width>3840</tt:Width><tt:Height>2160</tt:Height></tt:Resolution>
+                        <tt:Quality>5</tt:Quality>
+                    </tt:VideoEncoderConfiguration>
+                </trt:Profiles>
+                <trt:Profiles token="sub">
+                    <tt:Name>Sub</tt:Name>
+                    <tt:VideoSourceConfiguration token="vsc">
+                        <tt:Name>Source</tt:Name>
+                        <tt:UseCount>2</tt:UseCount>
+                        <tt:SourceToken>source</tt:SourceToken>
+                        <tt:Bounds x="0" y="0" width="3840" height="2160"/>
+                    </tt:VideoSourceConfiguration>
+                    <tt:VideoEncoderConfiguration token="vga">
+                        <tt:Name>VGA</tt:Name>
+                        <tt:UseCount>1</tt:UseCount>
+                        <tt:Encoding>H264</tt:Encoding>
+                        <tt:Resolution><tt:Width>640</tt:Width><tt:Height>480</tt:Height></tt:Resolution>
+                        <tt:Quality>5</tt:Quality>
+                    </tt:VideoEncoderConfiguration>
+                </trt:Profiles>
+            </trt:GetProfilesResponse>
+            "#,
+        )
+        .unwrap();
+        let mode = media::VideoSourceMode {
+            max_framerate: 60.0,
+            max_resolution: tt::VideoResolution {
+                width: 1920,
+                height: 1080,
+            },
+            encodings: media::EncodingTypes(vec!["H264".to_string()]),
+            token: tt::ReferenceToken("fhd60".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            invalidated_configurations(
+                &mode,
+                &profiles.profiles,
+                &tt::ReferenceToken("source".to_string())
+            ),
+            vec![InvalidatedConfiguration {
+                token: "uhd".to_string(),
+                reason: "resolution 3840x2160 exceeds 1920x1080".to_string(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn stream_uris_of_all_profiles() {
+        let uris = stream_uris(&FakeMedia, &[StreamTransport::Http, StreamTransport::Rtsp])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            uris.iter()
+                .map(|(token, uri)| (token.as_str(), uri.transport, uri.media_uri.uri.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("main", StreamTransport::Rtsp, "rtsp://192.168.0.2/main"),
+                ("sub", StreamTransport::Rtsp, "rtsp://192.168.0.2/sub")
+            ]
+        );
+        assert!(stream_uris(&FakeMedia, &[StreamTransport::Http])
+            .await
+            .is_err());
+    }
+}
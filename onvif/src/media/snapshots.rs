@@ -0,0 +1,302 @@
+//! Still sequences, e.g. for time-lapses and thumbnails: snapshots fetched at a fixed interval
+//! and handed to a [`SnapshotSink`].
+//!
+//! Fetch failures that may go away, such as timeouts or a device busy rebooting, skip a snapshot
+//! rather than ending the sequence, until too many happen in a row. Authorization failures end
+//! it right away, as retrying doesn't help.
+//!
+//! ```no_run
+//! # async fn example(media_service: onvif::soap::client::Client) {
+//! use onvif::{media::snapshots::{Snapshot, StillSequence}, schema::onvif as tt};
+//! use std::time::Duration;
+//!
+//! let mut snapshots: Vec<Snapshot> = vec![];
+//! StillSequence::new(Duration::from_secs(60))
+//!     .jitter(Duration::from_secs(5))
+//!     .count(60)
+//!     .run_profile(
+//!         &media_service,
+//!         &tt::ReferenceToken("main".to_string()),
+//!         &mut snapshots,
+//!     )
+//!     .await
+//!     .unwrap();
+//! # }
+//! ```
+
+use crate::soap::client::Client;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use schema::{
+    media, onvif as tt,
+    transport::{Error as TransportError, Transport},
+};
+use std::{
+    future::Future,
+    io,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use thiserror::Error;
+use tokio::time::{self, Instant};
+use url::Url;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Transport error: {0}")]
+    Transport(#[from] TransportError),
+
+    #[error("Sink error: {0}")]
+    Sink(io::Error),
+
+    #[error("{failures} snapshots failed in a row, the last with: {last}")]
+    TooManyFailures { failures: u32, last: TransportError },
+
+    #[error("Invalid snapshot URI: {0}")]
+    InvalidUri(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Snapshot {
+    /// Number of the snapshot in the sequence, counting from 0. Skipped snapshots don't count.
+    pub sequence: u64,
+    pub taken: DateTime<Utc>,
+
+    /// The image as served by the device, usually JPEG.
+    pub data: Vec<u8>,
+}
+
+/// Where a [`StillSequence`] writes snapshots, e.g. files or an upload queue.
+#[async_trait]
+pub trait SnapshotSink: Send {
+    async fn write(&mut self, snapshot: Snapshot) -> io::Result<()>;
+}
+
+/// Collects snapshots in memory.
+#[async_trait]
+impl SnapshotSink for Vec<Snapshot> {
+    async fn write(&mut self, snapshot: Snapshot) -> io::Result<()> {
+        self.push(snapshot);
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct StillSequence {
+    interval: Duration,
+    jitter: Duration,
+    count: Option<u64>,
+    max_failures: u32,
+}
+
+impl StillSequence {
+    /// Fetches a snapshot every `interval`, without drifting: a slow fetch delays the next one
+    /// only if it takes longer than the interval, in which case missed snapshots are skipped.
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval: interval.max(Duration::from_millis(1)),
+            jitter: Duration::from_secs(0),
+            count: None,
+            max_failures: 5,
+        }
+    }
+
+    /// Delays each fetch by a random duration up to `jitter`, so that many sequences started at
+    /// once don't hit their devices, or the network, at the same time. Nothing by default.
+    pub fn jitter(mut self, jitter: Duration) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Ends the sequence after `count` snapshots. It runs until it fails otherwise.
+    pub fn count(mut self, count: u64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// How many fetches may fail in a row before the sequence fails, 5 by default.
+    pub fn max_consecutive_failures(mut self, max_failures: u32) -> Self {
+        self.max_failures = max_failures.max(1);
+        self
+    }
+
+    /// Runs the sequence, fetching each snapshot with `fetch`. Returns the number of snapshots
+    /// written once [`StillSequence::count`] is reached.
+    pub async fn run<F, Fut, S>(&self, mut fetch: F, sink: &mut S) -> Result<u64, Error>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<Vec<u8>, TransportError>>,
+        S: SnapshotSink + ?Sized,
+    {
+        let mut sequence = 0;
+        let mut failures = 0;
+        let mut next = Instant::now();
+        loop {
+            if self.count.map_or(false, |count| sequence >= count) {
+                return Ok(sequence);
+            }
+
+            time::sleep_until(next + random_jitter(self.jitter)).await;
+            match fetch().await {
+                Ok(data) => {
+                    failures = 0;
+                    let snapshot = Snapshot {
+                        sequence,
+                        taken: Utc::now(),
+                        data,
+                    };
+                    sink.write(snapshot).await.map_err(Error::Sink)?;
+                    sequence += 1;
+                }
+                Err(TransportError::Authorization(e)) => {
+                    return Err(TransportError::Authorization(e).into())
+                }
+                Err(e) => {
+                    failures += 1;
+                    tracing::warn!("snapshot failed ({} in a row): {}", failures, e);
+                    if failures >= self.max_failures {
+                        return Err(Error::TooManyFailures { failures, last: e });
+                    }
+                }
+            }
+
+            next += self.interval;
+            let now = Instant::now();
+            while next < now {
+                tracing::debug!("skipping a snapshot, the last one took too long");
+                next += self.interval;
+            }
+        }
+    }
+
+    /// Runs the sequence with the snapshot URI of the media profile `profile`. The URI is
+    /// requested again after a failed fetch, since devices may change it, e.g. on reboot.
+    pub async fn run_profile<S>(
+        &self,
+        media_service: &Client,
+        profile: &tt::ReferenceToken,
+        sink: &mut S,
+    ) -> Result<u64, Error>
+    where
+        S: SnapshotSink + ?Sized,
+    {
+        let uri = Arc::new(Mutex::new(Some(
+            snapshot_uri(media_service, profile).await?,
+        )));
+        let fetch = || {
+            let media_service = media_service.clone();
+            let profile = profile.clone();
+            let uri = uri.clone();
+            async move {
+                let current = uri.lock().unwrap().clone();
+                let current = match current {
+                    Some(current) => current,
+                    None => snapshot_uri(&media_service, &profile)
+                        .await
+                        .map_err(|e| match e {
+                            Error::Transport(e) => e,
+                            e => TransportError::Other(e.to_string()),
+                        })?,
+                };
+                let result = media_service.get(&current).await;
+                // The URI may have changed if the fetch failed.
+                *uri.lock().unwrap() = if result.is_ok() { Some(current) } else { None };
+                result
+            }
+        };
+        self.run(fetch, sink).await
+    }
+}
+
+/// Requests the snapshot URI of a media profile.
+pub async fn snapshot_uri<T: Transport>(
+    media_service: &T,
+    profile: &tt::ReferenceToken,
+) -> Result<Url, Error> {
+    let uri = media::get_snapshot_uri(
+        media_service,
+        &media::GetSnapshotUri {
+            profile_token: profile.clone(),
+        },
+    )
+    .await?
+    .media_uri
+    .uri;
+    Url::parse(&uri).map_err(|e| Error::InvalidUri(format!("{}: {}", uri, e)))
+}
+
+fn random_jitter(jitter: Duration) -> Duration {
+    match jitter.as_millis() as u64 {
+        0 => Duration::from_secs(0),
+        max => Duration::from_millis(rand::thread_rng().gen_range(0..=max)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Answers with `results` in turn, then with images.
+    fn fetcher(
+        results: Vec<Result<Vec<u8>, TransportError>>,
+    ) -> impl FnMut() -> futures_util::future::Ready<Result<Vec<u8>, TransportError>> {
+        let mut results = results.into_iter();
+        move || futures_util::future::ready(results.next().unwrap_or_else(|| Ok(vec![0xff, 0xd8])))
+    }
+
+    #[tokio::test]
+    async fn skips_transient_failures() {
+        let mut snapshots: Vec<Snapshot> = vec![];
+        let written = StillSequence::new(Duration::from_millis(5))
+            .count(3)
+            .run(
+                fetcher(vec![
+                    Ok(vec![1]),
+                    Err(TransportError::Timeout("timed out".to_string())),
+                    Ok(vec![2]),
+                ]),
+                &mut snapshots,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(written, 3);
+        assert_eq!(
+            snapshots
+                .iter()
+                .map(|s| (s.sequence, s.data.clone()))
+                .collect::<Vec<_>>(),
+            vec![(0, vec![1]), (1, vec![2]), (2, vec![0xff, 0xd8])]
+        );
+    }
+
+    #[tokio::test]
+    async fn stops_on_persistent_failures() {
+        let mut snapshots: Vec<Snapshot> = vec![];
+        let sequence = StillSequence::new(Duration::from_millis(5)).max_consecutive_failures(2);
+
+        assert!(matches!(
+            sequence
+                .run(
+                    fetcher(vec![Err(TransportError::Authorization("401".to_string()))]),
+                    &mut snapshots,
+                )
+                .await,
+            Err(Error::Transport(TransportError::Authorization(_)))
+        ));
+        assert!(matches!(
+            sequence
+                .run(
+                    fetcher(vec![
+                        Err(TransportError::Connection("refused".to_string())),
+                        Err(TransportError::Connection("refused".to_string())),
+                    ]),
+                    &mut snapshots,
+                )
+                .await,
+            Err(Error::TooManyFailures { failures: 2, .. })
+        ));
+        assert!(snapshots.is_empty());
+    }
+}
@@ -0,0 +1,645 @@
+//! Media2 service helpers for privacy masks and video encoder configurations.
+//!
+//! Masks are checked against `GetMaskOptions` before they are sent, so that unsupported shapes,
+//! types and colours are reported rather than silently adjusted by the device. Likewise, video
+//! encoder settings are checked against `GetVideoEncoderConfigurationOptions`, including whether
+//! the device supports the constant bitrate and guaranteed frame rate of Profile T.
+//!
+//! ```no_run
+//! # async fn example(media2_service: onvif::soap::client::Client) {
+//! use onvif::{media2::{self, EncoderSettings}, schema::onvif as tt};
+//!
+//! let settings = EncoderSettings::new()
+//!     .bitrate(4096)
+//!     .constant_bitrate(true)
+//!     .guaranteed_frame_rate(true);
+//! media2::set_encoder(
+//!     &media2_service,
+//!     &tt::ReferenceToken("encoder".to_string()),
+//!     &settings,
+//! )
+//! .await
+//! .unwrap();
+//! # }
+//! ```
+
+use crate::media::Codec;
+use schema::{
+    media2, onvif as tt,
+    transport::{Error as TransportError, Transport},
+};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Transport error: {0}")]
+    Transport(#[from] TransportError),
+
+    #[error("Invalid mask: {0}")]
+    InvalidMask(String),
+
+    #[error("Invalid encoder settings: {0}")]
+    InvalidEncoder(String),
+
+    #[error("Unexpected response: {0}")]
+    Response(String),
+}
+
+/// The area of a mask in normalized coordinates, where (-1, -1) is the bottom left and (1, 1)
+/// the top right corner of the video source configuration's bounds.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MaskArea {
+    points: Vec<(f64, f64)>,
+}
+
+impl MaskArea {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// An axis-aligned rectangle, which is all some devices support.
+    pub fn rectangle(left: f64, top: f64, right: f64, bottom: f64) -> Self {
+        Self::new()
+            .point(left, top)
+            .point(right, top)
+            .point(right, bottom)
+            .point(left, bottom)
+    }
+
+    /// Appends a corner of the polygon.
+    pub fn point(mut self, x: f64, y: f64) -> Self {
+        self.points.push((x, y));
+        self
+    }
+
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+
+    pub fn is_rectangle(&self) -> bool {
+        if self.points.len() != 4 {
+            return false;
+        }
+        // Each edge must be either horizontal or vertical, alternating.
+        let edges = (0..4).map(|i| (self.points[i], self.points[(i + 1) % 4]));
+        let horizontal: Vec<bool> = edges.clone().map(|(a, b)| a.1 == b.1).collect();
+        let vertical: Vec<bool> = edges.map(|(a, b)| a.0 == b.0).collect();
+        (0..4).all(|i| horizontal[i] != vertical[i] && horizontal[i] != horizontal[(i + 1) % 4])
+    }
+
+    pub fn to_polygon(&self) -> tt::Polygon {
+        tt::Polygon {
+            point: self
+                .points
+                .iter()
+                .map(|&(x, y)| tt::Vector {
+                    x: Some(x),
+                    y: Some(y),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// How the masked area is covered.
+#[derive(Debug, PartialEq)]
+pub enum MaskFill {
+    Color(tt::Color),
+    Pixelated,
+    Blurred,
+}
+
+impl MaskFill {
+    /// The `tr2:MaskType` name.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            MaskFill::Color(_) => "Color",
+            MaskFill::Pixelated => "Pixelated",
+            MaskFill::Blurred => "Blurred",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PrivacyMask {
+    pub area: MaskArea,
+    pub fill: MaskFill,
+
+    /// Disabled masks are kept, but fully transparent.
+    pub enabled: bool,
+}
+
+impl PrivacyMask {
+    pub fn new(area: MaskArea, fill: MaskFill) -> Self {
+        Self {
+            area,
+            fill,
+            enabled: true,
+        }
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Checks the mask against what the device supports.
+    pub fn validate(&self, options: &media2::MaskOptions) -> Result<(), Error> {
+        let points = self.area.points();
+        if points.len() < 3 {
+            return Err(Error::InvalidMask(format!(
+                "a polygon needs at least 3 points, got {}",
+                points.len()
+            )));
+        }
+        if points.len() > options.max_points as usize {
+            return Err(Error::InvalidMask(format!(
+                "{} points exceed the maximum of {}",
+                points.len(),
+                options.max_points
+            )));
+        }
+        if let Some(&(x, y)) = points
+            .iter()
+            .find(|(x, y)| !(-1.0..=1.0).contains(x) || !(-1.0..=1.0).contains(y))
+        {
+            return Err(Error::InvalidMask(format!(
+                "point ({}, {}) is outside of the normalized range -1..1",
+                x, y
+            )));
+        }
+        if options.rectangle_only == Some(true) && !self.area.is_rectangle() {
+            return Err(Error::InvalidMask(
+                "device only supports axis-aligned rectangles".to_string(),
+            ));
+        }
+
+        let type_name = self.fill.type_name();
+        if !options.types.iter().any(|t| t == type_name) {
+            return Err(Error::InvalidMask(format!(
+                "type {} isn't supported, expected one of {}",
+                type_name,
+                options.types.join(", ")
+            )));
+        }
+        if let MaskFill::Color(color) = &self.fill {
+            if !color_supported(color, &options.color) {
+                return Err(Error::InvalidMask(format!(
+                    "colour ({}, {}, {}) isn't supported",
+                    color.x, color.y, color.z
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn to_mask(
+        &self,
+        configuration_token: &tt::ReferenceToken,
+        token: Option<&tt::ReferenceToken>,
+    ) -> media2::Mask {
+        media2::Mask {
+            configuration_token: configuration_token.clone(),
+            polygon: self.area.to_polygon(),
+            _type: self.fill.type_name().to_string(),
+            color: match &self.fill {
+                MaskFill::Color(color) => Some(tt::Color {
+                    x: color.x,
+                    y: color.y,
+                    z: color.z,
+                    colorspace: color.colorspace.clone(),
+                }),
+                _ => None,
+            },
+            enabled: self.enabled,
+            token: token.cloned(),
+        }
+    }
+}
+
+fn color_supported(color: &tt::Color, options: &tt::ColorOptions) -> bool {
+    let same_space = |colorspace: &str| {
+        color
+            .colorspace
+            .as_deref()
+            .map_or(true, |c| c == colorspace)
+    };
+    match &options.color_options_choice {
+        tt::ColorOptionsChoice::ColorList(colors) => colors.iter().any(|c| {
+            c.x == color.x
+                && c.y == color.y
+                && c.z == color.z
+                && c.colorspace.as_deref().map_or(true, same_space)
+        }),
+        tt::ColorOptionsChoice::ColorspaceRange(ranges) => ranges.iter().any(|r| {
+            same_space(&r.colorspace)
+                && (r.x.min..=r.x.max).contains(&color.x)
+                && (r.y.min..=r.y.max).contains(&color.y)
+                && (r.z.min..=r.z.max).contains(&color.z)
+        }),
+        // The device doesn't restrict colours.
+        _ => true,
+    }
+}
+
+/// Lists the masks of a video source configuration, or all masks without one.
+pub async fn masks<T: Transport>(
+    media2_service: &T,
+    configuration_token: Option<&tt::ReferenceToken>,
+) -> Result<Vec<media2::Mask>, Error> {
+    Ok(media2::get_masks(
+        media2_service,
+        &media2::GetMasks {
+            token: None,
+            configuration_token: configuration_token.cloned(),
+        },
+    )
+    .await?
+    .masks)
+}
+
+/// Creates a mask on a video source configuration after checking it against the mask options,
+/// including the maximum number of masks.
+pub async fn create_mask<T: Transport>(
+    media2_service: &T,
+    configuration_token: &tt::ReferenceToken,
+    mask: &PrivacyMask,
+) -> Result<tt::ReferenceToken, Error> {
+    let options = mask_options(media2_service, configuration_token).await?;
+    mask.validate(&options)?;
+    let existing = masks(media2_service, Some(configuration_token))
+        .await?
+        .len();
+    if existing >= options.max_masks as usize {
+        return Err(Error::InvalidMask(format!(
+            "video source configuration has {} of {} masks already",
+            existing, options.max_masks
+        )));
+    }
+
+    Ok(media2::create_mask(
+        media2_service,
+        &media2::CreateMask {
+            mask: mask.to_mask(configuration_token, None),
+        },
+    )
+    .await?
+    .token)
+}
+
+/// Replaces an existing mask after checking the new one against the mask options.
+pub async fn set_mask<T: Transport>(
+    media2_service: &T,
+    configuration_token: &tt::ReferenceToken,
+    token: &tt::ReferenceToken,
+    mask: &PrivacyMask,
+) -> Result<(), Error> {
+    let options = mask_options(media2_service, configuration_token).await?;
+    mask.validate(&options)?;
+
+    media2::set_mask(
+        media2_service,
+        &media2::SetMask {
+            mask: mask.to_mask(configuration_token, Some(token)),
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+pub async fn delete_mask<T: Transport>(
+    media2_service: &T,
+    token: &tt::ReferenceToken,
+) -> Result<(), Error> {
+    media2::delete_mask(
+        media2_service,
+        &media2::DeleteMask {
+            token: token.clone(),
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+async fn mask_options<T: Transport>(
+    media2_service: &T,
+    configuration_token: &tt::ReferenceToken,
+) -> Result<media2::MaskOptions, Error> {
+    Ok(media2::get_mask_options(
+        media2_service,
+        &media2::GetMaskOptions {
+            configuration_token: configuration_token.clone(),
+        },
+    )
+    .await?
+    .options)
+}
+
+/// Changes to a video encoder configuration, see [`set_encoder`]. Settings that aren't given are
+/// left as they are.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EncoderSettings {
+    pub encoding: Option<Codec>,
+    pub resolution: Option<(i32, i32)>,
+    pub frame_rate: Option<f64>,
+
+    /// Bitrate limit in kbit/s.
+    pub bitrate: Option<i32>,
+    pub gov_length: Option<i32>,
+
+    /// Whether the bitrate is held at the limit rather than only capped by it.
+    pub constant_bitrate: Option<bool>,
+
+    /// Whether the device must never drop frames below the frame rate limit, at the expense of
+    /// other streams.
+    pub guaranteed_frame_rate: Option<bool>,
+}
+
+impl EncoderSettings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn encoding(mut self, encoding: Codec) -> Self {
+        self.encoding = Some(encoding);
+        self
+    }
+
+    pub fn resolution(mut self, width: i32, height: i32) -> Self {
+        self.resolution = Some((width, height));
+        self
+    }
+
+    pub fn frame_rate(mut self, frame_rate: f64) -> Self {
+        self.frame_rate = Some(frame_rate);
+        self
+    }
+
+    pub fn bitrate(mut self, bitrate: i32) -> Self {
+        self.bitrate = Some(bitrate);
+        self
+    }
+
+    pub fn gov_length(mut self, gov_length: i32) -> Self {
+        self.gov_length = Some(gov_length);
+        self
+    }
+
+    pub fn constant_bitrate(mut self, constant_bitrate: bool) -> Self {
+        self.constant_bitrate = Some(constant_bitrate);
+        self
+    }
+
+    pub fn guaranteed_frame_rate(mut self, guaranteed_frame_rate: bool) -> Self {
+        self.guaranteed_frame_rate = Some(guaranteed_frame_rate);
+        self
+    }
+
+    /// Checks the settings, applied to `configuration`, against the options for its encoding.
+    pub fn validate(
+        &self,
+        configuration: &tt::VideoEncoder2Configuration,
+        options: &[tt::VideoEncoder2ConfigurationOptions],
+    ) -> Result<(), Error> {
+        let invalid = |message: String| Err(Error::InvalidEncoder(message));
+        let encoding = self
+            .encoding
+            .clone()
+            .unwrap_or_else(|| Codec::parse(&configuration.encoding));
+        let options = match options
+            .iter()
+            .find(|o| Codec::parse(&o.encoding) == encoding)
+        {
+            Some(options) => options,
+            None => return invalid(format!("encoding {} isn't supported", encoding)),
+        };
+
+        if let Some((width, height)) = self.resolution {
+            if !options
+                .resolutions_available
+                .iter()
+                .any(|r| r.width == width && r.height == height)
+            {
+                return invalid(format!("resolution {}x{} isn't available", width, height));
+            }
+        }
+        if let (Some(frame_rate), Some(supported)) =
+            (self.frame_rate, &options.frame_rates_supported)
+        {
+            if !supported.0.iter().any(|&f| (f - frame_rate).abs() < 0.01) {
+                return invalid(format!("frame rate {} isn't supported", frame_rate));
+            }
+        }
+        if let Some(bitrate) = self.bitrate {
+            let range = &options.bitrate_range;
+            if bitrate < range.min || bitrate > range.max {
+                return invalid(format!(
+                    "bitrate {} is out of {}..={}",
+                    bitrate, range.min, range.max
+                ));
+            }
+        }
+        if let (Some(gov_length), Some(range)) = (self.gov_length, &options.gov_length_range) {
+            if let [min, max] = range.0[..] {
+                if gov_length < min || gov_length > max {
+                    return invalid(format!(
+                        "GOV length {} is out of {}..={}",
+                        gov_length, min, max
+                    ));
+                }
+            }
+        }
+        if self.constant_bitrate == Some(true) && options.constant_bit_rate_supported != Some(true)
+        {
+            return invalid("constant bitrate isn't supported".to_string());
+        }
+        if self.guaranteed_frame_rate == Some(true)
+            && options.guaranteed_frame_rate_supported != Some(true)
+        {
+            return invalid("guaranteed frame rate isn't supported".to_string());
+        }
+        Ok(())
+    }
+
+    /// Applies the settings to `configuration`, without checking them.
+    pub fn apply(&self, configuration: &mut tt::VideoEncoder2Configuration) {
+        if let Some(encoding) = &self.encoding {
+            configuration.encoding = encoding.media2_name().to_string();
+        }
+        if let Some((width, height)) = self.resolution {
+            configuration.resolution = tt::VideoResolution2 { width, height };
+        }
+        if self.gov_length.is_some() {
+            configuration.gov_length = self.gov_length;
+        }
+        if self.guaranteed_frame_rate.is_some() {
+            configuration.guaranteed_frame_rate = self.guaranteed_frame_rate;
+        }
+
+        if self.frame_rate.is_some() || self.bitrate.is_some() || self.constant_bitrate.is_some() {
+            let rate_control = configuration
+                .rate_control
+                .get_or_insert_with(Default::default);
+            if let Some(frame_rate) = self.frame_rate {
+                rate_control.frame_rate_limit = frame_rate;
+            }
+            if let Some(bitrate) = self.bitrate {
+                rate_control.bitrate_limit = bitrate;
+            }
+            if self.constant_bitrate.is_some() {
+                rate_control.constant_bit_rate = self.constant_bitrate;
+            }
+        }
+    }
+}
+
+/// Changes the video encoder configuration `token` after checking `settings` against its
+/// options.
+pub async fn set_encoder<T: Transport>(
+    media2_service: &T,
+    token: &tt::ReferenceToken,
+    settings: &EncoderSettings,
+) -> Result<(), Error> {
+    let request = media2::GetConfiguration {
+        configuration_token: Some(token.clone()),
+        profile_token: None,
+    };
+    let mut configuration = media2::get_video_encoder_configurations(media2_service, &request)
+        .await?
+        .configurations
+        .into_iter()
+        .find(|c| c.token == *token)
+        .ok_or_else(|| Error::Response(format!("no video encoder configuration {}", token.0)))?;
+    let options = media2::get_video_encoder_configuration_options(media2_service, &request)
+        .await?
+        .options;
+
+    settings.validate(&configuration, &options)?;
+    settings.apply(&mut configuration);
+    media2::set_video_encoder_configuration(
+        media2_service,
+        &media2::SetVideoEncoderConfiguration { configuration },
+    )
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(rectangle_only: bool) -> media2::MaskOptions {
+        media2::MaskOptions {
+            max_masks: 4,
+            max_points: 6,
+            types: vec!["Color".to_string(), "Blurred".to_string()],
+            color: tt::ColorOptions {
+                color_options_choice: tt::ColorOptionsChoice::ColorList(vec![tt::Color {
+                    x: 0.0,
+                    y: 0.0,
+                    z: 0.0,
+                    colorspace: None,
+                }]),
+            },
+            rectangle_only: Some(rectangle_only),
+            single_color_only: None,
+        }
+    }
+
+    fn black() -> MaskFill {
+        MaskFill::Color(tt::Color {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            colorspace: Some("http://www.onvif.org/ver10/colorspace/YCbCr".to_string()),
+        })
+    }
+
+    #[test]
+    fn validates_against_options() {
+        let rectangle = MaskArea::rectangle(-0.5, 0.5, 0.5, -0.5);
+        let triangle = MaskArea::new()
+            .point(0.0, 0.5)
+            .point(0.5, -0.5)
+            .point(-0.5, -0.5);
+        assert!(rectangle.is_rectangle());
+        assert!(!triangle.is_rectangle());
+
+        assert!(PrivacyMask::new(rectangle.clone(), black())
+            .validate(&options(true))
+            .is_ok());
+        assert!(PrivacyMask::new(triangle.clone(), MaskFill::Blurred)
+            .validate(&options(false))
+            .is_ok());
+
+        for invalid in &[
+            PrivacyMask::new(triangle, MaskFill::Blurred),
+            PrivacyMask::new(rectangle.clone(), MaskFill::Pixelated),
+            PrivacyMask::new(MaskArea::rectangle(-1.5, 0.5, 0.5, -0.5), black()),
+            PrivacyMask::new(
+                rectangle,
+                MaskFill::Color(tt::Color {
+                    x: 1.0,
+                    y: 0.0,
+                    z: 0.0,
+                    colorspace: None,
+                }),
+            ),
+        ] {
+            assert!(invalid.validate(&options(true)).is_err(), "{:?}", invalid);
+        }
+    }
+
+    #[test]
+    fn encoder_settings() {
+        let options: media2::GetVideoEncoderConfigurationOptionsResponse = yaserde::de::from_str(
+            r#"
+            <tr2:GetVideoEncoderConfigurationOptionsResponse
+                xmlns:tr2="http://www.onvif.org/ver20/media/wsdl"
+                xmlns:tt="http://www.onvif.org/ver10/schema">
+                <tr2:Options GovLengthRange="1 120" FrameRatesSupported="30 25 15"
+                    ConstantBitRateSupported="true">
+                    <tt:Encoding>H265</tt:Encoding>
+                    <tt:QualityRange><tt:Min>0</tt:Min><tt:Max>100</tt:Max></tt:QualityRange>
+                    <tt:ResolutionsAvailable><tt:Width>1920</tt:Width><tt:Height>1080</tt:Height></tt:ResolutionsAvailable>
+                    <tt:BitrateRange><tt:Min>256</tt:Min><tt:Max>16384</tt:Max></tt:BitrateRange>
+                </tr2:Options>
+            </tr2:GetVideoEncoderConfigurationOptionsResponse>
+            "#,
+        )
+        .unwrap();
+        let mut configuration = tt::VideoEncoder2Configuration {
+            encoding: "H265".to_string(),
+            ..Default::default()
+        };
+
+        let settings = EncoderSettings::new()
+            .resolution(1920, 1080)
+            .frame_rate(25.0)
+            .bitrate(4096)
+            .constant_bitrate(true);
+        settings.validate(&configuration, &options.options).unwrap();
+        settings.apply(&mut configuration);
+        let rate_control = configuration.rate_control.as_ref().unwrap();
+        assert_eq!(rate_control.bitrate_limit, 4096);
+        assert_eq!(rate_control.constant_bit_rate, Some(true));
+        assert_eq!(configuration.resolution.width, 1920);
+
+        for invalid in &[
+            EncoderSettings::new().guaranteed_frame_rate(true),
+            EncoderSettings::new().encoding(Codec::H264),
+            EncoderSettings::new().frame_rate(60.0),
+            EncoderSettings::new().bitrate(100_000),
+            EncoderSettings::new().gov_length(240),
+        ] {
+            assert!(
+                matches!(
+                    invalid.validate(&configuration, &options.options),
+                    Err(Error::InvalidEncoder(_))
+                ),
+                "{:?}",
+                invalid
+            );
+        }
+    }
+}
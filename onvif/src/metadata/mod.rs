@@ -0,0 +1,265 @@
+//! Object metadata of video analytics (Profile M), as sent in the metadata stream and in events.
+//!
+//! The generated `tt:Frame` types lose the class names of the `tt:Type` likelihoods and can't
+//! decode streams mixing frames and events, so metadata is decoded from the raw XML here.
+
+use crate::event::{self, Event};
+use serde::Serialize;
+use thiserror::Error;
+use xmltree::{Element, XMLNode};
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Invalid metadata: {0}")]
+    Parse(String),
+}
+
+/// A point in normalized coordinates, see [`Rectangle`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// A rectangle in normalized coordinates, where (-1, -1) is the bottom left and (1, 1) the top
+/// right corner of the image, unless the frame has a transformation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+pub struct Rectangle {
+    pub left: f64,
+    pub top: f64,
+    pub right: f64,
+    pub bottom: f64,
+}
+
+/// A class the object may belong to, e.g. `Human` or `Vehicle`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ClassCandidate {
+    pub class: String,
+    pub likelihood: Option<f64>,
+}
+
+/// A detected object.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Object {
+    /// Identifies the object across frames while it's tracked.
+    pub object_id: Option<i64>,
+
+    /// Candidates from both the `tt:Type` and deprecated `tt:ClassCandidate` elements.
+    pub classes: Vec<ClassCandidate>,
+
+    pub bounding_box: Option<Rectangle>,
+    pub center_of_gravity: Option<Point>,
+}
+
+impl Object {
+    /// The class with the highest likelihood. Classes without a likelihood count as certain.
+    pub fn most_likely_class(&self) -> Option<&str> {
+        self.classes
+            .iter()
+            .max_by(|a, b| {
+                let a = a.likelihood.unwrap_or(1.0);
+                let b = b.likelihood.unwrap_or(1.0);
+                a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|c| c.class.as_str())
+    }
+}
+
+/// A `tt:Frame` of the video analytics metadata.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Frame {
+    pub utc_time: Option<String>,
+    pub objects: Vec<Object>,
+}
+
+/// The content of a `tt:MetadataStream` document, as sent in RTP packets of a metadata stream.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct MetadataStream {
+    pub frames: Vec<Frame>,
+    pub events: Vec<Event>,
+}
+
+/// Decodes a `tt:MetadataStream` document. PTZ status and extensions are skipped.
+pub fn parse_metadata_stream(xml: &str) -> Result<MetadataStream, Error> {
+    let root = Element::parse(xml.as_bytes()).map_err(|e| Error::Parse(e.to_string()))?;
+    if root.name != "MetadataStream" {
+        return Err(Error::Parse(format!(
+            "expected MetadataStream, got {}",
+            root.name
+        )));
+    }
+
+    let mut stream = MetadataStream::default();
+    for analytics in children(&root, "VideoAnalytics") {
+        stream
+            .frames
+            .extend(children(analytics, "Frame").map(parse_frame));
+    }
+    for events in children(&root, "Event") {
+        stream
+            .events
+            .extend(children(events, "NotificationMessage").map(event::parse_notification_message));
+    }
+    Ok(stream)
+}
+
+fn parse_frame(frame: &Element) -> Frame {
+    Frame {
+        utc_time: frame.attributes.get("UtcTime").cloned(),
+        objects: children(frame, "Object").map(parse_object).collect(),
+    }
+}
+
+pub(crate) fn parse_object(object: &Element) -> Object {
+    let appearance = object.get_child("Appearance");
+    let shape = appearance.and_then(|a| a.get_child("Shape"));
+
+    let mut classes = vec![];
+    if let Some(class) = appearance.and_then(|a| a.get_child("Class")) {
+        for candidate in children(class, "ClassCandidate") {
+            if let Some(name) = candidate.get_child("Type").and_then(text) {
+                classes.push(ClassCandidate {
+                    class: name,
+                    likelihood: candidate
+                        .get_child("Likelihood")
+                        .and_then(text)
+                        .and_then(|l| l.parse().ok()),
+                });
+            }
+        }
+        for candidate in children(class, "Type") {
+            if let Some(name) = text(candidate) {
+                classes.push(ClassCandidate {
+                    class: name,
+                    likelihood: number(candidate, "Likelihood"),
+                });
+            }
+        }
+    }
+
+    Object {
+        object_id: object
+            .attributes
+            .get("ObjectId")
+            .and_then(|id| id.trim().parse().ok()),
+        classes,
+        bounding_box: shape
+            .and_then(|s| s.get_child("BoundingBox"))
+            .and_then(|b| {
+                Some(Rectangle {
+                    left: number(b, "left")?,
+                    top: number(b, "top")?,
+                    right: number(b, "right")?,
+                    bottom: number(b, "bottom")?,
+                })
+            }),
+        center_of_gravity: shape
+            .and_then(|s| s.get_child("CenterOfGravity"))
+            .and_then(|c| {
+                Some(Point {
+                    x: number(c, "x")?,
+                    y: number(c, "y")?,
+                })
+            }),
+    }
+}
+
+fn number(element: &Element, attribute: &str) -> Option<f64> {
+    element.attributes.get(attribute)?.trim().parse().ok()
+}
+
+fn text(element: &Element) -> Option<String> {
+    element.get_text().map(|t| t.trim().to_string())
+}
+
+fn children<'a>(element: &'a Element, name: &'a str) -> impl Iterator<Item = &'a Element> + 'a {
+    element.children.iter().filter_map(move |node| match node {
+        XMLNode::Element(child) if child.name == name => Some(child),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn metadata_stream() {
+        let stream = parse_metadata_stream(
+            r#"
+            <tt:MetadataStream
+                xmlns:tt="http://www.onvif.org/ver10/schema"
+                xmlns:wsnt="http://docs.oasis-open.org/wsn/b-2">
+                <tt:VideoAnalytics>
+                    <tt:Frame UtcTime="2021-06-01T10:00:00.1Z">
+                        <tt:Object ObjectId="12">
+                            <tt:Appearance>
+                                <tt:Shape>
+                                    <tt:BoundingBox left="-0.5" top="0.5" right="0.1" bottom="-0.2"/>
+                                    <tt:CenterOfGravity x="-0.2" y="0.15"/>
+                                </tt:Shape>
+                                <tt:Class>
+                                    <tt:Type Likelihood="0.3">Vehicle</tt:Type>
+                                    <tt:Type Likelihood="0.7">Human</tt:Type>
+                                </tt:Class>
+                            </tt:Appearance>
+                        </tt:Object>
+                        <tt:Object ObjectId="13">
+                            <tt:Appearance>
+                                <tt:Class>
+                                    <tt:ClassCandidate>
+                                        <tt:Type>Vehical</tt:Type>
+                                        <tt:Likelihood>0.9</tt:Likelihood>
+                                    </tt:ClassCandidate>
+                                </tt:Class>
+                            </tt:Appearance>
+                        </tt:Object>
+                    </tt:Frame>
+                </tt:VideoAnalytics>
+                <tt:Event>
+                    <wsnt:NotificationMessage>
+                        <wsnt:Topic>tns1:RuleEngine/FieldDetector/ObjectsInside</wsnt:Topic>
+                        <wsnt:Message>
+                            <tt:Message UtcTime="2021-06-01T10:00:00Z" PropertyOperation="Changed">
+                                <tt:Data>
+                                    <tt:SimpleItem Name="IsInside" Value="true"/>
+                                </tt:Data>
+                            </tt:Message>
+                        </wsnt:Message>
+                    </wsnt:NotificationMessage>
+                </tt:Event>
+            </tt:MetadataStream>
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(stream.frames.len(), 1);
+        let objects = &stream.frames[0].objects;
+        assert_eq!(objects.len(), 2);
+        assert_eq!(objects[0].object_id, Some(12));
+        assert_eq!(objects[0].most_likely_class(), Some("Human"));
+        assert_eq!(
+            objects[0].bounding_box,
+            Some(Rectangle {
+                left: -0.5,
+                top: 0.5,
+                right: 0.1,
+                bottom: -0.2
+            })
+        );
+        assert_eq!(
+            objects[0].center_of_gravity,
+            Some(Point { x: -0.2, y: 0.15 })
+        );
+        assert_eq!(
+            objects[1].classes,
+            vec![ClassCandidate {
+                class: "Vehical".to_string(),
+                likelihood: Some(0.9)
+            }]
+        );
+
+        assert_eq!(stream.events.len(), 1);
+        assert_eq!(stream.events[0].data["IsInside"], "true");
+    }
+}
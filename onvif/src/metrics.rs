@@ -0,0 +1,37 @@
+//! Instrumentation through the [`metrics`](https://docs.rs/metrics) facade, enabled with the
+//! `metrics` feature. Install any `metrics` recorder (e.g. a Prometheus exporter) to collect:
+//!
+//! - `onvif_requests_total`: counter of SOAP requests, labelled by `service` (the path of the
+//!   service address) and `operation`,
+//! - `onvif_request_errors_total`: counter of failed requests, additionally labelled by `class`
+//!   (`authorization`, `connection`, `timeout`, ...),
+//! - `onvif_request_duration_seconds`: histogram of request latency, including authentication
+//!   round trips, labelled like `onvif_requests_total`,
+//! - `onvif_request_size_bytes` and `onvif_response_size_bytes`: histograms of the sizes of the
+//!   SOAP envelopes of successful requests, labelled like `onvif_requests_total`,
+//! - `onvif_slow_requests_total`: counter of requests taking at least the slow call threshold
+//!   of the client, if set, labelled like `onvif_requests_total`,
+//! - `onvif_request_retries_total`: counter of extra round trips, labelled by `reason` (`auth` for
+//!   falling back to another authentication mode, `digest_challenge`, `redirect`, `resubscribe`
+//!   for another attempt to restore a lost event subscription),
+//! - `onvif_active_subscriptions`: gauge of event subscriptions created and not yet unsubscribed.
+//!
+//! Request metrics are recorded by the SOAP client of `onvif-soap`, whose `metrics` feature this
+//! feature enables. Without the feature, instrumentation compiles to nothing.
+
+#![cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+
+pub(crate) fn retry(reason: &'static str) {
+    #[cfg(feature = "metrics")]
+    metrics::increment_counter!("onvif_request_retries_total", "reason" => reason);
+}
+
+pub(crate) fn subscription_created() {
+    #[cfg(feature = "metrics")]
+    metrics::increment_gauge!("onvif_active_subscriptions", 1.0);
+}
+
+pub(crate) fn subscription_deleted() {
+    #[cfg(feature = "metrics")]
+    metrics::decrement_gauge!("onvif_active_subscriptions", 1.0);
+}
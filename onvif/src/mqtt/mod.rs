@@ -0,0 +1,183 @@
+//! Publishes events of a subscription to MQTT, e.g. for Home Assistant. Requires the
+//! `mqtt-bridge` feature.
+//!
+//! Topics are below `{prefix}/{device}`:
+//!
+//! - `motion` and `tamper`: `ON` or `OFF`, retained, for [`crate::event::MOTION_TOPICS`] and
+//!   [`crate::event::TAMPER_TOPICS`],
+//! - `sound`: `ON` or `OFF` for [`crate::event::DETECTED_SOUND_TOPIC`],
+//! - `events/{topic}`: all events as JSON, e.g. `events/RuleEngine/LineDetector/Crossed`,
+//! - `availability`: `online` or `offline`, retained, as the subscription works or is lost.
+//!
+//! States of events about a video source, as most are, go to e.g. `motion/{video source}`
+//! instead, so that cameras with several sensors get a topic per sensor.
+//!
+//! See `examples/mqtt_bridge.rs` for a complete bridge.
+
+use crate::event::{Event, Notification};
+use futures_core::stream::Stream;
+use futures_util::{pin_mut, StreamExt};
+use rumqttc::{AsyncClient, ClientError, QoS};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("MQTT error: {0}")]
+    Mqtt(#[from] ClientError),
+
+    #[error("Failed to serialize event: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+/// A message to publish.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Message {
+    pub topic: String,
+    pub payload: String,
+
+    /// Whether the broker keeps the message for clients subscribing later, as is done for states.
+    pub retain: bool,
+}
+
+/// The messages an event is published as: its state, if it's a known sensor, and the event as
+/// JSON.
+pub fn messages(prefix: &str, device: &str, event: &Event) -> Result<Vec<Message>, Error> {
+    let base = format!("{}/{}", prefix, device);
+    let mut messages = vec![];
+
+    let states = [
+        ("motion", event.is_motion()),
+        ("tamper", event.is_tamper()),
+        (
+            "sound",
+            event.detected_sound().map(|sound| sound.is_sound_detected),
+        ),
+    ];
+    for (sensor, state) in states.iter() {
+        let state = match state {
+            Some(state) => *state,
+            None => continue,
+        };
+        let topic = match event.video_source() {
+            Some(source) => format!("{}/{}/{}", base, sensor, topic_level(source)),
+            None => format!("{}/{}", base, sensor),
+        };
+        messages.push(Message {
+            topic,
+            payload: if state { "ON" } else { "OFF" }.to_string(),
+            retain: true,
+        });
+    }
+
+    let path = event
+        .topic
+        .split_once(':')
+        .map_or(event.topic.as_str(), |(_, path)| path);
+    messages.push(Message {
+        topic: format!("{}/events/{}", base, path),
+        payload: serde_json::to_string(event)?,
+        retain: false,
+    });
+    Ok(messages)
+}
+
+/// Topic of availability messages, e.g. for the last will of the MQTT connection.
+pub fn availability_topic(prefix: &str, device: &str) -> String {
+    format!("{}/{}/availability", prefix, device)
+}
+
+/// Publishes `notifications`, e.g. of [`crate::event::subscribe`], until the stream ends.
+///
+/// The event loop of `client` has to be polled concurrently for messages to be sent.
+pub async fn bridge<S>(
+    client: &AsyncClient,
+    prefix: &str,
+    device: &str,
+    notifications: S,
+) -> Result<(), Error>
+where
+    S: Stream<Item = Notification>,
+{
+    let availability = availability_topic(prefix, device);
+    client
+        .publish(&availability, QoS::AtLeastOnce, true, "online")
+        .await?;
+
+    pin_mut!(notifications);
+    while let Some(notification) = notifications.next().await {
+        match notification {
+            Notification::Event(event) | Notification::PropertyState(event) => {
+                for message in messages(prefix, device, &event)? {
+                    client
+                        .publish(
+                            message.topic,
+                            QoS::AtLeastOnce,
+                            message.retain,
+                            message.payload,
+                        )
+                        .await?;
+                }
+            }
+            Notification::SubscriptionLost { error } => {
+                tracing::debug!("{}: subscription lost: {}", device, error);
+                client
+                    .publish(&availability, QoS::AtLeastOnce, true, "offline")
+                    .await?;
+            }
+            Notification::SubscriptionRestored => {
+                client
+                    .publish(&availability, QoS::AtLeastOnce, true, "online")
+                    .await?;
+            }
+        }
+    }
+
+    client
+        .publish(&availability, QoS::AtLeastOnce, true, "offline")
+        .await?;
+    Ok(())
+}
+
+/// Replaces characters that have a special meaning in topics.
+fn topic_level(name: &str) -> String {
+    name.replace(|c: char| matches!(c, '/' | '+' | '#'), "_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_messages() {
+        let mut event = Event {
+            topic: "tns1:RuleEngine/CellMotionDetector/Motion".to_string(),
+            ..Default::default()
+        };
+        event.source.insert(
+            "VideoSourceConfigurationToken".to_string(),
+            "video/1".to_string(),
+        );
+        event
+            .data
+            .insert("IsMotion".to_string(), "true".to_string());
+
+        let published = messages("onvif", "cam1", &event).unwrap();
+        assert_eq!(
+            published[0],
+            Message {
+                topic: "onvif/cam1/motion/video_1".to_string(),
+                payload: "ON".to_string(),
+                retain: true,
+            }
+        );
+        assert_eq!(
+            published[1].topic,
+            "onvif/cam1/events/RuleEngine/CellMotionDetector/Motion"
+        );
+        assert!(!published[1].retain);
+        assert_eq!(published.len(), 2);
+
+        event.topic = "tns1:RuleEngine/LineDetector/Crossed".to_string();
+        assert_eq!(messages("onvif", "cam1", &event).unwrap().len(), 1);
+    }
+}
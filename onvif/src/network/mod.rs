@@ -0,0 +1,769 @@
+//! Network settings of a device that can cut off the connection to it if set carelessly.
+//!
+//! [`set_protocols`] changes the HTTP, HTTPS and RTSP ports while making sure the service address
+//! the client is connected through stays reachable, and returns the address to use afterwards.
+//!
+//! [`set_ipv4`], [`set_ipv6`] and [`set_zero_configuration`] change the addressing of an
+//! interface. A wrong address makes remote devices unreachable until someone resets them on
+//! site, so they support a dry run that only reports what would change.
+//!
+//! ```no_run
+//! # async fn example(device_service: onvif::soap::client::Client) {
+//! use onvif::network::{self, Disconnect, Protocol};
+//!
+//! let mut protocols = network::protocols(&device_service).await.unwrap();
+//! for setting in &mut protocols {
+//!     if setting.protocol == Protocol::Http {
+//!         setting.ports = vec![8080];
+//!     }
+//! }
+//! let uri = network::set_protocols(
+//!     &device_service,
+//!     &device_service.uri(),
+//!     &protocols,
+//!     Disconnect::Refuse,
+//! )
+//! .await
+//! .unwrap();
+//! device_service.set_uri(&uri);
+//! # }
+//! ```
+
+use crate::health::wait::{self, Progress};
+use schema::{
+    devicemgmt, onvif as tt,
+    transport::{Error as TransportError, Transport},
+};
+use std::{
+    convert::TryFrom,
+    net::{Ipv4Addr, Ipv6Addr},
+    time::Duration,
+};
+use thiserror::Error;
+use url::Url;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Transport error: {0}")]
+    Transport(#[from] TransportError),
+
+    /// The change would make the address the client is connected through unreachable.
+    #[error("Refusing to disconnect: {0}")]
+    WouldDisconnect(String),
+
+    #[error("Unknown network interface: {0}")]
+    UnknownInterface(String),
+
+    #[error("Invalid address: {0}")]
+    InvalidAddress(String),
+
+    #[error("Unexpected response: {0}")]
+    Response(String),
+
+    #[error("Device didn't come back after rebooting: {0}")]
+    Reboot(#[from] wait::Error),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Protocol {
+    Http,
+    Https,
+    Rtsp,
+}
+
+impl Protocol {
+    fn scheme(self) -> &'static str {
+        match self {
+            Protocol::Http => "http",
+            Protocol::Https => "https",
+            Protocol::Rtsp => "rtsp",
+        }
+    }
+
+    fn to_schema(self) -> tt::NetworkProtocolType {
+        match self {
+            Protocol::Http => tt::NetworkProtocolType::Http,
+            Protocol::Https => tt::NetworkProtocolType::Https,
+            Protocol::Rtsp => tt::NetworkProtocolType::Rtsp,
+        }
+    }
+}
+
+/// Whether a protocol is enabled and on which ports.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProtocolSetting {
+    pub protocol: Protocol,
+    pub enabled: bool,
+    pub ports: Vec<u16>,
+}
+
+/// What [`set_protocols`] does with changes that cut off the current connection.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Disconnect {
+    /// Fails with [`Error::WouldDisconnect`] without changing anything.
+    Refuse,
+
+    /// Logs a warning and applies the change. The returned address may be unreachable.
+    Warn,
+}
+
+/// Requests the protocol settings. Protocols other than HTTP, HTTPS and RTSP are left out.
+pub async fn protocols<T: Transport>(device_service: &T) -> Result<Vec<ProtocolSetting>, Error> {
+    devicemgmt::get_network_protocols(device_service, &Default::default())
+        .await?
+        .network_protocols
+        .into_iter()
+        .filter_map(|protocol| {
+            let kind = match protocol.name {
+                tt::NetworkProtocolType::Http => Protocol::Http,
+                tt::NetworkProtocolType::Https => Protocol::Https,
+                tt::NetworkProtocolType::Rtsp => Protocol::Rtsp,
+                _ => return None,
+            };
+            let ports: Result<Vec<u16>, Error> = protocol
+                .port
+                .iter()
+                .map(|&port| {
+                    u16::try_from(port)
+                        .map_err(|_| Error::Response(format!("invalid port {}", port)))
+                })
+                .collect();
+            Some(ports.map(|ports| ProtocolSetting {
+                protocol: kind,
+                enabled: protocol.enabled,
+                ports,
+            }))
+        })
+        .collect()
+}
+
+/// Applies `settings` and returns the service address to use afterwards, which is `connected`
+/// with the port or scheme changed if the device no longer serves it.
+///
+/// The change disconnects if it disables the protocol of `connected` or removes its port and no
+/// other enabled HTTP or HTTPS port remains to switch to. What happens then is up to `disconnect`.
+pub async fn set_protocols<T: Transport>(
+    device_service: &T,
+    connected: &Url,
+    settings: &[ProtocolSetting],
+    disconnect: Disconnect,
+) -> Result<Url, Error> {
+    let uri = match reconnect_uri(connected, settings) {
+        Ok(uri) => uri,
+        Err(reason) => match disconnect {
+            Disconnect::Refuse => return Err(Error::WouldDisconnect(reason)),
+            Disconnect::Warn => {
+                tracing::warn!("changing network protocols disconnects: {}", reason);
+                connected.clone()
+            }
+        },
+    };
+
+    devicemgmt::set_network_protocols(
+        device_service,
+        &devicemgmt::SetNetworkProtocols {
+            network_protocols: settings
+                .iter()
+                .map(|setting| tt::NetworkProtocol {
+                    name: setting.protocol.to_schema(),
+                    enabled: setting.enabled,
+                    port: setting.ports.iter().map(|&port| port.into()).collect(),
+                    extension: None,
+                })
+                .collect(),
+        },
+    )
+    .await?;
+    Ok(uri)
+}
+
+/// The address to reach the service at `connected` after `settings` are applied, or why there
+/// is none.
+fn reconnect_uri(connected: &Url, settings: &[ProtocolSetting]) -> Result<Url, String> {
+    let current = match connected.scheme() {
+        "http" => Protocol::Http,
+        "https" => Protocol::Https,
+        scheme => return Err(format!("unexpected scheme {}", scheme)),
+    };
+    let port = connected.port_or_known_default();
+
+    // Protocols that aren't in `settings` are left as they are.
+    let setting = match settings.iter().find(|s| s.protocol == current) {
+        Some(setting) => setting,
+        None => return Ok(connected.clone()),
+    };
+    if setting.enabled && port.map_or(false, |port| setting.ports.contains(&port)) {
+        return Ok(connected.clone());
+    }
+
+    // Same scheme on another port, then the other scheme. Switching from HTTPS to HTTP would send
+    // credentials in the clear, so only the other direction is taken.
+    let candidates = [
+        Some(current),
+        Some(Protocol::Https).filter(|_| current == Protocol::Http),
+    ];
+    for protocol in candidates.iter().flatten() {
+        let new_port = settings
+            .iter()
+            .find(|s| s.protocol == *protocol && s.enabled)
+            .and_then(|s| s.ports.first());
+        if let Some(&new_port) = new_port {
+            let mut uri = connected.clone();
+            uri.set_scheme(protocol.scheme())
+                .and_then(|_| uri.set_port(Some(new_port)))
+                .map_err(|_| format!("can't switch {} to {}", connected, protocol.scheme()))?;
+            return Ok(uri);
+        }
+    }
+    Err(format!(
+        "{} would no longer be served",
+        current.scheme().to_uppercase()
+    ))
+}
+
+/// How an interface gets its IPv4 address.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Ipv4Addressing {
+    Dhcp,
+    Static {
+        address: Ipv4Addr,
+        prefix_length: u8,
+    },
+}
+
+impl Ipv4Addressing {
+    /// A static address with a netmask such as `255.255.255.0` rather than a prefix length.
+    pub fn with_netmask(address: Ipv4Addr, netmask: Ipv4Addr) -> Result<Self, Error> {
+        let mask = u32::from(netmask);
+        // Netmasks are contiguous ones followed by zeros.
+        if mask.leading_ones() + mask.trailing_zeros() != 32 {
+            return Err(Error::InvalidAddress(format!(
+                "invalid netmask {}",
+                netmask
+            )));
+        }
+        Ok(Ipv4Addressing::Static {
+            address,
+            prefix_length: mask.leading_ones() as u8,
+        })
+    }
+}
+
+impl std::str::FromStr for Ipv4Addressing {
+    type Err = Error;
+
+    /// Parses `dhcp` or an address with prefix length, e.g. `192.168.0.10/24`.
+    fn from_str(s: &str) -> Result<Self, Error> {
+        if s.eq_ignore_ascii_case("dhcp") {
+            return Ok(Ipv4Addressing::Dhcp);
+        }
+        let (address, prefix_length) = parse_prefixed(s, 32)?;
+        Ok(Ipv4Addressing::Static {
+            address: address
+                .parse()
+                .map_err(|_| Error::InvalidAddress(s.to_string()))?,
+            prefix_length,
+        })
+    }
+}
+
+/// How an interface gets its IPv6 addresses, besides its link-local address.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Ipv6Addressing {
+    /// DHCPv6 and router advertisements, as the network offers.
+    Dhcp,
+    Static {
+        address: Ipv6Addr,
+        prefix_length: u8,
+    },
+}
+
+impl std::str::FromStr for Ipv6Addressing {
+    type Err = Error;
+
+    /// Parses `dhcp` or an address with prefix length, e.g. `2001:db8::10/64`.
+    fn from_str(s: &str) -> Result<Self, Error> {
+        if s.eq_ignore_ascii_case("dhcp") {
+            return Ok(Ipv6Addressing::Dhcp);
+        }
+        let (address, prefix_length) = parse_prefixed(s, 128)?;
+        Ok(Ipv6Addressing::Static {
+            address: address
+                .parse()
+                .map_err(|_| Error::InvalidAddress(s.to_string()))?,
+            prefix_length,
+        })
+    }
+}
+
+fn parse_prefixed(s: &str, max_prefix_length: u8) -> Result<(&str, u8), Error> {
+    let (address, prefix_length) = s
+        .split_once('/')
+        .ok_or_else(|| Error::InvalidAddress(format!("{} has no prefix length", s)))?;
+    match prefix_length.parse() {
+        Ok(prefix_length) if prefix_length <= max_prefix_length => Ok((address, prefix_length)),
+        _ => Err(Error::InvalidAddress(format!(
+            "invalid prefix length in {}",
+            s
+        ))),
+    }
+}
+
+/// Outcome of a change of an interface.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InterfaceChange {
+    /// What changes, e.g. `IPv4: DHCP -> 192.168.0.10/24`. Empty if the interface is already
+    /// configured as requested, in which case nothing is sent.
+    pub changes: Vec<String>,
+
+    /// Whether the device only applies the change after a reboot. Always `false` for dry runs.
+    pub reboot_needed: bool,
+}
+
+/// Switches the IPv4 addressing of the interface with `token`. With `dry_run`, only reports what
+/// would change.
+///
+/// Devices reached through the changed address become unreachable until clients use the new
+/// address, unless [`InterfaceChange::reboot_needed`] is set.
+pub async fn set_ipv4<T: Transport>(
+    device_service: &T,
+    token: &str,
+    addressing: &Ipv4Addressing,
+    dry_run: bool,
+) -> Result<InterfaceChange, Error> {
+    let interface = network_interface(device_service, token).await?;
+    let current = interface.i_pv_4.first().map(|ipv4| &ipv4.config);
+    let describe = |dhcp: bool, manual: &[tt::PrefixedIPv4Address]| match manual.first() {
+        _ if dhcp => "DHCP".to_string(),
+        Some(address) => format!("{}/{}", address.address.0, address.prefix_length),
+        None => "no address".to_string(),
+    };
+    let from = current.map_or_else(
+        || "disabled".to_string(),
+        |config| describe(config.dhcp, &config.manual),
+    );
+
+    let (dhcp, manual) = match addressing {
+        Ipv4Addressing::Dhcp => (true, vec![]),
+        Ipv4Addressing::Static {
+            address,
+            prefix_length,
+        } => (
+            false,
+            vec![tt::PrefixedIPv4Address {
+                address: tt::Ipv4Address(address.to_string()),
+                prefix_length: (*prefix_length).into(),
+            }],
+        ),
+    };
+    let to = describe(dhcp, &manual);
+    if from == to {
+        return Ok(InterfaceChange::default());
+    }
+
+    let configuration = tt::NetworkInterfaceSetConfiguration {
+        i_pv_4: vec![tt::Ipv4NetworkInterfaceSetConfiguration {
+            enabled: Some(true),
+            manual,
+            dhcp: Some(dhcp),
+        }],
+        ..Default::default()
+    };
+    apply(
+        device_service,
+        token,
+        configuration,
+        format!("IPv4: {} -> {}", from, to),
+        dry_run,
+    )
+    .await
+}
+
+/// Switches the IPv6 addressing of the interface with `token`, see [`set_ipv4`].
+pub async fn set_ipv6<T: Transport>(
+    device_service: &T,
+    token: &str,
+    addressing: &Ipv6Addressing,
+    dry_run: bool,
+) -> Result<InterfaceChange, Error> {
+    let interface = network_interface(device_service, token).await?;
+    let current = interface
+        .i_pv_6
+        .first()
+        .and_then(|ipv6| ipv6.config.as_ref());
+    let describe = |dhcp: bool, manual: &[tt::PrefixedIPv6Address]| match manual.first() {
+        _ if dhcp => "DHCP".to_string(),
+        Some(address) => format!("{}/{}", address.address.0, address.prefix_length),
+        None => "no address".to_string(),
+    };
+    let from = current.map_or_else(
+        || "disabled".to_string(),
+        |config| {
+            describe(
+                config.dhcp != tt::Ipv6DHCPConfiguration::Off,
+                &config.manual,
+            )
+        },
+    );
+
+    let (dhcp, manual) = match addressing {
+        Ipv6Addressing::Dhcp => (tt::Ipv6DHCPConfiguration::Auto, vec![]),
+        Ipv6Addressing::Static {
+            address,
+            prefix_length,
+        } => (
+            tt::Ipv6DHCPConfiguration::Off,
+            vec![tt::PrefixedIPv6Address {
+                address: tt::Ipv6Address(address.to_string()),
+                prefix_length: (*prefix_length).into(),
+            }],
+        ),
+    };
+    let to = describe(dhcp != tt::Ipv6DHCPConfiguration::Off, &manual);
+    if from == to {
+        return Ok(InterfaceChange::default());
+    }
+
+    let accept_router_advert = dhcp != tt::Ipv6DHCPConfiguration::Off;
+    let configuration = tt::NetworkInterfaceSetConfiguration {
+        i_pv_6: vec![tt::Ipv6NetworkInterfaceSetConfiguration {
+            enabled: Some(true),
+            accept_router_advert: Some(accept_router_advert),
+            manual,
+            dhcp: Some(dhcp),
+        }],
+        ..Default::default()
+    };
+    apply(
+        device_service,
+        token,
+        configuration,
+        format!("IPv6: {} -> {}", from, to),
+        dry_run,
+    )
+    .await
+}
+
+/// Enables or disables IPv4 link-local addressing (`169.254.0.0/16`) on the interface with
+/// `token`. With `dry_run`, only reports what would change.
+pub async fn set_zero_configuration<T: Transport>(
+    device_service: &T,
+    token: &str,
+    enabled: bool,
+    dry_run: bool,
+) -> Result<InterfaceChange, Error> {
+    let current = devicemgmt::get_zero_configuration(device_service, &Default::default())
+        .await?
+        .zero_configuration;
+    let configurations = std::iter::once(&current).chain(
+        current
+            .extension
+            .iter()
+            .flat_map(|extension| extension.additional.iter()),
+    );
+    let was_enabled = configurations
+        .filter(|configuration| configuration.interface_token.0 == token)
+        .map(|configuration| configuration.enabled)
+        .next()
+        .ok_or_else(|| Error::UnknownInterface(token.to_string()))?;
+    if was_enabled == enabled {
+        return Ok(InterfaceChange::default());
+    }
+
+    let change = InterfaceChange {
+        changes: vec![format!(
+            "zero configuration: {}",
+            if enabled { "enabled" } else { "disabled" }
+        )],
+        reboot_needed: false,
+    };
+    if !dry_run {
+        devicemgmt::set_zero_configuration(
+            device_service,
+            &devicemgmt::SetZeroConfiguration {
+                interface_token: tt::ReferenceToken(token.to_string()),
+                enabled,
+            },
+        )
+        .await?;
+    }
+    Ok(change)
+}
+
+async fn network_interface<T: Transport>(
+    device_service: &T,
+    token: &str,
+) -> Result<tt::NetworkInterface, Error> {
+    devicemgmt::get_network_interfaces(device_service, &Default::default())
+        .await?
+        .network_interfaces
+        .into_iter()
+        .find(|interface| interface.token.0 == token)
+        .ok_or_else(|| Error::UnknownInterface(token.to_string()))
+}
+
+/// Reboots the device if it needs to to apply `change`, and waits for it to come back, for at
+/// most `max_wait`, see [`wait::wait_for_reboot`]. Returns how long that took, or `None` if the
+/// change didn't need a reboot.
+///
+/// `device_service` has to reach the device after the reboot. Devices come back at their new
+/// address if the change was to the address it is connected through: reboot those with
+/// `SystemReboot`, then use [`wait::wait_until_down`] with the old address and
+/// [`wait::wait_for_device`] with the new one.
+pub async fn reboot_if_needed<T, F>(
+    device_service: &T,
+    change: &InterfaceChange,
+    max_wait: Duration,
+    progress: F,
+) -> Result<Option<Duration>, Error>
+where
+    T: Transport,
+    F: FnMut(&Progress),
+{
+    if !change.reboot_needed {
+        return Ok(None);
+    }
+    devicemgmt::system_reboot(device_service, &Default::default()).await?;
+    let elapsed = wait::wait_for_reboot(device_service, max_wait, progress).await?;
+    Ok(Some(elapsed))
+}
+
+async fn apply<T: Transport>(
+    device_service: &T,
+    token: &str,
+    configuration: tt::NetworkInterfaceSetConfiguration,
+    change: String,
+    dry_run: bool,
+) -> Result<InterfaceChange, Error> {
+    if dry_run {
+        return Ok(InterfaceChange {
+            changes: vec![change],
+            reboot_needed: false,
+        });
+    }
+    tracing::debug!("{}: {}", token, change);
+    let response = devicemgmt::set_network_interfaces(
+        device_service,
+        &devicemgmt::SetNetworkInterfaces {
+            interface_token: tt::ReferenceToken(token.to_string()),
+            network_interface: configuration,
+        },
+    )
+    .await?;
+    Ok(InterfaceChange {
+        changes: vec![change],
+        reboot_needed: response.reboot_needed,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::fake_device::FakeDevice;
+
+    fn setting(protocol: Protocol, enabled: bool, ports: &[u16]) -> ProtocolSetting {
+        ProtocolSetting {
+            protocol,
+            enabled,
+            ports: ports.to_vec(),
+        }
+    }
+
+    #[test]
+    fn addressing() {
+        assert_eq!(
+            "192.168.0.10/24".parse::<Ipv4Addressing>().unwrap(),
+            Ipv4Addressing::Static {
+                address: Ipv4Addr::new(192, 168, 0, 10),
+                prefix_length: 24
+            }
+        );
+        assert_eq!(
+            "DHCP".parse::<Ipv6Addressing>().unwrap(),
+            Ipv6Addressing::Dhcp
+        );
+        assert!("192.168.0.10/33".parse::<Ipv4Addressing>().is_err());
+        assert!("2001:db8::10".parse::<Ipv6Addressing>().is_err());
+
+        assert_eq!(
+            Ipv4Addressing::with_netmask(
+                Ipv4Addr::new(10, 0, 0, 2),
+                Ipv4Addr::new(255, 255, 240, 0)
+            )
+            .unwrap(),
+            Ipv4Addressing::Static {
+                address: Ipv4Addr::new(10, 0, 0, 2),
+                prefix_length: 20
+            }
+        );
+        assert!(Ipv4Addressing::with_netmask(
+            Ipv4Addr::new(10, 0, 0, 2),
+            Ipv4Addr::new(255, 0, 255, 0)
+        )
+        .is_err());
+    }
+
+    /// An interface `eth0` using DHCP, which needs a reboot to change.
+    fn fake_device() -> FakeDevice {
+        FakeDevice::new()
+            .respond(
+                "GetNetworkInterfaces",
+                r#"
+                <tds:GetNetworkInterfacesResponse
+                    xmlns:tds="http://www.onvif.org/ver10/device/wsdl"
+                    xmlns:tt="http://www.onvif.org/ver10/schema">
+                    <tds:NetworkInterfaces token="eth0">
+                        <tt:Enabled>true</tt:Enabled>
+                        <tt:IPv4>
+                            <tt:Enabled>true</tt:Enabled>
+                            <tt:Config>
+                                <tt:FromDHCP>
+                                    <tt:Address>192.168.0.2</tt:Address>
+                                    <tt:PrefixLength>24</tt:PrefixLength>
+                                </tt:FromDHCP>
+                                <tt:DHCP>true</tt:DHCP>
+                            </tt:Config>
+                        </tt:IPv4>
+                    </tds:NetworkInterfaces>
+                </tds:GetNetworkInterfacesResponse>
+                "#,
+            )
+            .respond(
+                "SetNetworkInterfaces",
+                r#"
+                <tds:SetNetworkInterfacesResponse
+                    xmlns:tds="http://www.onvif.org/ver10/device/wsdl">
+                    <tds:RebootNeeded>true</tds:RebootNeeded>
+                </tds:SetNetworkInterfacesResponse>
+                "#,
+            )
+    }
+
+    #[tokio::test]
+    async fn dry_runs() {
+        let device = fake_device();
+        let addressing = "192.168.0.10/24".parse().unwrap();
+
+        let change = set_ipv4(&device, "eth0", &addressing, true).await.unwrap();
+        assert_eq!(change.changes, vec!["IPv4: DHCP -> 192.168.0.10/24"]);
+        assert!(device.requests("SetNetworkInterfaces").is_empty());
+
+        let change = set_ipv4(&device, "eth0", &addressing, false).await.unwrap();
+        assert!(change.reboot_needed);
+        let request = device.requests("SetNetworkInterfaces").pop().unwrap();
+        assert!(request.contains("<tt:DHCP>false</tt:DHCP>"));
+
+        let unchanged = set_ipv4(&device, "eth0", &Ipv4Addressing::Dhcp, false)
+            .await
+            .unwrap();
+        assert!(unchanged.changes.is_empty());
+        assert!(matches!(
+            set_ipv4(&device, "eth1", &addressing, true).await,
+            Err(Error::UnknownInterface(_))
+        ));
+    }
+
+    #[test]
+    fn keeps_the_connection() {
+        let connected = Url::parse("http://192.168.0.2/onvif/device_service").unwrap();
+
+        let unchanged = [setting(Protocol::Http, true, &[80, 8080])];
+        assert_eq!(reconnect_uri(&connected, &unchanged).unwrap(), connected);
+
+        let moved = [setting(Protocol::Http, true, &[8080])];
+        assert_eq!(
+            reconnect_uri(&connected, &moved).unwrap().as_str(),
+            "http://192.168.0.2:8080/onvif/device_service"
+        );
+
+        let https_only = [
+            setting(Protocol::Http, false, &[80]),
+            setting(Protocol::Https, true, &[443]),
+        ];
+        assert_eq!(
+            reconnect_uri(&connected, &https_only).unwrap().as_str(),
+            "https://192.168.0.2/onvif/device_service"
+        );
+
+        let disabled = [
+            setting(Protocol::Http, false, &[80]),
+            setting(Protocol::Https, false, &[443]),
+        ];
+        assert!(reconnect_uri(&connected, &disabled).is_err());
+
+        // Never downgrades to HTTP.
+        let connected = Url::parse("https://192.168.0.2/onvif/device_service").unwrap();
+        let http_only = [
+            setting(Protocol::Http, true, &[80]),
+            setting(Protocol::Https, false, &[443]),
+        ];
+        assert!(reconnect_uri(&connected, &http_only).is_err());
+    }
+
+    /// A device which goes down for two polls once asked to reboot.
+    #[derive(Default)]
+    struct RebootingDevice {
+        requests: std::sync::Mutex<Vec<String>>,
+        polls: std::sync::atomic::AtomicU32,
+    }
+
+    #[async_trait::async_trait]
+    impl Transport for RebootingDevice {
+        async fn request(&self, message: &str) -> Result<String, TransportError> {
+            use std::sync::atomic::Ordering;
+
+            let ns = r#"xmlns:tds="http://www.onvif.org/ver10/device/wsdl""#;
+            let operation = xmltree::Element::parse(message.as_bytes()).unwrap().name;
+            self.requests.lock().unwrap().push(operation.clone());
+            match operation.as_str() {
+                "SystemReboot" => Ok(format!(
+                    "<tds:SystemRebootResponse {}><tds:Message>Rebooting</tds:Message>\
+                     </tds:SystemRebootResponse>",
+                    ns
+                )),
+                "GetSystemDateAndTime" if self.polls.fetch_add(1, Ordering::SeqCst) < 2 => {
+                    Err(TransportError::Connection("refused".to_string()))
+                }
+                "GetSystemDateAndTime" => Ok(format!(
+                    r#"<tds:GetSystemDateAndTimeResponse {} xmlns:tt="http://www.onvif.org/ver10/schema">
+                        <tds:SystemDateAndTime>
+                            <tt:DateTimeType>NTP</tt:DateTimeType>
+                            <tt:DaylightSavings>false</tt:DaylightSavings>
+                        </tds:SystemDateAndTime>
+                    </tds:GetSystemDateAndTimeResponse>"#,
+                    ns
+                )),
+                operation => Ok(format!("<tds:{}Response {}/>", operation, ns)),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn reboots_when_needed() {
+        tokio::time::pause();
+        let device = RebootingDevice::default();
+        let mut change = InterfaceChange {
+            changes: vec!["IPv6: DHCP -> 2001:db8::10/64".to_string()],
+            reboot_needed: false,
+        };
+        let timeout = Duration::from_secs(60);
+
+        let elapsed = reboot_if_needed(&device, &change, timeout, |_| {}).await;
+        assert_eq!(elapsed.unwrap(), None);
+        assert!(device.requests.lock().unwrap().is_empty());
+
+        change.reboot_needed = true;
+        let elapsed = reboot_if_needed(&device, &change, timeout, |_| {}).await;
+        assert_eq!(elapsed.unwrap(), Some(Duration::from_secs(1)));
+        assert_eq!(
+            *device.requests.lock().unwrap(),
+            vec![
+                "SystemReboot",
+                "GetSystemDateAndTime",
+                "GetSystemDateAndTime",
+                "GetSystemDateAndTime",
+                "GetDeviceInformation",
+            ]
+        );
+    }
+}
@@ -0,0 +1,266 @@
+//! Streams over list operations paged with `Limit` and `StartReference`, such as
+//! `GetAccessPointInfoList` or `GetCredentialList`.
+//!
+//! ```no_run
+//! # async fn example(credential_service: &onvif::soap::client::Client) {
+//! use futures_util::{pin_mut, StreamExt};
+//! use onvif::{paging, schema::credential};
+//!
+//! let credentials = paging::items::<_, credential::GetCredentialList>(credential_service, None);
+//! pin_mut!(credentials);
+//! while let Some(credential) = credentials.next().await {
+//!     println!("{:?}", credential);
+//! }
+//! # }
+//! ```
+
+use async_stream::try_stream;
+use futures_core::stream::Stream;
+use schema::{
+    accesscontrol, accessrules, authenticationbehavior, credential, doorcontrol, schedule,
+    transport::{self, Error, Transport},
+};
+use yaserde::{YaDeserialize, YaSerialize};
+
+/// A request of a paged list operation.
+pub trait PagedRequest: YaSerialize + Sized {
+    type Response: YaDeserialize;
+    type Item;
+
+    fn page(limit: Option<i32>, start_reference: Option<String>) -> Self;
+
+    /// Splits a response into its items and the `NextStartReference`.
+    fn into_page(response: Self::Response) -> (Vec<Self::Item>, Option<String>);
+}
+
+macro_rules! paged {
+    ($module:ident :: $request:ident, $response:ident, $items:ident: $item:ident) => {
+        impl PagedRequest for $module::$request {
+            type Response = $module::$response;
+            type Item = $module::$item;
+
+            fn page(limit: Option<i32>, start_reference: Option<String>) -> Self {
+                Self {
+                    limit,
+                    start_reference,
+                }
+            }
+
+            fn into_page(response: Self::Response) -> (Vec<Self::Item>, Option<String>) {
+                (response.$items, response.next_start_reference)
+            }
+        }
+    };
+}
+
+paged!(
+    accesscontrol::GetAccessPointInfoList,
+    GetAccessPointInfoListResponse,
+    access_point_info: AccessPointInfo
+);
+paged!(
+    accesscontrol::GetAccessPointList,
+    GetAccessPointListResponse,
+    access_point: AccessPoint
+);
+paged!(
+    accesscontrol::GetAreaInfoList,
+    GetAreaInfoListResponse,
+    area_info: AreaInfo
+);
+paged!(
+    accesscontrol::GetAreaList,
+    GetAreaListResponse,
+    area: Area
+);
+paged!(
+    accessrules::GetAccessProfileInfoList,
+    GetAccessProfileInfoListResponse,
+    access_profile_info: AccessProfileInfo
+);
+paged!(
+    accessrules::GetAccessProfileList,
+    GetAccessProfileListResponse,
+    access_profile: AccessProfile
+);
+paged!(
+    authenticationbehavior::GetAuthenticationProfileInfoList,
+    GetAuthenticationProfileInfoListResponse,
+    authentication_profile_info: AuthenticationProfileInfo
+);
+paged!(
+    authenticationbehavior::GetAuthenticationProfileList,
+    GetAuthenticationProfileListResponse,
+    authentication_profile: AuthenticationProfile
+);
+paged!(
+    authenticationbehavior::GetSecurityLevelInfoList,
+    GetSecurityLevelInfoListResponse,
+    security_level_info: SecurityLevelInfo
+);
+paged!(
+    authenticationbehavior::GetSecurityLevelList,
+    GetSecurityLevelListResponse,
+    security_level: SecurityLevel
+);
+paged!(
+    credential::GetCredentialInfoList,
+    GetCredentialInfoListResponse,
+    credential_info: CredentialInfo
+);
+paged!(
+    credential::GetCredentialList,
+    GetCredentialListResponse,
+    credential: Credential
+);
+paged!(
+    doorcontrol::GetDoorInfoList,
+    GetDoorInfoListResponse,
+    door_info: DoorInfo
+);
+paged!(
+    doorcontrol::GetDoorList,
+    GetDoorListResponse,
+    door: Door
+);
+paged!(
+    schedule::GetScheduleInfoList,
+    GetScheduleInfoListResponse,
+    schedule_info: ScheduleInfo
+);
+paged!(
+    schedule::GetScheduleList,
+    GetScheduleListResponse,
+    schedule: Schedule
+);
+paged!(
+    schedule::GetSpecialDayGroupInfoList,
+    GetSpecialDayGroupInfoListResponse,
+    special_day_group_info: SpecialDayGroupInfo
+);
+paged!(
+    schedule::GetSpecialDayGroupList,
+    GetSpecialDayGroupListResponse,
+    special_day_group: SpecialDayGroup
+);
+
+/// Requests the pages of `R` one after another as the stream is consumed, and yields their
+/// items. `limit` is the number of items per page, or the device's own limit if `None`.
+///
+/// The stream ends after the first error, at an empty start reference, which some devices send
+/// instead of leaving it out on the last page, and if the device hands out the same start
+/// reference twice, which would otherwise request the same page forever.
+pub fn items<'a, T, R>(
+    transport: &'a T,
+    limit: Option<i32>,
+) -> impl Stream<Item = Result<R::Item, Error>> + 'a
+where
+    T: Transport,
+    R: PagedRequest + 'a,
+{
+    try_stream! {
+        let mut start_reference: Option<String> = None;
+        loop {
+            let request = R::page(limit, start_reference.clone());
+            let response = transport::request(transport, &request).await?;
+            let (items, next) = R::into_page(response);
+            let next = next.filter(|next| !next.trim().is_empty());
+            for item in items {
+                yield item;
+            }
+
+            match next {
+                Some(next) if start_reference.as_ref() != Some(&next) => {
+                    start_reference = Some(next);
+                }
+                Some(next) => {
+                    tracing::debug!("device repeated start reference {}", next);
+                    break;
+                }
+                None => break,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use futures_util::{pin_mut, StreamExt};
+    use std::sync::Mutex;
+
+    /// Serves three doors in pages of two, recording the start references.
+    #[derive(Default)]
+    struct FakeDoorControl {
+        start_references: Mutex<Vec<Option<String>>>,
+        /// The next start reference of the last page, if any.
+        last: Option<&'static str>,
+    }
+
+    #[async_trait]
+    impl Transport for FakeDoorControl {
+        async fn request(&self, message: &str) -> Result<String, Error> {
+            let start = message
+                .split("<tdc:StartReference>")
+                .nth(1)
+                .and_then(|rest| rest.split('<').next())
+                .map(str::to_string);
+            self.start_references.lock().unwrap().push(start.clone());
+
+            let (count, next) = match start.as_deref() {
+                None => (2, Some("page2")),
+                Some("page2") => (1, self.last),
+                Some(other) => return Err(Error::Other(format!("unexpected {}", other))),
+            };
+            Ok(format!(
+                r#"<tdc:GetDoorInfoListResponse
+                    xmlns:tdc="http://www.onvif.org/ver10/doorcontrol/wsdl">{}{}
+                </tdc:GetDoorInfoListResponse>"#,
+                next.map(|n| format!("<tdc:NextStartReference>{}</tdc:NextStartReference>", n))
+                    .unwrap_or_default(),
+                r#"<tdc:DoorInfo><tdc:Capabilities Access="true"/></tdc:DoorInfo>"#.repeat(count)
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn pages_through_items() {
+        let door_control = FakeDoorControl::default();
+
+        let doors = items::<_, doorcontrol::GetDoorInfoList>(&door_control, Some(2));
+        pin_mut!(doors);
+        let mut count = 0;
+        while let Some(door) = doors.next().await {
+            assert_eq!(door.unwrap().capabilities.access, Some(true));
+            count += 1;
+        }
+
+        assert_eq!(count, 3);
+        assert_eq!(
+            *door_control.start_references.lock().unwrap(),
+            vec![None, Some("page2".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn ends_at_empty_start_reference() {
+        for last in ["", " "] {
+            let door_control = FakeDoorControl {
+                last: Some(last),
+                ..Default::default()
+            };
+
+            let doors = items::<_, doorcontrol::GetDoorInfoList>(&door_control, Some(2));
+            pin_mut!(doors);
+            let mut count = 0;
+            while let Some(door) = doors.next().await {
+                door.unwrap();
+                count += 1;
+            }
+
+            assert_eq!(count, 3);
+            assert_eq!(door_control.start_references.lock().unwrap().len(), 2);
+        }
+    }
+}
@@ -0,0 +1,282 @@
+//! First contact with factory-new devices.
+//!
+//! Devices without users don't require authentication, and many refuse most operations until an
+//! administrator exists. Some ship with a user, typically `admin`, that has no password yet,
+//! which they list with an empty password.
+//! [`provision_initial_admin`] handles both and returns a client using the new credentials.
+//!
+//! [`usage`] covers the ONVIF provisioning service, which moves the mechanics of video sources
+//! during installation and counts their moves.
+//!
+//! ```no_run
+//! # async fn example() {
+//! use onvif::{provisioning, soap::client::Credentials};
+//! use url::Url;
+//!
+//! let uri = Url::parse("http://192.168.0.2/onvif/device_service").unwrap();
+//! let device_service = provisioning::provision_initial_admin(
+//!     &uri,
+//!     &Credentials {
+//!         username: "admin".to_string(),
+//!         password: "a strong password".to_string(),
+//!     },
+//! )
+//! .await
+//! .unwrap();
+//! # }
+//! ```
+
+pub mod usage;
+
+use crate::soap::client::{Client, ClientBuilder, Credentials};
+use schema::{
+    devicemgmt, onvif as tt,
+    transport::{Error as TransportError, Transport},
+};
+use thiserror::Error;
+use url::Url;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Transport error: {0}")]
+    Transport(#[from] TransportError),
+
+    /// The device isn't in its factory state, so it was left as it is.
+    #[error("Device is already provisioned: {0}")]
+    AlreadyProvisioned(String),
+
+    /// The administrator was created, but the device refuses the new credentials.
+    #[error("New credentials are refused: {0}")]
+    Verification(TransportError),
+}
+
+/// What [`provision`] did to create the administrator.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Provisioned {
+    /// Created with `CreateUsers`, as the device had no users.
+    Created,
+
+    /// An existing user the device listed without a password got one with `SetUser`.
+    PasswordSet,
+}
+
+/// Creates an administrator with `credentials` on the factory-new device with the device service
+/// at `uri`, then connects with these credentials.
+pub async fn provision_initial_admin(
+    uri: &Url,
+    credentials: &Credentials,
+) -> Result<Client, Error> {
+    let anonymous = ClientBuilder::new(uri).build();
+    let authenticated = ClientBuilder::new(uri)
+        .credentials(Some(credentials.clone()))
+        .build();
+    provision(&anonymous, &authenticated, credentials).await?;
+    Ok(authenticated)
+}
+
+/// [`provision_initial_admin`] with given transports, of which `anonymous` has no credentials and
+/// `authenticated` has `credentials`.
+///
+/// The device is considered factory-new if it lists its users without authentication and has no
+/// administrator other than `credentials.username`, which it has to list with an empty password.
+/// Devices don't list passwords otherwise, and one that lets anonymous clients list its users may
+/// still have given that administrator a password, which isn't replaced.
+pub async fn provision<T: Transport>(
+    anonymous: &T,
+    authenticated: &T,
+    credentials: &Credentials,
+) -> Result<Provisioned, Error> {
+    let users = match devicemgmt::get_users(anonymous, &Default::default()).await {
+        Ok(response) => response.user,
+        Err(TransportError::Authorization(_)) => {
+            return Err(Error::AlreadyProvisioned(
+                "authentication is required".to_string(),
+            ))
+        }
+        Err(error) => return Err(error.into()),
+    };
+    if let Some(admin) = users.iter().find(|user| {
+        user.user_level == tt::UserLevel::Administrator && user.username != credentials.username
+    }) {
+        return Err(Error::AlreadyProvisioned(format!(
+            "{} is an administrator",
+            admin.username
+        )));
+    }
+    if let Some(admin) = users.iter().find(|user| {
+        user.user_level == tt::UserLevel::Administrator
+            && user.username == credentials.username
+            && user.password.as_deref() != Some("")
+    }) {
+        return Err(Error::AlreadyProvisioned(format!(
+            "{} is already an administrator",
+            admin.username
+        )));
+    }
+
+    let user = tt::User {
+        username: credentials.username.clone(),
+        password: Some(credentials.password.clone()),
+        user_level: tt::UserLevel::Administrator,
+        extension: None,
+    };
+    let provisioned = if users
+        .iter()
+        .any(|existing| existing.username == credentials.username)
+    {
+        tracing::debug!("setting the password of {}", credentials.username);
+        devicemgmt::set_user(anonymous, &devicemgmt::SetUser { user: vec![user] }).await?;
+        Provisioned::PasswordSet
+    } else {
+        tracing::debug!("creating {}", credentials.username);
+        devicemgmt::create_users(anonymous, &devicemgmt::CreateUsers { user: vec![user] }).await?;
+        Provisioned::Created
+    };
+
+    // Authentication is enforced as soon as the first user exists, so this fails if the user
+    // wasn't created as requested.
+    devicemgmt::get_users(authenticated, &Default::default())
+        .await
+        .map_err(Error::Verification)?;
+    Ok(provisioned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::{Arc, Mutex};
+
+    /// A device that requires authentication once it has a user with a password, unless it
+    /// allows anonymous access, with whether requests are authenticated fixed per transport. Users
+    /// without a password are listed with an empty one.
+    #[derive(Clone)]
+    struct FakeDevice {
+        users: Arc<Mutex<Vec<(String, Option<String>)>>>,
+        authenticated: bool,
+        anonymous_access: bool,
+    }
+
+    impl FakeDevice {
+        fn new(users: &[(&str, Option<&str>)]) -> (Self, Self) {
+            let users = Arc::new(Mutex::new(
+                users
+                    .iter()
+                    .map(|(name, password)| (name.to_string(), password.map(str::to_string)))
+                    .collect(),
+            ));
+            (
+                Self {
+                    users: users.clone(),
+                    authenticated: false,
+                    anonymous_access: false,
+                },
+                Self {
+                    users,
+                    authenticated: true,
+                    anonymous_access: false,
+                },
+            )
+        }
+    }
+
+    fn empty_response(name: &str) -> String {
+        format!(
+            r#"<tds:{} xmlns:tds="http://www.onvif.org/ver10/device/wsdl"/>"#,
+            name
+        )
+    }
+
+    #[async_trait]
+    impl Transport for FakeDevice {
+        async fn request(&self, message: &str) -> Result<String, TransportError> {
+            let mut users = self.users.lock().unwrap();
+            let protected = users.iter().any(|(_, password)| password.is_some());
+            if protected && !self.authenticated && !self.anonymous_access {
+                return Err(TransportError::Authorization("401".to_string()));
+            }
+
+            let username = || {
+                message
+                    .split("<tt:Username>")
+                    .nth(1)
+                    .and_then(|rest| rest.split('<').next())
+                    .unwrap()
+                    .to_string()
+            };
+            if message.contains("tds:CreateUsers") {
+                users.push((username(), Some("secret".to_string())));
+                Ok(empty_response("CreateUsersResponse"))
+            } else if message.contains("tds:SetUser") {
+                let username = username();
+                for user in users.iter_mut().filter(|(name, _)| *name == username) {
+                    user.1 = Some("secret".to_string());
+                }
+                Ok(empty_response("SetUserResponse"))
+            } else {
+                let listed: String = users
+                    .iter()
+                    .map(|(name, password)| {
+                        format!(
+                            "<tds:User><tt:Username>{}</tt:Username>{}<tt:UserLevel>Administrator</tt:UserLevel></tds:User>",
+                            name,
+                            match password {
+                                Some(_) => "",
+                                None => "<tt:Password></tt:Password>",
+                            }
+                        )
+                    })
+                    .collect();
+                Ok(format!(
+                    r#"<tds:GetUsersResponse
+                        xmlns:tds="http://www.onvif.org/ver10/device/wsdl"
+                        xmlns:tt="http://www.onvif.org/ver10/schema">{}</tds:GetUsersResponse>"#,
+                    listed
+                ))
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn provisions_factory_devices() {
+        let credentials = Credentials {
+            username: "admin".to_string(),
+            password: "secret".to_string(),
+        };
+
+        let (anonymous, authenticated) = FakeDevice::new(&[]);
+        assert_eq!(
+            provision(&anonymous, &authenticated, &credentials)
+                .await
+                .unwrap(),
+            Provisioned::Created
+        );
+        assert!(matches!(
+            provision(&anonymous, &authenticated, &credentials).await,
+            Err(Error::AlreadyProvisioned(_))
+        ));
+
+        let (anonymous, authenticated) = FakeDevice::new(&[("admin", None)]);
+        assert_eq!(
+            provision(&anonymous, &authenticated, &credentials)
+                .await
+                .unwrap(),
+            Provisioned::PasswordSet
+        );
+
+        let (anonymous, authenticated) = FakeDevice::new(&[("root", None)]);
+        assert!(matches!(
+            provision(&anonymous, &authenticated, &credentials).await,
+            Err(Error::AlreadyProvisioned(_))
+        ));
+
+        // The administrator may have a password the device doesn't list.
+        let (mut anonymous, authenticated) = FakeDevice::new(&[("admin", Some("old"))]);
+        anonymous.anonymous_access = true;
+        assert!(matches!(
+            provision(&anonymous, &authenticated, &credentials).await,
+            Err(Error::AlreadyProvisioned(_))
+        ));
+        assert_eq!(anonymous.users.lock().unwrap()[0].1.as_deref(), Some("old"));
+    }
+}
@@ -0,0 +1,251 @@
+//! Wear of PTZ mechanics, from the lifetime move counters of the provisioning service.
+//!
+//! Devices count the moves of each axis of a video source over their lifetime, and may rate the
+//! axes for a maximum number of moves. A [`WearReport`] puts the two side by side, e.g. to plan
+//! the servicing of cameras before their motors wear out.
+//!
+//! ```no_run
+//! # async fn example(provisioning_service: onvif::soap::client::Client) {
+//! use onvif::provisioning::usage;
+//!
+//! let report = usage::wear_report(&provisioning_service).await.unwrap();
+//! for (source, axis) in report.due(0.8) {
+//!     let wear = axis.wear().unwrap() * 100.0;
+//!     println!("{:?} of {} used {:.0}% of its moves", axis.axis, source, wear);
+//! }
+//! # }
+//! ```
+
+use schema::{
+    onvif as tt, provisioning,
+    transport::{Error as TransportError, Transport},
+};
+use serde::Serialize;
+use xsd_types::types as xs;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Axis {
+    Pan,
+    Tilt,
+    Zoom,
+    Roll,
+    Focus,
+}
+
+const AXES: [Axis; 5] = [Axis::Pan, Axis::Tilt, Axis::Zoom, Axis::Roll, Axis::Focus];
+
+/// Lifetime move counts of a video source, `None` for axes the device doesn't count.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Usage {
+    pub pan: Option<u64>,
+    pub tilt: Option<u64>,
+    pub zoom: Option<u64>,
+    pub roll: Option<u64>,
+    pub focus: Option<u64>,
+}
+
+impl Usage {
+    pub fn moves(&self, axis: Axis) -> Option<u64> {
+        match axis {
+            Axis::Pan => self.pan,
+            Axis::Tilt => self.tilt,
+            Axis::Zoom => self.zoom,
+            Axis::Roll => self.roll,
+            Axis::Focus => self.focus,
+        }
+    }
+}
+
+/// Requests the lifetime move counts of `video_source` with `GetUsage`.
+pub async fn usage<T: Transport>(
+    provisioning_service: &T,
+    video_source: &tt::ReferenceToken,
+) -> Result<Usage, TransportError> {
+    let usage = provisioning::get_usage(
+        provisioning_service,
+        &provisioning::GetUsage {
+            video_source: video_source.clone(),
+        },
+    )
+    .await?
+    .usage;
+
+    Ok(Usage {
+        pan: count(&usage.pan),
+        tilt: count(&usage.tilt),
+        zoom: count(&usage.zoom),
+        roll: count(&usage.roll),
+        focus: count(&usage.focus),
+    })
+}
+
+fn count(value: &Option<xs::Integer>) -> Option<u64> {
+    value.as_ref()?.to_string().parse().ok()
+}
+
+/// Moves of an axis against its rated maximum.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AxisWear {
+    pub axis: Axis,
+
+    /// Lifetime moves, `None` if the device doesn't count them.
+    pub moves: Option<u64>,
+
+    /// Lifetime limit of moves, `None` if the device doesn't rate the axis.
+    pub limit: Option<u64>,
+}
+
+impl AxisWear {
+    /// Fraction of the limit used, which can exceed 1 for axes moved beyond their rating.
+    pub fn wear(&self) -> Option<f64> {
+        match (self.moves, self.limit) {
+            (Some(moves), Some(limit)) if limit > 0 => Some(moves as f64 / limit as f64),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SourceWear {
+    pub video_source: String,
+
+    /// Axes the device supports moving or counts the moves of.
+    pub axes: Vec<AxisWear>,
+
+    /// Why `GetUsage` failed for this source, in which case `moves` are `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct WearReport {
+    pub sources: Vec<SourceWear>,
+}
+
+impl WearReport {
+    /// Axes which used at least `wear` of their limit, e.g. 0.8, with their video source.
+    pub fn due(&self, wear: f64) -> impl Iterator<Item = (&str, &AxisWear)> + '_ {
+        self.sources.iter().flat_map(move |source| {
+            source
+                .axes
+                .iter()
+                .filter(move |axis| axis.wear().map_or(false, |w| w >= wear))
+                .map(move |axis| (source.video_source.as_str(), axis))
+        })
+    }
+}
+
+/// Reports the wear of every video source of the provisioning service. Sources whose usage
+/// can't be read are reported with the error rather than failing the whole report.
+pub async fn wear_report<T: Transport>(
+    provisioning_service: &T,
+) -> Result<WearReport, TransportError> {
+    let capabilities =
+        provisioning::get_service_capabilities(provisioning_service, &Default::default())
+            .await?
+            .capabilities;
+
+    let mut report = WearReport::default();
+    for source in &capabilities.source {
+        let (usage, error) = match usage(provisioning_service, &source.video_source_token).await {
+            Ok(usage) => (usage, None),
+            Err(e) => (Usage::default(), Some(e.to_string())),
+        };
+
+        let axes = AXES
+            .iter()
+            .map(|&axis| AxisWear {
+                axis,
+                moves: usage.moves(axis),
+                limit: count(match axis {
+                    Axis::Pan => &source.maximum_pan_moves,
+                    Axis::Tilt => &source.maximum_tilt_moves,
+                    Axis::Zoom => &source.maximum_zoom_moves,
+                    Axis::Roll => &source.maximum_roll_moves,
+                    Axis::Focus => &source.maximum_focus_moves,
+                }),
+            })
+            .filter(|axis| axis.moves.is_some() || axis.limit.is_some())
+            .collect();
+
+        report.sources.push(SourceWear {
+            video_source: source.video_source_token.0.clone(),
+            axes,
+            error,
+        });
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+
+    /// A device with a pan/tilt head, and a fixed lens whose usage can't be read.
+    struct FakeProvisioning;
+
+    #[async_trait]
+    impl Transport for FakeProvisioning {
+        async fn request(&self, message: &str) -> Result<String, TransportError> {
+            let ns = r#"xmlns:tpv="http://www.onvif.org/ver10/provisioning/wsdl""#;
+            if message.contains("GetServiceCapabilities") {
+                Ok(format!(
+                    r#"<tpv:GetServiceCapabilitiesResponse {}>
+                        <tpv:Capabilities>
+                            <tpv:DefaultTimeout>PT1S</tpv:DefaultTimeout>
+                            <tpv:Source VideoSourceToken="head" MaximumPanMoves="100000"
+                                MaximumTiltMoves="100000"/>
+                            <tpv:Source VideoSourceToken="lens" MaximumFocusMoves="5000"/>
+                        </tpv:Capabilities>
+                    </tpv:GetServiceCapabilitiesResponse>"#,
+                    ns
+                ))
+            } else if message.contains("head") {
+                Ok(format!(
+                    "<tpv:GetUsageResponse {}><tpv:Usage><tpv:Pan>85000</tpv:Pan>\
+                     <tpv:Tilt>12000</tpv:Tilt></tpv:Usage></tpv:GetUsageResponse>",
+                    ns
+                ))
+            } else {
+                Err(TransportError::Protocol("ter:InvalidArgVal".to_string()))
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn reports_wear() {
+        let report = wear_report(&FakeProvisioning).await.unwrap();
+        assert_eq!(report.sources.len(), 2);
+
+        let head = &report.sources[0];
+        assert_eq!(head.video_source, "head");
+        assert_eq!(head.error, None);
+        assert_eq!(
+            head.axes,
+            vec![
+                AxisWear {
+                    axis: Axis::Pan,
+                    moves: Some(85000),
+                    limit: Some(100000),
+                },
+                AxisWear {
+                    axis: Axis::Tilt,
+                    moves: Some(12000),
+                    limit: Some(100000),
+                },
+            ]
+        );
+
+        let lens = &report.sources[1];
+        assert!(lens.error.is_some());
+        assert_eq!(lens.axes[0].axis, Axis::Focus);
+        assert_eq!(lens.axes[0].wear(), None);
+
+        let due: Vec<_> = report.due(0.8).collect();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].0, "head");
+        assert_eq!(due[0].1.axis, Axis::Pan);
+    }
+}
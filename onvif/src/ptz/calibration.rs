@@ -0,0 +1,311 @@
+//! Pan, tilt and zoom positions in degrees and percent.
+//!
+//! Devices report positions in the generic spaces, normalized to the ranges of the PTZ node,
+//! which mean little to operators. A [`Calibration`] maps them linearly onto the angles the
+//! mechanics cover, and adds offsets such as the compass bearing of the pan zero point.
+
+use super::{Error, Range};
+use schema::{onvif as tt, ptz, transport::Transport};
+
+pub const GENERIC_PAN_TILT_SPACE: &str =
+    "http://www.onvif.org/ver10/tptz/PanTiltSpaces/PositionGenericSpace";
+pub const GENERIC_ZOOM_SPACE: &str =
+    "http://www.onvif.org/ver10/tptz/ZoomSpaces/PositionGenericSpace";
+const DEGREES_SPACE: &str =
+    "http://www.onvif.org/ver10/tptz/PanTiltSpaces/SphericalPositionSpaceDegrees";
+
+/// A position in degrees, with zoom from 0 (wide) to 100 percent (tele).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Position {
+    pub pan: f64,
+    pub tilt: f64,
+    pub zoom: Option<f64>,
+}
+
+/// Maps between positions in the generic spaces and [`Position`]s.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Calibration {
+    /// Generic ranges of the node.
+    pub pan: Range,
+    pub tilt: Range,
+    pub zoom: Option<Range>,
+
+    /// Angles at the ends of the generic pan range, -180 to 180 degrees by default.
+    pub pan_degrees: Range,
+
+    /// Angles at the ends of the generic tilt range, -90 to 90 degrees by default.
+    pub tilt_degrees: Range,
+
+    /// Added to pan angles, e.g. the bearing the camera faces at pan 0.
+    pub pan_offset: f64,
+
+    /// Added to tilt angles, e.g. to make the horizon 0 on a tilted mount.
+    pub tilt_offset: f64,
+}
+
+impl Calibration {
+    /// Takes the generic ranges from the node, and the angles from its spherical degree space if
+    /// it has one. Other nodes need their angles set with [`Calibration::pan_degrees`] and
+    /// [`Calibration::tilt_degrees`]. Returns `None` if the node has no generic pan/tilt space.
+    pub fn from_node(node: &tt::Ptznode) -> Option<Self> {
+        let spaces = &node.supported_ptz_spaces;
+        let generic = spaces
+            .absolute_pan_tilt_position_space
+            .iter()
+            .find(|s| s.uri == GENERIC_PAN_TILT_SPACE)?;
+        let degrees = spaces
+            .absolute_pan_tilt_position_space
+            .iter()
+            .find(|s| s.uri == DEGREES_SPACE);
+
+        Some(Self {
+            pan: (&generic.x_range).into(),
+            tilt: (&generic.y_range).into(),
+            zoom: spaces
+                .absolute_zoom_position_space
+                .iter()
+                .find(|s| s.uri == GENERIC_ZOOM_SPACE)
+                .map(|s| (&s.x_range).into()),
+            pan_degrees: degrees.map_or(
+                Range {
+                    min: -180.0,
+                    max: 180.0,
+                },
+                |d| (&d.x_range).into(),
+            ),
+            tilt_degrees: degrees.map_or(
+                Range {
+                    min: -90.0,
+                    max: 90.0,
+                },
+                |d| (&d.y_range).into(),
+            ),
+            pan_offset: 0.0,
+            tilt_offset: 0.0,
+        })
+    }
+
+    pub fn pan_degrees(mut self, min: f64, max: f64) -> Self {
+        self.pan_degrees = Range { min, max };
+        self
+    }
+
+    pub fn tilt_degrees(mut self, min: f64, max: f64) -> Self {
+        self.tilt_degrees = Range { min, max };
+        self
+    }
+
+    pub fn offsets(mut self, pan: f64, tilt: f64) -> Self {
+        self.pan_offset = pan;
+        self.tilt_offset = tilt;
+        self
+    }
+
+    /// Converts a position in the generic spaces, such as that of `GetStatus`. Pan is in the
+    /// range -180 to 180 degrees after the offset. Returns `None` without a pan/tilt position in
+    /// the generic space; zoom in another space is left out.
+    pub fn to_degrees(&self, position: &tt::Ptzvector) -> Option<Position> {
+        let pan_tilt = position
+            .pan_tilt
+            .as_ref()
+            .filter(|v| is_space(&v.space, GENERIC_PAN_TILT_SPACE))?;
+        let zoom = match (&position.zoom, self.zoom) {
+            (Some(v), Some(range)) if is_space(&v.space, GENERIC_ZOOM_SPACE) => Some(map(
+                v.x,
+                range,
+                Range {
+                    min: 0.0,
+                    max: 100.0,
+                },
+            )),
+            _ => None,
+        };
+
+        Some(Position {
+            pan: wrap(
+                map(pan_tilt.x, self.pan, self.pan_degrees) + self.pan_offset,
+                Range {
+                    min: -180.0,
+                    max: 180.0,
+                },
+            ),
+            tilt: map(pan_tilt.y, self.tilt, self.tilt_degrees) + self.tilt_offset,
+            zoom,
+        })
+    }
+
+    /// Converts back to a position in the generic spaces, e.g. for an absolute move. Pan angles
+    /// are taken modulo 360 degrees where the pan range allows. Targets outside of the ranges
+    /// aren't clamped, see [`Limits`](super::Limits) for that.
+    pub fn to_generic(&self, position: &Position) -> tt::Ptzvector {
+        let pan = wrap(position.pan - self.pan_offset, self.pan_degrees);
+        tt::Ptzvector {
+            pan_tilt: Some(tt::Vector2D {
+                x: map(pan, self.pan_degrees, self.pan),
+                y: map(
+                    position.tilt - self.tilt_offset,
+                    self.tilt_degrees,
+                    self.tilt,
+                ),
+                space: Some(GENERIC_PAN_TILT_SPACE.to_string()),
+            }),
+            zoom: match (position.zoom, self.zoom) {
+                (Some(zoom), Some(range)) => Some(tt::Vector1D {
+                    x: map(
+                        zoom,
+                        Range {
+                            min: 0.0,
+                            max: 100.0,
+                        },
+                        range,
+                    ),
+                    space: Some(GENERIC_ZOOM_SPACE.to_string()),
+                }),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// Reads the calibration of the PTZ node of a media profile.
+pub async fn profile_calibration<T: Transport>(
+    ptz_service: &T,
+    profile: &tt::Profile,
+) -> Result<Calibration, Error> {
+    let configuration = profile
+        .ptz_configuration
+        .as_ref()
+        .ok_or_else(|| Error::Response("profile has no PTZ configuration".to_string()))?;
+    let node = ptz::get_node(
+        ptz_service,
+        &ptz::GetNode {
+            node_token: configuration.node_token.clone(),
+        },
+    )
+    .await?
+    .ptz_node;
+    Calibration::from_node(&node)
+        .ok_or_else(|| Error::Response("PTZ node has no generic position space".to_string()))
+}
+
+/// Requests the current position of a profile in degrees.
+pub async fn position<T: Transport>(
+    ptz_service: &T,
+    profile_token: &tt::ReferenceToken,
+    calibration: &Calibration,
+) -> Result<Position, Error> {
+    let status = ptz::get_status(
+        ptz_service,
+        &ptz::GetStatus {
+            profile_token: profile_token.clone(),
+        },
+    )
+    .await?;
+    status
+        .ptz_status
+        .position
+        .as_ref()
+        .and_then(|p| calibration.to_degrees(p))
+        .ok_or_else(|| {
+            Error::Response("device doesn't report a position in the generic space".to_string())
+        })
+}
+
+fn map(value: f64, from: Range, to: Range) -> f64 {
+    to.min + (value - from.min) / (from.max - from.min) * (to.max - to.min)
+}
+
+/// Moves `angle` into `range` by whole turns, if there's a turn that does.
+fn wrap(mut angle: f64, range: Range) -> f64 {
+    while angle > range.max && angle - 360.0 >= range.min {
+        angle -= 360.0;
+    }
+    while angle < range.min && angle + 360.0 <= range.max {
+        angle += 360.0;
+    }
+    angle
+}
+
+// Vectors without a space are taken to be in the generic space, which is the default of most
+// configurations.
+fn is_space(space: &Option<String>, uri: &str) -> bool {
+    space.as_deref().map_or(true, |s| s == uri)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn calibration() -> Calibration {
+        Calibration {
+            pan: Range {
+                min: -1.0,
+                max: 1.0,
+            },
+            tilt: Range {
+                min: -1.0,
+                max: 1.0,
+            },
+            zoom: Some(Range { min: 0.0, max: 1.0 }),
+            pan_degrees: Range {
+                min: -180.0,
+                max: 180.0,
+            },
+            tilt_degrees: Range {
+                min: -90.0,
+                max: 0.0,
+            },
+            pan_offset: 0.0,
+            tilt_offset: 0.0,
+        }
+        .offsets(90.0, 5.0)
+    }
+
+    fn generic(pan: f64, tilt: f64, zoom: f64) -> tt::Ptzvector {
+        tt::Ptzvector {
+            pan_tilt: Some(tt::Vector2D {
+                x: pan,
+                y: tilt,
+                space: None,
+            }),
+            zoom: Some(tt::Vector1D {
+                x: zoom,
+                space: None,
+            }),
+        }
+    }
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn converts_positions() {
+        let calibration = calibration();
+
+        let position = calibration.to_degrees(&generic(0.75, 0.0, 0.25)).unwrap();
+        // Pan 135 degrees plus the offset wraps around.
+        assert_close(position.pan, -135.0);
+        assert_close(position.tilt, -40.0);
+        assert_close(position.zoom.unwrap(), 25.0);
+
+        let back = calibration.to_generic(&position);
+        let pan_tilt = back.pan_tilt.unwrap();
+        assert_close(pan_tilt.x, 0.75);
+        assert_close(pan_tilt.y, 0.0);
+        assert_close(back.zoom.unwrap().x, 0.25);
+
+        let east = calibration.to_generic(&Position {
+            pan: 450.0,
+            tilt: 5.0,
+            zoom: None,
+        });
+        assert_close(east.pan_tilt.as_ref().unwrap().x, 0.0);
+        assert_close(east.pan_tilt.as_ref().unwrap().y, 1.0);
+        assert!(east.zoom.is_none());
+
+        let mut other_space = generic(0.5, 0.0, 0.25);
+        other_space.pan_tilt.as_mut().unwrap().space = Some(DEGREES_SPACE.to_string());
+        assert!(calibration.to_degrees(&other_space).is_none());
+    }
+}
@@ -0,0 +1,357 @@
+//! PTZ moves guarded by the limits of the PTZ configuration.
+//!
+//! Devices are supposed to refuse moves beyond `PanTiltLimits` and `ZoomLimits`, but some drive
+//! the mechanics into their stops instead. The helpers here check targets on the client side
+//! before sending them.
+
+pub mod calibration;
+
+use schema::{
+    onvif as tt, ptz,
+    transport::{Error as TransportError, Transport},
+};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Transport error: {0}")]
+    Transport(#[from] TransportError),
+
+    #[error("{axis} target {value} is outside of the limits {min}..{max}")]
+    OutOfLimits {
+        axis: &'static str,
+        value: f64,
+        min: f64,
+        max: f64,
+    },
+
+    #[error("Unexpected response: {0}")]
+    Response(String),
+}
+
+/// What to do with a target outside of the limits.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Guard {
+    /// Moves as far as the limits allow.
+    Clamp,
+
+    /// Fails with [`Error::OutOfLimits`] without moving.
+    Reject,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Range {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl From<&tt::FloatRange> for Range {
+    fn from(range: &tt::FloatRange) -> Self {
+        Self {
+            min: range.min,
+            max: range.max,
+        }
+    }
+}
+
+/// Position ranges of a PTZ configuration. Axes without a range are not restricted.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Limits {
+    pub pan: Option<Range>,
+    pub tilt: Option<Range>,
+    pub zoom: Option<Range>,
+
+    /// Space the pan and tilt ranges are expressed in. Vectors in other spaces are not checked.
+    pub pan_tilt_space: Option<String>,
+
+    /// Space the zoom range is expressed in. Vectors in other spaces are not checked.
+    pub zoom_space: Option<String>,
+}
+
+impl Limits {
+    pub fn from_configuration(configuration: &tt::Ptzconfiguration) -> Self {
+        let mut limits = Self::default();
+        if let Some(pan_tilt) = &configuration.pan_tilt_limits {
+            limits.pan = Some((&pan_tilt.range.x_range).into());
+            limits.tilt = Some((&pan_tilt.range.y_range).into());
+            limits.pan_tilt_space = Some(pan_tilt.range.uri.clone());
+        }
+        if let Some(zoom) = &configuration.zoom_limits {
+            limits.zoom = Some((&zoom.range.x_range).into());
+            limits.zoom_space = Some(zoom.range.uri.clone());
+        }
+        limits
+    }
+
+    /// Returns `position` restricted to the limits, or an error for [`Guard::Reject`] if it's
+    /// outside of them.
+    pub fn apply(&self, position: &tt::Ptzvector, guard: Guard) -> Result<tt::Ptzvector, Error> {
+        let pan_tilt = match &position.pan_tilt {
+            Some(v) if same_space(&v.space, &self.pan_tilt_space) => Some(tt::Vector2D {
+                x: guarded("pan", v.x, self.pan, guard)?,
+                y: guarded("tilt", v.y, self.tilt, guard)?,
+                space: v.space.clone(),
+            }),
+            Some(v) => Some(tt::Vector2D {
+                x: v.x,
+                y: v.y,
+                space: v.space.clone(),
+            }),
+            None => None,
+        };
+        let zoom = match &position.zoom {
+            Some(v) if same_space(&v.space, &self.zoom_space) => Some(tt::Vector1D {
+                x: guarded("zoom", v.x, self.zoom, guard)?,
+                space: v.space.clone(),
+            }),
+            Some(v) => Some(tt::Vector1D {
+                x: v.x,
+                space: v.space.clone(),
+            }),
+            None => None,
+        };
+
+        Ok(tt::Ptzvector { pan_tilt, zoom })
+    }
+}
+
+/// Reads the limits of the PTZ configuration of a media profile.
+pub fn profile_limits(profile: &tt::Profile) -> Limits {
+    profile
+        .ptz_configuration
+        .as_ref()
+        .map(Limits::from_configuration)
+        .unwrap_or_default()
+}
+
+/// Moves to an absolute position. With a guard, the position is first checked against `limits`.
+pub async fn absolute_move<T: Transport>(
+    ptz_service: &T,
+    profile_token: &tt::ReferenceToken,
+    position: &tt::Ptzvector,
+    speed: Option<tt::Ptzspeed>,
+    guard: Option<(&Limits, Guard)>,
+) -> Result<(), Error> {
+    let position = match guard {
+        Some((limits, guard)) => limits.apply(position, guard)?,
+        None => copy(position),
+    };
+
+    ptz::absolute_move(
+        ptz_service,
+        &ptz::AbsoluteMove {
+            profile_token: profile_token.clone(),
+            position,
+            speed,
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+/// Moves relative to the current position. With a guard, the current position is requested
+/// first and the resulting position is checked against `limits`. This treats the translation as
+/// an offset in the position space, as is the case for the generic spaces.
+pub async fn relative_move<T: Transport>(
+    ptz_service: &T,
+    profile_token: &tt::ReferenceToken,
+    translation: &tt::Ptzvector,
+    speed: Option<tt::Ptzspeed>,
+    guard: Option<(&Limits, Guard)>,
+) -> Result<(), Error> {
+    let translation = match guard {
+        Some((limits, guard)) => {
+            let status = ptz::get_status(
+                ptz_service,
+                &ptz::GetStatus {
+                    profile_token: profile_token.clone(),
+                },
+            )
+            .await?;
+            let current = status
+                .ptz_status
+                .position
+                .ok_or_else(|| Error::Response("device doesn't report its position".to_string()))?;
+            guarded_translation(&current, translation, limits, guard)?
+        }
+        None => copy(translation),
+    };
+
+    ptz::relative_move(
+        ptz_service,
+        &ptz::RelativeMove {
+            profile_token: profile_token.clone(),
+            translation,
+            speed,
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+fn guarded_translation(
+    current: &tt::Ptzvector,
+    translation: &tt::Ptzvector,
+    limits: &Limits,
+    guard: Guard,
+) -> Result<tt::Ptzvector, Error> {
+    // Axes the device doesn't report a position for can't be checked.
+    let target = tt::Ptzvector {
+        pan_tilt: match (&current.pan_tilt, &translation.pan_tilt) {
+            (Some(c), Some(t)) => Some(tt::Vector2D {
+                x: c.x + t.x,
+                y: c.y + t.y,
+                space: c.space.clone(),
+            }),
+            _ => None,
+        },
+        zoom: match (&current.zoom, &translation.zoom) {
+            (Some(c), Some(t)) => Some(tt::Vector1D {
+                x: c.x + t.x,
+                space: c.space.clone(),
+            }),
+            _ => None,
+        },
+    };
+    let target = limits.apply(&target, guard)?;
+
+    let mut guarded = copy(translation);
+    if let (Some(g), Some(c), Some(t)) =
+        (&mut guarded.pan_tilt, &current.pan_tilt, &target.pan_tilt)
+    {
+        g.x = t.x - c.x;
+        g.y = t.y - c.y;
+    }
+    if let (Some(g), Some(c), Some(t)) = (&mut guarded.zoom, &current.zoom, &target.zoom) {
+        g.x = t.x - c.x;
+    }
+    Ok(guarded)
+}
+
+fn guarded(
+    axis: &'static str,
+    value: f64,
+    range: Option<Range>,
+    guard: Guard,
+) -> Result<f64, Error> {
+    let range = match range {
+        Some(range) => range,
+        None => return Ok(value),
+    };
+    if (range.min..=range.max).contains(&value) {
+        return Ok(value);
+    }
+
+    match guard {
+        Guard::Clamp => Ok(value.max(range.min).min(range.max)),
+        Guard::Reject => Err(Error::OutOfLimits {
+            axis,
+            value,
+            min: range.min,
+            max: range.max,
+        }),
+    }
+}
+
+// Vectors without a space are in the default space of the configuration, which the limits are
+// usually expressed in.
+fn same_space(vector: &Option<String>, limits: &Option<String>) -> bool {
+    match (vector, limits) {
+        (Some(vector), Some(limits)) => vector == limits,
+        _ => true,
+    }
+}
+
+// The generated types don't implement `Clone`.
+fn copy(vector: &tt::Ptzvector) -> tt::Ptzvector {
+    tt::Ptzvector {
+        pan_tilt: vector.pan_tilt.as_ref().map(|v| tt::Vector2D {
+            x: v.x,
+            y: v.y,
+            space: v.space.clone(),
+        }),
+        zoom: vector.zoom.as_ref().map(|v| tt::Vector1D {
+            x: v.x,
+            space: v.space.clone(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vector(pan: f64, tilt: f64, zoom: f64) -> tt::Ptzvector {
+        tt::Ptzvector {
+            pan_tilt: Some(tt::Vector2D {
+                x: pan,
+                y: tilt,
+                space: None,
+            }),
+            zoom: Some(tt::Vector1D {
+                x: zoom,
+                space: None,
+            }),
+        }
+    }
+
+    fn limits() -> Limits {
+        Limits {
+            pan: Some(Range {
+                min: -0.5,
+                max: 0.5,
+            }),
+            tilt: Some(Range {
+                min: -1.0,
+                max: 0.0,
+            }),
+            zoom: Some(Range { min: 0.0, max: 1.0 }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn clamps_and_rejects() {
+        assert_eq!(
+            limits()
+                .apply(&vector(0.8, -0.5, 2.0), Guard::Clamp)
+                .unwrap(),
+            vector(0.5, -0.5, 1.0)
+        );
+        assert_eq!(
+            limits()
+                .apply(&vector(0.1, -0.5, 0.5), Guard::Reject)
+                .unwrap(),
+            vector(0.1, -0.5, 0.5)
+        );
+        assert!(matches!(
+            limits().apply(&vector(0.1, 0.5, 0.5), Guard::Reject),
+            Err(Error::OutOfLimits { axis: "tilt", .. })
+        ));
+
+        // Positions in another space can't be checked.
+        let mut position = vector(0.8, 0.0, 0.0);
+        position.pan_tilt.as_mut().unwrap().space = Some("urn:degrees".to_string());
+        let limits = Limits {
+            pan_tilt_space: Some("urn:generic".to_string()),
+            ..limits()
+        };
+        assert_eq!(limits.apply(&position, Guard::Reject).unwrap(), position);
+    }
+
+    #[test]
+    fn clamps_translation() {
+        let translation = guarded_translation(
+            &vector(0.4, -0.5, 0.5),
+            &vector(0.4, 0.2, -0.2),
+            &limits(),
+            Guard::Clamp,
+        )
+        .unwrap();
+
+        let pan_tilt = translation.pan_tilt.unwrap();
+        assert!((pan_tilt.x - 0.1).abs() < 1e-9);
+        assert!((pan_tilt.y - 0.2).abs() < 1e-9);
+        assert!((translation.zoom.unwrap().x + 0.2).abs() < 1e-9);
+    }
+}
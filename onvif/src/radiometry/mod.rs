@@ -0,0 +1,574 @@
+//! Temperature measurement on radiometric thermal devices.
+//!
+//! Temperatures are measured by analytics modules, spots measuring a point and boxes measuring
+//! an area, which report readings in events and in the metadata stream. [`SpotModule`] and
+//! [`BoxModule`] build module configurations for
+//! [`analytics::create_analytics_modules`](crate::analytics::create_analytics_modules), and
+//! readings are decoded into [`Event::temperatures`](crate::event::Event::temperatures).
+//!
+//! Devices compute temperatures from the radiometry parameters of the module, or the global
+//! parameters of the thermal service if the module has none. When the emissivity of an object
+//! is only known afterwards, [`TemperatureReading::corrected`] recomputes the reading with it.
+//!
+//! ```no_run
+//! # async fn example(analytics_service: onvif::soap::client::Client) {
+//! use onvif::{analytics, radiometry::{Parameters, SpotModule}, schema::onvif as tt};
+//!
+//! let spot = SpotModule::new("Bearing", (0.1, -0.2))
+//!     .parameters(Parameters {
+//!         emissivity: Some(0.95),
+//!         ..Default::default()
+//!     })
+//!     .build();
+//! analytics::create_analytics_modules(
+//!     &analytics_service,
+//!     &tt::ReferenceToken("VideoAnalyticsConfig_1".to_string()),
+//!     &[spot],
+//! )
+//! .await
+//! .unwrap();
+//! # }
+//! ```
+
+use crate::analytics::Config;
+use schema::{
+    onvif as tt, thermal,
+    transport::{Error as TransportError, Transport},
+};
+use serde::Serialize;
+use xmltree::{Element, XMLNode};
+
+const TTR_NS: &str = "http://www.onvif.org/ver20/analytics/radiometry";
+const TT_NS: &str = "http://www.onvif.org/ver10/schema";
+
+/// Topic of the readings of spot modules.
+pub const SPOT_TEMPERATURE_TOPIC: &str = "tns1:VideoAnalytics/Radiometry/SpotTemperatureReading";
+
+/// Topic of the readings of box modules.
+pub const BOX_TEMPERATURE_TOPIC: &str = "tns1:VideoAnalytics/Radiometry/BoxTemperatureReading";
+
+/// A temperature, in Kelvin as devices report them.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Serialize)]
+pub struct Temperature(pub f64);
+
+impl Temperature {
+    pub fn from_celsius(celsius: f64) -> Self {
+        Self(celsius + 273.15)
+    }
+
+    pub fn kelvin(self) -> f64 {
+        self.0
+    }
+
+    pub fn celsius(self) -> f64 {
+        self.0 - 273.15
+    }
+
+    pub fn fahrenheit(self) -> f64 {
+        self.celsius() * 9.0 / 5.0 + 32.0
+    }
+}
+
+/// Radiometry parameters, i.e. a `ttr:RadiometryParameters`. Parameters left out fall back to
+/// the global ones of the thermal service.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Parameters {
+    pub reflected_ambient_temperature: Option<Temperature>,
+
+    /// Between 0 and 1, where 1 is a black body.
+    pub emissivity: Option<f64>,
+
+    /// In meters.
+    pub distance_to_object: Option<f64>,
+
+    pub relative_humidity: Option<f64>,
+    pub atmospheric_temperature: Option<Temperature>,
+    pub atmospheric_transmittance: Option<f64>,
+    pub ext_optics_temperature: Option<Temperature>,
+    pub ext_optics_transmittance: Option<f64>,
+}
+
+impl Parameters {
+    /// These parameters, with those left out taken from `defaults`.
+    pub fn or(&self, defaults: &Parameters) -> Parameters {
+        Parameters {
+            reflected_ambient_temperature: self
+                .reflected_ambient_temperature
+                .or(defaults.reflected_ambient_temperature),
+            emissivity: self.emissivity.or(defaults.emissivity),
+            distance_to_object: self.distance_to_object.or(defaults.distance_to_object),
+            relative_humidity: self.relative_humidity.or(defaults.relative_humidity),
+            atmospheric_temperature: self
+                .atmospheric_temperature
+                .or(defaults.atmospheric_temperature),
+            atmospheric_transmittance: self
+                .atmospheric_transmittance
+                .or(defaults.atmospheric_transmittance),
+            ext_optics_temperature: self
+                .ext_optics_temperature
+                .or(defaults.ext_optics_temperature),
+            ext_optics_transmittance: self
+                .ext_optics_transmittance
+                .or(defaults.ext_optics_transmittance),
+        }
+    }
+
+    fn fields(&self) -> [(&'static str, Option<f64>); 8] {
+        [
+            (
+                "ReflectedAmbientTemperature",
+                self.reflected_ambient_temperature.map(Temperature::kelvin),
+            ),
+            ("Emissivity", self.emissivity),
+            ("DistanceToObject", self.distance_to_object),
+            ("RelativeHumidity", self.relative_humidity),
+            (
+                "AtmosphericTemperature",
+                self.atmospheric_temperature.map(Temperature::kelvin),
+            ),
+            ("AtmosphericTransmittance", self.atmospheric_transmittance),
+            (
+                "ExtOpticsTemperature",
+                self.ext_optics_temperature.map(Temperature::kelvin),
+            ),
+            ("ExtOpticsTransmittance", self.ext_optics_transmittance),
+        ]
+    }
+
+    fn from_element(element: &Element) -> Self {
+        let value = |name: &str| {
+            element
+                .get_child(name)
+                .and_then(|child| child.get_text())
+                .and_then(|text| text.trim().parse().ok())
+        };
+        Parameters {
+            reflected_ambient_temperature: value("ReflectedAmbientTemperature").map(Temperature),
+            emissivity: value("Emissivity"),
+            distance_to_object: value("DistanceToObject"),
+            relative_humidity: value("RelativeHumidity"),
+            atmospheric_temperature: value("AtmosphericTemperature").map(Temperature),
+            atmospheric_transmittance: value("AtmosphericTransmittance"),
+            ext_optics_temperature: value("ExtOpticsTemperature").map(Temperature),
+            ext_optics_transmittance: value("ExtOpticsTransmittance"),
+        }
+    }
+
+    fn to_element(&self) -> Element {
+        let mut element = ttr_element("RadiometryParameters");
+        for (name, value) in self.fields().iter() {
+            if let Some(value) = value {
+                let mut child = ttr_element(name);
+                child.children.push(XMLNode::Text(value.to_string()));
+                element.children.push(XMLNode::Element(child));
+            }
+        }
+        element
+    }
+
+    /// Radiation the camera receives from an object at `temperature`, up to the Stefan-Boltzmann
+    /// constant, as the total radiation of a grey body through the atmosphere.
+    fn radiance(&self, temperature: Temperature) -> Option<f64> {
+        let emissivity = self.emissivity?;
+        let reflected = if emissivity < 1.0 {
+            (1.0 - emissivity) * self.reflected_ambient_temperature?.0.powi(4)
+        } else {
+            0.0
+        };
+        let object = emissivity * temperature.0.powi(4) + reflected;
+
+        match self.atmospheric_transmittance {
+            Some(transmittance) if transmittance < 1.0 => Some(
+                transmittance * object
+                    + (1.0 - transmittance) * self.atmospheric_temperature?.0.powi(4),
+            ),
+            _ => Some(object),
+        }
+    }
+
+    /// Inverse of [`Parameters::radiance`].
+    fn temperature(&self, radiance: f64) -> Option<Temperature> {
+        let emissivity = self.emissivity.filter(|e| *e > 0.0)?;
+        let object = match self.atmospheric_transmittance {
+            Some(transmittance) if transmittance <= 0.0 => return None,
+            Some(transmittance) if transmittance < 1.0 => {
+                (radiance - (1.0 - transmittance) * self.atmospheric_temperature?.0.powi(4))
+                    / transmittance
+            }
+            _ => radiance,
+        };
+        let reflected = if emissivity < 1.0 {
+            (1.0 - emissivity) * self.reflected_ambient_temperature?.0.powi(4)
+        } else {
+            0.0
+        };
+        let fourth = (object - reflected) / emissivity;
+        (fourth > 0.0).then(|| Temperature(fourth.powf(0.25)))
+    }
+}
+
+/// What a temperature measurement module measured.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Measurement {
+    Spot {
+        temperature: Temperature,
+    },
+    Box {
+        max: Temperature,
+        min: Temperature,
+        average: Option<Temperature>,
+        median: Option<Temperature>,
+    },
+}
+
+/// A `ttr:SpotTemperatureReading` or `ttr:BoxTemperatureReading`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TemperatureReading {
+    /// `ItemID` of the module configuration.
+    pub item: Option<String>,
+
+    pub measurement: Measurement,
+
+    /// Parameters the device measured with, `None` if it used the global parameters.
+    pub parameters: Option<Parameters>,
+}
+
+impl TemperatureReading {
+    /// Recomputes the reading for the `actual` parameters of the object, e.g. its emissivity,
+    /// taking parameters left out from those the device measured with. Those fall back to
+    /// `global`, see [`global_parameters`].
+    ///
+    /// This models the object as a grey body and only considers the atmosphere between it and
+    /// the camera, not external optics. Returns `None` if parameters needed for that are
+    /// unknown, e.g. the reflected ambient temperature when the emissivity isn't 1.
+    pub fn corrected(&self, global: &Parameters, actual: &Parameters) -> Option<Self> {
+        let used = match &self.parameters {
+            Some(parameters) => parameters.or(global),
+            None => global.clone(),
+        };
+        let actual = actual.or(&used);
+        let correct = |temperature: Temperature| actual.temperature(used.radiance(temperature)?);
+        // Fails only if the temperature is present but can't be corrected.
+        let correct_optional = |temperature: Option<Temperature>| {
+            temperature
+                .map(|temperature| correct(temperature).ok_or(()))
+                .transpose()
+                .ok()
+        };
+
+        let measurement = match &self.measurement {
+            Measurement::Spot { temperature } => Measurement::Spot {
+                temperature: correct(*temperature)?,
+            },
+            Measurement::Box {
+                max,
+                min,
+                average,
+                median,
+            } => Measurement::Box {
+                max: correct(*max)?,
+                min: correct(*min)?,
+                average: correct_optional(*average)?,
+                median: correct_optional(*median)?,
+            },
+        };
+
+        Some(Self {
+            item: self.item.clone(),
+            measurement,
+            parameters: Some(actual),
+        })
+    }
+}
+
+/// Decodes the content of an element item holding a reading, or returns `None` for other
+/// content.
+pub(crate) fn parse_reading(element: &Element) -> Option<TemperatureReading> {
+    let temperature = |name: &str| {
+        element
+            .attributes
+            .get(name)
+            .and_then(|value| value.trim().parse().ok())
+            .map(Temperature)
+    };
+
+    let measurement = if let Some(temperature) = temperature("SpotTemperature") {
+        Measurement::Spot { temperature }
+    } else {
+        Measurement::Box {
+            max: temperature("MaxTemperature")?,
+            min: temperature("MinTemperature")?,
+            average: temperature("AverageTemperature"),
+            median: temperature("MedianTemperature"),
+        }
+    };
+
+    Some(TemperatureReading {
+        item: element.attributes.get("ItemID").cloned(),
+        measurement,
+        parameters: element
+            .get_child("RadiometryParameters")
+            .map(Parameters::from_element),
+    })
+}
+
+/// Builds a `ttr:SpotMeasurementModule`, which measures the temperature at a point.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpotModule {
+    name: String,
+    point: (f64, f64),
+    parameters: Option<Parameters>,
+}
+
+impl SpotModule {
+    /// A spot at `point`, in normalized screen coordinates. The name is also the `ItemID` of
+    /// its readings.
+    pub fn new(name: &str, point: (f64, f64)) -> Self {
+        Self {
+            name: name.to_string(),
+            point,
+            parameters: None,
+        }
+    }
+
+    /// Parameters of the measured object, instead of the global ones.
+    pub fn parameters(mut self, parameters: Parameters) -> Self {
+        self.parameters = Some(parameters);
+        self
+    }
+
+    pub fn build(&self) -> Config {
+        let mut coords = ttr_element("ScreenCoords");
+        coords
+            .attributes
+            .insert("x".to_string(), self.point.0.to_string());
+        coords
+            .attributes
+            .insert("y".to_string(), self.point.1.to_string());
+
+        module(
+            &self.name,
+            "SpotMeasurementModule",
+            "RadiometrySpotModuleConfig",
+            coords,
+            self.parameters.as_ref(),
+        )
+    }
+}
+
+/// Builds a `ttr:BoxMeasurementModule`, which measures the maximum, minimum and, depending on
+/// the device, average and median temperatures in a rectangle.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoxModule {
+    name: String,
+    top_left: (f64, f64),
+    bottom_right: (f64, f64),
+    parameters: Option<Parameters>,
+}
+
+impl BoxModule {
+    /// A box between `top_left` and `bottom_right`, in normalized screen coordinates, where `y`
+    /// grows upwards. The name is also the `ItemID` of its readings.
+    pub fn new(name: &str, top_left: (f64, f64), bottom_right: (f64, f64)) -> Self {
+        Self {
+            name: name.to_string(),
+            top_left,
+            bottom_right,
+            parameters: None,
+        }
+    }
+
+    /// Parameters of the measured object, instead of the global ones.
+    pub fn parameters(mut self, parameters: Parameters) -> Self {
+        self.parameters = Some(parameters);
+        self
+    }
+
+    pub fn build(&self) -> Config {
+        let mut coords = ttr_element("ScreenCoords");
+        for (name, value) in [
+            ("left", self.top_left.0),
+            ("top", self.top_left.1),
+            ("right", self.bottom_right.0),
+            ("bottom", self.bottom_right.1),
+        ]
+        .iter()
+        {
+            coords
+                .attributes
+                .insert(name.to_string(), value.to_string());
+        }
+
+        module(
+            &self.name,
+            "BoxMeasurementModule",
+            "RadiometryBoxModuleConfig",
+            coords,
+            self.parameters.as_ref(),
+        )
+    }
+}
+
+fn module(
+    name: &str,
+    module_type: &str,
+    config_type: &str,
+    coords: Element,
+    parameters: Option<&Parameters>,
+) -> Config {
+    let mut config = ttr_element(config_type);
+    let mut namespaces = xmltree::Namespace::empty();
+    namespaces.put("ttr", TTR_NS);
+    namespaces.put("tt", TT_NS);
+    config.namespaces = Some(namespaces);
+    config
+        .attributes
+        .insert("ItemID".to_string(), name.to_string());
+    config
+        .attributes
+        .insert("Active".to_string(), "true".to_string());
+    config.children.push(XMLNode::Element(coords));
+    if let Some(parameters) = parameters {
+        config
+            .children
+            .push(XMLNode::Element(parameters.to_element()));
+    }
+
+    Config::new(name, &format!("ttr:{}", module_type))
+        .type_namespace(TTR_NS)
+        .element_item(module_type, config)
+}
+
+fn ttr_element(name: &str) -> Element {
+    let mut element = Element::new(name);
+    element.prefix = Some("ttr".to_string());
+    element.namespace = Some(TTR_NS.to_string());
+    element
+}
+
+/// Global radiometry parameters of a video source of the thermal service, which modules
+/// without parameters of their own measure with. `None` if the device has none.
+pub async fn global_parameters<T: Transport>(
+    thermal_service: &T,
+    video_source: &tt::ReferenceToken,
+) -> Result<Option<Parameters>, TransportError> {
+    let parameters = thermal::get_radiometry_configuration(
+        thermal_service,
+        &thermal::GetRadiometryConfiguration {
+            video_source_token: video_source.clone(),
+        },
+    )
+    .await?
+    .configuration
+    .radiometry_global_parameters;
+
+    Ok(parameters.map(|p| Parameters {
+        reflected_ambient_temperature: Some(Temperature(p.reflected_ambient_temperature)),
+        emissivity: Some(p.emissivity),
+        distance_to_object: Some(p.distance_to_object),
+        relative_humidity: p.relative_humidity,
+        atmospheric_temperature: p.atmospheric_temperature.map(Temperature),
+        atmospheric_transmittance: p.atmospheric_transmittance,
+        ext_optics_temperature: p.ext_optics_temperature.map(Temperature),
+        ext_optics_transmittance: p.ext_optics_transmittance,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::parse_notification_message;
+
+    #[test]
+    fn spot_module() {
+        let config = SpotModule::new("Bearing", (0.1, -0.2))
+            .parameters(Parameters {
+                emissivity: Some(0.95),
+                ..Default::default()
+            })
+            .build();
+        assert_eq!(config.config_type, "ttr:SpotMeasurementModule");
+
+        let content = &config.element_items["SpotMeasurementModule"];
+        assert_eq!(content.attributes["ItemID"], "Bearing");
+        let coords = content.get_child("ScreenCoords").unwrap();
+        assert_eq!(coords.attributes["y"], "-0.2");
+        let parameters = content.get_child("RadiometryParameters").unwrap();
+        assert_eq!(parameters.children.len(), 1);
+        assert_eq!(
+            parameters
+                .get_child("Emissivity")
+                .unwrap()
+                .get_text()
+                .unwrap(),
+            "0.95"
+        );
+    }
+
+    #[test]
+    fn decodes_and_corrects_readings() {
+        let notification = Element::parse(
+            r#"
+            <wsnt:NotificationMessage
+                xmlns:wsnt="http://docs.oasis-open.org/wsn/b-2"
+                xmlns:tns1="http://www.onvif.org/ver10/topics"
+                xmlns:tt="http://www.onvif.org/ver10/schema"
+                xmlns:ttr="http://www.onvif.org/ver20/analytics/radiometry">
+                <wsnt:Topic>tns1:VideoAnalytics/Radiometry/BoxTemperatureReading</wsnt:Topic>
+                <wsnt:Message>
+                    <tt:Message UtcTime="2022-03-01T10:00:00Z" PropertyOperation="Changed">
+                        <tt:Source>
+                            <tt:SimpleItem Name="AnalyticsModuleName" Value="Furnace"/>
+                        </tt:Source>
+                        <tt:Data>
+                            <tt:ElementItem Name="Reading">
+                                <ttr:BoxTemperatureReading ItemID="Furnace"
+                                    MaxTemperature="300" MinTemperature="290">
+                                    <ttr:RadiometryParameters>
+                                        <ttr:Emissivity>1</ttr:Emissivity>
+                                    </ttr:RadiometryParameters>
+                                </ttr:BoxTemperatureReading>
+                            </tt:ElementItem>
+                        </tt:Data>
+                    </tt:Message>
+                </wsnt:Message>
+            </wsnt:NotificationMessage>
+            "#
+            .as_bytes(),
+        )
+        .unwrap();
+
+        let event = parse_notification_message(&notification);
+        assert!(event.has_topic(BOX_TEMPERATURE_TOPIC));
+        let reading = &event.temperatures[0];
+        assert_eq!(reading.item.as_deref(), Some("Furnace"));
+        match &reading.measurement {
+            Measurement::Box { max, median, .. } => {
+                assert!((max.celsius() - 26.85).abs() < 1e-9);
+                assert!((max.fahrenheit() - 80.33).abs() < 1e-9);
+                assert_eq!(*median, None);
+            }
+            measurement => panic!("unexpected {:?}", measurement),
+        }
+
+        let global = Parameters {
+            reflected_ambient_temperature: Some(Temperature::from_celsius(20.0)),
+            ..Default::default()
+        };
+        let actual = Parameters {
+            emissivity: Some(0.95),
+            ..Default::default()
+        };
+        let corrected = reading.corrected(&global, &actual).unwrap();
+        match corrected.measurement {
+            Measurement::Box { max, .. } => assert!((max.kelvin() - 300.34776).abs() < 1e-4),
+            measurement => panic!("unexpected {:?}", measurement),
+        }
+        assert_eq!(
+            reading.corrected(&Parameters::default(), &actual),
+            None,
+            "the reflected ambient temperature is needed"
+        );
+    }
+}
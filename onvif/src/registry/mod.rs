@@ -0,0 +1,404 @@
+use crate::{discovery::Device, identity};
+use async_stream::stream;
+use chrono::{DateTime, Utc};
+use futures_core::stream::Stream;
+use futures_util::{pin_mut, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fs::File,
+    io::{self, BufReader, BufWriter},
+    path::Path,
+};
+use thiserror::Error;
+use url::Url;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// Everything known about a single device.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DeviceEntry {
+    /// Device service address.
+    pub address: Url,
+
+    /// WS-Addressing endpoint reference, the stable identity of the device if known, e.g. from
+    /// discovery or [`identity::endpoint_reference`].
+    pub endpoint_reference: Option<String>,
+
+    pub name: Option<String>,
+
+    /// Key of the device credentials in an external secret store.
+    /// Credentials themselves are never stored in the registry.
+    pub credentials_ref: Option<String>,
+
+    /// Service XAddrs by service namespace, as returned by `GetServices`.
+    #[serde(default)]
+    pub services: BTreeMap<String, Url>,
+
+    /// Device-specific workarounds the application applies to this device.
+    #[serde(default)]
+    pub quirks: Vec<String>,
+
+    /// Last time the device answered to discovery.
+    pub last_seen: Option<DateTime<Utc>>,
+
+    /// Whether the device answered to the last discovery round.
+    #[serde(default)]
+    pub online: bool,
+}
+
+impl DeviceEntry {
+    pub fn new(address: Url) -> Self {
+        Self {
+            address,
+            endpoint_reference: None,
+            name: None,
+            credentials_ref: None,
+            services: BTreeMap::new(),
+            quirks: vec![],
+            last_seen: None,
+            online: false,
+        }
+    }
+
+    /// Key of the entry in the registry: the endpoint reference if known, the address otherwise.
+    pub fn id(&self) -> String {
+        device_id(&self.address, &self.endpoint_reference)
+    }
+}
+
+impl From<&Device> for DeviceEntry {
+    fn from(device: &Device) -> Self {
+        Self {
+            endpoint_reference: device
+                .endpoint_reference
+                .as_deref()
+                .map(identity::normalize),
+            name: device.name.clone(),
+            ..Self::new(device.url.clone())
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RegistryEvent {
+    /// A device was discovered for the first time or came back online.
+    Appeared(String),
+
+    /// A device that was online did not answer to a discovery round.
+    Disappeared(String),
+
+    /// A known device answered from a different address (e.g. after DHCP lease change).
+    AddressChanged { id: String, old: Url, new: Url },
+}
+
+/// Tracks discovered and manually added devices.
+///
+/// The registry can be saved to and loaded from a JSON file so that it survives restarts.
+///
+/// ```
+/// use onvif::{discovery, registry::DeviceRegistry};
+/// use futures_util::stream::StreamExt;
+///
+/// async {
+///     let mut registry = DeviceRegistry::load("devices.json").unwrap_or_default();
+///
+///     let devices = discovery::discover(std::time::Duration::from_secs(1))
+///         .await
+///         .unwrap();
+///
+///     registry
+///         .track(devices)
+///         .for_each(|event| async move {
+///             println!("{:?}", event);
+///         })
+///         .await;
+///
+///     registry.save("devices.json").unwrap();
+/// };
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeviceRegistry {
+    devices: BTreeMap<String, DeviceEntry>,
+}
+
+impl DeviceRegistry {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let file = File::open(path)?;
+        Ok(serde_json::from_reader(BufReader::new(file))?)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let file = File::create(path)?;
+        Ok(serde_json::to_writer_pretty(BufWriter::new(file), self)?)
+    }
+
+    /// Adds or replaces an entry, returning the previous one with the same id.
+    pub fn insert(&mut self, entry: DeviceEntry) -> Option<DeviceEntry> {
+        self.devices.insert(entry.id(), entry)
+    }
+
+    pub fn remove(&mut self, id: &str) -> Option<DeviceEntry> {
+        self.devices.remove(id)
+    }
+
+    pub fn get(&self, id: &str) -> Option<&DeviceEntry> {
+        self.devices.get(id)
+    }
+
+    pub fn get_mut(&mut self, id: &str) -> Option<&mut DeviceEntry> {
+        self.devices.get_mut(id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &DeviceEntry> {
+        self.devices.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.devices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.devices.is_empty()
+    }
+
+    /// Updates the registry from one discovery round, e.g. the stream returned by
+    /// [`discover`](crate::discovery::discover).
+    ///
+    /// Events are yielded as devices respond. When the stream of discovered devices ends, every
+    /// device that was online but did not respond is marked offline and reported as disappeared.
+    pub fn track<'a, S>(&'a mut self, devices: S) -> impl Stream<Item = RegistryEvent> + 'a
+    where
+        S: Stream<Item = Device> + 'a,
+    {
+        stream! {
+            pin_mut!(devices);
+
+            let mut seen = HashSet::new();
+
+            while let Some(device) = devices.next().await {
+                seen.insert(device_id(&device.url, &device.endpoint_reference));
+
+                for event in self.observe(&device) {
+                    yield event;
+                }
+            }
+
+            for entry in self.devices.values_mut() {
+                if entry.online && !seen.contains(&entry.id()) {
+                    entry.online = false;
+                    yield RegistryEvent::Disappeared(entry.id());
+                }
+            }
+        }
+    }
+
+    /// Re-keys the entry `id`, e.g. a manually added device keyed by its address, by its endpoint
+    /// reference, returning the new id. If the registry already has an entry with the endpoint
+    /// reference, e.g. from discovery, the two are merged, keeping the address and credentials of
+    /// `id`.
+    pub fn identify(&mut self, id: &str, endpoint_reference: &str) -> Option<String> {
+        let mut entry = self.devices.remove(id)?;
+        entry.endpoint_reference = Some(identity::normalize(endpoint_reference));
+        let new_id = entry.id();
+
+        if let Some(existing) = self.devices.remove(&new_id) {
+            entry.name = entry.name.or(existing.name);
+            entry.credentials_ref = entry.credentials_ref.or(existing.credentials_ref);
+            if entry.services.is_empty() {
+                entry.services = existing.services;
+            }
+            for quirk in existing.quirks {
+                if !entry.quirks.contains(&quirk) {
+                    entry.quirks.push(quirk);
+                }
+            }
+            entry.last_seen = entry.last_seen.max(existing.last_seen);
+            entry.online |= existing.online;
+        }
+
+        self.devices.insert(new_id.clone(), entry);
+        Some(new_id)
+    }
+
+    fn observe(&mut self, device: &Device) -> Vec<RegistryEvent> {
+        let id = device_id(&device.url, &device.endpoint_reference);
+        let entry = self
+            .devices
+            .entry(id.clone())
+            .or_insert_with(|| DeviceEntry::from(device));
+
+        let mut events = vec![];
+
+        if entry.address != device.url {
+            events.push(RegistryEvent::AddressChanged {
+                id: id.clone(),
+                old: entry.address.clone(),
+                new: device.url.clone(),
+            });
+            entry.address = device.url.clone();
+        }
+
+        if !entry.online {
+            events.push(RegistryEvent::Appeared(id));
+        }
+
+        if device.name.is_some() {
+            entry.name = device.name.clone();
+        }
+        entry.online = true;
+        entry.last_seen = Some(Utc::now());
+
+        events
+    }
+}
+
+fn device_id(address: &Url, endpoint_reference: &Option<String>) -> String {
+    endpoint_reference
+        .as_deref()
+        .map_or_else(|| address.to_string(), identity::normalize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::stream;
+
+    fn device(url: &str, endpoint_reference: &str) -> Device {
+        Device {
+            name: Some("MyCamera2000".to_string()),
+            url: url.parse().unwrap(),
+            endpoint_reference: Some(endpoint_reference.to_string()),
+        }
+    }
+
+    async fn track(registry: &mut DeviceRegistry, devices: Vec<Device>) -> Vec<RegistryEvent> {
+        registry.track(stream::iter(devices)).collect().await
+    }
+
+    #[tokio::test]
+    async fn discovery_rounds() {
+        let mut registry = DeviceRegistry::new();
+        let a = "urn:uuid:a1f48ac2-dc8b-11df-b255-00408c1836b2";
+        let b = "urn:uuid:84ede3de-e374-11df-b259-00408c1836b2";
+
+        let events = track(
+            &mut registry,
+            vec![
+                device("http://192.168.0.2/onvif/device_service", a),
+                device("http://192.168.0.3/onvif/device_service", b),
+            ],
+        )
+        .await;
+
+        assert_eq!(
+            events,
+            vec![
+                RegistryEvent::Appeared(a.to_string()),
+                RegistryEvent::Appeared(b.to_string())
+            ]
+        );
+        assert_eq!(registry.len(), 2);
+
+        let events = track(
+            &mut registry,
+            vec![device("http://192.168.0.4/onvif/device_service", a)],
+        )
+        .await;
+
+        assert_eq!(
+            events,
+            vec![
+                RegistryEvent::AddressChanged {
+                    id: a.to_string(),
+                    old: "http://192.168.0.2/onvif/device_service".parse().unwrap(),
+                    new: "http://192.168.0.4/onvif/device_service".parse().unwrap(),
+                },
+                RegistryEvent::Disappeared(b.to_string())
+            ]
+        );
+        assert!(registry.get(a).unwrap().online);
+        assert!(!registry.get(b).unwrap().online);
+
+        let events = track(
+            &mut registry,
+            vec![
+                device("http://192.168.0.4/onvif/device_service", a),
+                device("http://192.168.0.3/onvif/device_service", b),
+            ],
+        )
+        .await;
+
+        assert_eq!(events, vec![RegistryEvent::Appeared(b.to_string())]);
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let mut registry = DeviceRegistry::new();
+
+        let mut entry =
+            DeviceEntry::new("http://192.168.0.2/onvif/device_service".parse().unwrap());
+        entry.credentials_ref = Some("vault:cameras/lobby".to_string());
+        entry.quirks.push("no-digest".to_string());
+        entry.services.insert(
+            "http://www.onvif.org/ver10/media/wsdl".to_string(),
+            "http://192.168.0.2/onvif/media_service".parse().unwrap(),
+        );
+        registry.insert(entry);
+
+        let json = serde_json::to_string(&registry).unwrap();
+        let restored: DeviceRegistry = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, registry);
+        assert!(restored
+            .get("http://192.168.0.2/onvif/device_service")
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn identifies_manual_entries() {
+        let mut registry = DeviceRegistry::new();
+        let address = "http://192.168.0.2/onvif/device_service";
+
+        let mut entry = DeviceEntry::new(address.parse().unwrap());
+        entry.credentials_ref = Some("vault:cameras/lobby".to_string());
+        registry.insert(entry);
+
+        // Discovery and `GetEndpointReference` spell the same UUID differently.
+        track(
+            &mut registry,
+            vec![device(
+                "http://192.168.0.3/onvif/device_service",
+                "uuid:A1F48AC2-DC8B-11DF-B255-00408C1836B2",
+            )],
+        )
+        .await;
+        assert_eq!(registry.len(), 2);
+
+        let id = registry
+            .identify(address, "a1f48ac2-dc8b-11df-b255-00408c1836b2")
+            .unwrap();
+        assert_eq!(id, "urn:uuid:a1f48ac2-dc8b-11df-b255-00408c1836b2");
+        assert_eq!(registry.len(), 1);
+
+        let entry = registry.get(&id).unwrap();
+        assert_eq!(entry.address.as_str(), address);
+        assert_eq!(
+            entry.credentials_ref.as_deref(),
+            Some("vault:cameras/lobby")
+        );
+        assert_eq!(entry.name.as_deref(), Some("MyCamera2000"));
+        assert!(entry.online);
+    }
+}
@@ -0,0 +1,333 @@
+//! iCalendar bodies of schedules, e.g. when doors are accessible or cameras record.
+//!
+//! The schedule service takes the regular part of a schedule as an iCalendar string in
+//! `Schedule::standard`. [`ScheduleBuilder`] writes one from weekdays and time ranges, with the
+//! weekly recurrences every device supports, and [`parse`] reads them back.
+//!
+//! ```
+//! use chrono::{NaiveTime, Weekday};
+//! use onvif::schedule::{self, ScheduleBuilder};
+//!
+//! let standard = ScheduleBuilder::new()
+//!     .weekly(
+//!         &[Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri],
+//!         NaiveTime::from_hms(9, 0, 0),
+//!         NaiveTime::from_hms(18, 0, 0),
+//!     )
+//!     .build();
+//! assert!(standard.contains("RRULE:FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR"));
+//! assert_eq!(schedule::parse(&standard).unwrap().len(), 1);
+//! ```
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Utc, Weekday};
+use schema::{schedule as tsc, types as pt};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Invalid schedule: {0}")]
+    Invalid(String),
+
+    #[error("Unsupported: {0}")]
+    Unsupported(String),
+}
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+/// A time range recurring on some days of the week.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeRange {
+    pub days: Vec<Weekday>,
+    pub start: NaiveTime,
+
+    /// End of the range, on the next day if it isn't after `start`, e.g. midnight.
+    pub end: NaiveTime,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ScheduleBuilder {
+    ranges: Vec<TimeRange>,
+}
+
+impl ScheduleBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a time range on `days`. Ranges ending at or before `start` end on the next day.
+    pub fn weekly(mut self, days: &[Weekday], start: NaiveTime, end: NaiveTime) -> Self {
+        self.ranges.push(TimeRange {
+            days: days.to_vec(),
+            start,
+            end,
+        });
+        self
+    }
+
+    /// Adds a time range on every day.
+    pub fn daily(self, start: NaiveTime, end: NaiveTime) -> Self {
+        self.weekly(&WEEKDAYS, start, end)
+    }
+
+    /// Adds the whole week, for always active schedules.
+    pub fn always(self) -> Self {
+        let midnight = NaiveTime::from_hms(0, 0, 0);
+        self.daily(midnight, midnight)
+    }
+
+    /// The iCalendar body, with one event per time range. Events start on the first of their days
+    /// on or after 1970-01-01, the date of the examples of the schedule service specification:
+    /// the start of an event is an occurrence even if its weekday isn't in the recurrence.
+    pub fn build(&self) -> String {
+        let stamp = Utc::now().format("%Y%m%dT%H%M%SZ");
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_string(),
+            "VERSION:2.0".to_string(),
+            "PRODID:-//onvif-rs//schedule//EN".to_string(),
+        ];
+        for range in &self.ranges {
+            let date = first_day(&range.days);
+            let end_date = if range.end > range.start {
+                date
+            } else {
+                date.succ()
+            };
+            let rule = if WEEKDAYS.iter().all(|day| range.days.contains(day)) {
+                "FREQ=DAILY".to_string()
+            } else {
+                let days: Vec<_> = WEEKDAYS
+                    .iter()
+                    .filter(|day| range.days.contains(day))
+                    .map(|day| ical_day(*day))
+                    .collect();
+                format!("FREQ=WEEKLY;BYDAY={}", days.join(","))
+            };
+            lines.extend(vec![
+                "BEGIN:VEVENT".to_string(),
+                format!("UID:{}", uuid::Uuid::new_v4()),
+                format!("DTSTAMP:{}", stamp),
+                format!("DTSTART:{}", date.and_time(range.start).format(ICAL_FORMAT)),
+                format!("DTEND:{}", end_date.and_time(range.end).format(ICAL_FORMAT)),
+                format!("RRULE:{}", rule),
+                "END:VEVENT".to_string(),
+            ]);
+        }
+        lines.push("END:VCALENDAR".to_string());
+
+        let mut body = lines.join("\r\n");
+        body.push_str("\r\n");
+        body
+    }
+
+    /// A schedule for `CreateSchedule`, with the body of [`ScheduleBuilder::build`].
+    pub fn schedule(&self, name: &str) -> tsc::Schedule {
+        tsc::Schedule {
+            standard: self.build(),
+            base: tsc::ScheduleInfo {
+                name: pt::Name(name.to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+}
+
+/// The first date on or after 1970-01-01, a Thursday, which is one of `days`.
+fn first_day(days: &[Weekday]) -> NaiveDate {
+    let epoch = NaiveDate::from_ymd(1970, 1, 1);
+    (0..7)
+        .map(|i| epoch + chrono::Duration::days(i))
+        .find(|date| days.contains(&date.weekday()))
+        .unwrap_or(epoch)
+}
+
+/// Local date and time, which schedules are in.
+const ICAL_FORMAT: &str = "%Y%m%dT%H%M%S";
+
+fn ical_day(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+/// Reads the events of an iCalendar body back into time ranges. Events have to recur daily or
+/// weekly on some days, as those [`ScheduleBuilder`] writes, and may be longer than a day only
+/// by ending on the next one.
+pub fn parse(standard: &str) -> Result<Vec<TimeRange>, Error> {
+    // Long lines are folded by continuing them on lines starting with a space or tab.
+    let unfolded = standard
+        .replace("\r\n ", "")
+        .replace("\r\n\t", "")
+        .replace("\n ", "")
+        .replace("\n\t", "");
+
+    let mut ranges = vec![];
+    let mut event: Option<(Option<NaiveDateTime>, Option<NaiveDateTime>, Option<String>)> = None;
+    for line in unfolded.lines() {
+        let (name, value) = match line.split_once(':') {
+            Some((name, value)) => (name.split(';').next().unwrap_or_default(), value.trim()),
+            None => continue,
+        };
+        match (name.to_ascii_uppercase().as_str(), &mut event) {
+            ("BEGIN", _) if value.eq_ignore_ascii_case("VEVENT") => {
+                event = Some((None, None, None))
+            }
+            ("END", Some((start, end, rule))) if value.eq_ignore_ascii_case("VEVENT") => {
+                let start = start.ok_or_else(|| Error::Invalid("event without DTSTART".into()))?;
+                let end = end.ok_or_else(|| Error::Invalid("event without DTEND".into()))?;
+                let rule = rule
+                    .take()
+                    .ok_or_else(|| Error::Unsupported("events without RRULE".to_string()))?;
+                ranges.push(range(start, end, &rule)?);
+                event = None;
+            }
+            ("DTSTART", Some((start, _, _))) => *start = Some(date_time(value)?),
+            ("DTEND", Some((_, end, _))) => *end = Some(date_time(value)?),
+            ("RRULE", Some((_, _, rule))) => *rule = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    Ok(ranges)
+}
+
+fn date_time(value: &str) -> Result<NaiveDateTime, Error> {
+    NaiveDateTime::parse_from_str(value.trim_end_matches('Z'), ICAL_FORMAT)
+        .map_err(|e| Error::Invalid(format!("{}: {}", value, e)))
+}
+
+fn range(start: NaiveDateTime, end: NaiveDateTime, rule: &str) -> Result<TimeRange, Error> {
+    let days = (end.date() - start.date()).num_days();
+    if end <= start || days > 1 || (days == 1 && end.time() > start.time()) {
+        return Err(Error::Unsupported(format!(
+            "events from {} to {}",
+            start, end
+        )));
+    }
+
+    let mut frequency = None;
+    let mut by_day = None;
+    for part in rule.split(';') {
+        match part.split_once('=') {
+            Some(("FREQ", value)) => frequency = Some(value),
+            Some(("BYDAY", value)) => by_day = Some(value),
+            Some(("INTERVAL", "1")) | Some(("WKST", _)) => {}
+            _ => return Err(Error::Unsupported(format!("recurrence {}", rule))),
+        }
+    }
+
+    let days = match (frequency, by_day) {
+        (Some("DAILY"), None) => WEEKDAYS.to_vec(),
+        (Some("WEEKLY"), None) => vec![start.date().weekday()],
+        (Some("WEEKLY"), Some(by_day)) => by_day
+            .split(',')
+            .map(|day| {
+                WEEKDAYS
+                    .iter()
+                    .copied()
+                    .find(|weekday| ical_day(*weekday) == day)
+                    .ok_or_else(|| Error::Unsupported(format!("recurrence {}", rule)))
+            })
+            .collect::<Result<_, _>>()?,
+        _ => return Err(Error::Unsupported(format!("recurrence {}", rule))),
+    };
+
+    Ok(TimeRange {
+        days,
+        start: start.time(),
+        end: end.time(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips() {
+        let builder = ScheduleBuilder::new()
+            .weekly(
+                &[Weekday::Sat, Weekday::Mon],
+                NaiveTime::from_hms(22, 0, 0),
+                NaiveTime::from_hms(6, 0, 0),
+            )
+            .always();
+        let standard = builder.build();
+        assert!(standard.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(standard.contains(
+            "DTSTART:19700103T220000\r\nDTEND:19700104T060000\r\nRRULE:FREQ=WEEKLY;BYDAY=MO,SA\r\n"
+        ));
+        assert!(standard
+            .contains("DTSTART:19700101T000000\r\nDTEND:19700102T000000\r\nRRULE:FREQ=DAILY\r\n"));
+
+        assert_eq!(
+            parse(&standard).unwrap(),
+            vec![
+                TimeRange {
+                    days: vec![Weekday::Mon, Weekday::Sat],
+                    start: NaiveTime::from_hms(22, 0, 0),
+                    end: NaiveTime::from_hms(6, 0, 0),
+                },
+                TimeRange {
+                    days: WEEKDAYS.to_vec(),
+                    start: NaiveTime::from_hms(0, 0, 0),
+                    end: NaiveTime::from_hms(0, 0, 0),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn starts_on_the_first_day() {
+        let weekend = ScheduleBuilder::new()
+            .weekly(
+                &[Weekday::Sun, Weekday::Sat],
+                NaiveTime::from_hms(10, 0, 0),
+                NaiveTime::from_hms(16, 0, 0),
+            )
+            .build();
+        assert!(weekend.contains(
+            "DTSTART:19700103T100000\r\nDTEND:19700103T160000\r\nRRULE:FREQ=WEEKLY;BYDAY=SA,SU\r\n"
+        ));
+        assert_eq!(
+            parse(&weekend).unwrap()[0].days,
+            vec![Weekday::Sat, Weekday::Sun]
+        );
+    }
+
+    #[test]
+    fn parses_device_schedules() {
+        // From the schedule service specification.
+        let standard = "BEGIN:VCALENDAR\n\
+                        BEGIN:VEVENT\n\
+                        SUMMARY:Access on weekdays from 9 AM to 6 PM for employees\n\
+                        DTSTART:19700101T090000\n\
+                        DTEND:19700101T180000\n\
+                        RRULE:FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,\n \
+                        FR\n\
+                        END:VEVENT\n\
+                        END:VCALENDAR\n";
+        let ranges = parse(standard).unwrap();
+        assert_eq!(ranges[0].days.len(), 5);
+        assert_eq!(ranges[0].end, NaiveTime::from_hms(18, 0, 0));
+
+        assert!(matches!(
+            parse(&standard.replace("FREQ=WEEKLY", "FREQ=MONTHLY")),
+            Err(Error::Unsupported(_))
+        ));
+    }
+}
@@ -0,0 +1,345 @@
+//! Typed scopes and helpers to change them without touching fixed scopes.
+//!
+//! Scopes are URIs a device is discovered by, e.g. `onvif://www.onvif.org/name/Front%20door`.
+//! Devices have fixed scopes, such as the hardware and profiles, which can't be removed, and
+//! configurable ones, such as the name and location. `SetScopes` replaces all configurable
+//! scopes and many devices fault if it includes a fixed one, so [`set_scopes`] leaves them out.
+//!
+//! ```no_run
+//! # async fn example(device_service: onvif::soap::client::Client) {
+//! use onvif::scopes::{self, Scope};
+//!
+//! scopes::replace(&device_service, Scope::Name("Front door".to_string()))
+//!     .await
+//!     .unwrap();
+//! for scope in scopes::scopes(&device_service).await.unwrap() {
+//!     println!("{} (fixed: {})", scope.scope, scope.fixed);
+//! }
+//! # }
+//! ```
+
+use schema::{
+    devicemgmt, onvif as tt,
+    transport::{Error as TransportError, Transport},
+};
+use std::{fmt, mem};
+use thiserror::Error;
+
+const SCOPE_PREFIX: &str = "onvif://www.onvif.org/";
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Transport error: {0}")]
+    Transport(#[from] TransportError),
+
+    #[error("Scope {0} is fixed")]
+    FixedScope(String),
+}
+
+/// A scope URI, with the ONVIF categories decoded.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Scope {
+    /// `onvif://www.onvif.org/name/{name}`
+    Name(String),
+
+    /// `onvif://www.onvif.org/location/{location}`, where the location may be hierarchical,
+    /// e.g. `country/france`.
+    Location(String),
+
+    /// `onvif://www.onvif.org/hardware/{model}`
+    Hardware(String),
+
+    /// `onvif://www.onvif.org/Profile/{profile}`, e.g. `Streaming` or `T`.
+    Profile(String),
+
+    /// `onvif://www.onvif.org/type/{type}`, e.g. `video_encoder`.
+    Type(String),
+
+    /// Any other URI, kept as it is.
+    Custom(String),
+}
+
+impl Scope {
+    /// Parses a scope URI. URIs of unknown categories or outside `onvif://www.onvif.org/` are
+    /// [`Scope::Custom`].
+    pub fn parse(uri: &str) -> Self {
+        let uri = uri.trim();
+        let (category, value) = match uri
+            .strip_prefix(SCOPE_PREFIX)
+            .and_then(|path| path.split_once('/'))
+        {
+            Some((category, value)) if !value.is_empty() => (category, decode(value)),
+            _ => return Scope::Custom(uri.to_string()),
+        };
+        match category {
+            "name" => Scope::Name(value),
+            "location" => Scope::Location(value),
+            "hardware" => Scope::Hardware(value),
+            "Profile" => Scope::Profile(value),
+            "type" => Scope::Type(value),
+            _ => Scope::Custom(uri.to_string()),
+        }
+    }
+
+    /// The URI, with the value of ONVIF categories percent-encoded.
+    pub fn uri(&self) -> String {
+        let (category, value) = match self {
+            Scope::Name(value) => ("name", value),
+            Scope::Location(value) => ("location", value),
+            Scope::Hardware(value) => ("hardware", value),
+            Scope::Profile(value) => ("Profile", value),
+            Scope::Type(value) => ("type", value),
+            Scope::Custom(uri) => return uri.clone(),
+        };
+        format!("{}{}/{}", SCOPE_PREFIX, category, encode(value))
+    }
+
+    fn same_category(&self, other: &Scope) -> bool {
+        match (self, other) {
+            (Scope::Custom(_), _) | (_, Scope::Custom(_)) => false,
+            _ => mem::discriminant(self) == mem::discriminant(other),
+        }
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.uri())
+    }
+}
+
+/// A scope of a device.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeviceScope {
+    pub scope: Scope,
+
+    /// Whether the scope can't be changed.
+    pub fixed: bool,
+}
+
+/// Requests the scopes of the device.
+pub async fn scopes<T: Transport>(device_service: &T) -> Result<Vec<DeviceScope>, Error> {
+    Ok(devicemgmt::get_scopes(device_service, &Default::default())
+        .await?
+        .scopes
+        .into_iter()
+        .map(|scope| DeviceScope {
+            scope: Scope::parse(&scope.scope_item),
+            fixed: scope.scope_def == tt::ScopeDefinition::Fixed,
+        })
+        .collect())
+}
+
+/// Adds the scopes the device doesn't have yet.
+pub async fn add_scopes<T: Transport>(device_service: &T, add: &[Scope]) -> Result<(), Error> {
+    let existing = scopes(device_service).await?;
+    let scope_item: Vec<String> = add
+        .iter()
+        .filter(|scope| !existing.iter().any(|e| &e.scope == *scope))
+        .map(Scope::uri)
+        .collect();
+    if scope_item.is_empty() {
+        return Ok(());
+    }
+    devicemgmt::add_scopes(device_service, &devicemgmt::AddScopes { scope_item }).await?;
+    Ok(())
+}
+
+/// Removes the scopes the device has. Fails without changing anything if one of them is fixed.
+pub async fn remove_scopes<T: Transport>(
+    device_service: &T,
+    remove: &[Scope],
+) -> Result<(), Error> {
+    let existing = scopes(device_service).await?;
+    let mut scope_item = vec![];
+    for scope in remove {
+        match existing.iter().find(|e| &e.scope == scope) {
+            Some(e) if e.fixed => return Err(Error::FixedScope(scope.uri())),
+            Some(_) => scope_item.push(scope.uri()),
+            None => {}
+        }
+    }
+    if scope_item.is_empty() {
+        return Ok(());
+    }
+    devicemgmt::remove_scopes(device_service, &devicemgmt::RemoveScopes { scope_item }).await?;
+    Ok(())
+}
+
+/// Replaces the configurable scopes with `scopes`. Fixed scopes stay as they are, including ones
+/// that are also in `scopes`.
+pub async fn set_scopes<T: Transport>(device_service: &T, set: &[Scope]) -> Result<(), Error> {
+    let existing = scopes(device_service).await?;
+    set_configurable(device_service, &existing, set).await
+}
+
+/// Replaces the configurable scopes of the category of `scope`, e.g. the name, with `scope`.
+/// Fails if the device has a fixed scope of that category. [`Scope::Custom`] scopes are added to
+/// the others.
+pub async fn replace<T: Transport>(device_service: &T, scope: Scope) -> Result<(), Error> {
+    let existing = scopes(device_service).await?;
+    if let Some(fixed) = existing
+        .iter()
+        .find(|e| e.fixed && e.scope.same_category(&scope))
+    {
+        return Err(Error::FixedScope(fixed.scope.uri()));
+    }
+
+    let mut set: Vec<Scope> = existing
+        .iter()
+        .filter(|e| !e.fixed && !e.scope.same_category(&scope) && e.scope != scope)
+        .map(|e| e.scope.clone())
+        .collect();
+    set.push(scope);
+    set_configurable(device_service, &existing, &set).await
+}
+
+async fn set_configurable<T: Transport>(
+    device_service: &T,
+    existing: &[DeviceScope],
+    set: &[Scope],
+) -> Result<(), Error> {
+    let configurable: Vec<String> = set
+        .iter()
+        .filter(|scope| !existing.iter().any(|e| e.fixed && &e.scope == *scope))
+        .map(Scope::uri)
+        .collect();
+    devicemgmt::set_scopes(
+        device_service,
+        &devicemgmt::SetScopes {
+            scopes: configurable,
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+fn decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = match bytes.get(i + 1..i + 3) {
+            Some(hex) if bytes[i] == b'%' => std::str::from_utf8(hex)
+                .ok()
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok()),
+            _ => None,
+        };
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                (byte as char).to_string()
+            }
+            byte => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::fake_device::FakeDevice;
+
+    #[test]
+    fn parses_scopes() {
+        assert_eq!(
+            Scope::parse("onvif://www.onvif.org/name/Front%20door"),
+            Scope::Name("Front door".to_string())
+        );
+        assert_eq!(
+            Scope::parse("onvif://www.onvif.org/location/country/france"),
+            Scope::Location("country/france".to_string())
+        );
+        assert_eq!(
+            Scope::parse("onvif://www.onvif.org/Profile/Streaming"),
+            Scope::Profile("Streaming".to_string())
+        );
+        assert_eq!(
+            Scope::parse("onvif://www.onvif.org/extension/x"),
+            Scope::Custom("onvif://www.onvif.org/extension/x".to_string())
+        );
+        assert_eq!(
+            Scope::Name("Café 1".to_string()).uri(),
+            "onvif://www.onvif.org/name/Caf%C3%A9%201"
+        );
+        assert_eq!(
+            Scope::parse(&Scope::Name("Café 1".to_string()).uri()),
+            Scope::Name("Café 1".to_string())
+        );
+    }
+
+    /// Has a fixed hardware scope and a configurable name and location.
+    fn fake_device() -> FakeDevice {
+        FakeDevice::new()
+            .respond(
+                "GetScopes",
+                r#"
+                <tds:GetScopesResponse
+                    xmlns:tds="http://www.onvif.org/ver10/device/wsdl"
+                    xmlns:tt="http://www.onvif.org/ver10/schema">
+                    <tds:Scopes>
+                        <tt:ScopeDef>Fixed</tt:ScopeDef>
+                        <tt:ScopeItem>onvif://www.onvif.org/hardware/C100</tt:ScopeItem>
+                    </tds:Scopes>
+                    <tds:Scopes>
+                        <tt:ScopeDef>Configurable</tt:ScopeDef>
+                        <tt:ScopeItem>onvif://www.onvif.org/name/Camera</tt:ScopeItem>
+                    </tds:Scopes>
+                    <tds:Scopes>
+                        <tt:ScopeDef>Configurable</tt:ScopeDef>
+                        <tt:ScopeItem>onvif://www.onvif.org/location/garden</tt:ScopeItem>
+                    </tds:Scopes>
+                </tds:GetScopesResponse>
+                "#,
+            )
+            .respond(
+                "SetScopes",
+                r#"<tds:SetScopesResponse xmlns:tds="http://www.onvif.org/ver10/device/wsdl"/>"#,
+            )
+    }
+
+    #[tokio::test]
+    async fn keeps_fixed_scopes() {
+        let device = fake_device();
+
+        replace(&device, Scope::Name("Front door".to_string()))
+            .await
+            .unwrap();
+        let request = device.requests("SetScopes").pop().unwrap();
+        assert!(request.contains("onvif://www.onvif.org/location/garden"));
+        assert!(request.contains("onvif://www.onvif.org/name/Front%20door"));
+        assert!(!request.contains("name/Camera"));
+        assert!(!request.contains("hardware"));
+
+        assert!(matches!(
+            replace(&device, Scope::Hardware("C200".to_string())).await,
+            Err(Error::FixedScope(_))
+        ));
+        assert!(matches!(
+            remove_scopes(&device, &[Scope::Hardware("C100".to_string())]).await,
+            Err(Error::FixedScope(_))
+        ));
+
+        // Scopes the device already has aren't added again.
+        add_scopes(&device, &[Scope::Name("Camera".to_string())])
+            .await
+            .unwrap();
+        assert!(device.requests("AddScopes").is_empty());
+    }
+}
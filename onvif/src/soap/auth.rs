@@ -1,2 +0,0 @@
-pub(crate) mod digest;
-pub(crate) mod username_token;
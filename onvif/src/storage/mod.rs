@@ -0,0 +1,448 @@
+//! Recording to the storage of the device itself, usually an SD card.
+//!
+//! [`enable_edge_recording`] picks a local storage configuration, creates a recording of a media
+//! profile and a recording job on it, and activates the job. ONVIF has no operation reporting
+//! whether a card is inserted or formatted: devices report a missing, unformatted or full card as
+//! an error of the job instead, which [`StorageState::Failed`] carries.
+//!
+//! ```no_run
+//! # async fn example(
+//! #     device_service: onvif::soap::client::Client,
+//! #     recording_service: onvif::soap::client::Client,
+//! # ) {
+//! use onvif::storage::{self, StorageState};
+//! use std::time::Duration;
+//!
+//! let recording = storage::enable_edge_recording(
+//!     &device_service,
+//!     &recording_service,
+//!     "Profile_1",
+//!     Duration::from_secs(7 * 24 * 3600),
+//! )
+//! .await
+//! .unwrap();
+//! if let StorageState::Failed(error) = &recording.state {
+//!     println!("Check the SD card of the device: {}", error);
+//! }
+//! # }
+//! ```
+
+use schema::{
+    devicemgmt, onvif as tt,
+    recording::{self, RecordingJobStateInformation},
+    transport::{Error as TransportError, Transport},
+};
+use std::time::Duration;
+use thiserror::Error;
+use xsd_types::types as xs;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Transport error: {0}")]
+    Transport(#[from] TransportError),
+
+    #[error("The device has no local storage configured")]
+    NoLocalStorage,
+}
+
+/// A storage configuration of the device that isn't a network share.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LocalStorage {
+    pub token: String,
+
+    /// Type of the storage as reported, which devices fill in their own ways for local storage.
+    pub storage_type: String,
+
+    pub local_path: Option<String>,
+}
+
+/// State of the storage as far as the recording job on it tells.
+#[derive(Clone, Debug, PartialEq)]
+pub enum StorageState {
+    /// All tracks of the job are being recorded.
+    Recording,
+
+    /// Some tracks of the job are being recorded, the others failed.
+    PartiallyRecording(String),
+
+    /// The job isn't recording, e.g. as its mode is `Idle`.
+    Idle,
+
+    /// The job failed, e.g. as the card is missing, unformatted or full, with the errors of its
+    /// tracks.
+    Failed(String),
+}
+
+/// What [`enable_edge_recording`] created.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EdgeRecording {
+    pub storage: LocalStorage,
+    pub recording_token: String,
+    pub job_token: String,
+    pub state: StorageState,
+}
+
+/// Type of the sources of recording jobs that are media profiles.
+const PROFILE_SOURCE: &str = "http://www.onvif.org/ver10/schema/Profile";
+
+const JOB_MODE_IDLE: &str = "Idle";
+const JOB_MODE_ACTIVE: &str = "Active";
+
+/// Requests the storage configurations with `GetStorageConfigurations` and returns the local ones.
+pub async fn local_storages<T: Transport>(device_service: &T) -> Result<Vec<LocalStorage>, Error> {
+    let storages = devicemgmt::get_storage_configurations(device_service, &Default::default())
+        .await?
+        .storage_configurations
+        .into_iter()
+        .filter(|storage| {
+            let uri = storage.data.storage_uri.as_deref().unwrap_or_default();
+            uri.trim().is_empty() || uri.starts_with("file:")
+        })
+        .map(|storage| LocalStorage {
+            token: storage.base.token.0,
+            storage_type: storage.data._type,
+            local_path: storage.data.local_path,
+        })
+        .collect();
+    Ok(storages)
+}
+
+/// Records the media profile `profile_token` to the first local storage of the device, keeping
+/// recordings for `retention`.
+///
+/// The recording and the job are created with the recording service and the job is only activated
+/// once both exist. If anything fails after creating the recording, what was created is deleted
+/// again.
+pub async fn enable_edge_recording<D: Transport, R: Transport>(
+    device_service: &D,
+    recording_service: &R,
+    profile_token: &str,
+    retention: Duration,
+) -> Result<EdgeRecording, Error> {
+    let storage = local_storages(device_service)
+        .await?
+        .into_iter()
+        .next()
+        .ok_or(Error::NoLocalStorage)?;
+    let location = storage.local_path.as_ref().unwrap_or(&storage.token);
+
+    let created = recording::create_recording(
+        recording_service,
+        &recording::CreateRecording {
+            recording_configuration: tt::RecordingConfiguration {
+                source: tt::RecordingSourceInformation {
+                    source_id: profile_token.to_string(),
+                    name: tt::Name(profile_token.to_string()),
+                    location: tt::Description(location.clone()),
+                    description: tt::Description("Edge recording".to_string()),
+                    address: String::new(),
+                },
+                content: tt::Description(format!("Edge recording of {}", profile_token)),
+                maximum_retention_time: xs_duration(retention)?,
+            },
+        },
+    )
+    .await?;
+    let recording_token = (created.recording_token.0).0;
+
+    let job = match create_job(recording_service, &recording_token, profile_token).await {
+        Ok(job_token) => match activate(recording_service, &job_token).await {
+            Ok(state) => Ok((job_token, state)),
+            Err(e) => {
+                let request = recording::DeleteRecordingJob {
+                    job_token: job_reference(&job_token),
+                };
+                if let Err(e) = recording::delete_recording_job(recording_service, &request).await {
+                    tracing::debug!("failed to delete recording job {}: {}", job_token, e);
+                }
+                Err(e)
+            }
+        },
+        Err(e) => Err(e),
+    };
+    let (job_token, state) = match job {
+        Ok(job) => job,
+        Err(e) => {
+            let request = recording::DeleteRecording {
+                recording_token: recording_reference(&recording_token),
+            };
+            if let Err(e) = recording::delete_recording(recording_service, &request).await {
+                tracing::debug!("failed to delete recording {}: {}", recording_token, e);
+            }
+            return Err(e);
+        }
+    };
+
+    Ok(EdgeRecording {
+        storage,
+        recording_token,
+        job_token,
+        state,
+    })
+}
+
+/// Creates an idle job recording the media profile `profile_token` to `recording_token`.
+async fn create_job<T: Transport>(
+    recording_service: &T,
+    recording_token: &str,
+    profile_token: &str,
+) -> Result<String, Error> {
+    let request = recording::CreateRecordingJob {
+        job_configuration: tt::RecordingJobConfiguration {
+            recording_token: recording_reference(recording_token),
+            mode: tt::RecordingJobMode(JOB_MODE_IDLE.to_string()),
+            priority: 1,
+            source: vec![tt::RecordingJobSource {
+                source_token: Some(tt::SourceReference {
+                    token: tt::ReferenceToken(profile_token.to_string()),
+                    _type: Some(PROFILE_SOURCE.to_string()),
+                }),
+                auto_create_receiver: None,
+                tracks: vec![],
+                extension: None,
+            }],
+            extension: None,
+            schedule_token: None,
+        },
+    };
+    let job_token = recording::create_recording_job(recording_service, &request)
+        .await?
+        .job_token;
+    Ok((job_token.0).0)
+}
+
+async fn activate<T: Transport>(
+    recording_service: &T,
+    job_token: &str,
+) -> Result<StorageState, Error> {
+    let request = recording::SetRecordingJobMode {
+        job_token: job_reference(job_token),
+        mode: tt::RecordingJobMode(JOB_MODE_ACTIVE.to_string()),
+    };
+    recording::set_recording_job_mode(recording_service, &request).await?;
+    storage_state(recording_service, job_token).await
+}
+
+/// Requests the state of the recording job `job_token` with `GetRecordingJobState`.
+pub async fn storage_state<T: Transport>(
+    recording_service: &T,
+    job_token: &str,
+) -> Result<StorageState, Error> {
+    let request = recording::GetRecordingJobState {
+        job_token: job_reference(job_token),
+    };
+    let state = recording::get_recording_job_state(recording_service, &request)
+        .await?
+        .state;
+    Ok(StorageState::from(&state))
+}
+
+impl From<&RecordingJobStateInformation> for StorageState {
+    fn from(state: &RecordingJobStateInformation) -> Self {
+        let errors = || {
+            state
+                .sources
+                .iter()
+                .flat_map(|source| &source.tracks.track)
+                .filter_map(|track| track.error.as_deref())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        match state.state.0.as_str() {
+            "Active" => StorageState::Recording,
+            "PartiallyActive" => StorageState::PartiallyRecording(errors()),
+            "Idle" => StorageState::Idle,
+            _ => StorageState::Failed(errors()),
+        }
+    }
+}
+
+fn recording_reference(token: &str) -> tt::RecordingReference {
+    tt::RecordingReference(tt::ReferenceToken(token.to_string()))
+}
+
+fn job_reference(token: &str) -> tt::RecordingJobReference {
+    tt::RecordingJobReference(tt::ReferenceToken(token.to_string()))
+}
+
+fn xs_duration(duration: Duration) -> Result<xs::Duration, Error> {
+    format!("PT{}S", duration.as_secs())
+        .parse()
+        .map_err(|e| Error::Transport(TransportError::Serialization(format!("{:?}", e))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::fake_device::FakeDevice;
+
+    const TDS: &str = r#"xmlns:tds="http://www.onvif.org/ver10/device/wsdl""#;
+    const TRC: &str = r#"xmlns:trc="http://www.onvif.org/ver10/recording/wsdl""#;
+    const TT: &str = r#"xmlns:tt="http://www.onvif.org/ver10/schema""#;
+
+    /// A device with an NFS share and an SD card, which is missing if `card` is false, and whose
+    /// recording service fails to activate jobs unless `activates`.
+    fn fake_device(card: bool, activates: bool) -> FakeDevice {
+        let device = FakeDevice::new()
+            .respond(
+                "GetStorageConfigurations",
+                &format!(
+                    r#"<tds:GetStorageConfigurationsResponse {} {}>
+                        <tds:StorageConfigurations token="nas">
+                            <tds:Data type="NFS">
+                                <tds:StorageUri>nfs://192.168.0.10/recordings</tds:StorageUri>
+                            </tds:Data>
+                        </tds:StorageConfigurations>
+                        <tds:StorageConfigurations token="sd">
+                            <tds:Data type="LocalStorage">
+                                <tds:LocalPath>/mnt/sd</tds:LocalPath>
+                            </tds:Data>
+                        </tds:StorageConfigurations>
+                    </tds:GetStorageConfigurationsResponse>"#,
+                    TDS, TT
+                ),
+            )
+            .respond(
+                "CreateRecording",
+                &format!(
+                    "<trc:CreateRecordingResponse {}>\
+                        <trc:RecordingToken>rec</trc:RecordingToken>\
+                    </trc:CreateRecordingResponse>",
+                    TRC
+                ),
+            )
+            .respond(
+                "CreateRecordingJob",
+                &format!(
+                    "<trc:CreateRecordingJobResponse {} {}>\
+                        <trc:JobToken>job</trc:JobToken>\
+                        <trc:JobConfiguration>\
+                            <tt:RecordingToken>rec</tt:RecordingToken>\
+                            <tt:Mode>Idle</tt:Mode>\
+                            <tt:Priority>1</tt:Priority>\
+                        </trc:JobConfiguration>\
+                    </trc:CreateRecordingJobResponse>",
+                    TRC, TT
+                ),
+            );
+
+        let (state, error) = if card {
+            ("Active", "")
+        } else {
+            ("Error", "<tt:Error>No SD card</tt:Error>")
+        };
+        let device = device.respond(
+            "GetRecordingJobState",
+            &format!(
+                "<trc:GetRecordingJobStateResponse {} {}>\
+                    <trc:State>\
+                        <tt:RecordingToken>rec</tt:RecordingToken>\
+                        <tt:State>{state}</tt:State>\
+                        <tt:Sources>\
+                            <tt:SourceToken><tt:Token>Profile_1</tt:Token></tt:SourceToken>\
+                            <tt:State>{state}</tt:State>\
+                            <tt:Tracks>\
+                                <tt:Track>\
+                                    <tt:SourceTag>video</tt:SourceTag>\
+                                    <tt:Destination>track</tt:Destination>\
+                                    {error}\
+                                    <tt:State>{state}</tt:State>\
+                                </tt:Track>\
+                            </tt:Tracks>\
+                        </tt:Sources>\
+                    </trc:State>\
+                </trc:GetRecordingJobStateResponse>",
+                TRC,
+                TT,
+                state = state,
+                error = error
+            ),
+        );
+
+        let device = if activates {
+            device.respond(
+                "SetRecordingJobMode",
+                &format!("<trc:SetRecordingJobModeResponse {}/>", TRC),
+            )
+        } else {
+            device.fail("SetRecordingJobMode", || {
+                TransportError::Protocol("activation failed".to_string())
+            })
+        };
+        device
+            .respond(
+                "DeleteRecordingJob",
+                &format!("<trc:DeleteRecordingJobResponse {}/>", TRC),
+            )
+            .respond(
+                "DeleteRecording",
+                &format!("<trc:DeleteRecordingResponse {}/>", TRC),
+            )
+    }
+
+    const WEEK: Duration = Duration::from_secs(7 * 24 * 3600);
+
+    #[tokio::test]
+    async fn records_to_the_sd_card() {
+        let device = fake_device(true, true);
+        let recording = enable_edge_recording(&device, &device, "Profile_1", WEEK)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            recording,
+            EdgeRecording {
+                storage: LocalStorage {
+                    token: "sd".to_string(),
+                    storage_type: "LocalStorage".to_string(),
+                    local_path: Some("/mnt/sd".to_string()),
+                },
+                recording_token: "rec".to_string(),
+                job_token: "job".to_string(),
+                state: StorageState::Recording,
+            }
+        );
+        assert_eq!(
+            device.operations(),
+            vec![
+                "GetStorageConfigurations",
+                "CreateRecording",
+                "CreateRecordingJob",
+                "SetRecordingJobMode",
+                "GetRecordingJobState",
+            ]
+        );
+        let job = &device.requests("CreateRecordingJob")[0];
+        assert!(job.contains(">Idle<"), "{}", job);
+        assert!(job.contains(PROFILE_SOURCE), "{}", job);
+    }
+
+    #[tokio::test]
+    async fn reports_a_missing_card() {
+        let device = fake_device(false, true);
+        let recording = enable_edge_recording(&device, &device, "Profile_1", WEEK)
+            .await
+            .unwrap();
+        assert_eq!(
+            recording.state,
+            StorageState::Failed("No SD card".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn deletes_what_it_created_on_failure() {
+        let device = fake_device(true, false);
+        assert!(matches!(
+            enable_edge_recording(&device, &device, "Profile_1", WEEK).await,
+            Err(Error::Transport(TransportError::Protocol(_)))
+        ));
+        assert_eq!(
+            device.operations()[3..],
+            [
+                "SetRecordingJobMode",
+                "DeleteRecordingJob",
+                "DeleteRecording"
+            ]
+        );
+    }
+}
@@ -0,0 +1,223 @@
+//! Downloads of system logs, support information and backups from the URIs of `GetSystemUris`.
+//!
+//! `GetSystemLog` and `GetSystemSupportInformation` return their content in the SOAP response,
+//! either inline or as attachments. Newer devices rather offer it for download over plain HTTP,
+//! with the credentials of the device, which suits large support bundles better.
+//!
+//! ```no_run
+//! # async fn example(device_service: onvif::soap::client::Client) {
+//! use onvif::support::{self, Bundle};
+//!
+//! let downloads = support::download_all(
+//!     &device_service,
+//!     &[Bundle::SystemLog, Bundle::AccessLog, Bundle::SupportInfo],
+//! )
+//! .await
+//! .unwrap();
+//! for download in downloads {
+//!     match download.content {
+//!         Ok(content) => std::fs::write(download.bundle.name(), content).unwrap(),
+//!         Err(e) => eprintln!("failed to download {}: {}", download.uri, e),
+//!     }
+//! }
+//! # }
+//! ```
+
+use crate::soap::client::Client;
+use schema::{
+    devicemgmt, onvif as tt,
+    transport::{Error as TransportError, Transport},
+};
+use serde::Serialize;
+use std::future::Future;
+use thiserror::Error;
+use url::Url;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Transport error: {0}")]
+    Transport(#[from] TransportError),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Bundle {
+    SystemLog,
+    AccessLog,
+    SupportInfo,
+
+    /// Configuration of the device, which may include credentials and keys.
+    SystemBackup,
+}
+
+impl Bundle {
+    /// Name of the bundle, e.g. to save it as a file: `system_log`, `access_log`,
+    /// `support_info` or `system_backup`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Bundle::SystemLog => "system_log",
+            Bundle::AccessLog => "access_log",
+            Bundle::SupportInfo => "support_info",
+            Bundle::SystemBackup => "system_backup",
+        }
+    }
+}
+
+/// Download locations from `GetSystemUris`, `None` for bundles the device doesn't offer.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct SystemUris {
+    pub system_log: Option<Url>,
+    pub access_log: Option<Url>,
+    pub support_info: Option<Url>,
+    pub system_backup: Option<Url>,
+}
+
+impl SystemUris {
+    pub fn get(&self, bundle: Bundle) -> Option<&Url> {
+        match bundle {
+            Bundle::SystemLog => self.system_log.as_ref(),
+            Bundle::AccessLog => self.access_log.as_ref(),
+            Bundle::SupportInfo => self.support_info.as_ref(),
+            Bundle::SystemBackup => self.system_backup.as_ref(),
+        }
+    }
+}
+
+pub async fn system_uris<T: Transport>(device_service: &T) -> Result<SystemUris, Error> {
+    let response = devicemgmt::get_system_uris(device_service, &Default::default()).await?;
+
+    let mut uris = SystemUris {
+        support_info: parse(&response.support_info_uri),
+        system_backup: parse(&response.system_backup_uri),
+        ..Default::default()
+    };
+    for log in &response.system_log_uris.system_log {
+        match log._type {
+            tt::SystemLogType::System => uris.system_log = parse(&log.uri),
+            tt::SystemLogType::Access => uris.access_log = parse(&log.uri),
+            _ => tracing::debug!("ignoring log of unknown type at {}", log.uri),
+        }
+    }
+    Ok(uris)
+}
+
+fn parse(uri: &str) -> Option<Url> {
+    let uri = uri.trim();
+    if uri.is_empty() {
+        return None;
+    }
+    match Url::parse(uri) {
+        Ok(uri) => Some(uri),
+        Err(e) => {
+            tracing::debug!("invalid system URI {}: {}", uri, e);
+            None
+        }
+    }
+}
+
+/// A bundle fetched from the device.
+#[derive(Debug)]
+pub struct Download {
+    pub bundle: Bundle,
+    pub uri: Url,
+    pub content: Result<Vec<u8>, TransportError>,
+}
+
+/// Fetches `bundles` with `fetch`, one after the other, skipping those the device doesn't offer.
+/// A failed fetch doesn't stop the others, so that a support bundle timing out still leaves the
+/// logs.
+pub async fn download<T, F, Fut>(
+    device_service: &T,
+    bundles: &[Bundle],
+    mut fetch: F,
+) -> Result<Vec<Download>, Error>
+where
+    T: Transport,
+    F: FnMut(Url) -> Fut,
+    Fut: Future<Output = Result<Vec<u8>, TransportError>>,
+{
+    let uris = system_uris(device_service).await?;
+    let mut downloads = vec![];
+    for &bundle in bundles {
+        let uri = match uris.get(bundle) {
+            Some(uri) => uri.clone(),
+            None => continue,
+        };
+        let content = fetch(uri.clone()).await;
+        downloads.push(Download {
+            bundle,
+            uri,
+            content,
+        });
+    }
+    Ok(downloads)
+}
+
+/// [`download`] over HTTP with the client of the device service and its credentials.
+pub async fn download_all(
+    device_service: &Client,
+    bundles: &[Bundle],
+) -> Result<Vec<Download>, Error> {
+    download(device_service, bundles, |uri| async move {
+        device_service.get(&uri).await
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::fake_device::FakeDevice;
+
+    #[tokio::test]
+    async fn downloads_bundles() {
+        let device = FakeDevice::new().respond(
+            "GetSystemUris",
+            r#"<tds:GetSystemUrisResponse
+                xmlns:tds="http://www.onvif.org/ver10/device/wsdl"
+                xmlns:tt="http://www.onvif.org/ver10/schema">
+                <tds:SystemLogUris>
+                    <tt:SystemLog>
+                        <tt:Type>System</tt:Type>
+                        <tt:Uri>http://192.168.0.2/logs/system.log</tt:Uri>
+                    </tt:SystemLog>
+                    <tt:SystemLog>
+                        <tt:Type>Access</tt:Type>
+                        <tt:Uri>http://192.168.0.2/logs/access.log</tt:Uri>
+                    </tt:SystemLog>
+                </tds:SystemLogUris>
+                <tds:SupportInfoUri>http://192.168.0.2/support.tgz</tds:SupportInfoUri>
+                <tds:SystemBackupUri></tds:SystemBackupUri>
+            </tds:GetSystemUrisResponse>"#,
+        );
+
+        let uris = system_uris(&device).await.unwrap();
+        assert_eq!(
+            uris.access_log.unwrap().as_str(),
+            "http://192.168.0.2/logs/access.log"
+        );
+        assert_eq!(uris.system_backup, None);
+
+        let downloads = download(
+            &device,
+            &[Bundle::SystemLog, Bundle::SupportInfo, Bundle::SystemBackup],
+            |uri| async move {
+                match uri.path() {
+                    "/logs/system.log" => Ok(b"booted".to_vec()),
+                    _ => Err(TransportError::Timeout("timed out".to_string())),
+                }
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(downloads.len(), 2);
+        assert_eq!(downloads[0].bundle, Bundle::SystemLog);
+        assert_eq!(downloads[0].content.as_deref().unwrap(), b"booted");
+        assert_eq!(downloads[1].bundle, Bundle::SupportInfo);
+        assert!(matches!(
+            downloads[1].content,
+            Err(TransportError::Timeout(_))
+        ));
+    }
+}
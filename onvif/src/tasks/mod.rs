@@ -0,0 +1,137 @@
+//! Background tasks tied to the lifetime of their owner, e.g. event subscriptions or status
+//! pollers of a camera, so that none of them keeps running after the camera is gone.
+//!
+//! ```no_run
+//! # async fn example(event_service: onvif::soap::client::Client) {
+//! use futures_util::{pin_mut, StreamExt};
+//! use onvif::{event, soap::client::ClientBuilder, tasks::TaskGroup};
+//!
+//! let tasks = TaskGroup::new();
+//! tasks.spawn(async move {
+//!     let credentials = event_service.credentials();
+//!     let notifications = event::subscribe(
+//!         &event_service,
+//!         |address, timeout| {
+//!             ClientBuilder::new(address)
+//!                 .credentials(credentials.clone())
+//!                 .timeout(timeout)
+//!                 .build()
+//!         },
+//!         Default::default(),
+//!     );
+//!     pin_mut!(notifications);
+//!     while let Some(notification) = notifications.next().await {
+//!         println!("{:?}", notification);
+//!     }
+//! });
+//!
+//! // Stops the subscription, as would dropping `tasks`.
+//! tasks.shutdown().await;
+//! # }
+//! ```
+
+use futures_util::FutureExt;
+use std::{future::Future, sync::Mutex};
+use tokio::task::JoinHandle;
+
+/// Tasks spawned on the Tokio runtime that are aborted when the group is shut down or dropped.
+///
+/// Aborted tasks stop at their next `.await`. Cleanup on the device, such as deleting event
+/// subscriptions, doesn't happen and is left to expiry.
+#[derive(Debug, Default)]
+pub struct TaskGroup {
+    tasks: Mutex<Vec<JoinHandle<()>>>,
+}
+
+impl TaskGroup {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs `task` in the background until it completes or the group is shut down.
+    ///
+    /// Panics outside of a Tokio runtime.
+    pub fn spawn<F>(&self, task: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let mut tasks = self.tasks.lock().unwrap();
+        // Forget completed tasks so that long-lived groups don't grow without bound.
+        prune(&mut tasks);
+        tasks.push(tokio::spawn(task));
+    }
+
+    /// Number of tasks that haven't completed yet.
+    pub fn len(&self) -> usize {
+        let mut tasks = self.tasks.lock().unwrap();
+        prune(&mut tasks);
+        tasks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Aborts all tasks and waits until they stopped.
+    pub async fn shutdown(self) {
+        let tasks = std::mem::take(&mut *self.tasks.lock().unwrap());
+        for task in &tasks {
+            task.abort();
+        }
+        for task in tasks {
+            // Errors are cancellations, or panics which the runtime already reported.
+            let _ = task.await;
+        }
+    }
+}
+
+impl Drop for TaskGroup {
+    fn drop(&mut self) {
+        for task in self.tasks.get_mut().unwrap().iter() {
+            task.abort();
+        }
+    }
+}
+
+fn prune(tasks: &mut Vec<JoinHandle<()>>) {
+    *tasks = tasks
+        .drain(..)
+        .filter_map(|mut task| match (&mut task).now_or_never() {
+            Some(_) => None,
+            None => Some(task),
+        })
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tokio::sync::oneshot;
+
+    #[tokio::test]
+    async fn aborts_tasks() {
+        let tasks = TaskGroup::new();
+        let (sender, receiver) = oneshot::channel::<()>();
+        tasks.spawn(async move {
+            let _sender = sender;
+            tokio::time::sleep(Duration::from_secs(3600)).await;
+        });
+        tasks.spawn(async {});
+        tokio::task::yield_now().await;
+        assert_eq!(tasks.len(), 1);
+
+        tasks.shutdown().await;
+        // The sender was dropped with the aborted task.
+        assert!(receiver.await.is_err());
+
+        let tasks = TaskGroup::new();
+        let (sender, receiver) = oneshot::channel::<()>();
+        tasks.spawn(async move {
+            let _sender = sender;
+            tokio::time::sleep(Duration::from_secs(3600)).await;
+        });
+        drop(tasks);
+        assert!(receiver.await.is_err());
+    }
+}
@@ -0,0 +1,75 @@
+//! A device for tests which answers each operation with a canned response.
+
+use async_trait::async_trait;
+use schema::transport::{Error, Transport};
+use std::sync::Mutex;
+
+enum Answer {
+    Response(String),
+    Failure(fn() -> Error),
+}
+
+/// Answers requests by the name of their element, e.g. `GetScopes`, and keeps them. Operations
+/// without an answer fail with `ActionNotSupported`, as on devices which don't implement them.
+#[derive(Default)]
+pub struct FakeDevice {
+    answers: Vec<(&'static str, Answer)>,
+    requests: Mutex<Vec<(String, String)>>,
+}
+
+impl FakeDevice {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answers `operation` with `response`.
+    pub fn respond(mut self, operation: &'static str, response: &str) -> Self {
+        self.answers
+            .push((operation, Answer::Response(response.to_string())));
+        self
+    }
+
+    /// Fails `operation` with the error `error` returns.
+    pub fn fail(mut self, operation: &'static str, error: fn() -> Error) -> Self {
+        self.answers.push((operation, Answer::Failure(error)));
+        self
+    }
+
+    /// The requested operations in order.
+    pub fn operations(&self) -> Vec<String> {
+        let requests = self.requests.lock().unwrap();
+        requests
+            .iter()
+            .map(|(operation, _)| operation.clone())
+            .collect()
+    }
+
+    /// The requests of `operation` in order.
+    pub fn requests(&self, operation: &str) -> Vec<String> {
+        let requests = self.requests.lock().unwrap();
+        requests
+            .iter()
+            .filter(|(o, _)| o == operation)
+            .map(|(_, request)| request.clone())
+            .collect()
+    }
+}
+
+#[async_trait]
+impl Transport for FakeDevice {
+    async fn request(&self, message: &str) -> Result<String, Error> {
+        let operation = xmltree::Element::parse(message.as_bytes())
+            .map(|element| element.name)
+            .unwrap_or_default();
+        self.requests
+            .lock()
+            .unwrap()
+            .push((operation.clone(), message.to_string()));
+
+        match self.answers.iter().find(|(o, _)| *o == operation) {
+            Some((_, Answer::Response(response))) => Ok(response.clone()),
+            Some((_, Answer::Failure(error))) => Err(error()),
+            None => Err(Error::Protocol("ActionNotSupported".to_string())),
+        }
+    }
+}
@@ -1,2 +1,4 @@
 #[cfg(test)]
+pub mod fake_device;
+#[cfg(test)]
 pub mod xml_eq;
@@ -0,0 +1,411 @@
+//! Validation of the service addresses (XAddrs) a device advertises, e.g. in `GetServices`.
+//!
+//! Devices behind NAT or with a misconfigured network stack advertise addresses clients can't
+//! reach, such as `http://0.0.0.0/onvif/media` or their address on another network. An
+//! [`XAddrPolicy`] decides what happens to addresses which aren't within the base URI the device
+//! was reached at.
+//!
+//! Addresses are compared after [normalization](normalize), so that spelling differences such
+//! as `http://Camera.local.:80/onvif/` and `http://camera.local/onvif` don't count as being
+//! elsewhere. IPv6 hosts advertised without brackets or with a zone index, which URLs can't
+//! carry, are [parsed](parse) too.
+//!
+//! Services can also be given an address out of band, for devices which advertise wrong ones,
+//! which [`resolve_services`] uses instead of the advertised address.
+//!
+//! ```
+//! use onvif::xaddr::{self, XAddrPolicy};
+//! use url::Url;
+//!
+//! let base_uri = Url::parse("http://192.168.0.2:8080/").unwrap();
+//! let address = "http://0.0.0.0/onvif/media";
+//! assert!(xaddr::resolve(XAddrPolicy::Strict, &base_uri, address).is_err());
+//! assert_eq!(
+//!     xaddr::resolve(XAddrPolicy::WarnAndRewrite, &base_uri, address)
+//!         .unwrap()
+//!         .as_str(),
+//!     "http://192.168.0.2:8080/onvif/media"
+//! );
+//! ```
+
+use std::{fmt, net::Ipv6Addr, str::FromStr};
+use thiserror::Error;
+use url::Url;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Invalid service address {0}: {1}")]
+    InvalidAddress(String, url::ParseError),
+
+    #[error("Service address {x_addr} is not within base URI {base_uri}")]
+    OutsideBase { x_addr: String, base_uri: Url },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum XAddrPolicy {
+    /// Rejects addresses which aren't within the base URI.
+    Strict,
+
+    /// Logs a warning and reaches addresses which aren't within the base URI at the scheme, host
+    /// and port of the base URI instead, keeping their path.
+    WarnAndRewrite,
+
+    /// Uses addresses as advertised, e.g. for devices whose services are on other hosts.
+    Trust,
+}
+
+impl FromStr for XAddrPolicy {
+    type Err = String;
+
+    /// Parses `strict`, `rewrite` or `trust`.
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "strict" => Ok(XAddrPolicy::Strict),
+            "rewrite" => Ok(XAddrPolicy::WarnAndRewrite),
+            "trust" => Ok(XAddrPolicy::Trust),
+            _ => Err(format!("unknown address policy: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for XAddrPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            XAddrPolicy::Strict => "strict",
+            XAddrPolicy::WarnAndRewrite => "rewrite",
+            XAddrPolicy::Trust => "trust",
+        })
+    }
+}
+
+/// Services other than device management, which can be given an address out of band.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Service {
+    Event,
+    DeviceIo,
+    Media,
+    Media2,
+    Imaging,
+    Ptz,
+    Analytics,
+}
+
+impl Service {
+    pub const ALL: [Service; 7] = [
+        Service::Event,
+        Service::DeviceIo,
+        Service::Media,
+        Service::Media2,
+        Service::Imaging,
+        Service::Ptz,
+        Service::Analytics,
+    ];
+
+    /// The namespace of the service's WSDL, which identifies it in `GetServices`.
+    pub fn namespace(self) -> &'static str {
+        match self {
+            Service::Event => "http://www.onvif.org/ver10/events/wsdl",
+            Service::DeviceIo => "http://www.onvif.org/ver10/deviceIO/wsdl",
+            Service::Media => "http://www.onvif.org/ver10/media/wsdl",
+            Service::Media2 => "http://www.onvif.org/ver20/media/wsdl",
+            Service::Imaging => "http://www.onvif.org/ver20/imaging/wsdl",
+            Service::Ptz => "http://www.onvif.org/ver20/ptz/wsdl",
+            Service::Analytics => "http://www.onvif.org/ver20/analytics/wsdl",
+        }
+    }
+
+    pub fn from_namespace(namespace: &str) -> Option<Self> {
+        Service::ALL
+            .iter()
+            .copied()
+            .find(|service| service.namespace() == namespace)
+    }
+}
+
+impl FromStr for Service {
+    type Err = String;
+
+    /// Parses `event`, `deviceio`, `media`, `media2`, `imaging`, `ptz` or `analytics`.
+    fn from_str(s: &str) -> Result<Self, String> {
+        Ok(match s {
+            "event" => Service::Event,
+            "deviceio" => Service::DeviceIo,
+            "media" => Service::Media,
+            "media2" => Service::Media2,
+            "imaging" => Service::Imaging,
+            "ptz" => Service::Ptz,
+            "analytics" => Service::Analytics,
+            _ => return Err(format!("unknown service: {}", s)),
+        })
+    }
+}
+
+/// Parses an advertised address, also accepting IPv6 hosts without brackets, such as
+/// `http://fe80::1/onvif/device_service`, and dropping zone indexes, as in `[fe80::1%eth0]`.
+/// Such hosts can't have a port.
+pub fn parse(x_addr: &str) -> Result<Url, Error> {
+    let x_addr = x_addr.trim();
+    Url::parse(x_addr)
+        .or_else(|e| match bracket_ipv6(x_addr) {
+            Some(fixed) => Url::parse(&fixed).map_err(|_| e),
+            None => Err(e),
+        })
+        .map_err(|e| Error::InvalidAddress(x_addr.to_string(), e))
+}
+
+fn bracket_ipv6(x_addr: &str) -> Option<String> {
+    let (scheme, rest) = x_addr.split_once("://")?;
+    let end = rest.find(&['/', '?', '#'][..]).unwrap_or(rest.len());
+    let (authority, path) = rest.split_at(end);
+    let (userinfo, host) = authority.split_at(authority.rfind('@').map_or(0, |i| i + 1));
+    let (host, port) = match host.strip_prefix('[') {
+        Some(host) => host.split_once(']')?,
+        None if host.matches(':').count() >= 2 => (host, ""),
+        None => return None,
+    };
+    // Zone indexes are separated by `%`, percent-encoded as `%25` or not.
+    let host = host.split('%').next()?;
+    host.parse::<Ipv6Addr>().ok()?;
+    Some(format!(
+        "{}://{}[{}]{}{}",
+        scheme, userinfo, host, port, path
+    ))
+}
+
+/// `url` without a trailing dot in its host name or a trailing slash in its path, and with
+/// percent-encoded unreserved characters of its path decoded and other escapes in upper case.
+/// Parsing already lowercases host names, formats IPv6 hosts canonically and strips default
+/// ports.
+pub fn normalize(url: &Url) -> Url {
+    let mut url = url.clone();
+    if let Some(host) = url
+        .host_str()
+        .filter(|host| host.len() > 1 && host.ends_with('.'))
+    {
+        let host = host.trim_end_matches('.').to_string();
+        // Fails for hosts which only consist of dots, which are left alone.
+        let _ = url.set_host(Some(&host));
+    }
+    let mut path = normalize_escapes(url.path());
+    if path.len() > 1 && path.ends_with('/') {
+        path.pop();
+    }
+    url.set_path(&path);
+    url
+}
+
+fn normalize_escapes(path: &str) -> String {
+    let mut normalized = String::with_capacity(path.len());
+    let mut rest = path;
+    while let Some(i) = rest.find('%') {
+        normalized.push_str(&rest[..i]);
+        let escape = rest
+            .get(i + 1..i + 3)
+            .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()));
+        match escape {
+            Some(hex) => {
+                let byte = u8::from_str_radix(hex, 16).unwrap();
+                if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+                    normalized.push(byte as char);
+                } else {
+                    normalized.push('%');
+                    normalized.push_str(&hex.to_ascii_uppercase());
+                }
+                rest = &rest[i + 3..];
+            }
+            None => {
+                normalized.push('%');
+                rest = &rest[i + 1..];
+            }
+        }
+    }
+    normalized.push_str(rest);
+    normalized
+}
+
+/// Whether `a` and `b` are the same address once [normalized](normalize).
+pub fn equivalent(a: &Url, b: &Url) -> bool {
+    normalize(a) == normalize(b)
+}
+
+/// Whether `address` has the scheme, host and port of `base_uri`, and a path under its path,
+/// once both are [normalized](normalize).
+pub fn is_within(base_uri: &Url, address: &Url) -> bool {
+    let (base_uri, address) = (normalize(base_uri), normalize(address));
+    let segments = |url: &Url| {
+        url.path()
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    };
+    address.origin() == base_uri.origin() && segments(&address).starts_with(&segments(&base_uri))
+}
+
+/// The address to reach the service advertised at `x_addr` at, according to `policy`.
+pub fn resolve(policy: XAddrPolicy, base_uri: &Url, x_addr: &str) -> Result<Url, Error> {
+    let address = parse(x_addr)?;
+    if policy == XAddrPolicy::Trust || is_within(base_uri, &address) {
+        return Ok(address);
+    }
+    if policy == XAddrPolicy::Strict {
+        return Err(Error::OutsideBase {
+            x_addr: x_addr.to_string(),
+            base_uri: base_uri.clone(),
+        });
+    }
+
+    let mut rewritten = base_uri.clone();
+    rewritten.set_path(address.path());
+    rewritten.set_query(address.query());
+    tracing::warn!(
+        "service address {} is not within {}, using {}",
+        x_addr,
+        base_uri,
+        rewritten
+    );
+    Ok(rewritten)
+}
+
+/// The address of every service among the `(namespace, XAddr)` pairs of `advertised`, such as
+/// from `GetServices`, resolved according to `policy`. Services in `overrides` are at the given
+/// address instead, advertised or not, which isn't required to be within `base_uri`.
+pub fn resolve_services(
+    policy: XAddrPolicy,
+    base_uri: &Url,
+    advertised: &[(String, String)],
+    overrides: &[(Service, Url)],
+) -> Result<Vec<(String, Url)>, Error> {
+    let mut services = vec![];
+    for (namespace, x_addr) in advertised {
+        let overridden = Service::from_namespace(namespace)
+            .filter(|service| overrides.iter().any(|(s, _)| s == service));
+        if let Some(service) = overridden {
+            tracing::debug!("ignoring advertised {:?} service at {}", service, x_addr);
+            continue;
+        }
+        services.push((namespace.clone(), resolve(policy, base_uri, x_addr)?));
+    }
+    for (service, url) in overrides {
+        services.push((service.namespace().to_string(), url.clone()));
+    }
+    Ok(services)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_addresses() {
+        let base_uri = Url::parse("http://192.168.0.2/").unwrap();
+        let within = "http://192.168.0.2/onvif/media";
+        let outside = "https://10.0.0.2:8443/onvif/media?channel=1";
+
+        for policy in [
+            XAddrPolicy::Strict,
+            XAddrPolicy::WarnAndRewrite,
+            XAddrPolicy::Trust,
+        ] {
+            assert_eq!(resolve(policy, &base_uri, within).unwrap().as_str(), within);
+            assert!(matches!(
+                resolve(policy, &base_uri, "not a url"),
+                Err(Error::InvalidAddress(..))
+            ));
+        }
+
+        assert!(matches!(
+            resolve(XAddrPolicy::Strict, &base_uri, outside),
+            Err(Error::OutsideBase { .. })
+        ));
+        assert_eq!(
+            resolve(XAddrPolicy::WarnAndRewrite, &base_uri, outside)
+                .unwrap()
+                .as_str(),
+            "http://192.168.0.2/onvif/media?channel=1"
+        );
+        assert_eq!(
+            resolve(XAddrPolicy::Trust, &base_uri, outside)
+                .unwrap()
+                .as_str(),
+            outside
+        );
+    }
+
+    #[test]
+    fn normalizes_addresses() {
+        let base_uri = Url::parse("http://camera.local/onvif/").unwrap();
+        for x_addr in [
+            "http://Camera.local.:80/onvif",
+            "http://camera.local/onvif/media/",
+            "http://camera.local/%6Fnvif/media",
+        ] {
+            assert!(is_within(&base_uri, &parse(x_addr).unwrap()), "{}", x_addr);
+        }
+        let elsewhere = parse("http://camera.local/onvif2/media").unwrap();
+        assert!(!is_within(&base_uri, &elsewhere));
+        assert!(equivalent(
+            &parse("http://camera.local/a%2fb").unwrap(),
+            &parse("http://camera.local/a%2Fb/").unwrap()
+        ));
+
+        let base_uri = Url::parse("http://[fe80::1]/").unwrap();
+        for x_addr in [
+            "http://fe80::0:1/onvif/media",
+            "http://[fe80::1%25eth0]/onvif/media",
+            "http://[FE80::1]:80/onvif/media",
+        ] {
+            assert_eq!(
+                resolve(XAddrPolicy::Strict, &base_uri, x_addr)
+                    .unwrap()
+                    .host_str(),
+                Some("[fe80::1]"),
+                "{}",
+                x_addr
+            );
+        }
+    }
+
+    #[test]
+    fn overrides_services() {
+        let base_uri = Url::parse("http://192.168.0.2/").unwrap();
+        let advertised = [
+            (
+                "http://www.onvif.org/ver10/device/wsdl",
+                "http://192.168.0.2/onvif/device_service",
+            ),
+            (Service::Media.namespace(), "http://0.0.0.0/onvif/media"),
+            (Service::Ptz.namespace(), "http://192.168.0.2/onvif/ptz"),
+        ]
+        .iter()
+        .map(|(namespace, x_addr)| (namespace.to_string(), x_addr.to_string()))
+        .collect::<Vec<_>>();
+        let media = Url::parse("http://10.0.0.2:8080/onvif/media").unwrap();
+        let event = Url::parse("http://10.0.0.2:8080/onvif/events").unwrap();
+        let overrides = [
+            (Service::Media, media.clone()),
+            (Service::Event, event.clone()),
+        ];
+
+        assert!(resolve_services(XAddrPolicy::Strict, &base_uri, &advertised, &[]).is_err());
+        assert_eq!(
+            resolve_services(XAddrPolicy::Strict, &base_uri, &advertised, &overrides).unwrap(),
+            vec![
+                (
+                    "http://www.onvif.org/ver10/device/wsdl".to_string(),
+                    Url::parse("http://192.168.0.2/onvif/device_service").unwrap()
+                ),
+                (
+                    Service::Ptz.namespace().to_string(),
+                    Url::parse("http://192.168.0.2/onvif/ptz").unwrap()
+                ),
+                (Service::Media.namespace().to_string(), media),
+                (Service::Event.namespace().to_string(), event),
+            ]
+        );
+        assert_eq!(
+            "media2".parse::<Service>().unwrap().namespace(),
+            "http://www.onvif.org/ver20/media/wsdl"
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use schema::{devicemgmt, media, onvif as tt};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Keeps track of the peak of allocated memory, see [`peak_memory`].
+struct PeakAlloc;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for PeakAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let allocated = ALLOCATED.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+        PEAK.fetch_max(allocated, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        ALLOCATED.fetch_sub(layout.size(), Ordering::SeqCst);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: PeakAlloc = PeakAlloc;
+
+/// Bytes allocated by `f` at its peak, on top of what was allocated before.
+fn peak_memory<R>(f: impl FnOnce() -> R) -> usize {
+    let before = ALLOCATED.load(Ordering::SeqCst);
+    PEAK.store(before, Ordering::SeqCst);
+    drop(f());
+    PEAK.load(Ordering::SeqCst) - before
+}
+
+const PROFILE: &str = r#"
+    <trt:Profiles token="Profile_{n}" fixed="true">
+        <tt:Name>Profile_{n}</tt:Name>
+        <tt:VideoSourceConfiguration token="VideoSourceConfig_{n}">
+            <tt:Name>VideoSourceConfig_{n}</tt:Name>
+            <tt:UseCount>2</tt:UseCount>
+            <tt:SourceToken>VideoSource_1</tt:SourceToken>
+            <tt:Bounds x="0" y="0" width="1920" height="1080" />
+        </tt:VideoSourceConfiguration>
+        <tt:VideoEncoderConfiguration token="VideoEncoderConfig_{n}">
+            <tt:Name>VideoEncoderConfig_{n}</tt:Name>
+            <tt:UseCount>1</tt:UseCount>
+            <tt:Encoding>H264</tt:Encoding>
+            <tt:Resolution>
+                <tt:Width>1920</tt:Width>
+                <tt:Height>1080</tt:Height>
+            </tt:Resolution>
+            <tt:Quality>5</tt:Quality>
+            <tt:RateControl>
+                <tt:FrameRateLimit>25</tt:FrameRateLimit>
+                <tt:EncodingInterval>1</tt:EncodingInterval>
+                <tt:BitrateLimit>4096</tt:BitrateLimit>
+            </tt:RateControl>
+            <tt:H264>
+                <tt:GovLength>50</tt:GovLength>
+                <tt:H264Profile>Main</tt:H264Profile>
+            </tt:H264>
+            <tt:Multicast>
+                <tt:Address>
+                    <tt:Type>IPv4</tt:Type>
+                    <tt:IPv4Address>0.0.0.0</tt:IPv4Address>
+                </tt:Address>
+                <tt:Port>0</tt:Port>
+                <tt:TTL>5</tt:TTL>
+                <tt:AutoStart>false</tt:AutoStart>
+            </tt:Multicast>
+            <tt:SessionTimeout>PT60S</tt:SessionTimeout>
+        </tt:VideoEncoderConfiguration>
+        <tt:PTZConfiguration token="PTZConfig_1">
+            <tt:Name>PTZConfig_1</tt:Name>
+            <tt:UseCount>4</tt:UseCount>
+            <tt:NodeToken>PTZNode_1</tt:NodeToken>
+            <tt:DefaultContinuousPanTiltVelocitySpace>http://www.onvif.org/ver10/tptz/PanTiltSpaces/VelocityGenericSpace</tt:DefaultContinuousPanTiltVelocitySpace>
+            <tt:DefaultPTZTimeout>PT5S</tt:DefaultPTZTimeout>
+        </tt:PTZConfiguration>
+    </trt:Profiles>
+"#;
+
+const SERVICE: &str = r#"
+    <tds:Service>
+        <tds:Namespace>http://www.onvif.org/ver10/service_{n}/wsdl</tds:Namespace>
+        <tds:XAddr>http://192.168.0.2/onvif/service_{n}</tds:XAddr>
+        <tds:Version>
+            <tt:Major>2</tt:Major>
+            <tt:Minor>60</tt:Minor>
+        </tds:Version>
+    </tds:Service>
+"#;
+
+fn repeat(template: &str, count: usize) -> String {
+    (0..count)
+        .map(|n| template.replace("{n}", &n.to_string()))
+        .collect()
+}
+
+fn get_profiles_response(profiles: usize) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+        <trt:GetProfilesResponse
+                xmlns:trt="http://www.onvif.org/ver10/media/wsdl"
+                xmlns:tt="http://www.onvif.org/ver10/schema">
+            {}
+        </trt:GetProfilesResponse>"#,
+        repeat(PROFILE, profiles)
+    )
+}
+
+fn get_services_response(services: usize) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+        <tds:GetServicesResponse
+                xmlns:tds="http://www.onvif.org/ver10/device/wsdl"
+                xmlns:tt="http://www.onvif.org/ver10/schema">
+            {}
+        </tds:GetServicesResponse>"#,
+        repeat(SERVICE, services)
+    )
+}
+
+fn deserialization(c: &mut Criterion) {
+    for &count in &[1, 16] {
+        let xml = get_profiles_response(count);
+        c.bench_function(&format!("de GetProfilesResponse x{}", count), |b| {
+            b.iter(|| yaserde::de::from_str::<media::GetProfilesResponse>(black_box(&xml)).unwrap())
+        });
+    }
+
+    let xml = get_services_response(16);
+    c.bench_function("de GetServicesResponse x16", |b| {
+        b.iter(|| {
+            yaserde::de::from_str::<devicemgmt::GetServicesResponse>(black_box(&xml)).unwrap()
+        })
+    });
+}
+
+fn envelope(response: &str) -> String {
+    let body = response.splitn(2, "?>").nth(1).unwrap();
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+        <s:Envelope xmlns:s="http://www.w3.org/2003/05/soap-envelope">
+            <s:Body>{}</s:Body>
+        </s:Envelope>"#,
+        body
+    )
+}
+
+/// Unwrapping envelopes with a tree of the whole envelope compared to copying the body out, each
+/// followed by deserialization like in the client. Criterion only measures time, so the peak
+/// memory is printed.
+fn unwrapping(c: &mut Criterion) {
+    let xml = envelope(&get_profiles_response(64));
+    let unwrap_with: [(&str, fn(&str) -> Result<String, onvif_soap::Error>); 2] = [
+        ("unsoap", onvif_soap::unsoap),
+        ("extract_body", onvif_soap::extract_body),
+    ];
+
+    for (name, unwrap) in unwrap_with.iter() {
+        let parse = || {
+            let body = unwrap(black_box(&xml)).unwrap();
+            yaserde::de::from_str::<media::GetProfilesResponse>(&body).unwrap()
+        };
+        println!(
+            "{} GetProfilesResponse x64 ({} KiB): peak memory {} KiB",
+            name,
+            xml.len() / 1024,
+            peak_memory(parse) / 1024
+        );
+        c.bench_function(&format!("{} GetProfilesResponse x64", name), |b| {
+            b.iter(parse)
+        });
+    }
+}
+
+fn serialization(c: &mut Criterion) {
+    let profiles: media::GetProfilesResponse =
+        yaserde::de::from_str(&get_profiles_response(16)).unwrap();
+    c.bench_function("ser GetProfilesResponse x16", |b| {
+        b.iter(|| yaserde::ser::to_string(black_box(&profiles)).unwrap())
+    });
+
+    let request = media::GetStreamUri {
+        profile_token: tt::ReferenceToken("Profile_0".to_string()),
+        stream_setup: tt::StreamSetup {
+            stream: tt::StreamType::RtpUnicast,
+            transport: tt::Transport {
+                protocol: tt::TransportProtocol::Rtsp,
+                tunnel: vec![],
+            },
+        },
+    };
+    c.bench_function("ser GetStreamUri", |b| {
+        b.iter(|| yaserde::ser::to_string(black_box(&request)).unwrap())
+    });
+}
+
+criterion_group!(benches, deserialization, unwrapping, serialization);
+criterion_main!(benches);
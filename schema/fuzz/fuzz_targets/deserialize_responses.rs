@@ -0,0 +1,27 @@
+//! Feeds arbitrary documents to the deserializers of the most commonly used responses.
+//! Deserialization must return `Err` on malformed input, never panic.
+
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use schema::{devicemgmt, event, media, ptz, ws_discovery};
+
+fn deserialize<T: yaserde::YaDeserialize>(xml: &str) {
+    let _ = yaserde::de::from_str::<T>(xml);
+}
+
+fuzz_target!(|data: &[u8]| {
+    let xml = match std::str::from_utf8(data) {
+        Ok(xml) => xml,
+        Err(_) => return,
+    };
+
+    deserialize::<devicemgmt::GetCapabilitiesResponse>(xml);
+    deserialize::<devicemgmt::GetDeviceInformationResponse>(xml);
+    deserialize::<devicemgmt::GetServicesResponse>(xml);
+    deserialize::<devicemgmt::GetSystemDateAndTimeResponse>(xml);
+    deserialize::<event::PullMessagesResponse>(xml);
+    deserialize::<media::GetProfilesResponse>(xml);
+    deserialize::<media::GetStreamUriResponse>(xml);
+    deserialize::<ptz::GetStatusResponse>(xml);
+    deserialize::<ws_discovery::probe_matches::Envelope>(xml);
+});
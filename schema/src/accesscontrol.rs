@@ -2,35 +2,35 @@ use crate::transport;
 use crate::types as pt;
 use crate::validate::Validate;
 
-// The service capabilities reflect optional functionality of a service.
-// The information is static and does not change during device operation.
-// The following capabilities are available:
+/// The service capabilities reflect optional functionality of a service.
+/// The information is static and does not change during device operation.
+/// The following capabilities are available:
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tac",
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct ServiceCapabilities {
-    // The maximum number of entries returned by a single Get<Entity>List or
-    // Get<Entity> request.
-    // The device shall never return more than this number of entities in a
-    // single response.
+    /// The maximum number of entries returned by a single Get<Entity>List or
+    /// Get<Entity> request.
+    /// The device shall never return more than this number of entities in a
+    /// single response.
     #[yaserde(attribute, rename = "MaxLimit")]
     pub max_limit: u32,
 
-    // Indicates the maximum number of access points supported by the device.
+    /// Indicates the maximum number of access points supported by the device.
     #[yaserde(attribute, rename = "MaxAccessPoints")]
     pub max_access_points: Option<u32>,
 
-    // Indicates the maximum number of areas supported by the device.
+    /// Indicates the maximum number of areas supported by the device.
     #[yaserde(attribute, rename = "MaxAreas")]
     pub max_areas: Option<u32>,
 
-    // Indicates that the client is allowed to supply the token when creating
-    // access
-    // points and areas.
-    // To enable the use of the commands SetAccessPoint and SetArea, the value
-    // must be set to true.
+    /// Indicates that the client is allowed to supply the token when creating
+    /// access
+    /// points and areas.
+    /// To enable the use of the commands SetAccessPoint and SetArea, the value
+    /// must be set to true.
     #[yaserde(attribute, rename = "ClientSuppliedTokenSupported")]
     pub client_supplied_token_supported: Option<bool>,
 }
@@ -44,40 +44,40 @@ impl Validate for ServiceCapabilities {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct AccessPointInfoBase {
-    // A user readable name. It shall be up to 64 characters.
+    /// A user readable name. It shall be up to 64 characters.
     #[yaserde(prefix = "tac", rename = "Name")]
     pub name: pt::Name,
 
-    // Optional user readable description for the AccessPoint. It shall
-    // be up to 1024 characters.
+    /// Optional user readable description for the AccessPoint. It shall
+    /// be up to 1024 characters.
     #[yaserde(prefix = "tac", rename = "Description")]
     pub description: Option<pt::Description>,
 
-    // Optional reference to the Area from which access is requested.
+    /// Optional reference to the Area from which access is requested.
     #[yaserde(prefix = "tac", rename = "AreaFrom")]
     pub area_from: Option<pt::ReferenceToken>,
 
-    // Optional reference to the Area to which access is requested.
+    /// Optional reference to the Area to which access is requested.
     #[yaserde(prefix = "tac", rename = "AreaTo")]
     pub area_to: Option<pt::ReferenceToken>,
 
-    // Optional entity type; if missing, a Door type as defined by [ONVIF Door
-    // Control
-    // Service Specification] should be assumed. This can also be represented by
-    // the
-    // QName value "tdc:Door" – where tdc is the namespace of the door control
-    // service:
-    // "http://www.onvif.org/ver10/doorcontrol/wsdl". This field is provided for
-    // future
-    // extensions; it will allow an access point being extended to cover entity
-    // types
-    // other than doors as well.
+    /// Optional entity type; if missing, a Door type as defined by [ONVIF Door
+    /// Control
+    /// Service Specification] should be assumed. This can also be represented by
+    /// the
+    /// QName value "tdc:Door" – where tdc is the namespace of the door control
+    /// service:
+    /// "http://www.onvif.org/ver10/doorcontrol/wsdl". This field is provided for
+    /// future
+    /// extensions; it will allow an access point being extended to cover entity
+    /// types
+    /// other than doors as well.
     #[yaserde(prefix = "tac", rename = "EntityType")]
     pub entity_type: Option<String>,
 
-    // Reference to the entity used to control access; the entity type
-    // may be specified by the optional EntityType field explained below but is
-    // typically a Door.
+    /// Reference to the entity used to control access; the entity type
+    /// may be specified by the optional EntityType field explained below but is
+    /// typically a Door.
     #[yaserde(prefix = "tac", rename = "Entity")]
     pub entity: pt::ReferenceToken,
 
@@ -92,7 +92,7 @@ impl Validate for AccessPointInfoBase {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct AccessPointInfo {
-    // The capabilities for the AccessPoint.
+    /// The capabilities for the AccessPoint.
     #[yaserde(prefix = "tac", rename = "Capabilities")]
     pub capabilities: AccessPointCapabilities,
 
@@ -107,8 +107,8 @@ impl Validate for AccessPointInfo {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct AccessPoint {
-    // A reference to an authentication profile which defines the authentication
-    // behavior of the access point.
+    /// A reference to an authentication profile which defines the authentication
+    /// behavior of the access point.
     #[yaserde(prefix = "tac", rename = "AuthenticationProfileToken")]
     pub authentication_profile_token: Option<pt::ReferenceToken>,
 
@@ -129,57 +129,57 @@ pub struct AccessPointExtension {}
 
 impl Validate for AccessPointExtension {}
 
-// The AccessPoint capabilities reflect optional functionality of a particular
-// physical entity.
-// Different AccessPoint instances may have different set of capabilities. This
-// information may
-// change during device operation, e.g. if hardware settings are changed.
-// The following capabilities are available:
+/// The AccessPoint capabilities reflect optional functionality of a particular
+/// physical entity.
+/// Different AccessPoint instances may have different set of capabilities. This
+/// information may
+/// change during device operation, e.g. if hardware settings are changed.
+/// The following capabilities are available:
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tac",
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct AccessPointCapabilities {
-    // A list of security level tokens that this access point supports.
-    // See [Authentication Behavior Service Specification].
+    /// A list of security level tokens that this access point supports.
+    /// See [Authentication Behavior Service Specification].
     #[yaserde(prefix = "tac", rename = "SupportedSecurityLevels")]
     pub supported_security_levels: Vec<pt::ReferenceToken>,
 
     #[yaserde(prefix = "tac", rename = "Extension")]
     pub extension: Option<SupportedSecurityLevelsExtension>,
 
-    // Indicates whether or not this AccessPoint instance supports
-    // EnableAccessPoint
-    // and DisableAccessPoint commands.
+    /// Indicates whether or not this AccessPoint instance supports
+    /// EnableAccessPoint
+    /// and DisableAccessPoint commands.
     #[yaserde(attribute, rename = "DisableAccessPoint")]
     pub disable_access_point: bool,
 
-    // Indicates whether or not this AccessPoint instance supports generation of
-    // duress events.
+    /// Indicates whether or not this AccessPoint instance supports generation of
+    /// duress events.
     #[yaserde(attribute, rename = "Duress")]
     pub duress: Option<bool>,
 
-    // Indicates whether or not this AccessPoint has a REX switch or other input
-    // that
-    // allows anonymous access.
+    /// Indicates whether or not this AccessPoint has a REX switch or other input
+    /// that
+    /// allows anonymous access.
     #[yaserde(attribute, rename = "AnonymousAccess")]
     pub anonymous_access: Option<bool>,
 
-    // Indicates whether or not this AccessPoint instance supports generation of
-    // AccessTaken and AccessNotTaken events. If AnonymousAccess and AccessTaken
-    // are both true, it
-    // indicates that the Anonymous versions of AccessTaken and AccessNotTaken
-    // are supported.
+    /// Indicates whether or not this AccessPoint instance supports generation of
+    /// AccessTaken and AccessNotTaken events. If AnonymousAccess and AccessTaken
+    /// are both true, it
+    /// indicates that the Anonymous versions of AccessTaken and AccessNotTaken
+    /// are supported.
     #[yaserde(attribute, rename = "AccessTaken")]
     pub access_taken: Option<bool>,
 
-    // Indicates whether or not this AccessPoint instance supports the
-    // ExternalAuthorization operation and the generation of Request events. If
-    // AnonymousAccess and
-    // ExternalAuthorization are both true, it indicates that the Anonymous
-    // version is supported as
-    // well.
+    /// Indicates whether or not this AccessPoint instance supports the
+    /// ExternalAuthorization operation and the generation of Request events. If
+    /// AnonymousAccess and
+    /// ExternalAuthorization are both true, it indicates that the Anonymous
+    /// version is supported as
+    /// well.
     #[yaserde(attribute, rename = "ExternalAuthorization")]
     pub external_authorization: Option<bool>,
 }
@@ -201,12 +201,12 @@ impl Validate for SupportedSecurityLevelsExtension {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct AreaInfoBase {
-    // User readable name. It shall be up to 64 characters.
+    /// User readable name. It shall be up to 64 characters.
     #[yaserde(prefix = "tac", rename = "Name")]
     pub name: pt::Name,
 
-    // User readable description for the Area. It shall be up to 1024
-    // characters.
+    /// User readable description for the Area. It shall be up to 1024
+    /// characters.
     #[yaserde(prefix = "tac", rename = "Description")]
     pub description: Option<pt::Description>,
 
@@ -249,30 +249,31 @@ pub struct AreaExtension {}
 
 impl Validate for AreaExtension {}
 
-// The AccessPointState contains state information for an AccessPoint.
-// An ONVIF compliant device shall provide the following fields for each
-// AccessPoint instance:
+/// The AccessPointState contains state information for an AccessPoint.
+/// An ONVIF compliant device shall provide the following fields for each
+/// AccessPoint instance:
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tac",
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct AccessPointState {
-    // Indicates that the AccessPoint is enabled. By default this field value
-    // shall be True, if the DisableAccessPoint capabilities is not supported.
+    /// Indicates that the AccessPoint is enabled. By default this field value
+    /// shall be True, if the DisableAccessPoint capabilities is not supported.
     #[yaserde(prefix = "tac", rename = "Enabled")]
     pub enabled: bool,
 }
 
 impl Validate for AccessPointState {}
 
-// The Decision enumeration represents a choice of two available options for an
-// access request:
+/// The Decision enumeration represents a choice of two available options for an
+/// access request:
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[non_exhaustive]
 pub enum Decision {
-    // The decision is to grant access.
+    /// The decision is to grant access.
     Granted,
-    // The decision is to deny access.
+    /// The decision is to deny access.
     Denied,
     __Unknown__(String),
 }
@@ -285,38 +286,39 @@ impl Default for Decision {
 
 impl Validate for Decision {}
 
-// Non-normative enum that describes the various reasons for denying access.
-// The following strings shall be used for the reason field:
+/// Non-normative enum that describes the various reasons for denying access.
+/// The following strings shall be used for the reason field:
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[non_exhaustive]
 pub enum DenyReason {
-    // The device shall provide the following event, whenever a valid credential
-    // is not enabled or has been disabled (e.g., due to credential being lost
-    // etc.) to prevent
-    // unauthorized entry.
+    /// The device shall provide the following event, whenever a valid credential
+    /// is not enabled or has been disabled (e.g., due to credential being lost
+    /// etc.) to prevent
+    /// unauthorized entry.
     CredentialNotEnabled,
-    // The device shall provide the following event, whenever a valid credential
-    // is presented though it is not active yet;: e.g, the credential was
-    // presented before the
-    // start date.
+    /// The device shall provide the following event, whenever a valid credential
+    /// is presented though it is not active yet;: e.g, the credential was
+    /// presented before the
+    /// start date.
     CredentialNotActive,
-    // The device shall provide the following event, whenever a valid credential
-    // was presented after its expiry date.
+    /// The device shall provide the following event, whenever a valid credential
+    /// was presented after its expiry date.
     CredentialExpired,
-    // The device shall provide the following event, whenever an entered PIN
-    // code
-    // does not match the credential.
+    /// The device shall provide the following event, whenever an entered PIN
+    /// code
+    /// does not match the credential.
     InvalidPIN,
-    // The device shall provide the following event, whenever a valid credential
-    // is denied access to the requested AccessPoint because the credential is
-    // not permitted at
-    // the moment.
+    /// The device shall provide the following event, whenever a valid credential
+    /// is denied access to the requested AccessPoint because the credential is
+    /// not permitted at
+    /// the moment.
     NotPermittedAtThisTime,
-    // The device shall provide the following event, whenever the presented
-    // credential is not authorized.
+    /// The device shall provide the following event, whenever the presented
+    /// credential is not authorized.
     Unauthorized,
-    // The device shall provide the following event, whenever the request is
-    // denied and no other specific event matches it or is supported by the
-    // service.
+    /// The device shall provide the following event, whenever the request is
+    /// denied and no other specific event matches it or is supported by the
+    /// service.
     Other,
     __Unknown__(String),
 }
@@ -344,8 +346,8 @@ impl Validate for GetServiceCapabilities {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct GetServiceCapabilitiesResponse {
-    // The capability response message contains the requested Access Control
-    // service capabilities using a hierarchical XML capability structure.
+    /// The capability response message contains the requested Access Control
+    /// service capabilities using a hierarchical XML capability structure.
     #[yaserde(prefix = "tac", rename = "Capabilities")]
     pub capabilities: ServiceCapabilities,
 }
@@ -358,15 +360,15 @@ impl Validate for GetServiceCapabilitiesResponse {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct GetAccessPointInfoList {
-    // Maximum number of entries to return. If not specified, less than one
-    // or higher than what the device supports, the number of items is
-    // determined by the
-    // device.
+    /// Maximum number of entries to return. If not specified, less than one
+    /// or higher than what the device supports, the number of items is
+    /// determined by the
+    /// device.
     #[yaserde(prefix = "tac", rename = "Limit")]
     pub limit: Option<i32>,
 
-    // Start returning entries from this start reference. If not specified,
-    // entries shall start from the beginning of the dataset.
+    /// Start returning entries from this start reference. If not specified,
+    /// entries shall start from the beginning of the dataset.
     #[yaserde(prefix = "tac", rename = "StartReference")]
     pub start_reference: Option<String>,
 }
@@ -379,12 +381,12 @@ impl Validate for GetAccessPointInfoList {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct GetAccessPointInfoListResponse {
-    // StartReference to use in next call to get the following items. If
-    // absent, no more items to get.
+    /// StartReference to use in next call to get the following items. If
+    /// absent, no more items to get.
     #[yaserde(prefix = "tac", rename = "NextStartReference")]
     pub next_start_reference: Option<String>,
 
-    // List of AccessPointInfo items.
+    /// List of AccessPointInfo items.
     #[yaserde(prefix = "tac", rename = "AccessPointInfo")]
     pub access_point_info: Vec<AccessPointInfo>,
 }
@@ -397,7 +399,7 @@ impl Validate for GetAccessPointInfoListResponse {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct GetAccessPointInfo {
-    // Tokens of AccessPointInfo items to get.
+    /// Tokens of AccessPointInfo items to get.
     #[yaserde(prefix = "tac", rename = "Token")]
     pub token: Vec<pt::ReferenceToken>,
 }
@@ -410,7 +412,7 @@ impl Validate for GetAccessPointInfo {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct GetAccessPointInfoResponse {
-    // List of AccessPointInfo items.
+    /// List of AccessPointInfo items.
     #[yaserde(prefix = "tac", rename = "AccessPointInfo")]
     pub access_point_info: Vec<AccessPointInfo>,
 }
@@ -423,15 +425,15 @@ impl Validate for GetAccessPointInfoResponse {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct GetAccessPointList {
-    // Maximum number of entries to return. If not specified, less than one
-    // or higher than what the device supports, the number of items is
-    // determined by the
-    // device.
+    /// Maximum number of entries to return. If not specified, less than one
+    /// or higher than what the device supports, the number of items is
+    /// determined by the
+    /// device.
     #[yaserde(prefix = "tac", rename = "Limit")]
     pub limit: Option<i32>,
 
-    // Start returning entries from this start reference. If not specified,
-    // entries shall start from the beginning of the dataset.
+    /// Start returning entries from this start reference. If not specified,
+    /// entries shall start from the beginning of the dataset.
     #[yaserde(prefix = "tac", rename = "StartReference")]
     pub start_reference: Option<String>,
 }
@@ -444,12 +446,12 @@ impl Validate for GetAccessPointList {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct GetAccessPointListResponse {
-    // StartReference to use in next call to get the following items. If
-    // absent, no more items to get.
+    /// StartReference to use in next call to get the following items. If
+    /// absent, no more items to get.
     #[yaserde(prefix = "tac", rename = "NextStartReference")]
     pub next_start_reference: Option<String>,
 
-    // List of AccessPoint items.
+    /// List of AccessPoint items.
     #[yaserde(prefix = "tac", rename = "AccessPoint")]
     pub access_point: Vec<AccessPoint>,
 }
@@ -462,7 +464,7 @@ impl Validate for GetAccessPointListResponse {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct GetAccessPoints {
-    // Tokens of AccessPoint items to get.
+    /// Tokens of AccessPoint items to get.
     #[yaserde(prefix = "tac", rename = "Token")]
     pub token: Vec<pt::ReferenceToken>,
 }
@@ -475,7 +477,7 @@ impl Validate for GetAccessPoints {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct GetAccessPointsResponse {
-    // List of AccessPoint items.
+    /// List of AccessPoint items.
     #[yaserde(prefix = "tac", rename = "AccessPoint")]
     pub access_point: Vec<AccessPoint>,
 }
@@ -488,7 +490,7 @@ impl Validate for GetAccessPointsResponse {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct CreateAccessPoint {
-    // AccessPoint item to create
+    /// AccessPoint item to create
     #[yaserde(prefix = "tac", rename = "AccessPoint")]
     pub access_point: AccessPoint,
 }
@@ -501,7 +503,7 @@ impl Validate for CreateAccessPoint {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct CreateAccessPointResponse {
-    // Token of created AccessPoint item
+    /// Token of created AccessPoint item
     #[yaserde(prefix = "tac", rename = "Token")]
     pub token: pt::ReferenceToken,
 }
@@ -514,7 +516,7 @@ impl Validate for CreateAccessPointResponse {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct SetAccessPoint {
-    // AccessPoint item to create or modify
+    /// AccessPoint item to create or modify
     #[yaserde(prefix = "tac", rename = "AccessPoint")]
     pub access_point: AccessPoint,
 }
@@ -536,7 +538,7 @@ impl Validate for SetAccessPointResponse {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct ModifyAccessPoint {
-    // AccessPoint item to modify
+    /// AccessPoint item to modify
     #[yaserde(prefix = "tac", rename = "AccessPoint")]
     pub access_point: AccessPoint,
 }
@@ -558,7 +560,7 @@ impl Validate for ModifyAccessPointResponse {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct DeleteAccessPoint {
-    // Token of AccessPoint item to delete.
+    /// Token of AccessPoint item to delete.
     #[yaserde(prefix = "tac", rename = "Token")]
     pub token: pt::ReferenceToken,
 }
@@ -580,11 +582,11 @@ impl Validate for DeleteAccessPointResponse {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct SetAccessPointAuthenticationProfile {
-    // Token of the AccessPoint.
+    /// Token of the AccessPoint.
     #[yaserde(prefix = "tac", rename = "Token")]
     pub token: pt::ReferenceToken,
 
-    // Token of the AuthenticationProfile.
+    /// Token of the AuthenticationProfile.
     #[yaserde(prefix = "tac", rename = "AuthenticationProfileToken")]
     pub authentication_profile_token: pt::ReferenceToken,
 }
@@ -606,7 +608,7 @@ impl Validate for SetAccessPointAuthenticationProfileResponse {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct DeleteAccessPointAuthenticationProfile {
-    // Token of the AccessPoint.
+    /// Token of the AccessPoint.
     #[yaserde(prefix = "tac", rename = "Token")]
     pub token: pt::ReferenceToken,
 }
@@ -628,15 +630,15 @@ impl Validate for DeleteAccessPointAuthenticationProfileResponse {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct GetAreaInfoList {
-    // Maximum number of entries to return. If not specified, less than one
-    // or higher than what the device supports, the number of items is
-    // determined by the
-    // device.
+    /// Maximum number of entries to return. If not specified, less than one
+    /// or higher than what the device supports, the number of items is
+    /// determined by the
+    /// device.
     #[yaserde(prefix = "tac", rename = "Limit")]
     pub limit: Option<i32>,
 
-    // Start returning entries from this start reference. If not specified,
-    // entries shall start from the beginning of the dataset.
+    /// Start returning entries from this start reference. If not specified,
+    /// entries shall start from the beginning of the dataset.
     #[yaserde(prefix = "tac", rename = "StartReference")]
     pub start_reference: Option<String>,
 }
@@ -649,12 +651,12 @@ impl Validate for GetAreaInfoList {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct GetAreaInfoListResponse {
-    // StartReference to use in next call to get the following items. If
-    // absent, no more items to get.
+    /// StartReference to use in next call to get the following items. If
+    /// absent, no more items to get.
     #[yaserde(prefix = "tac", rename = "NextStartReference")]
     pub next_start_reference: Option<String>,
 
-    // List of AreaInfo items.
+    /// List of AreaInfo items.
     #[yaserde(prefix = "tac", rename = "AreaInfo")]
     pub area_info: Vec<AreaInfo>,
 }
@@ -667,7 +669,7 @@ impl Validate for GetAreaInfoListResponse {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct GetAreaInfo {
-    // Tokens of AreaInfo items to get.
+    /// Tokens of AreaInfo items to get.
     #[yaserde(prefix = "tac", rename = "Token")]
     pub token: Vec<pt::ReferenceToken>,
 }
@@ -680,7 +682,7 @@ impl Validate for GetAreaInfo {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct GetAreaInfoResponse {
-    // List of AreaInfo items.
+    /// List of AreaInfo items.
     #[yaserde(prefix = "tac", rename = "AreaInfo")]
     pub area_info: Vec<AreaInfo>,
 }
@@ -693,15 +695,15 @@ impl Validate for GetAreaInfoResponse {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct GetAreaList {
-    // Maximum number of entries to return. If not specified, less than one
-    // or higher than what the device supports, the number of items is
-    // determined by the
-    // device.
+    /// Maximum number of entries to return. If not specified, less than one
+    /// or higher than what the device supports, the number of items is
+    /// determined by the
+    /// device.
     #[yaserde(prefix = "tac", rename = "Limit")]
     pub limit: Option<i32>,
 
-    // Start returning entries from this start reference. If not specified,
-    // entries shall start from the beginning of the dataset.
+    /// Start returning entries from this start reference. If not specified,
+    /// entries shall start from the beginning of the dataset.
     #[yaserde(prefix = "tac", rename = "StartReference")]
     pub start_reference: Option<String>,
 }
@@ -714,12 +716,12 @@ impl Validate for GetAreaList {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct GetAreaListResponse {
-    // StartReference to use in next call to get the following items. If
-    // absent, no more items to get.
+    /// StartReference to use in next call to get the following items. If
+    /// absent, no more items to get.
     #[yaserde(prefix = "tac", rename = "NextStartReference")]
     pub next_start_reference: Option<String>,
 
-    // List of Area items.
+    /// List of Area items.
     #[yaserde(prefix = "tac", rename = "Area")]
     pub area: Vec<Area>,
 }
@@ -732,7 +734,7 @@ impl Validate for GetAreaListResponse {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct GetAreas {
-    // Tokens of Area items to get.
+    /// Tokens of Area items to get.
     #[yaserde(prefix = "tac", rename = "Token")]
     pub token: Vec<pt::ReferenceToken>,
 }
@@ -745,7 +747,7 @@ impl Validate for GetAreas {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct GetAreasResponse {
-    // List of Area items.
+    /// List of Area items.
     #[yaserde(prefix = "tac", rename = "Area")]
     pub area: Vec<Area>,
 }
@@ -758,7 +760,7 @@ impl Validate for GetAreasResponse {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct CreateArea {
-    // Area item to create
+    /// Area item to create
     #[yaserde(prefix = "tac", rename = "Area")]
     pub area: Area,
 }
@@ -771,7 +773,7 @@ impl Validate for CreateArea {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct CreateAreaResponse {
-    // Token of created Area item
+    /// Token of created Area item
     #[yaserde(prefix = "tac", rename = "Token")]
     pub token: pt::ReferenceToken,
 }
@@ -784,7 +786,7 @@ impl Validate for CreateAreaResponse {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct SetArea {
-    // Area item to create or modify
+    /// Area item to create or modify
     #[yaserde(prefix = "tac", rename = "Area")]
     pub area: Area,
 }
@@ -806,7 +808,7 @@ impl Validate for SetAreaResponse {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct ModifyArea {
-    // Area item to modify
+    /// Area item to modify
     #[yaserde(prefix = "tac", rename = "Area")]
     pub area: Area,
 }
@@ -828,7 +830,7 @@ impl Validate for ModifyAreaResponse {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct DeleteArea {
-    // Token of Area item to delete.
+    /// Token of Area item to delete.
     #[yaserde(prefix = "tac", rename = "Token")]
     pub token: pt::ReferenceToken,
 }
@@ -850,7 +852,7 @@ impl Validate for DeleteAreaResponse {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct GetAccessPointState {
-    // Token of AccessPoint instance to get AccessPointState for.
+    /// Token of AccessPoint instance to get AccessPointState for.
     #[yaserde(prefix = "tac", rename = "Token")]
     pub token: pt::ReferenceToken,
 }
@@ -863,7 +865,7 @@ impl Validate for GetAccessPointState {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct GetAccessPointStateResponse {
-    // AccessPointState item.
+    /// AccessPointState item.
     #[yaserde(prefix = "tac", rename = "AccessPointState")]
     pub access_point_state: AccessPointState,
 }
@@ -876,7 +878,7 @@ impl Validate for GetAccessPointStateResponse {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct EnableAccessPoint {
-    // Token of the AccessPoint instance to enable.
+    /// Token of the AccessPoint instance to enable.
     #[yaserde(prefix = "tac", rename = "Token")]
     pub token: pt::ReferenceToken,
 }
@@ -898,7 +900,7 @@ impl Validate for EnableAccessPointResponse {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct DisableAccessPoint {
-    // Token of the AccessPoint instance to disable.
+    /// Token of the AccessPoint instance to disable.
     #[yaserde(prefix = "tac", rename = "Token")]
     pub token: pt::ReferenceToken,
 }
@@ -920,19 +922,19 @@ impl Validate for DisableAccessPointResponse {}
     namespace = "tac: http://www.onvif.org/ver10/accesscontrol/wsdl"
 )]
 pub struct ExternalAuthorization {
-    // Token of the Access Point instance.
+    /// Token of the Access Point instance.
     #[yaserde(prefix = "tac", rename = "AccessPointToken")]
     pub access_point_token: pt::ReferenceToken,
 
-    // Optional token of the Credential involved.
+    /// Optional token of the Credential involved.
     #[yaserde(prefix = "tac", rename = "CredentialToken")]
     pub credential_token: Option<pt::ReferenceToken>,
 
-    // Optional reason for decision.
+    /// Optional reason for decision.
     #[yaserde(prefix = "tac", rename = "Reason")]
     pub reason: Option<String>,
 
-    // Decision - Granted or Denied.
+    /// Decision - Granted or Denied.
     #[yaserde(prefix = "tac", rename = "Decision")]
     pub decision: Decision,
 }
@@ -948,9 +950,9 @@ pub struct ExternalAuthorizationResponse {}
 
 impl Validate for ExternalAuthorizationResponse {}
 
-// This operation returns the capabilities of the access control service.
-// A device which provides the access control service shall implement this
-// method.
+/// This operation returns the capabilities of the access control service.
+/// A device which provides the access control service shall implement this
+/// method.
 pub async fn get_service_capabilities<T: transport::Transport>(
     transport: &T,
     request: &GetServiceCapabilities,
@@ -958,14 +960,14 @@ pub async fn get_service_capabilities<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation requests a list of AccessPointInfo items matching the given
-// tokens.
-// The device shall ignore tokens it cannot resolve and shall return an empty
-// list if
-// there are no items matching the specified tokens.
-// The device shall not return a fault in this case.
-// If the number of requested items is greater than MaxLimit, a TooManyItems
-// fault shall be returned.
+/// This operation requests a list of AccessPointInfo items matching the given
+/// tokens.
+/// The device shall ignore tokens it cannot resolve and shall return an empty
+/// list if
+/// there are no items matching the specified tokens.
+/// The device shall not return a fault in this case.
+/// If the number of requested items is greater than MaxLimit, a TooManyItems
+/// fault shall be returned.
 pub async fn get_access_point_info<T: transport::Transport>(
     transport: &T,
     request: &GetAccessPointInfo,
@@ -973,15 +975,15 @@ pub async fn get_access_point_info<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation requests a list of all AccessPointInfo items provided by the
-// device.
-// A call to this method shall return a StartReference when not all data is
-// returned and more
-// data is available. The reference shall be valid for retrieving the next set
-// of data.
-// Please refer to section 4.8.3 in [ONVIF PACS Architecture and Design
-// Considerations] for more details.
-// The number of items returned shall not be greater than the Limit parameter.
+/// This operation requests a list of all AccessPointInfo items provided by the
+/// device.
+/// A call to this method shall return a StartReference when not all data is
+/// returned and more
+/// data is available. The reference shall be valid for retrieving the next set
+/// of data.
+/// Please refer to section 4.8.3 in [ONVIF PACS Architecture and Design
+/// Considerations] for more details.
+/// The number of items returned shall not be greater than the Limit parameter.
 pub async fn get_access_point_info_list<T: transport::Transport>(
     transport: &T,
     request: &GetAccessPointInfoList,
@@ -989,14 +991,14 @@ pub async fn get_access_point_info_list<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation requests a list of AccessPoint items matching the given
-// tokens.
-// The device shall ignore tokens it cannot resolve and shall return an empty
-// list if there are
-// no items matching the specified tokens. The device shall not return a fault
-// in this case.
-// If the number of requested items is greater than MaxLimit, a TooManyItems
-// fault shall be returned.
+/// This operation requests a list of AccessPoint items matching the given
+/// tokens.
+/// The device shall ignore tokens it cannot resolve and shall return an empty
+/// list if there are
+/// no items matching the specified tokens. The device shall not return a fault
+/// in this case.
+/// If the number of requested items is greater than MaxLimit, a TooManyItems
+/// fault shall be returned.
 pub async fn get_access_points<T: transport::Transport>(
     transport: &T,
     request: &GetAccessPoints,
@@ -1004,12 +1006,12 @@ pub async fn get_access_points<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation requests a list of all AccessPoint items provided by the
-// device. A call to
-// this method shall return a StartReference when not all data is returned and
-// more data is available.
-// The reference shall be valid for retrieving the next set of data.
-// The number of items returned shall not be greater than the Limit parameter.
+/// This operation requests a list of all AccessPoint items provided by the
+/// device. A call to
+/// this method shall return a StartReference when not all data is returned and
+/// more data is available.
+/// The reference shall be valid for retrieving the next set of data.
+/// The number of items returned shall not be greater than the Limit parameter.
 pub async fn get_access_point_list<T: transport::Transport>(
     transport: &T,
     request: &GetAccessPointList,
@@ -1017,13 +1019,13 @@ pub async fn get_access_point_list<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation creates the specified access point in the device. The token
-// field of the
-// AccessPoint structure shall be empty and the device shall allocate a token
-// for the access point.
-// The allocated token shall be returned in the response. If the client sends
-// any value in the
-// token field, the device shall return InvalidArgVal as a generic fault code.
+/// This operation creates the specified access point in the device. The token
+/// field of the
+/// AccessPoint structure shall be empty and the device shall allocate a token
+/// for the access point.
+/// The allocated token shall be returned in the response. If the client sends
+/// any value in the
+/// token field, the device shall return InvalidArgVal as a generic fault code.
 pub async fn create_access_point<T: transport::Transport>(
     transport: &T,
     request: &CreateAccessPoint,
@@ -1031,18 +1033,18 @@ pub async fn create_access_point<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This method is used to synchronize an access point in a client with the
-// device. If an access
-// point with the specified token does not exist in the device, the access point
-// is created.
-// If an access point with the specified token exists, then the access point is
-// modified.
-// A call to this method takes an AccessPoint structure as input parameter.
-// The token field of the AccessPoint structure shall not be empty.
-// A device that signals support for the ClientSuppliedTokenSupported capability
-// shall implement this command.
-// If no token was specified in the request, the device shall return InvalidArgs
-// as a generic fault code.
+/// This method is used to synchronize an access point in a client with the
+/// device. If an access
+/// point with the specified token does not exist in the device, the access point
+/// is created.
+/// If an access point with the specified token exists, then the access point is
+/// modified.
+/// A call to this method takes an AccessPoint structure as input parameter.
+/// The token field of the AccessPoint structure shall not be empty.
+/// A device that signals support for the ClientSuppliedTokenSupported capability
+/// shall implement this command.
+/// If no token was specified in the request, the device shall return InvalidArgs
+/// as a generic fault code.
 pub async fn set_access_point<T: transport::Transport>(
     transport: &T,
     request: &SetAccessPoint,
@@ -1050,14 +1052,14 @@ pub async fn set_access_point<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation modifies the specified access point. The token of the access
-// point to modify
-// is specified in the token field of the AccessPoint structure and shall not be
-// empty.
-// All other fields in the structure shall overwrite the fields in the specified
-// access point.
-// If no token was specified in the request, the device shall return InvalidArgs
-// as a generic fault code.
+/// This operation modifies the specified access point. The token of the access
+/// point to modify
+/// is specified in the token field of the AccessPoint structure and shall not be
+/// empty.
+/// All other fields in the structure shall overwrite the fields in the specified
+/// access point.
+/// If no token was specified in the request, the device shall return InvalidArgs
+/// as a generic fault code.
 pub async fn modify_access_point<T: transport::Transport>(
     transport: &T,
     request: &ModifyAccessPoint,
@@ -1065,13 +1067,13 @@ pub async fn modify_access_point<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation deletes the specified access point. If it is associated with
-// one or more
-// entities some devices may not be able to delete the access point, and
-// consequently a
-// ReferenceInUse fault shall be generated.
-// If no token was specified in the request, the device shall return InvalidArgs
-// as a generic fault code.
+/// This operation deletes the specified access point. If it is associated with
+/// one or more
+/// entities some devices may not be able to delete the access point, and
+/// consequently a
+/// ReferenceInUse fault shall be generated.
+/// If no token was specified in the request, the device shall return InvalidArgs
+/// as a generic fault code.
 pub async fn delete_access_point<T: transport::Transport>(
     transport: &T,
     request: &DeleteAccessPoint,
@@ -1079,7 +1081,7 @@ pub async fn delete_access_point<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation defines the authentication behavior for an access point.
+/// This operation defines the authentication behavior for an access point.
 pub async fn set_access_point_authentication_profile<T: transport::Transport>(
     transport: &T,
     request: &SetAccessPointAuthenticationProfile,
@@ -1087,8 +1089,8 @@ pub async fn set_access_point_authentication_profile<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation reverts the authentication behavior for an access point to its
-// default behavior.
+/// This operation reverts the authentication behavior for an access point to its
+/// default behavior.
 pub async fn delete_access_point_authentication_profile<T: transport::Transport>(
     transport: &T,
     request: &DeleteAccessPointAuthenticationProfile,
@@ -1096,14 +1098,14 @@ pub async fn delete_access_point_authentication_profile<T: transport::Transport>
     transport::request(transport, request).await
 }
 
-// This operation requests a list of AreaInfo items matching the given tokens.
-// The device shall
-// ignore tokens it cannot resolve and shall return an empty list if there are
-// no items
-// matching the specified tokens. The device shall not return a fault in this
-// case.
-// If the number of requested items is greater than MaxLimit, a TooManyItems
-// fault shall be returned.
+/// This operation requests a list of AreaInfo items matching the given tokens.
+/// The device shall
+/// ignore tokens it cannot resolve and shall return an empty list if there are
+/// no items
+/// matching the specified tokens. The device shall not return a fault in this
+/// case.
+/// If the number of requested items is greater than MaxLimit, a TooManyItems
+/// fault shall be returned.
 pub async fn get_area_info<T: transport::Transport>(
     transport: &T,
     request: &GetAreaInfo,
@@ -1111,12 +1113,12 @@ pub async fn get_area_info<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation requests a list of all AreaInfo items provided by the device.
-// A call to this
-// method shall return a StartReference when not all data is returned and more
-// data is available.
-// The reference shall be valid for retrieving the next set of data.
-// The number of items returned shall not be greater than the Limit parameter.
+/// This operation requests a list of all AreaInfo items provided by the device.
+/// A call to this
+/// method shall return a StartReference when not all data is returned and more
+/// data is available.
+/// The reference shall be valid for retrieving the next set of data.
+/// The number of items returned shall not be greater than the Limit parameter.
 pub async fn get_area_info_list<T: transport::Transport>(
     transport: &T,
     request: &GetAreaInfoList,
@@ -1124,14 +1126,14 @@ pub async fn get_area_info_list<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation requests a list of Area items matching the given tokens. The
-// device shall
-// ignore tokens it cannot resolve and shall return an empty list if there are
-// no items
-// matching the specified tokens. The device shall not return a fault in this
-// case.
-// If the number of requested items is greater than MaxLimit, a TooManyItems
-// fault shall be returned.
+/// This operation requests a list of Area items matching the given tokens. The
+/// device shall
+/// ignore tokens it cannot resolve and shall return an empty list if there are
+/// no items
+/// matching the specified tokens. The device shall not return a fault in this
+/// case.
+/// If the number of requested items is greater than MaxLimit, a TooManyItems
+/// fault shall be returned.
 pub async fn get_areas<T: transport::Transport>(
     transport: &T,
     request: &GetAreas,
@@ -1139,12 +1141,12 @@ pub async fn get_areas<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation requests a list of all Area items provided by the device. A
-// call to this
-// method shall return a StartReference when not all data is returned and more
-// data is available.
-// The reference shall be valid for retrieving the next set of data.
-// The number of items returned shall not be greater than the Limit parameter.
+/// This operation requests a list of all Area items provided by the device. A
+/// call to this
+/// method shall return a StartReference when not all data is returned and more
+/// data is available.
+/// The reference shall be valid for retrieving the next set of data.
+/// The number of items returned shall not be greater than the Limit parameter.
 pub async fn get_area_list<T: transport::Transport>(
     transport: &T,
     request: &GetAreaList,
@@ -1152,12 +1154,12 @@ pub async fn get_area_list<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation creates the specified area in the device. The token field of
-// the Area
-// structure shall be empty and the device shall allocate a token for the area.
-// The allocated token shall be returned in the response.
-// If the client sends any value in the token field, the device shall return
-// InvalidArgVal as a generic fault code.
+/// This operation creates the specified area in the device. The token field of
+/// the Area
+/// structure shall be empty and the device shall allocate a token for the area.
+/// The allocated token shall be returned in the response.
+/// If the client sends any value in the token field, the device shall return
+/// InvalidArgVal as a generic fault code.
 pub async fn create_area<T: transport::Transport>(
     transport: &T,
     request: &CreateArea,
@@ -1165,19 +1167,19 @@ pub async fn create_area<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This method is used to synchronize an area in a client with the device. If an
-// area with the
-// specified token does not exist in the device, the area is created. If an area
-// with the
-// specified token exists, then the area is modified. A call to this method
-// takes an Area
-// structure as input parameter. The token field of the Area structure shall not
-// be empty.
-// A device that signals support for the ClientSuppliedTokenSupported capability
-// shall
-// implement this command.
-// If no token was specified in the request, the device shall return
-// InvalidArgs as a generic fault code.
+/// This method is used to synchronize an area in a client with the device. If an
+/// area with the
+/// specified token does not exist in the device, the area is created. If an area
+/// with the
+/// specified token exists, then the area is modified. A call to this method
+/// takes an Area
+/// structure as input parameter. The token field of the Area structure shall not
+/// be empty.
+/// A device that signals support for the ClientSuppliedTokenSupported capability
+/// shall
+/// implement this command.
+/// If no token was specified in the request, the device shall return
+/// InvalidArgs as a generic fault code.
 pub async fn set_area<T: transport::Transport>(
     transport: &T,
     request: &SetArea,
@@ -1185,13 +1187,13 @@ pub async fn set_area<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation modifies the specified area. The token of the area to modify
-// is specified in
-// the token field of the Area structure and shall not be empty. All other
-// fields in the
-// structure shall overwrite the fields in the specified area.
-// If no token was specified in the request, the device shall return InvalidArgs
-// as a generic fault code.
+/// This operation modifies the specified area. The token of the area to modify
+/// is specified in
+/// the token field of the Area structure and shall not be empty. All other
+/// fields in the
+/// structure shall overwrite the fields in the specified area.
+/// If no token was specified in the request, the device shall return InvalidArgs
+/// as a generic fault code.
 pub async fn modify_area<T: transport::Transport>(
     transport: &T,
     request: &ModifyArea,
@@ -1199,12 +1201,12 @@ pub async fn modify_area<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation deletes the specified area. If it is associated with one or
-// more entities
-// some devices may not be able to delete the area, and consequently a
-// ReferenceInUse fault shall be generated.
-// If no token was specified in the request, the device shall return InvalidArgs
-// as a generic fault code.
+/// This operation deletes the specified area. If it is associated with one or
+/// more entities
+/// some devices may not be able to delete the area, and consequently a
+/// ReferenceInUse fault shall be generated.
+/// If no token was specified in the request, the device shall return InvalidArgs
+/// as a generic fault code.
 pub async fn delete_area<T: transport::Transport>(
     transport: &T,
     request: &DeleteArea,
@@ -1212,8 +1214,8 @@ pub async fn delete_area<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation requests the AccessPointState for the access point instance
-// specified by the token.
+/// This operation requests the AccessPointState for the access point instance
+/// specified by the token.
 pub async fn get_access_point_state<T: transport::Transport>(
     transport: &T,
     request: &GetAccessPointState,
@@ -1221,10 +1223,10 @@ pub async fn get_access_point_state<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation allows enabling an access point. A device that signals support
-// for
-// DisableAccessPoint capability for a particular access point instance shall
-// implement this command.
+/// This operation allows enabling an access point. A device that signals support
+/// for
+/// DisableAccessPoint capability for a particular access point instance shall
+/// implement this command.
 pub async fn enable_access_point<T: transport::Transport>(
     transport: &T,
     request: &EnableAccessPoint,
@@ -1232,10 +1234,10 @@ pub async fn enable_access_point<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation allows disabling an access point. A device that signals
-// support for the
-// DisableAccessPoint capability for a particular access point instance shall
-// implement this command.
+/// This operation allows disabling an access point. A device that signals
+/// support for the
+/// DisableAccessPoint capability for a particular access point instance shall
+/// implement this command.
 pub async fn disable_access_point<T: transport::Transport>(
     transport: &T,
     request: &DisableAccessPoint,
@@ -1243,11 +1245,11 @@ pub async fn disable_access_point<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation allows to deny or grant decision at an access point instance.
-// A device that
-// signals support for ExternalAuthorization capability for a particular access
-// point instance
-// shall implement this method.
+/// This operation allows to deny or grant decision at an access point instance.
+/// A device that
+/// signals support for ExternalAuthorization capability for a particular access
+/// point instance
+/// shall implement this method.
 pub async fn external_authorization<T: transport::Transport>(
     transport: &T,
     request: &ExternalAuthorization,
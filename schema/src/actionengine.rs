@@ -18,7 +18,7 @@ impl Validate for GetSupportedActions {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct GetSupportedActionsResponse {
-    // Array of supported Action types
+    /// Array of supported Action types
     #[yaserde(prefix = "tae", rename = "SupportedActions")]
     pub supported_actions: SupportedActions,
 }
@@ -40,7 +40,7 @@ impl Validate for GetActions {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct GetActionsResponse {
-    // Array of current Action configurations
+    /// Array of current Action configurations
     #[yaserde(prefix = "tae", rename = "Action")]
     pub action: Vec<Action>,
 }
@@ -53,7 +53,7 @@ impl Validate for GetActionsResponse {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct CreateActions {
-    // Array of Actions to be configured on service provider
+    /// Array of Actions to be configured on service provider
     #[yaserde(prefix = "tae", rename = "Action")]
     pub action: Vec<ActionConfiguration>,
 }
@@ -66,8 +66,8 @@ impl Validate for CreateActions {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct CreateActionsResponse {
-    // Array of configured Actions with service provider assigned unique
-    // identifiers
+    /// Array of configured Actions with service provider assigned unique
+    /// identifiers
     #[yaserde(prefix = "tae", rename = "Action")]
     pub action: Vec<Action>,
 }
@@ -80,7 +80,7 @@ impl Validate for CreateActionsResponse {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct DeleteActions {
-    // Array of tokens referencing existing Action configurations to be removed
+    /// Array of tokens referencing existing Action configurations to be removed
     #[yaserde(prefix = "tae", rename = "Token")]
     pub token: Vec<tt::ReferenceToken>,
 }
@@ -102,8 +102,8 @@ impl Validate for DeleteActionsResponse {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct ModifyActions {
-    // Array of Action configurations to update the existing action
-    // configurations
+    /// Array of Action configurations to update the existing action
+    /// configurations
     #[yaserde(prefix = "tae", rename = "Action")]
     pub action: Vec<Action>,
 }
@@ -156,7 +156,7 @@ impl Validate for GetActionTriggers {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct GetActionTriggersResponse {
-    // Array of current Action Trigger configurations
+    /// Array of current Action Trigger configurations
     #[yaserde(prefix = "tae", rename = "ActionTrigger")]
     pub action_trigger: Vec<ActionTrigger>,
 }
@@ -169,7 +169,7 @@ impl Validate for GetActionTriggersResponse {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct CreateActionTriggers {
-    // Action Triggers to be configured
+    /// Action Triggers to be configured
     #[yaserde(prefix = "tae", rename = "ActionTrigger")]
     pub action_trigger: Vec<ActionTriggerConfiguration>,
 }
@@ -182,8 +182,8 @@ impl Validate for CreateActionTriggers {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct CreateActionTriggersResponse {
-    // Returns configured Action Triggers with service provider assigned unique
-    // identifers
+    /// Returns configured Action Triggers with service provider assigned unique
+    /// identifers
     #[yaserde(prefix = "tae", rename = "ActionTrigger")]
     pub action_trigger: Vec<ActionTrigger>,
 }
@@ -196,7 +196,7 @@ impl Validate for CreateActionTriggersResponse {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct ModifyActionTriggers {
-    // Array of Action Trigger configurations to be updated.
+    /// Array of Action Trigger configurations to be updated.
     #[yaserde(prefix = "tae", rename = "ActionTrigger")]
     pub action_trigger: Vec<ActionTrigger>,
 }
@@ -218,8 +218,8 @@ impl Validate for ModifyActionTriggersResponse {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct DeleteActionTriggers {
-    // Array of tokens referencing existing Action Trigger configurations to be
-    // removed
+    /// Array of tokens referencing existing Action Trigger configurations to be
+    /// removed
     #[yaserde(prefix = "tae", rename = "Token")]
     pub token: Vec<tt::ReferenceToken>,
 }
@@ -235,40 +235,40 @@ pub struct DeleteActionTriggersResponse {}
 
 impl Validate for DeleteActionTriggersResponse {}
 
-// Describes the configuration parameters of an action.
+/// Describes the configuration parameters of an action.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tae",
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct ActionConfigDescription {
-    // Action configuration parameter descriptions
+    /// Action configuration parameter descriptions
     #[yaserde(prefix = "tae", rename = "ParameterDescription")]
     pub parameter_description: tt::ItemListDescription,
 
-    // Action type name
+    /// Action type name
     #[yaserde(attribute, rename = "Name")]
     pub name: String,
 }
 
 impl Validate for ActionConfigDescription {}
 
-// SupportedActions data structure lists the available action types that service
-// provider supports. For each action type, data structure contains the action
-// configuration parameters.
+/// SupportedActions data structure lists the available action types that service
+/// provider supports. For each action type, data structure contains the action
+/// configuration parameters.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tae",
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct SupportedActions {
-    // Lists the location of all schemas that are referenced in the supported
-    // actions. If the action descriptions reference data types in the ONVIF
-    // schema file,the ONVIF schema file MUST be explicitly listed.
+    /// Lists the location of all schemas that are referenced in the supported
+    /// actions. If the action descriptions reference data types in the ONVIF
+    /// schema file,the ONVIF schema file MUST be explicitly listed.
     #[yaserde(prefix = "tae", rename = "ActionContentSchemaLocation")]
     pub action_content_schema_location: Vec<String>,
 
-    // List of actions supported by Action Engine Service provider.
+    /// List of actions supported by Action Engine Service provider.
     #[yaserde(prefix = "tae", rename = "ActionDescription")]
     pub action_description: Vec<ActionConfigDescription>,
 
@@ -287,29 +287,29 @@ pub struct SupportedActionsExtension {}
 
 impl Validate for SupportedActionsExtension {}
 
-// Action Engine Capabilities data structure contains the maximum number of
-// supported actions and number of actions in use for generic as well as
-// specific action types
+/// Action Engine Capabilities data structure contains the maximum number of
+/// supported actions and number of actions in use for generic as well as
+/// specific action types
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tae",
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct ActionEngineCapabilities {
-    // Limits for each action type
+    /// Limits for each action type
     #[yaserde(prefix = "tae", rename = "ActionCapabilities")]
     pub action_capabilities: Vec<ActionTypeLimits>,
 
     #[yaserde(prefix = "tae", rename = "Extension")]
     pub extension: Option<ActionEngineCapabilitiesExtension>,
 
-    // The maximum number of trigger configurations that the service provider
-    // can concurrently support
+    /// The maximum number of trigger configurations that the service provider
+    /// can concurrently support
     #[yaserde(attribute, rename = "MaximumTriggers")]
     pub maximum_triggers: Option<xs::Integer>,
 
-    // The maximum number of actions that the service provider can concurrently
-    // support
+    /// The maximum number of actions that the service provider can concurrently
+    /// support
     #[yaserde(attribute, rename = "MaximumActions")]
     pub maximum_actions: Option<xs::Integer>,
 }
@@ -325,98 +325,98 @@ pub struct ActionEngineCapabilitiesExtension {}
 
 impl Validate for ActionEngineCapabilitiesExtension {}
 
-// ActionTypeLimits data structure contains maximum and current usage
-// information for a specific action type in the service provider
+/// ActionTypeLimits data structure contains maximum and current usage
+/// information for a specific action type in the service provider
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tae",
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct ActionTypeLimits {
-    // Action Type
+    /// Action Type
     #[yaserde(attribute, rename = "Type")]
     pub _type: String,
 
-    // For the specific action type, the maximum number of actions that could be
-    // concurrently supported by the service provider
+    /// For the specific action type, the maximum number of actions that could be
+    /// concurrently supported by the service provider
     #[yaserde(attribute, rename = "Maximum")]
     pub maximum: xs::Integer,
 
-    // For the specific action type, the number of actions in use by the service
-    // provider
+    /// For the specific action type, the number of actions in use by the service
+    /// provider
     #[yaserde(attribute, rename = "InUse")]
     pub in_use: Option<xs::Integer>,
 }
 
 impl Validate for ActionTypeLimits {}
 
-// Action Configuration data type contains the configuration settings of action
-// configuration parameters, service requester given action Name, and service
-// provider supported action type value
+/// Action Configuration data type contains the configuration settings of action
+/// configuration parameters, service requester given action Name, and service
+/// provider supported action type value
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tae",
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct ActionConfiguration {
-    // Action configuration parameter settings.
+    /// Action configuration parameter settings.
     #[yaserde(prefix = "tae", rename = "Parameters")]
     pub parameters: tt::ItemList,
 
-    // User given name.
+    /// User given name.
     #[yaserde(attribute, rename = "Name")]
     pub name: String,
 
-    // Denotes the action type.
+    /// Denotes the action type.
     #[yaserde(attribute, rename = "Type")]
     pub _type: String,
 }
 
 impl Validate for ActionConfiguration {}
 
-// Action data type contains the configuration settings of one action instance
-// and service provider assigned unique identifier for this action
-// configuration.
+/// Action data type contains the configuration settings of one action instance
+/// and service provider assigned unique identifier for this action
+/// configuration.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tae",
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct Action {
-    // Action configuration contains action type, user given action name, and
-    // configuratin parameter settings.
+    /// Action configuration contains action type, user given action name, and
+    /// configuratin parameter settings.
     #[yaserde(prefix = "tae", rename = "Configuration")]
     pub configuration: ActionConfiguration,
 
-    // Unique Action identifier that service provider assigned to the action
-    // configuration.
+    /// Unique Action identifier that service provider assigned to the action
+    /// configuration.
     #[yaserde(attribute, rename = "Token")]
     pub token: tt::ReferenceToken,
 }
 
 impl Validate for Action {}
 
-// Action Trigger configuration data type contains mandatory Topic Expression
-// (Section Topic Filter in [Core Specification]), optional Message content
-// expression (Section Message Content Filter in [Core Specification]), and set
-// of actions to be triggered.
+/// Action Trigger configuration data type contains mandatory Topic Expression
+/// (Section Topic Filter in [Core Specification]), optional Message content
+/// expression (Section Message Content Filter in [Core Specification]), and set
+/// of actions to be triggered.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tae",
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct ActionTriggerConfiguration {
-    // Topic expression, for example, to trigger only for relays. Trigger based
-    // on event topic.
+    /// Topic expression, for example, to trigger only for relays. Trigger based
+    /// on event topic.
     #[yaserde(prefix = "tae", rename = "TopicExpression")]
     pub topic_expression: wsnt::TopicExpressionType,
 
-    // Content expression, for example, to trigger only when the relay value is
-    // on. Trigger based on content data in event.
+    /// Content expression, for example, to trigger only when the relay value is
+    /// on. Trigger based on content data in event.
     #[yaserde(prefix = "tae", rename = "ContentExpression")]
     pub content_expression: Option<wsnt::QueryExpressionType>,
 
-    // Reference to actions to be triggered when the conditions are satisfied.
+    /// Reference to actions to be triggered when the conditions are satisfied.
     #[yaserde(prefix = "tae", rename = "ActionToken")]
     pub action_token: Vec<tt::ReferenceToken>,
 
@@ -435,20 +435,20 @@ pub struct ActionTriggerConfigurationExtension {}
 
 impl Validate for ActionTriggerConfigurationExtension {}
 
-// Action Trigger data type contains the service provider assigned unique
-// identifier for the configuration and action trigger configuration data.
+/// Action Trigger data type contains the service provider assigned unique
+/// identifier for the configuration and action trigger configuration data.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tae",
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct ActionTrigger {
-    // Action Trigger Configuration
+    /// Action Trigger Configuration
     #[yaserde(prefix = "tae", rename = "Configuration")]
     pub configuration: ActionTriggerConfiguration,
 
-    // Unique Action Trigger identifier that service provider assigned to the
-    // action trigger configuration.
+    /// Unique Action Trigger identifier that service provider assigned to the
+    /// action trigger configuration.
     #[yaserde(attribute, rename = "Token")]
     pub token: tt::ReferenceToken,
 }
@@ -473,15 +473,15 @@ impl Validate for OnvifAction {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct EmailServerConfiguration {
-    // SMTP EMail Server configuration
+    /// SMTP EMail Server configuration
     #[yaserde(prefix = "tae", rename = "SMTPConfig")]
     pub smtp_config: Smtpconfig,
 
-    // POP EMail Server configuration
+    /// POP EMail Server configuration
     #[yaserde(prefix = "tae", rename = "POPConfig")]
     pub pop_config: Popconfig,
 
-    // Credentials configuration
+    /// Credentials configuration
     #[yaserde(prefix = "tae", rename = "AuthenticationConfig")]
     pub authentication_config: AuthenticationConfig,
 }
@@ -494,7 +494,7 @@ impl Validate for EmailServerConfiguration {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct Smtpconfig {
-    // Destination SMTP Address configuration
+    /// Destination SMTP Address configuration
     #[yaserde(prefix = "tae", rename = "HostAddress")]
     pub host_address: HostAddress,
 
@@ -510,7 +510,7 @@ impl Validate for Smtpconfig {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct Popconfig {
-    // Destination POP Server Address configuration
+    /// Destination POP Server Address configuration
     #[yaserde(prefix = "tae", rename = "HostAddress")]
     pub host_address: HostAddress,
 }
@@ -523,11 +523,11 @@ impl Validate for Popconfig {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct HostAddress {
-    // IP Address
+    /// IP Address
     #[yaserde(prefix = "tae", rename = "Value")]
     pub value: String,
 
-    // IP Address format type such as IPv4 or IPv6
+    /// IP Address format type such as IPv4 or IPv6
     #[yaserde(attribute, rename = "formatType")]
     pub format_type: AddressFormatType,
 }
@@ -535,6 +535,7 @@ pub struct HostAddress {
 impl Validate for HostAddress {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[non_exhaustive]
 pub enum AddressFormatType {
     #[yaserde(rename = "hostname")]
     Hostname,
@@ -560,11 +561,11 @@ impl Validate for AddressFormatType {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct UserCredentials {
-    // Username
+    /// Username
     #[yaserde(prefix = "tae", rename = "username")]
     pub username: String,
 
-    // Password
+    /// Password
     #[yaserde(prefix = "tae", rename = "password")]
     pub password: Option<String>,
 
@@ -589,11 +590,11 @@ impl Validate for UserCredentialsExtension {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct AuthenticationConfig {
-    // Username-password
+    /// Username-password
     #[yaserde(prefix = "tae", rename = "User")]
     pub user: UserCredentials,
 
-    // Email server authentication mode
+    /// Email server authentication mode
     #[yaserde(attribute, rename = "mode")]
     pub mode: EmailAuthenticationMode,
 }
@@ -601,6 +602,7 @@ pub struct AuthenticationConfig {
 impl Validate for AuthenticationConfig {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[non_exhaustive]
 pub enum EmailAuthenticationMode {
     #[yaserde(rename = "none")]
     None,
@@ -626,11 +628,11 @@ impl Validate for EmailAuthenticationMode {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct EmailReceiverConfiguration {
-    // Configuration for E-mail TO
+    /// Configuration for E-mail TO
     #[yaserde(prefix = "tae", rename = "TO")]
     pub to: Vec<String>,
 
-    // Configuration for E-mail CC
+    /// Configuration for E-mail CC
     #[yaserde(prefix = "tae", rename = "CC")]
     pub cc: Vec<String>,
 
@@ -682,7 +684,7 @@ impl Validate for EmailAttachmentConfigurationExtension {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct EmailBodyTextConfiguration {
-    // Whether content of E-mail message contains event data
+    /// Whether content of E-mail message contains event data
     #[yaserde(attribute, rename = "includeEvent")]
     pub include_event: Option<bool>,
 
@@ -698,7 +700,7 @@ impl Validate for EmailBodyTextConfiguration {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct MediaSource {
-    // MediaSource profile reference token
+    /// MediaSource profile reference token
     #[yaserde(prefix = "tae", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -711,7 +713,7 @@ impl Validate for MediaSource {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct HttpHostConfigurations {
-    // Destination HTTP Server configuration
+    /// Destination HTTP Server configuration
     #[yaserde(prefix = "tae", rename = "HttpDestination")]
     pub http_destination: Vec<HttpDestinationConfiguration>,
 
@@ -736,22 +738,22 @@ impl Validate for HttpHostConfigurationsExtension {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct HttpDestinationConfiguration {
-    // Destination HTTP Server address configuration
+    /// Destination HTTP Server address configuration
     #[yaserde(prefix = "tae", rename = "HostAddress")]
     pub host_address: HttpHostAddress,
 
-    // User Credentials configuration for destination HTTP Server
+    /// User Credentials configuration for destination HTTP Server
     #[yaserde(prefix = "tae", rename = "HttpAuthentication")]
     pub http_authentication: Option<HttpAuthenticationConfiguration>,
 
     #[yaserde(prefix = "tae", rename = "Extension")]
     pub extension: Option<HttpDestinationConfigurationExtension>,
 
-    // URI for POST Message destination
+    /// URI for POST Message destination
     #[yaserde(attribute, rename = "uri")]
     pub uri: Option<String>,
 
-    // HTTP/HTTPS protocol selection (default is http)
+    /// HTTP/HTTPS protocol selection (default is http)
     #[yaserde(attribute, rename = "protocol")]
     pub protocol: Option<HttpProtocolType>,
 }
@@ -759,6 +761,7 @@ pub struct HttpDestinationConfiguration {
 impl Validate for HttpDestinationConfiguration {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[non_exhaustive]
 pub enum HttpProtocolType {
     #[yaserde(rename = "http")]
     Http,
@@ -791,14 +794,14 @@ impl Validate for HttpDestinationConfigurationExtension {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct HttpAuthenticationConfiguration {
-    // User credentials
+    /// User credentials
     #[yaserde(prefix = "tae", rename = "User")]
     pub user: Option<UserCredentials>,
 
     #[yaserde(prefix = "tae", rename = "Extension")]
     pub extension: Option<HttpAuthenticationConfigurationExtension>,
 
-    // HTTP Authentication Method
+    /// HTTP Authentication Method
     #[yaserde(attribute, rename = "method")]
     pub method: Option<HttpAuthenticationMethodType>,
 }
@@ -815,6 +818,7 @@ pub struct HttpAuthenticationConfigurationExtension {}
 impl Validate for HttpAuthenticationConfigurationExtension {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[non_exhaustive]
 pub enum HttpAuthenticationMethodType {
     #[yaserde(rename = "none")]
     None,
@@ -838,15 +842,15 @@ impl Validate for HttpAuthenticationMethodType {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct HttpHostAddress {
-    // Destination HTTP Server IP Address
+    /// Destination HTTP Server IP Address
     #[yaserde(prefix = "tae", rename = "Value")]
     pub value: String,
 
-    // IPv4 or IPv6
+    /// IPv4 or IPv6
     #[yaserde(attribute, rename = "formatType")]
     pub format_type: AddressFormatType,
 
-    // Port Number if different from 80
+    /// Port Number if different from 80
     #[yaserde(attribute, rename = "portNo")]
     pub port_no: Option<xs::Integer>,
 }
@@ -859,11 +863,11 @@ impl Validate for HttpHostAddress {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct PostContentConfiguration {
-    // MediaSource reference when the media is attached to POST message
+    /// MediaSource reference when the media is attached to POST message
     #[yaserde(prefix = "tae", rename = "MediaReference")]
     pub media_reference: Option<MediaSource>,
 
-    // Configuration for POST Message content
+    /// Configuration for POST Message content
     #[yaserde(prefix = "tae", rename = "PostBody")]
     pub post_body: PostBodyConfiguration,
 }
@@ -879,11 +883,11 @@ pub struct PostBodyConfiguration {
     #[yaserde(attribute, rename = "formData")]
     pub form_data: Option<String>,
 
-    // Whether include event into POST message
+    /// Whether include event into POST message
     #[yaserde(attribute, rename = "includeEvent")]
     pub include_event: Option<bool>,
 
-    // Whether attach media into POST message
+    /// Whether attach media into POST message
     #[yaserde(attribute, rename = "includeMedia")]
     pub include_media: Option<bool>,
 }
@@ -896,7 +900,7 @@ impl Validate for PostBodyConfiguration {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct FtpHostConfigurations {
-    // FTP Action destination configuration
+    /// FTP Action destination configuration
     #[yaserde(prefix = "tae", rename = "FtpDestination")]
     pub ftp_destination: Vec<FtpDestinationConfiguration>,
 
@@ -921,15 +925,15 @@ impl Validate for FtpHostConfigurationsExtension {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct FtpDestinationConfiguration {
-    // FTP Server IP Address
+    /// FTP Server IP Address
     #[yaserde(prefix = "tae", rename = "HostAddress")]
     pub host_address: FtpHostAddress,
 
-    // Upload Directory Path
+    /// Upload Directory Path
     #[yaserde(prefix = "tae", rename = "UploadPath")]
     pub upload_path: String,
 
-    // User credentials confguration for target FTP Server
+    /// User credentials confguration for target FTP Server
     #[yaserde(prefix = "tae", rename = "FtpAuthentication")]
     pub ftp_authentication: FtpAuthenticationConfiguration,
 
@@ -954,7 +958,7 @@ impl Validate for FtpDestinationConfigurationExtension {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct FtpAuthenticationConfiguration {
-    // User Credentials
+    /// User Credentials
     #[yaserde(prefix = "tae", rename = "User")]
     pub user: Option<UserCredentials>,
 
@@ -979,15 +983,15 @@ impl Validate for FtpAuthenticationConfigurationExtension {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct FtpHostAddress {
-    // FTP Server IP Address
+    /// FTP Server IP Address
     #[yaserde(prefix = "tae", rename = "Value")]
     pub value: String,
 
-    // IPv4 or IPv6
+    /// IPv4 or IPv6
     #[yaserde(attribute, rename = "formatType")]
     pub format_type: AddressFormatType,
 
-    // Port Number
+    /// Port Number
     #[yaserde(attribute, rename = "portNo")]
     pub port_no: Option<xs::Integer>,
 }
@@ -1012,11 +1016,11 @@ impl Validate for FtpContent {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct FtpFileNameConfigurations {
-    // Name of file
+    /// Name of file
     #[yaserde(attribute, rename = "file_name")]
     pub file_name: Option<String>,
 
-    // Suffix of file
+    /// Suffix of file
     #[yaserde(attribute, rename = "suffix")]
     pub suffix: Option<FileSuffixType>,
 }
@@ -1024,6 +1028,7 @@ pub struct FtpFileNameConfigurations {
 impl Validate for FtpFileNameConfigurations {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[non_exhaustive]
 pub enum FileSuffixType {
     #[yaserde(rename = "none")]
     None,
@@ -1052,7 +1057,7 @@ pub struct FtpContentConfiguration {
     #[yaserde(prefix = "tae", rename = "FtpContentConfigurationChoice")]
     pub ftp_content_configuration_choice: ftp_content_configuration::FtpContentConfigurationChoice,
 
-    // Type of FTP Upload action
+    /// Type of FTP Upload action
     #[yaserde(attribute, rename = "Type")]
     pub _type: String,
 }
@@ -1063,10 +1068,11 @@ pub mod ftp_content_configuration {
     use super::*;
 
     #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
+    #[non_exhaustive]
     pub enum FtpContentConfigurationChoice {
-        // Upload Images action configuration
+        /// Upload Images action configuration
         UploadImages(FtpContentConfigurationUploadImages),
-        // Upload files action configuration
+        /// Upload files action configuration
         UploadFile(FtpContentConfigurationUploadFile),
         __Unknown__(String),
     }
@@ -1086,15 +1092,15 @@ pub mod ftp_content_configuration {
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct FtpContentConfigurationUploadImages {
-    // Upload Image action; how long?
+    /// Upload Image action; how long?
     #[yaserde(prefix = "tae", rename = "HowLong")]
     pub how_long: xs::Duration,
 
-    // Upload Image action; sample interval?
+    /// Upload Image action; sample interval?
     #[yaserde(prefix = "tae", rename = "SampleInterval")]
     pub sample_interval: xs::Duration,
 
-    // Upload Image action; name of destination file
+    /// Upload Image action; name of destination file
     #[yaserde(prefix = "tae", rename = "FileName")]
     pub file_name: FtpFileNameConfigurations,
 }
@@ -1107,11 +1113,11 @@ impl Validate for FtpContentConfigurationUploadImages {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct FtpContentConfigurationUploadFile {
-    // Name of source file
+    /// Name of source file
     #[yaserde(prefix = "tae", rename = "sourceFileName")]
     pub source_file_name: String,
 
-    // Name of destination file
+    /// Name of destination file
     #[yaserde(prefix = "tae", rename = "destinationFileName")]
     pub destination_file_name: String,
 }
@@ -1124,11 +1130,11 @@ impl Validate for FtpContentConfigurationUploadFile {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct SmsproviderConfiguration {
-    // SMS Provider's URL
+    /// SMS Provider's URL
     #[yaserde(prefix = "tae", rename = "ProviderURL")]
     pub provider_url: String,
 
-    // Username and password
+    /// Username and password
     #[yaserde(prefix = "tae", rename = "User")]
     pub user: UserCredentials,
 }
@@ -1141,7 +1147,7 @@ impl Validate for SmsproviderConfiguration {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct SmssenderConfiguration {
-    // Sender's e-mail address
+    /// Sender's e-mail address
     #[yaserde(prefix = "tae", rename = "EMail")]
     pub e_mail: String,
 }
@@ -1154,7 +1160,7 @@ impl Validate for SmssenderConfiguration {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct Smsmessage {
-    // Text Message
+    /// Text Message
     #[yaserde(prefix = "tae", rename = "Text")]
     pub text: String,
 }
@@ -1167,23 +1173,23 @@ impl Validate for Smsmessage {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct TriggeredRecordingConfiguration {
-    // Length of recording time before the triggering event
+    /// Length of recording time before the triggering event
     #[yaserde(prefix = "tae", rename = "PreRecordDuration")]
     pub pre_record_duration: xs::Duration,
 
-    // Recording after alarm recording duration
+    /// Recording after alarm recording duration
     #[yaserde(prefix = "tae", rename = "PostRecordDuration")]
     pub post_record_duration: xs::Duration,
 
-    // Record duration
+    /// Record duration
     #[yaserde(prefix = "tae", rename = "RecordDuration")]
     pub record_duration: xs::Duration,
 
-    // Recording frame rate
+    /// Recording frame rate
     #[yaserde(prefix = "tae", rename = "RecordFrameRate")]
     pub record_frame_rate: Option<xs::Integer>,
 
-    // Whether Audio recording on/off
+    /// Whether Audio recording on/off
     #[yaserde(prefix = "tae", rename = "DoRecordAudio")]
     pub do_record_audio: bool,
 }
@@ -1196,14 +1202,14 @@ impl Validate for TriggeredRecordingConfiguration {}
     namespace = "tae: http://www.onvif.org/ver10/actionengine/wsdl"
 )]
 pub struct RecordingActionConfiguration {
-    // Recording configuration
+    /// Recording configuration
     #[yaserde(prefix = "tae", rename = "RecordConfig")]
     pub record_config: TriggeredRecordingConfiguration,
 }
 
 impl Validate for RecordingActionConfiguration {}
 
-// The service provider returns the supported action types.
+/// The service provider returns the supported action types.
 pub async fn get_supported_actions<T: transport::Transport>(
     transport: &T,
     request: &GetSupportedActions,
@@ -1211,7 +1217,7 @@ pub async fn get_supported_actions<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// The service provider returns currently installed Actions.
+/// The service provider returns currently installed Actions.
 pub async fn get_actions<T: transport::Transport>(
     transport: &T,
     request: &GetActions,
@@ -1219,9 +1225,9 @@ pub async fn get_actions<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// The create action operation adds actions to configuration. The create action
-// operation is atomic. If a service provider can not create all of requested
-// actions, the service provider responds with a fault message.
+/// The create action operation adds actions to configuration. The create action
+/// operation is atomic. If a service provider can not create all of requested
+/// actions, the service provider responds with a fault message.
 pub async fn create_actions<T: transport::Transport>(
     transport: &T,
     request: &CreateActions,
@@ -1229,9 +1235,9 @@ pub async fn create_actions<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// The delete operation deletes actions. The delete action operation is atomic.
-// If a service provider can not delete all of requested actions, the service
-// provider responds with a fault message.
+/// The delete operation deletes actions. The delete action operation is atomic.
+/// If a service provider can not delete all of requested actions, the service
+/// provider responds with a fault message.
 pub async fn delete_actions<T: transport::Transport>(
     transport: &T,
     request: &DeleteActions,
@@ -1239,7 +1245,7 @@ pub async fn delete_actions<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// The modify action operation modifies action configurations.
+/// The modify action operation modifies action configurations.
 pub async fn modify_actions<T: transport::Transport>(
     transport: &T,
     request: &ModifyActions,
@@ -1247,7 +1253,7 @@ pub async fn modify_actions<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// The get capabilities operation returns the Action Engine capabilities
+/// The get capabilities operation returns the Action Engine capabilities
 pub async fn get_service_capabilities<T: transport::Transport>(
     transport: &T,
     request: &GetServiceCapabilities,
@@ -1255,7 +1261,7 @@ pub async fn get_service_capabilities<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// The service provider returns existing action triggers
+/// The service provider returns existing action triggers
 pub async fn get_action_triggers<T: transport::Transport>(
     transport: &T,
     request: &GetActionTriggers,
@@ -1263,9 +1269,9 @@ pub async fn get_action_triggers<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Creates action triggers. The create action triggers operation is atomic. If a
-// service provider can not create all of requested action triggers, the service
-// provider responds with a fault message.
+/// Creates action triggers. The create action triggers operation is atomic. If a
+/// service provider can not create all of requested action triggers, the service
+/// provider responds with a fault message.
 pub async fn create_action_triggers<T: transport::Transport>(
     transport: &T,
     request: &CreateActionTriggers,
@@ -1273,9 +1279,9 @@ pub async fn create_action_triggers<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Deletes action triggers. The delete action triggers operation is atomic. If a
-// service provider can not delete all of requested action triggers, the service
-// provider responds with a fault message.
+/// Deletes action triggers. The delete action triggers operation is atomic. If a
+/// service provider can not delete all of requested action triggers, the service
+/// provider responds with a fault message.
 pub async fn delete_action_triggers<T: transport::Transport>(
     transport: &T,
     request: &DeleteActionTriggers,
@@ -1283,9 +1289,9 @@ pub async fn delete_action_triggers<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Modifies existing action triggers. The modify action triggers operation is
-// atomic. If a service provider can not modify all of requested action trigger
-// configurations, the service provider responds with a fault message.
+/// Modifies existing action triggers. The modify action triggers operation is
+/// atomic. If a service provider can not modify all of requested action trigger
+/// configurations, the service provider responds with a fault message.
 pub async fn modify_action_triggers<T: transport::Transport>(
     transport: &T,
     request: &ModifyActionTriggers,
@@ -17,8 +17,8 @@ impl Validate for GetServiceCapabilities {}
     namespace = "tan: http://www.onvif.org/ver20/analytics/wsdl"
 )]
 pub struct GetServiceCapabilitiesResponse {
-    // The capabilities for the analytics service is returned in the
-    // Capabilities element.
+    /// The capabilities for the analytics service is returned in the
+    /// Capabilities element.
     #[yaserde(prefix = "tan", rename = "Capabilities")]
     pub capabilities: Capabilities,
 }
@@ -31,26 +31,26 @@ impl Validate for GetServiceCapabilitiesResponse {}
     namespace = "tan: http://www.onvif.org/ver20/analytics/wsdl"
 )]
 pub struct Capabilities {
-    // Indication that the device supports the rules interface and the rules
-    // syntax.
+    /// Indication that the device supports the rules interface and the rules
+    /// syntax.
     #[yaserde(attribute, rename = "RuleSupport")]
     pub rule_support: Option<bool>,
 
-    // Indication that the device supports the scene analytics module interface.
+    /// Indication that the device supports the scene analytics module interface.
     #[yaserde(attribute, rename = "AnalyticsModuleSupport")]
     pub analytics_module_support: Option<bool>,
 
-    // Indication that the device produces the cell based scene description
+    /// Indication that the device produces the cell based scene description
     #[yaserde(attribute, rename = "CellBasedSceneDescriptionSupported")]
     pub cell_based_scene_description_supported: Option<bool>,
 
-    // Indication that the device supports the GetRuleOptions operation on the
-    // rules interface
+    /// Indication that the device supports the GetRuleOptions operation on the
+    /// rules interface
     #[yaserde(attribute, rename = "RuleOptionsSupported")]
     pub rule_options_supported: Option<bool>,
 
-    // Indication that the device supports the GetAnalyticsModuleOptions
-    // operation on the analytics interface
+    /// Indication that the device supports the GetAnalyticsModuleOptions
+    /// operation on the analytics interface
     #[yaserde(attribute, rename = "AnalyticsModuleOptionsSupported")]
     pub analytics_module_options_supported: Option<bool>,
 }
@@ -64,9 +64,9 @@ impl Validate for Capabilities {}
     namespace = "tan: http://www.onvif.org/ver20/analytics/wsdl"
 )]
 pub struct GetSupportedRules {
-    // References an existing Video Analytics configuration. The list of
-    // available tokens can be obtained
-    // via the Media service GetVideoAnalyticsConfigurations method.
+    /// References an existing Video Analytics configuration. The list of
+    /// available tokens can be obtained
+    /// via the Media service GetVideoAnalyticsConfigurations method.
     #[yaserde(prefix = "tan", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -91,7 +91,7 @@ impl Validate for GetSupportedRulesResponse {}
     namespace = "tan: http://www.onvif.org/ver20/analytics/wsdl"
 )]
 pub struct CreateRules {
-    // Reference to an existing VideoAnalyticsConfiguration.
+    /// Reference to an existing VideoAnalyticsConfiguration.
     #[yaserde(prefix = "tan", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 
@@ -116,11 +116,11 @@ impl Validate for CreateRulesResponse {}
     namespace = "tan: http://www.onvif.org/ver20/analytics/wsdl"
 )]
 pub struct DeleteRules {
-    // Reference to an existing VideoAnalyticsConfiguration.
+    /// Reference to an existing VideoAnalyticsConfiguration.
     #[yaserde(prefix = "tan", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 
-    // References the specific rule to be deleted (e.g. "MyLineDetector").
+    /// References the specific rule to be deleted (e.g. "MyLineDetector").
     #[yaserde(prefix = "tan", rename = "RuleName")]
     pub rule_name: Vec<String>,
 }
@@ -142,7 +142,7 @@ impl Validate for DeleteRulesResponse {}
     namespace = "tan: http://www.onvif.org/ver20/analytics/wsdl"
 )]
 pub struct ModifyRules {
-    // Reference to an existing VideoAnalyticsConfiguration.
+    /// Reference to an existing VideoAnalyticsConfiguration.
     #[yaserde(prefix = "tan", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 
@@ -167,7 +167,7 @@ impl Validate for ModifyRulesResponse {}
     namespace = "tan: http://www.onvif.org/ver20/analytics/wsdl"
 )]
 pub struct GetRules {
-    // Reference to an existing VideoAnalyticsConfiguration.
+    /// Reference to an existing VideoAnalyticsConfiguration.
     #[yaserde(prefix = "tan", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -192,11 +192,11 @@ impl Validate for GetRulesResponse {}
     namespace = "tan: http://www.onvif.org/ver20/analytics/wsdl"
 )]
 pub struct GetRuleOptions {
-    // Reference to an SupportedRule Type returned from GetSupportedRules.
+    /// Reference to an SupportedRule Type returned from GetSupportedRules.
     #[yaserde(prefix = "tan", rename = "RuleType")]
     pub rule_type: Option<String>,
 
-    // Reference to an existing analytics configuration.
+    /// Reference to an existing analytics configuration.
     #[yaserde(prefix = "tan", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -209,9 +209,9 @@ impl Validate for GetRuleOptions {}
     namespace = "tan: http://www.onvif.org/ver20/analytics/wsdl"
 )]
 pub struct GetRuleOptionsResponse {
-    // A device shall provide respective ConfigOptions.RuleType for each
-    // RuleOption if the request does not specify RuleType. The response Options
-    // shall not contain any AnalyticsModule attribute.
+    /// A device shall provide respective ConfigOptions.RuleType for each
+    /// RuleOption if the request does not specify RuleType. The response Options
+    /// shall not contain any AnalyticsModule attribute.
     #[yaserde(prefix = "tan", rename = "RuleOptions")]
     pub rule_options: Vec<ConfigOptions>,
 }
@@ -224,26 +224,26 @@ impl Validate for GetRuleOptionsResponse {}
     namespace = "tan: http://www.onvif.org/ver20/analytics/wsdl"
 )]
 pub struct ConfigOptions {
-    // The RuleType the ConfigOptions applies to if the Name attribute is
-    // ambiguous.
+    /// The RuleType the ConfigOptions applies to if the Name attribute is
+    /// ambiguous.
     #[yaserde(attribute, rename = "RuleType")]
     pub rule_type: Option<String>,
 
-    // The Name of the SimpleItemDescription/ElementItemDescription
-    // the ConfigOptions applies to.
+    /// The Name of the SimpleItemDescription/ElementItemDescription
+    /// the ConfigOptions applies to.
     #[yaserde(attribute, rename = "Name")]
     pub name: String,
 
-    // Type of the Rule Options represented by a unique QName.
-    // The Type defines the element contained in this structure.
-    // This attribute is deprecated since its value must be identical to the
-    // embedded element.
+    /// Type of the Rule Options represented by a unique QName.
+    /// The Type defines the element contained in this structure.
+    /// This attribute is deprecated since its value must be identical to the
+    /// embedded element.
     #[yaserde(attribute, rename = "Type")]
     pub _type: Option<String>,
 
-    // Optional name of the analytics module this constraint applies to. This
-    // option is only necessary in cases where different constraints for
-    // elements with the same Name exist.
+    /// Optional name of the analytics module this constraint applies to. This
+    /// option is only necessary in cases where different constraints for
+    /// elements with the same Name exist.
     #[yaserde(attribute, rename = "AnalyticsModule")]
     pub analytics_module: Option<String>,
 }
@@ -256,7 +256,7 @@ impl Validate for ConfigOptions {}
     namespace = "tan: http://www.onvif.org/ver20/analytics/wsdl"
 )]
 pub struct GetSupportedAnalyticsModules {
-    // Reference to an existing VideoAnalyticsConfiguration.
+    /// Reference to an existing VideoAnalyticsConfiguration.
     #[yaserde(prefix = "tan", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -281,7 +281,7 @@ impl Validate for GetSupportedAnalyticsModulesResponse {}
     namespace = "tan: http://www.onvif.org/ver20/analytics/wsdl"
 )]
 pub struct CreateAnalyticsModules {
-    // Reference to an existing VideoAnalyticsConfiguration.
+    /// Reference to an existing VideoAnalyticsConfiguration.
     #[yaserde(prefix = "tan", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 
@@ -306,11 +306,11 @@ impl Validate for CreateAnalyticsModulesResponse {}
     namespace = "tan: http://www.onvif.org/ver20/analytics/wsdl"
 )]
 pub struct DeleteAnalyticsModules {
-    // Reference to an existing Video Analytics configuration.
+    /// Reference to an existing Video Analytics configuration.
     #[yaserde(prefix = "tan", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 
-    // Name of the AnalyticsModule to be deleted.
+    /// Name of the AnalyticsModule to be deleted.
     #[yaserde(prefix = "tan", rename = "AnalyticsModuleName")]
     pub analytics_module_name: Vec<String>,
 }
@@ -332,7 +332,7 @@ impl Validate for DeleteAnalyticsModulesResponse {}
     namespace = "tan: http://www.onvif.org/ver20/analytics/wsdl"
 )]
 pub struct ModifyAnalyticsModules {
-    // Reference to an existing VideoAnalyticsConfiguration.
+    /// Reference to an existing VideoAnalyticsConfiguration.
     #[yaserde(prefix = "tan", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 
@@ -357,7 +357,7 @@ impl Validate for ModifyAnalyticsModulesResponse {}
     namespace = "tan: http://www.onvif.org/ver20/analytics/wsdl"
 )]
 pub struct GetAnalyticsModules {
-    // Reference to an existing VideoAnalyticsConfiguration.
+    /// Reference to an existing VideoAnalyticsConfiguration.
     #[yaserde(prefix = "tan", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -382,12 +382,12 @@ impl Validate for GetAnalyticsModulesResponse {}
     namespace = "tan: http://www.onvif.org/ver20/analytics/wsdl"
 )]
 pub struct GetAnalyticsModuleOptions {
-    // Reference to an SupportedAnalyticsModule Type returned from
-    // GetSupportedAnalyticsModules.
+    /// Reference to an SupportedAnalyticsModule Type returned from
+    /// GetSupportedAnalyticsModules.
     #[yaserde(prefix = "tan", rename = "Type")]
     pub _type: Option<String>,
 
-    // Reference to an existing AnalyticsConfiguration.
+    /// Reference to an existing AnalyticsConfiguration.
     #[yaserde(prefix = "tan", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -400,16 +400,16 @@ impl Validate for GetAnalyticsModuleOptions {}
     namespace = "tan: http://www.onvif.org/ver20/analytics/wsdl"
 )]
 pub struct GetAnalyticsModuleOptionsResponse {
-    // List of options for the specified analytics module. The response Options
-    // shall not contain any RuleType attribute.
+    /// List of options for the specified analytics module. The response Options
+    /// shall not contain any RuleType attribute.
     #[yaserde(prefix = "tan", rename = "Options")]
     pub options: Vec<ConfigOptions>,
 }
 
 impl Validate for GetAnalyticsModuleOptionsResponse {}
 
-// Returns the capabilities of the analytics service. The result is returned in
-// a typed answer.
+/// Returns the capabilities of the analytics service. The result is returned in
+/// a typed answer.
 pub async fn get_service_capabilities<T: transport::Transport>(
     transport: &T,
     request: &GetServiceCapabilities,
@@ -417,8 +417,8 @@ pub async fn get_service_capabilities<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// List all analytics modules that are supported by the given
-// VideoAnalyticsConfiguration.
+/// List all analytics modules that are supported by the given
+/// VideoAnalyticsConfiguration.
 pub async fn get_supported_analytics_modules<T: transport::Transport>(
     transport: &T,
     request: &GetSupportedAnalyticsModules,
@@ -426,8 +426,8 @@ pub async fn get_supported_analytics_modules<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Return the options for the supported analytics modules that specify an Option
-// attribute.
+/// Return the options for the supported analytics modules that specify an Option
+/// attribute.
 pub async fn get_analytics_module_options<T: transport::Transport>(
     transport: &T,
     request: &GetAnalyticsModuleOptions,
@@ -435,8 +435,8 @@ pub async fn get_analytics_module_options<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Add one or more analytics modules to an existing VideoAnalyticsConfiguration.
-// The available supported types can be retrieved via
+/// Add one or more analytics modules to an existing VideoAnalyticsConfiguration.
+/// The available supported types can be retrieved via
 pub async fn create_analytics_modules<T: transport::Transport>(
     transport: &T,
     request: &CreateAnalyticsModules,
@@ -444,8 +444,8 @@ pub async fn create_analytics_modules<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Remove one or more analytics modules from a VideoAnalyticsConfiguration
-// referenced by their names.
+/// Remove one or more analytics modules from a VideoAnalyticsConfiguration
+/// referenced by their names.
 pub async fn delete_analytics_modules<T: transport::Transport>(
     transport: &T,
     request: &DeleteAnalyticsModules,
@@ -453,8 +453,8 @@ pub async fn delete_analytics_modules<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// List the currently assigned set of analytics modules of a
-// VideoAnalyticsConfiguration.
+/// List the currently assigned set of analytics modules of a
+/// VideoAnalyticsConfiguration.
 pub async fn get_analytics_modules<T: transport::Transport>(
     transport: &T,
     request: &GetAnalyticsModules,
@@ -462,9 +462,9 @@ pub async fn get_analytics_modules<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Modify the settings of one or more analytics modules of a
-// VideoAnalyticsConfiguration. The modules are referenced by their names.
-// It is allowed to pass only a subset to be modified.
+/// Modify the settings of one or more analytics modules of a
+/// VideoAnalyticsConfiguration. The modules are referenced by their names.
+/// It is allowed to pass only a subset to be modified.
 pub async fn modify_analytics_modules<T: transport::Transport>(
     transport: &T,
     request: &ModifyAnalyticsModules,
@@ -472,10 +472,10 @@ pub async fn modify_analytics_modules<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// List all rules that are supported by the given VideoAnalyticsConfiguration.
-// The result of this method may depend on the overall Video analytics
-// configuration of the device,
-// which is available via the current set of profiles.
+/// List all rules that are supported by the given VideoAnalyticsConfiguration.
+/// The result of this method may depend on the overall Video analytics
+/// configuration of the device,
+/// which is available via the current set of profiles.
 pub async fn get_supported_rules<T: transport::Transport>(
     transport: &T,
     request: &GetSupportedRules,
@@ -483,8 +483,8 @@ pub async fn get_supported_rules<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Add one or more rules to an existing VideoAnalyticsConfiguration.
-// The available supported types can be retrieved via
+/// Add one or more rules to an existing VideoAnalyticsConfiguration.
+/// The available supported types can be retrieved via
 pub async fn create_rules<T: transport::Transport>(
     transport: &T,
     request: &CreateRules,
@@ -492,7 +492,7 @@ pub async fn create_rules<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Remove one or more rules from a VideoAnalyticsConfiguration.
+/// Remove one or more rules from a VideoAnalyticsConfiguration.
 pub async fn delete_rules<T: transport::Transport>(
     transport: &T,
     request: &DeleteRules,
@@ -500,7 +500,7 @@ pub async fn delete_rules<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// List the currently assigned set of rules of a VideoAnalyticsConfiguration.
+/// List the currently assigned set of rules of a VideoAnalyticsConfiguration.
 pub async fn get_rules<T: transport::Transport>(
     transport: &T,
     request: &GetRules,
@@ -508,7 +508,7 @@ pub async fn get_rules<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Return the options for the supported rules that specify an Option attribute.
+/// Return the options for the supported rules that specify an Option attribute.
 pub async fn get_rule_options<T: transport::Transport>(
     transport: &T,
     request: &GetRuleOptions,
@@ -516,8 +516,8 @@ pub async fn get_rule_options<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Modify one or more rules of a VideoAnalyticsConfiguration. The rules are
-// referenced by their names.
+/// Modify one or more rules of a VideoAnalyticsConfiguration. The rules are
+/// referenced by their names.
 pub async fn modify_rules<T: transport::Transport>(
     transport: &T,
     request: &ModifyRules,
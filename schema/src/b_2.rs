@@ -154,6 +154,7 @@ pub struct Notify {
 impl Validate for Notify {}
 
 #[derive(PartialEq, Debug, UtilsUnionSerDe)]
+#[non_exhaustive]
 pub enum AbsoluteOrRelativeTimeType {
     DateTime(xs::DateTime),
     Duration(xs::Duration),
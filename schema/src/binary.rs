@@ -0,0 +1,93 @@
+//! Conversions between bytes and the generated types carrying binary payloads.
+//!
+//! Base64 content is stored encoded, as it appears on the wire. Use the `decoded_len` and
+//! `check_size` methods to reject oversized payloads before decoding them.
+
+use crate::{advancedsecurity as tas, onvif as tt, xmlmime as xmime, xop};
+
+impl tt::BinaryData {
+    pub fn from_bytes(data: &[u8], content_type: Option<&str>) -> Self {
+        Self {
+            data: base64::encode(data),
+            content_type: content_type.map(|c| xmime::ContentType(c.to_string())),
+        }
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        decode(&self.data)
+    }
+
+    pub fn decoded_len(&self) -> usize {
+        decoded_len(&self.data)
+    }
+
+    pub fn check_size(&self, max_len: usize) -> Result<(), String> {
+        check_size(&self.data, max_len)
+    }
+}
+
+impl tas::Base64DERencodedASN1Value {
+    pub fn from_bytes(der: &[u8]) -> Self {
+        Self(base64::encode(der))
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        decode(&self.0)
+    }
+
+    pub fn decoded_len(&self) -> usize {
+        decoded_len(&self.0)
+    }
+
+    pub fn check_size(&self, max_len: usize) -> Result<(), String> {
+        check_size(&self.0, max_len)
+    }
+}
+
+impl tt::AttachmentData {
+    /// Refers to a MIME part of an MTOM message by its `Content-ID`. The payload itself is not
+    /// part of the SOAP body.
+    pub fn new(content_id: &str, content_type: Option<&str>) -> Self {
+        Self {
+            include: xop::Include {
+                href: format!("cid:{}", content_id),
+            },
+            content_type: content_type.map(|c| xmime::ContentType(c.to_string())),
+        }
+    }
+
+    /// `Content-ID` of the MIME part holding the payload, if the reference is a `cid:` URI.
+    pub fn content_id(&self) -> Option<&str> {
+        self.include.href.strip_prefix("cid:")
+    }
+}
+
+// Base64 in XML documents is commonly wrapped into lines.
+fn decode(encoded: &str) -> Result<Vec<u8>, String> {
+    let compact: String = encoded.split_whitespace().collect();
+    base64::decode(compact).map_err(|e| e.to_string())
+}
+
+fn decoded_len(encoded: &str) -> usize {
+    let mut len = 0;
+    let mut padding = 0;
+    for c in encoded.chars().filter(|c| !c.is_whitespace()) {
+        len += 1;
+        if c == '=' {
+            padding += 1;
+        }
+    }
+
+    (len / 4 * 3 + len % 4 * 3 / 4).saturating_sub(padding)
+}
+
+fn check_size(encoded: &str, max_len: usize) -> Result<(), String> {
+    let len = decoded_len(encoded);
+    if len > max_len {
+        return Err(format!(
+            "Size validation error. \nExpected: decoded length <= {} \nActual: decoded length == {}",
+            max_len, len
+        ));
+    }
+    Ok(())
+}
@@ -3,11 +3,11 @@ use std::str::FromStr;
 use xsd_macro_utils::*;
 use xsd_types::types as xs;
 
-// Unique identifier for a physical or logical resource.
-// Tokens should be assigned such that they are unique within a device. Tokens
-// must be at least unique within its class.
-// Length up to 64 characters.
-#[derive(Default, PartialEq, Debug, UtilsTupleIo, UtilsDefaultSerde)]
+/// Unique identifier for a physical or logical resource.
+/// Tokens should be assigned such that they are unique within a device. Tokens
+/// must be at least unique within its class.
+/// Length up to 64 characters.
+#[derive(Default, Clone, PartialEq, Eq, Hash, Debug, UtilsTupleIo, UtilsDefaultSerde)]
 pub struct ReferenceToken(pub String);
 
 impl Validate for ReferenceToken {
@@ -22,7 +22,7 @@ impl Validate for ReferenceToken {
     }
 }
 
-// Range of values greater equal Min value and less equal Max value.
+/// Range of values greater equal Min value and less equal Max value.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct IntRange {
@@ -44,7 +44,7 @@ pub struct Vector2D {
     #[yaserde(attribute, rename = "y")]
     pub y: f64,
 
-    // Pan/tilt coordinate space selector. The following options are defined:
+    /// Pan/tilt coordinate space selector. The following options are defined:
     #[yaserde(attribute, rename = "space")]
     pub space: Option<String>,
 }
@@ -57,7 +57,7 @@ pub struct Vector1D {
     #[yaserde(attribute, rename = "x")]
     pub x: f64,
 
-    // Zoom coordinate space selector. The following options are defined:
+    /// Zoom coordinate space selector. The following options are defined:
     #[yaserde(attribute, rename = "space")]
     pub space: Option<String>,
 }
@@ -67,12 +67,12 @@ impl Validate for Vector1D {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Ptzvector {
-    // Pan and tilt position. The x component corresponds to pan and the y
-    // component to tilt.
+    /// Pan and tilt position. The x component corresponds to pan and the y
+    /// component to tilt.
     #[yaserde(prefix = "tt", rename = "PanTilt")]
     pub pan_tilt: Option<Vector2D>,
 
-    // A zoom position.
+    /// A zoom position.
     #[yaserde(prefix = "tt", rename = "Zoom")]
     pub zoom: Option<Vector1D>,
 }
@@ -82,22 +82,22 @@ impl Validate for Ptzvector {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Ptzstatus {
-    // Specifies the absolute position of the PTZ unit together with the Space
-    // references. The default absolute spaces of the corresponding PTZ
-    // configuration MUST be referenced within the Position element.
+    /// Specifies the absolute position of the PTZ unit together with the Space
+    /// references. The default absolute spaces of the corresponding PTZ
+    /// configuration MUST be referenced within the Position element.
     #[yaserde(prefix = "tt", rename = "Position")]
     pub position: Option<Ptzvector>,
 
-    // Indicates if the Pan/Tilt/Zoom device unit is currently moving, idle or
-    // in an unknown state.
+    /// Indicates if the Pan/Tilt/Zoom device unit is currently moving, idle or
+    /// in an unknown state.
     #[yaserde(prefix = "tt", rename = "MoveStatus")]
     pub move_status: Option<PtzmoveStatus>,
 
-    // States a current PTZ error.
+    /// States a current PTZ error.
     #[yaserde(prefix = "tt", rename = "Error")]
     pub error: Option<String>,
 
-    // Specifies the UTC time when this status was generated.
+    /// Specifies the UTC time when this status was generated.
     #[yaserde(prefix = "tt", rename = "UtcTime")]
     pub utc_time: xs::DateTime,
 }
@@ -118,6 +118,7 @@ impl Validate for PtzmoveStatus {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum MoveStatus {
     #[yaserde(rename = "IDLE")]
     Idle,
@@ -188,7 +189,7 @@ pub struct Color {
     #[yaserde(attribute, rename = "Z")]
     pub z: f64,
 
-    // Acceptable values:
+    /// Acceptable values:
     #[yaserde(attribute, rename = "Colorspace")]
     pub colorspace: Option<String>,
 }
@@ -216,7 +217,7 @@ pub struct ColorCovariance {
     #[yaserde(attribute, rename = "YZ")]
     pub yz: Option<f64>,
 
-    // Acceptable values are the same as in tt:Color.
+    /// Acceptable values are the same as in tt:Color.
     #[yaserde(attribute, rename = "Colorspace")]
     pub colorspace: Option<String>,
 }
@@ -247,15 +248,15 @@ impl Validate for TransformationExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct GeoLocation {
-    // East west location as angle.
+    /// East west location as angle.
     #[yaserde(attribute, rename = "lon")]
     pub lon: Option<f64>,
 
-    // North south location as angle.
+    /// North south location as angle.
     #[yaserde(attribute, rename = "lat")]
     pub lat: Option<f64>,
 
-    // Hight in meters above sea level.
+    /// Hight in meters above sea level.
     #[yaserde(attribute, rename = "elevation")]
     pub elevation: Option<f64>,
 }
@@ -265,15 +266,15 @@ impl Validate for GeoLocation {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct GeoOrientation {
-    // Rotation around the x axis.
+    /// Rotation around the x axis.
     #[yaserde(attribute, rename = "roll")]
     pub roll: Option<f64>,
 
-    // Rotation around the y axis.
+    /// Rotation around the y axis.
     #[yaserde(attribute, rename = "pitch")]
     pub pitch: Option<f64>,
 
-    // Rotation around the z axis.
+    /// Rotation around the z axis.
     #[yaserde(attribute, rename = "yaw")]
     pub yaw: Option<f64>,
 }
@@ -283,15 +284,15 @@ impl Validate for GeoOrientation {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct LocalLocation {
-    // East west location as angle.
+    /// East west location as angle.
     #[yaserde(attribute, rename = "x")]
     pub x: Option<f64>,
 
-    // North south location as angle.
+    /// North south location as angle.
     #[yaserde(attribute, rename = "y")]
     pub y: Option<f64>,
 
-    // Offset in meters from the sea level.
+    /// Offset in meters from the sea level.
     #[yaserde(attribute, rename = "z")]
     pub z: Option<f64>,
 }
@@ -301,15 +302,15 @@ impl Validate for LocalLocation {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct LocalOrientation {
-    // Rotation around the y axis.
+    /// Rotation around the y axis.
     #[yaserde(attribute, rename = "pan")]
     pub pan: Option<f64>,
 
-    // Rotation around the z axis.
+    /// Rotation around the z axis.
     #[yaserde(attribute, rename = "tilt")]
     pub tilt: Option<f64>,
 
-    // Rotation around the x axis.
+    /// Rotation around the x axis.
     #[yaserde(attribute, rename = "roll")]
     pub roll: Option<f64>,
 }
@@ -318,6 +319,7 @@ impl Validate for LocalOrientation {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum Entity {
     Device,
     VideoSource,
@@ -336,41 +338,41 @@ impl Validate for Entity {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct LocationEntity {
-    // Location on earth.
+    /// Location on earth.
     #[yaserde(prefix = "tt", rename = "GeoLocation")]
     pub geo_location: Option<GeoLocation>,
 
-    // Orientation relative to earth.
+    /// Orientation relative to earth.
     #[yaserde(prefix = "tt", rename = "GeoOrientation")]
     pub geo_orientation: Option<GeoOrientation>,
 
-    // Indoor location offset.
+    /// Indoor location offset.
     #[yaserde(prefix = "tt", rename = "LocalLocation")]
     pub local_location: Option<LocalLocation>,
 
-    // Indoor orientation offset.
+    /// Indoor orientation offset.
     #[yaserde(prefix = "tt", rename = "LocalOrientation")]
     pub local_orientation: Option<LocalOrientation>,
 
-    // Entity type the entry refers to, use a value from the tt:Entity
-    // enumeration.
+    /// Entity type the entry refers to, use a value from the tt:Entity
+    /// enumeration.
     #[yaserde(attribute, rename = "Entity")]
     pub entity: Option<String>,
 
-    // Optional entity token.
+    /// Optional entity token.
     #[yaserde(attribute, rename = "Token")]
     pub token: Option<ReferenceToken>,
 
-    // If this value is true the entity cannot be deleted.
+    /// If this value is true the entity cannot be deleted.
     #[yaserde(attribute, rename = "Fixed")]
     pub fixed: Option<bool>,
 
-    // Optional reference to the XAddr of another devices DeviceManagement
-    // service.
+    /// Optional reference to the XAddr of another devices DeviceManagement
+    /// service.
     #[yaserde(attribute, rename = "GeoSource")]
     pub geo_source: Option<String>,
 
-    // If set the geo location is obtained internally.
+    /// If set the geo location is obtained internally.
     #[yaserde(attribute, rename = "AutoGeo")]
     pub auto_geo: Option<bool>,
 }
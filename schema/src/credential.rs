@@ -5,76 +5,76 @@ use std::str::FromStr;
 use xsd_macro_utils::*;
 use xsd_types::types as xs;
 
-// The service capabilities reflect optional functionality of a service. The
-// information is static
-// and does not change during device operation. The following capabilities are
-// available:
+/// The service capabilities reflect optional functionality of a service. The
+/// information is static
+/// and does not change during device operation. The following capabilities are
+/// available:
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tcr",
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct ServiceCapabilities {
-    // A list of identifier types that the device supports. Identifiers types
-    // starting with
-    // the prefix pt: are reserved to define ONVIF specific types. For custom
-    // defined identifier types
-    // shall all share the "pt:<Name>" syntax.
+    /// A list of identifier types that the device supports. Identifiers types
+    /// starting with
+    /// the prefix pt: are reserved to define ONVIF specific types. For custom
+    /// defined identifier types
+    /// shall all share the "pt:<Name>" syntax.
     #[yaserde(prefix = "tcr", rename = "SupportedIdentifierType")]
     pub supported_identifier_type: Vec<pt::Name>,
 
     #[yaserde(prefix = "tcr", rename = "Extension")]
     pub extension: Option<ServiceCapabilitiesExtension>,
 
-    // The maximum number of entries returned by a single Get<Entity>List or
-    // Get<Entity>
-    // request. The device shall never return more than this number of entities
-    // in a single response.
+    /// The maximum number of entries returned by a single Get<Entity>List or
+    /// Get<Entity>
+    /// request. The device shall never return more than this number of entities
+    /// in a single response.
     #[yaserde(attribute, rename = "MaxLimit")]
     pub max_limit: pt::PositiveInteger,
 
-    // Indicates that the device supports credential validity.
+    /// Indicates that the device supports credential validity.
     #[yaserde(attribute, rename = "CredentialValiditySupported")]
     pub credential_validity_supported: bool,
 
-    // Indicates that the device supports validity on the association between a
-    // credential and an
-    // access profile.
+    /// Indicates that the device supports validity on the association between a
+    /// credential and an
+    /// access profile.
     #[yaserde(attribute, rename = "CredentialAccessProfileValiditySupported")]
     pub credential_access_profile_validity_supported: bool,
 
-    // Indicates that the device supports both date and time value for validity.
-    // If set to false,
-    // then the time value is ignored.
+    /// Indicates that the device supports both date and time value for validity.
+    /// If set to false,
+    /// then the time value is ignored.
     #[yaserde(attribute, rename = "ValiditySupportsTimeValue")]
     pub validity_supports_time_value: bool,
 
-    // The maximum number of credential supported by the device.
+    /// The maximum number of credential supported by the device.
     #[yaserde(attribute, rename = "MaxCredentials")]
     pub max_credentials: pt::PositiveInteger,
 
-    // The maximum number of access profiles for a credential.
+    /// The maximum number of access profiles for a credential.
     #[yaserde(attribute, rename = "MaxAccessProfilesPerCredential")]
     pub max_access_profiles_per_credential: pt::PositiveInteger,
 
-    // Indicates the device supports resetting of anti-passback violations and
-    // notifying on
-    // anti-passback violations.
+    /// Indicates the device supports resetting of anti-passback violations and
+    /// notifying on
+    /// anti-passback violations.
     #[yaserde(attribute, rename = "ResetAntipassbackSupported")]
     pub reset_antipassback_supported: bool,
 
-    // Indicates that the client is allowed to supply the token when creating
-    // credentials.
-    // To enable the use of the command SetCredential, the value must be set to
-    // true.
+    /// Indicates that the client is allowed to supply the token when creating
+    /// credentials.
+    /// To enable the use of the command SetCredential, the value must be set to
+    /// true.
     #[yaserde(attribute, rename = "ClientSuppliedTokenSupported")]
     pub client_supplied_token_supported: Option<bool>,
 
-    // The default time period that the credential will temporary be suspended
-    // (e.g. by using
-    // the wrong PIN a predetermined number of times).
-    // The time period is defined as an [ISO 8601] duration string (e.g.
-    // “PT5M”).
+    /// The default time period that the credential will temporary be suspended
+    /// (e.g. by using
+    /// the wrong PIN a predetermined number of times).
+    /// The time period is defined as an [ISO 8601] duration string (e.g.
+    /// “PT5M”).
     #[yaserde(attribute, rename = "DefaultCredentialSuspensionDuration")]
     pub default_credential_suspension_duration: Option<xs::Duration>,
 }
@@ -87,11 +87,11 @@ impl Validate for ServiceCapabilities {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct ServiceCapabilitiesExtension {
-    // A list of exemptions that the device supports. Supported exemptions
-    // starting with the
-    // prefix pt: are reserved to define ONVIF specific exemption types and
-    // these reserved
-    // exemption types shall all share "pt:<Name>" syntax.
+    /// A list of exemptions that the device supports. Supported exemptions
+    /// starting with the
+    /// prefix pt: are reserved to define ONVIF specific exemption types and
+    /// these reserved
+    /// exemption types shall all share "pt:<Name>" syntax.
     #[yaserde(prefix = "tcr", rename = "SupportedExemptionType")]
     pub supported_exemption_type: Vec<pt::Name>,
 }
@@ -105,27 +105,27 @@ impl Validate for ServiceCapabilitiesExtension {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct CredentialInfo {
-    // User readable description for the credential. It shall be up to 1024
-    // characters.
+    /// User readable description for the credential. It shall be up to 1024
+    /// characters.
     #[yaserde(prefix = "tcr", rename = "Description")]
     pub description: Option<pt::Description>,
 
-    // An external reference to a person holding this credential. The
-    // reference is a username or used ID in an external system, such as a
-    // directory
-    // service.
+    /// An external reference to a person holding this credential. The
+    /// reference is a username or used ID in an external system, such as a
+    /// directory
+    /// service.
     #[yaserde(prefix = "tcr", rename = "CredentialHolderReference")]
     pub credential_holder_reference: credential_info::CredentialHolderReferenceType,
 
-    // The start date/time validity of the credential. If the
-    // ValiditySupportsTimeValue capability is set to false, then only date is
-    // supported (time is ignored).
+    /// The start date/time validity of the credential. If the
+    /// ValiditySupportsTimeValue capability is set to false, then only date is
+    /// supported (time is ignored).
     #[yaserde(prefix = "tcr", rename = "ValidFrom")]
     pub valid_from: Option<xs::DateTime>,
 
-    // The expiration date/time validity of the credential. If the
-    // ValiditySupportsTimeValue capability is set to false, then only date is
-    // supported (time is ignored).
+    /// The expiration date/time validity of the credential. If the
+    /// ValiditySupportsTimeValue capability is set to false, then only date is
+    /// supported (time is ignored).
     #[yaserde(prefix = "tcr", rename = "ValidTo")]
     pub valid_to: Option<xs::DateTime>,
 
@@ -159,28 +159,28 @@ pub mod credential_info {
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct Credential {
-    // A list of credential identifier structures. At least one
-    // credential identifier is required. Maximum one credential identifier
-    // structure
-    // per type is allowed.
+    /// A list of credential identifier structures. At least one
+    /// credential identifier is required. Maximum one credential identifier
+    /// structure
+    /// per type is allowed.
     #[yaserde(prefix = "tcr", rename = "CredentialIdentifier")]
     pub credential_identifier: Vec<CredentialIdentifier>,
 
-    // A list of credential access profile structures.
+    /// A list of credential access profile structures.
     #[yaserde(prefix = "tcr", rename = "CredentialAccessProfile")]
     pub credential_access_profile: Vec<CredentialAccessProfile>,
 
-    // A boolean indicating that the credential holder needs extra time to get
-    // through the door.
-    // ExtendedReleaseTime will be added to ReleaseTime, and ExtendedOpenTime
-    // will be added to OpenTime
+    /// A boolean indicating that the credential holder needs extra time to get
+    /// through the door.
+    /// ExtendedReleaseTime will be added to ReleaseTime, and ExtendedOpenTime
+    /// will be added to OpenTime
     #[yaserde(prefix = "tcr", rename = "ExtendedGrantTime")]
     pub extended_grant_time: Option<bool>,
 
-    // A list of credential attributes as name value pairs. Key names
-    // starting with the prefix pt: are reserved to define PACS specific
-    // attributes
-    // following the "pt:<Name>" syntax.
+    /// A list of credential attributes as name value pairs. Key names
+    /// starting with the prefix pt: are reserved to define PACS specific
+    /// attributes
+    /// following the "pt:<Name>" syntax.
     #[yaserde(prefix = "tcr", rename = "Attribute")]
     pub attribute: Vec<pt::Attribute>,
 
@@ -201,109 +201,109 @@ pub struct CredentialExtension {}
 
 impl Validate for CredentialExtension {}
 
-// A credential identifier is a card number, unique card information, PIN or
-// biometric information such as fingerprint, iris, vein, face recognition, that
-// can be validated
-// in an access point.
+/// A credential identifier is a card number, unique card information, PIN or
+/// biometric information such as fingerprint, iris, vein, face recognition, that
+/// can be validated
+/// in an access point.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tcr",
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct CredentialIdentifier {
-    // Contains the details of the credential identifier type. Is of type
-    // CredentialIdentifierType.
+    /// Contains the details of the credential identifier type. Is of type
+    /// CredentialIdentifierType.
     #[yaserde(prefix = "tcr", rename = "Type")]
     pub _type: CredentialIdentifierType,
 
-    // If set to true, this credential identifier is not considered for
-    // authentication. For example if the access point requests Card plus PIN,
-    // and the credential
-    // identifier of type PIN is exempted from authentication, then the access
-    // point will not prompt
-    // for the PIN.
+    /// If set to true, this credential identifier is not considered for
+    /// authentication. For example if the access point requests Card plus PIN,
+    /// and the credential
+    /// identifier of type PIN is exempted from authentication, then the access
+    /// point will not prompt
+    /// for the PIN.
     #[yaserde(prefix = "tcr", rename = "ExemptedFromAuthentication")]
     pub exempted_from_authentication: bool,
 
-    // The value of the identifier in hexadecimal representation.
+    /// The value of the identifier in hexadecimal representation.
     #[yaserde(prefix = "tcr", rename = "Value")]
     pub value: String,
 }
 
 impl Validate for CredentialIdentifier {}
 
-// Specifies the name of credential identifier type and its format for the
-// credential
-// value.
+/// Specifies the name of credential identifier type and its format for the
+/// credential
+/// value.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tcr",
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct CredentialIdentifierType {
-    // The name of the credential identifier type, such as pt:Card, pt:PIN,
-    // etc.
+    /// The name of the credential identifier type, such as pt:Card, pt:PIN,
+    /// etc.
     #[yaserde(prefix = "tcr", rename = "Name")]
     pub name: pt::Name,
 
-    // Specifies the format of the credential value for the specified identifier
-    // type name.
+    /// Specifies the format of the credential value for the specified identifier
+    /// type name.
     #[yaserde(prefix = "tcr", rename = "FormatType")]
     pub format_type: String,
 }
 
 impl Validate for CredentialIdentifierType {}
 
-// The association between a credential and an access profile.
+/// The association between a credential and an access profile.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tcr",
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct CredentialAccessProfile {
-    // The reference token of the associated access profile.
+    /// The reference token of the associated access profile.
     #[yaserde(prefix = "tcr", rename = "AccessProfileToken")]
     pub access_profile_token: pt::ReferenceToken,
 
-    // The start date/time of the validity for the association between the
-    // credential and the access profile. If the ValiditySupportsTimeValue
-    // capability is set to
-    // false, then only date is supported (time is ignored).
+    /// The start date/time of the validity for the association between the
+    /// credential and the access profile. If the ValiditySupportsTimeValue
+    /// capability is set to
+    /// false, then only date is supported (time is ignored).
     #[yaserde(prefix = "tcr", rename = "ValidFrom")]
     pub valid_from: Option<xs::DateTime>,
 
-    // The end date/time of the validity for the association between the
-    // credential and the access profile. If the ValiditySupportsTimeValue
-    // capability is set to
-    // false, then only date is supported (time is ignored).
+    /// The end date/time of the validity for the association between the
+    /// credential and the access profile. If the ValiditySupportsTimeValue
+    /// capability is set to
+    /// false, then only date is supported (time is ignored).
     #[yaserde(prefix = "tcr", rename = "ValidTo")]
     pub valid_to: Option<xs::DateTime>,
 }
 
 impl Validate for CredentialAccessProfile {}
 
-// The CredentialState structure contains information about the state of the
-// credential and
-// optionally the reason of why the credential was disabled.
+/// The CredentialState structure contains information about the state of the
+/// credential and
+/// optionally the reason of why the credential was disabled.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tcr",
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct CredentialState {
-    // True if the credential is enabled or false if the credential is
-    // disabled.
+    /// True if the credential is enabled or false if the credential is
+    /// disabled.
     #[yaserde(prefix = "tcr", rename = "Enabled")]
     pub enabled: bool,
 
-    // Predefined ONVIF reasons as mentioned in the section 5.4.2.7
-    // of credential service specification document. For any other reason, free
-    // text can be used.
+    /// Predefined ONVIF reasons as mentioned in the section 5.4.2.7
+    /// of credential service specification document. For any other reason, free
+    /// text can be used.
     #[yaserde(prefix = "tcr", rename = "Reason")]
     pub reason: Option<pt::Name>,
 
-    // A structure indicating the anti-passback state. This field shall be
-    // supported if the ResetAntipassbackSupported capability is set to true.
+    /// A structure indicating the anti-passback state. This field shall be
+    /// supported if the ResetAntipassbackSupported capability is set to true.
     #[yaserde(prefix = "tcr", rename = "AntipassbackState")]
     pub antipassback_state: Option<AntipassbackState>,
 
@@ -322,42 +322,42 @@ pub struct CredentialStateExtension {}
 
 impl Validate for CredentialStateExtension {}
 
-// A structure containing anti-passback related state information.
+/// A structure containing anti-passback related state information.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tcr",
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct AntipassbackState {
-    // Indicates if anti-passback is violated for the credential.
+    /// Indicates if anti-passback is violated for the credential.
     #[yaserde(prefix = "tcr", rename = "AntipassbackViolated")]
     pub antipassback_violated: bool,
 }
 
 impl Validate for AntipassbackState {}
 
-// Contains information about a format type.
+/// Contains information about a format type.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tcr",
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct CredentialIdentifierFormatTypeInfo {
-    // A format type supported by the device. A list of supported format types
-    // is
-    // provided in [ISO 16484-5:2014-09 Annex P]. The BACnet type "CUSTOM" is
-    // not used in this
-    // specification. Instead device manufacturers can define their own format
-    // types.
+    /// A format type supported by the device. A list of supported format types
+    /// is
+    /// provided in [ISO 16484-5:2014-09 Annex P]. The BACnet type "CUSTOM" is
+    /// not used in this
+    /// specification. Instead device manufacturers can define their own format
+    /// types.
     #[yaserde(prefix = "tcr", rename = "FormatType")]
     pub format_type: String,
 
-    // User readable description of the credential identifier format type. It
-    // shall be up to 1024 characters. For custom types, it is recommended to
-    // describe how the
-    // octet string is encoded (following the structure in column Authentication
-    // Factor Value
-    // Encoding of [ISO 16484-5:2014-09 Annex P]).
+    /// User readable description of the credential identifier format type. It
+    /// shall be up to 1024 characters. For custom types, it is recommended to
+    /// describe how the
+    /// octet string is encoded (following the structure in column Authentication
+    /// Factor Value
+    /// Encoding of [ISO 16484-5:2014-09 Annex P]).
     #[yaserde(prefix = "tcr", rename = "Description")]
     pub description: pt::Description,
 
@@ -376,28 +376,28 @@ pub struct CredentialIdentifierFormatTypeInfoExtension {}
 
 impl Validate for CredentialIdentifierFormatTypeInfoExtension {}
 
-// Contains information about a format type.
+/// Contains information about a format type.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tcr",
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct CredentialData {
-    // A format type supported by the device. A list of supported format types
-    // is
-    // provided in [ISO 16484-5:2014-09 Annex P]. The BACnet type "CUSTOM" is
-    // not used in this
-    // specification. Instead device manufacturers can define their own format
-    // types.
+    /// A format type supported by the device. A list of supported format types
+    /// is
+    /// provided in [ISO 16484-5:2014-09 Annex P]. The BACnet type "CUSTOM" is
+    /// not used in this
+    /// specification. Instead device manufacturers can define their own format
+    /// types.
     #[yaserde(prefix = "tcr", rename = "Credential")]
     pub credential: Credential,
 
-    // User readable description of the credential identifier format type. It
-    // shall be up to 1024 characters. For custom types, it is recommended to
-    // describe how the
-    // octet string is encoded (following the structure in column Authentication
-    // Factor Value
-    // Encoding of [ISO 16484-5:2014-09 Annex P]).
+    /// User readable description of the credential identifier format type. It
+    /// shall be up to 1024 characters. For custom types, it is recommended to
+    /// describe how the
+    /// octet string is encoded (following the structure in column Authentication
+    /// Factor Value
+    /// Encoding of [ISO 16484-5:2014-09 Annex P]).
     #[yaserde(prefix = "tcr", rename = "CredentialState")]
     pub credential_state: CredentialState,
 
@@ -416,28 +416,28 @@ pub struct CredentialDataExtension {}
 
 impl Validate for CredentialDataExtension {}
 
-// Contains information about a format type.
+/// Contains information about a format type.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tcr",
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct FaultResponse {
-    // A format type supported by the device. A list of supported format types
-    // is
-    // provided in [ISO 16484-5:2014-09 Annex P]. The BACnet type "CUSTOM" is
-    // not used in this
-    // specification. Instead device manufacturers can define their own format
-    // types.
+    /// A format type supported by the device. A list of supported format types
+    /// is
+    /// provided in [ISO 16484-5:2014-09 Annex P]. The BACnet type "CUSTOM" is
+    /// not used in this
+    /// specification. Instead device manufacturers can define their own format
+    /// types.
     #[yaserde(prefix = "tcr", rename = "Token")]
     pub token: pt::ReferenceToken,
 
-    // User readable description of the credential identifier format type. It
-    // shall be up to 1024 characters. For custom types, it is recommended to
-    // describe how the
-    // octet string is encoded (following the structure in column Authentication
-    // Factor Value
-    // Encoding of [ISO 16484-5:2014-09 Annex P]).
+    /// User readable description of the credential identifier format type. It
+    /// shall be up to 1024 characters. For custom types, it is recommended to
+    /// describe how the
+    /// octet string is encoded (following the structure in column Authentication
+    /// Factor Value
+    /// Encoding of [ISO 16484-5:2014-09 Annex P]).
     #[yaserde(prefix = "tcr", rename = "Fault")]
     pub fault: String,
 
@@ -471,8 +471,8 @@ impl Validate for GetServiceCapabilities {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct GetServiceCapabilitiesResponse {
-    // The capability response message contains the requested credential
-    // service capabilities using a hierarchical XML capability structure.
+    /// The capability response message contains the requested credential
+    /// service capabilities using a hierarchical XML capability structure.
     #[yaserde(prefix = "tcr", rename = "Capabilities")]
     pub capabilities: ServiceCapabilities,
 }
@@ -485,7 +485,7 @@ impl Validate for GetServiceCapabilitiesResponse {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct GetSupportedFormatTypes {
-    // Name of the credential identifier type
+    /// Name of the credential identifier type
     #[yaserde(prefix = "tcr", rename = "CredentialIdentifierTypeName")]
     pub credential_identifier_type_name: String,
 }
@@ -498,7 +498,7 @@ impl Validate for GetSupportedFormatTypes {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct GetSupportedFormatTypesResponse {
-    // Identifier format type
+    /// Identifier format type
     #[yaserde(prefix = "tcr", rename = "FormatTypeInfo")]
     pub format_type_info: Vec<CredentialIdentifierFormatTypeInfo>,
 }
@@ -511,7 +511,7 @@ impl Validate for GetSupportedFormatTypesResponse {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct GetCredentialInfo {
-    // Tokens of CredentialInfo items to get.
+    /// Tokens of CredentialInfo items to get.
     #[yaserde(prefix = "tcr", rename = "Token")]
     pub token: Vec<pt::ReferenceToken>,
 }
@@ -524,7 +524,7 @@ impl Validate for GetCredentialInfo {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct GetCredentialInfoResponse {
-    // List of CredentialInfo items.
+    /// List of CredentialInfo items.
     #[yaserde(prefix = "tcr", rename = "CredentialInfo")]
     pub credential_info: Vec<CredentialInfo>,
 }
@@ -537,15 +537,15 @@ impl Validate for GetCredentialInfoResponse {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct GetCredentialInfoList {
-    // Maximum number of entries to return. If not specified, less than one
-    // or higher than what the device supports, the number of items is
-    // determined by the
-    // device.
+    /// Maximum number of entries to return. If not specified, less than one
+    /// or higher than what the device supports, the number of items is
+    /// determined by the
+    /// device.
     #[yaserde(prefix = "tcr", rename = "Limit")]
     pub limit: Option<i32>,
 
-    // Start returning entries from this start reference. If not specified,
-    // entries shall start from the beginning of the dataset.
+    /// Start returning entries from this start reference. If not specified,
+    /// entries shall start from the beginning of the dataset.
     #[yaserde(prefix = "tcr", rename = "StartReference")]
     pub start_reference: Option<String>,
 }
@@ -558,12 +558,12 @@ impl Validate for GetCredentialInfoList {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct GetCredentialInfoListResponse {
-    // StartReference to use in next call to get the following items. If
-    // absent, no more items to get.
+    /// StartReference to use in next call to get the following items. If
+    /// absent, no more items to get.
     #[yaserde(prefix = "tcr", rename = "NextStartReference")]
     pub next_start_reference: Option<String>,
 
-    // List of CredentialInfo items.
+    /// List of CredentialInfo items.
     #[yaserde(prefix = "tcr", rename = "CredentialInfo")]
     pub credential_info: Vec<CredentialInfo>,
 }
@@ -576,7 +576,7 @@ impl Validate for GetCredentialInfoListResponse {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct GetCredentials {
-    // Token of Credentials to get
+    /// Token of Credentials to get
     #[yaserde(prefix = "tcr", rename = "Token")]
     pub token: Vec<pt::ReferenceToken>,
 }
@@ -589,7 +589,7 @@ impl Validate for GetCredentials {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct GetCredentialsResponse {
-    // List of Credential items.
+    /// List of Credential items.
     #[yaserde(prefix = "tcr", rename = "Credential")]
     pub credential: Vec<Credential>,
 }
@@ -602,15 +602,15 @@ impl Validate for GetCredentialsResponse {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct GetCredentialList {
-    // Maximum number of entries to return. If not specified, less than one
-    // or higher than what the device supports, the number of items is
-    // determined by the
-    // device.
+    /// Maximum number of entries to return. If not specified, less than one
+    /// or higher than what the device supports, the number of items is
+    /// determined by the
+    /// device.
     #[yaserde(prefix = "tcr", rename = "Limit")]
     pub limit: Option<i32>,
 
-    // Start returning entries from this start reference. If not specified,
-    // entries shall start from the beginning of the dataset.
+    /// Start returning entries from this start reference. If not specified,
+    /// entries shall start from the beginning of the dataset.
     #[yaserde(prefix = "tcr", rename = "StartReference")]
     pub start_reference: Option<String>,
 }
@@ -623,12 +623,12 @@ impl Validate for GetCredentialList {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct GetCredentialListResponse {
-    // StartReference to use in next call to get the following items. If
-    // absent, no more items to get.
+    /// StartReference to use in next call to get the following items. If
+    /// absent, no more items to get.
     #[yaserde(prefix = "tcr", rename = "NextStartReference")]
     pub next_start_reference: Option<String>,
 
-    // List of Credential items.
+    /// List of Credential items.
     #[yaserde(prefix = "tcr", rename = "Credential")]
     pub credential: Vec<Credential>,
 }
@@ -641,11 +641,11 @@ impl Validate for GetCredentialListResponse {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct CreateCredential {
-    // The credential to create.
+    /// The credential to create.
     #[yaserde(prefix = "tcr", rename = "Credential")]
     pub credential: Credential,
 
-    // The state of the credential.
+    /// The state of the credential.
     #[yaserde(prefix = "tcr", rename = "State")]
     pub state: CredentialState,
 }
@@ -658,7 +658,7 @@ impl Validate for CreateCredential {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct CreateCredentialResponse {
-    // The token of the created credential
+    /// The token of the created credential
     #[yaserde(prefix = "tcr", rename = "Token")]
     pub token: pt::ReferenceToken,
 }
@@ -671,7 +671,7 @@ impl Validate for CreateCredentialResponse {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct ModifyCredential {
-    // Details of the credential.
+    /// Details of the credential.
     #[yaserde(prefix = "tcr", rename = "Credential")]
     pub credential: Credential,
 }
@@ -693,7 +693,7 @@ impl Validate for ModifyCredentialResponse {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct SetCredential {
-    // Details of the credential.
+    /// Details of the credential.
     #[yaserde(prefix = "tcr", rename = "CredentialData")]
     pub credential_data: CredentialData,
 }
@@ -715,7 +715,7 @@ impl Validate for SetCredentialResponse {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct DeleteCredential {
-    // The token of the credential to delete.
+    /// The token of the credential to delete.
     #[yaserde(prefix = "tcr", rename = "Token")]
     pub token: pt::ReferenceToken,
 }
@@ -737,7 +737,7 @@ impl Validate for DeleteCredentialResponse {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct GetCredentialState {
-    // Token of Credential
+    /// Token of Credential
     #[yaserde(prefix = "tcr", rename = "Token")]
     pub token: pt::ReferenceToken,
 }
@@ -750,7 +750,7 @@ impl Validate for GetCredentialState {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct GetCredentialStateResponse {
-    // State of the credential.
+    /// State of the credential.
     #[yaserde(prefix = "tcr", rename = "State")]
     pub state: CredentialState,
 }
@@ -763,11 +763,11 @@ impl Validate for GetCredentialStateResponse {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct EnableCredential {
-    // The token of the credential
+    /// The token of the credential
     #[yaserde(prefix = "tcr", rename = "Token")]
     pub token: pt::ReferenceToken,
 
-    // Reason for enabling the credential.
+    /// Reason for enabling the credential.
     #[yaserde(prefix = "tcr", rename = "Reason")]
     pub reason: Option<pt::Name>,
 }
@@ -789,11 +789,11 @@ impl Validate for EnableCredentialResponse {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct DisableCredential {
-    // Token of the Credential
+    /// Token of the Credential
     #[yaserde(prefix = "tcr", rename = "Token")]
     pub token: pt::ReferenceToken,
 
-    // Reason for disabling the credential
+    /// Reason for disabling the credential
     #[yaserde(prefix = "tcr", rename = "Reason")]
     pub reason: Option<pt::Name>,
 }
@@ -815,7 +815,7 @@ impl Validate for DisableCredentialResponse {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct ResetAntipassbackViolation {
-    // Token of the Credential
+    /// Token of the Credential
     #[yaserde(prefix = "tcr", rename = "CredentialToken")]
     pub credential_token: pt::ReferenceToken,
 }
@@ -837,7 +837,7 @@ impl Validate for ResetAntipassbackViolationResponse {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct GetCredentialIdentifiers {
-    // Token of the Credential
+    /// Token of the Credential
     #[yaserde(prefix = "tcr", rename = "CredentialToken")]
     pub credential_token: pt::ReferenceToken,
 }
@@ -850,7 +850,7 @@ impl Validate for GetCredentialIdentifiers {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct GetCredentialIdentifiersResponse {
-    // Identifier of the credential
+    /// Identifier of the credential
     #[yaserde(prefix = "tcr", rename = "CredentialIdentifier")]
     pub credential_identifier: Vec<CredentialIdentifier>,
 }
@@ -863,11 +863,11 @@ impl Validate for GetCredentialIdentifiersResponse {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct SetCredentialIdentifier {
-    // Token of the Credential
+    /// Token of the Credential
     #[yaserde(prefix = "tcr", rename = "CredentialToken")]
     pub credential_token: pt::ReferenceToken,
 
-    // Identifier of the credential
+    /// Identifier of the credential
     #[yaserde(prefix = "tcr", rename = "CredentialIdentifier")]
     pub credential_identifier: CredentialIdentifier,
 }
@@ -889,11 +889,11 @@ impl Validate for SetCredentialIdentifierResponse {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct DeleteCredentialIdentifier {
-    // Token of the Credential
+    /// Token of the Credential
     #[yaserde(prefix = "tcr", rename = "CredentialToken")]
     pub credential_token: pt::ReferenceToken,
 
-    // Identifier type name of a credential
+    /// Identifier type name of a credential
     #[yaserde(prefix = "tcr", rename = "CredentialIdentifierTypeName")]
     pub credential_identifier_type_name: pt::Name,
 }
@@ -915,7 +915,7 @@ impl Validate for DeleteCredentialIdentifierResponse {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct GetCredentialAccessProfiles {
-    // Token of the Credential
+    /// Token of the Credential
     #[yaserde(prefix = "tcr", rename = "CredentialToken")]
     pub credential_token: pt::ReferenceToken,
 }
@@ -928,7 +928,7 @@ impl Validate for GetCredentialAccessProfiles {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct GetCredentialAccessProfilesResponse {
-    // Access Profiles of the credential
+    /// Access Profiles of the credential
     #[yaserde(prefix = "tcr", rename = "CredentialAccessProfile")]
     pub credential_access_profile: Vec<CredentialAccessProfile>,
 }
@@ -941,11 +941,11 @@ impl Validate for GetCredentialAccessProfilesResponse {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct SetCredentialAccessProfiles {
-    // Token of the Credential
+    /// Token of the Credential
     #[yaserde(prefix = "tcr", rename = "CredentialToken")]
     pub credential_token: pt::ReferenceToken,
 
-    // Access Profiles of the credential
+    /// Access Profiles of the credential
     #[yaserde(prefix = "tcr", rename = "CredentialAccessProfile")]
     pub credential_access_profile: Vec<CredentialAccessProfile>,
 }
@@ -967,11 +967,11 @@ impl Validate for SetCredentialAccessProfilesResponse {}
     namespace = "tcr: http://www.onvif.org/ver10/credential/wsdl"
 )]
 pub struct DeleteCredentialAccessProfiles {
-    // Token of the Credential
+    /// Token of the Credential
     #[yaserde(prefix = "tcr", rename = "CredentialToken")]
     pub credential_token: pt::ReferenceToken,
 
-    // Tokens of Access Profiles
+    /// Tokens of Access Profiles
     #[yaserde(prefix = "tcr", rename = "AccessProfileToken")]
     pub access_profile_token: Vec<pt::ReferenceToken>,
 }
@@ -987,7 +987,7 @@ pub struct DeleteCredentialAccessProfilesResponse {}
 
 impl Validate for DeleteCredentialAccessProfilesResponse {}
 
-// This operation returns the capabilities of the credential service.
+/// This operation returns the capabilities of the credential service.
 pub async fn get_service_capabilities<T: transport::Transport>(
     transport: &T,
     request: &GetServiceCapabilities,
@@ -995,9 +995,9 @@ pub async fn get_service_capabilities<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This method returns all the supported format types of a specified identifier
-// type that is supported by
-// the device.
+/// This method returns all the supported format types of a specified identifier
+/// type that is supported by
+/// the device.
 pub async fn get_supported_format_types<T: transport::Transport>(
     transport: &T,
     request: &GetSupportedFormatTypes,
@@ -1005,14 +1005,14 @@ pub async fn get_supported_format_types<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation requests a list of CredentialInfo items matching the given
-// tokens.
-// The device shall ignore tokens it cannot resolve and shall return an empty
-// list if there are no
-// items matching the specified tokens. The device shall not return a fault in
-// this case.
-// If the number of requested items is greater than MaxLimit, a TooManyItems
-// fault shall be returned.
+/// This operation requests a list of CredentialInfo items matching the given
+/// tokens.
+/// The device shall ignore tokens it cannot resolve and shall return an empty
+/// list if there are no
+/// items matching the specified tokens. The device shall not return a fault in
+/// this case.
+/// If the number of requested items is greater than MaxLimit, a TooManyItems
+/// fault shall be returned.
 pub async fn get_credential_info<T: transport::Transport>(
     transport: &T,
     request: &GetCredentialInfo,
@@ -1020,14 +1020,14 @@ pub async fn get_credential_info<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation requests a list of all CredentialInfo items provided by the
-// device.
-// A call to this method shall return a StartReference when not all data is
-// returned and more data is available.
-// The reference shall be valid for retrieving the next set of data. Please
-// refer to section 4.8.3 in
-// [ONVIF Access Control Service Specification] for more details.
-// The number of items returned shall not be greater than the Limit parameter.
+/// This operation requests a list of all CredentialInfo items provided by the
+/// device.
+/// A call to this method shall return a StartReference when not all data is
+/// returned and more data is available.
+/// The reference shall be valid for retrieving the next set of data. Please
+/// refer to section 4.8.3 in
+/// [ONVIF Access Control Service Specification] for more details.
+/// The number of items returned shall not be greater than the Limit parameter.
 pub async fn get_credential_info_list<T: transport::Transport>(
     transport: &T,
     request: &GetCredentialInfoList,
@@ -1035,13 +1035,13 @@ pub async fn get_credential_info_list<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation returns the specified credential items matching the given
-// tokens.
-// The device shall ignore tokens it cannot resolve and shall return an empty
-// list if there are no items
-// matching specified tokens. The device shall not return a fault in this case.
-// If the number of requested items is greater than MaxLimit, a TooManyItems
-// fault shall be returned.
+/// This operation returns the specified credential items matching the given
+/// tokens.
+/// The device shall ignore tokens it cannot resolve and shall return an empty
+/// list if there are no items
+/// matching specified tokens. The device shall not return a fault in this case.
+/// If the number of requested items is greater than MaxLimit, a TooManyItems
+/// fault shall be returned.
 pub async fn get_credentials<T: transport::Transport>(
     transport: &T,
     request: &GetCredentials,
@@ -1049,15 +1049,15 @@ pub async fn get_credentials<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation requests a list of all credential items provided by the
-// device.
-// A call to this method shall return a StartReference when not all data is
-// returned and more data is
-// available. The reference shall be valid for retrieving the next set of data.
-// Please refer section 4.8.3
-// in [Access Control Service Specification] for more details. The number of
-// items returned shall not be
-// greater the Limit parameter.
+/// This operation requests a list of all credential items provided by the
+/// device.
+/// A call to this method shall return a StartReference when not all data is
+/// returned and more data is
+/// available. The reference shall be valid for retrieving the next set of data.
+/// Please refer section 4.8.3
+/// in [Access Control Service Specification] for more details. The number of
+/// items returned shall not be
+/// greater the Limit parameter.
 pub async fn get_credential_list<T: transport::Transport>(
     transport: &T,
     request: &GetCredentialList,
@@ -1065,15 +1065,15 @@ pub async fn get_credential_list<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation creates a credential. A call to this method takes a credential
-// structure and a credential
-// state structure as input parameters. The credential state can be created in
-// disabled or enabled state.
-// The token field of the credential shall be empty, the device shall allocate a
-// token for the credential.
-// The allocated token shall be returned in the response. If the client sends
-// any value in the token field,
-// the device shall return InvalidArgVal as generic fault code.
+/// This operation creates a credential. A call to this method takes a credential
+/// structure and a credential
+/// state structure as input parameters. The credential state can be created in
+/// disabled or enabled state.
+/// The token field of the credential shall be empty, the device shall allocate a
+/// token for the credential.
+/// The allocated token shall be returned in the response. If the client sends
+/// any value in the token field,
+/// the device shall return InvalidArgVal as generic fault code.
 pub async fn create_credential<T: transport::Transport>(
     transport: &T,
     request: &CreateCredential,
@@ -1081,7 +1081,7 @@ pub async fn create_credential<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This method is used to synchronize a credential in a client with the device.
+/// This method is used to synchronize a credential in a client with the device.
 pub async fn set_credential<T: transport::Transport>(
     transport: &T,
     request: &SetCredential,
@@ -1089,19 +1089,19 @@ pub async fn set_credential<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation modifies the specified credential.
-// The token of the credential to modify is specified in the token field of the
-// Credential structure and
-// shall not be empty. All other fields in the structure shall overwrite the
-// fields in the specified credential.
-// When an existing credential is modified, the state is not modified
-// explicitly. The only way for a client to
-// change the state of a credential is to explicitly call the EnableCredential,
-// DisableCredential or
-// ResetAntipassback command.
-// All existing credential identifiers and credential access profiles are
-// removed and replaced with the
-// specified entities.
+/// This operation modifies the specified credential.
+/// The token of the credential to modify is specified in the token field of the
+/// Credential structure and
+/// shall not be empty. All other fields in the structure shall overwrite the
+/// fields in the specified credential.
+/// When an existing credential is modified, the state is not modified
+/// explicitly. The only way for a client to
+/// change the state of a credential is to explicitly call the EnableCredential,
+/// DisableCredential or
+/// ResetAntipassback command.
+/// All existing credential identifiers and credential access profiles are
+/// removed and replaced with the
+/// specified entities.
 pub async fn modify_credential<T: transport::Transport>(
     transport: &T,
     request: &ModifyCredential,
@@ -1109,10 +1109,10 @@ pub async fn modify_credential<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This method deletes the specified credential.
-// If it is associated with one or more entities some devices may not be able to
-// delete the credential,
-// and consequently a ReferenceInUse fault shall be generated.
+/// This method deletes the specified credential.
+/// If it is associated with one or more entities some devices may not be able to
+/// delete the credential,
+/// and consequently a ReferenceInUse fault shall be generated.
 pub async fn delete_credential<T: transport::Transport>(
     transport: &T,
     request: &DeleteCredential,
@@ -1120,10 +1120,10 @@ pub async fn delete_credential<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This method returns the state for the specified credential.
-// If the capability ResetAntipassbackSupported is set to true, then the device
-// shall supply the
-// anti-passback state in the returned credential state structure.
+/// This method returns the state for the specified credential.
+/// If the capability ResetAntipassbackSupported is set to true, then the device
+/// shall supply the
+/// anti-passback state in the returned credential state structure.
 pub async fn get_credential_state<T: transport::Transport>(
     transport: &T,
     request: &GetCredentialState,
@@ -1131,7 +1131,7 @@ pub async fn get_credential_state<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This method is used to enable a credential.
+/// This method is used to enable a credential.
 pub async fn enable_credential<T: transport::Transport>(
     transport: &T,
     request: &EnableCredential,
@@ -1139,7 +1139,7 @@ pub async fn enable_credential<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This method is used to disable a credential.
+/// This method is used to disable a credential.
 pub async fn disable_credential<T: transport::Transport>(
     transport: &T,
     request: &DisableCredential,
@@ -1147,8 +1147,8 @@ pub async fn disable_credential<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This method is used to reset anti-passback violations for a specified
-// credential.
+/// This method is used to reset anti-passback violations for a specified
+/// credential.
 pub async fn reset_antipassback_violation<T: transport::Transport>(
     transport: &T,
     request: &ResetAntipassbackViolation,
@@ -1156,7 +1156,7 @@ pub async fn reset_antipassback_violation<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This method returns all the credential identifiers for a credential.
+/// This method returns all the credential identifiers for a credential.
 pub async fn get_credential_identifiers<T: transport::Transport>(
     transport: &T,
     request: &GetCredentialIdentifiers,
@@ -1164,10 +1164,10 @@ pub async fn get_credential_identifiers<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation creates or updates a credential identifier for a credential.
-// If the type of specified credential identifier already exists, the current
-// credential identifier of that
-// type is replaced. Otherwise the credential identifier is added.
+/// This operation creates or updates a credential identifier for a credential.
+/// If the type of specified credential identifier already exists, the current
+/// credential identifier of that
+/// type is replaced. Otherwise the credential identifier is added.
 pub async fn set_credential_identifier<T: transport::Transport>(
     transport: &T,
     request: &SetCredentialIdentifier,
@@ -1175,10 +1175,10 @@ pub async fn set_credential_identifier<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This method deletes all the identifier values for the specified type.
-// However, if the identifier type
-// name doesn’t exist in the device, it will be silently ignored without any
-// response.
+/// This method deletes all the identifier values for the specified type.
+/// However, if the identifier type
+/// name doesn’t exist in the device, it will be silently ignored without any
+/// response.
 pub async fn delete_credential_identifier<T: transport::Transport>(
     transport: &T,
     request: &DeleteCredentialIdentifier,
@@ -1186,7 +1186,7 @@ pub async fn delete_credential_identifier<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This method returns all the credential access profiles for a credential.
+/// This method returns all the credential access profiles for a credential.
 pub async fn get_credential_access_profiles<T: transport::Transport>(
     transport: &T,
     request: &GetCredentialAccessProfiles,
@@ -1194,12 +1194,12 @@ pub async fn get_credential_access_profiles<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation add or updates the credential access profiles for a
-// credential.
-// The device shall update the credential access profile if the access profile
-// token in the specified
-// credential access profile matches. Otherwise the credential access profile is
-// added.
+/// This operation add or updates the credential access profiles for a
+/// credential.
+/// The device shall update the credential access profile if the access profile
+/// token in the specified
+/// credential access profile matches. Otherwise the credential access profile is
+/// added.
 pub async fn set_credential_access_profiles<T: transport::Transport>(
     transport: &T,
     request: &SetCredentialAccessProfiles,
@@ -1207,11 +1207,11 @@ pub async fn set_credential_access_profiles<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This method deletes all the credential access profiles for the specified
-// tokens.
-// However, if no matching credential access profiles are found, the
-// corresponding access profile tokens
-// are silently ignored without any response.
+/// This method deletes all the credential access profiles for the specified
+/// tokens.
+/// However, if no matching credential access profiles are found, the
+/// corresponding access profile tokens
+/// are silently ignored without any response.
 pub async fn delete_credential_access_profiles<T: transport::Transport>(
     transport: &T,
     request: &DeleteCredentialAccessProfiles,
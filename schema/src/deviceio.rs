@@ -20,8 +20,8 @@ impl Validate for GetServiceCapabilities {}
     namespace = "tmd: http://www.onvif.org/ver10/deviceIO/wsdl"
 )]
 pub struct GetServiceCapabilitiesResponse {
-    // The capabilities for the device IO service is returned in the
-    // Capabilities element.
+    /// The capabilities for the device IO service is returned in the
+    /// Capabilities element.
     #[yaserde(prefix = "tmd", rename = "Capabilities")]
     pub capabilities: Capabilities,
 }
@@ -34,36 +34,36 @@ impl Validate for GetServiceCapabilitiesResponse {}
     namespace = "tmd: http://www.onvif.org/ver10/deviceIO/wsdl"
 )]
 pub struct Capabilities {
-    // Number of video sources (defaults to none).
+    /// Number of video sources (defaults to none).
     #[yaserde(attribute, rename = "VideoSources")]
     pub video_sources: Option<i32>,
 
-    // Number of video outputs (defaults to none).
+    /// Number of video outputs (defaults to none).
     #[yaserde(attribute, rename = "VideoOutputs")]
     pub video_outputs: Option<i32>,
 
-    // Number of audio sources (defaults to none).
+    /// Number of audio sources (defaults to none).
     #[yaserde(attribute, rename = "AudioSources")]
     pub audio_sources: Option<i32>,
 
-    // Number of audio outputs (defaults to none).
+    /// Number of audio outputs (defaults to none).
     #[yaserde(attribute, rename = "AudioOutputs")]
     pub audio_outputs: Option<i32>,
 
-    // Number of relay outputs (defaults to none).
+    /// Number of relay outputs (defaults to none).
     #[yaserde(attribute, rename = "RelayOutputs")]
     pub relay_outputs: Option<i32>,
 
-    // Number of serial ports (defaults to none).
+    /// Number of serial ports (defaults to none).
     #[yaserde(attribute, rename = "SerialPorts")]
     pub serial_ports: Option<i32>,
 
-    // Number of digital inputs (defaults to none).
+    /// Number of digital inputs (defaults to none).
     #[yaserde(attribute, rename = "DigitalInputs")]
     pub digital_inputs: Option<i32>,
 
-    // Indicates support for DigitalInput configuration of the idle state
-    // (defaults to false).
+    /// Indicates support for DigitalInput configuration of the idle state
+    /// (defaults to false).
     #[yaserde(attribute, rename = "DigitalInputOptions")]
     pub digital_input_options: Option<bool>,
 }
@@ -77,8 +77,8 @@ impl Validate for Capabilities {}
     namespace = "tmd: http://www.onvif.org/ver10/deviceIO/wsdl"
 )]
 pub struct GetRelayOutputOptions {
-    // Optional reference token to the relay for which the options are
-    // requested.
+    /// Optional reference token to the relay for which the options are
+    /// requested.
     #[yaserde(prefix = "tmd", rename = "RelayOutputToken")]
     pub relay_output_token: Option<tt::ReferenceToken>,
 }
@@ -91,7 +91,7 @@ impl Validate for GetRelayOutputOptions {}
     namespace = "tmd: http://www.onvif.org/ver10/deviceIO/wsdl"
 )]
 pub struct GetRelayOutputOptionsResponse {
-    // Valid values and ranges for the configuration of a relay output.
+    /// Valid values and ranges for the configuration of a relay output.
     #[yaserde(prefix = "tmd", rename = "RelayOutputOptions")]
     pub relay_output_options: Vec<RelayOutputOptions>,
 }
@@ -104,24 +104,24 @@ impl Validate for GetRelayOutputOptionsResponse {}
     namespace = "tmd: http://www.onvif.org/ver10/deviceIO/wsdl"
 )]
 pub struct RelayOutputOptions {
-    // Supported Modes.
+    /// Supported Modes.
     #[yaserde(prefix = "tmd", rename = "Mode")]
     pub mode: Vec<tt::RelayMode>,
 
-    // Supported delay time range or discrete values in seconds. This element
-    // must be present if MonoStable mode is supported.
+    /// Supported delay time range or discrete values in seconds. This element
+    /// must be present if MonoStable mode is supported.
     #[yaserde(prefix = "tmd", rename = "DelayTimes")]
     pub delay_times: Option<DelayTimes>,
 
-    // True if the relay only supports the exact values for the DelayTimes
-    // listed. Default is false.
+    /// True if the relay only supports the exact values for the DelayTimes
+    /// listed. Default is false.
     #[yaserde(prefix = "tmd", rename = "Discrete")]
     pub discrete: Option<bool>,
 
     #[yaserde(prefix = "tmd", rename = "Extension")]
     pub extension: Option<RelayOutputOptionsExtension>,
 
-    // Token of the relay output.
+    /// Token of the relay output.
     #[yaserde(attribute, rename = "token")]
     pub token: tt::ReferenceToken,
 }
@@ -156,7 +156,7 @@ impl Validate for Get {}
     namespace = "tmd: http://www.onvif.org/ver10/deviceIO/wsdl"
 )]
 pub struct GetResponse {
-    // List tokens of a physical IO of a device.
+    /// List tokens of a physical IO of a device.
     #[yaserde(prefix = "tmd", rename = "Token")]
     pub token: Vec<tt::ReferenceToken>,
 }
@@ -184,7 +184,7 @@ impl Validate for GetVideoOutputs {}
     namespace = "tmd: http://www.onvif.org/ver10/deviceIO/wsdl"
 )]
 pub struct GetVideoOutputsResponse {
-    // List containing all physical Video output connections of a device.
+    /// List containing all physical Video output connections of a device.
     #[yaserde(prefix = "tmd", rename = "VideoOutputs")]
     pub video_outputs: Vec<tt::VideoOutput>,
 }
@@ -197,7 +197,7 @@ impl Validate for GetVideoOutputsResponse {}
     namespace = "tmd: http://www.onvif.org/ver10/deviceIO/wsdl"
 )]
 pub struct GetAudioSourceConfiguration {
-    // Token of the requested AudioSource.
+    /// Token of the requested AudioSource.
     #[yaserde(prefix = "tmd", rename = "AudioSourceToken")]
     pub audio_source_token: tt::ReferenceToken,
 }
@@ -210,7 +210,7 @@ impl Validate for GetAudioSourceConfiguration {}
     namespace = "tmd: http://www.onvif.org/ver10/deviceIO/wsdl"
 )]
 pub struct GetAudioSourceConfigurationResponse {
-    // Current configuration of the Audio input.
+    /// Current configuration of the Audio input.
     #[yaserde(prefix = "tmd", rename = "AudioSourceConfiguration")]
     pub audio_source_configuration: tt::AudioSourceConfiguration,
 }
@@ -223,7 +223,7 @@ impl Validate for GetAudioSourceConfigurationResponse {}
     namespace = "tmd: http://www.onvif.org/ver10/deviceIO/wsdl"
 )]
 pub struct GetAudioOutputConfiguration {
-    // Token of the physical Audio output.
+    /// Token of the physical Audio output.
     #[yaserde(prefix = "tmd", rename = "AudioOutputToken")]
     pub audio_output_token: tt::ReferenceToken,
 }
@@ -236,7 +236,7 @@ impl Validate for GetAudioOutputConfiguration {}
     namespace = "tmd: http://www.onvif.org/ver10/deviceIO/wsdl"
 )]
 pub struct GetAudioOutputConfigurationResponse {
-    // Current configuration of the Audio output.
+    /// Current configuration of the Audio output.
     #[yaserde(prefix = "tmd", rename = "AudioOutputConfiguration")]
     pub audio_output_configuration: tt::AudioOutputConfiguration,
 }
@@ -249,7 +249,7 @@ impl Validate for GetAudioOutputConfigurationResponse {}
     namespace = "tmd: http://www.onvif.org/ver10/deviceIO/wsdl"
 )]
 pub struct GetVideoSourceConfiguration {
-    // Token of the requested VideoSource.
+    /// Token of the requested VideoSource.
     #[yaserde(prefix = "tmd", rename = "VideoSourceToken")]
     pub video_source_token: tt::ReferenceToken,
 }
@@ -262,7 +262,7 @@ impl Validate for GetVideoSourceConfiguration {}
     namespace = "tmd: http://www.onvif.org/ver10/deviceIO/wsdl"
 )]
 pub struct GetVideoSourceConfigurationResponse {
-    // Current configuration of the Video input.
+    /// Current configuration of the Video input.
     #[yaserde(prefix = "tmd", rename = "VideoSourceConfiguration")]
     pub video_source_configuration: tt::VideoSourceConfiguration,
 }
@@ -275,7 +275,7 @@ impl Validate for GetVideoSourceConfigurationResponse {}
     namespace = "tmd: http://www.onvif.org/ver10/deviceIO/wsdl"
 )]
 pub struct GetVideoOutputConfiguration {
-    // Token of the requested VideoOutput.
+    /// Token of the requested VideoOutput.
     #[yaserde(prefix = "tmd", rename = "VideoOutputToken")]
     pub video_output_token: tt::ReferenceToken,
 }
@@ -288,7 +288,7 @@ impl Validate for GetVideoOutputConfiguration {}
     namespace = "tmd: http://www.onvif.org/ver10/deviceIO/wsdl"
 )]
 pub struct GetVideoOutputConfigurationResponse {
-    // Current configuration of the Video output.
+    /// Current configuration of the Video output.
     #[yaserde(prefix = "tmd", rename = "VideoOutputConfiguration")]
     pub video_output_configuration: tt::VideoOutputConfiguration,
 }
@@ -304,10 +304,10 @@ pub struct SetAudioSourceConfiguration {
     #[yaserde(prefix = "tmd", rename = "Configuration")]
     pub configuration: tt::AudioSourceConfiguration,
 
-    // The ForcePersistence element determines how configuration
-    // changes shall be stored. If true, changes shall be persistent. If false,
-    // changes MAY revert to previous values
-    // after reboot.
+    /// The ForcePersistence element determines how configuration
+    /// changes shall be stored. If true, changes shall be persistent. If false,
+    /// changes MAY revert to previous values
+    /// after reboot.
     #[yaserde(prefix = "tmd", rename = "ForcePersistence")]
     pub force_persistence: bool,
 }
@@ -332,10 +332,10 @@ pub struct SetAudioOutputConfiguration {
     #[yaserde(prefix = "tmd", rename = "Configuration")]
     pub configuration: tt::AudioOutputConfiguration,
 
-    // The ForcePersistence element determines how configuration
-    // changes shall be stored. If true, changes shall be persistent. If false,
-    // changes MAY revert to previous values
-    // after reboot.
+    /// The ForcePersistence element determines how configuration
+    /// changes shall be stored. If true, changes shall be persistent. If false,
+    /// changes MAY revert to previous values
+    /// after reboot.
     #[yaserde(prefix = "tmd", rename = "ForcePersistence")]
     pub force_persistence: bool,
 }
@@ -360,10 +360,10 @@ pub struct SetVideoSourceConfiguration {
     #[yaserde(prefix = "tmd", rename = "Configuration")]
     pub configuration: tt::VideoSourceConfiguration,
 
-    // The ForcePersistence element determines how configuration
-    // changes shall be stored. If true, changes shall be persistent. If false,
-    // changes MAY revert to previous values
-    // after reboot.
+    /// The ForcePersistence element determines how configuration
+    /// changes shall be stored. If true, changes shall be persistent. If false,
+    /// changes MAY revert to previous values
+    /// after reboot.
     #[yaserde(prefix = "tmd", rename = "ForcePersistence")]
     pub force_persistence: bool,
 }
@@ -388,10 +388,10 @@ pub struct SetVideoOutputConfiguration {
     #[yaserde(prefix = "tmd", rename = "Configuration")]
     pub configuration: tt::VideoOutputConfiguration,
 
-    // The ForcePersistence element determines how configuration
-    // changes shall be stored. If true, changes shall be persistent. If false,
-    // changes MAY revert to previous values
-    // after reboot.
+    /// The ForcePersistence element determines how configuration
+    /// changes shall be stored. If true, changes shall be persistent. If false,
+    /// changes MAY revert to previous values
+    /// after reboot.
     #[yaserde(prefix = "tmd", rename = "ForcePersistence")]
     pub force_persistence: bool,
 }
@@ -413,7 +413,7 @@ impl Validate for SetVideoOutputConfigurationResponse {}
     namespace = "tmd: http://www.onvif.org/ver10/deviceIO/wsdl"
 )]
 pub struct GetVideoSourceConfigurationOptions {
-    // Token of the Video input whose options are requested..
+    /// Token of the Video input whose options are requested..
     #[yaserde(prefix = "tmd", rename = "VideoSourceToken")]
     pub video_source_token: tt::ReferenceToken,
 }
@@ -438,7 +438,7 @@ impl Validate for GetVideoSourceConfigurationOptionsResponse {}
     namespace = "tmd: http://www.onvif.org/ver10/deviceIO/wsdl"
 )]
 pub struct GetVideoOutputConfigurationOptions {
-    // Token of the Video Output whose options are requested..
+    /// Token of the Video Output whose options are requested..
     #[yaserde(prefix = "tmd", rename = "VideoOutputToken")]
     pub video_output_token: tt::ReferenceToken,
 }
@@ -463,7 +463,7 @@ impl Validate for GetVideoOutputConfigurationOptionsResponse {}
     namespace = "tmd: http://www.onvif.org/ver10/deviceIO/wsdl"
 )]
 pub struct GetAudioSourceConfigurationOptions {
-    // Token of the physical Audio input whose options are requested..
+    /// Token of the physical Audio input whose options are requested..
     #[yaserde(prefix = "tmd", rename = "AudioSourceToken")]
     pub audio_source_token: tt::ReferenceToken,
 }
@@ -476,7 +476,7 @@ impl Validate for GetAudioSourceConfigurationOptions {}
     namespace = "tmd: http://www.onvif.org/ver10/deviceIO/wsdl"
 )]
 pub struct GetAudioSourceConfigurationOptionsResponse {
-    // Returns the AudioSourceToken available.
+    /// Returns the AudioSourceToken available.
     #[yaserde(prefix = "tmd", rename = "AudioSourceOptions")]
     pub audio_source_options: tt::AudioSourceConfigurationOptions,
 }
@@ -489,7 +489,7 @@ impl Validate for GetAudioSourceConfigurationOptionsResponse {}
     namespace = "tmd: http://www.onvif.org/ver10/deviceIO/wsdl"
 )]
 pub struct GetAudioOutputConfigurationOptions {
-    // Token of the physical Audio Output whose options are requested..
+    /// Token of the physical Audio Output whose options are requested..
     #[yaserde(prefix = "tmd", rename = "AudioOutputToken")]
     pub audio_output_token: tt::ReferenceToken,
 }
@@ -502,7 +502,7 @@ impl Validate for GetAudioOutputConfigurationOptions {}
     namespace = "tmd: http://www.onvif.org/ver10/deviceIO/wsdl"
 )]
 pub struct GetAudioOutputConfigurationOptionsResponse {
-    // Available settings and ranges for the requested Audio output.
+    /// Available settings and ranges for the requested Audio output.
     #[yaserde(prefix = "tmd", rename = "AudioOutputOptions")]
     pub audio_output_options: tt::AudioOutputConfigurationOptions,
 }
@@ -557,7 +557,7 @@ impl Validate for GetDigitalInputsResponse {}
     namespace = "tmd: http://www.onvif.org/ver10/deviceIO/wsdl"
 )]
 pub struct DigitalInputConfigurationOptions {
-    // Configuration Options for a digital input.
+    /// Configuration Options for a digital input.
     #[yaserde(prefix = "tmd", rename = "IdleState")]
     pub idle_state: Vec<tt::DigitalIdleState>,
 }
@@ -708,33 +708,33 @@ impl Validate for GetSerialPortConfigurationOptionsResponse {}
     namespace = "tmd: http://www.onvif.org/ver10/deviceIO/wsdl"
 )]
 pub struct SendReceiveSerialCommand {
-    // The physical serial port reference to be used when this request is
-    // invoked.
+    /// The physical serial port reference to be used when this request is
+    /// invoked.
     #[yaserde(prefix = "tmd", rename = "Token")]
     pub token: Option<tt::ReferenceToken>,
 
-    // The serial port data.
+    /// The serial port data.
     #[yaserde(prefix = "tmd", rename = "SerialData")]
     pub serial_data: Option<SerialData>,
 
-    // Indicates that the command should be responded back within the specified
-    // period of time.
+    /// Indicates that the command should be responded back within the specified
+    /// period of time.
     #[yaserde(prefix = "tmd", rename = "TimeOut")]
     pub time_out: Option<xs::Duration>,
 
-    // This element may be put in the case that data length returned from the
-    // connected serial device is already determined as some fixed bytes length.
-    // It indicates the length of received data which can be regarded as
-    // available.
+    /// This element may be put in the case that data length returned from the
+    /// connected serial device is already determined as some fixed bytes length.
+    /// It indicates the length of received data which can be regarded as
+    /// available.
     #[yaserde(prefix = "tmd", rename = "DataLength")]
     pub data_length: Option<xs::Integer>,
 
-    // This element may be put in the case that the delimiter codes returned
-    // from the connected serial device is already known. It indicates the
-    // termination data sequence of the responded data. In case the string has
-    // more than one character a device shall interpret the whole string as a
-    // single delimiter. Furthermore a device shall return the delimiter
-    // character(s) to the client.
+    /// This element may be put in the case that the delimiter codes returned
+    /// from the connected serial device is already known. It indicates the
+    /// termination data sequence of the responded data. In case the string has
+    /// more than one character a device shall interpret the whole string as a
+    /// single delimiter. Furthermore a device shall return the delimiter
+    /// character(s) to the client.
     #[yaserde(prefix = "tmd", rename = "Delimiter")]
     pub delimiter: Option<String>,
 }
@@ -754,6 +754,7 @@ pub struct SendReceiveSerialCommandResponse {
 impl Validate for SendReceiveSerialCommandResponse {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[non_exhaustive]
 pub enum SerialDataChoice {
     Binary(String),
     String(String),
@@ -768,7 +769,7 @@ impl Default for SerialDataChoice {
 
 impl Validate for SerialDataChoice {}
 
-// The serial port data.
+/// The serial port data.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tmd",
@@ -792,9 +793,10 @@ pub struct SerialPort {
 
 impl Validate for SerialPort {}
 
-// The type of serial port.Generic can be signaled as a vendor specific serial
-// port type.
+/// The type of serial port.Generic can be signaled as a vendor specific serial
+/// port type.
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[non_exhaustive]
 pub enum SerialPortType {
     #[yaserde(rename = "RS232")]
     Rs232,
@@ -818,26 +820,26 @@ impl Default for SerialPortType {
 
 impl Validate for SerialPortType {}
 
-// The parameters for configuring the serial port.
+/// The parameters for configuring the serial port.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tmd",
     namespace = "tmd: http://www.onvif.org/ver10/deviceIO/wsdl"
 )]
 pub struct SerialPortConfiguration {
-    // The transfer bitrate.
+    /// The transfer bitrate.
     #[yaserde(prefix = "tmd", rename = "BaudRate")]
     pub baud_rate: i32,
 
-    // The parity for the data error detection.
+    /// The parity for the data error detection.
     #[yaserde(prefix = "tmd", rename = "ParityBit")]
     pub parity_bit: ParityBit,
 
-    // The bit length for each character.
+    /// The bit length for each character.
     #[yaserde(prefix = "tmd", rename = "CharacterLength")]
     pub character_length: i32,
 
-    // The number of stop bits used to terminate each character.
+    /// The number of stop bits used to terminate each character.
     #[yaserde(prefix = "tmd", rename = "StopBit")]
     pub stop_bit: f64,
 
@@ -850,8 +852,9 @@ pub struct SerialPortConfiguration {
 
 impl Validate for SerialPortConfiguration {}
 
-// The parity for the data error detection.
+/// The parity for the data error detection.
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[non_exhaustive]
 pub enum ParityBit {
     None,
     Even,
@@ -870,27 +873,27 @@ impl Default for ParityBit {
 
 impl Validate for ParityBit {}
 
-// The configuration options that relates to serial port.
+/// The configuration options that relates to serial port.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tmd",
     namespace = "tmd: http://www.onvif.org/ver10/deviceIO/wsdl"
 )]
 pub struct SerialPortConfigurationOptions {
-    // The list of configurable transfer bitrate.
+    /// The list of configurable transfer bitrate.
     #[yaserde(prefix = "tmd", rename = "BaudRateList")]
     pub baud_rate_list: tt::IntList,
 
-    // The list of configurable parity for the data error detection.
+    /// The list of configurable parity for the data error detection.
     #[yaserde(prefix = "tmd", rename = "ParityBitList")]
     pub parity_bit_list: ParityBitList,
 
-    // The list of configurable bit length for each character.
+    /// The list of configurable bit length for each character.
     #[yaserde(prefix = "tmd", rename = "CharacterLengthList")]
     pub character_length_list: tt::IntList,
 
-    // The list of configurable number of stop bits used to terminate each
-    // character.
+    /// The list of configurable number of stop bits used to terminate each
+    /// character.
     #[yaserde(prefix = "tmd", rename = "StopBitList")]
     pub stop_bit_list: tt::FloatList,
 
@@ -900,7 +903,7 @@ pub struct SerialPortConfigurationOptions {
 
 impl Validate for SerialPortConfigurationOptions {}
 
-// The list of configurable parity for the data error detection.
+/// The list of configurable parity for the data error detection.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tmd",
@@ -913,8 +916,8 @@ pub struct ParityBitList {
 
 impl Validate for ParityBitList {}
 
-// Returns the capabilities of the device IO service. The result is returned in
-// a typed answer.
+/// Returns the capabilities of the device IO service. The result is returned in
+/// a typed answer.
 pub async fn get_service_capabilities<T: transport::Transport>(
     transport: &T,
     request: &GetServiceCapabilities,
@@ -922,8 +925,8 @@ pub async fn get_service_capabilities<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Request the available settings and ranges for one or all relay outputs. A
-// device that has one or more RelayOutputs should support this command.
+/// Request the available settings and ranges for one or all relay outputs. A
+/// device that has one or more RelayOutputs should support this command.
 pub async fn get_relay_output_options<T: transport::Transport>(
     transport: &T,
     request: &GetRelayOutputOptions,
@@ -931,9 +934,9 @@ pub async fn get_relay_output_options<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// List all available audio sources for the device. The device that has one or
-// more audio sources shall support the listing of available audio inputs
-// through the GetAudioSources command.
+/// List all available audio sources for the device. The device that has one or
+/// more audio sources shall support the listing of available audio inputs
+/// through the GetAudioSources command.
 pub async fn get_audio_sources<T: transport::Transport>(
     transport: &T,
     request: &GetAudioSources,
@@ -941,9 +944,9 @@ pub async fn get_audio_sources<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// List all available audio outputs of a device. A device that has one ore more
-// physical audio outputs shall support listing of available audio outputs
-// through the GetAudioOutputs command.
+/// List all available audio outputs of a device. A device that has one ore more
+/// physical audio outputs shall support listing of available audio outputs
+/// through the GetAudioOutputs command.
 pub async fn get_audio_outputs<T: transport::Transport>(
     transport: &T,
     request: &GetAudioOutputs,
@@ -951,9 +954,9 @@ pub async fn get_audio_outputs<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// List all available video sources for the device. The device that has one or
-// more video inputs shall support the listing of available video sources
-// through the GetVideoSources command.
+/// List all available video sources for the device. The device that has one or
+/// more video inputs shall support the listing of available video sources
+/// through the GetVideoSources command.
 pub async fn get_video_sources<T: transport::Transport>(
     transport: &T,
     request: &GetVideoSources,
@@ -961,9 +964,9 @@ pub async fn get_video_sources<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// List all available video outputs of a device. A device that has one or more
-// physical video outputs shall support listing of available video outputs
-// through the GetVideoOutputs command.
+/// List all available video outputs of a device. A device that has one or more
+/// physical video outputs shall support listing of available video outputs
+/// through the GetVideoOutputs command.
 pub async fn get_video_outputs<T: transport::Transport>(
     transport: &T,
     request: &GetVideoOutputs,
@@ -971,8 +974,8 @@ pub async fn get_video_outputs<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Get the video source configurations of a VideoSource. A device with one or
-// more video sources shall support the GetVideoSourceConfigurations command.
+/// Get the video source configurations of a VideoSource. A device with one or
+/// more video sources shall support the GetVideoSourceConfigurations command.
 pub async fn get_video_source_configuration<T: transport::Transport>(
     transport: &T,
     request: &GetVideoSourceConfiguration,
@@ -980,9 +983,9 @@ pub async fn get_video_source_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Get the configuration of a Video Output. A device that has one or more Video
-// Outputs shall support the retrieval of the VideoOutputConfiguration through
-// this command.
+/// Get the configuration of a Video Output. A device that has one or more Video
+/// Outputs shall support the retrieval of the VideoOutputConfiguration through
+/// this command.
 pub async fn get_video_output_configuration<T: transport::Transport>(
     transport: &T,
     request: &GetVideoOutputConfiguration,
@@ -990,8 +993,8 @@ pub async fn get_video_output_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// List the configuration of an Audio Input. A device with one or more audio
-// inputs shall support the GetAudioSourceConfiguration command.
+/// List the configuration of an Audio Input. A device with one or more audio
+/// inputs shall support the GetAudioSourceConfiguration command.
 pub async fn get_audio_source_configuration<T: transport::Transport>(
     transport: &T,
     request: &GetAudioSourceConfiguration,
@@ -999,9 +1002,9 @@ pub async fn get_audio_source_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Request the current configuration of a physical Audio output. A device that
-// has one or more AudioOutputs shall support the retrieval of the
-// AudioOutputConfiguration through this command.
+/// Request the current configuration of a physical Audio output. A device that
+/// has one or more AudioOutputs shall support the retrieval of the
+/// AudioOutputConfiguration through this command.
 pub async fn get_audio_output_configuration<T: transport::Transport>(
     transport: &T,
     request: &GetAudioOutputConfiguration,
@@ -1009,9 +1012,9 @@ pub async fn get_audio_output_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Modify a video input configuration. A device that has one or more video
-// sources shall support the setting of the VideoSourceConfiguration through
-// this command.
+/// Modify a video input configuration. A device that has one or more video
+/// sources shall support the setting of the VideoSourceConfiguration through
+/// this command.
 pub async fn set_video_source_configuration<T: transport::Transport>(
     transport: &T,
     request: &SetVideoSourceConfiguration,
@@ -1019,9 +1022,9 @@ pub async fn set_video_source_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Modify a video output configuration. A device that has one or more video
-// outputs shall support the setting of its video output configuration through
-// this command.
+/// Modify a video output configuration. A device that has one or more video
+/// outputs shall support the setting of its video output configuration through
+/// this command.
 pub async fn set_video_output_configuration<T: transport::Transport>(
     transport: &T,
     request: &SetVideoOutputConfiguration,
@@ -1029,9 +1032,9 @@ pub async fn set_video_output_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Modify an audio source configuration. A device that has a one or more audio
-// sources shall support the setting of the AudioSourceConfiguration through
-// this command.
+/// Modify an audio source configuration. A device that has a one or more audio
+/// sources shall support the setting of the AudioSourceConfiguration through
+/// this command.
 pub async fn set_audio_source_configuration<T: transport::Transport>(
     transport: &T,
     request: &SetAudioSourceConfiguration,
@@ -1039,9 +1042,9 @@ pub async fn set_audio_source_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Modify an audio output configuration. A device that has one ore more audio
-// outputs shall support the setting of the AudioOutputConfiguration through
-// this command.
+/// Modify an audio output configuration. A device that has one ore more audio
+/// outputs shall support the setting of the AudioOutputConfiguration through
+/// this command.
 pub async fn set_audio_output_configuration<T: transport::Transport>(
     transport: &T,
     request: &SetAudioOutputConfiguration,
@@ -1049,8 +1052,8 @@ pub async fn set_audio_output_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Request the VideoSourceConfigurationOptions of a VideoSource. A device with
-// one or more video sources shall support this command.
+/// Request the VideoSourceConfigurationOptions of a VideoSource. A device with
+/// one or more video sources shall support this command.
 pub async fn get_video_source_configuration_options<T: transport::Transport>(
     transport: &T,
     request: &GetVideoSourceConfigurationOptions,
@@ -1058,9 +1061,9 @@ pub async fn get_video_source_configuration_options<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Request the VideoOutputConfigurationOptions of a VideoOutput. A device that
-// has one or more video outputs shall support the retrieval of
-// VideoOutputConfigurationOptions through this command.
+/// Request the VideoOutputConfigurationOptions of a VideoOutput. A device that
+/// has one or more video outputs shall support the retrieval of
+/// VideoOutputConfigurationOptions through this command.
 pub async fn get_video_output_configuration_options<T: transport::Transport>(
     transport: &T,
     request: &GetVideoOutputConfigurationOptions,
@@ -1068,8 +1071,8 @@ pub async fn get_video_output_configuration_options<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Request the AudioSourceConfigurationOptions of an AudioSource. A device with
-// one ore more AudioSources shall support this command.
+/// Request the AudioSourceConfigurationOptions of an AudioSource. A device with
+/// one ore more AudioSources shall support this command.
 pub async fn get_audio_source_configuration_options<T: transport::Transport>(
     transport: &T,
     request: &GetAudioSourceConfigurationOptions,
@@ -1077,8 +1080,8 @@ pub async fn get_audio_source_configuration_options<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Request the available settings and ranges for a physical Audio output. A
-// device that has one or more AudioOutputs shall support this command.
+/// Request the available settings and ranges for a physical Audio output. A
+/// device that has one or more AudioOutputs shall support this command.
 pub async fn get_audio_output_configuration_options<T: transport::Transport>(
     transport: &T,
     request: &GetAudioOutputConfigurationOptions,
@@ -1086,7 +1089,7 @@ pub async fn get_audio_output_configuration_options<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation gets a list of all available relay outputs and their settings.
+/// This operation gets a list of all available relay outputs and their settings.
 pub async fn get_relay_outputs<T: transport::Transport>(
     transport: &T,
     request: &tds::GetRelayOutputs,
@@ -1094,8 +1097,8 @@ pub async fn get_relay_outputs<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation sets the settings of a relay output.
-// The relay can work in two relay modes:
+/// This operation sets the settings of a relay output.
+/// The relay can work in two relay modes:
 pub async fn set_relay_output_settings<T: transport::Transport>(
     transport: &T,
     request: &SetRelayOutputSettings,
@@ -1103,7 +1106,7 @@ pub async fn set_relay_output_settings<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Modify the relay state.
+/// Modify the relay state.
 pub async fn set_relay_output_state<T: transport::Transport>(
     transport: &T,
     request: &tds::SetRelayOutputState,
@@ -1111,7 +1114,7 @@ pub async fn set_relay_output_state<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation gets a list of all available digital inputs.
+/// This operation gets a list of all available digital inputs.
 pub async fn get_digital_inputs<T: transport::Transport>(
     transport: &T,
     request: &GetDigitalInputs,
@@ -1119,7 +1122,7 @@ pub async fn get_digital_inputs<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation lists what configuration is available for digital inputs.
+/// This operation lists what configuration is available for digital inputs.
 pub async fn get_digital_input_configuration_options<T: transport::Transport>(
     transport: &T,
     request: &GetDigitalInputConfigurationOptions,
@@ -1127,7 +1130,7 @@ pub async fn get_digital_input_configuration_options<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Modify a digital input configuration.
+/// Modify a digital input configuration.
 pub async fn set_digital_input_configurations<T: transport::Transport>(
     transport: &T,
     request: &SetDigitalInputConfigurations,
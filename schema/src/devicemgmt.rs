@@ -11,8 +11,8 @@ use xsd_types::types as xs;
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetServices {
-    // Indicates if the service capabilities (untyped) should be included in the
-    // response.
+    /// Indicates if the service capabilities (untyped) should be included in the
+    /// response.
     #[yaserde(prefix = "tds", rename = "IncludeCapability")]
     pub include_capability: bool,
 }
@@ -25,7 +25,7 @@ impl Validate for GetServices {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetServicesResponse {
-    // Each Service element contains information about one service.
+    /// Each Service element contains information about one service.
     #[yaserde(prefix = "tds", rename = "Service")]
     pub service: Vec<Service>,
 }
@@ -38,22 +38,22 @@ impl Validate for GetServicesResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct Service {
-    // Namespace of the service being described. This parameter allows to match
-    // the service capabilities to the service. Note that only one set of
-    // capabilities is supported per namespace.
+    /// Namespace of the service being described. This parameter allows to match
+    /// the service capabilities to the service. Note that only one set of
+    /// capabilities is supported per namespace.
     #[yaserde(prefix = "tds", rename = "Namespace")]
     pub namespace: String,
 
-    // The transport addresses where the service can be reached. The scheme and
-    // IP part shall match the one used in the request (i.e. the GetServices
-    // request).
+    /// The transport addresses where the service can be reached. The scheme and
+    /// IP part shall match the one used in the request (i.e. the GetServices
+    /// request).
     #[yaserde(prefix = "tds", rename = "XAddr")]
     pub x_addr: String,
 
     #[yaserde(prefix = "tds", rename = "Capabilities")]
     pub capabilities: Option<service::CapabilitiesType>,
 
-    // The version of the service (not the ONVIF core spec version).
+    /// The version of the service (not the ONVIF core spec version).
     #[yaserde(prefix = "tds", rename = "Version")]
     pub version: tt::OnvifVersion,
 }
@@ -88,8 +88,8 @@ impl Validate for GetServiceCapabilities {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetServiceCapabilitiesResponse {
-    // The capabilities for the device service is returned in the Capabilities
-    // element.
+    /// The capabilities for the device service is returned in the Capabilities
+    /// element.
     #[yaserde(prefix = "tds", rename = "Capabilities")]
     pub capabilities: DeviceServiceCapabilities,
 }
@@ -102,19 +102,19 @@ impl Validate for GetServiceCapabilitiesResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct DeviceServiceCapabilities {
-    // Network capabilities.
+    /// Network capabilities.
     #[yaserde(prefix = "tds", rename = "Network")]
     pub network: NetworkCapabilities,
 
-    // Security capabilities.
+    /// Security capabilities.
     #[yaserde(prefix = "tds", rename = "Security")]
     pub security: SecurityCapabilities,
 
-    // System capabilities.
+    /// System capabilities.
     #[yaserde(prefix = "tds", rename = "System")]
     pub system: SystemCapabilities,
 
-    // Capabilities that do not fit in any of the other categories.
+    /// Capabilities that do not fit in any of the other categories.
     #[yaserde(prefix = "tds", rename = "Misc")]
     pub misc: Option<MiscCapabilities>,
 }
@@ -128,40 +128,40 @@ impl Validate for DeviceServiceCapabilities {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct NetworkCapabilities {
-    // Indicates support for IP filtering.
+    /// Indicates support for IP filtering.
     #[yaserde(attribute, rename = "IPFilter")]
     pub ip_filter: Option<bool>,
 
-    // Indicates support for zeroconf.
+    /// Indicates support for zeroconf.
     #[yaserde(attribute, rename = "ZeroConfiguration")]
     pub zero_configuration: Option<bool>,
 
-    // Indicates support for IPv6.
+    /// Indicates support for IPv6.
     #[yaserde(attribute, rename = "IPVersion6")]
     pub ip_version_6: Option<bool>,
 
-    // Indicates support for dynamic DNS configuration.
+    /// Indicates support for dynamic DNS configuration.
     #[yaserde(attribute, rename = "DynDNS")]
     pub dyn_dns: Option<bool>,
 
-    // Indicates support for IEEE 802.11 configuration.
+    /// Indicates support for IEEE 802.11 configuration.
     #[yaserde(attribute, rename = "Dot11Configuration")]
     pub dot_11_configuration: Option<bool>,
 
-    // Indicates the maximum number of Dot1X configurations supported by the
-    // device
+    /// Indicates the maximum number of Dot1X configurations supported by the
+    /// device
     #[yaserde(attribute, rename = "Dot1XConfigurations")]
     pub dot_1x_configurations: Option<i32>,
 
-    // Indicates support for retrieval of hostname from DHCP.
+    /// Indicates support for retrieval of hostname from DHCP.
     #[yaserde(attribute, rename = "HostnameFromDHCP")]
     pub hostname_from_dhcp: Option<bool>,
 
-    // Maximum number of NTP servers supported by the devices SetNTP command.
+    /// Maximum number of NTP servers supported by the devices SetNTP command.
     #[yaserde(attribute, rename = "NTP")]
     pub ntp: Option<i32>,
 
-    // Indicates support for Stateful IPv6 DHCP.
+    /// Indicates support for Stateful IPv6 DHCP.
     #[yaserde(attribute, rename = "DHCPv6")]
     pub dhc_pv_6: Option<bool>,
 }
@@ -178,78 +178,78 @@ impl Validate for EapmethodTypes {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct SecurityCapabilities {
-    // Indicates support for TLS 1.0.
+    /// Indicates support for TLS 1.0.
     #[yaserde(attribute, rename = "TLS1.0")]
     pub tls1_0: Option<bool>,
 
-    // Indicates support for TLS 1.1.
+    /// Indicates support for TLS 1.1.
     #[yaserde(attribute, rename = "TLS1.1")]
     pub tls1_1: Option<bool>,
 
-    // Indicates support for TLS 1.2.
+    /// Indicates support for TLS 1.2.
     #[yaserde(attribute, rename = "TLS1.2")]
     pub tls1_2: Option<bool>,
 
-    // Indicates support for onboard key generation.
+    /// Indicates support for onboard key generation.
     #[yaserde(attribute, rename = "OnboardKeyGeneration")]
     pub onboard_key_generation: Option<bool>,
 
-    // Indicates support for access policy configuration.
+    /// Indicates support for access policy configuration.
     #[yaserde(attribute, rename = "AccessPolicyConfig")]
     pub access_policy_config: Option<bool>,
 
-    // Indicates support for the ONVIF default access policy.
+    /// Indicates support for the ONVIF default access policy.
     #[yaserde(attribute, rename = "DefaultAccessPolicy")]
     pub default_access_policy: Option<bool>,
 
-    // Indicates support for IEEE 802.1X configuration.
+    /// Indicates support for IEEE 802.1X configuration.
     #[yaserde(attribute, rename = "Dot1X")]
     pub dot_1x: Option<bool>,
 
-    // Indicates support for remote user configuration. Used when accessing
-    // another device.
+    /// Indicates support for remote user configuration. Used when accessing
+    /// another device.
     #[yaserde(attribute, rename = "RemoteUserHandling")]
     pub remote_user_handling: Option<bool>,
 
-    // Indicates support for WS-Security X.509 token.
+    /// Indicates support for WS-Security X.509 token.
     #[yaserde(attribute, rename = "X.509Token")]
     pub x_509_token: Option<bool>,
 
-    // Indicates support for WS-Security SAML token.
+    /// Indicates support for WS-Security SAML token.
     #[yaserde(attribute, rename = "SAMLToken")]
     pub saml_token: Option<bool>,
 
-    // Indicates support for WS-Security Kerberos token.
+    /// Indicates support for WS-Security Kerberos token.
     #[yaserde(attribute, rename = "KerberosToken")]
     pub kerberos_token: Option<bool>,
 
-    // Indicates support for WS-Security Username token.
+    /// Indicates support for WS-Security Username token.
     #[yaserde(attribute, rename = "UsernameToken")]
     pub username_token: Option<bool>,
 
-    // Indicates support for WS over HTTP digest authenticated communication
-    // layer.
+    /// Indicates support for WS over HTTP digest authenticated communication
+    /// layer.
     #[yaserde(attribute, rename = "HttpDigest")]
     pub http_digest: Option<bool>,
 
-    // Indicates support for WS-Security REL token.
+    /// Indicates support for WS-Security REL token.
     #[yaserde(attribute, rename = "RELToken")]
     pub rel_token: Option<bool>,
 
-    // EAP Methods supported by the device. The int values refer to the
+    /// EAP Methods supported by the device. The int values refer to the
     #[yaserde(attribute, rename = "SupportedEAPMethods")]
     pub supported_eap_methods: Option<EapmethodTypes>,
 
-    // The maximum number of users that the device supports.
+    /// The maximum number of users that the device supports.
     #[yaserde(attribute, rename = "MaxUsers")]
     pub max_users: Option<i32>,
 
-    // Maximum number of characters supported for the username by CreateUsers.
+    /// Maximum number of characters supported for the username by CreateUsers.
     #[yaserde(attribute, rename = "MaxUserNameLength")]
     pub max_user_name_length: Option<i32>,
 
-    // Maximum number of characters supported for the password by CreateUsers
-    // and SetUser.
+    /// Maximum number of characters supported for the password by CreateUsers
+    /// and SetUser.
     #[yaserde(attribute, rename = "MaxPasswordLength")]
     pub max_password_length: Option<i32>,
 }
@@ -262,65 +262,65 @@ impl Validate for SecurityCapabilities {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct SystemCapabilities {
-    // Indicates support for WS Discovery resolve requests.
+    /// Indicates support for WS Discovery resolve requests.
     #[yaserde(attribute, rename = "DiscoveryResolve")]
     pub discovery_resolve: Option<bool>,
 
-    // Indicates support for WS-Discovery Bye.
+    /// Indicates support for WS-Discovery Bye.
     #[yaserde(attribute, rename = "DiscoveryBye")]
     pub discovery_bye: Option<bool>,
 
-    // Indicates support for remote discovery.
+    /// Indicates support for remote discovery.
     #[yaserde(attribute, rename = "RemoteDiscovery")]
     pub remote_discovery: Option<bool>,
 
-    // Indicates support for system backup through MTOM.
+    /// Indicates support for system backup through MTOM.
     #[yaserde(attribute, rename = "SystemBackup")]
     pub system_backup: Option<bool>,
 
-    // Indicates support for retrieval of system logging through MTOM.
+    /// Indicates support for retrieval of system logging through MTOM.
     #[yaserde(attribute, rename = "SystemLogging")]
     pub system_logging: Option<bool>,
 
-    // Indicates support for firmware upgrade through MTOM.
+    /// Indicates support for firmware upgrade through MTOM.
     #[yaserde(attribute, rename = "FirmwareUpgrade")]
     pub firmware_upgrade: Option<bool>,
 
-    // Indicates support for firmware upgrade through HTTP.
+    /// Indicates support for firmware upgrade through HTTP.
     #[yaserde(attribute, rename = "HttpFirmwareUpgrade")]
     pub http_firmware_upgrade: Option<bool>,
 
-    // Indicates support for system backup through HTTP.
+    /// Indicates support for system backup through HTTP.
     #[yaserde(attribute, rename = "HttpSystemBackup")]
     pub http_system_backup: Option<bool>,
 
-    // Indicates support for retrieval of system logging through HTTP.
+    /// Indicates support for retrieval of system logging through HTTP.
     #[yaserde(attribute, rename = "HttpSystemLogging")]
     pub http_system_logging: Option<bool>,
 
-    // Indicates support for retrieving support information through HTTP.
+    /// Indicates support for retrieving support information through HTTP.
     #[yaserde(attribute, rename = "HttpSupportInformation")]
     pub http_support_information: Option<bool>,
 
-    // Indicates support for storage configuration interfaces.
+    /// Indicates support for storage configuration interfaces.
     #[yaserde(attribute, rename = "StorageConfiguration")]
     pub storage_configuration: Option<bool>,
 
-    // Indicates maximum number of storage configurations supported.
+    /// Indicates maximum number of storage configurations supported.
     #[yaserde(attribute, rename = "MaxStorageConfigurations")]
     pub max_storage_configurations: Option<i32>,
 
-    // If present signals support for geo location. The value signals the
-    // supported number of entries.
+    /// If present signals support for geo location. The value signals the
+    /// supported number of entries.
     #[yaserde(attribute, rename = "GeoLocationEntries")]
     pub geo_location_entries: Option<i32>,
 
-    // List of supported automatic GeoLocation adjustment supported by the
-    // device. Valid items are defined by tds:AutoGeoMode.
+    /// List of supported automatic GeoLocation adjustment supported by the
+    /// device. Valid items are defined by tds:AutoGeoMode.
     #[yaserde(attribute, rename = "AutoGeo")]
     pub auto_geo: Option<tt::StringAttrList>,
 
-    // Enumerates the supported StorageTypes, see tds:StorageType.
+    /// Enumerates the supported StorageTypes, see tds:StorageType.
     #[yaserde(attribute, rename = "StorageTypesSupported")]
     pub storage_types_supported: Option<tt::StringAttrList>,
 }
@@ -328,14 +328,15 @@ pub struct SystemCapabilities {
 impl Validate for SystemCapabilities {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[non_exhaustive]
 pub enum AutoGeoModes {
-    // Automatic adjustment of the device location.
+    /// Automatic adjustment of the device location.
     Location,
-    // Automatic adjustment of the device orientation relative to the compass
-    // also called yaw.
+    /// Automatic adjustment of the device orientation relative to the compass
+    /// also called yaw.
     Heading,
-    // Automatic adjustment of the deviation from the horizon also called pitch
-    // and roll.
+    /// Automatic adjustment of the deviation from the horizon also called pitch
+    /// and roll.
     Leveling,
     __Unknown__(String),
 }
@@ -354,7 +355,7 @@ impl Validate for AutoGeoModes {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct MiscCapabilities {
-    // Lists of commands supported by SendAuxiliaryCommand.
+    /// Lists of commands supported by SendAuxiliaryCommand.
     #[yaserde(attribute, rename = "AuxiliaryCommands")]
     pub auxiliary_commands: Option<tt::StringAttrList>,
 }
@@ -376,23 +377,23 @@ impl Validate for GetDeviceInformation {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetDeviceInformationResponse {
-    // The manufactor of the device.
+    /// The manufactor of the device.
     #[yaserde(prefix = "tds", rename = "Manufacturer")]
     pub manufacturer: String,
 
-    // The device model.
+    /// The device model.
     #[yaserde(prefix = "tds", rename = "Model")]
     pub model: String,
 
-    // The firmware version in the device.
+    /// The firmware version in the device.
     #[yaserde(prefix = "tds", rename = "FirmwareVersion")]
     pub firmware_version: String,
 
-    // The serial number of the device.
+    /// The serial number of the device.
     #[yaserde(prefix = "tds", rename = "SerialNumber")]
     pub serial_number: String,
 
-    // The hardware ID of the device.
+    /// The hardware ID of the device.
     #[yaserde(prefix = "tds", rename = "HardwareId")]
     pub hardware_id: String,
 }
@@ -405,20 +406,20 @@ impl Validate for GetDeviceInformationResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct SetSystemDateAndTime {
-    // Defines if the date and time is set via NTP or manually.
+    /// Defines if the date and time is set via NTP or manually.
     #[yaserde(prefix = "tds", rename = "DateTimeType")]
     pub date_time_type: tt::SetDateTimeType,
 
-    // Automatically adjust Daylight savings if defined in TimeZone.
+    /// Automatically adjust Daylight savings if defined in TimeZone.
     #[yaserde(prefix = "tds", rename = "DaylightSavings")]
     pub daylight_savings: bool,
 
-    // The time zone in POSIX 1003.1 format
+    /// The time zone in POSIX 1003.1 format
     #[yaserde(prefix = "tds", rename = "TimeZone")]
     pub time_zone: Option<tt::TimeZone>,
 
-    // Date and time in UTC. If time is obtained via NTP, UTCDateTime has no
-    // meaning
+    /// Date and time in UTC. If time is obtained via NTP, UTCDateTime has no
+    /// meaning
     #[yaserde(prefix = "tds", rename = "UTCDateTime")]
     pub utc_date_time: Option<tt::DateTime>,
 }
@@ -449,9 +450,9 @@ impl Validate for GetSystemDateAndTime {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetSystemDateAndTimeResponse {
-    // Contains information whether system date and time are set manually or by
-    // NTP, daylight savings is on or off, time zone in POSIX 1003.1 format and
-    // system date and time in UTC and also local system date and time.
+    /// Contains information whether system date and time are set manually or by
+    /// NTP, daylight savings is on or off, time zone in POSIX 1003.1 format and
+    /// system date and time in UTC and also local system date and time.
     #[yaserde(prefix = "tds", rename = "SystemDateAndTime")]
     pub system_date_and_time: tt::SystemDateTime,
 }
@@ -464,7 +465,7 @@ impl Validate for GetSystemDateAndTimeResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct SetSystemFactoryDefault {
-    // Specifies the factory default action type.
+    /// Specifies the factory default action type.
     #[yaserde(prefix = "tds", rename = "FactoryDefault")]
     pub factory_default: tt::FactoryDefaultType,
 }
@@ -519,7 +520,7 @@ impl Validate for SystemReboot {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct SystemRebootResponse {
-    // Contains the reboot message sent by the device.
+    /// Contains the reboot message sent by the device.
     #[yaserde(prefix = "tds", rename = "Message")]
     pub message: String,
 }
@@ -583,7 +584,7 @@ impl Validate for GetSystemSupportInformation {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetSystemSupportInformationResponse {
-    // Contains the arbitary device diagnostics information.
+    /// Contains the arbitary device diagnostics information.
     #[yaserde(prefix = "tds", rename = "SupportInformation")]
     pub support_information: tt::SupportInformation,
 }
@@ -596,7 +597,7 @@ impl Validate for GetSystemSupportInformationResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetSystemLog {
-    // Specifies the type of system log to get.
+    /// Specifies the type of system log to get.
     #[yaserde(prefix = "tds", rename = "LogType")]
     pub log_type: tt::SystemLogType,
 }
@@ -609,7 +610,7 @@ impl Validate for GetSystemLog {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetSystemLogResponse {
-    // Contains the system log information.
+    /// Contains the system log information.
     #[yaserde(prefix = "tds", rename = "SystemLog")]
     pub system_log: tt::SystemLog,
 }
@@ -631,9 +632,9 @@ impl Validate for GetScopes {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetScopesResponse {
-    // Contains a list of URI definining the device scopes. Scope parameters can
-    // be of two types: fixed and configurable. Fixed parameters can not be
-    // altered.
+    /// Contains a list of URI definining the device scopes. Scope parameters can
+    /// be of two types: fixed and configurable. Fixed parameters can not be
+    /// altered.
     #[yaserde(prefix = "tds", rename = "Scopes")]
     pub scopes: Vec<tt::Scope>,
 }
@@ -646,8 +647,8 @@ impl Validate for GetScopesResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct SetScopes {
-    // Contains a list of scope parameters that will replace all existing
-    // configurable scope parameters.
+    /// Contains a list of scope parameters that will replace all existing
+    /// configurable scope parameters.
     #[yaserde(prefix = "tds", rename = "Scopes")]
     pub scopes: Vec<String>,
 }
@@ -669,8 +670,8 @@ impl Validate for SetScopesResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct AddScopes {
-    // Contains a list of new configurable scope parameters that will be added
-    // to the existing configurable scope.
+    /// Contains a list of new configurable scope parameters that will be added
+    /// to the existing configurable scope.
     #[yaserde(prefix = "tds", rename = "ScopeItem")]
     pub scope_item: Vec<String>,
 }
@@ -692,7 +693,7 @@ impl Validate for AddScopesResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct RemoveScopes {
-    // Contains a list of URIs that should be removed from the device scope.
+    /// Contains a list of URIs that should be removed from the device scope.
     #[yaserde(prefix = "tds", rename = "ScopeItem")]
     pub scope_item: Vec<String>,
 }
@@ -705,7 +706,7 @@ impl Validate for RemoveScopes {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct RemoveScopesResponse {
-    // Contains a list of URIs that has been removed from the device scope
+    /// Contains a list of URIs that has been removed from the device scope
     #[yaserde(prefix = "tds", rename = "ScopeItem")]
     pub scope_item: Vec<String>,
 }
@@ -727,7 +728,7 @@ impl Validate for GetDiscoveryMode {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetDiscoveryModeResponse {
-    // Indicator of discovery mode: Discoverable, NonDiscoverable.
+    /// Indicator of discovery mode: Discoverable, NonDiscoverable.
     #[yaserde(prefix = "tds", rename = "DiscoveryMode")]
     pub discovery_mode: tt::DiscoveryMode,
 }
@@ -740,7 +741,7 @@ impl Validate for GetDiscoveryModeResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct SetDiscoveryMode {
-    // Indicator of discovery mode: Discoverable, NonDiscoverable.
+    /// Indicator of discovery mode: Discoverable, NonDiscoverable.
     #[yaserde(prefix = "tds", rename = "DiscoveryMode")]
     pub discovery_mode: tt::DiscoveryMode,
 }
@@ -771,7 +772,7 @@ impl Validate for GetRemoteDiscoveryMode {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetRemoteDiscoveryModeResponse {
-    // Indicator of discovery mode: Discoverable, NonDiscoverable.
+    /// Indicator of discovery mode: Discoverable, NonDiscoverable.
     #[yaserde(prefix = "tds", rename = "RemoteDiscoveryMode")]
     pub remote_discovery_mode: tt::DiscoveryMode,
 }
@@ -784,7 +785,7 @@ impl Validate for GetRemoteDiscoveryModeResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct SetRemoteDiscoveryMode {
-    // Indicator of discovery mode: Discoverable, NonDiscoverable.
+    /// Indicator of discovery mode: Discoverable, NonDiscoverable.
     #[yaserde(prefix = "tds", rename = "RemoteDiscoveryMode")]
     pub remote_discovery_mode: tt::DiscoveryMode,
 }
@@ -920,8 +921,8 @@ impl Validate for GetUsers {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetUsersResponse {
-    // Contains a list of the onvif users and following information is included
-    // in each entry: username and user level.
+    /// Contains a list of the onvif users and following information is included
+    /// in each entry: username and user level.
     #[yaserde(prefix = "tds", rename = "User")]
     pub user: Vec<tt::User>,
 }
@@ -934,12 +935,12 @@ impl Validate for GetUsersResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct CreateUsers {
-    // Creates new device users and corresponding credentials. Each user entry
-    // includes: username, password and user level. Either all users are created
-    // successfully or a fault message MUST be returned without creating any
-    // user. If trying to create several users with exactly the same username
-    // the request is rejected and no users are created. If password is missing,
-    // then fault message Too weak password is returned.
+    /// Creates new device users and corresponding credentials. Each user entry
+    /// includes: username, password and user level. Either all users are created
+    /// successfully or a fault message MUST be returned without creating any
+    /// user. If trying to create several users with exactly the same username
+    /// the request is rejected and no users are created. If password is missing,
+    /// then fault message Too weak password is returned.
     #[yaserde(prefix = "tds", rename = "User")]
     pub user: Vec<tt::User>,
 }
@@ -961,11 +962,11 @@ impl Validate for CreateUsersResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct DeleteUsers {
-    // Deletes users on an device and there may exist users that cannot be
-    // deleted to ensure access to the unit. Either all users are deleted
-    // successfully or a fault message MUST be returned and no users be deleted.
-    // If a username exists multiple times in the request, then a fault message
-    // is returned.
+    /// Deletes users on an device and there may exist users that cannot be
+    /// deleted to ensure access to the unit. Either all users are deleted
+    /// successfully or a fault message MUST be returned and no users be deleted.
+    /// If a username exists multiple times in the request, then a fault message
+    /// is returned.
     #[yaserde(prefix = "tds", rename = "Username")]
     pub username: Vec<String>,
 }
@@ -987,10 +988,10 @@ impl Validate for DeleteUsersResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct SetUser {
-    // Updates the credentials for one or several users on an device. Either all
-    // change requests are processed successfully or a fault message MUST be
-    // returned. If the request contains the same username multiple times, a
-    // fault message is returned.
+    /// Updates the credentials for one or several users on an device. Either all
+    /// change requests are processed successfully or a fault message MUST be
+    /// returned. If the request contains the same username multiple times, a
+    /// fault message is returned.
     #[yaserde(prefix = "tds", rename = "User")]
     pub user: Vec<tt::User>,
 }
@@ -1033,7 +1034,7 @@ impl Validate for GetWsdlUrlResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetCapabilities {
-    // List of categories to retrieve capability information on.
+    /// List of categories to retrieve capability information on.
     #[yaserde(prefix = "tds", rename = "Category")]
     pub category: Vec<tt::CapabilityCategory>,
 }
@@ -1046,7 +1047,7 @@ impl Validate for GetCapabilities {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetCapabilitiesResponse {
-    // Capability information.
+    /// Capability information.
     #[yaserde(prefix = "tds", rename = "Capabilities")]
     pub capabilities: tt::Capabilities,
 }
@@ -1068,7 +1069,7 @@ impl Validate for GetHostname {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetHostnameResponse {
-    // Contains the hostname information.
+    /// Contains the hostname information.
     #[yaserde(prefix = "tds", rename = "HostnameInformation")]
     pub hostname_information: tt::HostnameInformation,
 }
@@ -1081,7 +1082,7 @@ impl Validate for GetHostnameResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct SetHostname {
-    // The hostname to set.
+    /// The hostname to set.
     #[yaserde(prefix = "tds", rename = "Name")]
     pub name: String,
 }
@@ -1103,7 +1104,7 @@ impl Validate for SetHostnameResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct SetHostnameFromDHCP {
-    // True if the hostname shall be obtained via DHCP.
+    /// True if the hostname shall be obtained via DHCP.
     #[yaserde(prefix = "tds", rename = "FromDHCP")]
     pub from_dhcp: bool,
 }
@@ -1116,8 +1117,8 @@ impl Validate for SetHostnameFromDHCP {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct SetHostnameFromDHCPResponse {
-    // Indicates whether or not a reboot is required after configuration
-    // updates.
+    /// Indicates whether or not a reboot is required after configuration
+    /// updates.
     #[yaserde(prefix = "tds", rename = "RebootNeeded")]
     pub reboot_needed: bool,
 }
@@ -1139,7 +1140,7 @@ impl Validate for GetDNS {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetDNSResponse {
-    // DNS information.
+    /// DNS information.
     #[yaserde(prefix = "tds", rename = "DNSInformation")]
     pub dns_information: tt::Dnsinformation,
 }
@@ -1152,15 +1153,15 @@ impl Validate for GetDNSResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct SetDNS {
-    // Indicate if the DNS address is to be retrieved using DHCP.
+    /// Indicate if the DNS address is to be retrieved using DHCP.
     #[yaserde(prefix = "tds", rename = "FromDHCP")]
     pub from_dhcp: bool,
 
-    // DNS search domain.
+    /// DNS search domain.
     #[yaserde(prefix = "tds", rename = "SearchDomain")]
     pub search_domain: Vec<String>,
 
-    // DNS address(es) set manually.
+    /// DNS address(es) set manually.
     #[yaserde(prefix = "tds", rename = "DNSManual")]
     pub dns_manual: Vec<tt::Ipaddress>,
 }
@@ -1191,7 +1192,7 @@ impl Validate for GetNTP {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetNTPResponse {
-    // NTP information.
+    /// NTP information.
     #[yaserde(prefix = "tds", rename = "NTPInformation")]
     pub ntp_information: tt::Ntpinformation,
 }
@@ -1204,11 +1205,11 @@ impl Validate for GetNTPResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct SetNTP {
-    // Indicate if NTP address information is to be retrieved using DHCP.
+    /// Indicate if NTP address information is to be retrieved using DHCP.
     #[yaserde(prefix = "tds", rename = "FromDHCP")]
     pub from_dhcp: bool,
 
-    // Manual NTP settings.
+    /// Manual NTP settings.
     #[yaserde(prefix = "tds", rename = "NTPManual")]
     pub ntp_manual: Vec<tt::NetworkHost>,
 }
@@ -1239,7 +1240,7 @@ impl Validate for GetDynamicDNS {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetDynamicDNSResponse {
-    // Dynamic DNS information.
+    /// Dynamic DNS information.
     #[yaserde(prefix = "tds", rename = "DynamicDNSInformation")]
     pub dynamic_dns_information: tt::DynamicDNSInformation,
 }
@@ -1252,15 +1253,15 @@ impl Validate for GetDynamicDNSResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct SetDynamicDNS {
-    // Dynamic DNS type.
+    /// Dynamic DNS type.
     #[yaserde(prefix = "tds", rename = "Type")]
     pub _type: tt::DynamicDNSType,
 
-    // DNS name.
+    /// DNS name.
     #[yaserde(prefix = "tds", rename = "Name")]
     pub name: Option<tt::Dnsname>,
 
-    // DNS record time to live.
+    /// DNS record time to live.
     #[yaserde(prefix = "tds", rename = "TTL")]
     pub ttl: Option<xs::Duration>,
 }
@@ -1291,7 +1292,7 @@ impl Validate for GetNetworkInterfaces {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetNetworkInterfacesResponse {
-    // List of network interfaces.
+    /// List of network interfaces.
     #[yaserde(prefix = "tds", rename = "NetworkInterfaces")]
     pub network_interfaces: Vec<tt::NetworkInterface>,
 }
@@ -1304,11 +1305,11 @@ impl Validate for GetNetworkInterfacesResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct SetNetworkInterfaces {
-    // Symbolic network interface name.
+    /// Symbolic network interface name.
     #[yaserde(prefix = "tds", rename = "InterfaceToken")]
     pub interface_token: tt::ReferenceToken,
 
-    // Network interface name.
+    /// Network interface name.
     #[yaserde(prefix = "tds", rename = "NetworkInterface")]
     pub network_interface: tt::NetworkInterfaceSetConfiguration,
 }
@@ -1321,20 +1322,20 @@ impl Validate for SetNetworkInterfaces {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct SetNetworkInterfacesResponse {
-    // Indicates whether or not a reboot is required after configuration
-    // updates.
-    // If a device responds with RebootNeeded set to false, the device can be
-    // reached
-    // via the new IP address without further action. A client should be aware
-    // that a device
-    // may not be responsive for a short period of time until it signals
-    // availability at
-    // the new address via the discovery Hello messages.
-    // If a device responds with RebootNeeded set to true, it will be further
-    // available under
-    // its previous IP address. The settings will only be activated when the
-    // device is
-    // rebooted via the SystemReboot command.
+    /// Indicates whether or not a reboot is required after configuration
+    /// updates.
+    /// If a device responds with RebootNeeded set to false, the device can be
+    /// reached
+    /// via the new IP address without further action. A client should be aware
+    /// that a device
+    /// may not be responsive for a short period of time until it signals
+    /// availability at
+    /// the new address via the discovery Hello messages.
+    /// If a device responds with RebootNeeded set to true, it will be further
+    /// available under
+    /// its previous IP address. The settings will only be activated when the
+    /// device is
+    /// rebooted via the SystemReboot command.
     #[yaserde(prefix = "tds", rename = "RebootNeeded")]
     pub reboot_needed: bool,
 }
@@ -1356,9 +1357,9 @@ impl Validate for GetNetworkProtocols {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetNetworkProtocolsResponse {
-    // Contains an array of defined protocols supported by the device. There are
-    // three protocols defined; HTTP, HTTPS and RTSP. The following parameters
-    // can be retrieved for each protocol: port and enable/disable.
+    /// Contains an array of defined protocols supported by the device. There are
+    /// three protocols defined; HTTP, HTTPS and RTSP. The following parameters
+    /// can be retrieved for each protocol: port and enable/disable.
     #[yaserde(prefix = "tds", rename = "NetworkProtocols")]
     pub network_protocols: Vec<tt::NetworkProtocol>,
 }
@@ -1371,10 +1372,10 @@ impl Validate for GetNetworkProtocolsResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct SetNetworkProtocols {
-    // Configures one or more defined network protocols supported by the device.
-    // There are currently three protocols defined; HTTP, HTTPS and RTSP. The
-    // following parameters can be set for each protocol: port and
-    // enable/disable.
+    /// Configures one or more defined network protocols supported by the device.
+    /// There are currently three protocols defined; HTTP, HTTPS and RTSP. The
+    /// following parameters can be set for each protocol: port and
+    /// enable/disable.
     #[yaserde(prefix = "tds", rename = "NetworkProtocols")]
     pub network_protocols: Vec<tt::NetworkProtocol>,
 }
@@ -1405,7 +1406,7 @@ impl Validate for GetNetworkDefaultGateway {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetNetworkDefaultGatewayResponse {
-    // Gets the default IPv4 and IPv6 gateway settings from the device.
+    /// Gets the default IPv4 and IPv6 gateway settings from the device.
     #[yaserde(prefix = "tds", rename = "NetworkGateway")]
     pub network_gateway: tt::NetworkGateway,
 }
@@ -1418,11 +1419,11 @@ impl Validate for GetNetworkDefaultGatewayResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct SetNetworkDefaultGateway {
-    // Sets IPv4 gateway address used as default setting.
+    /// Sets IPv4 gateway address used as default setting.
     #[yaserde(prefix = "tds", rename = "IPv4Address")]
     pub i_pv_4_address: Vec<tt::Ipv4Address>,
 
-    // Sets IPv6 gateway address used as default setting.
+    /// Sets IPv6 gateway address used as default setting.
     #[yaserde(prefix = "tds", rename = "IPv6Address")]
     pub i_pv_6_address: Vec<tt::Ipv6Address>,
 }
@@ -1453,7 +1454,7 @@ impl Validate for GetZeroConfiguration {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetZeroConfigurationResponse {
-    // Contains the zero-configuration.
+    /// Contains the zero-configuration.
     #[yaserde(prefix = "tds", rename = "ZeroConfiguration")]
     pub zero_configuration: tt::NetworkZeroConfiguration,
 }
@@ -1466,11 +1467,11 @@ impl Validate for GetZeroConfigurationResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct SetZeroConfiguration {
-    // Unique identifier referencing the physical interface.
+    /// Unique identifier referencing the physical interface.
     #[yaserde(prefix = "tds", rename = "InterfaceToken")]
     pub interface_token: tt::ReferenceToken,
 
-    // Specifies if the zero-configuration should be enabled or not.
+    /// Specifies if the zero-configuration should be enabled or not.
     #[yaserde(prefix = "tds", rename = "Enabled")]
     pub enabled: bool,
 }
@@ -1618,19 +1619,19 @@ impl Validate for SetAccessPolicyResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct CreateCertificate {
-    // Certificate id.
+    /// Certificate id.
     #[yaserde(prefix = "tds", rename = "CertificateID")]
     pub certificate_id: Option<String>,
 
-    // Identification of the entity associated with the public-key.
+    /// Identification of the entity associated with the public-key.
     #[yaserde(prefix = "tds", rename = "Subject")]
     pub subject: Option<String>,
 
-    // Certificate validity start date.
+    /// Certificate validity start date.
     #[yaserde(prefix = "tds", rename = "ValidNotBefore")]
     pub valid_not_before: Option<xs::DateTime>,
 
-    // Certificate expiry start date.
+    /// Certificate expiry start date.
     #[yaserde(prefix = "tds", rename = "ValidNotAfter")]
     pub valid_not_after: Option<xs::DateTime>,
 }
@@ -1643,7 +1644,7 @@ impl Validate for CreateCertificate {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct CreateCertificateResponse {
-    // base64 encoded DER representation of certificate.
+    /// base64 encoded DER representation of certificate.
     #[yaserde(prefix = "tds", rename = "NvtCertificate")]
     pub nvt_certificate: tt::Certificate,
 }
@@ -1665,7 +1666,7 @@ impl Validate for GetCertificates {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetCertificatesResponse {
-    // Id and base64 encoded DER representation of all available certificates.
+    /// Id and base64 encoded DER representation of all available certificates.
     #[yaserde(prefix = "tds", rename = "NvtCertificate")]
     pub nvt_certificate: Vec<tt::Certificate>,
 }
@@ -1687,8 +1688,8 @@ impl Validate for GetCertificatesStatus {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetCertificatesStatusResponse {
-    // Indicates if a certificate is used in an optional HTTPS configuration of
-    // the device.
+    /// Indicates if a certificate is used in an optional HTTPS configuration of
+    /// the device.
     #[yaserde(prefix = "tds", rename = "CertificateStatus")]
     pub certificate_status: Vec<tt::CertificateStatus>,
 }
@@ -1701,8 +1702,8 @@ impl Validate for GetCertificatesStatusResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct SetCertificatesStatus {
-    // Indicates if a certificate is to be used in an optional HTTPS
-    // configuration of the device.
+    /// Indicates if a certificate is to be used in an optional HTTPS
+    /// configuration of the device.
     #[yaserde(prefix = "tds", rename = "CertificateStatus")]
     pub certificate_status: Vec<tt::CertificateStatus>,
 }
@@ -1724,7 +1725,7 @@ impl Validate for SetCertificatesStatusResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct DeleteCertificates {
-    // List of ids of certificates to delete.
+    /// List of ids of certificates to delete.
     #[yaserde(prefix = "tds", rename = "CertificateID")]
     pub certificate_id: Vec<String>,
 }
@@ -1746,15 +1747,15 @@ impl Validate for DeleteCertificatesResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetPkcs10Request {
-    // List of ids of certificates to delete.
+    /// List of ids of certificates to delete.
     #[yaserde(prefix = "tds", rename = "CertificateID")]
     pub certificate_id: String,
 
-    // Relative Dinstinguished Name(RDN) CommonName(CN).
+    /// Relative Dinstinguished Name(RDN) CommonName(CN).
     #[yaserde(prefix = "tds", rename = "Subject")]
     pub subject: Option<String>,
 
-    // Optional base64 encoded DER attributes.
+    /// Optional base64 encoded DER attributes.
     #[yaserde(prefix = "tds", rename = "Attributes")]
     pub attributes: Option<tt::BinaryData>,
 }
@@ -1767,7 +1768,7 @@ impl Validate for GetPkcs10Request {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetPkcs10RequestResponse {
-    // base64 encoded DER representation of certificate.
+    /// base64 encoded DER representation of certificate.
     #[yaserde(prefix = "tds", rename = "Pkcs10Request")]
     pub pkcs_10_request: tt::BinaryData,
 }
@@ -1780,7 +1781,7 @@ impl Validate for GetPkcs10RequestResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct LoadCertificates {
-    // Optional id and base64 encoded DER representation of certificate.
+    /// Optional id and base64 encoded DER representation of certificate.
     #[yaserde(prefix = "tds", rename = "NVTCertificate")]
     pub nvt_certificate: Vec<tt::Certificate>,
 }
@@ -1811,7 +1812,7 @@ impl Validate for GetClientCertificateMode {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct GetClientCertificateModeResponse {
-    // Indicates whether or not client certificates are required by device.
+    /// Indicates whether or not client certificates are required by device.
     #[yaserde(prefix = "tds", rename = "Enabled")]
     pub enabled: bool,
 }
@@ -1824,7 +1825,7 @@ impl Validate for GetClientCertificateModeResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct SetClientCertificateMode {
-    // Indicates whether or not client certificates are required by device.
+    /// Indicates whether or not client certificates are required by device.
     #[yaserde(prefix = "tds", rename = "Enabled")]
     pub enabled: bool,
 }
@@ -2297,11 +2298,11 @@ impl Validate for StartSystemRestoreResponse {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct UserCredential {
-    // User name
+    /// User name
     #[yaserde(prefix = "tds", rename = "UserName")]
     pub user_name: String,
 
-    // optional password
+    /// optional password
     #[yaserde(prefix = "tds", rename = "Password")]
     pub password: Option<String>,
 
@@ -2325,17 +2326,18 @@ pub mod user_credential {
 }
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[non_exhaustive]
 pub enum StorageType {
-    // NFS protocol
+    /// NFS protocol
     #[yaserde(rename = "NFS")]
     Nfs,
-    // CIFS protocol
+    /// CIFS protocol
     #[yaserde(rename = "CIFS")]
     Cifs,
-    // CDMI protocol
+    /// CDMI protocol
     #[yaserde(rename = "CDMI")]
     Cdmi,
-    // FTP protocol
+    /// FTP protocol
     #[yaserde(rename = "FTP")]
     Ftp,
     __Unknown__(String),
@@ -2355,22 +2357,22 @@ impl Validate for StorageType {}
     namespace = "tds: http://www.onvif.org/ver10/device/wsdl"
 )]
 pub struct StorageConfigurationData {
-    // local path
+    /// local path
     #[yaserde(prefix = "tds", rename = "LocalPath")]
     pub local_path: Option<String>,
 
-    // Storage server address
+    /// Storage server address
     #[yaserde(prefix = "tds", rename = "StorageUri")]
     pub storage_uri: Option<String>,
 
-    // User credential for the storage server
+    /// User credential for the storage server
     #[yaserde(prefix = "tds", rename = "User")]
     pub user: Option<UserCredential>,
 
     #[yaserde(prefix = "tds", rename = "Extension")]
     pub extension: Option<storage_configuration_data::ExtensionType>,
 
-    // StorageType lists the acceptable values for type attribute
+    /// StorageType lists the acceptable values for type attribute
     #[yaserde(attribute, rename = "type")]
     pub _type: String,
 }
@@ -2578,7 +2580,7 @@ pub struct DeleteGeoLocationResponse {}
 
 impl Validate for DeleteGeoLocationResponse {}
 
-// Returns information about services on the device.
+/// Returns information about services on the device.
 pub async fn get_services<T: transport::Transport>(
     transport: &T,
     request: &GetServices,
@@ -2586,8 +2588,8 @@ pub async fn get_services<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Returns the capabilities of the device service. The result is returned in a
-// typed answer.
+/// Returns the capabilities of the device service. The result is returned in a
+/// typed answer.
 pub async fn get_service_capabilities<T: transport::Transport>(
     transport: &T,
     request: &GetServiceCapabilities,
@@ -2595,7 +2597,7 @@ pub async fn get_service_capabilities<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation gets basic device information from the device.
+/// This operation gets basic device information from the device.
 pub async fn get_device_information<T: transport::Transport>(
     transport: &T,
     request: &GetDeviceInformation,
@@ -2603,13 +2605,13 @@ pub async fn get_device_information<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation sets the device system date and time. The device shall support
-// the
-// configuration of the daylight saving setting and of the manual system date
-// and time (if
-// applicable) or indication of NTP time (if applicable) through the
-// SetSystemDateAndTime
-// command.
+/// This operation sets the device system date and time. The device shall support
+/// the
+/// configuration of the daylight saving setting and of the manual system date
+/// and time (if
+/// applicable) or indication of NTP time (if applicable) through the
+/// SetSystemDateAndTime
+/// command.
 pub async fn set_system_date_and_time<T: transport::Transport>(
     transport: &T,
     request: &SetSystemDateAndTime,
@@ -2617,11 +2619,11 @@ pub async fn set_system_date_and_time<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation gets the device system date and time. The device shall support
-// the return of
-// the daylight saving setting and of the manual system date and time (if
-// applicable) or indication
-// of NTP time (if applicable) through the GetSystemDateAndTime command.
+/// This operation gets the device system date and time. The device shall support
+/// the return of
+/// the daylight saving setting and of the manual system date and time (if
+/// applicable) or indication
+/// of NTP time (if applicable) through the GetSystemDateAndTime command.
 pub async fn get_system_date_and_time<T: transport::Transport>(
     transport: &T,
     request: &GetSystemDateAndTime,
@@ -2629,8 +2631,8 @@ pub async fn get_system_date_and_time<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation reloads the parameters on the device to their factory default
-// values.
+/// This operation reloads the parameters on the device to their factory default
+/// values.
 pub async fn set_system_factory_default<T: transport::Transport>(
     transport: &T,
     request: &SetSystemFactoryDefault,
@@ -2638,13 +2640,13 @@ pub async fn set_system_factory_default<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation upgrades a device firmware version. After a successful upgrade
-// the response
-// message is sent before the device reboots. The device should support firmware
-// upgrade
-// through the UpgradeSystemFirmware command. The exact format of the firmware
-// data is
-// outside the scope of this standard.
+/// This operation upgrades a device firmware version. After a successful upgrade
+/// the response
+/// message is sent before the device reboots. The device should support firmware
+/// upgrade
+/// through the UpgradeSystemFirmware command. The exact format of the firmware
+/// data is
+/// outside the scope of this standard.
 pub async fn upgrade_system_firmware<T: transport::Transport>(
     transport: &T,
     request: &UpgradeSystemFirmware,
@@ -2652,7 +2654,7 @@ pub async fn upgrade_system_firmware<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation reboots the device.
+/// This operation reboots the device.
 pub async fn system_reboot<T: transport::Transport>(
     transport: &T,
     request: &SystemReboot,
@@ -2660,15 +2662,15 @@ pub async fn system_reboot<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation restores the system backup configuration files(s) previously
-// retrieved from a
-// device. The device should support restore of backup configuration file(s)
-// through the
-// RestoreSystem command. The exact format of the backup configuration file(s)
-// is outside the
-// scope of this standard. If the command is supported, it shall accept backup
-// files returned by
-// the GetSystemBackup command.
+/// This operation restores the system backup configuration files(s) previously
+/// retrieved from a
+/// device. The device should support restore of backup configuration file(s)
+/// through the
+/// RestoreSystem command. The exact format of the backup configuration file(s)
+/// is outside the
+/// scope of this standard. If the command is supported, it shall accept backup
+/// files returned by
+/// the GetSystemBackup command.
 pub async fn restore_system<T: transport::Transport>(
     transport: &T,
     request: &RestoreSystem,
@@ -2676,14 +2678,14 @@ pub async fn restore_system<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation is retrieves system backup configuration file(s) from a
-// device. The device
-// should support return of back up configuration file(s) through the
-// GetSystemBackup command.
-// The backup is returned with reference to a name and mime-type together with
-// binary data.
-// The exact format of the backup configuration files is outside the scope of
-// this standard.
+/// This operation is retrieves system backup configuration file(s) from a
+/// device. The device
+/// should support return of back up configuration file(s) through the
+/// GetSystemBackup command.
+/// The backup is returned with reference to a name and mime-type together with
+/// binary data.
+/// The exact format of the backup configuration files is outside the scope of
+/// this standard.
 pub async fn get_system_backup<T: transport::Transport>(
     transport: &T,
     request: &GetSystemBackup,
@@ -2691,8 +2693,8 @@ pub async fn get_system_backup<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation gets a system log from the device. The exact format of the
-// system logs is outside the scope of this standard.
+/// This operation gets a system log from the device. The exact format of the
+/// system logs is outside the scope of this standard.
 pub async fn get_system_log<T: transport::Transport>(
     transport: &T,
     request: &GetSystemLog,
@@ -2700,7 +2702,7 @@ pub async fn get_system_log<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation gets arbitary device diagnostics information from the device.
+/// This operation gets arbitary device diagnostics information from the device.
 pub async fn get_system_support_information<T: transport::Transport>(
     transport: &T,
     request: &GetSystemSupportInformation,
@@ -2708,11 +2710,11 @@ pub async fn get_system_support_information<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation requests the scope parameters of a device. The scope
-// parameters are used in
-// the device discovery to match a probe message, see Section 7. The Scope
-// parameters are of
-// two different types:
+/// This operation requests the scope parameters of a device. The scope
+/// parameters are used in
+/// the device discovery to match a probe message, see Section 7. The Scope
+/// parameters are of
+/// two different types:
 pub async fn get_scopes<T: transport::Transport>(
     transport: &T,
     request: &GetScopes,
@@ -2720,15 +2722,15 @@ pub async fn get_scopes<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation sets the scope parameters of a device. The scope parameters
-// are used in the
-// device discovery to match a probe message.
-// This operation replaces all existing configurable scope parameters (not fixed
-// parameters). If
-// this shall be avoided, one should use the scope add command instead. The
-// device shall
-// support configuration of discovery scope parameters through the SetScopes
-// command.
+/// This operation sets the scope parameters of a device. The scope parameters
+/// are used in the
+/// device discovery to match a probe message.
+/// This operation replaces all existing configurable scope parameters (not fixed
+/// parameters). If
+/// this shall be avoided, one should use the scope add command instead. The
+/// device shall
+/// support configuration of discovery scope parameters through the SetScopes
+/// command.
 pub async fn set_scopes<T: transport::Transport>(
     transport: &T,
     request: &SetScopes,
@@ -2736,10 +2738,10 @@ pub async fn set_scopes<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation adds new configurable scope parameters to a device. The scope
-// parameters
-// are used in the device discovery to match a probe message. The device shall
-// support addition of discovery scope parameters through the AddScopes command.
+/// This operation adds new configurable scope parameters to a device. The scope
+/// parameters
+/// are used in the device discovery to match a probe message. The device shall
+/// support addition of discovery scope parameters through the AddScopes command.
 pub async fn add_scopes<T: transport::Transport>(
     transport: &T,
     request: &AddScopes,
@@ -2747,14 +2749,14 @@ pub async fn add_scopes<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation deletes scope-configurable scope parameters from a device. The
-// scope
-// parameters are used in the device discovery to match a probe message, see
-// Section 7. The
-// device shall support deletion of discovery scope parameters through the
-// RemoveScopes
-// command.
-// Table
+/// This operation deletes scope-configurable scope parameters from a device. The
+/// scope
+/// parameters are used in the device discovery to match a probe message, see
+/// Section 7. The
+/// device shall support deletion of discovery scope parameters through the
+/// RemoveScopes
+/// command.
+/// Table
 pub async fn remove_scopes<T: transport::Transport>(
     transport: &T,
     request: &RemoveScopes,
@@ -2762,11 +2764,11 @@ pub async fn remove_scopes<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation gets the discovery mode of a device. See Section 7.2 for the
-// definition of the
-// different device discovery modes. The device shall support retrieval of the
-// discovery mode
-// setting through the GetDiscoveryMode command.
+/// This operation gets the discovery mode of a device. See Section 7.2 for the
+/// definition of the
+/// different device discovery modes. The device shall support retrieval of the
+/// discovery mode
+/// setting through the GetDiscoveryMode command.
 pub async fn get_discovery_mode<T: transport::Transport>(
     transport: &T,
     request: &GetDiscoveryMode,
@@ -2774,11 +2776,11 @@ pub async fn get_discovery_mode<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation sets the discovery mode operation of a device. See Section 7.2
-// for the
-// definition of the different device discovery modes. The device shall support
-// configuration of
-// the discovery mode setting through the SetDiscoveryMode command.
+/// This operation sets the discovery mode operation of a device. See Section 7.2
+/// for the
+/// definition of the different device discovery modes. The device shall support
+/// configuration of
+/// the discovery mode setting through the SetDiscoveryMode command.
 pub async fn set_discovery_mode<T: transport::Transport>(
     transport: &T,
     request: &SetDiscoveryMode,
@@ -2786,13 +2788,13 @@ pub async fn set_discovery_mode<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation gets the remote discovery mode of a device. See Section 7.4
-// for the definition
-// of remote discovery extensions. A device that supports remote discovery shall
-// support
-// retrieval of the remote discovery mode setting through the
-// GetRemoteDiscoveryMode
-// command.
+/// This operation gets the remote discovery mode of a device. See Section 7.4
+/// for the definition
+/// of remote discovery extensions. A device that supports remote discovery shall
+/// support
+/// retrieval of the remote discovery mode setting through the
+/// GetRemoteDiscoveryMode
+/// command.
 pub async fn get_remote_discovery_mode<T: transport::Transport>(
     transport: &T,
     request: &GetRemoteDiscoveryMode,
@@ -2800,12 +2802,12 @@ pub async fn get_remote_discovery_mode<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation sets the remote discovery mode of operation of a device. See
-// Section 7.4 for
-// the definition of remote discovery remote extensions. A device that supports
-// remote discovery
-// shall support configuration of the discovery mode setting through the
-// SetRemoteDiscoveryMode command.
+/// This operation sets the remote discovery mode of operation of a device. See
+/// Section 7.4 for
+/// the definition of remote discovery remote extensions. A device that supports
+/// remote discovery
+/// shall support configuration of the discovery mode setting through the
+/// SetRemoteDiscoveryMode command.
 pub async fn set_remote_discovery_mode<T: transport::Transport>(
     transport: &T,
     request: &SetRemoteDiscoveryMode,
@@ -2813,11 +2815,11 @@ pub async fn set_remote_discovery_mode<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation gets the remote DP address or addresses from a device. If the
-// device supports
-// remote discovery, as specified in Section 7.4, the device shall support
-// retrieval of the remote
-// DP address(es) through the GetDPAddresses command.
+/// This operation gets the remote DP address or addresses from a device. If the
+/// device supports
+/// remote discovery, as specified in Section 7.4, the device shall support
+/// retrieval of the remote
+/// DP address(es) through the GetDPAddresses command.
 pub async fn get_dp_addresses<T: transport::Transport>(
     transport: &T,
     request: &GetDPAddresses,
@@ -2825,11 +2827,11 @@ pub async fn get_dp_addresses<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation sets the remote DP address or addresses on a device. If the
-// device supports
-// remote discovery, as specified in Section 7.4, the device shall support
-// configuration of the
-// remote DP address(es) through the SetDPAddresses command.
+/// This operation sets the remote DP address or addresses on a device. If the
+/// device supports
+/// remote discovery, as specified in Section 7.4, the device shall support
+/// configuration of the
+/// remote DP address(es) through the SetDPAddresses command.
 pub async fn set_dp_addresses<T: transport::Transport>(
     transport: &T,
     request: &SetDPAddresses,
@@ -2837,13 +2839,13 @@ pub async fn set_dp_addresses<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// A client can ask for the device service endpoint reference address property
-// that can be used
-// to derive the password equivalent for remote user operation. The device shall
-// support the
-// GetEndpointReference command returning the address property of the device
-// service
-// endpoint reference.
+/// A client can ask for the device service endpoint reference address property
+/// that can be used
+/// to derive the password equivalent for remote user operation. The device shall
+/// support the
+/// GetEndpointReference command returning the address property of the device
+/// service
+/// endpoint reference.
 pub async fn get_endpoint_reference<T: transport::Transport>(
     transport: &T,
     request: &GetEndpointReference,
@@ -2851,11 +2853,11 @@ pub async fn get_endpoint_reference<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation returns the configured remote user (if any). A device
-// supporting remote user
-// handling shall support this operation. The user is only valid for the
-// WS-UserToken profile or
-// as a HTTP / RTSP user.
+/// This operation returns the configured remote user (if any). A device
+/// supporting remote user
+/// handling shall support this operation. The user is only valid for the
+/// WS-UserToken profile or
+/// as a HTTP / RTSP user.
 pub async fn get_remote_user<T: transport::Transport>(
     transport: &T,
     request: &GetRemoteUser,
@@ -2863,10 +2865,10 @@ pub async fn get_remote_user<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation sets the remote user. A device supporting remote user handling
-// shall support this
-// operation. The user is only valid for the WS-UserToken profile or as a HTTP /
-// RTSP user.
+/// This operation sets the remote user. A device supporting remote user handling
+/// shall support this
+/// operation. The user is only valid for the WS-UserToken profile or as a HTTP /
+/// RTSP user.
 pub async fn set_remote_user<T: transport::Transport>(
     transport: &T,
     request: &SetRemoteUser,
@@ -2874,11 +2876,11 @@ pub async fn set_remote_user<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation lists the registered users and corresponding credentials on a
-// device. The
-// device shall support retrieval of registered device users and their
-// credentials for the user
-// token through the GetUsers command.
+/// This operation lists the registered users and corresponding credentials on a
+/// device. The
+/// device shall support retrieval of registered device users and their
+/// credentials for the user
+/// token through the GetUsers command.
 pub async fn get_users<T: transport::Transport>(
     transport: &T,
     request: &GetUsers,
@@ -2886,13 +2888,13 @@ pub async fn get_users<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation creates new device users and corresponding credentials on a
-// device for authentication purposes.
-// The device shall support creation of device users and their credentials
-// through the CreateUsers
-// command. Either all users are created successfully or a fault message shall
-// be returned
-// without creating any user.
+/// This operation creates new device users and corresponding credentials on a
+/// device for authentication purposes.
+/// The device shall support creation of device users and their credentials
+/// through the CreateUsers
+/// command. Either all users are created successfully or a fault message shall
+/// be returned
+/// without creating any user.
 pub async fn create_users<T: transport::Transport>(
     transport: &T,
     request: &CreateUsers,
@@ -2900,12 +2902,12 @@ pub async fn create_users<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation deletes users on a device. The device shall support deletion
-// of device users and their credentials
-// through the DeleteUsers command. A device may have one or more fixed users
-// that cannot be deleted to ensure access to the unit. Either all users are
-// deleted successfully or a
-// fault message shall be returned and no users be deleted.
+/// This operation deletes users on a device. The device shall support deletion
+/// of device users and their credentials
+/// through the DeleteUsers command. A device may have one or more fixed users
+/// that cannot be deleted to ensure access to the unit. Either all users are
+/// deleted successfully or a
+/// fault message shall be returned and no users be deleted.
 pub async fn delete_users<T: transport::Transport>(
     transport: &T,
     request: &DeleteUsers,
@@ -2913,12 +2915,12 @@ pub async fn delete_users<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation updates the settings for one or several users on a device for
-// authentication purposes.
-// The device shall support update of device users and their credentials through
-// the SetUser command.
-// Either all change requests are processed successfully or a fault message
-// shall be returned and no change requests be processed.
+/// This operation updates the settings for one or several users on a device for
+/// authentication purposes.
+/// The device shall support update of device users and their credentials through
+/// the SetUser command.
+/// Either all change requests are processed successfully or a fault message
+/// shall be returned and no change requests be processed.
 pub async fn set_user<T: transport::Transport>(
     transport: &T,
     request: &SetUser,
@@ -2926,14 +2928,14 @@ pub async fn set_user<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// It is possible for an endpoint to request a URL that can be used to retrieve
-// the complete
-// schema and WSDL definitions of a device. The command gives in return a URL
-// entry point
-// where all the necessary product specific WSDL and schema definitions can be
-// retrieved. The
-// device shall provide a URL for WSDL and schema download through the
-// GetWsdlUrl command.
+/// It is possible for an endpoint to request a URL that can be used to retrieve
+/// the complete
+/// schema and WSDL definitions of a device. The command gives in return a URL
+/// entry point
+/// where all the necessary product specific WSDL and schema definitions can be
+/// retrieved. The
+/// device shall provide a URL for WSDL and schema download through the
+/// GetWsdlUrl command.
 pub async fn get_wsdl_url<T: transport::Transport>(
     transport: &T,
     request: &GetWsdlUrl,
@@ -2941,9 +2943,9 @@ pub async fn get_wsdl_url<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This method has been replaced by the more generic GetServices method.
-// For capabilities of individual services refer to the GetServiceCapabilities
-// methods.
+/// This method has been replaced by the more generic GetServices method.
+/// For capabilities of individual services refer to the GetServiceCapabilities
+/// methods.
 pub async fn get_capabilities<T: transport::Transport>(
     transport: &T,
     request: &GetCapabilities,
@@ -2951,9 +2953,9 @@ pub async fn get_capabilities<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation is used by an endpoint to get the hostname from a device. The
-// device shall
-// return its hostname configurations through the GetHostname command.
+/// This operation is used by an endpoint to get the hostname from a device. The
+/// device shall
+/// return its hostname configurations through the GetHostname command.
 pub async fn get_hostname<T: transport::Transport>(
     transport: &T,
     request: &GetHostname,
@@ -2961,9 +2963,9 @@ pub async fn get_hostname<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation sets the hostname on a device. It shall be possible to set the
-// device hostname
-// configurations through the SetHostname command.
+/// This operation sets the hostname on a device. It shall be possible to set the
+/// device hostname
+/// configurations through the SetHostname command.
 pub async fn set_hostname<T: transport::Transport>(
     transport: &T,
     request: &SetHostname,
@@ -2971,8 +2973,8 @@ pub async fn set_hostname<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation controls whether the hostname is set manually or retrieved via
-// DHCP.
+/// This operation controls whether the hostname is set manually or retrieved via
+/// DHCP.
 pub async fn set_hostname_from_dhcp<T: transport::Transport>(
     transport: &T,
     request: &SetHostnameFromDHCP,
@@ -2980,9 +2982,9 @@ pub async fn set_hostname_from_dhcp<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation gets the DNS settings from a device. The device shall return
-// its DNS
-// configurations through the GetDNS command.
+/// This operation gets the DNS settings from a device. The device shall return
+/// its DNS
+/// configurations through the GetDNS command.
 pub async fn get_dns<T: transport::Transport>(
     transport: &T,
     request: &GetDNS,
@@ -2990,9 +2992,9 @@ pub async fn get_dns<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation sets the DNS settings on a device. It shall be possible to set
-// the device DNS
-// configurations through the SetDNS command.
+/// This operation sets the DNS settings on a device. It shall be possible to set
+/// the device DNS
+/// configurations through the SetDNS command.
 pub async fn set_dns<T: transport::Transport>(
     transport: &T,
     request: &SetDNS,
@@ -3000,9 +3002,9 @@ pub async fn set_dns<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation gets the NTP settings from a device. If the device supports
-// NTP, it shall be
-// possible to get the NTP server settings through the GetNTP command.
+/// This operation gets the NTP settings from a device. If the device supports
+/// NTP, it shall be
+/// possible to get the NTP server settings through the GetNTP command.
 pub async fn get_ntp<T: transport::Transport>(
     transport: &T,
     request: &GetNTP,
@@ -3010,9 +3012,9 @@ pub async fn get_ntp<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation sets the NTP settings on a device. If the device supports NTP,
-// it shall be
-// possible to set the NTP server settings through the SetNTP command.
+/// This operation sets the NTP settings on a device. If the device supports NTP,
+/// it shall be
+/// possible to set the NTP server settings through the SetNTP command.
 pub async fn set_ntp<T: transport::Transport>(
     transport: &T,
     request: &SetNTP,
@@ -3020,11 +3022,11 @@ pub async fn set_ntp<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation gets the dynamic DNS settings from a device. If the device
-// supports dynamic
-// DNS as specified in [RFC 2136] and [RFC 4702], it shall be possible to get
-// the type, name
-// and TTL through the GetDynamicDNS command.
+/// This operation gets the dynamic DNS settings from a device. If the device
+/// supports dynamic
+/// DNS as specified in [RFC 2136] and [RFC 4702], it shall be possible to get
+/// the type, name
+/// and TTL through the GetDynamicDNS command.
 pub async fn get_dynamic_dns<T: transport::Transport>(
     transport: &T,
     request: &GetDynamicDNS,
@@ -3032,11 +3034,11 @@ pub async fn get_dynamic_dns<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation sets the dynamic DNS settings on a device. If the device
-// supports dynamic
-// DNS as specified in [RFC 2136] and [RFC 4702], it shall be possible to set
-// the type, name
-// and TTL through the SetDynamicDNS command.
+/// This operation sets the dynamic DNS settings on a device. If the device
+/// supports dynamic
+/// DNS as specified in [RFC 2136] and [RFC 4702], it shall be possible to set
+/// the type, name
+/// and TTL through the SetDynamicDNS command.
 pub async fn set_dynamic_dns<T: transport::Transport>(
     transport: &T,
     request: &SetDynamicDNS,
@@ -3044,11 +3046,11 @@ pub async fn set_dynamic_dns<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation gets the network interface configuration from a device. The
-// device shall
-// support return of network interface configuration settings as defined by the
-// NetworkInterface
-// type through the GetNetworkInterfaces command.
+/// This operation gets the network interface configuration from a device. The
+/// device shall
+/// support return of network interface configuration settings as defined by the
+/// NetworkInterface
+/// type through the GetNetworkInterfaces command.
 pub async fn get_network_interfaces<T: transport::Transport>(
     transport: &T,
     request: &GetNetworkInterfaces,
@@ -3056,11 +3058,11 @@ pub async fn get_network_interfaces<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation sets the network interface configuration on a device. The
-// device shall support
-// network configuration of supported network interfaces through the
-// SetNetworkInterfaces
-// command.
+/// This operation sets the network interface configuration on a device. The
+/// device shall support
+/// network configuration of supported network interfaces through the
+/// SetNetworkInterfaces
+/// command.
 pub async fn set_network_interfaces<T: transport::Transport>(
     transport: &T,
     request: &SetNetworkInterfaces,
@@ -3068,9 +3070,9 @@ pub async fn set_network_interfaces<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation gets defined network protocols from a device. The device shall
-// support the
-// GetNetworkProtocols command returning configured network protocols.
+/// This operation gets defined network protocols from a device. The device shall
+/// support the
+/// GetNetworkProtocols command returning configured network protocols.
 pub async fn get_network_protocols<T: transport::Transport>(
     transport: &T,
     request: &GetNetworkProtocols,
@@ -3078,10 +3080,10 @@ pub async fn get_network_protocols<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation configures defined network protocols on a device. The device
-// shall support
-// configuration of defined network protocols through the SetNetworkProtocols
-// command.
+/// This operation configures defined network protocols on a device. The device
+/// shall support
+/// configuration of defined network protocols through the SetNetworkProtocols
+/// command.
 pub async fn set_network_protocols<T: transport::Transport>(
     transport: &T,
     request: &SetNetworkProtocols,
@@ -3089,10 +3091,10 @@ pub async fn set_network_protocols<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation gets the default gateway settings from a device. The device
-// shall support the
-// GetNetworkDefaultGateway command returning configured default gateway
-// address(es).
+/// This operation gets the default gateway settings from a device. The device
+/// shall support the
+/// GetNetworkDefaultGateway command returning configured default gateway
+/// address(es).
 pub async fn get_network_default_gateway<T: transport::Transport>(
     transport: &T,
     request: &GetNetworkDefaultGateway,
@@ -3100,10 +3102,10 @@ pub async fn get_network_default_gateway<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation sets the default gateway settings on a device. The device
-// shall support
-// configuration of default gateway through the SetNetworkDefaultGateway
-// command.
+/// This operation sets the default gateway settings on a device. The device
+/// shall support
+/// configuration of default gateway through the SetNetworkDefaultGateway
+/// command.
 pub async fn set_network_default_gateway<T: transport::Transport>(
     transport: &T,
     request: &SetNetworkDefaultGateway,
@@ -3111,11 +3113,11 @@ pub async fn set_network_default_gateway<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation gets the zero-configuration from a device. If the device
-// supports dynamic IP
-// configuration according to [RFC3927], it shall support the return of IPv4
-// zero configuration
-// address and status through the GetZeroConfiguration command.
+/// This operation gets the zero-configuration from a device. If the device
+/// supports dynamic IP
+/// configuration according to [RFC3927], it shall support the return of IPv4
+/// zero configuration
+/// address and status through the GetZeroConfiguration command.
 pub async fn get_zero_configuration<T: transport::Transport>(
     transport: &T,
     request: &GetZeroConfiguration,
@@ -3123,8 +3125,8 @@ pub async fn get_zero_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation sets the zero-configuration. Use GetCapalities to get if
-// zero-zero-configuration is supported or not.
+/// This operation sets the zero-configuration. Use GetCapalities to get if
+/// zero-zero-configuration is supported or not.
 pub async fn set_zero_configuration<T: transport::Transport>(
     transport: &T,
     request: &SetZeroConfiguration,
@@ -3132,11 +3134,11 @@ pub async fn set_zero_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation gets the IP address filter settings from a device. If the
-// device supports device
-// access control based on IP filtering rules (denied or accepted ranges of IP
-// addresses), the
-// device shall support the GetIPAddressFilter command.
+/// This operation gets the IP address filter settings from a device. If the
+/// device supports device
+/// access control based on IP filtering rules (denied or accepted ranges of IP
+/// addresses), the
+/// device shall support the GetIPAddressFilter command.
 pub async fn get_ip_address_filter<T: transport::Transport>(
     transport: &T,
     request: &GetIPAddressFilter,
@@ -3144,13 +3146,13 @@ pub async fn get_ip_address_filter<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation sets the IP address filter settings on a device. If the device
-// supports device
-// access control based on IP filtering rules (denied or accepted ranges of IP
-// addresses), the
-// device shall support configuration of IP filtering rules through the
-// SetIPAddressFilter
-// command.
+/// This operation sets the IP address filter settings on a device. If the device
+/// supports device
+/// access control based on IP filtering rules (denied or accepted ranges of IP
+/// addresses), the
+/// device shall support configuration of IP filtering rules through the
+/// SetIPAddressFilter
+/// command.
 pub async fn set_ip_address_filter<T: transport::Transport>(
     transport: &T,
     request: &SetIPAddressFilter,
@@ -3158,12 +3160,12 @@ pub async fn set_ip_address_filter<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation adds an IP filter address to a device. If the device supports
-// device access
-// control based on IP filtering rules (denied or accepted ranges of IP
-// addresses), the device
-// shall support adding of IP filtering addresses through the AddIPAddressFilter
-// command.
+/// This operation adds an IP filter address to a device. If the device supports
+/// device access
+/// control based on IP filtering rules (denied or accepted ranges of IP
+/// addresses), the device
+/// shall support adding of IP filtering addresses through the AddIPAddressFilter
+/// command.
 pub async fn add_ip_address_filter<T: transport::Transport>(
     transport: &T,
     request: &AddIPAddressFilter,
@@ -3171,12 +3173,12 @@ pub async fn add_ip_address_filter<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation deletes an IP filter address from a device. If the device
-// supports device access
-// control based on IP filtering rules (denied or accepted ranges of IP
-// addresses), the device
-// shall support deletion of IP filtering addresses through the
-// RemoveIPAddressFilter command.
+/// This operation deletes an IP filter address from a device. If the device
+/// supports device access
+/// control based on IP filtering rules (denied or accepted ranges of IP
+/// addresses), the device
+/// shall support deletion of IP filtering addresses through the
+/// RemoveIPAddressFilter command.
 pub async fn remove_ip_address_filter<T: transport::Transport>(
     transport: &T,
     request: &RemoveIPAddressFilter,
@@ -3184,21 +3186,21 @@ pub async fn remove_ip_address_filter<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Access to different services and sub-sets of services should be subject to
-// access control. The
-// WS-Security framework gives the prerequisite for end-point authentication.
-// Authorization
-// decisions can then be taken using an access security policy. This standard
-// does not mandate
-// any particular policy description format or security policy but this is up to
-// the device
-// manufacturer or system provider to choose policy and policy description
-// format of choice.
-// However, an access policy (in arbitrary format) can be requested using this
-// command. If the
-// device supports access policy settings based on WS-Security authentication,
-// then the device
-// shall support this command.
+/// Access to different services and sub-sets of services should be subject to
+/// access control. The
+/// WS-Security framework gives the prerequisite for end-point authentication.
+/// Authorization
+/// decisions can then be taken using an access security policy. This standard
+/// does not mandate
+/// any particular policy description format or security policy but this is up to
+/// the device
+/// manufacturer or system provider to choose policy and policy description
+/// format of choice.
+/// However, an access policy (in arbitrary format) can be requested using this
+/// command. If the
+/// device supports access policy settings based on WS-Security authentication,
+/// then the device
+/// shall support this command.
 pub async fn get_access_policy<T: transport::Transport>(
     transport: &T,
     request: &GetAccessPolicy,
@@ -3206,11 +3208,11 @@ pub async fn get_access_policy<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This command sets the device access security policy (for more details on the
-// access security
-// policy see the Get command). If the device supports access policy settings
-// based on WS-Security authentication, then the device shall support this
-// command.
+/// This command sets the device access security policy (for more details on the
+/// access security
+/// policy see the Get command). If the device supports access policy settings
+/// based on WS-Security authentication, then the device shall support this
+/// command.
 pub async fn set_access_policy<T: transport::Transport>(
     transport: &T,
     request: &SetAccessPolicy,
@@ -3218,11 +3220,11 @@ pub async fn set_access_policy<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation generates a private/public key pair and also can create a
-// self-signed device
-// certificate as a result of key pair generation. The certificate is created
-// using a suitable
-// onboard key pair generation mechanism.
+/// This operation generates a private/public key pair and also can create a
+/// self-signed device
+/// certificate as a result of key pair generation. The certificate is created
+/// using a suitable
+/// onboard key pair generation mechanism.
 pub async fn create_certificate<T: transport::Transport>(
     transport: &T,
     request: &CreateCertificate,
@@ -3230,19 +3232,19 @@ pub async fn create_certificate<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation gets all device server certificates (including self-signed)
-// for the purpose of TLS
-// authentication and all device client certificates for the purpose of IEEE
-// 802.1X authentication.
-// This command lists only the TLS server certificates and IEEE 802.1X client
-// certificates for the
-// device (neither trusted CA certificates nor trusted root certificates). The
-// certificates are
-// returned as binary data. A device that supports TLS shall support this
-// command and the
-// certificates shall be encoded using ASN.1 [X.681], [X.682], [X.683] DER
-// [X.690] encoding
-// rules.
+/// This operation gets all device server certificates (including self-signed)
+/// for the purpose of TLS
+/// authentication and all device client certificates for the purpose of IEEE
+/// 802.1X authentication.
+/// This command lists only the TLS server certificates and IEEE 802.1X client
+/// certificates for the
+/// device (neither trusted CA certificates nor trusted root certificates). The
+/// certificates are
+/// returned as binary data. A device that supports TLS shall support this
+/// command and the
+/// certificates shall be encoded using ASN.1 [X.681], [X.682], [X.683] DER
+/// [X.690] encoding
+/// rules.
 pub async fn get_certificates<T: transport::Transport>(
     transport: &T,
     request: &GetCertificates,
@@ -3250,11 +3252,11 @@ pub async fn get_certificates<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation is specific to TLS functionality. This operation gets the
-// status
-// (enabled/disabled) of the device TLS server certificates. A device that
-// supports TLS shall
-// support this command.
+/// This operation is specific to TLS functionality. This operation gets the
+/// status
+/// (enabled/disabled) of the device TLS server certificates. A device that
+/// supports TLS shall
+/// support this command.
 pub async fn get_certificates_status<T: transport::Transport>(
     transport: &T,
     request: &GetCertificatesStatus,
@@ -3262,12 +3264,12 @@ pub async fn get_certificates_status<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation is specific to TLS functionality. This operation sets the
-// status (enable/disable)
-// of the device TLS server certificates. A device that supports TLS shall
-// support this command.
-// Typically only one device server certificate is allowed to be enabled at a
-// time.
+/// This operation is specific to TLS functionality. This operation sets the
+/// status (enable/disable)
+/// of the device TLS server certificates. A device that supports TLS shall
+/// support this command.
+/// Typically only one device server certificate is allowed to be enabled at a
+/// time.
 pub async fn set_certificates_status<T: transport::Transport>(
     transport: &T,
     request: &SetCertificatesStatus,
@@ -3275,15 +3277,15 @@ pub async fn set_certificates_status<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation deletes a certificate or multiple certificates. The device MAY
-// also delete a
-// private/public key pair which is coupled with the certificate to be deleted.
-// The device that
-// support either TLS or IEEE 802.1X shall support the deletion of a certificate
-// or multiple
-// certificates through this command. Either all certificates are deleted
-// successfully or a fault
-// message shall be returned without deleting any certificate.
+/// This operation deletes a certificate or multiple certificates. The device MAY
+/// also delete a
+/// private/public key pair which is coupled with the certificate to be deleted.
+/// The device that
+/// support either TLS or IEEE 802.1X shall support the deletion of a certificate
+/// or multiple
+/// certificates through this command. Either all certificates are deleted
+/// successfully or a fault
+/// message shall be returned without deleting any certificate.
 pub async fn delete_certificates<T: transport::Transport>(
     transport: &T,
     request: &DeleteCertificates,
@@ -3291,17 +3293,17 @@ pub async fn delete_certificates<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation requests a PKCS #10 certificate signature request from the
-// device. The
-// returned information field shall be either formatted exactly as specified in
-// [PKCS#10] or PEM
-// encoded [PKCS#10] format. In order for this command to work, the device must
-// already have
-// a private/public key pair. This key pair should be referred by CertificateID
-// as specified in the
-// input parameter description. This CertificateID refers to the key pair
-// generated using
-// CreateCertificate command.
+/// This operation requests a PKCS #10 certificate signature request from the
+/// device. The
+/// returned information field shall be either formatted exactly as specified in
+/// [PKCS#10] or PEM
+/// encoded [PKCS#10] format. In order for this command to work, the device must
+/// already have
+/// a private/public key pair. This key pair should be referred by CertificateID
+/// as specified in the
+/// input parameter description. This CertificateID refers to the key pair
+/// generated using
+/// CreateCertificate command.
 pub async fn get_pkcs_10_request<T: transport::Transport>(
     transport: &T,
     request: &GetPkcs10Request,
@@ -3309,21 +3311,21 @@ pub async fn get_pkcs_10_request<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// TLS server certificate(s) or IEEE 802.1X client certificate(s) created using
-// the PKCS#10
-// certificate request command can be loaded into the device using this command
-// (see Section
-// 8.4.13). The certificate ID in the request shall be present. The device may
-// sort the received
-// certificate(s) based on the public key and subject information in the
-// certificate(s).
-// The certificate ID in the request will be the ID value the client wish to
-// have. The device is
-// supposed to scan the generated key pairs present in the device to identify
-// which is the
-// correspondent key pair with the loaded certificate and then make the link
-// between the
-// certificate and the key pair.
+/// TLS server certificate(s) or IEEE 802.1X client certificate(s) created using
+/// the PKCS#10
+/// certificate request command can be loaded into the device using this command
+/// (see Section
+/// 8.4.13). The certificate ID in the request shall be present. The device may
+/// sort the received
+/// certificate(s) based on the public key and subject information in the
+/// certificate(s).
+/// The certificate ID in the request will be the ID value the client wish to
+/// have. The device is
+/// supposed to scan the generated key pairs present in the device to identify
+/// which is the
+/// correspondent key pair with the loaded certificate and then make the link
+/// between the
+/// certificate and the key pair.
 pub async fn load_certificates<T: transport::Transport>(
     transport: &T,
     request: &LoadCertificates,
@@ -3331,11 +3333,11 @@ pub async fn load_certificates<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation is specific to TLS functionality. This operation gets the
-// status
-// (enabled/disabled) of the device TLS client authentication. A device that
-// supports TLS shall
-// support this command.
+/// This operation is specific to TLS functionality. This operation gets the
+/// status
+/// (enabled/disabled) of the device TLS client authentication. A device that
+/// supports TLS shall
+/// support this command.
 pub async fn get_client_certificate_mode<T: transport::Transport>(
     transport: &T,
     request: &GetClientCertificateMode,
@@ -3343,11 +3345,11 @@ pub async fn get_client_certificate_mode<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation is specific to TLS functionality. This operation sets the
-// status
-// (enabled/disabled) of the device TLS client authentication. A device that
-// supports TLS shall
-// support this command.
+/// This operation is specific to TLS functionality. This operation sets the
+/// status
+/// (enabled/disabled) of the device TLS client authentication. A device that
+/// supports TLS shall
+/// support this command.
 pub async fn set_client_certificate_mode<T: transport::Transport>(
     transport: &T,
     request: &SetClientCertificateMode,
@@ -3355,7 +3357,7 @@ pub async fn set_client_certificate_mode<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation gets a list of all available relay outputs and their settings.
+/// This operation gets a list of all available relay outputs and their settings.
 pub async fn get_relay_outputs<T: transport::Transport>(
     transport: &T,
     request: &GetRelayOutputs,
@@ -3363,7 +3365,7 @@ pub async fn get_relay_outputs<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation sets the settings of a relay output.
+/// This operation sets the settings of a relay output.
 pub async fn set_relay_output_settings<T: transport::Transport>(
     transport: &T,
     request: &SetRelayOutputSettings,
@@ -3371,7 +3373,7 @@ pub async fn set_relay_output_settings<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation sets the state of a relay output.
+/// This operation sets the state of a relay output.
 pub async fn set_relay_output_state<T: transport::Transport>(
     transport: &T,
     request: &SetRelayOutputState,
@@ -3379,9 +3381,9 @@ pub async fn set_relay_output_state<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Manage auxiliary commands supported by a device, such as controlling an
-// Infrared (IR) lamp,
-// a heater or a wiper or a thermometer that is connected to the device.
+/// Manage auxiliary commands supported by a device, such as controlling an
+/// Infrared (IR) lamp,
+/// a heater or a wiper or a thermometer that is connected to the device.
 pub async fn send_auxiliary_command<T: transport::Transport>(
     transport: &T,
     request: &SendAuxiliaryCommand,
@@ -3389,17 +3391,17 @@ pub async fn send_auxiliary_command<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// CA certificates will be loaded into a device and be used for the sake of
-// following two cases.
-// The one is for the purpose of TLS client authentication in TLS server
-// function. The other one
-// is for the purpose of Authentication Server authentication in IEEE 802.1X
-// function. This
-// operation gets all CA certificates loaded into a device. A device that
-// supports either TLS client
-// authentication or IEEE 802.1X shall support this command and the returned
-// certificates shall
-// be encoded using ASN.1 [X.681], [X.682], [X.683] DER [X.690] encoding rules.
+/// CA certificates will be loaded into a device and be used for the sake of
+/// following two cases.
+/// The one is for the purpose of TLS client authentication in TLS server
+/// function. The other one
+/// is for the purpose of Authentication Server authentication in IEEE 802.1X
+/// function. This
+/// operation gets all CA certificates loaded into a device. A device that
+/// supports either TLS client
+/// authentication or IEEE 802.1X shall support this command and the returned
+/// certificates shall
+/// be encoded using ASN.1 [X.681], [X.682], [X.683] DER [X.690] encoding rules.
 pub async fn get_ca_certificates<T: transport::Transport>(
     transport: &T,
     request: &GetCACertificates,
@@ -3407,17 +3409,17 @@ pub async fn get_ca_certificates<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// There might be some cases that a Certificate Authority or some other
-// equivalent creates a
-// certificate without having PKCS#10 certificate signing request. In such
-// cases, the certificate
-// will be bundled in conjunction with its private key. This command will be
-// used for such use
-// case scenarios. The certificate ID in the request is optionally set to the ID
-// value the client
-// wish to have. If the certificate ID is not specified in the request, device
-// can choose the ID
-// accordingly.
+/// There might be some cases that a Certificate Authority or some other
+/// equivalent creates a
+/// certificate without having PKCS#10 certificate signing request. In such
+/// cases, the certificate
+/// will be bundled in conjunction with its private key. This command will be
+/// used for such use
+/// case scenarios. The certificate ID in the request is optionally set to the ID
+/// value the client
+/// wish to have. If the certificate ID is not specified in the request, device
+/// can choose the ID
+/// accordingly.
 pub async fn load_certificate_with_private_key<T: transport::Transport>(
     transport: &T,
     request: &LoadCertificateWithPrivateKey,
@@ -3425,15 +3427,15 @@ pub async fn load_certificate_with_private_key<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation requests the information of a certificate specified by
-// certificate ID. The device
-// should respond with its “Issuer DN”, “Subject DN”, “Key usage”,
-// "Extended key usage”, “Key
-// Length”, “Version”, “Serial Number”, “Signature Algorithm” and
-// “Validity” data as the
-// information of the certificate, as long as the device can retrieve such
-// information from the
-// specified certificate.
+/// This operation requests the information of a certificate specified by
+/// certificate ID. The device
+/// should respond with its “Issuer DN”, “Subject DN”, “Key usage”,
+/// "Extended key usage”, “Key
+/// Length”, “Version”, “Serial Number”, “Signature Algorithm” and
+/// “Validity” data as the
+/// information of the certificate, as long as the device can retrieve such
+/// information from the
+/// specified certificate.
 pub async fn get_certificate_information<T: transport::Transport>(
     transport: &T,
     request: &GetCertificateInformation,
@@ -3441,11 +3443,11 @@ pub async fn get_certificate_information<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This command is used when it is necessary to load trusted CA certificates or
-// trusted root
-// certificates for the purpose of verification for its counterpart i.e. client
-// certificate verification in
-// TLS function or server certificate verification in IEEE 802.1X function.
+/// This command is used when it is necessary to load trusted CA certificates or
+/// trusted root
+/// certificates for the purpose of verification for its counterpart i.e. client
+/// certificate verification in
+/// TLS function or server certificate verification in IEEE 802.1X function.
 pub async fn load_ca_certificates<T: transport::Transport>(
     transport: &T,
     request: &LoadCACertificates,
@@ -3453,15 +3455,15 @@ pub async fn load_ca_certificates<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation newly creates IEEE 802.1X configuration parameter set of the
-// device. The
-// device shall support this command if it supports IEEE 802.1X. If the device
-// receives this
-// request with already existing configuration token (Dot1XConfigurationToken)
-// specification, the
-// device should respond with 'ter:ReferenceToken ' error to indicate there is
-// some configuration
-// conflict.
+/// This operation newly creates IEEE 802.1X configuration parameter set of the
+/// device. The
+/// device shall support this command if it supports IEEE 802.1X. If the device
+/// receives this
+/// request with already existing configuration token (Dot1XConfigurationToken)
+/// specification, the
+/// device should respond with 'ter:ReferenceToken ' error to indicate there is
+/// some configuration
+/// conflict.
 pub async fn create_dot_1x_configuration<T: transport::Transport>(
     transport: &T,
     request: &CreateDot1XConfiguration,
@@ -3469,11 +3471,11 @@ pub async fn create_dot_1x_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// While the CreateDot1XConfiguration command is trying to create a new
-// configuration
-// parameter set, this operation modifies existing IEEE 802.1X configuration
-// parameter set of
-// the device. A device that support IEEE 802.1X shall support this command.
+/// While the CreateDot1XConfiguration command is trying to create a new
+/// configuration
+/// parameter set, this operation modifies existing IEEE 802.1X configuration
+/// parameter set of
+/// the device. A device that support IEEE 802.1X shall support this command.
 pub async fn set_dot_1x_configuration<T: transport::Transport>(
     transport: &T,
     request: &SetDot1XConfiguration,
@@ -3481,9 +3483,9 @@ pub async fn set_dot_1x_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation gets one IEEE 802.1X configuration parameter set from the
-// device by
-// specifying the configuration token (Dot1XConfigurationToken).
+/// This operation gets one IEEE 802.1X configuration parameter set from the
+/// device by
+/// specifying the configuration token (Dot1XConfigurationToken).
 pub async fn get_dot_1x_configuration<T: transport::Transport>(
     transport: &T,
     request: &GetDot1XConfiguration,
@@ -3491,12 +3493,12 @@ pub async fn get_dot_1x_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation gets all the existing IEEE 802.1X configuration parameter sets
-// from the device.
-// The device shall respond with all the IEEE 802.1X configurations so that the
-// client can get to
-// know how many IEEE 802.1X configurations are existing and how they are
-// configured.
+/// This operation gets all the existing IEEE 802.1X configuration parameter sets
+/// from the device.
+/// The device shall respond with all the IEEE 802.1X configurations so that the
+/// client can get to
+/// know how many IEEE 802.1X configurations are existing and how they are
+/// configured.
 pub async fn get_dot_1x_configurations<T: transport::Transport>(
     transport: &T,
     request: &GetDot1XConfigurations,
@@ -3504,11 +3506,11 @@ pub async fn get_dot_1x_configurations<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation deletes an IEEE 802.1X configuration parameter set from the
-// device. Which
-// configuration should be deleted is specified by the 'Dot1XConfigurationToken'
-// in the request.
-// A device that support IEEE 802.1X shall support this command.
+/// This operation deletes an IEEE 802.1X configuration parameter set from the
+/// device. Which
+/// configuration should be deleted is specified by the 'Dot1XConfigurationToken'
+/// in the request.
+/// A device that support IEEE 802.1X shall support this command.
 pub async fn delete_dot_1x_configuration<T: transport::Transport>(
     transport: &T,
     request: &DeleteDot1XConfiguration,
@@ -3516,8 +3518,8 @@ pub async fn delete_dot_1x_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation returns the IEEE802.11 capabilities. The device shall support
-// this operation.
+/// This operation returns the IEEE802.11 capabilities. The device shall support
+/// this operation.
 pub async fn get_dot_11_capabilities<T: transport::Transport>(
     transport: &T,
     request: &GetDot11Capabilities,
@@ -3525,9 +3527,9 @@ pub async fn get_dot_11_capabilities<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation returns the status of a wireless network interface. The device
-// shall support this
-// command.
+/// This operation returns the status of a wireless network interface. The device
+/// shall support this
+/// command.
 pub async fn get_dot_11_status<T: transport::Transport>(
     transport: &T,
     request: &GetDot11Status,
@@ -3535,9 +3537,9 @@ pub async fn get_dot_11_status<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation returns a lists of the wireless networks in range of the
-// device. A device should
-// support this operation.
+/// This operation returns a lists of the wireless networks in range of the
+/// device. A device should
+/// support this operation.
 pub async fn scan_available_dot_11_networks<T: transport::Transport>(
     transport: &T,
     request: &ScanAvailableDot11Networks,
@@ -3545,9 +3547,9 @@ pub async fn scan_available_dot_11_networks<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation is used to retrieve URIs from which system information may be
-// downloaded
-// using HTTP. URIs may be returned for the following system information:
+/// This operation is used to retrieve URIs from which system information may be
+/// downloaded
+/// using HTTP. URIs may be returned for the following system information:
 pub async fn get_system_uris<T: transport::Transport>(
     transport: &T,
     request: &GetSystemUris,
@@ -3555,16 +3557,16 @@ pub async fn get_system_uris<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation initiates a firmware upgrade using the HTTP POST mechanism.
-// The response
-// to the command includes an HTTP URL to which the upgrade file may be
-// uploaded. The
-// actual upgrade takes place as soon as the HTTP POST operation has completed.
-// The device
-// should support firmware upgrade through the StartFirmwareUpgrade command. The
-// exact
-// format of the firmware data is outside the scope of this specification.
-// Firmware upgrade over HTTP may be achieved using the following steps:
+/// This operation initiates a firmware upgrade using the HTTP POST mechanism.
+/// The response
+/// to the command includes an HTTP URL to which the upgrade file may be
+/// uploaded. The
+/// actual upgrade takes place as soon as the HTTP POST operation has completed.
+/// The device
+/// should support firmware upgrade through the StartFirmwareUpgrade command. The
+/// exact
+/// format of the firmware data is outside the scope of this specification.
+/// Firmware upgrade over HTTP may be achieved using the following steps:
 pub async fn start_firmware_upgrade<T: transport::Transport>(
     transport: &T,
     request: &StartFirmwareUpgrade,
@@ -3572,17 +3574,17 @@ pub async fn start_firmware_upgrade<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation initiates a system restore from backed up configuration data
-// using the HTTP
-// POST mechanism. The response to the command includes an HTTP URL to which the
-// backup
-// file may be uploaded. The actual restore takes place as soon as the HTTP POST
-// operation
-// has completed. Devices should support system restore through the
-// StartSystemRestore
-// command. The exact format of the backup configuration data is outside the
-// scope of this
-// specification.
+/// This operation initiates a system restore from backed up configuration data
+/// using the HTTP
+/// POST mechanism. The response to the command includes an HTTP URL to which the
+/// backup
+/// file may be uploaded. The actual restore takes place as soon as the HTTP POST
+/// operation
+/// has completed. Devices should support system restore through the
+/// StartSystemRestore
+/// command. The exact format of the backup configuration data is outside the
+/// scope of this
+/// specification.
 pub async fn start_system_restore<T: transport::Transport>(
     transport: &T,
     request: &StartSystemRestore,
@@ -3590,7 +3592,7 @@ pub async fn start_system_restore<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation lists all existing storage configurations for the device.
+/// This operation lists all existing storage configurations for the device.
 pub async fn get_storage_configurations<T: transport::Transport>(
     transport: &T,
     request: &GetStorageConfigurations,
@@ -3598,9 +3600,9 @@ pub async fn get_storage_configurations<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation creates a new storage configuration.
-// The configuration data shall be created in the device and shall be persistent
-// (remain after reboot).
+/// This operation creates a new storage configuration.
+/// The configuration data shall be created in the device and shall be persistent
+/// (remain after reboot).
 pub async fn create_storage_configuration<T: transport::Transport>(
     transport: &T,
     request: &CreateStorageConfiguration,
@@ -3608,8 +3610,8 @@ pub async fn create_storage_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation retrieves the Storage configuration associated with the given
-// storage configuration token.
+/// This operation retrieves the Storage configuration associated with the given
+/// storage configuration token.
 pub async fn get_storage_configuration<T: transport::Transport>(
     transport: &T,
     request: &GetStorageConfiguration,
@@ -3617,7 +3619,7 @@ pub async fn get_storage_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation modifies an existing Storage configuration.
+/// This operation modifies an existing Storage configuration.
 pub async fn set_storage_configuration<T: transport::Transport>(
     transport: &T,
     request: &SetStorageConfiguration,
@@ -3625,8 +3627,8 @@ pub async fn set_storage_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation deletes the given storage configuration and configuration
-// change shall always be persistent.
+/// This operation deletes the given storage configuration and configuration
+/// change shall always be persistent.
 pub async fn delete_storage_configuration<T: transport::Transport>(
     transport: &T,
     request: &DeleteStorageConfiguration,
@@ -3634,7 +3636,7 @@ pub async fn delete_storage_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation lists all existing geo location configurations for the device.
+/// This operation lists all existing geo location configurations for the device.
 pub async fn get_geo_location<T: transport::Transport>(
     transport: &T,
     request: &GetGeoLocation,
@@ -3642,7 +3644,7 @@ pub async fn get_geo_location<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation allows to modify one or more geo configuration entries.
+/// This operation allows to modify one or more geo configuration entries.
 pub async fn set_geo_location<T: transport::Transport>(
     transport: &T,
     request: &SetGeoLocation,
@@ -3650,7 +3652,7 @@ pub async fn set_geo_location<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation deletes the given geo location entries.
+/// This operation deletes the given geo location entries.
 pub async fn delete_geo_location<T: transport::Transport>(
     transport: &T,
     request: &DeleteGeoLocation,
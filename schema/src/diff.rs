@@ -0,0 +1,146 @@
+//! Structural differences between two values of a generated type, e.g. a current and a desired
+//! `VideoEncoderConfiguration`, to show what a change does before it's sent to a device.
+//!
+//! Values are compared through their [JSON rendering](crate::json), so changes are reported with
+//! the local names of elements and attributes, e.g. `RateControl.BitrateLimit: 4096 -> 2048`.
+//! Repeated elements are compared by position, as `User[1].UserLevel`, and added or removed
+//! elements are reported leaf by leaf.
+
+use crate::json::ToJson;
+use serde_json::Value;
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Change {
+    Added {
+        path: String,
+        value: String,
+    },
+    Removed {
+        path: String,
+        value: String,
+    },
+    Changed {
+        path: String,
+        from: String,
+        to: String,
+    },
+}
+
+impl Change {
+    pub fn path(&self) -> &str {
+        match self {
+            Change::Added { path, .. } | Change::Removed { path, .. } => path,
+            Change::Changed { path, .. } => path,
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Added { path, value } => write!(f, "{}: added {}", path, value),
+            Change::Removed { path, value } => write!(f, "{}: removed {}", path, value),
+            Change::Changed { path, from, to } => write!(f, "{}: {} -> {}", path, from, to),
+        }
+    }
+}
+
+/// Changes turning `current` into `desired`, empty if they're equal. Changes follow the key order
+/// of the JSON rendering, which is the document order with the `preserve_order` feature of
+/// `serde_json`.
+pub fn diff<T: ToJson>(current: &T, desired: &T) -> Result<Vec<Change>, String> {
+    Ok(diff_json(&current.to_json()?, &desired.to_json()?))
+}
+
+/// Changes between two JSON renderings, for values that are rendered before they're modified,
+/// as generated types can't be cloned.
+pub fn diff_json(current: &Value, desired: &Value) -> Vec<Change> {
+    let mut changes = vec![];
+    compare("", Some(current), Some(desired), &mut changes);
+    changes
+}
+
+fn compare(
+    path: &str,
+    current: Option<&Value>,
+    desired: Option<&Value>,
+    changes: &mut Vec<Change>,
+) {
+    match (current, desired) {
+        (Some(current), Some(desired)) if current == desired => {}
+        (Some(Value::Object(current)), Some(Value::Object(desired))) => {
+            let added = desired.keys().filter(|key| !current.contains_key(*key));
+            for key in current.keys().chain(added) {
+                compare(
+                    &join(path, key),
+                    current.get(key),
+                    desired.get(key),
+                    changes,
+                );
+            }
+        }
+        // A list of one item is rendered as the item itself.
+        (Some(current), Some(desired)) if current.is_array() || desired.is_array() => {
+            let (current, desired) = (items(current), items(desired));
+            for i in 0..current.len().max(desired.len()) {
+                compare(
+                    &format!("{}[{}]", path, i),
+                    current.get(i).copied(),
+                    desired.get(i).copied(),
+                    changes,
+                );
+            }
+        }
+        (Some(current), Some(desired)) => changes.push(Change::Changed {
+            path: path.to_string(),
+            from: text(current),
+            to: text(desired),
+        }),
+        (Some(current), None) => leaves(path, current, &mut |path, value| {
+            changes.push(Change::Removed { path, value })
+        }),
+        (None, Some(desired)) => leaves(path, desired, &mut |path, value| {
+            changes.push(Change::Added { path, value })
+        }),
+        (None, None) => {}
+    }
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+fn items(value: &Value) -> Vec<&Value> {
+    match value {
+        Value::Array(items) => items.iter().collect(),
+        value => vec![value],
+    }
+}
+
+fn leaves(path: &str, value: &Value, f: &mut impl FnMut(String, String)) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, value) in map {
+                leaves(&join(path, key), value, f);
+            }
+        }
+        Value::Array(items) => {
+            for (i, value) in items.iter().enumerate() {
+                leaves(&format!("{}[{}]", path, i), value, f);
+            }
+        }
+        value => f(path.to_string(), text(value)),
+    }
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
@@ -17,8 +17,8 @@ impl Validate for GetServiceCapabilities {}
     namespace = "tls: http://www.onvif.org/ver10/display/wsdl"
 )]
 pub struct GetServiceCapabilitiesResponse {
-    // The capabilities for the display service is returned in the Capabilities
-    // element.
+    /// The capabilities for the display service is returned in the Capabilities
+    /// element.
     #[yaserde(prefix = "tls", rename = "Capabilities")]
     pub capabilities: Capabilities,
 }
@@ -31,7 +31,7 @@ impl Validate for GetServiceCapabilitiesResponse {}
     namespace = "tls: http://www.onvif.org/ver10/display/wsdl"
 )]
 pub struct Capabilities {
-    // Indication that the SetLayout command supports only predefined layouts.
+    /// Indication that the SetLayout command supports only predefined layouts.
     #[yaserde(attribute, rename = "FixedLayout")]
     pub fixed_layout: Option<bool>,
 }
@@ -45,7 +45,7 @@ impl Validate for Capabilities {}
     namespace = "tls: http://www.onvif.org/ver10/display/wsdl"
 )]
 pub struct GetLayout {
-    // Token of the Video Output whose Layout is requested
+    /// Token of the Video Output whose Layout is requested
     #[yaserde(prefix = "tls", rename = "VideoOutput")]
     pub video_output: tt::ReferenceToken,
 }
@@ -58,7 +58,7 @@ impl Validate for GetLayout {}
     namespace = "tls: http://www.onvif.org/ver10/display/wsdl"
 )]
 pub struct GetLayoutResponse {
-    // Current layout of the video output.
+    /// Current layout of the video output.
     #[yaserde(prefix = "tls", rename = "Layout")]
     pub layout: tt::Layout,
 }
@@ -71,11 +71,11 @@ impl Validate for GetLayoutResponse {}
     namespace = "tls: http://www.onvif.org/ver10/display/wsdl"
 )]
 pub struct SetLayout {
-    // Token of the Video Output whose Layout shall be changed.
+    /// Token of the Video Output whose Layout shall be changed.
     #[yaserde(prefix = "tls", rename = "VideoOutput")]
     pub video_output: tt::ReferenceToken,
 
-    // Layout to be set
+    /// Layout to be set
     #[yaserde(prefix = "tls", rename = "Layout")]
     pub layout: tt::Layout,
 }
@@ -97,7 +97,7 @@ impl Validate for SetLayoutResponse {}
     namespace = "tls: http://www.onvif.org/ver10/display/wsdl"
 )]
 pub struct GetDisplayOptions {
-    // Token of the Video Output whose options are requested
+    /// Token of the Video Output whose options are requested
     #[yaserde(prefix = "tls", rename = "VideoOutput")]
     pub video_output: tt::ReferenceToken,
 }
@@ -110,14 +110,14 @@ impl Validate for GetDisplayOptions {}
     namespace = "tls: http://www.onvif.org/ver10/display/wsdl"
 )]
 pub struct GetDisplayOptionsResponse {
-    // The LayoutOptions describe the fixed and predefined layouts of a device.
-    // If the device does
-    // not offer fixed layouts and allows setting the layout free this element
-    // is empty.
+    /// The LayoutOptions describe the fixed and predefined layouts of a device.
+    /// If the device does
+    /// not offer fixed layouts and allows setting the layout free this element
+    /// is empty.
     #[yaserde(prefix = "tls", rename = "LayoutOptions")]
     pub layout_options: Option<tt::LayoutOptions>,
 
-    // decoding and encoding capabilities of the device
+    /// decoding and encoding capabilities of the device
     #[yaserde(prefix = "tls", rename = "CodingCapabilities")]
     pub coding_capabilities: tt::CodingCapabilities,
 }
@@ -130,8 +130,8 @@ impl Validate for GetDisplayOptionsResponse {}
     namespace = "tls: http://www.onvif.org/ver10/display/wsdl"
 )]
 pub struct GetPaneConfigurations {
-    // Reference Token of the Video Output whose Pane Configurations are
-    // requested
+    /// Reference Token of the Video Output whose Pane Configurations are
+    /// requested
     #[yaserde(prefix = "tls", rename = "VideoOutput")]
     pub video_output: tt::ReferenceToken,
 }
@@ -144,8 +144,8 @@ impl Validate for GetPaneConfigurations {}
     namespace = "tls: http://www.onvif.org/ver10/display/wsdl"
 )]
 pub struct GetPaneConfigurationsResponse {
-    // Contains a list of defined Panes of the specified VideoOutput. Each
-    // VideoOutput has at least one PaneConfiguration.
+    /// Contains a list of defined Panes of the specified VideoOutput. Each
+    /// VideoOutput has at least one PaneConfiguration.
     #[yaserde(prefix = "tls", rename = "PaneConfiguration")]
     pub pane_configuration: Vec<tt::PaneConfiguration>,
 }
@@ -158,11 +158,11 @@ impl Validate for GetPaneConfigurationsResponse {}
     namespace = "tls: http://www.onvif.org/ver10/display/wsdl"
 )]
 pub struct GetPaneConfiguration {
-    // Reference Token of the Video Output the requested pane belongs to
+    /// Reference Token of the Video Output the requested pane belongs to
     #[yaserde(prefix = "tls", rename = "VideoOutput")]
     pub video_output: tt::ReferenceToken,
 
-    // Reference Token of the Pane whose Configuration is requested
+    /// Reference Token of the Pane whose Configuration is requested
     #[yaserde(prefix = "tls", rename = "Pane")]
     pub pane: tt::ReferenceToken,
 }
@@ -175,7 +175,7 @@ impl Validate for GetPaneConfiguration {}
     namespace = "tls: http://www.onvif.org/ver10/display/wsdl"
 )]
 pub struct GetPaneConfigurationResponse {
-    // returns the configuration of the requested pane.
+    /// returns the configuration of the requested pane.
     #[yaserde(prefix = "tls", rename = "PaneConfiguration")]
     pub pane_configuration: tt::PaneConfiguration,
 }
@@ -188,11 +188,11 @@ impl Validate for GetPaneConfigurationResponse {}
     namespace = "tls: http://www.onvif.org/ver10/display/wsdl"
 )]
 pub struct SetPaneConfigurations {
-    // Token of the video output whose panes to set.
+    /// Token of the video output whose panes to set.
     #[yaserde(prefix = "tls", rename = "VideoOutput")]
     pub video_output: tt::ReferenceToken,
 
-    // Pane Configuration to be set.
+    /// Pane Configuration to be set.
     #[yaserde(prefix = "tls", rename = "PaneConfiguration")]
     pub pane_configuration: Vec<tt::PaneConfiguration>,
 }
@@ -214,11 +214,11 @@ impl Validate for SetPaneConfigurationsResponse {}
     namespace = "tls: http://www.onvif.org/ver10/display/wsdl"
 )]
 pub struct SetPaneConfiguration {
-    // Token of the video output whose panes to set.
+    /// Token of the video output whose panes to set.
     #[yaserde(prefix = "tls", rename = "VideoOutput")]
     pub video_output: tt::ReferenceToken,
 
-    // Pane Configuration to be set.
+    /// Pane Configuration to be set.
     #[yaserde(prefix = "tls", rename = "PaneConfiguration")]
     pub pane_configuration: tt::PaneConfiguration,
 }
@@ -240,11 +240,11 @@ impl Validate for SetPaneConfigurationResponse {}
     namespace = "tls: http://www.onvif.org/ver10/display/wsdl"
 )]
 pub struct CreatePaneConfiguration {
-    // Token of the video output where the pane shall be created.
+    /// Token of the video output where the pane shall be created.
     #[yaserde(prefix = "tls", rename = "VideoOutput")]
     pub video_output: tt::ReferenceToken,
 
-    // Configuration of the pane to be created.
+    /// Configuration of the pane to be created.
     #[yaserde(prefix = "tls", rename = "PaneConfiguration")]
     pub pane_configuration: tt::PaneConfiguration,
 }
@@ -257,7 +257,7 @@ impl Validate for CreatePaneConfiguration {}
     namespace = "tls: http://www.onvif.org/ver10/display/wsdl"
 )]
 pub struct CreatePaneConfigurationResponse {
-    // Token of the new pane configuration.
+    /// Token of the new pane configuration.
     #[yaserde(prefix = "tls", rename = "PaneToken")]
     pub pane_token: tt::ReferenceToken,
 }
@@ -270,11 +270,11 @@ impl Validate for CreatePaneConfigurationResponse {}
     namespace = "tls: http://www.onvif.org/ver10/display/wsdl"
 )]
 pub struct DeletePaneConfiguration {
-    // Token of the video output where the pane shall be deleted.
+    /// Token of the video output where the pane shall be deleted.
     #[yaserde(prefix = "tls", rename = "VideoOutput")]
     pub video_output: tt::ReferenceToken,
 
-    // Token of the pane to be deleted.
+    /// Token of the pane to be deleted.
     #[yaserde(prefix = "tls", rename = "PaneToken")]
     pub pane_token: tt::ReferenceToken,
 }
@@ -290,8 +290,8 @@ pub struct DeletePaneConfigurationResponse {}
 
 impl Validate for DeletePaneConfigurationResponse {}
 
-// Returns the capabilities of the display service. The result is returned in a
-// typed answer.
+/// Returns the capabilities of the display service. The result is returned in a
+/// typed answer.
 pub async fn get_service_capabilities<T: transport::Transport>(
     transport: &T,
     request: &GetServiceCapabilities,
@@ -299,11 +299,11 @@ pub async fn get_service_capabilities<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Return the current layout of a video output. The Layout assigns a pane
-// configuration to a certain area of the display. The layout settings
-// directly affect a specific video output. The layout consists of a list of
-// PaneConfigurations and
-// their associated display areas.
+/// Return the current layout of a video output. The Layout assigns a pane
+/// configuration to a certain area of the display. The layout settings
+/// directly affect a specific video output. The layout consists of a list of
+/// PaneConfigurations and
+/// their associated display areas.
 pub async fn get_layout<T: transport::Transport>(
     transport: &T,
     request: &GetLayout,
@@ -311,12 +311,12 @@ pub async fn get_layout<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Change the layout of a display (e.g. change from
-// single view to split screen view).The Layout assigns a pane configuration to
-// a certain area of the display. The layout settings
-// directly affect a specific video output. The layout consists of a list of
-// PaneConfigurations and
-// their associated display areas.
+/// Change the layout of a display (e.g. change from
+/// single view to split screen view).The Layout assigns a pane configuration to
+/// a certain area of the display. The layout settings
+/// directly affect a specific video output. The layout consists of a list of
+/// PaneConfigurations and
+/// their associated display areas.
 pub async fn set_layout<T: transport::Transport>(
     transport: &T,
     request: &SetLayout,
@@ -324,11 +324,11 @@ pub async fn set_layout<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// The Display Options contain the supported layouts (LayoutOptions) and the
-// decoding and
-// encoding capabilities (CodingCapabilities) of the device. The
-// GetDisplayOptions command
-// returns both, Layout and Coding Capabilities, of a VideoOutput.
+/// The Display Options contain the supported layouts (LayoutOptions) and the
+/// decoding and
+/// encoding capabilities (CodingCapabilities) of the device. The
+/// GetDisplayOptions command
+/// returns both, Layout and Coding Capabilities, of a VideoOutput.
 pub async fn get_display_options<T: transport::Transport>(
     transport: &T,
     request: &GetDisplayOptions,
@@ -336,15 +336,15 @@ pub async fn get_display_options<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// List all currently defined panes of a device for a specified video output
-// (regardless if this pane is visible at a moment). A Pane is a display area on
-// the monitor that is attached to a video output. A pane has a
-// PaneConfiguration that describes which entities are associated with the pane.
-// A client has to configure the pane according to the connection to be
-// established by setting the
-// AudioOutput and/or AudioSourceToken. If a Token is not set, the corresponding
-// session will
-// not be established.
+/// List all currently defined panes of a device for a specified video output
+/// (regardless if this pane is visible at a moment). A Pane is a display area on
+/// the monitor that is attached to a video output. A pane has a
+/// PaneConfiguration that describes which entities are associated with the pane.
+/// A client has to configure the pane according to the connection to be
+/// established by setting the
+/// AudioOutput and/or AudioSourceToken. If a Token is not set, the corresponding
+/// session will
+/// not be established.
 pub async fn get_pane_configurations<T: transport::Transport>(
     transport: &T,
     request: &GetPaneConfigurations,
@@ -352,7 +352,7 @@ pub async fn get_pane_configurations<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Retrieve the pane configuration for a pane token.
+/// Retrieve the pane configuration for a pane token.
 pub async fn get_pane_configuration<T: transport::Transport>(
     transport: &T,
     request: &GetPaneConfiguration,
@@ -360,10 +360,10 @@ pub async fn get_pane_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Modify one or more configurations of the specified video output.
-// This method will only modify the provided configurations and leave the others
-// unchanged.
-// Use
+/// Modify one or more configurations of the specified video output.
+/// This method will only modify the provided configurations and leave the others
+/// unchanged.
+/// Use
 pub async fn set_pane_configurations<T: transport::Transport>(
     transport: &T,
     request: &SetPaneConfigurations,
@@ -371,7 +371,7 @@ pub async fn set_pane_configurations<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This command changes the configuration of the specified pane (tbd)
+/// This command changes the configuration of the specified pane (tbd)
 pub async fn set_pane_configuration<T: transport::Transport>(
     transport: &T,
     request: &SetPaneConfiguration,
@@ -379,8 +379,8 @@ pub async fn set_pane_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Create a new pane configuration describing the streaming and coding settings
-// for a display area.
+/// Create a new pane configuration describing the streaming and coding settings
+/// for a display area.
 pub async fn create_pane_configuration<T: transport::Transport>(
     transport: &T,
     request: &CreatePaneConfiguration,
@@ -388,9 +388,9 @@ pub async fn create_pane_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Delete a pane configuration. A service must respond with an error if the pane
-// configuration
-// is in use by the current layout.
+/// Delete a pane configuration. A service must respond with an error if the pane
+/// configuration
+/// is in use by the current layout.
 pub async fn delete_pane_configuration<T: transport::Transport>(
     transport: &T,
     request: &DeletePaneConfiguration,
@@ -0,0 +1,108 @@
+//! JSON rendering of generated types, for piping into tools like `jq`.
+//!
+//! The generated types only know how to (de)serialize XML, so values are first serialized with
+//! yaserde and the resulting document is then mapped to JSON:
+//!
+//! - elements become object keys named after their local name (namespace prefixes are dropped),
+//! - attributes become keys of their element's object,
+//! - elements with only text content become strings (XML carries no type information, so numbers
+//!   and booleans are rendered as strings too),
+//! - text next to attributes or child elements is stored under a `value` key,
+//! - repeated elements become arrays. A list with a single item is indistinguishable from a
+//!   single element and is rendered as a plain value.
+
+use serde_json::{map::Entry, Map, Value};
+use xml::reader::{EventReader, XmlEvent};
+
+pub trait ToJson {
+    fn to_json(&self) -> Result<Value, String>;
+
+    fn to_json_pretty(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(&self.to_json()?).map_err(|e| e.to_string())
+    }
+}
+
+impl<T: yaserde::YaSerialize> ToJson for T {
+    fn to_json(&self) -> Result<Value, String> {
+        xml_to_json(&yaserde::ser::to_string(self)?)
+    }
+}
+
+#[derive(Default)]
+struct Node {
+    map: Map<String, Value>,
+    text: String,
+    has_children: bool,
+}
+
+impl Node {
+    fn into_value(mut self) -> Value {
+        let text = self.text.trim();
+        if self.map.is_empty() && !self.has_children {
+            return Value::String(text.to_string());
+        }
+        if !text.is_empty() {
+            self.map
+                .insert("value".to_string(), Value::String(text.to_string()));
+        }
+        Value::Object(self.map)
+    }
+
+    fn insert(&mut self, key: String, value: Value) {
+        match self.map.entry(key) {
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+            }
+            Entry::Occupied(mut entry) => match entry.get_mut() {
+                Value::Array(items) => items.push(value),
+                existing => {
+                    let first = existing.take();
+                    *existing = Value::Array(vec![first, value]);
+                }
+            },
+        }
+    }
+}
+
+/// Maps an XML document to JSON as described in the module documentation. The root element
+/// itself is not included in the output, only its content.
+pub fn xml_to_json(xml: &str) -> Result<Value, String> {
+    let mut stack: Vec<(String, Node)> = vec![];
+
+    for event in EventReader::new(xml.as_bytes()) {
+        match event.map_err(|e| e.to_string())? {
+            XmlEvent::StartElement {
+                name, attributes, ..
+            } => {
+                let mut node = Node::default();
+                for attr in attributes {
+                    node.insert(attr.name.local_name, Value::String(attr.value));
+                }
+                stack.push((name.local_name, node));
+            }
+            XmlEvent::Characters(text) | XmlEvent::CData(text) => {
+                if let Some((_, node)) = stack.last_mut() {
+                    node.text.push_str(&text);
+                }
+            }
+            XmlEvent::EndElement { .. } => {
+                let (name, node) = stack.pop().ok_or("unbalanced XML")?;
+                match stack.last_mut() {
+                    Some((_, parent)) => {
+                        parent.has_children = true;
+                        parent.insert(name, node.into_value());
+                    }
+                    None => {
+                        return Ok(match node.into_value() {
+                            Value::String(s) if s.is_empty() => Value::Object(Map::new()),
+                            value => value,
+                        })
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Err("XML document has no root element".to_string())
+}
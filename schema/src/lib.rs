@@ -6,6 +6,11 @@
 #[macro_use]
 extern crate yaserde_derive;
 
+pub mod binary;
+pub mod diff;
+pub mod json;
+pub mod operations;
+pub mod raw;
 pub mod transport;
 pub mod validate;
 
@@ -53,6 +58,8 @@ pub mod ws_addr;
 pub mod ws_discovery;
 pub mod xml_xsd;
 
+#[cfg(test)]
+mod proptests;
 #[cfg(test)]
 mod tests;
 mod utils;
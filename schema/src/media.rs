@@ -19,8 +19,8 @@ impl Validate for GetServiceCapabilities {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetServiceCapabilitiesResponse {
-    // The capabilities for the media service is returned in the Capabilities
-    // element.
+    /// The capabilities for the media service is returned in the Capabilities
+    /// element.
     #[yaserde(prefix = "trt", rename = "Capabilities")]
     pub capabilities: Capabilities,
 }
@@ -33,36 +33,36 @@ impl Validate for GetServiceCapabilitiesResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct Capabilities {
-    // Media profile capabilities.
+    /// Media profile capabilities.
     #[yaserde(prefix = "trt", rename = "ProfileCapabilities")]
     pub profile_capabilities: Vec<ProfileCapabilities>,
 
-    // Streaming capabilities.
+    /// Streaming capabilities.
     #[yaserde(prefix = "trt", rename = "StreamingCapabilities")]
     pub streaming_capabilities: Vec<StreamingCapabilities>,
 
-    // Indicates if GetSnapshotUri is supported.
+    /// Indicates if GetSnapshotUri is supported.
     #[yaserde(attribute, rename = "SnapshotUri")]
     pub snapshot_uri: Option<bool>,
 
-    // Indicates whether or not Rotation feature is supported.
+    /// Indicates whether or not Rotation feature is supported.
     #[yaserde(attribute, rename = "Rotation")]
     pub rotation: Option<bool>,
 
-    // Indicates the support for changing video source mode.
+    /// Indicates the support for changing video source mode.
     #[yaserde(attribute, rename = "VideoSourceMode")]
     pub video_source_mode: Option<bool>,
 
-    // Indicates if OSD is supported.
+    /// Indicates if OSD is supported.
     #[yaserde(attribute, rename = "OSD")]
     pub osd: Option<bool>,
 
-    // Indicates the support for temporary osd text configuration.
+    /// Indicates the support for temporary osd text configuration.
     #[yaserde(attribute, rename = "TemporaryOSDText")]
     pub temporary_osd_text: Option<bool>,
 
-    // Indicates the support for the Efficient XML Interchange (EXI) binary XML
-    // format.
+    /// Indicates the support for the Efficient XML Interchange (EXI) binary XML
+    /// format.
     #[yaserde(attribute, rename = "EXICompression")]
     pub exi_compression: Option<bool>,
 }
@@ -76,7 +76,7 @@ impl Validate for Capabilities {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct ProfileCapabilities {
-    // Maximum number of profiles supported.
+    /// Maximum number of profiles supported.
     #[yaserde(attribute, rename = "MaximumNumberOfProfiles")]
     pub maximum_number_of_profiles: Option<i32>,
 }
@@ -89,23 +89,23 @@ impl Validate for ProfileCapabilities {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct StreamingCapabilities {
-    // Indicates support for RTP multicast.
+    /// Indicates support for RTP multicast.
     #[yaserde(attribute, rename = "RTPMulticast")]
     pub rtp_multicast: Option<bool>,
 
-    // Indicates support for RTP over TCP.
+    /// Indicates support for RTP over TCP.
     #[yaserde(attribute, rename = "RTP_TCP")]
     pub rtp_tcp: Option<bool>,
 
-    // Indicates support for RTP/RTSP/TCP.
+    /// Indicates support for RTP/RTSP/TCP.
     #[yaserde(attribute, rename = "RTP_RTSP_TCP")]
     pub rtp_rtsp_tcp: Option<bool>,
 
-    // Indicates support for non aggregate RTSP control.
+    /// Indicates support for non aggregate RTSP control.
     #[yaserde(attribute, rename = "NonAggregateControl")]
     pub non_aggregate_control: Option<bool>,
 
-    // Indicates the device does not support live media streaming via RTSP.
+    /// Indicates the device does not support live media streaming via RTSP.
     #[yaserde(attribute, rename = "NoRTSPStreaming")]
     pub no_rtsp_streaming: Option<bool>,
 }
@@ -127,7 +127,7 @@ impl Validate for GetVideoSources {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetVideoSourcesResponse {
-    // List of existing Video Sources
+    /// List of existing Video Sources
     #[yaserde(prefix = "trt", rename = "VideoSources")]
     pub video_sources: Vec<tt::VideoSource>,
 }
@@ -149,7 +149,7 @@ impl Validate for GetAudioSources {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetAudioSourcesResponse {
-    // List of existing Audio Sources
+    /// List of existing Audio Sources
     #[yaserde(prefix = "trt", rename = "AudioSources")]
     pub audio_sources: Vec<tt::AudioSource>,
 }
@@ -171,7 +171,7 @@ impl Validate for GetAudioOutputs {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetAudioOutputsResponse {
-    // List of existing Audio Outputs
+    /// List of existing Audio Outputs
     #[yaserde(prefix = "trt", rename = "AudioOutputs")]
     pub audio_outputs: Vec<tt::AudioOutput>,
 }
@@ -184,11 +184,11 @@ impl Validate for GetAudioOutputsResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct CreateProfile {
-    // friendly name of the profile to be created
+    /// friendly name of the profile to be created
     #[yaserde(prefix = "trt", rename = "Name")]
     pub name: tt::Name,
 
-    // Optional token, specifying the unique identifier of the new profile.
+    /// Optional token, specifying the unique identifier of the new profile.
     #[yaserde(prefix = "trt", rename = "Token")]
     pub token: Option<tt::ReferenceToken>,
 }
@@ -201,7 +201,7 @@ impl Validate for CreateProfile {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct CreateProfileResponse {
-    // returns the new created profile
+    /// returns the new created profile
     #[yaserde(prefix = "trt", rename = "Profile")]
     pub profile: tt::Profile,
 }
@@ -214,7 +214,7 @@ impl Validate for CreateProfileResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetProfile {
-    // this command requests a specific profile
+    /// this command requests a specific profile
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -227,7 +227,7 @@ impl Validate for GetProfile {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetProfileResponse {
-    // returns the requested media profile
+    /// returns the requested media profile
     #[yaserde(prefix = "trt", rename = "Profile")]
     pub profile: tt::Profile,
 }
@@ -249,7 +249,7 @@ impl Validate for GetProfiles {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetProfilesResponse {
-    // lists all profiles that exist in the media service
+    /// lists all profiles that exist in the media service
     #[yaserde(prefix = "trt", rename = "Profiles")]
     pub profiles: Vec<tt::Profile>,
 }
@@ -262,11 +262,11 @@ impl Validate for GetProfilesResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct AddVideoEncoderConfiguration {
-    // Reference to the profile where the configuration should be added
+    /// Reference to the profile where the configuration should be added
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 
-    // Contains a reference to the VideoEncoderConfiguration to add
+    /// Contains a reference to the VideoEncoderConfiguration to add
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -288,8 +288,8 @@ impl Validate for AddVideoEncoderConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct RemoveVideoEncoderConfiguration {
-    // Contains a reference to the media profile from which the
-    // VideoEncoderConfiguration shall be removed.
+    /// Contains a reference to the media profile from which the
+    /// VideoEncoderConfiguration shall be removed.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -311,11 +311,11 @@ impl Validate for RemoveVideoEncoderConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct AddVideoSourceConfiguration {
-    // Reference to the profile where the configuration should be added
+    /// Reference to the profile where the configuration should be added
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 
-    // Contains a reference to the VideoSourceConfiguration to add
+    /// Contains a reference to the VideoSourceConfiguration to add
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -337,8 +337,8 @@ impl Validate for AddVideoSourceConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct RemoveVideoSourceConfiguration {
-    // Contains a reference to the media profile from which the
-    // VideoSourceConfiguration shall be removed.
+    /// Contains a reference to the media profile from which the
+    /// VideoSourceConfiguration shall be removed.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -360,11 +360,11 @@ impl Validate for RemoveVideoSourceConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct AddAudioEncoderConfiguration {
-    // Reference to the profile where the configuration should be added
+    /// Reference to the profile where the configuration should be added
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 
-    // Contains a reference to the AudioEncoderConfiguration to add
+    /// Contains a reference to the AudioEncoderConfiguration to add
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -386,8 +386,8 @@ impl Validate for AddAudioEncoderConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct RemoveAudioEncoderConfiguration {
-    // Contains a reference to the media profile from which the
-    // AudioEncoderConfiguration shall be removed.
+    /// Contains a reference to the media profile from which the
+    /// AudioEncoderConfiguration shall be removed.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -409,11 +409,11 @@ impl Validate for RemoveAudioEncoderConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct AddAudioSourceConfiguration {
-    // Reference to the profile where the configuration should be added
+    /// Reference to the profile where the configuration should be added
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 
-    // Contains a reference to the AudioSourceConfiguration to add
+    /// Contains a reference to the AudioSourceConfiguration to add
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -435,8 +435,8 @@ impl Validate for AddAudioSourceConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct RemoveAudioSourceConfiguration {
-    // Contains a reference to the media profile from which the
-    // AudioSourceConfiguration shall be removed.
+    /// Contains a reference to the media profile from which the
+    /// AudioSourceConfiguration shall be removed.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -458,11 +458,11 @@ impl Validate for RemoveAudioSourceConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct AddPTZConfiguration {
-    // Reference to the profile where the configuration should be added
+    /// Reference to the profile where the configuration should be added
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 
-    // Contains a reference to the PTZConfiguration to add
+    /// Contains a reference to the PTZConfiguration to add
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -484,8 +484,8 @@ impl Validate for AddPTZConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct RemovePTZConfiguration {
-    // Contains a reference to the media profile from which the
-    // PTZConfiguration shall be removed.
+    /// Contains a reference to the media profile from which the
+    /// PTZConfiguration shall be removed.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -507,11 +507,11 @@ impl Validate for RemovePTZConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct AddVideoAnalyticsConfiguration {
-    // Reference to the profile where the configuration should be added
+    /// Reference to the profile where the configuration should be added
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 
-    // Contains a reference to the VideoAnalyticsConfiguration to add
+    /// Contains a reference to the VideoAnalyticsConfiguration to add
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -533,8 +533,8 @@ impl Validate for AddVideoAnalyticsConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct RemoveVideoAnalyticsConfiguration {
-    // Contains a reference to the media profile from which the
-    // VideoAnalyticsConfiguration shall be removed.
+    /// Contains a reference to the media profile from which the
+    /// VideoAnalyticsConfiguration shall be removed.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -556,11 +556,11 @@ impl Validate for RemoveVideoAnalyticsConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct AddMetadataConfiguration {
-    // Reference to the profile where the configuration should be added
+    /// Reference to the profile where the configuration should be added
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 
-    // Contains a reference to the MetadataConfiguration to add
+    /// Contains a reference to the MetadataConfiguration to add
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -582,8 +582,8 @@ impl Validate for AddMetadataConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct RemoveMetadataConfiguration {
-    // Contains a reference to the media profile from which the
-    // MetadataConfiguration shall be removed.
+    /// Contains a reference to the media profile from which the
+    /// MetadataConfiguration shall be removed.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -605,11 +605,11 @@ impl Validate for RemoveMetadataConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct AddAudioOutputConfiguration {
-    // Reference to the profile where the configuration should be added
+    /// Reference to the profile where the configuration should be added
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 
-    // Contains a reference to the AudioOutputConfiguration to add
+    /// Contains a reference to the AudioOutputConfiguration to add
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -631,8 +631,8 @@ impl Validate for AddAudioOutputConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct RemoveAudioOutputConfiguration {
-    // Contains a reference to the media profile from which the
-    // AudioOutputConfiguration shall be removed.
+    /// Contains a reference to the media profile from which the
+    /// AudioOutputConfiguration shall be removed.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -654,13 +654,13 @@ impl Validate for RemoveAudioOutputConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct AddAudioDecoderConfiguration {
-    // This element contains a reference to the profile where the configuration
-    // should be added.
+    /// This element contains a reference to the profile where the configuration
+    /// should be added.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 
-    // This element contains a reference to the AudioDecoderConfiguration to
-    // add.
+    /// This element contains a reference to the AudioDecoderConfiguration to
+    /// add.
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -682,8 +682,8 @@ impl Validate for AddAudioDecoderConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct RemoveAudioDecoderConfiguration {
-    // This element contains a reference to the media profile from which the
-    // AudioDecoderConfiguration shall be removed.
+    /// This element contains a reference to the media profile from which the
+    /// AudioDecoderConfiguration shall be removed.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -705,7 +705,7 @@ impl Validate for RemoveAudioDecoderConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct DeleteProfile {
-    // This element contains a reference to the profile that should be deleted.
+    /// This element contains a reference to the profile that should be deleted.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -736,7 +736,7 @@ impl Validate for GetVideoEncoderConfigurations {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetVideoEncoderConfigurationsResponse {
-    // This element contains a list of video encoder configurations.
+    /// This element contains a list of video encoder configurations.
     #[yaserde(prefix = "trt", rename = "Configurations")]
     pub configurations: Vec<tt::VideoEncoderConfiguration>,
 }
@@ -758,7 +758,7 @@ impl Validate for GetVideoSourceConfigurations {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetVideoSourceConfigurationsResponse {
-    // This element contains a list of video source configurations.
+    /// This element contains a list of video source configurations.
     #[yaserde(prefix = "trt", rename = "Configurations")]
     pub configurations: Vec<tt::VideoSourceConfiguration>,
 }
@@ -780,7 +780,7 @@ impl Validate for GetAudioEncoderConfigurations {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetAudioEncoderConfigurationsResponse {
-    // This element contains a list of audio encoder configurations.
+    /// This element contains a list of audio encoder configurations.
     #[yaserde(prefix = "trt", rename = "Configurations")]
     pub configurations: Vec<tt::AudioEncoderConfiguration>,
 }
@@ -802,7 +802,7 @@ impl Validate for GetAudioSourceConfigurations {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetAudioSourceConfigurationsResponse {
-    // This element contains a list of audio source configurations.
+    /// This element contains a list of audio source configurations.
     #[yaserde(prefix = "trt", rename = "Configurations")]
     pub configurations: Vec<tt::AudioSourceConfiguration>,
 }
@@ -824,7 +824,7 @@ impl Validate for GetVideoAnalyticsConfigurations {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetVideoAnalyticsConfigurationsResponse {
-    // This element contains a list of VideoAnalytics configurations.
+    /// This element contains a list of VideoAnalytics configurations.
     #[yaserde(prefix = "trt", rename = "Configurations")]
     pub configurations: Vec<tt::VideoAnalyticsConfiguration>,
 }
@@ -846,7 +846,7 @@ impl Validate for GetMetadataConfigurations {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetMetadataConfigurationsResponse {
-    // This element contains a list of metadata configurations
+    /// This element contains a list of metadata configurations
     #[yaserde(prefix = "trt", rename = "Configurations")]
     pub configurations: Vec<tt::MetadataConfiguration>,
 }
@@ -868,7 +868,7 @@ impl Validate for GetAudioOutputConfigurations {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetAudioOutputConfigurationsResponse {
-    // This element contains a list of audio output configurations
+    /// This element contains a list of audio output configurations
     #[yaserde(prefix = "trt", rename = "Configurations")]
     pub configurations: Vec<tt::AudioOutputConfiguration>,
 }
@@ -890,7 +890,7 @@ impl Validate for GetAudioDecoderConfigurations {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetAudioDecoderConfigurationsResponse {
-    // This element contains a list of audio decoder configurations
+    /// This element contains a list of audio decoder configurations
     #[yaserde(prefix = "trt", rename = "Configurations")]
     pub configurations: Vec<tt::AudioDecoderConfiguration>,
 }
@@ -903,7 +903,7 @@ impl Validate for GetAudioDecoderConfigurationsResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetVideoSourceConfiguration {
-    // Token of the requested video source configuration.
+    /// Token of the requested video source configuration.
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -916,7 +916,7 @@ impl Validate for GetVideoSourceConfiguration {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetVideoSourceConfigurationResponse {
-    // The requested video source configuration.
+    /// The requested video source configuration.
     #[yaserde(prefix = "trt", rename = "Configuration")]
     pub configuration: tt::VideoSourceConfiguration,
 }
@@ -929,7 +929,7 @@ impl Validate for GetVideoSourceConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetVideoEncoderConfiguration {
-    // Token of the requested video encoder configuration.
+    /// Token of the requested video encoder configuration.
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -942,7 +942,7 @@ impl Validate for GetVideoEncoderConfiguration {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetVideoEncoderConfigurationResponse {
-    // The requested video encoder configuration.
+    /// The requested video encoder configuration.
     #[yaserde(prefix = "trt", rename = "Configuration")]
     pub configuration: tt::VideoEncoderConfiguration,
 }
@@ -955,7 +955,7 @@ impl Validate for GetVideoEncoderConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetAudioSourceConfiguration {
-    // Token of the requested audio source configuration.
+    /// Token of the requested audio source configuration.
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -968,7 +968,7 @@ impl Validate for GetAudioSourceConfiguration {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetAudioSourceConfigurationResponse {
-    // The requested audio source configuration.
+    /// The requested audio source configuration.
     #[yaserde(prefix = "trt", rename = "Configuration")]
     pub configuration: tt::AudioSourceConfiguration,
 }
@@ -981,7 +981,7 @@ impl Validate for GetAudioSourceConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetAudioEncoderConfiguration {
-    // Token of the requested audio encoder configuration.
+    /// Token of the requested audio encoder configuration.
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -994,7 +994,7 @@ impl Validate for GetAudioEncoderConfiguration {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetAudioEncoderConfigurationResponse {
-    // The requested audio encoder configuration
+    /// The requested audio encoder configuration
     #[yaserde(prefix = "trt", rename = "Configuration")]
     pub configuration: tt::AudioEncoderConfiguration,
 }
@@ -1007,7 +1007,7 @@ impl Validate for GetAudioEncoderConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetVideoAnalyticsConfiguration {
-    // Token of the requested video analytics configuration.
+    /// Token of the requested video analytics configuration.
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -1020,7 +1020,7 @@ impl Validate for GetVideoAnalyticsConfiguration {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetVideoAnalyticsConfigurationResponse {
-    // The requested video analytics configuration.
+    /// The requested video analytics configuration.
     #[yaserde(prefix = "trt", rename = "Configuration")]
     pub configuration: tt::VideoAnalyticsConfiguration,
 }
@@ -1033,7 +1033,7 @@ impl Validate for GetVideoAnalyticsConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetMetadataConfiguration {
-    // Token of the requested metadata configuration.
+    /// Token of the requested metadata configuration.
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -1046,7 +1046,7 @@ impl Validate for GetMetadataConfiguration {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetMetadataConfigurationResponse {
-    // The requested metadata configuration.
+    /// The requested metadata configuration.
     #[yaserde(prefix = "trt", rename = "Configuration")]
     pub configuration: tt::MetadataConfiguration,
 }
@@ -1059,7 +1059,7 @@ impl Validate for GetMetadataConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetAudioOutputConfiguration {
-    // Token of the requested audio output configuration.
+    /// Token of the requested audio output configuration.
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -1072,7 +1072,7 @@ impl Validate for GetAudioOutputConfiguration {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetAudioOutputConfigurationResponse {
-    // The requested audio output configuration.
+    /// The requested audio output configuration.
     #[yaserde(prefix = "trt", rename = "Configuration")]
     pub configuration: tt::AudioOutputConfiguration,
 }
@@ -1085,7 +1085,7 @@ impl Validate for GetAudioOutputConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetAudioDecoderConfiguration {
-    // Token of the requested audio decoder configuration.
+    /// Token of the requested audio decoder configuration.
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -1098,7 +1098,7 @@ impl Validate for GetAudioDecoderConfiguration {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetAudioDecoderConfigurationResponse {
-    // The requested audio decoder configuration
+    /// The requested audio decoder configuration
     #[yaserde(prefix = "trt", rename = "Configuration")]
     pub configuration: tt::AudioDecoderConfiguration,
 }
@@ -1111,8 +1111,8 @@ impl Validate for GetAudioDecoderConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetCompatibleVideoEncoderConfigurations {
-    // Contains the token of an existing media profile the configurations shall
-    // be compatible with.
+    /// Contains the token of an existing media profile the configurations shall
+    /// be compatible with.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -1125,8 +1125,8 @@ impl Validate for GetCompatibleVideoEncoderConfigurations {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetCompatibleVideoEncoderConfigurationsResponse {
-    // Contains a list of video encoder configurations that are compatible with
-    // the specified media profile.
+    /// Contains a list of video encoder configurations that are compatible with
+    /// the specified media profile.
     #[yaserde(prefix = "trt", rename = "Configurations")]
     pub configurations: Vec<tt::VideoEncoderConfiguration>,
 }
@@ -1139,8 +1139,8 @@ impl Validate for GetCompatibleVideoEncoderConfigurationsResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetCompatibleVideoSourceConfigurations {
-    // Contains the token of an existing media profile the configurations shall
-    // be compatible with.
+    /// Contains the token of an existing media profile the configurations shall
+    /// be compatible with.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -1153,8 +1153,8 @@ impl Validate for GetCompatibleVideoSourceConfigurations {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetCompatibleVideoSourceConfigurationsResponse {
-    // Contains a list of video source configurations that are compatible with
-    // the specified media profile.
+    /// Contains a list of video source configurations that are compatible with
+    /// the specified media profile.
     #[yaserde(prefix = "trt", rename = "Configurations")]
     pub configurations: Vec<tt::VideoSourceConfiguration>,
 }
@@ -1167,8 +1167,8 @@ impl Validate for GetCompatibleVideoSourceConfigurationsResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetCompatibleAudioEncoderConfigurations {
-    // Contains the token of an existing media profile the configurations shall
-    // be compatible with.
+    /// Contains the token of an existing media profile the configurations shall
+    /// be compatible with.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -1181,8 +1181,8 @@ impl Validate for GetCompatibleAudioEncoderConfigurations {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetCompatibleAudioEncoderConfigurationsResponse {
-    // Contains a list of audio encoder configurations that are compatible with
-    // the specified media profile.
+    /// Contains a list of audio encoder configurations that are compatible with
+    /// the specified media profile.
     #[yaserde(prefix = "trt", rename = "Configurations")]
     pub configurations: Vec<tt::AudioEncoderConfiguration>,
 }
@@ -1195,8 +1195,8 @@ impl Validate for GetCompatibleAudioEncoderConfigurationsResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetCompatibleAudioSourceConfigurations {
-    // Contains the token of an existing media profile the configurations shall
-    // be compatible with.
+    /// Contains the token of an existing media profile the configurations shall
+    /// be compatible with.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -1209,8 +1209,8 @@ impl Validate for GetCompatibleAudioSourceConfigurations {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetCompatibleAudioSourceConfigurationsResponse {
-    // Contains a list of audio source configurations that are compatible with
-    // the specified media profile.
+    /// Contains a list of audio source configurations that are compatible with
+    /// the specified media profile.
     #[yaserde(prefix = "trt", rename = "Configurations")]
     pub configurations: Vec<tt::AudioSourceConfiguration>,
 }
@@ -1223,8 +1223,8 @@ impl Validate for GetCompatibleAudioSourceConfigurationsResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetCompatibleVideoAnalyticsConfigurations {
-    // Contains the token of an existing media profile the configurations shall
-    // be compatible with.
+    /// Contains the token of an existing media profile the configurations shall
+    /// be compatible with.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -1237,8 +1237,8 @@ impl Validate for GetCompatibleVideoAnalyticsConfigurations {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetCompatibleVideoAnalyticsConfigurationsResponse {
-    // Contains a list of video analytics configurations that are compatible
-    // with the specified media profile.
+    /// Contains a list of video analytics configurations that are compatible
+    /// with the specified media profile.
     #[yaserde(prefix = "trt", rename = "Configurations")]
     pub configurations: Vec<tt::VideoAnalyticsConfiguration>,
 }
@@ -1251,8 +1251,8 @@ impl Validate for GetCompatibleVideoAnalyticsConfigurationsResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetCompatibleMetadataConfigurations {
-    // Contains the token of an existing media profile the configurations shall
-    // be compatible with.
+    /// Contains the token of an existing media profile the configurations shall
+    /// be compatible with.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -1265,8 +1265,8 @@ impl Validate for GetCompatibleMetadataConfigurations {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetCompatibleMetadataConfigurationsResponse {
-    // Contains a list of metadata configurations that are compatible with the
-    // specified media profile.
+    /// Contains a list of metadata configurations that are compatible with the
+    /// specified media profile.
     #[yaserde(prefix = "trt", rename = "Configurations")]
     pub configurations: Vec<tt::MetadataConfiguration>,
 }
@@ -1279,8 +1279,8 @@ impl Validate for GetCompatibleMetadataConfigurationsResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetCompatibleAudioOutputConfigurations {
-    // Contains the token of an existing media profile the configurations shall
-    // be compatible with.
+    /// Contains the token of an existing media profile the configurations shall
+    /// be compatible with.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -1293,8 +1293,8 @@ impl Validate for GetCompatibleAudioOutputConfigurations {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetCompatibleAudioOutputConfigurationsResponse {
-    // Contains a list of audio output configurations that are compatible with
-    // the specified media profile.
+    /// Contains a list of audio output configurations that are compatible with
+    /// the specified media profile.
     #[yaserde(prefix = "trt", rename = "Configurations")]
     pub configurations: Vec<tt::AudioOutputConfiguration>,
 }
@@ -1307,8 +1307,8 @@ impl Validate for GetCompatibleAudioOutputConfigurationsResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetCompatibleAudioDecoderConfigurations {
-    // Contains the token of an existing media profile the configurations shall
-    // be compatible with.
+    /// Contains the token of an existing media profile the configurations shall
+    /// be compatible with.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -1321,8 +1321,8 @@ impl Validate for GetCompatibleAudioDecoderConfigurations {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetCompatibleAudioDecoderConfigurationsResponse {
-    // Contains a list of audio decoder configurations that are compatible with
-    // the specified media profile.
+    /// Contains a list of audio decoder configurations that are compatible with
+    /// the specified media profile.
     #[yaserde(prefix = "trt", rename = "Configurations")]
     pub configurations: Vec<tt::AudioDecoderConfiguration>,
 }
@@ -1335,13 +1335,13 @@ impl Validate for GetCompatibleAudioDecoderConfigurationsResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct SetVideoEncoderConfiguration {
-    // Contains the modified video encoder configuration. The configuration
-    // shall exist in the device.
+    /// Contains the modified video encoder configuration. The configuration
+    /// shall exist in the device.
     #[yaserde(prefix = "trt", rename = "Configuration")]
     pub configuration: tt::VideoEncoderConfiguration,
 
-    // The ForcePersistence element is obsolete and should always be assumed to
-    // be true.
+    /// The ForcePersistence element is obsolete and should always be assumed to
+    /// be true.
     #[yaserde(prefix = "trt", rename = "ForcePersistence")]
     pub force_persistence: bool,
 }
@@ -1363,13 +1363,13 @@ impl Validate for SetVideoEncoderConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct SetVideoSourceConfiguration {
-    // Contains the modified video source configuration. The configuration shall
-    // exist in the device.
+    /// Contains the modified video source configuration. The configuration shall
+    /// exist in the device.
     #[yaserde(prefix = "trt", rename = "Configuration")]
     pub configuration: tt::VideoSourceConfiguration,
 
-    // The ForcePersistence element is obsolete and should always be assumed to
-    // be true.
+    /// The ForcePersistence element is obsolete and should always be assumed to
+    /// be true.
     #[yaserde(prefix = "trt", rename = "ForcePersistence")]
     pub force_persistence: bool,
 }
@@ -1391,13 +1391,13 @@ impl Validate for SetVideoSourceConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct SetAudioEncoderConfiguration {
-    // Contains the modified audio encoder configuration. The configuration
-    // shall exist in the device.
+    /// Contains the modified audio encoder configuration. The configuration
+    /// shall exist in the device.
     #[yaserde(prefix = "trt", rename = "Configuration")]
     pub configuration: tt::AudioEncoderConfiguration,
 
-    // The ForcePersistence element is obsolete and should always be assumed to
-    // be true.
+    /// The ForcePersistence element is obsolete and should always be assumed to
+    /// be true.
     #[yaserde(prefix = "trt", rename = "ForcePersistence")]
     pub force_persistence: bool,
 }
@@ -1419,13 +1419,13 @@ impl Validate for SetAudioEncoderConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct SetAudioSourceConfiguration {
-    // Contains the modified audio source configuration. The configuration shall
-    // exist in the device.
+    /// Contains the modified audio source configuration. The configuration shall
+    /// exist in the device.
     #[yaserde(prefix = "trt", rename = "Configuration")]
     pub configuration: tt::AudioSourceConfiguration,
 
-    // The ForcePersistence element is obsolete and should always be assumed to
-    // be true.
+    /// The ForcePersistence element is obsolete and should always be assumed to
+    /// be true.
     #[yaserde(prefix = "trt", rename = "ForcePersistence")]
     pub force_persistence: bool,
 }
@@ -1447,13 +1447,13 @@ impl Validate for SetAudioSourceConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct SetVideoAnalyticsConfiguration {
-    // Contains the modified video analytics configuration. The configuration
-    // shall exist in the device.
+    /// Contains the modified video analytics configuration. The configuration
+    /// shall exist in the device.
     #[yaserde(prefix = "trt", rename = "Configuration")]
     pub configuration: tt::VideoAnalyticsConfiguration,
 
-    // The ForcePersistence element is obsolete and should always be assumed to
-    // be true.
+    /// The ForcePersistence element is obsolete and should always be assumed to
+    /// be true.
     #[yaserde(prefix = "trt", rename = "ForcePersistence")]
     pub force_persistence: bool,
 }
@@ -1475,13 +1475,13 @@ impl Validate for SetVideoAnalyticsConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct SetMetadataConfiguration {
-    // Contains the modified metadata configuration. The configuration shall
-    // exist in the device.
+    /// Contains the modified metadata configuration. The configuration shall
+    /// exist in the device.
     #[yaserde(prefix = "trt", rename = "Configuration")]
     pub configuration: tt::MetadataConfiguration,
 
-    // The ForcePersistence element is obsolete and should always be assumed to
-    // be true.
+    /// The ForcePersistence element is obsolete and should always be assumed to
+    /// be true.
     #[yaserde(prefix = "trt", rename = "ForcePersistence")]
     pub force_persistence: bool,
 }
@@ -1503,13 +1503,13 @@ impl Validate for SetMetadataConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct SetAudioOutputConfiguration {
-    // Contains the modified audio output configuration. The configuration shall
-    // exist in the device.
+    /// Contains the modified audio output configuration. The configuration shall
+    /// exist in the device.
     #[yaserde(prefix = "trt", rename = "Configuration")]
     pub configuration: tt::AudioOutputConfiguration,
 
-    // The ForcePersistence element is obsolete and should always be assumed to
-    // be true.
+    /// The ForcePersistence element is obsolete and should always be assumed to
+    /// be true.
     #[yaserde(prefix = "trt", rename = "ForcePersistence")]
     pub force_persistence: bool,
 }
@@ -1531,13 +1531,13 @@ impl Validate for SetAudioOutputConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct SetAudioDecoderConfiguration {
-    // Contains the modified audio decoder configuration. The configuration
-    // shall exist in the device.
+    /// Contains the modified audio decoder configuration. The configuration
+    /// shall exist in the device.
     #[yaserde(prefix = "trt", rename = "Configuration")]
     pub configuration: tt::AudioDecoderConfiguration,
 
-    // The ForcePersistence element is obsolete and should always be assumed to
-    // be true.
+    /// The ForcePersistence element is obsolete and should always be assumed to
+    /// be true.
     #[yaserde(prefix = "trt", rename = "ForcePersistence")]
     pub force_persistence: bool,
 }
@@ -1559,13 +1559,13 @@ impl Validate for SetAudioDecoderConfigurationResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetVideoSourceConfigurationOptions {
-    // Optional video source configurationToken that specifies an existing
-    // configuration that the options are intended for.
+    /// Optional video source configurationToken that specifies an existing
+    /// configuration that the options are intended for.
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: Option<tt::ReferenceToken>,
 
-    // Optional ProfileToken that specifies an existing media profile that the
-    // options shall be compatible with.
+    /// Optional ProfileToken that specifies an existing media profile that the
+    /// options shall be compatible with.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: Option<tt::ReferenceToken>,
 }
@@ -1578,11 +1578,11 @@ impl Validate for GetVideoSourceConfigurationOptions {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetVideoSourceConfigurationOptionsResponse {
-    // This message contains the video source configuration options. If a video
-    // source configuration is specified, the options shall concern that
-    // particular configuration. If a media profile is specified, the options
-    // shall be compatible with that media profile. If no tokens are specified,
-    // the options shall be considered generic for the device.
+    /// This message contains the video source configuration options. If a video
+    /// source configuration is specified, the options shall concern that
+    /// particular configuration. If a media profile is specified, the options
+    /// shall be compatible with that media profile. If no tokens are specified,
+    /// the options shall be considered generic for the device.
     #[yaserde(prefix = "trt", rename = "Options")]
     pub options: tt::VideoSourceConfigurationOptions,
 }
@@ -1595,13 +1595,13 @@ impl Validate for GetVideoSourceConfigurationOptionsResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetVideoEncoderConfigurationOptions {
-    // Optional video encoder configuration token that specifies an existing
-    // configuration that the options are intended for.
+    /// Optional video encoder configuration token that specifies an existing
+    /// configuration that the options are intended for.
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: Option<tt::ReferenceToken>,
 
-    // Optional ProfileToken that specifies an existing media profile that the
-    // options shall be compatible with.
+    /// Optional ProfileToken that specifies an existing media profile that the
+    /// options shall be compatible with.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: Option<tt::ReferenceToken>,
 }
@@ -1626,13 +1626,13 @@ impl Validate for GetVideoEncoderConfigurationOptionsResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetAudioSourceConfigurationOptions {
-    // Optional audio source configuration token that specifies an existing
-    // configuration that the options are intended for.
+    /// Optional audio source configuration token that specifies an existing
+    /// configuration that the options are intended for.
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: Option<tt::ReferenceToken>,
 
-    // Optional ProfileToken that specifies an existing media profile that the
-    // options shall be compatible with.
+    /// Optional ProfileToken that specifies an existing media profile that the
+    /// options shall be compatible with.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: Option<tt::ReferenceToken>,
 }
@@ -1645,11 +1645,11 @@ impl Validate for GetAudioSourceConfigurationOptions {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetAudioSourceConfigurationOptionsResponse {
-    // This message contains the audio source configuration options. If a audio
-    // source configuration is specified, the options shall concern that
-    // particular configuration. If a media profile is specified, the options
-    // shall be compatible with that media profile. If no tokens are specified,
-    // the options shall be considered generic for the device.
+    /// This message contains the audio source configuration options. If a audio
+    /// source configuration is specified, the options shall concern that
+    /// particular configuration. If a media profile is specified, the options
+    /// shall be compatible with that media profile. If no tokens are specified,
+    /// the options shall be considered generic for the device.
     #[yaserde(prefix = "trt", rename = "Options")]
     pub options: tt::AudioSourceConfigurationOptions,
 }
@@ -1662,13 +1662,13 @@ impl Validate for GetAudioSourceConfigurationOptionsResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetAudioEncoderConfigurationOptions {
-    // Optional audio encoder configuration token that specifies an existing
-    // configuration that the options are intended for.
+    /// Optional audio encoder configuration token that specifies an existing
+    /// configuration that the options are intended for.
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: Option<tt::ReferenceToken>,
 
-    // Optional ProfileToken that specifies an existing media profile that the
-    // options shall be compatible with.
+    /// Optional ProfileToken that specifies an existing media profile that the
+    /// options shall be compatible with.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: Option<tt::ReferenceToken>,
 }
@@ -1681,11 +1681,11 @@ impl Validate for GetAudioEncoderConfigurationOptions {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetAudioEncoderConfigurationOptionsResponse {
-    // This message contains the audio encoder configuration options. If a audio
-    // encoder configuration is specified, the options shall concern that
-    // particular configuration. If a media profile is specified, the options
-    // shall be compatible with that media profile. If no tokens are specified,
-    // the options shall be considered generic for the device.
+    /// This message contains the audio encoder configuration options. If a audio
+    /// encoder configuration is specified, the options shall concern that
+    /// particular configuration. If a media profile is specified, the options
+    /// shall be compatible with that media profile. If no tokens are specified,
+    /// the options shall be considered generic for the device.
     #[yaserde(prefix = "trt", rename = "Options")]
     pub options: tt::AudioEncoderConfigurationOptions,
 }
@@ -1698,13 +1698,13 @@ impl Validate for GetAudioEncoderConfigurationOptionsResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetMetadataConfigurationOptions {
-    // Optional metadata configuration token that specifies an existing
-    // configuration that the options are intended for.
+    /// Optional metadata configuration token that specifies an existing
+    /// configuration that the options are intended for.
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: Option<tt::ReferenceToken>,
 
-    // Optional ProfileToken that specifies an existing media profile that the
-    // options shall be compatible with.
+    /// Optional ProfileToken that specifies an existing media profile that the
+    /// options shall be compatible with.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: Option<tt::ReferenceToken>,
 }
@@ -1717,11 +1717,11 @@ impl Validate for GetMetadataConfigurationOptions {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetMetadataConfigurationOptionsResponse {
-    // This message contains the metadata configuration options. If a metadata
-    // configuration is specified, the options shall concern that particular
-    // configuration. If a media profile is specified, the options shall be
-    // compatible with that media profile. If no tokens are specified, the
-    // options shall be considered generic for the device.
+    /// This message contains the metadata configuration options. If a metadata
+    /// configuration is specified, the options shall concern that particular
+    /// configuration. If a media profile is specified, the options shall be
+    /// compatible with that media profile. If no tokens are specified, the
+    /// options shall be considered generic for the device.
     #[yaserde(prefix = "trt", rename = "Options")]
     pub options: tt::MetadataConfigurationOptions,
 }
@@ -1734,13 +1734,13 @@ impl Validate for GetMetadataConfigurationOptionsResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetAudioOutputConfigurationOptions {
-    // Optional audio output configuration token that specifies an existing
-    // configuration that the options are intended for.
+    /// Optional audio output configuration token that specifies an existing
+    /// configuration that the options are intended for.
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: Option<tt::ReferenceToken>,
 
-    // Optional ProfileToken that specifies an existing media profile that the
-    // options shall be compatible with.
+    /// Optional ProfileToken that specifies an existing media profile that the
+    /// options shall be compatible with.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: Option<tt::ReferenceToken>,
 }
@@ -1753,11 +1753,11 @@ impl Validate for GetAudioOutputConfigurationOptions {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetAudioOutputConfigurationOptionsResponse {
-    // This message contains the audio output configuration options. If a audio
-    // output configuration is specified, the options shall concern that
-    // particular configuration. If a media profile is specified, the options
-    // shall be compatible with that media profile. If no tokens are specified,
-    // the options shall be considered generic for the device.
+    /// This message contains the audio output configuration options. If a audio
+    /// output configuration is specified, the options shall concern that
+    /// particular configuration. If a media profile is specified, the options
+    /// shall be compatible with that media profile. If no tokens are specified,
+    /// the options shall be considered generic for the device.
     #[yaserde(prefix = "trt", rename = "Options")]
     pub options: tt::AudioOutputConfigurationOptions,
 }
@@ -1770,13 +1770,13 @@ impl Validate for GetAudioOutputConfigurationOptionsResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetAudioDecoderConfigurationOptions {
-    // Optional audio decoder configuration token that specifies an existing
-    // configuration that the options are intended for.
+    /// Optional audio decoder configuration token that specifies an existing
+    /// configuration that the options are intended for.
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: Option<tt::ReferenceToken>,
 
-    // Optional ProfileToken that specifies an existing media profile that the
-    // options shall be compatible with.
+    /// Optional ProfileToken that specifies an existing media profile that the
+    /// options shall be compatible with.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: Option<tt::ReferenceToken>,
 }
@@ -1789,11 +1789,11 @@ impl Validate for GetAudioDecoderConfigurationOptions {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetAudioDecoderConfigurationOptionsResponse {
-    // This message contains the audio decoder configuration options. If a audio
-    // decoder configuration is specified, the options shall concern that
-    // particular configuration. If a media profile is specified, the options
-    // shall be compatible with that media profile. If no tokens are specified,
-    // the options shall be considered generic for the device.
+    /// This message contains the audio decoder configuration options. If a audio
+    /// decoder configuration is specified, the options shall concern that
+    /// particular configuration. If a media profile is specified, the options
+    /// shall be compatible with that media profile. If no tokens are specified,
+    /// the options shall be considered generic for the device.
     #[yaserde(prefix = "trt", rename = "Options")]
     pub options: tt::AudioDecoderConfigurationOptions,
 }
@@ -1806,7 +1806,7 @@ impl Validate for GetAudioDecoderConfigurationOptionsResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetGuaranteedNumberOfVideoEncoderInstances {
-    // Token of the video source configuration
+    /// Token of the video source configuration
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -1819,27 +1819,27 @@ impl Validate for GetGuaranteedNumberOfVideoEncoderInstances {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetGuaranteedNumberOfVideoEncoderInstancesResponse {
-    // The minimum guaranteed total number of encoder instances (applications)
-    // per VideoSourceConfiguration. The device is able to deliver the
-    // TotalNumber of streams
+    /// The minimum guaranteed total number of encoder instances (applications)
+    /// per VideoSourceConfiguration. The device is able to deliver the
+    /// TotalNumber of streams
     #[yaserde(prefix = "trt", rename = "TotalNumber")]
     pub total_number: i32,
 
-    // If a device limits the number of instances for respective Video Codecs
-    // the response contains the information how many Jpeg streams can be set up
-    // at the same time per VideoSource.
+    /// If a device limits the number of instances for respective Video Codecs
+    /// the response contains the information how many Jpeg streams can be set up
+    /// at the same time per VideoSource.
     #[yaserde(prefix = "trt", rename = "JPEG")]
     pub jpeg: Option<i32>,
 
-    // If a device limits the number of instances for respective Video Codecs
-    // the response contains the information how many H264 streams can be set up
-    // at the same time per VideoSource.
+    /// If a device limits the number of instances for respective Video Codecs
+    /// the response contains the information how many H264 streams can be set up
+    /// at the same time per VideoSource.
     #[yaserde(prefix = "trt", rename = "H264")]
     pub h264: Option<i32>,
 
-    // If a device limits the number of instances for respective Video Codecs
-    // the response contains the information how many Mpeg4 streams can be set
-    // up at the same time per VideoSource.
+    /// If a device limits the number of instances for respective Video Codecs
+    /// the response contains the information how many Mpeg4 streams can be set
+    /// up at the same time per VideoSource.
     #[yaserde(prefix = "trt", rename = "MPEG4")]
     pub mpeg4: Option<i32>,
 }
@@ -1852,12 +1852,12 @@ impl Validate for GetGuaranteedNumberOfVideoEncoderInstancesResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetStreamUri {
-    // Stream Setup that should be used with the uri
+    /// Stream Setup that should be used with the uri
     #[yaserde(prefix = "trt", rename = "StreamSetup")]
     pub stream_setup: tt::StreamSetup,
 
-    // The ProfileToken element indicates the media profile to use and will
-    // define the configuration of the content of the stream.
+    /// The ProfileToken element indicates the media profile to use and will
+    /// define the configuration of the content of the stream.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -1882,8 +1882,8 @@ impl Validate for GetStreamUriResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct StartMulticastStreaming {
-    // Contains the token of the Profile that is used to define the multicast
-    // stream.
+    /// Contains the token of the Profile that is used to define the multicast
+    /// stream.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -1905,8 +1905,8 @@ impl Validate for StartMulticastStreamingResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct StopMulticastStreaming {
-    // Contains the token of the Profile that is used to define the multicast
-    // stream.
+    /// Contains the token of the Profile that is used to define the multicast
+    /// stream.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -1928,8 +1928,8 @@ impl Validate for StopMulticastStreamingResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct SetSynchronizationPoint {
-    // Contains a Profile reference for which a Synchronization Point is
-    // requested.
+    /// Contains a Profile reference for which a Synchronization Point is
+    /// requested.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -1951,8 +1951,8 @@ impl Validate for SetSynchronizationPointResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetSnapshotUri {
-    // The ProfileToken element indicates the media profile to use and will
-    // define the source and dimensions of the snapshot.
+    /// The ProfileToken element indicates the media profile to use and will
+    /// define the source and dimensions of the snapshot.
     #[yaserde(prefix = "trt", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -1977,8 +1977,8 @@ impl Validate for GetSnapshotUriResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetVideoSourceModes {
-    // Contains a video source reference for which a video source mode is
-    // requested.
+    /// Contains a video source reference for which a video source mode is
+    /// requested.
     #[yaserde(prefix = "trt", rename = "VideoSourceToken")]
     pub video_source_token: tt::ReferenceToken,
 }
@@ -1991,7 +1991,7 @@ impl Validate for GetVideoSourceModes {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetVideoSourceModesResponse {
-    // Return the information for specified video source mode.
+    /// Return the information for specified video source mode.
     #[yaserde(prefix = "trt", rename = "VideoSourceModes")]
     pub video_source_modes: Vec<VideoSourceMode>,
 }
@@ -2004,12 +2004,12 @@ impl Validate for GetVideoSourceModesResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct SetVideoSourceMode {
-    // Contains a video source reference for which a video source mode is
-    // requested.
+    /// Contains a video source reference for which a video source mode is
+    /// requested.
     #[yaserde(prefix = "trt", rename = "VideoSourceToken")]
     pub video_source_token: tt::ReferenceToken,
 
-    // Indicate video source mode.
+    /// Indicate video source mode.
     #[yaserde(prefix = "trt", rename = "VideoSourceModeToken")]
     pub video_source_mode_token: tt::ReferenceToken,
 }
@@ -2022,17 +2022,17 @@ impl Validate for SetVideoSourceMode {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct SetVideoSourceModeResponse {
-    // The response contains information about rebooting after returning
-    // response. When Reboot is set true, a device will reboot automatically
-    // after setting mode.
+    /// The response contains information about rebooting after returning
+    /// response. When Reboot is set true, a device will reboot automatically
+    /// after setting mode.
     #[yaserde(prefix = "trt", rename = "Reboot")]
     pub reboot: bool,
 }
 
 impl Validate for SetVideoSourceModeResponse {}
 
-// Indication which encodings are supported for this video source. The list may
-// contain one or more enumeration values of tt:VideoEncoding.
+/// Indication which encodings are supported for this video source. The list may
+/// contain one or more enumeration values of tt:VideoEncoding.
 #[derive(Default, PartialEq, Debug, UtilsTupleIo, UtilsDefaultSerde)]
 pub struct EncodingTypes(pub Vec<String>);
 
@@ -2043,41 +2043,41 @@ impl Validate for EncodingTypes {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct VideoSourceMode {
-    // Max frame rate in frames per second for this video source mode.
+    /// Max frame rate in frames per second for this video source mode.
     #[yaserde(prefix = "trt", rename = "MaxFramerate")]
     pub max_framerate: f64,
 
-    // Max horizontal and vertical resolution for this video source mode.
+    /// Max horizontal and vertical resolution for this video source mode.
     #[yaserde(prefix = "trt", rename = "MaxResolution")]
     pub max_resolution: tt::VideoResolution,
 
-    // Indication which encodings are supported for this video source. The list
-    // may contain one or more enumeration values of tt:VideoEncoding.
+    /// Indication which encodings are supported for this video source. The list
+    /// may contain one or more enumeration values of tt:VideoEncoding.
     #[yaserde(prefix = "trt", rename = "Encodings")]
     pub encodings: EncodingTypes,
 
-    // After setting the mode if a device starts to reboot this value is true.
-    // If a device change the mode without rebooting this value is false. If
-    // true, configured parameters may not be guaranteed by the device after
-    // rebooting.
+    /// After setting the mode if a device starts to reboot this value is true.
+    /// If a device change the mode without rebooting this value is false. If
+    /// true, configured parameters may not be guaranteed by the device after
+    /// rebooting.
     #[yaserde(prefix = "trt", rename = "Reboot")]
     pub reboot: bool,
 
-    // Informative description of this video source mode. This field should be
-    // described in English.
+    /// Informative description of this video source mode. This field should be
+    /// described in English.
     #[yaserde(prefix = "trt", rename = "Description")]
     pub description: Option<tt::Description>,
 
     #[yaserde(prefix = "trt", rename = "Extension")]
     pub extension: Option<VideoSourceModeExtension>,
 
-    // Indicate token for video source mode.
+    /// Indicate token for video source mode.
     #[yaserde(attribute, rename = "token")]
     pub token: tt::ReferenceToken,
 
-    // Indication of whether this mode is active. If active this value is true.
-    // In case of non-indication, it means as false. The value of true shall be
-    // had by only one video source mode.
+    /// Indication of whether this mode is active. If active this value is true.
+    /// In case of non-indication, it means as false. The value of true shall be
+    /// had by only one video source mode.
     #[yaserde(attribute, rename = "Enabled")]
     pub enabled: Option<bool>,
 }
@@ -2099,8 +2099,8 @@ impl Validate for VideoSourceModeExtension {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetOSDs {
-    // Token of the Video Source Configuration, which has OSDs associated with
-    // are requested. If token not exist, request all available OSDs.
+    /// Token of the Video Source Configuration, which has OSDs associated with
+    /// are requested. If token not exist, request all available OSDs.
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -2113,7 +2113,7 @@ impl Validate for GetOSDs {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetOSDsResponse {
-    // This element contains a list of requested OSDs.
+    /// This element contains a list of requested OSDs.
     #[yaserde(prefix = "trt", rename = "OSDs")]
     pub os_ds: Vec<tt::Osdconfiguration>,
 }
@@ -2126,8 +2126,8 @@ impl Validate for GetOSDsResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetOSD {
-    // The GetOSD command fetches the OSD configuration if the OSD token is
-    // known.
+    /// The GetOSD command fetches the OSD configuration if the OSD token is
+    /// known.
     #[yaserde(prefix = "trt", rename = "OSDToken")]
     pub osd_token: tt::ReferenceToken,
 }
@@ -2140,7 +2140,7 @@ impl Validate for GetOSD {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetOSDResponse {
-    // The requested OSD configuration.
+    /// The requested OSD configuration.
     #[yaserde(prefix = "trt", rename = "OSD")]
     pub osd: tt::Osdconfiguration,
 }
@@ -2153,7 +2153,7 @@ impl Validate for GetOSDResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct SetOSD {
-    // Contains the modified OSD configuration.
+    /// Contains the modified OSD configuration.
     #[yaserde(prefix = "trt", rename = "OSD")]
     pub osd: tt::Osdconfiguration,
 }
@@ -2175,8 +2175,8 @@ impl Validate for SetOSDResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct GetOSDOptions {
-    // Video Source Configuration Token that specifies an existing video source
-    // configuration that the options shall be compatible with.
+    /// Video Source Configuration Token that specifies an existing video source
+    /// configuration that the options shall be compatible with.
     #[yaserde(prefix = "trt", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -2201,7 +2201,7 @@ impl Validate for GetOSDOptionsResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct CreateOSD {
-    // Contain the initial OSD configuration for create.
+    /// Contain the initial OSD configuration for create.
     #[yaserde(prefix = "trt", rename = "OSD")]
     pub osd: tt::Osdconfiguration,
 }
@@ -2214,7 +2214,7 @@ impl Validate for CreateOSD {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct CreateOSDResponse {
-    // Returns Token of the newly created OSD
+    /// Returns Token of the newly created OSD
     #[yaserde(prefix = "trt", rename = "OSDToken")]
     pub osd_token: tt::ReferenceToken,
 }
@@ -2227,8 +2227,8 @@ impl Validate for CreateOSDResponse {}
     namespace = "trt: http://www.onvif.org/ver10/media/wsdl"
 )]
 pub struct DeleteOSD {
-    // This element contains a reference to the OSD configuration that should be
-    // deleted.
+    /// This element contains a reference to the OSD configuration that should be
+    /// deleted.
     #[yaserde(prefix = "trt", rename = "OSDToken")]
     pub osd_token: tt::ReferenceToken,
 }
@@ -2244,8 +2244,8 @@ pub struct DeleteOSDResponse {}
 
 impl Validate for DeleteOSDResponse {}
 
-// Returns the capabilities of the media service. The result is returned in a
-// typed answer.
+/// Returns the capabilities of the media service. The result is returned in a
+/// typed answer.
 pub async fn get_service_capabilities<T: transport::Transport>(
     transport: &T,
     request: &GetServiceCapabilities,
@@ -2253,7 +2253,7 @@ pub async fn get_service_capabilities<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This command lists all available physical video inputs of the device.
+/// This command lists all available physical video inputs of the device.
 pub async fn get_video_sources<T: transport::Transport>(
     transport: &T,
     request: &GetVideoSources,
@@ -2261,7 +2261,7 @@ pub async fn get_video_sources<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This command lists all available physical audio inputs of the device.
+/// This command lists all available physical audio inputs of the device.
 pub async fn get_audio_sources<T: transport::Transport>(
     transport: &T,
     request: &GetAudioSources,
@@ -2269,7 +2269,7 @@ pub async fn get_audio_sources<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This command lists all available physical audio outputs of the device.
+/// This command lists all available physical audio outputs of the device.
 pub async fn get_audio_outputs<T: transport::Transport>(
     transport: &T,
     request: &GetAudioOutputs,
@@ -2277,11 +2277,11 @@ pub async fn get_audio_outputs<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation creates a new empty media profile. The media profile shall be
-// created in the
-// device and shall be persistent (remain after reboot). A created profile shall
-// be deletable and a device shall set the “fixed” attribute to false in the
-// returned Profile.
+/// This operation creates a new empty media profile. The media profile shall be
+/// created in the
+/// device and shall be persistent (remain after reboot). A created profile shall
+/// be deletable and a device shall set the “fixed” attribute to false in the
+/// returned Profile.
 pub async fn create_profile<T: transport::Transport>(
     transport: &T,
     request: &CreateProfile,
@@ -2289,8 +2289,8 @@ pub async fn create_profile<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// If the profile token is already known, a profile can be fetched through the
-// GetProfile command.
+/// If the profile token is already known, a profile can be fetched through the
+/// GetProfile command.
 pub async fn get_profile<T: transport::Transport>(
     transport: &T,
     request: &GetProfile,
@@ -2298,13 +2298,13 @@ pub async fn get_profile<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Any endpoint can ask for the existing media profiles of a device using the
-// GetProfiles
-// command. Pre-configured or dynamically configured profiles can be retrieved
-// using this
-// command. This command lists all configured profiles in a device. The client
-// does not need to
-// know the media profile in order to use the command.
+/// Any endpoint can ask for the existing media profiles of a device using the
+/// GetProfiles
+/// command. Pre-configured or dynamically configured profiles can be retrieved
+/// using this
+/// command. This command lists all configured profiles in a device. The client
+/// does not need to
+/// know the media profile in order to use the command.
 pub async fn get_profiles<T: transport::Transport>(
     transport: &T,
     request: &GetProfiles,
@@ -2312,13 +2312,13 @@ pub async fn get_profiles<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation adds a VideoEncoderConfiguration to an existing media profile.
-// If a
-// configuration exists in the media profile, it will be replaced. The change
-// shall be persistent. A device shall
-// support adding a compatible VideoEncoderConfiguration to a Profile containing
-// a VideoSourceConfiguration and shall
-// support streaming video data of such a profile.
+/// This operation adds a VideoEncoderConfiguration to an existing media profile.
+/// If a
+/// configuration exists in the media profile, it will be replaced. The change
+/// shall be persistent. A device shall
+/// support adding a compatible VideoEncoderConfiguration to a Profile containing
+/// a VideoSourceConfiguration and shall
+/// support streaming video data of such a profile.
 pub async fn add_video_encoder_configuration<T: transport::Transport>(
     transport: &T,
     request: &AddVideoEncoderConfiguration,
@@ -2326,10 +2326,10 @@ pub async fn add_video_encoder_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation removes a VideoEncoderConfiguration from an existing media
-// profile. If the
-// media profile does not contain a VideoEncoderConfiguration, the operation has
-// no effect. The removal shall be persistent.
+/// This operation removes a VideoEncoderConfiguration from an existing media
+/// profile. If the
+/// media profile does not contain a VideoEncoderConfiguration, the operation has
+/// no effect. The removal shall be persistent.
 pub async fn remove_video_encoder_configuration<T: transport::Transport>(
     transport: &T,
     request: &RemoveVideoEncoderConfiguration,
@@ -2337,10 +2337,10 @@ pub async fn remove_video_encoder_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation adds a VideoSourceConfiguration to an existing media profile.
-// If such a
-// configuration exists in the media profile, it will be replaced. The change
-// shall be persistent.
+/// This operation adds a VideoSourceConfiguration to an existing media profile.
+/// If such a
+/// configuration exists in the media profile, it will be replaced. The change
+/// shall be persistent.
 pub async fn add_video_source_configuration<T: transport::Transport>(
     transport: &T,
     request: &AddVideoSourceConfiguration,
@@ -2348,12 +2348,12 @@ pub async fn add_video_source_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation removes a VideoSourceConfiguration from an existing media
-// profile. If the
-// media profile does not contain a VideoSourceConfiguration, the operation has
-// no effect. The removal shall be persistent. Video source configurations
-// should only be removed after removing a
-// VideoEncoderConfiguration from the media profile.
+/// This operation removes a VideoSourceConfiguration from an existing media
+/// profile. If the
+/// media profile does not contain a VideoSourceConfiguration, the operation has
+/// no effect. The removal shall be persistent. Video source configurations
+/// should only be removed after removing a
+/// VideoEncoderConfiguration from the media profile.
 pub async fn remove_video_source_configuration<T: transport::Transport>(
     transport: &T,
     request: &RemoveVideoSourceConfiguration,
@@ -2361,13 +2361,13 @@ pub async fn remove_video_source_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation adds an AudioEncoderConfiguration to an existing media
-// profile. If a
-// configuration exists in the media profile, it will be replaced. The change
-// shall be persistent. A device shall
-// support adding a compatible AudioEncoderConfiguration to a profile containing
-// an AudioSourceConfiguration and shall
-// support streaming audio data of such a profile.
+/// This operation adds an AudioEncoderConfiguration to an existing media
+/// profile. If a
+/// configuration exists in the media profile, it will be replaced. The change
+/// shall be persistent. A device shall
+/// support adding a compatible AudioEncoderConfiguration to a profile containing
+/// an AudioSourceConfiguration and shall
+/// support streaming audio data of such a profile.
 pub async fn add_audio_encoder_configuration<T: transport::Transport>(
     transport: &T,
     request: &AddAudioEncoderConfiguration,
@@ -2375,11 +2375,11 @@ pub async fn add_audio_encoder_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation removes an AudioEncoderConfiguration from an existing media
-// profile. If the
-// media profile does not contain an AudioEncoderConfiguration, the operation
-// has no effect.
-// The removal shall be persistent.
+/// This operation removes an AudioEncoderConfiguration from an existing media
+/// profile. If the
+/// media profile does not contain an AudioEncoderConfiguration, the operation
+/// has no effect.
+/// The removal shall be persistent.
 pub async fn remove_audio_encoder_configuration<T: transport::Transport>(
     transport: &T,
     request: &RemoveAudioEncoderConfiguration,
@@ -2387,10 +2387,10 @@ pub async fn remove_audio_encoder_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation adds an AudioSourceConfiguration to an existing media profile.
-// If a
-// configuration exists in the media profile, it will be replaced. The change
-// shall be persistent.
+/// This operation adds an AudioSourceConfiguration to an existing media profile.
+/// If a
+/// configuration exists in the media profile, it will be replaced. The change
+/// shall be persistent.
 pub async fn add_audio_source_configuration<T: transport::Transport>(
     transport: &T,
     request: &AddAudioSourceConfiguration,
@@ -2398,13 +2398,13 @@ pub async fn add_audio_source_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation removes an AudioSourceConfiguration from an existing media
-// profile. If the
-// media profile does not contain an AudioSourceConfiguration, the operation has
-// no effect. The
-// removal shall be persistent. Audio source configurations should only be
-// removed after removing an
-// AudioEncoderConfiguration from the media profile.
+/// This operation removes an AudioSourceConfiguration from an existing media
+/// profile. If the
+/// media profile does not contain an AudioSourceConfiguration, the operation has
+/// no effect. The
+/// removal shall be persistent. Audio source configurations should only be
+/// removed after removing an
+/// AudioEncoderConfiguration from the media profile.
 pub async fn remove_audio_source_configuration<T: transport::Transport>(
     transport: &T,
     request: &RemoveAudioSourceConfiguration,
@@ -2412,14 +2412,14 @@ pub async fn remove_audio_source_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation adds a PTZConfiguration to an existing media profile. If a
-// configuration exists
-// in the media profile, it will be replaced. The change shall be persistent.
-// Adding a PTZConfiguration to a media profile means that streams using that
-// media profile can
-// contain PTZ status (in the metadata), and that the media profile can be used
-// for controlling
-// PTZ movement.
+/// This operation adds a PTZConfiguration to an existing media profile. If a
+/// configuration exists
+/// in the media profile, it will be replaced. The change shall be persistent.
+/// Adding a PTZConfiguration to a media profile means that streams using that
+/// media profile can
+/// contain PTZ status (in the metadata), and that the media profile can be used
+/// for controlling
+/// PTZ movement.
 pub async fn add_ptz_configuration<T: transport::Transport>(
     transport: &T,
     request: &AddPTZConfiguration,
@@ -2427,10 +2427,10 @@ pub async fn add_ptz_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation removes a PTZConfiguration from an existing media profile. If
-// the media profile
-// does not contain a PTZConfiguration, the operation has no effect. The removal
-// shall be persistent.
+/// This operation removes a PTZConfiguration from an existing media profile. If
+/// the media profile
+/// does not contain a PTZConfiguration, the operation has no effect. The removal
+/// shall be persistent.
 pub async fn remove_ptz_configuration<T: transport::Transport>(
     transport: &T,
     request: &RemovePTZConfiguration,
@@ -2438,18 +2438,18 @@ pub async fn remove_ptz_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation adds a VideoAnalytics configuration to an existing media
-// profile. If a
-// configuration exists in the media profile, it will be replaced. The change
-// shall be persistent. Adding a VideoAnalyticsConfiguration to a media profile
-// means that streams using that media
-// profile can contain video analytics data (in the metadata) as defined by the
-// submitted configuration reference. A profile containing only a video
-// analytics configuration but no video source configuration is incomplete.
-// Therefore, a client should first add a video source configuration to a
-// profile before adding a video analytics configuration. The device can deny
-// adding of a video analytics
-// configuration before a video source configuration.
+/// This operation adds a VideoAnalytics configuration to an existing media
+/// profile. If a
+/// configuration exists in the media profile, it will be replaced. The change
+/// shall be persistent. Adding a VideoAnalyticsConfiguration to a media profile
+/// means that streams using that media
+/// profile can contain video analytics data (in the metadata) as defined by the
+/// submitted configuration reference. A profile containing only a video
+/// analytics configuration but no video source configuration is incomplete.
+/// Therefore, a client should first add a video source configuration to a
+/// profile before adding a video analytics configuration. The device can deny
+/// adding of a video analytics
+/// configuration before a video source configuration.
 pub async fn add_video_analytics_configuration<T: transport::Transport>(
     transport: &T,
     request: &AddVideoAnalyticsConfiguration,
@@ -2457,10 +2457,10 @@ pub async fn add_video_analytics_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation removes a VideoAnalyticsConfiguration from an existing media
-// profile. If the media profile does not contain a VideoAnalyticsConfiguration,
-// the operation has no effect.
-// The removal shall be persistent.
+/// This operation removes a VideoAnalyticsConfiguration from an existing media
+/// profile. If the media profile does not contain a VideoAnalyticsConfiguration,
+/// the operation has no effect.
+/// The removal shall be persistent.
 pub async fn remove_video_analytics_configuration<T: transport::Transport>(
     transport: &T,
     request: &RemoveVideoAnalyticsConfiguration,
@@ -2468,11 +2468,11 @@ pub async fn remove_video_analytics_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation adds a Metadata configuration to an existing media profile. If
-// a configuration exists in the media profile, it will be replaced. The change
-// shall be persistent. Adding a MetadataConfiguration to a Profile means that
-// streams using that profile contain metadata. Metadata can consist of events,
-// PTZ status, and/or video analytics data.
+/// This operation adds a Metadata configuration to an existing media profile. If
+/// a configuration exists in the media profile, it will be replaced. The change
+/// shall be persistent. Adding a MetadataConfiguration to a Profile means that
+/// streams using that profile contain metadata. Metadata can consist of events,
+/// PTZ status, and/or video analytics data.
 pub async fn add_metadata_configuration<T: transport::Transport>(
     transport: &T,
     request: &AddMetadataConfiguration,
@@ -2480,9 +2480,9 @@ pub async fn add_metadata_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation removes a MetadataConfiguration from an existing media
-// profile. If the media profile does not contain a MetadataConfiguration, the
-// operation has no effect. The removal shall be persistent.
+/// This operation removes a MetadataConfiguration from an existing media
+/// profile. If the media profile does not contain a MetadataConfiguration, the
+/// operation has no effect. The removal shall be persistent.
 pub async fn remove_metadata_configuration<T: transport::Transport>(
     transport: &T,
     request: &RemoveMetadataConfiguration,
@@ -2490,9 +2490,9 @@ pub async fn remove_metadata_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation adds an AudioOutputConfiguration to an existing media profile.
-// If a configuration exists in the media profile, it will be replaced. The
-// change shall be persistent.
+/// This operation adds an AudioOutputConfiguration to an existing media profile.
+/// If a configuration exists in the media profile, it will be replaced. The
+/// change shall be persistent.
 pub async fn add_audio_output_configuration<T: transport::Transport>(
     transport: &T,
     request: &AddAudioOutputConfiguration,
@@ -2500,9 +2500,9 @@ pub async fn add_audio_output_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation removes an AudioOutputConfiguration from an existing media
-// profile. If the media profile does not contain an AudioOutputConfiguration,
-// the operation has no effect. The removal shall be persistent.
+/// This operation removes an AudioOutputConfiguration from an existing media
+/// profile. If the media profile does not contain an AudioOutputConfiguration,
+/// the operation has no effect. The removal shall be persistent.
 pub async fn remove_audio_output_configuration<T: transport::Transport>(
     transport: &T,
     request: &RemoveAudioOutputConfiguration,
@@ -2510,9 +2510,9 @@ pub async fn remove_audio_output_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation adds an AudioDecoderConfiguration to an existing media
-// profile. If a configuration exists in the media profile, it shall be
-// replaced. The change shall be persistent.
+/// This operation adds an AudioDecoderConfiguration to an existing media
+/// profile. If a configuration exists in the media profile, it shall be
+/// replaced. The change shall be persistent.
 pub async fn add_audio_decoder_configuration<T: transport::Transport>(
     transport: &T,
     request: &AddAudioDecoderConfiguration,
@@ -2520,9 +2520,9 @@ pub async fn add_audio_decoder_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation removes an AudioDecoderConfiguration from an existing media
-// profile. If the media profile does not contain an AudioDecoderConfiguration,
-// the operation has no effect. The removal shall be persistent.
+/// This operation removes an AudioDecoderConfiguration from an existing media
+/// profile. If the media profile does not contain an AudioDecoderConfiguration,
+/// the operation has no effect. The removal shall be persistent.
 pub async fn remove_audio_decoder_configuration<T: transport::Transport>(
     transport: &T,
     request: &RemoveAudioDecoderConfiguration,
@@ -2530,8 +2530,8 @@ pub async fn remove_audio_decoder_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation deletes a profile. This change shall always be persistent.
-// Deletion of a profile is only possible for non-fixed profiles
+/// This operation deletes a profile. This change shall always be persistent.
+/// Deletion of a profile is only possible for non-fixed profiles
 pub async fn delete_profile<T: transport::Transport>(
     transport: &T,
     request: &DeleteProfile,
@@ -2539,9 +2539,9 @@ pub async fn delete_profile<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation lists all existing video source configurations for a device.
-// The client need not know anything about the video source configurations in
-// order to use the command.
+/// This operation lists all existing video source configurations for a device.
+/// The client need not know anything about the video source configurations in
+/// order to use the command.
 pub async fn get_video_source_configurations<T: transport::Transport>(
     transport: &T,
     request: &GetVideoSourceConfigurations,
@@ -2549,10 +2549,10 @@ pub async fn get_video_source_configurations<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation lists all existing video encoder configurations of a device.
-// This command lists all configured video encoder configurations in a device.
-// The client need not know anything apriori about the video encoder
-// configurations in order to use the command.
+/// This operation lists all existing video encoder configurations of a device.
+/// This command lists all configured video encoder configurations in a device.
+/// The client need not know anything apriori about the video encoder
+/// configurations in order to use the command.
 pub async fn get_video_encoder_configurations<T: transport::Transport>(
     transport: &T,
     request: &GetVideoEncoderConfigurations,
@@ -2560,10 +2560,10 @@ pub async fn get_video_encoder_configurations<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation lists all existing audio source configurations of a device.
-// This command lists all audio source configurations in a device. The client
-// need not know anything apriori about the audio source configurations in order
-// to use the command.
+/// This operation lists all existing audio source configurations of a device.
+/// This command lists all audio source configurations in a device. The client
+/// need not know anything apriori about the audio source configurations in order
+/// to use the command.
 pub async fn get_audio_source_configurations<T: transport::Transport>(
     transport: &T,
     request: &GetAudioSourceConfigurations,
@@ -2571,9 +2571,9 @@ pub async fn get_audio_source_configurations<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation lists all existing device audio encoder configurations. The
-// client need not know anything apriori about the audio encoder configurations
-// in order to use the command.
+/// This operation lists all existing device audio encoder configurations. The
+/// client need not know anything apriori about the audio encoder configurations
+/// in order to use the command.
 pub async fn get_audio_encoder_configurations<T: transport::Transport>(
     transport: &T,
     request: &GetAudioEncoderConfigurations,
@@ -2581,9 +2581,9 @@ pub async fn get_audio_encoder_configurations<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation lists all video analytics configurations of a device. This
-// command lists all configured video analytics in a device. The client need not
-// know anything apriori about the video analytics in order to use the command.
+/// This operation lists all video analytics configurations of a device. This
+/// command lists all configured video analytics in a device. The client need not
+/// know anything apriori about the video analytics in order to use the command.
 pub async fn get_video_analytics_configurations<T: transport::Transport>(
     transport: &T,
     request: &GetVideoAnalyticsConfigurations,
@@ -2591,8 +2591,8 @@ pub async fn get_video_analytics_configurations<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation lists all existing metadata configurations. The client need
-// not know anything apriori about the metadata in order to use the command.
+/// This operation lists all existing metadata configurations. The client need
+/// not know anything apriori about the metadata in order to use the command.
 pub async fn get_metadata_configurations<T: transport::Transport>(
     transport: &T,
     request: &GetMetadataConfigurations,
@@ -2600,9 +2600,9 @@ pub async fn get_metadata_configurations<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This command lists all existing AudioOutputConfigurations of a device. The
-// NVC need not know anything apriori about the audio configurations to use this
-// command.
+/// This command lists all existing AudioOutputConfigurations of a device. The
+/// NVC need not know anything apriori about the audio configurations to use this
+/// command.
 pub async fn get_audio_output_configurations<T: transport::Transport>(
     transport: &T,
     request: &GetAudioOutputConfigurations,
@@ -2610,10 +2610,10 @@ pub async fn get_audio_output_configurations<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This command lists all existing AudioDecoderConfigurations of a device. The
-// NVC need not know anything apriori about the audio decoder configurations in
-// order to
-// use this command.
+/// This command lists all existing AudioDecoderConfigurations of a device. The
+/// NVC need not know anything apriori about the audio decoder configurations in
+/// order to
+/// use this command.
 pub async fn get_audio_decoder_configurations<T: transport::Transport>(
     transport: &T,
     request: &GetAudioDecoderConfigurations,
@@ -2621,8 +2621,8 @@ pub async fn get_audio_decoder_configurations<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// If the video source configuration token is already known, the video source
-// configuration can be fetched through the GetVideoSourceConfiguration command.
+/// If the video source configuration token is already known, the video source
+/// configuration can be fetched through the GetVideoSourceConfiguration command.
 pub async fn get_video_source_configuration<T: transport::Transport>(
     transport: &T,
     request: &GetVideoSourceConfiguration,
@@ -2630,9 +2630,9 @@ pub async fn get_video_source_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// If the video encoder configuration token is already known, the encoder
-// configuration can be fetched through the GetVideoEncoderConfiguration
-// command.
+/// If the video encoder configuration token is already known, the encoder
+/// configuration can be fetched through the GetVideoEncoderConfiguration
+/// command.
 pub async fn get_video_encoder_configuration<T: transport::Transport>(
     transport: &T,
     request: &GetVideoEncoderConfiguration,
@@ -2640,8 +2640,8 @@ pub async fn get_video_encoder_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// The GetAudioSourceConfiguration command fetches the audio source
-// configurations if the audio source configuration token is already known. An
+/// The GetAudioSourceConfiguration command fetches the audio source
+/// configurations if the audio source configuration token is already known. An
 pub async fn get_audio_source_configuration<T: transport::Transport>(
     transport: &T,
     request: &GetAudioSourceConfiguration,
@@ -2649,8 +2649,8 @@ pub async fn get_audio_source_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// The GetAudioEncoderConfiguration command fetches the encoder configuration if
-// the audio encoder configuration token is known.
+/// The GetAudioEncoderConfiguration command fetches the encoder configuration if
+/// the audio encoder configuration token is known.
 pub async fn get_audio_encoder_configuration<T: transport::Transport>(
     transport: &T,
     request: &GetAudioEncoderConfiguration,
@@ -2658,8 +2658,8 @@ pub async fn get_audio_encoder_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// The GetVideoAnalyticsConfiguration command fetches the video analytics
-// configuration if the video analytics token is known.
+/// The GetVideoAnalyticsConfiguration command fetches the video analytics
+/// configuration if the video analytics token is known.
 pub async fn get_video_analytics_configuration<T: transport::Transport>(
     transport: &T,
     request: &GetVideoAnalyticsConfiguration,
@@ -2667,8 +2667,8 @@ pub async fn get_video_analytics_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// The GetMetadataConfiguration command fetches the metadata configuration if
-// the metadata token is known.
+/// The GetMetadataConfiguration command fetches the metadata configuration if
+/// the metadata token is known.
 pub async fn get_metadata_configuration<T: transport::Transport>(
     transport: &T,
     request: &GetMetadataConfiguration,
@@ -2676,8 +2676,8 @@ pub async fn get_metadata_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// If the audio output configuration token is already known, the output
-// configuration can be fetched through the GetAudioOutputConfiguration command.
+/// If the audio output configuration token is already known, the output
+/// configuration can be fetched through the GetAudioOutputConfiguration command.
 pub async fn get_audio_output_configuration<T: transport::Transport>(
     transport: &T,
     request: &GetAudioOutputConfiguration,
@@ -2685,9 +2685,9 @@ pub async fn get_audio_output_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// If the audio decoder configuration token is already known, the decoder
-// configuration can be fetched through the GetAudioDecoderConfiguration
-// command.
+/// If the audio decoder configuration token is already known, the decoder
+/// configuration can be fetched through the GetAudioDecoderConfiguration
+/// command.
 pub async fn get_audio_decoder_configuration<T: transport::Transport>(
     transport: &T,
     request: &GetAudioDecoderConfiguration,
@@ -2695,12 +2695,12 @@ pub async fn get_audio_decoder_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation lists all the video encoder configurations of the device that
-// are compatible with a certain media profile. Each of the returned
-// configurations shall be a valid input parameter for the
-// AddVideoEncoderConfiguration command on the media profile. The result will
-// vary depending on the capabilities, configurations and settings in the
-// device.
+/// This operation lists all the video encoder configurations of the device that
+/// are compatible with a certain media profile. Each of the returned
+/// configurations shall be a valid input parameter for the
+/// AddVideoEncoderConfiguration command on the media profile. The result will
+/// vary depending on the capabilities, configurations and settings in the
+/// device.
 pub async fn get_compatible_video_encoder_configurations<T: transport::Transport>(
     transport: &T,
     request: &GetCompatibleVideoEncoderConfigurations,
@@ -2708,14 +2708,14 @@ pub async fn get_compatible_video_encoder_configurations<T: transport::Transport
     transport::request(transport, request).await
 }
 
-// This operation requests all the video source configurations of the device
-// that are compatible
-// with a certain media profile. Each of the returned configurations shall be a
-// valid input
-// parameter for the AddVideoSourceConfiguration command on the media profile.
-// The result
-// will vary depending on the capabilities, configurations and settings in the
-// device.
+/// This operation requests all the video source configurations of the device
+/// that are compatible
+/// with a certain media profile. Each of the returned configurations shall be a
+/// valid input
+/// parameter for the AddVideoSourceConfiguration command on the media profile.
+/// The result
+/// will vary depending on the capabilities, configurations and settings in the
+/// device.
 pub async fn get_compatible_video_source_configurations<T: transport::Transport>(
     transport: &T,
     request: &GetCompatibleVideoSourceConfigurations,
@@ -2723,11 +2723,11 @@ pub async fn get_compatible_video_source_configurations<T: transport::Transport>
     transport::request(transport, request).await
 }
 
-// This operation requests all audio encoder configurations of a device that are
-// compatible with a certain media profile. Each of the returned configurations
-// shall be a valid input parameter for the AddAudioSourceConfiguration command
-// on the media profile. The result varies depending on the capabilities,
-// configurations and settings in the device.
+/// This operation requests all audio encoder configurations of a device that are
+/// compatible with a certain media profile. Each of the returned configurations
+/// shall be a valid input parameter for the AddAudioSourceConfiguration command
+/// on the media profile. The result varies depending on the capabilities,
+/// configurations and settings in the device.
 pub async fn get_compatible_audio_encoder_configurations<T: transport::Transport>(
     transport: &T,
     request: &GetCompatibleAudioEncoderConfigurations,
@@ -2735,11 +2735,11 @@ pub async fn get_compatible_audio_encoder_configurations<T: transport::Transport
     transport::request(transport, request).await
 }
 
-// This operation requests all audio source configurations of the device that
-// are compatible with a certain media profile. Each of the returned
-// configurations shall be a valid input parameter for the
-// AddAudioEncoderConfiguration command on the media profile. The result varies
-// depending on the capabilities, configurations and settings in the device.
+/// This operation requests all audio source configurations of the device that
+/// are compatible with a certain media profile. Each of the returned
+/// configurations shall be a valid input parameter for the
+/// AddAudioEncoderConfiguration command on the media profile. The result varies
+/// depending on the capabilities, configurations and settings in the device.
 pub async fn get_compatible_audio_source_configurations<T: transport::Transport>(
     transport: &T,
     request: &GetCompatibleAudioSourceConfigurations,
@@ -2747,12 +2747,12 @@ pub async fn get_compatible_audio_source_configurations<T: transport::Transport>
     transport::request(transport, request).await
 }
 
-// This operation requests all video analytic configurations of the device that
-// are compatible with a certain media profile. Each of the returned
-// configurations shall be a valid input parameter for the
-// AddVideoAnalyticsConfiguration command on the media profile. The result
-// varies depending on the capabilities, configurations and settings in the
-// device.
+/// This operation requests all video analytic configurations of the device that
+/// are compatible with a certain media profile. Each of the returned
+/// configurations shall be a valid input parameter for the
+/// AddVideoAnalyticsConfiguration command on the media profile. The result
+/// varies depending on the capabilities, configurations and settings in the
+/// device.
 pub async fn get_compatible_video_analytics_configurations<T: transport::Transport>(
     transport: &T,
     request: &GetCompatibleVideoAnalyticsConfigurations,
@@ -2760,11 +2760,11 @@ pub async fn get_compatible_video_analytics_configurations<T: transport::Transpo
     transport::request(transport, request).await
 }
 
-// This operation requests all the metadata configurations of the device that
-// are compatible with a certain media profile. Each of the returned
-// configurations shall be a valid input parameter for the
-// AddMetadataConfiguration command on the media profile. The result varies
-// depending on the capabilities, configurations and settings in the device.
+/// This operation requests all the metadata configurations of the device that
+/// are compatible with a certain media profile. Each of the returned
+/// configurations shall be a valid input parameter for the
+/// AddMetadataConfiguration command on the media profile. The result varies
+/// depending on the capabilities, configurations and settings in the device.
 pub async fn get_compatible_metadata_configurations<T: transport::Transport>(
     transport: &T,
     request: &GetCompatibleMetadataConfigurations,
@@ -2772,10 +2772,10 @@ pub async fn get_compatible_metadata_configurations<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This command lists all audio output configurations of a device that are
-// compatible with a certain media profile. Each returned configuration shall be
-// a valid input for the
-// AddAudioOutputConfiguration command.
+/// This command lists all audio output configurations of a device that are
+/// compatible with a certain media profile. Each returned configuration shall be
+/// a valid input for the
+/// AddAudioOutputConfiguration command.
 pub async fn get_compatible_audio_output_configurations<T: transport::Transport>(
     transport: &T,
     request: &GetCompatibleAudioOutputConfigurations,
@@ -2783,10 +2783,10 @@ pub async fn get_compatible_audio_output_configurations<T: transport::Transport>
     transport::request(transport, request).await
 }
 
-// This operation lists all the audio decoder configurations of the device that
-// are compatible with a certain media profile. Each of the returned
-// configurations shall be a valid input parameter for the
-// AddAudioDecoderConfiguration command on the media profile.
+/// This operation lists all the audio decoder configurations of the device that
+/// are compatible with a certain media profile. Each of the returned
+/// configurations shall be a valid input parameter for the
+/// AddAudioDecoderConfiguration command on the media profile.
 pub async fn get_compatible_audio_decoder_configurations<T: transport::Transport>(
     transport: &T,
     request: &GetCompatibleAudioDecoderConfigurations,
@@ -2794,13 +2794,13 @@ pub async fn get_compatible_audio_decoder_configurations<T: transport::Transport
     transport::request(transport, request).await
 }
 
-// This operation modifies a video source configuration. The ForcePersistence
-// flag indicates if the changes shall remain after reboot of the device.
-// Running streams using this configuration may be immediately updated according
-// to the new settings. The changes are not guaranteed to take effect unless the
-// client requests a new stream URI and restarts any affected stream. NVC
-// methods for changing a running stream are out of scope for this
-// specification.
+/// This operation modifies a video source configuration. The ForcePersistence
+/// flag indicates if the changes shall remain after reboot of the device.
+/// Running streams using this configuration may be immediately updated according
+/// to the new settings. The changes are not guaranteed to take effect unless the
+/// client requests a new stream URI and restarts any affected stream. NVC
+/// methods for changing a running stream are out of scope for this
+/// specification.
 pub async fn set_video_source_configuration<T: transport::Transport>(
     transport: &T,
     request: &SetVideoSourceConfiguration,
@@ -2808,13 +2808,13 @@ pub async fn set_video_source_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation modifies a video encoder configuration. The ForcePersistence
-// flag indicates if the changes shall remain after reboot of the device.
-// Changes in the Multicast settings shall always be persistent. Running streams
-// using this configuration may be immediately updated according to the new
-// settings. The changes are not guaranteed to take effect unless the client
-// requests a new stream URI and restarts any affected stream. NVC methods for
-// changing a running stream are out of scope for this specification.
+/// This operation modifies a video encoder configuration. The ForcePersistence
+/// flag indicates if the changes shall remain after reboot of the device.
+/// Changes in the Multicast settings shall always be persistent. Running streams
+/// using this configuration may be immediately updated according to the new
+/// settings. The changes are not guaranteed to take effect unless the client
+/// requests a new stream URI and restarts any affected stream. NVC methods for
+/// changing a running stream are out of scope for this specification.
 pub async fn set_video_encoder_configuration<T: transport::Transport>(
     transport: &T,
     request: &SetVideoEncoderConfiguration,
@@ -2822,16 +2822,16 @@ pub async fn set_video_encoder_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation modifies an audio source configuration. The ForcePersistence
-// flag indicates if
-// the changes shall remain after reboot of the device. Running streams using
-// this configuration
-// may be immediately updated according to the new settings. The changes are not
-// guaranteed
-// to take effect unless the client requests a new stream URI and restarts any
-// affected stream
-// NVC methods for changing a running stream are out of scope for this
-// specification.
+/// This operation modifies an audio source configuration. The ForcePersistence
+/// flag indicates if
+/// the changes shall remain after reboot of the device. Running streams using
+/// this configuration
+/// may be immediately updated according to the new settings. The changes are not
+/// guaranteed
+/// to take effect unless the client requests a new stream URI and restarts any
+/// affected stream
+/// NVC methods for changing a running stream are out of scope for this
+/// specification.
 pub async fn set_audio_source_configuration<T: transport::Transport>(
     transport: &T,
     request: &SetAudioSourceConfiguration,
@@ -2839,15 +2839,15 @@ pub async fn set_audio_source_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation modifies an audio encoder configuration. The ForcePersistence
-// flag indicates if
-// the changes shall remain after reboot of the device. Running streams using
-// this configuration may be immediately updated
-// according to the new settings. The changes are not guaranteed to take effect
-// unless the client
-// requests a new stream URI and restarts any affected streams. NVC methods for
-// changing a
-// running stream are out of scope for this specification.
+/// This operation modifies an audio encoder configuration. The ForcePersistence
+/// flag indicates if
+/// the changes shall remain after reboot of the device. Running streams using
+/// this configuration may be immediately updated
+/// according to the new settings. The changes are not guaranteed to take effect
+/// unless the client
+/// requests a new stream URI and restarts any affected streams. NVC methods for
+/// changing a
+/// running stream are out of scope for this specification.
 pub async fn set_audio_encoder_configuration<T: transport::Transport>(
     transport: &T,
     request: &SetAudioEncoderConfiguration,
@@ -2855,17 +2855,17 @@ pub async fn set_audio_encoder_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// A video analytics configuration is modified using this command. The
-// ForcePersistence flag
-// indicates if the changes shall remain after reboot of the device or not.
-// Running streams using
-// this configuration shall be immediately updated according to the new
-// settings. Otherwise
-// inconsistencies can occur between the scene description processed by the rule
-// engine and
-// the notifications produced by analytics engine and rule engine which
-// reference the very same
-// video analytics configuration token.
+/// A video analytics configuration is modified using this command. The
+/// ForcePersistence flag
+/// indicates if the changes shall remain after reboot of the device or not.
+/// Running streams using
+/// this configuration shall be immediately updated according to the new
+/// settings. Otherwise
+/// inconsistencies can occur between the scene description processed by the rule
+/// engine and
+/// the notifications produced by analytics engine and rule engine which
+/// reference the very same
+/// video analytics configuration token.
 pub async fn set_video_analytics_configuration<T: transport::Transport>(
     transport: &T,
     request: &SetVideoAnalyticsConfiguration,
@@ -2873,17 +2873,17 @@ pub async fn set_video_analytics_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation modifies a metadata configuration. The ForcePersistence flag
-// indicates if the
-// changes shall remain after reboot of the device. Changes in the Multicast
-// settings shall
-// always be persistent. Running streams using this configuration may be updated
-// immediately
-// according to the new settings. The changes are not guaranteed to take effect
-// unless the client
-// requests a new stream URI and restarts any affected streams. NVC methods for
-// changing a
-// running stream are out of scope for this specification.
+/// This operation modifies a metadata configuration. The ForcePersistence flag
+/// indicates if the
+/// changes shall remain after reboot of the device. Changes in the Multicast
+/// settings shall
+/// always be persistent. Running streams using this configuration may be updated
+/// immediately
+/// according to the new settings. The changes are not guaranteed to take effect
+/// unless the client
+/// requests a new stream URI and restarts any affected streams. NVC methods for
+/// changing a
+/// running stream are out of scope for this specification.
 pub async fn set_metadata_configuration<T: transport::Transport>(
     transport: &T,
     request: &SetMetadataConfiguration,
@@ -2891,9 +2891,9 @@ pub async fn set_metadata_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation modifies an audio output configuration. The ForcePersistence
-// flag indicates if
-// the changes shall remain after reboot of the device.
+/// This operation modifies an audio output configuration. The ForcePersistence
+/// flag indicates if
+/// the changes shall remain after reboot of the device.
 pub async fn set_audio_output_configuration<T: transport::Transport>(
     transport: &T,
     request: &SetAudioOutputConfiguration,
@@ -2901,9 +2901,9 @@ pub async fn set_audio_output_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation modifies an audio decoder configuration. The ForcePersistence
-// flag indicates if
-// the changes shall remain after reboot of the device.
+/// This operation modifies an audio decoder configuration. The ForcePersistence
+/// flag indicates if
+/// the changes shall remain after reboot of the device.
 pub async fn set_audio_decoder_configuration<T: transport::Transport>(
     transport: &T,
     request: &SetAudioDecoderConfiguration,
@@ -2911,13 +2911,13 @@ pub async fn set_audio_decoder_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation returns the available options (supported values and ranges for
-// video source configuration parameters) when the video source parameters are
-// reconfigured If a video source configuration is specified, the options shall
-// concern that
-// particular configuration. If a media profile is specified, the options shall
-// be compatible with
-// that media profile.
+/// This operation returns the available options (supported values and ranges for
+/// video source configuration parameters) when the video source parameters are
+/// reconfigured If a video source configuration is specified, the options shall
+/// concern that
+/// particular configuration. If a media profile is specified, the options shall
+/// be compatible with
+/// that media profile.
 pub async fn get_video_source_configuration_options<T: transport::Transport>(
     transport: &T,
     request: &GetVideoSourceConfigurationOptions,
@@ -2925,9 +2925,9 @@ pub async fn get_video_source_configuration_options<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation returns the available options (supported values and ranges for
-// video encoder
-// configuration parameters) when the video encoder parameters are reconfigured.
+/// This operation returns the available options (supported values and ranges for
+/// video encoder
+/// configuration parameters) when the video encoder parameters are reconfigured.
 pub async fn get_video_encoder_configuration_options<T: transport::Transport>(
     transport: &T,
     request: &GetVideoEncoderConfigurationOptions,
@@ -2935,13 +2935,13 @@ pub async fn get_video_encoder_configuration_options<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation returns the available options (supported values and ranges for
-// audio source configuration parameters) when the audio source parameters are
-// reconfigured. If an audio source configuration is specified, the options
-// shall concern that
-// particular configuration. If a media profile is specified, the options shall
-// be compatible with
-// that media profile.
+/// This operation returns the available options (supported values and ranges for
+/// audio source configuration parameters) when the audio source parameters are
+/// reconfigured. If an audio source configuration is specified, the options
+/// shall concern that
+/// particular configuration. If a media profile is specified, the options shall
+/// be compatible with
+/// that media profile.
 pub async fn get_audio_source_configuration_options<T: transport::Transport>(
     transport: &T,
     request: &GetAudioSourceConfigurationOptions,
@@ -2949,9 +2949,9 @@ pub async fn get_audio_source_configuration_options<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation returns the available options (supported values and ranges for
-// audio encoder configuration parameters) when the audio encoder parameters are
-// reconfigured.
+/// This operation returns the available options (supported values and ranges for
+/// audio encoder configuration parameters) when the audio encoder parameters are
+/// reconfigured.
 pub async fn get_audio_encoder_configuration_options<T: transport::Transport>(
     transport: &T,
     request: &GetAudioEncoderConfigurationOptions,
@@ -2959,8 +2959,8 @@ pub async fn get_audio_encoder_configuration_options<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation returns the available options (supported values and ranges for
-// metadata configuration parameters) for changing the metadata configuration.
+/// This operation returns the available options (supported values and ranges for
+/// metadata configuration parameters) for changing the metadata configuration.
 pub async fn get_metadata_configuration_options<T: transport::Transport>(
     transport: &T,
     request: &GetMetadataConfigurationOptions,
@@ -2968,8 +2968,8 @@ pub async fn get_metadata_configuration_options<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This operation returns the available options (supported values and ranges for
-// audio output configuration parameters) for configuring an audio output.
+/// This operation returns the available options (supported values and ranges for
+/// audio output configuration parameters) for configuring an audio output.
 pub async fn get_audio_output_configuration_options<T: transport::Transport>(
     transport: &T,
     request: &GetAudioOutputConfigurationOptions,
@@ -2977,9 +2977,9 @@ pub async fn get_audio_output_configuration_options<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This command list the audio decoding capabilities for a given profile and
-// configuration of a
-// device.
+/// This command list the audio decoding capabilities for a given profile and
+/// configuration of a
+/// device.
 pub async fn get_audio_decoder_configuration_options<T: transport::Transport>(
     transport: &T,
     request: &GetAudioDecoderConfigurationOptions,
@@ -2987,11 +2987,11 @@ pub async fn get_audio_decoder_configuration_options<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// The GetGuaranteedNumberOfVideoEncoderInstances command can be used to request
-// the
-// minimum number of guaranteed video encoder instances (applications) per Video
-// Source
-// Configuration.
+/// The GetGuaranteedNumberOfVideoEncoderInstances command can be used to request
+/// the
+/// minimum number of guaranteed video encoder instances (applications) per Video
+/// Source
+/// Configuration.
 pub async fn get_guaranteed_number_of_video_encoder_instances<T: transport::Transport>(
     transport: &T,
     request: &GetGuaranteedNumberOfVideoEncoderInstances,
@@ -2999,13 +2999,13 @@ pub async fn get_guaranteed_number_of_video_encoder_instances<T: transport::Tran
     transport::request(transport, request).await
 }
 
-// This operation requests a URI that can be used to initiate a live media
-// stream using RTSP as
-// the control protocol. The returned URI shall remain valid indefinitely even
-// if the profile is
-// changed. The ValidUntilConnect, ValidUntilReboot and Timeout Parameter shall
-// be set
-// accordingly (ValidUntilConnect=false, ValidUntilReboot=false, timeout=PT0S).
+/// This operation requests a URI that can be used to initiate a live media
+/// stream using RTSP as
+/// the control protocol. The returned URI shall remain valid indefinitely even
+/// if the profile is
+/// changed. The ValidUntilConnect, ValidUntilReboot and Timeout Parameter shall
+/// be set
+/// accordingly (ValidUntilConnect=false, ValidUntilReboot=false, timeout=PT0S).
 pub async fn get_stream_uri<T: transport::Transport>(
     transport: &T,
     request: &GetStreamUri,
@@ -3013,16 +3013,16 @@ pub async fn get_stream_uri<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This command starts multicast streaming using a specified media profile of a
-// device.
-// Streaming continues until StopMulticastStreaming is called for the same
-// Profile. The
-// streaming shall continue after a reboot of the device until a
-// StopMulticastStreaming request is
-// received. The multicast address, port and TTL are configured in the
-// VideoEncoderConfiguration, AudioEncoderConfiguration and
-// MetadataConfiguration
-// respectively.
+/// This command starts multicast streaming using a specified media profile of a
+/// device.
+/// Streaming continues until StopMulticastStreaming is called for the same
+/// Profile. The
+/// streaming shall continue after a reboot of the device until a
+/// StopMulticastStreaming request is
+/// received. The multicast address, port and TTL are configured in the
+/// VideoEncoderConfiguration, AudioEncoderConfiguration and
+/// MetadataConfiguration
+/// respectively.
 pub async fn start_multicast_streaming<T: transport::Transport>(
     transport: &T,
     request: &StartMulticastStreaming,
@@ -3030,8 +3030,8 @@ pub async fn start_multicast_streaming<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This command stop multicast streaming using a specified media profile of a
-// device
+/// This command stop multicast streaming using a specified media profile of a
+/// device
 pub async fn stop_multicast_streaming<T: transport::Transport>(
     transport: &T,
     request: &StopMulticastStreaming,
@@ -3039,25 +3039,25 @@ pub async fn stop_multicast_streaming<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Synchronization points allow clients to decode and correctly use all data
-// after the
-// synchronization point.
-// For example, if a video stream is configured with a large I-frame distance
-// and a client loses a
-// single packet, the client does not display video until the next I-frame is
-// transmitted. In such
-// cases, the client can request a Synchronization Point which enforces the
-// device to add an I-Frame as soon as possible. Clients can request
-// Synchronization Points for profiles. The device
-// shall add synchronization points for all streams associated with this
-// profile.
-// Similarly, a synchronization point is used to get an update on full PTZ or
-// event status through
-// the metadata stream.
-// If a video stream is associated with the profile, an I-frame shall be added
-// to this video stream.
-// If a PTZ metadata stream is associated to the profile,
-// the PTZ position shall be repeated within the metadata stream.
+/// Synchronization points allow clients to decode and correctly use all data
+/// after the
+/// synchronization point.
+/// For example, if a video stream is configured with a large I-frame distance
+/// and a client loses a
+/// single packet, the client does not display video until the next I-frame is
+/// transmitted. In such
+/// cases, the client can request a Synchronization Point which enforces the
+/// device to add an I-Frame as soon as possible. Clients can request
+/// Synchronization Points for profiles. The device
+/// shall add synchronization points for all streams associated with this
+/// profile.
+/// Similarly, a synchronization point is used to get an update on full PTZ or
+/// event status through
+/// the metadata stream.
+/// If a video stream is associated with the profile, an I-frame shall be added
+/// to this video stream.
+/// If a PTZ metadata stream is associated to the profile,
+/// the PTZ position shall be repeated within the metadata stream.
 pub async fn set_synchronization_point<T: transport::Transport>(
     transport: &T,
     request: &SetSynchronizationPoint,
@@ -3065,21 +3065,21 @@ pub async fn set_synchronization_point<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// A client uses the GetSnapshotUri command to obtain a JPEG snapshot from the
-// device. The returned URI shall remain valid indefinitely even if the profile
-// is changed. The
-// ValidUntilConnect, ValidUntilReboot and Timeout Parameter shall be set
-// accordingly
-// (ValidUntilConnect=false, ValidUntilReboot=false, timeout=PT0S). The URI can
-// be used for
-// acquiring a JPEG image through a HTTP GET operation. The image encoding will
-// always be
-// JPEG regardless of the encoding setting in the media profile. The Jpeg
-// settings
-// (like resolution or quality) may be taken from the profile if suitable. The
-// provided
-// image will be updated automatically and independent from calls to
-// GetSnapshotUri.
+/// A client uses the GetSnapshotUri command to obtain a JPEG snapshot from the
+/// device. The returned URI shall remain valid indefinitely even if the profile
+/// is changed. The
+/// ValidUntilConnect, ValidUntilReboot and Timeout Parameter shall be set
+/// accordingly
+/// (ValidUntilConnect=false, ValidUntilReboot=false, timeout=PT0S). The URI can
+/// be used for
+/// acquiring a JPEG image through a HTTP GET operation. The image encoding will
+/// always be
+/// JPEG regardless of the encoding setting in the media profile. The Jpeg
+/// settings
+/// (like resolution or quality) may be taken from the profile if suitable. The
+/// provided
+/// image will be updated automatically and independent from calls to
+/// GetSnapshotUri.
 pub async fn get_snapshot_uri<T: transport::Transport>(
     transport: &T,
     request: &GetSnapshotUri,
@@ -3087,9 +3087,9 @@ pub async fn get_snapshot_uri<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// A device returns the information for current video source mode and settable
-// video source modes of specified video source. A device that indicates a
-// capability of VideoSourceModes shall support this command.
+/// A device returns the information for current video source mode and settable
+/// video source modes of specified video source. A device that indicates a
+/// capability of VideoSourceModes shall support this command.
 pub async fn get_video_source_modes<T: transport::Transport>(
     transport: &T,
     request: &GetVideoSourceModes,
@@ -3097,10 +3097,10 @@ pub async fn get_video_source_modes<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// SetVideoSourceMode changes the media profile structure relating to video
-// source for the specified video source mode. A device that indicates a
-// capability of VideoSourceModes shall support this command. The behavior after
-// changing the mode is not defined in this specification.
+/// SetVideoSourceMode changes the media profile structure relating to video
+/// source for the specified video source mode. A device that indicates a
+/// capability of VideoSourceModes shall support this command. The behavior after
+/// changing the mode is not defined in this specification.
 pub async fn set_video_source_mode<T: transport::Transport>(
     transport: &T,
     request: &SetVideoSourceMode,
@@ -3108,7 +3108,7 @@ pub async fn set_video_source_mode<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Get the OSDs.
+/// Get the OSDs.
 pub async fn get_os_ds<T: transport::Transport>(
     transport: &T,
     request: &GetOSDs,
@@ -3116,7 +3116,7 @@ pub async fn get_os_ds<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Get the OSD.
+/// Get the OSD.
 pub async fn get_osd<T: transport::Transport>(
     transport: &T,
     request: &GetOSD,
@@ -3124,7 +3124,7 @@ pub async fn get_osd<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Get the OSD Options.
+/// Get the OSD Options.
 pub async fn get_osd_options<T: transport::Transport>(
     transport: &T,
     request: &GetOSDOptions,
@@ -3132,7 +3132,7 @@ pub async fn get_osd_options<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Set the OSD
+/// Set the OSD
 pub async fn set_osd<T: transport::Transport>(
     transport: &T,
     request: &SetOSD,
@@ -3140,7 +3140,7 @@ pub async fn set_osd<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Create the OSD.
+/// Create the OSD.
 pub async fn create_osd<T: transport::Transport>(
     transport: &T,
     request: &CreateOSD,
@@ -3148,7 +3148,7 @@ pub async fn create_osd<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Delete the OSD.
+/// Delete the OSD.
 pub async fn delete_osd<T: transport::Transport>(
     transport: &T,
     request: &DeleteOSD,
@@ -1,5 +1,6 @@
 use crate::b_2 as wsnt;
 pub use crate::common::*;
+use crate::raw::RawXml;
 use crate::validate::Validate;
 use xsd_types::types as xs;
 
@@ -40,6 +41,7 @@ pub struct AppearanceExtension {}
 impl Validate for AppearanceExtension {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[non_exhaustive]
 pub enum VehicleType {
     Bus,
     Car,
@@ -58,6 +60,7 @@ impl Default for VehicleType {
 impl Validate for VehicleType {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[non_exhaustive]
 pub enum PlateType {
     Normal,
     Police,
@@ -92,21 +95,21 @@ impl Validate for VehicleInfo {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct LicensePlateInfo {
-    // A string of vehicle license plate number.
+    /// A string of vehicle license plate number.
     #[yaserde(prefix = "tt", rename = "PlateNumber")]
     pub plate_number: StringLikelihood,
 
-    // A description of the vehicle license plate, e.g., "Normal", "Police",
-    // "Diplomat"
+    /// A description of the vehicle license plate, e.g., "Normal", "Police",
+    /// "Diplomat"
     #[yaserde(prefix = "tt", rename = "PlateType")]
     pub plate_type: StringLikelihood,
 
-    // Describe the country of the license plate, in order to avoid the same
-    // license plate number.
+    /// Describe the country of the license plate, in order to avoid the same
+    /// license plate number.
     #[yaserde(prefix = "tt", rename = "CountryCode")]
     pub country_code: StringLikelihood,
 
-    // State province or authority that issue the license plate.
+    /// State province or authority that issue the license plate.
     #[yaserde(prefix = "tt", rename = "IssuingEntity")]
     pub issuing_entity: StringLikelihood,
 }
@@ -175,6 +178,7 @@ pub struct ColorDescriptorExtension {}
 impl Validate for ColorDescriptorExtension {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[non_exhaustive]
 pub enum ClassType {
     Animal,
     Face,
@@ -210,8 +214,8 @@ pub struct ClassDescriptor {
     #[yaserde(prefix = "tt", rename = "Extension")]
     pub extension: Option<ClassDescriptorExtension>,
 
-    // ONVIF recommends to use this 'Type' element instead of 'ClassCandidate'
-    // and 'Extension' above for new design.
+    /// ONVIF recommends to use this 'Type' element instead of 'ClassCandidate'
+    /// and 'Extension' above for new design.
     #[yaserde(prefix = "tt", rename = "Type")]
     pub _type: Vec<StringLikelihood>,
 }
@@ -240,8 +244,11 @@ pub struct ClassDescriptorExtension {
     #[yaserde(prefix = "tt", rename = "OtherTypes")]
     pub other_types: Vec<OtherType>,
 
+    /// `Extension` inside `Extension` causes infinite loop at deserialization
+    /// https://github.com/media-io/yaserde/issues/76
+    /// so it's kept as raw XML.
     #[yaserde(prefix = "tt", rename = "Extension")]
-    pub extension: Option<ClassDescriptorExtension2>,
+    pub extension: Option<RawXml>,
 }
 
 impl Validate for ClassDescriptorExtension {}
@@ -255,12 +262,12 @@ impl Validate for ClassDescriptorExtension2 {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct OtherType {
-    // Object Class Type
+    /// Object Class Type
     #[yaserde(prefix = "tt", rename = "Type")]
     pub _type: String,
 
-    // A likelihood/probability that the corresponding object belongs to this
-    // class. The sum of the likelihoods shall NOT exceed 1
+    /// A likelihood/probability that the corresponding object belongs to this
+    /// class. The sum of the likelihoods shall NOT exceed 1
     #[yaserde(prefix = "tt", rename = "Likelihood")]
     pub likelihood: f64,
 }
@@ -321,8 +328,11 @@ pub struct FrameExtension {
     #[yaserde(prefix = "tt", rename = "MotionInCells")]
     pub motion_in_cells: Option<MotionInCells>,
 
+    /// `Extension` inside `Extension` causes infinite loop at deserialization
+    /// https://github.com/media-io/yaserde/issues/76
+    /// so it's kept as raw XML.
     #[yaserde(prefix = "tt", rename = "Extension")]
-    pub extension: Option<FrameExtension2>,
+    pub extension: Option<RawXml>,
 }
 
 impl Validate for FrameExtension {}
@@ -447,20 +457,20 @@ impl Validate for ObjectTreeExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct MotionInCells {
-    // Number of columns of the cell grid (x dimension)
+    /// Number of columns of the cell grid (x dimension)
     #[yaserde(attribute, rename = "Columns")]
     pub columns: xs::Integer,
 
-    // Number of rows of the cell grid (y dimension)
+    /// Number of rows of the cell grid (y dimension)
     #[yaserde(attribute, rename = "Rows")]
     pub rows: xs::Integer,
 
-    // A “1” denotes a cell where motion is detected and a “0” an empty
-    // cell. The first cell is in the upper left corner. Then the cell order
-    // goes first from left to right and then from up to down. If the number of
-    // cells is not a multiple of 8 the last byte is filled with zeros. The
-    // information is run length encoded according to Packbit coding in ISO
-    // 12369 (TIFF, Revision 6.0).
+    /// A “1” denotes a cell where motion is detected and a “0” an empty
+    /// cell. The first cell is in the upper left corner. Then the cell order
+    /// goes first from left to right and then from up to down. If the number of
+    /// cells is not a multiple of 8 the last byte is filled with zeros. The
+    /// information is run length encoded according to Packbit coding in ISO
+    /// 12369 (TIFF, Revision 6.0).
     #[yaserde(attribute, rename = "Cells")]
     pub cells: String,
 }
@@ -480,6 +490,7 @@ pub mod metadata_stream {
     use super::*;
 
     #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
+    #[non_exhaustive]
     pub enum MetadataStreamChoice {
         VideoAnalytics(VideoAnalyticsStream),
         #[yaserde(rename = "PTZ")]
@@ -504,8 +515,11 @@ pub struct MetadataStreamExtension {
     #[yaserde(prefix = "tt", rename = "AudioAnalyticsStream")]
     pub audio_analytics_stream: Option<AudioAnalyticsStream>,
 
+    /// `Extension` inside `Extension` causes infinite loop at deserialization
+    /// https://github.com/media-io/yaserde/issues/76
+    /// so it's kept as raw XML.
     #[yaserde(prefix = "tt", rename = "Extension")]
-    pub extension: Option<MetadataStreamExtension2>,
+    pub extension: Option<RawXml>,
 }
 
 impl Validate for MetadataStreamExtension {}
@@ -545,6 +559,7 @@ impl Validate for AudioAnalyticsStreamExtension {}
 
 // pub type MetadataStream = MetadataStream;
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[non_exhaustive]
 pub enum VideoAnalyticsStreamChoice {
     Frame(Frame),
     Extension(VideoAnalyticsStreamExtension),
@@ -575,6 +590,7 @@ pub struct VideoAnalyticsStreamExtension {}
 impl Validate for VideoAnalyticsStreamExtension {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[non_exhaustive]
 pub enum PtzstreamChoice {
     #[yaserde(rename = "PTZStatus")]
     Ptzstatus(Ptzstatus),
@@ -606,6 +622,7 @@ pub struct PtzstreamExtension {}
 impl Validate for PtzstreamExtension {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[non_exhaustive]
 pub enum EventStreamChoice {
     #[yaserde(prefix = "wsnt", rename = "NotificationMessage")]
     NotificationMessage(wsnt::NotificationMessage),
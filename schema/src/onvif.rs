@@ -1,23 +1,25 @@
-use crate::{b_2 as wsnt, soap_envelope as soapenv, validate::Validate, xmlmime as xmime, xop};
+use crate::{
+    b_2 as wsnt, raw::RawXml, soap_envelope as soapenv, validate::Validate, xmlmime as xmime, xop,
+};
 use std::str::FromStr;
 use xsd_macro_utils::*;
 use xsd_types::types as xs;
 
 pub use crate::common::*;
 
-// Base class for physical entities like inputs and outputs.
+/// Base class for physical entities like inputs and outputs.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct DeviceEntity {
-    // Unique identifier referencing the physical entity.
+    /// Unique identifier referencing the physical entity.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
 
 impl Validate for DeviceEntity {}
 
-// User readable name. Length up to 64 characters.
-#[derive(Default, PartialEq, Debug, UtilsTupleIo, UtilsDefaultSerde)]
+/// User readable name. Length up to 64 characters.
+#[derive(Default, Clone, PartialEq, Eq, Hash, Debug, UtilsTupleIo, UtilsDefaultSerde)]
 pub struct Name(pub String);
 
 impl Validate for Name {
@@ -32,7 +34,7 @@ impl Validate for Name {
     }
 }
 
-// Rectangle defined by lower left corner position and size. Units are pixel.
+/// Rectangle defined by lower left corner position and size. Units are pixel.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct IntRectangle {
@@ -51,31 +53,31 @@ pub struct IntRectangle {
 
 impl Validate for IntRectangle {}
 
-// Range of a rectangle. The rectangle itself is defined by lower left corner
-// position and size. Units are pixel.
+/// Range of a rectangle. The rectangle itself is defined by lower left corner
+/// position and size. Units are pixel.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct IntRectangleRange {
-    // Range of X-axis.
+    /// Range of X-axis.
     #[yaserde(prefix = "tt", rename = "XRange")]
     pub x_range: IntRange,
 
-    // Range of Y-axis.
+    /// Range of Y-axis.
     #[yaserde(prefix = "tt", rename = "YRange")]
     pub y_range: IntRange,
 
-    // Range of width.
+    /// Range of width.
     #[yaserde(prefix = "tt", rename = "WidthRange")]
     pub width_range: IntRange,
 
-    // Range of height.
+    /// Range of height.
     #[yaserde(prefix = "tt", rename = "HeightRange")]
     pub height_range: IntRange,
 }
 
 impl Validate for IntRectangleRange {}
 
-// Range of values greater equal Min value and less equal Max value.
+/// Range of values greater equal Min value and less equal Max value.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct FloatRange {
@@ -88,7 +90,7 @@ pub struct FloatRange {
 
 impl Validate for FloatRange {}
 
-// Range of duration greater equal Min duration and less equal Max duration.
+/// Range of duration greater equal Min duration and less equal Max duration.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct DurationRange {
@@ -101,7 +103,7 @@ pub struct DurationRange {
 
 impl Validate for DurationRange {}
 
-// List of values.
+/// List of values.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct IntList {
@@ -165,22 +167,22 @@ impl Validate for AnyHolder {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct VideoSource {
-    // Frame rate in frames per second.
+    /// Frame rate in frames per second.
     #[yaserde(prefix = "tt", rename = "Framerate")]
     pub framerate: f64,
 
-    // Horizontal and vertical resolution
+    /// Horizontal and vertical resolution
     #[yaserde(prefix = "tt", rename = "Resolution")]
     pub resolution: VideoResolution,
 
-    // Optional configuration of the image sensor.
+    /// Optional configuration of the image sensor.
     #[yaserde(prefix = "tt", rename = "Imaging")]
     pub imaging: Option<ImagingSettings>,
 
     #[yaserde(prefix = "tt", rename = "Extension")]
     pub extension: Option<VideoSourceExtension>,
 
-    // Unique identifier referencing the physical entity.
+    /// Unique identifier referencing the physical entity.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
@@ -190,14 +192,15 @@ impl Validate for VideoSource {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct VideoSourceExtension {
-    // Optional configuration of the image sensor. To be used if imaging service
-    // 2.00 is supported.
+    /// Optional configuration of the image sensor. To be used if imaging service
+    /// 2.00 is supported.
     #[yaserde(prefix = "tt", rename = "Imaging")]
     pub imaging: Option<ImagingSettings20>,
-    // `Extension` inside `Extension` causes infinite loop at deserialization
-    // https://github.com/media-io/yaserde/issues/76
-    // #[yaserde(prefix = "tt", rename = "Extension")]
-    // pub extension: Option<VideoSourceExtension2>,
+    /// `Extension` inside `Extension` causes infinite loop at deserialization
+    /// https://github.com/media-io/yaserde/issues/76
+    /// so it's kept as raw XML.
+    #[yaserde(prefix = "tt", rename = "Extension")]
+    pub extension: Option<RawXml>,
 }
 
 impl Validate for VideoSourceExtension {}
@@ -211,65 +214,65 @@ impl Validate for VideoSourceExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct AudioSource {
-    // number of available audio channels. (1: mono, 2: stereo)
+    /// number of available audio channels. (1: mono, 2: stereo)
     #[yaserde(prefix = "tt", rename = "Channels")]
     pub channels: i32,
 
-    // Unique identifier referencing the physical entity.
+    /// Unique identifier referencing the physical entity.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
 
 impl Validate for AudioSource {}
 
-// A media profile consists of a set of media configurations. Media profiles are
-// used by a client
-// to configure properties of a media stream from an NVT.
+/// A media profile consists of a set of media configurations. Media profiles are
+/// used by a client
+/// to configure properties of a media stream from an NVT.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Profile {
-    // User readable name of the profile.
+    /// User readable name of the profile.
     #[yaserde(prefix = "tt", rename = "Name")]
     pub name: Name,
 
-    // Optional configuration of the Video input.
+    /// Optional configuration of the Video input.
     #[yaserde(prefix = "tt", rename = "VideoSourceConfiguration")]
     pub video_source_configuration: Option<VideoSourceConfiguration>,
 
-    // Optional configuration of the Audio input.
+    /// Optional configuration of the Audio input.
     #[yaserde(prefix = "tt", rename = "AudioSourceConfiguration")]
     pub audio_source_configuration: Option<AudioSourceConfiguration>,
 
-    // Optional configuration of the Video encoder.
+    /// Optional configuration of the Video encoder.
     #[yaserde(prefix = "tt", rename = "VideoEncoderConfiguration")]
     pub video_encoder_configuration: Option<VideoEncoderConfiguration>,
 
-    // Optional configuration of the Audio encoder.
+    /// Optional configuration of the Audio encoder.
     #[yaserde(prefix = "tt", rename = "AudioEncoderConfiguration")]
     pub audio_encoder_configuration: Option<AudioEncoderConfiguration>,
 
-    // Optional configuration of the video analytics module and rule engine.
+    /// Optional configuration of the video analytics module and rule engine.
     #[yaserde(prefix = "tt", rename = "VideoAnalyticsConfiguration")]
     pub video_analytics_configuration: Option<VideoAnalyticsConfiguration>,
 
-    // Optional configuration of the pan tilt zoom unit.
+    /// Optional configuration of the pan tilt zoom unit.
     #[yaserde(prefix = "tt", rename = "PTZConfiguration")]
     pub ptz_configuration: Option<Ptzconfiguration>,
 
-    // Optional configuration of the metadata stream.
+    /// Optional configuration of the metadata stream.
     #[yaserde(prefix = "tt", rename = "MetadataConfiguration")]
     pub metadata_configuration: Option<MetadataConfiguration>,
 
-    // Extensions defined in ONVIF 2.0
+    /// Extensions defined in ONVIF 2.0
     #[yaserde(prefix = "tt", rename = "Extension")]
     pub extension: Option<ProfileExtension>,
 
-    // Unique identifier of the profile.
+    /// Unique identifier of the profile.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 
-    // A value of true signals that the profile cannot be deleted. Default is
-    // false.
+    /// A value of true signals that the profile cannot be deleted. Default is
+    /// false.
     #[yaserde(attribute, rename = "fixed")]
     pub fixed: Option<bool>,
 }
@@ -279,17 +282,18 @@ impl Validate for Profile {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ProfileExtension {
-    // Optional configuration of the Audio output.
+    /// Optional configuration of the Audio output.
     #[yaserde(prefix = "tt", rename = "AudioOutputConfiguration")]
     pub audio_output_configuration: Option<AudioOutputConfiguration>,
 
-    // Optional configuration of the Audio decoder.
+    /// Optional configuration of the Audio decoder.
     #[yaserde(prefix = "tt", rename = "AudioDecoderConfiguration")]
     pub audio_decoder_configuration: Option<AudioDecoderConfiguration>,
-    // `Extension` inside `Extension` causes infinite loop at deserialization
-    // https://github.com/media-io/yaserde/issues/76
-    // #[yaserde(prefix = "tt", rename = "Extension")]
-    // pub extension: Option<ProfileExtension2>,
+    /// `Extension` inside `Extension` causes infinite loop at deserialization
+    /// https://github.com/media-io/yaserde/issues/76
+    /// so it's kept as raw XML.
+    #[yaserde(prefix = "tt", rename = "Extension")]
+    pub extension: Option<RawXml>,
 }
 
 impl Validate for ProfileExtension {}
@@ -309,20 +313,20 @@ impl Validate for ProfileExtension {}
 // pub type MetadataConfiguration = MetadataConfiguration;
 // pub type AudioOutputConfiguration = AudioOutputConfiguration;
 // pub type AudioDecoderConfiguration = AudioDecoderConfiguration;
-// Base type defining the common properties of a configuration.
+/// Base type defining the common properties of a configuration.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ConfigurationEntity {
-    // User readable name. Length up to 64 characters.
+    /// User readable name. Length up to 64 characters.
     #[yaserde(prefix = "tt", rename = "Name")]
     pub name: Name,
 
-    // Number of internal references currently using this configuration.
+    /// Number of internal references currently using this configuration.
     #[yaserde(prefix = "tt", rename = "UseCount")]
     pub use_count: i32,
 
-    // Token that uniquely references this configuration. Length up to 64
-    // characters.
+    /// Token that uniquely references this configuration. Length up to 64
+    /// characters.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
@@ -332,33 +336,33 @@ impl Validate for ConfigurationEntity {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct VideoSourceConfiguration {
-    // Reference to the physical input.
+    /// Reference to the physical input.
     #[yaserde(prefix = "tt", rename = "SourceToken")]
     pub source_token: ReferenceToken,
 
-    // Rectangle specifying the Video capturing area. The capturing area shall
-    // not be larger than the whole Video source area.
+    /// Rectangle specifying the Video capturing area. The capturing area shall
+    /// not be larger than the whole Video source area.
     #[yaserde(prefix = "tt", rename = "Bounds")]
     pub bounds: IntRectangle,
 
     #[yaserde(prefix = "tt", rename = "Extension")]
     pub extension: Option<VideoSourceConfigurationExtension>,
 
-    // Readonly parameter signalling Source configuration's view mode, for
-    // devices supporting different view modes as defined in tt:viewModes.
+    /// Readonly parameter signalling Source configuration's view mode, for
+    /// devices supporting different view modes as defined in tt:viewModes.
     #[yaserde(attribute, rename = "ViewMode")]
     pub view_mode: Option<String>,
 
-    // User readable name. Length up to 64 characters.
+    /// User readable name. Length up to 64 characters.
     #[yaserde(prefix = "tt", rename = "Name")]
     pub name: Name,
 
-    // Number of internal references currently using this configuration.
+    /// Number of internal references currently using this configuration.
     #[yaserde(prefix = "tt", rename = "UseCount")]
     pub use_count: i32,
 
-    // Token that uniquely references this configuration. Length up to 64
-    // characters.
+    /// Token that uniquely references this configuration. Length up to 64
+    /// characters.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
@@ -368,15 +372,16 @@ impl Validate for VideoSourceConfiguration {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct VideoSourceConfigurationExtension {
-    // Optional element to configure rotation of captured image.
-    // What resolutions a device supports shall be unaffected by the Rotate
-    // parameters.
+    /// Optional element to configure rotation of captured image.
+    /// What resolutions a device supports shall be unaffected by the Rotate
+    /// parameters.
     #[yaserde(prefix = "tt", rename = "Rotate")]
     pub rotate: Option<Rotate>,
-    // `Extension` inside `Extension` causes infinite loop at deserialization
-    // https://github.com/media-io/yaserde/issues/76
-    // #[yaserde(prefix = "tt", rename = "Extension")]
-    // pub extension: Option<VideoSourceConfigurationExtension2>,
+    /// `Extension` inside `Extension` causes infinite loop at deserialization
+    /// https://github.com/media-io/yaserde/issues/76
+    /// so it's kept as raw XML.
+    #[yaserde(prefix = "tt", rename = "Extension")]
+    pub extension: Option<RawXml>,
 }
 
 impl Validate for VideoSourceConfigurationExtension {}
@@ -400,13 +405,13 @@ impl Validate for VideoSourceConfigurationExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Rotate {
-    // Parameter to enable/disable Rotation feature.
+    /// Parameter to enable/disable Rotation feature.
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: RotateMode,
 
-    // Optional parameter to configure how much degree of clockwise rotation of
-    // image for On mode. Omitting this parameter for On mode means 180 degree
-    // rotation.
+    /// Optional parameter to configure how much degree of clockwise rotation of
+    /// image for On mode. Omitting this parameter for On mode means 180 degree
+    /// rotation.
     #[yaserde(prefix = "tt", rename = "Degree")]
     pub degree: Option<i32>,
 
@@ -424,15 +429,16 @@ impl Validate for RotateExtension {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum RotateMode {
-    // Enable the Rotate feature. Degree of rotation is specified Degree
-    // parameter.
+    /// Enable the Rotate feature. Degree of rotation is specified Degree
+    /// parameter.
     #[yaserde(rename = "OFF")]
     Off,
-    // Disable the Rotate feature.
+    /// Disable the Rotate feature.
     #[yaserde(rename = "ON")]
     On,
-    // Rotate feature is automatically activated by the device.
+    /// Rotate feature is automatically activated by the device.
     #[yaserde(rename = "AUTO")]
     Auto,
     __Unknown__(String),
@@ -449,16 +455,16 @@ impl Validate for RotateMode {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct LensProjection {
-    // Angle of incidence.
+    /// Angle of incidence.
     #[yaserde(prefix = "tt", rename = "Angle")]
     pub angle: f64,
 
-    // Mapping radius as a consequence of the emergent angle.
+    /// Mapping radius as a consequence of the emergent angle.
     #[yaserde(prefix = "tt", rename = "Radius")]
     pub radius: f64,
 
-    // Optional ray absorption at the given angle due to vignetting. A value of
-    // one means no absorption.
+    /// Optional ray absorption at the given angle due to vignetting. A value of
+    /// one means no absorption.
     #[yaserde(prefix = "tt", rename = "Transmittance")]
     pub transmittance: Option<f64>,
 }
@@ -468,11 +474,11 @@ impl Validate for LensProjection {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct LensOffset {
-    // Optional horizontal offset of the lens center in normalized coordinates.
+    /// Optional horizontal offset of the lens center in normalized coordinates.
     #[yaserde(attribute, rename = "x")]
     pub x: Option<f64>,
 
-    // Optional vertical offset of the lens center in normalized coordinates.
+    /// Optional vertical offset of the lens center in normalized coordinates.
     #[yaserde(attribute, rename = "y")]
     pub y: Option<f64>,
 }
@@ -482,25 +488,25 @@ impl Validate for LensOffset {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct LensDescription {
-    // Offset of the lens center to the imager center in normalized coordinates.
+    /// Offset of the lens center to the imager center in normalized coordinates.
     #[yaserde(prefix = "tt", rename = "Offset")]
     pub offset: LensOffset,
 
-    // Radial description of the projection characteristics. The resulting curve
-    // is defined by the B-Spline interpolation
-    // over the given elements. The element for Radius zero shall not be
-    // provided. The projection points shall be ordered with ascending Radius.
-    // Items outside the last projection Radius shall be assumed to be invisible
-    // (black).
+    /// Radial description of the projection characteristics. The resulting curve
+    /// is defined by the B-Spline interpolation
+    /// over the given elements. The element for Radius zero shall not be
+    /// provided. The projection points shall be ordered with ascending Radius.
+    /// Items outside the last projection Radius shall be assumed to be invisible
+    /// (black).
     #[yaserde(prefix = "tt", rename = "Projection")]
     pub projection: Vec<LensProjection>,
 
-    // Compensation of the x coordinate needed for the ONVIF normalized
-    // coordinate system.
+    /// Compensation of the x coordinate needed for the ONVIF normalized
+    /// coordinate system.
     #[yaserde(prefix = "tt", rename = "XFactor")]
     pub x_factor: f64,
 
-    // Optional focal length of the optical system.
+    /// Optional focal length of the optical system.
     #[yaserde(attribute, rename = "FocalLength")]
     pub focal_length: Option<f64>,
 }
@@ -510,23 +516,23 @@ impl Validate for LensDescription {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct VideoSourceConfigurationOptions {
-    // Supported range for the capturing area.
-    // Device that does not support cropped streaming shall express BoundsRange
-    // option as mentioned below
-    // BoundsRange->XRange and BoundsRange->YRange with same Min/Max values
-    // HeightRange and WidthRange Min/Max values same as VideoSource Height and
-    // Width Limits.
+    /// Supported range for the capturing area.
+    /// Device that does not support cropped streaming shall express BoundsRange
+    /// option as mentioned below
+    /// BoundsRange->XRange and BoundsRange->YRange with same Min/Max values
+    /// HeightRange and WidthRange Min/Max values same as VideoSource Height and
+    /// Width Limits.
     #[yaserde(prefix = "tt", rename = "BoundsRange")]
     pub bounds_range: IntRectangleRange,
 
-    // List of physical inputs.
+    /// List of physical inputs.
     #[yaserde(prefix = "tt", rename = "VideoSourceTokensAvailable")]
     pub video_source_tokens_available: Vec<ReferenceToken>,
 
     #[yaserde(prefix = "tt", rename = "Extension")]
     pub extension: Option<VideoSourceConfigurationOptionsExtension>,
 
-    // Maximum number of profiles.
+    /// Maximum number of profiles.
     #[yaserde(attribute, rename = "MaximumNumberOfProfiles")]
     pub maximum_number_of_profiles: Option<i32>,
 }
@@ -536,13 +542,14 @@ impl Validate for VideoSourceConfigurationOptions {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct VideoSourceConfigurationOptionsExtension {
-    // Options of parameters for Rotation feature.
+    /// Options of parameters for Rotation feature.
     #[yaserde(prefix = "tt", rename = "Rotate")]
     pub rotate: Option<RotateOptions>,
-    // `Extension` inside `Extension` causes infinite loop at deserialization
-    // https://github.com/media-io/yaserde/issues/76
-    // #[yaserde(prefix = "tt", rename = "Extension")]
-    // pub extension: Option<VideoSourceConfigurationOptionsExtension2>,
+    /// `Extension` inside `Extension` causes infinite loop at deserialization
+    /// https://github.com/media-io/yaserde/issues/76
+    /// so it's kept as raw XML.
+    #[yaserde(prefix = "tt", rename = "Extension")]
+    pub extension: Option<RawXml>,
 }
 
 impl Validate for VideoSourceConfigurationOptionsExtension {}
@@ -560,21 +567,21 @@ impl Validate for VideoSourceConfigurationOptionsExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct RotateOptions {
-    // Supported options of Rotate mode parameter.
+    /// Supported options of Rotate mode parameter.
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: Vec<RotateMode>,
 
-    // List of supported degree value for rotation.
+    /// List of supported degree value for rotation.
     #[yaserde(prefix = "tt", rename = "DegreeList")]
     pub degree_list: Option<IntList>,
 
     #[yaserde(prefix = "tt", rename = "Extension")]
     pub extension: Option<RotateOptionsExtension>,
 
-    // After setting the rotation, if a device starts to reboot this value is
-    // true.
-    // If a device can handle rotation setting without rebooting this value is
-    // false.
+    /// After setting the rotation, if a device starts to reboot this value is
+    /// true.
+    /// If a device can handle rotation setting without rebooting this value is
+    /// false.
     #[yaserde(attribute, rename = "Reboot")]
     pub reboot: Option<bool>,
 }
@@ -589,6 +596,7 @@ impl Validate for RotateOptionsExtension {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum SceneOrientationMode {
     #[yaserde(rename = "MANUAL")]
     Manual,
@@ -607,6 +615,7 @@ impl Validate for SceneOrientationMode {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum SceneOrientationOption {
     Below,
     Horizon,
@@ -625,22 +634,22 @@ impl Validate for SceneOrientationOption {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct SceneOrientation {
-    // Parameter to assign the way the camera determines the scene orientation.
+    /// Parameter to assign the way the camera determines the scene orientation.
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: SceneOrientationMode,
 
-    // Assigned or determined scene orientation based on the Mode. When
-    // assigning the Mode to AUTO, this field
-    // is optional and will be ignored by the device. When assigning the Mode to
-    // MANUAL, this field is required
-    // and the device will return an InvalidArgs fault if missing.
+    /// Assigned or determined scene orientation based on the Mode. When
+    /// assigning the Mode to AUTO, this field
+    /// is optional and will be ignored by the device. When assigning the Mode to
+    /// MANUAL, this field is required
+    /// and the device will return an InvalidArgs fault if missing.
     #[yaserde(prefix = "tt", rename = "Orientation")]
     pub orientation: Option<String>,
 }
 
 impl Validate for SceneOrientation {}
 
-// Source view modes supported by device.
+/// Source view modes supported by device.
 #[derive(Default, PartialEq, Debug, UtilsTupleIo, UtilsDefaultSerde)]
 pub struct ViewModes(pub String);
 
@@ -649,57 +658,57 @@ impl Validate for ViewModes {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct VideoEncoderConfiguration {
-    // Used video codec, either Jpeg, H.264 or Mpeg4
+    /// Used video codec, either Jpeg, H.264 or Mpeg4
     #[yaserde(prefix = "tt", rename = "Encoding")]
     pub encoding: VideoEncoding,
 
-    // Configured video resolution
+    /// Configured video resolution
     #[yaserde(prefix = "tt", rename = "Resolution")]
     pub resolution: VideoResolution,
 
-    // Relative value for the video quantizers and the quality of the video. A
-    // high value within supported quality range means higher quality
+    /// Relative value for the video quantizers and the quality of the video. A
+    /// high value within supported quality range means higher quality
     #[yaserde(prefix = "tt", rename = "Quality")]
     pub quality: f64,
 
-    // Optional element to configure rate control related parameters.
+    /// Optional element to configure rate control related parameters.
     #[yaserde(prefix = "tt", rename = "RateControl")]
     pub rate_control: Option<VideoRateControl>,
 
-    // Optional element to configure Mpeg4 related parameters.
+    /// Optional element to configure Mpeg4 related parameters.
     #[yaserde(prefix = "tt", rename = "MPEG4")]
     pub mpeg4: Option<Mpeg4Configuration>,
 
-    // Optional element to configure H.264 related parameters.
+    /// Optional element to configure H.264 related parameters.
     #[yaserde(prefix = "tt", rename = "H264")]
     pub h264: Option<H264Configuration>,
 
-    // Defines the multicast settings that could be used for video streaming.
+    /// Defines the multicast settings that could be used for video streaming.
     #[yaserde(prefix = "tt", rename = "Multicast")]
     pub multicast: MulticastConfiguration,
 
-    // The rtsp session timeout for the related video stream
+    /// The rtsp session timeout for the related video stream
     #[yaserde(prefix = "tt", rename = "SessionTimeout")]
     pub session_timeout: xs::Duration,
 
-    // A value of true indicates that frame rate is a fixed value rather than an
-    // upper limit,
-    // and that the video encoder shall prioritize frame rate over all other
-    // adaptable
-    // configuration values such as bitrate. Default is false.
+    /// A value of true indicates that frame rate is a fixed value rather than an
+    /// upper limit,
+    /// and that the video encoder shall prioritize frame rate over all other
+    /// adaptable
+    /// configuration values such as bitrate. Default is false.
     #[yaserde(attribute, rename = "GuaranteedFrameRate")]
     pub guaranteed_frame_rate: Option<bool>,
 
-    // User readable name. Length up to 64 characters.
+    /// User readable name. Length up to 64 characters.
     #[yaserde(prefix = "tt", rename = "Name")]
     pub name: Name,
 
-    // Number of internal references currently using this configuration.
+    /// Number of internal references currently using this configuration.
     #[yaserde(prefix = "tt", rename = "UseCount")]
     pub use_count: i32,
 
-    // Token that uniquely references this configuration. Length up to 64
-    // characters.
+    /// Token that uniquely references this configuration. Length up to 64
+    /// characters.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
@@ -708,6 +717,7 @@ impl Validate for VideoEncoderConfiguration {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum VideoEncoding {
     #[yaserde(rename = "JPEG")]
     Jpeg,
@@ -727,6 +737,7 @@ impl Validate for VideoEncoding {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum Mpeg4Profile {
     #[yaserde(rename = "SP")]
     Sp,
@@ -745,6 +756,7 @@ impl Validate for Mpeg4Profile {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum H264Profile {
     Baseline,
     Main,
@@ -764,11 +776,11 @@ impl Validate for H264Profile {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct VideoResolution {
-    // Number of the columns of the Video image.
+    /// Number of the columns of the Video image.
     #[yaserde(prefix = "tt", rename = "Width")]
     pub width: i32,
 
-    // Number of the lines of the Video image.
+    /// Number of the lines of the Video image.
     #[yaserde(prefix = "tt", rename = "Height")]
     pub height: i32,
 }
@@ -778,18 +790,18 @@ impl Validate for VideoResolution {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct VideoRateControl {
-    // Maximum output framerate in fps. If an EncodingInterval is provided the
-    // resulting encoded framerate will be reduced by the given factor.
+    /// Maximum output framerate in fps. If an EncodingInterval is provided the
+    /// resulting encoded framerate will be reduced by the given factor.
     #[yaserde(prefix = "tt", rename = "FrameRateLimit")]
     pub frame_rate_limit: i32,
 
-    // Interval at which images are encoded and transmitted. (A value of 1 means
-    // that every frame is encoded, a value of 2 means that every 2nd frame is
-    // encoded ...)
+    /// Interval at which images are encoded and transmitted. (A value of 1 means
+    /// that every frame is encoded, a value of 2 means that every 2nd frame is
+    /// encoded ...)
     #[yaserde(prefix = "tt", rename = "EncodingInterval")]
     pub encoding_interval: i32,
 
-    // the maximum output bitrate in kbps
+    /// the maximum output bitrate in kbps
     #[yaserde(prefix = "tt", rename = "BitrateLimit")]
     pub bitrate_limit: i32,
 }
@@ -799,15 +811,15 @@ impl Validate for VideoRateControl {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Mpeg4Configuration {
-    // Determines the interval in which the I-Frames will be coded. An entry of
-    // 1 indicates I-Frames are continuously generated. An entry of 2 indicates
-    // that every 2nd image is an I-Frame, and 3 only every 3rd frame, etc. The
-    // frames in between are coded as P or B Frames.
+    /// Determines the interval in which the I-Frames will be coded. An entry of
+    /// 1 indicates I-Frames are continuously generated. An entry of 2 indicates
+    /// that every 2nd image is an I-Frame, and 3 only every 3rd frame, etc. The
+    /// frames in between are coded as P or B Frames.
     #[yaserde(prefix = "tt", rename = "GovLength")]
     pub gov_length: i32,
 
-    // the Mpeg4 profile, either simple profile (SP) or advanced simple profile
-    // (ASP)
+    /// the Mpeg4 profile, either simple profile (SP) or advanced simple profile
+    /// (ASP)
     #[yaserde(prefix = "tt", rename = "Mpeg4Profile")]
     pub mpeg_4_profile: Mpeg4Profile,
 }
@@ -817,15 +829,15 @@ impl Validate for Mpeg4Configuration {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct H264Configuration {
-    // Group of Video frames length. Determines typically the interval in which
-    // the I-Frames will be coded. An entry of 1 indicates I-Frames are
-    // continuously generated. An entry of 2 indicates that every 2nd image is
-    // an I-Frame, and 3 only every 3rd frame, etc. The frames in between are
-    // coded as P or B Frames.
+    /// Group of Video frames length. Determines typically the interval in which
+    /// the I-Frames will be coded. An entry of 1 indicates I-Frames are
+    /// continuously generated. An entry of 2 indicates that every 2nd image is
+    /// an I-Frame, and 3 only every 3rd frame, etc. The frames in between are
+    /// coded as P or B Frames.
     #[yaserde(prefix = "tt", rename = "GovLength")]
     pub gov_length: i32,
 
-    // the H.264 profile, either baseline, main, extended or high
+    /// the H.264 profile, either baseline, main, extended or high
     #[yaserde(prefix = "tt", rename = "H264Profile")]
     pub h264_profile: H264Profile,
 }
@@ -835,27 +847,27 @@ impl Validate for H264Configuration {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct VideoEncoderConfigurationOptions {
-    // Range of the quality values. A high value means higher quality.
+    /// Range of the quality values. A high value means higher quality.
     #[yaserde(prefix = "tt", rename = "QualityRange")]
     pub quality_range: IntRange,
 
-    // Optional JPEG encoder settings ranges (See also Extension element).
+    /// Optional JPEG encoder settings ranges (See also Extension element).
     #[yaserde(prefix = "tt", rename = "JPEG")]
     pub jpeg: Option<JpegOptions>,
 
-    // Optional MPEG-4 encoder settings ranges (See also Extension element).
+    /// Optional MPEG-4 encoder settings ranges (See also Extension element).
     #[yaserde(prefix = "tt", rename = "MPEG4")]
     pub mpeg4: Option<Mpeg4Options>,
 
-    // Optional H.264 encoder settings ranges (See also Extension element).
+    /// Optional H.264 encoder settings ranges (See also Extension element).
     #[yaserde(prefix = "tt", rename = "H264")]
     pub h264: Option<H264Options>,
 
     #[yaserde(prefix = "tt", rename = "Extension")]
     pub extension: Option<VideoEncoderOptionsExtension>,
 
-    // Indicates the support for the GuaranteedFrameRate attribute on the
-    // VideoEncoderConfiguration element.
+    /// Indicates the support for the GuaranteedFrameRate attribute on the
+    /// VideoEncoderConfiguration element.
     #[yaserde(attribute, rename = "GuaranteedFrameRateSupported")]
     pub guaranteed_frame_rate_supported: Option<bool>,
 }
@@ -865,21 +877,22 @@ impl Validate for VideoEncoderConfigurationOptions {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct VideoEncoderOptionsExtension {
-    // Optional JPEG encoder settings ranges.
+    /// Optional JPEG encoder settings ranges.
     #[yaserde(prefix = "tt", rename = "JPEG")]
     pub jpeg: Option<JpegOptions2>,
 
-    // Optional MPEG-4 encoder settings ranges.
+    /// Optional MPEG-4 encoder settings ranges.
     #[yaserde(prefix = "tt", rename = "MPEG4")]
     pub mpeg4: Option<Mpeg4Options2>,
 
-    // Optional H.264 encoder settings ranges.
+    /// Optional H.264 encoder settings ranges.
     #[yaserde(prefix = "tt", rename = "H264")]
     pub h264: Option<H264Options2>,
-    // `Extension` inside `Extension` causes infinite loop at deserialization
-    // https://github.com/media-io/yaserde/issues/76
-    // #[yaserde(prefix = "tt", rename = "Extension")]
-    // pub extension: Option<VideoEncoderOptionsExtension2>,
+    /// `Extension` inside `Extension` causes infinite loop at deserialization
+    /// https://github.com/media-io/yaserde/issues/76
+    /// so it's kept as raw XML.
+    #[yaserde(prefix = "tt", rename = "Extension")]
+    pub extension: Option<RawXml>,
 }
 
 impl Validate for VideoEncoderOptionsExtension {}
@@ -893,17 +906,17 @@ impl Validate for VideoEncoderOptionsExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct JpegOptions {
-    // List of supported image sizes.
+    /// List of supported image sizes.
     #[yaserde(prefix = "tt", rename = "ResolutionsAvailable")]
     pub resolutions_available: Vec<VideoResolution>,
 
-    // Supported frame rate in fps (frames per second).
+    /// Supported frame rate in fps (frames per second).
     #[yaserde(prefix = "tt", rename = "FrameRateRange")]
     pub frame_rate_range: IntRange,
 
-    // Supported encoding interval range. The encoding interval corresponds to
-    // the number of frames devided by the encoded frames. An encoding interval
-    // value of "1" means that all frames are encoded.
+    /// Supported encoding interval range. The encoding interval corresponds to
+    /// the number of frames devided by the encoded frames. An encoding interval
+    /// value of "1" means that all frames are encoded.
     #[yaserde(prefix = "tt", rename = "EncodingIntervalRange")]
     pub encoding_interval_range: IntRange,
 }
@@ -913,21 +926,21 @@ impl Validate for JpegOptions {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct JpegOptions2 {
-    // Supported range of encoded bitrate in kbps.
+    /// Supported range of encoded bitrate in kbps.
     #[yaserde(prefix = "tt", rename = "BitrateRange")]
     pub bitrate_range: IntRange,
 
-    // List of supported image sizes.
+    /// List of supported image sizes.
     #[yaserde(prefix = "tt", rename = "ResolutionsAvailable")]
     pub resolutions_available: Vec<VideoResolution>,
 
-    // Supported frame rate in fps (frames per second).
+    /// Supported frame rate in fps (frames per second).
     #[yaserde(prefix = "tt", rename = "FrameRateRange")]
     pub frame_rate_range: IntRange,
 
-    // Supported encoding interval range. The encoding interval corresponds to
-    // the number of frames devided by the encoded frames. An encoding interval
-    // value of "1" means that all frames are encoded.
+    /// Supported encoding interval range. The encoding interval corresponds to
+    /// the number of frames devided by the encoded frames. An encoding interval
+    /// value of "1" means that all frames are encoded.
     #[yaserde(prefix = "tt", rename = "EncodingIntervalRange")]
     pub encoding_interval_range: IntRange,
 }
@@ -937,26 +950,26 @@ impl Validate for JpegOptions2 {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Mpeg4Options {
-    // List of supported image sizes.
+    /// List of supported image sizes.
     #[yaserde(prefix = "tt", rename = "ResolutionsAvailable")]
     pub resolutions_available: Vec<VideoResolution>,
 
-    // Supported group of Video frames length. This value typically corresponds
-    // to the I-Frame distance.
+    /// Supported group of Video frames length. This value typically corresponds
+    /// to the I-Frame distance.
     #[yaserde(prefix = "tt", rename = "GovLengthRange")]
     pub gov_length_range: IntRange,
 
-    // Supported frame rate in fps (frames per second).
+    /// Supported frame rate in fps (frames per second).
     #[yaserde(prefix = "tt", rename = "FrameRateRange")]
     pub frame_rate_range: IntRange,
 
-    // Supported encoding interval range. The encoding interval corresponds to
-    // the number of frames devided by the encoded frames. An encoding interval
-    // value of "1" means that all frames are encoded.
+    /// Supported encoding interval range. The encoding interval corresponds to
+    /// the number of frames devided by the encoded frames. An encoding interval
+    /// value of "1" means that all frames are encoded.
     #[yaserde(prefix = "tt", rename = "EncodingIntervalRange")]
     pub encoding_interval_range: IntRange,
 
-    // List of supported MPEG-4 profiles.
+    /// List of supported MPEG-4 profiles.
     #[yaserde(prefix = "tt", rename = "Mpeg4ProfilesSupported")]
     pub mpeg_4_profiles_supported: Vec<Mpeg4Profile>,
 }
@@ -966,30 +979,30 @@ impl Validate for Mpeg4Options {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Mpeg4Options2 {
-    // Supported range of encoded bitrate in kbps.
+    /// Supported range of encoded bitrate in kbps.
     #[yaserde(prefix = "tt", rename = "BitrateRange")]
     pub bitrate_range: IntRange,
 
-    // List of supported image sizes.
+    /// List of supported image sizes.
     #[yaserde(prefix = "tt", rename = "ResolutionsAvailable")]
     pub resolutions_available: Vec<VideoResolution>,
 
-    // Supported group of Video frames length. This value typically corresponds
-    // to the I-Frame distance.
+    /// Supported group of Video frames length. This value typically corresponds
+    /// to the I-Frame distance.
     #[yaserde(prefix = "tt", rename = "GovLengthRange")]
     pub gov_length_range: IntRange,
 
-    // Supported frame rate in fps (frames per second).
+    /// Supported frame rate in fps (frames per second).
     #[yaserde(prefix = "tt", rename = "FrameRateRange")]
     pub frame_rate_range: IntRange,
 
-    // Supported encoding interval range. The encoding interval corresponds to
-    // the number of frames devided by the encoded frames. An encoding interval
-    // value of "1" means that all frames are encoded.
+    /// Supported encoding interval range. The encoding interval corresponds to
+    /// the number of frames devided by the encoded frames. An encoding interval
+    /// value of "1" means that all frames are encoded.
     #[yaserde(prefix = "tt", rename = "EncodingIntervalRange")]
     pub encoding_interval_range: IntRange,
 
-    // List of supported MPEG-4 profiles.
+    /// List of supported MPEG-4 profiles.
     #[yaserde(prefix = "tt", rename = "Mpeg4ProfilesSupported")]
     pub mpeg_4_profiles_supported: Vec<Mpeg4Profile>,
 }
@@ -999,26 +1012,26 @@ impl Validate for Mpeg4Options2 {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct H264Options {
-    // List of supported image sizes.
+    /// List of supported image sizes.
     #[yaserde(prefix = "tt", rename = "ResolutionsAvailable")]
     pub resolutions_available: Vec<VideoResolution>,
 
-    // Supported group of Video frames length. This value typically corresponds
-    // to the I-Frame distance.
+    /// Supported group of Video frames length. This value typically corresponds
+    /// to the I-Frame distance.
     #[yaserde(prefix = "tt", rename = "GovLengthRange")]
     pub gov_length_range: IntRange,
 
-    // Supported frame rate in fps (frames per second).
+    /// Supported frame rate in fps (frames per second).
     #[yaserde(prefix = "tt", rename = "FrameRateRange")]
     pub frame_rate_range: IntRange,
 
-    // Supported encoding interval range. The encoding interval corresponds to
-    // the number of frames devided by the encoded frames. An encoding interval
-    // value of "1" means that all frames are encoded.
+    /// Supported encoding interval range. The encoding interval corresponds to
+    /// the number of frames devided by the encoded frames. An encoding interval
+    /// value of "1" means that all frames are encoded.
     #[yaserde(prefix = "tt", rename = "EncodingIntervalRange")]
     pub encoding_interval_range: IntRange,
 
-    // List of supported H.264 profiles.
+    /// List of supported H.264 profiles.
     #[yaserde(prefix = "tt", rename = "H264ProfilesSupported")]
     pub h264_profiles_supported: Vec<H264Profile>,
 }
@@ -1028,40 +1041,41 @@ impl Validate for H264Options {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct H264Options2 {
-    // Supported range of encoded bitrate in kbps.
+    /// Supported range of encoded bitrate in kbps.
     #[yaserde(prefix = "tt", rename = "BitrateRange")]
     pub bitrate_range: IntRange,
 
-    // List of supported image sizes.
+    /// List of supported image sizes.
     #[yaserde(prefix = "tt", rename = "ResolutionsAvailable")]
     pub resolutions_available: Vec<VideoResolution>,
 
-    // Supported group of Video frames length. This value typically corresponds
-    // to the I-Frame distance.
+    /// Supported group of Video frames length. This value typically corresponds
+    /// to the I-Frame distance.
     #[yaserde(prefix = "tt", rename = "GovLengthRange")]
     pub gov_length_range: IntRange,
 
-    // Supported frame rate in fps (frames per second).
+    /// Supported frame rate in fps (frames per second).
     #[yaserde(prefix = "tt", rename = "FrameRateRange")]
     pub frame_rate_range: IntRange,
 
-    // Supported encoding interval range. The encoding interval corresponds to
-    // the number of frames devided by the encoded frames. An encoding interval
-    // value of "1" means that all frames are encoded.
+    /// Supported encoding interval range. The encoding interval corresponds to
+    /// the number of frames devided by the encoded frames. An encoding interval
+    /// value of "1" means that all frames are encoded.
     #[yaserde(prefix = "tt", rename = "EncodingIntervalRange")]
     pub encoding_interval_range: IntRange,
 
-    // List of supported H.264 profiles.
+    /// List of supported H.264 profiles.
     #[yaserde(prefix = "tt", rename = "H264ProfilesSupported")]
     pub h264_profiles_supported: Vec<H264Profile>,
 }
 
 impl Validate for H264Options2 {}
 
-// Video Media Subtypes as referenced by IANA (without the leading "video/"
-// Video Media Type). See also
+/// Video Media Subtypes as referenced by IANA (without the leading "video/"
+/// Video Media Type). See also
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum VideoEncodingMimeNames {
     #[yaserde(rename = "JPEG")]
     Jpeg,
@@ -1082,6 +1096,7 @@ impl Validate for VideoEncodingMimeNames {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum VideoEncodingProfiles {
     Simple,
     AdvancedSimple,
@@ -1104,58 +1119,58 @@ impl Validate for VideoEncodingProfiles {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct VideoEncoder2Configuration {
-    // Video Media Subtype for the video format. For definitions see
-    // tt:VideoEncodingMimeNames and
+    /// Video Media Subtype for the video format. For definitions see
+    /// tt:VideoEncodingMimeNames and
     #[yaserde(prefix = "tt", rename = "Encoding")]
     pub encoding: String,
 
-    // Configured video resolution
+    /// Configured video resolution
     #[yaserde(prefix = "tt", rename = "Resolution")]
     pub resolution: VideoResolution2,
 
-    // Optional element to configure rate control related parameters.
+    /// Optional element to configure rate control related parameters.
     #[yaserde(prefix = "tt", rename = "RateControl")]
     pub rate_control: Option<VideoRateControl2>,
 
-    // Defines the multicast settings that could be used for video streaming.
+    /// Defines the multicast settings that could be used for video streaming.
     #[yaserde(prefix = "tt", rename = "Multicast")]
     pub multicast: Option<MulticastConfiguration>,
 
-    // Relative value for the video quantizers and the quality of the video. A
-    // high value within supported quality range means higher quality
+    /// Relative value for the video quantizers and the quality of the video. A
+    /// high value within supported quality range means higher quality
     #[yaserde(prefix = "tt", rename = "Quality")]
     pub quality: f64,
 
-    // Group of Video frames length. Determines typically the interval in which
-    // the I-Frames will be coded. An entry of 1 indicates I-Frames are
-    // continuously generated. An entry of 2 indicates that every 2nd image is
-    // an I-Frame, and 3 only every 3rd frame, etc. The frames in between are
-    // coded as P or B Frames.
+    /// Group of Video frames length. Determines typically the interval in which
+    /// the I-Frames will be coded. An entry of 1 indicates I-Frames are
+    /// continuously generated. An entry of 2 indicates that every 2nd image is
+    /// an I-Frame, and 3 only every 3rd frame, etc. The frames in between are
+    /// coded as P or B Frames.
     #[yaserde(attribute, rename = "GovLength")]
     pub gov_length: Option<i32>,
 
-    // The encoder profile as defined in tt:VideoEncodingProfiles.
+    /// The encoder profile as defined in tt:VideoEncodingProfiles.
     #[yaserde(attribute, rename = "Profile")]
     pub profile: Option<String>,
 
-    // A value of true indicates that frame rate is a fixed value rather than an
-    // upper limit,
-    // and that the video encoder shall prioritize frame rate over all other
-    // adaptable
-    // configuration values such as bitrate. Default is false.
+    /// A value of true indicates that frame rate is a fixed value rather than an
+    /// upper limit,
+    /// and that the video encoder shall prioritize frame rate over all other
+    /// adaptable
+    /// configuration values such as bitrate. Default is false.
     #[yaserde(attribute, rename = "GuaranteedFrameRate")]
     pub guaranteed_frame_rate: Option<bool>,
 
-    // User readable name. Length up to 64 characters.
+    /// User readable name. Length up to 64 characters.
     #[yaserde(prefix = "tt", rename = "Name")]
     pub name: Name,
 
-    // Number of internal references currently using this configuration.
+    /// Number of internal references currently using this configuration.
     #[yaserde(prefix = "tt", rename = "UseCount")]
     pub use_count: i32,
 
-    // Token that uniquely references this configuration. Length up to 64
-    // characters.
+    /// Token that uniquely references this configuration. Length up to 64
+    /// characters.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
@@ -1165,11 +1180,11 @@ impl Validate for VideoEncoder2Configuration {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct VideoResolution2 {
-    // Number of the columns of the Video image.
+    /// Number of the columns of the Video image.
     #[yaserde(prefix = "tt", rename = "Width")]
     pub width: i32,
 
-    // Number of the lines of the Video image.
+    /// Number of the lines of the Video image.
     #[yaserde(prefix = "tt", rename = "Height")]
     pub height: i32,
 }
@@ -1179,16 +1194,16 @@ impl Validate for VideoResolution2 {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct VideoRateControl2 {
-    // Desired frame rate in fps. The actual rate may be lower due to e.g.
-    // performance limitations.
+    /// Desired frame rate in fps. The actual rate may be lower due to e.g.
+    /// performance limitations.
     #[yaserde(prefix = "tt", rename = "FrameRateLimit")]
     pub frame_rate_limit: f64,
 
-    // the maximum output bitrate in kbps
+    /// the maximum output bitrate in kbps
     #[yaserde(prefix = "tt", rename = "BitrateLimit")]
     pub bitrate_limit: i32,
 
-    // Enforce constant bitrate.
+    /// Enforce constant bitrate.
     #[yaserde(attribute, rename = "ConstantBitRate")]
     pub constant_bit_rate: Option<bool>,
 }
@@ -1198,45 +1213,45 @@ impl Validate for VideoRateControl2 {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct VideoEncoder2ConfigurationOptions {
-    // Video Media Subtype for the video format. For definitions see
-    // tt:VideoEncodingMimeNames and
+    /// Video Media Subtype for the video format. For definitions see
+    /// tt:VideoEncodingMimeNames and
     #[yaserde(prefix = "tt", rename = "Encoding")]
     pub encoding: String,
 
-    // Range of the quality values. A high value means higher quality.
+    /// Range of the quality values. A high value means higher quality.
     #[yaserde(prefix = "tt", rename = "QualityRange")]
     pub quality_range: FloatRange,
 
-    // List of supported image sizes.
+    /// List of supported image sizes.
     #[yaserde(prefix = "tt", rename = "ResolutionsAvailable")]
     pub resolutions_available: Vec<VideoResolution2>,
 
-    // Supported range of encoded bitrate in kbps.
+    /// Supported range of encoded bitrate in kbps.
     #[yaserde(prefix = "tt", rename = "BitrateRange")]
     pub bitrate_range: IntRange,
 
-    // Exactly two values, which define the Lower and Upper bounds for the
-    // supported group of Video frames length. These values typically correspond
-    // to the I-Frame distance.
+    /// Exactly two values, which define the Lower and Upper bounds for the
+    /// supported group of Video frames length. These values typically correspond
+    /// to the I-Frame distance.
     #[yaserde(attribute, rename = "GovLengthRange")]
     pub gov_length_range: Option<IntAttrList>,
 
-    // List of supported target frame rates in fps (frames per second). The list
-    // shall be sorted with highest values first.
+    /// List of supported target frame rates in fps (frames per second). The list
+    /// shall be sorted with highest values first.
     #[yaserde(attribute, rename = "FrameRatesSupported")]
     pub frame_rates_supported: Option<FloatAttrList>,
 
-    // List of supported encoder profiles as defined in
-    // tt::VideoEncodingProfiles.
+    /// List of supported encoder profiles as defined in
+    /// tt::VideoEncodingProfiles.
     #[yaserde(attribute, rename = "ProfilesSupported")]
     pub profiles_supported: Option<StringAttrList>,
 
-    // Signal whether enforcing constant bitrate is supported.
+    /// Signal whether enforcing constant bitrate is supported.
     #[yaserde(attribute, rename = "ConstantBitRateSupported")]
     pub constant_bit_rate_supported: Option<bool>,
 
-    // Indicates the support for the GuaranteedFrameRate attribute on the
-    // VideoEncoder2Configuration element.
+    /// Indicates the support for the GuaranteedFrameRate attribute on the
+    /// VideoEncoder2Configuration element.
     #[yaserde(attribute, rename = "GuaranteedFrameRateSupported")]
     pub guaranteed_frame_rate_supported: Option<bool>,
 }
@@ -1246,20 +1261,20 @@ impl Validate for VideoEncoder2ConfigurationOptions {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct AudioSourceConfiguration {
-    // Token of the Audio Source the configuration applies to
+    /// Token of the Audio Source the configuration applies to
     #[yaserde(prefix = "tt", rename = "SourceToken")]
     pub source_token: ReferenceToken,
 
-    // User readable name. Length up to 64 characters.
+    /// User readable name. Length up to 64 characters.
     #[yaserde(prefix = "tt", rename = "Name")]
     pub name: Name,
 
-    // Number of internal references currently using this configuration.
+    /// Number of internal references currently using this configuration.
     #[yaserde(prefix = "tt", rename = "UseCount")]
     pub use_count: i32,
 
-    // Token that uniquely references this configuration. Length up to 64
-    // characters.
+    /// Token that uniquely references this configuration. Length up to 64
+    /// characters.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
@@ -1269,7 +1284,7 @@ impl Validate for AudioSourceConfiguration {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct AudioSourceConfigurationOptions {
-    // Tokens of the audio source the configuration can be used for.
+    /// Tokens of the audio source the configuration can be used for.
     #[yaserde(prefix = "tt", rename = "InputTokensAvailable")]
     pub input_tokens_available: Vec<ReferenceToken>,
 
@@ -1288,37 +1303,37 @@ impl Validate for AudioSourceOptionsExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct AudioEncoderConfiguration {
-    // Audio codec used for encoding the audio input (either G.711, G.726 or
-    // AAC)
+    /// Audio codec used for encoding the audio input (either G.711, G.726 or
+    /// AAC)
     #[yaserde(prefix = "tt", rename = "Encoding")]
     pub encoding: AudioEncoding,
 
-    // The output bitrate in kbps.
+    /// The output bitrate in kbps.
     #[yaserde(prefix = "tt", rename = "Bitrate")]
     pub bitrate: i32,
 
-    // The output sample rate in kHz.
+    /// The output sample rate in kHz.
     #[yaserde(prefix = "tt", rename = "SampleRate")]
     pub sample_rate: i32,
 
-    // Defines the multicast settings that could be used for video streaming.
+    /// Defines the multicast settings that could be used for video streaming.
     #[yaserde(prefix = "tt", rename = "Multicast")]
     pub multicast: MulticastConfiguration,
 
-    // The rtsp session timeout for the related audio stream
+    /// The rtsp session timeout for the related audio stream
     #[yaserde(prefix = "tt", rename = "SessionTimeout")]
     pub session_timeout: xs::Duration,
 
-    // User readable name. Length up to 64 characters.
+    /// User readable name. Length up to 64 characters.
     #[yaserde(prefix = "tt", rename = "Name")]
     pub name: Name,
 
-    // Number of internal references currently using this configuration.
+    /// Number of internal references currently using this configuration.
     #[yaserde(prefix = "tt", rename = "UseCount")]
     pub use_count: i32,
 
-    // Token that uniquely references this configuration. Length up to 64
-    // characters.
+    /// Token that uniquely references this configuration. Length up to 64
+    /// characters.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
@@ -1327,6 +1342,7 @@ impl Validate for AudioEncoderConfiguration {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum AudioEncoding {
     G711,
     G726,
@@ -1346,7 +1362,7 @@ impl Validate for AudioEncoding {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct AudioEncoderConfigurationOptions {
-    // list of supported AudioEncoderConfigurations
+    /// list of supported AudioEncoderConfigurations
     #[yaserde(prefix = "tt", rename = "Options")]
     pub options: Vec<AudioEncoderConfigurationOption>,
 }
@@ -1356,25 +1372,26 @@ impl Validate for AudioEncoderConfigurationOptions {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct AudioEncoderConfigurationOption {
-    // The enoding used for audio data (either G.711, G.726 or AAC)
+    /// The enoding used for audio data (either G.711, G.726 or AAC)
     #[yaserde(prefix = "tt", rename = "Encoding")]
     pub encoding: AudioEncoding,
 
-    // List of supported bitrates in kbps for the specified Encoding
+    /// List of supported bitrates in kbps for the specified Encoding
     #[yaserde(prefix = "tt", rename = "BitrateList")]
     pub bitrate_list: IntList,
 
-    // List of supported Sample Rates in kHz for the specified Encoding
+    /// List of supported Sample Rates in kHz for the specified Encoding
     #[yaserde(prefix = "tt", rename = "SampleRateList")]
     pub sample_rate_list: IntList,
 }
 
 impl Validate for AudioEncoderConfigurationOption {}
 
-// Audio Media Subtypes as referenced by IANA (without the leading "audio/"
-// Audio Media Type). See also
+/// Audio Media Subtypes as referenced by IANA (without the leading "audio/"
+/// Audio Media Type). See also
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum AudioEncodingMimeNames {
     #[yaserde(rename = "PCMU")]
     Pcmu,
@@ -1397,33 +1414,33 @@ impl Validate for AudioEncodingMimeNames {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct AudioEncoder2Configuration {
-    // Audio Media Subtype for the audio format. For definitions see
-    // tt:AudioEncodingMimeNames and
+    /// Audio Media Subtype for the audio format. For definitions see
+    /// tt:AudioEncodingMimeNames and
     #[yaserde(prefix = "tt", rename = "Encoding")]
     pub encoding: String,
 
-    // Optional multicast configuration of the audio stream.
+    /// Optional multicast configuration of the audio stream.
     #[yaserde(prefix = "tt", rename = "Multicast")]
     pub multicast: Option<MulticastConfiguration>,
 
-    // The output bitrate in kbps.
+    /// The output bitrate in kbps.
     #[yaserde(prefix = "tt", rename = "Bitrate")]
     pub bitrate: i32,
 
-    // The output sample rate in kHz.
+    /// The output sample rate in kHz.
     #[yaserde(prefix = "tt", rename = "SampleRate")]
     pub sample_rate: i32,
 
-    // User readable name. Length up to 64 characters.
+    /// User readable name. Length up to 64 characters.
     #[yaserde(prefix = "tt", rename = "Name")]
     pub name: Name,
 
-    // Number of internal references currently using this configuration.
+    /// Number of internal references currently using this configuration.
     #[yaserde(prefix = "tt", rename = "UseCount")]
     pub use_count: i32,
 
-    // Token that uniquely references this configuration. Length up to 64
-    // characters.
+    /// Token that uniquely references this configuration. Length up to 64
+    /// characters.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
@@ -1433,16 +1450,16 @@ impl Validate for AudioEncoder2Configuration {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct AudioEncoder2ConfigurationOptions {
-    // Audio Media Subtype for the audio format. For definitions see
-    // tt:AudioEncodingMimeNames and
+    /// Audio Media Subtype for the audio format. For definitions see
+    /// tt:AudioEncodingMimeNames and
     #[yaserde(prefix = "tt", rename = "Encoding")]
     pub encoding: String,
 
-    // List of supported bitrates in kbps for the specified Encoding
+    /// List of supported bitrates in kbps for the specified Encoding
     #[yaserde(prefix = "tt", rename = "BitrateList")]
     pub bitrate_list: IntList,
 
-    // List of supported Sample Rates in kHz for the specified Encoding
+    /// List of supported Sample Rates in kHz for the specified Encoding
     #[yaserde(prefix = "tt", rename = "SampleRateList")]
     pub sample_rate_list: IntList,
 }
@@ -1458,16 +1475,16 @@ pub struct VideoAnalyticsConfiguration {
     #[yaserde(prefix = "tt", rename = "RuleEngineConfiguration")]
     pub rule_engine_configuration: RuleEngineConfiguration,
 
-    // User readable name. Length up to 64 characters.
+    /// User readable name. Length up to 64 characters.
     #[yaserde(prefix = "tt", rename = "Name")]
     pub name: Name,
 
-    // Number of internal references currently using this configuration.
+    /// Number of internal references currently using this configuration.
     #[yaserde(prefix = "tt", rename = "UseCount")]
     pub use_count: i32,
 
-    // Token that uniquely references this configuration. Length up to 64
-    // characters.
+    /// Token that uniquely references this configuration. Length up to 64
+    /// characters.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
@@ -1477,28 +1494,28 @@ impl Validate for VideoAnalyticsConfiguration {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct MetadataConfiguration {
-    // optional element to configure which PTZ related data is to include in the
-    // metadata stream
+    /// optional element to configure which PTZ related data is to include in the
+    /// metadata stream
     #[yaserde(prefix = "tt", rename = "PTZStatus")]
     pub ptz_status: Option<Ptzfilter>,
 
-    // Optional element to configure the streaming of events. A client might be
-    // interested in receiving all,
-    // none or some of the events produced by the device:
+    /// Optional element to configure the streaming of events. A client might be
+    /// interested in receiving all,
+    /// none or some of the events produced by the device:
     #[yaserde(prefix = "tt", rename = "Events")]
     pub events: Option<EventSubscription>,
 
-    // Defines whether the streamed metadata will include metadata from the
-    // analytics engines (video, cell motion, audio etc.)
+    /// Defines whether the streamed metadata will include metadata from the
+    /// analytics engines (video, cell motion, audio etc.)
     #[yaserde(prefix = "tt", rename = "Analytics")]
     pub analytics: Option<bool>,
 
-    // Defines the multicast settings that could be used for video streaming.
+    /// Defines the multicast settings that could be used for video streaming.
     #[yaserde(prefix = "tt", rename = "Multicast")]
     pub multicast: MulticastConfiguration,
 
-    // The rtsp session timeout for the related audio stream (when using Media2
-    // Service, this value is deprecated and ignored)
+    /// The rtsp session timeout for the related audio stream (when using Media2
+    /// Service, this value is deprecated and ignored)
     #[yaserde(prefix = "tt", rename = "SessionTimeout")]
     pub session_timeout: xs::Duration,
 
@@ -1508,26 +1525,26 @@ pub struct MetadataConfiguration {
     #[yaserde(prefix = "tt", rename = "Extension")]
     pub extension: Option<MetadataConfigurationExtension>,
 
-    // Optional parameter to configure compression type of Metadata payload. Use
-    // values from enumeration MetadataCompressionType.
+    /// Optional parameter to configure compression type of Metadata payload. Use
+    /// values from enumeration MetadataCompressionType.
     #[yaserde(attribute, rename = "CompressionType")]
     pub compression_type: Option<String>,
 
-    // Optional parameter to configure if the metadata stream shall contain the
-    // Geo Location coordinates of each target.
+    /// Optional parameter to configure if the metadata stream shall contain the
+    /// Geo Location coordinates of each target.
     #[yaserde(attribute, rename = "GeoLocation")]
     pub geo_location: Option<bool>,
 
-    // User readable name. Length up to 64 characters.
+    /// User readable name. Length up to 64 characters.
     #[yaserde(prefix = "tt", rename = "Name")]
     pub name: Name,
 
-    // Number of internal references currently using this configuration.
+    /// Number of internal references currently using this configuration.
     #[yaserde(prefix = "tt", rename = "UseCount")]
     pub use_count: i32,
 
-    // Token that uniquely references this configuration. Length up to 64
-    // characters.
+    /// Token that uniquely references this configuration. Length up to 64
+    /// characters.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
@@ -1543,19 +1560,19 @@ impl Validate for MetadataConfigurationExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Ptzfilter {
-    // True if the metadata stream shall contain the PTZ status (IDLE, MOVING or
-    // UNKNOWN)
+    /// True if the metadata stream shall contain the PTZ status (IDLE, MOVING or
+    /// UNKNOWN)
     #[yaserde(prefix = "tt", rename = "Status")]
     pub status: bool,
 
-    // True if the metadata stream shall contain the PTZ position
+    /// True if the metadata stream shall contain the PTZ position
     #[yaserde(prefix = "tt", rename = "Position")]
     pub position: bool,
 }
 
 impl Validate for Ptzfilter {}
 
-// Subcription handling in the same way as base notification subscription.
+/// Subcription handling in the same way as base notification subscription.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct EventSubscription {
@@ -1587,8 +1604,8 @@ pub struct MetadataConfigurationOptions {
     #[yaserde(prefix = "tt", rename = "Extension")]
     pub extension: Option<MetadataConfigurationOptionsExtension>,
 
-    // True if the device is able to stream the Geo Located positions of each
-    // target.
+    /// True if the device is able to stream the Geo Located positions of each
+    /// target.
     #[yaserde(attribute, rename = "GeoLocation")]
     pub geo_location: Option<bool>,
 }
@@ -1598,14 +1615,15 @@ impl Validate for MetadataConfigurationOptions {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct MetadataConfigurationOptionsExtension {
-    // List of supported metadata compression type. Its options shall be chosen
-    // from tt:MetadataCompressionType.
+    /// List of supported metadata compression type. Its options shall be chosen
+    /// from tt:MetadataCompressionType.
     #[yaserde(prefix = "tt", rename = "CompressionType")]
     pub compression_type: Vec<String>,
-    // `Extension` inside `Extension` causes infinite loop at deserialization
-    // https://github.com/media-io/yaserde/issues/76
-    // #[yaserde(prefix = "tt", rename = "Extension")]
-    // pub extension: Option<MetadataConfigurationOptionsExtension2>,
+    /// `Extension` inside `Extension` causes infinite loop at deserialization
+    /// https://github.com/media-io/yaserde/issues/76
+    /// so it's kept as raw XML.
+    #[yaserde(prefix = "tt", rename = "Extension")]
+    pub extension: Option<RawXml>,
 }
 
 impl Validate for MetadataConfigurationOptionsExtension {}
@@ -1618,6 +1636,7 @@ impl Validate for MetadataConfigurationOptionsExtension {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum MetadataCompressionType {
     None,
     #[yaserde(rename = "GZIP")]
@@ -1638,19 +1657,19 @@ impl Validate for MetadataCompressionType {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct PtzstatusFilterOptions {
-    // True if the device is able to stream pan or tilt status information.
+    /// True if the device is able to stream pan or tilt status information.
     #[yaserde(prefix = "tt", rename = "PanTiltStatusSupported")]
     pub pan_tilt_status_supported: bool,
 
-    // True if the device is able to stream zoom status inforamtion.
+    /// True if the device is able to stream zoom status inforamtion.
     #[yaserde(prefix = "tt", rename = "ZoomStatusSupported")]
     pub zoom_status_supported: bool,
 
-    // True if the device is able to stream the pan or tilt position.
+    /// True if the device is able to stream the pan or tilt position.
     #[yaserde(prefix = "tt", rename = "PanTiltPositionSupported")]
     pub pan_tilt_position_supported: Option<bool>,
 
-    // True if the device is able to stream zoom position information.
+    /// True if the device is able to stream zoom position information.
     #[yaserde(prefix = "tt", rename = "ZoomPositionSupported")]
     pub zoom_position_supported: Option<bool>,
 
@@ -1672,23 +1691,23 @@ pub struct VideoOutput {
     #[yaserde(prefix = "tt", rename = "Layout")]
     pub layout: Layout,
 
-    // Resolution of the display in Pixel.
+    /// Resolution of the display in Pixel.
     #[yaserde(prefix = "tt", rename = "Resolution")]
     pub resolution: Option<VideoResolution>,
 
-    // Refresh rate of the display in Hertz.
+    /// Refresh rate of the display in Hertz.
     #[yaserde(prefix = "tt", rename = "RefreshRate")]
     pub refresh_rate: Option<f64>,
 
-    // Aspect ratio of the display as physical extent of width divided by
-    // height.
+    /// Aspect ratio of the display as physical extent of width divided by
+    /// height.
     #[yaserde(prefix = "tt", rename = "AspectRatio")]
     pub aspect_ratio: Option<f64>,
 
     #[yaserde(prefix = "tt", rename = "Extension")]
     pub extension: Option<VideoOutputExtension>,
 
-    // Unique identifier referencing the physical entity.
+    /// Unique identifier referencing the physical entity.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
@@ -1704,20 +1723,20 @@ impl Validate for VideoOutputExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct VideoOutputConfiguration {
-    // Token of the Video Output the configuration applies to
+    /// Token of the Video Output the configuration applies to
     #[yaserde(prefix = "tt", rename = "OutputToken")]
     pub output_token: ReferenceToken,
 
-    // User readable name. Length up to 64 characters.
+    /// User readable name. Length up to 64 characters.
     #[yaserde(prefix = "tt", rename = "Name")]
     pub name: Name,
 
-    // Number of internal references currently using this configuration.
+    /// Number of internal references currently using this configuration.
     #[yaserde(prefix = "tt", rename = "UseCount")]
     pub use_count: i32,
 
-    // Token that uniquely references this configuration. Length up to 64
-    // characters.
+    /// Token that uniquely references this configuration. Length up to 64
+    /// characters.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
@@ -1733,18 +1752,18 @@ impl Validate for VideoOutputConfigurationOptions {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct VideoDecoderConfigurationOptions {
-    // If the device is able to decode Jpeg streams this element describes the
-    // supported codecs and configurations
+    /// If the device is able to decode Jpeg streams this element describes the
+    /// supported codecs and configurations
     #[yaserde(prefix = "tt", rename = "JpegDecOptions")]
     pub jpeg_dec_options: Option<JpegDecOptions>,
 
-    // If the device is able to decode H.264 streams this element describes the
-    // supported codecs and configurations
+    /// If the device is able to decode H.264 streams this element describes the
+    /// supported codecs and configurations
     #[yaserde(prefix = "tt", rename = "H264DecOptions")]
     pub h264_dec_options: Option<H264DecOptions>,
 
-    // If the device is able to decode Mpeg4 streams this element describes the
-    // supported codecs and configurations
+    /// If the device is able to decode Mpeg4 streams this element describes the
+    /// supported codecs and configurations
     #[yaserde(prefix = "tt", rename = "Mpeg4DecOptions")]
     pub mpeg_4_dec_options: Option<Mpeg4DecOptions>,
 
@@ -1757,19 +1776,19 @@ impl Validate for VideoDecoderConfigurationOptions {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct H264DecOptions {
-    // List of supported H.264 Video Resolutions
+    /// List of supported H.264 Video Resolutions
     #[yaserde(prefix = "tt", rename = "ResolutionsAvailable")]
     pub resolutions_available: Vec<VideoResolution>,
 
-    // List of supported H264 Profiles (either baseline, main, extended or high)
+    /// List of supported H264 Profiles (either baseline, main, extended or high)
     #[yaserde(prefix = "tt", rename = "SupportedH264Profiles")]
     pub supported_h264_profiles: Vec<H264Profile>,
 
-    // Supported H.264 bitrate range in kbps
+    /// Supported H.264 bitrate range in kbps
     #[yaserde(prefix = "tt", rename = "SupportedInputBitrate")]
     pub supported_input_bitrate: IntRange,
 
-    // Supported H.264 framerate range in fps
+    /// Supported H.264 framerate range in fps
     #[yaserde(prefix = "tt", rename = "SupportedFrameRate")]
     pub supported_frame_rate: IntRange,
 }
@@ -1779,15 +1798,15 @@ impl Validate for H264DecOptions {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct JpegDecOptions {
-    // List of supported Jpeg Video Resolutions
+    /// List of supported Jpeg Video Resolutions
     #[yaserde(prefix = "tt", rename = "ResolutionsAvailable")]
     pub resolutions_available: Vec<VideoResolution>,
 
-    // Supported Jpeg bitrate range in kbps
+    /// Supported Jpeg bitrate range in kbps
     #[yaserde(prefix = "tt", rename = "SupportedInputBitrate")]
     pub supported_input_bitrate: IntRange,
 
-    // Supported Jpeg framerate range in fps
+    /// Supported Jpeg framerate range in fps
     #[yaserde(prefix = "tt", rename = "SupportedFrameRate")]
     pub supported_frame_rate: IntRange,
 }
@@ -1797,19 +1816,19 @@ impl Validate for JpegDecOptions {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Mpeg4DecOptions {
-    // List of supported Mpeg4 Video Resolutions
+    /// List of supported Mpeg4 Video Resolutions
     #[yaserde(prefix = "tt", rename = "ResolutionsAvailable")]
     pub resolutions_available: Vec<VideoResolution>,
 
-    // List of supported Mpeg4 Profiles (either SP or ASP)
+    /// List of supported Mpeg4 Profiles (either SP or ASP)
     #[yaserde(prefix = "tt", rename = "SupportedMpeg4Profiles")]
     pub supported_mpeg_4_profiles: Vec<Mpeg4Profile>,
 
-    // Supported Mpeg4 bitrate range in kbps
+    /// Supported Mpeg4 bitrate range in kbps
     #[yaserde(prefix = "tt", rename = "SupportedInputBitrate")]
     pub supported_input_bitrate: IntRange,
 
-    // Supported Mpeg4 framerate range in fps
+    /// Supported Mpeg4 framerate range in fps
     #[yaserde(prefix = "tt", rename = "SupportedFrameRate")]
     pub supported_frame_rate: IntRange,
 }
@@ -1825,7 +1844,7 @@ impl Validate for VideoDecoderConfigurationOptionsExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct AudioOutput {
-    // Unique identifier referencing the physical entity.
+    /// Unique identifier referencing the physical entity.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
@@ -1835,38 +1854,38 @@ impl Validate for AudioOutput {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct AudioOutputConfiguration {
-    // Token of the phsycial Audio output.
+    /// Token of the phsycial Audio output.
     #[yaserde(prefix = "tt", rename = "OutputToken")]
     pub output_token: ReferenceToken,
 
-    // An audio channel MAY support different types of audio transmission. While
-    // for full duplex
-    // operation no special handling is required, in half duplex operation the
-    // transmission direction
-    // needs to be switched.
-    // The optional SendPrimacy parameter inside the AudioOutputConfiguration
-    // indicates which
-    // direction is currently active. An NVC can switch between different modes
-    // by setting the
-    // AudioOutputConfiguration.
+    /// An audio channel MAY support different types of audio transmission. While
+    /// for full duplex
+    /// operation no special handling is required, in half duplex operation the
+    /// transmission direction
+    /// needs to be switched.
+    /// The optional SendPrimacy parameter inside the AudioOutputConfiguration
+    /// indicates which
+    /// direction is currently active. An NVC can switch between different modes
+    /// by setting the
+    /// AudioOutputConfiguration.
     #[yaserde(prefix = "tt", rename = "SendPrimacy")]
     pub send_primacy: Option<String>,
 
-    // Volume setting of the output. The applicable range is defined via the
-    // option AudioOutputOptions.OutputLevelRange.
+    /// Volume setting of the output. The applicable range is defined via the
+    /// option AudioOutputOptions.OutputLevelRange.
     #[yaserde(prefix = "tt", rename = "OutputLevel")]
     pub output_level: i32,
 
-    // User readable name. Length up to 64 characters.
+    /// User readable name. Length up to 64 characters.
     #[yaserde(prefix = "tt", rename = "Name")]
     pub name: Name,
 
-    // Number of internal references currently using this configuration.
+    /// Number of internal references currently using this configuration.
     #[yaserde(prefix = "tt", rename = "UseCount")]
     pub use_count: i32,
 
-    // Token that uniquely references this configuration. Length up to 64
-    // characters.
+    /// Token that uniquely references this configuration. Length up to 64
+    /// characters.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
@@ -1876,15 +1895,15 @@ impl Validate for AudioOutputConfiguration {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct AudioOutputConfigurationOptions {
-    // Tokens of the physical Audio outputs (typically one).
+    /// Tokens of the physical Audio outputs (typically one).
     #[yaserde(prefix = "tt", rename = "OutputTokensAvailable")]
     pub output_tokens_available: Vec<ReferenceToken>,
 
-    // An
+    /// An
     #[yaserde(prefix = "tt", rename = "SendPrimacyOptions")]
     pub send_primacy_options: Vec<String>,
 
-    // Minimum and maximum level range supported for this Output.
+    /// Minimum and maximum level range supported for this Output.
     #[yaserde(prefix = "tt", rename = "OutputLevelRange")]
     pub output_level_range: IntRange,
 }
@@ -1894,16 +1913,16 @@ impl Validate for AudioOutputConfigurationOptions {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct AudioDecoderConfiguration {
-    // User readable name. Length up to 64 characters.
+    /// User readable name. Length up to 64 characters.
     #[yaserde(prefix = "tt", rename = "Name")]
     pub name: Name,
 
-    // Number of internal references currently using this configuration.
+    /// Number of internal references currently using this configuration.
     #[yaserde(prefix = "tt", rename = "UseCount")]
     pub use_count: i32,
 
-    // Token that uniquely references this configuration. Length up to 64
-    // characters.
+    /// Token that uniquely references this configuration. Length up to 64
+    /// characters.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
@@ -1913,18 +1932,18 @@ impl Validate for AudioDecoderConfiguration {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct AudioDecoderConfigurationOptions {
-    // If the device is able to decode AAC encoded audio this section describes
-    // the supported configurations
+    /// If the device is able to decode AAC encoded audio this section describes
+    /// the supported configurations
     #[yaserde(prefix = "tt", rename = "AACDecOptions")]
     pub aac_dec_options: Option<AacdecOptions>,
 
-    // If the device is able to decode G711 encoded audio this section describes
-    // the supported configurations
+    /// If the device is able to decode G711 encoded audio this section describes
+    /// the supported configurations
     #[yaserde(prefix = "tt", rename = "G711DecOptions")]
     pub g711_dec_options: Option<G711DecOptions>,
 
-    // If the device is able to decode G726 encoded audio this section describes
-    // the supported configurations
+    /// If the device is able to decode G726 encoded audio this section describes
+    /// the supported configurations
     #[yaserde(prefix = "tt", rename = "G726DecOptions")]
     pub g726_dec_options: Option<G726DecOptions>,
 
@@ -1937,11 +1956,11 @@ impl Validate for AudioDecoderConfigurationOptions {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct G711DecOptions {
-    // List of supported bitrates in kbps
+    /// List of supported bitrates in kbps
     #[yaserde(prefix = "tt", rename = "Bitrate")]
     pub bitrate: IntList,
 
-    // List of supported sample rates in kHz
+    /// List of supported sample rates in kHz
     #[yaserde(prefix = "tt", rename = "SampleRateRange")]
     pub sample_rate_range: IntList,
 }
@@ -1951,11 +1970,11 @@ impl Validate for G711DecOptions {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct AacdecOptions {
-    // List of supported bitrates in kbps
+    /// List of supported bitrates in kbps
     #[yaserde(prefix = "tt", rename = "Bitrate")]
     pub bitrate: IntList,
 
-    // List of supported sample rates in kHz
+    /// List of supported sample rates in kHz
     #[yaserde(prefix = "tt", rename = "SampleRateRange")]
     pub sample_rate_range: IntList,
 }
@@ -1965,11 +1984,11 @@ impl Validate for AacdecOptions {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct G726DecOptions {
-    // List of supported bitrates in kbps
+    /// List of supported bitrates in kbps
     #[yaserde(prefix = "tt", rename = "Bitrate")]
     pub bitrate: IntList,
 
-    // List of supported sample rates in kHz
+    /// List of supported sample rates in kHz
     #[yaserde(prefix = "tt", rename = "SampleRateRange")]
     pub sample_rate_range: IntList,
 }
@@ -1985,28 +2004,28 @@ impl Validate for AudioDecoderConfigurationOptionsExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct MulticastConfiguration {
-    // The multicast address (if this address is set to 0 no multicast streaming
-    // is enaled)
+    /// The multicast address (if this address is set to 0 no multicast streaming
+    /// is enaled)
     #[yaserde(prefix = "tt", rename = "Address")]
     pub address: Ipaddress,
 
-    // The RTP mutlicast destination port. A device may support RTCP. In this
-    // case the port value shall be even to allow the corresponding RTCP stream
-    // to be mapped to the next higher (odd) destination port number as defined
-    // in the RTSP specification.
+    /// The RTP mutlicast destination port. A device may support RTCP. In this
+    /// case the port value shall be even to allow the corresponding RTCP stream
+    /// to be mapped to the next higher (odd) destination port number as defined
+    /// in the RTSP specification.
     #[yaserde(prefix = "tt", rename = "Port")]
     pub port: i32,
 
-    // In case of IPv6 the TTL value is assumed as the hop limit. Note that for
-    // IPV6 and administratively scoped IPv4 multicast the primary use for hop
-    // limit / TTL is to prevent packets from (endlessly) circulating and not
-    // limiting scope. In these cases the address contains the scope.
+    /// In case of IPv6 the TTL value is assumed as the hop limit. Note that for
+    /// IPV6 and administratively scoped IPv4 multicast the primary use for hop
+    /// limit / TTL is to prevent packets from (endlessly) circulating and not
+    /// limiting scope. In these cases the address contains the scope.
     #[yaserde(prefix = "tt", rename = "TTL")]
     pub ttl: i32,
 
-    // Read only property signalling that streaming is persistant. Use the
-    // methods StartMulticastStreaming and StopMulticastStreaming to switch its
-    // state.
+    /// Read only property signalling that streaming is persistant. Use the
+    /// methods StartMulticastStreaming and StopMulticastStreaming to switch its
+    /// state.
     #[yaserde(prefix = "tt", rename = "AutoStart")]
     pub auto_start: bool,
 }
@@ -2016,7 +2035,7 @@ impl Validate for MulticastConfiguration {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct StreamSetup {
-    // Defines if a multicast or unicast stream is requested
+    /// Defines if a multicast or unicast stream is requested
     #[yaserde(prefix = "tt", rename = "Stream")]
     pub stream: StreamType,
 
@@ -2028,6 +2047,7 @@ impl Validate for StreamSetup {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum StreamType {
     #[yaserde(rename = "RTP-Unicast")]
     RtpUnicast,
@@ -2047,13 +2067,13 @@ impl Validate for StreamType {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Transport {
-    // Defines the network protocol for streaming, either UDP=RTP/UDP,
-    // RTSP=RTP/RTSP/TCP or HTTP=RTP/RTSP/HTTP/TCP
+    /// Defines the network protocol for streaming, either UDP=RTP/UDP,
+    /// RTSP=RTP/RTSP/TCP or HTTP=RTP/RTSP/HTTP/TCP
     #[yaserde(prefix = "tt", rename = "Protocol")]
     pub protocol: TransportProtocol,
 
-    // Optional element to describe further tunnel options. This element is
-    // normally not needed
+    /// Optional element to describe further tunnel options. This element is
+    /// normally not needed
     #[yaserde(prefix = "tt", rename = "Tunnel")]
     pub tunnel: Vec<Transport>,
 }
@@ -2062,10 +2082,11 @@ impl Validate for Transport {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum TransportProtocol {
     #[yaserde(rename = "UDP")]
     Udp,
-    // This value is deprecated.
+    /// This value is deprecated.
     #[yaserde(rename = "TCP")]
     Tcp,
     #[yaserde(rename = "RTSP")]
@@ -2086,23 +2107,23 @@ impl Validate for TransportProtocol {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct MediaUri {
-    // Stable Uri to be used for requesting the media stream
+    /// Stable Uri to be used for requesting the media stream
     #[yaserde(prefix = "tt", rename = "Uri")]
     pub uri: String,
 
-    // Indicates if the Uri is only valid until the connection is established.
-    // The value shall be set to "false".
+    /// Indicates if the Uri is only valid until the connection is established.
+    /// The value shall be set to "false".
     #[yaserde(prefix = "tt", rename = "InvalidAfterConnect")]
     pub invalid_after_connect: bool,
 
-    // Indicates if the Uri is invalid after a reboot of the device. The value
-    // shall be set to "false".
+    /// Indicates if the Uri is invalid after a reboot of the device. The value
+    /// shall be set to "false".
     #[yaserde(prefix = "tt", rename = "InvalidAfterReboot")]
     pub invalid_after_reboot: bool,
 
-    // Duration how long the Uri is valid. This parameter shall be set to PT0S
-    // to indicate that this stream URI is indefinitely valid even if the
-    // profile changes
+    /// Duration how long the Uri is valid. This parameter shall be set to PT0S
+    /// to indicate that this stream URI is indefinitely valid even if the
+    /// profile changes
     #[yaserde(prefix = "tt", rename = "Timeout")]
     pub timeout: xs::Duration,
 }
@@ -2111,6 +2132,7 @@ impl Validate for MediaUri {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum ScopeDefinition {
     Fixed,
     Configurable,
@@ -2128,11 +2150,11 @@ impl Validate for ScopeDefinition {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Scope {
-    // Indicates if the scope is fixed or configurable.
+    /// Indicates if the scope is fixed or configurable.
     #[yaserde(prefix = "tt", rename = "ScopeDef")]
     pub scope_def: ScopeDefinition,
 
-    // Scope item URI.
+    /// Scope item URI.
     #[yaserde(prefix = "tt", rename = "ScopeItem")]
     pub scope_item: String,
 }
@@ -2141,6 +2163,7 @@ impl Validate for Scope {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum DiscoveryMode {
     Discoverable,
     NonDiscoverable,
@@ -2158,30 +2181,30 @@ impl Validate for DiscoveryMode {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct NetworkInterface {
-    // Indicates whether or not an interface is enabled.
+    /// Indicates whether or not an interface is enabled.
     #[yaserde(prefix = "tt", rename = "Enabled")]
     pub enabled: bool,
 
-    // Network interface information
+    /// Network interface information
     #[yaserde(prefix = "tt", rename = "Info")]
     pub info: Option<NetworkInterfaceInfo>,
 
-    // Link configuration.
+    /// Link configuration.
     #[yaserde(prefix = "tt", rename = "Link")]
     pub link: Option<NetworkInterfaceLink>,
 
-    // IPv4 network interface configuration.
+    /// IPv4 network interface configuration.
     #[yaserde(prefix = "tt", rename = "IPv4")]
     pub i_pv_4: Vec<Ipv4NetworkInterface>,
 
-    // IPv6 network interface configuration.
+    /// IPv6 network interface configuration.
     #[yaserde(prefix = "tt", rename = "IPv6")]
     pub i_pv_6: Vec<Ipv6NetworkInterface>,
 
     #[yaserde(prefix = "tt", rename = "Extension")]
     pub extension: Option<NetworkInterfaceExtension>,
 
-    // Unique identifier referencing the physical entity.
+    /// Unique identifier referencing the physical entity.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
@@ -2194,16 +2217,17 @@ pub struct NetworkInterfaceExtension {
     #[yaserde(prefix = "tt", rename = "InterfaceType")]
     pub interface_type: IanaIfTypes,
 
-    // Extension point prepared for future 802.3 configuration.
+    /// Extension point prepared for future 802.3 configuration.
     #[yaserde(prefix = "tt", rename = "Dot3")]
     pub dot_3: Vec<Dot3Configuration>,
 
     #[yaserde(prefix = "tt", rename = "Dot11")]
     pub dot_11: Vec<Dot11Configuration>,
-    // `Extension` inside `Extension` causes infinite loop at deserialization
-    // https://github.com/media-io/yaserde/issues/76
-    // #[yaserde(prefix = "tt", rename = "Extension")]
-    // pub extension: Option<NetworkInterfaceExtension2>,
+    /// `Extension` inside `Extension` causes infinite loop at deserialization
+    /// https://github.com/media-io/yaserde/issues/76
+    /// so it's kept as raw XML.
+    #[yaserde(prefix = "tt", rename = "Extension")]
+    pub extension: Option<RawXml>,
 }
 
 impl Validate for NetworkInterfaceExtension {}
@@ -2238,15 +2262,15 @@ impl Validate for Dot3Configuration {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct NetworkInterfaceLink {
-    // Configured link settings.
+    /// Configured link settings.
     #[yaserde(prefix = "tt", rename = "AdminSettings")]
     pub admin_settings: NetworkInterfaceConnectionSetting,
 
-    // Current active link settings.
+    /// Current active link settings.
     #[yaserde(prefix = "tt", rename = "OperSettings")]
     pub oper_settings: NetworkInterfaceConnectionSetting,
 
-    // Integer indicating interface type, for example: 6 is ethernet.
+    /// Integer indicating interface type, for example: 6 is ethernet.
     #[yaserde(prefix = "tt", rename = "InterfaceType")]
     pub interface_type: IanaIfTypes,
 }
@@ -2256,15 +2280,15 @@ impl Validate for NetworkInterfaceLink {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct NetworkInterfaceConnectionSetting {
-    // Auto negotiation on/off.
+    /// Auto negotiation on/off.
     #[yaserde(prefix = "tt", rename = "AutoNegotiation")]
     pub auto_negotiation: bool,
 
-    // Speed.
+    /// Speed.
     #[yaserde(prefix = "tt", rename = "Speed")]
     pub speed: i32,
 
-    // Duplex type, Half or Full.
+    /// Duplex type, Half or Full.
     #[yaserde(prefix = "tt", rename = "Duplex")]
     pub duplex: Duplex,
 }
@@ -2273,6 +2297,7 @@ impl Validate for NetworkInterfaceConnectionSetting {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum Duplex {
     Full,
     Half,
@@ -2295,15 +2320,15 @@ impl Validate for IanaIfTypes {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct NetworkInterfaceInfo {
-    // Network interface name, for example eth0.
+    /// Network interface name, for example eth0.
     #[yaserde(prefix = "tt", rename = "Name")]
     pub name: Option<String>,
 
-    // Network interface MAC address.
+    /// Network interface MAC address.
     #[yaserde(prefix = "tt", rename = "HwAddress")]
     pub hw_address: HwAddress,
 
-    // Maximum transmission unit.
+    /// Maximum transmission unit.
     #[yaserde(prefix = "tt", rename = "MTU")]
     pub mtu: Option<i32>,
 }
@@ -2313,11 +2338,11 @@ impl Validate for NetworkInterfaceInfo {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Ipv6NetworkInterface {
-    // Indicates whether or not IPv6 is enabled.
+    /// Indicates whether or not IPv6 is enabled.
     #[yaserde(prefix = "tt", rename = "Enabled")]
     pub enabled: bool,
 
-    // IPv6 configuration.
+    /// IPv6 configuration.
     #[yaserde(prefix = "tt", rename = "Config")]
     pub config: Option<Ipv6Configuration>,
 }
@@ -2327,11 +2352,11 @@ impl Validate for Ipv6NetworkInterface {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Ipv4NetworkInterface {
-    // Indicates whether or not IPv4 is enabled.
+    /// Indicates whether or not IPv4 is enabled.
     #[yaserde(prefix = "tt", rename = "Enabled")]
     pub enabled: bool,
 
-    // IPv4 configuration.
+    /// IPv4 configuration.
     #[yaserde(prefix = "tt", rename = "Config")]
     pub config: Ipv4Configuration,
 }
@@ -2341,19 +2366,19 @@ impl Validate for Ipv4NetworkInterface {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Ipv4Configuration {
-    // List of manually added IPv4 addresses.
+    /// List of manually added IPv4 addresses.
     #[yaserde(prefix = "tt", rename = "Manual")]
     pub manual: Vec<PrefixedIPv4Address>,
 
-    // Link local address.
+    /// Link local address.
     #[yaserde(prefix = "tt", rename = "LinkLocal")]
     pub link_local: Option<PrefixedIPv4Address>,
 
-    // IPv4 address configured by using DHCP.
+    /// IPv4 address configured by using DHCP.
     #[yaserde(prefix = "tt", rename = "FromDHCP")]
     pub from_dhcp: Option<PrefixedIPv4Address>,
 
-    // Indicates whether or not DHCP is used.
+    /// Indicates whether or not DHCP is used.
     #[yaserde(prefix = "tt", rename = "DHCP")]
     pub dhcp: bool,
 }
@@ -2363,27 +2388,27 @@ impl Validate for Ipv4Configuration {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Ipv6Configuration {
-    // Indicates whether router advertisment is used.
+    /// Indicates whether router advertisment is used.
     #[yaserde(prefix = "tt", rename = "AcceptRouterAdvert")]
     pub accept_router_advert: Option<bool>,
 
-    // DHCP configuration.
+    /// DHCP configuration.
     #[yaserde(prefix = "tt", rename = "DHCP")]
     pub dhcp: Ipv6DHCPConfiguration,
 
-    // List of manually entered IPv6 addresses.
+    /// List of manually entered IPv6 addresses.
     #[yaserde(prefix = "tt", rename = "Manual")]
     pub manual: Vec<PrefixedIPv6Address>,
 
-    // List of link local IPv6 addresses.
+    /// List of link local IPv6 addresses.
     #[yaserde(prefix = "tt", rename = "LinkLocal")]
     pub link_local: Vec<PrefixedIPv6Address>,
 
-    // List of IPv6 addresses configured by using DHCP.
+    /// List of IPv6 addresses configured by using DHCP.
     #[yaserde(prefix = "tt", rename = "FromDHCP")]
     pub from_dhcp: Vec<PrefixedIPv6Address>,
 
-    // List of IPv6 addresses configured by using router advertisment.
+    /// List of IPv6 addresses configured by using router advertisment.
     #[yaserde(prefix = "tt", rename = "FromRA")]
     pub from_ra: Vec<PrefixedIPv6Address>,
 
@@ -2401,6 +2426,7 @@ impl Validate for Ipv6ConfigurationExtension {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum Ipv6DHCPConfiguration {
     Auto,
     Stateful,
@@ -2420,15 +2446,15 @@ impl Validate for Ipv6DHCPConfiguration {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct NetworkProtocol {
-    // Network protocol type string.
+    /// Network protocol type string.
     #[yaserde(prefix = "tt", rename = "Name")]
     pub name: NetworkProtocolType,
 
-    // Indicates if the protocol is enabled or not.
+    /// Indicates if the protocol is enabled or not.
     #[yaserde(prefix = "tt", rename = "Enabled")]
     pub enabled: bool,
 
-    // The port that is used by the protocol.
+    /// The port that is used by the protocol.
     #[yaserde(prefix = "tt", rename = "Port")]
     pub port: Vec<i32>,
 
@@ -2446,6 +2472,7 @@ impl Validate for NetworkProtocolExtension {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum NetworkProtocolType {
     #[yaserde(rename = "HTTP")]
     Http,
@@ -2466,6 +2493,7 @@ impl Validate for NetworkProtocolType {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum NetworkHostType {
     #[yaserde(rename = "IPv4")]
     Ipv4,
@@ -2487,19 +2515,19 @@ impl Validate for NetworkHostType {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct NetworkHost {
-    // Network host type: IPv4, IPv6 or DNS.
+    /// Network host type: IPv4, IPv6 or DNS.
     #[yaserde(prefix = "tt", rename = "Type")]
     pub _type: NetworkHostType,
 
-    // IPv4 address.
+    /// IPv4 address.
     #[yaserde(prefix = "tt", rename = "IPv4Address")]
     pub i_pv_4_address: Option<Ipv4Address>,
 
-    // IPv6 address.
+    /// IPv6 address.
     #[yaserde(prefix = "tt", rename = "IPv6Address")]
     pub i_pv_6_address: Option<Ipv6Address>,
 
-    // DNS name.
+    /// DNS name.
     #[yaserde(prefix = "tt", rename = "DNSname")]
     pub dn_sname: Option<Dnsname>,
 
@@ -2518,15 +2546,15 @@ impl Validate for NetworkHostExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Ipaddress {
-    // Indicates if the address is an IPv4 or IPv6 address.
+    /// Indicates if the address is an IPv4 or IPv6 address.
     #[yaserde(prefix = "tt", rename = "Type")]
     pub _type: Iptype,
 
-    // IPv4 address.
+    /// IPv4 address.
     #[yaserde(prefix = "tt", rename = "IPv4Address")]
     pub i_pv_4_address: Option<Ipv4Address>,
 
-    // IPv6 address
+    /// IPv6 address
     #[yaserde(prefix = "tt", rename = "IPv6Address")]
     pub i_pv_6_address: Option<Ipv6Address>,
 }
@@ -2536,11 +2564,11 @@ impl Validate for Ipaddress {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct PrefixedIPv4Address {
-    // IPv4 address
+    /// IPv4 address
     #[yaserde(prefix = "tt", rename = "Address")]
     pub address: Ipv4Address,
 
-    // Prefix/submask length
+    /// Prefix/submask length
     #[yaserde(prefix = "tt", rename = "PrefixLength")]
     pub prefix_length: i32,
 }
@@ -2555,11 +2583,11 @@ impl Validate for Ipv4Address {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct PrefixedIPv6Address {
-    // IPv6 address
+    /// IPv6 address
     #[yaserde(prefix = "tt", rename = "Address")]
     pub address: Ipv6Address,
 
-    // Prefix/submask length
+    /// Prefix/submask length
     #[yaserde(prefix = "tt", rename = "PrefixLength")]
     pub prefix_length: i32,
 }
@@ -2578,6 +2606,7 @@ impl Validate for HwAddress {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum Iptype {
     #[yaserde(rename = "IPv4")]
     Ipv4,
@@ -2602,11 +2631,11 @@ impl Validate for Dnsname {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct HostnameInformation {
-    // Indicates whether the hostname is obtained from DHCP or not.
+    /// Indicates whether the hostname is obtained from DHCP or not.
     #[yaserde(prefix = "tt", rename = "FromDHCP")]
     pub from_dhcp: bool,
 
-    // Indicates the hostname.
+    /// Indicates the hostname.
     #[yaserde(prefix = "tt", rename = "Name")]
     pub name: Option<String>,
 
@@ -2625,19 +2654,19 @@ impl Validate for HostnameInformationExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Dnsinformation {
-    // Indicates whether or not DNS information is retrieved from DHCP.
+    /// Indicates whether or not DNS information is retrieved from DHCP.
     #[yaserde(prefix = "tt", rename = "FromDHCP")]
     pub from_dhcp: bool,
 
-    // Search domain.
+    /// Search domain.
     #[yaserde(prefix = "tt", rename = "SearchDomain")]
     pub search_domain: Vec<String>,
 
-    // List of DNS addresses received from DHCP.
+    /// List of DNS addresses received from DHCP.
     #[yaserde(prefix = "tt", rename = "DNSFromDHCP")]
     pub dns_from_dhcp: Vec<Ipaddress>,
 
-    // List of manually entered DNS addresses.
+    /// List of manually entered DNS addresses.
     #[yaserde(prefix = "tt", rename = "DNSManual")]
     pub dns_manual: Vec<Ipaddress>,
 
@@ -2656,15 +2685,15 @@ impl Validate for DnsinformationExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Ntpinformation {
-    // Indicates if NTP information is to be retrieved by using DHCP.
+    /// Indicates if NTP information is to be retrieved by using DHCP.
     #[yaserde(prefix = "tt", rename = "FromDHCP")]
     pub from_dhcp: bool,
 
-    // List of NTP addresses retrieved by using DHCP.
+    /// List of NTP addresses retrieved by using DHCP.
     #[yaserde(prefix = "tt", rename = "NTPFromDHCP")]
     pub ntp_from_dhcp: Vec<NetworkHost>,
 
-    // List of manually entered NTP addresses.
+    /// List of manually entered NTP addresses.
     #[yaserde(prefix = "tt", rename = "NTPManual")]
     pub ntp_manual: Vec<NetworkHost>,
 
@@ -2687,6 +2716,7 @@ impl Validate for Domain {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum IpaddressFilterType {
     Allow,
     Deny,
@@ -2704,15 +2734,15 @@ impl Validate for IpaddressFilterType {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct DynamicDNSInformation {
-    // Dynamic DNS type.
+    /// Dynamic DNS type.
     #[yaserde(prefix = "tt", rename = "Type")]
     pub _type: DynamicDNSType,
 
-    // DNS name.
+    /// DNS name.
     #[yaserde(prefix = "tt", rename = "Name")]
     pub name: Option<Dnsname>,
 
-    // Time to live.
+    /// Time to live.
     #[yaserde(prefix = "tt", rename = "TTL")]
     pub ttl: Option<xs::Duration>,
 
@@ -2730,6 +2760,7 @@ impl Validate for DynamicDNSInformationExtension {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum DynamicDNSType {
     NoUpdate,
     ClientUpdates,
@@ -2748,23 +2779,23 @@ impl Validate for DynamicDNSType {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct NetworkInterfaceSetConfiguration {
-    // Indicates whether or not an interface is enabled.
+    /// Indicates whether or not an interface is enabled.
     #[yaserde(prefix = "tt", rename = "Enabled")]
     pub enabled: Option<bool>,
 
-    // Link configuration.
+    /// Link configuration.
     #[yaserde(prefix = "tt", rename = "Link")]
     pub link: Option<NetworkInterfaceConnectionSetting>,
 
-    // Maximum transmission unit.
+    /// Maximum transmission unit.
     #[yaserde(prefix = "tt", rename = "MTU")]
     pub mtu: Option<i32>,
 
-    // IPv4 network interface configuration.
+    /// IPv4 network interface configuration.
     #[yaserde(prefix = "tt", rename = "IPv4")]
     pub i_pv_4: Vec<Ipv4NetworkInterfaceSetConfiguration>,
 
-    // IPv6 network interface configuration.
+    /// IPv6 network interface configuration.
     #[yaserde(prefix = "tt", rename = "IPv6")]
     pub i_pv_6: Vec<Ipv6NetworkInterfaceSetConfiguration>,
 
@@ -2782,10 +2813,11 @@ pub struct NetworkInterfaceSetConfigurationExtension {
 
     #[yaserde(prefix = "tt", rename = "Dot11")]
     pub dot_11: Vec<Dot11Configuration>,
-    // `Extension` inside `Extension` causes infinite loop at deserialization
-    // https://github.com/media-io/yaserde/issues/76
-    // #[yaserde(prefix = "tt", rename = "Extension")]
-    // pub extension: Option<NetworkInterfaceSetConfigurationExtension2>,
+    /// `Extension` inside `Extension` causes infinite loop at deserialization
+    /// https://github.com/media-io/yaserde/issues/76
+    /// so it's kept as raw XML.
+    #[yaserde(prefix = "tt", rename = "Extension")]
+    pub extension: Option<RawXml>,
 }
 
 impl Validate for NetworkInterfaceSetConfigurationExtension {}
@@ -2793,19 +2825,19 @@ impl Validate for NetworkInterfaceSetConfigurationExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Ipv6NetworkInterfaceSetConfiguration {
-    // Indicates whether or not IPv6 is enabled.
+    /// Indicates whether or not IPv6 is enabled.
     #[yaserde(prefix = "tt", rename = "Enabled")]
     pub enabled: Option<bool>,
 
-    // Indicates whether router advertisment is used.
+    /// Indicates whether router advertisment is used.
     #[yaserde(prefix = "tt", rename = "AcceptRouterAdvert")]
     pub accept_router_advert: Option<bool>,
 
-    // List of manually added IPv6 addresses.
+    /// List of manually added IPv6 addresses.
     #[yaserde(prefix = "tt", rename = "Manual")]
     pub manual: Vec<PrefixedIPv6Address>,
 
-    // DHCP configuration.
+    /// DHCP configuration.
     #[yaserde(prefix = "tt", rename = "DHCP")]
     pub dhcp: Option<Ipv6DHCPConfiguration>,
 }
@@ -2815,15 +2847,15 @@ impl Validate for Ipv6NetworkInterfaceSetConfiguration {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Ipv4NetworkInterfaceSetConfiguration {
-    // Indicates whether or not IPv4 is enabled.
+    /// Indicates whether or not IPv4 is enabled.
     #[yaserde(prefix = "tt", rename = "Enabled")]
     pub enabled: Option<bool>,
 
-    // List of manually added IPv4 addresses.
+    /// List of manually added IPv4 addresses.
     #[yaserde(prefix = "tt", rename = "Manual")]
     pub manual: Vec<PrefixedIPv4Address>,
 
-    // Indicates whether or not DHCP is used.
+    /// Indicates whether or not DHCP is used.
     #[yaserde(prefix = "tt", rename = "DHCP")]
     pub dhcp: Option<bool>,
 }
@@ -2833,11 +2865,11 @@ impl Validate for Ipv4NetworkInterfaceSetConfiguration {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct NetworkGateway {
-    // IPv4 address string.
+    /// IPv4 address string.
     #[yaserde(prefix = "tt", rename = "IPv4Address")]
     pub i_pv_4_address: Vec<Ipv4Address>,
 
-    // IPv6 address string.
+    /// IPv6 address string.
     #[yaserde(prefix = "tt", rename = "IPv6Address")]
     pub i_pv_6_address: Vec<Ipv6Address>,
 }
@@ -2847,15 +2879,15 @@ impl Validate for NetworkGateway {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct NetworkZeroConfiguration {
-    // Unique identifier of network interface.
+    /// Unique identifier of network interface.
     #[yaserde(prefix = "tt", rename = "InterfaceToken")]
     pub interface_token: ReferenceToken,
 
-    // Indicates whether the zero-configuration is enabled or not.
+    /// Indicates whether the zero-configuration is enabled or not.
     #[yaserde(prefix = "tt", rename = "Enabled")]
     pub enabled: bool,
 
-    // The zero-configuration IPv4 address(es)
+    /// The zero-configuration IPv4 address(es)
     #[yaserde(prefix = "tt", rename = "Addresses")]
     pub addresses: Vec<Ipv4Address>,
 
@@ -2868,14 +2900,15 @@ impl Validate for NetworkZeroConfiguration {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct NetworkZeroConfigurationExtension {
-    // Optional array holding the configuration for the second and possibly
-    // further interfaces.
+    /// Optional array holding the configuration for the second and possibly
+    /// further interfaces.
     #[yaserde(prefix = "tt", rename = "Additional")]
     pub additional: Vec<NetworkZeroConfiguration>,
-    // `Extension` inside `Extension` causes infinite loop at deserialization
-    // https://github.com/media-io/yaserde/issues/76
-    // #[yaserde(prefix = "tt", rename = "Extension")]
-    // pub extension: Option<NetworkZeroConfigurationExtension2>,
+    /// `Extension` inside `Extension` causes infinite loop at deserialization
+    /// https://github.com/media-io/yaserde/issues/76
+    /// so it's kept as raw XML.
+    #[yaserde(prefix = "tt", rename = "Extension")]
+    pub extension: Option<RawXml>,
 }
 
 impl Validate for NetworkZeroConfigurationExtension {}
@@ -2954,6 +2987,7 @@ impl Validate for Dot11SSIDType {
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum Dot11StationMode {
     #[yaserde(rename = "Ad-hoc")]
     AdHoc,
@@ -2999,6 +3033,7 @@ impl Validate for Dot11SecurityConfigurationExtension {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum Dot11SecurityMode {
     None,
     #[yaserde(rename = "WEP")]
@@ -3020,6 +3055,7 @@ impl Validate for Dot11SecurityMode {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum Dot11Cipher {
     #[yaserde(rename = "CCMP")]
     Ccmp,
@@ -3061,15 +3097,15 @@ impl Validate for Dot11PSKPassphrase {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Dot11PSKSet {
-    // According to IEEE802.11-2007 H.4.1 the RSNA PSK consists of 256 bits, or
-    // 64 octets when represented in hex
+    /// According to IEEE802.11-2007 H.4.1 the RSNA PSK consists of 256 bits, or
+    /// 64 octets when represented in hex
     #[yaserde(prefix = "tt", rename = "Key")]
     pub key: Option<Dot11PSK>,
 
-    // According to IEEE802.11-2007 H.4.1 a pass-phrase is a sequence of between
-    // 8 and 63 ASCII-encoded characters and
-    // each character in the pass-phrase must have an encoding in the range of
-    // 32 to 126 (decimal),inclusive.
+    /// According to IEEE802.11-2007 H.4.1 a pass-phrase is a sequence of between
+    /// 8 and 63 ASCII-encoded characters and
+    /// each character in the pass-phrase must have an encoding in the range of
+    /// 32 to 126 (decimal),inclusive.
     #[yaserde(prefix = "tt", rename = "Passphrase")]
     pub passphrase: Option<Dot11PSKPassphrase>,
 
@@ -3143,6 +3179,7 @@ impl Validate for Dot11Status {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum Dot11AuthAndMangementSuite {
     None,
     Dot1X,
@@ -3169,7 +3206,7 @@ pub struct Dot11AvailableNetworks {
     #[yaserde(prefix = "tt", rename = "BSSID")]
     pub bssid: Option<String>,
 
-    // See IEEE802.11 7.3.2.25.2 for details.
+    /// See IEEE802.11 7.3.2.25.2 for details.
     #[yaserde(prefix = "tt", rename = "AuthAndMangementSuite")]
     pub auth_and_mangement_suite: Vec<Dot11AuthAndMangementSuite>,
 
@@ -3196,6 +3233,7 @@ impl Validate for Dot11AvailableNetworksExtension {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum CapabilityCategory {
     All,
     Analytics,
@@ -3219,27 +3257,27 @@ impl Validate for CapabilityCategory {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Capabilities {
-    // Analytics capabilities
+    /// Analytics capabilities
     #[yaserde(prefix = "tt", rename = "Analytics")]
     pub analytics: Vec<AnalyticsCapabilities>,
 
-    // Device capabilities
+    /// Device capabilities
     #[yaserde(prefix = "tt", rename = "Device")]
     pub device: Vec<DeviceCapabilities>,
 
-    // Event capabilities
+    /// Event capabilities
     #[yaserde(prefix = "tt", rename = "Events")]
     pub events: Vec<EventCapabilities>,
 
-    // Imaging capabilities
+    /// Imaging capabilities
     #[yaserde(prefix = "tt", rename = "Imaging")]
     pub imaging: Vec<ImagingCapabilities>,
 
-    // Media capabilities
+    /// Media capabilities
     #[yaserde(prefix = "tt", rename = "Media")]
     pub media: Vec<MediaCapabilities>,
 
-    // PTZ capabilities
+    /// PTZ capabilities
     #[yaserde(prefix = "tt", rename = "PTZ")]
     pub ptz: Vec<Ptzcapabilities>,
 
@@ -3272,10 +3310,11 @@ pub struct CapabilitiesExtension {
 
     #[yaserde(prefix = "tt", rename = "AnalyticsDevice")]
     pub analytics_device: Option<AnalyticsDeviceCapabilities>,
-    // `Extension` inside `Extension` causes infinite loop at deserialization
-    // https://github.com/media-io/yaserde/issues/76
-    // #[yaserde(prefix = "tt", rename = "Extensions")]
-    // pub extensions: Option<CapabilitiesExtension2>,
+    /// `Extension` inside `Extension` causes infinite loop at deserialization
+    /// https://github.com/media-io/yaserde/issues/76
+    /// so it's kept as raw XML.
+    #[yaserde(prefix = "tt", rename = "Extensions")]
+    pub extensions: Option<RawXml>,
 }
 
 impl Validate for CapabilitiesExtension {}
@@ -3289,15 +3328,15 @@ impl Validate for CapabilitiesExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct AnalyticsCapabilities {
-    // Analytics service URI.
+    /// Analytics service URI.
     #[yaserde(prefix = "tt", rename = "XAddr")]
     pub x_addr: String,
 
-    // Indicates whether or not rules are supported.
+    /// Indicates whether or not rules are supported.
     #[yaserde(prefix = "tt", rename = "RuleSupport")]
     pub rule_support: bool,
 
-    // Indicates whether or not modules are supported.
+    /// Indicates whether or not modules are supported.
     #[yaserde(prefix = "tt", rename = "AnalyticsModuleSupport")]
     pub analytics_module_support: bool,
 }
@@ -3307,23 +3346,23 @@ impl Validate for AnalyticsCapabilities {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct DeviceCapabilities {
-    // Device service URI.
+    /// Device service URI.
     #[yaserde(prefix = "tt", rename = "XAddr")]
     pub x_addr: String,
 
-    // Network capabilities.
+    /// Network capabilities.
     #[yaserde(prefix = "tt", rename = "Network")]
     pub network: Option<NetworkCapabilities>,
 
-    // System capabilities.
+    /// System capabilities.
     #[yaserde(prefix = "tt", rename = "System")]
     pub system: Option<SystemCapabilities>,
 
-    // I/O capabilities.
+    /// I/O capabilities.
     #[yaserde(prefix = "tt", rename = "IO")]
     pub io: Option<Iocapabilities>,
 
-    // Security capabilities.
+    /// Security capabilities.
     #[yaserde(prefix = "tt", rename = "Security")]
     pub security: Option<SecurityCapabilities>,
 
@@ -3342,20 +3381,20 @@ impl Validate for DeviceCapabilitiesExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct EventCapabilities {
-    // Event service URI.
+    /// Event service URI.
     #[yaserde(prefix = "tt", rename = "XAddr")]
     pub x_addr: String,
 
-    // Indicates whether or not WS Subscription policy is supported.
+    /// Indicates whether or not WS Subscription policy is supported.
     #[yaserde(prefix = "tt", rename = "WSSubscriptionPolicySupport")]
     pub ws_subscription_policy_support: bool,
 
-    // Indicates whether or not WS Pull Point is supported.
+    /// Indicates whether or not WS Pull Point is supported.
     #[yaserde(prefix = "tt", rename = "WSPullPointSupport")]
     pub ws_pull_point_support: bool,
 
-    // Indicates whether or not WS Pausable Subscription Manager Interface is
-    // supported.
+    /// Indicates whether or not WS Pausable Subscription Manager Interface is
+    /// supported.
     #[yaserde(
         prefix = "tt",
         rename = "WSPausableSubscriptionManagerInterfaceSupport"
@@ -3368,11 +3407,11 @@ impl Validate for EventCapabilities {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Iocapabilities {
-    // Number of input connectors.
+    /// Number of input connectors.
     #[yaserde(prefix = "tt", rename = "InputConnectors")]
     pub input_connectors: Option<i32>,
 
-    // Number of relay outputs.
+    /// Number of relay outputs.
     #[yaserde(prefix = "tt", rename = "RelayOutputs")]
     pub relay_outputs: Option<i32>,
 
@@ -3390,10 +3429,11 @@ pub struct IocapabilitiesExtension {
 
     #[yaserde(prefix = "tt", rename = "AuxiliaryCommands")]
     pub auxiliary_commands: Vec<AuxiliaryData>,
-    // `Extension` inside `Extension` causes infinite loop at deserialization
-    // https://github.com/media-io/yaserde/issues/76
-    // #[yaserde(prefix = "tt", rename = "Extension")]
-    // pub extension: IocapabilitiesExtension2,
+    /// `Extension` inside `Extension` causes infinite loop at deserialization
+    /// https://github.com/media-io/yaserde/issues/76
+    /// so it's kept as raw XML.
+    #[yaserde(prefix = "tt", rename = "Extension")]
+    pub extension: Option<RawXml>,
 }
 
 impl Validate for IocapabilitiesExtension {}
@@ -3407,11 +3447,11 @@ impl Validate for IocapabilitiesExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct MediaCapabilities {
-    // Media service URI.
+    /// Media service URI.
     #[yaserde(prefix = "tt", rename = "XAddr")]
     pub x_addr: String,
 
-    // Streaming capabilities.
+    /// Streaming capabilities.
     #[yaserde(prefix = "tt", rename = "StreamingCapabilities")]
     pub streaming_capabilities: RealTimeStreamingCapabilities,
 
@@ -3433,15 +3473,15 @@ impl Validate for MediaCapabilitiesExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct RealTimeStreamingCapabilities {
-    // Indicates whether or not RTP multicast is supported.
+    /// Indicates whether or not RTP multicast is supported.
     #[yaserde(prefix = "tt", rename = "RTPMulticast")]
     pub rtp_multicast: Option<bool>,
 
-    // Indicates whether or not RTP over TCP is supported.
+    /// Indicates whether or not RTP over TCP is supported.
     #[yaserde(prefix = "tt", rename = "RTP_TCP")]
     pub rtp_tcp: Option<bool>,
 
-    // Indicates whether or not RTP/RTSP/TCP is supported.
+    /// Indicates whether or not RTP/RTSP/TCP is supported.
     #[yaserde(prefix = "tt", rename = "RTP_RTSP_TCP")]
     pub rtp_rtsp_tcp: Option<bool>,
 
@@ -3460,7 +3500,7 @@ impl Validate for RealTimeStreamingCapabilitiesExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ProfileCapabilities {
-    // Maximum number of profiles.
+    /// Maximum number of profiles.
     #[yaserde(prefix = "tt", rename = "MaximumNumberOfProfiles")]
     pub maximum_number_of_profiles: i32,
 }
@@ -3470,19 +3510,19 @@ impl Validate for ProfileCapabilities {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct NetworkCapabilities {
-    // Indicates whether or not IP filtering is supported.
+    /// Indicates whether or not IP filtering is supported.
     #[yaserde(prefix = "tt", rename = "IPFilter")]
     pub ip_filter: Option<bool>,
 
-    // Indicates whether or not zeroconf is supported.
+    /// Indicates whether or not zeroconf is supported.
     #[yaserde(prefix = "tt", rename = "ZeroConfiguration")]
     pub zero_configuration: Option<bool>,
 
-    // Indicates whether or not IPv6 is supported.
+    /// Indicates whether or not IPv6 is supported.
     #[yaserde(prefix = "tt", rename = "IPVersion6")]
     pub ip_version_6: Option<bool>,
 
-    // Indicates whether or not is supported.
+    /// Indicates whether or not is supported.
     #[yaserde(prefix = "tt", rename = "DynDNS")]
     pub dyn_dns: Option<bool>,
 
@@ -3497,10 +3537,11 @@ impl Validate for NetworkCapabilities {}
 pub struct NetworkCapabilitiesExtension {
     #[yaserde(prefix = "tt", rename = "Dot11Configuration")]
     pub dot_11_configuration: Option<bool>,
-    // `Extension` inside `Extension` causes infinite loop at deserialization
-    // https://github.com/media-io/yaserde/issues/76
-    // #[yaserde(prefix = "tt", rename = "Extension")]
-    // pub extension: Option<NetworkCapabilitiesExtension2>,
+    /// `Extension` inside `Extension` causes infinite loop at deserialization
+    /// https://github.com/media-io/yaserde/issues/76
+    /// so it's kept as raw XML.
+    #[yaserde(prefix = "tt", rename = "Extension")]
+    pub extension: Option<RawXml>,
 }
 
 impl Validate for NetworkCapabilitiesExtension {}
@@ -3514,35 +3555,35 @@ impl Validate for NetworkCapabilitiesExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct SecurityCapabilities {
-    // Indicates whether or not TLS 1.1 is supported.
+    /// Indicates whether or not TLS 1.1 is supported.
     #[yaserde(prefix = "tt", rename = "TLS1.1")]
     pub tls1_1: bool,
 
-    // Indicates whether or not TLS 1.2 is supported.
+    /// Indicates whether or not TLS 1.2 is supported.
     #[yaserde(prefix = "tt", rename = "TLS1.2")]
     pub tls1_2: bool,
 
-    // Indicates whether or not onboard key generation is supported.
+    /// Indicates whether or not onboard key generation is supported.
     #[yaserde(prefix = "tt", rename = "OnboardKeyGeneration")]
     pub onboard_key_generation: bool,
 
-    // Indicates whether or not access policy configuration is supported.
+    /// Indicates whether or not access policy configuration is supported.
     #[yaserde(prefix = "tt", rename = "AccessPolicyConfig")]
     pub access_policy_config: bool,
 
-    // Indicates whether or not WS-Security X.509 token is supported.
+    /// Indicates whether or not WS-Security X.509 token is supported.
     #[yaserde(prefix = "tt", rename = "X.509Token")]
     pub x_509_token: bool,
 
-    // Indicates whether or not WS-Security SAML token is supported.
+    /// Indicates whether or not WS-Security SAML token is supported.
     #[yaserde(prefix = "tt", rename = "SAMLToken")]
     pub saml_token: bool,
 
-    // Indicates whether or not WS-Security Kerberos token is supported.
+    /// Indicates whether or not WS-Security Kerberos token is supported.
     #[yaserde(prefix = "tt", rename = "KerberosToken")]
     pub kerberos_token: bool,
 
-    // Indicates whether or not WS-Security REL token is supported.
+    /// Indicates whether or not WS-Security REL token is supported.
     #[yaserde(prefix = "tt", rename = "RELToken")]
     pub rel_token: bool,
 
@@ -3557,10 +3598,11 @@ impl Validate for SecurityCapabilities {}
 pub struct SecurityCapabilitiesExtension {
     #[yaserde(prefix = "tt", rename = "TLS1.0")]
     pub tls1_0: bool,
-    // `Extension` inside `Extension` causes infinite loop at deserialization
-    // https://github.com/media-io/yaserde/issues/76
-    // #[yaserde(prefix = "tt", rename = "Extension")]
-    // pub extension: Option<SecurityCapabilitiesExtension2>,
+    /// `Extension` inside `Extension` causes infinite loop at deserialization
+    /// https://github.com/media-io/yaserde/issues/76
+    /// so it's kept as raw XML.
+    #[yaserde(prefix = "tt", rename = "Extension")]
+    pub extension: Option<RawXml>,
 }
 
 impl Validate for SecurityCapabilitiesExtension {}
@@ -3584,31 +3626,31 @@ impl Validate for SecurityCapabilitiesExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct SystemCapabilities {
-    // Indicates whether or not WS Discovery resolve requests are supported.
+    /// Indicates whether or not WS Discovery resolve requests are supported.
     #[yaserde(prefix = "tt", rename = "DiscoveryResolve")]
     pub discovery_resolve: bool,
 
-    // Indicates whether or not WS-Discovery Bye is supported.
+    /// Indicates whether or not WS-Discovery Bye is supported.
     #[yaserde(prefix = "tt", rename = "DiscoveryBye")]
     pub discovery_bye: bool,
 
-    // Indicates whether or not remote discovery is supported.
+    /// Indicates whether or not remote discovery is supported.
     #[yaserde(prefix = "tt", rename = "RemoteDiscovery")]
     pub remote_discovery: bool,
 
-    // Indicates whether or not system backup is supported.
+    /// Indicates whether or not system backup is supported.
     #[yaserde(prefix = "tt", rename = "SystemBackup")]
     pub system_backup: bool,
 
-    // Indicates whether or not system logging is supported.
+    /// Indicates whether or not system logging is supported.
     #[yaserde(prefix = "tt", rename = "SystemLogging")]
     pub system_logging: bool,
 
-    // Indicates whether or not firmware upgrade is supported.
+    /// Indicates whether or not firmware upgrade is supported.
     #[yaserde(prefix = "tt", rename = "FirmwareUpgrade")]
     pub firmware_upgrade: bool,
 
-    // Indicates supported ONVIF version(s).
+    /// Indicates supported ONVIF version(s).
     #[yaserde(prefix = "tt", rename = "SupportedVersions")]
     pub supported_versions: Vec<OnvifVersion>,
 
@@ -3632,10 +3674,11 @@ pub struct SystemCapabilitiesExtension {
 
     #[yaserde(prefix = "tt", rename = "HttpSupportInformation")]
     pub http_support_information: Option<bool>,
-    // `Extension` inside `Extension` causes infinite loop at deserialization
-    // https://github.com/media-io/yaserde/issues/76
-    // #[yaserde(prefix = "tt", rename = "Extension")]
-    // pub extension: Option<SystemCapabilitiesExtension2>,
+    /// `Extension` inside `Extension` causes infinite loop at deserialization
+    /// https://github.com/media-io/yaserde/issues/76
+    /// so it's kept as raw XML.
+    #[yaserde(prefix = "tt", rename = "Extension")]
+    pub extension: Option<RawXml>,
 }
 
 impl Validate for SystemCapabilitiesExtension {}
@@ -3649,14 +3692,14 @@ impl Validate for SystemCapabilitiesExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct OnvifVersion {
-    // Major version number.
+    /// Major version number.
     #[yaserde(prefix = "tt", rename = "Major")]
     pub major: i32,
 
-    // Two digit minor version number.
-    // If major version number is less than "16", X.0.1 maps to "01" and X.2.1
-    // maps to "21" where X stands for Major version number.
-    // Otherwise, minor number is month of release, such as "06" for June.
+    /// Two digit minor version number.
+    /// If major version number is less than "16", X.0.1 maps to "01" and X.2.1
+    /// maps to "21" where X stands for Major version number.
+    /// Otherwise, minor number is month of release, such as "06" for June.
     #[yaserde(prefix = "tt", rename = "Minor")]
     pub minor: i32,
 }
@@ -3666,7 +3709,7 @@ impl Validate for OnvifVersion {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ImagingCapabilities {
-    // Imaging service URI.
+    /// Imaging service URI.
     #[yaserde(prefix = "tt", rename = "XAddr")]
     pub x_addr: String,
 }
@@ -3676,7 +3719,7 @@ impl Validate for ImagingCapabilities {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Ptzcapabilities {
-    // PTZ service URI.
+    /// PTZ service URI.
     #[yaserde(prefix = "tt", rename = "XAddr")]
     pub x_addr: String,
 }
@@ -3713,7 +3756,7 @@ pub struct DisplayCapabilities {
     #[yaserde(prefix = "tt", rename = "XAddr")]
     pub x_addr: String,
 
-    // Indication that the SetLayout command supports only predefined layouts.
+    /// Indication that the SetLayout command supports only predefined layouts.
     #[yaserde(prefix = "tt", rename = "FixedLayout")]
     pub fixed_layout: bool,
 }
@@ -3759,7 +3802,7 @@ impl Validate for SearchCapabilities {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ReplayCapabilities {
-    // The address of the replay service.
+    /// The address of the replay service.
     #[yaserde(prefix = "tt", rename = "XAddr")]
     pub x_addr: String,
 }
@@ -3769,27 +3812,27 @@ impl Validate for ReplayCapabilities {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ReceiverCapabilities {
-    // The address of the receiver service.
+    /// The address of the receiver service.
     #[yaserde(prefix = "tt", rename = "XAddr")]
     pub x_addr: String,
 
-    // Indicates whether the device can receive RTP multicast streams.
+    /// Indicates whether the device can receive RTP multicast streams.
     #[yaserde(prefix = "tt", rename = "RTP_Multicast")]
     pub rtp_multicast: bool,
 
-    // Indicates whether the device can receive RTP/TCP streams
+    /// Indicates whether the device can receive RTP/TCP streams
     #[yaserde(prefix = "tt", rename = "RTP_TCP")]
     pub rtp_tcp: bool,
 
-    // Indicates whether the device can receive RTP/RTSP/TCP streams.
+    /// Indicates whether the device can receive RTP/RTSP/TCP streams.
     #[yaserde(prefix = "tt", rename = "RTP_RTSP_TCP")]
     pub rtp_rtsp_tcp: bool,
 
-    // The maximum number of receivers supported by the device.
+    /// The maximum number of receivers supported by the device.
     #[yaserde(prefix = "tt", rename = "SupportedReceivers")]
     pub supported_receivers: i32,
 
-    // The maximum allowed length for RTSP URIs.
+    /// The maximum allowed length for RTSP URIs.
     #[yaserde(prefix = "tt", rename = "MaximumRTSPURILength")]
     pub maximum_rtspuri_length: i32,
 }
@@ -3802,7 +3845,7 @@ pub struct AnalyticsDeviceCapabilities {
     #[yaserde(prefix = "tt", rename = "XAddr")]
     pub x_addr: String,
 
-    // Obsolete property.
+    /// Obsolete property.
     #[yaserde(prefix = "tt", rename = "RuleSupport")]
     pub rule_support: Option<bool>,
 
@@ -3818,13 +3861,14 @@ pub struct AnalyticsDeviceExtension {}
 
 impl Validate for AnalyticsDeviceExtension {}
 
-// Enumeration describing the available system log modes.
+/// Enumeration describing the available system log modes.
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum SystemLogType {
-    // Indicates that a system log is requested.
+    /// Indicates that a system log is requested.
     System,
-    // Indicates that a access log is requested.
+    /// Indicates that a access log is requested.
     Access,
     __Unknown__(String),
 }
@@ -3840,11 +3884,11 @@ impl Validate for SystemLogType {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct SystemLog {
-    // The log information as attachment data.
+    /// The log information as attachment data.
     #[yaserde(prefix = "tt", rename = "Binary")]
     pub binary: Option<AttachmentData>,
 
-    // The log information as character data.
+    /// The log information as character data.
     #[yaserde(prefix = "tt", rename = "String")]
     pub string: Option<String>,
 }
@@ -3854,11 +3898,11 @@ impl Validate for SystemLog {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct SupportInformation {
-    // The support information as attachment data.
+    /// The support information as attachment data.
     #[yaserde(prefix = "tt", rename = "Binary")]
     pub binary: Option<AttachmentData>,
 
-    // The support information as character data.
+    /// The support information as character data.
     #[yaserde(prefix = "tt", rename = "String")]
     pub string: Option<String>,
 }
@@ -3868,7 +3912,7 @@ impl Validate for SupportInformation {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct BinaryData {
-    // base64 encoded binary data.
+    /// base64 encoded binary data.
     #[yaserde(prefix = "tt", rename = "Data")]
     pub data: String,
 
@@ -3923,13 +3967,14 @@ pub struct SystemLogUri {
 
 impl Validate for SystemLogUri {}
 
-// Enumeration describing the available factory default modes.
+/// Enumeration describing the available factory default modes.
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum FactoryDefaultType {
-    // Indicates that a hard factory default is requested.
+    /// Indicates that a hard factory default is requested.
     Hard,
-    // Indicates that a soft factory default is requested.
+    /// Indicates that a soft factory default is requested.
     Soft,
     __Unknown__(String),
 }
@@ -3944,10 +3989,11 @@ impl Validate for FactoryDefaultType {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum SetDateTimeType {
-    // Indicates that the date and time are set manually.
+    /// Indicates that the date and time are set manually.
     Manual,
-    // Indicates that the date and time are set through NTP
+    /// Indicates that the date and time are set through NTP
     #[yaserde(rename = "NTP")]
     Ntp,
     __Unknown__(String),
@@ -3961,28 +4007,28 @@ impl Default for SetDateTimeType {
 
 impl Validate for SetDateTimeType {}
 
-// General date time inforamtion returned by the GetSystemDateTime method.
+/// General date time inforamtion returned by the GetSystemDateTime method.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct SystemDateTime {
-    // Indicates if the time is set manully or through NTP.
+    /// Indicates if the time is set manully or through NTP.
     #[yaserde(prefix = "tt", rename = "DateTimeType")]
     pub date_time_type: SetDateTimeType,
 
-    // Informative indicator whether daylight savings is currently on/off.
+    /// Informative indicator whether daylight savings is currently on/off.
     #[yaserde(prefix = "tt", rename = "DaylightSavings")]
     pub daylight_savings: bool,
 
-    // Timezone information in Posix format.
+    /// Timezone information in Posix format.
     #[yaserde(prefix = "tt", rename = "TimeZone")]
     pub time_zone: Option<TimeZone>,
 
-    // Current system date and time in UTC format. This field is mandatory since
-    // version 2.0.
+    /// Current system date and time in UTC format. This field is mandatory since
+    /// version 2.0.
     #[yaserde(prefix = "tt", rename = "UTCDateTime")]
     pub utc_date_time: Option<DateTime>,
 
-    // Date and time in local format.
+    /// Date and time in local format.
     #[yaserde(prefix = "tt", rename = "LocalDateTime")]
     pub local_date_time: Option<DateTime>,
 
@@ -4016,11 +4062,11 @@ pub struct Date {
     #[yaserde(prefix = "tt", rename = "Year")]
     pub year: i32,
 
-    // Range is 1 to 12.
+    /// Range is 1 to 12.
     #[yaserde(prefix = "tt", rename = "Month")]
     pub month: i32,
 
-    // Range is 1 to 31.
+    /// Range is 1 to 31.
     #[yaserde(prefix = "tt", rename = "Day")]
     pub day: i32,
 }
@@ -4030,26 +4076,26 @@ impl Validate for Date {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Time {
-    // Range is 0 to 23.
+    /// Range is 0 to 23.
     #[yaserde(prefix = "tt", rename = "Hour")]
     pub hour: i32,
 
-    // Range is 0 to 59.
+    /// Range is 0 to 59.
     #[yaserde(prefix = "tt", rename = "Minute")]
     pub minute: i32,
 
-    // Range is 0 to 61 (typically 59).
+    /// Range is 0 to 61 (typically 59).
     #[yaserde(prefix = "tt", rename = "Second")]
     pub second: i32,
 }
 
 impl Validate for Time {}
 
-// The TZ format is specified by POSIX, please refer to POSIX 1003.1 section 8.3
+/// The TZ format is specified by POSIX, please refer to POSIX 1003.1 section 8.3
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct TimeZone {
-    // Posix timezone string.
+    /// Posix timezone string.
     #[yaserde(prefix = "tt", rename = "TZ")]
     pub tz: String,
 }
@@ -4073,6 +4119,7 @@ impl Validate for RemoteUser {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum UserLevel {
     Administrator,
     Operator,
@@ -4093,15 +4140,15 @@ impl Validate for UserLevel {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct User {
-    // Username string.
+    /// Username string.
     #[yaserde(prefix = "tt", rename = "Username")]
     pub username: String,
 
-    // Password string.
+    /// Password string.
     #[yaserde(prefix = "tt", rename = "Password")]
     pub password: Option<String>,
 
-    // User level string.
+    /// User level string.
     #[yaserde(prefix = "tt", rename = "UserLevel")]
     pub user_level: UserLevel,
 
@@ -4147,11 +4194,11 @@ impl Validate for CertificateGenerationParametersExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Certificate {
-    // Certificate id.
+    /// Certificate id.
     #[yaserde(prefix = "tt", rename = "CertificateID")]
     pub certificate_id: String,
 
-    // base64 encoded DER representation of certificate.
+    /// base64 encoded DER representation of certificate.
     #[yaserde(prefix = "tt", rename = "Certificate")]
     pub certificate: BinaryData,
 }
@@ -4161,11 +4208,11 @@ impl Validate for Certificate {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct CertificateStatus {
-    // Certificate id.
+    /// Certificate id.
     #[yaserde(prefix = "tt", rename = "CertificateID")]
     pub certificate_id: String,
 
-    // Indicates whether or not a certificate is used in a HTTPS configuration.
+    /// Indicates whether or not a certificate is used in a HTTPS configuration.
     #[yaserde(prefix = "tt", rename = "Status")]
     pub status: bool,
 }
@@ -4214,8 +4261,8 @@ pub struct CertificateInformation {
     #[yaserde(prefix = "tt", rename = "SerialNum")]
     pub serial_num: Option<String>,
 
-    // Validity Range is from "NotBefore" to "NotAfter"; the corresponding
-    // DateTimeRange is from "From" to "Until"
+    /// Validity Range is from "NotBefore" to "NotAfter"; the corresponding
+    /// DateTimeRange is from "From" to "Until"
     #[yaserde(prefix = "tt", rename = "SignatureAlgorithm")]
     pub signature_algorithm: Option<String>,
 
@@ -4255,7 +4302,7 @@ pub struct Dot1XConfiguration {
     #[yaserde(prefix = "tt", rename = "AnonymousID")]
     pub anonymous_id: Option<String>,
 
-    // EAP Method type as defined in
+    /// EAP Method type as defined in
     #[yaserde(prefix = "tt", rename = "EAPMethod")]
     pub eap_method: i32,
 
@@ -4280,12 +4327,12 @@ impl Validate for Dot1XConfigurationExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct EapmethodConfiguration {
-    // Confgiuration information for TLS Method.
+    /// Confgiuration information for TLS Method.
     #[yaserde(prefix = "tt", rename = "TLSConfiguration")]
     pub tls_configuration: Option<Tlsconfiguration>,
 
-    // Password for those EAP Methods that require a password. The password
-    // shall never be returned on a get method.
+    /// Password for those EAP Methods that require a password. The password
+    /// shall never be returned on a get method.
     #[yaserde(prefix = "tt", rename = "Password")]
     pub password: Option<String>,
 
@@ -4318,6 +4365,7 @@ impl Validate for GenericEapPwdConfigurationExtension {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum RelayLogicalState {
     #[yaserde(rename = "active")]
     Active,
@@ -4336,6 +4384,7 @@ impl Validate for RelayLogicalState {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum RelayIdleState {
     #[yaserde(rename = "closed")]
     Closed,
@@ -4355,17 +4404,17 @@ impl Validate for RelayIdleState {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct RelayOutputSettings {
-    // 'Bistable' or 'Monostable'
+    /// 'Bistable' or 'Monostable'
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: RelayMode,
 
-    // Time after which the relay returns to its idle state if it is in
-    // monostable mode. If the Mode field is set to bistable mode the value of
-    // the parameter can be ignored.
+    /// Time after which the relay returns to its idle state if it is in
+    /// monostable mode. If the Mode field is set to bistable mode the value of
+    /// the parameter can be ignored.
     #[yaserde(prefix = "tt", rename = "DelayTime")]
     pub delay_time: xs::Duration,
 
-    // 'open' or 'closed'
+    /// 'open' or 'closed'
     #[yaserde(prefix = "tt", rename = "IdleState")]
     pub idle_state: RelayIdleState,
 }
@@ -4374,6 +4423,7 @@ impl Validate for RelayOutputSettings {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum RelayMode {
     Monostable,
     Bistable,
@@ -4394,7 +4444,7 @@ pub struct RelayOutput {
     #[yaserde(prefix = "tt", rename = "Properties")]
     pub properties: RelayOutputSettings,
 
-    // Unique identifier referencing the physical entity.
+    /// Unique identifier referencing the physical entity.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
@@ -4403,6 +4453,7 @@ impl Validate for RelayOutput {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum DigitalIdleState {
     #[yaserde(rename = "closed")]
     Closed,
@@ -4422,11 +4473,11 @@ impl Validate for DigitalIdleState {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct DigitalInput {
-    // Indicate the Digital IdleState status.
+    /// Indicate the Digital IdleState status.
     #[yaserde(attribute, rename = "IdleState")]
     pub idle_state: Option<DigitalIdleState>,
 
-    // Unique identifier referencing the physical entity.
+    /// Unique identifier referencing the physical entity.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
@@ -4436,44 +4487,44 @@ impl Validate for DigitalInput {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Ptznode {
-    // A unique identifier that is used to reference PTZ Nodes.
+    /// A unique identifier that is used to reference PTZ Nodes.
     #[yaserde(prefix = "tt", rename = "Name")]
     pub name: Option<Name>,
 
-    // A list of Coordinate Systems available for the PTZ Node. For each
-    // Coordinate System, the PTZ Node MUST specify its allowed range.
+    /// A list of Coordinate Systems available for the PTZ Node. For each
+    /// Coordinate System, the PTZ Node MUST specify its allowed range.
     #[yaserde(prefix = "tt", rename = "SupportedPTZSpaces")]
     pub supported_ptz_spaces: Ptzspaces,
 
-    // All preset operations MUST be available for this PTZ Node if one preset
-    // is supported.
+    /// All preset operations MUST be available for this PTZ Node if one preset
+    /// is supported.
     #[yaserde(prefix = "tt", rename = "MaximumNumberOfPresets")]
     pub maximum_number_of_presets: i32,
 
-    // A boolean operator specifying the availability of a home position. If set
-    // to true, the Home Position Operations MUST be available for this PTZ
-    // Node.
+    /// A boolean operator specifying the availability of a home position. If set
+    /// to true, the Home Position Operations MUST be available for this PTZ
+    /// Node.
     #[yaserde(prefix = "tt", rename = "HomeSupported")]
     pub home_supported: bool,
 
-    // A list of supported Auxiliary commands. If the list is not empty, the
-    // Auxiliary Operations MUST be available for this PTZ Node.
+    /// A list of supported Auxiliary commands. If the list is not empty, the
+    /// Auxiliary Operations MUST be available for this PTZ Node.
     #[yaserde(prefix = "tt", rename = "AuxiliaryCommands")]
     pub auxiliary_commands: Vec<AuxiliaryData>,
 
     #[yaserde(prefix = "tt", rename = "Extension")]
     pub extension: Option<PtznodeExtension>,
 
-    // Indication whether the HomePosition of a Node is fixed or it can be
-    // changed via the SetHomePosition command.
+    /// Indication whether the HomePosition of a Node is fixed or it can be
+    /// changed via the SetHomePosition command.
     #[yaserde(attribute, rename = "FixedHomePosition")]
     pub fixed_home_position: Option<bool>,
 
-    // Indication whether the Node supports the geo-referenced move command.
+    /// Indication whether the Node supports the geo-referenced move command.
     #[yaserde(attribute, rename = "GeoMove")]
     pub geo_move: Option<bool>,
 
-    // Unique identifier referencing the physical entity.
+    /// Unique identifier referencing the physical entity.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
@@ -4483,13 +4534,14 @@ impl Validate for Ptznode {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct PtznodeExtension {
-    // Detail of supported Preset Tour feature.
+    /// Detail of supported Preset Tour feature.
     #[yaserde(prefix = "tt", rename = "SupportedPresetTour")]
     pub supported_preset_tour: Option<PtzpresetTourSupported>,
-    // `Extension` inside `Extension` causes infinite loop at deserialization
-    // https://github.com/media-io/yaserde/issues/76
-    // #[yaserde(prefix = "tt", rename = "Extension")]
-    // pub extension: Option<PtznodeExtension2>,
+    /// `Extension` inside `Extension` causes infinite loop at deserialization
+    /// https://github.com/media-io/yaserde/issues/76
+    /// so it's kept as raw XML.
+    #[yaserde(prefix = "tt", rename = "Extension")]
+    pub extension: Option<RawXml>,
 }
 
 impl Validate for PtznodeExtension {}
@@ -4503,13 +4555,13 @@ impl Validate for PtznodeExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct PtzpresetTourSupported {
-    // Indicates number of preset tours that can be created. Required preset
-    // tour operations shall be available for this PTZ Node if one or more
-    // preset tour is supported.
+    /// Indicates number of preset tours that can be created. Required preset
+    /// tour operations shall be available for this PTZ Node if one or more
+    /// preset tour is supported.
     #[yaserde(prefix = "tt", rename = "MaximumNumberOfPresetTours")]
     pub maximum_number_of_preset_tours: i32,
 
-    // Indicates which preset tour operations are available for this PTZ Node.
+    /// Indicates which preset tour operations are available for this PTZ Node.
     #[yaserde(prefix = "tt", rename = "PTZPresetTourOperation")]
     pub ptz_preset_tour_operation: Vec<PtzpresetTourOperation>,
 
@@ -4528,93 +4580,93 @@ impl Validate for PtzpresetTourSupportedExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Ptzconfiguration {
-    // A mandatory reference to the PTZ Node that the PTZ Configuration belongs
-    // to.
+    /// A mandatory reference to the PTZ Node that the PTZ Configuration belongs
+    /// to.
     #[yaserde(prefix = "tt", rename = "NodeToken")]
     pub node_token: ReferenceToken,
 
-    // If the PTZ Node supports absolute Pan/Tilt movements, it shall specify
-    // one Absolute Pan/Tilt Position Space as default.
+    /// If the PTZ Node supports absolute Pan/Tilt movements, it shall specify
+    /// one Absolute Pan/Tilt Position Space as default.
     #[yaserde(prefix = "tt", rename = "DefaultAbsolutePantTiltPositionSpace")]
     pub default_absolute_pant_tilt_position_space: Option<String>,
 
-    // If the PTZ Node supports absolute zoom movements, it shall specify one
-    // Absolute Zoom Position Space as default.
+    /// If the PTZ Node supports absolute zoom movements, it shall specify one
+    /// Absolute Zoom Position Space as default.
     #[yaserde(prefix = "tt", rename = "DefaultAbsoluteZoomPositionSpace")]
     pub default_absolute_zoom_position_space: Option<String>,
 
-    // If the PTZ Node supports relative Pan/Tilt movements, it shall specify
-    // one RelativePan/Tilt Translation Space as default.
+    /// If the PTZ Node supports relative Pan/Tilt movements, it shall specify
+    /// one RelativePan/Tilt Translation Space as default.
     #[yaserde(prefix = "tt", rename = "DefaultRelativePanTiltTranslationSpace")]
     pub default_relative_pan_tilt_translation_space: Option<String>,
 
-    // If the PTZ Node supports relative zoom movements, it shall specify one
-    // Relative Zoom Translation Space as default.
+    /// If the PTZ Node supports relative zoom movements, it shall specify one
+    /// Relative Zoom Translation Space as default.
     #[yaserde(prefix = "tt", rename = "DefaultRelativeZoomTranslationSpace")]
     pub default_relative_zoom_translation_space: Option<String>,
 
-    // If the PTZ Node supports continuous Pan/Tilt movements, it shall specify
-    // one Continuous Pan/Tilt Velocity Space as default.
+    /// If the PTZ Node supports continuous Pan/Tilt movements, it shall specify
+    /// one Continuous Pan/Tilt Velocity Space as default.
     #[yaserde(prefix = "tt", rename = "DefaultContinuousPanTiltVelocitySpace")]
     pub default_continuous_pan_tilt_velocity_space: Option<String>,
 
-    // If the PTZ Node supports continuous zoom movements, it shall specify one
-    // Continuous Zoom Velocity Space as default.
+    /// If the PTZ Node supports continuous zoom movements, it shall specify one
+    /// Continuous Zoom Velocity Space as default.
     #[yaserde(prefix = "tt", rename = "DefaultContinuousZoomVelocitySpace")]
     pub default_continuous_zoom_velocity_space: Option<String>,
 
-    // If the PTZ Node supports absolute or relative PTZ movements, it shall
-    // specify corresponding default Pan/Tilt and Zoom speeds.
+    /// If the PTZ Node supports absolute or relative PTZ movements, it shall
+    /// specify corresponding default Pan/Tilt and Zoom speeds.
     #[yaserde(prefix = "tt", rename = "DefaultPTZSpeed")]
     pub default_ptz_speed: Option<Ptzspeed>,
 
-    // If the PTZ Node supports continuous movements, it shall specify a default
-    // timeout, after which the movement stops.
+    /// If the PTZ Node supports continuous movements, it shall specify a default
+    /// timeout, after which the movement stops.
     #[yaserde(prefix = "tt", rename = "DefaultPTZTimeout")]
     pub default_ptz_timeout: Option<xs::Duration>,
 
-    // The Pan/Tilt limits element should be present for a PTZ Node that
-    // supports an absolute Pan/Tilt. If the element is present it signals the
-    // support for configurable Pan/Tilt limits. If limits are enabled, the
-    // Pan/Tilt movements shall always stay within the specified range. The
-    // Pan/Tilt limits are disabled by setting the limits to –INF or +INF.
+    /// The Pan/Tilt limits element should be present for a PTZ Node that
+    /// supports an absolute Pan/Tilt. If the element is present it signals the
+    /// support for configurable Pan/Tilt limits. If limits are enabled, the
+    /// Pan/Tilt movements shall always stay within the specified range. The
+    /// Pan/Tilt limits are disabled by setting the limits to –INF or +INF.
     #[yaserde(prefix = "tt", rename = "PanTiltLimits")]
     pub pan_tilt_limits: Option<PanTiltLimits>,
 
-    // The Zoom limits element should be present for a PTZ Node that supports
-    // absolute zoom. If the element is present it signals the supports for
-    // configurable Zoom limits. If limits are enabled the zoom movements shall
-    // always stay within the specified range. The Zoom limits are disabled by
-    // settings the limits to -INF and +INF.
+    /// The Zoom limits element should be present for a PTZ Node that supports
+    /// absolute zoom. If the element is present it signals the supports for
+    /// configurable Zoom limits. If limits are enabled the zoom movements shall
+    /// always stay within the specified range. The Zoom limits are disabled by
+    /// settings the limits to -INF and +INF.
     #[yaserde(prefix = "tt", rename = "ZoomLimits")]
     pub zoom_limits: Option<ZoomLimits>,
 
     #[yaserde(prefix = "tt", rename = "Extension")]
     pub extension: Option<PtzconfigurationExtension>,
 
-    // The optional acceleration ramp used by the device when moving.
+    /// The optional acceleration ramp used by the device when moving.
     #[yaserde(attribute, rename = "MoveRamp")]
     pub move_ramp: Option<i32>,
 
-    // The optional acceleration ramp used by the device when recalling presets.
+    /// The optional acceleration ramp used by the device when recalling presets.
     #[yaserde(attribute, rename = "PresetRamp")]
     pub preset_ramp: Option<i32>,
 
-    // The optional acceleration ramp used by the device when executing
-    // PresetTours.
+    /// The optional acceleration ramp used by the device when executing
+    /// PresetTours.
     #[yaserde(attribute, rename = "PresetTourRamp")]
     pub preset_tour_ramp: Option<i32>,
 
-    // User readable name. Length up to 64 characters.
+    /// User readable name. Length up to 64 characters.
     #[yaserde(prefix = "tt", rename = "Name")]
     pub name: Name,
 
-    // Number of internal references currently using this configuration.
+    /// Number of internal references currently using this configuration.
     #[yaserde(prefix = "tt", rename = "UseCount")]
     pub use_count: i32,
 
-    // Token that uniquely references this configuration. Length up to 64
-    // characters.
+    /// Token that uniquely references this configuration. Length up to 64
+    /// characters.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
@@ -4624,13 +4676,14 @@ impl Validate for Ptzconfiguration {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct PtzconfigurationExtension {
-    // Optional element to configure PT Control Direction related features.
+    /// Optional element to configure PT Control Direction related features.
     #[yaserde(prefix = "tt", rename = "PTControlDirection")]
     pub pt_control_direction: Option<PtcontrolDirection>,
-    // `Extension` inside `Extension` causes infinite loop at deserialization
-    // https://github.com/media-io/yaserde/issues/76
-    // #[yaserde(prefix = "tt", rename = "Extension")]
-    // pub extension: Option<PtzconfigurationExtension2>,
+    /// `Extension` inside `Extension` causes infinite loop at deserialization
+    /// https://github.com/media-io/yaserde/issues/76
+    /// so it's kept as raw XML.
+    #[yaserde(prefix = "tt", rename = "Extension")]
+    pub extension: Option<RawXml>,
 }
 
 impl Validate for PtzconfigurationExtension {}
@@ -4644,12 +4697,12 @@ impl Validate for PtzconfigurationExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct PtcontrolDirection {
-    // Optional element to configure related parameters for E-Flip.
+    /// Optional element to configure related parameters for E-Flip.
     #[yaserde(prefix = "tt", rename = "EFlip")]
     pub e_flip: Option<Eflip>,
 
-    // Optional element to configure related parameters for reversing of PT
-    // Control Direction.
+    /// Optional element to configure related parameters for reversing of PT
+    /// Control Direction.
     #[yaserde(prefix = "tt", rename = "Reverse")]
     pub reverse: Option<Reverse>,
 
@@ -4668,7 +4721,7 @@ impl Validate for PtcontrolDirectionExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Eflip {
-    // Parameter to enable/disable E-Flip feature.
+    /// Parameter to enable/disable E-Flip feature.
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: EflipMode,
 }
@@ -4678,7 +4731,7 @@ impl Validate for Eflip {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Reverse {
-    // Parameter to enable/disable Reverse feature.
+    /// Parameter to enable/disable Reverse feature.
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: ReverseMode,
 }
@@ -4687,6 +4740,7 @@ impl Validate for Reverse {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum EflipMode {
     #[yaserde(rename = "OFF")]
     Off,
@@ -4706,6 +4760,7 @@ impl Validate for EflipMode {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum ReverseMode {
     #[yaserde(rename = "OFF")]
     Off,
@@ -4728,24 +4783,24 @@ impl Validate for ReverseMode {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct PtzconfigurationOptions {
-    // A list of supported coordinate systems including their range limitations.
+    /// A list of supported coordinate systems including their range limitations.
     #[yaserde(prefix = "tt", rename = "Spaces")]
     pub spaces: Ptzspaces,
 
-    // A timeout Range within which Timeouts are accepted by the PTZ Node.
+    /// A timeout Range within which Timeouts are accepted by the PTZ Node.
     #[yaserde(prefix = "tt", rename = "PTZTimeout")]
     pub ptz_timeout: DurationRange,
 
-    // Supported options for PT Direction Control.
+    /// Supported options for PT Direction Control.
     #[yaserde(prefix = "tt", rename = "PTControlDirection")]
     pub pt_control_direction: Option<PtcontrolDirectionOptions>,
 
     #[yaserde(prefix = "tt", rename = "Extension")]
     pub extension: Option<PtzconfigurationOptions2>,
 
-    // The list of acceleration ramps supported by the device. The
-    // smallest acceleration value corresponds to the minimal index, the
-    // highest acceleration corresponds to the maximum index.
+    /// The list of acceleration ramps supported by the device. The
+    /// smallest acceleration value corresponds to the minimal index, the
+    /// highest acceleration corresponds to the maximum index.
     #[yaserde(attribute, rename = "PTZRamps")]
     pub ptz_ramps: Option<IntAttrList>,
 }
@@ -4761,11 +4816,11 @@ impl Validate for PtzconfigurationOptions2 {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct PtcontrolDirectionOptions {
-    // Supported options for EFlip feature.
+    /// Supported options for EFlip feature.
     #[yaserde(prefix = "tt", rename = "EFlip")]
     pub e_flip: Option<EflipOptions>,
 
-    // Supported options for Reverse feature.
+    /// Supported options for Reverse feature.
     #[yaserde(prefix = "tt", rename = "Reverse")]
     pub reverse: Option<ReverseOptions>,
 
@@ -4784,7 +4839,7 @@ impl Validate for PtcontrolDirectionOptionsExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct EflipOptions {
-    // Options of EFlip mode parameter.
+    /// Options of EFlip mode parameter.
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: Vec<EflipMode>,
 
@@ -4803,7 +4858,7 @@ impl Validate for EflipOptionsExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ReverseOptions {
-    // Options of Reverse mode parameter.
+    /// Options of Reverse mode parameter.
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: Vec<ReverseMode>,
 
@@ -4822,7 +4877,7 @@ impl Validate for ReverseOptionsExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct PanTiltLimits {
-    // A range of pan tilt limits.
+    /// A range of pan tilt limits.
     #[yaserde(prefix = "tt", rename = "Range")]
     pub range: Space2DDescription,
 }
@@ -4832,7 +4887,7 @@ impl Validate for PanTiltLimits {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ZoomLimits {
-    // A range of zoom limit
+    /// A range of zoom limit
     #[yaserde(prefix = "tt", rename = "Range")]
     pub range: Space1DDescription,
 }
@@ -4842,81 +4897,81 @@ impl Validate for ZoomLimits {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Ptzspaces {
-    // The Generic Pan/Tilt Position space is provided by every PTZ node that
-    // supports absolute Pan/Tilt, since it does not relate to a specific
-    // physical range.
-    // Instead, the range should be defined as the full range of the PTZ unit
-    // normalized to the range -1 to 1 resulting in the following space
-    // description.
+    /// The Generic Pan/Tilt Position space is provided by every PTZ node that
+    /// supports absolute Pan/Tilt, since it does not relate to a specific
+    /// physical range.
+    /// Instead, the range should be defined as the full range of the PTZ unit
+    /// normalized to the range -1 to 1 resulting in the following space
+    /// description.
     #[yaserde(prefix = "tt", rename = "AbsolutePanTiltPositionSpace")]
     pub absolute_pan_tilt_position_space: Vec<Space2DDescription>,
 
-    // The Generic Zoom Position Space is provided by every PTZ node that
-    // supports absolute Zoom, since it does not relate to a specific physical
-    // range.
-    // Instead, the range should be defined as the full range of the Zoom
-    // normalized to the range 0 (wide) to 1 (tele).
-    // There is no assumption about how the generic zoom range is mapped to
-    // magnification, FOV or other physical zoom dimension.
+    /// The Generic Zoom Position Space is provided by every PTZ node that
+    /// supports absolute Zoom, since it does not relate to a specific physical
+    /// range.
+    /// Instead, the range should be defined as the full range of the Zoom
+    /// normalized to the range 0 (wide) to 1 (tele).
+    /// There is no assumption about how the generic zoom range is mapped to
+    /// magnification, FOV or other physical zoom dimension.
     #[yaserde(prefix = "tt", rename = "AbsoluteZoomPositionSpace")]
     pub absolute_zoom_position_space: Vec<Space1DDescription>,
 
-    // The Generic Pan/Tilt translation space is provided by every PTZ node that
-    // supports relative Pan/Tilt, since it does not relate to a specific
-    // physical range.
-    // Instead, the range should be defined as the full positive and negative
-    // translation range of the PTZ unit normalized to the range -1 to 1,
-    // where positive translation would mean clockwise rotation or movement in
-    // right/up direction resulting in the following space description.
+    /// The Generic Pan/Tilt translation space is provided by every PTZ node that
+    /// supports relative Pan/Tilt, since it does not relate to a specific
+    /// physical range.
+    /// Instead, the range should be defined as the full positive and negative
+    /// translation range of the PTZ unit normalized to the range -1 to 1,
+    /// where positive translation would mean clockwise rotation or movement in
+    /// right/up direction resulting in the following space description.
     #[yaserde(prefix = "tt", rename = "RelativePanTiltTranslationSpace")]
     pub relative_pan_tilt_translation_space: Vec<Space2DDescription>,
 
-    // The Generic Zoom Translation Space is provided by every PTZ node that
-    // supports relative Zoom, since it does not relate to a specific physical
-    // range.
-    // Instead, the corresponding absolute range should be defined as the full
-    // positive and negative translation range of the Zoom normalized to the
-    // range -1 to1,
-    // where a positive translation maps to a movement in TELE direction. The
-    // translation is signed to indicate direction (negative is to wide,
-    // positive is to tele).
-    // There is no assumption about how the generic zoom range is mapped to
-    // magnification, FOV or other physical zoom dimension. This results in the
-    // following space description.
+    /// The Generic Zoom Translation Space is provided by every PTZ node that
+    /// supports relative Zoom, since it does not relate to a specific physical
+    /// range.
+    /// Instead, the corresponding absolute range should be defined as the full
+    /// positive and negative translation range of the Zoom normalized to the
+    /// range -1 to1,
+    /// where a positive translation maps to a movement in TELE direction. The
+    /// translation is signed to indicate direction (negative is to wide,
+    /// positive is to tele).
+    /// There is no assumption about how the generic zoom range is mapped to
+    /// magnification, FOV or other physical zoom dimension. This results in the
+    /// following space description.
     #[yaserde(prefix = "tt", rename = "RelativeZoomTranslationSpace")]
     pub relative_zoom_translation_space: Vec<Space1DDescription>,
 
-    // The generic Pan/Tilt velocity space shall be provided by every PTZ node,
-    // since it does not relate to a specific physical range.
-    // Instead, the range should be defined as a range of the PTZ unit’s speed
-    // normalized to the range -1 to 1, where a positive velocity would map to
-    // clockwise
-    // rotation or movement in the right/up direction. A signed speed can be
-    // independently specified for the pan and tilt component resulting in the
-    // following space description.
+    /// The generic Pan/Tilt velocity space shall be provided by every PTZ node,
+    /// since it does not relate to a specific physical range.
+    /// Instead, the range should be defined as a range of the PTZ unit’s speed
+    /// normalized to the range -1 to 1, where a positive velocity would map to
+    /// clockwise
+    /// rotation or movement in the right/up direction. A signed speed can be
+    /// independently specified for the pan and tilt component resulting in the
+    /// following space description.
     #[yaserde(prefix = "tt", rename = "ContinuousPanTiltVelocitySpace")]
     pub continuous_pan_tilt_velocity_space: Vec<Space2DDescription>,
 
-    // The generic zoom velocity space specifies a zoom factor velocity without
-    // knowing the underlying physical model. The range should be normalized
-    // from -1 to 1,
-    // where a positive velocity would map to TELE direction. A generic zoom
-    // velocity space description resembles the following.
+    /// The generic zoom velocity space specifies a zoom factor velocity without
+    /// knowing the underlying physical model. The range should be normalized
+    /// from -1 to 1,
+    /// where a positive velocity would map to TELE direction. A generic zoom
+    /// velocity space description resembles the following.
     #[yaserde(prefix = "tt", rename = "ContinuousZoomVelocitySpace")]
     pub continuous_zoom_velocity_space: Vec<Space1DDescription>,
 
-    // The speed space specifies the speed for a Pan/Tilt movement when moving
-    // to an absolute position or to a relative translation.
-    // In contrast to the velocity spaces, speed spaces do not contain any
-    // directional information. The speed of a combined Pan/Tilt
-    // movement is represented by a single non-negative scalar value.
+    /// The speed space specifies the speed for a Pan/Tilt movement when moving
+    /// to an absolute position or to a relative translation.
+    /// In contrast to the velocity spaces, speed spaces do not contain any
+    /// directional information. The speed of a combined Pan/Tilt
+    /// movement is represented by a single non-negative scalar value.
     #[yaserde(prefix = "tt", rename = "PanTiltSpeedSpace")]
     pub pan_tilt_speed_space: Vec<Space1DDescription>,
 
-    // The speed space specifies the speed for a Zoom movement when moving to an
-    // absolute position or to a relative translation.
-    // In contrast to the velocity spaces, speed spaces do not contain any
-    // directional information.
+    /// The speed space specifies the speed for a Zoom movement when moving to an
+    /// absolute position or to a relative translation.
+    /// In contrast to the velocity spaces, speed spaces do not contain any
+    /// directional information.
     #[yaserde(prefix = "tt", rename = "ZoomSpeedSpace")]
     pub zoom_speed_space: Vec<Space1DDescription>,
 
@@ -4935,15 +4990,15 @@ impl Validate for PtzspacesExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Space2DDescription {
-    // A URI of coordinate systems.
+    /// A URI of coordinate systems.
     #[yaserde(prefix = "tt", rename = "URI")]
     pub uri: String,
 
-    // A range of x-axis.
+    /// A range of x-axis.
     #[yaserde(prefix = "tt", rename = "XRange")]
     pub x_range: FloatRange,
 
-    // A range of y-axis.
+    /// A range of y-axis.
     #[yaserde(prefix = "tt", rename = "YRange")]
     pub y_range: FloatRange,
 }
@@ -4953,11 +5008,11 @@ impl Validate for Space2DDescription {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Space1DDescription {
-    // A URI of coordinate systems.
+    /// A URI of coordinate systems.
     #[yaserde(prefix = "tt", rename = "URI")]
     pub uri: String,
 
-    // A range of x-axis.
+    /// A range of x-axis.
     #[yaserde(prefix = "tt", rename = "XRange")]
     pub x_range: FloatRange,
 }
@@ -4967,14 +5022,14 @@ impl Validate for Space1DDescription {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Ptzspeed {
-    // Pan and tilt speed. The x component corresponds to pan and the y
-    // component to tilt. If omitted in a request, the current (if any) PanTilt
-    // movement should not be affected.
+    /// Pan and tilt speed. The x component corresponds to pan and the y
+    /// component to tilt. If omitted in a request, the current (if any) PanTilt
+    /// movement should not be affected.
     #[yaserde(prefix = "tt", rename = "PanTilt")]
     pub pan_tilt: Option<Vector2D>,
 
-    // A zoom speed. If omitted in a request, the current (if any) Zoom movement
-    // should not be affected.
+    /// A zoom speed. If omitted in a request, the current (if any) Zoom movement
+    /// should not be affected.
     #[yaserde(prefix = "tt", rename = "Zoom")]
     pub zoom: Option<Vector1D>,
 }
@@ -4984,11 +5039,11 @@ impl Validate for Ptzspeed {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Ptzpreset {
-    // A list of preset position name.
+    /// A list of preset position name.
     #[yaserde(prefix = "tt", rename = "Name")]
     pub name: Option<Name>,
 
-    // A list of preset position.
+    /// A list of preset position.
     #[yaserde(prefix = "tt", rename = "PTZPosition")]
     pub ptz_position: Option<Ptzvector>,
 
@@ -5015,6 +5070,7 @@ impl Validate for AuxiliaryData {
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum PtzpresetTourState {
     Idle,
     Touring,
@@ -5033,6 +5089,7 @@ impl Validate for PtzpresetTourState {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum PtzpresetTourDirection {
     Forward,
     Backward,
@@ -5050,6 +5107,7 @@ impl Validate for PtzpresetTourDirection {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum PtzpresetTourOperation {
     Start,
     Stop,
@@ -5069,31 +5127,31 @@ impl Validate for PtzpresetTourOperation {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct PresetTour {
-    // Readable name of the preset tour.
+    /// Readable name of the preset tour.
     #[yaserde(prefix = "tt", rename = "Name")]
     pub name: Option<Name>,
 
-    // Read only parameters to indicate the status of the preset tour.
+    /// Read only parameters to indicate the status of the preset tour.
     #[yaserde(prefix = "tt", rename = "Status")]
     pub status: PtzpresetTourStatus,
 
-    // Auto Start flag of the preset tour. True allows the preset tour to be
-    // activated always.
+    /// Auto Start flag of the preset tour. True allows the preset tour to be
+    /// activated always.
     #[yaserde(prefix = "tt", rename = "AutoStart")]
     pub auto_start: bool,
 
-    // Parameters to specify the detail behavior of the preset tour.
+    /// Parameters to specify the detail behavior of the preset tour.
     #[yaserde(prefix = "tt", rename = "StartingCondition")]
     pub starting_condition: PtzpresetTourStartingCondition,
 
-    // A list of detail of touring spots including preset positions.
+    /// A list of detail of touring spots including preset positions.
     #[yaserde(prefix = "tt", rename = "TourSpot")]
     pub tour_spot: Vec<PtzpresetTourSpot>,
 
     #[yaserde(prefix = "tt", rename = "Extension")]
     pub extension: Option<PtzpresetTourExtension>,
 
-    // Unique identifier of this preset tour.
+    /// Unique identifier of this preset tour.
     #[yaserde(attribute, rename = "token")]
     pub token: Option<ReferenceToken>,
 }
@@ -5109,17 +5167,17 @@ impl Validate for PtzpresetTourExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct PtzpresetTourSpot {
-    // Detail definition of preset position of the tour spot.
+    /// Detail definition of preset position of the tour spot.
     #[yaserde(prefix = "tt", rename = "PresetDetail")]
     pub preset_detail: PtzpresetTourPresetDetail,
 
-    // Optional parameter to specify Pan/Tilt and Zoom speed on moving toward
-    // this tour spot.
+    /// Optional parameter to specify Pan/Tilt and Zoom speed on moving toward
+    /// this tour spot.
     #[yaserde(prefix = "tt", rename = "Speed")]
     pub speed: Option<Ptzspeed>,
 
-    // Optional parameter to specify time duration of staying on this tour
-    // sport.
+    /// Optional parameter to specify time duration of staying on this tour
+    /// sport.
     #[yaserde(prefix = "tt", rename = "StayTime")]
     pub stay_time: Option<xs::Duration>,
 
@@ -5150,14 +5208,15 @@ pub mod ptz_preset_tour_preset_detail {
 
     #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
     #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+    #[non_exhaustive]
     pub enum PtzpresetTourPresetDetailChoice {
-        // Option to specify the preset position with Preset Token defined in
-        // advance.
+        /// Option to specify the preset position with Preset Token defined in
+        /// advance.
         PresetToken(ReferenceToken),
-        // Option to specify the preset position with the home position of this PTZ
-        // Node. "False" to this parameter shall be treated as an invalid argument.
+        /// Option to specify the preset position with the home position of this PTZ
+        /// Node. "False" to this parameter shall be treated as an invalid argument.
         Home(bool),
-        // Option to specify the preset position with vector of PTZ node directly.
+        /// Option to specify the preset position with vector of PTZ node directly.
         #[yaserde(rename = "PTZPosition")]
         Ptzposition(Ptzvector),
         TypeExtension(PtzpresetTourTypeExtension),
@@ -5182,11 +5241,11 @@ impl Validate for PtzpresetTourTypeExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct PtzpresetTourStatus {
-    // Indicates state of this preset tour by Idle/Touring/Paused.
+    /// Indicates state of this preset tour by Idle/Touring/Paused.
     #[yaserde(prefix = "tt", rename = "State")]
     pub state: PtzpresetTourState,
 
-    // Indicates a tour spot currently staying.
+    /// Indicates a tour spot currently staying.
     #[yaserde(prefix = "tt", rename = "CurrentTourSpot")]
     pub current_tour_spot: Option<PtzpresetTourSpot>,
 
@@ -5205,26 +5264,26 @@ impl Validate for PtzpresetTourStatusExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct PtzpresetTourStartingCondition {
-    // Optional parameter to specify how many times the preset tour is recurred.
+    /// Optional parameter to specify how many times the preset tour is recurred.
     #[yaserde(prefix = "tt", rename = "RecurringTime")]
     pub recurring_time: Option<i32>,
 
-    // Optional parameter to specify how long time duration the preset tour is
-    // recurred.
+    /// Optional parameter to specify how long time duration the preset tour is
+    /// recurred.
     #[yaserde(prefix = "tt", rename = "RecurringDuration")]
     pub recurring_duration: Option<xs::Duration>,
 
-    // Optional parameter to choose which direction the preset tour goes.
-    // Forward shall be chosen in case it is omitted.
+    /// Optional parameter to choose which direction the preset tour goes.
+    /// Forward shall be chosen in case it is omitted.
     #[yaserde(prefix = "tt", rename = "Direction")]
     pub direction: Option<PtzpresetTourDirection>,
 
     #[yaserde(prefix = "tt", rename = "Extension")]
     pub extension: Option<PtzpresetTourStartingConditionExtension>,
 
-    // Execute presets in random order. If set to true and Direction is also
-    // present, Direction will be ignored and presets of the Tour will be
-    // recalled randomly.
+    /// Execute presets in random order. If set to true and Direction is also
+    /// present, Direction will be ignored and presets of the Tour will be
+    /// recalled randomly.
     #[yaserde(attribute, rename = "RandomPresetOrder")]
     pub random_preset_order: Option<bool>,
 }
@@ -5240,15 +5299,15 @@ impl Validate for PtzpresetTourStartingConditionExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct PtzpresetTourOptions {
-    // Indicates whether or not the AutoStart is supported.
+    /// Indicates whether or not the AutoStart is supported.
     #[yaserde(prefix = "tt", rename = "AutoStart")]
     pub auto_start: bool,
 
-    // Supported options for Preset Tour Starting Condition.
+    /// Supported options for Preset Tour Starting Condition.
     #[yaserde(prefix = "tt", rename = "StartingCondition")]
     pub starting_condition: PtzpresetTourStartingConditionOptions,
 
-    // Supported options for Preset Tour Spot.
+    /// Supported options for Preset Tour Spot.
     #[yaserde(prefix = "tt", rename = "TourSpot")]
     pub tour_spot: PtzpresetTourSpotOptions,
 }
@@ -5258,12 +5317,12 @@ impl Validate for PtzpresetTourOptions {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct PtzpresetTourSpotOptions {
-    // Supported options for detail definition of preset position of the tour
-    // spot.
+    /// Supported options for detail definition of preset position of the tour
+    /// spot.
     #[yaserde(prefix = "tt", rename = "PresetDetail")]
     pub preset_detail: PtzpresetTourPresetDetailOptions,
 
-    // Supported range of stay time for a tour spot.
+    /// Supported range of stay time for a tour spot.
     #[yaserde(prefix = "tt", rename = "StayTime")]
     pub stay_time: DurationRange,
 }
@@ -5273,19 +5332,19 @@ impl Validate for PtzpresetTourSpotOptions {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct PtzpresetTourPresetDetailOptions {
-    // A list of available Preset Tokens for tour spots.
+    /// A list of available Preset Tokens for tour spots.
     #[yaserde(prefix = "tt", rename = "PresetToken")]
     pub preset_token: Vec<ReferenceToken>,
 
-    // An option to indicate Home postion for tour spots.
+    /// An option to indicate Home postion for tour spots.
     #[yaserde(prefix = "tt", rename = "Home")]
     pub home: Option<bool>,
 
-    // Supported range of Pan and Tilt for tour spots.
+    /// Supported range of Pan and Tilt for tour spots.
     #[yaserde(prefix = "tt", rename = "PanTiltPositionSpace")]
     pub pan_tilt_position_space: Option<Space2DDescription>,
 
-    // Supported range of Zoom for a tour spot.
+    /// Supported range of Zoom for a tour spot.
     #[yaserde(prefix = "tt", rename = "ZoomPositionSpace")]
     pub zoom_position_space: Option<Space1DDescription>,
 
@@ -5304,15 +5363,15 @@ impl Validate for PtzpresetTourPresetDetailOptionsExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct PtzpresetTourStartingConditionOptions {
-    // Supported range of Recurring Time.
+    /// Supported range of Recurring Time.
     #[yaserde(prefix = "tt", rename = "RecurringTime")]
     pub recurring_time: Option<IntRange>,
 
-    // Supported range of Recurring Duration.
+    /// Supported range of Recurring Duration.
     #[yaserde(prefix = "tt", rename = "RecurringDuration")]
     pub recurring_duration: Option<DurationRange>,
 
-    // Supported options for Direction of Preset Tour.
+    /// Supported options for Direction of Preset Tour.
     #[yaserde(prefix = "tt", rename = "Direction")]
     pub direction: Vec<PtzpresetTourDirection>,
 
@@ -5340,15 +5399,15 @@ impl Validate for ImagingStatus {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct FocusStatus {
-    // Status of focus position.
+    /// Status of focus position.
     #[yaserde(prefix = "tt", rename = "Position")]
     pub position: f64,
 
-    // Status of focus MoveStatus.
+    /// Status of focus MoveStatus.
     #[yaserde(prefix = "tt", rename = "MoveStatus")]
     pub move_status: MoveStatus,
 
-    // Error status of focus.
+    /// Error status of focus.
     #[yaserde(prefix = "tt", rename = "Error")]
     pub error: String,
 }
@@ -5364,12 +5423,12 @@ pub struct FocusConfiguration {
     #[yaserde(prefix = "tt", rename = "DefaultSpeed")]
     pub default_speed: f64,
 
-    // Parameter to set autofocus near limit (unit: meter).
+    /// Parameter to set autofocus near limit (unit: meter).
     #[yaserde(prefix = "tt", rename = "NearLimit")]
     pub near_limit: f64,
 
-    // Parameter to set autofocus far limit (unit: meter).
-    // If set to 0.0, infinity will be used.
+    /// Parameter to set autofocus far limit (unit: meter).
+    /// If set to 0.0, infinity will be used.
     #[yaserde(prefix = "tt", rename = "FarLimit")]
     pub far_limit: f64,
 }
@@ -5378,6 +5437,7 @@ impl Validate for FocusConfiguration {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum AutoFocusMode {
     #[yaserde(rename = "AUTO")]
     Auto,
@@ -5396,9 +5456,10 @@ impl Validate for AutoFocusMode {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum Afmodes {
-    // Focus of a moving camera is updated only once after stopping a pan, tilt
-    // or zoom movement.
+    /// Focus of a moving camera is updated only once after stopping a pan, tilt
+    /// or zoom movement.
     OnceAfterMove,
     __Unknown__(String),
 }
@@ -5414,43 +5475,43 @@ impl Validate for Afmodes {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ImagingSettings {
-    // Enabled/disabled BLC mode (on/off).
+    /// Enabled/disabled BLC mode (on/off).
     #[yaserde(prefix = "tt", rename = "BacklightCompensation")]
     pub backlight_compensation: Option<BacklightCompensation>,
 
-    // Image brightness (unit unspecified).
+    /// Image brightness (unit unspecified).
     #[yaserde(prefix = "tt", rename = "Brightness")]
     pub brightness: Option<f64>,
 
-    // Color saturation of the image (unit unspecified).
+    /// Color saturation of the image (unit unspecified).
     #[yaserde(prefix = "tt", rename = "ColorSaturation")]
     pub color_saturation: Option<f64>,
 
-    // Contrast of the image (unit unspecified).
+    /// Contrast of the image (unit unspecified).
     #[yaserde(prefix = "tt", rename = "Contrast")]
     pub contrast: Option<f64>,
 
-    // Exposure mode of the device.
+    /// Exposure mode of the device.
     #[yaserde(prefix = "tt", rename = "Exposure")]
     pub exposure: Option<Exposure>,
 
-    // Focus configuration.
+    /// Focus configuration.
     #[yaserde(prefix = "tt", rename = "Focus")]
     pub focus: Option<FocusConfiguration>,
 
-    // Infrared Cutoff Filter settings.
+    /// Infrared Cutoff Filter settings.
     #[yaserde(prefix = "tt", rename = "IrCutFilter")]
     pub ir_cut_filter: Option<IrCutFilterMode>,
 
-    // Sharpness of the Video image.
+    /// Sharpness of the Video image.
     #[yaserde(prefix = "tt", rename = "Sharpness")]
     pub sharpness: Option<f64>,
 
-    // WDR settings.
+    /// WDR settings.
     #[yaserde(prefix = "tt", rename = "WideDynamicRange")]
     pub wide_dynamic_range: Option<WideDynamicRange>,
 
-    // White balance settings.
+    /// White balance settings.
     #[yaserde(prefix = "tt", rename = "WhiteBalance")]
     pub white_balance: Option<WhiteBalance>,
 
@@ -5469,54 +5530,54 @@ impl Validate for ImagingSettingsExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Exposure {
-    // Exposure Mode
+    /// Exposure Mode
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: ExposureMode,
 
-    // The exposure priority mode (low noise/framerate).
+    /// The exposure priority mode (low noise/framerate).
     #[yaserde(prefix = "tt", rename = "Priority")]
     pub priority: ExposurePriority,
 
-    // Rectangular exposure mask.
+    /// Rectangular exposure mask.
     #[yaserde(prefix = "tt", rename = "Window")]
     pub window: Rectangle,
 
-    // Minimum value of exposure time range allowed to be used by the algorithm.
+    /// Minimum value of exposure time range allowed to be used by the algorithm.
     #[yaserde(prefix = "tt", rename = "MinExposureTime")]
     pub min_exposure_time: f64,
 
-    // Maximum value of exposure time range allowed to be used by the algorithm.
+    /// Maximum value of exposure time range allowed to be used by the algorithm.
     #[yaserde(prefix = "tt", rename = "MaxExposureTime")]
     pub max_exposure_time: f64,
 
-    // Minimum value of the sensor gain range that is allowed to be used by the
-    // algorithm.
+    /// Minimum value of the sensor gain range that is allowed to be used by the
+    /// algorithm.
     #[yaserde(prefix = "tt", rename = "MinGain")]
     pub min_gain: f64,
 
-    // Maximum value of the sensor gain range that is allowed to be used by the
-    // algorithm.
+    /// Maximum value of the sensor gain range that is allowed to be used by the
+    /// algorithm.
     #[yaserde(prefix = "tt", rename = "MaxGain")]
     pub max_gain: f64,
 
-    // Minimum value of the iris range allowed to be used by the algorithm.
+    /// Minimum value of the iris range allowed to be used by the algorithm.
     #[yaserde(prefix = "tt", rename = "MinIris")]
     pub min_iris: f64,
 
-    // Maximum value of the iris range allowed to be used by the algorithm.
+    /// Maximum value of the iris range allowed to be used by the algorithm.
     #[yaserde(prefix = "tt", rename = "MaxIris")]
     pub max_iris: f64,
 
-    // The fixed exposure time used by the image sensor (μs).
+    /// The fixed exposure time used by the image sensor (μs).
     #[yaserde(prefix = "tt", rename = "ExposureTime")]
     pub exposure_time: f64,
 
-    // The fixed gain used by the image sensor (dB).
+    /// The fixed gain used by the image sensor (dB).
     #[yaserde(prefix = "tt", rename = "Gain")]
     pub gain: f64,
 
-    // The fixed attenuation of input light affected by the iris (dB). 0dB maps
-    // to a fully opened iris.
+    /// The fixed attenuation of input light affected by the iris (dB). 0dB maps
+    /// to a fully opened iris.
     #[yaserde(prefix = "tt", rename = "Iris")]
     pub iris: f64,
 }
@@ -5525,6 +5586,7 @@ impl Validate for Exposure {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum WideDynamicMode {
     #[yaserde(rename = "OFF")]
     Off,
@@ -5544,25 +5606,26 @@ impl Validate for WideDynamicMode {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct WideDynamicRange {
-    // White dynamic range (on/off)
+    /// White dynamic range (on/off)
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: WideDynamicMode,
 
-    // Optional level parameter (unitless)
+    /// Optional level parameter (unitless)
     #[yaserde(prefix = "tt", rename = "Level")]
     pub level: f64,
 }
 
 impl Validate for WideDynamicRange {}
 
-// Enumeration describing the available backlight compenstation modes.
+/// Enumeration describing the available backlight compenstation modes.
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum BacklightCompensationMode {
-    // Backlight compensation is disabled.
+    /// Backlight compensation is disabled.
     #[yaserde(rename = "OFF")]
     Off,
-    // Backlight compensation is enabled.
+    /// Backlight compensation is enabled.
     #[yaserde(rename = "ON")]
     On,
     __Unknown__(String),
@@ -5579,11 +5642,11 @@ impl Validate for BacklightCompensationMode {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct BacklightCompensation {
-    // Backlight compensation mode (on/off).
+    /// Backlight compensation mode (on/off).
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: BacklightCompensationMode,
 
-    // Optional level parameter (unit unspecified).
+    /// Optional level parameter (unit unspecified).
     #[yaserde(prefix = "tt", rename = "Level")]
     pub level: f64,
 }
@@ -5592,6 +5655,7 @@ impl Validate for BacklightCompensation {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum ExposurePriority {
     LowNoise,
     FrameRate,
@@ -5741,15 +5805,15 @@ impl Validate for WhiteBalanceOptions {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct FocusMove {
-    // Parameters for the absolute focus control.
+    /// Parameters for the absolute focus control.
     #[yaserde(prefix = "tt", rename = "Absolute")]
     pub absolute: Option<AbsoluteFocus>,
 
-    // Parameters for the relative focus control.
+    /// Parameters for the relative focus control.
     #[yaserde(prefix = "tt", rename = "Relative")]
     pub relative: Option<RelativeFocus>,
 
-    // Parameter for the continuous focus control.
+    /// Parameter for the continuous focus control.
     #[yaserde(prefix = "tt", rename = "Continuous")]
     pub continuous: Option<ContinuousFocus>,
 }
@@ -5759,11 +5823,11 @@ impl Validate for FocusMove {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct AbsoluteFocus {
-    // Position parameter for the absolute focus control.
+    /// Position parameter for the absolute focus control.
     #[yaserde(prefix = "tt", rename = "Position")]
     pub position: f64,
 
-    // Speed parameter for the absolute focus control.
+    /// Speed parameter for the absolute focus control.
     #[yaserde(prefix = "tt", rename = "Speed")]
     pub speed: Option<f64>,
 }
@@ -5773,11 +5837,11 @@ impl Validate for AbsoluteFocus {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct RelativeFocus {
-    // Distance parameter for the relative focus control.
+    /// Distance parameter for the relative focus control.
     #[yaserde(prefix = "tt", rename = "Distance")]
     pub distance: f64,
 
-    // Speed parameter for the relative focus control.
+    /// Speed parameter for the relative focus control.
     #[yaserde(prefix = "tt", rename = "Speed")]
     pub speed: Option<f64>,
 }
@@ -5787,7 +5851,7 @@ impl Validate for RelativeFocus {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ContinuousFocus {
-    // Speed parameter for the Continuous focus control.
+    /// Speed parameter for the Continuous focus control.
     #[yaserde(prefix = "tt", rename = "Speed")]
     pub speed: f64,
 }
@@ -5812,11 +5876,11 @@ impl Validate for MoveOptions {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct AbsoluteFocusOptions {
-    // Valid ranges of the position.
+    /// Valid ranges of the position.
     #[yaserde(prefix = "tt", rename = "Position")]
     pub position: FloatRange,
 
-    // Valid ranges of the speed.
+    /// Valid ranges of the speed.
     #[yaserde(prefix = "tt", rename = "Speed")]
     pub speed: Option<FloatRange>,
 }
@@ -5826,11 +5890,11 @@ impl Validate for AbsoluteFocusOptions {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct RelativeFocusOptions {
-    // Valid ranges of the distance.
+    /// Valid ranges of the distance.
     #[yaserde(prefix = "tt", rename = "Distance")]
     pub distance: FloatRange,
 
-    // Valid ranges of the speed.
+    /// Valid ranges of the speed.
     #[yaserde(prefix = "tt", rename = "Speed")]
     pub speed: FloatRange,
 }
@@ -5840,7 +5904,7 @@ impl Validate for RelativeFocusOptions {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ContinuousFocusOptions {
-    // Valid ranges of the speed.
+    /// Valid ranges of the speed.
     #[yaserde(prefix = "tt", rename = "Speed")]
     pub speed: FloatRange,
 }
@@ -5849,6 +5913,7 @@ impl Validate for ContinuousFocusOptions {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum ExposureMode {
     #[yaserde(rename = "AUTO")]
     Auto,
@@ -5867,6 +5932,7 @@ impl Validate for ExposureMode {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum Enabled {
     #[yaserde(rename = "ENABLED")]
     Enabled,
@@ -5885,6 +5951,7 @@ impl Validate for Enabled {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum WhiteBalanceMode {
     #[yaserde(rename = "AUTO")]
     Auto,
@@ -5903,6 +5970,7 @@ impl Validate for WhiteBalanceMode {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum IrCutFilterMode {
     #[yaserde(rename = "ON")]
     On,
@@ -5924,15 +5992,15 @@ impl Validate for IrCutFilterMode {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct WhiteBalance {
-    // Auto whitebalancing mode (auto/manual).
+    /// Auto whitebalancing mode (auto/manual).
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: WhiteBalanceMode,
 
-    // Rgain (unitless).
+    /// Rgain (unitless).
     #[yaserde(prefix = "tt", rename = "CrGain")]
     pub cr_gain: f64,
 
-    // Bgain (unitless).
+    /// Bgain (unitless).
     #[yaserde(prefix = "tt", rename = "CbGain")]
     pub cb_gain: f64,
 }
@@ -5942,7 +6010,7 @@ impl Validate for WhiteBalance {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ImagingStatus20 {
-    // Status of focus.
+    /// Status of focus.
     #[yaserde(prefix = "tt", rename = "FocusStatus20")]
     pub focus_status_20: Option<FocusStatus20>,
 
@@ -5961,15 +6029,15 @@ impl Validate for ImagingStatus20Extension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct FocusStatus20 {
-    // Status of focus position.
+    /// Status of focus position.
     #[yaserde(prefix = "tt", rename = "Position")]
     pub position: f64,
 
-    // Status of focus MoveStatus.
+    /// Status of focus MoveStatus.
     #[yaserde(prefix = "tt", rename = "MoveStatus")]
     pub move_status: MoveStatus,
 
-    // Error status of focus.
+    /// Error status of focus.
     #[yaserde(prefix = "tt", rename = "Error")]
     pub error: Option<String>,
 
@@ -5985,48 +6053,48 @@ pub struct FocusStatus20Extension {}
 
 impl Validate for FocusStatus20Extension {}
 
-// Type describing the ImagingSettings of a VideoSource. The supported options
-// and ranges can be obtained via the GetOptions command.
+/// Type describing the ImagingSettings of a VideoSource. The supported options
+/// and ranges can be obtained via the GetOptions command.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ImagingSettings20 {
-    // Enabled/disabled BLC mode (on/off).
+    /// Enabled/disabled BLC mode (on/off).
     #[yaserde(prefix = "tt", rename = "BacklightCompensation")]
     pub backlight_compensation: Option<BacklightCompensation20>,
 
-    // Image brightness (unit unspecified).
+    /// Image brightness (unit unspecified).
     #[yaserde(prefix = "tt", rename = "Brightness")]
     pub brightness: Option<f64>,
 
-    // Color saturation of the image (unit unspecified).
+    /// Color saturation of the image (unit unspecified).
     #[yaserde(prefix = "tt", rename = "ColorSaturation")]
     pub color_saturation: Option<f64>,
 
-    // Contrast of the image (unit unspecified).
+    /// Contrast of the image (unit unspecified).
     #[yaserde(prefix = "tt", rename = "Contrast")]
     pub contrast: Option<f64>,
 
-    // Exposure mode of the device.
+    /// Exposure mode of the device.
     #[yaserde(prefix = "tt", rename = "Exposure")]
     pub exposure: Option<Exposure20>,
 
-    // Focus configuration.
+    /// Focus configuration.
     #[yaserde(prefix = "tt", rename = "Focus")]
     pub focus: Option<FocusConfiguration20>,
 
-    // Infrared Cutoff Filter settings.
+    /// Infrared Cutoff Filter settings.
     #[yaserde(prefix = "tt", rename = "IrCutFilter")]
     pub ir_cut_filter: Option<IrCutFilterMode>,
 
-    // Sharpness of the Video image.
+    /// Sharpness of the Video image.
     #[yaserde(prefix = "tt", rename = "Sharpness")]
     pub sharpness: Option<f64>,
 
-    // WDR settings.
+    /// WDR settings.
     #[yaserde(prefix = "tt", rename = "WideDynamicRange")]
     pub wide_dynamic_range: Option<WideDynamicRange20>,
 
-    // White balance settings.
+    /// White balance settings.
     #[yaserde(prefix = "tt", rename = "WhiteBalance")]
     pub white_balance: Option<WhiteBalance20>,
 
@@ -6039,13 +6107,14 @@ impl Validate for ImagingSettings20 {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ImagingSettingsExtension20 {
-    // Optional element to configure Image Stabilization feature.
+    /// Optional element to configure Image Stabilization feature.
     #[yaserde(prefix = "tt", rename = "ImageStabilization")]
     pub image_stabilization: Option<ImageStabilization>,
-    // `Extension` inside `Extension` causes infinite loop at deserialization
-    // https://github.com/media-io/yaserde/issues/76
-    // #[yaserde(prefix = "tt", rename = "Extension")]
-    // pub extension: Option<ImagingSettingsExtension202>,
+    /// `Extension` inside `Extension` causes infinite loop at deserialization
+    /// https://github.com/media-io/yaserde/issues/76
+    /// so it's kept as raw XML.
+    #[yaserde(prefix = "tt", rename = "Extension")]
+    pub extension: Option<RawXml>,
 }
 
 impl Validate for ImagingSettingsExtension20 {}
@@ -6094,11 +6163,11 @@ impl Validate for ImagingSettingsExtension20 {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ImageStabilization {
-    // Parameter to enable/disable Image Stabilization feature.
+    /// Parameter to enable/disable Image Stabilization feature.
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: ImageStabilizationMode,
 
-    // Optional level parameter (unit unspecified)
+    /// Optional level parameter (unit unspecified)
     #[yaserde(prefix = "tt", rename = "Level")]
     pub level: Option<f64>,
 
@@ -6116,6 +6185,7 @@ impl Validate for ImageStabilizationExtension {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum ImageStabilizationMode {
     #[yaserde(rename = "OFF")]
     Off,
@@ -6138,20 +6208,20 @@ impl Validate for ImageStabilizationMode {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct IrCutFilterAutoAdjustment {
-    // Specifies which boundaries to automatically toggle Ir cut filter
-    // following parameters are applied to. Its options shall be chosen from
-    // tt:IrCutFilterAutoBoundaryType.
+    /// Specifies which boundaries to automatically toggle Ir cut filter
+    /// following parameters are applied to. Its options shall be chosen from
+    /// tt:IrCutFilterAutoBoundaryType.
     #[yaserde(prefix = "tt", rename = "BoundaryType")]
     pub boundary_type: String,
 
-    // Adjusts boundary exposure level for toggling Ir cut filter to on/off
-    // specified with unitless normalized value from +1.0 to -1.0. Zero is
-    // default and -1.0 is the darkest adjustment (Unitless).
+    /// Adjusts boundary exposure level for toggling Ir cut filter to on/off
+    /// specified with unitless normalized value from +1.0 to -1.0. Zero is
+    /// default and -1.0 is the darkest adjustment (Unitless).
     #[yaserde(prefix = "tt", rename = "BoundaryOffset")]
     pub boundary_offset: Option<f64>,
 
-    // Delay time of toggling Ir cut filter to on/off after crossing of the
-    // boundary exposure levels.
+    /// Delay time of toggling Ir cut filter to on/off after crossing of the
+    /// boundary exposure levels.
     #[yaserde(prefix = "tt", rename = "ResponseTime")]
     pub response_time: Option<xs::Duration>,
 
@@ -6169,6 +6239,7 @@ impl Validate for IrCutFilterAutoAdjustmentExtension {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum IrCutFilterAutoBoundaryType {
     Common,
     ToOn,
@@ -6185,92 +6256,92 @@ impl Default for IrCutFilterAutoBoundaryType {
 
 impl Validate for IrCutFilterAutoBoundaryType {}
 
-// Type describing whether WDR mode is enabled or disabled (on/off).
+/// Type describing whether WDR mode is enabled or disabled (on/off).
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct WideDynamicRange20 {
-    // Wide dynamic range mode (on/off).
+    /// Wide dynamic range mode (on/off).
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: WideDynamicMode,
 
-    // Optional level parameter (unit unspecified).
+    /// Optional level parameter (unit unspecified).
     #[yaserde(prefix = "tt", rename = "Level")]
     pub level: Option<f64>,
 }
 
 impl Validate for WideDynamicRange20 {}
 
-// Type describing whether BLC mode is enabled or disabled (on/off).
+/// Type describing whether BLC mode is enabled or disabled (on/off).
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct BacklightCompensation20 {
-    // Backlight compensation mode (on/off).
+    /// Backlight compensation mode (on/off).
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: BacklightCompensationMode,
 
-    // Optional level parameter (unit unspecified).
+    /// Optional level parameter (unit unspecified).
     #[yaserde(prefix = "tt", rename = "Level")]
     pub level: Option<f64>,
 }
 
 impl Validate for BacklightCompensation20 {}
 
-// Type describing the exposure settings.
+/// Type describing the exposure settings.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Exposure20 {
-    // Exposure Mode
+    /// Exposure Mode
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: ExposureMode,
 
-    // The exposure priority mode (low noise/framerate).
+    /// The exposure priority mode (low noise/framerate).
     #[yaserde(prefix = "tt", rename = "Priority")]
     pub priority: Option<ExposurePriority>,
 
-    // Rectangular exposure mask.
+    /// Rectangular exposure mask.
     #[yaserde(prefix = "tt", rename = "Window")]
     pub window: Option<Rectangle>,
 
-    // Minimum value of exposure time range allowed to be used by the algorithm.
+    /// Minimum value of exposure time range allowed to be used by the algorithm.
     #[yaserde(prefix = "tt", rename = "MinExposureTime")]
     pub min_exposure_time: Option<f64>,
 
-    // Maximum value of exposure time range allowed to be used by the algorithm.
+    /// Maximum value of exposure time range allowed to be used by the algorithm.
     #[yaserde(prefix = "tt", rename = "MaxExposureTime")]
     pub max_exposure_time: Option<f64>,
 
-    // Minimum value of the sensor gain range that is allowed to be used by the
-    // algorithm.
+    /// Minimum value of the sensor gain range that is allowed to be used by the
+    /// algorithm.
     #[yaserde(prefix = "tt", rename = "MinGain")]
     pub min_gain: Option<f64>,
 
-    // Maximum value of the sensor gain range that is allowed to be used by the
-    // algorithm.
+    /// Maximum value of the sensor gain range that is allowed to be used by the
+    /// algorithm.
     #[yaserde(prefix = "tt", rename = "MaxGain")]
     pub max_gain: Option<f64>,
 
-    // Minimum value of the iris range allowed to be used by the algorithm. 0dB
-    // maps to a fully opened iris and positive values map to higher
-    // attenuation.
+    /// Minimum value of the iris range allowed to be used by the algorithm. 0dB
+    /// maps to a fully opened iris and positive values map to higher
+    /// attenuation.
     #[yaserde(prefix = "tt", rename = "MinIris")]
     pub min_iris: Option<f64>,
 
-    // Maximum value of the iris range allowed to be used by the algorithm. 0dB
-    // maps to a fully opened iris and positive values map to higher
-    // attenuation.
+    /// Maximum value of the iris range allowed to be used by the algorithm. 0dB
+    /// maps to a fully opened iris and positive values map to higher
+    /// attenuation.
     #[yaserde(prefix = "tt", rename = "MaxIris")]
     pub max_iris: Option<f64>,
 
-    // The fixed exposure time used by the image sensor (μs).
+    /// The fixed exposure time used by the image sensor (μs).
     #[yaserde(prefix = "tt", rename = "ExposureTime")]
     pub exposure_time: Option<f64>,
 
-    // The fixed gain used by the image sensor (dB).
+    /// The fixed gain used by the image sensor (dB).
     #[yaserde(prefix = "tt", rename = "Gain")]
     pub gain: Option<f64>,
 
-    // The fixed attenuation of input light affected by the iris (dB). 0dB maps
-    // to a fully opened iris and positive values map to higher attenuation.
+    /// The fixed attenuation of input light affected by the iris (dB). 0dB maps
+    /// to a fully opened iris and positive values map to higher attenuation.
     #[yaserde(prefix = "tt", rename = "Iris")]
     pub iris: Option<f64>,
 }
@@ -6280,13 +6351,13 @@ impl Validate for Exposure20 {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ToneCompensation {
-    // Parameter to enable/disable or automatic ToneCompensation feature. Its
-    // options shall be chosen from tt:ToneCompensationMode Type.
+    /// Parameter to enable/disable or automatic ToneCompensation feature. Its
+    /// options shall be chosen from tt:ToneCompensationMode Type.
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: String,
 
-    // Optional level parameter specified with unitless normalized value from
-    // 0.0 to +1.0.
+    /// Optional level parameter specified with unitless normalized value from
+    /// 0.0 to +1.0.
     #[yaserde(prefix = "tt", rename = "Level")]
     pub level: Option<f64>,
 
@@ -6304,6 +6375,7 @@ impl Validate for ToneCompensationExtension {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum ToneCompensationMode {
     #[yaserde(rename = "OFF")]
     Off,
@@ -6325,13 +6397,13 @@ impl Validate for ToneCompensationMode {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Defogging {
-    // Parameter to enable/disable or automatic Defogging feature. Its options
-    // shall be chosen from tt:DefoggingMode Type.
+    /// Parameter to enable/disable or automatic Defogging feature. Its options
+    /// shall be chosen from tt:DefoggingMode Type.
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: String,
 
-    // Optional level parameter specified with unitless normalized value from
-    // 0.0 to +1.0.
+    /// Optional level parameter specified with unitless normalized value from
+    /// 0.0 to +1.0.
     #[yaserde(prefix = "tt", rename = "Level")]
     pub level: Option<f64>,
 
@@ -6349,6 +6421,7 @@ impl Validate for DefoggingExtension {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum DefoggingMode {
     #[yaserde(rename = "OFF")]
     Off,
@@ -6370,8 +6443,8 @@ impl Validate for DefoggingMode {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct NoiseReduction {
-    // Level parameter specified with unitless normalized value from 0.0 to
-    // +1.0. Level=0 means no noise reduction or minimal noise reduction.
+    /// Level parameter specified with unitless normalized value from 0.0 to
+    /// +1.0. Level=0 means no noise reduction or minimal noise reduction.
     #[yaserde(prefix = "tt", rename = "Level")]
     pub level: f64,
 }
@@ -6381,43 +6454,43 @@ impl Validate for NoiseReduction {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ImagingOptions20 {
-    // Valid range of Backlight Compensation.
+    /// Valid range of Backlight Compensation.
     #[yaserde(prefix = "tt", rename = "BacklightCompensation")]
     pub backlight_compensation: Option<BacklightCompensationOptions20>,
 
-    // Valid range of Brightness.
+    /// Valid range of Brightness.
     #[yaserde(prefix = "tt", rename = "Brightness")]
     pub brightness: Option<FloatRange>,
 
-    // Valid range of Color Saturation.
+    /// Valid range of Color Saturation.
     #[yaserde(prefix = "tt", rename = "ColorSaturation")]
     pub color_saturation: Option<FloatRange>,
 
-    // Valid range of Contrast.
+    /// Valid range of Contrast.
     #[yaserde(prefix = "tt", rename = "Contrast")]
     pub contrast: Option<FloatRange>,
 
-    // Valid range of Exposure.
+    /// Valid range of Exposure.
     #[yaserde(prefix = "tt", rename = "Exposure")]
     pub exposure: Option<ExposureOptions20>,
 
-    // Valid range of Focus.
+    /// Valid range of Focus.
     #[yaserde(prefix = "tt", rename = "Focus")]
     pub focus: Option<FocusOptions20>,
 
-    // Valid range of IrCutFilterModes.
+    /// Valid range of IrCutFilterModes.
     #[yaserde(prefix = "tt", rename = "IrCutFilterModes")]
     pub ir_cut_filter_modes: Vec<IrCutFilterMode>,
 
-    // Valid range of Sharpness.
+    /// Valid range of Sharpness.
     #[yaserde(prefix = "tt", rename = "Sharpness")]
     pub sharpness: Option<FloatRange>,
 
-    // Valid range of WideDynamicRange.
+    /// Valid range of WideDynamicRange.
     #[yaserde(prefix = "tt", rename = "WideDynamicRange")]
     pub wide_dynamic_range: Option<WideDynamicRangeOptions20>,
 
-    // Valid range of WhiteBalance.
+    /// Valid range of WhiteBalance.
     #[yaserde(prefix = "tt", rename = "WhiteBalance")]
     pub white_balance: Option<WhiteBalanceOptions20>,
 
@@ -6430,13 +6503,14 @@ impl Validate for ImagingOptions20 {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ImagingOptions20Extension {
-    // Options of parameters for Image Stabilization feature.
+    /// Options of parameters for Image Stabilization feature.
     #[yaserde(prefix = "tt", rename = "ImageStabilization")]
     pub image_stabilization: Option<ImageStabilizationOptions>,
-    // `Extension` inside `Extension` causes infinite loop at deserialization
-    // https://github.com/media-io/yaserde/issues/76
-    // #[yaserde(prefix = "tt", rename = "Extension")]
-    // pub extension: Option<ImagingOptions20Extension2>,
+    /// `Extension` inside `Extension` causes infinite loop at deserialization
+    /// https://github.com/media-io/yaserde/issues/76
+    /// so it's kept as raw XML.
+    #[yaserde(prefix = "tt", rename = "Extension")]
+    pub extension: Option<RawXml>,
 }
 
 impl Validate for ImagingOptions20Extension {}
@@ -6457,15 +6531,15 @@ impl Validate for ImagingOptions20Extension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ImagingOptions20Extension3 {
-    // Options of parameters for Tone Compensation feature.
+    /// Options of parameters for Tone Compensation feature.
     #[yaserde(prefix = "tt", rename = "ToneCompensationOptions")]
     pub tone_compensation_options: Option<ToneCompensationOptions>,
 
-    // Options of parameters for Defogging feature.
+    /// Options of parameters for Defogging feature.
     #[yaserde(prefix = "tt", rename = "DefoggingOptions")]
     pub defogging_options: Option<DefoggingOptions>,
 
-    // Options of parameter for Noise Reduction feature.
+    /// Options of parameter for Noise Reduction feature.
     #[yaserde(prefix = "tt", rename = "NoiseReductionOptions")]
     pub noise_reduction_options: Option<NoiseReductionOptions>,
 
@@ -6484,11 +6558,11 @@ impl Validate for ImagingOptions20Extension4 {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ImageStabilizationOptions {
-    // Supported options of Image Stabilization mode parameter.
+    /// Supported options of Image Stabilization mode parameter.
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: Vec<ImageStabilizationMode>,
 
-    // Valid range of the Image Stabilization.
+    /// Valid range of the Image Stabilization.
     #[yaserde(prefix = "tt", rename = "Level")]
     pub level: Option<FloatRange>,
 
@@ -6507,17 +6581,17 @@ impl Validate for ImageStabilizationOptionsExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct IrCutFilterAutoAdjustmentOptions {
-    // Supported options of boundary types for adjustment of Ir cut filter auto
-    // mode. The opptions shall be chosen from tt:IrCutFilterAutoBoundaryType.
+    /// Supported options of boundary types for adjustment of Ir cut filter auto
+    /// mode. The opptions shall be chosen from tt:IrCutFilterAutoBoundaryType.
     #[yaserde(prefix = "tt", rename = "BoundaryType")]
     pub boundary_type: Vec<String>,
 
-    // Indicates whether or not boundary offset for toggling Ir cut filter is
-    // supported.
+    /// Indicates whether or not boundary offset for toggling Ir cut filter is
+    /// supported.
     #[yaserde(prefix = "tt", rename = "BoundaryOffset")]
     pub boundary_offset: Option<bool>,
 
-    // Supported range of delay time for toggling Ir cut filter.
+    /// Supported range of delay time for toggling Ir cut filter.
     #[yaserde(prefix = "tt", rename = "ResponseTimeRange")]
     pub response_time_range: Option<DurationRange>,
 
@@ -6548,11 +6622,11 @@ impl Validate for WideDynamicRangeOptions20 {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct BacklightCompensationOptions20 {
-    // 'ON' or 'OFF'
+    /// 'ON' or 'OFF'
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: Vec<BacklightCompensationMode>,
 
-    // Level range of BacklightCompensation.
+    /// Level range of BacklightCompensation.
     #[yaserde(prefix = "tt", rename = "Level")]
     pub level: Option<FloatRange>,
 }
@@ -6562,47 +6636,47 @@ impl Validate for BacklightCompensationOptions20 {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ExposureOptions20 {
-    // Exposure Mode
+    /// Exposure Mode
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: Vec<ExposureMode>,
 
-    // The exposure priority mode (low noise/framerate).
+    /// The exposure priority mode (low noise/framerate).
     #[yaserde(prefix = "tt", rename = "Priority")]
     pub priority: Vec<ExposurePriority>,
 
-    // Valid range of the Minimum ExposureTime.
+    /// Valid range of the Minimum ExposureTime.
     #[yaserde(prefix = "tt", rename = "MinExposureTime")]
     pub min_exposure_time: Option<FloatRange>,
 
-    // Valid range of the Maximum ExposureTime.
+    /// Valid range of the Maximum ExposureTime.
     #[yaserde(prefix = "tt", rename = "MaxExposureTime")]
     pub max_exposure_time: Option<FloatRange>,
 
-    // Valid range of the Minimum Gain.
+    /// Valid range of the Minimum Gain.
     #[yaserde(prefix = "tt", rename = "MinGain")]
     pub min_gain: Option<FloatRange>,
 
-    // Valid range of the Maximum Gain.
+    /// Valid range of the Maximum Gain.
     #[yaserde(prefix = "tt", rename = "MaxGain")]
     pub max_gain: Option<FloatRange>,
 
-    // Valid range of the Minimum Iris.
+    /// Valid range of the Minimum Iris.
     #[yaserde(prefix = "tt", rename = "MinIris")]
     pub min_iris: Option<FloatRange>,
 
-    // Valid range of the Maximum Iris.
+    /// Valid range of the Maximum Iris.
     #[yaserde(prefix = "tt", rename = "MaxIris")]
     pub max_iris: Option<FloatRange>,
 
-    // Valid range of the ExposureTime.
+    /// Valid range of the ExposureTime.
     #[yaserde(prefix = "tt", rename = "ExposureTime")]
     pub exposure_time: Option<FloatRange>,
 
-    // Valid range of the Gain.
+    /// Valid range of the Gain.
     #[yaserde(prefix = "tt", rename = "Gain")]
     pub gain: Option<FloatRange>,
 
-    // Valid range of the Iris.
+    /// Valid range of the Iris.
     #[yaserde(prefix = "tt", rename = "Iris")]
     pub iris: Option<FloatRange>,
 }
@@ -6612,15 +6686,15 @@ impl Validate for ExposureOptions20 {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct MoveOptions20 {
-    // Valid ranges for the absolute control.
+    /// Valid ranges for the absolute control.
     #[yaserde(prefix = "tt", rename = "Absolute")]
     pub absolute: Option<AbsoluteFocusOptions>,
 
-    // Valid ranges for the relative control.
+    /// Valid ranges for the relative control.
     #[yaserde(prefix = "tt", rename = "Relative")]
     pub relative: Option<RelativeFocusOptions20>,
 
-    // Valid ranges for the continuous control.
+    /// Valid ranges for the continuous control.
     #[yaserde(prefix = "tt", rename = "Continuous")]
     pub continuous: Option<ContinuousFocusOptions>,
 }
@@ -6630,11 +6704,11 @@ impl Validate for MoveOptions20 {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct RelativeFocusOptions20 {
-    // Valid ranges of the distance.
+    /// Valid ranges of the distance.
     #[yaserde(prefix = "tt", rename = "Distance")]
     pub distance: FloatRange,
 
-    // Valid ranges of the speed.
+    /// Valid ranges of the speed.
     #[yaserde(prefix = "tt", rename = "Speed")]
     pub speed: Option<FloatRange>,
 }
@@ -6644,15 +6718,15 @@ impl Validate for RelativeFocusOptions20 {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct WhiteBalance20 {
-    // 'AUTO' or 'MANUAL'
+    /// 'AUTO' or 'MANUAL'
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: WhiteBalanceMode,
 
-    // Rgain (unitless).
+    /// Rgain (unitless).
     #[yaserde(prefix = "tt", rename = "CrGain")]
     pub cr_gain: Option<f64>,
 
-    // Bgain (unitless).
+    /// Bgain (unitless).
     #[yaserde(prefix = "tt", rename = "CbGain")]
     pub cb_gain: Option<f64>,
 
@@ -6671,25 +6745,25 @@ impl Validate for WhiteBalance20Extension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct FocusConfiguration20 {
-    // Mode of auto focus.
+    /// Mode of auto focus.
     #[yaserde(prefix = "tt", rename = "AutoFocusMode")]
     pub auto_focus_mode: AutoFocusMode,
 
     #[yaserde(prefix = "tt", rename = "DefaultSpeed")]
     pub default_speed: Option<f64>,
 
-    // Parameter to set autofocus near limit (unit: meter).
+    /// Parameter to set autofocus near limit (unit: meter).
     #[yaserde(prefix = "tt", rename = "NearLimit")]
     pub near_limit: Option<f64>,
 
-    // Parameter to set autofocus far limit (unit: meter).
+    /// Parameter to set autofocus far limit (unit: meter).
     #[yaserde(prefix = "tt", rename = "FarLimit")]
     pub far_limit: Option<f64>,
 
     #[yaserde(prefix = "tt", rename = "Extension")]
     pub extension: Option<FocusConfiguration20Extension>,
 
-    // Zero or more modes as defined in enumeration tt:AFModes.
+    /// Zero or more modes as defined in enumeration tt:AFModes.
     #[yaserde(attribute, rename = "AFMode")]
     pub af_mode: Option<StringAttrList>,
 }
@@ -6705,7 +6779,7 @@ impl Validate for FocusConfiguration20Extension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct WhiteBalanceOptions20 {
-    // Mode of WhiteBalance.
+    /// Mode of WhiteBalance.
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: Vec<WhiteBalanceMode>,
 
@@ -6730,19 +6804,19 @@ impl Validate for WhiteBalanceOptions20Extension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct FocusOptions20 {
-    // Supported modes for auto focus.
+    /// Supported modes for auto focus.
     #[yaserde(prefix = "tt", rename = "AutoFocusModes")]
     pub auto_focus_modes: Vec<AutoFocusMode>,
 
-    // Valid range of DefaultSpeed.
+    /// Valid range of DefaultSpeed.
     #[yaserde(prefix = "tt", rename = "DefaultSpeed")]
     pub default_speed: Option<FloatRange>,
 
-    // Valid range of NearLimit.
+    /// Valid range of NearLimit.
     #[yaserde(prefix = "tt", rename = "NearLimit")]
     pub near_limit: Option<FloatRange>,
 
-    // Valid range of FarLimit.
+    /// Valid range of FarLimit.
     #[yaserde(prefix = "tt", rename = "FarLimit")]
     pub far_limit: Option<FloatRange>,
 
@@ -6755,8 +6829,8 @@ impl Validate for FocusOptions20 {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct FocusOptions20Extension {
-    // Supported options for auto focus. Options shall be chosen from
-    // tt:AFModes.
+    /// Supported options for auto focus. Options shall be chosen from
+    /// tt:AFModes.
     #[yaserde(prefix = "tt", rename = "AFModes")]
     pub af_modes: Option<StringAttrList>,
 }
@@ -6766,12 +6840,12 @@ impl Validate for FocusOptions20Extension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ToneCompensationOptions {
-    // Supported options for Tone Compensation mode. Its options shall be chosen
-    // from tt:ToneCompensationMode Type.
+    /// Supported options for Tone Compensation mode. Its options shall be chosen
+    /// from tt:ToneCompensationMode Type.
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: Vec<String>,
 
-    // Indicates whether or not support Level parameter for Tone Compensation.
+    /// Indicates whether or not support Level parameter for Tone Compensation.
     #[yaserde(prefix = "tt", rename = "Level")]
     pub level: bool,
 }
@@ -6781,12 +6855,12 @@ impl Validate for ToneCompensationOptions {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct DefoggingOptions {
-    // Supported options for Defogging mode. Its options shall be chosen from
-    // tt:DefoggingMode Type.
+    /// Supported options for Defogging mode. Its options shall be chosen from
+    /// tt:DefoggingMode Type.
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: Vec<String>,
 
-    // Indicates whether or not support Level parameter for Defogging.
+    /// Indicates whether or not support Level parameter for Defogging.
     #[yaserde(prefix = "tt", rename = "Level")]
     pub level: bool,
 }
@@ -6796,7 +6870,7 @@ impl Validate for DefoggingOptions {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct NoiseReductionOptions {
-    // Indicates whether or not support Level parameter for NoiseReduction.
+    /// Indicates whether or not support Level parameter for NoiseReduction.
     #[yaserde(prefix = "tt", rename = "Level")]
     pub level: bool,
 }
@@ -6810,6 +6884,7 @@ impl Validate for TopicNamespaceLocation {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum PropertyOperation {
     Initialized,
     Deleted,
@@ -6828,8 +6903,8 @@ impl Validate for PropertyOperation {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Message {
-    // Token value pairs that triggered this message. Typically only one item is
-    // present.
+    /// Token value pairs that triggered this message. Typically only one item is
+    /// present.
     #[yaserde(prefix = "tt", rename = "Source")]
     pub source: Option<ItemList>,
 
@@ -6857,16 +6932,16 @@ pub struct MessageExtension {}
 
 impl Validate for MessageExtension {}
 
-// List of parameters according to the corresponding ItemListDescription.
-// Each item in the list shall have a unique name.
+/// List of parameters according to the corresponding ItemListDescription.
+/// Each item in the list shall have a unique name.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ItemList {
-    // Value name pair as defined by the corresponding description.
+    /// Value name pair as defined by the corresponding description.
     #[yaserde(prefix = "tt", rename = "SimpleItem")]
     pub simple_item: Vec<item_list::SimpleItemType>,
 
-    // Complex value structure.
+    /// Complex value structure.
     #[yaserde(prefix = "tt", rename = "ElementItem")]
     pub element_item: Vec<item_list::ElementItemType>,
 
@@ -6882,11 +6957,11 @@ pub mod item_list {
     #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
     #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
     pub struct SimpleItemType {
-        // Item name.
+        /// Item name.
         #[yaserde(attribute, rename = "Name")]
         pub name: String,
 
-        // Item value. The type is defined in the corresponding description.
+        /// Item value. The type is defined in the corresponding description.
         #[yaserde(attribute, rename = "Value")]
         pub value: String,
     }
@@ -6896,7 +6971,7 @@ pub mod item_list {
     #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
     #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
     pub struct ElementItemType {
-        // Item name.
+        /// Item name.
         #[yaserde(attribute, rename = "Name")]
         pub name: String,
     }
@@ -6913,28 +6988,28 @@ impl Validate for ItemListExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct MessageDescription {
-    // Set of tokens producing this message. The list may only contain
-    // SimpleItemDescription items.
-    // The set of tokens identify the component within the WS-Endpoint, which is
-    // responsible for the producing the message.
+    /// Set of tokens producing this message. The list may only contain
+    /// SimpleItemDescription items.
+    /// The set of tokens identify the component within the WS-Endpoint, which is
+    /// responsible for the producing the message.
     #[yaserde(prefix = "tt", rename = "Source")]
     pub source: Option<ItemListDescription>,
 
-    // Describes optional message payload parameters that may be used as key.
-    // E.g. object IDs of tracked objects are conveyed as key.
+    /// Describes optional message payload parameters that may be used as key.
+    /// E.g. object IDs of tracked objects are conveyed as key.
     #[yaserde(prefix = "tt", rename = "Key")]
     pub key: Option<ItemListDescription>,
 
-    // Describes the payload of the message.
+    /// Describes the payload of the message.
     #[yaserde(prefix = "tt", rename = "Data")]
     pub data: Option<ItemListDescription>,
 
     #[yaserde(prefix = "tt", rename = "Extension")]
     pub extension: Option<MessageDescriptionExtension>,
 
-    // Must be set to true when the described Message relates to a property. An
-    // alternative term of "property" is a "state" in contrast to a pure event,
-    // which contains relevant information for only a single point in time.
+    /// Must be set to true when the described Message relates to a property. An
+    /// alternative term of "property" is a "state" in contrast to a pure event,
+    /// which contains relevant information for only a single point in time.
     #[yaserde(attribute, rename = "IsProperty")]
     pub is_property: Option<bool>,
 }
@@ -6947,19 +7022,19 @@ pub struct MessageDescriptionExtension {}
 
 impl Validate for MessageDescriptionExtension {}
 
-// Describes a list of items. Each item in the list shall have a unique name.
-// The list is designed as linear structure without optional or unbounded
-// elements.
-// Use ElementItems only when complex structures are inevitable.
+/// Describes a list of items. Each item in the list shall have a unique name.
+/// The list is designed as linear structure without optional or unbounded
+/// elements.
+/// Use ElementItems only when complex structures are inevitable.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ItemListDescription {
-    // Description of a simple item. The type must be of cathegory simpleType
-    // (xs:string, xs:integer, xs:float, ...).
+    /// Description of a simple item. The type must be of cathegory simpleType
+    /// (xs:string, xs:integer, xs:float, ...).
     #[yaserde(prefix = "tt", rename = "SimpleItemDescription")]
     pub simple_item_description: Vec<item_list_description::SimpleItemDescriptionType>,
 
-    // Description of a complex type. The Type must reference a defined type.
+    /// Description of a complex type. The Type must reference a defined type.
     #[yaserde(prefix = "tt", rename = "ElementItemDescription")]
     pub element_item_description: Vec<item_list_description::ElementItemDescriptionType>,
 
@@ -6975,7 +7050,7 @@ pub mod item_list_description {
     #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
     #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
     pub struct SimpleItemDescriptionType {
-        // Item name. Must be unique within a list.
+        /// Item name. Must be unique within a list.
         #[yaserde(attribute, rename = "Name")]
         pub name: String,
 
@@ -6988,11 +7063,11 @@ pub mod item_list_description {
     #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
     #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
     pub struct ElementItemDescriptionType {
-        // Item name. Must be unique within a list.
+        /// Item name. Must be unique within a list.
         #[yaserde(attribute, rename = "Name")]
         pub name: String,
 
-        // The type of the item. The Type must reference a defined type.
+        /// The type of the item. The Type must reference a defined type.
         #[yaserde(attribute, rename = "Type")]
         pub _type: String,
     }
@@ -7018,6 +7093,7 @@ impl Validate for Polyline {}
 // pub type Polyline = Polyline;
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum Direction {
     Left,
     Right,
@@ -7072,18 +7148,18 @@ impl Validate for RuleEngineConfigurationExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Config {
-    // List of configuration parameters as defined in the correspding
-    // description.
+    /// List of configuration parameters as defined in the correspding
+    /// description.
     #[yaserde(prefix = "tt", rename = "Parameters")]
     pub parameters: ItemList,
 
-    // Name of the configuration.
+    /// Name of the configuration.
     #[yaserde(attribute, rename = "Name")]
     pub name: String,
 
-    // The Type attribute specifies the type of rule and shall be equal to value
-    // of one of Name attributes of ConfigDescription elements returned by
-    // GetSupportedRules and GetSupportedAnalyticsModules command.
+    /// The Type attribute specifies the type of rule and shall be equal to value
+    /// of one of Name attributes of ConfigDescription elements returned by
+    /// GetSupportedRules and GetSupportedAnalyticsModules command.
     #[yaserde(attribute, rename = "Type")]
     pub _type: String,
 }
@@ -7093,41 +7169,41 @@ impl Validate for Config {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ConfigDescription {
-    // List describing the configuration parameters. The names of the parameters
-    // must be unique. If possible SimpleItems
-    // should be used to transport the information to ease parsing of
-    // dynamically defined messages by a client
-    // application.
+    /// List describing the configuration parameters. The names of the parameters
+    /// must be unique. If possible SimpleItems
+    /// should be used to transport the information to ease parsing of
+    /// dynamically defined messages by a client
+    /// application.
     #[yaserde(prefix = "tt", rename = "Parameters")]
     pub parameters: ItemListDescription,
 
-    // The analytics modules and rule engine produce Events, which must be
-    // listed within the Analytics Module Description. In order to do so
-    // the structure of the Message is defined and consists of three groups:
-    // Source, Key, and Data. It is recommended to use SimpleItemDescriptions
-    // wherever applicable.
-    // The name of all Items must be unique within all Items contained in any
-    // group of this Message.
-    // Depending on the component multiple parameters or none may be needed to
-    // identify the component uniquely.
+    /// The analytics modules and rule engine produce Events, which must be
+    /// listed within the Analytics Module Description. In order to do so
+    /// the structure of the Message is defined and consists of three groups:
+    /// Source, Key, and Data. It is recommended to use SimpleItemDescriptions
+    /// wherever applicable.
+    /// The name of all Items must be unique within all Items contained in any
+    /// group of this Message.
+    /// Depending on the component multiple parameters or none may be needed to
+    /// identify the component uniquely.
     #[yaserde(prefix = "tt", rename = "Messages")]
     pub messages: Vec<config_description::MessagesType>,
 
     #[yaserde(prefix = "tt", rename = "Extension")]
     pub extension: Option<ConfigDescriptionExtension>,
 
-    // The Name attribute (e.g. "tt::LineDetector") uniquely identifies the type
-    // of rule, not a type definition in a schema.
+    /// The Name attribute (e.g. "tt::LineDetector") uniquely identifies the type
+    /// of rule, not a type definition in a schema.
     #[yaserde(attribute, rename = "Name")]
     pub name: String,
 
-    // The fixed attribute signals that it is not allowed to add or remove this
-    // type of configuration.
+    /// The fixed attribute signals that it is not allowed to add or remove this
+    /// type of configuration.
     #[yaserde(attribute, rename = "fixed")]
     pub fixed: Option<bool>,
 
-    // The maxInstances attribute signals the maximum number of instances per
-    // configuration.
+    /// The maxInstances attribute signals the maximum number of instances per
+    /// configuration.
     #[yaserde(attribute, rename = "maxInstances")]
     pub max_instances: Option<xs::Integer>,
 }
@@ -7140,41 +7216,41 @@ pub mod config_description {
     #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
     #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
     pub struct MessagesType {
-        // The ParentTopic labels the message (e.g. "nn:RuleEngine/LineCrossing").
-        // The real message can extend the ParentTopic
-        // by for example the name of the instaniated rule (e.g.
-        // "nn:RuleEngine/LineCrossing/corssMyFirstLine").
-        // Even without knowing the complete topic name, the subscriber will be able
-        // to distiguish the
-        // messages produced by different rule instances of the same type via the
-        // Source fields of the message.
-        // There the name of the rule instance, which produced the message, must be
-        // listed.
+        /// The ParentTopic labels the message (e.g. "nn:RuleEngine/LineCrossing").
+        /// The real message can extend the ParentTopic
+        /// by for example the name of the instaniated rule (e.g.
+        /// "nn:RuleEngine/LineCrossing/corssMyFirstLine").
+        /// Even without knowing the complete topic name, the subscriber will be able
+        /// to distiguish the
+        /// messages produced by different rule instances of the same type via the
+        /// Source fields of the message.
+        /// There the name of the rule instance, which produced the message, must be
+        /// listed.
         #[yaserde(prefix = "tt", rename = "ParentTopic")]
         pub parent_topic: String,
 
-        // Set of tokens producing this message. The list may only contain
-        // SimpleItemDescription items.
-        // The set of tokens identify the component within the WS-Endpoint, which is
-        // responsible for the producing the message.
+        /// Set of tokens producing this message. The list may only contain
+        /// SimpleItemDescription items.
+        /// The set of tokens identify the component within the WS-Endpoint, which is
+        /// responsible for the producing the message.
         #[yaserde(prefix = "tt", rename = "Source")]
         pub source: Option<ItemListDescription>,
 
-        // Describes optional message payload parameters that may be used as key.
-        // E.g. object IDs of tracked objects are conveyed as key.
+        /// Describes optional message payload parameters that may be used as key.
+        /// E.g. object IDs of tracked objects are conveyed as key.
         #[yaserde(prefix = "tt", rename = "Key")]
         pub key: Option<ItemListDescription>,
 
-        // Describes the payload of the message.
+        /// Describes the payload of the message.
         #[yaserde(prefix = "tt", rename = "Data")]
         pub data: Option<ItemListDescription>,
 
         #[yaserde(prefix = "tt", rename = "Extension")]
         pub extension: Option<MessageDescriptionExtension>,
 
-        // Must be set to true when the described Message relates to a property. An
-        // alternative term of "property" is a "state" in contrast to a pure event,
-        // which contains relevant information for only a single point in time.
+        /// Must be set to true when the described Message relates to a property. An
+        /// alternative term of "property" is a "state" in contrast to a pure event,
+        /// which contains relevant information for only a single point in time.
         #[yaserde(attribute, rename = "IsProperty")]
         pub is_property: Option<bool>,
     }
@@ -7191,11 +7267,11 @@ impl Validate for ConfigDescriptionExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct SupportedRules {
-    // Lists the location of all schemas that are referenced in the rules.
+    /// Lists the location of all schemas that are referenced in the rules.
     #[yaserde(prefix = "tt", rename = "RuleContentSchemaLocation")]
     pub rule_content_schema_location: Vec<String>,
 
-    // List of rules supported by the Video Analytics configuration..
+    /// List of rules supported by the Video Analytics configuration..
     #[yaserde(prefix = "tt", rename = "RuleDescription")]
     pub rule_description: Vec<ConfigDescription>,
 
@@ -7214,13 +7290,13 @@ impl Validate for SupportedRulesExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct SupportedAnalyticsModules {
-    // It optionally contains a list of URLs that provide the location of schema
-    // files.
-    // These schema files describe the types and elements used in the analytics
-    // module descriptions.
-    // Analytics module descriptions that reference types or elements imported
-    // from any ONVIF defined schema files
-    // need not explicitly list those schema files.
+    /// It optionally contains a list of URLs that provide the location of schema
+    /// files.
+    /// These schema files describe the types and elements used in the analytics
+    /// module descriptions.
+    /// Analytics module descriptions that reference types or elements imported
+    /// from any ONVIF defined schema files
+    /// need not explicitly list those schema files.
     #[yaserde(prefix = "tt", rename = "AnalyticsModuleContentSchemaLocation")]
     pub analytics_module_content_schema_location: Vec<String>,
 
@@ -7242,7 +7318,7 @@ impl Validate for SupportedAnalyticsModulesExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct PolygonConfiguration {
-    // Contains Polygon configuration for rule parameters
+    /// Contains Polygon configuration for rule parameters
     #[yaserde(prefix = "tt", rename = "Polygon")]
     pub polygon: Polygon,
 }
@@ -7252,7 +7328,7 @@ impl Validate for PolygonConfiguration {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct PolylineArray {
-    // Contains array of Polyline
+    /// Contains array of Polyline
     #[yaserde(prefix = "tt", rename = "Segment")]
     pub segment: Vec<Polyline>,
 
@@ -7271,7 +7347,7 @@ impl Validate for PolylineArrayExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct PolylineArrayConfiguration {
-    // Contains PolylineArray configuration data
+    /// Contains PolylineArray configuration data
     #[yaserde(prefix = "tt", rename = "PolylineArray")]
     pub polyline_array: PolylineArray,
 }
@@ -7281,9 +7357,9 @@ impl Validate for PolylineArrayConfiguration {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct MotionExpression {
-    // Motion Expression data structure contains motion expression which is
-    // based on Scene Descriptor schema with XPATH syntax. The Type argument
-    // could allow introduction of different dialects
+    /// Motion Expression data structure contains motion expression which is
+    /// based on Scene Descriptor schema with XPATH syntax. The Type argument
+    /// could allow introduction of different dialects
     #[yaserde(prefix = "tt", rename = "Expression")]
     pub expression: String,
 
@@ -7296,7 +7372,7 @@ impl Validate for MotionExpression {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct MotionExpressionConfiguration {
-    // Contains Rule MotionExpression configuration
+    /// Contains Rule MotionExpression configuration
     #[yaserde(prefix = "tt", rename = "MotionExpression")]
     pub motion_expression: MotionExpression,
 }
@@ -7306,90 +7382,90 @@ impl Validate for MotionExpressionConfiguration {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct CellLayout {
-    // Mapping of the cell grid to the Video frame. The cell grid is starting
-    // from the upper left corner and x dimension is going from left to right
-    // and the y dimension from up to down.
+    /// Mapping of the cell grid to the Video frame. The cell grid is starting
+    /// from the upper left corner and x dimension is going from left to right
+    /// and the y dimension from up to down.
     #[yaserde(prefix = "tt", rename = "Transformation")]
     pub transformation: Transformation,
 
-    // Number of columns of the cell grid (x dimension)
+    /// Number of columns of the cell grid (x dimension)
     #[yaserde(attribute, rename = "Columns")]
     pub columns: xs::Integer,
 
-    // Number of rows of the cell grid (y dimension)
+    /// Number of rows of the cell grid (y dimension)
     #[yaserde(attribute, rename = "Rows")]
     pub rows: xs::Integer,
 }
 
 impl Validate for CellLayout {}
 
-// Configuration of the streaming and coding settings of a Video window.
+/// Configuration of the streaming and coding settings of a Video window.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct PaneConfiguration {
-    // Optional name of the pane configuration.
+    /// Optional name of the pane configuration.
     #[yaserde(prefix = "tt", rename = "PaneName")]
     pub pane_name: Option<String>,
 
-    // If the device has audio outputs, this element contains a pointer to the
-    // audio output that is associated with the pane. A client
-    // can retrieve the available audio outputs of a device using the
-    // GetAudioOutputs command of the DeviceIO service.
+    /// If the device has audio outputs, this element contains a pointer to the
+    /// audio output that is associated with the pane. A client
+    /// can retrieve the available audio outputs of a device using the
+    /// GetAudioOutputs command of the DeviceIO service.
     #[yaserde(prefix = "tt", rename = "AudioOutputToken")]
     pub audio_output_token: Option<ReferenceToken>,
 
-    // If the device has audio sources, this element contains a pointer to the
-    // audio source that is associated with this pane.
-    // The audio connection from a decoder device to the NVT is established
-    // using the backchannel mechanism. A client can retrieve the available
-    // audio sources of a device using the GetAudioSources command of the
-    // DeviceIO service.
+    /// If the device has audio sources, this element contains a pointer to the
+    /// audio source that is associated with this pane.
+    /// The audio connection from a decoder device to the NVT is established
+    /// using the backchannel mechanism. A client can retrieve the available
+    /// audio sources of a device using the GetAudioSources command of the
+    /// DeviceIO service.
     #[yaserde(prefix = "tt", rename = "AudioSourceToken")]
     pub audio_source_token: Option<ReferenceToken>,
 
-    // The configuration of the audio encoder including codec, bitrate
-    // and sample rate.
+    /// The configuration of the audio encoder including codec, bitrate
+    /// and sample rate.
     #[yaserde(prefix = "tt", rename = "AudioEncoderConfiguration")]
     pub audio_encoder_configuration: Option<AudioEncoderConfiguration>,
 
-    // A pointer to a Receiver that has the necessary information to receive
-    // data from a Transmitter. This Receiver can be connected and the network
-    // video decoder displays the received data on the specified outputs. A
-    // client can retrieve the available Receivers using the
-    // GetReceivers command of the Receiver Service.
+    /// A pointer to a Receiver that has the necessary information to receive
+    /// data from a Transmitter. This Receiver can be connected and the network
+    /// video decoder displays the received data on the specified outputs. A
+    /// client can retrieve the available Receivers using the
+    /// GetReceivers command of the Receiver Service.
     #[yaserde(prefix = "tt", rename = "ReceiverToken")]
     pub receiver_token: Option<ReferenceToken>,
 
-    // A unique identifier in the display device.
+    /// A unique identifier in the display device.
     #[yaserde(prefix = "tt", rename = "Token")]
     pub token: ReferenceToken,
 }
 
 impl Validate for PaneConfiguration {}
 
-// A pane layout describes one Video window of a display. It links a pane
-// configuration to a region of the screen.
+/// A pane layout describes one Video window of a display. It links a pane
+/// configuration to a region of the screen.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct PaneLayout {
-    // Reference to the configuration of the streaming and coding parameters.
+    /// Reference to the configuration of the streaming and coding parameters.
     #[yaserde(prefix = "tt", rename = "Pane")]
     pub pane: ReferenceToken,
 
-    // Describes the location and size of the area on the monitor. The area
-    // coordinate values are espressed in normalized units [-1.0, 1.0].
+    /// Describes the location and size of the area on the monitor. The area
+    /// coordinate values are espressed in normalized units [-1.0, 1.0].
     #[yaserde(prefix = "tt", rename = "Area")]
     pub area: Rectangle,
 }
 
 impl Validate for PaneLayout {}
 
-// A layout describes a set of Video windows that are displayed simultaniously
-// on a display.
+/// A layout describes a set of Video windows that are displayed simultaniously
+/// on a display.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Layout {
-    // List of panes assembling the display layout.
+    /// List of panes assembling the display layout.
     #[yaserde(prefix = "tt", rename = "PaneLayout")]
     pub pane_layout: Vec<PaneLayout>,
 
@@ -7405,34 +7481,34 @@ pub struct LayoutExtension {}
 
 impl Validate for LayoutExtension {}
 
-// This type contains the Audio and Video coding capabilities of a display
-// service.
+/// This type contains the Audio and Video coding capabilities of a display
+/// service.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct CodingCapabilities {
-    // If the device supports audio encoding this section describes the
-    // supported codecs and their configuration.
+    /// If the device supports audio encoding this section describes the
+    /// supported codecs and their configuration.
     #[yaserde(prefix = "tt", rename = "AudioEncodingCapabilities")]
     pub audio_encoding_capabilities: Option<AudioEncoderConfigurationOptions>,
 
-    // If the device supports audio decoding this section describes the
-    // supported codecs and their settings.
+    /// If the device supports audio decoding this section describes the
+    /// supported codecs and their settings.
     #[yaserde(prefix = "tt", rename = "AudioDecodingCapabilities")]
     pub audio_decoding_capabilities: Option<AudioDecoderConfigurationOptions>,
 
-    // This section describes the supported video codesc and their
-    // configuration.
+    /// This section describes the supported video codesc and their
+    /// configuration.
     #[yaserde(prefix = "tt", rename = "VideoDecodingCapabilities")]
     pub video_decoding_capabilities: VideoDecoderConfigurationOptions,
 }
 
 impl Validate for CodingCapabilities {}
 
-// The options supported for a display layout.
+/// The options supported for a display layout.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct LayoutOptions {
-    // Lists the possible Pane Layouts of the Video Output
+    /// Lists the possible Pane Layouts of the Video Output
     #[yaserde(prefix = "tt", rename = "PaneLayoutOptions")]
     pub pane_layout_options: Vec<PaneLayoutOptions>,
 
@@ -7448,12 +7524,12 @@ pub struct LayoutOptionsExtension {}
 
 impl Validate for LayoutOptionsExtension {}
 
-// Description of a pane layout describing a complete display layout.
+/// Description of a pane layout describing a complete display layout.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct PaneLayoutOptions {
-    // List of areas assembling a layout. Coordinate values are in the range
-    // [-1.0, 1.0].
+    /// List of areas assembling a layout. Coordinate values are in the range
+    /// [-1.0, 1.0].
     #[yaserde(prefix = "tt", rename = "Area")]
     pub area: Vec<Rectangle>,
 
@@ -7469,53 +7545,54 @@ pub struct PaneOptionExtension {}
 
 impl Validate for PaneOptionExtension {}
 
-// Description of a receiver, including its token and configuration.
+/// Description of a receiver, including its token and configuration.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Receiver {
-    // Unique identifier of the receiver.
+    /// Unique identifier of the receiver.
     #[yaserde(prefix = "tt", rename = "Token")]
     pub token: ReferenceToken,
 
-    // Describes the configuration of the receiver.
+    /// Describes the configuration of the receiver.
     #[yaserde(prefix = "tt", rename = "Configuration")]
     pub configuration: ReceiverConfiguration,
 }
 
 impl Validate for Receiver {}
 
-// Describes the configuration of a receiver.
+/// Describes the configuration of a receiver.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ReceiverConfiguration {
-    // The following connection modes are defined:
+    /// The following connection modes are defined:
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: ReceiverMode,
 
-    // Details of the URI to which the receiver should connect.
+    /// Details of the URI to which the receiver should connect.
     #[yaserde(prefix = "tt", rename = "MediaUri")]
     pub media_uri: String,
 
-    // Stream connection parameters.
+    /// Stream connection parameters.
     #[yaserde(prefix = "tt", rename = "StreamSetup")]
     pub stream_setup: StreamSetup,
 }
 
 impl Validate for ReceiverConfiguration {}
 
-// Specifies a receiver connection mode.
+/// Specifies a receiver connection mode.
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum ReceiverMode {
-    // The receiver connects on demand, as required by consumers of the media
-    // streams.
+    /// The receiver connects on demand, as required by consumers of the media
+    /// streams.
     AutoConnect,
-    // The receiver attempts to maintain a persistent connection to the
-    // configured endpoint.
+    /// The receiver attempts to maintain a persistent connection to the
+    /// configured endpoint.
     AlwaysConnect,
-    // The receiver does not attempt to connect.
+    /// The receiver does not attempt to connect.
     NeverConnect,
-    // This case should never happen.
+    /// This case should never happen.
     Unknown,
     __Unknown__(String),
 }
@@ -7528,17 +7605,18 @@ impl Default for ReceiverMode {
 
 impl Validate for ReceiverMode {}
 
-// Specifies the current connection state of the receiver.
+/// Specifies the current connection state of the receiver.
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum ReceiverState {
-    // The receiver is not connected.
+    /// The receiver is not connected.
     NotConnected,
-    // The receiver is attempting to connect.
+    /// The receiver is attempting to connect.
     Connecting,
-    // The receiver is connected.
+    /// The receiver is connected.
     Connected,
-    // This case should never happen.
+    /// This case should never happen.
     Unknown,
     __Unknown__(String),
 }
@@ -7551,16 +7629,16 @@ impl Default for ReceiverState {
 
 impl Validate for ReceiverState {}
 
-// Contains information about a receiver's current state.
+/// Contains information about a receiver's current state.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ReceiverStateInformation {
-    // The connection state of the receiver may have one of the following
-    // states:
+    /// The connection state of the receiver may have one of the following
+    /// states:
     #[yaserde(prefix = "tt", rename = "State")]
     pub state: ReceiverState,
 
-    // Indicates whether or not the receiver was created automatically.
+    /// Indicates whether or not the receiver was created automatically.
     #[yaserde(prefix = "tt", rename = "AutoCreated")]
     pub auto_created: bool,
 }
@@ -7594,7 +7672,7 @@ pub struct TrackReference(pub ReferenceToken);
 
 impl Validate for TrackReference {}
 
-#[derive(Default, PartialEq, Debug, UtilsTupleIo, UtilsDefaultSerde)]
+#[derive(Default, Clone, PartialEq, Eq, Hash, Debug, UtilsTupleIo, UtilsDefaultSerde)]
 pub struct Description(pub String);
 
 impl Validate for Description {}
@@ -7614,42 +7692,42 @@ impl Validate for DateTimeRange {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct RecordingSummary {
-    // The earliest point in time where there is recorded data on the device.
+    /// The earliest point in time where there is recorded data on the device.
     #[yaserde(prefix = "tt", rename = "DataFrom")]
     pub data_from: xs::DateTime,
 
-    // The most recent point in time where there is recorded data on the device.
+    /// The most recent point in time where there is recorded data on the device.
     #[yaserde(prefix = "tt", rename = "DataUntil")]
     pub data_until: xs::DateTime,
 
-    // The device contains this many recordings.
+    /// The device contains this many recordings.
     #[yaserde(prefix = "tt", rename = "NumberRecordings")]
     pub number_recordings: i32,
 }
 
 impl Validate for RecordingSummary {}
 
-// A structure for defining a limited scope when searching in recorded data.
+/// A structure for defining a limited scope when searching in recorded data.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct SearchScope {
-    // A list of sources that are included in the scope. If this list is
-    // included, only data from one of these sources shall be searched.
+    /// A list of sources that are included in the scope. If this list is
+    /// included, only data from one of these sources shall be searched.
     #[yaserde(prefix = "tt", rename = "IncludedSources")]
     pub included_sources: Vec<SourceReference>,
 
-    // A list of recordings that are included in the scope. If this list is
-    // included, only data from one of these recordings shall be searched.
+    /// A list of recordings that are included in the scope. If this list is
+    /// included, only data from one of these recordings shall be searched.
     #[yaserde(prefix = "tt", rename = "IncludedRecordings")]
     pub included_recordings: Vec<RecordingReference>,
 
-    // An xpath expression used to specify what recordings to search. Only those
-    // recordings with an RecordingInformation structure that matches the filter
-    // shall be searched.
+    /// An xpath expression used to specify what recordings to search. Only those
+    /// recordings with an RecordingInformation structure that matches the filter
+    /// shall be searched.
     #[yaserde(prefix = "tt", rename = "RecordingInformationFilter")]
     pub recording_information_filter: Option<XpathExpression>,
 
-    // Extension point
+    /// Extension point
     #[yaserde(prefix = "tt", rename = "Extension")]
     pub extension: Option<SearchScopeExtension>,
 }
@@ -7671,15 +7749,15 @@ impl Validate for EventFilter {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct PtzpositionFilter {
-    // The lower boundary of the PTZ volume to look for.
+    /// The lower boundary of the PTZ volume to look for.
     #[yaserde(prefix = "tt", rename = "MinPosition")]
     pub min_position: Ptzvector,
 
-    // The upper boundary of the PTZ volume to look for.
+    /// The upper boundary of the PTZ volume to look for.
     #[yaserde(prefix = "tt", rename = "MaxPosition")]
     pub max_position: Ptzvector,
 
-    // If true, search for when entering the specified PTZ volume.
+    /// If true, search for when entering the specified PTZ volume.
     #[yaserde(prefix = "tt", rename = "EnterOrExit")]
     pub enter_or_exit: bool,
 }
@@ -7703,13 +7781,13 @@ impl Validate for XpathExpression {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct FindRecordingResultList {
-    // The state of the search when the result is returned. Indicates if there
-    // can be more results, or if the search is completed.
+    /// The state of the search when the result is returned. Indicates if there
+    /// can be more results, or if the search is completed.
     #[yaserde(prefix = "tt", rename = "SearchState")]
     pub search_state: SearchState,
 
-    // A RecordingInformation structure for each found recording matching the
-    // search.
+    /// A RecordingInformation structure for each found recording matching the
+    /// search.
     #[yaserde(prefix = "tt", rename = "RecordingInformation")]
     pub recording_information: Vec<RecordingInformation>,
 }
@@ -7719,12 +7797,12 @@ impl Validate for FindRecordingResultList {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct FindEventResultList {
-    // The state of the search when the result is returned. Indicates if there
-    // can be more results, or if the search is completed.
+    /// The state of the search when the result is returned. Indicates if there
+    /// can be more results, or if the search is completed.
     #[yaserde(prefix = "tt", rename = "SearchState")]
     pub search_state: SearchState,
 
-    // A FindEventResult structure for each found event matching the search.
+    /// A FindEventResult structure for each found event matching the search.
     #[yaserde(prefix = "tt", rename = "Result")]
     pub result: Vec<FindEventResult>,
 }
@@ -7734,27 +7812,27 @@ impl Validate for FindEventResultList {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct FindEventResult {
-    // The recording where this event was found. Empty string if no recording is
-    // associated with this event.
+    /// The recording where this event was found. Empty string if no recording is
+    /// associated with this event.
     #[yaserde(prefix = "tt", rename = "RecordingToken")]
     pub recording_token: RecordingReference,
 
-    // A reference to the track where this event was found. Empty string if no
-    // track is associated with this event.
+    /// A reference to the track where this event was found. Empty string if no
+    /// track is associated with this event.
     #[yaserde(prefix = "tt", rename = "TrackToken")]
     pub track_token: TrackReference,
 
-    // The time when the event occured.
+    /// The time when the event occured.
     #[yaserde(prefix = "tt", rename = "Time")]
     pub time: xs::DateTime,
 
-    // The description of the event.
+    /// The description of the event.
     #[yaserde(prefix = "tt", rename = "Event")]
     pub event: wsnt::NotificationMessageHolderType,
 
-    // If true, indicates that the event is a virtual event generated for this
-    // particular search session to give the state of a property at the start
-    // time of the search.
+    /// If true, indicates that the event is a virtual event generated for this
+    /// particular search session to give the state of a property at the start
+    /// time of the search.
     #[yaserde(prefix = "tt", rename = "StartStateEvent")]
     pub start_state_event: bool,
 }
@@ -7764,13 +7842,13 @@ impl Validate for FindEventResult {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct FindPTZPositionResultList {
-    // The state of the search when the result is returned. Indicates if there
-    // can be more results, or if the search is completed.
+    /// The state of the search when the result is returned. Indicates if there
+    /// can be more results, or if the search is completed.
     #[yaserde(prefix = "tt", rename = "SearchState")]
     pub search_state: SearchState,
 
-    // A FindPTZPositionResult structure for each found PTZ position matching
-    // the search.
+    /// A FindPTZPositionResult structure for each found PTZ position matching
+    /// the search.
     #[yaserde(prefix = "tt", rename = "Result")]
     pub result: Vec<FindPTZPositionResult>,
 }
@@ -7780,19 +7858,19 @@ impl Validate for FindPTZPositionResultList {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct FindPTZPositionResult {
-    // A reference to the recording containing the PTZ position.
+    /// A reference to the recording containing the PTZ position.
     #[yaserde(prefix = "tt", rename = "RecordingToken")]
     pub recording_token: RecordingReference,
 
-    // A reference to the metadata track containing the PTZ position.
+    /// A reference to the metadata track containing the PTZ position.
     #[yaserde(prefix = "tt", rename = "TrackToken")]
     pub track_token: TrackReference,
 
-    // The time when the PTZ position was valid.
+    /// The time when the PTZ position was valid.
     #[yaserde(prefix = "tt", rename = "Time")]
     pub time: xs::DateTime,
 
-    // The PTZ position.
+    /// The PTZ position.
     #[yaserde(prefix = "tt", rename = "Position")]
     pub position: Ptzvector,
 }
@@ -7802,13 +7880,13 @@ impl Validate for FindPTZPositionResult {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct FindMetadataResultList {
-    // The state of the search when the result is returned. Indicates if there
-    // can be more results, or if the search is completed.
+    /// The state of the search when the result is returned. Indicates if there
+    /// can be more results, or if the search is completed.
     #[yaserde(prefix = "tt", rename = "SearchState")]
     pub search_state: SearchState,
 
-    // A FindMetadataResult structure for each found set of Metadata matching
-    // the search.
+    /// A FindMetadataResult structure for each found set of Metadata matching
+    /// the search.
     #[yaserde(prefix = "tt", rename = "Result")]
     pub result: Vec<FindMetadataResult>,
 }
@@ -7818,16 +7896,16 @@ impl Validate for FindMetadataResultList {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct FindMetadataResult {
-    // A reference to the recording containing the metadata.
+    /// A reference to the recording containing the metadata.
     #[yaserde(prefix = "tt", rename = "RecordingToken")]
     pub recording_token: RecordingReference,
 
-    // A reference to the metadata track containing the matching metadata.
+    /// A reference to the metadata track containing the matching metadata.
     #[yaserde(prefix = "tt", rename = "TrackToken")]
     pub track_token: TrackReference,
 
-    // The point in time when the matching metadata occurs in the metadata
-    // track.
+    /// The point in time when the matching metadata occurs in the metadata
+    /// track.
     #[yaserde(prefix = "tt", rename = "Time")]
     pub time: xs::DateTime,
 }
@@ -7836,15 +7914,16 @@ impl Validate for FindMetadataResult {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum SearchState {
-    // The search is queued and not yet started.
+    /// The search is queued and not yet started.
     Queued,
-    // The search is underway and not yet completed.
+    /// The search is underway and not yet completed.
     Searching,
-    // The search has been completed and no new results will be found.
+    /// The search has been completed and no new results will be found.
     Completed,
-    // The state of the search is unknown. (This is not a valid response from
-    // GetSearchState.)
+    /// The state of the search is unknown. (This is not a valid response from
+    /// GetSearchState.)
     Unknown,
     __Unknown__(String),
 }
@@ -7868,12 +7947,12 @@ pub struct RecordingInformation {
     #[yaserde(prefix = "tt", rename = "RecordingToken")]
     pub recording_token: RecordingReference,
 
-    // Information about the source of the recording. This gives a description
-    // of where the data in the recording comes from. Since a single
-    // recording is intended to record related material, there is just one
-    // source. It is indicates the physical location or the
-    // major data source for the recording. Currently the recordingconfiguration
-    // cannot describe each individual data source.
+    /// Information about the source of the recording. This gives a description
+    /// of where the data in the recording comes from. Since a single
+    /// recording is intended to record related material, there is just one
+    /// source. It is indicates the physical location or the
+    /// major data source for the recording. Currently the recordingconfiguration
+    /// cannot describe each individual data source.
     #[yaserde(prefix = "tt", rename = "Source")]
     pub source: RecordingSourceInformation,
 
@@ -7886,8 +7965,8 @@ pub struct RecordingInformation {
     #[yaserde(prefix = "tt", rename = "Content")]
     pub content: Description,
 
-    // Basic information about the track. Note that a track may represent a
-    // single contiguous time span or consist of multiple slices.
+    /// Basic information about the track. Note that a track may represent a
+    /// single contiguous time span or consist of multiple slices.
     #[yaserde(prefix = "tt", rename = "Track")]
     pub track: Vec<TrackInformation>,
 
@@ -7897,34 +7976,34 @@ pub struct RecordingInformation {
 
 impl Validate for RecordingInformation {}
 
-// A set of informative desciptions of a data source. The Search searvice allows
-// a client to filter on recordings based on information in this structure.
+/// A set of informative desciptions of a data source. The Search searvice allows
+/// a client to filter on recordings based on information in this structure.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct RecordingSourceInformation {
-    // Identifier for the source chosen by the client that creates the
-    // structure.
-    // This identifier is opaque to the device. Clients may use any type of URI
-    // for this field. A device shall support at least 128 characters.
+    /// Identifier for the source chosen by the client that creates the
+    /// structure.
+    /// This identifier is opaque to the device. Clients may use any type of URI
+    /// for this field. A device shall support at least 128 characters.
     #[yaserde(prefix = "tt", rename = "SourceId")]
     pub source_id: String,
 
-    // Informative user readable name of the source, e.g. "Camera23". A device
-    // shall support at least 20 characters.
+    /// Informative user readable name of the source, e.g. "Camera23". A device
+    /// shall support at least 20 characters.
     #[yaserde(prefix = "tt", rename = "Name")]
     pub name: Name,
 
-    // Informative description of the physical location of the source, e.g. the
-    // coordinates on a map.
+    /// Informative description of the physical location of the source, e.g. the
+    /// coordinates on a map.
     #[yaserde(prefix = "tt", rename = "Location")]
     pub location: Description,
 
-    // Informative description of the source.
+    /// Informative description of the source.
     #[yaserde(prefix = "tt", rename = "Description")]
     pub description: Description,
 
-    // URI provided by the service supplying data to be recorded. A device shall
-    // support at least 128 characters.
+    /// URI provided by the service supplying data to be recorded. A device shall
+    /// support at least 128 characters.
     #[yaserde(prefix = "tt", rename = "Address")]
     pub address: String,
 }
@@ -7933,13 +8012,14 @@ impl Validate for RecordingSourceInformation {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum RecordingStatus {
     Initiated,
     Recording,
     Stopped,
     Removing,
     Removed,
-    // This case should never happen.
+    /// This case should never happen.
     Unknown,
     __Unknown__(String),
 }
@@ -7958,20 +8038,20 @@ pub struct TrackInformation {
     #[yaserde(prefix = "tt", rename = "TrackToken")]
     pub track_token: TrackReference,
 
-    // Type of the track: "Video", "Audio" or "Metadata".
-    // The track shall only be able to hold data of that type.
+    /// Type of the track: "Video", "Audio" or "Metadata".
+    /// The track shall only be able to hold data of that type.
     #[yaserde(prefix = "tt", rename = "TrackType")]
     pub track_type: TrackType,
 
-    // Informative description of the contents of the track.
+    /// Informative description of the contents of the track.
     #[yaserde(prefix = "tt", rename = "Description")]
     pub description: Description,
 
-    // The start date and time of the oldest recorded data in the track.
+    /// The start date and time of the oldest recorded data in the track.
     #[yaserde(prefix = "tt", rename = "DataFrom")]
     pub data_from: xs::DateTime,
 
-    // The stop date and time of the newest recorded data in the track.
+    /// The stop date and time of the newest recorded data in the track.
     #[yaserde(prefix = "tt", rename = "DataTo")]
     pub data_to: xs::DateTime,
 }
@@ -7980,11 +8060,12 @@ impl Validate for TrackInformation {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum TrackType {
     Video,
     Audio,
     Metadata,
-    // Placeholder for future extension.
+    /// Placeholder for future extension.
     Extended,
     __Unknown__(String),
 }
@@ -7997,26 +8078,26 @@ impl Default for TrackType {
 
 impl Validate for TrackType {}
 
-// A set of media attributes valid for a recording at a point in time or for a
-// time interval.
+/// A set of media attributes valid for a recording at a point in time or for a
+/// time interval.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct MediaAttributes {
-    // A reference to the recording that has these attributes.
+    /// A reference to the recording that has these attributes.
     #[yaserde(prefix = "tt", rename = "RecordingToken")]
     pub recording_token: RecordingReference,
 
-    // A set of attributes for each track.
+    /// A set of attributes for each track.
     #[yaserde(prefix = "tt", rename = "TrackAttributes")]
     pub track_attributes: Vec<TrackAttributes>,
 
-    // The attributes are valid from this point in time in the recording.
+    /// The attributes are valid from this point in time in the recording.
     #[yaserde(prefix = "tt", rename = "From")]
     pub from: xs::DateTime,
 
-    // The attributes are valid until this point in time in the recording. Can
-    // be equal to 'From' to indicate that the attributes are only known to be
-    // valid for this particular point in time.
+    /// The attributes are valid until this point in time in the recording. Can
+    /// be equal to 'From' to indicate that the attributes are only known to be
+    /// valid for this particular point in time.
     #[yaserde(prefix = "tt", rename = "Until")]
     pub until: xs::DateTime,
 }
@@ -8026,23 +8107,23 @@ impl Validate for MediaAttributes {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct TrackAttributes {
-    // The basic information about the track. Note that a track may represent a
-    // single contiguous time span or consist of multiple slices.
+    /// The basic information about the track. Note that a track may represent a
+    /// single contiguous time span or consist of multiple slices.
     #[yaserde(prefix = "tt", rename = "TrackInformation")]
     pub track_information: TrackInformation,
 
-    // If the track is a video track, exactly one of this structure shall be
-    // present and contain the video attributes.
+    /// If the track is a video track, exactly one of this structure shall be
+    /// present and contain the video attributes.
     #[yaserde(prefix = "tt", rename = "VideoAttributes")]
     pub video_attributes: Option<VideoAttributes>,
 
-    // If the track is an audio track, exactly one of this structure shall be
-    // present and contain the audio attributes.
+    /// If the track is an audio track, exactly one of this structure shall be
+    /// present and contain the audio attributes.
     #[yaserde(prefix = "tt", rename = "AudioAttributes")]
     pub audio_attributes: Option<AudioAttributes>,
 
-    // If the track is an metadata track, exactly one of this structure shall be
-    // present and contain the metadata attributes.
+    /// If the track is an metadata track, exactly one of this structure shall be
+    /// present and contain the metadata attributes.
     #[yaserde(prefix = "tt", rename = "MetadataAttributes")]
     pub metadata_attributes: Option<MetadataAttributes>,
 
@@ -8061,24 +8142,24 @@ impl Validate for TrackAttributesExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct VideoAttributes {
-    // Average bitrate in kbps.
+    /// Average bitrate in kbps.
     #[yaserde(prefix = "tt", rename = "Bitrate")]
     pub bitrate: Option<i32>,
 
-    // The width of the video in pixels.
+    /// The width of the video in pixels.
     #[yaserde(prefix = "tt", rename = "Width")]
     pub width: i32,
 
-    // The height of the video in pixels.
+    /// The height of the video in pixels.
     #[yaserde(prefix = "tt", rename = "Height")]
     pub height: i32,
 
-    // Video encoding of the track. Use value from tt:VideoEncoding for MPEG4.
-    // Otherwise use values from tt:VideoEncodingMimeNames and
+    /// Video encoding of the track. Use value from tt:VideoEncoding for MPEG4.
+    /// Otherwise use values from tt:VideoEncodingMimeNames and
     #[yaserde(prefix = "tt", rename = "Encoding")]
     pub encoding: String,
 
-    // Average framerate in frames per second.
+    /// Average framerate in frames per second.
     #[yaserde(prefix = "tt", rename = "Framerate")]
     pub framerate: f64,
 }
@@ -8088,16 +8169,16 @@ impl Validate for VideoAttributes {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct AudioAttributes {
-    // The bitrate in kbps.
+    /// The bitrate in kbps.
     #[yaserde(prefix = "tt", rename = "Bitrate")]
     pub bitrate: Option<i32>,
 
-    // Audio encoding of the track. Use values from tt:AudioEncoding for G711
-    // and AAC. Otherwise use values from tt:AudioEncodingMimeNames and
+    /// Audio encoding of the track. Use values from tt:AudioEncoding for G711
+    /// and AAC. Otherwise use values from tt:AudioEncodingMimeNames and
     #[yaserde(prefix = "tt", rename = "Encoding")]
     pub encoding: String,
 
-    // The sample rate in kHz.
+    /// The sample rate in kHz.
     #[yaserde(prefix = "tt", rename = "Samplerate")]
     pub samplerate: i32,
 }
@@ -8107,24 +8188,24 @@ impl Validate for AudioAttributes {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct MetadataAttributes {
-    // Indicates that there can be PTZ data in the metadata track in the
-    // specified time interval.
+    /// Indicates that there can be PTZ data in the metadata track in the
+    /// specified time interval.
     #[yaserde(prefix = "tt", rename = "CanContainPTZ")]
     pub can_contain_ptz: bool,
 
-    // Indicates that there can be analytics data in the metadata track in the
-    // specified time interval.
+    /// Indicates that there can be analytics data in the metadata track in the
+    /// specified time interval.
     #[yaserde(prefix = "tt", rename = "CanContainAnalytics")]
     pub can_contain_analytics: bool,
 
-    // Indicates that there can be notifications in the metadata track in the
-    // specified time interval.
+    /// Indicates that there can be notifications in the metadata track in the
+    /// specified time interval.
     #[yaserde(prefix = "tt", rename = "CanContainNotifications")]
     pub can_contain_notifications: bool,
 
-    // List of all PTZ spaces active for recording. Note that events are only
-    // recorded on position changes and the actual point of recording may not
-    // necessarily contain an event of the specified type.
+    /// List of all PTZ spaces active for recording. Note that events are only
+    /// recorded on position changes and the actual point of recording may not
+    /// necessarily contain an event of the specified type.
     #[yaserde(attribute, rename = "PtzSpaces")]
     pub ptz_spaces: Option<StringAttrList>,
 }
@@ -8139,24 +8220,24 @@ impl Validate for RecordingJobReference {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct RecordingConfiguration {
-    // Information about the source of the recording.
+    /// Information about the source of the recording.
     #[yaserde(prefix = "tt", rename = "Source")]
     pub source: RecordingSourceInformation,
 
-    // Informative description of the source.
+    /// Informative description of the source.
     #[yaserde(prefix = "tt", rename = "Content")]
     pub content: Description,
 
-    // Sspecifies the maximum time that data in any track within the
-    // recording shall be stored. The device shall delete any data older than
-    // the maximum retention
-    // time. Such data shall not be accessible anymore. If the
-    // MaximumRetentionPeriod is set to 0,
-    // the device shall not limit the retention time of stored data, except by
-    // resource constraints.
-    // Whatever the value of MaximumRetentionTime, the device may automatically
-    // delete
-    // recordings to free up storage space for new recordings.
+    /// Sspecifies the maximum time that data in any track within the
+    /// recording shall be stored. The device shall delete any data older than
+    /// the maximum retention
+    /// time. Such data shall not be accessible anymore. If the
+    /// MaximumRetentionPeriod is set to 0,
+    /// the device shall not limit the retention time of stored data, except by
+    /// resource constraints.
+    /// Whatever the value of MaximumRetentionTime, the device may automatically
+    /// delete
+    /// recordings to free up storage space for new recordings.
     #[yaserde(prefix = "tt", rename = "MaximumRetentionTime")]
     pub maximum_retention_time: xs::Duration,
 }
@@ -8166,13 +8247,13 @@ impl Validate for RecordingConfiguration {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct TrackConfiguration {
-    // Type of the track. It shall be equal to the strings “Video”,
-    // “Audio” or “Metadata”. The track shall only be able to hold data
-    // of that type.
+    /// Type of the track. It shall be equal to the strings “Video”,
+    /// “Audio” or “Metadata”. The track shall only be able to hold data
+    /// of that type.
     #[yaserde(prefix = "tt", rename = "TrackType")]
     pub track_type: TrackType,
 
-    // Informative description of the track.
+    /// Informative description of the track.
     #[yaserde(prefix = "tt", rename = "Description")]
     pub description: Description,
 }
@@ -8182,15 +8263,15 @@ impl Validate for TrackConfiguration {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct GetRecordingsResponseItem {
-    // Token of the recording.
+    /// Token of the recording.
     #[yaserde(prefix = "tt", rename = "RecordingToken")]
     pub recording_token: RecordingReference,
 
-    // Configuration of the recording.
+    /// Configuration of the recording.
     #[yaserde(prefix = "tt", rename = "Configuration")]
     pub configuration: RecordingConfiguration,
 
-    // List of tracks.
+    /// List of tracks.
     #[yaserde(prefix = "tt", rename = "Tracks")]
     pub tracks: GetTracksResponseList,
 }
@@ -8200,7 +8281,7 @@ impl Validate for GetRecordingsResponseItem {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct GetTracksResponseList {
-    // Configuration of a track.
+    /// Configuration of a track.
     #[yaserde(prefix = "tt", rename = "Track")]
     pub track: Vec<GetTracksResponseItem>,
 }
@@ -8210,11 +8291,11 @@ impl Validate for GetTracksResponseList {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct GetTracksResponseItem {
-    // Token of the track.
+    /// Token of the track.
     #[yaserde(prefix = "tt", rename = "TrackToken")]
     pub track_token: TrackReference,
 
-    // Configuration of the track.
+    /// Configuration of the track.
     #[yaserde(prefix = "tt", rename = "Configuration")]
     pub configuration: TrackConfiguration,
 }
@@ -8224,42 +8305,42 @@ impl Validate for GetTracksResponseItem {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct RecordingJobConfiguration {
-    // Identifies the recording to which this job shall store the received data.
+    /// Identifies the recording to which this job shall store the received data.
     #[yaserde(prefix = "tt", rename = "RecordingToken")]
     pub recording_token: RecordingReference,
 
-    // The mode of the job. If it is idle, nothing shall happen. If it is
-    // active, the device shall try
-    // to obtain data from the receivers. A client shall use
-    // GetRecordingJobState to determine if data transfer is really taking
-    // place.
+    /// The mode of the job. If it is idle, nothing shall happen. If it is
+    /// active, the device shall try
+    /// to obtain data from the receivers. A client shall use
+    /// GetRecordingJobState to determine if data transfer is really taking
+    /// place.
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: RecordingJobMode,
 
-    // This shall be a non-negative number. If there are multiple recording jobs
-    // that store data to
-    // the same track, the device will only store the data for the recording job
-    // with the highest
-    // priority. The priority is specified per recording job, but the device
-    // shall determine the priority
-    // of each track individually. If there are two recording jobs with the same
-    // priority, the device
-    // shall record the data corresponding to the recording job that was
-    // activated the latest.
+    /// This shall be a non-negative number. If there are multiple recording jobs
+    /// that store data to
+    /// the same track, the device will only store the data for the recording job
+    /// with the highest
+    /// priority. The priority is specified per recording job, but the device
+    /// shall determine the priority
+    /// of each track individually. If there are two recording jobs with the same
+    /// priority, the device
+    /// shall record the data corresponding to the recording job that was
+    /// activated the latest.
     #[yaserde(prefix = "tt", rename = "Priority")]
     pub priority: i32,
 
-    // Source of the recording.
+    /// Source of the recording.
     #[yaserde(prefix = "tt", rename = "Source")]
     pub source: Vec<RecordingJobSource>,
 
     #[yaserde(prefix = "tt", rename = "Extension")]
     pub extension: Option<RecordingJobConfigurationExtension>,
 
-    // This attribute adds an additional requirement for activating the
-    // recording job.
-    // If this optional field is provided the job shall only record if the
-    // schedule exists and is active.
+    /// This attribute adds an additional requirement for activating the
+    /// recording job.
+    /// If this optional field is provided the job shall only record if the
+    /// schedule exists and is active.
     #[yaserde(attribute, rename = "ScheduleToken")]
     pub schedule_token: Option<String>,
 }
@@ -8280,29 +8361,29 @@ impl Validate for RecordingJobConfigurationExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct RecordingJobSource {
-    // This field shall be a reference to the source of the data. The type of
-    // the source
-    // is determined by the attribute Type in the SourceToken structure. If Type
-    // is
-    // http://www.onvif.org/ver10/schema/Receiver, the token is a
-    // ReceiverReference. In this case
-    // the device shall receive the data over the network. If Type is
-    // http://www.onvif.org/ver10/schema/Profile, the token identifies a media
-    // profile, instructing the
-    // device to obtain data from a profile that exists on the local device.
+    /// This field shall be a reference to the source of the data. The type of
+    /// the source
+    /// is determined by the attribute Type in the SourceToken structure. If Type
+    /// is
+    /// http://www.onvif.org/ver10/schema/Receiver, the token is a
+    /// ReceiverReference. In this case
+    /// the device shall receive the data over the network. If Type is
+    /// http://www.onvif.org/ver10/schema/Profile, the token identifies a media
+    /// profile, instructing the
+    /// device to obtain data from a profile that exists on the local device.
     #[yaserde(prefix = "tt", rename = "SourceToken")]
     pub source_token: Option<SourceReference>,
 
-    // If this field is TRUE, and if the SourceToken is omitted, the device
-    // shall create a receiver object (through the receiver service) and assign
-    // the
-    // ReceiverReference to the SourceToken field. When retrieving the
-    // RecordingJobConfiguration
-    // from the device, the AutoCreateReceiver field shall never be present.
+    /// If this field is TRUE, and if the SourceToken is omitted, the device
+    /// shall create a receiver object (through the receiver service) and assign
+    /// the
+    /// ReceiverReference to the SourceToken field. When retrieving the
+    /// RecordingJobConfiguration
+    /// from the device, the AutoCreateReceiver field shall never be present.
     #[yaserde(prefix = "tt", rename = "AutoCreateReceiver")]
     pub auto_create_receiver: Option<bool>,
 
-    // List of tracks associated with the recording.
+    /// List of tracks associated with the recording.
     #[yaserde(prefix = "tt", rename = "Tracks")]
     pub tracks: Vec<RecordingJobTrack>,
 
@@ -8321,16 +8402,16 @@ impl Validate for RecordingJobSourceExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct RecordingJobTrack {
-    // If the received RTSP stream contains multiple tracks of the same type,
-    // the
-    // SourceTag differentiates between those Tracks. This field can be ignored
-    // in case of recording a local source.
+    /// If the received RTSP stream contains multiple tracks of the same type,
+    /// the
+    /// SourceTag differentiates between those Tracks. This field can be ignored
+    /// in case of recording a local source.
     #[yaserde(prefix = "tt", rename = "SourceTag")]
     pub source_tag: String,
 
-    // The destination is the tracktoken of the track to which the device shall
-    // store the
-    // received data.
+    /// The destination is the tracktoken of the track to which the device shall
+    /// store the
+    /// received data.
     #[yaserde(prefix = "tt", rename = "Destination")]
     pub destination: TrackReference,
 }
@@ -8340,16 +8421,16 @@ impl Validate for RecordingJobTrack {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct RecordingJobStateInformation {
-    // Identification of the recording that the recording job records to.
+    /// Identification of the recording that the recording job records to.
     #[yaserde(prefix = "tt", rename = "RecordingToken")]
     pub recording_token: RecordingReference,
 
-    // Holds the aggregated state over the whole RecordingJobInformation
-    // structure.
+    /// Holds the aggregated state over the whole RecordingJobInformation
+    /// structure.
     #[yaserde(prefix = "tt", rename = "State")]
     pub state: RecordingJobState,
 
-    // Identifies the data source of the recording job.
+    /// Identifies the data source of the recording job.
     #[yaserde(prefix = "tt", rename = "Sources")]
     pub sources: Vec<RecordingJobStateSource>,
 
@@ -8373,16 +8454,16 @@ impl Validate for RecordingJobState {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct RecordingJobStateSource {
-    // Identifies the data source of the recording job.
+    /// Identifies the data source of the recording job.
     #[yaserde(prefix = "tt", rename = "SourceToken")]
     pub source_token: SourceReference,
 
-    // Holds the aggregated state over all substructures of
-    // RecordingJobStateSource.
+    /// Holds the aggregated state over all substructures of
+    /// RecordingJobStateSource.
     #[yaserde(prefix = "tt", rename = "State")]
     pub state: RecordingJobState,
 
-    // List of track items.
+    /// List of track items.
     #[yaserde(prefix = "tt", rename = "Tracks")]
     pub tracks: RecordingJobStateTracks,
 }
@@ -8401,24 +8482,24 @@ impl Validate for RecordingJobStateTracks {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct RecordingJobStateTrack {
-    // Identifies the track of the data source that provides the data.
+    /// Identifies the track of the data source that provides the data.
     #[yaserde(prefix = "tt", rename = "SourceTag")]
     pub source_tag: String,
 
-    // Indicates the destination track.
+    /// Indicates the destination track.
     #[yaserde(prefix = "tt", rename = "Destination")]
     pub destination: TrackReference,
 
-    // Optionally holds an implementation defined string value that describes
-    // the error.
-    // The string should be in the English language.
+    /// Optionally holds an implementation defined string value that describes
+    /// the error.
+    /// The string should be in the English language.
     #[yaserde(prefix = "tt", rename = "Error")]
     pub error: Option<String>,
 
-    // Provides the job state of the track. The valid
-    // values of state shall be “Idle”, “Active” and “Error”. If
-    // state equals “Error”, the Error field may be filled in with an
-    // implementation defined value.
+    /// Provides the job state of the track. The valid
+    /// values of state shall be “Idle”, “Active” and “Error”. If
+    /// state equals “Error”, the Error field may be filled in with an
+    /// implementation defined value.
     #[yaserde(prefix = "tt", rename = "State")]
     pub state: RecordingJobState,
 }
@@ -8437,11 +8518,11 @@ pub struct GetRecordingJobsResponseItem {
 
 impl Validate for GetRecordingJobsResponseItem {}
 
-// Configuration parameters for the replay service.
+/// Configuration parameters for the replay service.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ReplayConfiguration {
-    // The RTSP session timeout.
+    /// The RTSP session timeout.
     #[yaserde(prefix = "tt", rename = "SessionTimeout")]
     pub session_timeout: xs::Duration,
 }
@@ -8454,16 +8535,16 @@ pub struct AnalyticsEngine {
     #[yaserde(prefix = "tt", rename = "AnalyticsEngineConfiguration")]
     pub analytics_engine_configuration: AnalyticsDeviceEngineConfiguration,
 
-    // User readable name. Length up to 64 characters.
+    /// User readable name. Length up to 64 characters.
     #[yaserde(prefix = "tt", rename = "Name")]
     pub name: Name,
 
-    // Number of internal references currently using this configuration.
+    /// Number of internal references currently using this configuration.
     #[yaserde(prefix = "tt", rename = "UseCount")]
     pub use_count: i32,
 
-    // Token that uniquely references this configuration. Length up to 64
-    // characters.
+    /// Token that uniquely references this configuration. Length up to 64
+    /// characters.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
@@ -8530,16 +8611,16 @@ pub struct AnalyticsEngineInput {
     #[yaserde(prefix = "tt", rename = "MetadataInput")]
     pub metadata_input: MetadataInput,
 
-    // User readable name. Length up to 64 characters.
+    /// User readable name. Length up to 64 characters.
     #[yaserde(prefix = "tt", rename = "Name")]
     pub name: Name,
 
-    // Number of internal references currently using this configuration.
+    /// Number of internal references currently using this configuration.
     #[yaserde(prefix = "tt", rename = "UseCount")]
     pub use_count: i32,
 
-    // Token that uniquely references this configuration. Length up to 64
-    // characters.
+    /// Token that uniquely references this configuration. Length up to 64
+    /// characters.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
@@ -8588,21 +8669,21 @@ impl Validate for MetadataInputExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct AnalyticsEngineControl {
-    // Token of the analytics engine (AnalyticsEngine) being controlled.
+    /// Token of the analytics engine (AnalyticsEngine) being controlled.
     #[yaserde(prefix = "tt", rename = "EngineToken")]
     pub engine_token: ReferenceToken,
 
-    // Token of the analytics engine configuration (VideoAnalyticsConfiguration)
-    // in effect.
+    /// Token of the analytics engine configuration (VideoAnalyticsConfiguration)
+    /// in effect.
     #[yaserde(prefix = "tt", rename = "EngineConfigToken")]
     pub engine_config_token: ReferenceToken,
 
-    // Tokens of the input (AnalyticsEngineInput) configuration applied.
+    /// Tokens of the input (AnalyticsEngineInput) configuration applied.
     #[yaserde(prefix = "tt", rename = "InputToken")]
     pub input_token: Vec<ReferenceToken>,
 
-    // Tokens of the receiver providing media input data. The order of
-    // ReceiverToken shall exactly match the order of InputToken.
+    /// Tokens of the receiver providing media input data. The order of
+    /// ReceiverToken shall exactly match the order of InputToken.
     #[yaserde(prefix = "tt", rename = "ReceiverToken")]
     pub receiver_token: Vec<ReferenceToken>,
 
@@ -8615,16 +8696,16 @@ pub struct AnalyticsEngineControl {
     #[yaserde(prefix = "tt", rename = "Mode")]
     pub mode: ModeOfOperation,
 
-    // User readable name. Length up to 64 characters.
+    /// User readable name. Length up to 64 characters.
     #[yaserde(prefix = "tt", rename = "Name")]
     pub name: Name,
 
-    // Number of internal references currently using this configuration.
+    /// Number of internal references currently using this configuration.
     #[yaserde(prefix = "tt", rename = "UseCount")]
     pub use_count: i32,
 
-    // Token that uniquely references this configuration. Length up to 64
-    // characters.
+    /// Token that uniquely references this configuration. Length up to 64
+    /// characters.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
@@ -8633,10 +8714,11 @@ impl Validate for AnalyticsEngineControl {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum ModeOfOperation {
     Idle,
     Active,
-    // This case should never happen.
+    /// This case should never happen.
     Unknown,
     __Unknown__(String),
 }
@@ -8652,7 +8734,7 @@ impl Validate for ModeOfOperation {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct AnalyticsStateInformation {
-    // Token of the control object whose status is requested.
+    /// Token of the control object whose status is requested.
     #[yaserde(prefix = "tt", rename = "AnalyticsEngineControlToken")]
     pub analytics_engine_control_token: ReferenceToken,
 
@@ -8674,23 +8756,23 @@ pub struct AnalyticsState {
 
 impl Validate for AnalyticsState {}
 
-// Action Engine Event Payload data structure contains the information about the
-// ONVIF command invocations. Since this event could be generated by other or
-// proprietary actions, the command invocation specific fields are defined as
-// optional and additional extension mechanism is provided for future or
-// additional action definitions.
+/// Action Engine Event Payload data structure contains the information about the
+/// ONVIF command invocations. Since this event could be generated by other or
+/// proprietary actions, the command invocation specific fields are defined as
+/// optional and additional extension mechanism is provided for future or
+/// additional action definitions.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ActionEngineEventPayload {
-    // Request Message
+    /// Request Message
     #[yaserde(prefix = "tt", rename = "RequestInfo")]
     pub request_info: soapenv::Envelope,
 
-    // Response Message
+    /// Response Message
     #[yaserde(prefix = "tt", rename = "ResponseInfo")]
     pub response_info: soapenv::Envelope,
 
-    // Fault Message
+    /// Fault Message
     #[yaserde(prefix = "tt", rename = "Fault")]
     pub fault: soapenv::Fault,
 
@@ -8706,8 +8788,8 @@ pub struct ActionEngineEventPayloadExtension {}
 
 impl Validate for ActionEngineEventPayloadExtension {}
 
-// AudioClassType acceptable values are;
-// gun_shot, scream, glass_breaking, tire_screech
+/// AudioClassType acceptable values are;
+/// gun_shot, scream, glass_breaking, tire_screech
 #[derive(Default, PartialEq, Debug, UtilsTupleIo, UtilsDefaultSerde)]
 pub struct AudioClassType(pub String);
 
@@ -8716,12 +8798,12 @@ impl Validate for AudioClassType {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct AudioClassCandidate {
-    // Indicates audio class label
+    /// Indicates audio class label
     #[yaserde(prefix = "tt", rename = "Type")]
     pub _type: AudioClassType,
 
-    // A likelihood/probability that the corresponding audio event belongs to
-    // this class. The sum of the likelihoods shall NOT exceed 1
+    /// A likelihood/probability that the corresponding audio event belongs to
+    /// this class. The sum of the likelihoods shall NOT exceed 1
     #[yaserde(prefix = "tt", rename = "Likelihood")]
     pub likelihood: f64,
 }
@@ -8731,7 +8813,7 @@ impl Validate for AudioClassCandidate {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct AudioClassDescriptor {
-    // Array of audio class label and class probability
+    /// Array of audio class label and class probability
     #[yaserde(prefix = "tt", rename = "ClassCandidate")]
     pub class_candidate: Vec<AudioClassCandidate>,
 
@@ -8785,6 +8867,7 @@ impl Validate for Osdreference {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum Osdtype {
     Text,
     Image,
@@ -8803,7 +8886,7 @@ impl Validate for Osdtype {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct OsdposConfiguration {
-    // For OSD position type, following are the pre-defined:
+    /// For OSD position type, following are the pre-defined:
     #[yaserde(prefix = "tt", rename = "Type")]
     pub _type: String,
 
@@ -8822,9 +8905,9 @@ pub struct OsdposConfigurationExtension {}
 
 impl Validate for OsdposConfigurationExtension {}
 
-// The value range of "Transparent" could be defined by vendors only should
-// follow this rule: the minimum value means non-transparent and the maximum
-// value maens fully transparent.
+/// The value range of "Transparent" could be defined by vendors only should
+/// follow this rule: the minimum value means non-transparent and the maximum
+/// value maens fully transparent.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Osdcolor {
@@ -8840,43 +8923,43 @@ impl Validate for Osdcolor {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct OsdtextConfiguration {
-    // The following OSD Text Type are defined:
+    /// The following OSD Text Type are defined:
     #[yaserde(prefix = "tt", rename = "Type")]
     pub _type: String,
 
-    // List of supported OSD date formats. This element shall be present when
-    // the value of Type field has Date or DateAndTime. The following DateFormat
-    // are defined:
+    /// List of supported OSD date formats. This element shall be present when
+    /// the value of Type field has Date or DateAndTime. The following DateFormat
+    /// are defined:
     #[yaserde(prefix = "tt", rename = "DateFormat")]
     pub date_format: Option<String>,
 
-    // List of supported OSD time formats. This element shall be present when
-    // the value of Type field has Time or DateAndTime. The following TimeFormat
-    // are defined:
+    /// List of supported OSD time formats. This element shall be present when
+    /// the value of Type field has Time or DateAndTime. The following TimeFormat
+    /// are defined:
     #[yaserde(prefix = "tt", rename = "TimeFormat")]
     pub time_format: Option<String>,
 
-    // Font size of the text in pt.
+    /// Font size of the text in pt.
     #[yaserde(prefix = "tt", rename = "FontSize")]
     pub font_size: Option<i32>,
 
-    // Font color of the text.
+    /// Font color of the text.
     #[yaserde(prefix = "tt", rename = "FontColor")]
     pub font_color: Option<Osdcolor>,
 
-    // Background color of the text.
+    /// Background color of the text.
     #[yaserde(prefix = "tt", rename = "BackgroundColor")]
     pub background_color: Option<Osdcolor>,
 
-    // The content of text to be displayed.
+    /// The content of text to be displayed.
     #[yaserde(prefix = "tt", rename = "PlainText")]
     pub plain_text: Option<String>,
 
     #[yaserde(prefix = "tt", rename = "Extension")]
     pub extension: Option<OsdtextConfigurationExtension>,
 
-    // This flag is applicable for Type Plain and defaults to true. When set to
-    // false the PlainText content will not be persistent across device reboots.
+    /// This flag is applicable for Type Plain and defaults to true. When set to
+    /// false the PlainText content will not be persistent across device reboots.
     #[yaserde(attribute, rename = "IsPersistentText")]
     pub is_persistent_text: Option<bool>,
 }
@@ -8892,7 +8975,7 @@ impl Validate for OsdtextConfigurationExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct OsdimgConfiguration {
-    // The URI of the image which to be displayed.
+    /// The URI of the image which to be displayed.
     #[yaserde(prefix = "tt", rename = "ImgPath")]
     pub img_path: String,
 
@@ -8920,7 +9003,7 @@ pub struct ColorspaceRange {
     #[yaserde(prefix = "tt", rename = "Z")]
     pub z: FloatRange,
 
-    // Acceptable values are the same as in tt:Color.
+    /// Acceptable values are the same as in tt:Color.
     #[yaserde(prefix = "tt", rename = "Colorspace")]
     pub colorspace: String,
 }
@@ -8929,10 +9012,11 @@ impl Validate for ColorspaceRange {}
 
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
+#[non_exhaustive]
 pub enum ColorOptionsChoice {
-    // List the supported color.
+    /// List the supported color.
     ColorList(Vec<Color>),
-    // Define the range of color supported.
+    /// Define the range of color supported.
     ColorspaceRange(Vec<ColorspaceRange>),
     __Unknown__(String),
 }
@@ -8945,8 +9029,8 @@ impl Default for ColorOptionsChoice {
 
 impl Validate for ColorOptionsChoice {}
 
-// Describe the colors supported. Either list each color or define the range of
-// color values.
+/// Describe the colors supported. Either list each color or define the range of
+/// color values.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ColorOptions {
@@ -8956,15 +9040,15 @@ pub struct ColorOptions {
 
 impl Validate for ColorOptions {}
 
-// Describe the option of the color and its transparency.
+/// Describe the option of the color and its transparency.
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct OsdcolorOptions {
-    // Optional list of supported colors.
+    /// Optional list of supported colors.
     #[yaserde(prefix = "tt", rename = "Color")]
     pub color: Option<ColorOptions>,
 
-    // Range of the transparent level. Larger means more tranparent.
+    /// Range of the transparent level. Larger means more tranparent.
     #[yaserde(prefix = "tt", rename = "Transparent")]
     pub transparent: Option<IntRange>,
 
@@ -8983,29 +9067,29 @@ impl Validate for OsdcolorOptionsExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct OsdtextOptions {
-    // List of supported OSD text type. When a device indicates the supported
-    // number relating to Text type in MaximumNumberOfOSDs, the type shall be
-    // presented.
+    /// List of supported OSD text type. When a device indicates the supported
+    /// number relating to Text type in MaximumNumberOfOSDs, the type shall be
+    /// presented.
     #[yaserde(prefix = "tt", rename = "Type")]
     pub _type: Vec<String>,
 
-    // Range of the font size value.
+    /// Range of the font size value.
     #[yaserde(prefix = "tt", rename = "FontSizeRange")]
     pub font_size_range: Option<IntRange>,
 
-    // List of supported date format.
+    /// List of supported date format.
     #[yaserde(prefix = "tt", rename = "DateFormat")]
     pub date_format: Vec<String>,
 
-    // List of supported time format.
+    /// List of supported time format.
     #[yaserde(prefix = "tt", rename = "TimeFormat")]
     pub time_format: Vec<String>,
 
-    // List of supported font color.
+    /// List of supported font color.
     #[yaserde(prefix = "tt", rename = "FontColor")]
     pub font_color: Option<OsdcolorOptions>,
 
-    // List of supported background color.
+    /// List of supported background color.
     #[yaserde(prefix = "tt", rename = "BackgroundColor")]
     pub background_color: Option<OsdcolorOptions>,
 
@@ -9024,26 +9108,26 @@ impl Validate for OsdtextOptionsExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct OsdimgOptions {
-    // List of available image URIs.
+    /// List of available image URIs.
     #[yaserde(prefix = "tt", rename = "ImagePath")]
     pub image_path: Vec<String>,
 
     #[yaserde(prefix = "tt", rename = "Extension")]
     pub extension: Option<OsdimgOptionsExtension>,
 
-    // List of supported image MIME types, such as "image/png".
+    /// List of supported image MIME types, such as "image/png".
     #[yaserde(attribute, rename = "FormatsSupported")]
     pub formats_supported: Option<StringAttrList>,
 
-    // The maximum size (in bytes) of the image that can be uploaded.
+    /// The maximum size (in bytes) of the image that can be uploaded.
     #[yaserde(attribute, rename = "MaxSize")]
     pub max_size: Option<i32>,
 
-    // The maximum width (in pixels) of the image that can be uploaded.
+    /// The maximum width (in pixels) of the image that can be uploaded.
     #[yaserde(attribute, rename = "MaxWidth")]
     pub max_width: Option<i32>,
 
-    // The maximum height (in pixels) of the image that can be uploaded.
+    /// The maximum height (in pixels) of the image that can be uploaded.
     #[yaserde(attribute, rename = "MaxHeight")]
     pub max_height: Option<i32>,
 }
@@ -9059,32 +9143,32 @@ impl Validate for OsdimgOptionsExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct Osdconfiguration {
-    // Reference to the video source configuration.
+    /// Reference to the video source configuration.
     #[yaserde(prefix = "tt", rename = "VideoSourceConfigurationToken")]
     pub video_source_configuration_token: Osdreference,
 
-    // Type of OSD.
+    /// Type of OSD.
     #[yaserde(prefix = "tt", rename = "Type")]
     pub _type: Osdtype,
 
-    // Position configuration of OSD.
+    /// Position configuration of OSD.
     #[yaserde(prefix = "tt", rename = "Position")]
     pub position: OsdposConfiguration,
 
-    // Text configuration of OSD. It shall be present when the value of Type
-    // field is Text.
+    /// Text configuration of OSD. It shall be present when the value of Type
+    /// field is Text.
     #[yaserde(prefix = "tt", rename = "TextString")]
     pub text_string: Option<OsdtextConfiguration>,
 
-    // Image configuration of OSD. It shall be present when the value of Type
-    // field is Image
+    /// Image configuration of OSD. It shall be present when the value of Type
+    /// field is Image
     #[yaserde(prefix = "tt", rename = "Image")]
     pub image: Option<OsdimgConfiguration>,
 
     #[yaserde(prefix = "tt", rename = "Extension")]
     pub extension: Option<OsdconfigurationExtension>,
 
-    // Unique identifier referencing the physical entity.
+    /// Unique identifier referencing the physical entity.
     #[yaserde(attribute, rename = "token")]
     pub token: ReferenceToken,
 }
@@ -9124,32 +9208,32 @@ impl Validate for MaximumNumberOfOSDs {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct OsdconfigurationOptions {
-    // The maximum number of OSD configurations supported for the specified
-    // video source configuration. If the configuration does not support OSDs,
-    // this value shall be zero and the Type and PositionOption elements are
-    // ignored. If a device limits the number of instances by OSDType, it shall
-    // indicate the supported number for each type via the related attribute.
+    /// The maximum number of OSD configurations supported for the specified
+    /// video source configuration. If the configuration does not support OSDs,
+    /// this value shall be zero and the Type and PositionOption elements are
+    /// ignored. If a device limits the number of instances by OSDType, it shall
+    /// indicate the supported number for each type via the related attribute.
     #[yaserde(prefix = "tt", rename = "MaximumNumberOfOSDs")]
     pub maximum_number_of_os_ds: MaximumNumberOfOSDs,
 
-    // List supported type of OSD configuration. When a device indicates the
-    // supported number for each types in MaximumNumberOfOSDs, related type
-    // shall be presented. A device shall return Option element relating to
-    // listed type.
+    /// List supported type of OSD configuration. When a device indicates the
+    /// supported number for each types in MaximumNumberOfOSDs, related type
+    /// shall be presented. A device shall return Option element relating to
+    /// listed type.
     #[yaserde(prefix = "tt", rename = "Type")]
     pub _type: Vec<Osdtype>,
 
-    // List available OSD position type. Following are the pre-defined:
+    /// List available OSD position type. Following are the pre-defined:
     #[yaserde(prefix = "tt", rename = "PositionOption")]
     pub position_option: Vec<String>,
 
-    // Option of the OSD text configuration. This element shall be returned if
-    // the device is signaling the support for Text.
+    /// Option of the OSD text configuration. This element shall be returned if
+    /// the device is signaling the support for Text.
     #[yaserde(prefix = "tt", rename = "TextOption")]
     pub text_option: Option<OsdtextOptions>,
 
-    // Option of the OSD image configuration. This element shall be returned if
-    // the device is signaling the support for Image.
+    /// Option of the OSD image configuration. This element shall be returned if
+    /// the device is signaling the support for Image.
     #[yaserde(prefix = "tt", rename = "ImageOption")]
     pub image_option: Option<OsdimgOptions>,
 
@@ -9168,11 +9252,11 @@ impl Validate for OsdconfigurationOptionsExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct FileProgress {
-    // Exported file name
+    /// Exported file name
     #[yaserde(prefix = "tt", rename = "FileName")]
     pub file_name: String,
 
-    // Normalized percentage completion for uploading the exported file
+    /// Normalized percentage completion for uploading the exported file
     #[yaserde(prefix = "tt", rename = "Progress")]
     pub progress: f64,
 }
@@ -9182,7 +9266,7 @@ impl Validate for FileProgress {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct ArrayOfFileProgress {
-    // Exported file name and export progress information
+    /// Exported file name and export progress information
     #[yaserde(prefix = "tt", rename = "FileProgress")]
     pub file_progress: Vec<FileProgress>,
 
@@ -9201,11 +9285,11 @@ impl Validate for ArrayOfFileProgressExtension {}
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(prefix = "tt", namespace = "tt: http://www.onvif.org/ver10/schema")]
 pub struct StorageReferencePath {
-    // identifier of an existing Storage Configuration.
+    /// identifier of an existing Storage Configuration.
     #[yaserde(prefix = "tt", rename = "StorageToken")]
     pub storage_token: ReferenceToken,
 
-    // gives the relative directory path on the storage
+    /// gives the relative directory path on the storage
     #[yaserde(prefix = "tt", rename = "RelativePath")]
     pub relative_path: Option<String>,
 
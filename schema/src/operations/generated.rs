@@ -0,0 +1,4350 @@
+// Generated by `cargo xtask operations` from the WSDL modules, do not edit.
+
+use super::Operation;
+
+pub(super) const OPERATIONS: &[Operation] = &[
+    Operation {
+        service: "accesscontrol",
+        name: "GetServiceCapabilities",
+        namespace: "http://www.onvif.org/ver10/accesscontrol/wsdl",
+        action: "http://www.onvif.org/ver10/accesscontrol/wsdl/GetServiceCapabilities",
+        request: "GetServiceCapabilities",
+        response: "GetServiceCapabilitiesResponse",
+    },
+    Operation {
+        service: "accesscontrol",
+        name: "GetAccessPointInfo",
+        namespace: "http://www.onvif.org/ver10/accesscontrol/wsdl",
+        action: "http://www.onvif.org/ver10/accesscontrol/wsdl/GetAccessPointInfo",
+        request: "GetAccessPointInfo",
+        response: "GetAccessPointInfoResponse",
+    },
+    Operation {
+        service: "accesscontrol",
+        name: "GetAccessPointInfoList",
+        namespace: "http://www.onvif.org/ver10/accesscontrol/wsdl",
+        action: "http://www.onvif.org/ver10/accesscontrol/wsdl/GetAccessPointInfoList",
+        request: "GetAccessPointInfoList",
+        response: "GetAccessPointInfoListResponse",
+    },
+    Operation {
+        service: "accesscontrol",
+        name: "GetAccessPoints",
+        namespace: "http://www.onvif.org/ver10/accesscontrol/wsdl",
+        action: "http://www.onvif.org/ver10/accesscontrol/wsdl/GetAccessPoints",
+        request: "GetAccessPoints",
+        response: "GetAccessPointsResponse",
+    },
+    Operation {
+        service: "accesscontrol",
+        name: "GetAccessPointList",
+        namespace: "http://www.onvif.org/ver10/accesscontrol/wsdl",
+        action: "http://www.onvif.org/ver10/accesscontrol/wsdl/GetAccessPointList",
+        request: "GetAccessPointList",
+        response: "GetAccessPointListResponse",
+    },
+    Operation {
+        service: "accesscontrol",
+        name: "CreateAccessPoint",
+        namespace: "http://www.onvif.org/ver10/accesscontrol/wsdl",
+        action: "http://www.onvif.org/ver10/accesscontrol/wsdl/CreateAccessPoint",
+        request: "CreateAccessPoint",
+        response: "CreateAccessPointResponse",
+    },
+    Operation {
+        service: "accesscontrol",
+        name: "SetAccessPoint",
+        namespace: "http://www.onvif.org/ver10/accesscontrol/wsdl",
+        action: "http://www.onvif.org/ver10/accesscontrol/wsdl/SetAccessPoint",
+        request: "SetAccessPoint",
+        response: "SetAccessPointResponse",
+    },
+    Operation {
+        service: "accesscontrol",
+        name: "ModifyAccessPoint",
+        namespace: "http://www.onvif.org/ver10/accesscontrol/wsdl",
+        action: "http://www.onvif.org/ver10/accesscontrol/wsdl/ModifyAccessPoint",
+        request: "ModifyAccessPoint",
+        response: "ModifyAccessPointResponse",
+    },
+    Operation {
+        service: "accesscontrol",
+        name: "DeleteAccessPoint",
+        namespace: "http://www.onvif.org/ver10/accesscontrol/wsdl",
+        action: "http://www.onvif.org/ver10/accesscontrol/wsdl/DeleteAccessPoint",
+        request: "DeleteAccessPoint",
+        response: "DeleteAccessPointResponse",
+    },
+    Operation {
+        service: "accesscontrol",
+        name: "SetAccessPointAuthenticationProfile",
+        namespace: "http://www.onvif.org/ver10/accesscontrol/wsdl",
+        action: "http://www.onvif.org/ver10/accesscontrol/wsdl/SetAccessPointAuthenticationProfile",
+        request: "SetAccessPointAuthenticationProfile",
+        response: "SetAccessPointAuthenticationProfileResponse",
+    },
+    Operation {
+        service: "accesscontrol",
+        name: "DeleteAccessPointAuthenticationProfile",
+        namespace: "http://www.onvif.org/ver10/accesscontrol/wsdl",
+        action: "http://www.onvif.org/ver10/accesscontrol/wsdl/DeleteAccessPointAuthenticationProfile",
+        request: "DeleteAccessPointAuthenticationProfile",
+        response: "DeleteAccessPointAuthenticationProfileResponse",
+    },
+    Operation {
+        service: "accesscontrol",
+        name: "GetAreaInfo",
+        namespace: "http://www.onvif.org/ver10/accesscontrol/wsdl",
+        action: "http://www.onvif.org/ver10/accesscontrol/wsdl/GetAreaInfo",
+        request: "GetAreaInfo",
+        response: "GetAreaInfoResponse",
+    },
+    Operation {
+        service: "accesscontrol",
+        name: "GetAreaInfoList",
+        namespace: "http://www.onvif.org/ver10/accesscontrol/wsdl",
+        action: "http://www.onvif.org/ver10/accesscontrol/wsdl/GetAreaInfoList",
+        request: "GetAreaInfoList",
+        response: "GetAreaInfoListResponse",
+    },
+    Operation {
+        service: "accesscontrol",
+        name: "GetAreas",
+        namespace: "http://www.onvif.org/ver10/accesscontrol/wsdl",
+        action: "http://www.onvif.org/ver10/accesscontrol/wsdl/GetAreas",
+        request: "GetAreas",
+        response: "GetAreasResponse",
+    },
+    Operation {
+        service: "accesscontrol",
+        name: "GetAreaList",
+        namespace: "http://www.onvif.org/ver10/accesscontrol/wsdl",
+        action: "http://www.onvif.org/ver10/accesscontrol/wsdl/GetAreaList",
+        request: "GetAreaList",
+        response: "GetAreaListResponse",
+    },
+    Operation {
+        service: "accesscontrol",
+        name: "CreateArea",
+        namespace: "http://www.onvif.org/ver10/accesscontrol/wsdl",
+        action: "http://www.onvif.org/ver10/accesscontrol/wsdl/CreateArea",
+        request: "CreateArea",
+        response: "CreateAreaResponse",
+    },
+    Operation {
+        service: "accesscontrol",
+        name: "SetArea",
+        namespace: "http://www.onvif.org/ver10/accesscontrol/wsdl",
+        action: "http://www.onvif.org/ver10/accesscontrol/wsdl/SetArea",
+        request: "SetArea",
+        response: "SetAreaResponse",
+    },
+    Operation {
+        service: "accesscontrol",
+        name: "ModifyArea",
+        namespace: "http://www.onvif.org/ver10/accesscontrol/wsdl",
+        action: "http://www.onvif.org/ver10/accesscontrol/wsdl/ModifyArea",
+        request: "ModifyArea",
+        response: "ModifyAreaResponse",
+    },
+    Operation {
+        service: "accesscontrol",
+        name: "DeleteArea",
+        namespace: "http://www.onvif.org/ver10/accesscontrol/wsdl",
+        action: "http://www.onvif.org/ver10/accesscontrol/wsdl/DeleteArea",
+        request: "DeleteArea",
+        response: "DeleteAreaResponse",
+    },
+    Operation {
+        service: "accesscontrol",
+        name: "GetAccessPointState",
+        namespace: "http://www.onvif.org/ver10/accesscontrol/wsdl",
+        action: "http://www.onvif.org/ver10/accesscontrol/wsdl/GetAccessPointState",
+        request: "GetAccessPointState",
+        response: "GetAccessPointStateResponse",
+    },
+    Operation {
+        service: "accesscontrol",
+        name: "EnableAccessPoint",
+        namespace: "http://www.onvif.org/ver10/accesscontrol/wsdl",
+        action: "http://www.onvif.org/ver10/accesscontrol/wsdl/EnableAccessPoint",
+        request: "EnableAccessPoint",
+        response: "EnableAccessPointResponse",
+    },
+    Operation {
+        service: "accesscontrol",
+        name: "DisableAccessPoint",
+        namespace: "http://www.onvif.org/ver10/accesscontrol/wsdl",
+        action: "http://www.onvif.org/ver10/accesscontrol/wsdl/DisableAccessPoint",
+        request: "DisableAccessPoint",
+        response: "DisableAccessPointResponse",
+    },
+    Operation {
+        service: "accesscontrol",
+        name: "ExternalAuthorization",
+        namespace: "http://www.onvif.org/ver10/accesscontrol/wsdl",
+        action: "http://www.onvif.org/ver10/accesscontrol/wsdl/ExternalAuthorization",
+        request: "ExternalAuthorization",
+        response: "ExternalAuthorizationResponse",
+    },
+    Operation {
+        service: "accessrules",
+        name: "GetServiceCapabilities",
+        namespace: "http://www.onvif.org/ver10/accessrules/wsdl",
+        action: "http://www.onvif.org/ver10/accessrules/wsdl/GetServiceCapabilities",
+        request: "GetServiceCapabilities",
+        response: "GetServiceCapabilitiesResponse",
+    },
+    Operation {
+        service: "accessrules",
+        name: "GetAccessProfileInfo",
+        namespace: "http://www.onvif.org/ver10/accessrules/wsdl",
+        action: "http://www.onvif.org/ver10/accessrules/wsdl/GetAccessProfileInfo",
+        request: "GetAccessProfileInfo",
+        response: "GetAccessProfileInfoResponse",
+    },
+    Operation {
+        service: "accessrules",
+        name: "GetAccessProfileInfoList",
+        namespace: "http://www.onvif.org/ver10/accessrules/wsdl",
+        action: "http://www.onvif.org/ver10/accessrules/wsdl/GetAccessProfileInfoList",
+        request: "GetAccessProfileInfoList",
+        response: "GetAccessProfileInfoListResponse",
+    },
+    Operation {
+        service: "accessrules",
+        name: "GetAccessProfiles",
+        namespace: "http://www.onvif.org/ver10/accessrules/wsdl",
+        action: "http://www.onvif.org/ver10/accessrules/wsdl/GetAccessProfiles",
+        request: "GetAccessProfiles",
+        response: "GetAccessProfilesResponse",
+    },
+    Operation {
+        service: "accessrules",
+        name: "GetAccessProfileList",
+        namespace: "http://www.onvif.org/ver10/accessrules/wsdl",
+        action: "http://www.onvif.org/ver10/accessrules/wsdl/GetAccessProfileList",
+        request: "GetAccessProfileList",
+        response: "GetAccessProfileListResponse",
+    },
+    Operation {
+        service: "accessrules",
+        name: "CreateAccessProfile",
+        namespace: "http://www.onvif.org/ver10/accessrules/wsdl",
+        action: "http://www.onvif.org/ver10/accessrules/wsdl/CreateAccessProfile",
+        request: "CreateAccessProfile",
+        response: "CreateAccessProfileResponse",
+    },
+    Operation {
+        service: "accessrules",
+        name: "ModifyAccessProfile",
+        namespace: "http://www.onvif.org/ver10/accessrules/wsdl",
+        action: "http://www.onvif.org/ver10/accessrules/wsdl/ModifyAccessProfile",
+        request: "ModifyAccessProfile",
+        response: "ModifyAccessProfileResponse",
+    },
+    Operation {
+        service: "accessrules",
+        name: "SetAccessProfile",
+        namespace: "http://www.onvif.org/ver10/accessrules/wsdl",
+        action: "http://www.onvif.org/ver10/accessrules/wsdl/SetAccessProfile",
+        request: "SetAccessProfile",
+        response: "SetAccessProfileResponse",
+    },
+    Operation {
+        service: "accessrules",
+        name: "DeleteAccessProfile",
+        namespace: "http://www.onvif.org/ver10/accessrules/wsdl",
+        action: "http://www.onvif.org/ver10/accessrules/wsdl/DeleteAccessProfile",
+        request: "DeleteAccessProfile",
+        response: "DeleteAccessProfileResponse",
+    },
+    Operation {
+        service: "actionengine",
+        name: "GetSupportedActions",
+        namespace: "http://www.onvif.org/ver10/actionengine/wsdl",
+        action: "http://www.onvif.org/ver10/actionengine/wsdl/GetSupportedActions",
+        request: "GetSupportedActions",
+        response: "GetSupportedActionsResponse",
+    },
+    Operation {
+        service: "actionengine",
+        name: "GetActions",
+        namespace: "http://www.onvif.org/ver10/actionengine/wsdl",
+        action: "http://www.onvif.org/ver10/actionengine/wsdl/GetActions",
+        request: "GetActions",
+        response: "GetActionsResponse",
+    },
+    Operation {
+        service: "actionengine",
+        name: "CreateActions",
+        namespace: "http://www.onvif.org/ver10/actionengine/wsdl",
+        action: "http://www.onvif.org/ver10/actionengine/wsdl/CreateActions",
+        request: "CreateActions",
+        response: "CreateActionsResponse",
+    },
+    Operation {
+        service: "actionengine",
+        name: "DeleteActions",
+        namespace: "http://www.onvif.org/ver10/actionengine/wsdl",
+        action: "http://www.onvif.org/ver10/actionengine/wsdl/DeleteActions",
+        request: "DeleteActions",
+        response: "DeleteActionsResponse",
+    },
+    Operation {
+        service: "actionengine",
+        name: "ModifyActions",
+        namespace: "http://www.onvif.org/ver10/actionengine/wsdl",
+        action: "http://www.onvif.org/ver10/actionengine/wsdl/ModifyActions",
+        request: "ModifyActions",
+        response: "ModifyActionsResponse",
+    },
+    Operation {
+        service: "actionengine",
+        name: "GetServiceCapabilities",
+        namespace: "http://www.onvif.org/ver10/actionengine/wsdl",
+        action: "http://www.onvif.org/ver10/actionengine/wsdl/GetServiceCapabilities",
+        request: "GetServiceCapabilities",
+        response: "GetServiceCapabilitiesResponse",
+    },
+    Operation {
+        service: "actionengine",
+        name: "GetActionTriggers",
+        namespace: "http://www.onvif.org/ver10/actionengine/wsdl",
+        action: "http://www.onvif.org/ver10/actionengine/wsdl/GetActionTriggers",
+        request: "GetActionTriggers",
+        response: "GetActionTriggersResponse",
+    },
+    Operation {
+        service: "actionengine",
+        name: "CreateActionTriggers",
+        namespace: "http://www.onvif.org/ver10/actionengine/wsdl",
+        action: "http://www.onvif.org/ver10/actionengine/wsdl/CreateActionTriggers",
+        request: "CreateActionTriggers",
+        response: "CreateActionTriggersResponse",
+    },
+    Operation {
+        service: "actionengine",
+        name: "DeleteActionTriggers",
+        namespace: "http://www.onvif.org/ver10/actionengine/wsdl",
+        action: "http://www.onvif.org/ver10/actionengine/wsdl/DeleteActionTriggers",
+        request: "DeleteActionTriggers",
+        response: "DeleteActionTriggersResponse",
+    },
+    Operation {
+        service: "actionengine",
+        name: "ModifyActionTriggers",
+        namespace: "http://www.onvif.org/ver10/actionengine/wsdl",
+        action: "http://www.onvif.org/ver10/actionengine/wsdl/ModifyActionTriggers",
+        request: "ModifyActionTriggers",
+        response: "ModifyActionTriggersResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "AddDot1XConfiguration",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/AddDot1XConfiguration",
+        request: "AddDot1XConfiguration",
+        response: "AddDot1XConfigurationResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "GetAllDot1XConfigurations",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/GetAllDot1XConfigurations",
+        request: "GetAllDot1XConfigurations",
+        response: "GetAllDot1XConfigurationsResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "GetDot1XConfiguration",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/GetDot1XConfiguration",
+        request: "GetDot1XConfiguration",
+        response: "GetDot1XConfigurationResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "DeleteDot1XConfiguration",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/DeleteDot1XConfiguration",
+        request: "DeleteDot1XConfiguration",
+        response: "DeleteDot1XConfigurationResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "SetNetworkInterfaceDot1XConfiguration",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/SetNetworkInterfaceDot1XConfiguration",
+        request: "SetNetworkInterfaceDot1XConfiguration",
+        response: "SetNetworkInterfaceDot1XConfigurationResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "GetNetworkInterfaceDot1XConfiguration",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/GetNetworkInterfaceDot1XConfiguration",
+        request: "GetNetworkInterfaceDot1XConfiguration",
+        response: "GetNetworkInterfaceDot1XConfigurationResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "DeleteNetworkInterfaceDot1XConfiguration",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/DeleteNetworkInterfaceDot1XConfiguration",
+        request: "DeleteNetworkInterfaceDot1XConfiguration",
+        response: "DeleteNetworkInterfaceDot1XConfigurationResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "CreateRSAKeyPair",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/CreateRSAKeyPair",
+        request: "CreateRSAKeyPair",
+        response: "CreateRSAKeyPairResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "UploadKeyPairInPKCS8",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/UploadKeyPairInPKCS8",
+        request: "UploadKeyPairInPKCS8",
+        response: "UploadKeyPairInPKCS8Response",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "UploadCertificateWithPrivateKeyInPKCS12",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/UploadCertificateWithPrivateKeyInPKCS12",
+        request: "UploadCertificateWithPrivateKeyInPKCS12",
+        response: "UploadCertificateWithPrivateKeyInPKCS12Response",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "GetKeyStatus",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/GetKeyStatus",
+        request: "GetKeyStatus",
+        response: "GetKeyStatusResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "GetPrivateKeyStatus",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/GetPrivateKeyStatus",
+        request: "GetPrivateKeyStatus",
+        response: "GetPrivateKeyStatusResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "GetAllKeys",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/GetAllKeys",
+        request: "GetAllKeys",
+        response: "GetAllKeysResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "DeleteKey",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/DeleteKey",
+        request: "DeleteKey",
+        response: "DeleteKeyResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "CreatePKCS10CSR",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/CreatePKCS10CSR",
+        request: "CreatePKCS10CSR",
+        response: "CreatePKCS10CSRResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "CreateSelfSignedCertificate",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/CreateSelfSignedCertificate",
+        request: "CreateSelfSignedCertificate",
+        response: "CreateSelfSignedCertificateResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "UploadCertificate",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/UploadCertificate",
+        request: "UploadCertificate",
+        response: "UploadCertificateResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "GetCertificate",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/GetCertificate",
+        request: "GetCertificate",
+        response: "GetCertificateResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "GetAllCertificates",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/GetAllCertificates",
+        request: "GetAllCertificates",
+        response: "GetAllCertificatesResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "DeleteCertificate",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/DeleteCertificate",
+        request: "DeleteCertificate",
+        response: "DeleteCertificateResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "CreateCertificationPath",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/CreateCertificationPath",
+        request: "CreateCertificationPath",
+        response: "CreateCertificationPathResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "GetCertificationPath",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/GetCertificationPath",
+        request: "GetCertificationPath",
+        response: "GetCertificationPathResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "GetAllCertificationPaths",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/GetAllCertificationPaths",
+        request: "GetAllCertificationPaths",
+        response: "GetAllCertificationPathsResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "DeleteCertificationPath",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/DeleteCertificationPath",
+        request: "DeleteCertificationPath",
+        response: "DeleteCertificationPathResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "UploadPassphrase",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/UploadPassphrase",
+        request: "UploadPassphrase",
+        response: "UploadPassphraseResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "GetAllPassphrases",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/GetAllPassphrases",
+        request: "GetAllPassphrases",
+        response: "GetAllPassphrasesResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "DeletePassphrase",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/DeletePassphrase",
+        request: "DeletePassphrase",
+        response: "DeletePassphraseResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "UploadCRL",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/UploadCRL",
+        request: "UploadCRL",
+        response: "UploadCRLResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "GetCRL",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/GetCRL",
+        request: "GetCRL",
+        response: "GetCRLResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "GetAllCRLs",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/GetAllCRLs",
+        request: "GetAllCRLs",
+        response: "GetAllCRLsResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "DeleteCRL",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/DeleteCRL",
+        request: "DeleteCRL",
+        response: "DeleteCRLResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "CreateCertPathValidationPolicy",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/CreateCertPathValidationPolicy",
+        request: "CreateCertPathValidationPolicy",
+        response: "CreateCertPathValidationPolicyResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "GetCertPathValidationPolicy",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/GetCertPathValidationPolicy",
+        request: "GetCertPathValidationPolicy",
+        response: "GetCertPathValidationPolicyResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "GetAllCertPathValidationPolicies",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/GetAllCertPathValidationPolicies",
+        request: "GetAllCertPathValidationPolicies",
+        response: "GetAllCertPathValidationPoliciesResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "DeleteCertPathValidationPolicy",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/DeleteCertPathValidationPolicy",
+        request: "DeleteCertPathValidationPolicy",
+        response: "DeleteCertPathValidationPolicyResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "GetServiceCapabilities",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/GetServiceCapabilities",
+        request: "GetServiceCapabilities",
+        response: "GetServiceCapabilitiesResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "AddServerCertificateAssignment",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/AddServerCertificateAssignment",
+        request: "AddServerCertificateAssignment",
+        response: "AddServerCertificateAssignmentResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "RemoveServerCertificateAssignment",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/RemoveServerCertificateAssignment",
+        request: "RemoveServerCertificateAssignment",
+        response: "RemoveServerCertificateAssignmentResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "ReplaceServerCertificateAssignment",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/ReplaceServerCertificateAssignment",
+        request: "ReplaceServerCertificateAssignment",
+        response: "ReplaceServerCertificateAssignmentResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "SetEnabledTLSVersions",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/SetEnabledTLSVersions",
+        request: "SetEnabledTLSVersions",
+        response: "SetEnabledTLSVersionsResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "GetEnabledTLSVersions",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/GetEnabledTLSVersions",
+        request: "GetEnabledTLSVersions",
+        response: "GetEnabledTLSVersionsResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "GetAssignedServerCertificates",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/GetAssignedServerCertificates",
+        request: "GetAssignedServerCertificates",
+        response: "GetAssignedServerCertificatesResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "SetClientAuthenticationRequired",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/SetClientAuthenticationRequired",
+        request: "SetClientAuthenticationRequired",
+        response: "SetClientAuthenticationRequiredResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "GetClientAuthenticationRequired",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/GetClientAuthenticationRequired",
+        request: "GetClientAuthenticationRequired",
+        response: "GetClientAuthenticationRequiredResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "AddCertPathValidationPolicyAssignment",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/AddCertPathValidationPolicyAssignment",
+        request: "AddCertPathValidationPolicyAssignment",
+        response: "AddCertPathValidationPolicyAssignmentResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "RemoveCertPathValidationPolicyAssignment",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/RemoveCertPathValidationPolicyAssignment",
+        request: "RemoveCertPathValidationPolicyAssignment",
+        response: "RemoveCertPathValidationPolicyAssignmentResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "ReplaceCertPathValidationPolicyAssignment",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/ReplaceCertPathValidationPolicyAssignment",
+        request: "ReplaceCertPathValidationPolicyAssignment",
+        response: "ReplaceCertPathValidationPolicyAssignmentResponse",
+    },
+    Operation {
+        service: "advancedsecurity",
+        name: "GetAssignedCertPathValidationPolicies",
+        namespace: "http://www.onvif.org/ver10/advancedsecurity/wsdl",
+        action: "http://www.onvif.org/ver10/advancedsecurity/wsdl/GetAssignedCertPathValidationPolicies",
+        request: "GetAssignedCertPathValidationPolicies",
+        response: "GetAssignedCertPathValidationPoliciesResponse",
+    },
+    Operation {
+        service: "analytics",
+        name: "GetServiceCapabilities",
+        namespace: "http://www.onvif.org/ver20/analytics/wsdl",
+        action: "http://www.onvif.org/ver20/analytics/wsdl/GetServiceCapabilities",
+        request: "GetServiceCapabilities",
+        response: "GetServiceCapabilitiesResponse",
+    },
+    Operation {
+        service: "analytics",
+        name: "GetSupportedAnalyticsModules",
+        namespace: "http://www.onvif.org/ver20/analytics/wsdl",
+        action: "http://www.onvif.org/ver20/analytics/wsdl/GetSupportedAnalyticsModules",
+        request: "GetSupportedAnalyticsModules",
+        response: "GetSupportedAnalyticsModulesResponse",
+    },
+    Operation {
+        service: "analytics",
+        name: "GetAnalyticsModuleOptions",
+        namespace: "http://www.onvif.org/ver20/analytics/wsdl",
+        action: "http://www.onvif.org/ver20/analytics/wsdl/GetAnalyticsModuleOptions",
+        request: "GetAnalyticsModuleOptions",
+        response: "GetAnalyticsModuleOptionsResponse",
+    },
+    Operation {
+        service: "analytics",
+        name: "CreateAnalyticsModules",
+        namespace: "http://www.onvif.org/ver20/analytics/wsdl",
+        action: "http://www.onvif.org/ver20/analytics/wsdl/CreateAnalyticsModules",
+        request: "CreateAnalyticsModules",
+        response: "CreateAnalyticsModulesResponse",
+    },
+    Operation {
+        service: "analytics",
+        name: "DeleteAnalyticsModules",
+        namespace: "http://www.onvif.org/ver20/analytics/wsdl",
+        action: "http://www.onvif.org/ver20/analytics/wsdl/DeleteAnalyticsModules",
+        request: "DeleteAnalyticsModules",
+        response: "DeleteAnalyticsModulesResponse",
+    },
+    Operation {
+        service: "analytics",
+        name: "GetAnalyticsModules",
+        namespace: "http://www.onvif.org/ver20/analytics/wsdl",
+        action: "http://www.onvif.org/ver20/analytics/wsdl/GetAnalyticsModules",
+        request: "GetAnalyticsModules",
+        response: "GetAnalyticsModulesResponse",
+    },
+    Operation {
+        service: "analytics",
+        name: "ModifyAnalyticsModules",
+        namespace: "http://www.onvif.org/ver20/analytics/wsdl",
+        action: "http://www.onvif.org/ver20/analytics/wsdl/ModifyAnalyticsModules",
+        request: "ModifyAnalyticsModules",
+        response: "ModifyAnalyticsModulesResponse",
+    },
+    Operation {
+        service: "analytics",
+        name: "GetSupportedRules",
+        namespace: "http://www.onvif.org/ver20/analytics/wsdl",
+        action: "http://www.onvif.org/ver20/analytics/wsdl/GetSupportedRules",
+        request: "GetSupportedRules",
+        response: "GetSupportedRulesResponse",
+    },
+    Operation {
+        service: "analytics",
+        name: "CreateRules",
+        namespace: "http://www.onvif.org/ver20/analytics/wsdl",
+        action: "http://www.onvif.org/ver20/analytics/wsdl/CreateRules",
+        request: "CreateRules",
+        response: "CreateRulesResponse",
+    },
+    Operation {
+        service: "analytics",
+        name: "DeleteRules",
+        namespace: "http://www.onvif.org/ver20/analytics/wsdl",
+        action: "http://www.onvif.org/ver20/analytics/wsdl/DeleteRules",
+        request: "DeleteRules",
+        response: "DeleteRulesResponse",
+    },
+    Operation {
+        service: "analytics",
+        name: "GetRules",
+        namespace: "http://www.onvif.org/ver20/analytics/wsdl",
+        action: "http://www.onvif.org/ver20/analytics/wsdl/GetRules",
+        request: "GetRules",
+        response: "GetRulesResponse",
+    },
+    Operation {
+        service: "analytics",
+        name: "GetRuleOptions",
+        namespace: "http://www.onvif.org/ver20/analytics/wsdl",
+        action: "http://www.onvif.org/ver20/analytics/wsdl/GetRuleOptions",
+        request: "GetRuleOptions",
+        response: "GetRuleOptionsResponse",
+    },
+    Operation {
+        service: "analytics",
+        name: "ModifyRules",
+        namespace: "http://www.onvif.org/ver20/analytics/wsdl",
+        action: "http://www.onvif.org/ver20/analytics/wsdl/ModifyRules",
+        request: "ModifyRules",
+        response: "ModifyRulesResponse",
+    },
+    Operation {
+        service: "authenticationbehavior",
+        name: "GetServiceCapabilities",
+        namespace: "http://www.onvif.org/ver10/authenticationbehavior/wsdl",
+        action: "http://www.onvif.org/ver10/authenticationbehavior/wsdl/GetServiceCapabilities",
+        request: "GetServiceCapabilities",
+        response: "GetServiceCapabilitiesResponse",
+    },
+    Operation {
+        service: "authenticationbehavior",
+        name: "GetAuthenticationProfileInfo",
+        namespace: "http://www.onvif.org/ver10/authenticationbehavior/wsdl",
+        action: "http://www.onvif.org/ver10/authenticationbehavior/wsdl/GetAuthenticationProfileInfo",
+        request: "GetAuthenticationProfileInfo",
+        response: "GetAuthenticationProfileInfoResponse",
+    },
+    Operation {
+        service: "authenticationbehavior",
+        name: "GetAuthenticationProfileInfoList",
+        namespace: "http://www.onvif.org/ver10/authenticationbehavior/wsdl",
+        action: "http://www.onvif.org/ver10/authenticationbehavior/wsdl/GetAuthenticationProfileInfoList",
+        request: "GetAuthenticationProfileInfoList",
+        response: "GetAuthenticationProfileInfoListResponse",
+    },
+    Operation {
+        service: "authenticationbehavior",
+        name: "GetAuthenticationProfiles",
+        namespace: "http://www.onvif.org/ver10/authenticationbehavior/wsdl",
+        action: "http://www.onvif.org/ver10/authenticationbehavior/wsdl/GetAuthenticationProfiles",
+        request: "GetAuthenticationProfiles",
+        response: "GetAuthenticationProfilesResponse",
+    },
+    Operation {
+        service: "authenticationbehavior",
+        name: "GetAuthenticationProfileList",
+        namespace: "http://www.onvif.org/ver10/authenticationbehavior/wsdl",
+        action: "http://www.onvif.org/ver10/authenticationbehavior/wsdl/GetAuthenticationProfileList",
+        request: "GetAuthenticationProfileList",
+        response: "GetAuthenticationProfileListResponse",
+    },
+    Operation {
+        service: "authenticationbehavior",
+        name: "CreateAuthenticationProfile",
+        namespace: "http://www.onvif.org/ver10/authenticationbehavior/wsdl",
+        action: "http://www.onvif.org/ver10/authenticationbehavior/wsdl/CreateAuthenticationProfile",
+        request: "CreateAuthenticationProfile",
+        response: "CreateAuthenticationProfileResponse",
+    },
+    Operation {
+        service: "authenticationbehavior",
+        name: "SetAuthenticationProfile",
+        namespace: "http://www.onvif.org/ver10/authenticationbehavior/wsdl",
+        action: "http://www.onvif.org/ver10/authenticationbehavior/wsdl/SetAuthenticationProfile",
+        request: "SetAuthenticationProfile",
+        response: "SetAuthenticationProfileResponse",
+    },
+    Operation {
+        service: "authenticationbehavior",
+        name: "ModifyAuthenticationProfile",
+        namespace: "http://www.onvif.org/ver10/authenticationbehavior/wsdl",
+        action: "http://www.onvif.org/ver10/authenticationbehavior/wsdl/ModifyAuthenticationProfile",
+        request: "ModifyAuthenticationProfile",
+        response: "ModifyAuthenticationProfileResponse",
+    },
+    Operation {
+        service: "authenticationbehavior",
+        name: "DeleteAuthenticationProfile",
+        namespace: "http://www.onvif.org/ver10/authenticationbehavior/wsdl",
+        action: "http://www.onvif.org/ver10/authenticationbehavior/wsdl/DeleteAuthenticationProfile",
+        request: "DeleteAuthenticationProfile",
+        response: "DeleteAuthenticationProfileResponse",
+    },
+    Operation {
+        service: "authenticationbehavior",
+        name: "GetSecurityLevelInfo",
+        namespace: "http://www.onvif.org/ver10/authenticationbehavior/wsdl",
+        action: "http://www.onvif.org/ver10/authenticationbehavior/wsdl/GetSecurityLevelInfo",
+        request: "GetSecurityLevelInfo",
+        response: "GetSecurityLevelInfoResponse",
+    },
+    Operation {
+        service: "authenticationbehavior",
+        name: "GetSecurityLevelInfoList",
+        namespace: "http://www.onvif.org/ver10/authenticationbehavior/wsdl",
+        action: "http://www.onvif.org/ver10/authenticationbehavior/wsdl/GetSecurityLevelInfoList",
+        request: "GetSecurityLevelInfoList",
+        response: "GetSecurityLevelInfoListResponse",
+    },
+    Operation {
+        service: "authenticationbehavior",
+        name: "GetSecurityLevels",
+        namespace: "http://www.onvif.org/ver10/authenticationbehavior/wsdl",
+        action: "http://www.onvif.org/ver10/authenticationbehavior/wsdl/GetSecurityLevels",
+        request: "GetSecurityLevels",
+        response: "GetSecurityLevelsResponse",
+    },
+    Operation {
+        service: "authenticationbehavior",
+        name: "GetSecurityLevelList",
+        namespace: "http://www.onvif.org/ver10/authenticationbehavior/wsdl",
+        action: "http://www.onvif.org/ver10/authenticationbehavior/wsdl/GetSecurityLevelList",
+        request: "GetSecurityLevelList",
+        response: "GetSecurityLevelListResponse",
+    },
+    Operation {
+        service: "authenticationbehavior",
+        name: "CreateSecurityLevel",
+        namespace: "http://www.onvif.org/ver10/authenticationbehavior/wsdl",
+        action: "http://www.onvif.org/ver10/authenticationbehavior/wsdl/CreateSecurityLevel",
+        request: "CreateSecurityLevel",
+        response: "CreateSecurityLevelResponse",
+    },
+    Operation {
+        service: "authenticationbehavior",
+        name: "SetSecurityLevel",
+        namespace: "http://www.onvif.org/ver10/authenticationbehavior/wsdl",
+        action: "http://www.onvif.org/ver10/authenticationbehavior/wsdl/SetSecurityLevel",
+        request: "SetSecurityLevel",
+        response: "SetSecurityLevelResponse",
+    },
+    Operation {
+        service: "authenticationbehavior",
+        name: "ModifySecurityLevel",
+        namespace: "http://www.onvif.org/ver10/authenticationbehavior/wsdl",
+        action: "http://www.onvif.org/ver10/authenticationbehavior/wsdl/ModifySecurityLevel",
+        request: "ModifySecurityLevel",
+        response: "ModifySecurityLevelResponse",
+    },
+    Operation {
+        service: "authenticationbehavior",
+        name: "DeleteSecurityLevel",
+        namespace: "http://www.onvif.org/ver10/authenticationbehavior/wsdl",
+        action: "http://www.onvif.org/ver10/authenticationbehavior/wsdl/DeleteSecurityLevel",
+        request: "DeleteSecurityLevel",
+        response: "DeleteSecurityLevelResponse",
+    },
+    Operation {
+        service: "credential",
+        name: "GetServiceCapabilities",
+        namespace: "http://www.onvif.org/ver10/credential/wsdl",
+        action: "http://www.onvif.org/ver10/credential/wsdl/GetServiceCapabilities",
+        request: "GetServiceCapabilities",
+        response: "GetServiceCapabilitiesResponse",
+    },
+    Operation {
+        service: "credential",
+        name: "GetSupportedFormatTypes",
+        namespace: "http://www.onvif.org/ver10/credential/wsdl",
+        action: "http://www.onvif.org/ver10/credential/wsdl/GetSupportedFormatTypes",
+        request: "GetSupportedFormatTypes",
+        response: "GetSupportedFormatTypesResponse",
+    },
+    Operation {
+        service: "credential",
+        name: "GetCredentialInfo",
+        namespace: "http://www.onvif.org/ver10/credential/wsdl",
+        action: "http://www.onvif.org/ver10/credential/wsdl/GetCredentialInfo",
+        request: "GetCredentialInfo",
+        response: "GetCredentialInfoResponse",
+    },
+    Operation {
+        service: "credential",
+        name: "GetCredentialInfoList",
+        namespace: "http://www.onvif.org/ver10/credential/wsdl",
+        action: "http://www.onvif.org/ver10/credential/wsdl/GetCredentialInfoList",
+        request: "GetCredentialInfoList",
+        response: "GetCredentialInfoListResponse",
+    },
+    Operation {
+        service: "credential",
+        name: "GetCredentials",
+        namespace: "http://www.onvif.org/ver10/credential/wsdl",
+        action: "http://www.onvif.org/ver10/credential/wsdl/GetCredentials",
+        request: "GetCredentials",
+        response: "GetCredentialsResponse",
+    },
+    Operation {
+        service: "credential",
+        name: "GetCredentialList",
+        namespace: "http://www.onvif.org/ver10/credential/wsdl",
+        action: "http://www.onvif.org/ver10/credential/wsdl/GetCredentialList",
+        request: "GetCredentialList",
+        response: "GetCredentialListResponse",
+    },
+    Operation {
+        service: "credential",
+        name: "CreateCredential",
+        namespace: "http://www.onvif.org/ver10/credential/wsdl",
+        action: "http://www.onvif.org/ver10/credential/wsdl/CreateCredential",
+        request: "CreateCredential",
+        response: "CreateCredentialResponse",
+    },
+    Operation {
+        service: "credential",
+        name: "SetCredential",
+        namespace: "http://www.onvif.org/ver10/credential/wsdl",
+        action: "http://www.onvif.org/ver10/credential/wsdl/SetCredential",
+        request: "SetCredential",
+        response: "SetCredentialResponse",
+    },
+    Operation {
+        service: "credential",
+        name: "ModifyCredential",
+        namespace: "http://www.onvif.org/ver10/credential/wsdl",
+        action: "http://www.onvif.org/ver10/credential/wsdl/ModifyCredential",
+        request: "ModifyCredential",
+        response: "ModifyCredentialResponse",
+    },
+    Operation {
+        service: "credential",
+        name: "DeleteCredential",
+        namespace: "http://www.onvif.org/ver10/credential/wsdl",
+        action: "http://www.onvif.org/ver10/credential/wsdl/DeleteCredential",
+        request: "DeleteCredential",
+        response: "DeleteCredentialResponse",
+    },
+    Operation {
+        service: "credential",
+        name: "GetCredentialState",
+        namespace: "http://www.onvif.org/ver10/credential/wsdl",
+        action: "http://www.onvif.org/ver10/credential/wsdl/GetCredentialState",
+        request: "GetCredentialState",
+        response: "GetCredentialStateResponse",
+    },
+    Operation {
+        service: "credential",
+        name: "EnableCredential",
+        namespace: "http://www.onvif.org/ver10/credential/wsdl",
+        action: "http://www.onvif.org/ver10/credential/wsdl/EnableCredential",
+        request: "EnableCredential",
+        response: "EnableCredentialResponse",
+    },
+    Operation {
+        service: "credential",
+        name: "DisableCredential",
+        namespace: "http://www.onvif.org/ver10/credential/wsdl",
+        action: "http://www.onvif.org/ver10/credential/wsdl/DisableCredential",
+        request: "DisableCredential",
+        response: "DisableCredentialResponse",
+    },
+    Operation {
+        service: "credential",
+        name: "ResetAntipassbackViolation",
+        namespace: "http://www.onvif.org/ver10/credential/wsdl",
+        action: "http://www.onvif.org/ver10/credential/wsdl/ResetAntipassbackViolation",
+        request: "ResetAntipassbackViolation",
+        response: "ResetAntipassbackViolationResponse",
+    },
+    Operation {
+        service: "credential",
+        name: "GetCredentialIdentifiers",
+        namespace: "http://www.onvif.org/ver10/credential/wsdl",
+        action: "http://www.onvif.org/ver10/credential/wsdl/GetCredentialIdentifiers",
+        request: "GetCredentialIdentifiers",
+        response: "GetCredentialIdentifiersResponse",
+    },
+    Operation {
+        service: "credential",
+        name: "SetCredentialIdentifier",
+        namespace: "http://www.onvif.org/ver10/credential/wsdl",
+        action: "http://www.onvif.org/ver10/credential/wsdl/SetCredentialIdentifier",
+        request: "SetCredentialIdentifier",
+        response: "SetCredentialIdentifierResponse",
+    },
+    Operation {
+        service: "credential",
+        name: "DeleteCredentialIdentifier",
+        namespace: "http://www.onvif.org/ver10/credential/wsdl",
+        action: "http://www.onvif.org/ver10/credential/wsdl/DeleteCredentialIdentifier",
+        request: "DeleteCredentialIdentifier",
+        response: "DeleteCredentialIdentifierResponse",
+    },
+    Operation {
+        service: "credential",
+        name: "GetCredentialAccessProfiles",
+        namespace: "http://www.onvif.org/ver10/credential/wsdl",
+        action: "http://www.onvif.org/ver10/credential/wsdl/GetCredentialAccessProfiles",
+        request: "GetCredentialAccessProfiles",
+        response: "GetCredentialAccessProfilesResponse",
+    },
+    Operation {
+        service: "credential",
+        name: "SetCredentialAccessProfiles",
+        namespace: "http://www.onvif.org/ver10/credential/wsdl",
+        action: "http://www.onvif.org/ver10/credential/wsdl/SetCredentialAccessProfiles",
+        request: "SetCredentialAccessProfiles",
+        response: "SetCredentialAccessProfilesResponse",
+    },
+    Operation {
+        service: "credential",
+        name: "DeleteCredentialAccessProfiles",
+        namespace: "http://www.onvif.org/ver10/credential/wsdl",
+        action: "http://www.onvif.org/ver10/credential/wsdl/DeleteCredentialAccessProfiles",
+        request: "DeleteCredentialAccessProfiles",
+        response: "DeleteCredentialAccessProfilesResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "GetServiceCapabilities",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/GetServiceCapabilities",
+        request: "GetServiceCapabilities",
+        response: "GetServiceCapabilitiesResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "GetRelayOutputOptions",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/GetRelayOutputOptions",
+        request: "GetRelayOutputOptions",
+        response: "GetRelayOutputOptionsResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "GetAudioSources",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/GetAudioSources",
+        request: "GetAudioSources",
+        response: "GetAudioSourcesResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "GetAudioOutputs",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/GetAudioOutputs",
+        request: "GetAudioOutputs",
+        response: "GetAudioOutputsResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "GetVideoSources",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/GetVideoSources",
+        request: "GetVideoSources",
+        response: "GetVideoSourcesResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "GetVideoOutputs",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/GetVideoOutputs",
+        request: "GetVideoOutputs",
+        response: "GetVideoOutputsResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "GetVideoSourceConfiguration",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/GetVideoSourceConfiguration",
+        request: "GetVideoSourceConfiguration",
+        response: "GetVideoSourceConfigurationResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "GetVideoOutputConfiguration",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/GetVideoOutputConfiguration",
+        request: "GetVideoOutputConfiguration",
+        response: "GetVideoOutputConfigurationResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "GetAudioSourceConfiguration",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/GetAudioSourceConfiguration",
+        request: "GetAudioSourceConfiguration",
+        response: "GetAudioSourceConfigurationResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "GetAudioOutputConfiguration",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/GetAudioOutputConfiguration",
+        request: "GetAudioOutputConfiguration",
+        response: "GetAudioOutputConfigurationResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "SetVideoSourceConfiguration",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/SetVideoSourceConfiguration",
+        request: "SetVideoSourceConfiguration",
+        response: "SetVideoSourceConfigurationResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "SetVideoOutputConfiguration",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/SetVideoOutputConfiguration",
+        request: "SetVideoOutputConfiguration",
+        response: "SetVideoOutputConfigurationResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "SetAudioSourceConfiguration",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/SetAudioSourceConfiguration",
+        request: "SetAudioSourceConfiguration",
+        response: "SetAudioSourceConfigurationResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "SetAudioOutputConfiguration",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/SetAudioOutputConfiguration",
+        request: "SetAudioOutputConfiguration",
+        response: "SetAudioOutputConfigurationResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "GetVideoSourceConfigurationOptions",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/GetVideoSourceConfigurationOptions",
+        request: "GetVideoSourceConfigurationOptions",
+        response: "GetVideoSourceConfigurationOptionsResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "GetVideoOutputConfigurationOptions",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/GetVideoOutputConfigurationOptions",
+        request: "GetVideoOutputConfigurationOptions",
+        response: "GetVideoOutputConfigurationOptionsResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "GetAudioSourceConfigurationOptions",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/GetAudioSourceConfigurationOptions",
+        request: "GetAudioSourceConfigurationOptions",
+        response: "GetAudioSourceConfigurationOptionsResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "GetAudioOutputConfigurationOptions",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/GetAudioOutputConfigurationOptions",
+        request: "GetAudioOutputConfigurationOptions",
+        response: "GetAudioOutputConfigurationOptionsResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "GetRelayOutputs",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/GetRelayOutputs",
+        request: "tds::GetRelayOutputs",
+        response: "tds::GetRelayOutputsResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "SetRelayOutputSettings",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/SetRelayOutputSettings",
+        request: "SetRelayOutputSettings",
+        response: "SetRelayOutputSettingsResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "SetRelayOutputState",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/SetRelayOutputState",
+        request: "tds::SetRelayOutputState",
+        response: "tds::SetRelayOutputStateResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "GetDigitalInputs",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/GetDigitalInputs",
+        request: "GetDigitalInputs",
+        response: "GetDigitalInputsResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "GetDigitalInputConfigurationOptions",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/GetDigitalInputConfigurationOptions",
+        request: "GetDigitalInputConfigurationOptions",
+        response: "GetDigitalInputConfigurationOptionsResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "SetDigitalInputConfigurations",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/SetDigitalInputConfigurations",
+        request: "SetDigitalInputConfigurations",
+        response: "SetDigitalInputConfigurationsResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "GetSerialPorts",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/GetSerialPorts",
+        request: "GetSerialPorts",
+        response: "GetSerialPortsResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "GetSerialPortConfiguration",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/GetSerialPortConfigurations",
+        request: "GetSerialPortConfiguration",
+        response: "GetSerialPortConfigurationResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "SetSerialPortConfiguration",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/SetSerialPortConfiguration",
+        request: "SetSerialPortConfiguration",
+        response: "SetSerialPortConfigurationResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "GetSerialPortConfigurationOptions",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/GetSerialPortConfigurationOptions",
+        request: "GetSerialPortConfigurationOptions",
+        response: "GetSerialPortConfigurationOptionsResponse",
+    },
+    Operation {
+        service: "deviceio",
+        name: "SendReceiveSerialCommand",
+        namespace: "http://www.onvif.org/ver10/deviceIO/wsdl",
+        action: "http://www.onvif.org/ver10/deviceio/wsdl/SendReceiveSerialCommand",
+        request: "SendReceiveSerialCommand",
+        response: "SendReceiveSerialCommandResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetServices",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetServices",
+        request: "GetServices",
+        response: "GetServicesResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetServiceCapabilities",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetServiceCapabilities",
+        request: "GetServiceCapabilities",
+        response: "GetServiceCapabilitiesResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetDeviceInformation",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetDeviceInformation",
+        request: "GetDeviceInformation",
+        response: "GetDeviceInformationResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetSystemDateAndTime",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetSystemDateAndTime",
+        request: "SetSystemDateAndTime",
+        response: "SetSystemDateAndTimeResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetSystemDateAndTime",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetSystemDateAndTime",
+        request: "GetSystemDateAndTime",
+        response: "GetSystemDateAndTimeResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetSystemFactoryDefault",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetSystemFactoryDefault",
+        request: "SetSystemFactoryDefault",
+        response: "SetSystemFactoryDefaultResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "UpgradeSystemFirmware",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/UpgradeSystemFirmware",
+        request: "UpgradeSystemFirmware",
+        response: "UpgradeSystemFirmwareResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SystemReboot",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SystemReboot",
+        request: "SystemReboot",
+        response: "SystemRebootResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "RestoreSystem",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/RestoreSystem",
+        request: "RestoreSystem",
+        response: "RestoreSystemResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetSystemBackup",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetSystemBackup",
+        request: "GetSystemBackup",
+        response: "GetSystemBackupResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetSystemLog",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetSystemLog",
+        request: "GetSystemLog",
+        response: "GetSystemLogResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetSystemSupportInformation",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetSystemSupportInformation",
+        request: "GetSystemSupportInformation",
+        response: "GetSystemSupportInformationResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetScopes",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetScopes",
+        request: "GetScopes",
+        response: "GetScopesResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetScopes",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetScopes",
+        request: "SetScopes",
+        response: "SetScopesResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "AddScopes",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/AddScopes",
+        request: "AddScopes",
+        response: "AddScopesResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "RemoveScopes",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/RemoveScopes",
+        request: "RemoveScopes",
+        response: "RemoveScopesResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetDiscoveryMode",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetDiscoveryMode",
+        request: "GetDiscoveryMode",
+        response: "GetDiscoveryModeResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetDiscoveryMode",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetDiscoveryMode",
+        request: "SetDiscoveryMode",
+        response: "SetDiscoveryModeResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetRemoteDiscoveryMode",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetRemoteDiscoveryMode",
+        request: "GetRemoteDiscoveryMode",
+        response: "GetRemoteDiscoveryModeResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetRemoteDiscoveryMode",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetRemoteDiscoveryMode",
+        request: "SetRemoteDiscoveryMode",
+        response: "SetRemoteDiscoveryModeResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetDPAddresses",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetDPAddresses",
+        request: "GetDPAddresses",
+        response: "GetDPAddressesResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetDPAddresses",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetDPAddresses",
+        request: "SetDPAddresses",
+        response: "SetDPAddressesResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetEndpointReference",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetEndpointReference",
+        request: "GetEndpointReference",
+        response: "GetEndpointReferenceResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetRemoteUser",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetRemoteUser",
+        request: "GetRemoteUser",
+        response: "GetRemoteUserResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetRemoteUser",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetRemoteUser",
+        request: "SetRemoteUser",
+        response: "SetRemoteUserResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetUsers",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetUsers",
+        request: "GetUsers",
+        response: "GetUsersResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "CreateUsers",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/CreateUsers",
+        request: "CreateUsers",
+        response: "CreateUsersResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "DeleteUsers",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/DeleteUsers",
+        request: "DeleteUsers",
+        response: "DeleteUsersResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetUser",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetUser",
+        request: "SetUser",
+        response: "SetUserResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetWsdlUrl",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetWsdlUrl",
+        request: "GetWsdlUrl",
+        response: "GetWsdlUrlResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetCapabilities",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetCapabilities",
+        request: "GetCapabilities",
+        response: "GetCapabilitiesResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetHostname",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetHostname",
+        request: "GetHostname",
+        response: "GetHostnameResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetHostname",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetHostname",
+        request: "SetHostname",
+        response: "SetHostnameResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetHostnameFromDHCP",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetHostnameFromDHCP",
+        request: "SetHostnameFromDHCP",
+        response: "SetHostnameFromDHCPResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetDNS",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetDNS",
+        request: "GetDNS",
+        response: "GetDNSResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetDNS",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetDNS",
+        request: "SetDNS",
+        response: "SetDNSResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetNTP",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetNTP",
+        request: "GetNTP",
+        response: "GetNTPResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetNTP",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetNTP",
+        request: "SetNTP",
+        response: "SetNTPResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetDynamicDNS",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetDynamicDNS",
+        request: "GetDynamicDNS",
+        response: "GetDynamicDNSResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetDynamicDNS",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetDynamicDNS",
+        request: "SetDynamicDNS",
+        response: "SetDynamicDNSResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetNetworkInterfaces",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetNetworkInterfaces",
+        request: "GetNetworkInterfaces",
+        response: "GetNetworkInterfacesResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetNetworkInterfaces",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetNetworkInterfaces",
+        request: "SetNetworkInterfaces",
+        response: "SetNetworkInterfacesResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetNetworkProtocols",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetNetworkProtocols",
+        request: "GetNetworkProtocols",
+        response: "GetNetworkProtocolsResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetNetworkProtocols",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetNetworkProtocols",
+        request: "SetNetworkProtocols",
+        response: "SetNetworkProtocolsResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetNetworkDefaultGateway",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetNetworkDefaultGateway",
+        request: "GetNetworkDefaultGateway",
+        response: "GetNetworkDefaultGatewayResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetNetworkDefaultGateway",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetNetworkDefaultGateway",
+        request: "SetNetworkDefaultGateway",
+        response: "SetNetworkDefaultGatewayResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetZeroConfiguration",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetZeroConfiguration",
+        request: "GetZeroConfiguration",
+        response: "GetZeroConfigurationResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetZeroConfiguration",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetZeroConfiguration",
+        request: "SetZeroConfiguration",
+        response: "SetZeroConfigurationResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetIPAddressFilter",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetIPAddressFilter",
+        request: "GetIPAddressFilter",
+        response: "GetIPAddressFilterResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetIPAddressFilter",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetIPAddressFilter",
+        request: "SetIPAddressFilter",
+        response: "SetIPAddressFilterResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "AddIPAddressFilter",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/AddIPAddressFilter",
+        request: "AddIPAddressFilter",
+        response: "AddIPAddressFilterResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "RemoveIPAddressFilter",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/RemoveIPAddressFilter",
+        request: "RemoveIPAddressFilter",
+        response: "RemoveIPAddressFilterResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetAccessPolicy",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetAccessPolicy",
+        request: "GetAccessPolicy",
+        response: "GetAccessPolicyResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetAccessPolicy",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetAccessPolicy",
+        request: "SetAccessPolicy",
+        response: "SetAccessPolicyResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "CreateCertificate",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/CreateCertificate",
+        request: "CreateCertificate",
+        response: "CreateCertificateResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetCertificates",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetCertificates",
+        request: "GetCertificates",
+        response: "GetCertificatesResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetCertificatesStatus",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetCertificatesStatus",
+        request: "GetCertificatesStatus",
+        response: "GetCertificatesStatusResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetCertificatesStatus",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetCertificatesStatus",
+        request: "SetCertificatesStatus",
+        response: "SetCertificatesStatusResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "DeleteCertificates",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/DeleteCertificates",
+        request: "DeleteCertificates",
+        response: "DeleteCertificatesResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetPkcs10Request",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetPkcs10Request",
+        request: "GetPkcs10Request",
+        response: "GetPkcs10RequestResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "LoadCertificates",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/LoadCertificates",
+        request: "LoadCertificates",
+        response: "LoadCertificatesResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetClientCertificateMode",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetClientCertificateMode",
+        request: "GetClientCertificateMode",
+        response: "GetClientCertificateModeResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetClientCertificateMode",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetClientCertificateMode",
+        request: "SetClientCertificateMode",
+        response: "SetClientCertificateModeResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetRelayOutputs",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetRelayOutputs",
+        request: "GetRelayOutputs",
+        response: "GetRelayOutputsResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetRelayOutputSettings",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetRelayOutputSettings",
+        request: "SetRelayOutputSettings",
+        response: "SetRelayOutputSettingsResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetRelayOutputState",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetRelayOutputState",
+        request: "SetRelayOutputState",
+        response: "SetRelayOutputStateResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SendAuxiliaryCommand",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SendAuxiliaryCommand",
+        request: "SendAuxiliaryCommand",
+        response: "SendAuxiliaryCommandResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetCACertificates",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetCACertificates",
+        request: "GetCACertificates",
+        response: "GetCACertificatesResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "LoadCertificateWithPrivateKey",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/LoadCertificateWithPrivateKey",
+        request: "LoadCertificateWithPrivateKey",
+        response: "LoadCertificateWithPrivateKeyResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetCertificateInformation",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetCertificateInformation",
+        request: "GetCertificateInformation",
+        response: "GetCertificateInformationResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "LoadCACertificates",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/LoadCACertificates",
+        request: "LoadCACertificates",
+        response: "LoadCACertificatesResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "CreateDot1XConfiguration",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/CreateDot1XConfiguration",
+        request: "CreateDot1XConfiguration",
+        response: "CreateDot1XConfigurationResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetDot1XConfiguration",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetDot1XConfiguration",
+        request: "SetDot1XConfiguration",
+        response: "SetDot1XConfigurationResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetDot1XConfiguration",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetDot1XConfiguration",
+        request: "GetDot1XConfiguration",
+        response: "GetDot1XConfigurationResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetDot1XConfigurations",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetDot1XConfigurations",
+        request: "GetDot1XConfigurations",
+        response: "GetDot1XConfigurationsResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "DeleteDot1XConfiguration",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/DeleteDot1XConfiguration",
+        request: "DeleteDot1XConfiguration",
+        response: "DeleteDot1XConfigurationResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetDot11Capabilities",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetDot11Capabilities",
+        request: "GetDot11Capabilities",
+        response: "GetDot11CapabilitiesResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetDot11Status",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetDot11Status",
+        request: "GetDot11Status",
+        response: "GetDot11StatusResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "ScanAvailableDot11Networks",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/ScanAvailableDot11Networks",
+        request: "ScanAvailableDot11Networks",
+        response: "ScanAvailableDot11NetworksResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetSystemUris",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetSystemUris",
+        request: "GetSystemUris",
+        response: "GetSystemUrisResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "StartFirmwareUpgrade",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/StartFirmwareUpgrade",
+        request: "StartFirmwareUpgrade",
+        response: "StartFirmwareUpgradeResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "StartSystemRestore",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/StartSystemRestore",
+        request: "StartSystemRestore",
+        response: "StartSystemRestoreResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetStorageConfigurations",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetStorageConfigurations",
+        request: "GetStorageConfigurations",
+        response: "GetStorageConfigurationsResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "CreateStorageConfiguration",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/CreateStorageConfiguration",
+        request: "CreateStorageConfiguration",
+        response: "CreateStorageConfigurationResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetStorageConfiguration",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetStorageConfiguration",
+        request: "GetStorageConfiguration",
+        response: "GetStorageConfigurationResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetStorageConfiguration",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetStorageConfiguration",
+        request: "SetStorageConfiguration",
+        response: "SetStorageConfigurationResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "DeleteStorageConfiguration",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/DeleteStorageConfiguration",
+        request: "DeleteStorageConfiguration",
+        response: "DeleteStorageConfigurationResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "GetGeoLocation",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/GetGeoLocation",
+        request: "GetGeoLocation",
+        response: "GetGeoLocationResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "SetGeoLocation",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/SetGeoLocation",
+        request: "SetGeoLocation",
+        response: "SetGeoLocationResponse",
+    },
+    Operation {
+        service: "devicemgmt",
+        name: "DeleteGeoLocation",
+        namespace: "http://www.onvif.org/ver10/device/wsdl",
+        action: "http://www.onvif.org/ver10/device/wsdl/DeleteGeoLocation",
+        request: "DeleteGeoLocation",
+        response: "DeleteGeoLocationResponse",
+    },
+    Operation {
+        service: "display",
+        name: "GetServiceCapabilities",
+        namespace: "http://www.onvif.org/ver10/display/wsdl",
+        action: "http://www.onvif.org/ver10/display/wsdl/GetServiceCapabilities",
+        request: "GetServiceCapabilities",
+        response: "GetServiceCapabilitiesResponse",
+    },
+    Operation {
+        service: "display",
+        name: "GetLayout",
+        namespace: "http://www.onvif.org/ver10/display/wsdl",
+        action: "http://www.onvif.org/ver10/display/wsdl/GetLayout",
+        request: "GetLayout",
+        response: "GetLayoutResponse",
+    },
+    Operation {
+        service: "display",
+        name: "SetLayout",
+        namespace: "http://www.onvif.org/ver10/display/wsdl",
+        action: "http://www.onvif.org/ver10/display/wsdl/SetLayout",
+        request: "SetLayout",
+        response: "SetLayoutResponse",
+    },
+    Operation {
+        service: "display",
+        name: "GetDisplayOptions",
+        namespace: "http://www.onvif.org/ver10/display/wsdl",
+        action: "http://www.onvif.org/ver10/display/wsdl/GetDisplayOptions",
+        request: "GetDisplayOptions",
+        response: "GetDisplayOptionsResponse",
+    },
+    Operation {
+        service: "display",
+        name: "GetPaneConfigurations",
+        namespace: "http://www.onvif.org/ver10/display/wsdl",
+        action: "http://www.onvif.org/ver10/display/wsdl/GetPaneConfigurations",
+        request: "GetPaneConfigurations",
+        response: "GetPaneConfigurationsResponse",
+    },
+    Operation {
+        service: "display",
+        name: "GetPaneConfiguration",
+        namespace: "http://www.onvif.org/ver10/display/wsdl",
+        action: "http://www.onvif.org/ver10/display/wsdl/GetPaneConfiguration",
+        request: "GetPaneConfiguration",
+        response: "GetPaneConfigurationResponse",
+    },
+    Operation {
+        service: "display",
+        name: "SetPaneConfigurations",
+        namespace: "http://www.onvif.org/ver10/display/wsdl",
+        action: "http://www.onvif.org/ver10/display/wsdl/SetPaneConfigurations",
+        request: "SetPaneConfigurations",
+        response: "SetPaneConfigurationsResponse",
+    },
+    Operation {
+        service: "display",
+        name: "SetPaneConfiguration",
+        namespace: "http://www.onvif.org/ver10/display/wsdl",
+        action: "http://www.onvif.org/ver10/display/wsdl/SetPaneConfiguration",
+        request: "SetPaneConfiguration",
+        response: "SetPaneConfigurationResponse",
+    },
+    Operation {
+        service: "display",
+        name: "CreatePaneConfiguration",
+        namespace: "http://www.onvif.org/ver10/display/wsdl",
+        action: "http://www.onvif.org/ver10/display/wsdl/CreatePaneConfiguration",
+        request: "CreatePaneConfiguration",
+        response: "CreatePaneConfigurationResponse",
+    },
+    Operation {
+        service: "display",
+        name: "DeletePaneConfiguration",
+        namespace: "http://www.onvif.org/ver10/display/wsdl",
+        action: "http://www.onvif.org/ver10/display/wsdl/DeletePaneConfiguration",
+        request: "DeletePaneConfiguration",
+        response: "DeletePaneConfigurationResponse",
+    },
+    Operation {
+        service: "doorcontrol",
+        name: "GetServiceCapabilities",
+        namespace: "http://www.onvif.org/ver10/doorcontrol/wsdl",
+        action: "http://www.onvif.org/ver10/doorcontrol/wsdl/GetServiceCapabilities",
+        request: "GetServiceCapabilities",
+        response: "GetServiceCapabilitiesResponse",
+    },
+    Operation {
+        service: "doorcontrol",
+        name: "GetDoorInfoList",
+        namespace: "http://www.onvif.org/ver10/doorcontrol/wsdl",
+        action: "http://www.onvif.org/ver10/doorcontrol/wsdl/GetDoorInfoList",
+        request: "GetDoorInfoList",
+        response: "GetDoorInfoListResponse",
+    },
+    Operation {
+        service: "doorcontrol",
+        name: "GetDoorInfo",
+        namespace: "http://www.onvif.org/ver10/doorcontrol/wsdl",
+        action: "http://www.onvif.org/ver10/doorcontrol/wsdl/GetDoorInfo",
+        request: "GetDoorInfo",
+        response: "GetDoorInfoResponse",
+    },
+    Operation {
+        service: "doorcontrol",
+        name: "GetDoorList",
+        namespace: "http://www.onvif.org/ver10/doorcontrol/wsdl",
+        action: "http://www.onvif.org/ver10/doorcontrol/wsdl/GetDoorList",
+        request: "GetDoorList",
+        response: "GetDoorListResponse",
+    },
+    Operation {
+        service: "doorcontrol",
+        name: "GetDoors",
+        namespace: "http://www.onvif.org/ver10/doorcontrol/wsdl",
+        action: "http://www.onvif.org/ver10/doorcontrol/wsdl/GetDoors",
+        request: "GetDoors",
+        response: "GetDoorsResponse",
+    },
+    Operation {
+        service: "doorcontrol",
+        name: "CreateDoor",
+        namespace: "http://www.onvif.org/ver10/doorcontrol/wsdl",
+        action: "http://www.onvif.org/ver10/doorcontrol/wsdl/CreateDoor",
+        request: "CreateDoor",
+        response: "CreateDoorResponse",
+    },
+    Operation {
+        service: "doorcontrol",
+        name: "SetDoor",
+        namespace: "http://www.onvif.org/ver10/doorcontrol/wsdl",
+        action: "http://www.onvif.org/ver10/doorcontrol/wsdl/SetDoor",
+        request: "SetDoor",
+        response: "SetDoorResponse",
+    },
+    Operation {
+        service: "doorcontrol",
+        name: "ModifyDoor",
+        namespace: "http://www.onvif.org/ver10/doorcontrol/wsdl",
+        action: "http://www.onvif.org/ver10/doorcontrol/wsdl/ModifyDoor",
+        request: "ModifyDoor",
+        response: "ModifyDoorResponse",
+    },
+    Operation {
+        service: "doorcontrol",
+        name: "DeleteDoor",
+        namespace: "http://www.onvif.org/ver10/doorcontrol/wsdl",
+        action: "http://www.onvif.org/ver10/doorcontrol/wsdl/DeleteDoor",
+        request: "DeleteDoor",
+        response: "DeleteDoorResponse",
+    },
+    Operation {
+        service: "doorcontrol",
+        name: "GetDoorState",
+        namespace: "http://www.onvif.org/ver10/doorcontrol/wsdl",
+        action: "http://www.onvif.org/ver10/doorcontrol/wsdl/GetDoorState",
+        request: "GetDoorState",
+        response: "GetDoorStateResponse",
+    },
+    Operation {
+        service: "doorcontrol",
+        name: "AccessDoor",
+        namespace: "http://www.onvif.org/ver10/doorcontrol/wsdl",
+        action: "http://www.onvif.org/ver10/doorcontrol/wsdl/AccessDoor",
+        request: "AccessDoor",
+        response: "AccessDoorResponse",
+    },
+    Operation {
+        service: "doorcontrol",
+        name: "LockDoor",
+        namespace: "http://www.onvif.org/ver10/doorcontrol/wsdl",
+        action: "http://www.onvif.org/ver10/doorcontrol/wsdl/LockDoor",
+        request: "LockDoor",
+        response: "LockDoorResponse",
+    },
+    Operation {
+        service: "doorcontrol",
+        name: "UnlockDoor",
+        namespace: "http://www.onvif.org/ver10/doorcontrol/wsdl",
+        action: "http://www.onvif.org/ver10/doorcontrol/wsdl/UnlockDoor",
+        request: "UnlockDoor",
+        response: "UnlockDoorResponse",
+    },
+    Operation {
+        service: "doorcontrol",
+        name: "BlockDoor",
+        namespace: "http://www.onvif.org/ver10/doorcontrol/wsdl",
+        action: "http://www.onvif.org/ver10/doorcontrol/wsdl/BlockDoor",
+        request: "BlockDoor",
+        response: "BlockDoorResponse",
+    },
+    Operation {
+        service: "doorcontrol",
+        name: "LockDownDoor",
+        namespace: "http://www.onvif.org/ver10/doorcontrol/wsdl",
+        action: "http://www.onvif.org/ver10/doorcontrol/wsdl/LockDownDoor",
+        request: "LockDownDoor",
+        response: "LockDownDoorResponse",
+    },
+    Operation {
+        service: "doorcontrol",
+        name: "LockDownReleaseDoor",
+        namespace: "http://www.onvif.org/ver10/doorcontrol/wsdl",
+        action: "http://www.onvif.org/ver10/doorcontrol/wsdl/LockDownReleaseDoor",
+        request: "LockDownReleaseDoor",
+        response: "LockDownReleaseDoorResponse",
+    },
+    Operation {
+        service: "doorcontrol",
+        name: "LockOpenDoor",
+        namespace: "http://www.onvif.org/ver10/doorcontrol/wsdl",
+        action: "http://www.onvif.org/ver10/doorcontrol/wsdl/LockOpenDoor",
+        request: "LockOpenDoor",
+        response: "LockOpenDoorResponse",
+    },
+    Operation {
+        service: "doorcontrol",
+        name: "LockOpenReleaseDoor",
+        namespace: "http://www.onvif.org/ver10/doorcontrol/wsdl",
+        action: "http://www.onvif.org/ver10/doorcontrol/wsdl/LockOpenReleaseDoor",
+        request: "LockOpenReleaseDoor",
+        response: "LockOpenReleaseDoorResponse",
+    },
+    Operation {
+        service: "doorcontrol",
+        name: "DoubleLockDoor",
+        namespace: "http://www.onvif.org/ver10/doorcontrol/wsdl",
+        action: "http://www.onvif.org/ver10/doorcontrol/wsdl/DoubleLockDoor",
+        request: "DoubleLockDoor",
+        response: "DoubleLockDoorResponse",
+    },
+    Operation {
+        service: "event",
+        name: "GetServiceCapabilities",
+        namespace: "http://www.onvif.org/ver10/events/wsdl",
+        action: "http://www.onvif.org/ver10/events/wsdl/EventPortType/GetServiceCapabilitiesRequest",
+        request: "GetServiceCapabilities",
+        response: "GetServiceCapabilitiesResponse",
+    },
+    Operation {
+        service: "event",
+        name: "CreatePullPointSubscription",
+        namespace: "http://www.onvif.org/ver10/events/wsdl",
+        action: "http://www.onvif.org/ver10/events/wsdl/EventPortType/CreatePullPointSubscriptionRequest",
+        request: "CreatePullPointSubscription",
+        response: "CreatePullPointSubscriptionResponse",
+    },
+    Operation {
+        service: "event",
+        name: "GetEventProperties",
+        namespace: "http://www.onvif.org/ver10/events/wsdl",
+        action: "http://www.onvif.org/ver10/events/wsdl/EventPortType/GetEventPropertiesRequest",
+        request: "GetEventProperties",
+        response: "GetEventPropertiesResponse",
+    },
+    Operation {
+        service: "event",
+        name: "PullMessages",
+        namespace: "http://www.onvif.org/ver10/events/wsdl",
+        action: "http://www.onvif.org/ver10/events/wsdl/PullPointSubscription/PullMessagesRequest",
+        request: "PullMessages",
+        response: "PullMessagesResponse",
+    },
+    Operation {
+        service: "event",
+        name: "Seek",
+        namespace: "http://www.onvif.org/ver10/events/wsdl",
+        action: "http://www.onvif.org/ver10/events/wsdl/PullPointSubscription/SeekRequest",
+        request: "Seek",
+        response: "SeekResponse",
+    },
+    Operation {
+        service: "event",
+        name: "SetSynchronizationPoint",
+        namespace: "http://www.onvif.org/ver10/events/wsdl",
+        action: "http://www.onvif.org/ver10/events/wsdl/PullPointSubscription/SetSynchronizationPointRequest",
+        request: "SetSynchronizationPoint",
+        response: "SetSynchronizationPointResponse",
+    },
+    Operation {
+        service: "imaging",
+        name: "GetServiceCapabilities",
+        namespace: "http://www.onvif.org/ver20/imaging/wsdl",
+        action: "http://www.onvif.org/ver20/imaging/wsdl/GetServiceCapabilities",
+        request: "GetServiceCapabilities",
+        response: "GetServiceCapabilitiesResponse",
+    },
+    Operation {
+        service: "imaging",
+        name: "GetImagingSettings",
+        namespace: "http://www.onvif.org/ver20/imaging/wsdl",
+        action: "http://www.onvif.org/ver20/imaging/wsdl/GetImagingSettings",
+        request: "GetImagingSettings",
+        response: "GetImagingSettingsResponse",
+    },
+    Operation {
+        service: "imaging",
+        name: "SetImagingSettings",
+        namespace: "http://www.onvif.org/ver20/imaging/wsdl",
+        action: "http://www.onvif.org/ver20/imaging/wsdl/SetImagingSettings",
+        request: "SetImagingSettings",
+        response: "SetImagingSettingsResponse",
+    },
+    Operation {
+        service: "imaging",
+        name: "GetOptions",
+        namespace: "http://www.onvif.org/ver20/imaging/wsdl",
+        action: "http://www.onvif.org/ver20/imaging/wsdl/GetOptions",
+        request: "GetOptions",
+        response: "GetOptionsResponse",
+    },
+    Operation {
+        service: "imaging",
+        name: "Move",
+        namespace: "http://www.onvif.org/ver20/imaging/wsdl",
+        action: "http://www.onvif.org/ver20/imaging/wsdl/Move",
+        request: "Move",
+        response: "MoveResponse",
+    },
+    Operation {
+        service: "imaging",
+        name: "GetMoveOptions",
+        namespace: "http://www.onvif.org/ver20/imaging/wsdl",
+        action: "http://www.onvif.org/ver20/imaging/wsdl/GetMoveOptions",
+        request: "GetMoveOptions",
+        response: "GetMoveOptionsResponse",
+    },
+    Operation {
+        service: "imaging",
+        name: "Stop",
+        namespace: "http://www.onvif.org/ver20/imaging/wsdl",
+        action: "http://www.onvif.org/ver20/imaging/wsdl/FocusStop",
+        request: "Stop",
+        response: "StopResponse",
+    },
+    Operation {
+        service: "imaging",
+        name: "GetStatus",
+        namespace: "http://www.onvif.org/ver20/imaging/wsdl",
+        action: "http://www.onvif.org/ver20/imaging/wsdl/GetStatus",
+        request: "GetStatus",
+        response: "GetStatusResponse",
+    },
+    Operation {
+        service: "imaging",
+        name: "GetPresets",
+        namespace: "http://www.onvif.org/ver20/imaging/wsdl",
+        action: "http://www.onvif.org/ver20/imaging/wsdl/GetPresets",
+        request: "GetPresets",
+        response: "GetPresetsResponse",
+    },
+    Operation {
+        service: "imaging",
+        name: "GetCurrentPreset",
+        namespace: "http://www.onvif.org/ver20/imaging/wsdl",
+        action: "http://www.onvif.org/ver20/imaging/wsdl/GetCurrentPreset",
+        request: "GetCurrentPreset",
+        response: "GetCurrentPresetResponse",
+    },
+    Operation {
+        service: "imaging",
+        name: "SetCurrentPreset",
+        namespace: "http://www.onvif.org/ver20/imaging/wsdl",
+        action: "http://www.onvif.org/ver20/imaging/wsdl/SetCurrentPreset",
+        request: "SetCurrentPreset",
+        response: "SetCurrentPresetResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetServiceCapabilities",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetServiceCapabilities",
+        request: "GetServiceCapabilities",
+        response: "GetServiceCapabilitiesResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetVideoSources",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdlGetVideoSources/",
+        request: "GetVideoSources",
+        response: "GetVideoSourcesResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetAudioSources",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetAudioSources",
+        request: "GetAudioSources",
+        response: "GetAudioSourcesResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetAudioOutputs",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetAudioOutputs",
+        request: "GetAudioOutputs",
+        response: "GetAudioOutputsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "CreateProfile",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/CreateProfile",
+        request: "CreateProfile",
+        response: "CreateProfileResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetProfile",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdlGetProfile/",
+        request: "GetProfile",
+        response: "GetProfileResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetProfiles",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetProfiles",
+        request: "GetProfiles",
+        response: "GetProfilesResponse",
+    },
+    Operation {
+        service: "media",
+        name: "AddVideoEncoderConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/AddVideoEncoderConfiguration",
+        request: "AddVideoEncoderConfiguration",
+        response: "AddVideoEncoderConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "RemoveVideoEncoderConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/RemoveVideoEncoderConfiguration",
+        request: "RemoveVideoEncoderConfiguration",
+        response: "RemoveVideoEncoderConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "AddVideoSourceConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/AddVideoSourceConfiguration",
+        request: "AddVideoSourceConfiguration",
+        response: "AddVideoSourceConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "RemoveVideoSourceConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/RemoveVideoSourceConfiguration",
+        request: "RemoveVideoSourceConfiguration",
+        response: "RemoveVideoSourceConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "AddAudioEncoderConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/AddAudioEncoderConfiguration",
+        request: "AddAudioEncoderConfiguration",
+        response: "AddAudioEncoderConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "RemoveAudioEncoderConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/RemoveAudioEncoderConfiguration",
+        request: "RemoveAudioEncoderConfiguration",
+        response: "RemoveAudioEncoderConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "AddAudioSourceConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/AddAudioSourceConfiguration",
+        request: "AddAudioSourceConfiguration",
+        response: "AddAudioSourceConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "RemoveAudioSourceConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/RemoveAudioSourceConfiguration",
+        request: "RemoveAudioSourceConfiguration",
+        response: "RemoveAudioSourceConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "AddPTZConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/AddPTZConfiguration",
+        request: "AddPTZConfiguration",
+        response: "AddPTZConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "RemovePTZConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/RemovePTZConfiguration",
+        request: "RemovePTZConfiguration",
+        response: "RemovePTZConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "AddVideoAnalyticsConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/AddVideoAnalyticsConfiguration",
+        request: "AddVideoAnalyticsConfiguration",
+        response: "AddVideoAnalyticsConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "RemoveVideoAnalyticsConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/RemoveVideoAnalyticsConfiguration",
+        request: "RemoveVideoAnalyticsConfiguration",
+        response: "RemoveVideoAnalyticsConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "AddMetadataConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/AddMetadataConfiguration",
+        request: "AddMetadataConfiguration",
+        response: "AddMetadataConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "RemoveMetadataConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/RemoveMetadataConfiguration",
+        request: "RemoveMetadataConfiguration",
+        response: "RemoveMetadataConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "AddAudioOutputConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/AddAudioOutputConfiguration",
+        request: "AddAudioOutputConfiguration",
+        response: "AddAudioOutputConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "RemoveAudioOutputConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/RemoveAudioOutputConfiguration",
+        request: "RemoveAudioOutputConfiguration",
+        response: "RemoveAudioOutputConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "AddAudioDecoderConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/AddAudioDecoderConfiguration",
+        request: "AddAudioDecoderConfiguration",
+        response: "AddAudioDecoderConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "RemoveAudioDecoderConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/RemoveAudioDecoderConfiguration",
+        request: "RemoveAudioDecoderConfiguration",
+        response: "RemoveAudioDecoderConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "DeleteProfile",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/DeleteProfile",
+        request: "DeleteProfile",
+        response: "DeleteProfileResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetVideoSourceConfigurations",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetVideoSourceConfigurations",
+        request: "GetVideoSourceConfigurations",
+        response: "GetVideoSourceConfigurationsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetVideoEncoderConfigurations",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetVideoEncoderConfigurations",
+        request: "GetVideoEncoderConfigurations",
+        response: "GetVideoEncoderConfigurationsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetAudioSourceConfigurations",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdlGetAudioSourceConfigurations/",
+        request: "GetAudioSourceConfigurations",
+        response: "GetAudioSourceConfigurationsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetAudioEncoderConfigurations",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetAudioEncoderConfigurations",
+        request: "GetAudioEncoderConfigurations",
+        response: "GetAudioEncoderConfigurationsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetVideoAnalyticsConfigurations",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetVideoAnalyticsConfigurations",
+        request: "GetVideoAnalyticsConfigurations",
+        response: "GetVideoAnalyticsConfigurationsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetMetadataConfigurations",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetMetadataConfigurations",
+        request: "GetMetadataConfigurations",
+        response: "GetMetadataConfigurationsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetAudioOutputConfigurations",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetAudioOutputConfigurations",
+        request: "GetAudioOutputConfigurations",
+        response: "GetAudioOutputConfigurationsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetAudioDecoderConfigurations",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetAudioDecoderConfigurations",
+        request: "GetAudioDecoderConfigurations",
+        response: "GetAudioDecoderConfigurationsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetVideoSourceConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetVideoSourceConfiguration",
+        request: "GetVideoSourceConfiguration",
+        response: "GetVideoSourceConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetVideoEncoderConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetVideoEncoderConfiguration",
+        request: "GetVideoEncoderConfiguration",
+        response: "GetVideoEncoderConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetAudioSourceConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetAudioSourceConfiguration",
+        request: "GetAudioSourceConfiguration",
+        response: "GetAudioSourceConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetAudioEncoderConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetAudioEncoderConfiguration",
+        request: "GetAudioEncoderConfiguration",
+        response: "GetAudioEncoderConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetVideoAnalyticsConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetVideoAnalyticsConfiguration",
+        request: "GetVideoAnalyticsConfiguration",
+        response: "GetVideoAnalyticsConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetMetadataConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetMetadataConfiguration",
+        request: "GetMetadataConfiguration",
+        response: "GetMetadataConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetAudioOutputConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetAudioOutputConfiguration",
+        request: "GetAudioOutputConfiguration",
+        response: "GetAudioOutputConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetAudioDecoderConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetAudioDecoderConfiguration",
+        request: "GetAudioDecoderConfiguration",
+        response: "GetAudioDecoderConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetCompatibleVideoEncoderConfigurations",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetCompatibleVideoEncoderConfigurations",
+        request: "GetCompatibleVideoEncoderConfigurations",
+        response: "GetCompatibleVideoEncoderConfigurationsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetCompatibleVideoSourceConfigurations",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetCompatibleVideoSourceConfigurations",
+        request: "GetCompatibleVideoSourceConfigurations",
+        response: "GetCompatibleVideoSourceConfigurationsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetCompatibleAudioEncoderConfigurations",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetCompatibleAudioEncoderConfigurations",
+        request: "GetCompatibleAudioEncoderConfigurations",
+        response: "GetCompatibleAudioEncoderConfigurationsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetCompatibleAudioSourceConfigurations",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetCompatibleAudioSourceConfigurations",
+        request: "GetCompatibleAudioSourceConfigurations",
+        response: "GetCompatibleAudioSourceConfigurationsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetCompatibleVideoAnalyticsConfigurations",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetCompatibleVideoAnalyticsConfigurations",
+        request: "GetCompatibleVideoAnalyticsConfigurations",
+        response: "GetCompatibleVideoAnalyticsConfigurationsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetCompatibleMetadataConfigurations",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetCompatibleMetadataConfigurations",
+        request: "GetCompatibleMetadataConfigurations",
+        response: "GetCompatibleMetadataConfigurationsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetCompatibleAudioOutputConfigurations",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetCompatibleAudioOutputConfigurations",
+        request: "GetCompatibleAudioOutputConfigurations",
+        response: "GetCompatibleAudioOutputConfigurationsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetCompatibleAudioDecoderConfigurations",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetCompatibleAudioDecoderConfigurations",
+        request: "GetCompatibleAudioDecoderConfigurations",
+        response: "GetCompatibleAudioDecoderConfigurationsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "SetVideoSourceConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/SetVideoSourceConfiguration",
+        request: "SetVideoSourceConfiguration",
+        response: "SetVideoSourceConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "SetVideoEncoderConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/SetVideoEncoderConfiguration",
+        request: "SetVideoEncoderConfiguration",
+        response: "SetVideoEncoderConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "SetAudioSourceConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/SetAudioSourceConfiguration",
+        request: "SetAudioSourceConfiguration",
+        response: "SetAudioSourceConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "SetAudioEncoderConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/SetAudioEncoderConfiguration",
+        request: "SetAudioEncoderConfiguration",
+        response: "SetAudioEncoderConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "SetVideoAnalyticsConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/SetVideoAnalyticsConfiguration",
+        request: "SetVideoAnalyticsConfiguration",
+        response: "SetVideoAnalyticsConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "SetMetadataConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/SetMetadataConfiguration",
+        request: "SetMetadataConfiguration",
+        response: "SetMetadataConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "SetAudioOutputConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/SetAudioOutputConfiguration",
+        request: "SetAudioOutputConfiguration",
+        response: "SetAudioOutputConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "SetAudioDecoderConfiguration",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/SetAudioDecoderConfiguration",
+        request: "SetAudioDecoderConfiguration",
+        response: "SetAudioDecoderConfigurationResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetVideoSourceConfigurationOptions",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdlGetVideoSourceConfigurationOptions/",
+        request: "GetVideoSourceConfigurationOptions",
+        response: "GetVideoSourceConfigurationOptionsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetVideoEncoderConfigurationOptions",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetVideoEncoderConfigurationOptions",
+        request: "GetVideoEncoderConfigurationOptions",
+        response: "GetVideoEncoderConfigurationOptionsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetAudioSourceConfigurationOptions",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetAudioSourceConfigurationOptions",
+        request: "GetAudioSourceConfigurationOptions",
+        response: "GetAudioSourceConfigurationOptionsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetAudioEncoderConfigurationOptions",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetAudioEncoderConfigurationOptions",
+        request: "GetAudioEncoderConfigurationOptions",
+        response: "GetAudioEncoderConfigurationOptionsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetMetadataConfigurationOptions",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetMetadataConfigurationOptions",
+        request: "GetMetadataConfigurationOptions",
+        response: "GetMetadataConfigurationOptionsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetAudioOutputConfigurationOptions",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetAudioOutputConfigurationOptions",
+        request: "GetAudioOutputConfigurationOptions",
+        response: "GetAudioOutputConfigurationOptionsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetAudioDecoderConfigurationOptions",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetAudioDecoderConfigurationOptions",
+        request: "GetAudioDecoderConfigurationOptions",
+        response: "GetAudioDecoderConfigurationOptionsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetGuaranteedNumberOfVideoEncoderInstances",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetGuaranteedNumberOfVideoEncoderInstances",
+        request: "GetGuaranteedNumberOfVideoEncoderInstances",
+        response: "GetGuaranteedNumberOfVideoEncoderInstancesResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetStreamUri",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetStreamUri",
+        request: "GetStreamUri",
+        response: "GetStreamUriResponse",
+    },
+    Operation {
+        service: "media",
+        name: "StartMulticastStreaming",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/StartMulticastStreaming",
+        request: "StartMulticastStreaming",
+        response: "StartMulticastStreamingResponse",
+    },
+    Operation {
+        service: "media",
+        name: "StopMulticastStreaming",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/StopMulticastStreaming",
+        request: "StopMulticastStreaming",
+        response: "StopMulticastStreamingResponse",
+    },
+    Operation {
+        service: "media",
+        name: "SetSynchronizationPoint",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/SetSynchronizationPoint",
+        request: "SetSynchronizationPoint",
+        response: "SetSynchronizationPointResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetSnapshotUri",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetSnapshotUri",
+        request: "GetSnapshotUri",
+        response: "GetSnapshotUriResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetVideoSourceModes",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetVideoSourceModes",
+        request: "GetVideoSourceModes",
+        response: "GetVideoSourceModesResponse",
+    },
+    Operation {
+        service: "media",
+        name: "SetVideoSourceMode",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/SetVideoSourceMode",
+        request: "SetVideoSourceMode",
+        response: "SetVideoSourceModeResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetOSDs",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetOSDs",
+        request: "GetOSDs",
+        response: "GetOSDsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetOSD",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetOSD",
+        request: "GetOSD",
+        response: "GetOSDResponse",
+    },
+    Operation {
+        service: "media",
+        name: "GetOSDOptions",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/GetOSDOptions",
+        request: "GetOSDOptions",
+        response: "GetOSDOptionsResponse",
+    },
+    Operation {
+        service: "media",
+        name: "SetOSD",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/SetOSD",
+        request: "SetOSD",
+        response: "SetOSDResponse",
+    },
+    Operation {
+        service: "media",
+        name: "CreateOSD",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/CreateOSD",
+        request: "CreateOSD",
+        response: "CreateOSDResponse",
+    },
+    Operation {
+        service: "media",
+        name: "DeleteOSD",
+        namespace: "http://www.onvif.org/ver10/media/wsdl",
+        action: "http://www.onvif.org/ver10/media/wsdl/DeleteOSD",
+        request: "DeleteOSD",
+        response: "DeleteOSDResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "GetServiceCapabilities",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/GetServiceCapabilities",
+        request: "GetServiceCapabilities",
+        response: "GetServiceCapabilitiesResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "CreateProfile",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/CreateProfile",
+        request: "CreateProfile",
+        response: "CreateProfileResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "GetProfiles",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/GetProfiles",
+        request: "GetProfiles",
+        response: "GetProfilesResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "AddConfiguration",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/AddConfiguration",
+        request: "AddConfiguration",
+        response: "AddConfigurationResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "RemoveConfiguration",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/RemoveConfiguration",
+        request: "RemoveConfiguration",
+        response: "RemoveConfigurationResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "DeleteProfile",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/DeleteProfile",
+        request: "DeleteProfile",
+        response: "DeleteProfileResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "GetVideoSourceConfigurations",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/GetVideoSourceConfigurations",
+        request: "GetVideoSourceConfigurations",
+        response: "GetVideoSourceConfigurationsResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "GetVideoEncoderConfigurations",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/GetVideoEncoderConfigurations",
+        request: "GetVideoEncoderConfigurations",
+        response: "GetVideoEncoderConfigurationsResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "GetAudioSourceConfigurations",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/GetAudioSourceConfigurations/",
+        request: "GetAudioSourceConfigurations",
+        response: "GetAudioSourceConfigurationsResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "GetAudioEncoderConfigurations",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/GetAudioEncoderConfigurations",
+        request: "GetAudioEncoderConfigurations",
+        response: "GetAudioEncoderConfigurationsResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "GetAnalyticsConfigurations",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/GetAnalyticsConfigurations",
+        request: "GetAnalyticsConfigurations",
+        response: "GetAnalyticsConfigurationsResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "GetMetadataConfigurations",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/GetMetadataConfigurations",
+        request: "GetMetadataConfigurations",
+        response: "GetMetadataConfigurationsResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "GetAudioOutputConfigurations",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/GetAudioOutputConfigurations",
+        request: "GetAudioOutputConfigurations",
+        response: "GetAudioOutputConfigurationsResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "GetAudioDecoderConfigurations",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/GetAudioDecoderConfigurations",
+        request: "GetAudioDecoderConfigurations",
+        response: "GetAudioDecoderConfigurationsResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "SetVideoSourceConfiguration",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/SetVideoSourceConfiguration",
+        request: "SetVideoSourceConfiguration",
+        response: "SetVideoSourceConfigurationResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "SetVideoEncoderConfiguration",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/SetVideoEncoderConfiguration",
+        request: "SetVideoEncoderConfiguration",
+        response: "SetVideoEncoderConfigurationResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "SetAudioSourceConfiguration",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/SetAudioSourceConfiguration",
+        request: "SetAudioSourceConfiguration",
+        response: "SetAudioSourceConfigurationResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "SetAudioEncoderConfiguration",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/SetAudioEncoderConfiguration",
+        request: "SetAudioEncoderConfiguration",
+        response: "SetAudioEncoderConfigurationResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "SetMetadataConfiguration",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/SetMetadataConfiguration",
+        request: "SetMetadataConfiguration",
+        response: "SetMetadataConfigurationResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "SetAudioOutputConfiguration",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/SetAudioOutputConfiguration",
+        request: "SetAudioOutputConfiguration",
+        response: "SetAudioOutputConfigurationResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "SetAudioDecoderConfiguration",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/SetAudioDecoderConfiguration",
+        request: "SetAudioDecoderConfiguration",
+        response: "SetAudioDecoderConfigurationResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "GetVideoSourceConfigurationOptions",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/GetVideoSourceConfigurationOptions/",
+        request: "GetVideoSourceConfigurationOptions",
+        response: "GetVideoSourceConfigurationOptionsResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "GetVideoEncoderConfigurationOptions",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/GetVideoEncoderConfigurationOptions",
+        request: "GetVideoEncoderConfigurationOptions",
+        response: "GetVideoEncoderConfigurationOptionsResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "GetAudioSourceConfigurationOptions",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/GetAudioSourceConfigurationOptions",
+        request: "GetAudioSourceConfigurationOptions",
+        response: "GetAudioSourceConfigurationOptionsResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "GetAudioEncoderConfigurationOptions",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/GetAudioEncoderConfigurationOptions",
+        request: "GetAudioEncoderConfigurationOptions",
+        response: "GetAudioEncoderConfigurationOptionsResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "GetMetadataConfigurationOptions",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/GetMetadataConfigurationOptions",
+        request: "GetMetadataConfigurationOptions",
+        response: "GetMetadataConfigurationOptionsResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "GetAudioOutputConfigurationOptions",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/GetAudioOutputConfigurationOptions",
+        request: "GetAudioOutputConfigurationOptions",
+        response: "GetAudioOutputConfigurationOptionsResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "GetAudioDecoderConfigurationOptions",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/GetAudioDecoderConfigurationOptions",
+        request: "GetAudioDecoderConfigurationOptions",
+        response: "GetAudioDecoderConfigurationOptionsResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "GetVideoEncoderInstances",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/GetVideoEncoderInstances",
+        request: "GetVideoEncoderInstances",
+        response: "GetVideoEncoderInstancesResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "GetStreamUri",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/GetStreamUri",
+        request: "GetStreamUri",
+        response: "GetStreamUriResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "StartMulticastStreaming",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/StartMulticastStreaming",
+        request: "StartMulticastStreaming",
+        response: "StartMulticastStreamingResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "StopMulticastStreaming",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/StopMulticastStreaming",
+        request: "StopMulticastStreaming",
+        response: "StopMulticastStreamingResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "SetSynchronizationPoint",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/SetSynchronizationPoint",
+        request: "SetSynchronizationPoint",
+        response: "SetSynchronizationPointResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "GetSnapshotUri",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/GetSnapshotUri",
+        request: "GetSnapshotUri",
+        response: "GetSnapshotUriResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "GetVideoSourceModes",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/GetVideoSourceModes",
+        request: "GetVideoSourceModes",
+        response: "GetVideoSourceModesResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "SetVideoSourceMode",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/SetVideoSourceMode",
+        request: "SetVideoSourceMode",
+        response: "SetVideoSourceModeResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "GetOSDs",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/GetOSDs",
+        request: "GetOSDs",
+        response: "GetOSDsResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "GetOSDOptions",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/GetOSDOptions",
+        request: "GetOSDOptions",
+        response: "GetOSDOptionsResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "SetOSD",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/SetOSD",
+        request: "SetOSD",
+        response: "SetOSDResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "CreateOSD",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/CreateOSD",
+        request: "CreateOSD",
+        response: "CreateOSDResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "DeleteOSD",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/DeleteOSD",
+        request: "DeleteOSD",
+        response: "DeleteOSDResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "GetMasks",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/GetMasks",
+        request: "GetMasks",
+        response: "GetMasksResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "GetMaskOptions",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/GetMaskOptions",
+        request: "GetMaskOptions",
+        response: "GetMaskOptionsResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "SetMask",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/SetMask",
+        request: "SetMask",
+        response: "SetMaskResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "CreateMask",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/CreateMask",
+        request: "CreateMask",
+        response: "CreateMaskResponse",
+    },
+    Operation {
+        service: "media2",
+        name: "DeleteMask",
+        namespace: "http://www.onvif.org/ver20/media/wsdl",
+        action: "http://www.onvif.org/ver20/media/wsdl/DeleteMask",
+        request: "DeleteMask",
+        response: "DeleteMaskResponse",
+    },
+    Operation {
+        service: "provisioning",
+        name: "GetServiceCapabilities",
+        namespace: "http://www.onvif.org/ver10/provisioning/wsdl",
+        action: "http://www.onvif.org/ver10/provisioning/wsdl/GetServiceCapabilities",
+        request: "GetServiceCapabilities",
+        response: "GetServiceCapabilitiesResponse",
+    },
+    Operation {
+        service: "provisioning",
+        name: "PanMove",
+        namespace: "http://www.onvif.org/ver10/provisioning/wsdl",
+        action: "http://www.onvif.org/ver10/provisioning/wsdl/PanMove",
+        request: "PanMove",
+        response: "PanMoveResponse",
+    },
+    Operation {
+        service: "provisioning",
+        name: "TiltMove",
+        namespace: "http://www.onvif.org/ver10/provisioning/wsdl",
+        action: "http://www.onvif.org/ver10/provisioning/wsdl/TiltMove",
+        request: "TiltMove",
+        response: "TiltMoveResponse",
+    },
+    Operation {
+        service: "provisioning",
+        name: "ZoomMove",
+        namespace: "http://www.onvif.org/ver10/provisioning/wsdl",
+        action: "http://www.onvif.org/ver10/provisioning/wsdl/ZoomMove",
+        request: "ZoomMove",
+        response: "ZoomMoveResponse",
+    },
+    Operation {
+        service: "provisioning",
+        name: "RollMove",
+        namespace: "http://www.onvif.org/ver10/provisioning/wsdl",
+        action: "http://www.onvif.org/ver10/provisioning/wsdl/RollMove",
+        request: "RollMove",
+        response: "RollMoveResponse",
+    },
+    Operation {
+        service: "provisioning",
+        name: "FocusMove",
+        namespace: "http://www.onvif.org/ver10/provisioning/wsdl",
+        action: "http://www.onvif.org/ver10/provisioning/wsdl/FocusMove",
+        request: "FocusMove",
+        response: "FocusMoveResponse",
+    },
+    Operation {
+        service: "provisioning",
+        name: "Stop",
+        namespace: "http://www.onvif.org/ver10/provisioning/wsdl",
+        action: "http://www.onvif.org/ver10/provisioning/wsdl/Stop",
+        request: "Stop",
+        response: "StopResponse",
+    },
+    Operation {
+        service: "provisioning",
+        name: "GetUsage",
+        namespace: "http://www.onvif.org/ver10/provisioning/wsdl",
+        action: "http://www.onvif.org/ver10/provisioning/wsdl/Usage",
+        request: "GetUsage",
+        response: "GetUsageResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "GetServiceCapabilities",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/GetServiceCapabilities",
+        request: "GetServiceCapabilities",
+        response: "GetServiceCapabilitiesResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "GetNodes",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/GetNodes",
+        request: "GetNodes",
+        response: "GetNodesResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "GetNode",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/GetNode",
+        request: "GetNode",
+        response: "GetNodeResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "GetConfiguration",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/GetConfiguration",
+        request: "GetConfiguration",
+        response: "GetConfigurationResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "GetConfigurations",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/GetConfigurations",
+        request: "GetConfigurations",
+        response: "GetConfigurationsResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "SetConfiguration",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/SetConfiguration",
+        request: "SetConfiguration",
+        response: "SetConfigurationResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "GetConfigurationOptions",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/GetConfigurationOptions",
+        request: "GetConfigurationOptions",
+        response: "GetConfigurationOptionsResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "SendAuxiliaryCommand",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/SendAuxiliaryCommand",
+        request: "SendAuxiliaryCommand",
+        response: "SendAuxiliaryCommandResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "GetPresets",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/GetPresets",
+        request: "GetPresets",
+        response: "GetPresetsResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "SetPreset",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/SetPreset",
+        request: "SetPreset",
+        response: "SetPresetResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "RemovePreset",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/RemovePreset",
+        request: "RemovePreset",
+        response: "RemovePresetResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "GotoPreset",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/GotoPreset",
+        request: "GotoPreset",
+        response: "GotoPresetResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "GotoHomePosition",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/GotoHomePosition",
+        request: "GotoHomePosition",
+        response: "GotoHomePositionResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "SetHomePosition",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/SetHomePosition",
+        request: "SetHomePosition",
+        response: "SetHomePositionResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "ContinuousMove",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/ContinuousMove",
+        request: "ContinuousMove",
+        response: "ContinuousMoveResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "RelativeMove",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/RelativeMove",
+        request: "RelativeMove",
+        response: "RelativeMoveResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "GetStatus",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/GetStatus",
+        request: "GetStatus",
+        response: "GetStatusResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "AbsoluteMove",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/AbsoluteMove",
+        request: "AbsoluteMove",
+        response: "AbsoluteMoveResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "GeoMove",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/GeoMove",
+        request: "GeoMove",
+        response: "GeoMoveResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "Stop",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/Stop",
+        request: "Stop",
+        response: "StopResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "GetPresetTours",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/GetPresetTours",
+        request: "GetPresetTours",
+        response: "GetPresetToursResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "GetPresetTour",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/GetPresetTour",
+        request: "GetPresetTour",
+        response: "GetPresetTourResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "GetPresetTourOptions",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/GetPresetTourOptions",
+        request: "GetPresetTourOptions",
+        response: "GetPresetTourOptionsResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "CreatePresetTour",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/CreatePresetTour",
+        request: "CreatePresetTour",
+        response: "CreatePresetTourResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "ModifyPresetTour",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/ModifyPresetTour",
+        request: "ModifyPresetTour",
+        response: "ModifyPresetTourResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "OperatePresetTour",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/OperatePresetTour",
+        request: "OperatePresetTour",
+        response: "OperatePresetTourResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "RemovePresetTour",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/RemovePresetTour",
+        request: "RemovePresetTour",
+        response: "RemovePresetTourResponse",
+    },
+    Operation {
+        service: "ptz",
+        name: "GetCompatibleConfigurations",
+        namespace: "http://www.onvif.org/ver20/ptz/wsdl",
+        action: "http://www.onvif.org/ver20/ptz/wsdl/GetCompatibleConfigurations",
+        request: "GetCompatibleConfigurations",
+        response: "GetCompatibleConfigurationsResponse",
+    },
+    Operation {
+        service: "receiver",
+        name: "GetServiceCapabilities",
+        namespace: "http://www.onvif.org/ver10/receiver/wsdl",
+        action: "http://www.onvif.org/ver10/receiver/wsdl/GetServiceCapabilities",
+        request: "GetServiceCapabilities",
+        response: "GetServiceCapabilitiesResponse",
+    },
+    Operation {
+        service: "receiver",
+        name: "GetReceivers",
+        namespace: "http://www.onvif.org/ver10/receiver/wsdl",
+        action: "http://www.onvif.org/ver10/receiver/wsdl/GetReceivers",
+        request: "GetReceivers",
+        response: "GetReceiversResponse",
+    },
+    Operation {
+        service: "receiver",
+        name: "GetReceiver",
+        namespace: "http://www.onvif.org/ver10/receiver/wsdl",
+        action: "http://www.onvif.org/ver10/receiver/wsdl/GetReceiver",
+        request: "GetReceiver",
+        response: "GetReceiverResponse",
+    },
+    Operation {
+        service: "receiver",
+        name: "CreateReceiver",
+        namespace: "http://www.onvif.org/ver10/receiver/wsdl",
+        action: "http://www.onvif.org/ver10/receiver/wsdl/CreateReceiver",
+        request: "CreateReceiver",
+        response: "CreateReceiverResponse",
+    },
+    Operation {
+        service: "receiver",
+        name: "DeleteReceiver",
+        namespace: "http://www.onvif.org/ver10/receiver/wsdl",
+        action: "http://www.onvif.org/ver10/receiver/wsdl/DeleteReceiver",
+        request: "DeleteReceiver",
+        response: "DeleteReceiverResponse",
+    },
+    Operation {
+        service: "receiver",
+        name: "ConfigureReceiver",
+        namespace: "http://www.onvif.org/ver10/receiver/wsdl",
+        action: "http://www.onvif.org/ver10/receiver/wsdl/ConfigureReceiver",
+        request: "ConfigureReceiver",
+        response: "ConfigureReceiverResponse",
+    },
+    Operation {
+        service: "receiver",
+        name: "SetReceiverMode",
+        namespace: "http://www.onvif.org/ver10/receiver/wsdl",
+        action: "http://www.onvif.org/ver10/receiver/wsdl/SetReceiverMode",
+        request: "SetReceiverMode",
+        response: "SetReceiverModeResponse",
+    },
+    Operation {
+        service: "receiver",
+        name: "GetReceiverState",
+        namespace: "http://www.onvif.org/ver10/receiver/wsdl",
+        action: "http://www.onvif.org/ver10/receiver/wsdl/GetReceiverState",
+        request: "GetReceiverState",
+        response: "GetReceiverStateResponse",
+    },
+    Operation {
+        service: "recording",
+        name: "GetServiceCapabilities",
+        namespace: "http://www.onvif.org/ver10/recording/wsdl",
+        action: "http://www.onvif.org/ver10/recording/wsdl/GetServiceCapabilities",
+        request: "GetServiceCapabilities",
+        response: "GetServiceCapabilitiesResponse",
+    },
+    Operation {
+        service: "recording",
+        name: "CreateRecording",
+        namespace: "http://www.onvif.org/ver10/recording/wsdl",
+        action: "http://www.onvif.org/ver10/recording/wsdl/CreateRecording",
+        request: "CreateRecording",
+        response: "CreateRecordingResponse",
+    },
+    Operation {
+        service: "recording",
+        name: "DeleteRecording",
+        namespace: "http://www.onvif.org/ver10/recording/wsdl",
+        action: "http://www.onvif.org/ver10/recording/wsdl/DeleteRecording",
+        request: "DeleteRecording",
+        response: "DeleteRecordingResponse",
+    },
+    Operation {
+        service: "recording",
+        name: "GetRecordings",
+        namespace: "http://www.onvif.org/ver10/recording/wsdl",
+        action: "http://www.onvif.org/ver10/recording/wsdl/GetRecordings",
+        request: "GetRecordings",
+        response: "GetRecordingsResponse",
+    },
+    Operation {
+        service: "recording",
+        name: "SetRecordingConfiguration",
+        namespace: "http://www.onvif.org/ver10/recording/wsdl",
+        action: "http://www.onvif.org/ver10/recording/wsdl/SetRecordingConfiguration",
+        request: "SetRecordingConfiguration",
+        response: "SetRecordingConfigurationResponse",
+    },
+    Operation {
+        service: "recording",
+        name: "GetRecordingConfiguration",
+        namespace: "http://www.onvif.org/ver10/recording/wsdl",
+        action: "http://www.onvif.org/ver10/recording/wsdl/GetRecordingConfiguration",
+        request: "GetRecordingConfiguration",
+        response: "GetRecordingConfigurationResponse",
+    },
+    Operation {
+        service: "recording",
+        name: "GetRecordingOptions",
+        namespace: "http://www.onvif.org/ver10/recording/wsdl",
+        action: "http://www.onvif.org/ver10/recording/wsdl/GetRecordingOptions",
+        request: "GetRecordingOptions",
+        response: "GetRecordingOptionsResponse",
+    },
+    Operation {
+        service: "recording",
+        name: "CreateTrack",
+        namespace: "http://www.onvif.org/ver10/recording/wsdl",
+        action: "http://www.onvif.org/ver10/recording/wsdl/CreateTrack",
+        request: "CreateTrack",
+        response: "CreateTrackResponse",
+    },
+    Operation {
+        service: "recording",
+        name: "DeleteTrack",
+        namespace: "http://www.onvif.org/ver10/recording/wsdl",
+        action: "http://www.onvif.org/ver10/recording/wsdl/DeleteTrack",
+        request: "DeleteTrack",
+        response: "DeleteTrackResponse",
+    },
+    Operation {
+        service: "recording",
+        name: "GetTrackConfiguration",
+        namespace: "http://www.onvif.org/ver10/recording/wsdl",
+        action: "http://www.onvif.org/ver10/recording/wsdl/GetTrackConfiguration",
+        request: "GetTrackConfiguration",
+        response: "GetTrackConfigurationResponse",
+    },
+    Operation {
+        service: "recording",
+        name: "SetTrackConfiguration",
+        namespace: "http://www.onvif.org/ver10/recording/wsdl",
+        action: "http://www.onvif.org/ver10/recording/wsdl/SetTrackConfiguration",
+        request: "SetTrackConfiguration",
+        response: "SetTrackConfigurationResponse",
+    },
+    Operation {
+        service: "recording",
+        name: "CreateRecordingJob",
+        namespace: "http://www.onvif.org/ver10/recording/wsdl",
+        action: "http://www.onvif.org/ver10/recording/wsdl/CreateRecordingJob",
+        request: "CreateRecordingJob",
+        response: "CreateRecordingJobResponse",
+    },
+    Operation {
+        service: "recording",
+        name: "DeleteRecordingJob",
+        namespace: "http://www.onvif.org/ver10/recording/wsdl",
+        action: "http://www.onvif.org/ver10/recording/wsdl/DeleteRecordingJob",
+        request: "DeleteRecordingJob",
+        response: "DeleteRecordingJobResponse",
+    },
+    Operation {
+        service: "recording",
+        name: "GetRecordingJobs",
+        namespace: "http://www.onvif.org/ver10/recording/wsdl",
+        action: "http://www.onvif.org/ver10/recording/wsdl/GetRecordingJobs",
+        request: "GetRecordingJobs",
+        response: "GetRecordingJobsResponse",
+    },
+    Operation {
+        service: "recording",
+        name: "SetRecordingJobConfiguration",
+        namespace: "http://www.onvif.org/ver10/recording/wsdl",
+        action: "http://www.onvif.org/ver10/recording/wsdl/SetRecordingJobConfiguration",
+        request: "SetRecordingJobConfiguration",
+        response: "SetRecordingJobConfigurationResponse",
+    },
+    Operation {
+        service: "recording",
+        name: "GetRecordingJobConfiguration",
+        namespace: "http://www.onvif.org/ver10/recording/wsdl",
+        action: "http://www.onvif.org/ver10/recording/wsdl/GetRecordingJobConfiguration",
+        request: "GetRecordingJobConfiguration",
+        response: "GetRecordingJobConfigurationResponse",
+    },
+    Operation {
+        service: "recording",
+        name: "SetRecordingJobMode",
+        namespace: "http://www.onvif.org/ver10/recording/wsdl",
+        action: "http://www.onvif.org/ver10/recording/wsdl/SetRecordingJobMode",
+        request: "SetRecordingJobMode",
+        response: "SetRecordingJobModeResponse",
+    },
+    Operation {
+        service: "recording",
+        name: "GetRecordingJobState",
+        namespace: "http://www.onvif.org/ver10/recording/wsdl",
+        action: "http://www.onvif.org/ver10/recording/wsdl/GetRecordingJobState",
+        request: "GetRecordingJobState",
+        response: "GetRecordingJobStateResponse",
+    },
+    Operation {
+        service: "recording",
+        name: "ExportRecordedData",
+        namespace: "http://www.onvif.org/ver10/recording/wsdl",
+        action: "http://www.onvif.org/ver10/recording/wsdl/ExportRecordedData",
+        request: "ExportRecordedData",
+        response: "ExportRecordedDataResponse",
+    },
+    Operation {
+        service: "recording",
+        name: "StopExportRecordedData",
+        namespace: "http://www.onvif.org/ver10/recording/wsdl",
+        action: "http://www.onvif.org/ver10/recording/wsdl/StopExportRecordedData",
+        request: "StopExportRecordedData",
+        response: "StopExportRecordedDataResponse",
+    },
+    Operation {
+        service: "recording",
+        name: "GetExportRecordedDataState",
+        namespace: "http://www.onvif.org/ver10/recording/wsdl",
+        action: "http://www.onvif.org/ver10/recording/wsdl/GetExportRecordedDataState",
+        request: "GetExportRecordedDataState",
+        response: "GetExportRecordedDataStateResponse",
+    },
+    Operation {
+        service: "replay",
+        name: "GetServiceCapabilities",
+        namespace: "http://www.onvif.org/ver10/replay/wsdl",
+        action: "http://www.onvif.org/ver10/replay/wsdl/GetServiceCapabilities",
+        request: "GetServiceCapabilities",
+        response: "GetServiceCapabilitiesResponse",
+    },
+    Operation {
+        service: "replay",
+        name: "GetReplayUri",
+        namespace: "http://www.onvif.org/ver10/replay/wsdl",
+        action: "http://www.onvif.org/ver10/replay/wsdl/GetReplayUri",
+        request: "GetReplayUri",
+        response: "GetReplayUriResponse",
+    },
+    Operation {
+        service: "replay",
+        name: "GetReplayConfiguration",
+        namespace: "http://www.onvif.org/ver10/replay/wsdl",
+        action: "http://www.onvif.org/ver10/replay/wsdl/GetReplayConfiguration",
+        request: "GetReplayConfiguration",
+        response: "GetReplayConfigurationResponse",
+    },
+    Operation {
+        service: "replay",
+        name: "SetReplayConfiguration",
+        namespace: "http://www.onvif.org/ver10/replay/wsdl",
+        action: "http://www.onvif.org/ver10/replay/wsdl/SetReplayConfiguration",
+        request: "SetReplayConfiguration",
+        response: "SetReplayConfigurationResponse",
+    },
+    Operation {
+        service: "schedule",
+        name: "GetServiceCapabilities",
+        namespace: "http://www.onvif.org/ver10/schedule/wsdl",
+        action: "http://www.onvif.org/ver10/schedule/wsdl/GetServiceCapabilities",
+        request: "GetServiceCapabilities",
+        response: "GetServiceCapabilitiesResponse",
+    },
+    Operation {
+        service: "schedule",
+        name: "GetScheduleState",
+        namespace: "http://www.onvif.org/ver10/schedule/wsdl",
+        action: "http://www.onvif.org/ver10/schedule/wsdl/GetScheduleState",
+        request: "GetScheduleState",
+        response: "GetScheduleStateResponse",
+    },
+    Operation {
+        service: "schedule",
+        name: "GetScheduleInfo",
+        namespace: "http://www.onvif.org/ver10/schedule/wsdl",
+        action: "http://www.onvif.org/ver10/schedule/wsdl/GetScheduleInfo",
+        request: "GetScheduleInfo",
+        response: "GetScheduleInfoResponse",
+    },
+    Operation {
+        service: "schedule",
+        name: "GetScheduleInfoList",
+        namespace: "http://www.onvif.org/ver10/schedule/wsdl",
+        action: "http://www.onvif.org/ver10/schedule/wsdl/GetScheduleInfoList",
+        request: "GetScheduleInfoList",
+        response: "GetScheduleInfoListResponse",
+    },
+    Operation {
+        service: "schedule",
+        name: "GetSchedules",
+        namespace: "http://www.onvif.org/ver10/schedule/wsdl",
+        action: "http://www.onvif.org/ver10/schedule/wsdl/GetSchedules",
+        request: "GetSchedules",
+        response: "GetSchedulesResponse",
+    },
+    Operation {
+        service: "schedule",
+        name: "GetScheduleList",
+        namespace: "http://www.onvif.org/ver10/schedule/wsdl",
+        action: "http://www.onvif.org/ver10/schedule/wsdl/GetScheduleList",
+        request: "GetScheduleList",
+        response: "GetScheduleListResponse",
+    },
+    Operation {
+        service: "schedule",
+        name: "CreateSchedule",
+        namespace: "http://www.onvif.org/ver10/schedule/wsdl",
+        action: "http://www.onvif.org/ver10/schedule/wsdl/CreateSchedule",
+        request: "CreateSchedule",
+        response: "CreateScheduleResponse",
+    },
+    Operation {
+        service: "schedule",
+        name: "SetSchedule",
+        namespace: "http://www.onvif.org/ver10/schedule/wsdl",
+        action: "http://www.onvif.org/ver10/schedule/wsdl/SetSchedule",
+        request: "SetSchedule",
+        response: "SetScheduleResponse",
+    },
+    Operation {
+        service: "schedule",
+        name: "ModifySchedule",
+        namespace: "http://www.onvif.org/ver10/schedule/wsdl",
+        action: "http://www.onvif.org/ver10/schedule/wsdl/ModifySchedule",
+        request: "ModifySchedule",
+        response: "ModifyScheduleResponse",
+    },
+    Operation {
+        service: "schedule",
+        name: "DeleteSchedule",
+        namespace: "http://www.onvif.org/ver10/schedule/wsdl",
+        action: "http://www.onvif.org/ver10/schedule/wsdl/DeleteSchedule",
+        request: "DeleteSchedule",
+        response: "DeleteScheduleResponse",
+    },
+    Operation {
+        service: "schedule",
+        name: "GetSpecialDayGroupInfo",
+        namespace: "http://www.onvif.org/ver10/schedule/wsdl",
+        action: "http://www.onvif.org/ver10/schedule/wsdl/GetSpecialDayGroupInfo",
+        request: "GetSpecialDayGroupInfo",
+        response: "GetSpecialDayGroupInfoResponse",
+    },
+    Operation {
+        service: "schedule",
+        name: "GetSpecialDayGroupInfoList",
+        namespace: "http://www.onvif.org/ver10/schedule/wsdl",
+        action: "http://www.onvif.org/ver10/schedule/wsdl/GetSpecialDayGroupInfoList",
+        request: "GetSpecialDayGroupInfoList",
+        response: "GetSpecialDayGroupInfoListResponse",
+    },
+    Operation {
+        service: "schedule",
+        name: "GetSpecialDayGroups",
+        namespace: "http://www.onvif.org/ver10/schedule/wsdl",
+        action: "http://www.onvif.org/ver10/schedule/wsdl/GetSpecialDayGroups",
+        request: "GetSpecialDayGroups",
+        response: "GetSpecialDayGroupsResponse",
+    },
+    Operation {
+        service: "schedule",
+        name: "GetSpecialDayGroupList",
+        namespace: "http://www.onvif.org/ver10/schedule/wsdl",
+        action: "http://www.onvif.org/ver10/schedule/wsdl/GetSpecialDayGroupList",
+        request: "GetSpecialDayGroupList",
+        response: "GetSpecialDayGroupListResponse",
+    },
+    Operation {
+        service: "schedule",
+        name: "CreateSpecialDayGroup",
+        namespace: "http://www.onvif.org/ver10/schedule/wsdl",
+        action: "http://www.onvif.org/ver10/schedule/wsdl/CreateSpecialDayGroup",
+        request: "CreateSpecialDayGroup",
+        response: "CreateSpecialDayGroupResponse",
+    },
+    Operation {
+        service: "schedule",
+        name: "SetSpecialDayGroup",
+        namespace: "http://www.onvif.org/ver10/schedule/wsdl",
+        action: "http://www.onvif.org/ver10/schedule/wsdl/SetSpecialDayGroup",
+        request: "SetSpecialDayGroup",
+        response: "SetSpecialDayGroupResponse",
+    },
+    Operation {
+        service: "schedule",
+        name: "ModifySpecialDayGroup",
+        namespace: "http://www.onvif.org/ver10/schedule/wsdl",
+        action: "http://www.onvif.org/ver10/schedule/wsdl/ModifySpecialDayGroup",
+        request: "ModifySpecialDayGroup",
+        response: "ModifySpecialDayGroupResponse",
+    },
+    Operation {
+        service: "schedule",
+        name: "DeleteSpecialDayGroup",
+        namespace: "http://www.onvif.org/ver10/schedule/wsdl",
+        action: "http://www.onvif.org/ver10/schedule/wsdl/DeleteSpecialDayGroup",
+        request: "DeleteSpecialDayGroup",
+        response: "DeleteSpecialDayGroupResponse",
+    },
+    Operation {
+        service: "search",
+        name: "GetServiceCapabilities",
+        namespace: "http://www.onvif.org/ver10/search/wsdl",
+        action: "http://www.onvif.org/ver10/search/wsdl/GetServiceCapabilities",
+        request: "GetServiceCapabilities",
+        response: "GetServiceCapabilitiesResponse",
+    },
+    Operation {
+        service: "search",
+        name: "GetRecordingSummary",
+        namespace: "http://www.onvif.org/ver10/search/wsdl",
+        action: "http://www.onvif.org/ver10/search/wsdl/GetRecordingSummary",
+        request: "GetRecordingSummary",
+        response: "GetRecordingSummaryResponse",
+    },
+    Operation {
+        service: "search",
+        name: "GetRecordingInformation",
+        namespace: "http://www.onvif.org/ver10/search/wsdl",
+        action: "http://www.onvif.org/ver10/search/wsdl/GetRecordingInformation",
+        request: "GetRecordingInformation",
+        response: "GetRecordingInformationResponse",
+    },
+    Operation {
+        service: "search",
+        name: "GetMediaAttributes",
+        namespace: "http://www.onvif.org/ver10/search/wsdl",
+        action: "http://www.onvif.org/ver10/search/wsdl/GetMediaAttributes",
+        request: "GetMediaAttributes",
+        response: "GetMediaAttributesResponse",
+    },
+    Operation {
+        service: "search",
+        name: "FindRecordings",
+        namespace: "http://www.onvif.org/ver10/search/wsdl",
+        action: "http://www.onvif.org/ver10/search/wsdl/FindRecordings",
+        request: "FindRecordings",
+        response: "FindRecordingsResponse",
+    },
+    Operation {
+        service: "search",
+        name: "GetRecordingSearchResults",
+        namespace: "http://www.onvif.org/ver10/search/wsdl",
+        action: "http://www.onvif.org/ver10/search/wsdl/GetRecordingSearchResults",
+        request: "GetRecordingSearchResults",
+        response: "GetRecordingSearchResultsResponse",
+    },
+    Operation {
+        service: "search",
+        name: "FindEvents",
+        namespace: "http://www.onvif.org/ver10/search/wsdl",
+        action: "http://www.onvif.org/ver10/search/wsdl/FindEvents",
+        request: "FindEvents",
+        response: "FindEventsResponse",
+    },
+    Operation {
+        service: "search",
+        name: "GetEventSearchResults",
+        namespace: "http://www.onvif.org/ver10/search/wsdl",
+        action: "http://www.onvif.org/ver10/search/wsdl/GetEventSearchResults",
+        request: "GetEventSearchResults",
+        response: "GetEventSearchResultsResponse",
+    },
+    Operation {
+        service: "search",
+        name: "FindPTZPosition",
+        namespace: "http://www.onvif.org/ver10/search/wsdl",
+        action: "http://www.onvif.org/ver10/search/wsdl/FindPTZPosition",
+        request: "FindPTZPosition",
+        response: "FindPTZPositionResponse",
+    },
+    Operation {
+        service: "search",
+        name: "GetPTZPositionSearchResults",
+        namespace: "http://www.onvif.org/ver10/search/wsdl",
+        action: "http://www.onvif.org/ver10/search/wsdl/GetPTZPositionSearchResults",
+        request: "GetPTZPositionSearchResults",
+        response: "GetPTZPositionSearchResultsResponse",
+    },
+    Operation {
+        service: "search",
+        name: "GetSearchState",
+        namespace: "http://www.onvif.org/ver10/search/wsdl",
+        action: "http://www.onvif.org/ver10/search/wsdl/GetSearchState",
+        request: "GetSearchState",
+        response: "GetSearchStateResponse",
+    },
+    Operation {
+        service: "search",
+        name: "EndSearch",
+        namespace: "http://www.onvif.org/ver10/search/wsdl",
+        action: "http://www.onvif.org/ver10/search/wsdl/EndSearch",
+        request: "EndSearch",
+        response: "EndSearchResponse",
+    },
+    Operation {
+        service: "search",
+        name: "FindMetadata",
+        namespace: "http://www.onvif.org/ver10/search/wsdl",
+        action: "http://www.onvif.org/ver10/search/wsdl/FindMetadata",
+        request: "FindMetadata",
+        response: "FindMetadataResponse",
+    },
+    Operation {
+        service: "search",
+        name: "GetMetadataSearchResults",
+        namespace: "http://www.onvif.org/ver10/search/wsdl",
+        action: "http://www.onvif.org/ver10/search/wsdl/GetMetadataSearchResults",
+        request: "GetMetadataSearchResults",
+        response: "GetMetadataSearchResultsResponse",
+    },
+    Operation {
+        service: "thermal",
+        name: "GetServiceCapabilities",
+        namespace: "http://www.onvif.org/ver10/thermal/wsdl",
+        action: "http://www.onvif.org/ver10/thermal/wsdl/GetServiceCapabilities",
+        request: "GetServiceCapabilities",
+        response: "GetServiceCapabilitiesResponse",
+    },
+    Operation {
+        service: "thermal",
+        name: "GetConfigurationOptions",
+        namespace: "http://www.onvif.org/ver10/thermal/wsdl",
+        action: "http://www.onvif.org/ver10/thermal/wsdl/GetConfigurationOptions",
+        request: "GetConfigurationOptions",
+        response: "GetConfigurationOptionsResponse",
+    },
+    Operation {
+        service: "thermal",
+        name: "GetConfiguration",
+        namespace: "http://www.onvif.org/ver10/thermal/wsdl",
+        action: "http://www.onvif.org/ver10/thermal/wsdl/GetConfiguration",
+        request: "GetConfiguration",
+        response: "GetConfigurationResponse",
+    },
+    Operation {
+        service: "thermal",
+        name: "GetConfigurations",
+        namespace: "http://www.onvif.org/ver10/thermal/wsdl",
+        action: "http://www.onvif.org/ver10/thermal/wsdl/GetConfigurations",
+        request: "GetConfigurations",
+        response: "GetConfigurationsResponse",
+    },
+    Operation {
+        service: "thermal",
+        name: "SetConfiguration",
+        namespace: "http://www.onvif.org/ver10/thermal/wsdl",
+        action: "http://www.onvif.org/ver10/thermal/wsdl/SetConfiguration",
+        request: "SetConfiguration",
+        response: "SetConfigurationResponse",
+    },
+    Operation {
+        service: "thermal",
+        name: "GetRadiometryConfigurationOptions",
+        namespace: "http://www.onvif.org/ver10/thermal/wsdl",
+        action: "http://www.onvif.org/ver10/thermal/wsdl/GetRadiometryConfigurationOptions",
+        request: "GetRadiometryConfigurationOptions",
+        response: "GetRadiometryConfigurationOptionsResponse",
+    },
+    Operation {
+        service: "thermal",
+        name: "GetRadiometryConfiguration",
+        namespace: "http://www.onvif.org/ver10/thermal/wsdl",
+        action: "http://www.onvif.org/ver10/thermal/wsdl/GetRadiometryConfiguration",
+        request: "GetRadiometryConfiguration",
+        response: "GetRadiometryConfigurationResponse",
+    },
+    Operation {
+        service: "thermal",
+        name: "SetRadiometryConfiguration",
+        namespace: "http://www.onvif.org/ver10/thermal/wsdl",
+        action: "http://www.onvif.org/ver10/thermal/wsdl/SetRadiometryConfiguration",
+        request: "SetRadiometryConfiguration",
+        response: "SetRadiometryConfigurationResponse",
+    },
+    Operation {
+        service: "uplink",
+        name: "GetServiceCapabilities",
+        namespace: "http://www.onvif.org/ver10/uplink/wsdl",
+        action: "http://www.onvif.org/ver10/uplink/wsdl/GetServiceCapabilities",
+        request: "GetServiceCapabilities",
+        response: "GetServiceCapabilitiesResponse",
+    },
+    Operation {
+        service: "uplink",
+        name: "GetUplinks",
+        namespace: "http://www.onvif.org/ver10/uplink/wsdl",
+        action: "http://www.onvif.org/ver10/uplink/wsdl/GetUplinks",
+        request: "GetUplinks",
+        response: "GetUplinksResponse",
+    },
+    Operation {
+        service: "uplink",
+        name: "SetUplink",
+        namespace: "http://www.onvif.org/ver10/uplink/wsdl",
+        action: "http://www.onvif.org/ver10/uplink/wsdl/SetUplink",
+        request: "SetUplink",
+        response: "SetUplinkResponse",
+    },
+    Operation {
+        service: "uplink",
+        name: "DeleteUplink",
+        namespace: "http://www.onvif.org/ver10/uplink/wsdl",
+        action: "http://www.onvif.org/ver10/uplink/wsdl/DeleteUplink",
+        request: "DeleteUplink",
+        response: "DeleteUplinkResponse",
+    },
+];
@@ -0,0 +1,64 @@
+//! Metadata of all operations of the generated WSDL modules, for tooling that handles
+//! operations generically, e.g. a CLI, a conformance prober or a server dispatcher.
+//!
+//! ```
+//! let operation = schema::operations::find("devicemgmt", "GetDeviceInformation").unwrap();
+//! assert_eq!(
+//!     operation.action,
+//!     "http://www.onvif.org/ver10/device/wsdl/GetDeviceInformation"
+//! );
+//! assert_eq!(operation.response, "GetDeviceInformationResponse");
+//! ```
+
+mod generated;
+
+/// An operation, i.e. a function such as [`crate::devicemgmt::get_device_information`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Operation {
+    /// The module of the operation, e.g. `devicemgmt`.
+    pub service: &'static str,
+
+    /// Name of the operation in the WSDL, e.g. `GetDeviceInformation`.
+    pub name: &'static str,
+
+    /// Target namespace of the WSDL.
+    pub namespace: &'static str,
+
+    /// SOAP action of the binding, empty if the binding doesn't declare one.
+    pub action: &'static str,
+
+    /// Request and response types, relative to the module of the operation.
+    pub request: &'static str,
+    pub response: &'static str,
+}
+
+/// All operations, grouped by service.
+pub fn all() -> &'static [Operation] {
+    generated::OPERATIONS
+}
+
+pub fn find(service: &str, name: &str) -> Option<&'static Operation> {
+    all()
+        .iter()
+        .find(|operation| operation.service == service && operation.name == name)
+}
+
+/// Finds the operation a request is for by its SOAP action, e.g. in a server.
+pub fn by_action(action: &str) -> Option<&'static Operation> {
+    all().iter().find(|operation| operation.action == action)
+}
+
+/// Finds the operation of a request element by its namespace and local name, e.g. to send the
+/// SOAP action with a request.
+pub fn by_request(namespace: &str, request: &str) -> Option<&'static Operation> {
+    all()
+        .iter()
+        .find(|operation| operation.namespace == namespace && operation.request == request)
+}
+
+/// Operations of a service, e.g. `ptz`.
+pub fn of_service<'a>(service: &'a str) -> impl Iterator<Item = &'static Operation> + 'a {
+    all()
+        .iter()
+        .filter(move |operation| operation.service == service)
+}
@@ -0,0 +1,139 @@
+//! Serialize → deserialize round trips of arbitrary values, to catch yaserde attribute mistakes
+//! (missing namespaces, misplaced attributes, flattened options) that otherwise only show up
+//! against real devices.
+
+use super::*;
+
+use onvif as tt;
+use proptest::{option, prelude::*};
+use yaserde::{YaDeserialize, YaSerialize};
+
+fn round_trip<T: YaSerialize + YaDeserialize + PartialEq + std::fmt::Debug>(
+    value: &T,
+) -> Result<(), TestCaseError> {
+    let xml = yaserde::ser::to_string(value).map_err(TestCaseError::fail)?;
+    let de: T = yaserde::de::from_str(&xml).map_err(TestCaseError::fail)?;
+    prop_assert_eq!(&de, value, "serialized as {}", xml);
+    Ok(())
+}
+
+// Leading and trailing whitespace isn't preserved by XML parsers, and most string fields are
+// tokens or names anyway.
+fn token() -> impl Strategy<Value = String> {
+    "[a-zA-Z0-9_.-]{1,16}"
+}
+
+fn date_time() -> impl Strategy<Value = tt::DateTime> {
+    (0..24, 0..60, 0..60, 1970..2100, 1..13, 1..29).prop_map(
+        |(hour, minute, second, year, month, day)| tt::DateTime {
+            time: tt::Time {
+                hour,
+                minute,
+                second,
+            },
+            date: tt::Date { year, month, day },
+        },
+    )
+}
+
+fn vector_2d() -> impl Strategy<Value = tt::Vector2D> {
+    (-1.0..1.0f64, -1.0..1.0f64, option::of(token())).prop_map(|(x, y, space)| tt::Vector2D {
+        x,
+        y,
+        space,
+    })
+}
+
+fn vector_1d() -> impl Strategy<Value = tt::Vector1D> {
+    (-1.0..1.0f64, option::of(token())).prop_map(|(x, space)| tt::Vector1D { x, space })
+}
+
+fn user_level() -> impl Strategy<Value = tt::UserLevel> {
+    prop_oneof![
+        Just(tt::UserLevel::Administrator),
+        Just(tt::UserLevel::Operator),
+        Just(tt::UserLevel::User),
+        Just(tt::UserLevel::Anonymous),
+        Just(tt::UserLevel::Extended),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn get_system_date_and_time_response(
+        ntp in any::<bool>(),
+        daylight_savings in any::<bool>(),
+        tz in option::of(token()),
+        utc_date_time in option::of(date_time()),
+        local_date_time in option::of(date_time()),
+    ) {
+        round_trip(&devicemgmt::GetSystemDateAndTimeResponse {
+            system_date_and_time: tt::SystemDateTime {
+                date_time_type: if ntp {
+                    tt::SetDateTimeType::Ntp
+                } else {
+                    tt::SetDateTimeType::Manual
+                },
+                daylight_savings,
+                time_zone: tz.map(|tz| tt::TimeZone { tz }),
+                utc_date_time,
+                local_date_time,
+                extension: None,
+            },
+        })?;
+    }
+
+    #[test]
+    fn get_users_response(
+        users in prop::collection::vec((token(), option::of(token()), user_level()), 0..4),
+    ) {
+        round_trip(&devicemgmt::GetUsersResponse {
+            user: users
+                .into_iter()
+                .map(|(username, password, user_level)| tt::User {
+                    username,
+                    password,
+                    user_level,
+                    extension: None,
+                })
+                .collect(),
+        })?;
+    }
+
+    #[test]
+    fn continuous_move(
+        profile_token in token(),
+        pan_tilt in option::of(vector_2d()),
+        zoom in option::of(vector_1d()),
+    ) {
+        round_trip(&ptz::ContinuousMove {
+            profile_token: tt::ReferenceToken(profile_token),
+            velocity: tt::Ptzspeed { pan_tilt, zoom },
+            timeout: None,
+        })?;
+    }
+
+    #[test]
+    fn get_presets_response(
+        presets in prop::collection::vec(
+            (
+                option::of(token()),
+                option::of((option::of(vector_2d()), option::of(vector_1d()))),
+                option::of(token()),
+            ),
+            0..4,
+        ),
+    ) {
+        round_trip(&ptz::GetPresetsResponse {
+            preset: presets
+                .into_iter()
+                .map(|(name, position, token)| tt::Ptzpreset {
+                    name: name.map(tt::Name),
+                    ptz_position: position
+                        .map(|(pan_tilt, zoom)| tt::Ptzvector { pan_tilt, zoom }),
+                    token: token.map(tt::ReferenceToken),
+                })
+                .collect(),
+        })?;
+    }
+}
@@ -18,8 +18,8 @@ impl Validate for GetServiceCapabilities {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct GetServiceCapabilitiesResponse {
-    // The capabilities for the PTZ service is returned in the Capabilities
-    // element.
+    /// The capabilities for the PTZ service is returned in the Capabilities
+    /// element.
     #[yaserde(prefix = "tptz", rename = "Capabilities")]
     pub capabilities: Capabilities,
 }
@@ -32,23 +32,23 @@ impl Validate for GetServiceCapabilitiesResponse {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct Capabilities {
-    // Indicates whether or not EFlip is supported.
+    /// Indicates whether or not EFlip is supported.
     #[yaserde(attribute, rename = "EFlip")]
     pub e_flip: Option<bool>,
 
-    // Indicates whether or not reversing of PT control direction is supported.
+    /// Indicates whether or not reversing of PT control direction is supported.
     #[yaserde(attribute, rename = "Reverse")]
     pub reverse: Option<bool>,
 
-    // Indicates support for the GetCompatibleConfigurations command.
+    /// Indicates support for the GetCompatibleConfigurations command.
     #[yaserde(attribute, rename = "GetCompatibleConfigurations")]
     pub get_compatible_configurations: Option<bool>,
 
-    // Indicates that the PTZStatus includes MoveStatus information.
+    /// Indicates that the PTZStatus includes MoveStatus information.
     #[yaserde(attribute, rename = "MoveStatus")]
     pub move_status: Option<bool>,
 
-    // Indicates that the PTZStatus includes Position information.
+    /// Indicates that the PTZStatus includes Position information.
     #[yaserde(attribute, rename = "StatusPosition")]
     pub status_position: Option<bool>,
 }
@@ -71,7 +71,7 @@ impl Validate for GetNodes {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct GetNodesResponse {
-    // A list of the existing PTZ Nodes on the device.
+    /// A list of the existing PTZ Nodes on the device.
     #[yaserde(prefix = "tptz", rename = "PTZNode")]
     pub ptz_node: Vec<tt::Ptznode>,
 }
@@ -84,7 +84,7 @@ impl Validate for GetNodesResponse {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct GetNode {
-    // Token of the requested PTZNode.
+    /// Token of the requested PTZNode.
     #[yaserde(prefix = "tptz", rename = "NodeToken")]
     pub node_token: tt::ReferenceToken,
 }
@@ -97,7 +97,7 @@ impl Validate for GetNode {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct GetNodeResponse {
-    // A requested PTZNode.
+    /// A requested PTZNode.
     #[yaserde(prefix = "tptz", rename = "PTZNode")]
     pub ptz_node: tt::Ptznode,
 }
@@ -119,7 +119,7 @@ impl Validate for GetConfigurations {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct GetConfigurationsResponse {
-    // A list of all existing PTZConfigurations on the device.
+    /// A list of all existing PTZConfigurations on the device.
     #[yaserde(prefix = "tptz", rename = "PTZConfiguration")]
     pub ptz_configuration: Vec<tt::Ptzconfiguration>,
 }
@@ -132,7 +132,7 @@ impl Validate for GetConfigurationsResponse {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct GetConfiguration {
-    // Token of the requested PTZConfiguration.
+    /// Token of the requested PTZConfiguration.
     #[yaserde(prefix = "tptz", rename = "PTZConfigurationToken")]
     pub ptz_configuration_token: tt::ReferenceToken,
 }
@@ -145,7 +145,7 @@ impl Validate for GetConfiguration {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct GetConfigurationResponse {
-    // A requested PTZConfiguration.
+    /// A requested PTZConfiguration.
     #[yaserde(prefix = "tptz", rename = "PTZConfiguration")]
     pub ptz_configuration: tt::Ptzconfiguration,
 }
@@ -161,8 +161,8 @@ pub struct SetConfiguration {
     #[yaserde(prefix = "tptz", rename = "PTZConfiguration")]
     pub ptz_configuration: tt::Ptzconfiguration,
 
-    // Flag that makes configuration persistent. Example: User wants the
-    // configuration to exist after reboot.
+    /// Flag that makes configuration persistent. Example: User wants the
+    /// configuration to exist after reboot.
     #[yaserde(prefix = "tptz", rename = "ForcePersistence")]
     pub force_persistence: bool,
 }
@@ -184,7 +184,7 @@ impl Validate for SetConfigurationResponse {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct GetConfigurationOptions {
-    // Token of an existing configuration that the options are intended for.
+    /// Token of an existing configuration that the options are intended for.
     #[yaserde(prefix = "tptz", rename = "ConfigurationToken")]
     pub configuration_token: tt::ReferenceToken,
 }
@@ -197,7 +197,7 @@ impl Validate for GetConfigurationOptions {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct GetConfigurationOptionsResponse {
-    // The requested PTZ configuration options.
+    /// The requested PTZ configuration options.
     #[yaserde(prefix = "tptz", rename = "PTZConfigurationOptions")]
     pub ptz_configuration_options: tt::PtzconfigurationOptions,
 }
@@ -210,11 +210,11 @@ impl Validate for GetConfigurationOptionsResponse {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct SendAuxiliaryCommand {
-    // A reference to the MediaProfile where the operation should take place.
+    /// A reference to the MediaProfile where the operation should take place.
     #[yaserde(prefix = "tptz", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 
-    // The Auxiliary request data.
+    /// The Auxiliary request data.
     #[yaserde(prefix = "tptz", rename = "AuxiliaryData")]
     pub auxiliary_data: tt::AuxiliaryData,
 }
@@ -227,7 +227,7 @@ impl Validate for SendAuxiliaryCommand {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct SendAuxiliaryCommandResponse {
-    // The response contains the auxiliary response.
+    /// The response contains the auxiliary response.
     #[yaserde(prefix = "tptz", rename = "AuxiliaryResponse")]
     pub auxiliary_response: tt::AuxiliaryData,
 }
@@ -240,7 +240,7 @@ impl Validate for SendAuxiliaryCommandResponse {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct GetPresets {
-    // A reference to the MediaProfile where the operation should take place.
+    /// A reference to the MediaProfile where the operation should take place.
     #[yaserde(prefix = "tptz", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -253,7 +253,7 @@ impl Validate for GetPresets {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct GetPresetsResponse {
-    // A list of presets which are available for the requested MediaProfile.
+    /// A list of presets which are available for the requested MediaProfile.
     #[yaserde(prefix = "tptz", rename = "Preset")]
     pub preset: Vec<tt::Ptzpreset>,
 }
@@ -266,15 +266,15 @@ impl Validate for GetPresetsResponse {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct SetPreset {
-    // A reference to the MediaProfile where the operation should take place.
+    /// A reference to the MediaProfile where the operation should take place.
     #[yaserde(prefix = "tptz", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 
-    // A requested preset name.
+    /// A requested preset name.
     #[yaserde(prefix = "tptz", rename = "PresetName")]
     pub preset_name: Option<String>,
 
-    // A requested preset token.
+    /// A requested preset token.
     #[yaserde(prefix = "tptz", rename = "PresetToken")]
     pub preset_token: Option<tt::ReferenceToken>,
 }
@@ -287,7 +287,7 @@ impl Validate for SetPreset {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct SetPresetResponse {
-    // A token to the Preset which has been set.
+    /// A token to the Preset which has been set.
     #[yaserde(prefix = "tptz", rename = "PresetToken")]
     pub preset_token: tt::ReferenceToken,
 }
@@ -300,11 +300,11 @@ impl Validate for SetPresetResponse {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct RemovePreset {
-    // A reference to the MediaProfile where the operation should take place.
+    /// A reference to the MediaProfile where the operation should take place.
     #[yaserde(prefix = "tptz", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 
-    // A requested preset token.
+    /// A requested preset token.
     #[yaserde(prefix = "tptz", rename = "PresetToken")]
     pub preset_token: tt::ReferenceToken,
 }
@@ -326,16 +326,16 @@ impl Validate for RemovePresetResponse {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct GotoPreset {
-    // A reference to the MediaProfile where the operation should take place.
+    /// A reference to the MediaProfile where the operation should take place.
     #[yaserde(prefix = "tptz", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 
-    // A requested preset token.
+    /// A requested preset token.
     #[yaserde(prefix = "tptz", rename = "PresetToken")]
     pub preset_token: tt::ReferenceToken,
 
-    // A requested speed.The speed parameter can only be specified when Speed
-    // Spaces are available for the PTZ Node.
+    /// A requested speed.The speed parameter can only be specified when Speed
+    /// Spaces are available for the PTZ Node.
     #[yaserde(prefix = "tptz", rename = "Speed")]
     pub speed: Option<tt::Ptzspeed>,
 }
@@ -357,7 +357,7 @@ impl Validate for GotoPresetResponse {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct GetStatus {
-    // A reference to the MediaProfile where the PTZStatus should be requested.
+    /// A reference to the MediaProfile where the PTZStatus should be requested.
     #[yaserde(prefix = "tptz", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -370,7 +370,7 @@ impl Validate for GetStatus {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct GetStatusResponse {
-    // The PTZStatus for the requested MediaProfile.
+    /// The PTZStatus for the requested MediaProfile.
     #[yaserde(prefix = "tptz", rename = "PTZStatus")]
     pub ptz_status: tt::Ptzstatus,
 }
@@ -383,12 +383,12 @@ impl Validate for GetStatusResponse {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct GotoHomePosition {
-    // A reference to the MediaProfile where the operation should take place.
+    /// A reference to the MediaProfile where the operation should take place.
     #[yaserde(prefix = "tptz", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 
-    // A requested speed.The speed parameter can only be specified when Speed
-    // Spaces are available for the PTZ Node.
+    /// A requested speed.The speed parameter can only be specified when Speed
+    /// Spaces are available for the PTZ Node.
     #[yaserde(prefix = "tptz", rename = "Speed")]
     pub speed: Option<tt::Ptzspeed>,
 }
@@ -410,7 +410,7 @@ impl Validate for GotoHomePositionResponse {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct SetHomePosition {
-    // A reference to the MediaProfile where the home position should be set.
+    /// A reference to the MediaProfile where the home position should be set.
     #[yaserde(prefix = "tptz", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -432,15 +432,15 @@ impl Validate for SetHomePositionResponse {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct ContinuousMove {
-    // A reference to the MediaProfile.
+    /// A reference to the MediaProfile.
     #[yaserde(prefix = "tptz", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 
-    // A Velocity vector specifying the velocity of pan, tilt and zoom.
+    /// A Velocity vector specifying the velocity of pan, tilt and zoom.
     #[yaserde(prefix = "tptz", rename = "Velocity")]
     pub velocity: tt::Ptzspeed,
 
-    // An optional Timeout parameter.
+    /// An optional Timeout parameter.
     #[yaserde(prefix = "tptz", rename = "Timeout")]
     pub timeout: Option<xs::Duration>,
 }
@@ -462,15 +462,15 @@ impl Validate for ContinuousMoveResponse {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct RelativeMove {
-    // A reference to the MediaProfile.
+    /// A reference to the MediaProfile.
     #[yaserde(prefix = "tptz", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 
-    // A positional Translation relative to the current position
+    /// A positional Translation relative to the current position
     #[yaserde(prefix = "tptz", rename = "Translation")]
     pub translation: tt::Ptzvector,
 
-    // An optional Speed parameter.
+    /// An optional Speed parameter.
     #[yaserde(prefix = "tptz", rename = "Speed")]
     pub speed: Option<tt::Ptzspeed>,
 }
@@ -492,15 +492,15 @@ impl Validate for RelativeMoveResponse {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct AbsoluteMove {
-    // A reference to the MediaProfile.
+    /// A reference to the MediaProfile.
     #[yaserde(prefix = "tptz", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 
-    // A Position vector specifying the absolute target position.
+    /// A Position vector specifying the absolute target position.
     #[yaserde(prefix = "tptz", rename = "Position")]
     pub position: tt::Ptzvector,
 
-    // An optional Speed.
+    /// An optional Speed.
     #[yaserde(prefix = "tptz", rename = "Speed")]
     pub speed: Option<tt::Ptzspeed>,
 }
@@ -522,23 +522,23 @@ impl Validate for AbsoluteMoveResponse {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct GeoMove {
-    // A reference to the MediaProfile.
+    /// A reference to the MediaProfile.
     #[yaserde(prefix = "tptz", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 
-    // The geolocation of the target position.
+    /// The geolocation of the target position.
     #[yaserde(prefix = "tptz", rename = "Target")]
     pub target: tt::GeoLocation,
 
-    // An optional Speed.
+    /// An optional Speed.
     #[yaserde(prefix = "tptz", rename = "Speed")]
     pub speed: Option<tt::Ptzspeed>,
 
-    // An optional indication of the height of the target/area.
+    /// An optional indication of the height of the target/area.
     #[yaserde(prefix = "tptz", rename = "AreaHeight")]
     pub area_height: Option<f64>,
 
-    // An optional indication of the width of the target/area.
+    /// An optional indication of the width of the target/area.
     #[yaserde(prefix = "tptz", rename = "AreaWidth")]
     pub area_width: Option<f64>,
 }
@@ -560,17 +560,17 @@ impl Validate for GeoMoveResponse {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct Stop {
-    // A reference to the MediaProfile that indicate what should be stopped.
+    /// A reference to the MediaProfile that indicate what should be stopped.
     #[yaserde(prefix = "tptz", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 
-    // Set true when we want to stop ongoing pan and tilt movements.If PanTilt
-    // arguments are not present, this command stops these movements.
+    /// Set true when we want to stop ongoing pan and tilt movements.If PanTilt
+    /// arguments are not present, this command stops these movements.
     #[yaserde(prefix = "tptz", rename = "PanTilt")]
     pub pan_tilt: Option<bool>,
 
-    // Set true when we want to stop ongoing zoom movement.If Zoom arguments are
-    // not present, this command stops ongoing zoom movement.
+    /// Set true when we want to stop ongoing zoom movement.If Zoom arguments are
+    /// not present, this command stops ongoing zoom movement.
     #[yaserde(prefix = "tptz", rename = "Zoom")]
     pub zoom: Option<bool>,
 }
@@ -769,8 +769,8 @@ impl Validate for RemovePresetTourResponse {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct GetCompatibleConfigurations {
-    // Contains the token of an existing media profile the configurations shall
-    // be compatible with.
+    /// Contains the token of an existing media profile the configurations shall
+    /// be compatible with.
     #[yaserde(prefix = "tptz", rename = "ProfileToken")]
     pub profile_token: tt::ReferenceToken,
 }
@@ -783,16 +783,16 @@ impl Validate for GetCompatibleConfigurations {}
     namespace = "tptz: http://www.onvif.org/ver20/ptz/wsdl"
 )]
 pub struct GetCompatibleConfigurationsResponse {
-    // A list of all existing PTZConfigurations on the NVT that is suitable to
-    // be added to the addressed media profile.
+    /// A list of all existing PTZConfigurations on the NVT that is suitable to
+    /// be added to the addressed media profile.
     #[yaserde(prefix = "tptz", rename = "PTZConfiguration")]
     pub ptz_configuration: Vec<tt::Ptzconfiguration>,
 }
 
 impl Validate for GetCompatibleConfigurationsResponse {}
 
-// Returns the capabilities of the PTZ service. The result is returned in a
-// typed answer.
+/// Returns the capabilities of the PTZ service. The result is returned in a
+/// typed answer.
 pub async fn get_service_capabilities<T: transport::Transport>(
     transport: &T,
     request: &GetServiceCapabilities,
@@ -800,7 +800,7 @@ pub async fn get_service_capabilities<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Get the descriptions of the available PTZ Nodes.
+/// Get the descriptions of the available PTZ Nodes.
 pub async fn get_nodes<T: transport::Transport>(
     transport: &T,
     request: &GetNodes,
@@ -808,8 +808,8 @@ pub async fn get_nodes<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Get a specific PTZ Node identified by a reference
-// token or a name.
+/// Get a specific PTZ Node identified by a reference
+/// token or a name.
 pub async fn get_node<T: transport::Transport>(
     transport: &T,
     request: &GetNode,
@@ -817,8 +817,8 @@ pub async fn get_node<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Get a specific PTZconfiguration from the device, identified by its reference
-// token or name.
+/// Get a specific PTZconfiguration from the device, identified by its reference
+/// token or name.
 pub async fn get_configuration<T: transport::Transport>(
     transport: &T,
     request: &GetConfiguration,
@@ -826,7 +826,7 @@ pub async fn get_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Get all the existing PTZConfigurations from the device.
+/// Get all the existing PTZConfigurations from the device.
 pub async fn get_configurations<T: transport::Transport>(
     transport: &T,
     request: &GetConfigurations,
@@ -834,7 +834,7 @@ pub async fn get_configurations<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Set/update a existing PTZConfiguration on the device.
+/// Set/update a existing PTZConfiguration on the device.
 pub async fn set_configuration<T: transport::Transport>(
     transport: &T,
     request: &SetConfiguration,
@@ -842,17 +842,17 @@ pub async fn set_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// List supported coordinate systems including their range limitations.
-// Therefore, the options
-// MAY differ depending on whether the PTZ Configuration is assigned to a
-// Profile containing a
-// Video Source Configuration. In that case, the options may additionally
-// contain coordinate
-// systems referring to the image coordinate system described by the Video
-// Source
-// Configuration. If the PTZ Node supports continuous movements, it shall return
-// a Timeout Range within
-// which Timeouts are accepted by the PTZ Node.
+/// List supported coordinate systems including their range limitations.
+/// Therefore, the options
+/// MAY differ depending on whether the PTZ Configuration is assigned to a
+/// Profile containing a
+/// Video Source Configuration. In that case, the options may additionally
+/// contain coordinate
+/// systems referring to the image coordinate system described by the Video
+/// Source
+/// Configuration. If the PTZ Node supports continuous movements, it shall return
+/// a Timeout Range within
+/// which Timeouts are accepted by the PTZ Node.
 pub async fn get_configuration_options<T: transport::Transport>(
     transport: &T,
     request: &GetConfigurationOptions,
@@ -860,10 +860,10 @@ pub async fn get_configuration_options<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Operation to send auxiliary commands to the PTZ device
-// mapped by the PTZNode in the selected profile. The
-// operation is supported
-// if the AuxiliarySupported element of the PTZNode is true
+/// Operation to send auxiliary commands to the PTZ device
+/// mapped by the PTZNode in the selected profile. The
+/// operation is supported
+/// if the AuxiliarySupported element of the PTZNode is true
 pub async fn send_auxiliary_command<T: transport::Transport>(
     transport: &T,
     request: &SendAuxiliaryCommand,
@@ -871,9 +871,9 @@ pub async fn send_auxiliary_command<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Operation to request all PTZ presets for the PTZNode
-// in the selected profile. The operation is supported if there is support
-// for at least on PTZ preset by the PTZNode.
+/// Operation to request all PTZ presets for the PTZNode
+/// in the selected profile. The operation is supported if there is support
+/// for at least on PTZ preset by the PTZNode.
 pub async fn get_presets<T: transport::Transport>(
     transport: &T,
     request: &GetPresets,
@@ -881,21 +881,21 @@ pub async fn get_presets<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// The SetPreset command saves the current device position parameters so that
-// the device can
-// move to the saved preset position through the GotoPreset operation.
-// In order to create a new preset, the SetPresetRequest contains no
-// PresetToken. If creation is
-// successful, the Response contains the PresetToken which uniquely identifies
-// the Preset. An
-// existing Preset can be overwritten by specifying the PresetToken of the
-// corresponding Preset.
-// In both cases (overwriting or creation) an optional PresetName can be
-// specified. The
-// operation fails if the PTZ device is moving during the SetPreset operation.
-// The device MAY internally save additional states such as imaging properties
-// in the PTZ
-// Preset which then should be recalled in the GotoPreset operation.
+/// The SetPreset command saves the current device position parameters so that
+/// the device can
+/// move to the saved preset position through the GotoPreset operation.
+/// In order to create a new preset, the SetPresetRequest contains no
+/// PresetToken. If creation is
+/// successful, the Response contains the PresetToken which uniquely identifies
+/// the Preset. An
+/// existing Preset can be overwritten by specifying the PresetToken of the
+/// corresponding Preset.
+/// In both cases (overwriting or creation) an optional PresetName can be
+/// specified. The
+/// operation fails if the PTZ device is moving during the SetPreset operation.
+/// The device MAY internally save additional states such as imaging properties
+/// in the PTZ
+/// Preset which then should be recalled in the GotoPreset operation.
 pub async fn set_preset<T: transport::Transport>(
     transport: &T,
     request: &SetPreset,
@@ -903,12 +903,12 @@ pub async fn set_preset<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Operation to remove a PTZ preset for the Node in
-// the
-// selected profile. The operation is supported if the
-// PresetPosition
-// capability exists for teh Node in the
-// selected profile.
+/// Operation to remove a PTZ preset for the Node in
+/// the
+/// selected profile. The operation is supported if the
+/// PresetPosition
+/// capability exists for teh Node in the
+/// selected profile.
 pub async fn remove_preset<T: transport::Transport>(
     transport: &T,
     request: &RemovePreset,
@@ -916,9 +916,9 @@ pub async fn remove_preset<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Operation to go to a saved preset position for the
-// PTZNode in the selected profile. The operation is supported if there is
-// support for at least on PTZ preset by the PTZNode.
+/// Operation to go to a saved preset position for the
+/// PTZNode in the selected profile. The operation is supported if there is
+/// support for at least on PTZ preset by the PTZNode.
 pub async fn goto_preset<T: transport::Transport>(
     transport: &T,
     request: &GotoPreset,
@@ -926,8 +926,8 @@ pub async fn goto_preset<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Operation to move the PTZ device to it's "home" position. The operation is
-// supported if the HomeSupported element in the PTZNode is true.
+/// Operation to move the PTZ device to it's "home" position. The operation is
+/// supported if the HomeSupported element in the PTZNode is true.
 pub async fn goto_home_position<T: transport::Transport>(
     transport: &T,
     request: &GotoHomePosition,
@@ -935,12 +935,12 @@ pub async fn goto_home_position<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Operation to save current position as the home position.
-// The SetHomePosition command returns with a failure if the “home” position
-// is fixed and
-// cannot be overwritten. If the SetHomePosition is successful, it is possible
-// to recall the
-// Home Position with the GotoHomePosition command.
+/// Operation to save current position as the home position.
+/// The SetHomePosition command returns with a failure if the “home” position
+/// is fixed and
+/// cannot be overwritten. If the SetHomePosition is successful, it is possible
+/// to recall the
+/// Home Position with the GotoHomePosition command.
 pub async fn set_home_position<T: transport::Transport>(
     transport: &T,
     request: &SetHomePosition,
@@ -948,10 +948,10 @@ pub async fn set_home_position<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Operation for continuous Pan/Tilt and Zoom movements. The operation is
-// supported if the PTZNode supports at least one continuous Pan/Tilt or Zoom
-// space. If the space argument is omitted, the default space set by the
-// PTZConfiguration will be used.
+/// Operation for continuous Pan/Tilt and Zoom movements. The operation is
+/// supported if the PTZNode supports at least one continuous Pan/Tilt or Zoom
+/// space. If the space argument is omitted, the default space set by the
+/// PTZConfiguration will be used.
 pub async fn continuous_move<T: transport::Transport>(
     transport: &T,
     request: &ContinuousMove,
@@ -959,8 +959,8 @@ pub async fn continuous_move<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Operation for Relative Pan/Tilt and Zoom Move. The operation is supported if
-// the PTZNode supports at least one relative Pan/Tilt or Zoom space.
+/// Operation for Relative Pan/Tilt and Zoom Move. The operation is supported if
+/// the PTZNode supports at least one relative Pan/Tilt or Zoom space.
 pub async fn relative_move<T: transport::Transport>(
     transport: &T,
     request: &RelativeMove,
@@ -968,8 +968,8 @@ pub async fn relative_move<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Operation to request PTZ status for the Node in the
-// selected profile.
+/// Operation to request PTZ status for the Node in the
+/// selected profile.
 pub async fn get_status<T: transport::Transport>(
     transport: &T,
     request: &GetStatus,
@@ -977,7 +977,7 @@ pub async fn get_status<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Operation to move pan,tilt or zoom to a absolute destination.
+/// Operation to move pan,tilt or zoom to a absolute destination.
 pub async fn absolute_move<T: transport::Transport>(
     transport: &T,
     request: &AbsoluteMove,
@@ -985,8 +985,8 @@ pub async fn absolute_move<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Operation to move pan,tilt or zoom to point to a destination based on the
-// geolocation of the target.
+/// Operation to move pan,tilt or zoom to point to a destination based on the
+/// geolocation of the target.
 pub async fn geo_move<T: transport::Transport>(
     transport: &T,
     request: &GeoMove,
@@ -994,10 +994,10 @@ pub async fn geo_move<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Operation to stop ongoing pan, tilt and zoom movements of absolute relative
-// and continuous type.
-// If no stop argument for pan, tilt or zoom is set, the device will stop all
-// ongoing pan, tilt and zoom movements.
+/// Operation to stop ongoing pan, tilt and zoom movements of absolute relative
+/// and continuous type.
+/// If no stop argument for pan, tilt or zoom is set, the device will stop all
+/// ongoing pan, tilt and zoom movements.
 pub async fn stop<T: transport::Transport>(
     transport: &T,
     request: &Stop,
@@ -1005,7 +1005,7 @@ pub async fn stop<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Operation to request PTZ preset tours in the selected media profiles.
+/// Operation to request PTZ preset tours in the selected media profiles.
 pub async fn get_preset_tours<T: transport::Transport>(
     transport: &T,
     request: &GetPresetTours,
@@ -1013,8 +1013,8 @@ pub async fn get_preset_tours<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Operation to request a specific PTZ preset tour in the selected media
-// profile.
+/// Operation to request a specific PTZ preset tour in the selected media
+/// profile.
 pub async fn get_preset_tour<T: transport::Transport>(
     transport: &T,
     request: &GetPresetTour,
@@ -1022,7 +1022,7 @@ pub async fn get_preset_tour<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Operation to request available options to configure PTZ preset tour.
+/// Operation to request available options to configure PTZ preset tour.
 pub async fn get_preset_tour_options<T: transport::Transport>(
     transport: &T,
     request: &GetPresetTourOptions,
@@ -1030,7 +1030,7 @@ pub async fn get_preset_tour_options<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Operation to create a preset tour for the selected media profile.
+/// Operation to create a preset tour for the selected media profile.
 pub async fn create_preset_tour<T: transport::Transport>(
     transport: &T,
     request: &CreatePresetTour,
@@ -1038,7 +1038,7 @@ pub async fn create_preset_tour<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Operation to modify a preset tour for the selected media profile.
+/// Operation to modify a preset tour for the selected media profile.
 pub async fn modify_preset_tour<T: transport::Transport>(
     transport: &T,
     request: &ModifyPresetTour,
@@ -1046,8 +1046,8 @@ pub async fn modify_preset_tour<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Operation to perform specific operation on the preset tour in selected media
-// profile.
+/// Operation to perform specific operation on the preset tour in selected media
+/// profile.
 pub async fn operate_preset_tour<T: transport::Transport>(
     transport: &T,
     request: &OperatePresetTour,
@@ -1055,7 +1055,7 @@ pub async fn operate_preset_tour<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Operation to delete a specific preset tour from the media profile.
+/// Operation to delete a specific preset tour from the media profile.
 pub async fn remove_preset_tour<T: transport::Transport>(
     transport: &T,
     request: &RemovePresetTour,
@@ -1063,8 +1063,8 @@ pub async fn remove_preset_tour<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Operation to get all available PTZConfigurations that can be added to the
-// referenced media profile.
+/// Operation to get all available PTZConfigurations that can be added to the
+/// referenced media profile.
 pub async fn get_compatible_configurations<T: transport::Transport>(
     transport: &T,
     request: &GetCompatibleConfigurations,
@@ -0,0 +1,95 @@
+//! Verbatim XML for elements that can't be (de)serialized as typed fields.
+
+use std::io::{Read, Write};
+use xml::{
+    attribute::OwnedAttribute,
+    namespace::Namespace,
+    reader::{EventReader, XmlEvent},
+    writer::{EmitterConfig, EventWriter},
+};
+use yaserde::{de::Deserializer, ser::Serializer, YaDeserialize, YaSerialize};
+
+/// An element with all of its content, stored as an XML string.
+///
+/// Used for extension points containing `xs:any` and for elements the generated types can't
+/// represent, so that vendor data survives a read-modify-write cycle: a value deserialized from a
+/// response is serialized back unchanged (up to namespace declarations and insignificant
+/// whitespace).
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct RawXml(pub String);
+
+impl RawXml {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl YaDeserialize for RawXml {
+    fn deserialize<R: Read>(reader: &mut Deserializer<R>) -> Result<Self, String> {
+        let mut writer = EventWriter::new_with_config(
+            vec![],
+            EmitterConfig::new()
+                .write_document_declaration(false)
+                .perform_indent(false),
+        );
+
+        match reader.next_event()? {
+            event @ XmlEvent::StartElement { .. } => write(&mut writer, &event)?,
+            event => return Err(format!("expected start of element, found {:?}", event)),
+        }
+
+        let mut depth = 1;
+        loop {
+            // Like the derived deserializers, leave the closing tag of the element to the caller.
+            let event = reader.peek()?.clone();
+            match event {
+                XmlEvent::StartElement { .. } => depth += 1,
+                XmlEvent::EndElement { .. } if depth == 1 => {
+                    write(&mut writer, &event)?;
+                    break;
+                }
+                XmlEvent::EndElement { .. } => depth -= 1,
+                XmlEvent::EndDocument => return Err("unexpected end of document".to_string()),
+                _ => {}
+            }
+            reader.next_event()?;
+            write(&mut writer, &event)?;
+        }
+
+        String::from_utf8(writer.into_inner())
+            .map(RawXml)
+            .map_err(|e| e.to_string())
+    }
+}
+
+impl YaSerialize for RawXml {
+    fn serialize<W: Write>(&self, writer: &mut Serializer<W>) -> Result<(), String> {
+        for event in EventReader::new(self.0.as_bytes()) {
+            let event = event.map_err(|e| e.to_string())?;
+            match event {
+                XmlEvent::StartDocument { .. } | XmlEvent::EndDocument => {}
+                _ => {
+                    if let Some(event) = event.as_writer_event() {
+                        writer.write(event).map_err(|e| e.to_string())?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn serialize_attributes(
+        &self,
+        attributes: Vec<OwnedAttribute>,
+        namespace: Namespace,
+    ) -> Result<(Vec<OwnedAttribute>, Namespace), String> {
+        Ok((attributes, namespace))
+    }
+}
+
+fn write<W: Write>(writer: &mut EventWriter<W>, event: &XmlEvent) -> Result<(), String> {
+    match event.as_writer_event() {
+        Some(event) => writer.write(event).map_err(|e| e.to_string()),
+        None => Ok(()),
+    }
+}
@@ -17,8 +17,8 @@ impl Validate for GetServiceCapabilities {}
     namespace = "trv: http://www.onvif.org/ver10/receiver/wsdl"
 )]
 pub struct GetServiceCapabilitiesResponse {
-    // The capabilities for the receiver service is returned in the Capabilities
-    // element.
+    /// The capabilities for the receiver service is returned in the Capabilities
+    /// element.
     #[yaserde(prefix = "trv", rename = "Capabilities")]
     pub capabilities: Capabilities,
 }
@@ -31,24 +31,24 @@ impl Validate for GetServiceCapabilitiesResponse {}
     namespace = "trv: http://www.onvif.org/ver10/receiver/wsdl"
 )]
 pub struct Capabilities {
-    // Indicates that the device can receive RTP multicast streams.
+    /// Indicates that the device can receive RTP multicast streams.
     #[yaserde(attribute, rename = "RTP_Multicast")]
     pub rtp_multicast: Option<bool>,
 
-    // Indicates that the device can receive RTP/TCP streams
+    /// Indicates that the device can receive RTP/TCP streams
     #[yaserde(attribute, rename = "RTP_TCP")]
     pub rtp_tcp: Option<bool>,
 
-    // Indicates that the device can receive RTP/RTSP/TCP streams.
+    /// Indicates that the device can receive RTP/RTSP/TCP streams.
     #[yaserde(attribute, rename = "RTP_RTSP_TCP")]
     pub rtp_rtsp_tcp: Option<bool>,
 
-    // The maximum number of receivers supported by the device.
+    /// The maximum number of receivers supported by the device.
     #[yaserde(attribute, rename = "SupportedReceivers")]
     pub supported_receivers: i32,
 
-    // The maximum allowed length for RTSP URIs (Minimum and default value is
-    // 128 octet).
+    /// The maximum allowed length for RTSP URIs (Minimum and default value is
+    /// 128 octet).
     #[yaserde(attribute, rename = "MaximumRTSPURILength")]
     pub maximum_rtspuri_length: Option<i32>,
 }
@@ -71,7 +71,7 @@ impl Validate for GetReceivers {}
     namespace = "trv: http://www.onvif.org/ver10/receiver/wsdl"
 )]
 pub struct GetReceiversResponse {
-    // A list of all receivers that currently exist on the device.
+    /// A list of all receivers that currently exist on the device.
     #[yaserde(prefix = "trv", rename = "Receivers")]
     pub receivers: Vec<tt::Receiver>,
 }
@@ -84,7 +84,7 @@ impl Validate for GetReceiversResponse {}
     namespace = "trv: http://www.onvif.org/ver10/receiver/wsdl"
 )]
 pub struct GetReceiver {
-    // The token of the receiver to be retrieved.
+    /// The token of the receiver to be retrieved.
     #[yaserde(prefix = "trv", rename = "ReceiverToken")]
     pub receiver_token: tt::ReferenceToken,
 }
@@ -97,7 +97,7 @@ impl Validate for GetReceiver {}
     namespace = "trv: http://www.onvif.org/ver10/receiver/wsdl"
 )]
 pub struct GetReceiverResponse {
-    // The details of the receiver.
+    /// The details of the receiver.
     #[yaserde(prefix = "trv", rename = "Receiver")]
     pub receiver: tt::Receiver,
 }
@@ -110,7 +110,7 @@ impl Validate for GetReceiverResponse {}
     namespace = "trv: http://www.onvif.org/ver10/receiver/wsdl"
 )]
 pub struct CreateReceiver {
-    // The initial configuration for the new receiver.
+    /// The initial configuration for the new receiver.
     #[yaserde(prefix = "trv", rename = "Configuration")]
     pub configuration: tt::ReceiverConfiguration,
 }
@@ -123,7 +123,7 @@ impl Validate for CreateReceiver {}
     namespace = "trv: http://www.onvif.org/ver10/receiver/wsdl"
 )]
 pub struct CreateReceiverResponse {
-    // The details of the receiver that was created.
+    /// The details of the receiver that was created.
     #[yaserde(prefix = "trv", rename = "Receiver")]
     pub receiver: tt::Receiver,
 }
@@ -136,7 +136,7 @@ impl Validate for CreateReceiverResponse {}
     namespace = "trv: http://www.onvif.org/ver10/receiver/wsdl"
 )]
 pub struct DeleteReceiver {
-    // The token of the receiver to be deleted.
+    /// The token of the receiver to be deleted.
     #[yaserde(prefix = "trv", rename = "ReceiverToken")]
     pub receiver_token: tt::ReferenceToken,
 }
@@ -158,11 +158,11 @@ impl Validate for DeleteReceiverResponse {}
     namespace = "trv: http://www.onvif.org/ver10/receiver/wsdl"
 )]
 pub struct ConfigureReceiver {
-    // The token of the receiver to be configured.
+    /// The token of the receiver to be configured.
     #[yaserde(prefix = "trv", rename = "ReceiverToken")]
     pub receiver_token: tt::ReferenceToken,
 
-    // The new configuration for the receiver.
+    /// The new configuration for the receiver.
     #[yaserde(prefix = "trv", rename = "Configuration")]
     pub configuration: tt::ReceiverConfiguration,
 }
@@ -184,11 +184,11 @@ impl Validate for ConfigureReceiverResponse {}
     namespace = "trv: http://www.onvif.org/ver10/receiver/wsdl"
 )]
 pub struct SetReceiverMode {
-    // The token of the receiver to be changed.
+    /// The token of the receiver to be changed.
     #[yaserde(prefix = "trv", rename = "ReceiverToken")]
     pub receiver_token: tt::ReferenceToken,
 
-    // The new receiver mode. Options available are:
+    /// The new receiver mode. Options available are:
     #[yaserde(prefix = "trv", rename = "Mode")]
     pub mode: tt::ReceiverMode,
 }
@@ -210,7 +210,7 @@ impl Validate for SetReceiverModeResponse {}
     namespace = "trv: http://www.onvif.org/ver10/receiver/wsdl"
 )]
 pub struct GetReceiverState {
-    // The token of the receiver to be queried.
+    /// The token of the receiver to be queried.
     #[yaserde(prefix = "trv", rename = "ReceiverToken")]
     pub receiver_token: tt::ReferenceToken,
 }
@@ -223,15 +223,15 @@ impl Validate for GetReceiverState {}
     namespace = "trv: http://www.onvif.org/ver10/receiver/wsdl"
 )]
 pub struct GetReceiverStateResponse {
-    // Description of the current receiver state.
+    /// Description of the current receiver state.
     #[yaserde(prefix = "trv", rename = "ReceiverState")]
     pub receiver_state: tt::ReceiverStateInformation,
 }
 
 impl Validate for GetReceiverStateResponse {}
 
-// Returns the capabilities of the receiver service. The result is returned in a
-// typed answer.
+/// Returns the capabilities of the receiver service. The result is returned in a
+/// typed answer.
 pub async fn get_service_capabilities<T: transport::Transport>(
     transport: &T,
     request: &GetServiceCapabilities,
@@ -239,8 +239,8 @@ pub async fn get_service_capabilities<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Lists all receivers currently present on a device. This operation is
-// mandatory.
+/// Lists all receivers currently present on a device. This operation is
+/// mandatory.
 pub async fn get_receivers<T: transport::Transport>(
     transport: &T,
     request: &GetReceivers,
@@ -248,7 +248,7 @@ pub async fn get_receivers<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Retrieves the details of a specific receiver. This operation is mandatory.
+/// Retrieves the details of a specific receiver. This operation is mandatory.
 pub async fn get_receiver<T: transport::Transport>(
     transport: &T,
     request: &GetReceiver,
@@ -256,8 +256,8 @@ pub async fn get_receiver<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Creates a new receiver. This operation is mandatory, although the service may
-// raise a fault if the receiver cannot be created.
+/// Creates a new receiver. This operation is mandatory, although the service may
+/// raise a fault if the receiver cannot be created.
 pub async fn create_receiver<T: transport::Transport>(
     transport: &T,
     request: &CreateReceiver,
@@ -265,9 +265,9 @@ pub async fn create_receiver<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Deletes an existing receiver. A receiver may be deleted only if it is not
-// currently in use; otherwise a fault shall be raised.
-// This operation is mandatory.
+/// Deletes an existing receiver. A receiver may be deleted only if it is not
+/// currently in use; otherwise a fault shall be raised.
+/// This operation is mandatory.
 pub async fn delete_receiver<T: transport::Transport>(
     transport: &T,
     request: &DeleteReceiver,
@@ -275,7 +275,7 @@ pub async fn delete_receiver<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Configures an existing receiver. This operation is mandatory.
+/// Configures an existing receiver. This operation is mandatory.
 pub async fn configure_receiver<T: transport::Transport>(
     transport: &T,
     request: &ConfigureReceiver,
@@ -283,9 +283,9 @@ pub async fn configure_receiver<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Sets the mode of the receiver without affecting the rest of its
-// configuration.
-// This operation is mandatory.
+/// Sets the mode of the receiver without affecting the rest of its
+/// configuration.
+/// This operation is mandatory.
 pub async fn set_receiver_mode<T: transport::Transport>(
     transport: &T,
     request: &SetReceiverMode,
@@ -293,9 +293,9 @@ pub async fn set_receiver_mode<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Determines whether the receiver is currently disconnected, connected or
-// attempting to connect.
-// This operation is mandatory.
+/// Determines whether the receiver is currently disconnected, connected or
+/// attempting to connect.
+/// This operation is mandatory.
 pub async fn get_receiver_state<T: transport::Transport>(
     transport: &T,
     request: &GetReceiverState,
@@ -20,8 +20,8 @@ impl Validate for GetServiceCapabilities {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct GetServiceCapabilitiesResponse {
-    // The capabilities for the recording service is returned in the
-    // Capabilities element.
+    /// The capabilities for the recording service is returned in the
+    /// Capabilities element.
     #[yaserde(prefix = "trc", rename = "Capabilities")]
     pub capabilities: Capabilities,
 }
@@ -34,51 +34,51 @@ impl Validate for GetServiceCapabilitiesResponse {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct Capabilities {
-    // Indication if the device supports dynamic creation and deletion of
-    // recordings
+    /// Indication if the device supports dynamic creation and deletion of
+    /// recordings
     #[yaserde(attribute, rename = "DynamicRecordings")]
     pub dynamic_recordings: Option<bool>,
 
-    // Indication if the device supports dynamic creation and deletion of tracks
+    /// Indication if the device supports dynamic creation and deletion of tracks
     #[yaserde(attribute, rename = "DynamicTracks")]
     pub dynamic_tracks: Option<bool>,
 
-    // Indication which encodings are supported for recording. The list may
-    // contain one or more enumeration values of tt:VideoEncoding and
-    // tt:AudioEncoding. For encodings that are neither defined in
-    // tt:VideoEncoding nor tt:AudioEncoding the device shall use the
+    /// Indication which encodings are supported for recording. The list may
+    /// contain one or more enumeration values of tt:VideoEncoding and
+    /// tt:AudioEncoding. For encodings that are neither defined in
+    /// tt:VideoEncoding nor tt:AudioEncoding the device shall use the
     #[yaserde(attribute, rename = "Encoding")]
     pub encoding: Option<EncodingTypes>,
 
-    // Maximum supported bit rate for all tracks of a recording in kBit/s.
+    /// Maximum supported bit rate for all tracks of a recording in kBit/s.
     #[yaserde(attribute, rename = "MaxRate")]
     pub max_rate: Option<f64>,
 
-    // Maximum supported bit rate for all recordings in kBit/s.
+    /// Maximum supported bit rate for all recordings in kBit/s.
     #[yaserde(attribute, rename = "MaxTotalRate")]
     pub max_total_rate: Option<f64>,
 
-    // Maximum number of recordings supported. (Integer values only.)
+    /// Maximum number of recordings supported. (Integer values only.)
     #[yaserde(attribute, rename = "MaxRecordings")]
     pub max_recordings: Option<f64>,
 
-    // Maximum total number of supported recording jobs by the device.
+    /// Maximum total number of supported recording jobs by the device.
     #[yaserde(attribute, rename = "MaxRecordingJobs")]
     pub max_recording_jobs: Option<i32>,
 
-    // Indication if the device supports the GetRecordingOptions command.
+    /// Indication if the device supports the GetRecordingOptions command.
     #[yaserde(attribute, rename = "Options")]
     pub options: Option<bool>,
 
-    // Indication if the device supports recording metadata.
+    /// Indication if the device supports recording metadata.
     #[yaserde(attribute, rename = "MetadataRecording")]
     pub metadata_recording: Option<bool>,
 
-    // Indication that the device supports ExportRecordedData command for the
-    // listed export file formats.
-    // The list shall return at least one export file format value. The value of
-    // 'ONVIF' refers to
-    // ONVIF Export File Format specification.
+    /// Indication that the device supports ExportRecordedData command for the
+    /// listed export file formats.
+    /// The list shall return at least one export file format value. The value of
+    /// 'ONVIF' refers to
+    /// ONVIF Export File Format specification.
     #[yaserde(attribute, rename = "SupportedExportFileFormats")]
     pub supported_export_file_formats: Option<tt::StringAttrList>,
 }
@@ -96,7 +96,7 @@ impl Validate for EncodingTypes {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct CreateRecording {
-    // Initial configuration for the recording.
+    /// Initial configuration for the recording.
     #[yaserde(prefix = "trc", rename = "RecordingConfiguration")]
     pub recording_configuration: tt::RecordingConfiguration,
 }
@@ -109,7 +109,7 @@ impl Validate for CreateRecording {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct CreateRecordingResponse {
-    // The reference to the created recording.
+    /// The reference to the created recording.
     #[yaserde(prefix = "trc", rename = "RecordingToken")]
     pub recording_token: tt::RecordingReference,
 }
@@ -122,7 +122,7 @@ impl Validate for CreateRecordingResponse {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct DeleteRecording {
-    // The reference of the recording to be deleted.
+    /// The reference of the recording to be deleted.
     #[yaserde(prefix = "trc", rename = "RecordingToken")]
     pub recording_token: tt::RecordingReference,
 }
@@ -153,7 +153,7 @@ impl Validate for GetRecordings {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct GetRecordingsResponse {
-    // List of recording items.
+    /// List of recording items.
     #[yaserde(prefix = "trc", rename = "RecordingItem")]
     pub recording_item: Vec<tt::GetRecordingsResponseItem>,
 }
@@ -166,11 +166,11 @@ impl Validate for GetRecordingsResponse {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct SetRecordingConfiguration {
-    // Token of the recording that shall be changed.
+    /// Token of the recording that shall be changed.
     #[yaserde(prefix = "trc", rename = "RecordingToken")]
     pub recording_token: tt::RecordingReference,
 
-    // The new configuration.
+    /// The new configuration.
     #[yaserde(prefix = "trc", rename = "RecordingConfiguration")]
     pub recording_configuration: tt::RecordingConfiguration,
 }
@@ -192,7 +192,7 @@ impl Validate for SetRecordingConfigurationResponse {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct GetRecordingConfiguration {
-    // Token of the configuration to be retrieved.
+    /// Token of the configuration to be retrieved.
     #[yaserde(prefix = "trc", rename = "RecordingToken")]
     pub recording_token: tt::RecordingReference,
 }
@@ -205,7 +205,7 @@ impl Validate for GetRecordingConfiguration {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct GetRecordingConfigurationResponse {
-    // Configuration of the recording.
+    /// Configuration of the recording.
     #[yaserde(prefix = "trc", rename = "RecordingConfiguration")]
     pub recording_configuration: tt::RecordingConfiguration,
 }
@@ -218,11 +218,11 @@ impl Validate for GetRecordingConfigurationResponse {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct CreateTrack {
-    // Identifies the recording to which a track shall be added.
+    /// Identifies the recording to which a track shall be added.
     #[yaserde(prefix = "trc", rename = "RecordingToken")]
     pub recording_token: tt::RecordingReference,
 
-    // The configuration of the new track.
+    /// The configuration of the new track.
     #[yaserde(prefix = "trc", rename = "TrackConfiguration")]
     pub track_configuration: tt::TrackConfiguration,
 }
@@ -235,9 +235,9 @@ impl Validate for CreateTrack {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct CreateTrackResponse {
-    // The TrackToken shall identify the newly created track. The
-    // TrackToken shall be unique within the recoding to which
-    // the new track belongs.
+    /// The TrackToken shall identify the newly created track. The
+    /// TrackToken shall be unique within the recoding to which
+    /// the new track belongs.
     #[yaserde(prefix = "trc", rename = "TrackToken")]
     pub track_token: tt::TrackReference,
 }
@@ -250,11 +250,11 @@ impl Validate for CreateTrackResponse {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct DeleteTrack {
-    // Token of the recording the track belongs to.
+    /// Token of the recording the track belongs to.
     #[yaserde(prefix = "trc", rename = "RecordingToken")]
     pub recording_token: tt::RecordingReference,
 
-    // Token of the track to be deleted.
+    /// Token of the track to be deleted.
     #[yaserde(prefix = "trc", rename = "TrackToken")]
     pub track_token: tt::TrackReference,
 }
@@ -276,11 +276,11 @@ impl Validate for DeleteTrackResponse {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct GetTrackConfiguration {
-    // Token of the recording the track belongs to.
+    /// Token of the recording the track belongs to.
     #[yaserde(prefix = "trc", rename = "RecordingToken")]
     pub recording_token: tt::RecordingReference,
 
-    // Token of the track.
+    /// Token of the track.
     #[yaserde(prefix = "trc", rename = "TrackToken")]
     pub track_token: tt::TrackReference,
 }
@@ -293,7 +293,7 @@ impl Validate for GetTrackConfiguration {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct GetTrackConfigurationResponse {
-    // Configuration of the track.
+    /// Configuration of the track.
     #[yaserde(prefix = "trc", rename = "TrackConfiguration")]
     pub track_configuration: tt::TrackConfiguration,
 }
@@ -306,15 +306,15 @@ impl Validate for GetTrackConfigurationResponse {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct SetTrackConfiguration {
-    // Token of the recording the track belongs to.
+    /// Token of the recording the track belongs to.
     #[yaserde(prefix = "trc", rename = "RecordingToken")]
     pub recording_token: tt::RecordingReference,
 
-    // Token of the track to be modified.
+    /// Token of the track to be modified.
     #[yaserde(prefix = "trc", rename = "TrackToken")]
     pub track_token: tt::TrackReference,
 
-    // New configuration for the track.
+    /// New configuration for the track.
     #[yaserde(prefix = "trc", rename = "TrackConfiguration")]
     pub track_configuration: tt::TrackConfiguration,
 }
@@ -336,7 +336,7 @@ impl Validate for SetTrackConfigurationResponse {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct CreateRecordingJob {
-    // The initial configuration of the new recording job.
+    /// The initial configuration of the new recording job.
     #[yaserde(prefix = "trc", rename = "JobConfiguration")]
     pub job_configuration: tt::RecordingJobConfiguration,
 }
@@ -349,13 +349,13 @@ impl Validate for CreateRecordingJob {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct CreateRecordingJobResponse {
-    // The JobToken shall identify the created recording job.
+    /// The JobToken shall identify the created recording job.
     #[yaserde(prefix = "trc", rename = "JobToken")]
     pub job_token: tt::RecordingJobReference,
 
-    // The JobConfiguration structure shall be the configuration as it is used
-    // by the device. This may be different from the
-    // JobConfiguration passed to CreateRecordingJob.
+    /// The JobConfiguration structure shall be the configuration as it is used
+    /// by the device. This may be different from the
+    /// JobConfiguration passed to CreateRecordingJob.
     #[yaserde(prefix = "trc", rename = "JobConfiguration")]
     pub job_configuration: tt::RecordingJobConfiguration,
 }
@@ -368,7 +368,7 @@ impl Validate for CreateRecordingJobResponse {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct DeleteRecordingJob {
-    // The token of the job to be deleted.
+    /// The token of the job to be deleted.
     #[yaserde(prefix = "trc", rename = "JobToken")]
     pub job_token: tt::RecordingJobReference,
 }
@@ -399,7 +399,7 @@ impl Validate for GetRecordingJobs {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct GetRecordingJobsResponse {
-    // List of recording jobs.
+    /// List of recording jobs.
     #[yaserde(prefix = "trc", rename = "JobItem")]
     pub job_item: Vec<tt::GetRecordingJobsResponseItem>,
 }
@@ -412,11 +412,11 @@ impl Validate for GetRecordingJobsResponse {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct SetRecordingJobConfiguration {
-    // Token of the job to be modified.
+    /// Token of the job to be modified.
     #[yaserde(prefix = "trc", rename = "JobToken")]
     pub job_token: tt::RecordingJobReference,
 
-    // New configuration of the recording job.
+    /// New configuration of the recording job.
     #[yaserde(prefix = "trc", rename = "JobConfiguration")]
     pub job_configuration: tt::RecordingJobConfiguration,
 }
@@ -429,9 +429,9 @@ impl Validate for SetRecordingJobConfiguration {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct SetRecordingJobConfigurationResponse {
-    // The JobConfiguration structure shall be the configuration
-    // as it is used by the device. This may be different from the
-    // JobConfiguration passed to SetRecordingJobConfiguration.
+    /// The JobConfiguration structure shall be the configuration
+    /// as it is used by the device. This may be different from the
+    /// JobConfiguration passed to SetRecordingJobConfiguration.
     #[yaserde(prefix = "trc", rename = "JobConfiguration")]
     pub job_configuration: tt::RecordingJobConfiguration,
 }
@@ -444,7 +444,7 @@ impl Validate for SetRecordingJobConfigurationResponse {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct GetRecordingJobConfiguration {
-    // Token of the recording job.
+    /// Token of the recording job.
     #[yaserde(prefix = "trc", rename = "JobToken")]
     pub job_token: tt::RecordingJobReference,
 }
@@ -457,7 +457,7 @@ impl Validate for GetRecordingJobConfiguration {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct GetRecordingJobConfigurationResponse {
-    // Current configuration of the recording job.
+    /// Current configuration of the recording job.
     #[yaserde(prefix = "trc", rename = "JobConfiguration")]
     pub job_configuration: tt::RecordingJobConfiguration,
 }
@@ -470,11 +470,11 @@ impl Validate for GetRecordingJobConfigurationResponse {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct SetRecordingJobMode {
-    // Token of the recording job.
+    /// Token of the recording job.
     #[yaserde(prefix = "trc", rename = "JobToken")]
     pub job_token: tt::RecordingJobReference,
 
-    // The new mode for the recording job.
+    /// The new mode for the recording job.
     #[yaserde(prefix = "trc", rename = "Mode")]
     pub mode: tt::RecordingJobMode,
 }
@@ -496,7 +496,7 @@ impl Validate for SetRecordingJobModeResponse {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct GetRecordingJobState {
-    // Token of the recording job.
+    /// Token of the recording job.
     #[yaserde(prefix = "trc", rename = "JobToken")]
     pub job_token: tt::RecordingJobReference,
 }
@@ -509,7 +509,7 @@ impl Validate for GetRecordingJobState {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct GetRecordingJobStateResponse {
-    // The current state of the recording job.
+    /// The current state of the recording job.
     #[yaserde(prefix = "trc", rename = "State")]
     pub state: tt::RecordingJobStateInformation,
 }
@@ -522,7 +522,7 @@ impl Validate for GetRecordingJobStateResponse {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct GetRecordingOptions {
-    // Token of the recording.
+    /// Token of the recording.
     #[yaserde(prefix = "trc", rename = "RecordingToken")]
     pub recording_token: tt::RecordingReference,
 }
@@ -535,7 +535,7 @@ impl Validate for GetRecordingOptions {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct GetRecordingOptionsResponse {
-    // Configuration of the recording.
+    /// Configuration of the recording.
     #[yaserde(prefix = "trc", rename = "Options")]
     pub options: RecordingOptions,
 }
@@ -548,23 +548,23 @@ impl Validate for GetRecordingOptionsResponse {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct ExportRecordedData {
-    // Optional parameter that specifies start time for the exporting.
+    /// Optional parameter that specifies start time for the exporting.
     #[yaserde(prefix = "trc", rename = "StartPoint")]
     pub start_point: Option<xs::DateTime>,
 
-    // Optional parameter that specifies end time for the exporting.
+    /// Optional parameter that specifies end time for the exporting.
     #[yaserde(prefix = "trc", rename = "EndPoint")]
     pub end_point: Option<xs::DateTime>,
 
-    // Indicates the selection criterion on the existing recordings. .
+    /// Indicates the selection criterion on the existing recordings. .
     #[yaserde(prefix = "trc", rename = "SearchScope")]
     pub search_scope: tt::SearchScope,
 
-    // Indicates which export file format to be used.
+    /// Indicates which export file format to be used.
     #[yaserde(prefix = "trc", rename = "FileFormat")]
     pub file_format: String,
 
-    // Indicates the target storage and relative directory path.
+    /// Indicates the target storage and relative directory path.
     #[yaserde(prefix = "trc", rename = "StorageDestination")]
     pub storage_destination: tt::StorageReferencePath,
 }
@@ -577,12 +577,12 @@ impl Validate for ExportRecordedData {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct ExportRecordedDataResponse {
-    // Unique operation token for client to associate the relevant events.
+    /// Unique operation token for client to associate the relevant events.
     #[yaserde(prefix = "trc", rename = "OperationToken")]
     pub operation_token: tt::ReferenceToken,
 
-    // List of exported file names. The device can also use
-    // AsyncronousOperationStatus event to publish this list.
+    /// List of exported file names. The device can also use
+    /// AsyncronousOperationStatus event to publish this list.
     #[yaserde(prefix = "trc", rename = "FileNames")]
     pub file_names: Vec<String>,
 
@@ -611,7 +611,7 @@ pub mod export_recorded_data_response {
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct StopExportRecordedData {
-    // Unique ExportRecordedData operation token
+    /// Unique ExportRecordedData operation token
     #[yaserde(prefix = "trc", rename = "OperationToken")]
     pub operation_token: tt::ReferenceToken,
 }
@@ -624,7 +624,7 @@ impl Validate for StopExportRecordedData {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct StopExportRecordedDataResponse {
-    // Progress percentage of ExportRecordedData operation.
+    /// Progress percentage of ExportRecordedData operation.
     #[yaserde(prefix = "trc", rename = "Progress")]
     pub progress: f64,
 
@@ -640,7 +640,7 @@ impl Validate for StopExportRecordedDataResponse {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct GetExportRecordedDataState {
-    // Unique ExportRecordedData operation token
+    /// Unique ExportRecordedData operation token
     #[yaserde(prefix = "trc", rename = "OperationToken")]
     pub operation_token: tt::ReferenceToken,
 }
@@ -653,7 +653,7 @@ impl Validate for GetExportRecordedDataState {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct GetExportRecordedDataStateResponse {
-    // Progress percentage of ExportRecordedData operation.
+    /// Progress percentage of ExportRecordedData operation.
     #[yaserde(prefix = "trc", rename = "Progress")]
     pub progress: f64,
 
@@ -684,13 +684,13 @@ impl Validate for RecordingOptions {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct JobOptions {
-    // Number of spare jobs that can be created for the recording.
+    /// Number of spare jobs that can be created for the recording.
     #[yaserde(attribute, rename = "Spare")]
     pub spare: Option<i32>,
 
-    // A device that supports recording of a restricted set of Media/Media2
-    // Service Profiles returns the list of profiles that can be recorded on the
-    // given Recording.
+    /// A device that supports recording of a restricted set of Media/Media2
+    /// Service Profiles returns the list of profiles that can be recorded on the
+    /// given Recording.
     #[yaserde(attribute, rename = "CompatibleSources")]
     pub compatible_sources: Option<tt::StringAttrList>,
 }
@@ -703,27 +703,27 @@ impl Validate for JobOptions {}
     namespace = "trc: http://www.onvif.org/ver10/recording/wsdl"
 )]
 pub struct TrackOptions {
-    // Total spare number of tracks that can be added to this recording.
+    /// Total spare number of tracks that can be added to this recording.
     #[yaserde(attribute, rename = "SpareTotal")]
     pub spare_total: Option<i32>,
 
-    // Number of spare Video tracks that can be added to this recording.
+    /// Number of spare Video tracks that can be added to this recording.
     #[yaserde(attribute, rename = "SpareVideo")]
     pub spare_video: Option<i32>,
 
-    // Number of spare Aduio tracks that can be added to this recording.
+    /// Number of spare Aduio tracks that can be added to this recording.
     #[yaserde(attribute, rename = "SpareAudio")]
     pub spare_audio: Option<i32>,
 
-    // Number of spare Metadata tracks that can be added to this recording.
+    /// Number of spare Metadata tracks that can be added to this recording.
     #[yaserde(attribute, rename = "SpareMetadata")]
     pub spare_metadata: Option<i32>,
 }
 
 impl Validate for TrackOptions {}
 
-// Returns the capabilities of the recording service. The result is returned in
-// a typed answer.
+/// Returns the capabilities of the recording service. The result is returned in
+/// a typed answer.
 pub async fn get_service_capabilities<T: transport::Transport>(
     transport: &T,
     request: &GetServiceCapabilities,
@@ -731,9 +731,9 @@ pub async fn get_service_capabilities<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// CreateRecording shall create a new recording. The new recording shall be
-// created with a track
-// for each supported TrackType see Recording Control Spec.
+/// CreateRecording shall create a new recording. The new recording shall be
+/// created with a track
+/// for each supported TrackType see Recording Control Spec.
 pub async fn create_recording<T: transport::Transport>(
     transport: &T,
     request: &CreateRecording,
@@ -741,17 +741,17 @@ pub async fn create_recording<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// DeleteRecording shall delete a recording object. Whenever a recording is
-// deleted, the device
-// shall delete all the tracks that are part of the recording, and it shall
-// delete all the Recording
-// Jobs that record into the recording. For each deleted recording job, the
-// device shall also
-// delete all the receiver objects associated with the recording job that are
-// automatically created
-// using the AutoCreateReceiver field of the recording job configuration
-// structure and are not
-// used in any other recording job.
+/// DeleteRecording shall delete a recording object. Whenever a recording is
+/// deleted, the device
+/// shall delete all the tracks that are part of the recording, and it shall
+/// delete all the Recording
+/// Jobs that record into the recording. For each deleted recording job, the
+/// device shall also
+/// delete all the receiver objects associated with the recording job that are
+/// automatically created
+/// using the AutoCreateReceiver field of the recording job configuration
+/// structure and are not
+/// used in any other recording job.
 pub async fn delete_recording<T: transport::Transport>(
     transport: &T,
     request: &DeleteRecording,
@@ -759,9 +759,9 @@ pub async fn delete_recording<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// GetRecordings shall return a description of all the recordings in the device.
-// This description
-// shall include a list of all the tracks for each recording.
+/// GetRecordings shall return a description of all the recordings in the device.
+/// This description
+/// shall include a list of all the tracks for each recording.
 pub async fn get_recordings<T: transport::Transport>(
     transport: &T,
     request: &GetRecordings,
@@ -769,7 +769,7 @@ pub async fn get_recordings<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// SetRecordingConfiguration shall change the configuration of a recording.
+/// SetRecordingConfiguration shall change the configuration of a recording.
 pub async fn set_recording_configuration<T: transport::Transport>(
     transport: &T,
     request: &SetRecordingConfiguration,
@@ -777,8 +777,8 @@ pub async fn set_recording_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// GetRecordingConfiguration shall retrieve the recording configuration for a
-// recording.
+/// GetRecordingConfiguration shall retrieve the recording configuration for a
+/// recording.
 pub async fn get_recording_configuration<T: transport::Transport>(
     transport: &T,
     request: &GetRecordingConfiguration,
@@ -786,9 +786,9 @@ pub async fn get_recording_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// GetRecordingOptions returns information for a recording identified by the
-// RecordingToken. The information includes the number of additonal tracks as
-// well as recording jobs that can be configured.
+/// GetRecordingOptions returns information for a recording identified by the
+/// RecordingToken. The information includes the number of additonal tracks as
+/// well as recording jobs that can be configured.
 pub async fn get_recording_options<T: transport::Transport>(
     transport: &T,
     request: &GetRecordingOptions,
@@ -796,7 +796,7 @@ pub async fn get_recording_options<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// This method shall create a new track within a recording.
+/// This method shall create a new track within a recording.
 pub async fn create_track<T: transport::Transport>(
     transport: &T,
     request: &CreateTrack,
@@ -804,8 +804,8 @@ pub async fn create_track<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// DeleteTrack shall remove a track from a recording. All the data in the track
-// shall be deleted.
+/// DeleteTrack shall remove a track from a recording. All the data in the track
+/// shall be deleted.
 pub async fn delete_track<T: transport::Transport>(
     transport: &T,
     request: &DeleteTrack,
@@ -813,7 +813,7 @@ pub async fn delete_track<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// GetTrackConfiguration shall retrieve the configuration for a specific track.
+/// GetTrackConfiguration shall retrieve the configuration for a specific track.
 pub async fn get_track_configuration<T: transport::Transport>(
     transport: &T,
     request: &GetTrackConfiguration,
@@ -821,7 +821,7 @@ pub async fn get_track_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// SetTrackConfiguration shall change the configuration of a track.
+/// SetTrackConfiguration shall change the configuration of a track.
 pub async fn set_track_configuration<T: transport::Transport>(
     transport: &T,
     request: &SetTrackConfiguration,
@@ -829,7 +829,7 @@ pub async fn set_track_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// CreateRecordingJob shall create a new recording job.
+/// CreateRecordingJob shall create a new recording job.
 pub async fn create_recording_job<T: transport::Transport>(
     transport: &T,
     request: &CreateRecordingJob,
@@ -837,13 +837,13 @@ pub async fn create_recording_job<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// DeleteRecordingJob removes a recording job. It shall also implicitly delete
-// all the receiver
-// objects associated with the recording job that are automatically created
-// using the
-// AutoCreateReceiver field of the recording job configuration structure and are
-// not used in any
-// other recording job.
+/// DeleteRecordingJob removes a recording job. It shall also implicitly delete
+/// all the receiver
+/// objects associated with the recording job that are automatically created
+/// using the
+/// AutoCreateReceiver field of the recording job configuration structure and are
+/// not used in any
+/// other recording job.
 pub async fn delete_recording_job<T: transport::Transport>(
     transport: &T,
     request: &DeleteRecordingJob,
@@ -851,7 +851,7 @@ pub async fn delete_recording_job<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// GetRecordingJobs shall return a list of all the recording jobs in the device.
+/// GetRecordingJobs shall return a list of all the recording jobs in the device.
 pub async fn get_recording_jobs<T: transport::Transport>(
     transport: &T,
     request: &GetRecordingJobs,
@@ -859,8 +859,8 @@ pub async fn get_recording_jobs<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// SetRecordingJobConfiguration shall change the configuration for a recording
-// job.
+/// SetRecordingJobConfiguration shall change the configuration for a recording
+/// job.
 pub async fn set_recording_job_configuration<T: transport::Transport>(
     transport: &T,
     request: &SetRecordingJobConfiguration,
@@ -868,8 +868,8 @@ pub async fn set_recording_job_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// GetRecordingJobConfiguration shall return the current configuration for a
-// recording job.
+/// GetRecordingJobConfiguration shall return the current configuration for a
+/// recording job.
 pub async fn get_recording_job_configuration<T: transport::Transport>(
     transport: &T,
     request: &GetRecordingJobConfiguration,
@@ -877,11 +877,11 @@ pub async fn get_recording_job_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// SetRecordingJobMode shall change the mode of the recording job. Using this
-// method shall be
-// equivalent to retrieving the recording job configuration, and writing it back
-// with a different
-// mode.
+/// SetRecordingJobMode shall change the mode of the recording job. Using this
+/// method shall be
+/// equivalent to retrieving the recording job configuration, and writing it back
+/// with a different
+/// mode.
 pub async fn set_recording_job_mode<T: transport::Transport>(
     transport: &T,
     request: &SetRecordingJobMode,
@@ -889,9 +889,9 @@ pub async fn set_recording_job_mode<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// GetRecordingJobState returns the state of a recording job. It includes an
-// aggregated state,
-// and state for each track of the recording job.
+/// GetRecordingJobState returns the state of a recording job. It includes an
+/// aggregated state,
+/// and state for each track of the recording job.
 pub async fn get_recording_job_state<T: transport::Transport>(
     transport: &T,
     request: &GetRecordingJobState,
@@ -899,8 +899,8 @@ pub async fn get_recording_job_state<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Exports the selected recordings (from existing recorded data) to the given
-// storage target based on the requested file format.
+/// Exports the selected recordings (from existing recorded data) to the given
+/// storage target based on the requested file format.
 pub async fn export_recorded_data<T: transport::Transport>(
     transport: &T,
     request: &ExportRecordedData,
@@ -908,7 +908,7 @@ pub async fn export_recorded_data<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Stops the selected ExportRecordedData operation.
+/// Stops the selected ExportRecordedData operation.
 pub async fn stop_export_recorded_data<T: transport::Transport>(
     transport: &T,
     request: &StopExportRecordedData,
@@ -916,7 +916,7 @@ pub async fn stop_export_recorded_data<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Retrieves the status of selected ExportRecordedData operation.
+/// Retrieves the status of selected ExportRecordedData operation.
 pub async fn get_export_recorded_data_state<T: transport::Transport>(
     transport: &T,
     request: &GetExportRecordedDataState,
@@ -17,8 +17,8 @@ impl Validate for GetServiceCapabilities {}
     namespace = "trp: http://www.onvif.org/ver10/replay/wsdl"
 )]
 pub struct GetServiceCapabilitiesResponse {
-    // The capabilities for the replay service is returned in the Capabilities
-    // element.
+    /// The capabilities for the replay service is returned in the Capabilities
+    /// element.
     #[yaserde(prefix = "trp", rename = "Capabilities")]
     pub capabilities: Capabilities,
 }
@@ -31,23 +31,23 @@ impl Validate for GetServiceCapabilitiesResponse {}
     namespace = "trp: http://www.onvif.org/ver10/replay/wsdl"
 )]
 pub struct Capabilities {
-    // Indicator that the Device supports reverse playback as defined in the
-    // ONVIF Streaming Specification.
+    /// Indicator that the Device supports reverse playback as defined in the
+    /// ONVIF Streaming Specification.
     #[yaserde(attribute, rename = "ReversePlayback")]
     pub reverse_playback: Option<bool>,
 
-    // The list contains two elements defining the minimum and maximum valid
-    // values supported as session timeout in seconds.
+    /// The list contains two elements defining the minimum and maximum valid
+    /// values supported as session timeout in seconds.
     #[yaserde(attribute, rename = "SessionTimeoutRange")]
     pub session_timeout_range: Option<tt::FloatAttrList>,
 
-    // Indicates support for RTP/RTSP/TCP.
+    /// Indicates support for RTP/RTSP/TCP.
     #[yaserde(attribute, rename = "RTP_RTSP_TCP")]
     pub rtp_rtsp_tcp: Option<bool>,
 
-    // If playback streaming over WebSocket is supported, this shall return the
-    // RTSP WebSocket URI as described in Streaming Specification Section
-    // 5.1.1.5.
+    /// If playback streaming over WebSocket is supported, this shall return the
+    /// RTSP WebSocket URI as described in Streaming Specification Section
+    /// 5.1.1.5.
     #[yaserde(attribute, rename = "RTSPWebSocketUri")]
     pub rtsp_web_socket_uri: Option<String>,
 }
@@ -61,12 +61,12 @@ impl Validate for Capabilities {}
     namespace = "trp: http://www.onvif.org/ver10/replay/wsdl"
 )]
 pub struct GetReplayUri {
-    // Specifies the connection parameters to be used for the stream. The URI
-    // that is returned may depend on these parameters.
+    /// Specifies the connection parameters to be used for the stream. The URI
+    /// that is returned may depend on these parameters.
     #[yaserde(prefix = "trp", rename = "StreamSetup")]
     pub stream_setup: tt::StreamSetup,
 
-    // The identifier of the recording to be streamed.
+    /// The identifier of the recording to be streamed.
     #[yaserde(prefix = "trp", rename = "RecordingToken")]
     pub recording_token: tt::ReferenceToken,
 }
@@ -79,8 +79,8 @@ impl Validate for GetReplayUri {}
     namespace = "trp: http://www.onvif.org/ver10/replay/wsdl"
 )]
 pub struct GetReplayUriResponse {
-    // The URI to which the client should connect in order to stream the
-    // recording.
+    /// The URI to which the client should connect in order to stream the
+    /// recording.
     #[yaserde(prefix = "trp", rename = "Uri")]
     pub uri: String,
 }
@@ -93,7 +93,7 @@ impl Validate for GetReplayUriResponse {}
     namespace = "trp: http://www.onvif.org/ver10/replay/wsdl"
 )]
 pub struct SetReplayConfiguration {
-    // Description of the new replay configuration parameters.
+    /// Description of the new replay configuration parameters.
     #[yaserde(prefix = "trp", rename = "Configuration")]
     pub configuration: tt::ReplayConfiguration,
 }
@@ -124,15 +124,15 @@ impl Validate for GetReplayConfiguration {}
     namespace = "trp: http://www.onvif.org/ver10/replay/wsdl"
 )]
 pub struct GetReplayConfigurationResponse {
-    // The current replay configuration parameters.
+    /// The current replay configuration parameters.
     #[yaserde(prefix = "trp", rename = "Configuration")]
     pub configuration: tt::ReplayConfiguration,
 }
 
 impl Validate for GetReplayConfigurationResponse {}
 
-// Returns the capabilities of the replay service. The result is returned in a
-// typed answer.
+/// Returns the capabilities of the replay service. The result is returned in a
+/// typed answer.
 pub async fn get_service_capabilities<T: transport::Transport>(
     transport: &T,
     request: &GetServiceCapabilities,
@@ -140,10 +140,10 @@ pub async fn get_service_capabilities<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Requests a URI that can be used to initiate playback of a recorded stream
-// using RTSP as the control protocol. The URI is valid only as it is
-// specified in the response.
-// This operation is mandatory.
+/// Requests a URI that can be used to initiate playback of a recorded stream
+/// using RTSP as the control protocol. The URI is valid only as it is
+/// specified in the response.
+/// This operation is mandatory.
 pub async fn get_replay_uri<T: transport::Transport>(
     transport: &T,
     request: &GetReplayUri,
@@ -151,8 +151,8 @@ pub async fn get_replay_uri<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Returns the current configuration of the replay service.
-// This operation is mandatory.
+/// Returns the current configuration of the replay service.
+/// This operation is mandatory.
 pub async fn get_replay_configuration<T: transport::Transport>(
     transport: &T,
     request: &GetReplayConfiguration,
@@ -160,8 +160,8 @@ pub async fn get_replay_configuration<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Changes the current configuration of the replay service.
-// This operation is mandatory.
+/// Changes the current configuration of the replay service.
+/// This operation is mandatory.
 pub async fn set_replay_configuration<T: transport::Transport>(
     transport: &T,
     request: &SetReplayConfiguration,
@@ -18,8 +18,8 @@ impl Validate for GetServiceCapabilities {}
     namespace = "tse: http://www.onvif.org/ver10/search/wsdl"
 )]
 pub struct GetServiceCapabilitiesResponse {
-    // The capabilities for the search service is returned in the Capabilities
-    // element.
+    /// The capabilities for the search service is returned in the Capabilities
+    /// element.
     #[yaserde(prefix = "tse", rename = "Capabilities")]
     pub capabilities: Capabilities,
 }
@@ -35,8 +35,8 @@ pub struct Capabilities {
     #[yaserde(attribute, rename = "MetadataSearch")]
     pub metadata_search: Option<bool>,
 
-    // Indicates support for general virtual property events in the FindEvents
-    // method.
+    /// Indicates support for general virtual property events in the FindEvents
+    /// method.
     #[yaserde(attribute, rename = "GeneralStartEvents")]
     pub general_start_events: Option<bool>,
 }
@@ -122,19 +122,19 @@ impl Validate for GetMediaAttributesResponse {}
     namespace = "tse: http://www.onvif.org/ver10/search/wsdl"
 )]
 pub struct FindRecordings {
-    // Scope defines the dataset to consider for this search.
+    /// Scope defines the dataset to consider for this search.
     #[yaserde(prefix = "tse", rename = "Scope")]
     pub scope: tt::SearchScope,
 
-    // The search will be completed after this many matches. If not specified,
-    // the search will continue until reaching the endpoint or until the session
-    // expires.
+    /// The search will be completed after this many matches. If not specified,
+    /// the search will continue until reaching the endpoint or until the session
+    /// expires.
     #[yaserde(prefix = "tse", rename = "MaxMatches")]
     pub max_matches: Option<i32>,
 
-    // The time the search session will be kept alive after responding to this
-    // and subsequent requests. A device shall support at least values up to ten
-    // seconds.
+    /// The time the search session will be kept alive after responding to this
+    /// and subsequent requests. A device shall support at least values up to ten
+    /// seconds.
     #[yaserde(prefix = "tse", rename = "KeepAliveTime")]
     pub keep_alive_time: xs::Duration,
 }
@@ -159,20 +159,20 @@ impl Validate for FindRecordingsResponse {}
     namespace = "tse: http://www.onvif.org/ver10/search/wsdl"
 )]
 pub struct GetRecordingSearchResults {
-    // The search session to get results from.
+    /// The search session to get results from.
     #[yaserde(prefix = "tse", rename = "SearchToken")]
     pub search_token: tt::JobToken,
 
-    // The minimum number of results to return in one response.
+    /// The minimum number of results to return in one response.
     #[yaserde(prefix = "tse", rename = "MinResults")]
     pub min_results: Option<i32>,
 
-    // The maximum number of results to return in one response.
+    /// The maximum number of results to return in one response.
     #[yaserde(prefix = "tse", rename = "MaxResults")]
     pub max_results: Option<i32>,
 
-    // The maximum time before responding to the request, even if the MinResults
-    // parameter is not fulfilled.
+    /// The maximum time before responding to the request, even if the MinResults
+    /// parameter is not fulfilled.
     #[yaserde(prefix = "tse", rename = "WaitTime")]
     pub wait_time: Option<xs::Duration>,
 }
@@ -197,12 +197,12 @@ impl Validate for GetRecordingSearchResultsResponse {}
     namespace = "tse: http://www.onvif.org/ver10/search/wsdl"
 )]
 pub struct FindEvents {
-    // The point of time where the search will start.
+    /// The point of time where the search will start.
     #[yaserde(prefix = "tse", rename = "StartPoint")]
     pub start_point: xs::DateTime,
 
-    // The point of time where the search will stop. This can be a time before
-    // the StartPoint, in which case the search is performed backwards in time.
+    /// The point of time where the search will stop. This can be a time before
+    /// the StartPoint, in which case the search is performed backwards in time.
     #[yaserde(prefix = "tse", rename = "EndPoint")]
     pub end_point: Option<xs::DateTime>,
 
@@ -212,22 +212,22 @@ pub struct FindEvents {
     #[yaserde(prefix = "tse", rename = "SearchFilter")]
     pub search_filter: tt::EventFilter,
 
-    // Setting IncludeStartState to true means that the server should return
-    // virtual events representing the start state for any recording included in
-    // the scope. Start state events are limited to the topics defined in the
-    // SearchFilter that have the IsProperty flag set to true.
+    /// Setting IncludeStartState to true means that the server should return
+    /// virtual events representing the start state for any recording included in
+    /// the scope. Start state events are limited to the topics defined in the
+    /// SearchFilter that have the IsProperty flag set to true.
     #[yaserde(prefix = "tse", rename = "IncludeStartState")]
     pub include_start_state: bool,
 
-    // The search will be completed after this many matches. If not specified,
-    // the search will continue until reaching the endpoint or until the session
-    // expires.
+    /// The search will be completed after this many matches. If not specified,
+    /// the search will continue until reaching the endpoint or until the session
+    /// expires.
     #[yaserde(prefix = "tse", rename = "MaxMatches")]
     pub max_matches: Option<i32>,
 
-    // The time the search session will be kept alive after responding to this
-    // and subsequent requests. A device shall support at least values up to ten
-    // seconds.
+    /// The time the search session will be kept alive after responding to this
+    /// and subsequent requests. A device shall support at least values up to ten
+    /// seconds.
     #[yaserde(prefix = "tse", rename = "KeepAliveTime")]
     pub keep_alive_time: xs::Duration,
 }
@@ -240,7 +240,7 @@ impl Validate for FindEvents {}
     namespace = "tse: http://www.onvif.org/ver10/search/wsdl"
 )]
 pub struct FindEventsResponse {
-    // A unique reference to the search session created by this request.
+    /// A unique reference to the search session created by this request.
     #[yaserde(prefix = "tse", rename = "SearchToken")]
     pub search_token: tt::JobToken,
 }
@@ -253,20 +253,20 @@ impl Validate for FindEventsResponse {}
     namespace = "tse: http://www.onvif.org/ver10/search/wsdl"
 )]
 pub struct GetEventSearchResults {
-    // The search session to get results from.
+    /// The search session to get results from.
     #[yaserde(prefix = "tse", rename = "SearchToken")]
     pub search_token: tt::JobToken,
 
-    // The minimum number of results to return in one response.
+    /// The minimum number of results to return in one response.
     #[yaserde(prefix = "tse", rename = "MinResults")]
     pub min_results: Option<i32>,
 
-    // The maximum number of results to return in one response.
+    /// The maximum number of results to return in one response.
     #[yaserde(prefix = "tse", rename = "MaxResults")]
     pub max_results: Option<i32>,
 
-    // The maximum time before responding to the request, even if the MinResults
-    // parameter is not fulfilled.
+    /// The maximum time before responding to the request, even if the MinResults
+    /// parameter is not fulfilled.
     #[yaserde(prefix = "tse", rename = "WaitTime")]
     pub wait_time: Option<xs::Duration>,
 }
@@ -291,12 +291,12 @@ impl Validate for GetEventSearchResultsResponse {}
     namespace = "tse: http://www.onvif.org/ver10/search/wsdl"
 )]
 pub struct FindPTZPosition {
-    // The point of time where the search will start.
+    /// The point of time where the search will start.
     #[yaserde(prefix = "tse", rename = "StartPoint")]
     pub start_point: xs::DateTime,
 
-    // The point of time where the search will stop. This can be a time before
-    // the StartPoint, in which case the search is performed backwards in time.
+    /// The point of time where the search will stop. This can be a time before
+    /// the StartPoint, in which case the search is performed backwards in time.
     #[yaserde(prefix = "tse", rename = "EndPoint")]
     pub end_point: Option<xs::DateTime>,
 
@@ -306,15 +306,15 @@ pub struct FindPTZPosition {
     #[yaserde(prefix = "tse", rename = "SearchFilter")]
     pub search_filter: tt::PtzpositionFilter,
 
-    // The search will be completed after this many matches. If not specified,
-    // the search will continue until reaching the endpoint or until the session
-    // expires.
+    /// The search will be completed after this many matches. If not specified,
+    /// the search will continue until reaching the endpoint or until the session
+    /// expires.
     #[yaserde(prefix = "tse", rename = "MaxMatches")]
     pub max_matches: Option<i32>,
 
-    // The time the search session will be kept alive after responding to this
-    // and subsequent requests. A device shall support at least values up to ten
-    // seconds.
+    /// The time the search session will be kept alive after responding to this
+    /// and subsequent requests. A device shall support at least values up to ten
+    /// seconds.
     #[yaserde(prefix = "tse", rename = "KeepAliveTime")]
     pub keep_alive_time: xs::Duration,
 }
@@ -327,7 +327,7 @@ impl Validate for FindPTZPosition {}
     namespace = "tse: http://www.onvif.org/ver10/search/wsdl"
 )]
 pub struct FindPTZPositionResponse {
-    // A unique reference to the search session created by this request.
+    /// A unique reference to the search session created by this request.
     #[yaserde(prefix = "tse", rename = "SearchToken")]
     pub search_token: tt::JobToken,
 }
@@ -340,20 +340,20 @@ impl Validate for FindPTZPositionResponse {}
     namespace = "tse: http://www.onvif.org/ver10/search/wsdl"
 )]
 pub struct GetPTZPositionSearchResults {
-    // The search session to get results from.
+    /// The search session to get results from.
     #[yaserde(prefix = "tse", rename = "SearchToken")]
     pub search_token: tt::JobToken,
 
-    // The minimum number of results to return in one response.
+    /// The minimum number of results to return in one response.
     #[yaserde(prefix = "tse", rename = "MinResults")]
     pub min_results: Option<i32>,
 
-    // The maximum number of results to return in one response.
+    /// The maximum number of results to return in one response.
     #[yaserde(prefix = "tse", rename = "MaxResults")]
     pub max_results: Option<i32>,
 
-    // The maximum time before responding to the request, even if the MinResults
-    // parameter is not fulfilled.
+    /// The maximum time before responding to the request, even if the MinResults
+    /// parameter is not fulfilled.
     #[yaserde(prefix = "tse", rename = "WaitTime")]
     pub wait_time: Option<xs::Duration>,
 }
@@ -378,12 +378,12 @@ impl Validate for GetPTZPositionSearchResultsResponse {}
     namespace = "tse: http://www.onvif.org/ver10/search/wsdl"
 )]
 pub struct FindMetadata {
-    // The point of time where the search will start.
+    /// The point of time where the search will start.
     #[yaserde(prefix = "tse", rename = "StartPoint")]
     pub start_point: xs::DateTime,
 
-    // The point of time where the search will stop. This can be a time before
-    // the StartPoint, in which case the search is performed backwards in time.
+    /// The point of time where the search will stop. This can be a time before
+    /// the StartPoint, in which case the search is performed backwards in time.
     #[yaserde(prefix = "tse", rename = "EndPoint")]
     pub end_point: Option<xs::DateTime>,
 
@@ -393,15 +393,15 @@ pub struct FindMetadata {
     #[yaserde(prefix = "tse", rename = "MetadataFilter")]
     pub metadata_filter: tt::MetadataFilter,
 
-    // The search will be completed after this many matches. If not specified,
-    // the search will continue until reaching the endpoint or until the session
-    // expires.
+    /// The search will be completed after this many matches. If not specified,
+    /// the search will continue until reaching the endpoint or until the session
+    /// expires.
     #[yaserde(prefix = "tse", rename = "MaxMatches")]
     pub max_matches: Option<i32>,
 
-    // The time the search session will be kept alive after responding to this
-    // and subsequent requests. A device shall support at least values up to ten
-    // seconds.
+    /// The time the search session will be kept alive after responding to this
+    /// and subsequent requests. A device shall support at least values up to ten
+    /// seconds.
     #[yaserde(prefix = "tse", rename = "KeepAliveTime")]
     pub keep_alive_time: xs::Duration,
 }
@@ -414,7 +414,7 @@ impl Validate for FindMetadata {}
     namespace = "tse: http://www.onvif.org/ver10/search/wsdl"
 )]
 pub struct FindMetadataResponse {
-    // A unique reference to the search session created by this request.
+    /// A unique reference to the search session created by this request.
     #[yaserde(prefix = "tse", rename = "SearchToken")]
     pub search_token: tt::JobToken,
 }
@@ -427,20 +427,20 @@ impl Validate for FindMetadataResponse {}
     namespace = "tse: http://www.onvif.org/ver10/search/wsdl"
 )]
 pub struct GetMetadataSearchResults {
-    // The search session to get results from.
+    /// The search session to get results from.
     #[yaserde(prefix = "tse", rename = "SearchToken")]
     pub search_token: tt::JobToken,
 
-    // The minimum number of results to return in one response.
+    /// The minimum number of results to return in one response.
     #[yaserde(prefix = "tse", rename = "MinResults")]
     pub min_results: Option<i32>,
 
-    // The maximum number of results to return in one response.
+    /// The maximum number of results to return in one response.
     #[yaserde(prefix = "tse", rename = "MaxResults")]
     pub max_results: Option<i32>,
 
-    // The maximum time before responding to the request, even if the MinResults
-    // parameter is not fulfilled.
+    /// The maximum time before responding to the request, even if the MinResults
+    /// parameter is not fulfilled.
     #[yaserde(prefix = "tse", rename = "WaitTime")]
     pub wait_time: Option<xs::Duration>,
 }
@@ -465,7 +465,7 @@ impl Validate for GetMetadataSearchResultsResponse {}
     namespace = "tse: http://www.onvif.org/ver10/search/wsdl"
 )]
 pub struct GetSearchState {
-    // The search session to get the state from.
+    /// The search session to get the state from.
     #[yaserde(prefix = "tse", rename = "SearchToken")]
     pub search_token: tt::JobToken,
 }
@@ -490,7 +490,7 @@ impl Validate for GetSearchStateResponse {}
     namespace = "tse: http://www.onvif.org/ver10/search/wsdl"
 )]
 pub struct EndSearch {
-    // The search session to end.
+    /// The search session to end.
     #[yaserde(prefix = "tse", rename = "SearchToken")]
     pub search_token: tt::JobToken,
 }
@@ -503,16 +503,16 @@ impl Validate for EndSearch {}
     namespace = "tse: http://www.onvif.org/ver10/search/wsdl"
 )]
 pub struct EndSearchResponse {
-    // The point of time the search had reached when it was ended. It is equal
-    // to the EndPoint specified in Find-operation if the search was completed.
+    /// The point of time the search had reached when it was ended. It is equal
+    /// to the EndPoint specified in Find-operation if the search was completed.
     #[yaserde(prefix = "tse", rename = "Endpoint")]
     pub endpoint: xs::DateTime,
 }
 
 impl Validate for EndSearchResponse {}
 
-// Returns the capabilities of the search service. The result is returned in a
-// typed answer.
+/// Returns the capabilities of the search service. The result is returned in a
+/// typed answer.
 pub async fn get_service_capabilities<T: transport::Transport>(
     transport: &T,
     request: &GetServiceCapabilities,
@@ -520,10 +520,10 @@ pub async fn get_service_capabilities<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// GetRecordingSummary is used to get a summary description of all recorded
-// data. This
-// operation is mandatory to support for a device implementing the recording
-// search service.
+/// GetRecordingSummary is used to get a summary description of all recorded
+/// data. This
+/// operation is mandatory to support for a device implementing the recording
+/// search service.
 pub async fn get_recording_summary<T: transport::Transport>(
     transport: &T,
     request: &GetRecordingSummary,
@@ -531,10 +531,10 @@ pub async fn get_recording_summary<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Returns information about a single Recording specified by a RecordingToken.
-// This operation
-// is mandatory to support for a device implementing the recording search
-// service.
+/// Returns information about a single Recording specified by a RecordingToken.
+/// This operation
+/// is mandatory to support for a device implementing the recording search
+/// service.
 pub async fn get_recording_information<T: transport::Transport>(
     transport: &T,
     request: &GetRecordingInformation,
@@ -542,17 +542,17 @@ pub async fn get_recording_information<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// Returns a set of media attributes for all tracks of the specified recordings
-// at a specified point
-// in time. Clients using this operation shall be able to use it as a non
-// blocking operation. A
-// device shall set the starttime and endtime of the MediaAttributes structure
-// to equal values if
-// calculating this range would causes this operation to block. See
-// MediaAttributes structure for
-// more information. This operation is mandatory to support for a device
-// implementing the
-// recording search service.
+/// Returns a set of media attributes for all tracks of the specified recordings
+/// at a specified point
+/// in time. Clients using this operation shall be able to use it as a non
+/// blocking operation. A
+/// device shall set the starttime and endtime of the MediaAttributes structure
+/// to equal values if
+/// calculating this range would causes this operation to block. See
+/// MediaAttributes structure for
+/// more information. This operation is mandatory to support for a device
+/// implementing the
+/// recording search service.
 pub async fn get_media_attributes<T: transport::Transport>(
     transport: &T,
     request: &GetMediaAttributes,
@@ -560,13 +560,13 @@ pub async fn get_media_attributes<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// FindRecordings starts a search session, looking for recordings that matches
-// the scope (See
-// 20.2.4) defined in the request. Results from the search are acquired using
-// the
-// GetRecordingSearchResults request, specifying the search token returned from
-// this request.
-// The device shall continue searching until one of the following occurs:
+/// FindRecordings starts a search session, looking for recordings that matches
+/// the scope (See
+/// 20.2.4) defined in the request. Results from the search are acquired using
+/// the
+/// GetRecordingSearchResults request, specifying the search token returned from
+/// this request.
+/// The device shall continue searching until one of the following occurs:
 pub async fn find_recordings<T: transport::Transport>(
     transport: &T,
     request: &FindRecordings,
@@ -574,16 +574,16 @@ pub async fn find_recordings<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// GetRecordingSearchResults acquires the results from a recording search
-// session previously
-// initiated by a FindRecordings operation. The response shall not include
-// results already
-// returned in previous requests for the same session. If MaxResults is
-// specified, the response
-// shall not contain more than MaxResults results. The number of results relates
-// to the number of recordings.
-// For viewing individual recorded data for a signal track use the FindEvents
-// method.
+/// GetRecordingSearchResults acquires the results from a recording search
+/// session previously
+/// initiated by a FindRecordings operation. The response shall not include
+/// results already
+/// returned in previous requests for the same session. If MaxResults is
+/// specified, the response
+/// shall not contain more than MaxResults results. The number of results relates
+/// to the number of recordings.
+/// For viewing individual recorded data for a signal track use the FindEvents
+/// method.
 pub async fn get_recording_search_results<T: transport::Transport>(
     transport: &T,
     request: &GetRecordingSearchResults,
@@ -591,12 +591,12 @@ pub async fn get_recording_search_results<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// FindEvents starts a search session, looking for recording events (in the
-// scope that
-// matches the search filter defined in the request. Results from the search are
-// acquired using the GetEventSearchResults request, specifying the search token
-// returned from
-// this request.
+/// FindEvents starts a search session, looking for recording events (in the
+/// scope that
+/// matches the search filter defined in the request. Results from the search are
+/// acquired using the GetEventSearchResults request, specifying the search token
+/// returned from
+/// this request.
 pub async fn find_events<T: transport::Transport>(
     transport: &T,
     request: &FindEvents,
@@ -604,13 +604,13 @@ pub async fn find_events<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// GetEventSearchResults acquires the results from a recording event search
-// session previously
-// initiated by a FindEvents operation. The response shall not include results
-// already returned in
-// previous requests for the same session. If MaxResults is specified, the
-// response shall not
-// contain more than MaxResults results.
+/// GetEventSearchResults acquires the results from a recording event search
+/// session previously
+/// initiated by a FindEvents operation. The response shall not include results
+/// already returned in
+/// previous requests for the same session. If MaxResults is specified, the
+/// response shall not
+/// contain more than MaxResults results.
 pub async fn get_event_search_results<T: transport::Transport>(
     transport: &T,
     request: &GetEventSearchResults,
@@ -618,13 +618,13 @@ pub async fn get_event_search_results<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// FindPTZPosition starts a search session, looking for ptz positions in the
-// scope (See 20.2.4)
-// that matches the search filter defined in the request. Results from the
-// search are acquired
-// using the GetPTZPositionSearchResults request, specifying the search token
-// returned from
-// this request.
+/// FindPTZPosition starts a search session, looking for ptz positions in the
+/// scope (See 20.2.4)
+/// that matches the search filter defined in the request. Results from the
+/// search are acquired
+/// using the GetPTZPositionSearchResults request, specifying the search token
+/// returned from
+/// this request.
 pub async fn find_ptz_position<T: transport::Transport>(
     transport: &T,
     request: &FindPTZPosition,
@@ -632,13 +632,13 @@ pub async fn find_ptz_position<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// GetPTZPositionSearchResults acquires the results from a ptz position search
-// session
-// previously initiated by a FindPTZPosition operation. The response shall not
-// include results
-// already returned in previous requests for the same session. If MaxResults is
-// specified, the
-// response shall not contain more than MaxResults results.
+/// GetPTZPositionSearchResults acquires the results from a ptz position search
+/// session
+/// previously initiated by a FindPTZPosition operation. The response shall not
+/// include results
+/// already returned in previous requests for the same session. If MaxResults is
+/// specified, the
+/// response shall not contain more than MaxResults results.
 pub async fn get_ptz_position_search_results<T: transport::Transport>(
     transport: &T,
     request: &GetPTZPositionSearchResults,
@@ -646,8 +646,8 @@ pub async fn get_ptz_position_search_results<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// GetSearchState returns the current state of the specified search session.
-// This command is deprecated .
+/// GetSearchState returns the current state of the specified search session.
+/// This command is deprecated .
 pub async fn get_search_state<T: transport::Transport>(
     transport: &T,
     request: &GetSearchState,
@@ -655,21 +655,21 @@ pub async fn get_search_state<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// EndSearch stops and ongoing search session, causing any blocking result
-// request to return
-// and the SearchToken to become invalid. If the search was interrupted before
-// completion, the
-// point in time that the search had reached shall be returned. If the search
-// had not yet begun,
-// the StartPoint shall be returned. If the search was completed the original
-// EndPoint supplied
-// by the Find operation shall be returned. When issuing EndSearch on a
-// FindRecordings request the
-// EndPoint is undefined and shall not be used since the FindRecordings request
-// doesn't have
-// StartPoint/EndPoint. This operation is mandatory to support for a device
-// implementing the
-// recording search service.
+/// EndSearch stops and ongoing search session, causing any blocking result
+/// request to return
+/// and the SearchToken to become invalid. If the search was interrupted before
+/// completion, the
+/// point in time that the search had reached shall be returned. If the search
+/// had not yet begun,
+/// the StartPoint shall be returned. If the search was completed the original
+/// EndPoint supplied
+/// by the Find operation shall be returned. When issuing EndSearch on a
+/// FindRecordings request the
+/// EndPoint is undefined and shall not be used since the FindRecordings request
+/// doesn't have
+/// StartPoint/EndPoint. This operation is mandatory to support for a device
+/// implementing the
+/// recording search service.
 pub async fn end_search<T: transport::Transport>(
     transport: &T,
     request: &EndSearch,
@@ -677,13 +677,13 @@ pub async fn end_search<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// FindMetadata starts a search session, looking for metadata in the scope (See
-// 20.2.4) that
-// matches the search filter defined in the request. Results from the search are
-// acquired using
-// the GetMetadataSearchResults request, specifying the search token returned
-// from this
-// request.
+/// FindMetadata starts a search session, looking for metadata in the scope (See
+/// 20.2.4) that
+/// matches the search filter defined in the request. Results from the search are
+/// acquired using
+/// the GetMetadataSearchResults request, specifying the search token returned
+/// from this
+/// request.
 pub async fn find_metadata<T: transport::Transport>(
     transport: &T,
     request: &FindMetadata,
@@ -691,13 +691,13 @@ pub async fn find_metadata<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// GetMetadataSearchResults acquires the results from a recording search session
-// previously
-// initiated by a FindMetadata operation. The response shall not include results
-// already returned
-// in previous requests for the same session. If MaxResults is specified, the
-// response shall not
-// contain more than MaxResults results.
+/// GetMetadataSearchResults acquires the results from a recording search session
+/// previously
+/// initiated by a FindMetadata operation. The response shall not include results
+/// already returned
+/// in previous requests for the same session. If MaxResults is specified, the
+/// response shall not
+/// contain more than MaxResults results.
 pub async fn get_metadata_search_results<T: transport::Transport>(
     transport: &T,
     request: &GetMetadataSearchResults,
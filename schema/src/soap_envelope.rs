@@ -19,8 +19,8 @@ pub struct Envelope {
 impl Validate for Envelope {}
 
 // pub type Header = Header;
-// Elements replacing the wildcard MUST be namespace qualified, but can be in
-// the targetNamespace
+/// Elements replacing the wildcard MUST be namespace qualified, but can be in
+/// the targetNamespace
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tns",
@@ -45,7 +45,7 @@ impl Validate for Body {}
 // pub type Role = String;
 // pub type EncodingStyle = String;
 // pub type Fault = Fault;
-// Fault reporting structure
+/// Fault reporting structure
 #[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
 #[yaserde(
     prefix = "tns",
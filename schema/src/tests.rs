@@ -392,6 +392,52 @@ async fn operation_get_device_information() {
     assert_eq!(resp.manufacturer, "Somebody");
 }
 
+#[tokio::test]
+async fn operation_get_storage_configurations() {
+    let req: devicemgmt::GetStorageConfigurations = Default::default();
+
+    let transport = FakeTransport {
+        response: r#"
+            <tds:GetStorageConfigurationsResponse
+                        xmlns:tds="http://www.onvif.org/ver10/device/wsdl"
+                        xmlns:tt="http://www.onvif.org/ver10/schema">
+                <tds:StorageConfigurations token="SD_000">
+                    <tds:Data type="LocalStorage">
+                        <tds:LocalPath>/mnt/sd</tds:LocalPath>
+                    </tds:Data>
+                </tds:StorageConfigurations>
+                <tds:StorageConfigurations token="NAS_000">
+                    <tds:Data type="NFS">
+                        <tds:StorageUri>nfs://192.168.0.10/recordings</tds:StorageUri>
+                        <tds:User>
+                            <tds:UserName>recorder</tds:UserName>
+                        </tds:User>
+                    </tds:Data>
+                </tds:StorageConfigurations>
+            </tds:GetStorageConfigurationsResponse>"#
+            .into(),
+    };
+
+    let resp = devicemgmt::get_storage_configurations(&transport, &req)
+        .await
+        .unwrap();
+
+    assert_eq!(resp.storage_configurations.len(), 2);
+
+    let sd = &resp.storage_configurations[0].data;
+    assert_eq!(sd._type, "LocalStorage");
+    assert_eq!(sd.local_path.as_deref(), Some("/mnt/sd"));
+    assert_eq!(sd.storage_uri, None);
+
+    let nas = &resp.storage_configurations[1].data;
+    assert_eq!(nas._type, "NFS");
+    assert_eq!(
+        nas.storage_uri.as_deref(),
+        Some("nfs://192.168.0.10/recordings")
+    );
+    assert_eq!(nas.user.as_ref().unwrap().user_name, "recorder");
+}
+
 #[test]
 fn probe_serialization() {
     let expected = r#"
@@ -484,6 +530,10 @@ fn probe_match_deserialization() {
         des.header.relates_to,
         "uuid:84ede3de-7dec-11d0-c360-F01234567890".to_string()
     );
+    assert_eq!(
+        des.body.probe_matches.probe_match[0].endpoint_reference(),
+        Some("urn:uuid:a1f48ac2-dc8b-11df-b255-00408c1836b2")
+    );
     assert_eq!(
         des.body.probe_matches.probe_match[0]
             .x_addrs
@@ -603,8 +653,9 @@ fn nested_structs_with_same_named_fields() {
 fn extension_inside_extension() {
     // `Extension` inside `Extension` causes infinite loop at deserialization
     // https://github.com/media-io/yaserde/issues/76
-    // If field `extension` in `SecurityCapabilitiesExtension` is uncommented accidentally
-    // then this test will fail. Also note that there's a bunch of such cases in `onvif.rs`.
+    // If field `extension` in `SecurityCapabilitiesExtension` is changed from `RawXml` to
+    // `SecurityCapabilitiesExtension2` accidentally then this test will fail. Also note that
+    // there's a bunch of such cases in `onvif.rs`.
     let ser = r#"
     <?xml version="1.0" encoding="utf-8"?>
     <tt:SecurityCapabilities xmlns:tt="http://www.onvif.org/ver10/schema">
@@ -617,5 +668,217 @@ fn extension_inside_extension() {
     </tt:SecurityCapabilities>
     "#;
 
-    let _ = yaserde::de::from_str::<tt::SecurityCapabilities>(ser).unwrap();
+    let des = yaserde::de::from_str::<tt::SecurityCapabilities>(ser).unwrap();
+    let extension = des.extension.unwrap();
+
+    assert!(!extension.tls1_0);
+    assert!(extension
+        .extension
+        .unwrap()
+        .as_str()
+        .contains("<tt:RemoteUserHandling>false</tt:RemoteUserHandling>"));
+}
+
+#[test]
+fn raw_extension_round_trip() {
+    let ser = r#"
+    <?xml version="1.0" encoding="utf-8"?>
+    <tt:ImagingSettings20 xmlns:tt="http://www.onvif.org/ver10/schema">
+        <tt:Brightness>50</tt:Brightness>
+        <tt:Extension>
+            <tt:Extension>
+                <vnd:Defog xmlns:vnd="http://example.com/vendor" Level="3">
+                    <vnd:Mode>Auto</vnd:Mode>
+                </vnd:Defog>
+            </tt:Extension>
+        </tt:Extension>
+    </tt:ImagingSettings20>
+    "#;
+
+    let des: tt::ImagingSettings20 = yaserde::de::from_str(ser).unwrap();
+    assert_eq!(des.brightness, Some(50.0));
+
+    let actual = yaserde::ser::to_string(&des).unwrap();
+    assert_xml_eq(actual.as_str(), ser);
+}
+
+#[test]
+fn json_rendering() {
+    use crate::json::ToJson;
+
+    let response = devicemgmt::GetUsersResponse {
+        user: vec![
+            tt::User {
+                username: "admin".to_string(),
+                password: None,
+                user_level: tt::UserLevel::Administrator,
+                extension: None,
+            },
+            tt::User {
+                username: "viewer".to_string(),
+                password: None,
+                user_level: tt::UserLevel::User,
+                extension: None,
+            },
+        ],
+    };
+
+    assert_eq!(
+        response.to_json().unwrap(),
+        serde_json::json!({
+            "User": [
+                { "Username": "admin", "UserLevel": "Administrator" },
+                { "Username": "viewer", "UserLevel": "User" },
+            ]
+        })
+    );
+
+    let preset = tt::Ptzpreset {
+        name: Some(tt::Name("Door".to_string())),
+        ptz_position: None,
+        token: Some(tt::ReferenceToken("1".to_string())),
+    };
+
+    assert_eq!(
+        preset.to_json().unwrap(),
+        serde_json::json!({ "token": "1", "Name": "Door" })
+    );
+
+    assert_eq!(
+        devicemgmt::CreateUsersResponse {}.to_json().unwrap(),
+        serde_json::json!({})
+    );
+}
+
+#[test]
+fn structural_diff() {
+    use crate::diff::{diff, Change};
+
+    let user = |username: &str, user_level| tt::User {
+        username: username.to_string(),
+        password: None,
+        user_level,
+        extension: None,
+    };
+    let current = devicemgmt::GetUsersResponse {
+        user: vec![user("admin", tt::UserLevel::Administrator)],
+    };
+    let desired = devicemgmt::GetUsersResponse {
+        user: vec![
+            user("admin", tt::UserLevel::Operator),
+            user("viewer", tt::UserLevel::User),
+        ],
+    };
+
+    // Keys are only in document order with the `preserve_order` feature of `serde_json`.
+    let mut changes = diff(&current, &desired).unwrap();
+    changes.sort_by(|a, b| a.path().cmp(b.path()));
+    assert_eq!(
+        changes,
+        vec![
+            Change::Changed {
+                path: "User[0].UserLevel".to_string(),
+                from: "Administrator".to_string(),
+                to: "Operator".to_string(),
+            },
+            Change::Added {
+                path: "User[1].UserLevel".to_string(),
+                value: "User".to_string(),
+            },
+            Change::Added {
+                path: "User[1].Username".to_string(),
+                value: "viewer".to_string(),
+            },
+        ]
+    );
+    assert_eq!(
+        changes[0].to_string(),
+        "User[0].UserLevel: Administrator -> Operator"
+    );
+    assert_eq!(diff(&desired, &desired).unwrap(), vec![]);
+
+    let preset = |name: Option<&str>| tt::Ptzpreset {
+        name: name.map(|name| tt::Name(name.to_string())),
+        ptz_position: None,
+        token: Some(tt::ReferenceToken("1".to_string())),
+    };
+    assert_eq!(
+        diff(&preset(Some("Door")), &preset(None)).unwrap(),
+        vec![Change::Removed {
+            path: "Name".to_string(),
+            value: "Door".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn malformed_responses_are_errors() {
+    let inputs = [
+        "",
+        "not xml",
+        "<tds:GetSystemDateAndTimeResponse",
+        r#"<tds:GetSystemDateAndTimeResponse xmlns:tds="http://www.onvif.org/ver10/device/wsdl">"#,
+        r#"<tds:GetSystemDateAndTimeResponse
+               xmlns:tt="http://www.onvif.org/ver10/schema"
+               xmlns:tds="http://www.onvif.org/ver10/device/wsdl">
+               <tds:SystemDateAndTime>
+                   <tt:DateTimeType>NTP</tt:DateTimeType>
+                   <tt:DaylightSavings>maybe</tt:DaylightSavings>
+               </tds:SystemDateAndTime>
+           </tds:GetSystemDateAndTimeResponse>"#,
+    ];
+
+    for input in inputs.iter() {
+        assert!(
+            yaserde::de::from_str::<devicemgmt::GetSystemDateAndTimeResponse>(input).is_err(),
+            "{:?} should not deserialize",
+            input
+        );
+    }
+}
+
+#[test]
+fn binary_data() {
+    let data = tt::BinaryData::from_bytes(b"\x00\x01binary\xff", Some("application/octet-stream"));
+
+    assert_eq!(data.data, "AAFiaW5hcnn/");
+    assert_eq!(data.decoded_len(), 9);
+    assert_eq!(data.to_bytes().unwrap(), b"\x00\x01binary\xff");
+    assert!(data.check_size(9).is_ok());
+    assert!(data.check_size(8).is_err());
+
+    let wrapped = tt::BinaryData {
+        data: "AAFi\n  aW5h\n  cg==".to_string(),
+        content_type: None,
+    };
+
+    assert_eq!(wrapped.decoded_len(), 7);
+    assert_eq!(wrapped.to_bytes().unwrap(), b"\x00\x01binar");
+
+    let attachment = tt::AttachmentData::new("firmware@example.com", None);
+
+    assert_eq!(attachment.include.href, "cid:firmware@example.com");
+    assert_eq!(attachment.content_id(), Some("firmware@example.com"));
+}
+
+#[test]
+fn operations_table() {
+    let all = operations::all();
+    for (i, operation) in all.iter().enumerate() {
+        assert!(!operation.action.is_empty(), "{:?}", operation);
+        assert!(
+            all[i + 1..]
+                .iter()
+                .all(|o| (o.service, o.name) != (operation.service, operation.name)),
+            "{:?} is listed twice",
+            operation
+        );
+    }
+
+    let pull = operations::by_action(
+        "http://www.onvif.org/ver10/events/wsdl/PullPointSubscription/PullMessagesRequest",
+    )
+    .unwrap();
+    assert_eq!((pull.service, pull.name), ("event", "PullMessages"));
+    assert!(operations::of_service("ptz").any(|o| o.name == "AbsoluteMove"));
 }
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use std::fmt;
 use thiserror::Error;
 use yaserde::{YaDeserialize, YaSerialize};
 
@@ -18,6 +19,14 @@ pub enum Error {
     Timeout(String),
     #[error("Protocol error: {0}")]
     Protocol(String),
+    #[error("SOAP fault: {0}")]
+    Fault(Fault),
+    /// Refused locally, without sending the request, as the device doesn't support the operation.
+    #[error("{operation} is not supported by the {service} service")]
+    Unsupported { service: String, operation: String },
+    /// Refused locally, as another device than the expected one answers at the address.
+    #[error("Expected device {expected}, found {found}")]
+    DeviceIdentityChanged { expected: String, found: String },
     #[error("Other: {0}")]
     Other(String),
 }
@@ -28,8 +37,143 @@ impl From<Error> for String {
     }
 }
 
+/// A SOAP fault returned by a device.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fault {
+    /// Values of the code and every nested subcode, outermost first, e.g.
+    /// `["env:Sender", "ter:InvalidArgVal", "ter:NoProfile"]`.
+    pub codes: Vec<String>,
+    pub reason: Option<String>,
+    pub kind: OnvifFaultKind,
+}
+
+impl Fault {
+    pub fn new(codes: Vec<String>, reason: Option<String>) -> Self {
+        let kind = OnvifFaultKind::from_codes(&codes);
+        Self {
+            codes,
+            reason,
+            kind,
+        }
+    }
+}
+
+impl fmt::Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.codes.join(" "))?;
+        match &self.reason {
+            Some(reason) => write!(f, " ({})", reason),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Well-known subcodes of ONVIF faults, in the `ter` namespace
+/// (`http://www.onvif.org/ver10/error`), see the ONVIF Core specification.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OnvifFaultKind {
+    /// `ter:InvalidArgs`, the request is malformed.
+    InvalidArgs,
+    /// `ter:InvalidArgVal`, an argument value is invalid, without a more specific subcode.
+    InvalidArgVal,
+    /// `ter:ActionNotSupported`, the device doesn't implement the operation.
+    ActionNotSupported,
+    /// `ter:NotAuthorized`, the credentials don't allow the operation.
+    NotAuthorized,
+    /// `ter:NoProfile`, the media profile doesn't exist.
+    NoProfile,
+    /// `ter:NoConfig`, the configuration doesn't exist.
+    NoConfig,
+    /// `ter:NoSource`, the video or audio source doesn't exist.
+    NoSource,
+    /// `ter:ConfigModify`, the configuration parameters aren't possible.
+    ConfigModify,
+    /// `ter:ConfigurationConflict`, the configuration conflicts with other ones in use.
+    ConfigurationConflict,
+    /// `ter:IncompleteConfiguration`, the profile lacks a configuration the operation needs,
+    /// e.g. a stream URI of a profile without video encoder.
+    IncompleteConfiguration,
+    /// `ter:InvalidStreamSetup`, the stream setup, e.g. the transport, isn't supported.
+    InvalidStreamSetup,
+    /// `ter:MaxNVTProfiles`, the device can't have more media profiles.
+    MaxProfiles,
+    /// `ter:MaxOSDs`, the device can't have more on-screen displays.
+    MaxOsds,
+    /// `ter:TooManyPresets`, the PTZ node can't have more presets.
+    TooManyPresets,
+    /// `ter:NoPTZProfile`, the media profile has no PTZ configuration.
+    NoPtzProfile,
+    /// `ter:NoEntity`, an entity such as a preset or a token doesn't exist.
+    NoEntity,
+    /// `ter:MovingPTZ`, the operation isn't possible while the PTZ unit moves.
+    MovingPtz,
+    /// Any other subcode, or the code if there are none, e.g. `env:Receiver`.
+    Other(String),
+}
+
+impl OnvifFaultKind {
+    /// The kind of a single code, with or without namespace prefix.
+    pub fn parse(code: &str) -> Self {
+        let code = code.trim();
+        let name = code.rsplit(':').next().unwrap_or(code);
+        match name {
+            "InvalidArgs" => OnvifFaultKind::InvalidArgs,
+            "InvalidArgVal" => OnvifFaultKind::InvalidArgVal,
+            "ActionNotSupported" => OnvifFaultKind::ActionNotSupported,
+            "NotAuthorized" => OnvifFaultKind::NotAuthorized,
+            "NoProfile" => OnvifFaultKind::NoProfile,
+            "NoConfig" => OnvifFaultKind::NoConfig,
+            "NoSource" => OnvifFaultKind::NoSource,
+            "ConfigModify" => OnvifFaultKind::ConfigModify,
+            "ConfigurationConflict" => OnvifFaultKind::ConfigurationConflict,
+            "IncompleteConfiguration" => OnvifFaultKind::IncompleteConfiguration,
+            "InvalidStreamSetup" => OnvifFaultKind::InvalidStreamSetup,
+            "MaxNVTProfiles" => OnvifFaultKind::MaxProfiles,
+            "MaxOSDs" => OnvifFaultKind::MaxOsds,
+            "TooManyPresets" => OnvifFaultKind::TooManyPresets,
+            "NoPTZProfile" => OnvifFaultKind::NoPtzProfile,
+            "NoEntity" => OnvifFaultKind::NoEntity,
+            "MovingPTZ" => OnvifFaultKind::MovingPtz,
+            _ => OnvifFaultKind::Other(code.to_string()),
+        }
+    }
+
+    /// The kind of a fault from its codes, outermost first. The innermost known subcode is the
+    /// most specific one, e.g. `ter:NoProfile` in `env:Sender ter:InvalidArgVal ter:NoProfile`.
+    pub fn from_codes(codes: &[String]) -> Self {
+        codes
+            .iter()
+            .rev()
+            .map(|code| OnvifFaultKind::parse(code))
+            .find(|kind| !matches!(kind, OnvifFaultKind::Other(_)))
+            .unwrap_or_else(|| OnvifFaultKind::Other(codes.last().cloned().unwrap_or_default()))
+    }
+}
+
+/// Sends requests to a service, the only thing the generated operations need.
+///
+/// Implementations may use any HTTP client and runtime. `onvif_soap::client::Client` is the
+/// tokio-based one; others can wrap requests in an envelope with `onvif_soap::soap`, e.g. adding
+/// credentials, and unwrap responses with `onvif_soap::unsoap`.
+///
+/// ```
+/// use async_trait::async_trait;
+/// use schema::transport::{Error, Transport};
+///
+/// struct Offline;
+///
+/// #[async_trait]
+/// impl Transport for Offline {
+///     async fn request(&self, message: &str) -> Result<String, Error> {
+///         Err(Error::Connection(format!("not sending {}", message)))
+///     }
+/// }
+/// ```
 #[async_trait]
 pub trait Transport {
+    /// Sends the request element, without XML declaration and envelope, and returns the
+    /// response element. Faults are returned as [`Error::Fault`].
     async fn request(&self, message: &str) -> Result<String, Error>;
 }
 
@@ -57,3 +201,37 @@ fn test_crop_xml_declaration() {
         "<element />"
     );
 }
+
+#[test]
+fn test_onvif_fault_kind() {
+    let codes = |codes: &[&str]| codes.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+
+    assert_eq!(
+        OnvifFaultKind::from_codes(&codes(&[
+            "env:Sender",
+            "ter:InvalidArgVal",
+            "ter:NoProfile"
+        ])),
+        OnvifFaultKind::NoProfile
+    );
+    assert_eq!(
+        OnvifFaultKind::from_codes(&codes(&["env:Receiver", "ter:ActionNotSupported", "ter:X"])),
+        OnvifFaultKind::ActionNotSupported
+    );
+    assert_eq!(
+        OnvifFaultKind::from_codes(&codes(&["env:Receiver", "acme:Overheated"])),
+        OnvifFaultKind::Other("acme:Overheated".to_string())
+    );
+    assert_eq!(
+        OnvifFaultKind::from_codes(&[]),
+        OnvifFaultKind::Other(String::new())
+    );
+    assert_eq!(
+        Fault::new(
+            codes(&["env:Sender", "ter:MaxOSDs"]),
+            Some("Too many".to_string())
+        )
+        .to_string(),
+        "env:Sender ter:MaxOSDs (Too many)"
+    );
+}
@@ -16,8 +16,8 @@ impl Validate for GetServiceCapabilities {}
     namespace = "tup: http://www.onvif.org/ver10/uplink/wsdl"
 )]
 pub struct GetServiceCapabilitiesResponse {
-    // The capabilities for the uplink service is returned in the Capabilities
-    // element.
+    /// The capabilities for the uplink service is returned in the Capabilities
+    /// element.
     #[yaserde(prefix = "tup", rename = "Capabilities")]
     pub capabilities: Capabilities,
 }
@@ -30,7 +30,7 @@ impl Validate for GetServiceCapabilitiesResponse {}
     namespace = "tup: http://www.onvif.org/ver10/uplink/wsdl"
 )]
 pub struct Capabilities {
-    // Maximum number of uplink connections that can be configured.
+    /// Maximum number of uplink connections that can be configured.
     #[yaserde(attribute, rename = "MaxUplinks")]
     pub max_uplinks: Option<i32>,
 }
@@ -39,6 +39,7 @@ impl Validate for Capabilities {}
 
 // pub type Capabilities = Capabilities;
 #[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[non_exhaustive]
 pub enum ConnectionStatus {
     Offline,
     Connecting,
@@ -60,19 +61,19 @@ impl Validate for ConnectionStatus {}
     namespace = "tup: http://www.onvif.org/ver10/uplink/wsdl"
 )]
 pub struct Configuration {
-    // Uniform resource locator by which the remote client can be reached.
+    /// Uniform resource locator by which the remote client can be reached.
     #[yaserde(prefix = "tup", rename = "RemoteAddress")]
     pub remote_address: String,
 
-    // ID of the certificate to be used for client authentication.
+    /// ID of the certificate to be used for client authentication.
     #[yaserde(prefix = "tup", rename = "CertificateID")]
     pub certificate_id: String,
 
-    // Authorization level that will be assigned to the uplink connection.
+    /// Authorization level that will be assigned to the uplink connection.
     #[yaserde(prefix = "tup", rename = "UserLevel")]
     pub user_level: String,
 
-    // Current connection status (see tup:ConnectionStatus for possible values).
+    /// Current connection status (see tup:ConnectionStatus for possible values).
     #[yaserde(prefix = "tup", rename = "Status")]
     pub status: Option<String>,
 }
@@ -94,7 +95,7 @@ impl Validate for GetUplinks {}
     namespace = "tup: http://www.onvif.org/ver10/uplink/wsdl"
 )]
 pub struct GetUplinksResponse {
-    // List of configured uplinks.
+    /// List of configured uplinks.
     #[yaserde(prefix = "tup", rename = "Configuration")]
     pub configuration: Vec<Configuration>,
 }
@@ -107,7 +108,7 @@ impl Validate for GetUplinksResponse {}
     namespace = "tup: http://www.onvif.org/ver10/uplink/wsdl"
 )]
 pub struct SetUplink {
-    // SConfiguration to be added or modified.
+    /// SConfiguration to be added or modified.
     #[yaserde(prefix = "tup", rename = "Configuration")]
     pub configuration: Configuration,
 }
@@ -129,7 +130,7 @@ impl Validate for SetUplinkResponse {}
     namespace = "tup: http://www.onvif.org/ver10/uplink/wsdl"
 )]
 pub struct DeleteUplink {
-    // Uniform resource locator of the configuration to be deleted.
+    /// Uniform resource locator of the configuration to be deleted.
     #[yaserde(prefix = "tup", rename = "RemoteAddress")]
     pub remote_address: String,
 }
@@ -145,7 +146,7 @@ pub struct DeleteUplinkResponse {}
 
 impl Validate for DeleteUplinkResponse {}
 
-// Returns the capabilities of the uplink service.
+/// Returns the capabilities of the uplink service.
 pub async fn get_service_capabilities<T: transport::Transport>(
     transport: &T,
     request: &GetServiceCapabilities,
@@ -153,10 +154,10 @@ pub async fn get_service_capabilities<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// A device supporting uplinks shall support this command to retrieve the
-// configured uplink configurations.
-// The Status field shall signal whether a connection is Offline, Connecting or
-// Online.
+/// A device supporting uplinks shall support this command to retrieve the
+/// configured uplink configurations.
+/// The Status field shall signal whether a connection is Offline, Connecting or
+/// Online.
 pub async fn get_uplinks<T: transport::Transport>(
     transport: &T,
     request: &GetUplinks,
@@ -164,12 +165,12 @@ pub async fn get_uplinks<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// A device supporting uplinks shall support this command to add or modify an
-// uplink configuration.
-// The Status property of the UplinkConfiguration shall be ignored by the
-// device. A device shall
-// use the field RemoteAddress to decide whether to update an existing entry or
-// create a new entry.
+/// A device supporting uplinks shall support this command to add or modify an
+/// uplink configuration.
+/// The Status property of the UplinkConfiguration shall be ignored by the
+/// device. A device shall
+/// use the field RemoteAddress to decide whether to update an existing entry or
+/// create a new entry.
 pub async fn set_uplink<T: transport::Transport>(
     transport: &T,
     request: &SetUplink,
@@ -177,8 +178,8 @@ pub async fn set_uplink<T: transport::Transport>(
     transport::request(transport, request).await
 }
 
-// A device supporting uplinks shall support this command to remove an uplink
-// configuration.
+/// A device supporting uplinks shall support this command to remove an uplink
+/// configuration.
 pub async fn delete_uplink<T: transport::Transport>(
     transport: &T,
     request: &DeleteUplink,
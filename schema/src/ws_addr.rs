@@ -60,6 +60,7 @@ pub struct RelatesToType {
 impl Validate for RelatesToType {}
 
 #[derive(PartialEq, Debug, UtilsUnionSerDe)]
+#[non_exhaustive]
 pub enum RelationshipTypeOpenEnum {
     RelationshipType(RelationshipType),
     AnyURI(String),
@@ -94,6 +95,7 @@ impl Validate for AttributedURIType {}
 
 pub type IsReferenceParameter = bool;
 #[derive(PartialEq, Debug, UtilsUnionSerDe)]
+#[non_exhaustive]
 pub enum FaultCodesOpenEnumType {
     FaultCodesType(FaultCodesType),
     Qname(String),
@@ -57,12 +57,26 @@ pub mod probe_matches {
     use percent_encoding::percent_decode_str;
     use url::Url;
 
+    #[derive(Default, PartialEq, Debug, YaDeserialize)]
+    #[yaserde(
+        prefix = "w",
+        namespace = "w: http://schemas.xmlsoap.org/ws/2004/08/addressing"
+    )]
+    pub struct EndpointReference {
+        #[yaserde(prefix = "w", rename = "Address")]
+        pub address: String,
+    }
+
     #[derive(Default, PartialEq, Debug, YaDeserialize)]
     #[yaserde(
         prefix = "d",
-        namespace = "d: http://schemas.xmlsoap.org/ws/2005/04/discovery"
+        namespace = "d: http://schemas.xmlsoap.org/ws/2005/04/discovery",
+        namespace = "w: http://schemas.xmlsoap.org/ws/2004/08/addressing"
     )]
     pub struct ProbeMatch {
+        #[yaserde(prefix = "w", rename = "EndpointReference")]
+        pub endpoint_reference: EndpointReference,
+
         #[yaserde(prefix = "d", rename = "Types")]
         pub types: String,
 
@@ -128,6 +142,15 @@ pub mod probe_matches {
             Self::split_string_to_urls(&self.x_addrs)
         }
 
+        /// Stable identifier of the device (typically `urn:uuid:...`), the same for every
+        /// network interface and IP version the device answers on.
+        pub fn endpoint_reference(&self) -> Option<&str> {
+            match self.endpoint_reference.address.trim() {
+                "" => None,
+                address => Some(address),
+            }
+        }
+
         pub fn name(&self) -> Option<String> {
             self.find_in_scopes("onvif://www.onvif.org/name/")
         }
@@ -0,0 +1,3 @@
+#[cfg(feature = "client")]
+pub(crate) mod digest;
+pub mod username_token;
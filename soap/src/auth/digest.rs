@@ -1,5 +1,5 @@
-use crate::soap::client::Credentials;
-use reqwest::{RequestBuilder, Response};
+use crate::client::Credentials;
+use reqwest::{Method, RequestBuilder, Response};
 use std::fmt::{Debug, Formatter};
 use thiserror::Error;
 use url::Url;
@@ -17,6 +17,7 @@ pub enum Error {
 pub struct Digest {
     creds: Option<Credentials>,
     uri: Url,
+    method: Method,
     state: State,
 }
 
@@ -31,9 +32,16 @@ impl Digest {
         Self {
             creds: creds.clone(),
             uri: uri.clone(),
+            method: Method::POST,
             state: State::Default,
         }
     }
+
+    /// Sets the HTTP method the digest is computed for. SOAP requests use `POST`, the default.
+    pub fn method(mut self, method: Method) -> Self {
+        self.method = method;
+        self
+    }
 }
 
 impl Digest {
@@ -55,7 +63,10 @@ impl Digest {
             State::Got401(response) => {
                 let creds = self.creds.as_ref().ok_or(Error::NoCredentials)?;
 
-                request = request.header("Authorization", digest_auth(response, creds, &self.uri)?);
+                request = request.header(
+                    "Authorization",
+                    digest_auth(response, creds, &self.uri, &self.method)?,
+                );
 
                 Ok(request)
             }
@@ -64,7 +75,12 @@ impl Digest {
     }
 }
 
-fn digest_auth(res: &reqwest::Response, creds: &Credentials, url: &Url) -> Result<String, Error> {
+fn digest_auth(
+    res: &reqwest::Response,
+    creds: &Credentials,
+    url: &Url,
+    method: &Method,
+) -> Result<String, Error> {
     let www_authenticate = res
         .headers()
         .get(reqwest::header::WWW_AUTHENTICATE)
@@ -72,9 +88,21 @@ fn digest_auth(res: &reqwest::Response, creds: &Credentials, url: &Url) -> Resul
         .to_str()
         .map_err(|e| Error::Digest(e.to_string()))?;
 
-    let mut context = digest_auth::AuthContext::new(&creds.username, &creds.password, url.path());
+    // The digest covers the request URI, which includes the query (as in many snapshot URIs).
+    let uri = match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_string(),
+    };
+
+    let mut context = digest_auth::AuthContext::new(&creds.username, &creds.password, &uri);
 
-    context.method = digest_auth::HttpMethod::POST;
+    context.method = if *method == Method::GET {
+        digest_auth::HttpMethod::GET
+    } else if *method == Method::HEAD {
+        digest_auth::HttpMethod::HEAD
+    } else {
+        digest_auth::HttpMethod::POST
+    };
 
     Ok(digest_auth::parse(www_authenticate)
         .map_err(|e| Error::Digest(e.to_string()))?
@@ -0,0 +1,200 @@
+use rand::{CryptoRng, RngCore};
+use sha2::{Digest, Sha256};
+
+/// Length of the random nonce, as recommended by the ONVIF Application Programmer's Guide.
+const NONCE_LEN: usize = 16;
+
+const PASSWORD_DIGEST: &str = "http://docs.oasis-open.org/wss/2004/01/oasis-200401-wss-username-token-profile-1.0#PasswordDigest";
+const PASSWORD_TEXT: &str = "http://docs.oasis-open.org/wss/2004/01/oasis-200401-wss-username-token-profile-1.0#PasswordText";
+
+/// How the password is sent in a UsernameToken.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PasswordType {
+    /// `Base64(SHA-1(nonce + created + password))`, as required by the ONVIF core specification.
+    Digest,
+
+    /// Like `Digest` with SHA-256, which some devices accept or require to be compliant with
+    /// security policies banning SHA-1. Sent with the same `PasswordDigest` type.
+    DigestSha256,
+
+    /// The password itself, which should only be sent over TLS.
+    Text,
+}
+
+impl Default for PasswordType {
+    fn default() -> Self {
+        PasswordType::Digest
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct UsernameToken {
+    pub username: String,
+    pub password_type: PasswordType,
+    pub nonce: String,
+
+    /// Content of the `Password` element: the digest, or the password itself for
+    /// [`PasswordType::Text`].
+    pub digest: String,
+    pub created: String,
+}
+
+impl UsernameToken {
+    /// Creates a token with a fresh nonce from the thread's CSPRNG and the current time. Devices
+    /// reject reused nonces and stale timestamps, so a token must be created for every request.
+    pub fn new(username: &str, password: &str, password_type: PasswordType) -> UsernameToken {
+        Self::with_rng(username, password, password_type, &mut rand::thread_rng())
+    }
+
+    /// Like [`UsernameToken::new`], drawing the nonce from `rng`, e.g. a seeded one in tests.
+    pub fn with_rng<R: RngCore + CryptoRng>(
+        username: &str,
+        password: &str,
+        password_type: PasswordType,
+        rng: &mut R,
+    ) -> UsernameToken {
+        let mut nonce = [0; NONCE_LEN];
+        rng.fill_bytes(&mut nonce);
+        let created = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+        Self::with_nonce(username, password, password_type, &nonce, &created)
+    }
+
+    /// Computes the password of the token as `password_type` says from the raw nonce bytes.
+    pub fn with_nonce(
+        username: &str,
+        password: &str,
+        password_type: PasswordType,
+        nonce: &[u8],
+        created: &str,
+    ) -> UsernameToken {
+        let digest = match password_type {
+            PasswordType::Digest => {
+                let mut hasher = sha1::Sha1::new();
+                hasher.update(nonce);
+                hasher.update(created.as_bytes());
+                hasher.update(password.as_bytes());
+                base64::encode(&hasher.digest().bytes())
+            }
+            PasswordType::DigestSha256 => {
+                let mut hasher = Sha256::new();
+                hasher.update(nonce);
+                hasher.update(created.as_bytes());
+                hasher.update(password.as_bytes());
+                base64::encode(&hasher.finalize())
+            }
+            PasswordType::Text => password.to_string(),
+        };
+
+        UsernameToken {
+            username: username.to_string(),
+            password_type,
+            nonce: base64::encode(nonce),
+            digest,
+            created: created.to_string(),
+        }
+    }
+
+    pub fn to_xml(&self) -> String {
+        format!(
+            r##"<?xml version="1.0" encoding="UTF-8"?>
+            <wsse:Security
+                    xmlns:wsse="http://docs.oasis-open.org/wss/2004/01/oasis-200401-wss-wssecurity-secext-1.0.xsd"
+                    xmlns:wsu="http://docs.oasis-open.org/wss/2004/01/oasis-200401-wss-wssecurity-utility-1.0.xsd">
+                <wsse:UsernameToken>
+                    <wsse:Username>{}</wsse:Username>
+                    <wsse:Password Type="{}">{}</wsse:Password>
+                    <wsse:Nonce EncodingType="http://docs.oasis-open.org/wss/2004/01/oasis-200401-wss-soap-message-security-1.0#Base64Binary">{}</wsse:Nonce>
+                    <wsu:Created>{}</wsu:Created>
+                </wsse:UsernameToken>
+            </wsse:Security>"##,
+            escape(&self.username),
+            match self.password_type {
+                PasswordType::Digest | PasswordType::DigestSha256 => PASSWORD_DIGEST,
+                PasswordType::Text => PASSWORD_TEXT,
+            },
+            escape(&self.digest),
+            self.nonce,
+            self.created
+        )
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn ws_username_token_example() {
+        // Example from App guide (6.1.1.3 ONVIF::AuthenticatingByWS-UsernameToken)
+        // https://www.onvif.org/wp-content/uploads/2016/12/ONVIF_WG-APG-Application_Programmers_Guide-1.pdf
+
+        let nonce = base64::decode("LKqI6G/AikKCQrN0zqZFlg==").unwrap();
+        let token = UsernameToken::with_nonce(
+            "user",
+            "userpassword",
+            PasswordType::Digest,
+            &nonce,
+            "2010-09-16T07:50:45Z",
+        );
+
+        assert_eq!(token.nonce, "LKqI6G/AikKCQrN0zqZFlg==");
+        assert_eq!(token.digest, "tuOSpGlFlIXsozq4HFNeeGeFLEI=");
+        assert_eq!(token.created, "2010-09-16T07:50:45Z");
+    }
+
+    #[test]
+    fn password_types() {
+        // The inputs of the App guide example above.
+        let nonce = base64::decode("LKqI6G/AikKCQrN0zqZFlg==").unwrap();
+        let token = |password, password_type| {
+            UsernameToken::with_nonce(
+                "user",
+                password,
+                password_type,
+                &nonce,
+                "2010-09-16T07:50:45Z",
+            )
+        };
+
+        let sha256 = token("userpassword", PasswordType::DigestSha256);
+        assert_eq!(
+            sha256.digest,
+            "YWE7itrKayABhZptN5m07DxPa0R+qxVL78NKk4QjlvA="
+        );
+        assert!(sha256.to_xml().contains("#PasswordDigest\">"));
+
+        let text = token("user<password>", PasswordType::Text).to_xml();
+        assert!(text.contains("#PasswordText\">user&lt;password&gt;</wsse:Password>"));
+    }
+
+    #[test]
+    fn nonces() {
+        let a = UsernameToken::with_rng(
+            "user",
+            "pass",
+            PasswordType::Digest,
+            &mut StdRng::seed_from_u64(1),
+        );
+        let b = UsernameToken::with_rng(
+            "user",
+            "pass",
+            PasswordType::Digest,
+            &mut StdRng::seed_from_u64(1),
+        );
+        assert_eq!(a.nonce, b.nonce);
+        assert_eq!(base64::decode(&a.nonce).unwrap().len(), NONCE_LEN);
+        assert!(a.created.ends_with('Z'));
+
+        let c = UsernameToken::new("user", "pass", PasswordType::Digest);
+        let d = UsernameToken::new("user", "pass", PasswordType::Digest);
+        assert_ne!(c.nonce, d.nonce);
+        assert_ne!(c.digest, d.digest);
+    }
+}
@@ -0,0 +1,222 @@
+//! Copies the body out of an envelope by scanning its markup, see [`crate::extract_body`].
+
+use crate::Error;
+
+enum Markup<'a> {
+    Start {
+        name: &'a str,
+        attributes: &'a str,
+        empty: bool,
+    },
+    End {
+        name: &'a str,
+    },
+    // Comments, processing instructions, CDATA sections and declarations.
+    Other,
+}
+
+pub(crate) fn extract(xml: &str) -> Result<String, Error> {
+    let (envelope_attributes, mut pos) = match next_element(xml, 0)? {
+        Some((Markup::Start { name, .. }, _, _)) if local(name) != "Envelope" => {
+            return Err(Error::EnvelopeNotFound)
+        }
+        Some((Markup::Start { empty: true, .. }, _, _)) => return Err(Error::BodyNotFound),
+        Some((Markup::Start { attributes, .. }, _, end)) => (attributes, end),
+        _ => return Err(Error::ParseError),
+    };
+
+    let body_attributes = loop {
+        match next_element(xml, pos)? {
+            Some((
+                Markup::Start {
+                    name,
+                    attributes,
+                    empty,
+                },
+                start,
+                end,
+            )) => match local(name) {
+                "Body" if empty => return Err(Error::BodyIsEmpty),
+                "Body" => {
+                    pos = end;
+                    break attributes;
+                }
+                // E.g. the header.
+                _ => pos = skip_element(xml, start)?,
+            },
+            Some((Markup::End { .. }, _, _)) => return Err(Error::BodyNotFound),
+            _ => return Err(Error::ParseError),
+        }
+    };
+
+    let (name, attributes, start) = match next_element(xml, pos)? {
+        Some((
+            Markup::Start {
+                name, attributes, ..
+            },
+            start,
+            _,
+        )) => (name, attributes, start),
+        Some((Markup::End { .. }, _, _)) => return Err(Error::BodyIsEmpty),
+        _ => return Err(Error::ParseError),
+    };
+    if local(name) == "Fault" {
+        // Small, and deserialized from a tree anyway.
+        return crate::unsoap(xml);
+    }
+    let end = skip_element(xml, start)?;
+
+    // The body element may use namespaces declared by the envelope or the body, which have to be
+    // declared on it once it's on its own. Inner declarations win.
+    let own = namespaces(attributes);
+    let mut inherited: Vec<(&str, &str)> = vec![];
+    for (prefix, declaration) in namespaces(envelope_attributes)
+        .into_iter()
+        .chain(namespaces(body_attributes))
+    {
+        inherited.retain(|(p, _)| *p != prefix);
+        if !own.iter().any(|(p, _)| *p == prefix) {
+            inherited.push((prefix, declaration));
+        }
+    }
+
+    let name_end = start + 1 + name.len();
+    let mut out = String::with_capacity(
+        end - start + inherited.iter().map(|(_, d)| d.len() + 1).sum::<usize>(),
+    );
+    out += &xml[start..name_end];
+    for (_, declaration) in inherited {
+        out.push(' ');
+        out += declaration;
+    }
+    out += &xml[name_end..end];
+    Ok(out)
+}
+
+/// The next start or end tag from `pos`, skipping text and other markup, with its start and end.
+fn next_element(xml: &str, mut pos: usize) -> Result<Option<(Markup<'_>, usize, usize)>, Error> {
+    while let Some(offset) = xml[pos..].find('<') {
+        let start = pos + offset;
+        let (markup, end) = markup(xml, start)?;
+        match markup {
+            Markup::Other => pos = end,
+            markup => return Ok(Some((markup, start, end))),
+        }
+    }
+    Ok(None)
+}
+
+/// The end of the element starting at `start`.
+fn skip_element(xml: &str, start: usize) -> Result<usize, Error> {
+    let mut open = vec![];
+    let mut pos = start;
+    while let Some((markup, _, end)) = next_element(xml, pos)? {
+        match markup {
+            Markup::Start {
+                name, empty: false, ..
+            } => open.push(name),
+            Markup::Start { empty: true, .. } => {}
+            Markup::End { name } if open.last() == Some(&name) => {
+                open.pop();
+            }
+            Markup::End { .. } | Markup::Other => return Err(Error::ParseError),
+        }
+        if open.is_empty() {
+            return Ok(end);
+        }
+        pos = end;
+    }
+    Err(Error::ParseError)
+}
+
+/// The markup at `start`, which is a `<`, and its end.
+fn markup(xml: &str, start: usize) -> Result<(Markup<'_>, usize), Error> {
+    let rest = &xml[start..];
+    let until = |terminator: &str| {
+        rest.find(terminator)
+            .map(|offset| start + offset + terminator.len())
+            .ok_or(Error::ParseError)
+    };
+
+    if rest.starts_with("<!--") {
+        Ok((Markup::Other, until("-->")?))
+    } else if rest.starts_with("<![CDATA[") {
+        Ok((Markup::Other, until("]]>")?))
+    } else if rest.starts_with("<?") {
+        Ok((Markup::Other, until("?>")?))
+    } else if rest.starts_with("<!") {
+        Ok((Markup::Other, until(">")?))
+    } else if rest.starts_with("</") {
+        let end = until(">")?;
+        let name = rest[2..end - start - 1].trim_end();
+        Ok((Markup::End { name }, end))
+    } else {
+        let name_len = rest[1..]
+            .find(|c: char| c.is_whitespace() || c == '/' || c == '>')
+            .ok_or(Error::ParseError)?;
+        let name = &rest[1..1 + name_len];
+        if name.is_empty() {
+            return Err(Error::ParseError);
+        }
+
+        // `>` may appear in attribute values.
+        let mut quote = None;
+        for (offset, c) in rest
+            .char_indices()
+            .skip_while(|(offset, _)| *offset <= name_len)
+        {
+            match (quote, c) {
+                (Some(q), c) if c == q => quote = None,
+                (Some(_), _) => {}
+                (None, '"') | (None, '\'') => quote = Some(c),
+                (None, '>') => {
+                    let empty = rest[..offset].ends_with('/');
+                    let attributes = &rest[1 + name_len..offset - empty as usize];
+                    let markup = Markup::Start {
+                        name,
+                        attributes,
+                        empty,
+                    };
+                    return Ok((markup, start + offset + 1));
+                }
+                (None, _) => {}
+            }
+        }
+        Err(Error::ParseError)
+    }
+}
+
+/// The namespace declarations among `attributes` by prefix, `""` for the default namespace, with
+/// their text, e.g. `("tt", "xmlns:tt=\"http://www.onvif.org/ver10/schema\"")`.
+fn namespaces(attributes: &str) -> Vec<(&str, &str)> {
+    let mut declarations = vec![];
+    let mut rest = attributes.trim_start();
+    while let Some(equals) = rest.find('=') {
+        let name = rest[..equals].trim();
+        let value = rest[equals + 1..].trim_start();
+        let quote = match value.chars().next() {
+            Some(q @ '"') | Some(q @ '\'') => q,
+            _ => break,
+        };
+        let value_len = match value[1..].find(quote) {
+            Some(len) => len + 2,
+            None => break,
+        };
+        let end = rest.len() - value.len() + value_len;
+
+        let prefix = match name.strip_prefix("xmlns") {
+            Some("") => Some(""),
+            Some(prefix) => prefix.strip_prefix(':'),
+            None => None,
+        };
+        if let Some(prefix) = prefix {
+            declarations.push((prefix, &rest[..end]));
+        }
+        rest = rest[end..].trim_start();
+    }
+    declarations
+}
+
+fn local(name: &str) -> &str {
+    name.rsplit(':').next().unwrap_or_default()
+}
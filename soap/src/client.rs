@@ -0,0 +1,1344 @@
+use crate::{
+    action,
+    auth::{digest::Digest, username_token::UsernameToken},
+    metrics,
+    recorder::Recorder,
+};
+use async_recursion::async_recursion;
+use async_trait::async_trait;
+use schema::transport::{Error, Transport};
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use url::Url;
+
+pub use crate::auth::username_token::PasswordType;
+pub use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    StatusCode,
+};
+
+macro_rules! event {
+    ($lvl:expr, $self:ident, $($arg:tt)+) => {
+        tracing::event!($lvl, "{}: {}", $self.uri(), format_args!($($arg)+))
+    };
+}
+
+macro_rules! debug {
+    ($($arg:tt)+) => {
+        event!(tracing::Level::DEBUG, $($arg)+)
+    }
+}
+
+macro_rules! warn {
+    ($($arg:tt)+) => {
+        event!(tracing::Level::WARN, $($arg)+)
+    };
+}
+
+#[derive(Clone)]
+pub struct Client {
+    client: reqwest::Client,
+    config: Config,
+    // Authentication that last succeeded for each operation, shared between clones.
+    auth_modes: Arc<Mutex<HashMap<String, AuthMode>>>,
+    // Authentication that last succeeded with credentials for any operation, tried first by
+    // `AuthType::Auto`.
+    preferred_auth_mode: Arc<Mutex<Option<AuthMode>>>,
+    // Service address, updated when the device redirects permanently. Shared between clones.
+    uri: Arc<Mutex<Url>>,
+    // Initially those of the builder, shared between clones.
+    credentials: Arc<Mutex<Option<Credentials>>>,
+    last_response: Arc<Mutex<Option<ResponseInfo>>>,
+    // Whether the device was last reachable, shared between clones.
+    reachability: Arc<Mutex<Reachability>>,
+}
+
+/// HTTP metadata of a response, e.g. to tell firmware versions or proxies apart by their
+/// `Server` header.
+#[derive(Clone, Debug)]
+pub struct ResponseInfo {
+    /// Address the request was sent to, after following redirections.
+    pub uri: Url,
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    /// Time from sending the request until the response headers arrived.
+    pub latency: Duration,
+}
+
+/// A change of the reachability of the device, see [`ClientBuilder::on_connection_event`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConnectionEvent {
+    /// The device answered for the first time.
+    Connected { uri: Url, tls: Option<TlsInfo> },
+    /// The device answered again after connections to it had been refused.
+    Reconnected {
+        uri: Url,
+        tls: Option<TlsInfo>,
+        /// Time since the first refused connection.
+        downtime: Duration,
+    },
+    /// A connection to the device was refused or couldn't be established. Reported once until
+    /// the device answers again. Timeouts aren't reported, as busy devices may still answer.
+    Refused { uri: Url, error: String },
+}
+
+/// TLS details of an HTTPS connection, e.g. to notice a device certificate changing.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TlsInfo {
+    /// DER encoded certificate of the device, if the `tls` feature is enabled.
+    pub peer_certificate: Option<Vec<u8>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Reachability {
+    Unknown,
+    Reachable,
+    Refused(Instant),
+}
+
+#[derive(Clone)]
+pub struct ClientBuilder {
+    config: Config,
+}
+
+impl ClientBuilder {
+    pub fn new(uri: &Url) -> Self {
+        Self {
+            config: Config {
+                uri: uri.clone(),
+                credentials: None,
+                auth_type: AuthType::Any,
+                timeout: Duration::from_secs(5),
+                deadline: None,
+                recorder: None,
+                basic_auth_over_http: false,
+                credentials_for_other_hosts: false,
+                headers: HeaderMap::new(),
+                max_redirections: 3,
+                max_response_size: 32 * 1024 * 1024,
+                https_upgrade: HttpsUpgrade::Auto,
+                tcp_keepalive: None,
+                slow_call_threshold: None,
+                on_connection_event: None,
+            },
+        }
+    }
+
+    pub fn credentials(mut self, credentials: Option<Credentials>) -> Self {
+        self.config.credentials = credentials;
+        self
+    }
+
+    pub fn auth_type(mut self, auth_type: AuthType) -> Self {
+        self.config.auth_type = auth_type;
+        self
+    }
+
+    /// Lets `AuthType::Auto` fall back to HTTP Basic auth on plain HTTP connections, where the
+    /// password is sent in clear text. `AuthType::Basic` is used regardless of this setting.
+    /// `AuthType::UsernameTokenText` is refused on plain HTTP unless this is set.
+    pub fn basic_auth_over_http(mut self, allow: bool) -> Self {
+        self.config.basic_auth_over_http = allow;
+        self
+    }
+
+    /// Lets [`Client::get`] answer authentication challenges of hosts other than the one of
+    /// [`Client::uri`] with the client's credentials, e.g. for devices serving snapshots from
+    /// another port. Off by default, so that credentials aren't handed to whatever host a device
+    /// advertises.
+    pub fn credentials_for_other_hosts(mut self, allow: bool) -> Self {
+        self.config.credentials_for_other_hosts = allow;
+        self
+    }
+
+    /// Sets the `User-Agent` header of every request, including [`Client::get`].
+    pub fn user_agent(mut self, user_agent: HeaderValue) -> Self {
+        self.config
+            .headers
+            .insert(reqwest::header::USER_AGENT, user_agent);
+        self
+    }
+
+    /// Adds headers to every request, including [`Client::get`]. Some gateways route or admit
+    /// clients based on them.
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        self.config.headers.extend(headers);
+        self
+    }
+
+    /// Sets how many redirections a request may follow, 3 by default. Redirections to another
+    /// host are never followed, so credentials aren't handed to it.
+    pub fn max_redirections(mut self, max_redirections: u32) -> Self {
+        self.config.max_redirections = max_redirections;
+        self
+    }
+
+    /// Sets the largest response body, in bytes, the client reads before failing with
+    /// [`Error::Protocol`], 32 MiB by default. This applies to SOAP responses and to
+    /// [`Client::get`], so a broken or hostile device can't exhaust memory.
+    ///
+    /// Header sections are already bounded by the HTTP implementation. Responses are never
+    /// decompressed, so the limit also covers what ends up in memory.
+    pub fn max_response_size(mut self, max_response_size: usize) -> Self {
+        self.config.max_response_size = max_response_size;
+        self
+    }
+
+    /// Sets what happens when a device refuses plain HTTP, [`HttpsUpgrade::Auto`] by default.
+    pub fn https_upgrade(mut self, https_upgrade: HttpsUpgrade) -> Self {
+        self.config.https_upgrade = https_upgrade;
+        self
+    }
+
+    /// Timeout of every HTTP request, 5 seconds by default. See [`Client::with_timeout`] to
+    /// override it for single operations.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.config.timeout = timeout;
+        self
+    }
+
+    /// Sends TCP keep-alive probes on idle connections every `interval`, so that connections
+    /// to a device which went away are noticed and dropped instead of being reused. Off by
+    /// default.
+    pub fn tcp_keepalive(mut self, interval: Option<Duration>) -> Self {
+        self.config.tcp_keepalive = interval;
+        self
+    }
+
+    /// Logs a warning naming the operation and the device for operations taking at least
+    /// `threshold`, including authentication round trips, e.g. to spot devices with
+    /// pathological responses. They are also counted by `onvif_slow_requests_total`, see the
+    /// `metrics` module of the `onvif` crate. Off by default.
+    pub fn slow_call_threshold(mut self, threshold: Option<Duration>) -> Self {
+        self.config.slow_call_threshold = threshold;
+        self
+    }
+
+    /// Calls `callback` when the device becomes reachable or unreachable, for this client and
+    /// all of its clones, e.g. to raise connectivity alarms without polling. Reachability is
+    /// only observed through requests, so it is best combined with periodic ones such as
+    /// `GetSystemDateAndTime`.
+    ///
+    /// Events can be turned into a stream with a channel:
+    ///
+    /// ```no_run
+    /// # async fn example(uri: url::Url) {
+    /// use onvif_soap::client::ClientBuilder;
+    ///
+    /// let (sender, mut events) = tokio::sync::mpsc::unbounded_channel();
+    /// let device_service = ClientBuilder::new(&uri)
+    ///     .on_connection_event(move |event| {
+    ///         let _ = sender.send(event.clone());
+    ///     })
+    ///     .build();
+    /// while let Some(event) = events.recv().await {
+    ///     println!("{:?}", event);
+    /// }
+    /// # }
+    /// ```
+    pub fn on_connection_event(
+        mut self,
+        callback: impl Fn(&ConnectionEvent) + Send + Sync + 'static,
+    ) -> Self {
+        self.config.on_connection_event = Some(Arc::new(callback));
+        self
+    }
+
+    /// Records every SOAP exchange of this client, e.g. for support bundles. A recorder may be
+    /// shared between clients.
+    pub fn recorder(mut self, recorder: Option<Arc<Recorder>>) -> Self {
+        self.config.recorder = recorder;
+        self
+    }
+
+    pub fn build(self) -> Client {
+        #[allow(unused_mut)]
+        let mut client_builder = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .timeout(self.config.timeout)
+            .tcp_keepalive(self.config.tcp_keepalive)
+            .default_headers(self.config.headers.clone());
+
+        #[cfg(feature = "tls")]
+        {
+            // hyper-rustls does not support IP hosts (like https://192.168.1.2) which are
+            // very common for IP cameras. So we can use only native-tls for now.
+            // https://github.com/ctz/hyper-rustls/issues/56
+            client_builder = client_builder
+                .use_native_tls()
+                .danger_accept_invalid_certs(true)
+                .tls_info(true);
+        }
+
+        Client {
+            client: client_builder.build().unwrap(),
+            uri: Arc::new(Mutex::new(self.config.uri.clone())),
+            credentials: Arc::new(Mutex::new(self.config.credentials.clone())),
+            config: self.config,
+            auth_modes: Default::default(),
+            preferred_auth_mode: Default::default(),
+            last_response: Default::default(),
+            reachability: Arc::new(Mutex::new(Reachability::Unknown)),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Config {
+    uri: Url,
+    credentials: Option<Credentials>,
+    auth_type: AuthType,
+    timeout: Duration,
+    deadline: Option<Instant>,
+    recorder: Option<Arc<Recorder>>,
+    basic_auth_over_http: bool,
+    credentials_for_other_hosts: bool,
+    headers: HeaderMap,
+    max_redirections: u32,
+    max_response_size: usize,
+    https_upgrade: HttpsUpgrade,
+    tcp_keepalive: Option<Duration>,
+    slow_call_threshold: Option<Duration>,
+    on_connection_event: Option<Arc<dyn Fn(&ConnectionEvent) + Send + Sync>>,
+}
+
+/// What the client does when a device only accepts HTTPS but is addressed over plain HTTP.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HttpsUpgrade {
+    /// Nothing: requests fail with the status the device answers, and redirections to HTTPS
+    /// are followed but only remembered if they are permanent.
+    Never,
+    /// Retry over HTTPS when the device answers `400 Bad Request` without a SOAP fault, as
+    /// HTTPS-only ports do, and remember redirections to HTTPS even if they aren't permanent.
+    /// Once a request succeeds over HTTPS, the client and its clones keep using it.
+    Auto,
+}
+
+#[derive(Clone, Debug)]
+pub enum AuthType {
+    /// First try to authorize with Digest and in case of error try UsernameToken auth
+    Any,
+    /// Try UsernameToken, Digest and Basic auth in turn, starting with whichever last worked for
+    /// this client. Basic auth is only tried over HTTPS unless
+    /// [`ClientBuilder::basic_auth_over_http`] is set.
+    Auto,
+    /// Use only Digest auth
+    Digest,
+    /// Use only UsernameToken auth
+    UsernameToken,
+    /// Use only UsernameToken auth with a SHA-256 instead of a SHA-1 password digest
+    UsernameTokenSha256,
+    /// Use only UsernameToken auth with the password in clear text, which is only sent over HTTPS
+    /// unless [`ClientBuilder::basic_auth_over_http`] is set
+    UsernameTokenText,
+    /// Use only HTTP Basic auth
+    Basic,
+}
+
+#[derive(Clone)]
+pub struct Credentials {
+    pub username: String,
+    pub password: String,
+}
+
+impl Credentials {
+    /// Reads credentials from the `ONVIF_USERNAME` and `ONVIF_PASSWORD` environment variables.
+    /// Returns `None` unless both are set.
+    pub fn from_env() -> Option<Self> {
+        Some(Self {
+            username: std::env::var("ONVIF_USERNAME").ok()?,
+            password: std::env::var("ONVIF_PASSWORD").ok()?,
+        })
+    }
+}
+
+impl Debug for Credentials {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{} [password hidden]", self.username))
+    }
+}
+
+/// Operations the ONVIF core specification requires devices to allow without authentication.
+/// Some devices nevertheless reject them when credentials are sent, e.g. a WS-UsernameToken while
+/// the device clock is off, so they are tried anonymously first.
+const PRE_AUTH_OPERATIONS: &[&str] = &[
+    "GetEndpointReference",
+    "GetServiceCapabilities",
+    "GetServices",
+    "GetSystemDateAndTime",
+    "GetWsdlUrl",
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum AuthMode {
+    Anonymous,
+    Digest,
+    UsernameToken(PasswordType),
+    Basic,
+}
+
+#[derive(Debug)]
+enum RequestAuthType {
+    Anonymous,
+    Digest(Digest),
+    UsernameToken(PasswordType),
+    Basic,
+}
+
+#[async_trait]
+impl Transport for Client {
+    async fn request(&self, message: &str) -> Result<String, Error> {
+        let action = action(message);
+        let start = Instant::now();
+
+        let result = match self.config.deadline {
+            Some(deadline) if deadline <= start => Err(Error::Timeout(format!(
+                "Deadline of {} passed before the request",
+                action
+            ))),
+            Some(deadline) => tokio::time::timeout_at(
+                deadline.into(),
+                self.authenticated_request(message, action),
+            )
+            .await
+            .unwrap_or_else(|_| Err(Error::Timeout(format!("Deadline of {} passed", action)))),
+            None => self.authenticated_request(message, action).await,
+        };
+
+        let elapsed = start.elapsed();
+        metrics::request(
+            self.uri().path().trim_matches('/'),
+            action,
+            elapsed,
+            &result,
+        );
+        self.check_slow(action, elapsed, &result);
+
+        match &result {
+            Ok(response) => debug!(self, "Request succeeded: {}", response),
+            Err(e) => warn!(self, "Request failed: {:?}", e),
+        }
+
+        result
+    }
+}
+
+impl Client {
+    /// A client sharing the state of this one, e.g. credentials and authentication modes, whose
+    /// HTTP requests time out after `timeout` instead. For operations that legitimately take
+    /// long, such as firmware upgrades or backups, or those that should fail fast.
+    ///
+    /// ```no_run
+    /// # async fn example(device_service: &onvif_soap::client::Client) {
+    /// use schema::devicemgmt;
+    /// use std::time::Duration;
+    ///
+    /// let patient = device_service.with_timeout(Duration::from_secs(600));
+    /// let backup = devicemgmt::get_system_backup(&patient, &Default::default()).await;
+    /// # }
+    /// ```
+    pub fn with_timeout(&self, timeout: Duration) -> Client {
+        let mut client = self.clone();
+        client.config.timeout = timeout;
+        client
+    }
+
+    /// A client sharing the state of this one whose operations fail with [`Error::Timeout`] at
+    /// `deadline`, including authentication retries and redirections.
+    pub fn with_deadline(&self, deadline: Instant) -> Client {
+        let mut client = self.clone();
+        client.config.deadline = Some(deadline);
+        client
+    }
+
+    /// Warns about an operation that took longer than the slow call threshold, returning whether
+    /// it did.
+    fn check_slow(&self, action: &str, elapsed: Duration, result: &Result<String, Error>) -> bool {
+        match self.config.slow_call_threshold {
+            Some(threshold) if elapsed >= threshold => {
+                metrics::slow(self.uri().path().trim_matches('/'), action);
+                match result {
+                    Ok(response) => warn!(
+                        self,
+                        "{} took {:?}, responding with {} bytes",
+                        action,
+                        elapsed,
+                        response.len()
+                    ),
+                    Err(e) => warn!(self, "{} took {:?} and failed: {}", action, elapsed, e),
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    async fn authenticated_request(&self, message: &str, action: &str) -> Result<String, Error> {
+        let cached = self.auth_modes.lock().unwrap().get(action).copied();
+        match cached {
+            Some(mode) => match self.request_with(message, mode).await {
+                Err(Error::Authorization(e)) => {
+                    debug!(
+                        self,
+                        "{} no longer works with {:?} auth: {}", action, mode, e
+                    );
+                    self.auth_modes.lock().unwrap().remove(action);
+                    self.negotiate(message, action).await
+                }
+                result => result,
+            },
+            None => self.negotiate(message, action).await,
+        }
+    }
+
+    /// Tries the authentication modes allowed for an operation in turn and remembers the first
+    /// one that succeeds.
+    async fn negotiate(&self, message: &str, action: &str) -> Result<String, Error> {
+        let mut modes = vec![];
+        if self.credentials().is_some() && PRE_AUTH_OPERATIONS.contains(&action) {
+            modes.push(AuthMode::Anonymous);
+        }
+        match self.config.auth_type {
+            // Digest requests are only authenticated once the device asks for it with a 401.
+            AuthType::Any => modes.extend(&[
+                AuthMode::Digest,
+                AuthMode::UsernameToken(PasswordType::Digest),
+            ]),
+            AuthType::Auto => {
+                let mut candidates = vec![
+                    AuthMode::UsernameToken(PasswordType::Digest),
+                    AuthMode::Digest,
+                ];
+                if self.allows_clear_text(&self.uri()) {
+                    candidates.push(AuthMode::Basic);
+                }
+                let preferred = *self.preferred_auth_mode.lock().unwrap();
+                if let Some(preferred) = preferred {
+                    candidates.retain(|&m| m != preferred);
+                    candidates.insert(0, preferred);
+                }
+                modes.extend(candidates);
+            }
+            AuthType::Digest => modes.push(AuthMode::Digest),
+            AuthType::UsernameToken => modes.push(AuthMode::UsernameToken(PasswordType::Digest)),
+            AuthType::UsernameTokenSha256 => {
+                modes.push(AuthMode::UsernameToken(PasswordType::DigestSha256))
+            }
+            AuthType::UsernameTokenText => modes.push(AuthMode::UsernameToken(PasswordType::Text)),
+            AuthType::Basic => modes.push(AuthMode::Basic),
+        }
+
+        let mut last_error = None;
+        for (i, mode) in modes.into_iter().enumerate() {
+            if i > 0 {
+                metrics::retry("auth");
+            }
+            match self.request_with(message, mode).await {
+                Ok(response) => {
+                    self.auth_modes
+                        .lock()
+                        .unwrap()
+                        .insert(action.to_string(), mode);
+                    if mode != AuthMode::Anonymous {
+                        *self.preferred_auth_mode.lock().unwrap() = Some(mode);
+                    }
+                    return Ok(response);
+                }
+                Err(Error::Authorization(e)) => {
+                    warn!(
+                        self,
+                        "Failed to authorize {} with {:?} auth: {}", action, mode, e
+                    );
+                    last_error = Some(Error::Authorization(e));
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| Error::Authorization("Unauthorized".to_string())))
+    }
+
+    async fn request_with(&self, message: &str, mode: AuthMode) -> Result<String, Error> {
+        let uri = self.uri();
+        if mode == AuthMode::UsernameToken(PasswordType::Text) && !self.allows_clear_text(&uri) {
+            return Err(Error::Authorization(
+                "Refusing to send a clear text password over plain HTTP".to_string(),
+            ));
+        }
+
+        let mut auth_type = match mode {
+            AuthMode::Anonymous => RequestAuthType::Anonymous,
+            AuthMode::Digest => RequestAuthType::Digest(Digest::new(&uri, &self.credentials())),
+            AuthMode::UsernameToken(password_type) => RequestAuthType::UsernameToken(password_type),
+            AuthMode::Basic => RequestAuthType::Basic,
+        };
+
+        self.request_recursive(message, &uri, &mut auth_type, 0)
+            .await
+    }
+
+    /// Address requests are sent to. Differs from the one the client was built with once the
+    /// device redirected permanently, i.e. with `301 Moved Permanently` or
+    /// `308 Permanent Redirect`, or turned out to require HTTPS, see [`HttpsUpgrade`].
+    pub fn uri(&self) -> Url {
+        self.uri.lock().unwrap().clone()
+    }
+
+    /// Changes the address requests are sent to, for this client and all of its clones, e.g.
+    /// after the device got a new IP address.
+    pub fn set_uri(&self, uri: &Url) {
+        *self.uri.lock().unwrap() = uri.clone();
+    }
+
+    pub fn credentials(&self) -> Option<Credentials> {
+        self.credentials.lock().unwrap().clone()
+    }
+
+    /// Changes the credentials of this client and all of its clones, e.g. after a password
+    /// rotation. Requests already in flight finish with the old ones. The authentication modes
+    /// remembered per operation are forgotten, since they may differ for the new credentials.
+    pub fn set_credentials(&self, credentials: Option<Credentials>) {
+        *self.credentials.lock().unwrap() = credentials;
+        self.auth_modes.lock().unwrap().clear();
+        *self.preferred_auth_mode.lock().unwrap() = None;
+    }
+
+    #[async_recursion]
+    async fn request_recursive(
+        &self,
+        message: &str,
+        uri: &Url,
+        auth_type: &mut RequestAuthType,
+        redirections: u32,
+    ) -> Result<String, Error> {
+        let username_token = match auth_type {
+            RequestAuthType::UsernameToken(password_type) => {
+                self.username_token_auth(*password_type)
+            }
+            _ => None,
+        };
+
+        debug!(
+            self,
+            "About to make request. auth_type={:?}, redirections={}", auth_type, redirections
+        );
+
+        let soap_msg = crate::soap(message, &username_token)
+            .map_err(|e| Error::Protocol(format!("{:?}", e)))?;
+
+        let mut request = self
+            .client
+            .post(uri.as_str())
+            .timeout(self.config.timeout)
+            .header("Content-Type", "application/soap+xml; charset=utf-8;");
+
+        if let RequestAuthType::Digest(digest) = auth_type {
+            request = digest
+                .add_headers(request)
+                .map_err(|e| Error::Authorization(e.to_string()))?;
+
+            debug!(self, "Digest headers added");
+        }
+
+        if let (RequestAuthType::Basic, Some(creds)) = (&*auth_type, &self.credentials()) {
+            request = request.basic_auth(&creds.username, Some(&creds.password));
+        }
+
+        debug!(
+            self,
+            "Request body ({} bytes): {}",
+            soap_msg.len(),
+            soap_msg
+        );
+
+        let start = Instant::now();
+        let response = match request.body(soap_msg.clone()).send().await {
+            Ok(response) => response,
+            Err(e) => {
+                self.record(uri, &soap_msg, None, None);
+                return Err(self.failed(uri, e));
+            }
+        };
+        self.remember(uri, &response, start);
+
+        let status = response.status();
+
+        debug!(self, "Response status: {}", status);
+
+        if status.is_success() {
+            let text = self.read_text(response).await?;
+            debug!(self, "Response body ({} bytes): {}", text.len(), text);
+            metrics::payload(
+                uri.path().trim_matches('/'),
+                action(message),
+                soap_msg.len(),
+                text.len(),
+            );
+            self.record(uri, &soap_msg, Some(status), Some(&text));
+            crate::extract_body(&text).map_err(|e| match crate::fault(&text) {
+                Some(fault) => Error::Fault(fault),
+                None => Error::Protocol(format!("{:?}", e)),
+            })
+        } else if status == reqwest::StatusCode::UNAUTHORIZED {
+            match auth_type {
+                RequestAuthType::Digest(digest) if !digest.is_failed() => {
+                    digest.set_401(response);
+                    metrics::retry("digest_challenge");
+                }
+                _ => {
+                    if let Ok(text) = self.read_text(response).await {
+                        debug!(self, "Got Unauthorized with body: {}", text);
+                    }
+
+                    return Err(Error::Authorization("Unauthorized".to_string()));
+                }
+            }
+
+            self.request_recursive(message, uri, auth_type, redirections)
+                .await
+        } else if status.is_redirection() {
+            // reqwest changes method on 302, so we have to handle redirections ourselves
+            // https://github.com/seanmonstar/reqwest/issues/912
+
+            if redirections >= self.config.max_redirections {
+                return Err(Error::Redirection("Redirection limit exceeded".to_string()));
+            }
+
+            let new_url = redirect_location(uri, response.headers())?;
+            if new_url.host() != uri.host() {
+                return Err(Error::Redirection(format!(
+                    "Redirection to another host is not followed: {}",
+                    new_url
+                )));
+            }
+            if matches!(
+                auth_type,
+                RequestAuthType::Basic | RequestAuthType::UsernameToken(PasswordType::Text)
+            ) && !self.allows_clear_text(&new_url)
+            {
+                return Err(Error::Redirection(format!(
+                    "Redirection would send {:?} auth credentials unencrypted: {}",
+                    auth_type, new_url
+                )));
+            }
+
+            debug!(self, "Redirecting to {} ...", new_url);
+            metrics::retry("redirect");
+
+            // The digest covers the request URI, and the new location may issue another
+            // challenge, so start over. A new UsernameToken is created for every request anyway.
+            if let RequestAuthType::Digest(_) = auth_type {
+                *auth_type = RequestAuthType::Digest(Digest::new(&new_url, &self.credentials()));
+            }
+
+            if status == reqwest::StatusCode::MOVED_PERMANENTLY
+                || status == reqwest::StatusCode::PERMANENT_REDIRECT
+                || self.https_upgrade(uri).as_ref() == Some(&new_url)
+            {
+                self.moved(uri, &new_url);
+            }
+
+            self.request_recursive(message, &new_url, auth_type, redirections + 1)
+                .await
+        } else {
+            if let Ok(text) = self.read_text(response).await {
+                debug!(self, "Got HTTP error with body: {}", text);
+                self.record(uri, &soap_msg, Some(status), Some(&text));
+                if let Err(crate::Error::Fault(f)) = crate::unsoap(&text) {
+                    if f.is_unauthorized() {
+                        return Err(Error::Authorization("Unauthorized".to_string()));
+                    }
+                }
+
+                // Faults of the sender also come with a 400, but the request was understood, so
+                // HTTPS isn't tried.
+                if let Some(fault) = crate::fault(&text) {
+                    return Err(Error::Fault(fault));
+                }
+            }
+
+            let https = match self.https_upgrade(uri) {
+                Some(https)
+                    if status == reqwest::StatusCode::BAD_REQUEST
+                        && redirections < self.config.max_redirections =>
+                {
+                    https
+                }
+                _ => return Err(Error::Other(status.to_string())),
+            };
+
+            debug!(self, "Retrying over HTTPS at {} ...", https);
+            metrics::retry("https");
+
+            if let RequestAuthType::Digest(_) = auth_type {
+                *auth_type = RequestAuthType::Digest(Digest::new(&https, &self.credentials()));
+            }
+
+            match self
+                .request_recursive(message, &https, auth_type, redirections + 1)
+                .await
+            {
+                Ok(response) => {
+                    self.moved(uri, &https);
+                    Ok(response)
+                }
+                // Not HTTPS-only after all, the original error is the relevant one.
+                Err(Error::Connection(e)) | Err(Error::Timeout(e)) => {
+                    debug!(self, "HTTPS is unavailable: {}", e);
+                    Err(Error::Other(status.to_string()))
+                }
+                Err(e) => Err(e),
+            }
+        }
+    }
+
+    /// Fetches a plain HTTP resource advertised by the device, such as a snapshot URI, with the
+    /// client's credentials. Both Digest and Basic authentication are supported, the latter only
+    /// over HTTPS unless [`ClientBuilder::basic_auth_over_http`] allows it.
+    ///
+    /// Resources are requested without credentials first. Challenges are only answered if `uri`
+    /// has the host and port of [`Client::uri`], unless
+    /// [`ClientBuilder::credentials_for_other_hosts`] allows others.
+    pub async fn get(&self, uri: &Url) -> Result<Vec<u8>, Error> {
+        let start = Instant::now();
+        let mut response = self
+            .client
+            .get(uri.as_str())
+            .timeout(self.config.timeout)
+            .send()
+            .await
+            .map_err(|e| self.failed(uri, e))?;
+        self.remember(uri, &response, start);
+
+        if response.status() == reqwest::StatusCode::UNAUTHORIZED {
+            let credentials = self.credentials();
+            let creds = credentials
+                .as_ref()
+                .ok_or_else(|| Error::Authorization("Unauthorized".to_string()))?;
+            if !self.is_own_host(uri) && !self.config.credentials_for_other_hosts {
+                return Err(Error::Authorization(format!(
+                    "Refusing to send credentials to another host: {}",
+                    uri
+                )));
+            }
+            let is_basic = response
+                .headers()
+                .get(reqwest::header::WWW_AUTHENTICATE)
+                .and_then(|h| h.to_str().ok())
+                .map_or(false, |h| {
+                    h.trim_start().to_ascii_lowercase().starts_with("basic")
+                });
+
+            let request = self.client.get(uri.as_str()).timeout(self.config.timeout);
+            let request = if is_basic {
+                if !self.allows_clear_text(uri) {
+                    return Err(Error::Authorization(
+                        "Refusing to send a clear text password over plain HTTP".to_string(),
+                    ));
+                }
+                request.basic_auth(&creds.username, Some(&creds.password))
+            } else {
+                let mut digest = Digest::new(uri, &credentials).method(reqwest::Method::GET);
+                digest.set_401(response);
+                digest
+                    .add_headers(request)
+                    .map_err(|e| Error::Authorization(e.to_string()))?
+            };
+
+            let start = Instant::now();
+            response = request.send().await.map_err(|e| self.failed(uri, e))?;
+            self.remember(uri, &response, start);
+        }
+
+        let status = response.status();
+
+        debug!(self, "GET {} response status: {}", uri, status);
+
+        if status == reqwest::StatusCode::UNAUTHORIZED {
+            Err(Error::Authorization("Unauthorized".to_string()))
+        } else if !status.is_success() {
+            Err(Error::Other(status.to_string()))
+        } else {
+            self.read_body(response).await
+        }
+    }
+
+    /// Reads a response body, failing as soon as it exceeds the configured limit.
+    async fn read_body(&self, mut response: reqwest::Response) -> Result<Vec<u8>, Error> {
+        let max = self.config.max_response_size;
+        let too_large =
+            || Error::Protocol(format!("Response body exceeds the limit of {} bytes", max));
+
+        if response
+            .content_length()
+            .map_or(false, |len| len > max as u64)
+        {
+            return Err(too_large());
+        }
+
+        let mut body = vec![];
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| Error::Protocol(e.to_string()))?
+        {
+            if body.len() + chunk.len() > max {
+                return Err(too_large());
+            }
+            body.extend_from_slice(&chunk);
+        }
+
+        Ok(body)
+    }
+
+    async fn read_text(&self, response: reqwest::Response) -> Result<String, Error> {
+        let body = self.read_body(response).await?;
+        Ok(String::from_utf8_lossy(&body).into_owned())
+    }
+
+    /// Metadata of the last HTTP response received by this client or any of its clones,
+    /// including intermediate ones such as authentication challenges. `None` before the first
+    /// response.
+    pub fn last_response(&self) -> Option<ResponseInfo> {
+        self.last_response.lock().unwrap().clone()
+    }
+
+    fn remember(&self, uri: &Url, response: &reqwest::Response, start: Instant) {
+        *self.last_response.lock().unwrap() = Some(ResponseInfo {
+            uri: uri.clone(),
+            status: response.status(),
+            headers: response.headers().clone(),
+            latency: start.elapsed(),
+        });
+
+        let tls = match uri.scheme() {
+            "https" => Some(TlsInfo {
+                peer_certificate: peer_certificate(response),
+            }),
+            _ => None,
+        };
+        self.reachable(uri, Ok(tls));
+    }
+
+    /// Maps the error of a request that got no response, noting refused connections.
+    fn failed(&self, uri: &Url, e: reqwest::Error) -> Error {
+        if e.is_connect() {
+            self.reachable(uri, Err(e.to_string()));
+        }
+        Client::map_reqwest_error(e)
+    }
+
+    /// Updates the reachability of the device with the outcome of a connection, and reports it
+    /// if it changed.
+    fn reachable(&self, uri: &Url, outcome: Result<Option<TlsInfo>, String>) {
+        let event = {
+            let mut reachability = self.reachability.lock().unwrap();
+            let previous = *reachability;
+            match outcome {
+                Ok(tls) => {
+                    *reachability = Reachability::Reachable;
+                    match previous {
+                        Reachability::Unknown => Some(ConnectionEvent::Connected {
+                            uri: uri.clone(),
+                            tls,
+                        }),
+                        Reachability::Refused(since) => Some(ConnectionEvent::Reconnected {
+                            uri: uri.clone(),
+                            tls,
+                            downtime: since.elapsed(),
+                        }),
+                        Reachability::Reachable => None,
+                    }
+                }
+                Err(_) if matches!(previous, Reachability::Refused(_)) => None,
+                Err(error) => {
+                    *reachability = Reachability::Refused(Instant::now());
+                    Some(ConnectionEvent::Refused {
+                        uri: uri.clone(),
+                        error,
+                    })
+                }
+            }
+        };
+
+        if let Some(event) = event {
+            debug!(self, "Connection event: {:?}", event);
+            if let Some(callback) = &self.config.on_connection_event {
+                callback(&event);
+            }
+        }
+    }
+
+    fn record(
+        &self,
+        uri: &Url,
+        request: &str,
+        status: Option<reqwest::StatusCode>,
+        response: Option<&str>,
+    ) {
+        if let Some(recorder) = &self.config.recorder {
+            if let Err(e) = recorder.record(uri, request, status.map(|s| s.as_u16()), response) {
+                warn!(
+                    self,
+                    "Failed to record SOAP exchange in {}: {}",
+                    recorder.dir().display(),
+                    e
+                );
+            }
+        }
+    }
+
+    fn map_reqwest_error(e: reqwest::Error) -> Error {
+        match e {
+            e if e.is_connect() => Error::Connection(e.to_string()),
+            e if e.is_timeout() => Error::Timeout(e.to_string()),
+            e if e.is_redirect() => Error::Redirection(e.to_string()),
+            e if e.is_decode() || e.is_body() => Error::Protocol(e.to_string()),
+            e => Error::Other(e.to_string()),
+        }
+    }
+
+    pub fn username_token_auth(&self, password_type: PasswordType) -> Option<UsernameToken> {
+        self.credentials()
+            .as_ref()
+            .map(|c| UsernameToken::new(&c.username, &c.password, password_type))
+    }
+
+    /// Switches the address requests are sent to from `from` to `to`, unless it was changed
+    /// since the request to `from` started.
+    fn moved(&self, from: &Url, to: &Url) {
+        let mut current = self.uri.lock().unwrap();
+        if *current == *from {
+            *current = to.clone();
+            // The log lines of the client start with the current address.
+            drop(current);
+            debug!(self, "Service moved from {}", from);
+        }
+    }
+
+    /// The HTTPS counterpart of `uri`, on the same port, if the policy allows upgrading it.
+    /// Ports of HTTPS-only devices answer plain HTTP with a 400, so the port is kept; the
+    /// default HTTP port becomes the default HTTPS one.
+    fn https_upgrade(&self, uri: &Url) -> Option<Url> {
+        if self.config.https_upgrade != HttpsUpgrade::Auto || uri.scheme() != "http" {
+            return None;
+        }
+        let mut https = uri.clone();
+        https.set_scheme("https").ok()?;
+        Some(https)
+    }
+
+    /// Whether `uri` has the host and port requests are sent to.
+    fn is_own_host(&self, uri: &Url) -> bool {
+        let own = self.uri();
+        uri.host() == own.host() && uri.port_or_known_default() == own.port_or_known_default()
+    }
+
+    fn allows_clear_text(&self, uri: &Url) -> bool {
+        uri.scheme() == "https" || self.config.basic_auth_over_http
+    }
+}
+
+#[cfg(feature = "tls")]
+fn peer_certificate(response: &reqwest::Response) -> Option<Vec<u8>> {
+    response
+        .extensions()
+        .get::<reqwest::tls::TlsInfo>()?
+        .peer_certificate()
+        .map(|der| der.to_vec())
+}
+
+#[cfg(not(feature = "tls"))]
+fn peer_certificate(_response: &reqwest::Response) -> Option<Vec<u8>> {
+    None
+}
+
+/// Resolves the `Location` of a redirection, which may be relative, against the requested URI.
+fn redirect_location(uri: &Url, headers: &HeaderMap) -> Result<Url, Error> {
+    let location = headers
+        .get(reqwest::header::LOCATION)
+        .ok_or_else(|| Error::Redirection("Redirection without Location".to_string()))?
+        .to_str()
+        .map_err(|e| Error::Redirection(e.to_string()))?;
+
+    uri.join(location)
+        .map_err(|e| Error::Redirection(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redirect_locations() {
+        let uri = Url::parse("http://192.168.0.2/onvif/device_service").unwrap();
+        let location = |value: &'static str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(reqwest::header::LOCATION, HeaderValue::from_static(value));
+            redirect_location(&uri, &headers).map(String::from)
+        };
+
+        assert_eq!(
+            location("https://192.168.0.2:8443/onvif/device_service").unwrap(),
+            "https://192.168.0.2:8443/onvif/device_service"
+        );
+        assert_eq!(
+            location("/onvif/device").unwrap(),
+            "http://192.168.0.2/onvif/device"
+        );
+        assert!(matches!(
+            redirect_location(&uri, &HeaderMap::new()),
+            Err(Error::Redirection(_))
+        ));
+    }
+
+    #[test]
+    fn credentials_shared_between_clones() {
+        let client =
+            ClientBuilder::new(&Url::parse("http://localhost/onvif/device_service").unwrap())
+                .credentials(Some(Credentials {
+                    username: "admin".to_string(),
+                    password: "old".to_string(),
+                }))
+                .build();
+        let clone = client.clone();
+        client
+            .auth_modes
+            .lock()
+            .unwrap()
+            .insert("GetProfiles".to_string(), AuthMode::Digest);
+
+        client.set_credentials(Some(Credentials {
+            username: "admin".to_string(),
+            password: "new".to_string(),
+        }));
+        assert_eq!(clone.credentials().unwrap().password, "new");
+        assert!(clone.auth_modes.lock().unwrap().is_empty());
+
+        let moved = Url::parse("http://192.168.0.3/onvif/device_service").unwrap();
+        clone.set_uri(&moved);
+        assert_eq!(client.uri(), moved);
+    }
+
+    #[tokio::test]
+    async fn refuses_clear_text_over_http() {
+        let client =
+            ClientBuilder::new(&Url::parse("http://localhost/onvif/device_service").unwrap())
+                .credentials(Some(Credentials {
+                    username: "admin".to_string(),
+                    password: "secret".to_string(),
+                }))
+                .auth_type(AuthType::UsernameTokenText)
+                .build();
+
+        assert!(matches!(
+            client.request("<tds:GetProfiles/>").await,
+            Err(Error::Authorization(_))
+        ));
+
+        // A snapshot URI on the device asking for Basic authentication.
+        let snapshot_uri = challenging_server("Basic realm=\"camera\"").await;
+        let client = ClientBuilder::new(&snapshot_uri.join("/onvif/device_service").unwrap())
+            .credentials(client.credentials())
+            .build();
+        match client.get(&snapshot_uri).await {
+            Err(Error::Authorization(message)) => assert!(message.contains("clear text")),
+            result => panic!("{:?}", result),
+        }
+    }
+
+    /// Answers every request with `401 Unauthorized` and `challenge`, returning its address.
+    async fn challenging_server(challenge: &'static str) -> Url {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let uri = Url::parse(&format!(
+            "http://{}/snapshot.jpg",
+            listener.local_addr().unwrap()
+        ))
+        .unwrap();
+        tokio::spawn(async move {
+            use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut request = [0; 1024];
+                let _ = stream.read(&mut request).await;
+                let response = format!(
+                    "HTTP/1.1 401 Unauthorized\r\n\
+                     WWW-Authenticate: {}\r\n\
+                     Content-Length: 0\r\n\r\n",
+                    challenge
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+        uri
+    }
+
+    #[tokio::test]
+    async fn authenticates_only_to_the_device() {
+        let snapshot_uri =
+            challenging_server(r#"Digest realm="camera", nonce="abc", qop="auth""#).await;
+        let builder =
+            ClientBuilder::new(&Url::parse("http://192.168.0.2/onvif/device_service").unwrap())
+                .credentials(Some(Credentials {
+                    username: "admin".to_string(),
+                    password: "secret".to_string(),
+                }));
+
+        match builder.clone().build().get(&snapshot_uri).await {
+            Err(Error::Authorization(message)) => assert!(message.contains("another host")),
+            result => panic!("{:?}", result),
+        }
+        // The challenge is answered, and refused again by the server.
+        match builder
+            .credentials_for_other_hosts(true)
+            .build()
+            .get(&snapshot_uri)
+            .await
+        {
+            Err(Error::Authorization(message)) => assert_eq!(message, "Unauthorized"),
+            result => panic!("{:?}", result),
+        }
+    }
+
+    #[tokio::test]
+    async fn per_operation_timeouts() {
+        let client =
+            ClientBuilder::new(&Url::parse("http://localhost/onvif/device_service").unwrap())
+                .build();
+        let patient = client.with_timeout(Duration::from_secs(600));
+        assert_eq!(patient.config.timeout, Duration::from_secs(600));
+        assert_eq!(client.config.timeout, Duration::from_secs(5));
+        assert!(Arc::ptr_eq(&patient.auth_modes, &client.auth_modes));
+
+        let late = client.with_deadline(Instant::now() - Duration::from_secs(1));
+        assert!(matches!(
+            late.request("<tds:GetProfiles/>").await,
+            Err(Error::Timeout(_))
+        ));
+    }
+
+    #[test]
+    fn https_upgrades() {
+        let client =
+            ClientBuilder::new(&Url::parse("http://192.168.0.2/onvif/device_service").unwrap())
+                .build();
+        let upgrade = |uri: &str| {
+            client
+                .https_upgrade(&Url::parse(uri).unwrap())
+                .map(String::from)
+        };
+
+        assert_eq!(
+            upgrade("http://192.168.0.2/onvif/device_service").as_deref(),
+            Some("https://192.168.0.2/onvif/device_service")
+        );
+        assert_eq!(
+            upgrade("http://192.168.0.2:8080/onvif/device_service").as_deref(),
+            Some("https://192.168.0.2:8080/onvif/device_service")
+        );
+        assert_eq!(upgrade("https://192.168.0.2/onvif/device_service"), None);
+
+        let https = Url::parse("https://192.168.0.2/onvif/device_service").unwrap();
+        client.moved(&client.uri(), &https);
+        assert_eq!(client.uri(), https);
+        // Requests that started before another move don't undo it.
+        client.moved(&Url::parse("http://192.168.0.2/").unwrap(), &client.uri());
+        assert_eq!(client.uri(), https);
+
+        let never = ClientBuilder::new(&https)
+            .https_upgrade(HttpsUpgrade::Never)
+            .build();
+        assert_eq!(
+            never.https_upgrade(&Url::parse("http://192.168.0.2/").unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn slow_calls() {
+        let uri = Url::parse("http://192.168.0.2/onvif/device_service").unwrap();
+        let response = Ok("<tds:GetProfilesResponse/>".to_string());
+
+        let client = ClientBuilder::new(&uri).build();
+        assert!(!client.check_slow("GetProfiles", Duration::from_secs(60), &response));
+
+        let client = ClientBuilder::new(&uri)
+            .slow_call_threshold(Some(Duration::from_secs(2)))
+            .build();
+        assert!(!client.check_slow("GetProfiles", Duration::from_secs(1), &response));
+        assert!(client.check_slow("GetProfiles", Duration::from_secs(2), &response));
+        assert!(client.check_slow(
+            "GetProfiles",
+            Duration::from_secs(3),
+            &Err(Error::Timeout("timed out".to_string()))
+        ));
+    }
+
+    #[test]
+    fn connection_events() {
+        let events = Arc::new(Mutex::new(vec![]));
+        let uri = Url::parse("https://192.168.0.2/onvif/device_service").unwrap();
+        let client = ClientBuilder::new(&uri)
+            .on_connection_event({
+                let events = events.clone();
+                move |event| events.lock().unwrap().push(event.clone())
+            })
+            .build();
+        let clone = client.clone();
+        let tls = TlsInfo {
+            peer_certificate: None,
+        };
+
+        client.reachable(&uri, Ok(Some(tls.clone())));
+        clone.reachable(&uri, Ok(Some(tls.clone())));
+        clone.reachable(&uri, Err("refused".to_string()));
+        client.reachable(&uri, Err("refused".to_string()));
+        client.reachable(&uri, Ok(Some(tls.clone())));
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 3);
+        assert_eq!(
+            events[0],
+            ConnectionEvent::Connected {
+                uri: uri.clone(),
+                tls: Some(tls.clone())
+            }
+        );
+        assert_eq!(
+            events[1],
+            ConnectionEvent::Refused {
+                uri: uri.clone(),
+                error: "refused".to_string()
+            }
+        );
+        assert!(matches!(
+            &events[2],
+            ConnectionEvent::Reconnected { tls: Some(t), .. } if *t == tls
+        ));
+    }
+
+    #[test]
+    fn default_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-gateway-token", HeaderValue::from_static("secret"));
+        headers.insert(
+            reqwest::header::USER_AGENT,
+            HeaderValue::from_static("overridden"),
+        );
+
+        let builder =
+            ClientBuilder::new(&Url::parse("http://localhost/onvif/device_service").unwrap())
+                .user_agent(HeaderValue::from_static("nvr/1.0"))
+                .default_headers(headers);
+
+        assert_eq!(builder.config.headers["x-gateway-token"], "secret");
+        assert_eq!(
+            builder.config.headers[reqwest::header::USER_AGENT],
+            "overridden"
+        );
+    }
+}
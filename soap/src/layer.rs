@@ -0,0 +1,125 @@
+//! Middleware for transports: a [`Layer`] wraps a [`Transport`] in another one which adds
+//! behaviour to every request, such as retries or rate limiting. Layers stack, and the result is
+//! a transport like any other, so all operations and helpers can use it.
+//!
+//! ```no_run
+//! # #[cfg(feature = "client")]
+//! # async fn example(device_service: onvif_soap::client::Client) {
+//! use onvif_soap::layer::{CircuitBreaker, MapRequest, RateLimit, Retry, TransportExt};
+//! use schema::devicemgmt;
+//! use std::time::Duration;
+//!
+//! let device_service = device_service
+//!     .with(MapRequest::new(|message: &str| message.replace("\t", " ")))
+//!     .with(Retry::new(3, Duration::from_millis(200)))
+//!     .with(CircuitBreaker::new(5, Duration::from_secs(30)))
+//!     .with(RateLimit::new(Duration::from_millis(100)));
+//! let hostname = devicemgmt::get_hostname(&device_service, &Default::default()).await;
+//! # }
+//! # fn main() {}
+//! ```
+//!
+//! Layers see the request and response elements, without the envelope. Authentication,
+//! redirections and HTTP headers are handled by the [client](crate::client) at the bottom of the
+//! stack, see its builder.
+
+use async_trait::async_trait;
+use schema::transport::{Error, Transport};
+
+#[cfg(feature = "client")]
+mod timing;
+
+#[cfg(feature = "client")]
+pub use timing::{Breaking, CircuitBreaker, RateLimit, RateLimited, Retry, Retrying};
+
+/// Wraps a transport in another.
+pub trait Layer<T> {
+    type Transport: Transport;
+
+    fn layer(&self, inner: T) -> Self::Transport;
+}
+
+pub trait TransportExt: Transport + Sized {
+    /// Wraps this transport with `layer`. Layers added later see requests first.
+    fn with<L: Layer<Self>>(self, layer: L) -> L::Transport {
+        layer.layer(self)
+    }
+}
+
+impl<T: Transport> TransportExt for T {}
+
+/// Rewrites every request, e.g. to work around a device quirk.
+#[derive(Clone, Debug)]
+pub struct MapRequest<F> {
+    map: F,
+}
+
+impl<F> MapRequest<F>
+where
+    F: Fn(&str) -> String + Clone + Send + Sync,
+{
+    pub fn new(map: F) -> Self {
+        Self { map }
+    }
+}
+
+impl<T, F> Layer<T> for MapRequest<F>
+where
+    T: Transport + Sync,
+    F: Fn(&str) -> String + Clone + Send + Sync,
+{
+    type Transport = MappedRequest<T, F>;
+
+    fn layer(&self, inner: T) -> MappedRequest<T, F> {
+        MappedRequest {
+            inner,
+            map: self.map.clone(),
+        }
+    }
+}
+
+pub struct MappedRequest<T, F> {
+    inner: T,
+    map: F,
+}
+
+#[async_trait]
+impl<T, F> Transport for MappedRequest<T, F>
+where
+    T: Transport + Sync,
+    F: Fn(&str) -> String + Send + Sync,
+{
+    async fn request(&self, message: &str) -> Result<String, Error> {
+        let message = (self.map)(message);
+        self.inner.request(&message).await
+    }
+}
+
+/// Logs every request and its outcome at the debug level.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Trace;
+
+impl<T: Transport + Sync> Layer<T> for Trace {
+    type Transport = Traced<T>;
+
+    fn layer(&self, inner: T) -> Traced<T> {
+        Traced { inner }
+    }
+}
+
+pub struct Traced<T> {
+    inner: T,
+}
+
+#[async_trait]
+impl<T: Transport + Sync> Transport for Traced<T> {
+    async fn request(&self, message: &str) -> Result<String, Error> {
+        tracing::debug!("request: {}", message);
+        let result = self.inner.request(message).await;
+        match &result {
+            Ok(response) => tracing::debug!("response: {}", response),
+            Err(e) => tracing::debug!("error: {}", e),
+        }
+        result
+    }
+}
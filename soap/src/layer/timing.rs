@@ -0,0 +1,332 @@
+//! Layers which wait between or instead of requests, and so depend on the timers of tokio. They're
+//! only available with the `client` feature.
+
+use super::Layer;
+use async_trait::async_trait;
+use schema::transport::{Error, Transport};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::time::{self, Instant};
+
+/// Retries requests that failed to connect or timed out, with a delay doubling after each
+/// attempt. Other errors, such as faults, are returned right away.
+#[derive(Clone, Debug)]
+pub struct Retry {
+    attempts: u32,
+    delay: Duration,
+}
+
+impl Retry {
+    /// Makes up to `attempts` attempts, waiting `delay` before the first retry.
+    pub fn new(attempts: u32, delay: Duration) -> Self {
+        Self {
+            attempts: attempts.max(1),
+            delay,
+        }
+    }
+}
+
+impl<T: Transport + Sync> Layer<T> for Retry {
+    type Transport = Retrying<T>;
+
+    fn layer(&self, inner: T) -> Retrying<T> {
+        Retrying {
+            inner,
+            retry: self.clone(),
+        }
+    }
+}
+
+pub struct Retrying<T> {
+    inner: T,
+    retry: Retry,
+}
+
+#[async_trait]
+impl<T: Transport + Sync> Transport for Retrying<T> {
+    async fn request(&self, message: &str) -> Result<String, Error> {
+        let mut delay = self.retry.delay;
+        let mut attempt = 1;
+        loop {
+            match self.inner.request(message).await {
+                Err(Error::Connection(e)) | Err(Error::Timeout(e))
+                    if attempt < self.retry.attempts =>
+                {
+                    tracing::debug!("attempt {} failed, retrying in {:?}: {}", attempt, delay, e);
+                    time::sleep(delay).await;
+                    delay *= 2;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Spaces requests by at least an interval, for devices that fault or hang when called too
+/// often. Requests wait their turn in the order they were made.
+#[derive(Clone, Debug)]
+pub struct RateLimit {
+    interval: Duration,
+}
+
+impl RateLimit {
+    pub fn new(interval: Duration) -> Self {
+        Self { interval }
+    }
+}
+
+impl<T: Transport + Sync> Layer<T> for RateLimit {
+    type Transport = RateLimited<T>;
+
+    fn layer(&self, inner: T) -> RateLimited<T> {
+        RateLimited {
+            inner,
+            interval: self.interval,
+            next: Mutex::new(None),
+        }
+    }
+}
+
+pub struct RateLimited<T> {
+    inner: T,
+    interval: Duration,
+    next: Mutex<Option<Instant>>,
+}
+
+#[async_trait]
+impl<T: Transport + Sync> Transport for RateLimited<T> {
+    async fn request(&self, message: &str) -> Result<String, Error> {
+        let start = {
+            let mut next = self.next.lock().unwrap();
+            let now = Instant::now();
+            let start = next.map_or(now, |next| next.max(now));
+            *next = Some(start + self.interval);
+            start
+        };
+        time::sleep_until(start).await;
+        self.inner.request(message).await
+    }
+}
+
+/// Fails requests right away while a device is down, rather than having each wait for its
+/// timeout. After `failures` consecutive requests fail to connect or time out, the circuit opens
+/// and requests fail with a connection error for `cooldown`. The next request then probes the
+/// device: the circuit closes if it gets through and opens again if it doesn't. Other requests
+/// fail right away while the probe is in flight.
+///
+/// Clones share their state, so layering the clients of all the services of a device with
+/// clones of the same breaker opens the circuit for all of them at once. Add it after a
+/// [`Retry`] layer, so that it counts requests whose retries all failed.
+#[derive(Clone, Debug)]
+pub struct CircuitBreaker {
+    failures: u32,
+    cooldown: Duration,
+    state: Arc<Mutex<Circuit>>,
+}
+
+#[derive(Debug)]
+enum Circuit {
+    Closed { failures: u32 },
+    Open { until: Instant },
+    Probing,
+}
+
+impl CircuitBreaker {
+    pub fn new(failures: u32, cooldown: Duration) -> Self {
+        Self {
+            failures: failures.max(1),
+            cooldown,
+            state: Arc::new(Mutex::new(Circuit::Closed { failures: 0 })),
+        }
+    }
+
+    /// Whether requests currently fail without reaching the device.
+    pub fn is_open(&self) -> bool {
+        !matches!(*self.state.lock().unwrap(), Circuit::Closed { .. })
+    }
+
+    /// Whether a request may go through, and whether it's a probe.
+    fn admit(&self) -> Option<bool> {
+        let mut state = self.state.lock().unwrap();
+        match *state {
+            Circuit::Closed { .. } => Some(false),
+            Circuit::Open { until } if Instant::now() >= until => {
+                *state = Circuit::Probing;
+                Some(true)
+            }
+            _ => None,
+        }
+    }
+
+    fn record(&self, failed: bool) {
+        let mut state = self.state.lock().unwrap();
+        let failures = match (&*state, failed) {
+            (_, false) => 0,
+            (Circuit::Closed { failures }, true) => failures + 1,
+            (_, true) => self.failures,
+        };
+        *state = if failures >= self.failures {
+            if let Circuit::Closed { .. } = *state {
+                tracing::warn!(
+                    "{} consecutive requests failed, opening the circuit for {:?}",
+                    failures,
+                    self.cooldown
+                );
+            }
+            Circuit::Open {
+                until: Instant::now() + self.cooldown,
+            }
+        } else {
+            Circuit::Closed { failures }
+        };
+    }
+}
+
+impl<T: Transport + Sync> Layer<T> for CircuitBreaker {
+    type Transport = Breaking<T>;
+
+    fn layer(&self, inner: T) -> Breaking<T> {
+        Breaking {
+            inner,
+            breaker: self.clone(),
+        }
+    }
+}
+
+pub struct Breaking<T> {
+    inner: T,
+    breaker: CircuitBreaker,
+}
+
+/// Reopens the circuit right away if a probe is dropped before it completes, so that the next
+/// request probes again.
+struct Probe<'a> {
+    breaker: Option<&'a CircuitBreaker>,
+}
+
+impl Drop for Probe<'_> {
+    fn drop(&mut self) {
+        if let Some(breaker) = self.breaker {
+            *breaker.state.lock().unwrap() = Circuit::Open {
+                until: Instant::now(),
+            };
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Transport + Sync> Transport for Breaking<T> {
+    async fn request(&self, message: &str) -> Result<String, Error> {
+        let mut probe = match self.breaker.admit() {
+            Some(true) => Probe {
+                breaker: Some(&self.breaker),
+            },
+            Some(false) => Probe { breaker: None },
+            None => return Err(Error::Connection("circuit open".to_string())),
+        };
+        let result = self.inner.request(message).await;
+        probe.breaker = None;
+        self.breaker.record(matches!(
+            result,
+            Err(Error::Connection(_)) | Err(Error::Timeout(_))
+        ));
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layer::{MapRequest, Trace, TransportExt};
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// Times out `failures` times, then echoes requests.
+    struct Flaky {
+        failures: u32,
+        requests: AtomicU32,
+    }
+
+    impl Flaky {
+        fn new(failures: u32) -> Self {
+            Self {
+                failures,
+                requests: AtomicU32::new(0),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Transport for Flaky {
+        async fn request(&self, message: &str) -> Result<String, Error> {
+            if self.requests.fetch_add(1, Ordering::SeqCst) < self.failures {
+                return Err(Error::Timeout("timed out".to_string()));
+            }
+            Ok(message.to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn retries() {
+        let transport = Flaky::new(2).with(Retry::new(3, Duration::from_millis(1)));
+        assert_eq!(transport.request("<a/>").await.unwrap(), "<a/>");
+        assert_eq!(transport.inner.requests.load(Ordering::SeqCst), 3);
+
+        let transport = Flaky::new(3).with(Retry::new(3, Duration::from_millis(1)));
+        assert!(matches!(
+            transport.request("<a/>").await,
+            Err(Error::Timeout(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn limits_rate() {
+        let transport = Flaky::new(0).with(RateLimit::new(Duration::from_millis(20)));
+        let start = Instant::now();
+        for _ in 0..3 {
+            transport.request("<a/>").await.unwrap();
+        }
+        assert!(start.elapsed() >= Duration::from_millis(40));
+    }
+
+    #[tokio::test]
+    async fn breaks_circuit() {
+        let breaker = CircuitBreaker::new(2, Duration::from_millis(20));
+        let device_service = Flaky::new(3).with(breaker.clone());
+        let media_service = Flaky::new(0).with(breaker.clone());
+
+        for _ in 0..2 {
+            assert!(matches!(
+                device_service.request("<a/>").await,
+                Err(Error::Timeout(_))
+            ));
+        }
+        assert!(breaker.is_open());
+        assert!(matches!(
+            media_service.request("<a/>").await,
+            Err(Error::Connection(_))
+        ));
+        assert_eq!(media_service.inner.requests.load(Ordering::SeqCst), 0);
+
+        // The probe fails and opens the circuit again, the next one closes it.
+        time::sleep(Duration::from_millis(20)).await;
+        assert!(device_service.request("<a/>").await.is_err());
+        assert_eq!(device_service.inner.requests.load(Ordering::SeqCst), 3);
+        assert!(device_service.request("<a/>").await.is_err());
+        time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(device_service.request("<a/>").await.unwrap(), "<a/>");
+        assert!(!breaker.is_open());
+        assert_eq!(media_service.request("<a/>").await.unwrap(), "<a/>");
+    }
+
+    #[tokio::test]
+    async fn stacks_layers() {
+        let transport = Flaky::new(1)
+            .with(Retry::new(2, Duration::from_millis(1)))
+            .with(MapRequest::new(|message: &str| message.replace("a", "b")))
+            .with(Trace);
+        assert_eq!(transport.request("<a/>").await.unwrap(), "<b/>");
+    }
+}
@@ -0,0 +1,188 @@
+//! SOAP messages and the HTTP client the operations of the `schema` crate are called through.
+//!
+//! Without the `client` feature, this crate doesn't depend on an HTTP client or an async runtime:
+//! a [`Transport`](schema::transport::Transport) can wrap requests with [`soap`], send them with
+//! any client and unwrap responses with [`unsoap`].
+
+#[cfg(test)]
+#[macro_use]
+extern crate yaserde_derive;
+
+pub mod auth;
+mod body;
+#[cfg(feature = "client")]
+pub mod client;
+pub mod layer;
+#[cfg(feature = "client")]
+mod metrics;
+pub mod operation;
+pub mod recorder;
+#[cfg(test)]
+mod tests;
+pub mod transport;
+#[cfg(test)]
+mod xml_eq;
+
+use auth::username_token::UsernameToken;
+use schema::{soap_envelope, transport::Fault};
+use xmltree::{Element, Namespace, XMLNode};
+
+const SOAP_URI: &str = "http://www.w3.org/2003/05/soap-envelope";
+
+#[derive(Debug)]
+pub enum Error {
+    ParseError,
+    EnvelopeNotFound,
+    BodyNotFound,
+    BodyIsEmpty,
+    Fault(soap_envelope::Fault),
+    InternalError(String),
+}
+
+#[derive(Debug)]
+pub struct Response {
+    pub response: Option<String>,
+}
+
+pub fn soap(xml: &str, username_token: &Option<UsernameToken>) -> Result<String, Error> {
+    let app_data = parse(xml)?;
+
+    let mut namespaces = app_data.namespaces.clone().unwrap_or_else(Namespace::empty);
+    namespaces.put("s", SOAP_URI);
+
+    let mut body = Element::new("Body");
+    body.prefix = Some("s".to_string());
+    body.children.push(XMLNode::Element(app_data));
+
+    let mut envelope = Element::new("Envelope");
+    envelope.namespaces = Some(namespaces);
+    envelope.prefix = Some("s".to_string());
+
+    if let Some(username_token) = username_token {
+        let mut header = Element::new("Header");
+        header.prefix = Some("s".to_string());
+        header
+            .children
+            .push(XMLNode::Element(parse(&username_token.to_xml())?));
+        envelope.children.push(XMLNode::Element(header));
+    }
+
+    envelope.children.push(XMLNode::Element(body));
+
+    xml_element_to_string(&envelope)
+}
+
+pub fn unsoap(xml: &str) -> Result<String, Error> {
+    let root = parse(xml)?;
+
+    if root.name != "Envelope" {
+        return Err(Error::EnvelopeNotFound);
+    }
+
+    let body = root.get_child("Body").ok_or(Error::BodyNotFound)?;
+
+    if let Some(fault) = body.get_child("Fault") {
+        let fault = deserialize_fault(fault)?;
+        return Err(Error::Fault(fault));
+    }
+
+    body.children
+        .iter()
+        .find_map(|node| match node {
+            XMLNode::Element(app_data) => Some(xml_element_to_string(app_data)),
+            _ => None,
+        })
+        .ok_or(Error::BodyIsEmpty)?
+}
+
+/// Like [`unsoap`], but copies the body element out of `xml` instead of building a tree of the
+/// whole envelope and serializing the body again. The tree takes several times the memory of the
+/// text, which matters for large responses, e.g. `GetEventProperties`, on small devices.
+///
+/// Namespaces declared by the envelope or the body are declared on the returned element. Faults
+/// are returned like [`unsoap`] does.
+pub fn extract_body(xml: &str) -> Result<String, Error> {
+    body::extract(xml)
+}
+
+/// Values of the code and every nested subcode of a SOAP fault, outermost first, e.g.
+/// `["env:Sender", "ter:InvalidArgVal", "ter:InvalidStreamSetup"]`. Empty unless `xml` is an
+/// envelope with a fault.
+pub fn fault_codes(xml: &str) -> Vec<String> {
+    let mut codes = vec![];
+    let root = match parse(xml) {
+        Ok(root) => root,
+        Err(_) => return codes,
+    };
+
+    let mut code = root
+        .get_child("Body")
+        .and_then(|body| body.get_child("Fault"))
+        .and_then(|fault| fault.get_child("Code"));
+    while let Some(c) = code {
+        if let Some(value) = c.get_child("Value").and_then(|v| v.get_text()) {
+            codes.push(value.trim().to_string());
+        }
+        code = c.get_child("Subcode");
+    }
+
+    codes
+}
+
+/// The fault of `xml`, with its codes, its first reason text and their [`OnvifFaultKind`].
+/// `None` unless `xml` is an envelope with a fault.
+///
+/// [`OnvifFaultKind`]: schema::transport::OnvifFaultKind
+pub fn fault(xml: &str) -> Option<Fault> {
+    let codes = fault_codes(xml);
+    if codes.is_empty() {
+        return None;
+    }
+
+    let reason = parse(xml).ok().and_then(|root| {
+        root.get_child("Body")?
+            .get_child("Fault")?
+            .get_child("Reason")?
+            .get_child("Text")?
+            .get_text()
+            .map(|text| text.trim().to_string())
+    });
+    Some(Fault::new(codes, reason))
+}
+
+/// Local name of the request element, e.g. `GetServices`.
+pub(crate) fn action(message: &str) -> &str {
+    let mut rest = message.trim_start();
+    // Skip the XML declaration, if any.
+    if rest.starts_with("<?") {
+        rest = rest
+            .find("?>")
+            .map_or("", |end| rest[end + 2..].trim_start());
+    }
+
+    let name = rest
+        .strip_prefix('<')
+        .and_then(|rest| {
+            rest.split(|c: char| c.is_whitespace() || c == '/' || c == '>')
+                .next()
+        })
+        .unwrap_or_default();
+
+    name.rsplit(':').next().unwrap_or_default()
+}
+
+fn parse(xml: &str) -> Result<Element, Error> {
+    Element::parse(xml.as_bytes()).map_err(|_| Error::ParseError)
+}
+
+fn xml_element_to_string(el: &Element) -> Result<String, Error> {
+    let mut out = vec![];
+    el.write(&mut out)
+        .map_err(|_| Error::InternalError("Could not write XML element".to_string()))?;
+    String::from_utf8(out).map_err(|e| Error::InternalError(e.to_string()))
+}
+
+fn deserialize_fault(envelope: &Element) -> Result<soap_envelope::Fault, Error> {
+    let string = xml_element_to_string(envelope)?;
+    yaserde::de::from_str(&string).map_err(Error::InternalError)
+}
@@ -0,0 +1,87 @@
+//! Instrumentation of requests, see the `metrics` module of the `onvif` crate for the metrics.
+
+#![cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+
+use schema::transport::Error;
+use std::time::Duration;
+
+pub(crate) fn request(
+    service: &str,
+    operation: &str,
+    duration: Duration,
+    result: &Result<String, Error>,
+) {
+    #[cfg(feature = "metrics")]
+    {
+        metrics::increment_counter!(
+            "onvif_requests_total",
+            "service" => service.to_string(),
+            "operation" => operation.to_string()
+        );
+        metrics::histogram!(
+            "onvif_request_duration_seconds",
+            duration.as_secs_f64(),
+            "service" => service.to_string(),
+            "operation" => operation.to_string()
+        );
+        if let Err(error) = result {
+            metrics::increment_counter!(
+                "onvif_request_errors_total",
+                "service" => service.to_string(),
+                "operation" => operation.to_string(),
+                "class" => error_class(error)
+            );
+        }
+    }
+}
+
+/// Sizes of the SOAP envelopes of a successful exchange, as sent and received. Responses are
+/// never compressed, since the client doesn't accept compressed encodings.
+pub(crate) fn payload(service: &str, operation: &str, request: usize, response: usize) {
+    #[cfg(feature = "metrics")]
+    {
+        metrics::histogram!(
+            "onvif_request_size_bytes",
+            request as f64,
+            "service" => service.to_string(),
+            "operation" => operation.to_string()
+        );
+        metrics::histogram!(
+            "onvif_response_size_bytes",
+            response as f64,
+            "service" => service.to_string(),
+            "operation" => operation.to_string()
+        );
+    }
+}
+
+pub(crate) fn slow(service: &str, operation: &str) {
+    #[cfg(feature = "metrics")]
+    metrics::increment_counter!(
+        "onvif_slow_requests_total",
+        "service" => service.to_string(),
+        "operation" => operation.to_string()
+    );
+}
+
+pub(crate) fn retry(reason: &'static str) {
+    #[cfg(feature = "metrics")]
+    metrics::increment_counter!("onvif_request_retries_total", "reason" => reason);
+}
+
+#[cfg(feature = "metrics")]
+fn error_class(error: &Error) -> &'static str {
+    match error {
+        Error::Serialization(_) => "serialization",
+        Error::Deserialization(_) => "deserialization",
+        Error::Authorization(_) => "authorization",
+        Error::Redirection(_) => "redirection",
+        Error::Connection(_) => "connection",
+        Error::Timeout(_) => "timeout",
+        Error::Protocol(_) => "protocol",
+        Error::Fault(_) => "fault",
+        Error::Unsupported { .. } => "unsupported",
+        Error::DeviceIdentityChanged { .. } => "identity_changed",
+        Error::Other(_) => "other",
+    }
+}
@@ -0,0 +1,211 @@
+//! Sans-IO requests: the HTTP headers and body of an operation, and the parsing of its response,
+//! for network stacks the [client](crate::client) doesn't run on, or to replay captured traffic.
+//!
+//! ```
+//! use onvif_soap::operation::{self, Auth};
+//! use schema::devicemgmt;
+//!
+//! let request = operation::build_request(&devicemgmt::GetHostname {}, &Auth::None).unwrap();
+//! assert_eq!(
+//!     request.action,
+//!     "http://www.onvif.org/ver10/device/wsdl/GetHostname"
+//! );
+//! // POST `request.body` with `request.headers` to the device service, then:
+//! # let response_body = br#"<s:Envelope xmlns:s="http://www.w3.org/2003/05/soap-envelope"><s:Body><tds:GetHostnameResponse xmlns:tds="http://www.onvif.org/ver10/device/wsdl" xmlns:tt="http://www.onvif.org/ver10/schema"><tds:HostnameInformation><tt:FromDHCP>false</tt:FromDHCP></tds:HostnameInformation></tds:GetHostnameResponse></s:Body></s:Envelope>"#;
+//! let response: devicemgmt::GetHostnameResponse =
+//!     operation::parse_response(response_body).unwrap();
+//! ```
+//!
+//! HTTP Digest authentication needs a challenge from the device, so it is left to the caller.
+
+use crate::auth::username_token::{PasswordType, UsernameToken};
+use schema::transport::Error;
+use xmltree::Element;
+use yaserde::{YaDeserialize, YaSerialize};
+
+/// Credentials to send with a request.
+#[derive(Clone, Copy)]
+pub enum Auth<'a> {
+    None,
+
+    /// A WS-Security UsernameToken in the SOAP header, with a fresh nonce.
+    UsernameToken {
+        username: &'a str,
+        password: &'a str,
+        password_type: PasswordType,
+    },
+
+    /// HTTP Basic authentication, which should only be used over TLS.
+    Basic {
+        username: &'a str,
+        password: &'a str,
+    },
+}
+
+/// An HTTP POST request of an operation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SoapRequest {
+    /// SOAP action of the operation, also in the `Content-Type` header. Empty if the request
+    /// isn't one of the generated operations or its binding doesn't declare one.
+    pub action: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+/// Wraps `request` in an envelope with the headers to send it with.
+pub fn build_request<R: YaSerialize>(request: &R, auth: &Auth) -> Result<SoapRequest, Error> {
+    let message = yaserde::ser::to_string(request).map_err(Error::Serialization)?;
+    let message = match message.split_once("?>") {
+        Some((_, element)) if message.starts_with("<?xml") => element.to_string(),
+        _ => message,
+    };
+
+    let action = Element::parse(message.as_bytes())
+        .ok()
+        .and_then(|root| schema::operations::by_request(root.namespace.as_deref()?, &root.name))
+        .map(|operation| operation.action.to_string())
+        .unwrap_or_default();
+
+    let username_token = match auth {
+        Auth::UsernameToken {
+            username,
+            password,
+            password_type,
+        } => Some(UsernameToken::new(username, password, *password_type)),
+        _ => None,
+    };
+    let body = crate::soap(&message, &username_token)
+        .map_err(|e| Error::Serialization(format!("{:?}", e)))?;
+
+    let mut content_type = "application/soap+xml; charset=utf-8".to_string();
+    if !action.is_empty() {
+        content_type += &format!("; action=\"{}\"", action);
+    }
+    let mut headers = vec![("Content-Type".to_string(), content_type)];
+    if let Auth::Basic { username, password } = auth {
+        headers.push((
+            "Authorization".to_string(),
+            format!(
+                "Basic {}",
+                base64::encode(format!("{}:{}", username, password))
+            ),
+        ));
+    }
+
+    Ok(SoapRequest {
+        action,
+        headers,
+        body,
+    })
+}
+
+/// Parses the body of a response, whatever its HTTP status. Faults are returned as
+/// [`Error::Fault`].
+pub fn parse_response<S: YaDeserialize>(body: &[u8]) -> Result<S, Error> {
+    let text = std::str::from_utf8(body).map_err(|e| Error::Deserialization(e.to_string()))?;
+    let message = crate::extract_body(text).map_err(|e| match crate::fault(text) {
+        Some(fault) => Error::Fault(fault),
+        None => Error::Protocol(format!("{:?}", e)),
+    })?;
+    yaserde::de::from_str(&message).map_err(Error::Deserialization)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use schema::{devicemgmt, transport::OnvifFaultKind};
+
+    #[test]
+    fn builds_requests() {
+        let request = build_request(
+            &devicemgmt::GetHostname {},
+            &Auth::UsernameToken {
+                username: "admin",
+                password: "secret",
+                password_type: PasswordType::Digest,
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            request.action,
+            "http://www.onvif.org/ver10/device/wsdl/GetHostname"
+        );
+        assert_eq!(
+            request.headers,
+            vec![(
+                "Content-Type".to_string(),
+                "application/soap+xml; charset=utf-8; \
+                 action=\"http://www.onvif.org/ver10/device/wsdl/GetHostname\""
+                    .to_string()
+            )]
+        );
+        assert!(request.body.contains("GetHostname"));
+        assert!(request
+            .body
+            .contains("<wsse:Username>admin</wsse:Username>"));
+        assert!(!request.body.contains("secret"));
+
+        let request = build_request(
+            &devicemgmt::GetHostname {},
+            &Auth::Basic {
+                username: "admin",
+                password: "secret",
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            request.headers[1],
+            (
+                "Authorization".to_string(),
+                "Basic YWRtaW46c2VjcmV0".to_string()
+            )
+        );
+        assert!(!request.body.contains("Security"));
+    }
+
+    #[test]
+    fn parses_responses() {
+        let response: devicemgmt::GetHostnameResponse = parse_response(
+            br#"
+            <s:Envelope xmlns:s="http://www.w3.org/2003/05/soap-envelope">
+                <s:Body>
+                    <tds:GetHostnameResponse
+                        xmlns:tds="http://www.onvif.org/ver10/device/wsdl"
+                        xmlns:tt="http://www.onvif.org/ver10/schema">
+                        <tds:HostnameInformation>
+                            <tt:FromDHCP>false</tt:FromDHCP>
+                            <tt:Name>camera</tt:Name>
+                        </tds:HostnameInformation>
+                    </tds:GetHostnameResponse>
+                </s:Body>
+            </s:Envelope>
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            response.hostname_information.name.as_deref(),
+            Some("camera")
+        );
+
+        let fault = parse_response::<devicemgmt::GetHostnameResponse>(
+            br#"
+            <s:Envelope xmlns:s="http://www.w3.org/2003/05/soap-envelope"
+                xmlns:ter="http://www.onvif.org/ver10/error">
+                <s:Body>
+                    <s:Fault>
+                        <s:Code>
+                            <s:Value>s:Receiver</s:Value>
+                            <s:Subcode><s:Value>ter:ActionNotSupported</s:Value></s:Subcode>
+                        </s:Code>
+                        <s:Reason><s:Text xml:lang="en">Not supported</s:Text></s:Reason>
+                    </s:Fault>
+                </s:Body>
+            </s:Envelope>
+            "#,
+        );
+        assert!(matches!(
+            fault,
+            Err(Error::Fault(f)) if f.kind == OnvifFaultKind::ActionNotSupported
+        ));
+    }
+}
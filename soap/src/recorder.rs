@@ -0,0 +1,172 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+};
+use url::Url;
+use xmltree::{Element, XMLNode};
+
+/// Writes every SOAP exchange of a client to its own file in a directory, for attaching to bug
+/// reports. Passwords and nonces are redacted from requests.
+///
+/// Files are named so that they sort chronologically; once there are more than `max_files`, the
+/// oldest are deleted.
+#[derive(Debug)]
+pub struct Recorder {
+    dir: PathBuf,
+    max_files: usize,
+    sequence: AtomicU64,
+}
+
+impl Recorder {
+    pub fn new(dir: impl Into<PathBuf>, max_files: usize) -> Self {
+        Self {
+            dir: dir.into(),
+            max_files,
+            sequence: AtomicU64::new(0),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Records one exchange. `status` and `response` are `None` if no response was received.
+    pub fn record(
+        &self,
+        uri: &Url,
+        request: &str,
+        status: Option<u16>,
+        response: Option<&str>,
+    ) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+
+        let now = chrono::Utc::now();
+        let sequence = self.sequence.fetch_add(1, Ordering::Relaxed);
+        let path = self.dir.join(format!(
+            "{}-{:06}.xml",
+            now.format("%Y%m%dT%H%M%S%.6fZ"),
+            sequence
+        ));
+
+        let mut file = fs::File::create(&path)?;
+        writeln!(file, "<!-- {} POST {} -->", now.to_rfc3339(), uri)?;
+        writeln!(file, "<!-- request -->")?;
+        writeln!(file, "{}", redact(request))?;
+        match status {
+            Some(status) => writeln!(file, "<!-- response: HTTP {} -->", status)?,
+            None => writeln!(file, "<!-- no response -->")?,
+        }
+        if let Some(response) = response {
+            writeln!(file, "{}", response)?;
+        }
+
+        self.rotate()
+    }
+
+    fn rotate(&self) -> io::Result<()> {
+        let mut files: Vec<PathBuf> = fs::read_dir(&self.dir)?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().map_or(false, |ext| ext == "xml"))
+            .collect();
+
+        if files.len() <= self.max_files {
+            return Ok(());
+        }
+
+        files.sort();
+        for path in &files[..files.len() - self.max_files] {
+            fs::remove_file(path)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Replaces the content of `Password` and `Nonce` elements, which covers both WS-UsernameToken
+/// headers and requests such as `CreateUsers` and `SetUser`.
+fn redact(xml: &str) -> String {
+    let mut root = match Element::parse(xml.as_bytes()) {
+        Ok(root) => root,
+        // Not expected since requests are generated by us, but don't record anything unredacted.
+        Err(_) => return "<!-- request could not be parsed for redaction -->".to_string(),
+    };
+
+    redact_element(&mut root);
+
+    let mut out = vec![];
+    match root.write(&mut out) {
+        Ok(()) => String::from_utf8_lossy(&out).into_owned(),
+        Err(_) => "<!-- request could not be written after redaction -->".to_string(),
+    }
+}
+
+fn redact_element(element: &mut Element) {
+    if element.name == "Password" || element.name == "Nonce" {
+        element.children = vec![XMLNode::Text("[redacted]".to_string())];
+        return;
+    }
+
+    for child in &mut element.children {
+        if let XMLNode::Element(child) = child {
+            redact_element(child);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::username_token::{PasswordType, UsernameToken};
+
+    #[test]
+    fn redacts_credentials() {
+        let request = crate::soap(
+            r#"<tds:CreateUsers xmlns:tds="http://www.onvif.org/ver10/device/wsdl"
+                                xmlns:tt="http://www.onvif.org/ver10/schema">
+                   <tds:User>
+                       <tt:Username>viewer</tt:Username>
+                       <tt:Password>new-user-secret</tt:Password>
+                       <tt:UserLevel>User</tt:UserLevel>
+                   </tds:User>
+               </tds:CreateUsers>"#,
+            &Some(UsernameToken::new(
+                "admin",
+                "admin-secret",
+                PasswordType::Digest,
+            )),
+        )
+        .unwrap();
+
+        let redacted = redact(&request);
+
+        assert!(!redacted.contains("new-user-secret"));
+        assert_eq!(redacted.matches("[redacted]").count(), 3);
+        assert!(redacted.contains("<tt:Username>viewer</tt:Username>"));
+        assert!(redacted.contains("admin"));
+    }
+
+    #[test]
+    fn rotates_files() {
+        let dir = std::env::temp_dir().join(format!("onvif-recorder-{}", uuid::Uuid::new_v4()));
+        let recorder = Recorder::new(&dir, 2);
+        let uri = Url::parse("http://192.168.0.2/onvif/device_service").unwrap();
+
+        for i in 0..5 {
+            recorder
+                .record(&uri, "<a/>", Some(200), Some(&format!("<r>{}</r>", i)))
+                .unwrap();
+        }
+
+        let mut files: Vec<_> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|e| e.unwrap().path())
+            .collect();
+        files.sort();
+        assert_eq!(files.len(), 2);
+        assert!(fs::read_to_string(&files[1]).unwrap().contains("<r>4</r>"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
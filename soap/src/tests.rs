@@ -0,0 +1,230 @@
+use super::*;
+use crate::xml_eq::assert_xml_eq;
+use schema::{
+    soap_envelope::{FaultcodeEnum, Reasontext, Subcode},
+    transport::OnvifFaultKind,
+};
+
+#[test]
+fn test_soap() {
+    let app_data = r#"
+        <my:Book xmlns:my="http://www.example.my/schema">
+            <my:Title>Such book</my:Title>
+            <my:Pages>42</my:Pages>
+        </my:Book>
+        "#;
+
+    let expected = r#"
+        <?xml version="1.0" encoding="UTF-8"?>
+        <s:Envelope xmlns:s="http://www.w3.org/2003/05/soap-envelope"
+                    xmlns:my="http://www.example.my/schema">
+            <s:Body>
+                <my:Book>
+                    <my:Title>Such book</my:Title>
+                    <my:Pages>42</my:Pages>
+                </my:Book>
+            </s:Body>
+        </s:Envelope>
+        "#;
+
+    let actual = soap(app_data, &None).unwrap();
+
+    println!("{}", actual);
+    println!("{}", expected);
+
+    assert_xml_eq(actual.as_str(), expected);
+}
+
+#[test]
+fn test_unsoap() {
+    #[derive(Default, PartialEq, Debug, YaDeserialize)]
+    #[yaserde(prefix = "my", namespace = "my: http://www.example.my/schema")]
+    pub struct Book {
+        #[yaserde(prefix = "my", rename = "Title")]
+        pub title: String,
+
+        #[yaserde(prefix = "my", rename = "Pages")]
+        pub pages: i32,
+    }
+
+    let input = r#"
+        <?xml version="1.0" encoding="utf-8"?>
+        <s:Envelope xmlns:s="http://www.w3.org/2003/05/soap-envelope"
+                    xmlns:my="http://www.example.my/schema">
+            <s:Body>
+                <my:Book>
+                    <my:Title>Such book</my:Title>
+                    <my:Pages>42</my:Pages>
+                </my:Book>
+            </s:Body>
+        </s:Envelope>
+        "#;
+
+    let actual = unsoap(input).unwrap();
+
+    println!("{:?}", actual);
+
+    let parsed: Book = yaserde::de::from_str(&actual).unwrap();
+
+    assert_eq!(parsed.title, "Such book");
+    assert_eq!(parsed.pages, 42);
+}
+
+#[test]
+fn extracts_body() {
+    let input = r#"
+        <?xml version="1.0" encoding="utf-8"?>
+        <!-- Comments <s:Body> aren't markup. -->
+        <s:Envelope xmlns:s="http://www.w3.org/2003/05/soap-envelope"
+                    xmlns:tt='http://www.onvif.org/ver10/schema'
+                    xmlns:my="http://www.example.my/old">
+            <s:Header>
+                <wsa:Action xmlns:wsa="http://www.w3.org/2005/08/addressing">Body</wsa:Action>
+            </s:Header>
+            <s:Body xmlns:my="http://www.example.my/schema">
+                <my:Book my:Note="a > b" xmlns="http://www.example.my/default">
+                    <my:Title><![CDATA[</my:Book>]]></my:Title>
+                    <tt:Pages>42</tt:Pages>
+                    <Cover/>
+                </my:Book>
+            </s:Body>
+        </s:Envelope>
+        "#;
+
+    let actual = extract_body(input).unwrap();
+    assert!(actual.starts_with("<my:Book xmlns:s="), "{}", actual);
+    assert!(actual.ends_with("</my:Book>"), "{}", actual);
+    assert!(!actual.contains("example.my/old"), "{}", actual);
+    assert_xml_eq(&actual, &unsoap(input).unwrap());
+
+    assert!(matches!(
+        extract_body("<Envelope><Body/></Envelope>"),
+        Err(Error::BodyIsEmpty)
+    ));
+    assert!(matches!(
+        extract_body("<Envelope><Header/></Envelope>"),
+        Err(Error::BodyNotFound)
+    ));
+    assert!(matches!(
+        extract_body("<Book/>"),
+        Err(Error::EnvelopeNotFound)
+    ));
+    assert!(matches!(
+        extract_body("<Envelope><Body><Book></Body></Envelope>"),
+        Err(Error::ParseError)
+    ));
+    assert!(matches!(extract_body("not xml"), Err(Error::ParseError)));
+}
+
+#[test]
+fn test_get_fault() {
+    let response = r#"
+        <?xml version="1.0" ?>
+        <soapenv:Fault
+            xmlns:soapenv="http://www.w3.org/2003/05/soap-envelope"
+            xmlns:ter="http://www.onvif.org/ver10/error"
+            xmlns:xs="http://www.w3.org/2000/10/XMLSchema">
+            <soapenv:Code>
+                <soapenv:Value>tns:DataEncodingUnknown</soapenv:Value>
+                <soapenv:Subcode>
+                    <soapenv:Value>ter:fault subcode</soapenv:Value>
+                    <soapenv:Subcode>
+                        <soapenv:Value>ter:fault subcode</soapenv:Value>
+                    </soapenv:Subcode>
+                </soapenv:Subcode>
+            </soapenv:Code>
+            <soapenv:Reason>
+                <soapenv:Text xml:lang="en">fault reason 1</soapenv:Text>
+                <soapenv:Text xml:lang="en">fault reason 2</soapenv:Text>
+            </soapenv:Reason>
+            <soapenv:Node>http://www.w3.org/2003/05/soap-envelope/node/ultimateReceiver</soapenv:Node>
+            <soapenv:Role>http://www.w3.org/2003/05/soap-envelope/role/ultimateReceiver</soapenv:Role>
+            <soapenv:Detail>
+                <soapenv:Text>fault detail</soapenv:Text>
+            </soapenv:Detail>
+        </soapenv:Fault>
+        "#;
+
+    let envelope = xmltree::Element::parse(response.as_bytes()).unwrap();
+
+    let fault = deserialize_fault(&envelope).unwrap();
+
+    assert_eq!(
+        fault.code.value,
+        FaultcodeEnum("tns:DataEncodingUnknown".to_string())
+    );
+    assert_eq!(
+        fault.code.subcode,
+        Some(Subcode {
+            value: "ter:fault subcode".to_string(),
+            // subcode: Vec::new()
+        })
+    );
+    assert_eq!(
+        fault.reason.text,
+        vec![
+            Reasontext {
+                lang: "en".to_string()
+            },
+            Reasontext {
+                lang: "en".to_string()
+            }
+        ]
+    );
+}
+
+#[test]
+fn test_fault_codes() {
+    let response = r#"
+        <soapenv:Envelope xmlns:soapenv="http://www.w3.org/2003/05/soap-envelope"
+            xmlns:ter="http://www.onvif.org/ver10/error">
+            <soapenv:Body>
+                <soapenv:Fault>
+                    <soapenv:Code>
+                        <soapenv:Value>soapenv:Sender</soapenv:Value>
+                        <soapenv:Subcode>
+                            <soapenv:Value>ter:InvalidArgVal</soapenv:Value>
+                            <soapenv:Subcode>
+                                <soapenv:Value>ter:InvalidStreamSetup</soapenv:Value>
+                            </soapenv:Subcode>
+                        </soapenv:Subcode>
+                    </soapenv:Code>
+                    <soapenv:Reason>
+                        <soapenv:Text xml:lang="en">Stream setup not supported</soapenv:Text>
+                    </soapenv:Reason>
+                </soapenv:Fault>
+            </soapenv:Body>
+        </soapenv:Envelope>
+        "#;
+
+    assert_eq!(
+        fault_codes(response),
+        vec![
+            "soapenv:Sender",
+            "ter:InvalidArgVal",
+            "ter:InvalidStreamSetup"
+        ]
+    );
+    assert!(fault_codes("<Envelope><Body/></Envelope>").is_empty());
+    assert!(fault_codes("not xml").is_empty());
+
+    let parsed = fault(response).unwrap();
+    assert_eq!(parsed.kind, OnvifFaultKind::InvalidStreamSetup);
+    assert_eq!(parsed.reason.as_deref(), Some("Stream setup not supported"));
+    assert!(fault("not xml").is_none());
+}
+
+#[test]
+fn request_action() {
+    assert_eq!(
+        action(
+            r#"<tds:GetServices xmlns:tds="http://www.onvif.org/ver10/device/wsdl"><tds:IncludeCapability>false</tds:IncludeCapability></tds:GetServices>"#
+        ),
+        "GetServices"
+    );
+    assert_eq!(
+        action(r#"<?xml version="1.0"?> <GetSystemDateAndTime/>"#),
+        "GetSystemDateAndTime"
+    );
+    assert_eq!(action("not xml"), "");
+}
@@ -0,0 +1,246 @@
+//! Record/replay transports for testing ONVIF logic without devices.
+//!
+//! Wrap a real transport in a [`RecordingTransport`], run the code under test against a device
+//! once and [`save`](RecordingTransport::save) the exchanges as a JSON fixture. Tests then load
+//! the fixture into a [`Replayer`], which answers requests with the recorded responses. Traces of
+//! a client for bug reports are written by [`Recorder`](crate::recorder::Recorder) instead.
+
+use crate::action;
+use async_trait::async_trait;
+use schema::transport::{Error, Transport};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::File,
+    io::{self, BufReader, BufWriter},
+    path::Path,
+    sync::Mutex,
+};
+use xmltree::{Element, XMLNode};
+
+/// A recorded request and its response, without the SOAP envelope.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Exchange {
+    /// Local name of the request element, e.g. `GetProfiles`.
+    pub action: String,
+    pub request: String,
+    pub response: String,
+}
+
+impl Exchange {
+    pub fn new(request: &str, response: &str) -> Self {
+        Self {
+            action: action(request).to_string(),
+            request: request.to_string(),
+            response: response.to_string(),
+        }
+    }
+}
+
+/// Passes requests to an inner transport and keeps the successful exchanges.
+/// Failed requests are passed through without being recorded.
+pub struct RecordingTransport<T> {
+    inner: T,
+    exchanges: Mutex<Vec<Exchange>>,
+}
+
+impl<T> RecordingTransport<T> {
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            exchanges: Mutex::new(vec![]),
+        }
+    }
+
+    pub fn exchanges(&self) -> Vec<Exchange> {
+        self.exchanges.lock().unwrap().clone()
+    }
+
+    /// Writes the exchanges recorded so far as a JSON fixture.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let file = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(file, &self.exchanges()).map_err(io::Error::from)
+    }
+}
+
+#[async_trait]
+impl<T: Transport + Sync> Transport for RecordingTransport<T> {
+    async fn request(&self, message: &str) -> Result<String, Error> {
+        let response = self.inner.request(message).await?;
+        self.exchanges
+            .lock()
+            .unwrap()
+            .push(Exchange::new(message, &response));
+        Ok(response)
+    }
+}
+
+/// Answers requests from recorded exchanges.
+///
+/// A request matches an exchange with the same action and request shape: the same elements,
+/// attributes and text, ignoring namespace prefixes and whitespace. Matching exchanges are
+/// replayed in recording order; once all have been used, the last one is repeated.
+pub struct Replayer {
+    exchanges: Vec<(Exchange, String)>,
+    used: Mutex<Vec<bool>>,
+}
+
+impl Replayer {
+    pub fn new(exchanges: Vec<Exchange>) -> Self {
+        let used = Mutex::new(vec![false; exchanges.len()]);
+        let exchanges = exchanges
+            .into_iter()
+            .map(|e| {
+                let shape = shape(&e.request);
+                (e, shape)
+            })
+            .collect();
+
+        Self { exchanges, used }
+    }
+
+    /// Loads a fixture written by [`RecordingTransport::save`].
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = BufReader::new(File::open(path)?);
+        let exchanges: Vec<Exchange> = serde_json::from_reader(file).map_err(io::Error::from)?;
+        Ok(Self::new(exchanges))
+    }
+}
+
+#[async_trait]
+impl Transport for Replayer {
+    async fn request(&self, message: &str) -> Result<String, Error> {
+        let action = action(message);
+        let shape = shape(message);
+        let matching: Vec<usize> = self
+            .exchanges
+            .iter()
+            .enumerate()
+            .filter(|(_, (e, s))| e.action == action && *s == shape)
+            .map(|(i, _)| i)
+            .collect();
+
+        let mut used = self.used.lock().unwrap();
+        let index = match matching
+            .iter()
+            .find(|&&i| !used[i])
+            .or_else(|| matching.last())
+        {
+            Some(&index) => index,
+            None => {
+                return Err(Error::Other(format!(
+                    "no recorded exchange matches {} request: {}",
+                    action, message
+                )))
+            }
+        };
+        used[index] = true;
+
+        Ok(self.exchanges[index].0.response.clone())
+    }
+}
+
+/// Canonical form of a request for matching. Unparsable requests only match themselves.
+fn shape(request: &str) -> String {
+    match Element::parse(request.as_bytes()) {
+        Ok(root) => {
+            let mut out = String::new();
+            write_shape(&root, &mut out);
+            out
+        }
+        Err(_) => request.to_string(),
+    }
+}
+
+fn write_shape(element: &Element, out: &mut String) {
+    out.push_str(&element.name);
+
+    let mut attributes: Vec<_> = element.attributes.iter().collect();
+    attributes.sort();
+    for (name, value) in attributes {
+        out.push_str(&format!(" {}={:?}", name, value));
+    }
+
+    out.push('{');
+    for child in &element.children {
+        match child {
+            XMLNode::Element(child) => write_shape(child, out),
+            XMLNode::Text(text) | XMLNode::CData(text) => {
+                out.push_str(&format!("{:?}", text.trim()))
+            }
+            _ => {}
+        }
+    }
+    out.push('}');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FakeTransport;
+
+    #[async_trait]
+    impl Transport for FakeTransport {
+        async fn request(&self, message: &str) -> Result<String, Error> {
+            Ok(format!("<Response>{}</Response>", action(message)))
+        }
+    }
+
+    #[tokio::test]
+    async fn record_and_replay() {
+        let recorder = RecordingTransport::new(FakeTransport);
+
+        recorder
+            .request(r#"<tds:GetHostname xmlns:tds="http://www.onvif.org/ver10/device/wsdl"/>"#)
+            .await
+            .unwrap();
+        recorder
+            .request(
+                r#"<trt:GetSnapshotUri xmlns:trt="http://www.onvif.org/ver10/media/wsdl">
+                       <trt:ProfileToken>main</trt:ProfileToken>
+                   </trt:GetSnapshotUri>"#,
+            )
+            .await
+            .unwrap();
+
+        let path =
+            std::env::temp_dir().join(format!("onvif-fixture-{}.json", uuid::Uuid::new_v4()));
+        recorder.save(&path).unwrap();
+        let replayer = Replayer::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        // Prefixes and whitespace don't matter.
+        assert_eq!(
+            replayer
+                .request(
+                    r#"<media:GetSnapshotUri xmlns:media="http://www.onvif.org/ver10/media/wsdl"><media:ProfileToken>main</media:ProfileToken></media:GetSnapshotUri>"#
+                )
+                .await
+                .unwrap(),
+            "<Response>GetSnapshotUri</Response>"
+        );
+
+        // Exchanges can be replayed more than once.
+        for _ in 0..2 {
+            assert_eq!(
+                replayer
+                    .request(
+                        r#"<tds:GetHostname xmlns:tds="http://www.onvif.org/ver10/device/wsdl"/>"#
+                    )
+                    .await
+                    .unwrap(),
+                "<Response>GetHostname</Response>"
+            );
+        }
+
+        // Different arguments don't match.
+        assert!(replayer
+            .request(
+                r#"<trt:GetSnapshotUri xmlns:trt="http://www.onvif.org/ver10/media/wsdl">
+                       <trt:ProfileToken>sub</trt:ProfileToken>
+                   </trt:GetSnapshotUri>"#
+            )
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,76 @@
+//! Derives `Clone`, `Eq` and `Hash` for the string newtypes used as identifiers.
+//!
+//! Tokens, names and descriptions are kept in maps and sets and copied between requests, which
+//! the generated derives of `Default, PartialEq, Debug` don't allow.
+
+const NEWTYPES: [&str; 3] = ["ReferenceToken", "Name", "Description"];
+
+/// Adds the missing derives to the `#[derive(..)]` line directly above each of [`NEWTYPES`].
+pub fn hashable(code: &str) -> String {
+    let lines: Vec<&str> = code.lines().collect();
+
+    let mut out = String::with_capacity(code.len());
+    for (i, line) in lines.iter().enumerate() {
+        match lines.get(i + 1) {
+            Some(next) if newtype(next) => out += &derive(line),
+            _ => out += line,
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn newtype(line: &str) -> bool {
+    NEWTYPES
+        .iter()
+        .any(|name| line.trim() == format!("pub struct {}(pub String);", name))
+}
+
+/// `line` with `Clone` after `Default`, `Eq` after `PartialEq` and `Hash` after `Eq`, unless
+/// it's not a derive or already has them.
+fn derive(line: &str) -> String {
+    let trimmed = line.trim_start();
+    let indent = &line[..line.len() - trimmed.len()];
+    let traits = match trimmed
+        .strip_prefix("#[derive(")
+        .and_then(|rest| rest.strip_suffix(")]"))
+    {
+        Some(traits) => traits,
+        None => return line.to_string(),
+    };
+
+    let mut traits: Vec<&str> = traits.split(", ").collect();
+    for &(after, missing) in &[("Default", "Clone"), ("PartialEq", "Eq"), ("Eq", "Hash")] {
+        if traits.contains(&missing) {
+            continue;
+        }
+        let position = traits.iter().position(|t| *t == after).map_or(0, |i| i + 1);
+        traits.insert(position, missing);
+    }
+    format!("{}#[derive({})]", indent, traits.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derives_hash() {
+        let code = "\
+#[derive(Default, PartialEq, Debug, UtilsTupleIo, UtilsDefaultSerde)]
+pub struct Description(pub String);
+
+#[derive(Default, PartialEq, Debug, UtilsTupleIo, UtilsDefaultSerde)]
+pub struct TrackReference(pub ReferenceToken);
+";
+        let expected = "\
+#[derive(Default, Clone, PartialEq, Eq, Hash, Debug, UtilsTupleIo, UtilsDefaultSerde)]
+pub struct Description(pub String);
+
+#[derive(Default, PartialEq, Debug, UtilsTupleIo, UtilsDefaultSerde)]
+pub struct TrackReference(pub ReferenceToken);
+";
+        assert_eq!(hashable(code), expected);
+        assert_eq!(hashable(expected), expected);
+    }
+}
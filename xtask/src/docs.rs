@@ -0,0 +1,153 @@
+//! Turns the comments the generator writes for `xs:documentation` into doc comments.
+//!
+//! The generator emits annotations as plain `//` comments right above the types, fields,
+//! variants and operations they document. It also comments out code it can't generate, such as
+//! type aliases and unsupported operations, which has to stay a plain comment.
+
+/// Rewrites every run of documentation comments directly above an item, field or variant as
+/// `///` comments. Commented out code above the documentation is left as it is.
+pub fn doc_comments(code: &str) -> String {
+    let lines: Vec<&str> = code.lines().collect();
+    let mut docs = vec![false; lines.len()];
+
+    for (i, line) in lines.iter().enumerate() {
+        if !documentable(line) {
+            continue;
+        }
+
+        let mut start = i;
+        while start > 0 && prose(lines[start - 1]).is_some() {
+            start -= 1;
+        }
+        // A run of empty comment lines isn't documentation either.
+        while start < i && prose(lines[start]) == Some("") {
+            start += 1;
+        }
+        for doc in &mut docs[start..i] {
+            *doc = true;
+        }
+    }
+
+    let mut out = String::with_capacity(code.len() + code.len() / 50);
+    for (line, doc) in lines.iter().zip(docs) {
+        if doc {
+            let indent = &line[..line.len() - line.trim_start().len()];
+            out += indent;
+            match prose(line) {
+                Some("") | None => out += "///",
+                Some(text) => {
+                    out += "/// ";
+                    out += text;
+                }
+            }
+        } else {
+            out += line;
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// The text of a comment line that reads as documentation rather than commented out code.
+fn prose(line: &str) -> Option<&str> {
+    let comment = line.trim_start().strip_prefix("//")?;
+    if comment.starts_with('/') || comment.starts_with('!') {
+        return None;
+    }
+    if comment.trim().is_empty() {
+        return Some("");
+    }
+
+    // Commented out code is indented, e.g. the fields of a commented out struct.
+    let text = comment.strip_prefix(' ')?;
+    let code = text.starts_with(char::is_whitespace)
+        || ["pub ", "#[", "impl ", "fn ", "}", ")"]
+            .iter()
+            .any(|start| text.starts_with(start))
+        || (text.starts_with("use ") && text.ends_with(';'));
+    if code {
+        None
+    } else {
+        Some(text.trim_end())
+    }
+}
+
+/// Whether a doc comment may precede the line: an item, an attribute or an enum variant.
+fn documentable(line: &str) -> bool {
+    let line = line.trim();
+    if line.starts_with("#[") || line.starts_with("pub ") {
+        return true;
+    }
+
+    let name = line.split(&['(', ','][..]).next().unwrap_or_default();
+    line != name
+        && name.starts_with(|c: char| c.is_ascii_uppercase())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_documentation() {
+        let code = "\
+// pub type Capabilities = DeviceServiceCapabilities;
+// Capabilities of the device.
+#[derive(Default)]
+pub struct Capabilities {
+    // The ForcePersistence element is obsolete and should always be assumed to
+    // be true.
+    #[yaserde(prefix = \"trt\", rename = \"ForcePersistence\")]
+    pub force_persistence: bool,
+}
+
+pub enum Mode {
+    // Standard mode.
+    Standard,
+    __Unknown__(String),
+}
+
+// Not above an item.
+
+// pub struct Commented {
+//     // Documentation of a commented out field.
+//     pub field: bool,
+// }
+pub fn f() {
+    // A statement.
+    transport::request(transport, request).await
+}
+";
+        let expected = "\
+// pub type Capabilities = DeviceServiceCapabilities;
+/// Capabilities of the device.
+#[derive(Default)]
+pub struct Capabilities {
+    /// The ForcePersistence element is obsolete and should always be assumed to
+    /// be true.
+    #[yaserde(prefix = \"trt\", rename = \"ForcePersistence\")]
+    pub force_persistence: bool,
+}
+
+pub enum Mode {
+    /// Standard mode.
+    Standard,
+    __Unknown__(String),
+}
+
+// Not above an item.
+
+// pub struct Commented {
+//     // Documentation of a commented out field.
+//     pub field: bool,
+// }
+pub fn f() {
+    // A statement.
+    transport::request(transport, request).await
+}
+";
+        assert_eq!(doc_comments(code), expected);
+        assert_eq!(doc_comments(expected), expected);
+    }
+}
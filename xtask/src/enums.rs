@@ -0,0 +1,54 @@
+//! Marks the generated enums `#[non_exhaustive]`.
+//!
+//! Enumerations of the schemas are extended by new ONVIF versions. Their generated enums already
+//! have an `__Unknown__(String)` variant for values a device reports that the schema didn't know,
+//! and `#[non_exhaustive]` lets regenerating them with new values be a non-breaking change.
+
+const ATTRIBUTE: &str = "#[non_exhaustive]";
+
+pub fn non_exhaustive(code: &str) -> String {
+    let mut out = String::with_capacity(code.len());
+    let mut previous = "";
+    for line in code.lines() {
+        if line.trim_start().starts_with("pub enum ") && previous.trim() != ATTRIBUTE {
+            let indent = &line[..line.len() - line.trim_start().len()];
+            out += indent;
+            out += ATTRIBUTE;
+            out.push('\n');
+        }
+        out += line;
+        out.push('\n');
+        previous = line;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn marks_enums() {
+        let code = "\
+#[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = \"tt\", namespace = \"tt: http://www.onvif.org/ver10/schema\")]
+pub enum DiscoveryMode {
+    Discoverable,
+    NonDiscoverable,
+    __Unknown__(String),
+}
+";
+        let expected = "\
+#[derive(PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = \"tt\", namespace = \"tt: http://www.onvif.org/ver10/schema\")]
+#[non_exhaustive]
+pub enum DiscoveryMode {
+    Discoverable,
+    NonDiscoverable,
+    __Unknown__(String),
+}
+";
+        assert_eq!(non_exhaustive(code), expected);
+        assert_eq!(non_exhaustive(expected), expected);
+    }
+}
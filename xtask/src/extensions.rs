@@ -0,0 +1,153 @@
+//! Keeps nested extensions of the generated structs as raw XML.
+//!
+//! Many `*Extension` types of the schemas contain an `Extension` element of the type of the same
+//! name with a `2` appended. yaserde loops forever deserializing an `Extension` inside an
+//! `Extension` (https://github.com/media-io/yaserde/issues/76), so such fields are typed
+//! `Option<RawXml>` instead, which also keeps the vendor specific content.
+
+const COMMENT: [&str; 3] = [
+    "/// `Extension` inside `Extension` causes infinite loop at deserialization",
+    "/// https://github.com/media-io/yaserde/issues/76",
+    "/// so it's kept as raw XML.",
+];
+
+const IMPORT: &str = "use crate::raw::RawXml;\n";
+
+/// Rewrites the fields of `*Extension` structs whose type is the struct's name with a `2`
+/// appended, optional or not, as `Option<RawXml>`, and imports `RawXml` if the module doesn't
+/// already.
+pub fn raw_extensions(code: &str) -> String {
+    let mut lines: Vec<String> = vec![];
+    let mut structure = "";
+    let mut rewritten = false;
+
+    for line in code.lines() {
+        let trimmed = line.trim_start();
+        if let Some(name) = trimmed
+            .strip_prefix("pub struct ")
+            .and_then(|rest| rest.strip_suffix(" {"))
+        {
+            structure = name;
+        } else if trimmed == "}" {
+            structure = "";
+        }
+
+        match nested_extension(trimmed, structure) {
+            Some(field) => {
+                let indent = &line[..line.len() - trimmed.len()];
+                let attribute = match lines.last() {
+                    Some(last) if last.trim_start().starts_with("#[yaserde(") => lines.pop(),
+                    _ => None,
+                };
+                for comment in COMMENT.iter() {
+                    lines.push(format!("{}{}", indent, comment));
+                }
+                lines.extend(attribute);
+                lines.push(format!("{}pub {}: Option<RawXml>,", indent, field));
+                rewritten = true;
+            }
+            None => lines.push(line.to_string()),
+        }
+    }
+
+    let mut out = String::with_capacity(code.len() + IMPORT.len());
+    if rewritten && !code.contains("raw::RawXml") {
+        out += IMPORT;
+    }
+    for line in lines {
+        out += &line;
+        out.push('\n');
+    }
+    out
+}
+
+/// The name of the field declared by `line` if it has the nested extension type of `structure`.
+fn nested_extension<'a>(line: &'a str, structure: &str) -> Option<&'a str> {
+    // Not only a suffix, as in `ImagingSettingsExtension20`.
+    if !structure.contains("Extension") {
+        return None;
+    }
+    let (field, ty) = line
+        .strip_prefix("pub ")?
+        .strip_suffix(',')?
+        .split_once(": ")?;
+    let ty = ty
+        .strip_prefix("Option<")
+        .and_then(|ty| ty.strip_suffix('>'))
+        .unwrap_or(ty);
+    if ty.strip_prefix(structure)? == "2" {
+        Some(field)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_nested_extensions_raw() {
+        let code = "\
+use crate::validate::Validate;
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = \"tt\", namespace = \"tt: http://www.onvif.org/ver10/schema\")]
+pub struct IocapabilitiesExtension {
+    #[yaserde(prefix = \"tt\", rename = \"Auxiliary\")]
+    pub auxiliary: Option<bool>,
+    #[yaserde(prefix = \"tt\", rename = \"Extension\")]
+    pub extension: IocapabilitiesExtension2,
+}
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = \"tt\", namespace = \"tt: http://www.onvif.org/ver10/schema\")]
+pub struct ImagingSettingsExtension20 {
+    #[yaserde(prefix = \"tt\", rename = \"Extension\")]
+    pub extension: Option<ImagingSettingsExtension202>,
+}
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = \"tt\", namespace = \"tt: http://www.onvif.org/ver10/schema\")]
+pub struct Profile {
+    #[yaserde(prefix = \"tt\", rename = \"Extension\")]
+    pub extension: Option<ProfileExtension>,
+}
+";
+        let expected = "\
+use crate::raw::RawXml;
+use crate::validate::Validate;
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = \"tt\", namespace = \"tt: http://www.onvif.org/ver10/schema\")]
+pub struct IocapabilitiesExtension {
+    #[yaserde(prefix = \"tt\", rename = \"Auxiliary\")]
+    pub auxiliary: Option<bool>,
+    /// `Extension` inside `Extension` causes infinite loop at deserialization
+    /// https://github.com/media-io/yaserde/issues/76
+    /// so it's kept as raw XML.
+    #[yaserde(prefix = \"tt\", rename = \"Extension\")]
+    pub extension: Option<RawXml>,
+}
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = \"tt\", namespace = \"tt: http://www.onvif.org/ver10/schema\")]
+pub struct ImagingSettingsExtension20 {
+    /// `Extension` inside `Extension` causes infinite loop at deserialization
+    /// https://github.com/media-io/yaserde/issues/76
+    /// so it's kept as raw XML.
+    #[yaserde(prefix = \"tt\", rename = \"Extension\")]
+    pub extension: Option<RawXml>,
+}
+
+#[derive(Default, PartialEq, Debug, YaSerialize, YaDeserialize)]
+#[yaserde(prefix = \"tt\", namespace = \"tt: http://www.onvif.org/ver10/schema\")]
+pub struct Profile {
+    #[yaserde(prefix = \"tt\", rename = \"Extension\")]
+    pub extension: Option<ProfileExtension>,
+}
+";
+        assert_eq!(raw_extensions(code), expected);
+        assert_eq!(raw_extensions(expected), expected);
+    }
+}
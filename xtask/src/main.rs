@@ -0,0 +1,322 @@
+//! Development tasks, run with `cargo xtask <task>`.
+
+mod derives;
+mod docs;
+mod enums;
+mod extensions;
+mod operations;
+mod sources;
+
+use sha2::{Digest, Sha256};
+use sources::{Source, SOURCES};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+use structopt::StructOpt;
+
+const GENERATOR_GIT: &str = "https://github.com/lumeohq/xsd-parser-rs";
+
+// The generated code depends on `xsd-types` and `xsd-macro-utils`, so this must be the same
+// revision as in `schema/Cargo.toml`.
+const GENERATOR_REV: &str = "7f3d433";
+
+#[derive(StructOpt)]
+#[structopt(name = "xtask")]
+enum Task {
+    /// Regenerate `schema` modules from the pinned schema files.
+    Codegen {
+        /// Don't write anything, fail if a module differs from what would be generated.
+        #[structopt(long)]
+        check: bool,
+
+        /// Modules to generate, e.g. `ptz` or `xop/include`. All modules if not specified.
+        modules: Vec<String>,
+    },
+
+    /// Regenerate the table of all operations, `schema/src/operations/generated.rs`, after
+    /// regenerating modules.
+    Operations {
+        /// Don't write anything, fail if the table differs from what would be generated.
+        #[structopt(long)]
+        check: bool,
+    },
+
+    /// Reapply the post-processing of the generator output, e.g. doc comments and derives, to the
+    /// checked-in modules without running the generator.
+    Postprocess {
+        /// Don't write anything, fail if a module isn't already post-processed.
+        #[structopt(long)]
+        check: bool,
+    },
+
+    /// Check that the schema files match their pinned checksums.
+    VerifySources,
+}
+
+fn main() {
+    if let Err(error) = run(Task::from_args()) {
+        eprintln!("Error: {}", error);
+        std::process::exit(1);
+    }
+}
+
+fn run(task: Task) -> Result<(), String> {
+    match task {
+        Task::Codegen { check, modules } => codegen(check, &modules),
+        Task::Operations { check } => {
+            let path = root().join(operations::PATH);
+            let generated = operations::generate()?;
+            if fs::read_to_string(&path).ok().as_deref() == Some(generated.as_str()) {
+                Ok(())
+            } else if check {
+                Err(format!(
+                    "{} differs from the generated table",
+                    operations::PATH
+                ))
+            } else {
+                println!("Writing {}", path.display());
+                fs::write(&path, generated).map_err(|e| format!("{}: {}", path.display(), e))
+            }
+        }
+        Task::Postprocess { check } => postprocess_modules(check),
+        Task::VerifySources => {
+            for source in SOURCES {
+                verify(source)?;
+            }
+            println!("All {} schema files match their checksums.", SOURCES.len());
+            Ok(())
+        }
+    }
+}
+
+fn codegen(check: bool, modules: &[String]) -> Result<(), String> {
+    let sources: Vec<&Source> = if modules.is_empty() {
+        SOURCES.iter().collect()
+    } else {
+        modules
+            .iter()
+            .map(|module| {
+                SOURCES
+                    .iter()
+                    .find(|s| s.module == module.as_str())
+                    .ok_or_else(|| format!("unknown module {}, see xtask/src/sources.rs", module))
+            })
+            .collect::<Result<_, _>>()?
+    };
+
+    let mut outdated = vec![];
+    for source in sources {
+        verify(source)?;
+
+        let path = root()
+            .join("schema/src")
+            .join(format!("{}.rs", source.module));
+        let existing = fs::read_to_string(&path).ok();
+        let generated = generate(source, existing.as_deref())?;
+
+        if existing.as_deref() == Some(generated.as_str()) {
+            continue;
+        }
+
+        if check {
+            outdated.push(source.module);
+        } else {
+            println!("Writing {}", path.display());
+            fs::write(&path, generated).map_err(|e| format!("{}: {}", path.display(), e))?;
+        }
+    }
+
+    if outdated.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "modules differ from the generator output: {}",
+            outdated.join(", ")
+        ))
+    }
+}
+
+/// Applies [`postprocess`] to every checked-in module. It's idempotent, so any difference means a
+/// module was edited by hand or generated before a pass was added.
+fn postprocess_modules(check: bool) -> Result<(), String> {
+    let mut outdated = vec![];
+    for source in SOURCES {
+        let path = root()
+            .join("schema/src")
+            .join(format!("{}.rs", source.module));
+        let existing =
+            fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let processed = postprocess(&existing);
+
+        if existing == processed {
+            continue;
+        }
+
+        if check {
+            outdated.push(source.module);
+        } else {
+            println!("Writing {}", path.display());
+            fs::write(&path, processed).map_err(|e| format!("{}: {}", path.display(), e))?;
+        }
+    }
+
+    if outdated.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "modules differ from their post-processed version: {}",
+            outdated.join(", ")
+        ))
+    }
+}
+
+fn verify(source: &Source) -> Result<(), String> {
+    let path = root().join(source.path);
+    let bytes = fs::read(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let sha256 = format!("{:x}", Sha256::digest(&bytes));
+
+    if sha256 == source.sha256 {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} has SHA-256 {}, but {} is pinned in xtask/src/sources.rs",
+            source.path, sha256, source.sha256
+        ))
+    }
+}
+
+/// Runs the generator on a schema and returns the formatted module, with the schema
+/// annotations as doc comments and non-exhaustive enums.
+///
+/// The generator doesn't know which Rust modules other namespaces are mapped to, so the `use`
+/// declarations at the top of the existing module are kept as they are. New modules get a
+/// minimal set which usually has to be extended by hand.
+fn generate(source: &Source, existing: Option<&str>) -> Result<String, String> {
+    let out_dir = root().join("target/xtask/out");
+    fs::create_dir_all(&out_dir).map_err(|e| e.to_string())?;
+    let raw = out_dir.join(format!("{}.rs", source.module.replace('/', "_")));
+
+    let generator = if source.is_wsdl() {
+        "wsdl-parser"
+    } else {
+        "xsd-parser"
+    };
+    let status = Command::new(install(generator)?)
+        .arg("--input")
+        .arg(root().join(source.path))
+        .arg("--output")
+        .arg(&raw)
+        .status()
+        .map_err(|e| format!("failed to run {}: {}", generator, e))?;
+    if !status.success() {
+        return Err(format!("{} failed on {}", generator, source.path));
+    }
+
+    let prelude = match existing {
+        Some(existing) => prelude(existing),
+        None if source.is_wsdl() => {
+            "use crate::transport;\nuse crate::validate::Validate;\n".into()
+        }
+        None => "use crate::validate::Validate;\n".into(),
+    };
+    let code = fs::read_to_string(&raw).map_err(|e| e.to_string())?;
+    fs::write(&raw, format!("{}\n{}", prelude.trim_end(), code)).map_err(|e| e.to_string())?;
+
+    let status = Command::new("rustfmt")
+        .args(&["--edition", "2018"])
+        .arg(&raw)
+        .status()
+        .map_err(|e| format!("failed to run rustfmt: {}", e))?;
+    if !status.success() {
+        return Err(format!("rustfmt failed on the output for {}", source.path));
+    }
+
+    let formatted = fs::read_to_string(&raw).map_err(|e| e.to_string())?;
+    Ok(postprocess(&formatted))
+}
+
+/// Rewrites the formatted generator output: schema annotations become doc comments, nested
+/// extensions raw XML, identifier newtypes hashable and enums non-exhaustive.
+fn postprocess(formatted: &str) -> String {
+    let code = extensions::raw_extensions(&docs::doc_comments(formatted));
+    let code = derives::hashable(&code);
+    enums::non_exhaustive(&code)
+}
+
+/// Returns the leading `use` declarations of a module.
+fn prelude(module: &str) -> String {
+    let mut prelude = String::new();
+    let mut depth = 0;
+
+    for line in module.lines() {
+        let trimmed = line.trim();
+        let is_use = trimmed.starts_with("use ") || trimmed.starts_with("pub use ");
+        if depth == 0 && !is_use && !trimmed.is_empty() {
+            break;
+        }
+
+        depth += trimmed.matches('{').count() as i32 - trimmed.matches('}').count() as i32;
+        prelude.push_str(line);
+        prelude.push('\n');
+    }
+
+    prelude
+}
+
+/// Installs a generator binary at the pinned revision, unless already installed, and returns its
+/// path.
+fn install(generator: &str) -> Result<PathBuf, String> {
+    let dir = root().join("target/xtask").join(GENERATOR_REV);
+    let binary = dir.join("bin").join(generator);
+    if binary.exists() {
+        return Ok(binary);
+    }
+
+    println!("Installing {} {}", generator, GENERATOR_REV);
+    let status = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".into()))
+        .args(&[
+            "install",
+            "--git",
+            GENERATOR_GIT,
+            "--rev",
+            GENERATOR_REV,
+            "--root",
+        ])
+        .arg(&dir)
+        .arg(generator)
+        .status()
+        .map_err(|e| format!("failed to run cargo install: {}", e))?;
+    if !status.success() {
+        return Err(format!("failed to install {}", generator));
+    }
+
+    Ok(binary)
+}
+
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_use_declarations() {
+        let module = "use crate::transport;\nuse crate::{\n    b_2 as wsnt,\n    onvif as tt,\n};\n\n// Comment.\n#[derive(Default)]\npub struct A {}\n";
+
+        assert_eq!(
+            prelude(module),
+            "use crate::transport;\nuse crate::{\n    b_2 as wsnt,\n    onvif as tt,\n};\n\n"
+        );
+    }
+
+    #[test]
+    fn sources_are_unique() {
+        for (i, source) in SOURCES.iter().enumerate() {
+            assert!(SOURCES[i + 1..].iter().all(|s| s.module != source.module));
+        }
+    }
+}
@@ -0,0 +1,142 @@
+//! Generates `schema/src/operations/generated.rs`, the table of all operations of the WSDL
+//! modules.
+//!
+//! Operations are taken from the functions of the generated modules, and their SOAP actions from
+//! the `soap:operation` elements of the WSDL bindings.
+
+use crate::sources::SOURCES;
+use std::{collections::HashMap, fs};
+
+pub const PATH: &str = "schema/src/operations/generated.rs";
+
+struct Operation {
+    service: &'static str,
+    name: String,
+    namespace: String,
+    action: String,
+    request: String,
+    response: String,
+}
+
+/// Returns the formatted table module.
+pub fn generate() -> Result<String, String> {
+    let mut operations = vec![];
+    for source in SOURCES.iter().filter(|s| s.is_wsdl()) {
+        let module_path = crate::root()
+            .join("schema/src")
+            .join(format!("{}.rs", source.module));
+        let module =
+            fs::read_to_string(&module_path).map_err(|e| format!("{}: {}", source.module, e))?;
+        let wsdl = fs::read_to_string(crate::root().join(source.path))
+            .map_err(|e| format!("{}: {}", source.path, e))?;
+
+        let namespace = attribute(&wsdl, "targetNamespace")
+            .ok_or_else(|| format!("{} has no targetNamespace", source.path))?;
+        let actions = actions(&wsdl);
+
+        let lines: Vec<&str> = module.lines().collect();
+        for (i, line) in lines.iter().enumerate() {
+            // Commented out functions start with `//`.
+            let function = match line.strip_prefix("pub async fn ") {
+                Some(rest) => rest.split('<').next().unwrap_or_default(),
+                None => continue,
+            };
+            let parameter = |n: usize, prefix: &str, end: char| {
+                lines
+                    .get(i + n)
+                    .and_then(|l| l.trim().strip_prefix(prefix))
+                    .and_then(|l| l.split(end).next())
+                    .map(str::to_string)
+                    .ok_or_else(|| {
+                        format!("unexpected signature of {}::{}", source.module, function)
+                    })
+            };
+            let request = parameter(2, "request: &", ',')?;
+            let response = parameter(3, ") -> Result<", ',')?;
+
+            let (name, action) = match actions.get(&normalize(function)) {
+                Some((name, action)) => (name.clone(), action.clone()),
+                None => (request.clone(), String::new()),
+            };
+            operations.push(Operation {
+                service: source.module,
+                name,
+                namespace: namespace.clone(),
+                action,
+                request,
+                response,
+            });
+        }
+    }
+
+    let mut table = String::from(
+        "// Generated by `cargo xtask operations` from the WSDL modules, do not edit.\n\nuse super::Operation;\n\npub(super) const OPERATIONS: &[Operation] = &[\n",
+    );
+    for operation in &operations {
+        table += &format!(
+            "    Operation {{\n        service: \"{}\",\n        name: \"{}\",\n        namespace: \"{}\",\n        action: \"{}\",\n        request: \"{}\",\n        response: \"{}\",\n    }},\n",
+            operation.service,
+            operation.name,
+            operation.namespace,
+            operation.action,
+            operation.request,
+            operation.response
+        );
+    }
+    table += "];\n";
+    Ok(table)
+}
+
+/// Operation names and SOAP actions of the bindings, by normalized operation name. The first
+/// binding wins where several declare the same operation.
+fn actions(wsdl: &str) -> HashMap<String, (String, String)> {
+    let mut actions = HashMap::new();
+    let mut parts = wsdl.split("soapAction=\"");
+    let mut before = parts.next().unwrap_or_default();
+    for part in parts {
+        let name = before
+            .rsplit("operation name=\"")
+            .next()
+            .and_then(|rest| rest.split('"').next())
+            .unwrap_or_default();
+        let action = part.split('"').next().unwrap_or_default();
+        actions
+            .entry(normalize(name))
+            .or_insert_with(|| (name.to_string(), action.to_string()));
+        before = part;
+    }
+    actions
+}
+
+fn attribute(xml: &str, name: &str) -> Option<String> {
+    let value = xml.split(&format!("{}=\"", name)).nth(1)?;
+    value.split('"').next().map(str::to_string)
+}
+
+/// Makes `GetOSDs` and the generated function name `get_os_ds` comparable.
+fn normalize(name: &str) -> String {
+    name.replace('_', "").to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binding_actions() {
+        let wsdl = r#"
+            <wsdl:binding name="DeviceBinding" type="tds:Device">
+                <wsdl:operation name="GetOSDs">
+                    <soap:operation soapAction="http://www.onvif.org/ver10/media/wsdl/GetOSDs"/>
+                </wsdl:operation>
+            </wsdl:binding>"#;
+
+        assert_eq!(
+            actions(wsdl).get(&normalize("get_os_ds")),
+            Some(&(
+                "GetOSDs".to_string(),
+                "http://www.onvif.org/ver10/media/wsdl/GetOSDs".to_string()
+            ))
+        );
+    }
+}
@@ -0,0 +1,220 @@
+//! Schema files the `schema` crate is generated from.
+//!
+//! Every file is pinned by its SHA-256 so that regenerating a module can't silently pick up a
+//! locally modified or re-downloaded schema. To update a schema or add a new service, replace or
+//! add the file, then update or add its entry here (and a `pub mod` in `schema/src/lib.rs`).
+
+pub struct Source {
+    /// Path of the generated module relative to `schema/src`, without the `.rs` extension.
+    pub module: &'static str,
+
+    /// Path of the schema relative to the workspace root. `.wsdl.xml` files are generated with
+    /// the WSDL generator, everything else with the XSD generator.
+    pub path: &'static str,
+
+    pub sha256: &'static str,
+}
+
+impl Source {
+    pub fn is_wsdl(&self) -> bool {
+        self.path.ends_with(".wsdl.xml")
+    }
+}
+
+pub const SOURCES: &[Source] = &[
+    Source {
+        module: "accesscontrol",
+        path: "wsdl/accesscontrol.wsdl.xml",
+        sha256: "d27232c5e807ac644cff60d7e161066f7599fab6b59e7ea6350b5cfa3ca346c5",
+    },
+    Source {
+        module: "accessrules",
+        path: "wsdl/accessrules.wsdl.xml",
+        sha256: "d12e73370ad8ad5af31035703e313a975530b7da6cd673a5d9c3b2a68707a678",
+    },
+    Source {
+        module: "actionengine",
+        path: "wsdl/actionengine.wsdl.xml",
+        sha256: "99009175131d879d7b11490308a11f04797f1af5fd74f001aad83053d2bc1561",
+    },
+    Source {
+        module: "advancedsecurity",
+        path: "wsdl/advancedsecurity.wsdl.xml",
+        sha256: "2e57f12558ed79ff7e9d1b9ec083774c8b3e8e45ff590a590669fe3ecbe1c860",
+    },
+    Source {
+        module: "analytics",
+        path: "wsdl/analytics.wsdl.xml",
+        sha256: "39fe4909423250000c8afa8bff786edef539d923bd157cb8ab2e5da88cd06402",
+    },
+    Source {
+        module: "authenticationbehavior",
+        path: "wsdl/authenticationbehavior.wsdl.xml",
+        sha256: "f1e4cd8356bcbb5839ec908c06dc1e31dcc1773e63daa2e20bf40b84c510be56",
+    },
+    Source {
+        module: "b_2",
+        path: "xsd_external/b-2.xsd",
+        sha256: "26f0c7aa9a3719412e2e3275bc80320edb4b2dc9783bf2bb08c6378f4ca681fe",
+    },
+    Source {
+        module: "bf_2",
+        path: "xsd_external/bf-2.xsd",
+        sha256: "7863ccf5b21105c374a405968266fa18ecd0ebe0f7b9b19cd9378656229ff468",
+    },
+    Source {
+        module: "common",
+        path: "xsd/common.xsd",
+        sha256: "62f300f19b9c71d869f44b6e1b909fd09890ed43ad957e1bdf47aab356f256a2",
+    },
+    Source {
+        module: "credential",
+        path: "wsdl/credential.wsdl.xml",
+        sha256: "15632f97a7ce0d867f420e1ca3a48eb065bd0f3473be801a2a6fee498e12a034",
+    },
+    Source {
+        module: "deviceio",
+        path: "wsdl/deviceio.wsdl.xml",
+        sha256: "13f86e4d13f1f998757d32e6390ca35946976882fbb2bd91934365746ce5c0fb",
+    },
+    Source {
+        module: "devicemgmt",
+        path: "wsdl/devicemgmt.wsdl.xml",
+        sha256: "e1658bf59292831e68f9cbfd9178d6ed5f4da566d94b7e985c7a955b32f4ea3f",
+    },
+    Source {
+        module: "display",
+        path: "wsdl/display.wsdl.xml",
+        sha256: "086e4ffb4f33bca8ab6e2bf7cb52846336a3d509765eca46a43b989f6cbf499e",
+    },
+    Source {
+        module: "doorcontrol",
+        path: "wsdl/doorcontrol.wsdl.xml",
+        sha256: "be4fb9cd223c46997485fb42a59563d22a8a5c6057c6847dec05092a199e1ea7",
+    },
+    Source {
+        module: "event",
+        path: "wsdl/event.wsdl.xml",
+        sha256: "d2403724a70b4ab3443eb3289ece49086e64b0bcf4186d124c9cb22cf83cba68",
+    },
+    Source {
+        module: "imaging",
+        path: "wsdl/imaging.wsdl.xml",
+        sha256: "81b35e25bb4b633034440a0d63492bd93f06e2b9cf0ec400f4b145c11bd944a8",
+    },
+    Source {
+        module: "media",
+        path: "wsdl/media.wsdl.xml",
+        sha256: "91de00c8df6afa58a8016fd57e6c5b50efd0bf6f49891002df254aa7eb848d79",
+    },
+    Source {
+        module: "media2",
+        path: "wsdl/media2.wsdl.xml",
+        sha256: "63084c235d0a8cb93349db46fd3bb453b7727f04915de4eee8e7dc994b434ef5",
+    },
+    Source {
+        module: "metadatastream",
+        path: "xsd/metadatastream.xsd",
+        sha256: "de9d2fdfa84a657ca70c298d4b804449ea5142ba630a0610a5b0f2ba61978877",
+    },
+    Source {
+        module: "onvif",
+        path: "xsd/onvif.xsd",
+        sha256: "0ac53d414b91261e533e4db82445e64cbb9aa914e618e87b725d2e064a6de93d",
+    },
+    Source {
+        module: "provisioning",
+        path: "wsdl/provisioning.wsdl.xml",
+        sha256: "979804945410a7b9cb3c367d6648e39d3c2275d248ea4d8738620ea62c242077",
+    },
+    Source {
+        module: "ptz",
+        path: "wsdl/ptz.wsdl.xml",
+        sha256: "49582eadeac796cabfab5031c47b5fd870793df3ba44f44eb04a77971f4f22e8",
+    },
+    Source {
+        module: "radiometry",
+        path: "xsd/radiometry.xsd",
+        sha256: "53dd3846dba207267ea8d918332dc4ca0c19573766fd591bb94dc4bb7d3b1296",
+    },
+    Source {
+        module: "receiver",
+        path: "wsdl/receiver.wsdl.xml",
+        sha256: "9482904f7ea4e14a6db475655e9cbf35f6f22d8b07e0895f0e8919fd4c8408d7",
+    },
+    Source {
+        module: "recording",
+        path: "wsdl/recording.wsdl.xml",
+        sha256: "02539293d56395ac0f4fd30f355c4a4aa7d4c5147f6029d56385c3ce4cec514e",
+    },
+    Source {
+        module: "replay",
+        path: "wsdl/replay.wsdl.xml",
+        sha256: "4b1fa1e9175b7d364429cee0ab2f6098c9318696762d85cb29f6ec9fb2dd0d47",
+    },
+    Source {
+        module: "rules",
+        path: "xsd/rules.xsd",
+        sha256: "a60751ab82e97095d2e94f7e84bb7592aeba2f65af12ee8d941c0967e0a2d2dc",
+    },
+    Source {
+        module: "schedule",
+        path: "wsdl/schedule.wsdl.xml",
+        sha256: "9c091930a2089ac529eae2b9fd5739dd50b8b13ad4b1a53cf0134d7504da5d1f",
+    },
+    Source {
+        module: "search",
+        path: "wsdl/search.wsdl.xml",
+        sha256: "5ebc8b30455ae0cc1b0c16cc0d8703909e47a5241ef076403a8e5767e144d50c",
+    },
+    Source {
+        module: "soap_envelope",
+        path: "xsd_external/soap-envelope.xsd",
+        sha256: "b90e21611d8bc7f92f1094a92463e7137c225da34d90125c0a01c130bff4ed43",
+    },
+    Source {
+        module: "t_1",
+        path: "xsd_external/t-1.xsd",
+        sha256: "ad5db0432a3885d1c8a574ba6b4058193af29bc971232c4bb6b31f8295fe3329",
+    },
+    Source {
+        module: "thermal",
+        path: "wsdl/thermal.wsdl.xml",
+        sha256: "806bf8253118c2d5d6ed14b6244ab6b7bde8dba8fe76f75fad18acfd19500461",
+    },
+    Source {
+        module: "types",
+        path: "xsd/types.xsd",
+        sha256: "e333569c8c9ad49eb7ad0a9b6c84d998523ecc14b6def5783ec307774b197ee2",
+    },
+    Source {
+        module: "uplink",
+        path: "wsdl/uplink.wsdl.xml",
+        sha256: "0194087b1b5c2efa71be9eaf7de435e4a37a1810f2e7e9ecc325a69174737f17",
+    },
+    Source {
+        module: "ws_addr",
+        path: "xsd_external/ws-addr.xsd",
+        sha256: "eacfd086db8e98692f0326b2605c6ac5954682c628fae2b62dc2bbcbdcdd3165",
+    },
+    Source {
+        module: "ws_discovery",
+        path: "xsd_external/ws-discovery.xsd",
+        sha256: "4e6b5c4be16af5d1f17d82e73f5abdd305b670e7dfac276464161dc898e12c85",
+    },
+    Source {
+        module: "xml_xsd",
+        path: "xsd_external/xml.xsd",
+        sha256: "b6f06a7bf96ac53c0cd6391b0e1e94894922326061868ca9246838378fe4eb0e",
+    },
+    Source {
+        module: "xmlmime",
+        path: "xsd_external/xmlmime.xsd",
+        sha256: "e58461673d6ce74279686194d9d875bd2d756bf316eedac2d98a34a8b61158e9",
+    },
+    Source {
+        module: "xop/include",
+        path: "xsd_external/include.xsd",
+        sha256: "dff9100efce4afb21de0cf673fb7fad103672a169862ab0f12e34c312b20e60d",
+    },
+];